@@ -30,14 +30,29 @@ menu_bar_debug = &Debug
 ## PackFile Menu
 
 new_packfile = &New PackFile
+new_packfile_from_folder = New PackFile from &Folder...
 open_packfile = &Open PackFile
 save_packfile = &Save PackFile
 save_packfile_as = Save PackFile &As...
+browse_workshop = &Browse Workshop...
 load_all_ca_packfiles = &Load All CA PackFiles
+compare_packfiles = Co&mpare PackFiles
+merge_tables_from_packfiles = Merge &Tables from PackFiles
+run_job_file = Run &Job File
+trash = T&rash
+create_snapshot = Create Snaps&hot
+snapshots = Sna&pshots...
+diagnostics_ignore_list = &Diagnostics Ignore List
+generate_checksum_manifest = &Generate Checksum Manifest
+verify_checksum_manifest = &Verify Checksum Manifest
+export_contents_manifest = E&xport Contents Manifest
+split_loc_by_language = Split &Loc by Language
+normalize_timestamps = &Normalize Timestamps
 preferences = &Preferences
 quit = &Quit
 open_from_content = Open From Content
 open_from_data = Open From Data
+open_game_packfile = Open Game PackFile (Read-Only)
 change_packfile_type = &Change PackFile Type
 
 ## Change Packfile Type Menu
@@ -61,10 +76,15 @@ mymod_new = &New MyMod
 mymod_delete_selected = &Delete Selected MyMod
 mymod_install = &Install
 mymod_uninstall = &Uninstall
+mymod_upload_to_workshop = Upload to &Workshop
+mymod_git_commit = &Commit MyMod
+mymod_live_sync = &Live Sync Assets
 
 mymod_name = Name of the Mod:
 mymod_name_default = For example: one_ring_for_me
 mymod_game = Game of the Mod:
+mymod_template = Template:
+mymod_template_none = None
 
 ## View Menu
 
@@ -74,9 +94,11 @@ view_toggle_global_search_panel = Toggle Global Search Window
 ## Game Selected Menu
 
 game_selected_launch_game = Launch Game Selected
+game_selected_launch_game_with_packfile = Launch Game Selected with this PackFile
 game_selected_open_game_data_folder = Open Game's Data Folder
 game_selected_open_game_assembly_kit_folder = Open Game's Assembly Kit Folder
 game_selected_open_config_folder = Open RPFM's Config Folder
+game_selected_search_vanilla_files = Search Vanilla Files...
 
 ## Special Stuff
 
@@ -91,9 +113,12 @@ special_stuff_select_raw_db_folder = Select Raw DB Folder
 about_about_qt = About &Qt
 about_about_rpfm = About RPFM
 about_open_manual = &Open Manual
+about_start_tour = Start &Tour
 about_patreon_link = &Support me on Patreon
 about_check_updates = &Check Updates
 about_check_schema_updates = Check Schema &Updates
+about_export_schema_bundle = &Export Schemas
+about_import_schema_bundle = &Import Schemas
 
 ## Debug Menu
 
@@ -139,9 +164,23 @@ open_packfiles = Open PackFiles
 ## PackFile menu tips
 
 tt_packfile_new_packfile = Creates a new PackFile and open it. Remember to save it later if you want to keep it!
+tt_packfile_new_packfile_from_folder = Creates a new PackFile with the files of a folder, named after it. TSV files exported by RPFM are imported back into their tables.
 tt_packfile_open_packfile = Open an existing PackFile, or multiple existing PackFiles into one.
 tt_packfile_save_packfile = Save the changes made in the currently open PackFile to disk.
 tt_packfile_save_packfile_as = Save the currently open PackFile as a new PackFile, instead of overwriting the original one.
+tt_packfile_browse_workshop = Open a list of the Workshop mods downloaded for the currently selected game, with their names, so you can open or copy their PackFiles without looking for them by their Workshop ID.
+tt_packfile_compare_packfiles = Compare two PackFiles (or the open one against another one, like a vanilla PackFile), showing the PackedFiles added, removed and modified, and the rows changed in the modified DB/Loc Tables.
+tt_packfile_merge_tables_from_packfiles = Merge the DB/Loc Tables edited by two PackFiles into the open PackFile, choosing which version wins for each conflicting row. Useful to make compatibility patches between mods.
+tt_packfile_run_job_file = Run a JSON/YAML Job File: a list of steps (open or create a PackFile, import TSVs, optimize it, save it,...) executed one after another over the open PackFile.
+tt_packfile_trash = Open the list of PackedFiles deleted since the PackFile was last saved, to restore them. The trash is emptied when the PackFile is saved or closed.
+tt_packfile_create_snapshot = Take a snapshot of the open PackFile as it's now, unsaved changes included, so you can roll back to it later without closing it. Snapshots are discarded when the PackFile is saved or closed.
+tt_packfile_snapshots = Open the list of snapshots of the open PackFile, to roll back to one of them or delete them.
+tt_packfile_diagnostics_ignore_list = Open the list of diagnostic findings ignored for this PackFile. Each line is a path (a file or a folder, like db/units_tables), optionally followed by ':' and the name of a column. The list is saved within the PackFile.
+tt_packfile_generate_checksum_manifest = Generate a manifest with the SHA256 of the saved PackFile and of each PackedFile in it, next to the PackFile. Distribute it with your mod so others can check they have the right version.
+tt_packfile_verify_checksum_manifest = Check the saved PackFile against a checksum manifest, reporting the PackedFiles missing, added or changed.
+tt_packfile_export_contents_manifest = Export the list of PackedFiles in the PackFile, with their sizes, types and SHA256, as JSON or plain text. Unsaved changes are included.
+tt_packfile_split_loc_by_language = Copy the Loc PackedFiles of each language of the Game Selected to their own PackFile (my_mod_local_fr.pack,...) in the chosen folder. Locs belong to a language if they're in a folder named like it (text/fr/) or their name ends with it (my_mod_fr.loc). The language is removed from their paths in the new PackFiles.
+tt_packfile_normalize_timestamps = Set the timestamps of all the PackedFiles of the PackFile to 0, so they don't make the PackFile change between saves if their contents don't change.
 tt_packfile_load_all_ca_packfiles = Try to load every PackedFile from every vanilla PackFile of the selected game into RPFM at the same time, using lazy-loading to load the PackedFiles. Keep in mind that if you try to save it, your PC may die.
 tt_packfile_preferences = Open the Preferences/Settings dialog.
 tt_packfile_quit = Exit the Program.
@@ -165,13 +204,18 @@ tt_mymod_new = Open the dialog to create a new MyMod.
 tt_mymod_delete_selected = Delete the currently selected MyMod.
 tt_mymod_install = Copy the currently selected MyMod into the data folder of the GameSelected.
 tt_mymod_uninstall = Removes the currently selected MyMod from the data folder of the GameSelected.
+tt_mymod_upload_to_workshop = Publish the currently selected MyMod to the Steam Workshop, or update it if it has already been published. Requires SteamCMD.
+tt_mymod_git_commit = Extract the currently selected MyMod to its folder and commit it to its Git repository. Requires Git for MyMods to be enabled in the settings.
+tt_mymod_live_sync = Watch the assets folder of the currently selected MyMod, and add to its PackFile every file created or modified there. Stays enabled for this MyMod until you disable it.
 
 ## GameSelected menu tips
 
 tt_game_selected_launch_game = Tries to launch the currently selected game on steam.
+tt_game_selected_launch_game_with_packfile = Writes the list of mods of the currently selected game (used_mods.txt or user.script.txt) so it loads the open PackFile, and the other MyMods of the game if it is a MyMod, and launches the game.
 tt_game_selected_open_game_data_folder = Tries to open the currently selected game's Data folder (if exists) in the default file manager.
 tt_game_selected_open_game_assembly_kit_folder = Tries to open the currently selected game's Assembly Kit folder (if exists) in the default file manager.
 tt_game_selected_open_config_folder = Tries to open RPFM's config folder, where the config/schemas/ctd reports are.
+tt_game_selected_search_vanilla_files = Quickly search a text in the DB and Loc Tables of the currently selected game, and in the PackFiles the open one depends on, without opening them. The matches are read-only.
 
 tt_game_selected_troy = Sets 'TW:Troy' as 'Game Selected'.
 tt_game_selected_three_kingdoms = Sets 'TW:Three Kingdoms' as 'Game Selected'.
@@ -188,7 +232,7 @@ tt_game_selected_arena = Sets 'TW:Arena' as 'Game Selected'.
 ## Special Stuff menu tips
 
 tt_generate_pak_file = Generates a PAK File (Processed Assembly Kit File) for the game selected, to help with dependency checking.
-tt_optimize_packfile = Check and remove any data in DB Tables and Locs (Locs only for english users) that is unchanged from the base game. That means your mod will only contain the stuff you change, avoiding incompatibilities with other mods.
+tt_optimize_packfile = Check and remove any data in DB Tables and Locs (Locs only for english users) that is unchanged from the base game, any Loc entry for rows that no longer exist, and any table that ends up empty. That means your mod will only contain the stuff you change, avoiding incompatibilities with other mods.
 tt_patch_siege_ai = Patch & Clean an exported map's PackFile. It fixes the Siege AI (if it has it) and remove useless xml files that bloat the PackFile, reducing his size.
 
 ## About menu tips
@@ -196,9 +240,12 @@ tt_patch_siege_ai = Patch & Clean an exported map's PackFile. It fixes the Siege
 tt_about_about_qt = Info about Qt, the UI Toolkit used to make this program.
 tt_about_about_rpfm = Info about RPFM.
 tt_about_open_manual = Open RPFM's Manual in a PDF Reader.
+tt_about_start_tour = Start the guided tour through the main parts of RPFM, the one shown the first time you open it.
 tt_about_patreon_link = Open RPFM's Patreon page. Even if you are not interested in becoming a Patron, check it out. I post info about the next updates and in-dev features from time to time.
 tt_about_check_updates = Checks if there is any update available for RPFM.
 tt_about_check_schema_updates = Checks if there is any update available for the schemas. This is what you have to use after a game's patch.
+tt_about_export_schema_bundle = Exports the schemas of all games to a single file, so they can be imported in computers without access to the schema repository.
+tt_about_import_schema_bundle = Replaces the schemas of all games with the ones in a file exported with "Export Schemas". The current schemas are backed up first.
 
 ### global_search_ui/mod.rs
 
@@ -210,6 +257,19 @@ global_search_replace_all = Replace All
 global_search_clear = Clear
 global_search_case_sensitive = Case Sensitive
 global_search_use_regex = Use Regex
+global_search_path_filter = Limit to paths (e.g. db/units_*)
+global_search_scope_packfile = Open PackFile Only
+global_search_scope_packfile_and_parents = Open PackFile + Parent Mods
+global_search_scope_all_game_packs = All Game PackFiles
+global_search_history = History
+global_search_history_pinned = Pinned Searches
+global_search_history_recent = Recent Searches
+global_search_history_empty = No searches yet
+global_search_history_pin = Pin Current Search
+global_search_history_unpin = Unpin Current Search
+global_search_history_clear = Clear History
+global_search_match_source = These matches are from '{"{"}{"}"}', not from the open PackFile. They're read-only.
+global_search_match_read_only = This match is from '{"{"}{"}"}', not from the open PackFile, so it cannot be opened or replaced.
 global_search_search_on = Search On
 
 global_search_all = All
@@ -236,6 +296,9 @@ global_search_column_index = Column Index
 
 tt_global_search_use_regex_checkbox = Enable search using Regex. Keep in mind that RPFM will fallback to a normal pattern search if the provided Regex is invalid.
 tt_global_search_case_sensitive_checkbox = Enable case sensitive search. Pretty self-explanatory.
+tt_global_search_history_button = Repeat one of your last searches, with the same settings they were done with. You can also pin the current search here, so it's kept even when it falls out of the history. Pinned and recent searches can be repeated from the Command Palette too.
+tt_global_search_scope_combobox = Which PackFiles to search on, besides the open one: none, the ones in its dependency list, or all the PackFiles of the game. Matches from other PackFiles are marked with their PackFile's name, and they're read-only. The first search on them may take a while, as their PackFiles need to be opened.
+tt_global_search_path_filter_line_edit = Only search on PackedFiles whose path matches this. It's a glob ('*' matches anything, '?' matches one character), or a Regex if 'Use Regex' is enabled. Leave it empty to search on all PackedFiles.
 tt_global_search_search_on_all_checkbox = Include all searchable PackedFiles/Schemas on the search.
 tt_global_search_search_on_dbs_checkbox = Include DB Tables on the search.
 tt_global_search_search_on_locs_checkbox = Include LOC Tables on the search.
@@ -253,6 +316,7 @@ treeview_aai = AaI
 treeview_autoexpand = Auto-Expand Matches
 treeview_expand_all = &Expand All
 treeview_collapse_all = &Collapse All
+packfile_extra_open_read_only = &Open Read-Only
 
 ### TreeView Tips
 
@@ -269,14 +333,19 @@ tt_context_menu_mass_import_tsv = Import a bunch of TSV files at the same time.
 tt_context_menu_mass_export_tsv = Export every DB Table and Loc PackedFile from this PackFile as TSV files at the same time. Existing files will be overwritten!
 tt_context_menu_merge_tables = Merge multple DB Tables/Loc PackedFiles into one.
 tt_context_menu_update_tables = Update a table to the last known working version of it for the Current game Selected.
+tt_context_menu_bind_folder_to_table = Generate or update the rows of a table from the files of the selected folder, mapping each file to a key and a value (like its path) through patterns.
 tt_context_menu_delete = Delete the selected File/Folder.
 
 tt_context_menu_extract = Extract the selected File/Folder from the PackFile.
+tt_context_menu_extract_with_path_rewrite = Extract the selected File/Folder from the PackFile, rewriting the paths of the extracted files with a regex.
+tt_context_menu_extract_converted = Extract the selected File/Folder from the PackFile, converting what can be converted: tables to TSV, DDS images to PNG and text files to UTF-8 with BOM.
 tt_context_menu_rename = Rename the selected File/Folder. Remember, whitespaces are NOT ALLOWED and duplicated names in the same folder will NOT BE RENAMED.
+tt_context_menu_mass_rename = Rename all the selected Files/Folders at once, using find/replace (with regex and capture groups), prefixes, suffixes and case transforms. You get a preview of the new names before applying them.
+tt_context_menu_undo_move = Undo the last move or rename of Files/Folders done in this PackFile, putting them back where they were.
 tt_context_menu_open_decoder = Open the selected table in the DB Decoder. To create/update schemas.
 tt_context_menu_open_dependency_manager = Open the list of PackFiles referenced from this PackFile.
 tt_context_menu_open_containing_folder = Open the currently open PackFile's location in your default file manager.
-tt_context_menu_open_with_external_program = Open the PackedFile in an external program.
+tt_context_menu_open_with_external_program = Open the PackedFile in an external program: the one configured for its extension in the External Tools of the Preferences or, if there is none, the default one of your system. Changes saved in the program are reimported automatically.
 tt_context_menu_open_notes = Open the PackFile's Notes in a secondary view, without closing the currently open PackedFile in the Main View.
 tt_filter_autoexpand_matches_button = Auto-Expand matches. NOTE: Filtering with all matches expanded in a big PackFile (+10k files, like data.pack) can hang the program for a while. You have been warned.
 tt_filter_case_sensitive_button = Enable/Disable case sensitive filtering for the TreeView.
@@ -305,7 +374,23 @@ files_to_import = Files to import: {"{"}{"}"}.
 
 decoder_title = PackedFile Decoder
 table_dependency_manager_title = Dependency Manager
+table_filter = Type here to filter the rows. Works with Regex too!
 table_filter_case_sensitive = Case Sensitive
+table_filter_builder = Filter Builder...
+table_filter_builder_count = Filter Builder ({"{"}{"}"})...
+table_filter_no_preset = No Preset
+table_filter_save_preset = Save Preset
+table_filter_delete_preset = Delete Preset
+table_filter_operator_contains = Contains
+table_filter_operator_not_contains = Doesn't Contain
+table_filter_operator_equals = Equals
+table_filter_operator_not_equals = Doesn't Equal
+table_filter_operator_starts_with = Starts With
+table_filter_operator_ends_with = Ends With
+table_filter_operator_greater_than = Greater Than
+table_filter_operator_greater_or_equal = Greater or Equal
+table_filter_operator_less_than = Less Than
+table_filter_operator_less_or_equal = Less or Equal
 table_enable_lookups = Use Lookups
 
 ### Contextual Menu for TreeView
@@ -332,8 +417,12 @@ context_menu_mass_import_tsv = Mass-Import TSV
 context_menu_mass_export_tsv = Mass-Export TSV
 context_menu_mass_export_tsv_folder = Select destination folder
 context_menu_rename = &Rename
+context_menu_mass_rename = &Mass Rename
+context_menu_undo_move = &Undo Move
 context_menu_delete = &Delete
 context_menu_extract = &Extract
+context_menu_extract_with_path_rewrite = Extract with &Path Rewrite...
+context_menu_extract_converted = Extract &Converted
 
 context_menu_open_decoder = &Open with Decoder
 context_menu_open_dependency_manager = Open &Dependency Manager
@@ -344,6 +433,7 @@ context_menu_open_notes = Open &Notes
 context_menu_check_tables = &Check Tables
 context_menu_merge_tables = &Merge Tables
 context_menu_update_table = &Update Table
+context_menu_bind_folder_to_table = &Bind Folder to Table
 
 ### Shortcuts
 
@@ -374,6 +464,13 @@ settings_paths_mymod_ph = This is the folder where you want to store all "MyMod"
 
 settings_paths_zip = 7Zip Exe's Path
 settings_paths_zip_ph = This is the full path to 7Zip's executable.
+settings_paths_steamcmd = SteamCMD Exe's Path
+settings_paths_steamcmd_ph = This is the full path to SteamCMD's executable. Used to upload MyMods to the Workshop.
+
+settings_paths_favorite_folders = Favorite Folders
+settings_paths_favorite_folders_ph = Folders to show in the sidebar of all file dialogs, separated by ';'.
+settings_paths_external_tools = External Tools
+settings_paths_external_tools_ph = One per line, as extension=program. For example: dds=C:\Program Files\paint.net\paintdotnet.exe
 
 settings_game_label = TW: {"{"}{"}"} Folder
 settings_game_line_ph = This is the folder where you have {"{"}{"}"} installed, where the .exe is.
@@ -387,10 +484,18 @@ settings_ui_table_adjust_columns_to_content = Adjust Columns to Content:
 settings_ui_table_disable_combos = Disable ComboBoxes on Tables:
 settings_ui_table_extend_last_column_label = Extend Last Column on Tables:
 settings_ui_table_tight_table_mode_label = Enable 'Tight Mode' on Tables:
+settings_ui_table_loc_variant_suffixes_label = Loc Variant Key Suffixes:
+settings_ui_table_f32_decimals_label = Decimals Shown in Float Columns:
+settings_ui_table_use_thousands_separator_label = Use Thousands Separator in Numeric Columns:
+settings_ui_table_show_timestamps_as_dates_label = Show Timestamp Columns as Dates:
+settings_ui_table_complete_with_vanilla_values_label = Complete with Vanilla Values:
+settings_ui_table_max_undo_steps_label = Max Undo Steps:
+settings_ui_table_max_undo_memory_label = Max Undo Memory:
 settings_ui_table_remember_column_visual_order_label = Remember Column's Visual Order:
 settings_ui_table_remember_table_state_permanently_label = Remember Table State Across PackFiles:
 settings_ui_window_start_maximized_label = Start Maximized:
 settings_ui_window_hide_background_icon = Hide Background Game Selected Icon:
+settings_ui_window_restore_last_session_label = Restore Last Session on Start:
 
 settings_select_file = Select File
 settings_select_folder = Select Folder
@@ -404,6 +509,13 @@ settings_optimize_not_renamed_packedfiles = Optimize Non-Renamed PackedFiles:
 settings_use_dependency_checker = Enable Dependency Checker for DB Tables:
 settings_use_lazy_loading = Use Lazy-Loading for PackFiles:
 settings_disable_uuid_regeneration_tables = Disable UUID Regeneration on DB Tables:
+settings_allow_overwriting_vanilla_packfiles = Allow Overwriting Vanilla PackFiles:
+settings_optimize_sort_table_rows = Sort Table Rows when Optimizing:
+settings_use_git_on_mymods = Use Git on MyMods:
+settings_autosave_interval = Autosave Interval (minutes):
+settings_autosave_amount = Autosaves to Keep:
+settings_normalize_timestamps_on_save = Normalize Timestamps on Save:
+settings_schema_update_interval = Auto-Update Schemas Every (hours):
 
 settings_debug_title = Debug Settings
 settings_debug_missing_table = Check for Missing Table Definitions
@@ -421,27 +533,86 @@ tt_ui_table_disable_combos_tip = If you disable this, no more combos will be sho
     Now shut up Baldy.
 tt_ui_table_extend_last_column_tip = If you enable this, the last column on DB Tables and Loc PackedFiles will extend itself to fill the empty space at his right, if there is any.
 tt_ui_table_tight_table_mode_tip = If you enable this, the vertical useless space in tables will be reduced, so you can see more data at the same time.
+tt_ui_table_loc_variant_suffixes_tip = Comma-separated list of the key suffixes the selected game uses for plural/gender variants of a Loc entry (like '_plural' or '_female').
+    These are the keys RPFM will generate when using 'Generate Variant Keys' on a Loc PackedFile.
+tt_ui_table_f32_decimals_tip = Amount of decimals shown in the float columns of DB Tables. This only affects how the values are shown: when editing a cell you'll still get the raw value.
+tt_ui_table_use_thousands_separator_tip = If you enable this, numeric columns of DB Tables will be shown with the thousands separator of your system's locale. This only affects how the values are shown: when editing a cell you'll still get the raw value.
+tt_ui_table_show_timestamps_as_dates_tip = If you enable this, integer columns that look like timestamps (their name contains 'timestamp' or ends in '_date') will be shown as UTC dates. When editing a cell you'll still get the raw value, in seconds since 1970-01-01.
+tt_ui_table_complete_with_vanilla_values_tip = When editing a text cell of a DB Table, RPFM suggests the values already used in the same column. If you enable this, it'll also suggest the values used in the same column of the vanilla tables. Changes apply to tables opened after saving the settings.
+tt_ui_table_max_undo_steps_tip = Max amount of operations kept in the undo history of each table. When there are more, the oldest ones are forgotten. 0 means no limit.
+tt_ui_table_max_undo_memory_tip = Max amount of memory (estimated) the undo history of each table can use. When it uses more, the oldest operations are forgotten. 0 means no limit.
 tt_ui_table_remember_column_visual_order_tip = Enable this to make RPFM remember the visual order of the columns of a DB Table/LOC, when closing it and opening it again.
 tt_ui_table_remember_table_state_permanently_tip = If you enable this, RPFM will remember the state of a DB Table or Loc PackedFile (filter data, columns moved, what column was sorting the Table,...) even when you close RPFM and open it again. If you don't want this behavior, leave this disabled.
 tt_ui_window_start_maximized_tip = If you enable this, RPFM will start maximized.
+tt_ui_window_restore_last_session_tip = If you enable this, RPFM will reopen on start the PackFile and the PackedFiles you had open when you closed it, in the same tabs and with the tables scrolled where you left them.
 
 
 tt_extra_network_check_updates_on_start_tip = If you enable this, RPFM will check for updates at the start of the program, and inform you if there is any update available.
     Whether download it or not is up to you.
 tt_extra_network_check_schema_updates_on_start_tip = If you enable this, RPFM will check for schema updates at the start of the program,
     and allow you to automatically download it if there is any update available.
+tt_extra_network_schema_update_interval_tip = Every how many hours RPFM checks for schema updates and, if there is one, downloads it without asking. The previous schemas are kept in the 'schemas_backup' folder in the config folder, and you'll get a summary of the table definitions that changed. Set it to 0 to disable automatic schema updates.
 tt_extra_packfile_allow_editing_of_ca_packfiles_tip = By default, only PackFiles of Type 'Mod' and 'Movie' are editables, as those are the only ones used for modding.
     If you enable this, you'll be able to edit 'Boot', 'Release' and 'Patch' PackFiles too. Just be careful of not writing over one of the game's original PackFiles!
 tt_extra_packfile_optimize_not_renamed_packedfiles_tip = If you enable this, when running the 'Optimize PackFile' feature RPFM will optimize Tables and Locs that have the same name as their vanilla counterparts.
     Usually, those files are intended to fully override their vanilla counterparts, so by default (this setting off) they are ignored by the optimizer. But it can be useful sometimes to optimize them too (AssKit including too many files), so that's why this setting exists.
-tt_extra_packfile_use_dependency_checker_tip = If you enable this, when opening a DB Table RPFM will try to get his dependencies and mark all cells with a reference to another table as 'Not Found In Table' (Red), 'Referenced Table Not Found' (Blue) or 'Correct Reference' (Black). It makes opening a big table a bit slower.
+tt_extra_packfile_use_dependency_checker_tip = If you enable this, RPFM will check the cells of DB Tables with a reference to another table against the keys of that table in your mod and in vanilla, and mark them as 'Not Found In Table' (Red), 'Referenced Table Not Found' (Blue) or 'Correct Reference' (Black). Cells are rechecked as you edit them, and the entire table is rechecked when the PackFile changes.
 tt_extra_packfile_use_lazy_loading_tip = If you enable this, PackFiles will load their data on-demand from the disk instead of loading the entire PackFile to Ram. This reduces Ram usage by a lot, but if something else changes/deletes the PackFile while it's open, the PackFile will likely be unrecoverable and you'll lose whatever is in it.
     If you mainly mod in Warhammer 2's /data folder LEAVE THIS DISABLED, as a bug in the Assembly Kit causes PackFiles to become broken/be deleted when you have this enabled.
 tt_extra_disable_uuid_regeneration_on_db_tables_label_tip = Check this if you plan to put your binary tables under Git/Svn/any kind of version control software.
+tt_extra_packfile_allow_overwriting_vanilla_packfiles_tip = By default, RPFM refuses to save a PackFile over one of the base PackFiles in the /data folder of the game (data.pack, local_en.pack,...), as that breaks the game. Only check this if you REALLY know what you're doing.
+tt_extra_packfile_optimize_sort_table_rows_tip = If you enable this, when running the 'Optimize PackFile' feature RPFM will also sort the rows of the remaining Tables and Locs by their key columns. Useful to get cleaner diffs if you keep your mod under version control.
+tt_extra_packfile_autosave_interval_tip = Every how many minutes RPFM saves a backup of the open PackFile, if it has unsaved changes. The backups go to the 'autosaves' folder in the config folder, and if RPFM crashes you'll be offered to recover the last one on the next start. Set it to 0 to disable autosaves.
+tt_extra_packfile_autosave_amount_tip = How many autosaves to keep. When there are more, the oldest ones are deleted.
+tt_extra_packfile_normalize_timestamps_on_save_tip = If you enable this, the timestamps of the PackFile and of all its PackedFiles are set to 0 when saving, so saving the same contents always gives you the exact same file. Useful if you distribute your mods by hash, or cache them in CI.
+tt_extra_mymod_use_git_tip = If you enable this, new MyMods get a Git repository in their folder, you can commit them from the MyMod menu, and the PackedFiles untracked (green text) or modified (yellow text) since the last commit are marked in the PackFile Contents.
 
 tt_debug_check_for_missing_table_definitions_tip = If you enable this, RPFM will try to decode EVERY TABLE in the current PackFile when opening it or when changing the Game Selected, and it'll output all the tables without an schema to a \"missing_table_definitions.txt\" file.
     DEBUG FEATURE, VERY SLOW. DON'T ENABLE IT UNLESS YOU REALLY WANT TO USE IT.
 
+### Images
+
+image_mipmap = Mipmap:
+image_channel_red = Red
+image_channel_green = Green
+image_channel_blue = Blue
+image_channel_alpha = Alpha
+
+### RigidModels
+
+rigidmodel_version = Version:
+rigidmodel_skeleton_id = Skeleton:
+rigidmodel_lod = Lod {"{"}{"}"}
+rigidmodel_visibility_distance = Visibility Distance:
+rigidmodel_mesh = Mesh {"{"}{"}"}
+rigidmodel_mesh_info = Material type: {"{"}{"}"}. Shader: {"{"}{"}"}. Vertices: {"{"}{"}"}. Indices: {"{"}{"}"}.
+rigidmodel_mesh_name = Name:
+rigidmodel_texture_directory = Texture Directory:
+rigidmodel_texture = Texture ({"{"}{"}"}):
+rigidmodel_material_not_supported = The material of this mesh is not supported. Its data will be kept as it is.
+
+### UnitVariants
+
+unit_variant_name = Category
+unit_variant_id = Id
+unit_variant_mesh_file = Mesh File
+unit_variant_texture_folder = Texture Folder
+unit_variant_unknown = Unknown
+unit_variant_add_category = Add Category
+unit_variant_add_variant = Add Variant
+unit_variant_remove = Remove
+
+### VariantMeshDefinitions
+
+variant_mesh_name = Name
+variant_mesh_value = Value
+variant_mesh_add_element = Add Element
+variant_mesh_add_attribute = Add Attribute
+variant_mesh_remove = Remove
+
+check_paths = Check Paths
+check_paths_result = Paths not found in the PackFile or its dependencies: {"{"}{"}"}.
+
 ### CA_VP8 Videos
 
 format = Format:
@@ -464,17 +635,34 @@ convert_to_ivf = Convert to IVF
 
 notes = Notes
 
+lua_syntax_ok = Lua syntax check: no errors found.
+lua_syntax_error = Lua syntax check: {"{"}{"}"}
+
 external_current_path = Current path for edition:
 stop_watching = Stop watching the file
 open_folder = Open folder in file manager
 
 game_selected_changed_on_opening = Game Selected changed to {"{"}{"}"}, as the PackFile you opened is not compatible with the game you had selected.
 
+command_palette_column_go_to = col: {"{"}{"}"}
+command_palette_column_toggle_hidden = col: {"{"}{"}"} (toggle hidden)
+command_palette_column_toggle_frozen = col: {"{"}{"}"} (toggle frozen)
+command_palette_packed_file = PackedFile
+command_palette_view_action = view: {"{"}{"}"}
+command_palette_recently_used = recently used
+command_palette_search = search: {"{"}{"}"}
+command_palette_pinned_search = pinned search
+command_palette_recent_search = recent search
+
 ### Extra stuff I don't remember where it goes.
 
 rpfm_title = Rusted PackFile Manager
 delete_mymod_0 = <p>You are about to delete this <i>'MyMod'</i> from your disk.</p><p>There is no way to recover it after that.</p><p>Are you sure?</p>
 delete_mymod_1 = <p>There are some changes yet to be saved.</p><p>Are you sure?</p>
+autosave_recover = <p>It seems RPFM didn't close properly last time, and there is an autosave from that session: <i>{"{"}{"}"}</i>.</p><p>Do you want to open it? If you do, remember to use <i>Save PackFile As...</i> to save it where it belongs.</p>
+autosave_in_progress = Autosaving the open PackFile...
+autosave_success = PackFile autosaved.
+autosave_error = Error while autosaving the open PackFile: {"{"}{"}"}
 
 api_response_success_new_update = "<h4>New major update found: {"{"}{"}"}</h4> <p>Download and changelog available here:<br><a href="{"{"}{"}"}">{"{"}{"}"}</a></p>
 api_response_success_new_update_hotfix = <h4>New minor update/hotfix found: {"{"}{"}"}</h4> <p>Download and changelog available here:<br><a href="{"{"}{"}"}">{"{"}{"}"}</a></p>
@@ -487,6 +675,10 @@ schema_new_update = <h4>New schema update available</h4> <p>Do you want to updat
 
 api_response_schema_error = <h4>Error while checking new updates :(</h4> <p>If you see this message, there has been a problem with your connection to the Github.com server. Please, make sure you can access to <a href=\"https://api.github.com\">https://api.github.com</a> and try again.</p>
 schema_update_success = <h4>Schemas updated and reloaded</h4><p>You can continue using RPFM now.</p>
+schema_update_changes = <p>{"{"}{"}"} table definitions changed with this update. The previous schemas have been kept in the 'schemas_backup' folder. Check the details for the full list of changes.</p>
+schema_update_changes_new_table = new table
+schema_update_changes_new_versions = new versions: {"{"}{"}"}
+schema_update_changes_new_fields = new fields: {"{"}{"}"}
 
 files_extracted_success = {"{"}{"}"} files extracted. No errors detected.
 mymod_delete_success = MyMod successfully deleted: \"{"{"}{"}"}\"
@@ -494,13 +686,14 @@ mymod_delete_success = MyMod successfully deleted: \"{"{"}{"}"}\"
 generate_pak_success = PAK File succesfully created and reloaded.
 game_selected_unsupported_operation = This operation is not supported for the Game Selected.
 
-optimize_packfile_success = PackFile optimized.
+optimize_packfile_success = PackFile optimized: {"{"}{"}"} rows removed, {"{"}{"}"} unused Loc entries removed, {"{"}{"}"} PackedFiles deleted and {"{"}{"}"} tables sorted. {"{"}{"}"} bytes saved.
 update_current_schema_from_asskit_success = Currently loaded schema updated.
 generate_schema_diff_success = Diff generated succesfully.
 settings_font_title = Font Settings
 
 title_success = Success!
 title_error = Error!
+slot_panic_error = <p>Something went terribly wrong and that action failed. The error was:</p><p>{"{"}{"}"}</p><p>RPFM recovered from it, but it's in an unknown state. Save your work and restart RPFM as soon as possible.</p>
 
 rename_instructions = It's easy, but you'll not understand it without an example, so here it's one:
      - Your files/folders says 'you' and 'I'.
@@ -510,7 +703,33 @@ rename_instructions = It's easy, but you'll not understand it without an example
     And, in case you ask, works with numeric cells too, as long as the resulting text is a valid number.
 
 update_table_success = Table updated from version '{"{"}{"}"}' to version '{"{"}{"}"}'.
+
+bind_folder_to_table = Bind Folder to Table
+bind_folder_folder = Folder: {"{"}{"}"}
+bind_folder_table = Table:
+bind_folder_key = Key column/pattern:
+bind_folder_value = Value column/pattern:
+bind_folder_patterns = Patterns can use {"{"}name{"}"} (file name without extension), {"{"}file{"}"} (file name), {"{"}folder{"}"} and {"{"}path{"}"}.
+bind_folder_bind = Bind
+bind_folder_no_tables = There are no DB/Loc Tables in the open PackFile.
+bind_folder_success = Rows added: {"{"}{"}"}. Rows updated: {"{"}{"}"}.
 no_errors_detected = No errors detected.
+check_tables_ignore_findings = <p>Do you want to ignore these findings in future checks of this PackFile? You can edit the ignored findings later from 'PackFile/Diagnostics Ignore List'.</p>
+checksum_manifest_unsaved_changes = <p>The PackFile has unsaved changes. Checksum manifests work over the PackFile on disk, so save it first.</p>
+launch_game_unsaved_changes = <p>The PackFile has unsaved changes. The game loads the PackFile from disk, so save it first.</p>
+save_game_running = <p>The game is running, and this PackFile is in its data folder. Saving it while the game uses it may break the game or the save.</p><p>Do you want to save it anyway, or wait until the game is closed to save it?</p>
+save_game_running_save = Save Anyway
+save_game_running_queue = Save When The Game Closes
+generate_checksum_manifest_success = <p>Checksum manifest generated in:</p><p><i>{"{"}{"}"}</i></p>
+verify_checksum_manifest_valid = The PackFile matches the checksum manifest.
+verify_checksum_manifest_invalid = <p>The PackFile doesn't match the checksum manifest:</p><ul>{"{"}{"}"}</ul>
+verify_checksum_manifest_total_hash = The hash of the entire PackFile is different
+verify_checksum_manifest_missing = Missing
+verify_checksum_manifest_extra = Not in the manifest
+verify_checksum_manifest_mismatched = Changed
+export_contents_manifest_success = <p>Contents manifest exported to:</p><p><i>{"{"}{"}"}</i></p>
+split_loc_by_language_success = <p>Loc PackFiles created:</p><ul>{"{"}{"}"}</ul>
+normalize_timestamps_success = The timestamps of {"{"}{"}"} PackedFiles have been normalized.
 original_data = Original Data: '{"{"}{"}"}'
 column_tooltip_1 = This column is a reference to:
 column_tooltip_2 = And many more. Exactly, {"{"}{"}"} more. Too many to show them here.
@@ -518,6 +737,7 @@ column_tooltip_3 = Fields that reference this column:
 
 tsv_select_title = Select TSV File to Import...
 tsv_export_title = Export TSV File...
+export_visible_rows_title = Export Visible Rows...
 
 rewrite_selection_title = Rewrite Selection
 rewrite_selection_instructions_title = Instructions
@@ -535,22 +755,33 @@ context_menu_copy_submenu = &Copy...
 context_menu_add_rows = &Add Row
 context_menu_insert_rows = &Insert Row
 context_menu_delete_rows = &Delete Row
+context_menu_move_rows_up = Move Rows &Up
+context_menu_move_rows_down = Move Rows Do&wn
 context_menu_rewrite_selection = &Rewrite Selection
+context_menu_generate_loc_variants = &Generate Variant Keys
 context_menu_clone_and_insert = &Clone and Insert
 context_menu_clone_and_append = Clone and &Append
+context_menu_clone_and_edit = Clone and &Edit Key Fields...
 context_menu_copy = &Copy
 context_menu_copy_as_lua_table = &Copy as &LUA Table
+context_menu_copy_to_row_clipboard = Copy Rows for &Other Tables
 context_menu_paste = &Paste
+context_menu_paste_from_row_clipboard = Paste Rows from O&ther Tables
 context_menu_search = &Search
 context_menu_sidebar = Si&debar
 context_menu_import_tsv = &Import TSV
 context_menu_export_tsv = &Export TSV
+context_menu_export_visible_rows = Export &Visible Rows
 context_menu_invert_selection = Inver&t Selection
 context_menu_reset_selection = Reset &Selection
 context_menu_resize_columns = Resize Columns
 context_menu_undo = &Undo
 context_menu_redo = &Redo
 
+tt_context_menu_export_visible_rows = Export only the rows and columns currently visible (respecting the active filter and hidden columns) to a TSV or JSON file.
+tt_context_menu_generate_loc_variants = Generate the missing plural/gender variant keys of the selected entries, and place them right under their base entry.
+generate_loc_variants_none = All the variants of the selected entries already exist.
+
 header_column = <b><i>Column Name</i></b>
 header_hidden = <b><i>Hidden</i></b>
 header_frozen = <b><i>Frozen</i></b>
@@ -558,6 +789,33 @@ header_frozen = <b><i>Frozen</i></b>
 file_count = File Count:
 file_paths = File Paths:
 animpack_unpack = Unpack
+animpack_open = Open
+animpack_extract = Extract...
+animpack_repack = Repack
+animpack_add = Add Files...
+animpack_replace = Replace...
+animpack_remove = Remove
+tt_animpack_open = Unpack the selected file into the PackFile and open it. Once you're done editing it, use 'Repack' to put it back into the AnimPack.
+tt_animpack_repack = Put back into the AnimPack the files of the PackFile with the same path as the ones inside it.
+tt_animpack_add = Add files from disk to the selected folder of the AnimPack. Files with the same path are replaced.
+animpack_none_selected = There are no files selected.
+animpack_open_only_one = You can only open one file at a time.
+animpack_replace_only_one = Select the one file you want to replace.
+animpack_extracted = {"{"}{"}"} files extracted to {"{"}{"}"}.
+animpack_repacked = {"{"}{"}"} files put back into the AnimPack.
+animpack_repack_none = None of the files of the AnimPack are in the PackFile. Unpack them first.
+
+key_value_key = Key
+key_value_value = Value
+key_value_vanilla_value = Vanilla Value
+key_value_add = Add Key
+key_value_remove = Remove
+key_value_revert = Revert to Vanilla
+key_value_only_modified = Only Modified
+tt_key_value_revert = Set the selected keys back to the value they have in vanilla. Keys not in vanilla are left as they are.
+key_value_state_modified = This value is different from the vanilla one.
+key_value_state_not_in_vanilla = This key is not in vanilla.
+key_value_state_invalid = This value is not a valid {"{"}{"}"}.
 
 special_stuff_repack_animtable = RePack AnimTable
 tt_repack_animtable = This action repacks an animtable (if found) back into an AnimPack.
@@ -575,3 +833,357 @@ tt_uodate_templates = This command attemps to update your templates.
 
 integer_1 = Unknown integer 1:
 integer_2 = Unknown integer 2:
+
+debug_diagnostics = &Diagnostics
+tt_debug_diagnostics = Open a panel with the memory used by the open PackFile and the dependencies, the state of the thread queues and the timings of the last operations. Useful to report performance problems.
+diagnostics_title = Diagnostics
+diagnostics_refresh = Refresh
+diagnostics_metric = Metric
+diagnostics_value = Value
+diagnostics_section_memory = Open PackFile
+diagnostics_section_dependencies = Dependencies
+diagnostics_section_threads = Thread Queues
+diagnostics_section_last_operations = Last Operations
+diagnostics_packed_files_count = PackedFiles
+diagnostics_packed_files_loaded_count = PackedFiles loaded in memory
+diagnostics_packed_files_decoded_count = PackedFiles decoded
+diagnostics_packed_files_loaded_size = Memory used by loaded PackedFiles
+diagnostics_extra_packfiles_count = Extra PackFiles open
+diagnostics_dependency_packed_files_count = Dependency PackedFiles cached
+diagnostics_dependency_packed_files_loaded_size = Memory used by the dependency cache
+diagnostics_fake_dependency_tables_count = Assembly Kit tables cached
+diagnostics_pending_commands = Commands waiting for the background thread
+diagnostics_pending_responses = Responses waiting for the UI
+diagnostics_pending_network_commands = Commands waiting for the network thread
+
+context_menu_history = Undo &History
+context_menu_summary_row = Su&mmary Row
+context_menu_column_statistics = Column S&tatistics
+context_menu_set_column_unit = Set Column &Unit...
+context_menu_edit_column_description = Edit Column &Description...
+context_menu_show_column_descriptions = Show Column Descriptions
+context_menu_edit_in_popup = &Edit in Popup...
+context_menu_word_wrap = &Word Wrap
+context_menu_refresh_reference_data = Re&fresh Reference Data
+tt_context_menu_copy_to_row_clipboard = Copy the selected rows, with their column names and types, so they can be pasted into the same table in another PackFile, or in another instance of RPFM.
+tt_context_menu_clone_and_edit = Duplicate the first selected row under it, asking only for new values for its key and name fields.
+tt_table_filter = <p>Type here to filter the rows by the selected column. Start with an operator to compare the cells by their value instead of their text:</p><ul><li><b>&gt;= 100</b>, <b>&gt; 100</b>, <b>&lt;= 0.5</b> or <b>&lt; 0.5</b> for numeric ranges.</li><li><b>= true</b> or <b>= false</b> for boolean columns.</li><li><b>= foo</b> or <b>!= foo</b> for exact matches.</li></ul>
+tt_context_menu_move_rows_up = Move the selected rows one position up. Only available in tables where the order of the rows matters, and while they're not sorted. You can also drag the rows by their number.
+tt_context_menu_move_rows_down = Move the selected rows one position down. Only available in tables where the order of the rows matters, and while they're not sorted. You can also drag the rows by their number.
+clone_and_edit_title = Clone and Edit Key Fields
+clone_and_edit_accept = Clone
+tt_context_menu_paste_from_row_clipboard = Paste the rows copied with 'Copy Rows for Other Tables' at the end of this table. Columns are matched by name, so it works even if the rows come from another version of the table.
+tt_context_menu_history = Open/Close the panel with the undo history of this table. From there you can check what each operation touched, and undo/redo several operations at once.
+history_title = <b><i>Undo History</i></b>
+history_hint = Select an operation to see what jumping to it will do. Hover over it to see what it touched.
+history_size = Undo: {} steps ({})
+tt_context_menu_word_wrap = Wrap the text of the cells into multiple lines, making each row as tall as its longest text. Remembered per table.
+tt_context_menu_refresh_reference_data = Reload the data of the tables this one references, so the dropdowns and the reference checks include the latest changes. It's done automatically when switching to this tab after editing the PackFile.
+tt_context_menu_summary_row = Show/Hide a row under the table with the totals of its columns, for the rows currently visible. Right-click a cell of it to choose what to show for that column.
+summary_row_header = Σ
+summary_row_sum = Sum
+summary_row_average = Average
+summary_row_count = Count
+summary_row_none = None
+summary_row_sum_value = Sum: {"{"}{"}"}
+summary_row_average_value = Avg: {"{"}{"}"}
+summary_row_count_value = Count: {"{"}{"}"}
+tt_context_menu_column_statistics = Show the count, distinct values, min, max and mean of the column of the selected cell, or how many times each value appears in it if it's not a numeric column. Only the rows visible with the current filter are used.
+column_statistics_title = Statistics of {"{"}{"}"}
+column_statistics_count = Count
+column_statistics_distinct = Distinct Values
+column_statistics_min = Min
+column_statistics_max = Max
+column_statistics_mean = Mean
+column_statistics_value = Value
+column_statistics_histogram = Frequency
+column_statistics_close = Close
+
+context_menu_pivot = Pi&vot View
+tt_context_menu_pivot = Show/Hide a tab with a read-only pivot of the table: the rows currently visible grouped by a column, with how many rows each group has and an aggregate (sum, average, min, max) of a numeric column. Useful for quick analyses, like the average cost of the units of each class.
+pivot_table_tab = Table
+pivot_tab = Pivot
+pivot_group_column = Group By:
+pivot_value_column = Value:
+pivot_aggregate = Aggregate:
+pivot_rows = Rows
+pivot_aggregate_header = {"{"}{"}"} of {"{"}{"}"}
+pivot_aggregate_count = Count
+pivot_aggregate_sum = Sum
+pivot_aggregate_average = Average
+pivot_aggregate_min = Min
+pivot_aggregate_max = Max
+tt_context_menu_set_column_unit = Set the unit of the numeric column of the selected cell. The unit is shown in the header of the column, and values like "1.5s" or "20%" in the Rewrite Selection tool are converted to what the column stores. Units are saved in a local patch of the schema, so they're kept when the schema updates.
+tt_context_menu_edit_column_description = Edit the description of the column of the selected cell. Descriptions are saved in the schema, and shown in the tooltip of the column header.
+tt_context_menu_show_column_descriptions = Show the descriptions of the columns below their names in the headers. Remembered for all tables.
+tt_context_menu_edit_in_popup = Edit the selected text cell in a resizable multi-line editor, with word wrap and a count of its characters. Useful for long descriptions.
+edit_in_popup_title = Editing {"{"}{"}"}
+edit_in_popup_count = {"{"}{"}"} characters
+edit_in_popup_count_max = {"{"}{"}"} / {"{"}{"}"} characters
+edit_in_popup_accept = Accept
+edit_in_popup_cancel = Cancel
+column_unit_title = Unit of {"{"}{"}"}
+column_unit_instructions = Choose the unit of the values of this column. Then you can use values like "1.5s", "500ms", "20%" or "2km" in the Rewrite Selection tool, and they'll be converted to this unit.
+column_unit_none = No Unit
+column_unit_accept = Accept
+column_unit_not_numeric = Only numeric columns can have units.
+history_jump = Jump to Selected
+history_original_state = <Original State>
+history_will_undo = Jumping here will undo the last {"{"}{"}"} operation(s).
+history_will_redo = Jumping here will redo the next {"{"}{"}"} operation(s).
+history_editing = Edited {"{"}{"}"} cell(s)
+history_add_rows = Added {"{"}{"}"} row(s)
+history_remove_rows = Removed {"{"}{"}"} row(s)
+history_import_tsv = Imported TSV
+history_move_rows = Moved {"{"}{"}"} row(s)
+history_carolina = {"{"}{"}"} operations at once
+history_details_editing = Cells and the values they'll get:
+history_details_cell = Row {"{"}{"}"}, {"{"}{"}"}: {"{"}{"}"}
+history_details_rows = Rows: {"{"}{"}"}
+history_details_more = ...and {"{"}{"}"} more.
+history_details_import_tsv = Puts back a table with {"{"}{"}"} row(s).
+
+row_clipboard_copied = {"{"}{"}"} row(s) copied to the row clipboard.
+row_clipboard_pasted = {"{"}{"}"} row(s) pasted from the row clipboard.
+
+compare_title = Compare PackFiles
+compare_base = Base PackFile:
+compare_base_placeholder = Leave it empty to use the open PackFile.
+compare_other = Compare against:
+compare_compare = Compare
+compare_section_added = Added
+compare_section_removed = Removed
+compare_section_modified = Modified
+compare_no_other_packfile = You need to select the PackFile you want to compare against.
+compare_no_differences = Both PackFiles have the same PackedFiles, with the same data.
+
+merge_title = Merge Tables from PackFiles
+merge_first = First PackFile:
+merge_second = Second PackFile:
+merge_find = Find Tables
+merge_merge = Merge into open PackFile
+merge_second_wins = Second Wins
+merge_no_packfiles = You need to select the two PackFiles you want to merge the tables from.
+merge_no_tables = There are no DB/Loc Tables edited by both PackFiles with different data.
+merge_merged = Table {"{"}{"}"} merged into the open PackFile.
+
+trash_title = Trash
+trash_restore = Restore
+trash_none_selected = You need to select the PackedFiles you want to restore.
+trash_restore_failed = Some of the selected PackedFiles couldn't be restored, or there is already another PackedFile with their path. They're still in the trash.
+
+create_snapshot_success = Snapshot created.
+snapshots_title = Snapshots
+snapshots_number = Snapshot
+snapshots_time = Taken At
+snapshots_packed_files = PackedFiles
+snapshots_restore = Roll Back
+snapshots_delete = Delete
+snapshots_none_selected = You need to select a snapshot first.
+
+vanilla_search_title = Search Vanilla Files
+vanilla_search_title_matches = Search Vanilla Files ({"{"}{"}"} matches)
+vanilla_search_placeholder = Text to search in the game files...
+
+dialogs_filter = Type here to filter the list. Works with Regex too!
+dialogs_cancel = Cancel
+dialogs_no_packed_files = There are no PackedFiles in the open PackFile you can pick here.
+
+workshop_title = Workshop Browser
+workshop_name = Mod
+workshop_packfile = PackFile
+workshop_id = Workshop ID
+workshop_time_updated = Last Updated
+workshop_open = Open
+workshop_copy = Copy To...
+workshop_open_only_one = You need to select one, and only one, PackFile to open.
+workshop_none_selected = You need to select the PackFiles you want to copy.
+workshop_copy_success = <p>The selected PackFiles have been copied to:</p><ul>{"{"}{"}"}</ul>
+
+run_job_file_step = Running step {"{"}{"}"} of {"{"}{"}"}: {"{"}{"}"}...
+run_job_file_success = <p>Job finished successfully:</p><ul>{"{"}{"}"}</ul>
+
+workshop_upload_title = Upload to Workshop
+workshop_upload_item_title = Title:
+workshop_upload_description = Description:
+workshop_upload_preview_image = Preview Image:
+workshop_upload_changelog = Changelog:
+workshop_upload_visibility = Visibility:
+workshop_upload_visibility_public = Public
+workshop_upload_visibility_friends = Friends Only
+workshop_upload_visibility_private = Private
+workshop_upload_visibility_unlisted = Unlisted
+workshop_upload_steam_user = Steam User:
+workshop_upload_accept = Upload
+workshop_upload_unsaved_changes = The MyMod has unsaved changes. Save it before uploading it to the Workshop.
+workshop_upload_success = MyMod uploaded to the Workshop. Its Workshop id is: {"{"}{"}"}.
+
+mymod_git_commit_title = Commit MyMod
+mymod_git_commit_message = Commit Message:
+mymod_git_commit_accept = Commit
+mymod_git_commit_success = MyMod committed.
+
+path_rewrite_title = Extract with Path Rewrite
+path_rewrite_instructions_title = Instructions
+path_rewrite_instructions = The regex is applied to the path of each extracted file (folders separated by '/'), and the matches are replaced with the replacement. You can use capture groups in the replacement, like $1. For example, the regex '^ui/portraits/' with an empty replacement extracts 'ui/portraits/units/x.png' as 'units/x.png'.
+path_rewrite_regex = Regex:
+path_rewrite_replacement = Replacement:
+path_rewrite_accept = Extract
+
+mass_rename_title = Mass Rename
+mass_rename_find = Find:
+mass_rename_replace = Replace:
+mass_rename_find_placeholder = Text or regex to find in the names
+mass_rename_replace_placeholder = Replacement. With regex, use $1, $2,... for capture groups
+mass_rename_use_regex = Use Regex
+mass_rename_case_sensitive = Case Sensitive
+mass_rename_prefix = Prefix:
+mass_rename_suffix = Suffix:
+mass_rename_case = Case:
+mass_rename_case_none = Keep
+mass_rename_case_lower = lowercase
+mass_rename_case_upper = UPPERCASE
+mass_rename_current_name = Current Name
+mass_rename_new_name = New Name
+mass_rename_accept = Rename
+
+tour_title = Tour
+tour_step = Tour ({"{"}{"}"} of {"{"}{"}"})
+tour_back = Back
+tour_next = Next
+tour_finish = Finish
+tour_skip = Skip Tour
+tour_packfile_contents_title = PackFile Contents
+tour_packfile_contents_description = This is the list of files (PackedFiles) inside the open PackFile. Click one to open it, and right-click anything to see what you can do with it: add files, create tables, extract them,...
+tour_tab_bar_title = Open PackedFiles
+tour_tab_bar_description = The PackedFiles you open appear here, each one in its own tab. Tabs opened with a single click are previews, and get replaced when you open another PackedFile, unless you edit them.
+tour_global_search_title = Global Search
+tour_global_search_description = This panel searches and replaces text in all the tables and text files of the open PackFile at once. If it's not visible, you can open it from the 'View' menu.
+tour_game_selected_title = Game Selected
+tour_game_selected_description = Choose here the game you're modding. Many things, like which tables can be opened or which PackFiles are used as dependencies, depend on it, so make sure it's the right one before doing anything.
+tour_about_title = Need Help?
+tour_about_description = The manual is in this menu, and you can start this tour again from here. Also, leave the mouse over any option in the 'Preferences' for a second to see what it does.
+help_label_tooltip = Open the page of the manual explaining this.
+
+add_conflict_text = <p>There is already a PackedFile in:</p><p><i>{}</i></p><p>What do you want to do with the one you're adding?</p>
+add_conflict_overwrite = Overwrite
+add_conflict_skip = Skip
+add_conflict_rename = Rename
+add_conflict_keep_both = Keep Both
+add_conflict_apply_to_all = Apply to all conflicts
+
+filter_builder_title = Filter Builder
+filter_builder_instructions = These conditions are applied along with the one in the filter line. Use an empty value with "Equals" to find empty cells.
+filter_builder_column = Column
+filter_builder_operator = Operator
+filter_builder_value = Value
+filter_builder_match_all = Match All Conditions (AND)
+filter_builder_match_any = Match Any Condition (OR)
+filter_builder_add = Add Condition
+filter_builder_remove = Remove Selected
+filter_builder_accept = Apply
+filter_preset_title = Save Filter Preset
+filter_preset_name = Name of the preset. Existing presets with the same name are replaced.
+filter_preset_accept = Save
+
+live_sync_title = Live Sync
+live_sync_status_syncing = <p>Syncing the files created or modified in:</p><p><i>{"{"}{"}"}</i></p><p>PackedFiles open in RPFM are synced once you close them.</p>
+live_sync_status_paused = Paused. Changes done in the meantime will be synced when you resume.
+live_sync_pause = Pause
+live_sync_stop = Stop
+live_sync_time = Time
+live_sync_result = Result
+live_sync_result_added = Added
+live_sync_result_updated = Updated
+live_sync_result_skipped = Skipped (open in RPFM)
+live_sync_result_error = Error: {"{"}{"}"}
+tt_live_sync_pause = Stop syncing for now, without forgetting what changed.
+tt_live_sync_stop = Stop the live sync and close this window.
+
+hex_offset = Offset:
+hex_bytes = Bytes, in hex (0A FF 10...)
+hex_overwrite = Overwrite
+hex_insert = Insert
+hex_delete = Delete
+hex_search = Bytes to search, in hex, or text if "Search as Text" is checked
+hex_search_as_text = Search as Text
+hex_find_next = Find Next
+hex_size = Size: {"{"}{"}"} bytes
+hex_invalid_bytes = The bytes are not valid hex. Write them as pairs of hex digits, like "0A FF 10".
+hex_search_no_matches = No matches found.
+tt_hex_offset = Offset where the bytes are overwritten, inserted or deleted. You can also set it by selecting bytes in the hex view.
+tt_hex_delete = Delete the selected bytes, or the one at the offset if there is nothing selected.
+
+sound_bank_info = SoundBank id: {"{"}{"}"}, Wwise version: {"{"}{"}"}
+sound_bank_wems = Embedded WEMs:
+sound_bank_events = Events:
+sound_bank_id = Id
+sound_bank_size = Size (bytes)
+sound_bank_export_selected = Export Selected WEMs
+sound_bank_export_all = Export All WEMs
+sound_bank_export_title = Export WEMs to...
+sound_bank_none_selected = There are no WEMs selected.
+sound_bank_exported = {"{"}{"}"} WEMs exported to:<p><i>{"{"}{"}"}</i></p>
+sound_bank_play = Play Selected WEM
+sound_bank_stop = Stop
+tt_sound_bank_play = Play the selected WEM. You can also double-click it. Only WEMs using PCM can be played.
+
+schema_bundle_export = Export Schemas
+schema_bundle_import = Import Schemas
+schema_bundle_export_success = <p>Schemas of {"{"}{"}"} games exported successfully.</p>
+
+column_description_title = Description of {"{"}{"}"}
+column_description_instructions = <p>Describe what this column is for. The description is saved in the schema of the Game Selected, so keep in mind it will be overwritten by the next schema update unless you contribute it upstream.</p>
+column_description_accept = Accept
+column_description_cancel = Cancel
+context_menu_zoom_submenu = &Zoom...
+context_menu_zoom_in = Zoom &In
+context_menu_zoom_out = Zoom &Out
+context_menu_zoom_reset = &Reset Zoom
+tt_context_menu_zoom_in = Make the text of the table bigger. You can also use Ctrl + Mouse Wheel. Remembered for all tables.
+tt_context_menu_zoom_out = Make the text of the table smaller. You can also use Ctrl + Mouse Wheel. Remembered for all tables.
+tt_context_menu_zoom_reset = Return the text of the table to its normal size. Remembered for all tables.
+
+report_broken_decode = Report
+report_broken_decode_explanation = This table comes from a vanilla PackFile, so the schema needs to be fixed for it. If you hit "Report", we'll check your schemas are up to date and open a pre-filled issue in the schema repository, with the info below. Please, add there anything else you know about the table.
+
+find_orphaned_assets = Find &Orphaned Assets
+tt_packfile_find_orphaned_assets = Open a list of the textures, models and sounds of this PackFile that nothing in it or in the game files seems to use, so you can delete them before releasing the mod.
+orphaned_assets_title = Orphaned Assets
+orphaned_assets_explanation = These assets are not referenced by any table, variant mesh, model, script or text file of this PackFile or of the game files. This is a best guess: assets only used by the game's code will show up here too, so check them before deleting anything. Deleted files go to the Trash, so you can restore them until you save the PackFile.
+orphaned_assets_count = Orphaned assets found: {"{"}{"}"}
+orphaned_assets_delete = Delete Selected
+orphaned_assets_none_selected = There are no assets selected.
+
+menu_bar_scripts = Scrip&ts
+scripts_reload = &Reload Scripts
+scripts_open_scripts_folder = &Open Scripts Folder
+scripts_tool_bar = Scripts
+scripts_reload_success = Scripts reloaded.
+tt_scripts_reload = Read again the scripts in the scripts folder, so new ones, renamed ones and changes to their shortcuts or toolbar buttons show up without restarting the program.
+tt_scripts_open_scripts_folder = Open the folder with your scripts. Any Job File (.json, .yaml or .yml) you put there shows up in this menu. Add "name", "shortcut" (like Ctrl+Alt+B) or "toolbar: true" to a Job File to change its name, give it a shortcut, or a button in the toolbar.
+
+tt_paths_external_tools_tip = Programs used by "Open with External Program" to open each kind of file, instead of the default program of your system. One per line, as the extension, an equal sign and the path (or name) of the program, like "lua=code" or "dds=C:\Program Files\paint.net\paintdotnet.exe". Tables and Locs are opened as "tsv" files. As with the default program, the file is extracted to a temporal folder and reimported into the PackFile every time the program saves it.
+
+title_problems = Problems Found
+problems_found = {"{"}{"}"} items could not be processed. The rest of the operation was completed. Check the details below for what went wrong with each one.
+
+dependency_manager_packfile = PackFile
+dependency_manager_add = Add
+dependency_manager_browse = Add from Disk
+dependency_manager_remove = Remove
+dependency_manager_not_a_packfile = This is not the name of a PackFile. Dependencies are written as the name of the PackFile, with its extension, like "my_mod.pack".
+dependency_manager_not_found = This PackFile is not in the data folder of the Game Selected. If it's a mod from the Workshop or you haven't installed it yet you can ignore this, but the game will not load it unless it's there when the game starts.
+dependency_manager_missing_count = {"{"}{"}"} PackFiles have problems. Hover over the ones in red to see what's wrong with them.
+tt_dependency_manager_browse = Pick one or more PackFiles from disk, and add them to the list by name. Dependencies are always searched in the data folder of the game, no matter where you pick them from.
+
+context_menu_compare_with = &Compare With...
+tt_context_menu_compare_with = Compare this table side-by-side with its version in another PackFile, or in the game files. Rows are aligned by their key fields, and the cells that changed are highlighted.
+table_compare_title = Compare Table: {"{"}{"}"}
+table_compare_other = Other PackFile:
+table_compare_other_placeholder = Leave this empty to compare with the game files and dependencies.
+table_compare_only_differences = Only show differences
+table_compare_base = This Table
+table_compare_other_table = Other Table
+table_compare_compare = Compare
+table_compare_summary = Rows added: {"{"}{"}"}. Rows removed: {"{"}{"}"}. Rows modified: {"{"}{"}"}.
@@ -128,6 +128,22 @@ pub fn initialize_app<'a, 'b>() -> App<'a, 'b> {
                 .takes_value(true)
                 .min_values(2))
 
+            // `Pack` option. The destination is the path of the PackFile you provided before.
+            .arg(Arg::with_name("pack")
+                .short("P")
+                .long("pack")
+                .value_name("FOLDER PATH")
+                .help("Creates a new PackFile with the provided path, containing all the files of the provided folder. Their paths within the folder become their paths within the PackFile.")
+                .takes_value(true))
+
+            // `Unpack` option. Requires you to provide the destination folder.
+            .arg(Arg::with_name("unpack")
+                .short("u")
+                .long("unpack")
+                .value_name("DESTINATION FOLDER")
+                .help("Extracts all the files of the PackFile to the provided folder. If the folder doesn't exist, it'll be created.")
+                .takes_value(true))
+
             // `List` option.
             .arg(Arg::with_name("list")
                 .short("l")
@@ -138,7 +154,33 @@ pub fn initialize_app<'a, 'b>() -> App<'a, 'b> {
             .arg(Arg::with_name("new-packfile")
                 .short("n")
                 .long("new-packfile")
-                .help("Creates a new empty Packfile with the provided path.")))
+                .help("Creates a new empty Packfile with the provided path."))
+
+            // `Generate Manifest` option. The manifest is saved next to the PackFile.
+            .arg(Arg::with_name("generate-manifest")
+                .short("m")
+                .long("generate-manifest")
+                .help("Generates a checksum manifest (SHA256 of every PackedFile and of the PackFile) next to the PackFile."))
+
+            // `Verify Manifest` option. If no manifest is provided, the one next to the PackFile is used.
+            .arg(Arg::with_name("verify-manifest")
+                .short("c")
+                .long("verify-manifest")
+                .value_name("MANIFEST PATH")
+                .help("Checks the PackFile against a checksum manifest. If no manifest is provided, the one next to the PackFile is used.")
+                .takes_value(true)
+                .min_values(0)
+                .max_values(1))
+
+            // `Export Contents Manifest` option. The format depends on the extension of the destination file.
+            .arg(Arg::with_name("export-contents-manifest")
+                .short("x")
+                .long("export-contents-manifest")
+                .value_name("DESTINATION FILE")
+                .help("Exports the list of PackedFiles of the PackFile, with their sizes, types and SHA256. As JSON if the destination ends in .json, as tab-separated text otherwise.")
+                .takes_value(true)
+                .min_values(1)
+                .max_values(1)))
 
         // `Table` Subcommand. Every command that allows you to manipulate DB/Loc Tables in any way goes here.
         .subcommand(SubCommand::with_name("table")
@@ -162,7 +204,19 @@ pub fn initialize_app<'a, 'b>() -> App<'a, 'b> {
                 .help("Export a DB/LOC Table's data to a TSV file.")
                 .takes_value(true)
                 .min_values(1)
-                .max_values(2)))
+                .max_values(2))
+
+            // `Check Round-Trip` option. To check the table serializers against the tables of the provided PackFile.
+            .arg(Arg::with_name("check-round-trip")
+                .short("r")
+                .long("check-round-trip")
+                .help("Decodes and encodes back every DB/LOC Table in the provided PackFile, reporting the tables whose data changes in the process."))
+
+            // `Check References` option. To run the same diagnostics as the UI's `Check Tables` against the tables of the provided PackFile.
+            .arg(Arg::with_name("check-references")
+                .short("c")
+                .long("check-references")
+                .help("Checks every DB Table in the provided PackFile for references to data that doesn't exist in the PackFile or in the game files. Findings ignored in the PackFile's settings are not reported.")))
 
         // `Schema` Subcommand. Basically, here goes commands destined to keep schemas up-to-date.
         .subcommand(SubCommand::with_name("schema")
@@ -170,6 +224,48 @@ pub fn initialize_app<'a, 'b>() -> App<'a, 'b> {
             .arg(Arg::with_name("update")
                 .short("u")
                 .long("update")
-                .takes_value(false)))
+                .takes_value(false))
+
+            // `Export Docs` option. To export the schema of the Game Selected in a format useful for documentation sites.
+            .arg(Arg::with_name("export-docs")
+                .short("d")
+                .long("export-docs")
+                .value_name("FORMAT - DESTINATION FILE")
+                .help("Export the DB Tables of the Game Selected's schema (tables, fields, descriptions and references) to a JSON or HTML file, for documentation purpouses. FORMAT can be 'json' or 'html'.")
+                .takes_value(true)
+                .min_values(2)
+                .max_values(2))
+
+            // `Export Descriptions` option. To edit the descriptions of the fields of the Game Selected's schema in a spreadsheet.
+            .arg(Arg::with_name("export-descriptions")
+                .short("e")
+                .long("export-descriptions")
+                .value_name("DESTINATION FILE")
+                .help("Export the descriptions of the fields of the DB/Loc Tables of the Game Selected's schema to a CSV file, with one (table, version, field, description) row per field.")
+                .takes_value(true))
+
+            // `Import Descriptions` option. To merge the descriptions edited in a spreadsheet back into the Game Selected's schema.
+            .arg(Arg::with_name("import-descriptions")
+                .short("i")
+                .long("import-descriptions")
+                .value_name("SOURCE FILE")
+                .help("Import the descriptions of a CSV file made with 'export-descriptions' into the Game Selected's schema, and save it.")
+                .takes_value(true))
+
+            // `Export Bundle` option. To share the schemas with machines without access to the schema repository.
+            .arg(Arg::with_name("export-bundle")
+                .short("x")
+                .long("export-bundle")
+                .value_name("DESTINATION FILE")
+                .help("Export the schemas of all games to a single compressed file, so they can be imported in machines without access to the schema repository.")
+                .takes_value(true))
+
+            // `Import Bundle` option. To update the schemas from a bundle instead of from the schema repository.
+            .arg(Arg::with_name("import-bundle")
+                .short("m")
+                .long("import-bundle")
+                .value_name("SOURCE FILE")
+                .help("Import the schemas of a file made with 'export-bundle', replacing the current ones. The current ones are kept in the 'schemas_backup' folder.")
+                .takes_value(true)))
 
 }
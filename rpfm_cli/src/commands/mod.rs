@@ -95,8 +95,13 @@ pub fn command_packfile(config: &Config, matches: &ArgMatches, packfile: Option<
                 }
             }
 
+            else if let Some(folder_path) = matches.value_of("pack") { packfile::pack(&config, packfile_path, folder_path) }
+            else if let Some(destination_path) = matches.value_of("unpack") { packfile::unpack(&config, packfile_path, destination_path) }
 			else if matches.is_present("list") { packfile::list_packfile_contents(&config, packfile_path) }
             else if matches.is_present("new-packfile") { packfile::new_packfile(&config, packfile_path)}
+            else if matches.is_present("generate-manifest") { packfile::generate_manifest(&config, packfile_path) }
+            else if matches.is_present("verify-manifest") { packfile::verify_manifest(&config, packfile_path, matches.value_of("verify-manifest")) }
+            else if matches.is_present("export-contents-manifest") { packfile::export_contents_manifest(&config, packfile_path, matches.value_of("export-contents-manifest").unwrap()) }
 
 			else { Err(ErrorKind::NoHTMLError("No valid argument provided.".to_owned()).into()) }
         },
@@ -105,7 +110,7 @@ pub fn command_packfile(config: &Config, matches: &ArgMatches, packfile: Option<
 }
 
 /// This function triggers functions that require the `Table` command.
-pub fn command_table(config: &Config, matches: &ArgMatches, _packfile: Option<&str>) -> Result<()> {
+pub fn command_table(config: &Config, matches: &ArgMatches, packfile: Option<&str>) -> Result<()> {
     if matches.is_present("import") {
 		match matches.values_of("import") {
 			Some(values) => {
@@ -126,6 +131,20 @@ pub fn command_table(config: &Config, matches: &ArgMatches, _packfile: Option<&s
 		}
     }
 
+    else if matches.is_present("check-round-trip") {
+        match packfile {
+            Some(packfile_path) => table::check_round_trip(&config, packfile_path),
+            None => Err(ErrorKind::NoHTMLError("No PackFile provided.".to_owned()).into()),
+        }
+    }
+
+    else if matches.is_present("check-references") {
+        match packfile {
+            Some(packfile_path) => table::check_references(&config, packfile_path),
+            None => Err(ErrorKind::NoHTMLError("No PackFile provided.".to_owned()).into()),
+        }
+    }
+
 	else { Err(ErrorKind::NoHTMLError("No valid argument provided.".to_owned()).into()) }
 }
 
@@ -135,5 +154,32 @@ pub fn command_schema(config: &Config, matches: &ArgMatches) -> Result<()> {
 		schema::update(config)
     }
 
+    else if matches.is_present("export-docs") {
+		match matches.values_of("export-docs") {
+			Some(mut values) => {
+                let format = values.next().unwrap();
+                let destination_path = values.next().unwrap();
+                schema::export_docs(config, format, destination_path)
+            },
+			None => Err(ErrorKind::NoHTMLError("No valid argument provided.".to_owned()).into())
+		}
+    }
+
+    else if let Some(destination_path) = matches.value_of("export-descriptions") {
+        schema::export_descriptions(&config, destination_path)
+    }
+
+    else if let Some(source_path) = matches.value_of("import-descriptions") {
+        schema::import_descriptions(&config, source_path)
+    }
+
+    else if let Some(destination_path) = matches.value_of("export-bundle") {
+        schema::export_bundle(&config, destination_path)
+    }
+
+    else if let Some(source_path) = matches.value_of("import-bundle") {
+        schema::import_bundle(&config, source_path)
+    }
+
 	else { Err(ErrorKind::NoHTMLError("No valid argument provided.".to_owned()).into()) }
 }
@@ -14,11 +14,15 @@ use bytesize::ByteSize;
 use log::info;
 use prettytable::{Table, row, cell};
 
+use std::fs::DirBuilder;
 use std::path::PathBuf;
 
 use rpfm_error::{ErrorKind, Result};
+use rpfm_lib::common::get_files_from_subdir;
 use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::packfile::{PackFile, PathType};
+use rpfm_lib::packfile::checksums::ChecksumManifest;
+use rpfm_lib::packfile::contents_manifest::ContentsManifest;
 use rpfm_lib::SUPPORTED_GAMES;
 
 use crate::config::Config;
@@ -194,7 +198,7 @@ pub fn extract_folders(
     let mut packfile = PackFile::open_packfiles(&[packfile_path], true, false, false)?;
 
     let paths = paths.iter().map(|x| x.split('/').map(|x| x.to_owned()).collect::<Vec<String>>()).map(PathType::Folder).collect::<Vec<PathType>>();
-    packfile.extract_packed_files_by_type(&paths, &destination_path)?;
+    packfile.extract_packed_files_by_type(&paths, &destination_path, None, false)?;
 
     if config.verbosity_level > 0 {
         info!("Folders successfully extracted from the PackFile.");
@@ -238,3 +242,124 @@ pub fn new_packfile(config: &Config, packfile: &str) -> Result<()> {
         None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
     }
 }
+
+/// This function creates a new PackFile with the provided path, containing all the files of the provided folder.
+pub fn pack(config: &Config, packfile: &str, folder_path: &str) -> Result<()> {
+    if config.verbosity_level > 0 {
+        info!("Packing the folder {} into the PackFile: {}", folder_path, packfile);
+    }
+
+    let folder_path = PathBuf::from(folder_path);
+    if !folder_path.is_dir() {
+        return Err(ErrorKind::IOReadFolder(folder_path).into());
+    }
+
+    match &config.game_selected {
+        Some(game_selected) => {
+            let paths = get_files_from_subdir(&folder_path)?.into_iter()
+                .filter_map(|path| {
+                    let packed_file_path = path.strip_prefix(&folder_path).ok()?.iter().map(|x| x.to_string_lossy().to_string()).collect::<Vec<String>>();
+                    Some((path, packed_file_path))
+                })
+                .collect::<Vec<(PathBuf, Vec<String>)>>();
+
+            let packfile_path = PathBuf::from(packfile);
+            let mut packfile = PackFile::new_with_name("x", SUPPORTED_GAMES[&**game_selected].pfh_version[0]);
+            packfile.add_from_files(&paths, true)?;
+            packfile.save(Some(packfile_path))?;
+
+            if config.verbosity_level > 0 {
+                info!("{} files packed successfully.", paths.len());
+            }
+
+            Ok(())
+        }
+        None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
+    }
+}
+
+/// This function extracts all the PackedFiles of the provided PackFile to the provided directory, creating it if it doesn't exist.
+pub fn unpack(config: &Config, packfile: &str, destination_path: &str) -> Result<()> {
+    if config.verbosity_level > 0 {
+        info!("Unpacking the PackFile {} into the folder: {}", packfile, destination_path);
+    }
+
+    let destination_path = PathBuf::from(destination_path);
+    DirBuilder::new().recursive(true).create(&destination_path)?;
+
+    let packfile_path = PathBuf::from(packfile);
+    let mut packfile = PackFile::open_packfiles(&[packfile_path], true, false, false)?;
+    let extracted_count = packfile.extract_packed_files_by_type(&[PathType::PackFile], &destination_path, None, false)?;
+
+    if config.verbosity_level > 0 {
+        info!("{} files unpacked successfully.", extracted_count);
+    }
+
+    Ok(())
+}
+
+/// This function generates a checksum manifest of the provided PackFile, and saves it next to it.
+pub fn generate_manifest(config: &Config, packfile: &str) -> Result<()> {
+    if config.verbosity_level > 0 {
+        info!("Generating Checksum Manifest.");
+    }
+
+    let packfile_path = PathBuf::from(packfile);
+    let manifest = ChecksumManifest::new_from_packfile_path(&packfile_path)?;
+    let manifest_path = ChecksumManifest::get_manifest_path(&packfile_path);
+    manifest.save(&manifest_path)?;
+
+    if config.verbosity_level > 0 {
+        info!("Checksum Manifest of {} PackedFiles saved to {}.", manifest.get_packed_files_count(), manifest_path.to_string_lossy());
+    }
+
+    Ok(())
+}
+
+/// This function checks the provided PackFile against a checksum manifest. If no manifest is provided, the one next to the PackFile is used.
+pub fn verify_manifest(config: &Config, packfile: &str, manifest_path: Option<&str>) -> Result<()> {
+    if config.verbosity_level > 0 {
+        info!("Verifying PackFile against Checksum Manifest.");
+    }
+
+    let packfile_path = PathBuf::from(packfile);
+    let manifest_path = match manifest_path {
+        Some(manifest_path) => PathBuf::from(manifest_path),
+        None => ChecksumManifest::get_manifest_path(&packfile_path),
+    };
+
+    let manifest = ChecksumManifest::read(&manifest_path)?;
+    let report = manifest.verify(&packfile_path)?;
+    if report.is_valid() {
+        info!("The PackFile matches the Checksum Manifest.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.add_row(row!["PackedFile Path", "Problem"]);
+    for path in &report.missing_paths { table.add_row(row![path, "Missing from the PackFile"]); }
+    for path in &report.extra_paths { table.add_row(row![path, "Not in the Checksum Manifest"]); }
+    for path in &report.mismatched_paths { table.add_row(row![path, "Different data"]); }
+    if !report.total_hash_matches { table.add_row(row!["-", "The PackFile's hash is different"]); }
+    table.printstd();
+
+    Err(ErrorKind::NoHTMLError("The PackFile doesn't match the Checksum Manifest.".to_owned()).into())
+}
+
+/// This function exports the list of contents of the provided PackFile to the provided path, as JSON or plain text depending on its extension.
+pub fn export_contents_manifest(config: &Config, packfile: &str, destination_path: &str) -> Result<()> {
+    if config.verbosity_level > 0 {
+        info!("Exporting Contents Manifest.");
+    }
+
+    let packfile_path = PathBuf::from(packfile);
+    let mut packfile = PackFile::open_packfiles(&[packfile_path], true, false, false)?;
+    let manifest = ContentsManifest::new_from_packfile(&mut packfile)?;
+    manifest.save(&PathBuf::from(destination_path))?;
+
+    if config.verbosity_level > 0 {
+        info!("Contents Manifest of {} PackedFiles exported to {}.", manifest.get_ref_entries().len(), destination_path);
+    }
+
+    Ok(())
+}
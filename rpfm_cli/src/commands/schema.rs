@@ -10,8 +10,11 @@
 
 use log::info;
 
-use rpfm_error::Result;
+use std::path::PathBuf;
+
+use rpfm_error::{ErrorKind, Result};
 use rpfm_lib::schema::Schema;
+use rpfm_lib::SUPPORTED_GAMES;
 
 use crate::config::Config;
 
@@ -30,3 +33,112 @@ pub fn update(config: &Config) -> Result<()> {
     }
     result
 }
+
+/// This function exports the DB Tables of the Game Selected's schema to a JSON or HTML file, for documentation purpouses.
+pub fn export_docs(
+    config: &Config,
+    format: &str,
+    destination_path: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Exporting schema docs as {} to: {}", format, destination_path);
+	}
+
+    match &config.game_selected {
+        Some(game_selected) => {
+            let schema = Schema::load(&SUPPORTED_GAMES[&**game_selected].schema)?;
+            let destination_path = PathBuf::from(destination_path);
+            let result = match format {
+                "json" => schema.export_docs_to_json(&destination_path),
+                "html" => schema.export_docs_to_html(&destination_path),
+                _ => return Err(ErrorKind::NoHTMLError(format!("Invalid format provided: {}. Valid formats are 'json' and 'html'.", format)).into()),
+            };
+
+            if config.verbosity_level > 0 {
+                info!("Schema docs exported.");
+            }
+            result
+        },
+        None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
+    }
+}
+
+/// This function exports the descriptions of the fields of the Game Selected's schema to a CSV file.
+pub fn export_descriptions(
+    config: &Config,
+    destination_path: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Exporting schema descriptions to: {}", destination_path);
+	}
+
+    match &config.game_selected {
+        Some(game_selected) => {
+            let schema = Schema::load(&SUPPORTED_GAMES[&**game_selected].schema)?;
+            let result = schema.export_descriptions_to_csv(&PathBuf::from(destination_path));
+
+            if config.verbosity_level > 0 {
+                info!("Schema descriptions exported.");
+            }
+            result
+        },
+        None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
+    }
+}
+
+/// This function imports the descriptions of a CSV file into the Game Selected's schema, and saves it.
+pub fn import_descriptions(
+    config: &Config,
+    source_path: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Importing schema descriptions from: {}", source_path);
+	}
+
+    match &config.game_selected {
+        Some(game_selected) => {
+            let schema_file = &SUPPORTED_GAMES[&**game_selected].schema;
+            let mut schema = Schema::load(schema_file)?;
+            let changes = schema.import_descriptions_from_csv(&PathBuf::from(source_path))?;
+            schema.save(schema_file)?;
+
+            if config.verbosity_level > 0 {
+                info!("Schema descriptions imported. Descriptions changed: {}.", changes);
+            }
+            Ok(())
+        },
+        None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
+    }
+}
+
+/// This function exports the schemas of all games to a bundle, to share them without access to the schema repository.
+pub fn export_bundle(
+    config: &Config,
+    destination_path: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Exporting schemas to: {}", destination_path);
+	}
+
+    let exported = Schema::export_bundle(&PathBuf::from(destination_path))?;
+    if config.verbosity_level > 0 {
+        info!("Schemas exported: {}.", exported);
+    }
+    Ok(())
+}
+
+/// This function imports the schemas of a bundle made with `export_bundle`, replacing the current ones.
+pub fn import_bundle(
+    config: &Config,
+    source_path: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Importing schemas from: {}", source_path);
+	}
+
+    let imported = Schema::import_bundle(&PathBuf::from(source_path))?;
+    if config.verbosity_level > 0 {
+        info!("Schemas imported: {}.", imported);
+    }
+    Ok(())
+}
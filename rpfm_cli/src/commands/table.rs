@@ -8,13 +8,18 @@
 // https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
-use log::info;
+use log::{info, warn};
+use prettytable::{Table, row, cell};
+
 use std::path::PathBuf;
 
 use rpfm_error::{ErrorKind, Result};
+use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::packedfile::table::db::DB;
+use rpfm_lib::packedfile::table::loc::Loc;
+use rpfm_lib::packfile::PackFile;
 use rpfm_lib::schema::Schema;
-use rpfm_lib::SUPPORTED_GAMES;
+use rpfm_lib::{DEPENDENCY_DATABASE, FAKE_DEPENDENCY_DATABASE, GAME_SELECTED, SCHEMA, SUPPORTED_GAMES};
 
 use crate::config::Config;
 
@@ -68,3 +73,117 @@ pub fn export_tsv(
         None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
     }
 }
+
+/// This function decodes and re-encodes every DB/Loc Table in the provided PackFile, comparing the result byte by byte with the original data.
+///
+/// It's meant to catch regressions in the table serializers against real-world data. Tables we cannot decode are reported, but are not considered failures.
+pub fn check_round_trip(
+    config: &Config,
+    packfile: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Checking encode/decode round-trip of the tables in: {}", packfile);
+	}
+
+    match &config.game_selected {
+        Some(game_selected) => {
+            let schema = Schema::load(&SUPPORTED_GAMES[&**game_selected].schema)?;
+            let packfile_path = PathBuf::from(packfile);
+            let packfile = PackFile::open_packfiles(&[packfile_path], true, false, false)?;
+
+            let mut checked = 0;
+            let mut skipped = 0;
+            let mut table = Table::new();
+            table.add_row(row!["PackedFile Path", "Problem"]);
+
+            for packed_file in packfile.get_ref_packed_files_all() {
+                let path = packed_file.get_path();
+                let data = packed_file.get_raw_data()?;
+                let encoded_data = match PackedFileType::get_packed_file_type(path) {
+                    PackedFileType::DB => match path.get(1) {
+                        Some(name) => DB::read(&data, name, &schema, false).and_then(|decoded| decoded.save()),
+                        None => Err(ErrorKind::DBTableIsNotADBTable.into()),
+                    },
+                    PackedFileType::Loc => Loc::read(&data, &schema, false).and_then(|decoded| decoded.save()),
+                    _ => continue,
+                };
+
+                match encoded_data {
+                    Ok(encoded_data) => {
+                        checked += 1;
+                        if let Some(problem) = get_round_trip_problem(&data, &encoded_data) {
+                            table.add_row(row![path.join("/"), problem]);
+                        }
+                    }
+                    Err(error) => {
+                        skipped += 1;
+                        if config.verbosity_level > 0 {
+                            warn!("Skipping {}: {}", path.join("/"), error.to_terminal());
+                        }
+                    }
+                }
+            }
+
+            let mismatches = table.len() - 1;
+            info!("Round-trip checked in {} tables. {} tables couldn't be decoded and were skipped.", checked, skipped);
+            if mismatches == 0 {
+                info!("All tables round-tripped without changes.");
+                Ok(())
+            } else {
+                table.printstd();
+                Err(ErrorKind::NoHTMLError(format!("{} tables changed after decoding and encoding them.", mismatches)).into())
+            }
+        },
+        None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
+    }
+}
+
+/// This function checks every DB Table in the provided PackFile for references to data that doesn't exist, reporting the broken columns.
+///
+/// The referenced data is searched in the PackFile itself, in its dependencies and in the game files, like the `Check Tables` action of the UI.
+pub fn check_references(
+    config: &Config,
+    packfile: &str,
+) -> Result<()> {
+	if config.verbosity_level > 0 {
+		info!("Checking the references of the tables in: {}", packfile);
+	}
+
+    match &config.game_selected {
+        Some(game_selected) => {
+            *GAME_SELECTED.write().unwrap() = game_selected.to_owned();
+            *SCHEMA.write().unwrap() = Some(Schema::load(&SUPPORTED_GAMES[&**game_selected].schema)?);
+
+            let packfile_path = PathBuf::from(packfile);
+            let mut packfile = PackFile::open_packfiles(&[packfile_path], true, false, false)?;
+            *DEPENDENCY_DATABASE.lock().unwrap() = PackFile::load_all_dependency_packfiles(&packfile.get_packfiles_list());
+            *FAKE_DEPENDENCY_DATABASE.write().unwrap() = DB::read_pak_file();
+
+            let missing_references = packfile.get_missing_references()?;
+            if missing_references.is_empty() {
+                info!("No missing references found.");
+                Ok(())
+            } else {
+                let mut table = Table::new();
+                table.add_row(row!["PackedFile Path", "Column"]);
+                for (path, column) in &missing_references {
+                    table.add_row(row![path.join("/"), column]);
+                }
+
+                table.printstd();
+                Err(ErrorKind::NoHTMLError(format!("{} columns reference data that doesn't exist.", missing_references.len())).into())
+            }
+        },
+        None => Err(ErrorKind::NoHTMLError("No Game Selected provided.".to_owned()).into()),
+    }
+}
+
+/// This function returns a description of the first difference between the original and the re-encoded data of a table, if there is any.
+fn get_round_trip_problem(data: &[u8], encoded_data: &[u8]) -> Option<String> {
+    match data.iter().zip(encoded_data.iter()).position(|(x, y)| x != y) {
+        Some(offset) => Some(format!("Different byte at offset {} (original: {:#04X}, encoded: {:#04X}).", offset, data[offset], encoded_data[offset])),
+        None => if data.len() != encoded_data.len() {
+            Some(format!("Different size (original: {} bytes, encoded: {} bytes).", data.len(), encoded_data.len()))
+        } else { None }
+    }
+}
@@ -98,9 +98,27 @@ pub enum ErrorKind {
     /// Error for when the version of a TSV file is not the one we're trying to import to.
     ImportTSVWrongVersion,
 
+    /// Error for when a row of a CSV file doesn't have the format we expect. It contains the line of the row.
+    ImportCSVWrongFormat(u64),
+
     /// Generic TSV import/export error.
     TSVErrorGeneric,
 
+    /// Error for when trying to paste rows from the row clipboard, but there is nothing in it.
+    RowClipboardEmpty,
+
+    /// Error for when the rows in the row clipboard belong to another table. Contains the names of the clipboard's table and the destination table.
+    RowClipboardTableMismatch(String, String),
+
+    /// Error for when a field of the row clipboard cannot be converted to the type of the destination column. Contains the field name and both types.
+    RowClipboardIncompatibleField(String, String, String),
+
+    /// Error for when the row clipboard file cannot be parsed. Contains the error of the parser.
+    RowClipboardParse(String),
+
+    /// Error for when a row of the row clipboard doesn't have as many values as fields. Contains the row, its length and the amount of fields.
+    RowClipboardWrongRowLength(usize, usize, usize),
+
     /// Generic error for when Fluent fails to parse a sentence.
     FluentParsingError,
 
@@ -197,6 +215,9 @@ pub enum ErrorKind {
     /// Error for when trying to save a non-editable PackFile.
     PackFileIsNonEditable,
 
+    /// Error for when trying to save a PackFile over one of the vanilla PackFiles of the game. Contains the path of the vanilla PackFile.
+    PackFileIsVanilla(PathBuf),
+
     /// Error for when the PackFile is not a file in the disk.
     PackFileIsNotAFile,
 
@@ -206,6 +227,12 @@ pub enum ErrorKind {
     /// Error for when the PackFile size doesn't match what we expect. Contains both, the real size and the expected size.
     PackFileSizeIsNotWhatWeExpect(u64, u64),
 
+    /// Error for when we try to split the Loc PackedFiles of a PackFile by language, but none of them belong to a language.
+    PackFileHasNoLocLanguages,
+
+    /// Error for when we try to use a snapshot of the PackFile that no longer exists.
+    PackFileSnapshotNotFound,
+
     //--------------------------------//
     // Schema Errors
     //--------------------------------//
@@ -228,6 +255,12 @@ pub enum ErrorKind {
     /// Error for when there was an error while downloading the updated schemas.
     SchemaUpdateError,
 
+    /// Error for when we try to report a broken decode with outdated schemas.
+    SchemaOutdatedForDecodeReport,
+
+    /// Error for when the file we want to import the schemas from is not a valid schema bundle.
+    SchemaBundleInvalid,
+
     //-----------------------------------------------------//
     //                PackedFile Errors
     //-----------------------------------------------------//
@@ -241,6 +274,9 @@ pub enum ErrorKind {
     /// Error for when we are trying to open a PackedFile in two different views at the same time.
     PackedFileIsOpenInAnotherView,
 
+    /// Error for when we try to use the view of a PackedFile after it has been closed.
+    PackedFileViewClosed,
+
     /// Error for when a load_data or get_data operation fails.
     PackedFileDataCouldNotBeLoaded,
 
@@ -265,6 +301,9 @@ pub enum ErrorKind {
     /// Error for when we cannot open a PackedFile due to not being decodeable on the lib.
     PackedFileTypeUnknown,
 
+    /// Error for when we expect a DB or Loc Table, but get another kind of PackedFile.
+    PackedFileIsNotATable,
+
     /// Error for when we replace the binary data of a PackedFile with another data that's not decodeable in the same way as the old data.
     NewDataIsNotDecodeableTheSameWayAsOldDAta,
 
@@ -309,6 +348,24 @@ pub enum ErrorKind {
     /// Error for when we can't find a vanilla version of a table to compare with.
     NoTableInGameFilesToCompare,
 
+    /// Error for when we try to compare the rows of two PackedFiles, and they're not both DB or Loc Tables.
+    TableDiffNotATable,
+
+    /// Error for when we try to compare a table with its version in another PackFile (or in the game files), and there is none.
+    TableDiffNoCounterpart,
+
+    /// Error for when a value generated while binding a folder to a table is not valid for its column. Contains the value and the column.
+    TableBindInvalidValue(String, String),
+
+    /// Error for when a column chosen to bind a folder to a table doesn't exist in it. Contains the index of the column.
+    TableBindInvalidColumn(usize),
+
+    /// Error for when the same column is chosen as key and value to bind a folder to a table.
+    TableBindSameColumn,
+
+    /// Error for when the value of a key in a key-value table is not valid for the type of its column. Contains the value and the key.
+    KeyValueTableInvalidValue(String, String),
+
     //--------------------------------//
     // RigidModel Errors
     //--------------------------------//
@@ -334,6 +391,38 @@ pub enum ErrorKind {
     /// Error for when the decal texture directory hasn't been found while examining a rigidmodel.
     RigidModelDecalTextureDirectoryNotFound,
 
+    //--------------------------------//
+    // UnitVariant Errors
+    //--------------------------------//
+
+    /// Error for when a UnitVariant fails to decode. Contains the error message.
+    UnitVariantDecode(String),
+
+    /// Error for when we try to decode an unsupported UnitVariant File.
+    UnitVariantNotSupportedFile,
+
+    /// Error for when a VariantMeshDefinition cannot be parsed into its structured form. Contains the error message.
+    VariantMeshDefinitionDecode(String),
+
+    /// Error for when a VariantMeshDefinition cannot be written back as valid XML. Contains the error message.
+    VariantMeshDefinitionEncode(String),
+
+    //--------------------------------//
+    // SoundBank Errors
+    //--------------------------------//
+
+    /// Error for when a SoundBank fails to decode. Contains the error message.
+    SoundBankDecode(String),
+
+    /// Error for when we try to decode an unsupported SoundBank File.
+    SoundBankNotSupportedFile,
+
+    /// Error for when we try to export a WEM that's not embedded in the SoundBank. Contains the id of the WEM.
+    SoundBankWemNotFound(u32),
+
+    /// Error for when we try to play a WEM using a codec we cannot convert. Contains the id of the WEM.
+    SoundBankWemNotPlayable(u32),
+
     //--------------------------------//
     // Text Errors
     //--------------------------------//
@@ -444,12 +533,21 @@ pub enum ErrorKind {
     /// Error for when you try to delete a MyMod without having a MyMod selected in the first place.
     MyModDeleteWithoutMyModSelected,
 
+    /// Error for when you try to upload a MyMod to the Workshop without having a MyMod selected in the first place.
+    MyModUploadWithoutMyModSelected,
+
     /// Error for when the MyMod PackFile has been deleted, but his folder is nowhere to be found.
     MyModPackFileDeletedFolderNotFound,
 
     /// Error for when trying to remove a non-existant MyMod PackFile.
     MyModPackFileDoesntExist,
 
+    /// Error for when the private folder of a MyMod doesn't have a Git repository.
+    MyModGitRepoNotFound,
+
+    /// Error for when trying to commit a MyMod without changes since the last commit.
+    MyModGitNothingToCommit,
+
     //-----------------------------------------------------//
     //                 Special Errors
     //-----------------------------------------------------//
@@ -470,6 +568,15 @@ pub enum ErrorKind {
     /// Error for when extracting one or more PackedFiles from a PackFile fails. Contains the path of the PackedFiles.
     ExtractError(Vec<String>),
 
+    /// Error for when the regex of a path rewrite rule is invalid. It contains the error of the regex.
+    InvalidPathRewriteRegex(String),
+
+    /// Error for when the find pattern of a mass rename is not a valid regex. It contains the error of the regex.
+    InvalidMassRenameRegex(String),
+
+    /// Error for when a value cannot be converted to the unit of its column. It contains the value and the symbol of the unit.
+    InvalidUnitValue(String, String),
+
     /// Errors for when we fail to mass-import/export TSV files. Contains the error message.
     MassImport(String),
 
@@ -504,6 +611,9 @@ pub enum ErrorKind {
     /// Error for when we try to parse a blacklisted table.
     AssemblyKitTableTableIgnored,
 
+    /// Error for when we cannot find the definition of a table in the Assembly Kit.
+    AssemblyKitTableDefinitionNotFound(String),
+
     //-----------------------------------------------------//
     //                  7-Zip Errors
     //-----------------------------------------------------//
@@ -548,6 +658,9 @@ pub enum ErrorKind {
     /// Error for when we try to decode the dependency PackFile List and fail.
     DependencyManagerDecode(String),
 
+    /// Error for when we try to use the dependency database and it's not loaded.
+    DependencyDatabaseNotLoaded,
+
     /// Error for when we try to read a PackedFile for the Decoder.
     DecoderDecode(String),
 
@@ -557,6 +670,27 @@ pub enum ErrorKind {
     /// Error for when we try to launch a game with no steam ID.
     LaunchNotSupportedForThisGame,
 
+    /// Error for when we try to launch a game that only loads mods from its data folder with a PackFile from another folder. It contains the name of the PackFile.
+    LaunchPackFileNotInDataFolder(String),
+
+    /// Error for when the external tool configured for an extension cannot be launched. It contains the command of the tool.
+    ExternalToolCouldNotBeLaunched(String),
+
+    /// Error for when we try to upload a MyMod to the Workshop without having the path of SteamCMD configured.
+    SteamCMDPathNotConfigured,
+
+    /// Error for when we try to upload a MyMod to the Workshop of a game without steam ID.
+    WorkshopNotSupportedForThisGame,
+
+    /// Error for when SteamCMD fails to upload a MyMod to the Workshop. It contains the last lines of its output.
+    WorkshopUploadFailed(String),
+
+    /// Error for when we try to upload a MyMod to the Workshop without a Steam user to log in with.
+    WorkshopUploadNoSteamUser,
+
+    /// Error for when the folder with the downloaded Workshop items of a game doesn't exist. It contains the path of the folder.
+    WorkshopContentFolderNotFound(PathBuf),
+
     /// Error for when we cannot open RPFM's config folder.
     ConfigFolderCouldNotBeOpened,
 
@@ -574,6 +708,15 @@ pub enum ErrorKind {
 
     /// Error for when RPFM cannot find an animtable in the currently open PackFile.
     NoAnimTableInPackFile,
+
+    /// Error for when we try to read a Job File with an unsupported extension.
+    JobFileNotSupported,
+
+    /// Error for when we fail to parse a Job File. Contains the error message.
+    JobFileParse(String),
+
+    /// Error for when a step of a Job fails. Contains the number of the step, its description and the error message.
+    JobStepFailed(usize, String, String),
 }
 
 /// Implementation of `Error`.
@@ -631,7 +774,13 @@ impl Display for ErrorKind {
             ErrorKind::ImportTSVWrongTypeTable => write!(f, "<p>This TSV file either belongs to another table, to a localisation PackedFile, it's broken or it's incompatible with RPFM.</p>"),
             ErrorKind::ImportTSVWrongVersion => write!(f, "<p>This TSV file belongs to another version of this table. If you want to use it, consider creating a new empty table, fill it with enough empty rows, open this file in a TSV editor, like Excel or LibreOffice, and copy column by column.</p><p>A more automatic solution is on the way, but not yet there.</p>"),
             ErrorKind::ImportTSVInvalidVersion => write!(f, "<p>This TSV file has an invalid version value at line 1.</p>"),
+            ErrorKind::ImportCSVWrongFormat(line) => write!(f, "<p>This CSV file has an invalid row at line {}. Each row must have a table name, a version number, a field name and a description.</p>", line),
             ErrorKind::TSVErrorGeneric => write!(f, "<p>Error while trying to import/export a TSV file.</p>"),
+            ErrorKind::RowClipboardEmpty => write!(f, "<p>There are no rows in the row clipboard. Copy some rows with <i>'Copy Rows for Other Tables'</i> first.</p>"),
+            ErrorKind::RowClipboardTableMismatch(clipboard_table, table) => write!(f, "<p>The rows in the row clipboard belong to <i><b>{}</b></i>, but this table is <i><b>{}</b></i>. Rows can only be pasted in the same table they were copied from.</p>", clipboard_table, table),
+            ErrorKind::RowClipboardIncompatibleField(field, clipboard_type, table_type) => write!(f, "<p>The values of the column <i><b>{}</b></i> in the row clipboard are of type <i>{}</i>, and they cannot be converted to the type of that column in this table (<i>{}</i>).</p>", field, clipboard_type, table_type),
+            ErrorKind::RowClipboardParse(error) => write!(f, "<p>The row clipboard file is not valid, and it cannot be read. Copy the rows again with <i>'Copy Rows for Other Tables'</i>. The error was:</p><p>{}</p>", error),
+            ErrorKind::RowClipboardWrongRowLength(row, length, fields) => write!(f, "<p>The row <i><b>{}</b></i> of the row clipboard has {} values, but the clipboard has {} fields. Copy the rows again with <i>'Copy Rows for Other Tables'</i>.</p>", row, length, fields),
             ErrorKind::FluentParsingError => write!(f, "<p>Error while trying to parse a fluent sentence.</p>"),
             ErrorKind::FluentResourceLoadingError => write!(f, "<p>Error while trying to load a fluent resource.</p>"),
             ErrorKind::ParsingFloatError => write!(f, "<p>Error while trying to parse a String as a Float.</p>"),
@@ -690,9 +839,15 @@ impl Display for ErrorKind {
             </ul>
             <p>If you really want to save it, go to <i>'PackFile/Change PackFile Type'</i> and change his type to 'Mod' or 'Movie'. Note that if the cause it's the third on the list, there is no way to save the PackFile, yet.</p>
             <p><b>NOTE</b>: If you created this PackFile using the <i>'Load All CA PackedFiles'</i> feature, NEVER try to save it unless you have 64GB of ram or more. Otherwise it may hang your entire computer to dead.</p>"),
+            ErrorKind::PackFileIsVanilla(path) => write!(f, "
+            <p>Saving cancelled: <i>'{}'</i> is one of the base PackFiles of the game.</p>
+            <p>Overwriting it would break your game install, and the only way to fix it would be verifying the integrity of the game files. Save your mod with another name, or in another folder.</p>
+            <p>If you really know what you're doing, you can disable this protection with <i>'Allow Overwriting Vanilla PackFiles'</i> in the settings.</p>", path.display()),
             ErrorKind::PackFileIsNotAPackFile => write!(f, "<p>This file is not a valid PackFile.</p>"),
             ErrorKind::PackFileIsNotAFile => write!(f, "<p>This PackFile doesn't exists as a file in the disk.</p>"),
             ErrorKind::PackFileSizeIsNotWhatWeExpect(reported_size, expected_size) => write!(f, "<p>This PackFile's reported size is <i><b>{}</b></i> bytes, but we expected it to be <i><b>{}</b></i> bytes. This means that either the decoding logic in RPFM is broken for this PackFile, or this PackFile is corrupted.</p>", reported_size, expected_size),
+            ErrorKind::PackFileSnapshotNotFound => write!(f, "<p>This snapshot no longer exists. Snapshots are discarded when the PackFile is saved or closed.</p>"),
+            ErrorKind::PackFileHasNoLocLanguages => write!(f, "<p>None of the Loc PackedFiles of this PackFile belong to a language. To split them, put them in a folder named like the language (<i>text/fr/my_mod.loc</i>) or end their names with it (<i>text/db/my_mod_fr.loc</i>).</p>"),
             ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta => write!(f, "<p>The PackedFile you added is not the same type as the one you had before. So... the view showing it will get closed.</p>"),
 
             //-----------------------------------------------------//
@@ -704,6 +859,8 @@ impl Display for ErrorKind {
             ErrorKind::SchemaDefinitionNotFound => write!(f, "<p>There is no Definition for this specific version of the table in the Schema.</p>"),
             ErrorKind::NoSchemaUpdatesAvailable => write!(f, "<p>No schema updates available</p>"),
             ErrorKind::SchemaUpdateError => write!(f, "<p>There was an error while downloading the schemas. Please, try again later.</p>"),
+            ErrorKind::SchemaOutdatedForDecodeReport => write!(f, "<p>Your schemas are not up to date. Please, update them and check if the table decodes before reporting it. It may have been already fixed.</p>"),
+            ErrorKind::SchemaBundleInvalid => write!(f, "<p>The provided file is not a valid schema bundle, or it has been made with an incompatible version of RPFM. Your schemas have not been changed.</p>"),
 
             //-----------------------------------------------------//
             //                PackedFile Errors
@@ -711,6 +868,7 @@ impl Display for ErrorKind {
             ErrorKind::PackedFileNotFound => write!(f, "<p>This PackedFile no longer exists in the PackFile.</p>"),
             ErrorKind::PackedFileIsOpen => write!(f, "<p>That operation cannot be done while the PackedFile involved on it is open. Please, close it by selecting a Folder/PackFile in the TreeView and try again.</p>"),
             ErrorKind::PackedFileIsOpenInAnotherView => write!(f, "<p>That PackedFile is already open in another view. Opening the same PackedFile in multiple views is not supported.</p>"),
+            ErrorKind::PackedFileViewClosed => write!(f, "<p>The view of this PackedFile has been closed, so its data can no longer be read from it.</p>"),
             ErrorKind::PackedFileDataCouldNotBeLoaded => write!(f, "<p>This PackedFile's data could not be loaded. This means RPFM can no longer read the PackFile from the disk.</p>"),
             ErrorKind::PackedFileSizeIsNotWhatWeExpect(reported_size, expected_size) => write!(f, "<p>This PackedFile's reported size is <i><b>{}</b></i> bytes, but we expected it to be <i><b>{}</b></i> bytes. This means that either the decoding logic in RPFM is broken for this PackedFile, or this PackedFile is corrupted.</p>", reported_size, expected_size),
             ErrorKind::PackedFileDataCouldNotBeDecompressed => write!(f, "<p>This is a compressed file and the decompresion failed for some reason. This means this PackedFile cannot be opened in RPFM.</p>"),
@@ -719,6 +877,7 @@ impl Display for ErrorKind {
             ErrorKind::PackedFileCouldNotBeImported(paths) => write!(f, "<p>The following failed to be imported:<ul>{}</ul></p>", paths.iter().map(|x| format!("<li>{}<li>", x)).collect::<String>()),
             ErrorKind::PackedFileSaveError(path) => write!(f, "<p>The following PackedFile failed to be saved: {}</p>", path.join("/")),
             ErrorKind::PackedFileTypeUnknown => write!(f, "<p>The PackedFile could not be opened.</p>"),
+            ErrorKind::PackedFileIsNotATable => write!(f, "<p>The PackedFile is not a DB or Loc Table.</p>"),
             ErrorKind::PackedFileChecksumFailed => write!(f, "<p>The PackedFile checksum failed. If you see this, please report it with the actions you did in RPFM before this happened.</p>"),
 
             //--------------------------------//
@@ -738,6 +897,12 @@ impl Display for ErrorKind {
             ErrorKind::DBMissingReferences(references) => write!(f, "<p>The currently open PackFile has reference errors in the following tables:<ul>{}</ul></p>", references.iter().map(|x| format!("<li>{}<li>", x)).collect::<String>()),
             ErrorKind::NoDefinitionUpdateAvailable => write!(f, "<p>This table already has the newer definition available.</p>"),
             ErrorKind::NoTableInGameFilesToCompare => write!(f, "<p>This table cannot be found in the Game Files, so it cannot be automatically updated (yet).</p>"),
+            ErrorKind::TableDiffNotATable => write!(f, "<p>Only DB and Loc Tables can be compared row by row.</p>"),
+            ErrorKind::TableDiffNoCounterpart => write!(f, "<p>There is no version of this table to compare it with. For DB Tables, any table of the same type is used if there is none with the same path.</p>"),
+            ErrorKind::TableBindInvalidValue(value, column) => write!(f, "<p>The value <i>\"{}\"</i> is not valid for the column <i>{}</i>. Check the patterns you used.</p>", value, column),
+            ErrorKind::TableBindInvalidColumn(column) => write!(f, "<p>The column <i>{}</i> doesn't exist in this table.</p>", column),
+            ErrorKind::TableBindSameColumn => write!(f, "<p>The key and the value cannot be written to the same column.</p>"),
+            ErrorKind::KeyValueTableInvalidValue(value, key) => write!(f, "<p>The value <i>\"{}\"</i> of the key <i>{}</i> is not valid for the type of the value column. Fix it before saving.</p>", value, key),
 
             //--------------------------------//
             // RigidModel Errors
@@ -750,6 +915,22 @@ impl Display for ErrorKind {
             ErrorKind::RigidModelTextureDirectoryNotFound => write!(f, "<p>Error while trying to decode the RigidModel file:</p><p><ul><li>Texture Directories not found.</li></ul>"),
             ErrorKind::RigidModelDecalTextureDirectoryNotFound => write!(f, "<p>Error while trying to decode the RigidModel file:</p><p><ul><li>Decal Texture Directory not found.</li></ul>"),
 
+            //--------------------------------//
+            // UnitVariant Errors
+            //--------------------------------//
+            ErrorKind::UnitVariantDecode(cause) => write!(f, "<p>Error while trying to decode the UnitVariant PackedFile:</p><p>{}</p>", cause),
+            ErrorKind::UnitVariantNotSupportedFile => write!(f, "<p>This file is not a Supported UnitVariant file.</p>"),
+            ErrorKind::VariantMeshDefinitionDecode(cause) => write!(f, "<p>Error while trying to read the VariantMeshDefinition:</p><p>{}</p>", cause),
+            ErrorKind::VariantMeshDefinitionEncode(cause) => write!(f, "<p>Error while trying to save the VariantMeshDefinition:</p><p>{}</p>", cause),
+
+            //--------------------------------//
+            // SoundBank Errors
+            //--------------------------------//
+            ErrorKind::SoundBankDecode(cause) => write!(f, "<p>Error while trying to decode the SoundBank PackedFile:</p><p>{}</p>", cause),
+            ErrorKind::SoundBankNotSupportedFile => write!(f, "<p>This file is not a Supported SoundBank file.</p>"),
+            ErrorKind::SoundBankWemNotFound(id) => write!(f, "<p>The WEM with the id {} is not embedded in this SoundBank.</p>", id),
+            ErrorKind::SoundBankWemNotPlayable(id) => write!(f, "<p>The WEM with the id {} cannot be previewed. Only WEMs using PCM can be played. Export it and use the Wwise tools to listen to it.</p>", id),
+
             //--------------------------------//
             // Text Errors
             //--------------------------------//
@@ -817,8 +998,11 @@ impl Display for ErrorKind {
             ErrorKind::GamePathNotConfigured => write!(f, "<p>Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.</p>"),
             ErrorKind::MyModPathNotConfigured => write!(f, "<p>MyMod path is not configured. Configure it in the settings and try again.</p>"),
             ErrorKind::MyModDeleteWithoutMyModSelected => write!(f, "<p>You can't delete the selected MyMod if there is no MyMod selected.</p>"),
+            ErrorKind::MyModUploadWithoutMyModSelected => write!(f, "<p>You can't upload the selected MyMod to the Workshop if there is no MyMod selected.</p>"),
             ErrorKind::MyModPackFileDeletedFolderNotFound => write!(f, "<p>The Mod's PackFile has been deleted, but his assets folder is nowhere to be found.</p>"),
             ErrorKind::MyModPackFileDoesntExist => write!(f, "<p>The PackFile of the selected MyMod doesn't exists, so it can't be installed or removed.</p>"),
+            ErrorKind::MyModGitRepoNotFound => write!(f, "<p>The folder of the selected MyMod doesn't have a Git repository. Enable Git for MyMods in the settings and commit it to create one.</p>"),
+            ErrorKind::MyModGitNothingToCommit => write!(f, "<p>There are no changes to commit since the last commit.</p>"),
 
             //-----------------------------------------------------//
             //                 Special Errors
@@ -831,6 +1015,9 @@ impl Display for ErrorKind {
             //                Contextual Errors
             //-----------------------------------------------------//
             ErrorKind::ExtractError(errors) => write!(f, "<p>There has been a problem extracting the following files:</p><ul>{:#?}</ul>", errors),
+            ErrorKind::InvalidPathRewriteRegex(error) => write!(f, "<p>The regex of the path rewrite rule is invalid:</p><pre>{}</pre>", error),
+            ErrorKind::InvalidMassRenameRegex(error) => write!(f, "<p>The regex to find in the names is invalid:</p><pre>{}</pre>", error),
+            ErrorKind::InvalidUnitValue(value, unit) => write!(f, "<p>The value '{}' cannot be converted to '{}'.</p>", value, unit),
             ErrorKind::MassImport(errors) => write!(f, "<p>The following files returned error when trying to import them:</p><ul>{}</ul><p>No files have been imported.</p>", errors),
             ErrorKind::EmptyInput => write!(f, "<p>Only my hearth can be empty.</p>"),
             ErrorKind::PathsAreEqual => write!(f, "<p>Both paths (source and destination) are the same.</p>"),
@@ -845,6 +1032,7 @@ impl Display for ErrorKind {
             ErrorKind::AssemblyKitLocalisableFieldsNotFound => write!(f, "<p>The `Localisable Fields` file hasn't been found.</p>"),
             ErrorKind::AssemblyKitUnsupportedVersion(version) => write!(f, "<p>Operations over the Assembly Kit of version {} are not currently supported.</p>", version),
             ErrorKind::AssemblyKitTableTableIgnored => write!(f, "<p>One of the Assembly Kit Tables you tried to decode has been blacklisted due to issues.</p>"),
+            ErrorKind::AssemblyKitTableDefinitionNotFound(table_name) => write!(f, "<p>The definition of the table `{}` hasn't been found in the Assembly Kit.</p>", table_name),
 
             //-----------------------------------------------------//
             //                  7-Zip Errors
@@ -865,15 +1053,26 @@ impl Display for ErrorKind {
             ErrorKind::GameSelectedPathNotCorrectlyConfigured => write!(f, "<p>The Game Selected's Path is not properly configured.</p>"),
             ErrorKind::InvalidLocalisationFileName(name) => write!(f, "<p>The name '{}' is not a valid localisation file name. It has to have one and only one '_' somewhere and an identifier (en, fr,...) after that.</p>", name),
             ErrorKind::DependencyManagerDecode(cause) => write!(f, "<p>Error while trying to decode the Dependency PackFile List:</p><p>{}</p>", cause),
+            ErrorKind::DependencyDatabaseNotLoaded => write!(f, "<p>The data of the Game Selected is not loaded. Make sure its path is configured in the settings.</p>"),
             ErrorKind::DecoderDecode(cause) => write!(f, "<p>Error while trying to load the following PackedFile to the decoder:</p><p>{}</p>", cause),
             ErrorKind::PackedFileNotDecodeableWithDecoder => write!(f, "<p>This PackedFile cannot be decoded using the PackedFile Decoder.</p>"),
             ErrorKind::LaunchNotSupportedForThisGame => write!(f, "<p>The currently selected game cannot be launched from Steam.</p>"),
+            ErrorKind::LaunchPackFileNotInDataFolder(name) => write!(f, "<p>The currently selected game can only load mods from its data folder, but the PackFile <i>{}</i> is not there. Install it first.</p>", name),
+            ErrorKind::ExternalToolCouldNotBeLaunched(tool) => write!(f, "<p>The external tool <i>{}</i> could not be launched. Check its path in the External Tools of the Preferences.</p>", tool),
+            ErrorKind::SteamCMDPathNotConfigured => write!(f, "<p>The path of SteamCMD is not configured. Configure it in the settings and log in at least once with it, so it remembers your credentials.</p>"),
+            ErrorKind::WorkshopNotSupportedForThisGame => write!(f, "<p>The currently selected game doesn't have a Steam Workshop.</p>"),
+            ErrorKind::WorkshopContentFolderNotFound(path) => write!(f, "<p>The folder with the Workshop items of the currently selected game doesn't exist. Are you subscribed to any mod?</p><p>{}</p>", path.to_string_lossy()),
+            ErrorKind::WorkshopUploadFailed(log) => write!(f, "<p>The upload to the Steam Workshop failed. These are the last lines of the output of SteamCMD:</p><pre>{}</pre>", log),
+            ErrorKind::WorkshopUploadNoSteamUser => write!(f, "<p>There is no Steam user to upload the MyMod with. Write the user you logged in SteamCMD with, and try again.</p>"),
             ErrorKind::ConfigFolderCouldNotBeOpened => write!(f, "<p>RPFM's config folder couldn't be open (maybe it doesn't exists?).</p>"),
             ErrorKind::InvalidPathsInTemplate => write!(f, "<p>An empty/invalid path has been detected when processing the template. This can be caused by a bad template or by an empty parameter.<p>"),
             ErrorKind::DownloadTemplatesError => write!(f, "<p>Failed to download the latest templates.<p>"),
             ErrorKind::AlreadyUpdatedTemplatesError => write!(f, "<p>Templates already up-to-date.<p>"),
             ErrorKind::CannotFindExtraPackFile(path) => write!(f, "<p>Cannot find extra PackFile with path: {:?}.<p>", path),
             ErrorKind::NoAnimTableInPackFile => write!(f, "<p>No AnimTable found in the PackFile.<p>"),
+            ErrorKind::JobFileNotSupported => write!(f, "<p>This file is not a supported Job File. Only <i>.json</i>, <i>.yaml</i> and <i>.yml</i> files can be used as Job Files.</p>"),
+            ErrorKind::JobFileParse(cause) => write!(f, "<p>Error while trying to read the Job File:</p><p>{}</p>", cause),
+            ErrorKind::JobStepFailed(step, description, cause) => write!(f, "<p>The step <b>{}</b> (<i>{}</i>) of the Job failed. The steps before it have already been applied.</p>{}", step, description, cause),
         }
     }
 }
@@ -166,6 +166,45 @@ pub fn update_schema_from_raw_files(ass_kit_path: Option<PathBuf>) -> Result<()>
     else { Err(ErrorKind::SchemaNotFound.into()) }
 }
 
+/// This function returns a `Definition` for the provided table, built from the Game Selected's Assembly Kit.
+///
+/// Some notes:
+/// - The returned definition has version `-1`, as the Assembly Kit doesn't know about binary versions.
+/// - If the Assembly Kit has a `Localisable Fields` file, the localisable fields are moved out of the normal fields.
+pub fn get_definition_from_raw_files(table_name: &str) -> Result<Definition> {
+    let raw_db_version = SUPPORTED_GAMES[&**GAME_SELECTED.read().unwrap()].raw_db_version;
+    match raw_db_version {
+        2 | 1 => {
+            let mut ass_kit_schemas_path = match get_game_selected_assembly_kit_path() {
+                Some(path) => path,
+                None => return Err(ErrorKind::GamePathNotConfigured.into()),
+            };
+
+            ass_kit_schemas_path.push("raw_data");
+            ass_kit_schemas_path.push("db");
+
+            let name = if table_name.ends_with("_tables") { &table_name[0..table_name.len() - 7] } else { table_name };
+            let raw_definition_path = ass_kit_schemas_path.join(format!("{}{}.xml", RAW_DEFINITION_NAME_PREFIX_V2, name));
+            if !raw_definition_path.is_file() {
+                return Err(ErrorKind::AssemblyKitTableDefinitionNotFound(table_name.to_owned()).into());
+            }
+
+            let raw_definition = RawDefinition::read(&raw_definition_path, raw_db_version)?;
+            let mut definition = Definition::from(&raw_definition);
+
+            // This one is notably missing in Warhammer 2, so it's optional.
+            if let Ok(raw_localisable_fields) = RawLocalisableFields::read(&ass_kit_schemas_path, raw_db_version) {
+                definition.update_from_raw_localisable_fields(&raw_definition, &raw_localisable_fields.fields);
+                let localised_fields = definition.get_localised_fields().iter().map(|x| x.get_name().to_owned()).collect::<Vec<String>>();
+                definition.get_ref_mut_fields().retain(|x| !localised_fields.iter().any(|y| y == x.get_name()));
+            }
+
+            Ok(definition)
+        }
+        _ => Err(ErrorKind::AssemblyKitUnsupportedVersion(raw_db_version).into())
+    }
+}
+
 //---------------------------------------------------------------------------//
 // Utility functions to process raw files from the Assembly Kit.
 //---------------------------------------------------------------------------//
@@ -87,6 +87,12 @@ pub trait Decoder {
     /// This function allows us to decode an UTF-16 String from raw data.
     fn decode_string_u16(&self, offset: usize, size: usize) -> Result<String>;
 
+    /// This function allows us to decode a 00-Padded UTF-16 String from raw data.
+    ///
+    /// Same as the UTF-8 one, but the size is in bytes and the String ends in the first `00 00` character.
+    /// We return the decoded String and his full size when encoded (string + zeros).
+    fn decode_string_u16_0padded(&self, offset: usize, size: usize) -> Result<(String, usize)>;
+
     /// This function allows us to decode a boolean from a byte, moving the provided index to the byte where the next data starts.
     fn decode_packedfile_bool(&self, offset: usize, index: &mut usize) -> Result<bool>;
 
@@ -246,6 +252,15 @@ impl Decoder for [u8] {
         else { Err(ErrorKind::HelperDecodingEncodingError(format!("<p>Error trying to decode an UTF-16 String:</p><ul><li>Required bytes: {}.</li><li>Provided bytes: {:?}.</li></ul>", size, offset.checked_sub(self.len()))).into()) }
     }
 
+    fn decode_string_u16_0padded(&self, offset: usize, size: usize) -> Result<(String, usize)> {
+        if self.len() >= offset + size && size % 2 == 0 {
+            let u16_characters = self[offset..offset + size].chunks_exact(2).map(|x| u16::from_le_bytes([x[0], x[1]])).take_while(|x| *x != 0).collect::<Vec<u16>>();
+            let string_decoded = String::from_utf16(&u16_characters).map_err(|_| Error::from(ErrorKind::HelperDecodingEncodingError("<p>Error trying to decode an UTF-16 0-Padded String.</p>".to_owned())))?;
+            Ok((string_decoded, size))
+        }
+        else { Err(ErrorKind::HelperDecodingEncodingError(format!("<p>Error trying to decode an UTF-16 0-Padded String:</p><ul><li>Required bytes: {}.</li><li>Provided bytes: {:?}.</li></ul>", size, offset.checked_sub(self.len()))).into()) }
+    }
+
     //---------------------------------------------------------------------------//
     //                              Indexed Decoders
     //---------------------------------------------------------------------------//
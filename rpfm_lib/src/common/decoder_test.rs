@@ -198,6 +198,22 @@ fn test_decode_string_u16() {
     assert_eq!(Decoder::decode_string_u16([87, 0, 0, 216, 104, 0, 97, 0, 104, 0, 97, 0].as_ref(), 0, 12).is_err(), true);
 }
 
+/// Test to make sure the u16 0-padded string decoder (`decode_string_u16_0padded()`) works and fails properly.
+#[test]
+fn test_decode_string_u16_0padded() {
+
+    // Check the decoding works for a proper encoded string.
+    assert_eq!(Decoder::decode_string_u16_0padded([87, 0, 97, 0, 104, 0, 97, 0, 0, 0, 0, 0].as_ref(), 0, 12).unwrap().0, "Waha");
+    assert_eq!(Decoder::decode_string_u16_0padded([87, 0, 97, 0, 104, 0, 97, 0, 0, 0, 0, 0].as_ref(), 0, 12).unwrap().1, 12);
+
+    // Check that, as soon as it finds a 00 00 (null character) the decoding stops.
+    assert_eq!(Decoder::decode_string_u16_0padded([87, 0, 97, 0, 0, 0, 97, 0, 0, 0, 0, 0].as_ref(), 0, 12).unwrap().0, "Wa");
+
+    // Check the decoder returns an error for a slice with non-UTF16 characters (216), or with not enough bytes.
+    assert_eq!(Decoder::decode_string_u16_0padded([87, 0, 0, 216, 104, 0, 0, 0].as_ref(), 0, 8).is_err(), true);
+    assert_eq!(Decoder::decode_string_u16_0padded([87, 0, 97, 0].as_ref(), 0, 8).is_err(), true);
+}
+
 //---------------------------------------------------------------------------//
 //                          Indexed Decoders
 //---------------------------------------------------------------------------//
@@ -74,6 +74,11 @@ pub trait Encoder {
     /// This function allows us to encode an UTF-16 String into the provided `Vec<u8>`.
     fn encode_string_u16(&mut self, string: &str);
 
+    /// This function allows us to encode a 00-Padded UTF-16 String into the provided `Vec<u8>`.
+    ///
+    /// Same as the UTF-8 one, but the size is in bytes. If the encoded String is longer than the provided size, we throw an error.
+    fn encode_string_u16_0padded(&mut self, string: &(String, usize)) -> Result<()>;
+
     /// This function allows us to encode an UTF-8 String with his lenght (u16) before the String into the provided `Vec<u8>`..
     fn encode_packedfile_string_u8(&mut self, string: &str);
 
@@ -152,6 +157,17 @@ impl Encoder for Vec<u8> {
         string.encode_utf16().for_each(|character| self.encode_integer_u16(character));
     }
 
+    fn encode_string_u16_0padded(&mut self, (string, size): &(String, usize)) -> Result<()> {
+        let length = string.encode_utf16().count() * 2;
+        if length <= *size {
+            self.encode_string_u16(string);
+            self.extend_from_slice(&vec![0; size - length]);
+            Ok(())
+        } else {
+            Err(ErrorKind::HelperDecodingEncodingError(format!("Error trying to encode an UTF-16 0-Padded String: \"{}\" has a lenght of {} bytes, but his length should be less or equal than {}.", string, length, size)).into())
+        }
+    }
+
     //---------------------------------------------------------------------------//
     //                          Indexed Encoders
     //---------------------------------------------------------------------------//
@@ -18,13 +18,17 @@ use chrono::{Utc, DateTime};
 
 use rpfm_error::{Error, ErrorKind, Result};
 
-use std::fs::{File, read_dir};
+use std::collections::HashSet;
+use std::fs::{DirBuilder, File, read_dir, remove_dir, remove_dir_all, remove_file, symlink_metadata};
 use std::path::{Path, PathBuf};
 
+use crate::mymod::template::MYMOD_TEMPLATES_FOLDER;
 use crate::template;
 use crate::schema;
 use crate::config::get_config_path;
+use crate::games::workshop::get_workshop_content_path;
 use crate::GAME_SELECTED;
+use crate::packfile::AUTOSAVES_FOLDER;
 use crate::{SETTINGS, SUPPORTED_GAMES};
 
 pub mod decoder;
@@ -39,9 +43,24 @@ mod decoder_test;
 mod encoder_test;
 
 /// This function retuns a `Vec<PathBuf>` containing all the files in the provided folder.
+///
+/// Symlinks and junctions to folders are followed, but each folder is only read once, so links pointing to one of their parents don't loop forever.
 #[allow(dead_code)]
 pub fn get_files_from_subdir(current_path: &Path) -> Result<Vec<PathBuf>> {
+    get_files_from_subdir_visited(current_path, &mut HashSet::new())
+}
+
+/// This function does the actual work of `get_files_from_subdir`, keeping track of the folders already read.
+fn get_files_from_subdir_visited(current_path: &Path, visited_folders: &mut HashSet<PathBuf>) -> Result<Vec<PathBuf>> {
     let mut file_list: Vec<PathBuf> = vec![];
+
+    // If we already read this folder through another path, skip it.
+    if let Ok(canonical_path) = current_path.canonicalize() {
+        if !visited_folders.insert(canonical_path) {
+            return Ok(file_list);
+        }
+    }
+
     match read_dir(current_path) {
         Ok(files_in_current_path) => {
             for file in files_in_current_path {
@@ -54,7 +73,7 @@ pub fn get_files_from_subdir(current_path: &Path) -> Result<Vec<PathBuf>> {
                         // If it's a file, add it to the list. If it's a folder, add his files to the list.
                         if file_path.is_file() { file_list.push(file_path); }
                         else if file_path.is_dir() {
-                            let mut subfolder_files_path = get_files_from_subdir(&file_path)?;
+                            let mut subfolder_files_path = get_files_from_subdir_visited(&file_path, visited_folders)?;
                             file_list.append(&mut subfolder_files_path);
                         }
                     }
@@ -71,6 +90,74 @@ pub fn get_files_from_subdir(current_path: &Path) -> Result<Vec<PathBuf>> {
     Ok(file_list)
 }
 
+/// This function returns the path of the provided file relative to the provided folder, or None if the file is not within it.
+///
+/// File dialogs may return paths with their symlinks and junctions resolved, so if the path is not within the folder
+/// as it is, we check it again with both of them resolved, and against the targets of the links within the folder.
+pub fn get_path_relative_to_folder(path: &Path, folder: &Path) -> Option<PathBuf> {
+    if let Ok(relative_path) = path.strip_prefix(folder) {
+        return Some(relative_path.to_path_buf());
+    }
+
+    let canonical_path = path.canonicalize().ok()?;
+    let canonical_folder = folder.canonicalize().ok()?;
+    if let Ok(relative_path) = canonical_path.strip_prefix(&canonical_folder) {
+        return Some(relative_path.to_path_buf());
+    }
+
+    get_linked_folders(folder, folder, &mut HashSet::new()).iter()
+        .find_map(|(link_path, target_path)| canonical_path.strip_prefix(target_path).ok().map(|x| link_path.join(x)))
+}
+
+/// This function returns the folders linked within the provided folder, as their path relative to `base_folder` and their resolved target.
+fn get_linked_folders(folder: &Path, base_folder: &Path, visited_folders: &mut HashSet<PathBuf>) -> Vec<(PathBuf, PathBuf)> {
+    let mut linked_folders = vec![];
+    if let Ok(canonical_folder) = folder.canonicalize() {
+        if !visited_folders.insert(canonical_folder) {
+            return linked_folders;
+        }
+    }
+
+    if let Ok(entries) = read_dir(folder) {
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+            if entry_path.is_dir() {
+                if is_link(&entry_path) {
+                    if let (Ok(relative_path), Ok(target_path)) = (entry_path.strip_prefix(base_folder), entry_path.canonicalize()) {
+                        linked_folders.push((relative_path.to_path_buf(), target_path));
+                    }
+                }
+
+                linked_folders.append(&mut get_linked_folders(&entry_path, base_folder, visited_folders));
+            }
+        }
+    }
+
+    linked_folders
+}
+
+/// This function returns if the provided path is a symlink or, on Windows, a junction.
+pub fn is_link(path: &Path) -> bool {
+    symlink_metadata(path).map(|metadata| metadata.file_type().is_symlink()).unwrap_or(false)
+}
+
+/// This function removes the provided folder with all its contents.
+///
+/// If the folder is a symlink or a junction, only the link is removed, leaving the folder it points to untouched.
+pub fn remove_folder(path: &Path) -> Result<()> {
+    if is_link(path) {
+
+        // On Windows, links to folders are removed as folders. Everywhere else, they're removed as files.
+        if cfg!(target_os = "windows") { remove_dir(path)?; }
+        else { remove_file(path)?; }
+    }
+    else {
+        remove_dir_all(path)?;
+    }
+
+    Ok(())
+}
+
 /// This function gets the current date and return it, as a decoded u32.
 #[allow(dead_code)]
 pub fn get_current_time() -> i64 {
@@ -161,14 +248,9 @@ pub fn get_game_selected_data_packfiles_paths() -> Option<Vec<PathBuf>> {
 #[allow(dead_code)]
 pub fn get_game_selected_content_packfiles_paths() -> Option<Vec<PathBuf>> {
     let game_selected: &str = &*GAME_SELECTED.read().unwrap();
-    let mut path = SETTINGS.read().unwrap().paths[game_selected].clone()?;
-    let id = SUPPORTED_GAMES.get(game_selected)?.steam_id?.to_string();
-
-    path.pop();
-    path.pop();
-    path.push("workshop");
-    path.push("content");
-    path.push(id);
+    let game_path = SETTINGS.read().unwrap().paths[game_selected].clone()?;
+    let steam_id = SUPPORTED_GAMES.get(game_selected)?.steam_id?;
+    let path = get_workshop_content_path(&game_path, steam_id);
 
     let mut paths = vec![];
 
@@ -270,6 +352,44 @@ pub fn get_custom_template_assets_path() -> Result<PathBuf> {
     Ok(get_config_path()?.join(template::CUSTOM_TEMPLATE_FOLDER.to_owned() + "/" + game_selected + "/" + template::ASSETS_FOLDER))
 }
 
+/// This function returns the MyMod templates path.
+pub fn get_mymod_templates_path() -> Result<PathBuf> {
+    Ok(get_config_path()?.join(MYMOD_TEMPLATES_FOLDER))
+}
+
+/// This function returns the path of the folder where the autosaves are stored.
+pub fn get_autosaves_path() -> Result<PathBuf> {
+    Ok(get_config_path()?.join(AUTOSAVES_FOLDER))
+}
+
+/// This function returns the path where the next autosave of the provided PackFile should go.
+///
+/// If there are already `max_autosaves` autosaves, the oldest ones are deleted to make room for the new one.
+pub fn get_next_autosave_path(pack_file_name: &str, max_autosaves: usize) -> Result<PathBuf> {
+    let autosaves_path = get_autosaves_path()?;
+    DirBuilder::new().recursive(true).create(&autosaves_path)?;
+
+    let mut autosaves = get_autosaves()?;
+    while !autosaves.is_empty() && autosaves.len() >= max_autosaves.max(1) {
+        remove_file(autosaves.remove(0))?;
+    }
+
+    let file_name = if pack_file_name.is_empty() { "unknown.pack" } else { pack_file_name };
+    Ok(autosaves_path.join(format!("{}_{}", get_current_time(), file_name)))
+}
+
+/// This function returns the paths of the autosaves we have, from oldest to newest.
+pub fn get_autosaves() -> Result<Vec<PathBuf>> {
+    let autosaves_path = get_autosaves_path()?;
+    if !autosaves_path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut autosaves = get_files_from_subdir(&autosaves_path)?;
+    autosaves.sort_by_key(|path| File::open(path).map(|file| get_last_modified_time_from_file(&file)).unwrap_or(0));
+    Ok(autosaves)
+}
+
 /// This function returns the schema path.
 #[allow(dead_code)]
 pub fn get_schemas_path() -> Result<PathBuf> {
@@ -43,12 +43,16 @@ pub fn init_config_path() -> Result<()> {
 			let schemas_path = config_path.to_path_buf().join("schemas");
             let templates_path = config_path.to_path_buf().join("templates");
             let templates_custom_path = config_path.to_path_buf().join("templates_custom");
+            let mymod_templates_path = config_path.to_path_buf().join("mymod_templates");
+            let scripts_path = config_path.to_path_buf().join("scripts");
 
 	        DirBuilder::new().recursive(true).create(&config_path)?;
 	        DirBuilder::new().recursive(true).create(&error_path)?;
 	        DirBuilder::new().recursive(true).create(&schemas_path)?;
             DirBuilder::new().recursive(true).create(&templates_path)?;
             DirBuilder::new().recursive(true).create(&templates_custom_path)?;
+            DirBuilder::new().recursive(true).create(&mymod_templates_path)?;
+            DirBuilder::new().recursive(true).create(&scripts_path)?;
 	        Ok(())
 		},
 		None => Err(ErrorKind::IOFolderCannotBeOpened.into())
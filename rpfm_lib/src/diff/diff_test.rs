@@ -0,0 +1,205 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the diffs between PackFiles and tables.
+
+Each test builds small DB Tables in memory, with a string key column and an integer value column.
+!*/
+
+use std::collections::BTreeMap;
+
+use crate::packfile::PackFile;
+use crate::packfile::packedfile::{PackedFile, RawPackedFile};
+use crate::packedfile::DecodedPackedFile;
+use crate::packedfile::table::{DecodedData, Table};
+use crate::packedfile::table::db::DB;
+use crate::schema::{Definition, Field, FieldType};
+
+use super::{PackFileDiff, RowDiffState, TableDiff};
+
+/// This function returns the definition used by the tables of these tests.
+fn get_definition() -> Definition {
+    let mut definition = Definition::new(1);
+    definition.get_ref_mut_fields().push(Field::new("key".to_owned(), FieldType::StringU8, true, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()));
+    definition.get_ref_mut_fields().push(Field::new("value".to_owned(), FieldType::I32, false, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()));
+    definition
+}
+
+/// This function returns the provided rows in the format of the tables of these tests.
+fn get_rows(rows: &[(&str, i32)]) -> Vec<Vec<DecodedData>> {
+    rows.iter().map(|(key, value)| vec![DecodedData::StringU8(key.to_string()), DecodedData::I32(*value)]).collect()
+}
+
+/// This function builds a DB Table with the provided path and rows.
+fn get_table(path: &[&str], rows: &[(&str, i32)]) -> PackedFile {
+    let mut table = DB::new(path[1], Some("uuid"), &get_definition());
+    table.set_table_data(&get_rows(rows)).unwrap();
+    PackedFile::new_from_decoded(&DecodedPackedFile::DB(table), &path.iter().map(|x| x.to_string()).collect::<Vec<String>>())
+}
+
+/// This function builds a PackedFile that is not a table, with the provided path and data.
+fn get_raw_file(path: &[&str], data: &[u8]) -> PackedFile {
+    let path = path.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+    PackedFile::new_from_raw(&RawPackedFile::read_from_vec(path, "test.pack".to_owned(), 0, false, data.to_vec()))
+}
+
+/// This function returns the states of the rows of the provided diff.
+fn get_states(diff: &TableDiff) -> Vec<RowDiffState> {
+    diff.get_ref_rows().iter().map(|(_, _, state)| *state).collect()
+}
+
+/// This function returns the rows of the provided DB Table.
+fn get_table_rows(packed_file: &mut PackedFile) -> Vec<Vec<DecodedData>> {
+    match packed_file.decode_return_ref().unwrap() {
+        DecodedPackedFile::DB(table) => table.get_table_data(),
+        _ => panic!("Not a DB Table."),
+    }
+}
+
+/// Test to make sure the rows of two tables are matched by their keys, and that only the conflicts can be won by the other table.
+#[test]
+fn test_table_diff_merge() {
+    let base = get_table(&["db", "units_tables", "base"], &[("a", 1), ("b", 2), ("c", 3)]);
+    let other = get_table(&["db", "units_tables", "base"], &[("b", 20), ("c", 3), ("d", 4)]);
+
+    let diff = TableDiff::new(&base, &other).unwrap();
+    assert_eq!(get_states(&diff), vec![RowDiffState::Added, RowDiffState::Modified, RowDiffState::Unchanged, RowDiffState::Removed]);
+    assert_eq!(diff.get_modified_columns(1), vec![1]);
+
+    // Without winners, the base version of the conflicts is kept. Rows only in one of the tables are always kept.
+    assert_eq!(diff.get_merged_rows(&[]), get_rows(&[("a", 1), ("b", 2), ("c", 3), ("d", 4)]));
+    assert_eq!(diff.get_merged_rows(&[1]), get_rows(&[("a", 1), ("b", 20), ("c", 3), ("d", 4)]));
+
+    // Marking rows that are not conflicts as won by the other table does nothing.
+    assert_eq!(diff.get_merged_rows(&[0, 2, 3]), get_rows(&[("a", 1), ("b", 2), ("c", 3), ("d", 4)]));
+
+    let mut merged = TableDiff::merge(&base, &other, &[1]).unwrap();
+    assert_eq!(merged.get_path(), base.get_path());
+    assert_eq!(get_table_rows(&mut merged), get_rows(&[("a", 1), ("b", 20), ("c", 3), ("d", 4)]));
+}
+
+/// Test to make sure tables of the same type with different names are merged together, and tables with no changes are not listed.
+#[test]
+fn test_merge_table_group() {
+    let mut base = PackFile::new();
+    base.add_packed_file(&get_table(&["db", "units_tables", "base_units"], &[("a", 1), ("b", 2)]), true).unwrap();
+    base.add_packed_file(&get_table(&["db", "factions_tables", "factions"], &[("x", 1)]), true).unwrap();
+
+    let mut other = PackFile::new();
+    other.add_packed_file(&get_table(&["db", "units_tables", "mod_units_2"], &[("d", 4)]), true).unwrap();
+    other.add_packed_file(&get_table(&["db", "units_tables", "mod_units"], &[("b", 20), ("c", 3)]), true).unwrap();
+    other.add_packed_file(&get_table(&["db", "factions_tables", "factions"], &[("x", 1)]), true).unwrap();
+
+    let group = vec!["db".to_owned(), "units_tables".to_owned()];
+    let diff = PackFileDiff::new(&base, &other).unwrap();
+    assert!(diff.get_ref_modified().is_empty());
+    assert_eq!(diff.get_ref_tables(), &[group.to_vec()]);
+
+    // The tables of the other PackFile are used sorted by path, and the merged table keeps the path of the base one.
+    let table_diff = TableDiff::new_from_table_group(&base, &other, &group).unwrap();
+    assert_eq!(table_diff.get_ref_rows()[1].2, RowDiffState::Modified);
+
+    let mut merged = TableDiff::merge_table_group(&base, &other, &group, &[1]).unwrap();
+    assert_eq!(merged.get_path(), &["db".to_owned(), "units_tables".to_owned(), "base_units".to_owned()]);
+    assert_eq!(get_table_rows(&mut merged), get_rows(&[("a", 1), ("b", 20), ("c", 3), ("d", 4)]));
+
+    // Groups missing in one of the PackFiles cannot be diffed.
+    let missing_group = vec!["db".to_owned(), "land_units_tables".to_owned()];
+    assert!(TableDiff::new_from_table_group(&base, &other, &missing_group).is_err());
+}
+
+/// Test to make sure DB Tables are grouped by type, Loc Tables by path, and anything else is not grouped.
+#[test]
+fn test_get_table_group() {
+    let path = |path: &[&str]| path.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+    assert_eq!(TableDiff::get_table_group(&path(&["db", "units_tables", "mod_units"])), Some(path(&["db", "units_tables"])));
+    assert_eq!(TableDiff::get_table_group(&path(&["text", "db", "mod.loc"])), Some(path(&["text", "db", "mod.loc"])));
+    assert_eq!(TableDiff::get_table_group(&path(&["db", "units_tables"])), None);
+    assert_eq!(TableDiff::get_table_group(&path(&["script", "mod.lua"])), None);
+}
+
+/// Test to make sure PackedFiles are matched by path, and that table groups are only listed if their tables or their data change.
+#[test]
+fn test_pack_file_diff() {
+    let mut base = PackFile::new();
+    base.add_packed_file(&get_raw_file(&["text", "a.txt"], b"a"), true).unwrap();
+    base.add_packed_file(&get_raw_file(&["text", "b.txt"], b"b"), true).unwrap();
+    base.add_packed_file(&get_table(&["db", "units_tables", "units"], &[("a", 1)]), true).unwrap();
+    assert!(PackFileDiff::new(&base, &base).unwrap().is_empty());
+
+    let mut other = PackFile::new();
+    other.add_packed_file(&get_raw_file(&["text", "b.txt"], b"b2"), true).unwrap();
+    other.add_packed_file(&get_raw_file(&["text", "c.txt"], b"c"), true).unwrap();
+    other.add_packed_file(&get_table(&["db", "units_tables", "units"], &[("a", 1)]), true).unwrap();
+
+    let path = |path: &[&str]| path.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+    let diff = PackFileDiff::new(&base, &other).unwrap();
+    assert!(!diff.is_empty());
+    assert_eq!(diff.get_ref_added(), &[path(&["text", "a.txt"])]);
+    assert_eq!(diff.get_ref_removed(), &[path(&["text", "c.txt"])]);
+    assert_eq!(diff.get_ref_modified(), &[path(&["text", "b.txt"])]);
+    assert!(diff.get_ref_tables().is_empty());
+
+    // Changing the data of a table marks both the table and its group.
+    other.add_packed_file(&get_table(&["db", "units_tables", "units"], &[("a", 2)]), true).unwrap();
+    let diff = PackFileDiff::new(&base, &other).unwrap();
+    assert_eq!(diff.get_ref_modified(), &[path(&["db", "units_tables", "units"]), path(&["text", "b.txt"])]);
+    assert_eq!(diff.get_ref_tables(), &[path(&["db", "units_tables"])]);
+}
+
+/// Test to make sure tables without key columns are matched by entire rows, and rows with the same key are matched in order.
+#[test]
+fn test_table_diff_matching() {
+    let mut definition = get_definition();
+    definition.get_ref_mut_fields()[0] = Field::new("key".to_owned(), FieldType::StringU8, false, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new());
+
+    let mut base = Table::new(&definition);
+    base.set_table_data(&get_rows(&[("a", 1), ("a", 1), ("b", 2)])).unwrap();
+    let other = get_table(&["db", "units_tables", "units"], &[("a", 1), ("b", 3)]);
+    let diff = TableDiff::new_from_table(&base, &["db".to_owned(), "units_tables".to_owned(), "units".to_owned()], &[&other]).unwrap();
+    assert_eq!(get_states(&diff), vec![RowDiffState::Unchanged, RowDiffState::Added, RowDiffState::Added, RowDiffState::Removed]);
+
+    let base = get_table(&["db", "units_tables", "units"], &[("a", 1), ("a", 2)]);
+    let other = get_table(&["db", "units_tables", "units"], &[("a", 2), ("a", 1), ("a", 3)]);
+    let diff = TableDiff::new(&base, &other).unwrap();
+    assert_eq!(get_states(&diff), vec![RowDiffState::Modified, RowDiffState::Modified, RowDiffState::Removed]);
+    assert_eq!(diff.get_ref_rows()[0].1, Some(get_rows(&[("a", 2)])[0].to_vec()));
+}
+
+/// Test to make sure tables are compared with all the tables of their type if there is none with their path,
+/// and that the columns of the other tables are matched by name and type.
+#[test]
+fn test_table_diff_from_table() {
+    let mut base = Table::new(&get_definition());
+    base.set_table_data(&get_rows(&[("a", 1), ("b", 2)])).unwrap();
+
+    // The other definition has an extra column, and lacks the value column.
+    let mut other_definition = Definition::new(2);
+    other_definition.get_ref_mut_fields().push(Field::new("extra".to_owned(), FieldType::StringU8, false, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()));
+    other_definition.get_ref_mut_fields().push(Field::new("key".to_owned(), FieldType::StringU8, true, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()));
+    let mut other_table = DB::new("units_tables", Some("uuid"), &other_definition);
+    other_table.set_table_data(&[vec![DecodedData::StringU8("extra".to_owned()), DecodedData::StringU8("b".to_owned())]]).unwrap();
+    let other = PackedFile::new_from_decoded(&DecodedPackedFile::DB(other_table), &["db".to_owned(), "units_tables".to_owned(), "theirs".to_owned()]);
+
+    let other_2 = get_table(&["db", "units_tables", "theirs_2"], &[("a", 1)]);
+    let unrelated = get_table(&["db", "factions_tables", "mine"], &[("a", 5)]);
+
+    let path = vec!["db".to_owned(), "units_tables".to_owned(), "mine".to_owned()];
+    let diff = TableDiff::new_from_table(&base, &path, &[&other, &other_2, &unrelated]).unwrap();
+    assert_eq!(get_states(&diff), vec![RowDiffState::Unchanged, RowDiffState::Modified]);
+    assert_eq!(diff.get_ref_rows()[1].1, Some(get_rows(&[("b", 0)])[0].to_vec()));
+
+    // Without tables of the same type, or for tables that are not DB Tables, there is nothing to compare.
+    let land_units_path = vec!["db".to_owned(), "land_units_tables".to_owned(), "mine".to_owned()];
+    assert!(TableDiff::new_from_table(&base, &land_units_path, &[&other, &unrelated]).is_err());
+    let loc_path = vec!["text".to_owned(), "db".to_owned(), "mine.loc".to_owned()];
+    assert!(TableDiff::new_from_table(&base, &loc_path, &[&other]).is_err());
+}
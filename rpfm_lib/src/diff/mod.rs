@@ -0,0 +1,411 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to the `PackFileDiff`.
+
+This module contains the code needed to compare two `PackFiles`, and to compare (and merge) the rows of a DB/Loc Table present in both of them.
+
+As mods rarely use the same name for their tables, DB Tables are compared by table type: all the tables of the same type in a
+`PackFile` are treated as one. Loc Tables have no type, so they're compared by path.
+!*/
+
+use rayon::prelude::*;
+
+use std::collections::{BTreeMap, HashMap};
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::packfile::PackFile;
+use crate::packfile::packedfile::PackedFile;
+use crate::packedfile::{DecodedPackedFile, PackedFileType};
+use crate::packedfile::table::{DecodedData, Table};
+use crate::schema::Definition;
+
+#[cfg(test)]
+mod diff_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the differences between two `PackFiles`, by path.
+#[derive(Debug, Clone, Default)]
+pub struct PackFileDiff {
+
+    /// PackedFiles only present in the base `PackFile`.
+    added: Vec<Vec<String>>,
+
+    /// PackedFiles only present in the `PackFile` we compare against.
+    removed: Vec<Vec<String>>,
+
+    /// PackedFiles present in both `PackFiles`, but with different data.
+    modified: Vec<Vec<String>>,
+
+    /// Table groups present in both `PackFiles`, but with different tables or different data. Check `TableDiff::get_table_group` for the details.
+    tables: Vec<Vec<String>>,
+}
+
+/// This struct contains the differences between the rows of two versions of the same table.
+///
+/// The rows of the other table are adapted to the definition of the base table, so both sides can be shown with the same columns.
+#[derive(Debug, Clone)]
+pub struct TableDiff {
+
+    /// Definition of the base table, used for both sides of the diff.
+    definition: Definition,
+
+    /// List of rows, with the base row, the other row and the state of the pair.
+    rows: Vec<(Option<Vec<DecodedData>>, Option<Vec<DecodedData>>, RowDiffState)>,
+}
+
+/// This enum represents the state of a row in a `TableDiff`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowDiffState {
+
+    /// The row is the same in both tables.
+    Unchanged,
+
+    /// The row is only in the base table.
+    Added,
+
+    /// The row is only in the other table.
+    Removed,
+
+    /// The row is in both tables, but some of its non-key cells are different.
+    Modified,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `PackFileDiff`.
+impl PackFileDiff {
+
+    /// This function compares the provided `PackFiles`, returning the PackedFiles added, removed and modified in the base one.
+    pub fn new(base: &PackFile, other: &PackFile) -> Result<Self> {
+        let mut diff = Self::default();
+
+        let base_paths = base.get_ref_packed_files_all_paths();
+        let other_paths = other.get_ref_packed_files_all_paths();
+
+        diff.added = base_paths.par_iter().filter(|path| !other_paths.contains(path)).map(|path| path.to_vec()).collect();
+        diff.removed = other_paths.par_iter().filter(|path| !base_paths.contains(path)).map(|path| path.to_vec()).collect();
+
+        diff.modified = base.get_ref_packed_files_all().par_iter()
+            .filter_map(|packed_file| other.get_ref_packed_file_by_path(packed_file.get_path()).map(|other_packed_file| (packed_file, other_packed_file)))
+            .map(|(packed_file, other_packed_file)| {
+
+                // Clone them, so we don't keep in memory the data of PackedFiles loaded lazily.
+                let data = Self::get_current_data(packed_file)?;
+                let other_data = Self::get_current_data(other_packed_file)?;
+                Ok(if data != other_data { Some(packed_file.get_path().to_vec()) } else { None })
+            })
+            .collect::<Result<Vec<Option<Vec<String>>>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.modified.sort();
+
+        // A group of tables is only the same in both PackFiles if it has the same tables, with the same data.
+        let base_groups = Self::get_table_groups(base);
+        let other_groups = Self::get_table_groups(other);
+        let modified = &diff.modified;
+        let tables = base_groups.iter()
+            .filter(|(group, paths)| match other_groups.get(*group) {
+                Some(other_paths) => paths.as_slice() != other_paths.as_slice() || paths.iter().any(|path| modified.contains(path)),
+                None => false,
+            })
+            .map(|(group, _)| group.to_vec())
+            .collect();
+
+        diff.tables = tables;
+
+        Ok(diff)
+    }
+
+    /// This function returns the table groups of the provided `PackFile`, with the sorted paths of the tables in each group.
+    fn get_table_groups(pack_file: &PackFile) -> BTreeMap<Vec<String>, Vec<Vec<String>>> {
+        let mut groups: BTreeMap<Vec<String>, Vec<Vec<String>>> = BTreeMap::new();
+        for path in pack_file.get_ref_packed_files_all_paths() {
+            if let Some(group) = TableDiff::get_table_group(path) {
+                groups.entry(group).or_insert_with(Vec::new).push(path.to_vec());
+            }
+        }
+
+        groups.values_mut().for_each(|paths| paths.sort());
+        groups
+    }
+
+    /// This function returns the data of the provided `PackedFile`, including any unsaved change in its decoded data.
+    fn get_current_data(packed_file: &PackedFile) -> Result<Vec<u8>> {
+        let mut packed_file = packed_file.clone();
+        packed_file.encode_and_return()?.get_data()
+    }
+
+    /// This function returns the paths of the PackedFiles only present in the base `PackFile`.
+    pub fn get_ref_added(&self) -> &[Vec<String>] {
+        &self.added
+    }
+
+    /// This function returns the paths of the PackedFiles only present in the `PackFile` we compared against.
+    pub fn get_ref_removed(&self) -> &[Vec<String>] {
+        &self.removed
+    }
+
+    /// This function returns the paths of the PackedFiles present in both `PackFiles`, but with different data.
+    pub fn get_ref_modified(&self) -> &[Vec<String>] {
+        &self.modified
+    }
+
+    /// This function returns the table groups present in both `PackFiles`, but with different tables or different data.
+    pub fn get_ref_tables(&self) -> &[Vec<String>] {
+        &self.tables
+    }
+
+    /// This function returns if both `PackFiles` are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Implementation of `TableDiff`.
+impl TableDiff {
+
+    /// This function compares the rows of the provided tables, which must be both DB Tables or both Loc Tables.
+    ///
+    /// Rows are matched using their key columns. If the table has no key columns, entire rows are compared instead.
+    pub fn new(base: &PackedFile, other: &PackedFile) -> Result<Self> {
+        Self::new_from_packed_files(&[base], &[other])
+    }
+
+    /// This function compares the rows of the tables of the provided group in both `PackFiles`.
+    ///
+    /// The tables of the group in each `PackFile` are treated as one, using the definition of the first table of the base `PackFile`.
+    pub fn new_from_table_group(base: &PackFile, other: &PackFile, group: &[String]) -> Result<Self> {
+        let base_packed_files = Self::get_table_group_packed_files(base, group);
+        let other_packed_files = Self::get_table_group_packed_files(other, group);
+        Self::new_from_packed_files(&base_packed_files, &other_packed_files)
+    }
+
+    /// This function compares the rows of the provided tables, as if the tables of each side were one.
+    ///
+    /// All the rows are adapted to the definition of the first base table.
+    fn new_from_packed_files(base: &[&PackedFile], other: &[&PackedFile]) -> Result<Self> {
+        if base.is_empty() || other.is_empty() {
+            return Err(ErrorKind::TableDiffNoCounterpart.into());
+        }
+
+        let base_tables = base.iter().map(|packed_file| Self::get_definition_and_rows(packed_file)).collect::<Result<Vec<(Definition, Vec<Vec<DecodedData>>)>>>()?;
+        let other_tables = other.iter().map(|packed_file| Self::get_definition_and_rows(packed_file)).collect::<Result<Vec<(Definition, Vec<Vec<DecodedData>>)>>>()?;
+
+        let definition = base_tables[0].0.clone();
+        let base_rows = base_tables.iter().flat_map(|(base_definition, base_rows)| Self::adapt_rows(&definition, base_definition, base_rows)).collect::<Vec<Vec<DecodedData>>>();
+        let other_rows = other_tables.iter().flat_map(|(other_definition, other_rows)| Self::adapt_rows(&definition, other_definition, other_rows)).collect::<Vec<Vec<DecodedData>>>();
+        Ok(Self::new_from_rows(definition, &base_rows, &other_rows))
+    }
+
+    /// This function returns the group the table with the provided path belongs to, or `None` if it's not a DB/Loc Table.
+    ///
+    /// The group of a DB Table is its table type (`db/table_name`), and the group of a Loc Table is its own path.
+    pub fn get_table_group(path: &[String]) -> Option<Vec<String>> {
+        match PackedFileType::get_packed_file_type(path) {
+            PackedFileType::DB if path.len() == 3 => Some(path[..2].to_vec()),
+            PackedFileType::Loc => Some(path.to_vec()),
+            _ => None,
+        }
+    }
+
+    /// This function returns the tables of the provided group in the provided `PackFile`, sorted by path.
+    fn get_table_group_packed_files<'a>(pack_file: &'a PackFile, group: &[String]) -> Vec<&'a PackedFile> {
+        let mut packed_files = pack_file.get_ref_packed_files_all().into_iter()
+            .filter(|packed_file| Self::get_table_group(packed_file.get_path()).map_or(false, |x| x == group))
+            .collect::<Vec<&PackedFile>>();
+        packed_files.sort_by(|x, y| x.get_path().cmp(y.get_path()));
+        packed_files
+    }
+
+    /// This function compares the rows of the provided table with the ones of its version in the provided PackedFiles.
+    ///
+    /// That version is the PackedFile with the provided path. If there is none and the table is a DB Table, all the DB Tables
+    /// of the same type are used instead, as if they were one. Tables that fail to decode are ignored.
+    pub fn new_from_table(base: &Table, path: &[String], packed_files: &[&PackedFile]) -> Result<Self> {
+        let definition = base.get_ref_definition();
+        let other_rows = match packed_files.iter().find(|packed_file| packed_file.get_path() == path) {
+            Some(packed_file) => {
+                let (other_definition, other_rows) = Self::get_definition_and_rows(packed_file)?;
+                Self::adapt_rows(definition, &other_definition, &other_rows)
+            }
+            None => {
+                if path.len() != 3 || path[0] != "db" {
+                    return Err(ErrorKind::TableDiffNoCounterpart.into());
+                }
+
+                let tables = packed_files.iter()
+                    .filter(|packed_file| {
+                        let other_path = packed_file.get_path();
+                        other_path.len() == 3 && other_path[0] == "db" && other_path[1] == path[1]
+                    })
+                    .filter_map(|packed_file| Self::get_definition_and_rows(packed_file).ok())
+                    .collect::<Vec<(Definition, Vec<Vec<DecodedData>>)>>();
+
+                if tables.is_empty() {
+                    return Err(ErrorKind::TableDiffNoCounterpart.into());
+                }
+
+                tables.iter().flat_map(|(other_definition, other_rows)| Self::adapt_rows(definition, other_definition, other_rows)).collect()
+            }
+        };
+
+        Ok(Self::new_from_rows(definition.clone(), base.get_ref_table_data(), &other_rows))
+    }
+
+    /// This function adapts the provided rows to the provided definition, matching the columns by name.
+    ///
+    /// Columns missing in the rows get their default value.
+    fn adapt_rows(definition: &Definition, other_definition: &Definition, other_rows: &[Vec<DecodedData>]) -> Vec<Vec<DecodedData>> {
+        let fields = definition.get_ref_fields();
+        let other_fields = other_definition.get_ref_fields();
+        let columns = fields.iter()
+            .map(|field| other_fields.iter().position(|other_field| other_field.get_name() == field.get_name() && other_field.get_ref_field_type() == field.get_ref_field_type()))
+            .collect::<Vec<Option<usize>>>();
+
+        other_rows.iter().map(|row| fields.iter().zip(columns.iter()).map(|(field, column)| match column {
+            Some(column) => row[*column].clone(),
+            None => DecodedData::default(field.get_ref_field_type()),
+        }).collect::<Vec<DecodedData>>()).collect::<Vec<Vec<DecodedData>>>()
+    }
+
+    /// This function compares the provided rows, which must be already adapted to the provided definition.
+    ///
+    /// Rows are matched using their key columns. If the table has no key columns, entire rows are compared instead.
+    fn new_from_rows(definition: Definition, base_rows: &[Vec<DecodedData>], other_rows: &[Vec<DecodedData>]) -> Self {
+        let fields = definition.get_ref_fields();
+        let key_columns = fields.iter().enumerate()
+            .filter(|(_, field)| field.get_is_key())
+            .map(|(column, _)| column)
+            .collect::<Vec<usize>>();
+
+        let get_key = |row: &[DecodedData]| -> Vec<String> {
+            if key_columns.is_empty() { row.iter().map(|x| x.data_to_string()).collect() }
+            else { key_columns.iter().map(|column| row[*column].data_to_string()).collect() }
+        };
+
+        // Keep the other rows by key, so we don't have to go through all of them for each one of our rows.
+        let mut other_rows_by_key: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+        for (index, other_row) in other_rows.iter().enumerate().rev() {
+            other_rows_by_key.entry(get_key(other_row)).or_insert_with(Vec::new).push(index);
+        }
+
+        let mut other_matched = vec![false; other_rows.len()];
+        let mut rows = vec![];
+        for base_row in base_rows {
+            match other_rows_by_key.get_mut(&get_key(base_row)).and_then(|indexes| indexes.pop()) {
+                Some(index) => {
+                    other_matched[index] = true;
+                    let state = if *base_row == other_rows[index] { RowDiffState::Unchanged } else { RowDiffState::Modified };
+                    rows.push((Some(base_row.to_vec()), Some(other_rows[index].to_vec()), state));
+                }
+                None => rows.push((Some(base_row.to_vec()), None, RowDiffState::Added)),
+            }
+        }
+
+        for (index, other_row) in other_rows.iter().enumerate() {
+            if !other_matched[index] {
+                rows.push((None, Some(other_row.to_vec()), RowDiffState::Removed));
+            }
+        }
+
+        Self {
+            definition,
+            rows,
+        }
+    }
+
+    /// This function merges the provided tables, returning a copy of the base table with the merged rows.
+    ///
+    /// Rows only present in one of the tables are kept. For rows present in both with different data, the version of the
+    /// base table wins, except for the rows whose index in the diff is in `other_wins`.
+    pub fn merge(base: &PackedFile, other: &PackedFile, other_wins: &[usize]) -> Result<PackedFile> {
+        Self::merge_packed_files(&[base], &[other], other_wins)
+    }
+
+    /// This function merges the tables of the provided group in both `PackFiles`, returning a copy of the first table
+    /// of the group in the base `PackFile` with the merged rows of the entire group.
+    ///
+    /// Conflicts are solved like in `TableDiff::merge`, with the indexes of the diff returned by `TableDiff::new_from_table_group`.
+    pub fn merge_table_group(base: &PackFile, other: &PackFile, group: &[String], other_wins: &[usize]) -> Result<PackedFile> {
+        let base_packed_files = Self::get_table_group_packed_files(base, group);
+        let other_packed_files = Self::get_table_group_packed_files(other, group);
+        Self::merge_packed_files(&base_packed_files, &other_packed_files, other_wins)
+    }
+
+    /// This function merges the provided tables, as if the tables of each side were one, into a copy of the first base table.
+    fn merge_packed_files(base: &[&PackedFile], other: &[&PackedFile], other_wins: &[usize]) -> Result<PackedFile> {
+        let diff = Self::new_from_packed_files(base, other)?;
+        let rows = diff.get_merged_rows(other_wins);
+
+        let mut packed_file = base[0].clone();
+        match packed_file.decode_return_ref_mut()? {
+            DecodedPackedFile::DB(table) => table.set_table_data(&rows)?,
+            DecodedPackedFile::Loc(table) => table.set_table_data(&rows)?,
+            _ => return Err(ErrorKind::TableDiffNotATable.into()),
+        }
+
+        Ok(packed_file)
+    }
+
+    /// This function returns the rows resulting of merging both sides of the diff. Check `TableDiff::merge` for the details.
+    pub fn get_merged_rows(&self, other_wins: &[usize]) -> Vec<Vec<DecodedData>> {
+        self.rows.iter().enumerate().filter_map(|(index, (base_row, other_row, state))| match state {
+            RowDiffState::Modified if other_wins.contains(&index) => other_row.clone(),
+            RowDiffState::Removed => other_row.clone(),
+            _ => base_row.clone(),
+        }).collect()
+    }
+
+    /// This function returns the definition and the rows of the provided table, without leaving it decoded in the cache.
+    fn get_definition_and_rows(packed_file: &PackedFile) -> Result<(Definition, Vec<Vec<DecodedData>>)> {
+        let mut packed_file = packed_file.clone();
+        match packed_file.decode_return_ref()? {
+            DecodedPackedFile::DB(table) => Ok((table.get_ref_definition().clone(), table.get_table_data())),
+            DecodedPackedFile::Loc(table) => Ok((table.get_ref_definition().clone(), table.get_table_data())),
+            _ => Err(ErrorKind::TableDiffNotATable.into()),
+        }
+    }
+
+    /// This function returns the definition used by both sides of the diff.
+    pub fn get_ref_definition(&self) -> &Definition {
+        &self.definition
+    }
+
+    /// This function returns the rows of the diff.
+    pub fn get_ref_rows(&self) -> &[(Option<Vec<DecodedData>>, Option<Vec<DecodedData>>, RowDiffState)] {
+        &self.rows
+    }
+
+    /// This function returns the columns with different data in the row of the diff with the provided index.
+    ///
+    /// Only modified rows have different columns, as the other rows are either the same or only in one of the tables.
+    pub fn get_modified_columns(&self, index: usize) -> Vec<usize> {
+        match self.rows.get(index) {
+            Some((Some(base_row), Some(other_row), RowDiffState::Modified)) => base_row.iter().zip(other_row.iter())
+                .enumerate()
+                .filter(|(_, (base_cell, other_cell))| base_cell != other_cell)
+                .map(|(column, _)| column)
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
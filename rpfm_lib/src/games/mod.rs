@@ -15,9 +15,21 @@ This module defines the list of games this lib support for any `Game-Specific` f
 You should have no business here, except for supporting a new game.
 !*/
 
+use directories::BaseDirs;
 use indexmap::IndexMap;
+use sysinfo::{ProcessExt, RefreshKind, System, SystemExt};
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rpfm_error::{ErrorKind, Result};
+
 use crate::packfile::PFHVersion;
 
+pub mod workshop;
+
 // Display Name for all the Supported Games.
 pub const DISPLAY_NAME_TROY: &str = "Troy";
 pub const DISPLAY_NAME_THREE_KINGDOMS: &str = "Three Kingdoms";
@@ -44,6 +56,12 @@ pub const KEY_NAPOLEON: &str = "napoleon";
 pub const KEY_EMPIRE: &str = "empire";
 pub const KEY_ARENA: &str = "arena";
 
+/// Name of the file with the list of mods to load, for games that take it as argument.
+pub const USED_MODS_FILE: &str = "used_mods.txt";
+
+/// Name of the file with the list of mods to load, for games that read it from their `scripts` folder.
+pub const USER_SCRIPT_FILE: &str = "user.script.txt";
+
 /// This struct represents the list of games supported by this lib.
 pub type SupportedGames = IndexMap<&'static str, GameInfo>;
 
@@ -86,6 +104,88 @@ pub struct GameInfo {
 
     /// Name of the big icon used to display the game as `Game Selected`, in an UI.
     pub game_selected_big_icon: String,
+
+    /// Name of the executable of the game, relative to its folder. If the game cannot be launched directly, set it as None.
+    pub executable: Option<String>,
+
+    /// Folder within `%AppData%/The Creative Assembly/` from where the game reads its `user.script.txt`.
+    /// Games that take a `used_mods.txt` as argument instead don't have one, so set it as None for them.
+    pub user_script_folder: Option<String>,
+}
+
+/// Implementation of `GameInfo`.
+impl GameInfo {
+
+    /// This function writes the list of mods of the game so it loads the provided PackFiles, and launches it.
+    ///
+    /// Games using a `used_mods.txt` can load PackFiles from any folder. Games using a `user.script.txt`
+    /// can only load PackFiles from their `/data` folder, so in that case all of them must be installed there.
+    pub fn launch_with_mods(&self, game_path: &Path, pack_file_paths: &[PathBuf]) -> Result<()> {
+        let executable = self.executable.as_ref().ok_or(ErrorKind::LaunchNotSupportedForThisGame)?;
+        let data_path = game_path.join("data");
+
+        let mut working_directories: Vec<&Path> = vec![];
+        let mut mod_list = String::new();
+        for path in pack_file_paths {
+            let file_name = path.file_name().ok_or(ErrorKind::PackFileIsNotAFile)?.to_string_lossy().to_string();
+            if let Some(folder) = path.parent() {
+                if folder != data_path {
+                    if self.user_script_folder.is_some() {
+                        return Err(ErrorKind::LaunchPackFileNotInDataFolder(file_name).into());
+                    }
+
+                    if !working_directories.contains(&folder) {
+                        working_directories.push(folder);
+                    }
+                }
+            }
+
+            mod_list.push_str(&format!("mod \"{}\";\n", file_name));
+        }
+
+        let working_directories = working_directories.iter().map(|folder| format!("add_working_directory \"{}\";\n", folder.to_string_lossy())).collect::<String>();
+        let arguments = match self.user_script_folder {
+            Some(ref user_script_folder) => {
+                let base_dirs = BaseDirs::new().ok_or(ErrorKind::IOFolderCannotBeOpened)?;
+                let scripts_path = base_dirs.config_dir().join("The Creative Assembly").join(user_script_folder).join("scripts");
+                DirBuilder::new().recursive(true).create(&scripts_path)?;
+
+                let mut file = BufWriter::new(File::create(scripts_path.join(USER_SCRIPT_FILE))?);
+                file.write_all(mod_list.as_bytes())?;
+                vec![]
+            }
+            None => {
+                let mut file = BufWriter::new(File::create(game_path.join(USED_MODS_FILE))?);
+                file.write_all(working_directories.as_bytes())?;
+                file.write_all(mod_list.as_bytes())?;
+                vec![format!("{};", USED_MODS_FILE)]
+            }
+        };
+
+        Command::new(game_path.join(executable))
+            .args(&arguments)
+            .current_dir(game_path)
+            .spawn()?;
+        Ok(())
+    }
+
+    /// This function returns if there is a process of the game running.
+    ///
+    /// Processes are matched by the name of their executable. Some systems truncate the names of the
+    /// processes (Linux keeps only the first 15 characters), so we also accept a truncated name.
+    pub fn is_running(&self) -> bool {
+        let executable = match self.executable {
+            Some(ref executable) => executable.to_lowercase(),
+            None => return false,
+        };
+
+        let system = System::new_with_specifics(RefreshKind::new().with_processes());
+        system.get_processes().values().any(|process| {
+            let name = process.name().to_lowercase();
+            let exe_name = process.exe().file_name().map(|name| name.to_string_lossy().to_lowercase()).unwrap_or_default();
+            name == executable || exe_name == executable || (name.len() >= 15 && executable.starts_with(&name))
+        })
+    }
 }
 
 /// This function returns a `SupportedGames` struct with the list of all games supported by this lib inside.
@@ -120,6 +220,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_troy.png".to_owned(),
         game_selected_big_icon: "gs_big_troy.png".to_owned(),
+        executable: Some("Troy.exe".to_owned()),
+        user_script_folder: None,
     });
 
     // Three Kingdoms
@@ -150,6 +252,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_3k.png".to_owned(),
         game_selected_big_icon: "gs_big_3k.png".to_owned(),
+        executable: Some("Three_Kingdoms.exe".to_owned()),
+        user_script_folder: None,
     });
 
     // Warhammer 2
@@ -180,6 +284,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_wh2.png".to_owned(),
         game_selected_big_icon: "gs_big_wh2.png".to_owned(),
+        executable: Some("Warhammer2.exe".to_owned()),
+        user_script_folder: None,
     });
 
     // Warhammer
@@ -214,6 +320,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_wh.png".to_owned(),
         game_selected_big_icon: "gs_big_wh.png".to_owned(),
+        executable: Some("Warhammer.exe".to_owned()),
+        user_script_folder: None,
     });
 
     // Thrones of Britannia
@@ -244,6 +352,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_tob.png".to_owned(),
         game_selected_big_icon: "gs_big_tob.png".to_owned(),
+        executable: Some("Thrones.exe".to_owned()),
+        user_script_folder: None,
     });
 
     // Attila
@@ -274,6 +384,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_att.png".to_owned(),
         game_selected_big_icon: "gs_big_att.png".to_owned(),
+        executable: Some("Attila.exe".to_owned()),
+        user_script_folder: Some("Attila".to_owned()),
     });
 
     // Rome 2
@@ -304,6 +416,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_rom2.png".to_owned(),
         game_selected_big_icon: "gs_big_rom2.png".to_owned(),
+        executable: Some("Rome2.exe".to_owned()),
+        user_script_folder: Some("Rome2".to_owned()),
     });
 
     // Shogun 2
@@ -334,6 +448,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_sho2.png".to_owned(),
         game_selected_big_icon: "gs_big_sho2.png".to_owned(),
+        executable: Some("Shogun2.exe".to_owned()),
+        user_script_folder: Some("Shogun2".to_owned()),
     });
 
     // Napoleon
@@ -384,6 +500,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_nap.png".to_owned(),
         game_selected_big_icon: "gs_big_nap.png".to_owned(),
+        executable: Some("Napoleon.exe".to_owned()),
+        user_script_folder: Some("Napoleon".to_owned()),
     });
 
     // Empire
@@ -435,6 +553,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: true,
         game_selected_icon: "gs_emp.png".to_owned(),
         game_selected_big_icon: "gs_big_emp.png".to_owned(),
+        executable: Some("Empire.exe".to_owned()),
+        user_script_folder: Some("Empire".to_owned()),
     });
 
     // NOTE: There are things that depend on the order of this list, and this game must ALWAYS be the last one.
@@ -453,6 +573,8 @@ pub fn get_supported_games_list() -> SupportedGames {
         supports_editing: false,
         game_selected_icon: "gs_are.png".to_owned(),
         game_selected_big_icon: "gs_big_are.png".to_owned(),
+        executable: None,
+        user_script_folder: None,
     });
 
     list
@@ -0,0 +1,202 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to list the Steam Workshop items a game has downloaded.
+
+Steam downloads every subscribed item to `steamapps/workshop/content/{game_steam_id}/{item_id}/`, and keeps the
+list of installed items, with the time they were last updated, in `steamapps/workshop/appworkshop_{game_steam_id}.acf`.
+The folders are only named after the numeric id of the item, so we take the names of the mods from the mod data
+files of the game's launcher, if they exist, and from the names of their PackFiles if they don't.
+!*/
+
+use directories::BaseDirs;
+use serde_json::Value;
+
+use std::collections::HashMap;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+use rpfm_error::{ErrorKind, Result};
+
+/// Name of the folder where Steam keeps the downloaded Workshop items, relative to `steamapps`.
+const WORKSHOP_FOLDER: &str = "workshop";
+
+/// Suffix of the names of the mod data files of the launcher of the games.
+const LAUNCHER_MOD_DATA_SUFFIX: &str = "moddata.dat";
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents a Workshop item downloaded for a game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkshopItem {
+
+    /// Numeric id of the item in the Workshop.
+    pub id: String,
+
+    /// Name of the mod. If we cannot find it in the launcher's data, it's the name of its first PackFile.
+    pub name: String,
+
+    /// Paths of the PackFiles within the item's folder.
+    pub pack_file_paths: Vec<PathBuf>,
+
+    /// Time the item was last updated, as an Unix timestamp, if Steam knows it.
+    pub time_updated: Option<i64>,
+}
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function returns the `steamapps/workshop` folder of the game installed in the provided path.
+fn get_workshop_path(game_path: &Path) -> PathBuf {
+    let mut path = game_path.to_path_buf();
+    path.pop();
+    path.pop();
+    path.push(WORKSHOP_FOLDER);
+    path
+}
+
+/// This function returns the folder where Steam downloads the Workshop items of the game installed in the provided path.
+pub fn get_workshop_content_path(game_path: &Path, steam_id: u64) -> PathBuf {
+    get_workshop_path(game_path).join("content").join(steam_id.to_string())
+}
+
+/// This function returns the Workshop items downloaded for the game installed in the provided path, sorted by name.
+///
+/// Items without PackFiles are skipped, as there is nothing we can do with them.
+pub fn get_workshop_items(game_path: &Path, steam_id: u64) -> Result<Vec<WorkshopItem>> {
+    let content_path = get_workshop_content_path(game_path, steam_id);
+    if !content_path.is_dir() {
+        return Err(ErrorKind::WorkshopContentFolderNotFound(content_path).into());
+    }
+
+    let times_updated = get_times_updated(&get_workshop_path(game_path).join(format!("appworkshop_{}.acf", steam_id)));
+    let mod_names = get_launcher_mod_names();
+
+    let mut items = vec![];
+    for entry in read_dir(&content_path)? {
+        let item_path = entry?.path();
+        if !item_path.is_dir() { continue; }
+
+        let mut pack_file_paths = read_dir(&item_path)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().map_or(false, |extension| extension == "pack"))
+            .collect::<Vec<PathBuf>>();
+        if pack_file_paths.is_empty() { continue; }
+        pack_file_paths.sort();
+
+        let id = item_path.file_name().unwrap().to_string_lossy().to_string();
+        let pack_file_name = pack_file_paths[0].file_name().unwrap().to_string_lossy().to_string();
+        let name = mod_names.get(&pack_file_name).cloned().unwrap_or(pack_file_name);
+        let time_updated = times_updated.get(&id).cloned();
+        items.push(WorkshopItem {
+            id,
+            name,
+            pack_file_paths,
+            time_updated,
+        });
+    }
+
+    items.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    Ok(items)
+}
+
+/// This function returns the time each installed Workshop item was last updated, from the provided `appworkshop_xxx.acf` file.
+///
+/// If the file cannot be read, it returns an empty list, as the times are just extra info.
+fn get_times_updated(acf_path: &Path) -> HashMap<String, i64> {
+    let mut times_updated = HashMap::new();
+    let data = match read_to_string(acf_path) {
+        Ok(data) => data,
+        Err(_) => return times_updated,
+    };
+
+    // The file is a tree of quoted keys and values, with blocks between braces. We only care about the
+    // `timeupdated` values within the blocks of `WorkshopItemsInstalled`, which are named after the item's id.
+    let tokens = get_vdf_tokens(&data);
+    let mut path: Vec<&str> = vec![];
+    let mut last_key: Option<&str> = None;
+    for token in tokens {
+        match token {
+            "{" => {
+                path.push(last_key.take().unwrap_or_default());
+            }
+            "}" => {
+                path.pop();
+                last_key = None;
+            }
+            _ => match last_key.take() {
+                Some(key) => {
+                    if key.eq_ignore_ascii_case("timeupdated") && path.len() >= 2 && path[path.len() - 2].eq_ignore_ascii_case("WorkshopItemsInstalled") {
+                        if let Ok(time_updated) = token.parse::<i64>() {
+                            times_updated.insert(path[path.len() - 1].to_owned(), time_updated);
+                        }
+                    }
+                }
+                None => last_key = Some(token),
+            }
+        }
+    }
+
+    times_updated
+}
+
+/// This function splits the provided VDF text into its quoted strings (without quotes) and braces.
+fn get_vdf_tokens(data: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (index, character) in data.char_indices() {
+        match (character, start) {
+            ('"', None) => start = Some(index + 1),
+            ('"', Some(token_start)) => {
+                tokens.push(&data[token_start..index]);
+                start = None;
+            }
+            ('{', None) => tokens.push("{"),
+            ('}', None) => tokens.push("}"),
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// This function returns the names of the mods known by the launchers of the games, by the name of their PackFile.
+///
+/// The launcher keeps them in JSON files within `%AppData%/The Creative Assembly/Launcher/`. Files that cannot be read are ignored.
+fn get_launcher_mod_names() -> HashMap<String, String> {
+    let mut mod_names = HashMap::new();
+    let launcher_path = match BaseDirs::new() {
+        Some(base_dirs) => base_dirs.config_dir().join("The Creative Assembly").join("Launcher"),
+        None => return mod_names,
+    };
+
+    if let Ok(entries) = read_dir(launcher_path) {
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            if !path.file_name().map_or(false, |name| name.to_string_lossy().ends_with(LAUNCHER_MOD_DATA_SUFFIX)) { continue; }
+            let mods = match read_to_string(&path).ok().and_then(|data| serde_json::from_str::<Value>(&data).ok()) {
+                Some(Value::Array(mods)) => mods,
+                _ => continue,
+            };
+
+            for mod_data in &mods {
+                if let (Some(pack_file_name), Some(name)) = (mod_data["packfile"].as_str(), mod_data["name"].as_str()) {
+                    if !name.is_empty() {
+                        mod_names.insert(pack_file_name.to_owned(), name.to_owned());
+                    }
+                }
+            }
+        }
+    }
+
+    mod_names
+}
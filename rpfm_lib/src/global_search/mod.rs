@@ -14,13 +14,18 @@ Module with all the code related to the `GlobalSearch`.
 This module contains the code needed to get a `GlobalSeach` over an entire `PackFile`.
 !*/
 
-use regex::{RegexBuilder, Regex};
+use regex::{escape, RegexBuilder, Regex};
 use rayon::prelude::*;
+use serde_derive::{Serialize, Deserialize};
+
+use std::path::PathBuf;
 
 use rpfm_error::{ErrorKind, Result};
 
+use crate::common::{get_game_selected_content_packfiles_paths, get_game_selected_data_packfiles_paths};
+
 use crate::packfile::{PackFile, PathType};
-use crate::packfile::packedfile::PackedFileInfo;
+use crate::packfile::packedfile::{PackedFile, PackedFileInfo};
 use crate::packedfile::{DecodedPackedFile, PackedFileType};
 use crate::packedfile::table::{DecodedData, db::DB, loc::Loc};
 use crate::packedfile::text::{Text, TextType};
@@ -67,6 +72,14 @@ pub struct GlobalSearch {
     /// If we should search on the currently loaded Schema.
     pub search_on_schema: bool,
 
+    /// Pattern the paths of the PackedFiles must match to be searched, like `db/units_*`. If empty, all PackedFiles are searched.
+    ///
+    /// It's a regex if `use_regex` is enabled, and a glob with `*` and `?` as wildcards if it's not. It's always case insensitive.
+    pub path_filter: String,
+
+    /// Which PackFiles, besides the open one, should be searched.
+    pub scope: SearchScope,
+
     /// Matches on DB Tables.
     pub matches_db: Vec<TableMatches>,
 
@@ -88,6 +101,22 @@ enum MatchingMode {
     Pattern,
 }
 
+/// This enum represents the PackFiles a search should cover.
+///
+/// PackFiles other than the open one are only searched read-only, so their matches cannot be replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SearchScope {
+
+    /// Only the open PackFile.
+    PackFile,
+
+    /// The open PackFile, and the PackFiles in its dependency list.
+    PackFileAndParents,
+
+    /// The open PackFile, and all the PackFiles in the `/data` folder of the Game Selected, and the ones its depends on from the Workshop.
+    AllGamePacks,
+}
+
 /// This enum is a way to put toguether all kind of matches.
 #[derive(Debug, Clone)]
 pub enum MatchHolder {
@@ -96,6 +125,14 @@ pub enum MatchHolder {
     Schema(SchemaMatches),
 }
 
+/// This enum holds the matches of a single PackedFile, so PackedFiles of any type can be searched in the same parallel pass, and their matches reported as soon as they are found.
+#[derive(Debug, Clone)]
+pub enum PackedFileMatches {
+    DB(TableMatches),
+    Loc(TableMatches),
+    Text(TextMatches),
+}
+
 //---------------------------------------------------------------p----------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
@@ -112,6 +149,8 @@ impl Default for GlobalSearch {
             search_on_locs: true,
             search_on_texts: true,
             search_on_schema: false,
+            path_filter: "".to_owned(),
+            scope: SearchScope::PackFile,
             matches_db: vec![],
             matches_loc: vec![],
             matches_text: vec![],
@@ -120,19 +159,79 @@ impl Default for GlobalSearch {
     }
 }
 
+/// Implementation of `PackedFileMatches`.
+impl PackedFileMatches {
+
+    /// This function sets the name of the PackFile the matches are from. None means they're from the open PackFile.
+    fn set_source(&mut self, source: Option<&str>) {
+        let source = source.map(|source| source.to_owned());
+        match self {
+            Self::DB(matches) | Self::Loc(matches) => matches.source = source,
+            Self::Text(matches) => matches.source = source,
+        }
+    }
+
+    /// This function returns if there are no matches.
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::DB(matches) | Self::Loc(matches) => matches.matches.is_empty(),
+            Self::Text(matches) => matches.matches.is_empty(),
+        }
+    }
+}
+
+/// Implementation of `SearchScope`.
+impl SearchScope {
+
+    /// This function returns the paths of the PackFiles this scope covers, besides the provided one.
+    pub fn get_pack_file_paths(self, pack_file: &PackFile) -> Vec<PathBuf> {
+        let data_paths = get_game_selected_data_packfiles_paths().unwrap_or_default();
+        let content_paths = get_game_selected_content_packfiles_paths().unwrap_or_default();
+        let dependencies = pack_file.get_packfiles_list();
+        let is_dependency = |path: &PathBuf| path.file_name().map_or(false, |name| dependencies.iter().any(|x| *x == name.to_string_lossy()));
+
+        let paths: Vec<PathBuf> = match self {
+            Self::PackFile => vec![],
+            Self::PackFileAndParents => data_paths.into_iter().chain(content_paths.into_iter()).filter(is_dependency).collect(),
+            Self::AllGamePacks => data_paths.into_iter().chain(content_paths.into_iter().filter(is_dependency)).collect(),
+        };
+
+        paths.into_iter().filter(|path| path != pack_file.get_file_path()).collect()
+    }
+}
+
 /// Implementation of `GlobalSearch`.
 impl GlobalSearch {
 
     /// This function performs a search over the parts of a `PackFile` you specify it, storing his results.
-    pub fn search(&mut self, pack_file: &mut PackFile) {
+    ///
+    /// The matches of each PackedFile are also passed to `on_matches` as soon as they're found, so they can be shown before the search ends.
+    pub fn search(&mut self, pack_file: &mut PackFile, on_matches: &(dyn Fn(&PackedFileMatches) + Sync)) {
 
-        // If we want to use regex and the pattern is invalid, don't search.
-        let matching_mode = if self.use_regex {
-            if let Ok(regex) = RegexBuilder::new(&self.pattern).case_insensitive(self.case_sensitive).build() {
-                MatchingMode::Regex(regex)
+        // Ensure we don't store results from previous searches.
+        self.matches_db = vec![];
+        self.matches_loc = vec![];
+        self.matches_text = vec![];
+        self.matches_schema = vec![];
+
+        let mut packed_files = pack_file.get_ref_mut_packed_files_all().into_iter().map(|packed_file| (None, packed_file)).collect::<Vec<(Option<&str>, &mut PackedFile)>>();
+        let (matches_db, matches_loc, matches_text) = self.search_on_packed_files(&mut packed_files, true, on_matches);
+        self.matches_db = matches_db;
+        self.matches_loc = matches_loc;
+        self.matches_text = matches_text;
+
+        if self.search_on_schema {
+            if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                let matching_mode = self.get_matching_mode();
+                self.search_on_schema(schema, &matching_mode);
             }
-            else { MatchingMode::Pattern }
-        } else { MatchingMode::Pattern };
+        }
+    }
+
+    /// This function performs a read-only search over the provided PackedFiles, like the ones in the dependency database, storing his results.
+    ///
+    /// Only DB, Loc and Text PackedFiles are searched, and PackedFiles without matches are not included in the results.
+    pub fn search_on_dependencies(&mut self, packed_files: &mut [PackedFile]) {
 
         // Ensure we don't store results from previous searches.
         self.matches_db = vec![];
@@ -140,47 +239,92 @@ impl GlobalSearch {
         self.matches_text = vec![];
         self.matches_schema = vec![];
 
+        let mut packed_files = packed_files.iter_mut().map(|packed_file| (None, packed_file)).collect::<Vec<(Option<&str>, &mut PackedFile)>>();
+        let (matches_db, matches_loc, matches_text) = self.search_on_packed_files(&mut packed_files, false, &|_| {});
+        self.matches_db = matches_db;
+        self.matches_loc = matches_loc;
+        self.matches_text = matches_text;
+    }
+
+    /// This function performs a read-only search over the provided PackFiles, adding his results to the current ones.
+    ///
+    /// The matches are flagged with the name of the PackFile they're from, so they're not mistaken by matches of the open PackFile.
+    /// The PackedFiles of all the PackFiles are searched toguether, so big PackFiles don't leave the other threads idle.
+    /// Like in `search`, the matches of each PackedFile are passed to `on_matches` as soon as they're found.
+    pub fn search_on_pack_files(&mut self, pack_files: &mut [&mut PackFile], on_matches: &(dyn Fn(&PackedFileMatches) + Sync)) {
+        let sources = pack_files.iter().map(|pack_file| pack_file.get_file_name()).collect::<Vec<String>>();
+        let mut packed_files = pack_files.iter_mut()
+            .zip(sources.iter())
+            .flat_map(|(pack_file, source)| pack_file.get_ref_mut_packed_files_all().into_iter().map(move |packed_file| (Some(&**source), packed_file)))
+            .collect::<Vec<(Option<&str>, &mut PackedFile)>>();
+
+        let (mut matches_db, mut matches_loc, mut matches_text) = self.search_on_packed_files(&mut packed_files, false, on_matches);
+        self.matches_db.append(&mut matches_db);
+        self.matches_loc.append(&mut matches_loc);
+        self.matches_text.append(&mut matches_text);
+    }
+
+    /// This function searches the provided PackedFiles in parallel and returns the matches on DB, Loc and Text PackedFiles, in that order.
+    ///
+    /// Each PackedFile comes with the name of the PackFile it's from, if it's not from the open one. PackedFiles out of the
+    /// path filter are not included in the results, and neither are the ones without matches, unless `keep_empty` is true.
+    ///
+    /// The PackedFiles with matches are also passed to `on_matches` from the searching threads, as soon as they're searched.
+    fn search_on_packed_files(
+        &self,
+        packed_files: &mut [(Option<&str>, &mut PackedFile)],
+        keep_empty: bool,
+        on_matches: &(dyn Fn(&PackedFileMatches) + Sync),
+    ) -> (Vec<TableMatches>, Vec<TableMatches>, Vec<TextMatches>) {
+        let mut matches_db = vec![];
+        let mut matches_loc = vec![];
+        let mut matches_text = vec![];
+
         // If we got no schema, don't even decode.
         if let Some(ref schema) = *SCHEMA.read().unwrap() {
-            if self.search_on_dbs {
-                let mut packed_files = pack_file.get_ref_mut_packed_files_by_type(PackedFileType::DB, false);
-                self.matches_db = packed_files.par_iter_mut().filter_map(|packed_file| {
-                    let path = packed_file.get_path().to_vec();
-                    if let Ok(decoded_packed_file) = packed_file.decode_return_ref_no_locks(&schema) {
-                        if let DecodedPackedFile::DB(data) = decoded_packed_file {
-                            Some(self.search_on_db(&path, &data, &matching_mode))
-                        } else { None }
-                    } else { None }
-                }).collect();
-            }
+            let matching_mode = self.get_matching_mode();
+            let path_filter = self.get_path_filter();
+            let matches = packed_files.par_iter_mut()
+                .filter(|(_, packed_file)| Self::is_path_included(&path_filter, packed_file.get_path()))
+                .filter_map(|(source, packed_file)| {
+                    let mut matches = self.search_on_packed_file(packed_file, schema, &matching_mode)?;
+                    matches.set_source(*source);
+                    if !matches.is_empty() {
+                        on_matches(&matches);
+                    }
 
-            if self.search_on_locs {
-                let mut packed_files = pack_file.get_ref_mut_packed_files_by_type(PackedFileType::Loc, false);
-                self.matches_loc = packed_files.par_iter_mut().filter_map(|packed_file| {
-                    let path = packed_file.get_path().to_vec();
-                    if let Ok(decoded_packed_file) = packed_file.decode_return_ref_no_locks(&schema) {
-                        if let DecodedPackedFile::Loc(data) = decoded_packed_file {
-                            Some(self.search_on_loc(&path, &data, &matching_mode))
-                        } else { None }
-                    } else { None }
-                }).collect();
-            }
+                    Some(matches).filter(|matches| keep_empty || !matches.is_empty())
+                })
+                .collect::<Vec<PackedFileMatches>>();
 
-            if self.search_on_texts {
-                let mut packed_files = pack_file.get_ref_mut_packed_files_by_type(PackedFileType::Text(TextType::Plain), false);
-                self.matches_text = packed_files.par_iter_mut().filter_map(|packed_file| {
-                    let path = packed_file.get_path().to_vec();
-                    if let Ok(decoded_packed_file) = packed_file.decode_return_ref_no_locks(&schema) {
-                        if let DecodedPackedFile::Text(data) = decoded_packed_file {
-                            Some(self.search_on_text(&path, &data, &matching_mode))
-                        } else { None }
-                    } else { None }
-                }).collect();
+            for matches in matches {
+                match matches {
+                    PackedFileMatches::DB(matches) => matches_db.push(matches),
+                    PackedFileMatches::Loc(matches) => matches_loc.push(matches),
+                    PackedFileMatches::Text(matches) => matches_text.push(matches),
+                }
             }
+        }
 
-            if self.search_on_schema {
-                self.search_on_schema(schema, &matching_mode);
-            }
+        (matches_db, matches_loc, matches_text)
+    }
+
+    /// This function searches the provided PackedFile, if it's of a type we're searching on. Otherwise, it returns None without decoding it.
+    fn search_on_packed_file(&self, packed_file: &mut PackedFile, schema: &Schema, matching_mode: &MatchingMode) -> Option<PackedFileMatches> {
+        let packed_file_type = PackedFileType::get_packed_file_type(packed_file.get_path());
+        let is_searchable = match packed_file_type {
+            PackedFileType::DB => self.search_on_dbs,
+            PackedFileType::Loc => self.search_on_locs,
+            _ => self.search_on_texts && packed_file_type.eq_non_strict(PackedFileType::Text(TextType::Plain)),
+        };
+
+        if !is_searchable { return None; }
+        let path = packed_file.get_path().to_vec();
+        match packed_file.decode_return_ref_no_locks(schema).ok()? {
+            DecodedPackedFile::DB(data) => Some(PackedFileMatches::DB(self.search_on_db(&path, data, matching_mode))),
+            DecodedPackedFile::Loc(data) => Some(PackedFileMatches::Loc(self.search_on_loc(&path, data, matching_mode))),
+            DecodedPackedFile::Text(data) => Some(PackedFileMatches::Text(self.search_on_text(&path, data, matching_mode))),
+            _ => None,
         }
     }
 
@@ -198,14 +342,6 @@ impl GlobalSearch {
         // Don't do anything if we have no pattern to search.
         if &self.pattern == "" { return }
 
-        // If we want to use regex and the pattern is invalid, don't search.
-        let matching_mode = if self.use_regex {
-            if let Ok(regex) = RegexBuilder::new(&self.pattern).case_insensitive(self.case_sensitive).build() {
-                MatchingMode::Regex(regex)
-            }
-            else { MatchingMode::Pattern }
-        } else { MatchingMode::Pattern };
-
         // Turn all our updated packs into `PackedFile` paths, and get them.
         let mut paths = vec![];
         for path_type in updated_paths {
@@ -216,38 +352,27 @@ impl GlobalSearch {
             }
         }
 
-        // We remove the added/edited/deleted files from all the search.
+        // We remove the added/edited/deleted files from all the search. Matches from other PackFiles are not affected by this.
         for path in &paths {
-            self.matches_db.retain(|x| &x.path != path);
-            self.matches_loc.retain(|x| &x.path != path);
-            self.matches_text.retain(|x| &x.path != path);
+            self.matches_db.retain(|x| x.source.is_some() || &x.path != path);
+            self.matches_loc.retain(|x| x.source.is_some() || &x.path != path);
+            self.matches_text.retain(|x| x.source.is_some() || &x.path != path);
         }
 
-        // If we got no schema, don't even decode.
-        if let Some(ref schema) = *SCHEMA.read().unwrap() {
-            for path in &paths {
-                if let Some(packed_file) = pack_file.get_ref_mut_packed_file_by_path(&path) {
-                    match packed_file.decode_return_ref_no_locks(&schema).unwrap_or_else(|_| &DecodedPackedFile::Unknown) {
-                        DecodedPackedFile::DB(data) => {
-                            if self.search_on_dbs {
-                                self.search_on_db(&path, data, &matching_mode);
-                            }
-                        }
-                        DecodedPackedFile::Loc(data) => {
-                            if self.search_on_locs {
-                                self.search_on_loc(&path, data, &matching_mode);
-                            }
-                        }
-                        DecodedPackedFile::Text(data) => {
-                            if self.search_on_texts {
-                                self.search_on_text(&path, data, &matching_mode);
-                            }
-                        }
-                        _ => continue,
-                    }
-                }
-            }
-        }
+        // Files out of the path filter were not part of the search, so they're not part of the update either.
+        let path_filter = self.get_path_filter();
+        paths.retain(|path| Self::is_path_included(&path_filter, path));
+
+        // The updated PackedFiles are searched the same way as in a full search, just limited to them.
+        let mut packed_files = pack_file.get_ref_mut_packed_files_by_paths(paths.iter().map(|x| x.as_ref()).collect::<Vec<&[String]>>())
+            .into_iter()
+            .map(|packed_file| (None, packed_file))
+            .collect::<Vec<(Option<&str>, &mut PackedFile)>>();
+
+        let (mut matches_db, mut matches_loc, mut matches_text) = self.search_on_packed_files(&mut packed_files, true, &|_| {});
+        self.matches_db.append(&mut matches_db);
+        self.matches_loc.append(&mut matches_loc);
+        self.matches_text.append(&mut matches_text);
     }
 
     /// This function clears the Global Search resutl's data, and reset the UI for it.
@@ -261,8 +386,9 @@ impl GlobalSearch {
         if self.search_on_dbs { types.push(PackedFileType::DB); }
         if self.search_on_locs { types.push(PackedFileType::Loc); }
         if self.search_on_texts { types.push(PackedFileType::Text(TextType::Plain)); }
+        let path_filter = self.get_path_filter();
         let packed_files = pack_file.get_ref_packed_files_by_types(&types, false);
-        packed_files.iter().map(|x| From::from(*x)).collect()
+        packed_files.iter().filter(|x| Self::is_path_included(&path_filter, x.get_path())).map(|x| From::from(*x)).collect()
     }
 
     /// This function returns the PackedFileInfo for all the PackedFiles with the provided paths.
@@ -272,25 +398,49 @@ impl GlobalSearch {
         packed_files.iter().map(|x| From::from(*x)).collect()
     }
 
+    /// This function returns the matching mode of the search. If we want to use regex and the pattern is invalid, it falls back to a pattern search.
+    fn get_matching_mode(&self) -> MatchingMode {
+        if self.use_regex {
+            if let Ok(regex) = RegexBuilder::new(&self.pattern).case_insensitive(self.case_sensitive).build() {
+                return MatchingMode::Regex(regex);
+            }
+        }
+        MatchingMode::Pattern
+    }
+
+    /// This function returns the regex to check the paths of the PackedFiles against the path filter, if we have one.
+    ///
+    /// Globs are turned into an anchored regex. If the filter should be a regex but it's invalid, we fall back to a glob.
+    fn get_path_filter(&self) -> Option<Regex> {
+        if self.path_filter.is_empty() { return None; }
+        if self.use_regex {
+            if let Ok(regex) = RegexBuilder::new(&self.path_filter).case_insensitive(true).build() {
+                return Some(regex);
+            }
+        }
+
+        let glob = escape(&self.path_filter).replace(r"\*", ".*").replace(r"\?", ".");
+        RegexBuilder::new(&format!("^{}$", glob)).case_insensitive(true).build().ok()
+    }
+
+    /// This function checks if the provided path is included in the search by the provided path filter.
+    fn is_path_included(path_filter: &Option<Regex>, path: &[String]) -> bool {
+        path_filter.as_ref().map_or(true, |regex| regex.is_match(&path.join("/")))
+    }
+
     /// This function performs a replace operation over the provided matches.
     ///
     /// NOTE: Schema matches are always ignored.
     pub fn replace_matches(&mut self, pack_file: &mut PackFile, matches: &[MatchHolder]) -> Vec<Vec<String>>{
         let mut errors = vec![];
 
-        // If we want to use regex and the pattern is invalid, don't search.
-        let matching_mode = if self.use_regex {
-            if let Ok(regex) = RegexBuilder::new(&self.pattern).case_insensitive(self.case_sensitive).build() {
-                MatchingMode::Regex(regex)
-            }
-            else { MatchingMode::Pattern }
-        } else { MatchingMode::Pattern };
+        let matching_mode = self.get_matching_mode();
         let schema = &*SCHEMA.read().unwrap();
         if let Some(ref schema) = schema {
             let mut changed_files = vec![];
             for match_file in matches {
                 match match_file {
-                    MatchHolder::Table(match_table) => {
+                    MatchHolder::Table(match_table) if match_table.source.is_none() => {
                         if let Some(packed_file) = pack_file.get_ref_mut_packed_file_by_path(&match_table.path) {
                             if let Ok(packed_file) = packed_file.decode_return_ref_mut_no_locks(&schema) {
                                 match packed_file {
@@ -339,6 +489,9 @@ impl GlobalSearch {
 
                     }
                     MatchHolder::Schema(_) => continue,
+
+                    // Matches from other PackFiles are read-only.
+                    MatchHolder::Table(_) => continue,
                 }
             }
 
@@ -352,17 +505,11 @@ impl GlobalSearch {
     pub fn replace_all(&mut self, pack_file: &mut PackFile) -> Vec<Vec<String>> {
         let mut errors = vec![];
 
-        // If we want to use regex and the pattern is invalid, don't search.
-        let matching_mode = if self.use_regex {
-            if let Ok(regex) = RegexBuilder::new(&self.pattern).case_insensitive(self.case_sensitive).build() {
-                MatchingMode::Regex(regex)
-            }
-            else { MatchingMode::Pattern }
-        } else { MatchingMode::Pattern };
+        let matching_mode = self.get_matching_mode();
         let schema = &*SCHEMA.read().unwrap();
         if let Some(ref schema) = schema {
             let mut changed_files = vec![];
-            for match_table in &self.matches_db {
+            for match_table in self.matches_db.iter().filter(|x| x.source.is_none()) {
                 if let Some(packed_file) = pack_file.get_ref_mut_packed_file_by_path(&match_table.path) {
                     if let Ok(packed_file) = packed_file.decode_return_ref_mut_no_locks(&schema) {
                         if let DecodedPackedFile::DB(ref mut table) = packed_file {
@@ -386,7 +533,7 @@ impl GlobalSearch {
                 }
             }
 
-            for match_table in &self.matches_loc {
+            for match_table in self.matches_loc.iter().filter(|x| x.source.is_none()) {
                 if let Some(packed_file) = pack_file.get_ref_mut_packed_file_by_path(&match_table.path) {
                     if let Ok(packed_file) = packed_file.decode_return_ref_mut_no_locks(&schema) {
                         if let DecodedPackedFile::Loc(ref mut table) = packed_file {
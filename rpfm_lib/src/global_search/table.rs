@@ -25,6 +25,9 @@ pub struct TableMatches {
     /// The path of the table.
     pub path: Vec<String>,
 
+    /// Name of the PackFile the matches are from, if they're not from the open PackFile. These matches are read-only.
+    pub source: Option<String>,
+
     /// The list of matches whithin a table.
     pub matches: Vec<TableMatch>,
 }
@@ -57,6 +60,7 @@ impl TableMatches {
     pub fn new(path: &[String]) -> Self {
         Self {
             path: path.to_vec(),
+            source: None,
             matches: vec![],
         }
     }
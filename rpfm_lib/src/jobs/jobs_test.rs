@@ -0,0 +1,150 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the Job Files.
+
+Each test works in its own folder within the temp folder, so they can run in parallel.
+!*/
+
+use std::env::temp_dir;
+use std::fs::{DirBuilder, File, remove_dir_all};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::packfile::{PackFile, PFHVersion};
+
+use super::{Job, JobStep};
+
+/// This function creates an empty folder with the provided name within the temp folder, and returns its path.
+fn get_test_folder(name: &str) -> PathBuf {
+    let folder = temp_dir().join(name);
+    if folder.is_dir() {
+        remove_dir_all(&folder).unwrap();
+    }
+    DirBuilder::new().recursive(true).create(&folder).unwrap();
+    folder
+}
+
+/// This function writes a Job File with the provided name and contents to the provided folder, and returns its path.
+fn write_job_file(folder: &Path, name: &str, data: &str) -> PathBuf {
+    let path = folder.join(name);
+    File::create(&path).unwrap().write_all(data.as_bytes()).unwrap();
+    path
+}
+
+/// Test to make sure JSON and YAML Job Files are read the same way.
+#[test]
+fn test_read_json_yaml() {
+    let folder = get_test_folder("rpfm_test_jobs_read");
+    let json = write_job_file(&folder, "job.json", r#"{
+        "name": "Build",
+        "toolbar": true,
+        "steps": [
+            { "operation": "open_packfile", "path": "my_mod.pack" },
+            { "operation": "optimize" },
+            { "operation": "save_as", "path": "build/my_mod.pack" }
+        ]
+    }"#);
+    let yaml = write_job_file(&folder, "job.YML", "
+name: Build
+toolbar: true
+steps:
+  - operation: open_packfile
+    path: my_mod.pack
+  - operation: optimize
+  - operation: save_as
+    path: build/my_mod.pack
+");
+
+    let job = Job::read(&json).unwrap();
+    assert_eq!(job, Job::read(&yaml).unwrap());
+    assert_eq!(job.get_ref_name(), "Build");
+    assert_eq!(job.get_ref_shortcut(), "");
+    assert!(job.is_in_toolbar());
+    assert!(job.is_saved_at_the_end());
+    assert_eq!(job.get_ref_steps(), &[
+        JobStep::OpenPackfile { path: PathBuf::from("my_mod.pack") },
+        JobStep::Optimize,
+        JobStep::SaveAs { path: PathBuf::from("build/my_mod.pack") },
+    ]);
+
+    remove_dir_all(&folder).unwrap();
+}
+
+/// Test to make sure files with an unsupported extension or invalid contents are rejected.
+#[test]
+fn test_read_invalid() {
+    let folder = get_test_folder("rpfm_test_jobs_read_invalid");
+    let toml = write_job_file(&folder, "job.toml", "steps = []");
+    let no_extension = write_job_file(&folder, "job", "steps: []");
+    let unknown_step = write_job_file(&folder, "unknown_step.yaml", "steps:\n  - operation: delete_everything\n");
+    let no_steps = write_job_file(&folder, "no_steps.json", "{}");
+
+    assert!(Job::read(&toml).is_err());
+    assert!(Job::read(&no_extension).is_err());
+    assert!(Job::read(&unknown_step).is_err());
+    assert!(Job::read(&no_steps).is_err());
+    assert!(Job::read(&folder.join("missing.yaml")).is_err());
+
+    remove_dir_all(&folder).unwrap();
+}
+
+/// Test to make sure relative paths are resolved from the folder of the Job File, and absolute ones are kept as they are.
+#[test]
+fn test_run_relative_paths() {
+    let folder = get_test_folder("rpfm_test_jobs_run");
+    let absolute_path = folder.join("absolute.pack");
+    let job_path = write_job_file(&folder, "job.yaml", &format!("
+steps:
+  - operation: save_as
+    path: relative.pack
+  - operation: save_as
+    path: '{}'
+  - operation: open_packfile
+    path: relative.pack
+", absolute_path.to_string_lossy()));
+
+    let job = Job::read(&job_path).unwrap();
+    assert!(!job.is_saved_at_the_end());
+
+    let mut pack_file = PackFile::new_with_name("job.pack", PFHVersion::PFH5);
+    let mut progress = vec![];
+    let report = job.run(&mut pack_file, |step, steps, _| progress.push((step, steps))).unwrap();
+
+    assert_eq!(progress, vec![(1, 3), (2, 3), (3, 3)]);
+    assert_eq!(report.len(), 3);
+    assert!(folder.join("relative.pack").is_file());
+    assert!(absolute_path.is_file());
+    assert_eq!(pack_file.get_file_path(), &folder.join("relative.pack"));
+
+    remove_dir_all(&folder).unwrap();
+}
+
+/// Test to make sure a Job stops at the first step that fails.
+#[test]
+fn test_run_failed_step() {
+    let folder = get_test_folder("rpfm_test_jobs_run_failed_step");
+    let job_path = write_job_file(&folder, "job.json", r#"{
+        "steps": [
+            { "operation": "open_packfile", "path": "missing.pack" },
+            { "operation": "save_as", "path": "never_saved.pack" }
+        ]
+    }"#);
+
+    let job = Job::read(&job_path).unwrap();
+    let mut pack_file = PackFile::new_with_name("job.pack", PFHVersion::PFH5);
+    let mut steps_run = 0;
+    assert!(job.run(&mut pack_file, |_, _, _| steps_run += 1).is_err());
+    assert_eq!(steps_run, 1);
+    assert!(!folder.join("never_saved.pack").is_file());
+
+    remove_dir_all(&folder).unwrap();
+}
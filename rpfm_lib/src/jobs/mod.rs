@@ -0,0 +1,256 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to deal with Job Files.
+
+Job Files are JSON/YAML files describing a sequence of operations (open a PackFile, import TSVs, optimize it, save it,...)
+to be executed one after another over the open PackFile. They're meant as a middle ground between doing things by hand
+and a full scripting API. A Job File looks like this:
+
+```yaml
+steps:
+  - operation: open_packfile
+    path: my_mod.pack
+  - operation: import_tsv
+    paths:
+      - tsv/units_tables.tsv
+  - operation: optimize
+  - operation: save_as
+    path: build/my_mod.pack
+```
+
+Relative paths are resolved from the folder of the Job File.
+
+Job Files in the `scripts` folder of the config folder are also User Scripts: they show up in the `Scripts` menu of the UI,
+so they can be run with a click. For them, these optional fields control how they show up:
+
+```yaml
+name: Build Release
+shortcut: Ctrl+Alt+B
+toolbar: true
+steps:
+  - operation: optimize
+  - operation: save
+```
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+
+use std::{fmt, fmt::Display};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::common::get_files_from_subdir;
+use crate::config::get_config_path;
+use crate::GAME_SELECTED;
+use crate::SETTINGS;
+use crate::SUPPORTED_GAMES;
+use crate::optimizer::OptimizerIndex;
+use crate::packfile::PackFile;
+
+#[cfg(test)]
+mod jobs_test;
+
+/// Name of the folder within the config folder containing the User Scripts.
+pub const USER_SCRIPTS_FOLDER: &str = "scripts";
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents a Job File in memory.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct Job {
+
+    /// Name of the Job in the `Scripts` menu. If empty, the name of the file is used.
+    #[serde(default)]
+    name: String,
+
+    /// Shortcut to run the Job from the `Scripts` menu, in Qt's format (`Ctrl+Alt+B`). Empty means no shortcut.
+    #[serde(default)]
+    shortcut: String,
+
+    /// If the Job should have a button in the `Scripts` toolbar.
+    #[serde(default)]
+    toolbar: bool,
+
+    /// The list of steps of the Job, in the order they should be executed.
+    steps: Vec<JobStep>,
+
+    /// Folder used to resolve the relative paths of the steps. Usually, the folder of the Job File.
+    #[serde(skip)]
+    base_path: PathBuf,
+}
+
+/// This enum represents each one of the operations a Job can perform.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum JobStep {
+
+    /// Replace the open PackFile with a new one for the Game Selected, with the provided name.
+    NewPackfile { name: String },
+
+    /// Replace the open PackFile with the one in the provided path.
+    OpenPackfile { path: PathBuf },
+
+    /// Import the provided TSV files into the open PackFile, overwriting any file with the same path.
+    ImportTsv { paths: Vec<PathBuf> },
+
+    /// Optimize the open PackFile.
+    Optimize,
+
+    /// Save the open PackFile to its current path.
+    Save,
+
+    /// Save the open PackFile to the provided path.
+    SaveAs { path: PathBuf },
+}
+
+//---------------------------------------------------------------------------//
+//                           Implementation of Job
+//---------------------------------------------------------------------------//
+
+/// Implementation of `Job`.
+impl Job {
+
+    /// This function reads a Job File from disk. Only JSON and YAML Job Files are supported.
+    pub fn read(path: &Path) -> Result<Self> {
+        let mut data = String::new();
+        BufReader::new(File::open(path)?).read_to_string(&mut data)?;
+
+        let mut job: Self = match path.extension().and_then(|x| x.to_str()).map(|x| x.to_lowercase()).as_deref() {
+            Some("json") => serde_json::from_str(&data).map_err(|error| ErrorKind::JobFileParse(error.to_string()))?,
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&data).map_err(|error| ErrorKind::JobFileParse(error.to_string()))?,
+            _ => return Err(ErrorKind::JobFileNotSupported.into()),
+        };
+
+        job.base_path = path.parent().map(|x| x.to_path_buf()).unwrap_or_default();
+        Ok(job)
+    }
+
+    /// This function returns the paths of the User Scripts, sorted by file name.
+    pub fn get_user_scripts_paths() -> Result<Vec<PathBuf>> {
+        let mut paths = get_files_from_subdir(&get_config_path()?.join(USER_SCRIPTS_FOLDER))?.into_iter()
+            .filter(|path| match path.extension().and_then(|x| x.to_str()).map(|x| x.to_lowercase()).as_deref() {
+                Some("json") | Some("yaml") | Some("yml") => true,
+                _ => false,
+            })
+            .collect::<Vec<PathBuf>>();
+
+        paths.sort_unstable_by_key(|path| path.file_name().map(|x| x.to_string_lossy().to_lowercase()));
+        Ok(paths)
+    }
+
+    /// This function returns the name of the Job in the `Scripts` menu, if it has one.
+    pub fn get_ref_name(&self) -> &str {
+        &self.name
+    }
+
+    /// This function returns the shortcut of the Job in the `Scripts` menu, if it has one.
+    pub fn get_ref_shortcut(&self) -> &str {
+        &self.shortcut
+    }
+
+    /// This function returns if the Job should have a button in the `Scripts` toolbar.
+    pub fn is_in_toolbar(&self) -> bool {
+        self.toolbar
+    }
+
+    /// This function returns a reference to the steps of the Job.
+    pub fn get_ref_steps(&self) -> &[JobStep] {
+        &self.steps
+    }
+
+    /// This function returns if the PackFile is left saved (no unsaved changes) after the Job finishes.
+    pub fn is_saved_at_the_end(&self) -> bool {
+        match self.steps.last() {
+            Some(JobStep::Save) | Some(JobStep::SaveAs { .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// This function executes all the steps of the Job over the provided PackFile, in order, stopping at the first one that fails.
+    ///
+    /// Before executing each step, the `progress` callback is called with the number of the step (starting at 1), the amount of steps and the step itself.
+    /// If all the steps succeed, it returns a report with what each step did.
+    pub fn run<F: FnMut(usize, usize, &JobStep)>(&self, pack_file: &mut PackFile, mut progress: F) -> Result<Vec<String>> {
+        let mut report = vec![];
+        for (index, step) in self.steps.iter().enumerate() {
+            progress(index + 1, self.steps.len(), step);
+            match self.run_step(pack_file, step) {
+                Ok(result) => report.push(format!("{}: {}", step, result)),
+                Err(error) => return Err(ErrorKind::JobStepFailed(index + 1, step.to_string(), error.to_string()).into()),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// This function executes a single step of the Job, returning a short description of its result.
+    fn run_step(&self, pack_file: &mut PackFile, step: &JobStep) -> Result<String> {
+        match step {
+            JobStep::NewPackfile { name } => {
+                let pfh_version = SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).ok_or_else(|| ErrorKind::GameNotSupported)?.pfh_version[0];
+                *pack_file = PackFile::new_with_name(name, pfh_version);
+                Ok("PackFile created.".to_owned())
+            }
+
+            JobStep::OpenPackfile { path } => {
+                let use_lazy_loading = SETTINGS.read().unwrap().settings_bool["use_lazy_loading"];
+                *pack_file = PackFile::open_packfiles(&[self.resolve_path(path)], use_lazy_loading, false, false)?;
+                Ok(format!("{} PackedFiles loaded.", pack_file.get_packedfiles_list().len()))
+            }
+
+            JobStep::ImportTsv { paths } => {
+                let paths = paths.iter().map(|path| self.resolve_path(path)).collect::<Vec<PathBuf>>();
+                let (_, added_paths) = pack_file.mass_import_tsv(&paths, None, true)?;
+                Ok(format!("{} PackedFiles imported.", added_paths.len()))
+            }
+
+            JobStep::Optimize => {
+                let report = pack_file.optimize(&OptimizerIndex::from_dependency_database());
+                Ok(format!("{} rows removed, {} PackedFiles deleted, {} bytes saved.", report.rows_removed + report.loc_keys_removed, report.deleted_paths.len(), report.bytes_saved))
+            }
+
+            JobStep::Save => {
+                pack_file.save(None)?;
+                Ok(format!("PackFile saved to {}.", pack_file.get_file_path().to_string_lossy()))
+            }
+
+            JobStep::SaveAs { path } => {
+                pack_file.save(Some(self.resolve_path(path)))?;
+                Ok(format!("PackFile saved to {}.", pack_file.get_file_path().to_string_lossy()))
+            }
+        }
+    }
+
+    /// This function turns the provided path into an absolute one, if it's relative to the folder of the Job File.
+    fn resolve_path(&self, path: &Path) -> PathBuf {
+        if path.is_relative() { self.base_path.join(path) } else { path.to_path_buf() }
+    }
+}
+
+/// Display implementation of `JobStep`.
+impl Display for JobStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JobStep::NewPackfile { name } => write!(f, "New PackFile \"{}\"", name),
+            JobStep::OpenPackfile { path } => write!(f, "Open PackFile \"{}\"", path.to_string_lossy()),
+            JobStep::ImportTsv { paths } => write!(f, "Import {} TSV files", paths.len()),
+            JobStep::Optimize => write!(f, "Optimize PackFile"),
+            JobStep::Save => write!(f, "Save PackFile"),
+            JobStep::SaveAs { path } => write!(f, "Save PackFile as \"{}\"", path.to_string_lossy()),
+        }
+    }
+}
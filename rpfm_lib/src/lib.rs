@@ -28,13 +28,18 @@ use crate::games::{SupportedGames, get_supported_games_list};
 use crate::packedfile::table::db::DB;
 use crate::packfile::packedfile::PackedFile;
 use crate::schema::Schema;
+use crate::schema::patch::SchemaPatch;
 use crate::settings::Settings;
 
 pub mod assembly_kit;
 pub mod common;
 pub mod config;
+pub mod diff;
 pub mod games;
 pub mod global_search;
+pub mod jobs;
+pub mod mymod;
+pub mod optimizer;
 pub mod packedfile;
 pub mod packfile;
 pub mod schema;
@@ -63,6 +68,9 @@ lazy_static! {
 
     /// Currently loaded schema.
     pub static ref SCHEMA: Arc<RwLock<Option<Schema>>> = Arc::new(RwLock::new(None));
+
+    /// Local patch of the currently loaded schema. Same as the settings, only edited from the background thread.
+    pub static ref SCHEMA_PATCH: Arc<RwLock<SchemaPatch>> = Arc::new(RwLock::new(SchemaPatch::default()));
 }
 
 pub const DOCS_BASE_URL: &str = "https://frodo45127.github.io/rpfm/";
@@ -0,0 +1,139 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to version MyMods with Git.
+
+The repository of a MyMod lives in its private folder. When committing, the contents of the MyMod's PackFile are extracted
+to that folder, so what gets versioned is the PackFile's contents plus whatever assets the modder keeps there.
+Files deleted from the PackFile are not deleted from the private folder, so they're not deleted from the repository either.
+!*/
+
+use git2::{IndexAddOption, ObjectType, Oid, Repository, Signature};
+use serde_derive::{Serialize, Deserialize};
+
+use std::path::Path;
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::packfile::PackFile;
+
+/// Name used to sign the commits if the user doesn't have Git configured.
+const DEFAULT_SIGNATURE_NAME: &str = "RPFM";
+
+/// Email used to sign the commits if the user doesn't have Git configured.
+const DEFAULT_SIGNATURE_EMAIL: &str = "rpfm@localhost";
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This enum represents the status of a PackedFile of a MyMod, compared with the last commit of its repository.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GitStatus {
+
+    /// The PackedFile is not in the last commit.
+    Untracked,
+
+    /// The PackedFile is in the last commit, but with different data.
+    Modified,
+
+    /// The PackedFile could not be encoded, so we don't know if it changed since the last commit.
+    Unknown,
+}
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function creates a Git repository in the provided private folder of a MyMod, if it doesn't have one already.
+pub fn init_repo(private_path: &Path) -> Result<()> {
+    if Repository::open(private_path).is_err() {
+        Repository::init(private_path)?;
+    }
+    Ok(())
+}
+
+/// This function returns the paths of the PackedFiles of the provided PackFile that are untracked or modified in the repository of the MyMod.
+///
+/// PackedFiles not returned are the same as in the last commit. PackedFiles that fail to encode are returned as `Unknown`, instead of failing the whole status.
+pub fn get_status(private_path: &Path, pack_file: &mut PackFile) -> Result<Vec<(Vec<String>, GitStatus)>> {
+    let repo = Repository::open(private_path).map_err(|_| ErrorKind::MyModGitRepoNotFound)?;
+    let tree = match repo.head() {
+        Ok(head) => Some(head.peel_to_tree()?),
+
+        // A repository without commits has no HEAD, so everything is untracked.
+        Err(_) => None,
+    };
+
+    let mut status = vec![];
+    for packed_file in pack_file.get_ref_mut_packed_files_all() {
+        let path = packed_file.get_path().to_vec();
+
+        // Save it, in case it's cached.
+        if packed_file.encode().is_err() {
+            status.push((path, GitStatus::Unknown));
+            continue;
+        }
+
+        let entry = match tree {
+            Some(ref tree) => tree.get_path(Path::new(&path.join("/"))).ok(),
+            None => None,
+        };
+
+        match entry {
+            Some(entry) => match packed_file.get_raw_data() {
+                Ok(data) => if Oid::hash_object(ObjectType::Blob, &data)? != entry.id() {
+                    status.push((path, GitStatus::Modified));
+                }
+                Err(_) => status.push((path, GitStatus::Unknown)),
+            }
+            None => status.push((path, GitStatus::Untracked)),
+        }
+    }
+
+    Ok(status)
+}
+
+/// This function extracts the provided PackFile to the private folder of its MyMod, and commits everything in it with the provided message.
+pub fn commit(private_path: &Path, pack_file: &mut PackFile, message: &str) -> Result<()> {
+    let repo = Repository::open(private_path).map_err(|_| ErrorKind::MyModGitRepoNotFound)?;
+
+    for packed_file in pack_file.get_ref_mut_packed_files_all() {
+        packed_file.extract_packed_file(private_path)?;
+    }
+
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+    index.update_all(["*"].iter(), None)?;
+    index.write()?;
+
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(_) => None,
+    };
+
+    if let Some(ref parent) = parent {
+        if parent.tree_id() == tree.id() {
+            return Err(ErrorKind::MyModGitNothingToCommit.into());
+        }
+    }
+
+    // Use the user's Git identity if it has one, and fallback to a generic one if not.
+    let signature = match repo.signature() {
+        Ok(signature) => signature,
+        Err(_) => Signature::now(DEFAULT_SIGNATURE_NAME, DEFAULT_SIGNATURE_EMAIL)?,
+    };
+
+    let parents = parent.iter().collect::<Vec<_>>();
+    repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)?;
+    Ok(())
+}
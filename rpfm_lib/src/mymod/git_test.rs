@@ -0,0 +1,77 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the Git integration of the MyMods.
+
+Each test works with its own repository within the temp folder, so they can run in parallel.
+!*/
+
+use std::env::temp_dir;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+
+use crate::packfile::PackFile;
+use crate::packfile::packedfile::{PackedFile, RawPackedFile};
+
+use super::git::{commit, get_status, GitStatus, init_repo};
+
+/// This function returns the path of an empty private folder with the provided name within the temp folder.
+fn get_private_path(name: &str) -> PathBuf {
+    let private_path = temp_dir().join(name);
+    if private_path.is_dir() {
+        remove_dir_all(&private_path).unwrap();
+    }
+    private_path
+}
+
+/// This function adds a PackedFile with the provided path and data to the provided PackFile, replacing any PackedFile already there.
+fn add_packed_file(pack_file: &mut PackFile, path: &[&str], data: &[u8]) {
+    let path = path.iter().map(|x| (*x).to_owned()).collect::<Vec<String>>();
+    let raw_data = RawPackedFile::read_from_vec(path, "test.pack".to_owned(), 0, false, data.to_vec());
+    pack_file.add_packed_file(&PackedFile::new_from_raw(&raw_data), true).unwrap();
+}
+
+/// Test to make sure the status reflects the changes since the last commit, and that commits without changes are rejected.
+#[test]
+fn test_status_and_commit() {
+    let private_path = get_private_path("rpfm_test_mymod_git");
+    let readme = vec!["text".to_owned(), "readme.txt".to_owned()];
+    let notes = vec!["text".to_owned(), "notes.txt".to_owned()];
+
+    // Getting the status or committing without a repository must fail.
+    let mut pack_file = PackFile::new();
+    add_packed_file(&mut pack_file, &["text", "readme.txt"], b"readme");
+    assert!(get_status(&private_path, &mut pack_file).is_err());
+    assert!(commit(&private_path, &mut pack_file, "First commit.").is_err());
+
+    // Initializing the repository twice must keep the first one.
+    init_repo(&private_path).unwrap();
+    init_repo(&private_path).unwrap();
+
+    // Without commits, everything is untracked.
+    assert_eq!(get_status(&private_path, &mut pack_file).unwrap(), vec![(readme.to_vec(), GitStatus::Untracked)]);
+
+    commit(&private_path, &mut pack_file, "First commit.").unwrap();
+    assert!(private_path.join("text").join("readme.txt").is_file());
+    assert!(get_status(&private_path, &mut pack_file).unwrap().is_empty());
+    assert!(commit(&private_path, &mut pack_file, "Nothing changed.").is_err());
+
+    add_packed_file(&mut pack_file, &["text", "readme.txt"], b"new readme");
+    add_packed_file(&mut pack_file, &["text", "notes.txt"], b"notes");
+    let mut status = get_status(&private_path, &mut pack_file).unwrap();
+    status.sort_by(|x, y| x.0.cmp(&y.0));
+    assert_eq!(status, vec![(notes, GitStatus::Untracked), (readme, GitStatus::Modified)]);
+
+    commit(&private_path, &mut pack_file, "Second commit.").unwrap();
+    assert!(get_status(&private_path, &mut pack_file).unwrap().is_empty());
+
+    remove_dir_all(&private_path).unwrap();
+}
@@ -0,0 +1,188 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the config of MyMods, and the code to publish them to the Steam Workshop.
+
+The config of a MyMod is a JSON file saved next to its PackFile, with the same name and the `.mymod.json` extension.
+Publishing is done through `steamcmd`, which must have been used at least once to log in with the account we upload with,
+so it has its credentials cached. Otherwise it'll ask for them and, as we don't give it a terminal, the upload will fail.
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+
+use std::env::temp_dir;
+use std::fs::{copy, remove_dir_all, DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use rpfm_error::{ErrorKind, Result};
+
+pub mod git;
+pub mod template;
+
+#[cfg(test)]
+mod git_test;
+
+#[cfg(test)]
+mod mymod_test;
+
+/// Extension added to the path of a MyMod's PackFile (without its `.pack`) to get the path of its config.
+pub const EXTENSION: &str = ".mymod.json";
+
+/// Name of the temporal folder used to prepare the uploads to the Workshop.
+const WORKSHOP_UPLOAD_FOLDER: &str = "rpfm_workshop_upload";
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents the config of a MyMod.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MyModConfig {
+
+    /// Id of the Workshop item of this MyMod, if it has been published.
+    pub published_file_id: Option<u64>,
+
+    /// Title of the Workshop item.
+    pub workshop_title: String,
+
+    /// Description of the Workshop item.
+    pub workshop_description: String,
+
+    /// Path of the preview image of the Workshop item.
+    pub workshop_preview_image: Option<PathBuf>,
+
+    /// Visibility of the Workshop item: 0 is public, 1 is friends only, 2 is private and 3 is unlisted.
+    pub workshop_visibility: u32,
+
+    /// If the assets folder of this MyMod should be synced into its PackFile while it's open.
+    #[serde(default)]
+    pub live_sync: bool,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `MyModConfig`.
+impl MyModConfig {
+
+    /// This function returns the path of the config of the MyMod with the provided PackFile path.
+    pub fn get_config_path(pack_file_path: &Path) -> PathBuf {
+        let mut path = pack_file_path.with_extension("").into_os_string();
+        path.push(EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// This function reads the config of the MyMod with the provided PackFile path. If it doesn't have one yet, it returns a default one.
+    pub fn read(pack_file_path: &Path) -> Result<Self> {
+        let path = Self::get_config_path(pack_file_path);
+        if path.is_file() {
+            let file = BufReader::new(File::open(path)?);
+            serde_json::from_reader(file).map_err(From::from)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// This function saves the config of the MyMod with the provided PackFile path.
+    pub fn save(&self, pack_file_path: &Path) -> Result<()> {
+        let mut file = BufWriter::new(File::create(Self::get_config_path(pack_file_path))?);
+        file.write_all(serde_json::to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function publishes the provided PackFile to the Workshop, or updates it if it has already been published.
+    ///
+    /// On success, the id of the Workshop item is stored in this config. The config is not saved to disk here.
+    pub fn upload_to_workshop(
+        &mut self,
+        steamcmd_path: &Path,
+        steam_user: &str,
+        steam_id: u64,
+        pack_file_path: &Path,
+        changelog: &str,
+    ) -> Result<u64> {
+
+        // The Workshop uploads entire folders, so we prepare one with only our PackFile in it.
+        let upload_path = temp_dir().join(WORKSHOP_UPLOAD_FOLDER);
+        let content_path = upload_path.join("content");
+        if upload_path.is_dir() {
+            remove_dir_all(&upload_path)?;
+        }
+        DirBuilder::new().recursive(true).create(&content_path)?;
+
+        let file_name = pack_file_path.file_name().ok_or(ErrorKind::PackFileIsNotAFile)?;
+        copy(pack_file_path, content_path.join(file_name))?;
+
+        let vdf = self.get_workshop_vdf(steam_id, &content_path, changelog);
+        let vdf_path = upload_path.join("workshop_item.vdf");
+        let mut file = BufWriter::new(File::create(&vdf_path)?);
+        file.write_all(vdf.as_bytes())?;
+        drop(file);
+
+        let output = Command::new(steamcmd_path)
+            .arg("+login")
+            .arg(steam_user)
+            .arg("+workshop_build_item")
+            .arg(&vdf_path)
+            .arg("+quit")
+            .stdin(Stdio::null())
+            .output()?;
+
+        // Steamcmd writes the id of the item back into the vdf file, so we get it from there.
+        let mut vdf = String::new();
+        File::open(&vdf_path)?.read_to_string(&mut vdf)?;
+        let published_file_id = get_published_file_id(&vdf);
+
+        if !output.status.success() || published_file_id == 0 {
+            let log = String::from_utf8_lossy(&output.stdout);
+            let log = log.lines().rev().take(10).collect::<Vec<&str>>().into_iter().rev().collect::<Vec<&str>>().join("\n");
+            return Err(ErrorKind::WorkshopUploadFailed(log).into());
+        }
+
+        self.published_file_id = Some(published_file_id);
+        Ok(published_file_id)
+    }
+
+    /// This function returns the vdf file `steamcmd` needs to upload the content of the provided folder as this MyMod's Workshop item.
+    fn get_workshop_vdf(&self, steam_id: u64, content_path: &Path, changelog: &str) -> String {
+        let mut vdf = String::new();
+        vdf.push_str("\"workshopitem\"\n{\n");
+        vdf.push_str(&format!("\t\"appid\"\t\t\"{}\"\n", steam_id));
+        vdf.push_str(&format!("\t\"publishedfileid\"\t\t\"{}\"\n", self.published_file_id.unwrap_or(0)));
+        vdf.push_str(&format!("\t\"contentfolder\"\t\t\"{}\"\n", escape_vdf_value(&content_path.to_string_lossy())));
+        if let Some(ref preview_image) = self.workshop_preview_image {
+            vdf.push_str(&format!("\t\"previewfile\"\t\t\"{}\"\n", escape_vdf_value(&preview_image.to_string_lossy())));
+        }
+        vdf.push_str(&format!("\t\"visibility\"\t\t\"{}\"\n", self.workshop_visibility));
+        vdf.push_str(&format!("\t\"title\"\t\t\"{}\"\n", escape_vdf_value(&self.workshop_title)));
+        vdf.push_str(&format!("\t\"description\"\t\t\"{}\"\n", escape_vdf_value(&self.workshop_description)));
+        vdf.push_str(&format!("\t\"changenote\"\t\t\"{}\"\n", escape_vdf_value(changelog)));
+        vdf.push_str("}\n");
+        vdf
+    }
+}
+
+/// This function returns the id of the Workshop item in the provided vdf file, or 0 if it has none.
+fn get_published_file_id(vdf: &str) -> u64 {
+    vdf.lines()
+        .find(|line| line.trim_start().starts_with("\"publishedfileid\""))
+        .and_then(|line| line.split('"').nth(3))
+        .and_then(|id| id.parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+/// This function escapes the characters that cannot be written as they are in a value of a vdf file.
+fn escape_vdf_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
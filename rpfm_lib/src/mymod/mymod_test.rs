@@ -0,0 +1,63 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the MyMod configs and their Workshop uploads.
+!*/
+
+use std::path::{Path, PathBuf};
+
+use super::{escape_vdf_value, get_published_file_id, MyModConfig};
+
+/// Test to make sure the vdf file for the Workshop has all the data of the MyMod, with its values escaped.
+#[test]
+fn test_get_workshop_vdf() {
+    let mut config = MyModConfig::default();
+    config.workshop_title = "My \"Best\" Mod".to_owned();
+    config.workshop_description = "First line.\n\"publishedfileid\"\t\t\"1\"".to_owned();
+    config.workshop_visibility = 2;
+
+    let vdf = config.get_workshop_vdf(594_570, Path::new("C:\\temp\\content"), "Fixed stuff.");
+    assert_eq!(vdf, "\"workshopitem\"
+{
+\t\"appid\"\t\t\"594570\"
+\t\"publishedfileid\"\t\t\"0\"
+\t\"contentfolder\"\t\t\"C:\\\\temp\\\\content\"
+\t\"visibility\"\t\t\"2\"
+\t\"title\"\t\t\"My \\\"Best\\\" Mod\"
+\t\"description\"\t\t\"First line.
+\\\"publishedfileid\\\"\t\t\\\"1\\\"\"
+\t\"changenote\"\t\t\"Fixed stuff.\"
+}
+");
+
+    // Values from the description cannot be mistaken for the id of the item.
+    assert_eq!(get_published_file_id(&vdf), 0);
+
+    config.published_file_id = Some(12345);
+    config.workshop_preview_image = Some(PathBuf::from("preview.png"));
+    let vdf = config.get_workshop_vdf(594_570, Path::new("content"), "");
+    assert!(vdf.contains("\t\"previewfile\"\t\t\"preview.png\"\n"));
+    assert_eq!(get_published_file_id(&vdf), 12345);
+}
+
+/// Test to make sure the id of the Workshop item is read from the vdf file written back by steamcmd.
+#[test]
+fn test_get_published_file_id() {
+    assert_eq!(get_published_file_id("\"workshopitem\"\n{\n\t\"publishedfileid\"\t\t\"2158746933\"\n}\n"), 2_158_746_933);
+    assert_eq!(get_published_file_id("\"workshopitem\"\n{\n\t\"publishedfileid\"\t\t\"invalid\"\n}\n"), 0);
+    assert_eq!(get_published_file_id(""), 0);
+}
+
+/// Test to make sure only backslashes and quotes are escaped in vdf values.
+#[test]
+fn test_escape_vdf_value() {
+    assert_eq!(escape_vdf_value("a\\b\"c\td"), "a\\\\b\\\"c\td");
+}
@@ -0,0 +1,192 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to deal with MyMod templates.
+
+MyMod templates are used to bootstrap new MyMods with the usual structure of a kind of mod: the folders of its private folder,
+empty tables, a stub loc file and some text files. They're JSON files in the `mymod_templates` folder of the config folder,
+so users can add their own ones. If there are none, the default ones are created there.
+!*/
+
+use serde_json::de::from_reader;
+use serde_derive::{Serialize, Deserialize};
+
+use std::fs::{read_dir, DirBuilder, File};
+use std::io::{BufReader, Write};
+use std::path::Path;
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::common::get_mymod_templates_path;
+use crate::packfile::{PackFile, packedfile::PackedFile};
+use crate::packedfile::DecodedPackedFile;
+use crate::packedfile::table::db::DB;
+use crate::packedfile::table::loc::Loc;
+use crate::packedfile::text::{self, Text};
+use crate::SCHEMA;
+
+/// Name of the folder where the MyMod templates are stored, within the config folder.
+pub const MYMOD_TEMPLATES_FOLDER: &str = "mymod_templates";
+
+/// Placeholder replaced by the name of the MyMod (without the `.pack`) in the paths and contents of the templates.
+const MOD_NAME_PLACEHOLDER: &str = "{mod_name}";
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents a MyMod Template File in memory.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct MyModTemplate {
+
+    /// Description of the template, to show it to the user.
+    pub description: String,
+
+    /// Folders to create in the private folder of the MyMod, relative to it.
+    pub folders: Vec<String>,
+
+    /// Tables to create empty in the PackFile, with the last definition available. Tables not in the schema of the game are skipped.
+    pub tables: Vec<String>,
+
+    /// If we should create a stub loc file for the MyMod in the PackFile.
+    pub loc: bool,
+
+    /// Text files to create in the PackFile, as (path, contents).
+    pub texts: Vec<(String, String)>,
+}
+
+//---------------------------------------------------------------------------//
+//                       Enum & Structs Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `MyModTemplate`.
+impl MyModTemplate {
+
+    /// This function returns the names of the MyMod templates available, creating the default ones if there are none.
+    pub fn get_available_templates() -> Result<Vec<String>> {
+        let templates_path = get_mymod_templates_path()?;
+        DirBuilder::new().recursive(true).create(&templates_path)?;
+
+        let mut templates = read_dir(&templates_path)?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().map_or(false, |extension| extension == "json"))
+            .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+            .collect::<Vec<String>>();
+
+        if templates.is_empty() {
+            for (name, template) in Self::get_default_templates() {
+                template.save(name)?;
+                templates.push(name.to_owned());
+            }
+        }
+
+        templates.sort();
+        Ok(templates)
+    }
+
+    /// This function loads a `MyModTemplate` to memory.
+    pub fn load(template: &str) -> Result<Self> {
+        let file_path = get_mymod_templates_path()?.join(format!("{}.json", template));
+        let file = BufReader::new(File::open(&file_path)?);
+        from_reader(file).map_err(From::from)
+    }
+
+    /// This function saves a `MyModTemplate` from memory to a file in the `mymod_templates/` folder.
+    pub fn save(&self, template: &str) -> Result<()> {
+        let file_path = get_mymod_templates_path()?.join(format!("{}.json", template));
+        let mut file = File::create(&file_path)?;
+        file.write_all(serde_json::to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function applies a `MyModTemplate` to the provided PackFile, creating its folders in the provided private folder of the MyMod.
+    pub fn apply_template(&self, mod_name: &str, private_path: &Path, pack_file: &mut PackFile) -> Result<()> {
+        for folder in &self.folders {
+            DirBuilder::new().recursive(true).create(private_path.join(folder.replace(MOD_NAME_PLACEHOLDER, mod_name)))?;
+        }
+
+        match &*SCHEMA.read().unwrap() {
+            Some(schema) => {
+                let mut packed_files = vec![];
+
+                for table in &self.tables {
+                    if let Ok(definition) = schema.get_ref_last_definition_db(table) {
+                        let path = vec!["db".to_owned(), table.to_owned(), mod_name.to_owned()];
+                        let db = DB::new(table, None, definition);
+                        packed_files.push(PackedFile::new_from_decoded(&DecodedPackedFile::DB(db), &path));
+                    }
+                }
+
+                if self.loc {
+                    let path = vec!["text".to_owned(), "db".to_owned(), format!("{}.loc", mod_name)];
+                    let loc = Loc::new(schema.get_ref_last_definition_loc()?);
+                    packed_files.push(PackedFile::new_from_decoded(&DecodedPackedFile::Loc(loc), &path));
+                }
+
+                for (path, contents) in &self.texts {
+                    let path = path.replace(MOD_NAME_PLACEHOLDER, mod_name);
+                    if path.is_empty() || path.contains("//") || path.ends_with('/') {
+                        return Err(ErrorKind::InvalidPathsInTemplate.into());
+                    }
+
+                    let mut text = Text::new();
+                    text.set_contents(&contents.replace(MOD_NAME_PLACEHOLDER, mod_name));
+                    if let Some((_, text_type)) = text::EXTENSIONS.iter().find(|(extension, _)| path.ends_with(extension)) {
+                        text.set_text_type(*text_type);
+                    }
+
+                    let path = path.split('/').map(|x| x.to_owned()).collect::<Vec<String>>();
+                    packed_files.push(PackedFile::new_from_decoded(&DecodedPackedFile::Text(text), &path));
+                }
+
+                pack_file.add_packed_files(&packed_files.iter().collect::<Vec<&PackedFile>>(), true)?;
+                Ok(())
+            }
+            None => Err(ErrorKind::SchemaNotFound.into()),
+        }
+    }
+
+    /// This function returns the templates we create if there are none in the `mymod_templates/` folder.
+    fn get_default_templates() -> Vec<(&'static str, Self)> {
+        vec![
+            ("units_mod", Self {
+                description: "Mod that adds new units.".to_owned(),
+                folders: vec!["db".to_owned(), "text/db".to_owned(), "ui/units/icons".to_owned(), "ui/units/info".to_owned()],
+                tables: vec![
+                    "land_units_tables".to_owned(),
+                    "main_units_tables".to_owned(),
+                    "unit_variants_tables".to_owned(),
+                    "units_to_groupings_military_permissions_tables".to_owned(),
+                ],
+                loc: true,
+                texts: vec![],
+            }),
+            ("reskin", Self {
+                description: "Mod that changes the look of existing units.".to_owned(),
+                folders: vec!["variantmeshes/variantmeshdefinitions".to_owned(), "variantmeshes/wh_variantmodels".to_owned()],
+                tables: vec!["variants_tables".to_owned()],
+                loc: false,
+                texts: vec![],
+            }),
+            ("script_mod", Self {
+                description: "Mod that adds a campaign script.".to_owned(),
+                folders: vec!["script/campaign/mod".to_owned()],
+                tables: vec![],
+                loc: true,
+                texts: vec![(
+                    "script/campaign/mod/{mod_name}.lua".to_owned(),
+                    "-- Campaign script of {mod_name}.\n\ncm:add_first_tick_callback(function()\n    out(\"{mod_name} loaded.\");\nend);\n".to_owned()
+                )],
+            }),
+        ]
+    }
+}
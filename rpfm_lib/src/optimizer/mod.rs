@@ -0,0 +1,205 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the index and report used by the PackFile optimizer.
+
+Optimizing a PackFile means comparing its tables against the ones in its dependencies. Decoding all the tables
+of the dependencies is slow, so we do it once, build an `OptimizerIndex` with what the optimizer needs from them,
+and keep it around until the dependencies change.
+!*/
+
+use std::collections::{HashMap, HashSet};
+
+use crate::DEPENDENCY_DATABASE;
+use crate::packedfile::DecodedPackedFile;
+use crate::packedfile::table::{Table, db::DB};
+
+#[cfg(test)]
+mod optimizer_test;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains the data from the dependencies needed to optimize a PackFile.
+#[derive(Clone, Debug, Default)]
+pub struct OptimizerIndex {
+
+    /// Paths of all the PackedFiles in the dependencies.
+    paths: HashSet<Vec<String>>,
+
+    /// Rows of the DB tables in the dependencies, by table name and version.
+    db_rows: HashMap<(String, i32), HashSet<Vec<String>>>,
+
+    /// Rows of the Loc tables in the dependencies, by version.
+    loc_rows: HashMap<i32, HashSet<Vec<String>>>,
+
+    /// Keys used in loc files by the DB tables in the dependencies.
+    loc_keys: LocKeysIndex,
+}
+
+/// This struct contains the info needed to know if a loc key belongs to a row of a DB table.
+///
+/// A loc key for a localised field is made of the table name (without `_tables`), the field name and the key of the row, separated by `_`.
+/// Only the keys of tables with a single key column are indexed, as we cannot know how the other ones generate their loc keys.
+#[derive(Clone, Debug, Default)]
+pub struct LocKeysIndex {
+
+    /// Prefixes of the loc keys of each localised field, with the name of the table they belong to.
+    prefixes: HashSet<(String, String)>,
+
+    /// Prefixes of the loc keys of the localised fields of tables without a single key column.
+    ///
+    /// As different tables can have the same prefix, loc keys starting with one of these may belong to them, so they're never unused.
+    unknown_prefixes: HashSet<String>,
+
+    /// Keys of the rows of each table, by table name.
+    keys: HashMap<String, HashSet<String>>,
+}
+
+/// This struct contains the results of optimizing a PackFile.
+#[derive(Clone, Debug, Default)]
+pub struct OptimizerReport {
+
+    /// Paths of the PackedFiles deleted because they were empty or useless.
+    pub deleted_paths: Vec<Vec<String>>,
+
+    /// Amount of table rows removed because they were identical to the ones in the dependencies.
+    pub rows_removed: usize,
+
+    /// Amount of loc rows removed because their keys didn't belong to any row of a DB table.
+    pub loc_keys_removed: usize,
+
+    /// Amount of tables with their rows re-sorted.
+    pub tables_sorted: usize,
+
+    /// Difference in bytes between the size of the optimized PackedFiles before and after the optimization.
+    pub bytes_saved: i64,
+}
+
+//---------------------------------------------------------------------------//
+//                           Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `OptimizerIndex`.
+impl OptimizerIndex {
+
+    /// This function builds the index from the currently loaded dependency database.
+    ///
+    /// This decodes every table in the dependencies, so it's slow. Keep the result around while the dependencies don't change.
+    pub fn from_dependency_database() -> Self {
+        let mut index = Self::default();
+        let mut dependencies = DEPENDENCY_DATABASE.lock().unwrap();
+        for packed_file in dependencies.iter_mut() {
+            let path = packed_file.get_path().to_vec();
+            let is_db = path.len() == 3 && path[0] == "db";
+            let is_loc = path.last().map_or(false, |name| name.ends_with(".loc"));
+            if is_db || is_loc {
+                match packed_file.decode_return_ref() {
+                    Ok(DecodedPackedFile::DB(db)) => {
+                        index.loc_keys.add_table(db);
+                        index.db_rows.entry((db.name.to_owned(), db.get_ref_definition().get_version()))
+                            .or_insert_with(HashSet::new)
+                            .extend(db.get_ref_table_data().iter().map(|row| Table::get_row_index_key(row)));
+                    }
+                    Ok(DecodedPackedFile::Loc(loc)) => {
+                        index.loc_rows.entry(loc.get_ref_definition().get_version())
+                            .or_insert_with(HashSet::new)
+                            .extend(loc.get_ref_table_data().iter().map(|row| Table::get_row_index_key(row)));
+                    }
+                    _ => {}
+                }
+            }
+
+            index.paths.insert(path);
+        }
+
+        index
+    }
+
+    /// This function returns if the index has no data from the dependencies.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// This function returns if there is a PackedFile in the dependencies with the provided path.
+    pub fn contains_path(&self, path: &[String]) -> bool {
+        self.paths.contains(path)
+    }
+
+    /// This function returns the rows of the provided DB table in the dependencies, if any.
+    pub fn get_db_rows(&self, table_name: &str, version: i32) -> Option<&HashSet<Vec<String>>> {
+        self.db_rows.get(&(table_name.to_owned(), version))
+    }
+
+    /// This function returns the rows of the Loc tables with the provided version in the dependencies, if any.
+    pub fn get_loc_rows(&self, version: i32) -> Option<&HashSet<Vec<String>>> {
+        self.loc_rows.get(&version)
+    }
+
+    /// This function returns the loc keys index of the dependencies.
+    pub fn get_ref_loc_keys(&self) -> &LocKeysIndex {
+        &self.loc_keys
+    }
+}
+
+/// Implementation of `LocKeysIndex`.
+impl LocKeysIndex {
+
+    /// This function adds the localised fields and the keys of the provided DB table to the index.
+    pub fn add_table(&mut self, db: &DB) {
+        let definition = db.get_ref_definition();
+        let fields = definition.get_fields_processed();
+        let key_columns = fields.iter().enumerate().filter(|(_, field)| field.get_is_key()).map(|(column, _)| column).collect::<Vec<usize>>();
+        if definition.get_localised_fields().is_empty() { return; }
+
+        let table_name = db.name.to_owned();
+        let short_name = if table_name.ends_with("_tables") { &table_name[..table_name.len() - 7] } else { &table_name };
+        let prefixes = definition.get_localised_fields().iter().map(|field| format!("{}_{}_", short_name, field.get_name())).collect::<Vec<String>>();
+        if key_columns.len() != 1 {
+            self.unknown_prefixes.extend(prefixes);
+            return;
+        }
+
+        for prefix in prefixes {
+            self.prefixes.insert((prefix, table_name.to_owned()));
+        }
+
+        self.keys.entry(table_name)
+            .or_insert_with(HashSet::new)
+            .extend(db.get_ref_table_data().iter().map(|row| row[key_columns[0]].data_to_string()));
+    }
+
+    /// This function returns if the provided loc key is unused, checking it against this index and, optionally, another one.
+    ///
+    /// A loc key is only considered unused if it matches the prefix of a localised field, and the rest of it is not a key
+    /// of the table of that field. Loc keys we don't know how to match, or that may belong to a table whose keys we don't know,
+    /// are always considered used.
+    pub fn is_loc_key_unused(&self, loc_key: &str, other: Option<&Self>) -> bool {
+        let indexes = [Some(self), other];
+        if indexes.iter().flatten().any(|index| index.unknown_prefixes.iter().any(|prefix| loc_key.starts_with(prefix.as_str()))) {
+            return false;
+        }
+
+        let mut matched_prefix = false;
+        for (prefix, table_name) in indexes.iter().flatten().flat_map(|index| index.prefixes.iter()) {
+            if loc_key.starts_with(prefix.as_str()) {
+                let row_key = &loc_key[prefix.len()..];
+                matched_prefix = true;
+                if indexes.iter().flatten().any(|index| index.keys.get(table_name).map_or(false, |keys| keys.contains(row_key))) {
+                    return false;
+                }
+            }
+        }
+
+        matched_prefix
+    }
+}
@@ -0,0 +1,199 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the PackFile optimizer.
+
+The indexes are built by hand instead of from the dependency database, so the tests don't depend on the game installed.
+!*/
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::packedfile::DecodedPackedFile;
+use crate::packedfile::table::{DecodedData, Table};
+use crate::packedfile::table::db::DB;
+use crate::packedfile::table::loc::Loc;
+use crate::packfile::PackFile;
+use crate::packfile::packedfile::PackedFile;
+use crate::schema::{Definition, Field, FieldType};
+
+use super::{LocKeysIndex, OptimizerIndex};
+
+/// This function returns a field with the provided name and type.
+fn get_field(name: &str, field_type: FieldType, is_key: bool) -> Field {
+    Field::new(name.to_owned(), field_type, is_key, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new())
+}
+
+/// This function returns a definition with the provided fields and localised fields.
+fn get_definition(fields: Vec<Field>, localised_fields: &[&str]) -> Definition {
+    let mut definition = Definition::new(1);
+    *definition.get_ref_mut_fields() = fields;
+    *definition.get_ref_mut_localised_fields() = localised_fields.iter().map(|name| get_field(name, FieldType::StringU16, false)).collect();
+    definition
+}
+
+/// This function returns a DB Table with a single string key, and the provided localised fields and keys.
+fn get_db(table_name: &str, localised_fields: &[&str], keys: &[&str]) -> DB {
+    let definition = get_definition(vec![get_field("key", FieldType::StringU8, true)], localised_fields);
+    let mut db = DB::new(table_name, Some("uuid"), &definition);
+    db.set_table_data(&keys.iter().map(|key| vec![DecodedData::StringU8(key.to_string())]).collect::<Vec<Vec<DecodedData>>>()).unwrap();
+    db
+}
+
+/// This function returns the loc keys index of the provided tables.
+fn get_loc_keys_index(tables: &[DB]) -> LocKeysIndex {
+    let mut index = LocKeysIndex::default();
+    tables.iter().for_each(|db| index.add_table(db));
+    index
+}
+
+/// This function returns the definition of the tables with a string key and a float value used in these tests.
+fn get_float_definition() -> Definition {
+    get_definition(vec![get_field("key", FieldType::StringU8, true), get_field("value", FieldType::F32, false)], &[])
+}
+
+/// This function returns rows with a string key and a float value.
+fn get_float_rows(rows: &[(&str, f32)]) -> Vec<Vec<DecodedData>> {
+    rows.iter().map(|(key, value)| vec![DecodedData::StringU8(key.to_string()), DecodedData::F32(*value)]).collect()
+}
+
+/// This function returns a Loc Table with the provided keys.
+fn get_loc(keys: &[&str]) -> Loc {
+    let definition = get_definition(vec![
+        get_field("key", FieldType::StringU16, true),
+        get_field("text", FieldType::StringU16, false),
+        get_field("tooltip", FieldType::Boolean, false),
+    ], &[]);
+
+    let mut loc = Loc::new(&definition);
+    loc.set_table_data(&keys.iter().map(|key| vec![DecodedData::StringU16(key.to_string()), DecodedData::StringU16(String::new()), DecodedData::Boolean(false)]).collect::<Vec<Vec<DecodedData>>>()).unwrap();
+    loc
+}
+
+/// Test to make sure rows are only removed if they're equal to a vanilla row, with floats compared with the same precision as in the tables.
+#[test]
+fn test_optimize_table_float_rounding() {
+    let vanilla_rows = get_float_rows(&[("a", 0.1), ("b", 2.0)]).iter().map(|row| Table::get_row_index_key(row)).collect::<HashSet<Vec<String>>>();
+
+    let mut table = Table::new(&get_float_definition());
+    table.set_table_data(&get_float_rows(&[("a", 0.100_000_01), ("b", 2.000_01), ("c", 0.1)])).unwrap();
+    assert_eq!(table.optimize_table(&vanilla_rows), 1);
+    assert_eq!(table.get_table_data(), get_float_rows(&[("b", 2.000_01), ("c", 0.1)]));
+}
+
+/// Test to make sure rows of tables with more than one key are compared entirely, and sorted by all their keys.
+#[test]
+fn test_optimize_table_multi_key() {
+    let definition = get_definition(vec![
+        get_field("key_1", FieldType::StringU8, true),
+        get_field("value", FieldType::I32, false),
+        get_field("key_2", FieldType::I32, true),
+    ], &[]);
+
+    let get_rows = |rows: &[(&str, i32, i32)]| rows.iter()
+        .map(|(key_1, value, key_2)| vec![DecodedData::StringU8(key_1.to_string()), DecodedData::I32(*value), DecodedData::I32(*key_2)])
+        .collect::<Vec<Vec<DecodedData>>>();
+
+    let vanilla_rows = get_rows(&[("a", 10, 1)]).iter().map(|row| Table::get_row_index_key(row)).collect::<HashSet<Vec<String>>>();
+    let mut table = Table::new(&definition);
+    table.set_table_data(&get_rows(&[("b", 10, 1), ("a", 10, 2), ("a", 11, 1), ("a", 10, 1)])).unwrap();
+    assert_eq!(table.optimize_table(&vanilla_rows), 1);
+
+    // The second key is used only to sort rows with the same first key, and non-key columns are ignored.
+    assert!(table.sort_by_key_fields());
+    assert_eq!(table.get_table_data(), get_rows(&[("a", 11, 1), ("a", 10, 2), ("b", 10, 1)]));
+
+    let mut table = Table::new(&get_definition(vec![get_field("value", FieldType::I32, false)], &[]));
+    assert!(!table.sort_by_key_fields());
+}
+
+/// Test to make sure loc keys are only considered unused when they match the prefix of a localised field, and no table with that prefix has their key.
+#[test]
+fn test_loc_key_prefix_collisions() {
+    let index = get_loc_keys_index(&[
+        get_db("units_tables", &["name", "name_short"], &["sword", "short"]),
+
+        // Both tables have the "abc_def_name_" prefix.
+        get_db("abc_tables", &["def_name"], &["x"]),
+        get_db("abc_def_tables", &["name"], &["y"]),
+    ]);
+
+    assert!(!index.is_loc_key_unused("units_name_sword", None));
+    assert!(!index.is_loc_key_unused("units_name_short_sword", None));
+    assert!(!index.is_loc_key_unused("units_name_short", None));
+    assert!(index.is_loc_key_unused("units_name_axe", None));
+    assert!(index.is_loc_key_unused("units_name_short_axe", None));
+    assert!(!index.is_loc_key_unused("units_description_axe", None));
+    assert!(!index.is_loc_key_unused("buildings_name_axe", None));
+
+    assert!(!index.is_loc_key_unused("abc_def_name_x", None));
+    assert!(!index.is_loc_key_unused("abc_def_name_y", None));
+    assert!(index.is_loc_key_unused("abc_def_name_z", None));
+
+    // Keys can also come from the other index, like the one with the tables of the PackFile being optimized.
+    let own_index = get_loc_keys_index(&[get_db("units_tables", &["name"], &["axe"])]);
+    assert!(!index.is_loc_key_unused("units_name_axe", Some(&own_index)));
+    assert!(!own_index.is_loc_key_unused("units_name_sword", Some(&index)));
+}
+
+/// Test to make sure loc keys that may belong to a table with more than one key are never considered unused.
+#[test]
+fn test_loc_key_multi_key_tables() {
+    let mut index = get_loc_keys_index(&[get_db("land_units_tables", &["name"], &["x"])]);
+    assert!(index.is_loc_key_unused("land_units_name_y_z", None));
+
+    let definition = get_definition(vec![get_field("key_1", FieldType::StringU8, true), get_field("key_2", FieldType::StringU8, true)], &["units_name"]);
+    index.add_table(&DB::new("land_tables", Some("uuid"), &definition));
+    assert!(!index.is_loc_key_unused("land_units_name_y_z", None));
+}
+
+/// Test to make sure optimizing a PackFile removes the vanilla rows, the unused loc keys and the tables left empty, and nothing else.
+#[test]
+fn test_optimize_packfile() {
+    let mut index = OptimizerIndex::default();
+    index.paths.insert(vec!["db".to_owned(), "units_tables".to_owned(), "data__".to_owned()]);
+    index.db_rows.insert(("units_tables".to_owned(), 1), get_float_rows(&[("a", 1.0)]).iter().map(|row| Table::get_row_index_key(row)).collect());
+    index.loc_keys = get_loc_keys_index(&[get_db("units_tables", &["name"], &["a"])]);
+
+    let path = |path: &[&str]| path.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+    let get_db_packed_file = |name: &str, rows: &[(&str, f32)]| {
+        let mut db = DB::new("units_tables", Some("uuid"), &get_float_definition());
+        db.set_table_data(&get_float_rows(rows)).unwrap();
+        PackedFile::new_from_decoded(&DecodedPackedFile::DB(db), &path(&["db", "units_tables", name]))
+    };
+
+    let mut pack_file = PackFile::new();
+    pack_file.add_packed_file(&get_db_packed_file("mod_units", &[("a", 1.000_000_1), ("b", 2.0)]), true).unwrap();
+    pack_file.add_packed_file(&get_db_packed_file("empty_units", &[("a", 1.0)]), true).unwrap();
+
+    // Our own tables don't have localised fields, so only the keys of the vanilla rows can be used.
+    let loc = get_loc(&["units_name_a", "units_name_b", "other_key"]);
+    pack_file.add_packed_file(&PackedFile::new_from_decoded(&DecodedPackedFile::Loc(loc), &path(&["text", "mod.loc"])), true).unwrap();
+
+    let report = pack_file.optimize(&index);
+    assert_eq!(report.rows_removed, 2);
+    assert_eq!(report.loc_keys_removed, 1);
+    assert_eq!(report.deleted_paths, vec![path(&["db", "units_tables", "empty_units"])]);
+    assert!(!pack_file.packedfile_exists(&path(&["db", "units_tables", "empty_units"])));
+
+    match pack_file.get_ref_mut_packed_file_by_path(&path(&["db", "units_tables", "mod_units"])).unwrap().decode_return_ref().unwrap() {
+        DecodedPackedFile::DB(db) => assert_eq!(db.get_table_data(), get_float_rows(&[("b", 2.0)])),
+        _ => panic!("Not a DB Table."),
+    }
+
+    match pack_file.get_ref_mut_packed_file_by_path(&path(&["text", "mod.loc"])).unwrap().decode_return_ref().unwrap() {
+        DecodedPackedFile::Loc(loc) => {
+            let mut keys = loc.get_ref_table_data().iter().map(|row| row[0].data_to_string()).collect::<Vec<String>>();
+            keys.sort();
+            assert_eq!(keys, vec!["other_key".to_owned(), "units_name_a".to_owned()]);
+        }
+        _ => panic!("Not a Loc Table."),
+    }
+}
@@ -19,10 +19,18 @@ AnimPack's structure is very simple:
 - List of files:
     - File Path.
     - Byte Count.
+    - Data.
+
+The files inside can be added, replaced, removed and extracted like the ones of a PackFile, but they're not decoded
+here. To edit one, unpack it into the PackFile, edit it there, and put it back in the AnimPack.
 !*/
 
 use serde_derive::{Serialize, Deserialize};
 
+use std::fs::{DirBuilder, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
 use rpfm_error::{ErrorKind, Result};
 
 use crate::common::{decoder::Decoder, encoder::Encoder};
@@ -84,6 +92,10 @@ impl AnimPack {
         for _ in 0..file_count {
             let path = packed_file_data.decode_packedfile_string_u8(index, &mut index)?.split('/').map(|x| x.to_owned()).collect::<Vec<String>>();
             let byte_count = packed_file_data.decode_packedfile_integer_i32(index, &mut index)?;
+            if byte_count < 0 || index + byte_count as usize > packed_file_data.len() {
+                return Err(ErrorKind::NotEnoughBytesToDecode.into());
+            }
+
             let data = packed_file_data[index..index + byte_count as usize].to_vec();
             index += byte_count as usize;
 
@@ -120,6 +132,45 @@ impl AnimPack {
             .collect()
     }
 
+    /// This function returns the PackedFiles contained within the provided AnimPack.
+    pub fn get_ref_packed_files(&self) -> &[AnimPacked] {
+        &self.packed_files
+    }
+
+    /// This function adds a PackedFile to the AnimPack. If there is already one with the same path, it's replaced.
+    pub fn add_packed_file(&mut self, path: Vec<String>, data: Vec<u8>) {
+        match self.packed_files.iter_mut().find(|packed_file| packed_file.path == path) {
+            Some(packed_file) => packed_file.data = data,
+            None => self.packed_files.push(AnimPacked { path, data }),
+        }
+    }
+
+    /// This function removes the PackedFiles with the provided paths from the AnimPack.
+    pub fn remove_packed_files(&mut self, paths: &[Vec<String>]) {
+        self.packed_files.retain(|packed_file| !paths.contains(&packed_file.path));
+    }
+
+    /// This function extracts the PackedFiles with the provided paths to the provided folder, keeping their path within the AnimPack.
+    ///
+    /// It returns the paths of the extracted files.
+    pub fn extract_packed_files(&self, paths: &[Vec<String>], folder: &Path) -> Result<Vec<PathBuf>> {
+        let mut extracted_paths = vec![];
+        for packed_file in self.packed_files.iter().filter(|packed_file| paths.contains(&packed_file.path)) {
+            let mut path = folder.to_path_buf();
+            path.extend(&packed_file.path);
+
+            if let Some(parent) = path.parent() {
+                DirBuilder::new().recursive(true).create(parent)?;
+            }
+
+            let mut file = BufWriter::new(File::create(&path)?);
+            file.write_all(&packed_file.data)?;
+            extracted_paths.push(path);
+        }
+
+        Ok(extracted_paths)
+    }
+
     /// This function unpacks the entire AnimPack into the current PackFile.
     pub fn unpack(&self, pack_file: &mut PackFile) -> Result<Vec<Vec<String>>> {
         let packed_files = self.packed_files.iter()
@@ -128,6 +179,16 @@ impl AnimPack {
         let packed_files = packed_files.iter().collect::<Vec<&PackedFile>>();
         pack_file.add_packed_files(&packed_files, true)
     }
+
+    /// This function unpacks the PackedFiles with the provided paths into the current PackFile.
+    pub fn unpack_packed_files(&self, paths: &[Vec<String>], pack_file: &mut PackFile) -> Result<Vec<Vec<String>>> {
+        let packed_files = self.packed_files.iter()
+            .filter(|packed_file| paths.contains(&packed_file.path))
+            .map(From::from)
+            .collect::<Vec<PackedFile>>();
+        let packed_files = packed_files.iter().collect::<Vec<&PackedFile>>();
+        pack_file.add_packed_files(&packed_files, true)
+    }
 }
 
 /// Implementation of AnimPacked.
@@ -0,0 +1,641 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with a decoder for DDS images.
+
+Qt only supports a few DDS formats, and not the ones used in recent games, so we decode them here into raw RGBA data.
+Only the first surface of the file (with all its mipmaps) is decoded. The supported formats are:
+- BC1 (DXT1).
+- BC3 (DXT5).
+- BC7.
+
+!*/
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::common::decoder::Decoder;
+
+/// Signature of DDS files.
+const SIGNATURE: &[u8; 4] = b"DDS ";
+
+/// Size of the signature plus the header of a DDS file.
+const HEADER_SIZE: usize = 128;
+
+/// Size of the extra header used by DX10 DDS files.
+const HEADER_DX10_SIZE: usize = 20;
+
+/// Offset of the FourCC of the pixel format of the image.
+const FOUR_CC_OFFSET: usize = 84;
+
+/// DXGI formats we can decode from DX10 DDS files.
+const DXGI_FORMAT_BC1_UNORM: u32 = 71;
+const DXGI_FORMAT_BC1_UNORM_SRGB: u32 = 72;
+const DXGI_FORMAT_BC3_UNORM: u32 = 77;
+const DXGI_FORMAT_BC3_UNORM_SRGB: u32 = 78;
+const DXGI_FORMAT_BC7_UNORM: u32 = 98;
+const DXGI_FORMAT_BC7_UNORM_SRGB: u32 = 99;
+
+/// Info of a BC7 mode: (subsets, partition bits, rotation bits, index selection bits, color bits, alpha bits, endpoint p-bits, shared p-bits, index bits, secondary index bits).
+type BC7Mode = (usize, usize, usize, usize, usize, usize, usize, usize, usize, usize);
+
+/// Info of each BC7 mode.
+const BC7_MODES: [BC7Mode; 8] = [
+    (3, 4, 0, 0, 4, 0, 1, 0, 3, 0),
+    (2, 6, 0, 0, 6, 0, 0, 1, 3, 0),
+    (3, 6, 0, 0, 5, 0, 0, 0, 2, 0),
+    (2, 6, 0, 0, 7, 0, 1, 0, 2, 0),
+    (1, 0, 2, 1, 5, 6, 0, 0, 2, 3),
+    (1, 0, 2, 0, 7, 8, 0, 0, 2, 2),
+    (1, 0, 0, 0, 7, 7, 1, 0, 4, 0),
+    (2, 6, 0, 0, 5, 5, 1, 0, 2, 0),
+];
+
+/// Interpolation weights for 2, 3 and 4 bits indexes.
+const BC7_WEIGHTS_2: [u32; 4] = [0, 21, 43, 64];
+const BC7_WEIGHTS_3: [u32; 8] = [0, 9, 18, 27, 37, 46, 55, 64];
+const BC7_WEIGHTS_4: [u32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+/// Subset of each pixel in BC7 blocks with two subsets, by partition.
+const BC7_PARTITIONS_2: [[u8; 16]; 64] = [
+    [0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1],
+    [0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1],
+    [0, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1],
+    [0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 1],
+    [0, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1],
+    [0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1],
+    [0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1],
+    [0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1],
+    [0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0],
+    [0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0],
+    [0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
+    [0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1],
+    [0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0],
+    [0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
+    [0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0],
+    [0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0],
+    [0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0],
+    [0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0],
+    [0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0],
+    [0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0],
+    [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1],
+    [0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1],
+    [0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0],
+    [0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0],
+    [0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0],
+    [0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0],
+    [0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1],
+    [0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 1],
+    [0, 1, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0],
+    [0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0],
+    [0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0],
+    [0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 1, 0, 0],
+    [0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0],
+    [0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1],
+    [0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1],
+    [0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+    [0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+    [0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0],
+    [0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0],
+    [0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1],
+    [0, 0, 1, 1, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1],
+    [0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 1, 1, 0, 0],
+    [0, 0, 1, 1, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0],
+    [0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1],
+    [0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1],
+    [0, 1, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1],
+    [0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 1, 1],
+    [0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1],
+    [0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0],
+    [0, 0, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 1, 1, 0],
+    [0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1],
+];
+
+/// Subset of each pixel in BC7 blocks with three subsets, by partition.
+const BC7_PARTITIONS_3: [[u8; 16]; 64] = [
+    [0, 0, 1, 1, 0, 0, 1, 1, 0, 2, 2, 1, 2, 2, 2, 2],
+    [0, 0, 0, 1, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2, 2, 1],
+    [0, 0, 0, 0, 2, 0, 0, 1, 2, 2, 1, 1, 2, 2, 1, 1],
+    [0, 2, 2, 2, 0, 0, 2, 2, 0, 0, 1, 1, 0, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2],
+    [0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 2, 2],
+    [0, 0, 2, 2, 0, 0, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 1, 1, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2],
+    [0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2],
+    [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2],
+    [0, 1, 1, 2, 0, 1, 1, 2, 0, 1, 1, 2, 0, 1, 1, 2],
+    [0, 1, 2, 2, 0, 1, 2, 2, 0, 1, 2, 2, 0, 1, 2, 2],
+    [0, 0, 1, 1, 0, 1, 1, 2, 1, 1, 2, 2, 1, 2, 2, 2],
+    [0, 0, 1, 1, 2, 0, 0, 1, 2, 2, 0, 0, 2, 2, 2, 0],
+    [0, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 2, 1, 1, 2, 2],
+    [0, 1, 1, 1, 0, 0, 1, 1, 2, 0, 0, 1, 2, 2, 0, 0],
+    [0, 0, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 2, 2],
+    [0, 0, 2, 2, 0, 0, 2, 2, 0, 0, 2, 2, 1, 1, 1, 1],
+    [0, 1, 1, 1, 0, 1, 1, 1, 0, 2, 2, 2, 0, 2, 2, 2],
+    [0, 0, 0, 1, 0, 0, 0, 1, 2, 2, 2, 1, 2, 2, 2, 1],
+    [0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 2, 2, 0, 1, 2, 2],
+    [0, 0, 0, 0, 1, 1, 0, 0, 2, 2, 1, 0, 2, 2, 1, 0],
+    [0, 1, 2, 2, 0, 1, 2, 2, 0, 0, 1, 1, 0, 0, 0, 0],
+    [0, 0, 1, 2, 0, 0, 1, 2, 1, 1, 2, 2, 2, 2, 2, 2],
+    [0, 1, 1, 0, 1, 2, 2, 1, 1, 2, 2, 1, 0, 1, 1, 0],
+    [0, 0, 0, 0, 0, 1, 1, 0, 1, 2, 2, 1, 1, 2, 2, 1],
+    [0, 0, 2, 2, 1, 1, 0, 2, 1, 1, 0, 2, 0, 0, 2, 2],
+    [0, 1, 1, 0, 0, 1, 1, 0, 2, 0, 0, 2, 2, 2, 2, 2],
+    [0, 0, 1, 1, 0, 1, 2, 2, 0, 1, 2, 2, 0, 0, 1, 1],
+    [0, 0, 0, 0, 2, 0, 0, 0, 2, 2, 1, 1, 2, 2, 2, 1],
+    [0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 2, 2, 1, 2, 2, 2],
+    [0, 2, 2, 2, 0, 0, 2, 2, 0, 0, 1, 2, 0, 0, 1, 1],
+    [0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 2, 2, 0, 2, 2, 2],
+    [0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0],
+    [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 0, 0, 0, 0],
+    [0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2, 0],
+    [0, 1, 2, 0, 2, 0, 1, 2, 1, 2, 0, 1, 0, 1, 2, 0],
+    [0, 0, 1, 1, 2, 2, 0, 0, 1, 1, 2, 2, 0, 0, 1, 1],
+    [0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 0, 0, 0, 0, 1, 1],
+    [0, 1, 0, 1, 0, 1, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 2, 1, 2, 1, 2, 1],
+    [0, 0, 2, 2, 1, 1, 2, 2, 0, 0, 2, 2, 1, 1, 2, 2],
+    [0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 1, 1],
+    [0, 2, 2, 0, 1, 2, 2, 1, 0, 2, 2, 0, 1, 2, 2, 1],
+    [0, 1, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0, 1, 0, 1],
+    [0, 0, 0, 0, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1],
+    [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 2, 2, 2, 2],
+    [0, 2, 2, 2, 0, 1, 1, 1, 0, 2, 2, 2, 0, 1, 1, 1],
+    [0, 0, 0, 2, 1, 1, 1, 2, 0, 0, 0, 2, 1, 1, 1, 2],
+    [0, 0, 0, 0, 2, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 2],
+    [0, 2, 2, 2, 0, 1, 1, 1, 0, 1, 1, 1, 0, 2, 2, 2],
+    [0, 0, 0, 2, 1, 1, 1, 2, 1, 1, 1, 2, 0, 0, 0, 2],
+    [0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 2, 2, 2, 2],
+    [0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 2, 2, 1, 1, 2],
+    [0, 1, 1, 0, 0, 1, 1, 0, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 2, 2],
+    [0, 0, 2, 2, 1, 1, 2, 2, 1, 1, 2, 2, 0, 0, 2, 2],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 2],
+    [0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1],
+    [0, 2, 2, 2, 1, 2, 2, 2, 0, 2, 2, 2, 1, 2, 2, 2],
+    [0, 1, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 1, 1, 1, 2, 0, 1, 1, 2, 2, 0, 1, 2, 2, 2, 0],
+];
+
+/// Anchor pixel of the second subset in BC7 blocks with two subsets, by partition.
+const BC7_ANCHORS_2: [usize; 64] = [
+    15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15,
+    15,  2,  8,  2,  2,  8,  8, 15,
+     2,  8,  2,  2,  8,  8,  2,  2,
+    15, 15,  6,  8,  2,  8, 15, 15,
+     2,  8,  2,  2,  2, 15, 15,  6,
+     6,  2,  6,  8, 15, 15,  2,  2,
+    15, 15, 15, 15, 15,  2,  2, 15,
+];
+
+/// Anchor pixel of the second subset in BC7 blocks with three subsets, by partition.
+const BC7_ANCHORS_3_SECOND: [usize; 64] = [
+     3,  3, 15, 15,  8,  3, 15, 15,
+     8,  8,  6,  6,  6,  5,  3,  3,
+     3,  3,  8, 15,  3,  3,  6, 10,
+     5,  8,  8,  6,  8,  5, 15, 15,
+     8, 15,  3,  5,  6, 10,  8, 15,
+    15,  3, 15,  5, 15, 15, 15, 15,
+     3, 15,  5,  5,  5,  8,  5, 10,
+     5, 10,  8, 13, 15, 12,  3,  3,
+];
+
+/// Anchor pixel of the third subset in BC7 blocks with three subsets, by partition.
+const BC7_ANCHORS_3_THIRD: [usize; 64] = [
+    15,  8,  8,  3, 15, 15,  3,  8,
+    15, 15, 15, 15, 15, 15, 15,  8,
+    15,  8, 15,  3, 15,  8, 15,  8,
+     3, 15,  6, 10, 15, 15, 10,  8,
+    15,  3, 15, 10, 10,  8,  9, 10,
+     6, 15,  8, 15,  3,  6,  6,  8,
+    15,  3, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15,  3, 15, 15,  8,
+];
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This holds a DDS image decoded to RGBA.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DDS {
+
+    /// The compression format of the image.
+    format: DDSFormat,
+
+    /// The mipmaps of the image, from the biggest one to the smallest one.
+    mipmaps: Vec<DDSMipmap>,
+}
+
+/// This holds a decoded mipmap of a DDS image.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DDSMipmap {
+
+    /// Width of the mipmap, in pixels.
+    width: u32,
+
+    /// Height of the mipmap, in pixels.
+    height: u32,
+
+    /// RGBA data of the mipmap, 4 bytes per pixel.
+    data: Vec<u8>,
+}
+
+/// This enum represents the compression formats we can decode.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DDSFormat {
+    BC1,
+    BC3,
+    BC7,
+}
+
+/// This struct is used to read a BC7 block bit by bit.
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+//---------------------------------------------------------------------------//
+//                           Implementation of DDS
+//---------------------------------------------------------------------------//
+
+/// Implementation of `DDS`.
+impl DDS {
+
+    /// This function returns if the provided data looks like a DDS image.
+    pub fn is_dds(data: &[u8]) -> bool {
+        data.len() >= 4 && &data[..4] == SIGNATURE
+    }
+
+    /// This function decodes a DDS image from the provided data.
+    pub fn read(data: &[u8]) -> Result<Self> {
+        if !Self::is_dds(data) || data.len() < HEADER_SIZE {
+            return Err(ErrorKind::ImageDecode("This is not a valid DDS image.".to_owned()).into());
+        }
+
+        let height = data.decode_integer_u32(12)?;
+        let width = data.decode_integer_u32(16)?;
+        let mipmap_count = data.decode_integer_u32(28)?.max(1);
+
+        let (format, mut offset) = match &data[FOUR_CC_OFFSET..FOUR_CC_OFFSET + 4] {
+            b"DXT1" => (DDSFormat::BC1, HEADER_SIZE),
+            b"DXT5" => (DDSFormat::BC3, HEADER_SIZE),
+            b"DX10" => {
+                let format = match data.decode_integer_u32(HEADER_SIZE)? {
+                    DXGI_FORMAT_BC1_UNORM | DXGI_FORMAT_BC1_UNORM_SRGB => DDSFormat::BC1,
+                    DXGI_FORMAT_BC3_UNORM | DXGI_FORMAT_BC3_UNORM_SRGB => DDSFormat::BC3,
+                    DXGI_FORMAT_BC7_UNORM | DXGI_FORMAT_BC7_UNORM_SRGB => DDSFormat::BC7,
+                    dxgi_format => return Err(ErrorKind::ImageDecode(format!("Unsupported DXGI format: {}.", dxgi_format)).into()),
+                };
+                (format, HEADER_SIZE + HEADER_DX10_SIZE)
+            }
+            four_cc => return Err(ErrorKind::ImageDecode(format!("Unsupported DDS format: {}.", String::from_utf8_lossy(four_cc))).into()),
+        };
+
+        let block_size = if let DDSFormat::BC1 = format { 8 } else { 16 };
+        let mut mipmaps = vec![];
+        for level in 0..mipmap_count {
+            let mipmap_width = width.checked_shr(level).unwrap_or(0).max(1);
+            let mipmap_height = height.checked_shr(level).unwrap_or(0).max(1);
+            let size = ((mipmap_width as usize + 3) / 4).checked_mul((mipmap_height as usize + 3) / 4)
+                .and_then(|blocks| blocks.checked_mul(block_size))
+                .ok_or_else(|| ErrorKind::ImageDecode(format!("The DDS image is too big: {}x{} pixels.", width, height)))?;
+
+            // Some files lie about their amount of mipmaps, so we just keep the ones we have.
+            if size > data.len() - offset { break; }
+            mipmaps.push(DDSMipmap::read(&data[offset..offset + size], mipmap_width, mipmap_height, format));
+            offset += size;
+        }
+
+        if mipmaps.is_empty() {
+            return Err(ErrorKind::ImageDecode("The DDS image has no data.".to_owned()).into());
+        }
+
+        Ok(Self {
+            format,
+            mipmaps,
+        })
+    }
+
+    /// This function returns the compression format of the image.
+    pub fn get_format(&self) -> DDSFormat {
+        self.format
+    }
+
+    /// This function returns the mipmaps of the image.
+    pub fn get_ref_mipmaps(&self) -> &[DDSMipmap] {
+        &self.mipmaps
+    }
+}
+
+/// Implementation of `DDSMipmap`.
+impl DDSMipmap {
+
+    /// This function decodes a mipmap from its compressed blocks.
+    fn read(data: &[u8], width: u32, height: u32, format: DDSFormat) -> Self {
+        let (width_usize, height_usize) = (width as usize, height as usize);
+        let blocks_wide = (width_usize + 3) / 4;
+        let block_size = if let DDSFormat::BC1 = format { 8 } else { 16 };
+
+        let mut rgba = vec![0; width_usize * height_usize * 4];
+        for (index, block) in data.chunks_exact(block_size).enumerate() {
+            let pixels = match format {
+                DDSFormat::BC1 => decode_bc1_block(block, true),
+                DDSFormat::BC3 => decode_bc3_block(block),
+                DDSFormat::BC7 => decode_bc7_block(block),
+            };
+
+            // Copy only the pixels inside the image, as the blocks of the borders can go out of it.
+            let (block_x, block_y) = ((index % blocks_wide) * 4, (index / blocks_wide) * 4);
+            for (pixel, color) in pixels.iter().enumerate() {
+                let (x, y) = (block_x + pixel % 4, block_y + pixel / 4);
+                if x < width_usize && y < height_usize {
+                    let position = (y * width_usize + x) * 4;
+                    rgba[position..position + 4].copy_from_slice(color);
+                }
+            }
+        }
+
+        Self {
+            width,
+            height,
+            data: rgba,
+        }
+    }
+
+    /// This function returns the width of the mipmap.
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    /// This function returns the height of the mipmap.
+    pub fn get_height(&self) -> u32 {
+        self.height
+    }
+
+    /// This function returns the RGBA data of the mipmap.
+    pub fn get_ref_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// This function returns the RGBA data of the mipmap, with only the provided channels (red, green, blue, alpha) enabled.
+    ///
+    /// Disabled color channels are set to 0, and a disabled alpha channel is set to opaque. If only one channel is enabled,
+    /// it's returned as an opaque grayscale image, so it can be checked on its own.
+    pub fn get_data_by_channels(&self, channels: [bool; 4]) -> Vec<u8> {
+        let enabled = channels.iter().filter(|x| **x).count();
+        self.data.chunks_exact(4).flat_map(|pixel| {
+            if enabled == 1 {
+                let value = pixel[channels.iter().position(|x| *x).unwrap()];
+                vec![value, value, value, 255]
+            } else {
+                vec![
+                    if channels[0] { pixel[0] } else { 0 },
+                    if channels[1] { pixel[1] } else { 0 },
+                    if channels[2] { pixel[2] } else { 0 },
+                    if channels[3] { pixel[3] } else { 255 },
+                ]
+            }
+        }).collect()
+    }
+}
+
+/// Implementation of `BitReader`.
+impl<'a> BitReader<'a> {
+
+    /// This function creates a new `BitReader` for the provided data.
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            position: 0,
+        }
+    }
+
+    /// This function reads the next `count` bits, starting from the least significant bit of the first byte.
+    fn read(&mut self, count: usize) -> u32 {
+        let mut value = 0;
+        for bit in 0..count {
+            let position = self.position + bit;
+            value |= u32::from((self.data[position / 8] >> (position % 8)) & 1) << bit;
+        }
+        self.position += count;
+        value
+    }
+}
+
+//---------------------------------------------------------------------------//
+//                              Block decoding
+//---------------------------------------------------------------------------//
+
+/// This function decodes a BC1 block into 16 RGBA pixels.
+///
+/// BC3 blocks contain a BC1 block for their colors, but those can never use the 3-colors mode, hence the `allow_3_colors` argument.
+fn decode_bc1_block(block: &[u8], allow_3_colors: bool) -> [[u8; 4]; 16] {
+    let color_0 = u16::from_le_bytes([block[0], block[1]]);
+    let color_1 = u16::from_le_bytes([block[2], block[3]]);
+    let indexes = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+
+    let rgb_0 = decode_rgb565(color_0);
+    let rgb_1 = decode_rgb565(color_1);
+    let mix = |weight_0: u32, weight_1: u32, divisor: u32| -> [u8; 4] {
+        let mut color = [0, 0, 0, 255];
+        for channel in 0..3 {
+            color[channel] = ((u32::from(rgb_0[channel]) * weight_0 + u32::from(rgb_1[channel]) * weight_1) / divisor) as u8;
+        }
+        color
+    };
+
+    let palette = if color_0 > color_1 || !allow_3_colors {
+        [mix(1, 0, 1), mix(0, 1, 1), mix(2, 1, 3), mix(1, 2, 3)]
+    } else {
+        [mix(1, 0, 1), mix(0, 1, 1), mix(1, 1, 2), [0, 0, 0, 0]]
+    };
+
+    let mut pixels = [[0; 4]; 16];
+    for (pixel, color) in pixels.iter_mut().enumerate() {
+        *color = palette[((indexes >> (pixel * 2)) & 0b11) as usize];
+    }
+    pixels
+}
+
+/// This function decodes a BC3 block into 16 RGBA pixels.
+fn decode_bc3_block(block: &[u8]) -> [[u8; 4]; 16] {
+    let alpha_0 = u32::from(block[0]);
+    let alpha_1 = u32::from(block[1]);
+    let indexes = block[2..8].iter().rev().fold(0u64, |indexes, byte| (indexes << 8) | u64::from(*byte));
+
+    let mut alphas = [0u8; 8];
+    alphas[0] = alpha_0 as u8;
+    alphas[1] = alpha_1 as u8;
+    if alpha_0 > alpha_1 {
+        for index in 1..7 {
+            alphas[index as usize + 1] = (((7 - index) * alpha_0 + index * alpha_1) / 7) as u8;
+        }
+    } else {
+        for index in 1..5 {
+            alphas[index as usize + 1] = (((5 - index) * alpha_0 + index * alpha_1) / 5) as u8;
+        }
+        alphas[6] = 0;
+        alphas[7] = 255;
+    }
+
+    let mut pixels = decode_bc1_block(&block[8..], false);
+    for (pixel, color) in pixels.iter_mut().enumerate() {
+        color[3] = alphas[((indexes >> (pixel * 3)) & 0b111) as usize];
+    }
+    pixels
+}
+
+/// This function decodes a BC7 block into 16 RGBA pixels.
+fn decode_bc7_block(block: &[u8]) -> [[u8; 4]; 16] {
+    let mut pixels = [[0; 4]; 16];
+
+    // The mode is the amount of zeros before the first 1. Blocks without mode are invalid, and must be decoded as transparent black.
+    let mode = block[0].trailing_zeros() as usize;
+    if mode >= BC7_MODES.len() { return pixels; }
+
+    let (subsets, partition_bits, rotation_bits, index_selection_bits, color_bits, alpha_bits, endpoint_p_bits, shared_p_bits, index_bits, index_2_bits) = BC7_MODES[mode];
+    let mut reader = BitReader::new(block);
+    reader.read(mode + 1);
+
+    let partition = reader.read(partition_bits) as usize;
+    let rotation = reader.read(rotation_bits);
+    let index_selection = reader.read(index_selection_bits);
+
+    // Endpoints are stored by channel, then by subset.
+    let mut endpoints = [[0u32; 4]; 6];
+    for channel in 0..3 {
+        for endpoint in endpoints.iter_mut().take(subsets * 2) {
+            endpoint[channel] = reader.read(color_bits);
+        }
+    }
+
+    for endpoint in endpoints.iter_mut().take(subsets * 2) {
+        endpoint[3] = if alpha_bits > 0 { reader.read(alpha_bits) } else { 255 };
+    }
+
+    // Apply the p-bits, if any, and expand the endpoints to 8 bits.
+    let mut p_bits = [0u32; 6];
+    if endpoint_p_bits > 0 {
+        for p_bit in p_bits.iter_mut().take(subsets * 2) {
+            *p_bit = reader.read(1);
+        }
+    } else if shared_p_bits > 0 {
+        for subset in 0..subsets {
+            let p_bit = reader.read(1);
+            p_bits[subset * 2] = p_bit;
+            p_bits[subset * 2 + 1] = p_bit;
+        }
+    }
+
+    let has_p_bits = endpoint_p_bits > 0 || shared_p_bits > 0;
+    for (endpoint, p_bit) in endpoints.iter_mut().zip(p_bits.iter()).take(subsets * 2) {
+        for (channel, value) in endpoint.iter_mut().enumerate() {
+            let bits = if channel < 3 { color_bits } else { alpha_bits };
+            if bits == 0 { continue; }
+
+            let (value_p, bits) = if has_p_bits { ((*value << 1) | p_bit, bits + 1) } else { (*value, bits) };
+            let expanded = value_p << (8 - bits);
+            *value = expanded | (expanded >> bits);
+        }
+    }
+
+    // Get the subset of each pixel, and read the indexes. Anchor pixels have one bit less.
+    let pixel_subsets = match subsets {
+        2 => BC7_PARTITIONS_2[partition],
+        3 => BC7_PARTITIONS_3[partition],
+        _ => [0; 16],
+    };
+
+    let anchors = match subsets {
+        2 => vec![0, BC7_ANCHORS_2[partition]],
+        3 => vec![0, BC7_ANCHORS_3_SECOND[partition], BC7_ANCHORS_3_THIRD[partition]],
+        _ => vec![0],
+    };
+
+    let mut indexes = [0u32; 16];
+    for (pixel, index) in indexes.iter_mut().enumerate() {
+        *index = reader.read(if anchors.contains(&pixel) { index_bits - 1 } else { index_bits });
+    }
+
+    let mut indexes_2 = [0u32; 16];
+    if index_2_bits > 0 {
+        for (pixel, index) in indexes_2.iter_mut().enumerate() {
+            *index = reader.read(if pixel == 0 { index_2_bits - 1 } else { index_2_bits });
+        }
+    }
+
+    // Interpolate the colors of each pixel.
+    for (pixel, color) in pixels.iter_mut().enumerate() {
+        let subset = pixel_subsets[pixel] as usize;
+        let endpoint_0 = endpoints[subset * 2];
+        let endpoint_1 = endpoints[subset * 2 + 1];
+
+        let (color_weight, alpha_weight) = if index_2_bits == 0 {
+            let weight = get_bc7_weight(index_bits, indexes[pixel]);
+            (weight, weight)
+        } else if index_selection == 0 {
+            (get_bc7_weight(index_bits, indexes[pixel]), get_bc7_weight(index_2_bits, indexes_2[pixel]))
+        } else {
+            (get_bc7_weight(index_2_bits, indexes_2[pixel]), get_bc7_weight(index_bits, indexes[pixel]))
+        };
+
+        for channel in 0..4 {
+            let weight = if channel < 3 { color_weight } else { alpha_weight };
+            color[channel] = (((64 - weight) * endpoint_0[channel] + weight * endpoint_1[channel] + 32) >> 6) as u8;
+        }
+
+        match rotation {
+            1 => color.swap(0, 3),
+            2 => color.swap(1, 3),
+            3 => color.swap(2, 3),
+            _ => {},
+        }
+    }
+
+    pixels
+}
+
+/// This function returns the weight of the provided BC7 index.
+fn get_bc7_weight(bits: usize, index: u32) -> u32 {
+    match bits {
+        2 => BC7_WEIGHTS_2[index as usize],
+        3 => BC7_WEIGHTS_3[index as usize],
+        _ => BC7_WEIGHTS_4[index as usize],
+    }
+}
+
+/// This function expands a RGB565 color to RGB888.
+fn decode_rgb565(color: u16) -> [u8; 3] {
+    let red = (color >> 11) & 0x1F;
+    let green = (color >> 5) & 0x3F;
+    let blue = color & 0x1F;
+    [
+        ((red << 3) | (red >> 2)) as u8,
+        ((green << 2) | (green >> 4)) as u8,
+        ((blue << 3) | (blue >> 2)) as u8,
+    ]
+}
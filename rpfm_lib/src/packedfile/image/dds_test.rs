@@ -0,0 +1,178 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the DDS decoder.
+
+Each test builds a small DDS image around known compressed blocks, and checks the decoded RGBA pixels.
+!*/
+
+use super::dds::{DDS, DDSFormat};
+
+/// This function builds a DDS image with the provided format and compressed data.
+///
+/// If a DXGI format is provided, the image uses the DX10 header.
+fn build_dds(four_cc: &[u8; 4], dxgi_format: Option<u32>, width: u32, height: u32, mipmap_count: u32, blocks: &[u8]) -> Vec<u8> {
+    let mut data = vec![0; 128];
+    data[..4].copy_from_slice(b"DDS ");
+    data[12..16].copy_from_slice(&height.to_le_bytes());
+    data[16..20].copy_from_slice(&width.to_le_bytes());
+    data[28..32].copy_from_slice(&mipmap_count.to_le_bytes());
+    data[84..88].copy_from_slice(four_cc);
+
+    if let Some(dxgi_format) = dxgi_format {
+        let mut header_dx10 = vec![0; 20];
+        header_dx10[..4].copy_from_slice(&dxgi_format.to_le_bytes());
+        data.extend_from_slice(&header_dx10);
+    }
+
+    data.extend_from_slice(blocks);
+    data
+}
+
+/// This function decodes a 4x4 image made of only the provided block, and returns its pixels.
+fn decode_single_block(four_cc: &[u8; 4], dxgi_format: Option<u32>, block: &[u8]) -> Vec<[u8; 4]> {
+    let dds = DDS::read(&build_dds(four_cc, dxgi_format, 4, 4, 1, block)).unwrap();
+    let mipmap = &dds.get_ref_mipmaps()[0];
+    assert_eq!(mipmap.get_width(), 4);
+    assert_eq!(mipmap.get_height(), 4);
+    mipmap.get_ref_data().chunks_exact(4).map(|x| [x[0], x[1], x[2], x[3]]).collect()
+}
+
+/// BC1 block with red (0xF800) and blue (0x001F) as colors, and each row of pixels using the indexes 0, 1, 2 and 3.
+const BC1_BLOCK: [u8; 8] = [0x00, 0xF8, 0x1F, 0x00, 0xE4, 0xE4, 0xE4, 0xE4];
+
+/// Test to make sure BC1 blocks with the first color bigger than the second one are decoded with 4 colors.
+#[test]
+fn test_decode_bc1_4_colors() {
+    let pixels = decode_single_block(b"DXT1", None, &BC1_BLOCK);
+    let row: [[u8; 4]; 4] = [[255, 0, 0, 255], [0, 0, 255, 255], [170, 0, 85, 255], [85, 0, 170, 255]];
+    for (pixel, color) in pixels.iter().enumerate() {
+        assert_eq!(*color, row[pixel % 4]);
+    }
+}
+
+/// Test to make sure BC1 blocks with the first color not bigger than the second one are decoded with 3 colors and transparent black.
+#[test]
+fn test_decode_bc1_3_colors() {
+    let block = [0x1F, 0x00, 0x00, 0xF8, 0xE4, 0xE4, 0xE4, 0xE4];
+    let pixels = decode_single_block(b"DXT1", None, &block);
+    let row: [[u8; 4]; 4] = [[0, 0, 255, 255], [255, 0, 0, 255], [127, 0, 127, 255], [0, 0, 0, 0]];
+    for (pixel, color) in pixels.iter().enumerate() {
+        assert_eq!(*color, row[pixel % 4]);
+    }
+}
+
+/// Test to make sure BC3 blocks with the first alpha bigger than the second one are decoded with 8 alphas,
+/// and that their colors never use the 3 colors mode of BC1.
+#[test]
+fn test_decode_bc3_8_alphas() {
+    let block = [
+        0xFF, 0x00, 0x88, 0xC6, 0xFA, 0x88, 0xC6, 0xFA,
+        0x1F, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    let pixels = decode_single_block(b"DXT5", None, &block);
+    let alphas: [u8; 8] = [255, 0, 218, 182, 145, 109, 72, 36];
+    for (pixel, color) in pixels.iter().enumerate() {
+        assert_eq!(*color, [170, 0, 85, alphas[pixel % 8]]);
+    }
+}
+
+/// Test to make sure BC3 blocks with the first alpha not bigger than the second one are decoded with 6 alphas, plus 0 and 255.
+#[test]
+fn test_decode_bc3_6_alphas() {
+    let block = [
+        0x00, 0xFF, 0x88, 0xC6, 0xFA, 0x88, 0xC6, 0xFA,
+        0x1F, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    let pixels = decode_single_block(b"DXT5", None, &block);
+    let alphas: [u8; 8] = [0, 255, 51, 102, 153, 204, 0, 255];
+    for (pixel, color) in pixels.iter().enumerate() {
+        assert_eq!(color[3], alphas[pixel % 8]);
+    }
+}
+
+/// Test to make sure BC7 blocks in mode 6 (one subset, RGBA endpoints with p-bits, 4 bits indexes) are decoded properly.
+///
+/// The endpoints are (255, 1, 1, 255) and (0, 254, 0, 254), and each pixel uses its own position as index.
+#[test]
+fn test_decode_bc7_mode_6() {
+    let block = [
+        0xC0, 0x3F, 0x00, 0xF0, 0x07, 0x00, 0xFE, 0xFF,
+        0x10, 0x32, 0x54, 0x76, 0x98, 0xBA, 0xDC, 0xFE,
+    ];
+    let pixels = decode_single_block(b"DX10", Some(98), &block);
+    let expected: [[u8; 4]; 16] = [
+        [255, 1, 1, 255], [239, 17, 1, 255], [219, 37, 1, 255], [203, 52, 1, 255],
+        [187, 68, 1, 255], [171, 84, 1, 255], [151, 104, 1, 255], [135, 120, 1, 255],
+        [120, 135, 0, 254], [104, 151, 0, 254], [84, 171, 0, 254], [68, 187, 0, 254],
+        [52, 203, 0, 254], [36, 218, 0, 254], [16, 238, 0, 254], [0, 254, 0, 254],
+    ];
+    assert_eq!(pixels, expected.to_vec());
+}
+
+/// Test to make sure BC7 blocks without a valid mode are decoded as transparent black.
+#[test]
+fn test_decode_bc7_invalid_mode() {
+    let pixels = decode_single_block(b"DX10", Some(98), &[0; 16]);
+    assert!(pixels.iter().all(|x| *x == [0u8; 4]));
+}
+
+/// Test to make sure the mipmaps smaller than a block are decoded, and only the pixels inside them are kept.
+#[test]
+fn test_decode_mipmaps() {
+    let blocks = [BC1_BLOCK, BC1_BLOCK, BC1_BLOCK].concat();
+    let dds = DDS::read(&build_dds(b"DXT1", None, 4, 4, 3, &blocks)).unwrap();
+    assert_eq!(dds.get_format(), DDSFormat::BC1);
+
+    let mipmaps = dds.get_ref_mipmaps();
+    assert_eq!(mipmaps.len(), 3);
+    assert_eq!((mipmaps[1].get_width(), mipmaps[1].get_height()), (2, 2));
+    assert_eq!(mipmaps[1].get_ref_data(), &[255u8, 0, 0, 255, 0, 0, 255, 255, 255, 0, 0, 255, 0, 0, 255, 255][..]);
+    assert_eq!((mipmaps[2].get_width(), mipmaps[2].get_height()), (1, 1));
+    assert_eq!(mipmaps[2].get_ref_data(), &[255u8, 0, 0, 255][..]);
+}
+
+/// Test to make sure images whose size is not a multiple of 4 read all their blocks.
+#[test]
+fn test_decode_partial_blocks() {
+    let blocks = [BC1_BLOCK, BC1_BLOCK, BC1_BLOCK, BC1_BLOCK].concat();
+    let dds = DDS::read(&build_dds(b"DXT1", None, 5, 5, 1, &blocks)).unwrap();
+    let mipmap = &dds.get_ref_mipmaps()[0];
+    assert_eq!(mipmap.get_ref_data().len(), 5 * 5 * 4);
+
+    // The fifth column comes from the first column of the blocks on the right.
+    assert_eq!(&mipmap.get_ref_data()[16..20], &[255u8, 0, 0, 255]);
+}
+
+/// Test to make sure images claiming more mipmaps than bits in their size decode the extra mipmaps as 1x1, as long as there is data for them.
+#[test]
+fn test_decode_too_many_mipmaps() {
+    let blocks = [BC1_BLOCK; 40].concat();
+    let dds = DDS::read(&build_dds(b"DXT1", None, 4, 4, 40, &blocks)).unwrap();
+    let mipmaps = dds.get_ref_mipmaps();
+    assert_eq!(mipmaps.len(), 40);
+    assert!(mipmaps[2..].iter().all(|mipmap| mipmap.get_width() == 1 && mipmap.get_height() == 1));
+}
+
+/// Test to make sure images whose size in bytes doesn't fit in memory are rejected, instead of overflowing.
+#[test]
+fn test_decode_too_big() {
+    assert!(DDS::read(&build_dds(b"DXT5", None, u32::MAX, u32::MAX, 1, &[0; 16])).is_err());
+}
+
+/// Test to make sure invalid or unsupported images are rejected.
+#[test]
+fn test_decode_invalid() {
+    assert!(DDS::read(&[0; 128]).is_err());
+    assert!(DDS::read(&build_dds(b"DXT3", None, 4, 4, 1, &[0; 16])).is_err());
+    assert!(DDS::read(&build_dds(b"DX10", Some(2), 4, 4, 1, &[0; 16])).is_err());
+    assert!(DDS::read(&build_dds(b"DXT1", None, 4, 4, 1, &[])).is_err());
+}
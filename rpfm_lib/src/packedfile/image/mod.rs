@@ -11,13 +11,20 @@
 /*!
 Module with all the code to interact with Image PackedFiles.
 
-Images... we really just get their that to memory. Nothing more.
+Images... we really just get their that to memory. Nothing more. The only exception are DDS images,
+which can be decoded to RGBA through the `dds` module, as Qt doesn't support most of the formats used by the games.
 !*/
 
 use serde_derive::{Serialize, Deserialize};
 
 use rpfm_error::Result;
 
+pub mod dds;
+pub mod png;
+
+#[cfg(test)]
+mod dds_test;
+
 /// Extensions used by Image PackedFiles.
 pub const EXTENSIONS: [&str; 5] = [
     ".jpg",
@@ -0,0 +1,110 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with a minimal encoder for PNG images.
+
+It's used to write images we decode ourselves (like DDS ones) to disk in a format any image viewer can open.
+It writes 8-bit RGBA images without filtering, and with the data stored without compression, so the files are
+bigger than the ones made by a proper image editor, but we don't need a deflate implementation for it.
+!*/
+
+/// Signature of PNG files.
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Max size of the data of a stored (non-compressed) deflate block.
+const MAX_STORED_BLOCK_SIZE: usize = 0xFFFF;
+
+/// Modulo used by the Adler-32 checksum of zlib streams.
+const ADLER_MOD: u32 = 65521;
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function encodes the provided RGBA data (4 bytes per pixel, row by row) as a PNG image.
+pub fn encode_rgba(width: u32, height: u32, data: &[u8]) -> Vec<u8> {
+    let mut png = SIGNATURE.to_vec();
+
+    let mut header = vec![];
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+
+    // Bit depth 8, color type RGBA, default compression and filter methods, no interlace.
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+
+    // Every row starts with its filter type, and we use no filter.
+    let row_size = width as usize * 4;
+    let mut raw = Vec::with_capacity((row_size + 1) * height as usize);
+    for row in data.chunks(row_size).take(height as usize) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// This function writes a chunk with the provided type and data at the end of the provided PNG.
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// This function wraps the provided data in a zlib stream, using stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+
+    // Deflate compression method with a 32K window, no dictionary and the lowest compression level.
+    let mut stream = vec![0x78, 0x01];
+    let blocks = data.chunks(MAX_STORED_BLOCK_SIZE).collect::<Vec<&[u8]>>();
+    if blocks.is_empty() {
+        stream.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+
+    for (index, block) in blocks.iter().enumerate() {
+        let is_last = index == blocks.len() - 1;
+        let len = block.len() as u16;
+        stream.push(if is_last { 1 } else { 0 });
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+/// This function calculates the CRC-32 of the provided data, as used by the chunks of PNG files.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { 0xEDB8_8320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// This function calculates the Adler-32 checksum of the provided data, as used by zlib streams.
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + u32::from(*byte)) % ADLER_MOD;
+        b = (b + a) % ADLER_MOD;
+    }
+    (b << 16) | a
+}
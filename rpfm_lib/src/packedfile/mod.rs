@@ -31,6 +31,8 @@ use crate::packedfile::image::Image;
 use crate::packedfile::table::{anim_fragment::AnimFragment, animtable::AnimTable, db::DB, loc::Loc, matched_combat::MatchedCombat};
 use crate::packedfile::text::{Text, TextType};
 use crate::packedfile::rigidmodel::RigidModel;
+use crate::packedfile::sound_bank::SoundBank;
+use crate::packedfile::unit_variant::UnitVariant;
 use crate::packfile::packedfile::{PackedFile, RawPackedFile};
 use crate::schema::Schema;
 use crate::SCHEMA;
@@ -39,8 +41,11 @@ pub mod animpack;
 pub mod ca_vp8;
 pub mod image;
 pub mod rigidmodel;
+pub mod sound_bank;
 pub mod table;
 pub mod text;
+pub mod unit_variant;
+pub mod variant_mesh_definition;
 
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
@@ -63,8 +68,10 @@ pub enum DecodedPackedFile {
     Loc(Loc),
     MatchedCombat(MatchedCombat),
     RigidModel(RigidModel),
+    SoundBank(SoundBank),
     StarPos,
     Text(Text),
+    UnitVariant(UnitVariant),
     Unknown,
 }
 
@@ -85,10 +92,12 @@ pub enum PackedFileType {
     Loc,
     MatchedCombat,
     RigidModel,
+    SoundBank,
     StarPos,
 
     /// This one is an exception, as it contains the MimeType of the Text PackedFile, so we can do things depending on the type.
     Text(TextType),
+    UnitVariant,
 
     /// This one is special. It's used just in case we want to open the Dependency PackFile List as a PackedFile.
     DependencyPackFilesList,
@@ -185,6 +194,18 @@ impl DecodedPackedFile {
                 }
             }
 
+            PackedFileType::RigidModel => {
+                let data = raw_packed_file.get_data_and_keep_it()?;
+                let packed_file = RigidModel::read(&data)?;
+                Ok(DecodedPackedFile::RigidModel(packed_file))
+            }
+
+            PackedFileType::SoundBank => {
+                let data = raw_packed_file.get_data()?;
+                let packed_file = SoundBank::read(&data)?;
+                Ok(DecodedPackedFile::SoundBank(packed_file))
+            }
+
             PackedFileType::Text(_) => {
                 let data = raw_packed_file.get_data_and_keep_it()?;
                 let mut packed_file = Text::read(&data)?;
@@ -194,6 +215,12 @@ impl DecodedPackedFile {
                 }
                 Ok(DecodedPackedFile::Text(packed_file))
             }
+
+            PackedFileType::UnitVariant => {
+                let data = raw_packed_file.get_data_and_keep_it()?;
+                let packed_file = UnitVariant::read(&data)?;
+                Ok(DecodedPackedFile::UnitVariant(packed_file))
+            }
             _=> Ok(DecodedPackedFile::Unknown)
         }
     }
@@ -239,7 +266,10 @@ impl DecodedPackedFile {
                 Ok(DecodedPackedFile::MatchedCombat(packed_file))
             }
 
+            PackedFileType::RigidModel => Self::decode(raw_packed_file),
+            PackedFileType::SoundBank => Self::decode(raw_packed_file),
             PackedFileType::Text(_) => Self::decode(raw_packed_file),
+            PackedFileType::UnitVariant => Self::decode(raw_packed_file),
             _=> Ok(DecodedPackedFile::Unknown)
         }
     }
@@ -256,7 +286,9 @@ impl DecodedPackedFile {
             DecodedPackedFile::DB(data) => Some(data.save()),
             DecodedPackedFile::Loc(data) => Some(data.save()),
             DecodedPackedFile::MatchedCombat(data) => Some(data.save()),
+            DecodedPackedFile::RigidModel(data) => Some(data.save()),
             DecodedPackedFile::Text(data) => Some(data.save()),
+            DecodedPackedFile::UnitVariant(data) => Some(data.save()),
             _=> None,
         }
     }
@@ -316,8 +348,10 @@ impl Display for PackedFileType {
             PackedFileType::Loc => write!(f, "Loc Table"),
             PackedFileType::MatchedCombat => write!(f, "Matched Combat"),
             PackedFileType::RigidModel => write!(f, "RigidModel"),
+            PackedFileType::SoundBank => write!(f, "SoundBank"),
             PackedFileType::StarPos => write!(f, "StartPos"),
             PackedFileType::Text(text_type) => write!(f, "Text, type: {:?}", text_type),
+            PackedFileType::UnitVariant => write!(f, "UnitVariant"),
             PackedFileType::Unknown => write!(f, "Unknown"),
         }
     }
@@ -332,7 +366,9 @@ impl PackedFileType {
             if packedfile_name.ends_with(table::loc::EXTENSION) { Self::Loc }
             else if packedfile_name.ends_with(animpack::EXTENSION) { Self::AnimPack }
             else if packedfile_name.ends_with(rigidmodel::EXTENSION) { Self::RigidModel }
+            else if packedfile_name.ends_with(unit_variant::EXTENSION) { Self::UnitVariant }
             else if packedfile_name.ends_with(ca_vp8::EXTENSION) { Self::CaVp8 }
+            else if packedfile_name.ends_with(sound_bank::EXTENSION) { Self::SoundBank }
             else if packedfile_name.ends_with(table::anim_fragment::EXTENSION) { Self::AnimFragment }
             else if path == table::animtable::PATH { Self::AnimTable }
             else if path == table::matched_combat::PATH { Self::MatchedCombat }
@@ -368,9 +404,15 @@ impl PackedFileType {
                     else if packedfile_name.ends_with(table::anim_fragment::EXTENSION) {
                         return Self::AnimFragment;
                     }
+                    else if packedfile_name.ends_with(unit_variant::EXTENSION) {
+                        return Self::UnitVariant
+                    }
                     else if packedfile_name.ends_with(animpack::EXTENSION) {
                         return Self::AnimPack
                     }
+                    else if packedfile_name.ends_with(sound_bank::EXTENSION) {
+                        return Self::SoundBank
+                    }
                     else if packed_file.get_path() == table::animtable::PATH {
                         return Self::AnimTable
                     }
@@ -417,7 +459,9 @@ impl PackedFileType {
             Self::Loc |
             Self::MatchedCombat |
             Self::RigidModel |
+            Self::SoundBank |
             Self::StarPos |
+            Self::UnitVariant |
             Self::Unknown => self == other,
             Self::Text(_) => if let Self::Text(_) = other { true } else { false },
         }
@@ -442,7 +486,9 @@ impl PackedFileType {
             Self::Loc |
             Self::MatchedCombat |
             Self::RigidModel |
+            Self::SoundBank |
             Self::StarPos |
+            Self::UnitVariant |
             Self::Unknown => others.contains(&self),
             Self::Text(_) => others.iter().any(|x| if let Self::Text(_) = x { true } else { false }),
         }
@@ -465,8 +511,10 @@ impl From<&DecodedPackedFile> for PackedFileType {
             DecodedPackedFile::Loc(_) => PackedFileType::Loc,
             DecodedPackedFile::MatchedCombat(_) => PackedFileType::MatchedCombat,
             DecodedPackedFile::RigidModel(_) => PackedFileType::RigidModel,
+            DecodedPackedFile::SoundBank(_) => PackedFileType::SoundBank,
             DecodedPackedFile::StarPos => PackedFileType::StarPos,
             DecodedPackedFile::Text(text) => PackedFileType::Text(text.get_text_type()),
+            DecodedPackedFile::UnitVariant(_) => PackedFileType::UnitVariant,
             DecodedPackedFile::Unknown => PackedFileType::Unknown,
         }
     }
@@ -14,26 +14,66 @@ Module with all the code to interact with RigidModel PackedFiles.
 RigidModel PackedFiles are 3D models used by Total War games since Empire.
 This is basically a rewrite in Rust of the work done by Phazer on his tool.
 Because I want to avoid more C++ libs if posible.
+
+We only decode the header, the lods and the metadata of each mesh (name, texture directory and textures).
+The geometry of the meshes is kept as raw data, and the editable fields are written back in place when saving,
+so files we cannot fully understand are still saved byte-by-byte as they were read.
 !*/
 
 use serde_derive::{Serialize, Deserialize};
 
+use rpfm_error::{ErrorKind, Result};
+
+use crate::common::{decoder::Decoder, encoder::Encoder};
+
+#[cfg(test)]
+mod rigidmodel_test;
+
 /// This represents the value that every RigidModel PackedFile has in their 0-4 bytes. A.k.a it's signature or preamble.
-#[allow(dead_code)]
 const PACKED_FILE_TYPE: &str = "RMV2";
 
 /// Extension used by RigidModel PackedFiles.
 pub const EXTENSION: &str = ".rigid_model_v2";
 
+/// Length of the header of a RigidModel, including the signature and the skeleton id.
+const HEADER_LENGTH: usize = 140;
+
+/// Length of the skeleton id in the header.
+const SKELETON_ID_LENGTH: usize = 128;
+
+/// Length of the header of a lod in Attila or older games (v6).
+const LOD_HEADER_LENGTH_V6: usize = 20;
+
+/// Length of the header of a lod in newer games (v7 and v8).
+const LOD_HEADER_LENGTH_V7: usize = 28;
+
+/// Offsets and lengths of the metadata we decode from each mesh, relative to the start of the mesh.
+const MESH_SHADER_NAME_OFFSET: usize = 48;
+const MESH_SHADER_NAME_LENGTH: usize = 12;
+const MESH_NAME_OFFSET: usize = 82;
+const MESH_NAME_LENGTH: usize = 32;
+const MESH_TEXTURE_DIRECTORY_OFFSET: usize = 114;
+const MESH_TEXTURE_DIRECTORY_LENGTH: usize = 256;
+const MESH_ATTACHMENT_POINTS_COUNT_OFFSET: usize = 792;
+const MESH_TEXTURES_COUNT_OFFSET: usize = 796;
+const MESH_ATTACHMENT_POINTS_OFFSET: usize = 940;
+const MESH_ATTACHMENT_POINT_LENGTH: usize = 84;
+const MESH_TEXTURE_LENGTH: usize = 260;
+const MESH_TEXTURE_PATH_LENGTH: usize = 256;
+
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
 
 /// This struct contains a RigidModel decoded in memory.
-#[derive(Clone, Debug,PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct RigidModel {
-    pub header: Header,
-    //pub packed_file_data: RigidModelData,
+
+    /// The header of the RigidModel.
+    header: Header,
+
+    /// The lods of the RigidModel, from the most detailed to the least detailed one.
+    lods: Vec<Lod>,
 }
 
 /// This struct represents the header of a RigidModel.
@@ -47,297 +87,453 @@ pub struct Header {
     version: u32,
 
     /// The skeleton used by this RigidModel.
-    skeleton_id: Vec<u8>,
+    skeleton_id: String,
 }
 
-//---------------------------------------------------------------------------//
-//                              Implementations
-//---------------------------------------------------------------------------//
+/// This struct represents a lod of a RigidModel.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lod {
 
-/// Implementation of RigidModel.
-impl RigidModel {
+    /// Length of the vertices data of all the meshes of this lod.
+    vertices_data_length: u32,
 
-    /// This function creates a new empty `Decal` RigidModel.
-    pub fn new_decal() -> Self {
-        Self {
-            header: Header::default(),
-        }
-    }
+    /// Length of the indices data of all the meshes of this lod.
+    indices_data_length: u32,
+
+    /// Offset from the start of the file where the meshes of this lod start.
+    start_offset: u32,
+
+    /// Distance from the camera at which this lod starts being used.
+    visibility_distance: f32,
+
+    /// Number of this lod, as authored. Only in v7 or newer.
+    authored_lod_number: Option<u32>,
+
+    /// Quality level of this lod. Only in v7 or newer.
+    quality_level: Option<u32>,
+
+    /// The meshes of this lod.
+    meshes: Vec<Mesh>,
 }
-/*
-/// Struct "RigidModelHeader". For more info about this, check the comment at the start of "packedfile/
-/// rigidmodel/mod.rs".
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct RigidModelHeader {
-    pub packed_file_header_signature: String,
-    pub packed_file_header_model_type: u32,
-    pub packed_file_header_lods_count: u32,
-    pub packed_file_data_base_skeleton: (String, usize),
+
+/// This struct represents a mesh of a lod.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Mesh {
+
+    /// Type of the material used by this mesh.
+    material_type: u16,
+
+    /// Amount of vertices of this mesh.
+    vertices_count: u32,
+
+    /// Amount of indices of this mesh.
+    indices_count: u32,
+
+    /// Name of the shader used by this mesh.
+    shader_name: String,
+
+    /// Decoded metadata of the material of the mesh. None if we don't know how to decode the material of this mesh.
+    material: Option<Material>,
+
+    /// Raw data of the entire mesh. The material data gets written over it when saving.
+    data: Vec<u8>,
 }
 
-/// Struct "RigidModelData". For more info about this, check the comment at the start of "packedfile/
-/// rigidmodel/mod.rs".
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct RigidModelData {
-    pub packed_file_data_lods_header: Vec<RigidModelLodHeader>,
-    pub packed_file_data_lods_data: Vec<u8>,
+/// This struct represents the metadata we can edit of the material of a mesh.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Material {
+
+    /// Name of the mesh.
+    name: String,
+
+    /// Directory containing the textures of the mesh.
+    texture_directory: String,
+
+    /// Amount of attachment points of the mesh. We need it to find the textures.
+    attachment_points_count: u32,
+
+    /// Textures used by the mesh.
+    textures: Vec<Texture>,
 }
 
-/// Struct "RigidModelLodHeader". For more info about this, check the comment at the start of "packedfile/
-/// rigidmodel/mod.rs".
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct RigidModelLodHeader {
-    pub groups_count: u32,
-    pub vertices_data_length: u32,
-    pub indices_data_length: u32,
-    pub start_offset: u32,
-    pub lod_zoom_factor: f32,
-    pub mysterious_data_1: Option<u32>,
-    pub mysterious_data_2: Option<u32>,
+/// This struct represents a texture used by a mesh.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Texture {
+
+    /// Type of the texture (diffuse, normal, mask,...).
+    texture_type: u32,
+
+    /// Path of the texture within the game's files.
+    path: String,
 }
 
-/// Implementation of "RigidModel"
-impl RigidModel {
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
 
-    /// This function reads the data from a Vec<u8> and decode it into a RigidModel. This CAN FAIL,
-    /// so we return Result<RigidModel, Error>.
-    pub fn read(packed_file_data: &[u8]) -> Result<RigidModel> {
-        let packed_file_header = RigidModelHeader::read(&packed_file_data[..140])?;
-        let packed_file_data = RigidModelData::read(&packed_file_data[140..], packed_file_header.packed_file_header_model_type, packed_file_header.packed_file_header_lods_count)?;
+/// Implementation of RigidModel.
+impl RigidModel {
 
-        Ok(RigidModel {
-            packed_file_header,
-            packed_file_data,
-        })
+    /// This function creates a new empty `Decal` RigidModel.
+    pub fn new_decal() -> Self {
+        Self::default()
     }
 
-    /// This function reads the data from a RigidModel and encode it into a Vec<u8>. This CAN FAIL,
-    /// so we return Result<Vec<u8>, Error>.
-    pub fn save(rigid_model_data: &RigidModel) -> Result<Vec<u8>> {
-        let mut packed_file_data_encoded = RigidModelData::save(&rigid_model_data.packed_file_data);
-        let mut packed_file_header_encoded = RigidModelHeader::save(&rigid_model_data.packed_file_header)?;
-
-        let mut packed_file_encoded = vec![];
-        packed_file_encoded.append(&mut packed_file_header_encoded);
-        packed_file_encoded.append(&mut packed_file_data_encoded);
-
-        Ok(packed_file_encoded)
-    }
-
-    /// This function is used to patch a RigidModel 3D model from Total War: Attila to work in Total War:
-    /// Warhammer 1 and 2. The process to patch a RigidModel is simple:
-    /// - We update the version of the RigidModel from 6(Attila) to 7(Warhammer 1&2).
-    /// - We add 2 u32 to the Lods: a counter starting at 0, and a 0.
-    /// - We increase the start_offset of every Lod by (8*amount_of_lods).
-    /// - We may need to increase the zoom_factor of the first Lod to 1000.0, because otherwise sometimes the models
-    ///   disappear when you move the camera far from them.
-    /// It requires a mut ref to a decoded PackFile, and returns an String (Result<Success, Error>).
-    pub fn patch_rigid_model_attila_to_warhammer (&mut self) -> Result<String> {
-
-        // If the RigidModel is an Attila RigidModel, we continue. Otherwise, return Error.
-        match self.packed_file_header.packed_file_header_model_type {
-            6 => {
-                // We update his version.
-                self.packed_file_header.packed_file_header_model_type = 7;
-
-                // Next, we change the needed data for every Lod.
-                for (index, lod) in self.packed_file_data.packed_file_data_lods_header.iter_mut().enumerate() {
-                    lod.mysterious_data_1 = Some(index as u32);
-                    lod.mysterious_data_2 = Some(0);
-                    lod.start_offset += 8 * self.packed_file_header.packed_file_header_lods_count;
-                }
-                Ok("RigidModel patched succesfully.".to_owned())
-            },
-            7 => Err(ErrorKind::RigidModelPatchToWarhammer("This is not an Attila's RigidModel, but a Warhammer one.".to_owned()).into()),
-            _ => Err(ErrorKind::RigidModelPatchToWarhammer("I don't even know from what game is this RigidModel.".to_owned()).into()),
+    /// This function creates a `RigidModel` from a `&[u8]`.
+    pub fn read(packed_file_data: &[u8]) -> Result<Self> {
+        if packed_file_data.decode_string_u8(0, 4)? != PACKED_FILE_TYPE {
+            return Err(ErrorKind::RigidModelNotSupportedFile.into());
         }
-    }
-}
-
-/// Implementation of "RigidModelHeader"
-impl RigidModelHeader {
 
-    /// This function reads the data from a Vec<u8> and decode it into a RigidModelHeader. This CAN FAIL,
-    /// so we return Result<RigidModelHeader, Error>.
-    pub fn read(packed_file_data: &[u8]) -> Result<RigidModelHeader> {
+        let version = packed_file_data.decode_integer_u32(4)?;
+        let lod_header_length = match version {
+            6 => LOD_HEADER_LENGTH_V6,
+            7 | 8 => LOD_HEADER_LENGTH_V7,
+            _ => return Err(ErrorKind::RigidModelNotSupportedType.into()),
+        };
 
-        let mut packed_file_header = RigidModelHeader {
-            packed_file_header_signature: String::new(),
-            packed_file_header_model_type: 0,
-            packed_file_header_lods_count: 0,
-            packed_file_data_base_skeleton: (String::new(), 0),
+        let lods_count = packed_file_data.decode_integer_u32(8)?;
+        let (skeleton_id, _) = packed_file_data.decode_string_u8_0padded(12, SKELETON_ID_LENGTH)?;
+        let header = Header {
+            version,
+            skeleton_id,
         };
 
-        match packed_file_data.decode_string_u8(0, 4) {
-            Ok(data) => packed_file_header.packed_file_header_signature = data,
-            Err(error) => return Err(error)
+        // First, the header of every lod.
+        let mut lods = vec![];
+        let mut meshes_counts = vec![];
+        let mut index = HEADER_LENGTH;
+        for _ in 0..lods_count {
+            let lod_header = packed_file_data.get_bytes_checked(index, lod_header_length)?;
+            meshes_counts.push(lod_header.decode_integer_u32(0)?);
+            let (authored_lod_number, quality_level) = if lod_header_length == LOD_HEADER_LENGTH_V7 {
+                (Some(lod_header.decode_integer_u32(20)?), Some(lod_header.decode_integer_u32(24)?))
+            } else { (None, None) };
+
+            lods.push(Lod {
+                vertices_data_length: lod_header.decode_integer_u32(4)?,
+                indices_data_length: lod_header.decode_integer_u32(8)?,
+                start_offset: lod_header.decode_integer_u32(12)?,
+                visibility_distance: lod_header.decode_float_f32(16)?,
+                authored_lod_number,
+                quality_level,
+                meshes: vec![],
+            });
+            index += lod_header_length;
         }
 
-        // We check this, just in case we try to read some malformed file with a string in the first
-        // four bytes (which is not uncommon).
-        if packed_file_header.packed_file_header_signature != "RMV2" {
-            return Err(ErrorKind::RigidModelNotSupportedFile.into())
+        // Then, the meshes of every lod. We need them one after another, or we will not be able to save them back.
+        for (lod, meshes_count) in lods.iter_mut().zip(meshes_counts.iter()) {
+            if lod.start_offset as usize != index {
+                return Err(ErrorKind::RigidModelDecode(format!("The meshes of the lod start at {}, but the previous lod ends at {}.", lod.start_offset, index)).into());
+            }
+
+            for _ in 0..*meshes_count {
+                let mesh_length = packed_file_data.decode_integer_u32(index + 4)? as usize;
+                let mesh = Mesh::read(packed_file_data.get_bytes_checked(index, mesh_length)?)?;
+                lod.meshes.push(mesh);
+                index += mesh_length;
+            }
         }
 
-        match packed_file_data.decode_integer_u32(4) {
-            Ok(data) => packed_file_header.packed_file_header_model_type = data,
-            Err(error) => return Err(error)
+        if index != packed_file_data.len() {
+            return Err(ErrorKind::RigidModelDecode(format!("{} bytes of data left undecoded at the end of the file.", packed_file_data.len() - index)).into());
         }
 
-        match packed_file_data.decode_integer_u32(8) {
-            Ok(data) => packed_file_header.packed_file_header_lods_count = data,
-            Err(error) => return Err(error)
+        Ok(Self {
+            header,
+            lods,
+        })
+    }
+
+    /// This function takes a `RigidModel` and encodes it to `Vec<u8>`.
+    pub fn save(&self) -> Result<Vec<u8>> {
+        let mut data = vec![];
+        data.encode_string_u8(PACKED_FILE_TYPE);
+        data.encode_integer_u32(self.header.version);
+        data.encode_integer_u32(self.lods.len() as u32);
+        data.encode_string_u8_0padded(&(self.header.skeleton_id.to_owned(), SKELETON_ID_LENGTH))?;
+
+        for lod in &self.lods {
+            data.encode_integer_u32(lod.meshes.len() as u32);
+            data.encode_integer_u32(lod.vertices_data_length);
+            data.encode_integer_u32(lod.indices_data_length);
+            data.encode_integer_u32(lod.start_offset);
+            data.encode_float_f32(lod.visibility_distance);
+
+            if self.header.version >= 7 {
+                data.encode_integer_u32(lod.authored_lod_number.unwrap_or_default());
+                data.encode_integer_u32(lod.quality_level.unwrap_or_default());
+            }
         }
 
-        match packed_file_data.decode_string_u8_0padded(12, 128) {
-            Ok(data) => packed_file_header.packed_file_data_base_skeleton = data,
-            Err(error) => return Err(error)
+        for lod in &self.lods {
+            for mesh in &lod.meshes {
+                data.extend_from_slice(&mesh.save()?);
+            }
         }
-        Ok(packed_file_header)
+
+        Ok(data)
     }
 
-    /// This function reads the data from a RigidModelHeader and encode it into a Vec<u8>. This CAN FAIL,
-    /// so we return Result<Vec<u8>, Error>.
-    pub fn save(rigid_model_header: &RigidModelHeader) -> Result<Vec<u8>> {
-        let mut packed_file_data: Vec<u8> = vec![];
+    /// This function returns the version of the RigidModel.
+    pub fn get_version(&self) -> u32 {
+        self.header.version
+    }
+
+    /// This function returns the skeleton id of the RigidModel.
+    pub fn get_ref_skeleton_id(&self) -> &str {
+        &self.header.skeleton_id
+    }
+
+    /// This function sets the skeleton id of the RigidModel.
+    pub fn set_skeleton_id(&mut self, skeleton_id: &str) -> Result<()> {
+        check_length(skeleton_id, SKELETON_ID_LENGTH)?;
+        self.header.skeleton_id = skeleton_id.to_owned();
+        Ok(())
+    }
 
-        packed_file_data.encode_string_u8(&rigid_model_header.packed_file_header_signature);
-        packed_file_data.encode_integer_u32(rigid_model_header.packed_file_header_model_type);
-        packed_file_data.encode_integer_u32(rigid_model_header.packed_file_header_lods_count);
-        packed_file_data.encode_string_u8_0padded(&rigid_model_header.packed_file_data_base_skeleton)?;
+    /// This function returns the lods of the RigidModel.
+    pub fn get_ref_lods(&self) -> &[Lod] {
+        &self.lods
+    }
 
-        Ok(packed_file_data)
+    /// This function returns a mutable reference to the lods of the RigidModel.
+    pub fn get_ref_mut_lods(&mut self) -> &mut [Lod] {
+        &mut self.lods
     }
 }
 
-/// Implementation of "RigidModelData"
-impl RigidModelData {
-
-    /// This function reads the data from a Vec<u8> and decode it into a RigidModelData. This CAN FAIL,
-    /// so we return Result<RigidModelData, Error>.
-    pub fn read(packed_file_data: &[u8], packed_file_header_model_type: u32, packed_file_header_lods_count: u32) -> Result<RigidModelData> {
-        let mut packed_file_data_lods_header: Vec<RigidModelLodHeader> = vec![];
-        let mut index: usize = 0;
-        let offset: usize = match packed_file_header_model_type {
-            6 => 20, // Attila
-            7 => 28, // Warhammer 1&2
-            _ => return Err(ErrorKind::RigidModelNotSupportedType.into())
-        };
+/// Implementation of `Lod`.
+impl Lod {
 
-        // We get the "headers" of every lod.
-        for _ in 0..packed_file_header_lods_count {
-            let lod_header = match RigidModelLodHeader::read(&packed_file_data[index..(index + offset)]) {
-                Ok(data) => data,
-                Err(error) => return Err(error)
-            };
-            packed_file_data_lods_header.push(lod_header);
-            index += offset;
-        }
+    /// This function returns the distance from the camera at which this lod starts being used.
+    pub fn get_visibility_distance(&self) -> f32 {
+        self.visibility_distance
+    }
+
+    /// This function sets the distance from the camera at which this lod starts being used.
+    pub fn set_visibility_distance(&mut self, visibility_distance: f32) {
+        self.visibility_distance = visibility_distance;
+    }
+
+    /// This function returns the authored number of this lod, if the RigidModel has it.
+    pub fn get_authored_lod_number(&self) -> Option<u32> {
+        self.authored_lod_number
+    }
+
+    /// This function returns the quality level of this lod, if the RigidModel has it.
+    pub fn get_quality_level(&self) -> Option<u32> {
+        self.quality_level
+    }
+
+    /// This function returns the meshes of this lod.
+    pub fn get_ref_meshes(&self) -> &[Mesh] {
+        &self.meshes
+    }
 
-        let packed_file_data_lods_data = packed_file_data[index..].to_vec();
+    /// This function returns a mutable reference to the meshes of this lod.
+    pub fn get_ref_mut_meshes(&mut self) -> &mut [Mesh] {
+        &mut self.meshes
+    }
+}
 
-        Ok(RigidModelData {
-            packed_file_data_lods_header,
-            packed_file_data_lods_data,
+/// Implementation of `Mesh`.
+impl Mesh {
+
+    /// This function creates a `Mesh` from the raw data of the mesh.
+    fn read(data: &[u8]) -> Result<Self> {
+        let vertices_offset = data.decode_integer_u32(8)? as usize;
+        let (shader_name, _) = data.decode_string_u8_0padded(MESH_SHADER_NAME_OFFSET, MESH_SHADER_NAME_LENGTH)?;
+
+        Ok(Self {
+            material_type: data.decode_integer_u16(0)?,
+            vertices_count: data.decode_integer_u32(12)?,
+            indices_count: data.decode_integer_u32(20)?,
+            shader_name,
+            material: Material::read(data, vertices_offset),
+            data: data.to_vec(),
         })
     }
 
-    /// This function reads the data from a RigidModelData and encode it into a Vec<u8>. This CAN FAIL,
-    /// so we return Result<Vec<u8>, Error>.
-    pub fn save(rigid_model_data: &RigidModelData) -> Vec<u8> {
-        let mut packed_file_data = vec![];
+    /// This function returns the raw data of the mesh, with the material data written over it.
+    fn save(&self) -> Result<Vec<u8>> {
+        let mut data = self.data.to_vec();
+        if let Some(ref material) = self.material {
+            write_string_0padded(&mut data, MESH_NAME_OFFSET, &material.name, MESH_NAME_LENGTH)?;
+            write_string_0padded(&mut data, MESH_TEXTURE_DIRECTORY_OFFSET, &material.texture_directory, MESH_TEXTURE_DIRECTORY_LENGTH)?;
 
-        // For each Lod, we save it, and add it to the "Encoded Data" vector. After that, we add to that
-        // vector the extra data, and return it.
-        for lod in &rigid_model_data.packed_file_data_lods_header {
-            packed_file_data.append(&mut RigidModelLodHeader::save(lod));
+            let textures_offset = MESH_ATTACHMENT_POINTS_OFFSET + material.attachment_points_count as usize * MESH_ATTACHMENT_POINT_LENGTH;
+            for (index, texture) in material.textures.iter().enumerate() {
+                write_string_0padded(&mut data, textures_offset + index * MESH_TEXTURE_LENGTH + 4, &texture.path, MESH_TEXTURE_PATH_LENGTH)?;
+            }
         }
+        Ok(data)
+    }
+
+    /// This function returns the type of the material used by this mesh.
+    pub fn get_material_type(&self) -> u16 {
+        self.material_type
+    }
+
+    /// This function returns the amount of vertices of this mesh.
+    pub fn get_vertices_count(&self) -> u32 {
+        self.vertices_count
+    }
+
+    /// This function returns the amount of indices of this mesh.
+    pub fn get_indices_count(&self) -> u32 {
+        self.indices_count
+    }
+
+    /// This function returns the name of the shader used by this mesh.
+    pub fn get_ref_shader_name(&self) -> &str {
+        &self.shader_name
+    }
+
+    /// This function returns the material metadata of this mesh, if we could decode it.
+    pub fn get_ref_material(&self) -> &Option<Material> {
+        &self.material
+    }
 
-        packed_file_data.extend_from_slice(&rigid_model_data.packed_file_data_lods_data);
-        packed_file_data
+    /// This function returns a mutable reference to the material metadata of this mesh, if we could decode it.
+    pub fn get_ref_mut_material(&mut self) -> &mut Option<Material> {
+        &mut self.material
     }
 }
 
-/// Implementation of "RigidModelLodHeader"
-impl RigidModelLodHeader {
-
-    /// This function reads the data from a Vec<u8> and decode it into a RigidModelLodHeader. This CAN FAIL,
-    /// so we return Result<RigidModelLodHeader, Error>.
-    pub fn read(packed_file_data: &[u8]) -> Result<RigidModelLodHeader> {
-        let mut header = RigidModelLodHeader {
-            groups_count: 0,
-            vertices_data_length: 0,
-            indices_data_length: 0,
-            start_offset: 0,
-            lod_zoom_factor: 0.0,
-            mysterious_data_1: None,
-            mysterious_data_2: None,
-        };
-        match packed_file_data.decode_integer_u32(0) {
-            Ok(data) => header.groups_count = data,
-            Err(error) => return Err(error)
-        }
-        match packed_file_data.decode_integer_u32(4) {
-            Ok(data) => header.vertices_data_length = data,
-            Err(error) => return Err(error)
-        }
-        match packed_file_data.decode_integer_u32(8) {
-            Ok(data) => header.indices_data_length = data,
-            Err(error) => return Err(error)
-        }
-        match packed_file_data.decode_integer_u32(12) {
-            Ok(data) => header.start_offset = data,
-            Err(error) => return Err(error)
-        }
-        match packed_file_data.decode_float_f32(16) {
-            Ok(data) => header.lod_zoom_factor = data,
-            Err(error) => return Err(error)
+/// Implementation of `Material`.
+impl Material {
+
+    /// This function tries to decode the material of a mesh, returning None if it doesn't look like a material we know.
+    fn read(data: &[u8], vertices_offset: usize) -> Option<Self> {
+        let (name, _) = data.decode_string_u8_0padded(MESH_NAME_OFFSET, MESH_NAME_LENGTH).ok()?;
+        let (texture_directory, _) = data.decode_string_u8_0padded(MESH_TEXTURE_DIRECTORY_OFFSET, MESH_TEXTURE_DIRECTORY_LENGTH).ok()?;
+        let attachment_points_count = data.decode_integer_u32(MESH_ATTACHMENT_POINTS_COUNT_OFFSET).ok()?;
+        let textures_count = data.decode_integer_u32(MESH_TEXTURES_COUNT_OFFSET).ok()?;
+
+        // If the textures do not fit before the vertices, this is not a material we know how to decode.
+        let textures_offset = MESH_ATTACHMENT_POINTS_OFFSET.checked_add((attachment_points_count as usize).checked_mul(MESH_ATTACHMENT_POINT_LENGTH)?)?;
+        let textures_end = textures_offset.checked_add((textures_count as usize).checked_mul(MESH_TEXTURE_LENGTH)?)?;
+        if textures_end > vertices_offset || vertices_offset > data.len() {
+            return None;
         }
 
-        // These two we only decode them if the RigidModel is v7 (Warhammer 1&2), as these doesn't exist
-        // in Attila's RigidModels.
-        if packed_file_data.len() == 28 {
-            match packed_file_data.decode_integer_u32(20) {
-                Ok(data) => header.mysterious_data_1 = Some(data),
-                Err(error) => return Err(error)
-            }
-            match packed_file_data.decode_integer_u32(24) {
-                Ok(data) => header.mysterious_data_2 = Some(data),
-                Err(error) => return Err(error)
-            }
+        let mut textures = vec![];
+        for index in 0..textures_count as usize {
+            let offset = textures_offset + index * MESH_TEXTURE_LENGTH;
+            let texture_type = data.decode_integer_u32(offset).ok()?;
+            let (path, _) = data.decode_string_u8_0padded(offset + 4, MESH_TEXTURE_PATH_LENGTH).ok()?;
+            textures.push(Texture {
+                texture_type,
+                path,
+            });
         }
 
-        Ok(header)
+        Some(Self {
+            name,
+            texture_directory,
+            attachment_points_count,
+            textures,
+        })
+    }
+
+    /// This function returns the name of the mesh.
+    pub fn get_ref_name(&self) -> &str {
+        &self.name
     }
 
-    /// This function reads the data from a RigidModelLodHeader and encode it into a Vec<u8>.
-    pub fn save(rigid_model_lod: &RigidModelLodHeader) -> Vec<u8> {
-        let mut packed_file_data: Vec<u8> = vec![];
+    /// This function sets the name of the mesh.
+    pub fn set_name(&mut self, name: &str) -> Result<()> {
+        check_length(name, MESH_NAME_LENGTH)?;
+        self.name = name.to_owned();
+        Ok(())
+    }
 
-        packed_file_data.encode_integer_u32(rigid_model_lod.groups_count);
-        packed_file_data.encode_integer_u32(rigid_model_lod.vertices_data_length);
-        packed_file_data.encode_integer_u32(rigid_model_lod.indices_data_length);
-        packed_file_data.encode_integer_u32(rigid_model_lod.start_offset);
-        packed_file_data.encode_float_f32(rigid_model_lod.lod_zoom_factor);
+    /// This function returns the texture directory of the mesh.
+    pub fn get_ref_texture_directory(&self) -> &str {
+        &self.texture_directory
+    }
 
-        let mysterious_data_1 = match rigid_model_lod.mysterious_data_1 {
-            Some(data) => Some(data),
-            None => None,
-        };
+    /// This function sets the texture directory of the mesh.
+    pub fn set_texture_directory(&mut self, texture_directory: &str) -> Result<()> {
+        check_length(texture_directory, MESH_TEXTURE_DIRECTORY_LENGTH)?;
+        self.texture_directory = texture_directory.to_owned();
+        Ok(())
+    }
 
-        let mysterious_data_2 = match rigid_model_lod.mysterious_data_2 {
-            Some(data) => Some(data),
-            None => None,
-        };
+    /// This function returns the textures of the mesh.
+    pub fn get_ref_textures(&self) -> &[Texture] {
+        &self.textures
+    }
 
-        // These two are only added if they are something (Warhammer1&2 RigidModels).
-        if mysterious_data_1 != None {
-            packed_file_data.encode_integer_u32(mysterious_data_1.unwrap());
-        }
-        if mysterious_data_2 != None {
-            packed_file_data.encode_integer_u32(mysterious_data_2.unwrap());
+    /// This function returns a mutable reference to the textures of the mesh.
+    pub fn get_ref_mut_textures(&mut self) -> &mut [Texture] {
+        &mut self.textures
+    }
+}
+
+/// Implementation of `Texture`.
+impl Texture {
+
+    /// This function returns the type of the texture.
+    pub fn get_texture_type(&self) -> u32 {
+        self.texture_type
+    }
+
+    /// This function returns the name of the type of the texture, if it's a known one.
+    pub fn get_texture_type_name(&self) -> Option<&'static str> {
+        match self.texture_type {
+            0 => Some("Diffuse"),
+            1 => Some("Normal"),
+            3 => Some("Mask"),
+            5 => Some("Ambient Occlusion"),
+            6 => Some("Tiling Dirt UV2"),
+            7 => Some("Dirt Alpha Mask"),
+            8 => Some("Skin Mask"),
+            9 => Some("Specular"),
+            11 => Some("Gloss"),
+            12 => Some("Decal Dirtmap"),
+            13 => Some("Decal Dirtmask"),
+            14 => Some("Decal Mask"),
+            15 => Some("Diffuse Damage"),
+            27 => Some("Base Colour"),
+            29 => Some("Material Map"),
+            _ => None,
         }
-        packed_file_data
     }
+
+    /// This function returns the path of the texture.
+    pub fn get_ref_path(&self) -> &str {
+        &self.path
+    }
+
+    /// This function sets the path of the texture.
+    pub fn set_path(&mut self, path: &str) -> Result<()> {
+        check_length(path, MESH_TEXTURE_PATH_LENGTH)?;
+        self.path = path.to_owned();
+        Ok(())
+    }
+}
+
+/// This function checks that the provided string fits in a 0-padded string of the provided length.
+fn check_length(string: &str, length: usize) -> Result<()> {
+    if string.len() <= length { Ok(()) }
+    else { Err(ErrorKind::RigidModelDecode(format!("\"{}\" is {} bytes long, but it cannot be longer than {} bytes.", string, string.len(), length)).into()) }
+}
+
+/// This function writes the provided string as a 0-padded string of the provided length over the data, at the provided offset.
+///
+/// If the data already has that string there, it's left untouched, so any garbage after the string is kept.
+fn write_string_0padded(data: &mut [u8], offset: usize, string: &str, length: usize) -> Result<()> {
+    if data.decode_string_u8_0padded(offset, length).map_or(false, |(current, _)| current == string) {
+        return Ok(());
+    }
+
+    let mut encoded = vec![];
+    encoded.encode_string_u8_0padded(&(string.to_owned(), length))?;
+    data.get_mut(offset..offset + length)
+        .ok_or_else(|| ErrorKind::RigidModelDecode(format!("Cannot write \"{}\" at offset {}: the mesh is too short.", string, offset)))?
+        .copy_from_slice(&encoded);
+    Ok(())
 }
-*/
@@ -0,0 +1,176 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the RigidModel decoder and encoder.
+
+Each test builds a small RigidModel in memory, with fake geometry data after the metadata of each mesh.
+!*/
+
+use super::RigidModel;
+use super::{HEADER_LENGTH, LOD_HEADER_LENGTH_V7, SKELETON_ID_LENGTH};
+use super::{MESH_SHADER_NAME_OFFSET, MESH_SHADER_NAME_LENGTH, MESH_NAME_OFFSET, MESH_NAME_LENGTH, MESH_TEXTURE_DIRECTORY_OFFSET, MESH_TEXTURE_DIRECTORY_LENGTH};
+use super::{MESH_ATTACHMENT_POINTS_COUNT_OFFSET, MESH_TEXTURES_COUNT_OFFSET, MESH_ATTACHMENT_POINTS_OFFSET, MESH_ATTACHMENT_POINT_LENGTH, MESH_TEXTURE_LENGTH, MESH_TEXTURE_PATH_LENGTH};
+
+/// Length of the fake geometry data of each mesh.
+const GEOMETRY_LENGTH: usize = 16;
+
+/// This function returns the provided string as a 0-padded string of the provided length, with the provided garbage after the string.
+fn get_string_0padded(string: &str, garbage: &[u8], length: usize) -> Vec<u8> {
+    let mut data = string.as_bytes().to_vec();
+    data.push(0);
+    data.extend_from_slice(garbage);
+    data.resize(length, 0);
+    data
+}
+
+/// This function builds a mesh with the provided textures, one attachment point, and fake geometry.
+///
+/// The paths of the textures are already encoded, so they can have garbage after them.
+fn build_mesh(textures: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let vertices_offset = MESH_ATTACHMENT_POINTS_OFFSET + MESH_ATTACHMENT_POINT_LENGTH + textures.len() * MESH_TEXTURE_LENGTH;
+    let mut data = vec![0; vertices_offset];
+    data[0..2].copy_from_slice(&3u16.to_le_bytes());
+    data[4..8].copy_from_slice(&((vertices_offset + GEOMETRY_LENGTH) as u32).to_le_bytes());
+    data[8..12].copy_from_slice(&(vertices_offset as u32).to_le_bytes());
+    data[12..16].copy_from_slice(&4u32.to_le_bytes());
+    data[20..24].copy_from_slice(&6u32.to_le_bytes());
+    data[MESH_SHADER_NAME_OFFSET..MESH_SHADER_NAME_OFFSET + MESH_SHADER_NAME_LENGTH].copy_from_slice(&get_string_0padded("default", &[], MESH_SHADER_NAME_LENGTH));
+    data[MESH_NAME_OFFSET..MESH_NAME_OFFSET + MESH_NAME_LENGTH].copy_from_slice(&get_string_0padded("head", b"garbage", MESH_NAME_LENGTH));
+    data[MESH_TEXTURE_DIRECTORY_OFFSET..MESH_TEXTURE_DIRECTORY_OFFSET + MESH_TEXTURE_DIRECTORY_LENGTH].copy_from_slice(&get_string_0padded("variantmeshes/tex", &[], MESH_TEXTURE_DIRECTORY_LENGTH));
+    data[MESH_ATTACHMENT_POINTS_COUNT_OFFSET..MESH_ATTACHMENT_POINTS_COUNT_OFFSET + 4].copy_from_slice(&1u32.to_le_bytes());
+    data[MESH_TEXTURES_COUNT_OFFSET..MESH_TEXTURES_COUNT_OFFSET + 4].copy_from_slice(&(textures.len() as u32).to_le_bytes());
+
+    // The attachment point has data we don't decode, so it must be kept as it is.
+    data[MESH_ATTACHMENT_POINTS_OFFSET..MESH_ATTACHMENT_POINTS_OFFSET + MESH_ATTACHMENT_POINT_LENGTH].copy_from_slice(&[0xAB; MESH_ATTACHMENT_POINT_LENGTH]);
+
+    let textures_offset = MESH_ATTACHMENT_POINTS_OFFSET + MESH_ATTACHMENT_POINT_LENGTH;
+    for (index, (texture_type, path)) in textures.iter().enumerate() {
+        let offset = textures_offset + index * MESH_TEXTURE_LENGTH;
+        data[offset..offset + 4].copy_from_slice(&texture_type.to_le_bytes());
+        data[offset + 4..offset + MESH_TEXTURE_LENGTH].copy_from_slice(path);
+    }
+
+    data.extend((0..GEOMETRY_LENGTH as u8).collect::<Vec<u8>>());
+    data
+}
+
+/// This function builds a v7 RigidModel with a lod per provided mesh.
+fn build_rigid_model(meshes: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = b"RMV2".to_vec();
+    data.extend_from_slice(&7u32.to_le_bytes());
+    data.extend_from_slice(&(meshes.len() as u32).to_le_bytes());
+    data.extend_from_slice(&get_string_0padded("humanoid01", &[], SKELETON_ID_LENGTH));
+
+    let mut start_offset = HEADER_LENGTH + meshes.len() * LOD_HEADER_LENGTH_V7;
+    for (index, mesh) in meshes.iter().enumerate() {
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&(GEOMETRY_LENGTH as u32).to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&(start_offset as u32).to_le_bytes());
+        data.extend_from_slice(&(index as f32 * 100.0).to_le_bytes());
+        data.extend_from_slice(&(index as u32).to_le_bytes());
+        data.extend_from_slice(&(index as u32 + 1).to_le_bytes());
+        start_offset += mesh.len();
+    }
+
+    meshes.iter().for_each(|mesh| data.extend_from_slice(mesh));
+    data
+}
+
+/// This function returns the RigidModel used by these tests.
+///
+/// The first lod has a mesh with two textures, one of them with garbage after its path. The second one has a mesh
+/// whose textures don't fit before its geometry, so its material cannot be decoded.
+fn get_test_data() -> Vec<u8> {
+    let mesh = build_mesh(&[
+        (0, get_string_0padded("variantmeshes/tex/head_diffuse.dds", b"old_path.dds", MESH_TEXTURE_PATH_LENGTH)),
+        (1, get_string_0padded("variantmeshes/tex/head_normal.dds", &[], MESH_TEXTURE_PATH_LENGTH)),
+    ]);
+
+    let mut unknown_mesh = build_mesh(&[]);
+    unknown_mesh[MESH_TEXTURES_COUNT_OFFSET..MESH_TEXTURES_COUNT_OFFSET + 4].copy_from_slice(&1000u32.to_le_bytes());
+
+    build_rigid_model(&[mesh, unknown_mesh])
+}
+
+/// Test to make sure a RigidModel is decoded properly, and saved back byte by byte as it was.
+#[test]
+fn test_rigid_model_round_trip() {
+    let data = get_test_data();
+    let rigid_model = RigidModel::read(&data).unwrap();
+    assert_eq!(rigid_model.get_version(), 7);
+    assert_eq!(rigid_model.get_ref_skeleton_id(), "humanoid01");
+
+    let lods = rigid_model.get_ref_lods();
+    assert_eq!(lods.len(), 2);
+    assert_eq!(lods[1].get_visibility_distance(), 100.0);
+    assert_eq!(lods[1].get_authored_lod_number(), Some(1));
+    assert_eq!(lods[1].get_quality_level(), Some(2));
+
+    let mesh = &lods[0].get_ref_meshes()[0];
+    assert_eq!(mesh.get_material_type(), 3);
+    assert_eq!(mesh.get_vertices_count(), 4);
+    assert_eq!(mesh.get_indices_count(), 6);
+    assert_eq!(mesh.get_ref_shader_name(), "default");
+
+    let material = mesh.get_ref_material().as_ref().unwrap();
+    assert_eq!(material.get_ref_name(), "head");
+    assert_eq!(material.get_ref_texture_directory(), "variantmeshes/tex");
+    assert_eq!(material.get_ref_textures().iter().map(|x| (x.get_texture_type(), x.get_ref_path())).collect::<Vec<(u32, &str)>>(), vec![
+        (0, "variantmeshes/tex/head_diffuse.dds"),
+        (1, "variantmeshes/tex/head_normal.dds"),
+    ]);
+
+    assert!(lods[1].get_ref_meshes()[0].get_ref_material().is_none());
+    assert_eq!(rigid_model.save().unwrap(), data);
+}
+
+/// Test to make sure editing a texture path only changes the bytes of that path.
+#[test]
+fn test_rigid_model_edit_texture_path() {
+    let data = get_test_data();
+    let mut rigid_model = RigidModel::read(&data).unwrap();
+    let new_path = "variantmeshes/tex/head_normal_02.dds";
+    {
+        let material = rigid_model.get_ref_mut_lods()[0].get_ref_mut_meshes()[0].get_ref_mut_material().as_mut().unwrap();
+        assert!(material.get_ref_mut_textures()[1].set_path(&"a".repeat(MESH_TEXTURE_PATH_LENGTH + 1)).is_err());
+        material.get_ref_mut_textures()[1].set_path(new_path).unwrap();
+    }
+
+    let new_data = rigid_model.save().unwrap();
+    assert_eq!(new_data.len(), data.len());
+
+    // The path of the second texture of the first mesh.
+    let path_offset = HEADER_LENGTH + LOD_HEADER_LENGTH_V7 * 2 + MESH_ATTACHMENT_POINTS_OFFSET + MESH_ATTACHMENT_POINT_LENGTH + MESH_TEXTURE_LENGTH + 4;
+    assert_eq!(&new_data[path_offset..path_offset + MESH_TEXTURE_PATH_LENGTH], &get_string_0padded(new_path, &[], MESH_TEXTURE_PATH_LENGTH)[..]);
+    assert_eq!(&new_data[..path_offset], &data[..path_offset]);
+    assert_eq!(&new_data[path_offset + MESH_TEXTURE_PATH_LENGTH..], &data[path_offset + MESH_TEXTURE_PATH_LENGTH..]);
+
+    let rigid_model = RigidModel::read(&new_data).unwrap();
+    let material = rigid_model.get_ref_lods()[0].get_ref_meshes()[0].get_ref_material().as_ref().unwrap();
+    assert_eq!(material.get_ref_textures()[1].get_ref_path(), new_path);
+}
+
+/// Test to make sure files with unsupported versions, or with data we cannot place, are rejected.
+#[test]
+fn test_rigid_model_invalid() {
+    let mut data = get_test_data();
+    data.push(0);
+    assert!(RigidModel::read(&data).is_err());
+
+    let mut data = get_test_data();
+    data[4..8].copy_from_slice(&9u32.to_le_bytes());
+    assert!(RigidModel::read(&data).is_err());
+
+    let mut data = get_test_data();
+    data[..4].copy_from_slice(b"RMV1");
+    assert!(RigidModel::read(&data).is_err());
+}
@@ -0,0 +1,279 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to interact with SoundBank PackedFiles.
+
+SoundBanks are Wwise `.bnk` files. They're made of chunks, each one being a 4 bytes tag, followed by the size
+of the chunk and its data. We only read the ones we need to list their contents:
+
+- `BKHD`: the header, with the version and the id of the bank. It's always the first chunk.
+- `DIDX`: the index of the embedded WEMs, with their id, and their offset and size within the `DATA` chunk.
+- `DATA`: the data of the embedded WEMs.
+- `HIRC`: the hierarchy of objects of the bank, from where we get the ids of the events.
+
+This is read-only. We don't support saving SoundBanks back.
+
+WEMs are RIFF files, usually with audio encoded in a Wwise-specific codec. The ones using plain PCM can be converted
+to standard WAV files, so they can be previewed without the Wwise toolchain.
+!*/
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::common::{decoder::Decoder, encoder::Encoder};
+
+#[cfg(test)]
+mod sound_bank_test;
+
+/// Extension used by SoundBank PackedFiles.
+pub const EXTENSION: &str = ".bnk";
+
+/// Extension used by the WEMs we export.
+const WEM_EXTENSION: &str = "wem";
+
+/// Tags of the chunks we read.
+const CHUNK_HEADER: &str = "BKHD";
+const CHUNK_INDEX: &str = "DIDX";
+const CHUNK_DATA: &str = "DATA";
+const CHUNK_HIERARCHY: &str = "HIRC";
+
+/// Length of each entry of the `DIDX` chunk.
+const INDEX_ENTRY_LENGTH: usize = 12;
+
+/// Type of the `HIRC` objects that are events.
+const HIERARCHY_TYPE_EVENT: u8 = 4;
+
+/// Codecs of WEMs that are just PCM, either as plain PCM or as `WAVE_FORMAT_EXTENSIBLE`.
+const WEM_CODEC_PCM: u16 = 0x0001;
+const WEM_CODEC_PCM_EXTENSIBLE: u16 = 0xFFFE;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains a SoundBank decoded in memory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SoundBank {
+
+    /// The version of Wwise used to generate the SoundBank.
+    version: u32,
+
+    /// The id of the SoundBank.
+    id: u32,
+
+    /// The WEMs embedded in the SoundBank, in the same order as in the file.
+    wems: Vec<Wem>,
+
+    /// The ids of the events of the SoundBank.
+    events: Vec<u32>,
+}
+
+/// This struct represents a WEM embedded in a SoundBank.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Wem {
+
+    /// The id of the WEM. It's also the name of the file when it's not embedded.
+    id: u32,
+
+    /// The raw data of the WEM.
+    data: Vec<u8>,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `SoundBank`.
+impl SoundBank {
+
+    /// This function creates a `SoundBank` from a `&[u8]`.
+    pub fn read(packed_file_data: &[u8]) -> Result<Self> {
+        if packed_file_data.len() < 8 || packed_file_data.decode_string_u8(0, 4)? != CHUNK_HEADER {
+            return Err(ErrorKind::SoundBankNotSupportedFile.into());
+        }
+
+        let mut sound_bank = Self::default();
+        let mut index_entries = vec![];
+        let mut data_chunk: Option<&[u8]> = None;
+
+        let mut index = 0;
+        while index < packed_file_data.len() {
+            let tag = packed_file_data.decode_string_u8(index, 4)?;
+            let size = packed_file_data.decode_integer_u32(index + 4)? as usize;
+            let chunk_start = index + 8;
+            let chunk_end = chunk_start + size;
+            if chunk_end > packed_file_data.len() {
+                return Err(ErrorKind::SoundBankDecode(format!("The chunk {} ends at byte {}, but the file has {} bytes.", tag, chunk_end, packed_file_data.len())).into());
+            }
+
+            let chunk = &packed_file_data[chunk_start..chunk_end];
+            match &*tag {
+                CHUNK_HEADER => {
+                    sound_bank.version = chunk.decode_integer_u32(0)?;
+                    sound_bank.id = chunk.decode_integer_u32(4)?;
+                }
+
+                CHUNK_INDEX => {
+                    for entry in chunk.chunks_exact(INDEX_ENTRY_LENGTH) {
+                        let id = entry.decode_integer_u32(0)?;
+                        let offset = entry.decode_integer_u32(4)? as usize;
+                        let size = entry.decode_integer_u32(8)? as usize;
+                        index_entries.push((id, offset, size));
+                    }
+                }
+
+                CHUNK_DATA => data_chunk = Some(chunk),
+
+                // Each object is its type, its size, and its id, followed by data we don't need.
+                CHUNK_HIERARCHY => {
+                    let objects_count = chunk.decode_integer_u32(0)?;
+                    let mut object_index = 4;
+                    for _ in 0..objects_count {
+                        let object_type = chunk.decode_integer_u8(object_index)?;
+                        let object_size = chunk.decode_integer_u32(object_index + 1)? as usize;
+                        if object_type == HIERARCHY_TYPE_EVENT {
+                            sound_bank.events.push(chunk.decode_integer_u32(object_index + 5)?);
+                        }
+                        object_index += 5 + object_size;
+                    }
+                }
+
+                // Chunks we don't need to list the contents of the SoundBank.
+                _ => {}
+            }
+
+            index = chunk_end;
+        }
+
+        // The index is useless without the data, and the other way around.
+        if !index_entries.is_empty() {
+            let data_chunk = data_chunk.ok_or_else(|| ErrorKind::SoundBankDecode("The SoundBank has embedded WEMs, but no data for them.".to_owned()))?;
+            for (id, offset, size) in index_entries {
+                match data_chunk.get(offset..offset + size) {
+                    Some(data) => sound_bank.wems.push(Wem { id, data: data.to_vec() }),
+                    None => return Err(ErrorKind::SoundBankDecode(format!("The data of the WEM {} is out of bounds.", id)).into()),
+                }
+            }
+        }
+
+        Ok(sound_bank)
+    }
+
+    /// This function returns the version of Wwise used to generate the SoundBank.
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    /// This function returns the id of the SoundBank.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    /// This function returns the WEMs embedded in the SoundBank.
+    pub fn get_ref_wems(&self) -> &[Wem] {
+        &self.wems
+    }
+
+    /// This function returns the ids of the events of the SoundBank.
+    pub fn get_ref_events(&self) -> &[u32] {
+        &self.events
+    }
+
+    /// This function exports the embedded WEMs with the provided ids to the provided folder, as `id.wem`.
+    ///
+    /// It returns the paths of the exported files.
+    pub fn export_wems(&self, ids: &[u32], folder: &Path) -> Result<Vec<PathBuf>> {
+        let mut paths = vec![];
+        for id in ids {
+            let wem = self.wems.iter().find(|wem| wem.id == *id).ok_or_else(|| ErrorKind::SoundBankWemNotFound(*id))?;
+            let path = folder.join(format!("{}.{}", wem.id, WEM_EXTENSION));
+            let mut file = BufWriter::new(File::create(&path)?);
+            file.write_all(&wem.data)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Implementation of `Wem`.
+impl Wem {
+
+    /// This function returns the id of the WEM.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    /// This function returns the raw data of the WEM.
+    pub fn get_ref_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// This function converts the WEM into a standard PCM WAV file, so it can be played by common audio players.
+    ///
+    /// Only WEMs using PCM can be converted. For any other codec, it returns an error.
+    pub fn to_wav(&self) -> Result<Vec<u8>> {
+        if self.data.len() < 12 || self.data.decode_string_u8(0, 4)? != "RIFF" || self.data.decode_string_u8(8, 4)? != "WAVE" {
+            return Err(ErrorKind::SoundBankWemNotPlayable(self.id).into());
+        }
+
+        // Find the format and the audio data. Chunks are padded to an even size.
+        let mut format = None;
+        let mut audio = None;
+        let mut index = 12;
+        while index + 8 <= self.data.len() {
+            let tag = self.data.decode_string_u8(index, 4)?;
+            let size = self.data.decode_integer_u32(index + 4)? as usize;
+            let chunk = self.data.get(index + 8..index + 8 + size).ok_or_else(|| ErrorKind::SoundBankDecode(format!("The chunk {} of the WEM {} is out of bounds.", tag, self.id)))?;
+            match &*tag {
+                "fmt " => format = Some(chunk),
+                "data" => audio = Some(chunk),
+                _ => {}
+            }
+            index += 8 + size + size % 2;
+        }
+
+        let (format, audio) = match (format, audio) {
+            (Some(format), Some(audio)) => (format, audio),
+            _ => return Err(ErrorKind::SoundBankWemNotPlayable(self.id).into()),
+        };
+
+        let codec = format.decode_integer_u16(0)?;
+        if codec != WEM_CODEC_PCM && codec != WEM_CODEC_PCM_EXTENSIBLE {
+            return Err(ErrorKind::SoundBankWemNotPlayable(self.id).into());
+        }
+
+        let channels = format.decode_integer_u16(2)?;
+        let sample_rate = format.decode_integer_u32(4)?;
+        let block_align = format.decode_integer_u16(12)?;
+        let bits_per_sample = format.decode_integer_u16(14)?;
+
+        let mut wav = vec![];
+        wav.encode_string_u8("RIFF");
+        wav.encode_integer_u32(36 + audio.len() as u32);
+        wav.encode_string_u8("WAVE");
+        wav.encode_string_u8("fmt ");
+        wav.encode_integer_u32(16);
+        wav.encode_integer_u16(WEM_CODEC_PCM);
+        wav.encode_integer_u16(channels);
+        wav.encode_integer_u32(sample_rate);
+        wav.encode_integer_u32(sample_rate * block_align as u32);
+        wav.encode_integer_u16(block_align);
+        wav.encode_integer_u16(bits_per_sample);
+        wav.encode_string_u8("data");
+        wav.encode_integer_u32(audio.len() as u32);
+        wav.extend_from_slice(audio);
+        Ok(wav)
+    }
+}
@@ -0,0 +1,133 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the SoundBank decoder.
+
+Each test builds a small SoundBank or WEM chunk by chunk, and checks what we read from it.
+!*/
+
+use super::{SoundBank, Wem};
+
+/// This function builds a chunk with the provided tag and data.
+fn build_chunk(tag: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut chunk = tag.to_vec();
+    chunk.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    chunk.extend_from_slice(data);
+    chunk
+}
+
+/// This function builds a `DIDX` chunk with the provided entries, as (id, offset, size).
+fn build_index(entries: &[(u32, u32, u32)]) -> Vec<u8> {
+    let data = entries.iter().flat_map(|(id, offset, size)| [id.to_le_bytes(), offset.to_le_bytes(), size.to_le_bytes()].concat()).collect::<Vec<u8>>();
+    build_chunk(b"DIDX", &data)
+}
+
+/// This function builds a SoundBank with two WEMs, a sound and an event, and a chunk we don't read.
+fn build_sound_bank() -> Vec<u8> {
+    let header = [0x88u32.to_le_bytes(), 1234u32.to_le_bytes()].concat();
+
+    // Objects are their type, their size, their id, and their data.
+    let mut hierarchy = 2u32.to_le_bytes().to_vec();
+    hierarchy.push(2);
+    hierarchy.extend_from_slice(&4u32.to_le_bytes());
+    hierarchy.extend_from_slice(&5u32.to_le_bytes());
+    hierarchy.push(4);
+    hierarchy.extend_from_slice(&8u32.to_le_bytes());
+    hierarchy.extend_from_slice(&99u32.to_le_bytes());
+    hierarchy.extend_from_slice(&[0xFF; 4]);
+
+    [
+        build_chunk(b"BKHD", &header),
+        build_index(&[(10, 0, 4), (20, 4, 2)]),
+        build_chunk(b"DATA", &[1, 2, 3, 4, 5, 6]),
+        build_chunk(b"STID", &[0; 6]),
+        build_chunk(b"HIRC", &hierarchy),
+    ].concat()
+}
+
+/// This function builds a WEM with the provided codec, with a chunk of odd size between its format and its data.
+fn build_wem(codec: u16, audio: &[u8]) -> Wem {
+    let mut format = codec.to_le_bytes().to_vec();
+    format.extend_from_slice(&2u16.to_le_bytes());
+    format.extend_from_slice(&48000u32.to_le_bytes());
+    format.extend_from_slice(&192000u32.to_le_bytes());
+    format.extend_from_slice(&4u16.to_le_bytes());
+    format.extend_from_slice(&16u16.to_le_bytes());
+
+    let chunks = [
+        build_chunk(b"fmt ", &format),
+        build_chunk(b"junk", &[0; 3]),
+        vec![0],
+        build_chunk(b"data", audio),
+    ].concat();
+
+    let mut data = b"RIFF".to_vec();
+    data.extend_from_slice(&(4 + chunks.len() as u32).to_le_bytes());
+    data.extend_from_slice(b"WAVE");
+    data.extend_from_slice(&chunks);
+    Wem { id: 10, data }
+}
+
+/// Test to make sure the header, the embedded WEMs and the events of a SoundBank are read, and unknown chunks are skipped.
+#[test]
+fn test_read() {
+    let sound_bank = SoundBank::read(&build_sound_bank()).unwrap();
+    assert_eq!(sound_bank.get_version(), 0x88);
+    assert_eq!(sound_bank.get_id(), 1234);
+    assert_eq!(sound_bank.get_ref_events(), &[99]);
+
+    let wems = sound_bank.get_ref_wems();
+    assert_eq!(wems.len(), 2);
+    assert_eq!((wems[0].get_id(), wems[0].get_ref_data()), (10, &[1u8, 2, 3, 4][..]));
+    assert_eq!((wems[1].get_id(), wems[1].get_ref_data()), (20, &[5u8, 6][..]));
+}
+
+/// Test to make sure WEMs whose data is not within the `DATA` chunk, or without a `DATA` chunk at all, are rejected.
+#[test]
+fn test_read_wem_out_of_bounds() {
+    let header = build_chunk(b"BKHD", &[0; 8]);
+    let data = build_chunk(b"DATA", &[1, 2, 3, 4, 5, 6]);
+    let sound_bank = [header.clone(), build_index(&[(10, 4, 4)]), data].concat();
+    assert!(SoundBank::read(&sound_bank).is_err());
+
+    let sound_bank = [header, build_index(&[(10, 0, 4)])].concat();
+    assert!(SoundBank::read(&sound_bank).is_err());
+}
+
+/// Test to make sure SoundBanks with chunks bigger than the file, or that are not SoundBanks, are rejected.
+#[test]
+fn test_read_invalid() {
+    let mut sound_bank = build_sound_bank();
+    sound_bank.pop();
+    assert!(SoundBank::read(&sound_bank).is_err());
+
+    assert!(SoundBank::read(&build_chunk(b"DIDX", &[])).is_err());
+    assert!(SoundBank::read(b"BKHD").is_err());
+}
+
+/// Test to make sure PCM WEMs are converted to WAV, skipping padded chunks, and WEMs with other codecs are not.
+#[test]
+fn test_wem_to_wav() {
+    let audio = [1, 2, 3, 4, 5, 6, 7, 8];
+    let wav = build_wem(0x0001, &audio).to_wav().unwrap();
+    assert_eq!(wav.len(), 44 + audio.len());
+    assert_eq!(&wav[..4], b"RIFF");
+    assert_eq!(&wav[4..8], &(36 + audio.len() as u32).to_le_bytes());
+    assert_eq!(&wav[22..24], &2u16.to_le_bytes());
+    assert_eq!(&wav[24..28], &48000u32.to_le_bytes());
+    assert_eq!(&wav[28..32], &192000u32.to_le_bytes());
+    assert_eq!(&wav[40..44], &(audio.len() as u32).to_le_bytes());
+    assert_eq!(&wav[44..], &audio);
+
+    assert!(build_wem(0xFFFE, &audio).to_wav().is_ok());
+    assert!(build_wem(0xFFFF, &audio).to_wav().is_err());
+    assert!(Wem { id: 10, data: b"RIFF".to_vec() }.to_wav().is_err());
+}
@@ -19,7 +19,7 @@ use bincode::deserialize;
 use serde_derive::{Serialize, Deserialize};
 use uuid::Uuid;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fs::File;
 use std::io::{BufReader, Read};
 use std::path::PathBuf;
@@ -45,6 +45,9 @@ const GUID_MARKER: &[u8] = &[253, 254, 252, 255];
 /// If this sequence is found, the DB Table has a version number after it.
 const VERSION_MARKER: &[u8] = &[252, 253, 254, 255];
 
+/// Prefix of the names of the key-value tables. These are config tables, with a row for each setting.
+pub const KEY_VALUE_TABLE_PREFIX: &str = "_kv_";
+
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
@@ -67,6 +70,35 @@ pub struct DB {
     table: Table,
 }
 
+/// This holds the info needed to report a vanilla DB Table that cannot be decoded with the current schema.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DecodeFailureReport {
+
+    /// The name of the table, like `land_units_tables`.
+    pub table_name: String,
+
+    /// Version of the table, as reported by its header.
+    pub version: i32,
+
+    /// Amount of entries of the table, as reported by its header.
+    pub entry_count: u32,
+
+    /// Key of the Game Selected when the decoding failed.
+    pub game: String,
+
+    /// Revision (commit) of the local schema repository.
+    pub schema_revision: String,
+
+    /// Offset of the byte where the decoding stopped.
+    pub failing_offset: usize,
+
+    /// Size of the table's data, in bytes.
+    pub data_size: usize,
+
+    /// The error the decoding returned.
+    pub error: String,
+}
+
 //---------------------------------------------------------------------------//
 //                           Implementation of DB
 //---------------------------------------------------------------------------//
@@ -180,6 +212,43 @@ impl DB {
         })
     }
 
+    /// This function tries to decode a `DB` from a `Vec<u8>` and, if it fails, returns a report with where and why it failed.
+    ///
+    /// If the table decodes without errors, this returns `None`. If even the header is broken, this returns an error instead.
+    pub fn get_decode_failure_report(
+        packed_file_data: &[u8],
+        name: &str,
+        schema: &Schema,
+    ) -> Result<Option<DecodeFailureReport>> {
+        let (version, _, _, entry_count, mut index) = Self::read_header(&packed_file_data)?;
+
+        // Unlike when reading the table, here a missing definition is a failure at the first byte after the header, even with no entries.
+        let result = schema.get_ref_versioned_file_db(&name)
+            .and_then(|versioned_file| versioned_file.get_version(version))
+            .and_then(|definition| {
+
+                // Table::decode only advances the index for the cells it decodes, so on failure it points to the cell that failed.
+                let mut table = Table::new(definition);
+                table.decode(&packed_file_data, entry_count, &mut index, false)?;
+                if index != packed_file_data.len() { Err(ErrorKind::PackedFileSizeIsNotWhatWeExpect(packed_file_data.len(), index).into()) }
+                else { Ok(()) }
+            });
+
+        match result {
+            Ok(_) => Ok(None),
+            Err(error) => Ok(Some(DecodeFailureReport {
+                table_name: name.to_owned(),
+                version,
+                entry_count,
+                game: GAME_SELECTED.read().unwrap().to_owned(),
+                schema_revision: Schema::get_schema_revision().unwrap_or_else(|_| "unknown".to_owned()),
+                failing_offset: index,
+                data_size: packed_file_data.len(),
+                error: error.to_string(),
+            })),
+        }
+    }
+
     /// This function takes a `DB` and encodes it to `Vec<u8>`.
     pub fn save(&self) -> Result<Vec<u8>> {
         let mut packed_file: Vec<u8> = vec![];
@@ -271,30 +340,14 @@ impl DB {
 
     /// This function is used to optimize the size of a DB Table.
     ///
-    /// It scans every line to check if it's a vanilla line, and remove it in that case. Also, if the entire
-    /// file is composed of only vanilla lines, it marks the entire PackedFile for removal.
-    pub fn optimize_table(&mut self, vanilla_tables: &[&Self]) -> bool {
-
-        // For each vanilla table, if it's the same table/version as our own, we check it
-        let mut new_entries = Vec::with_capacity(self.table.get_entry_count());
-        let entries = self.get_ref_table_data();
-        let definition = self.get_ref_definition();
-
-        // To do it faster, make a freaking big table with all the vanilla entries together.
-        let vanilla_table = vanilla_tables.iter()
-            .filter(|x| x.name == self.name && x.get_ref_definition().get_version() == definition.get_version())
-            .map(|x| x.get_ref_table_data())
-            .flatten();
-
-        for entry in entries {
-            if vanilla_table.clone().find(|x| x == &entry).is_none() {
-                new_entries.push(entry.to_vec());
-            }
-        }
+    /// It removes every line that's also in the provided vanilla rows (generated with `Table::get_row_index_key`), returning how many lines were removed.
+    pub fn optimize_table(&mut self, vanilla_rows: &HashSet<Vec<String>>) -> usize {
+        self.table.optimize_table(vanilla_rows)
+    }
 
-        // Then we overwrite the entries and return if the table is empty or now, so we can optimize it further at `PackedFile` level.
-        let _ = self.table.set_table_data(&new_entries);
-        self.table.get_ref_table_data().is_empty()
+    /// This function sorts the lines of the DB Table by their key columns. It returns false if the table has no key columns.
+    pub fn sort_by_key_fields(&mut self) -> bool {
+        self.table.sort_by_key_fields()
     }
 
     /// This function returns the dependency/lookup data of a column from the dependency database.
@@ -512,6 +565,79 @@ impl DB {
         data
     }
 
+    /// This function returns the distinct values of each string column of a DB Table in the dependency database.
+    ///
+    /// It's meant to offer completion of values used in vanilla for the same column. The values are sorted.
+    pub fn get_vanilla_column_values(
+        table_name: &str,
+        table_definition: &Definition,
+        real_dep_db: &mut Vec<PackedFile>,
+        schema: &Schema,
+    ) -> BTreeMap<i32, Vec<String>> {
+        let mut values: BTreeMap<i32, BTreeSet<String>> = BTreeMap::new();
+        let fields = table_definition.get_fields_processed();
+        let mut iter = real_dep_db.iter_mut();
+        while let Some(packed_file) = iter.find(|x| x.get_path().starts_with(&["db".to_owned(), table_name.to_owned()])) {
+            if let Ok(DecodedPackedFile::DB(db)) = packed_file.decode_return_ref_no_locks(schema) {
+                let db_fields = db.get_definition().get_fields_processed();
+                for (column, field) in fields.iter().enumerate() {
+                    if let Some(index) = db_fields.iter().position(|x| x.get_name() == field.get_name()) {
+                        let column_values = values.entry(column as i32).or_default();
+                        for row in db.get_ref_table_data() {
+                            match row[index] {
+                                DecodedData::StringU8(ref entry) |
+                                DecodedData::StringU16(ref entry) |
+                                DecodedData::OptionalStringU8(ref entry) |
+                                DecodedData::OptionalStringU16(ref entry) => if !entry.is_empty() { column_values.insert(entry.to_owned()); }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        values.into_iter().filter(|(_, values)| !values.is_empty()).map(|(column, values)| (column, values.into_iter().collect())).collect()
+    }
+
+    /// This function returns if this DB Table is a key-value table: a `_kv_` table with a `key` column and a `value` column.
+    pub fn is_key_value(&self) -> bool {
+        self.get_key_value_columns().is_some()
+    }
+
+    /// This function returns the indexes of the `key` and `value` columns of this DB Table, if it's a key-value table.
+    pub fn get_key_value_columns(&self) -> Option<(usize, usize)> {
+        if !self.name.starts_with(KEY_VALUE_TABLE_PREFIX) { return None; }
+
+        let fields = self.get_ref_definition().get_ref_fields();
+        let key_column = fields.iter().position(|field| field.get_name() == "key")?;
+        let value_column = fields.iter().position(|field| field.get_name() == "value")?;
+        Some((key_column, value_column))
+    }
+
+    /// This function returns the values of each key of the vanilla versions of a key-value table, in the dependency database.
+    ///
+    /// If a key is in more than one vanilla table, the value of the first one wins, like in the game.
+    pub fn get_vanilla_key_values(
+        table_name: &str,
+        real_dep_db: &mut Vec<PackedFile>,
+        schema: &Schema,
+    ) -> BTreeMap<String, String> {
+        let mut values = BTreeMap::new();
+        let mut iter = real_dep_db.iter_mut();
+        while let Some(packed_file) = iter.find(|x| x.get_path().starts_with(&["db".to_owned(), table_name.to_owned()])) {
+            if let Ok(DecodedPackedFile::DB(db)) = packed_file.decode_return_ref_no_locks(schema) {
+                if let Some((key_column, value_column)) = db.get_key_value_columns() {
+                    for row in db.get_ref_table_data() {
+                        values.entry(row[key_column].data_to_string()).or_insert_with(|| row[value_column].data_to_string());
+                    }
+                }
+            }
+        }
+
+        values
+    }
+
     /// This function imports a TSV file into a decoded table.
     pub fn import_tsv(
         definition: &Definition,
@@ -562,6 +688,29 @@ impl DB {
     }
 }
 
+/// Implementation of `DecodeFailureReport`.
+impl DecodeFailureReport {
+
+    /// This function returns the title of the issue to open in the schema repository for this report.
+    pub fn get_issue_title(&self) -> String {
+        format!("[{}] {} v{} fails to decode", self.game, self.table_name, self.version)
+    }
+
+    /// This function returns the body of the issue to open in the schema repository for this report.
+    pub fn get_issue_body(&self) -> String {
+        format!("- **Game:** {}\n- **Table:** {}\n- **Version:** {}\n- **Entries:** {}\n- **Schema revision:** {}\n- **Failing offset:** {} (of {} bytes)\n\n**Error:**\n\n{}\n",
+            self.game,
+            self.table_name,
+            self.version,
+            self.entry_count,
+            self.schema_revision,
+            self.failing_offset,
+            self.data_size,
+            self.error
+        )
+    }
+}
+
 /// Implementation to create a `DB` from a `Table`.
 impl From<Table> for DB {
     fn from(table: Table) -> Self {
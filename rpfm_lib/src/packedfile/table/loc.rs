@@ -15,6 +15,7 @@ Loc Tables are the files which contain all the localisation strings used by the
 They're just tables with a key, a text, and a boolean column.
 !*/
 
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use rpfm_error::{ErrorKind, Result};
@@ -176,30 +177,14 @@ impl Loc {
 
     /// This function is used to optimize the size of a Loc Table.
     ///
-    /// It scans every line to check if it's a vanilla line, and remove it in that case. Also, if the entire
-    /// file is composed of only vanilla lines, it marks the entire PackedFile for removal.
-    pub fn optimize_table(&mut self, vanilla_tables: &[&Self]) -> bool {
-
-        // For each vanilla table, if it's the same table/version as our own, we check
-        let mut new_entries = Vec::with_capacity(self.table.get_entry_count());
-        let entries = self.get_ref_table_data();
-        let definition = self.get_ref_definition();
-
-        // To do it faster, make a freaking big table with all the vanilla entries together.
-        let vanilla_table = vanilla_tables.iter()
-            .filter(|x| x.get_ref_definition().get_version() == definition.get_version())
-            .map(|x| x.get_ref_table_data())
-            .flatten();
-
-        for entry in entries {
-            if vanilla_table.clone().find(|x| x == &entry).is_none() {
-                new_entries.push(entry.to_vec());
-            }
-        }
+    /// It removes every line that's also in the provided vanilla rows (generated with `Table::get_row_index_key`), returning how many lines were removed.
+    pub fn optimize_table(&mut self, vanilla_rows: &HashSet<Vec<String>>) -> usize {
+        self.table.optimize_table(vanilla_rows)
+    }
 
-        // Then we overwrite the entries and return if the table is empty or now, so we can optimize it further at `PackedFile` level.
-        let _ = self.table.set_table_data(&new_entries);
-        self.table.get_ref_table_data().is_empty()
+    /// This function sorts the lines of the Loc Table by their key columns. It returns false if the table has no key columns.
+    pub fn sort_by_key_fields(&mut self) -> bool {
+        self.table.sort_by_key_fields()
     }
 
     /// This function imports a TSV file into a decoded table.
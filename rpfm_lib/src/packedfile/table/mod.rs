@@ -18,7 +18,8 @@ use bincode::serialize;
 use csv::{QuoteStyle, ReaderBuilder, WriterBuilder};
 use serde_derive::{Serialize, Deserialize};
 
-use std::collections::BTreeMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
 use std::{fmt, fmt::Display};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
@@ -36,6 +37,9 @@ pub mod db;
 pub mod loc;
 pub mod matched_combat;
 
+#[cfg(test)]
+mod table_test;
+
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
@@ -259,6 +263,17 @@ impl DecodedData {
         }
     }
 
+    /// This function compares two cells to sort them. Numbers are compared by value, and everything else by its text.
+    pub fn compare_for_sorting(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (DecodedData::F32(x), DecodedData::F32(y)) => x.partial_cmp(y).unwrap_or(Ordering::Equal),
+            (DecodedData::I16(x), DecodedData::I16(y)) => x.cmp(y),
+            (DecodedData::I32(x), DecodedData::I32(y)) => x.cmp(y),
+            (DecodedData::I64(x), DecodedData::I64(y)) => x.cmp(y),
+            _ => self.data_to_string().cmp(&other.data_to_string()),
+        }
+    }
+
     /// This function prints whatever you have in each variants to a String.
     pub fn data_to_string(&self) -> String {
         match self {
@@ -391,6 +406,46 @@ impl Table {
         Ok(())
     }
 
+    /// This function removes the entries of this table that are in the provided set of vanilla rows, returning how many were removed.
+    ///
+    /// The vanilla rows must be generated with `Table::get_row_index_key`.
+    pub fn optimize_table(&mut self, vanilla_rows: &HashSet<Vec<String>>) -> usize {
+        let old_count = self.entries.len();
+        self.entries.retain(|row| !vanilla_rows.contains(&Self::get_row_index_key(row)));
+        old_count - self.entries.len()
+    }
+
+    /// This function sorts the entries of this table by their key columns, in the order they're in the definition.
+    ///
+    /// It returns false if the table has no key columns, as there is nothing to sort them by.
+    pub fn sort_by_key_fields(&mut self) -> bool {
+        let key_columns = self.definition.get_fields_processed().iter()
+            .enumerate()
+            .filter(|(_, field)| field.get_is_key())
+            .map(|(column, _)| column)
+            .collect::<Vec<usize>>();
+
+        if key_columns.is_empty() { return false; }
+        self.entries.sort_by(|row_a, row_b| key_columns.iter()
+            .map(|column| row_a[*column].compare_for_sorting(&row_b[*column]))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+        );
+        true
+    }
+
+    /// This function returns a hashable version of the provided row, so it can be quickly compared against big amounts of rows.
+    ///
+    /// Floats are rounded the same way they are when comparing `DecodedData`, so rows that are equal have the same key.
+    pub fn get_row_index_key(row: &[DecodedData]) -> Vec<String> {
+        row.iter().map(|cell| match cell {
+            DecodedData::F32(data) => format!("{}", (data * 1_000_000f32).round() / 1_000_000f32),
+            DecodedData::SequenceU16(_) |
+            DecodedData::SequenceU32(_) => format!("{:?}", cell),
+            _ => cell.data_to_string(),
+        }).collect()
+    }
+
     /// This function decodes all the fields of a table from raw bytes.
     ///
     /// If return_incomplete == true, this function will return an error with the incompletely decoded table when it fails.
@@ -731,6 +786,70 @@ impl Table {
 
     }
 
+    /// This function binds the provided PackedFile paths to the rows of a table, generating a key and a value for each path from the provided patterns.
+    ///
+    /// If a row with the generated key (after converting it to the type of the key column) already exists, its value column is updated.
+    /// If not, a new row is added. Patterns accept
+    /// the following placeholders: `{name}` (file name without extension), `{file}` (file name), `{folder}` and `{path}`.
+    ///
+    /// It returns the amount of rows added and updated.
+    pub fn bind_paths(
+        definition: &Definition,
+        rows: &mut Vec<Vec<DecodedData>>,
+        paths: &[Vec<String>],
+        key_column: usize,
+        value_column: usize,
+        key_pattern: &str,
+        value_pattern: &str,
+    ) -> Result<(usize, usize)> {
+        if key_column == value_column {
+            return Err(ErrorKind::TableBindSameColumn.into());
+        }
+
+        let fields = definition.get_ref_fields();
+        let key_field = fields.get(key_column).ok_or_else(|| Error::from(ErrorKind::TableBindInvalidColumn(key_column)))?;
+        let value_field = fields.get(value_column).ok_or_else(|| Error::from(ErrorKind::TableBindInvalidColumn(value_column)))?;
+
+        let mut added = 0;
+        let mut updated = 0;
+        for path in paths {
+            let file = path.last().map(String::as_str).unwrap_or("");
+            let name = match file.find('.') {
+                Some(position) => &file[..position],
+                None => file,
+            };
+            let folder = path[..path.len().saturating_sub(1)].join("/");
+            let full_path = path.join("/");
+
+            let replace = |pattern: &str| pattern.replace("{name}", name).replace("{file}", file).replace("{folder}", &folder).replace("{path}", &full_path);
+            let key = replace(key_pattern);
+            let value = replace(value_pattern);
+
+            let key_data = DecodedData::StringU8(key.to_owned()).convert_between_types(key_field.get_ref_field_type())
+                .map_err(|_| Error::from(ErrorKind::TableBindInvalidValue(key.to_owned(), key_field.get_name().to_owned())))?;
+            let value_data = DecodedData::StringU8(value.to_owned()).convert_between_types(value_field.get_ref_field_type())
+                .map_err(|_| Error::from(ErrorKind::TableBindInvalidValue(value.to_owned(), value_field.get_name().to_owned())))?;
+
+            match rows.iter_mut().find(|row| row[key_column] == key_data) {
+                Some(row) => {
+                    if row[value_column] != value_data {
+                        row[value_column] = value_data;
+                        updated += 1;
+                    }
+                }
+                None => {
+                    let mut row = Self::get_new_row(definition);
+                    row[key_column] = key_data;
+                    row[value_column] = value_data;
+                    rows.push(row);
+                    added += 1;
+                }
+            }
+        }
+
+        Ok((added, updated))
+    }
+
     //----------------------------------------------------------------//
     // TSV Functions for PackedFiles.
     //----------------------------------------------------------------//
@@ -0,0 +1,106 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the generic table functions.
+!*/
+
+use std::collections::BTreeMap;
+
+use crate::schema::{Definition, Field, FieldType};
+
+use super::{DecodedData, Table};
+
+/// This function returns a definition with a key column of the provided type, a string value column and a boolean column.
+fn get_definition(key_type: FieldType) -> Definition {
+    let mut definition = Definition::new(1);
+    *definition.get_ref_mut_fields() = vec![
+        Field::new("key".to_owned(), key_type, true, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()),
+        Field::new("value".to_owned(), FieldType::StringU8, false, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()),
+        Field::new("flag".to_owned(), FieldType::Boolean, false, None, 0, false, None, None, None, String::new(), -1, 0, BTreeMap::new()),
+    ];
+    definition
+}
+
+/// This function returns the provided paths as PackedFile paths.
+fn get_paths(paths: &[&str]) -> Vec<Vec<String>> {
+    paths.iter().map(|path| path.split('/').map(|x| x.to_owned()).collect()).collect()
+}
+
+/// Test to make sure binding paths adds new rows with the placeholders of the patterns replaced.
+#[test]
+fn test_bind_paths_add() {
+    let definition = get_definition(FieldType::StringU8);
+    let mut rows = vec![];
+    let paths = get_paths(&["ui/skins/banner.png", "ui/skins/icons/shield.dds"]);
+
+    let result = Table::bind_paths(&definition, &mut rows, &paths, 0, 1, "{name}", "{folder}|{file}|{path}").unwrap();
+    assert_eq!(result, (2, 0));
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][0], DecodedData::StringU8("banner".to_owned()));
+    assert_eq!(rows[0][1], DecodedData::StringU8("ui/skins|banner.png|ui/skins/banner.png".to_owned()));
+    assert_eq!(rows[0][2], DecodedData::Boolean(false));
+    assert_eq!(rows[1][0], DecodedData::StringU8("shield".to_owned()));
+    assert_eq!(rows[1][1], DecodedData::StringU8("ui/skins/icons|shield.dds|ui/skins/icons/shield.dds".to_owned()));
+}
+
+/// Test to make sure binding paths updates the rows that already have the generated key, and leaves the unchanged ones alone.
+#[test]
+fn test_bind_paths_update() {
+    let definition = get_definition(FieldType::StringU8);
+    let mut rows = vec![
+        vec![DecodedData::StringU8("banner".to_owned()), DecodedData::StringU8("old".to_owned()), DecodedData::Boolean(true)],
+        vec![DecodedData::StringU8("shield".to_owned()), DecodedData::StringU8("ui/shield.png".to_owned()), DecodedData::Boolean(true)],
+    ];
+    let paths = get_paths(&["ui/banner.png", "ui/shield.png"]);
+
+    let result = Table::bind_paths(&definition, &mut rows, &paths, 0, 1, "{name}", "{path}").unwrap();
+    assert_eq!(result, (0, 1));
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][1], DecodedData::StringU8("ui/banner.png".to_owned()));
+
+    // The columns not bound must keep their values.
+    assert_eq!(rows[0][2], DecodedData::Boolean(true));
+}
+
+/// Test to make sure existing rows are matched by the converted key, not by the text generated by the pattern.
+#[test]
+fn test_bind_paths_converted_key() {
+    let definition = get_definition(FieldType::I32);
+    let mut rows = vec![
+        vec![DecodedData::I32(1), DecodedData::StringU8("old".to_owned()), DecodedData::Boolean(false)],
+    ];
+    let paths = get_paths(&["ui/001.png", "ui/2.png"]);
+
+    let result = Table::bind_paths(&definition, &mut rows, &paths, 0, 1, "{name}", "{path}").unwrap();
+    assert_eq!(result, (1, 1));
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][1], DecodedData::StringU8("ui/001.png".to_owned()));
+    assert_eq!(rows[1][0], DecodedData::I32(2));
+}
+
+/// Test to make sure invalid columns and values are rejected without touching the rows.
+#[test]
+fn test_bind_paths_invalid() {
+    let definition = get_definition(FieldType::I32);
+    let mut rows = vec![];
+    let paths = get_paths(&["ui/banner.png"]);
+
+    // Columns out of range.
+    assert!(Table::bind_paths(&definition, &mut rows, &paths, 3, 1, "{name}", "{path}").is_err());
+    assert!(Table::bind_paths(&definition, &mut rows, &paths, 0, 3, "{name}", "{path}").is_err());
+
+    // Same column for key and value.
+    assert!(Table::bind_paths(&definition, &mut rows, &paths, 1, 1, "{name}", "{path}").is_err());
+
+    // Keys that cannot be converted to the type of their column.
+    assert!(Table::bind_paths(&definition, &mut rows, &paths, 0, 1, "{name}", "{path}").is_err());
+    assert!(rows.is_empty());
+}
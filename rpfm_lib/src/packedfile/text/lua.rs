@@ -0,0 +1,193 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with a basic syntax checker for Lua scripts.
+
+This is not a full Lua parser. It only checks that strings, comments, brackets and blocks are properly closed,
+which covers the most common mistakes when editing scripts by hand.
+!*/
+
+use std::fmt;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This enum contains the syntax errors the checker can find in a Lua script. All of them contain the line (starting at 1) of the error.
+#[derive(PartialEq, Clone, Debug)]
+pub enum LuaSyntaxError {
+
+    /// A string was opened, but never closed.
+    UnclosedString(usize),
+
+    /// A long comment was opened, but never closed.
+    UnclosedComment(usize),
+
+    /// A bracket or block was opened, but never closed.
+    Unclosed(String, usize),
+
+    /// A bracket or block was closed, but it was never opened, or another one had to be closed first.
+    Unexpected(String, usize),
+}
+
+/// This enum represents something that must be closed later in the script.
+#[derive(PartialEq, Clone, Debug)]
+enum Opener {
+    Bracket(char),
+    Block(&'static str),
+}
+
+//---------------------------------------------------------------------------//
+//                           Implementations
+//---------------------------------------------------------------------------//
+
+/// Display implementation of `LuaSyntaxError`.
+impl fmt::Display for LuaSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnclosedString(line) => write!(f, "Line {}: unclosed string.", line),
+            Self::UnclosedComment(line) => write!(f, "Line {}: unclosed comment.", line),
+            Self::Unclosed(token, line) => write!(f, "Line {}: '{}' is never closed.", line, token),
+            Self::Unexpected(token, line) => write!(f, "Line {}: unexpected '{}'.", line, token),
+        }
+    }
+}
+
+/// This function checks the syntax of the provided Lua script, returning the first error found.
+pub fn check_syntax(contents: &str) -> Result<(), LuaSyntaxError> {
+    let chars = contents.chars().collect::<Vec<char>>();
+    let mut openers: Vec<(Opener, usize)> = vec![];
+    let mut line = 1;
+    let mut index = 0;
+
+    while index < chars.len() {
+        let character = chars[index];
+        match character {
+            '\n' => line += 1,
+
+            // Comments. Long ones can span multiple lines, short ones end at the end of the line.
+            '-' if chars.get(index + 1) == Some(&'-') => {
+                index += 2;
+                match get_long_bracket_level(&chars, index) {
+                    Some(level) => {
+                        let start_line = line;
+                        index = skip_long_bracket(&chars, index, level, &mut line).ok_or(LuaSyntaxError::UnclosedComment(start_line))?;
+                    }
+                    None => while index < chars.len() && chars[index] != '\n' { index += 1; },
+                }
+                continue;
+            }
+
+            // Quoted strings. They cannot span multiple lines, unless the line break is escaped.
+            '"' | '\'' => {
+                let start_line = line;
+                index += 1;
+                loop {
+                    match chars.get(index) {
+                        Some('\\') => {
+                            if chars.get(index + 1) == Some(&'\n') { line += 1; }
+                            index += 2;
+                        }
+                        Some(x) if *x == character => break,
+                        Some('\n') | None => return Err(LuaSyntaxError::UnclosedString(start_line)),
+                        Some(_) => index += 1,
+                    }
+                }
+            }
+
+            // Long strings.
+            '[' if get_long_bracket_level(&chars, index).is_some() => {
+                let start_line = line;
+                let level = get_long_bracket_level(&chars, index).unwrap();
+                index = skip_long_bracket(&chars, index, level, &mut line).ok_or(LuaSyntaxError::UnclosedString(start_line))?;
+                continue;
+            }
+
+            '(' | '[' | '{' => openers.push((Opener::Bracket(character), line)),
+            ')' | ']' | '}' => {
+                let expected = match character {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+
+                match openers.pop() {
+                    Some((Opener::Bracket(opener), _)) if opener == expected => {},
+                    _ => return Err(LuaSyntaxError::Unexpected(character.to_string(), line)),
+                }
+            }
+
+            // Identifiers and keywords. Numbers are also skipped here, as they cannot open or close anything.
+            _ if character.is_alphanumeric() || character == '_' => {
+                let start = index;
+                while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') { index += 1; }
+
+                // Fields accessed with dots or colons are never keywords.
+                let is_field = start > 0 && (chars[start - 1] == '.' || chars[start - 1] == ':') && (start < 2 || chars[start - 2] != '.');
+                if !is_field {
+                    let word = chars[start..index].iter().collect::<String>();
+                    match &*word {
+                        "function" => openers.push((Opener::Block("function"), line)),
+                        "if" => openers.push((Opener::Block("if"), line)),
+                        "do" => openers.push((Opener::Block("do"), line)),
+                        "repeat" => openers.push((Opener::Block("repeat"), line)),
+                        "elseif" | "else" => match openers.last() {
+                            Some((Opener::Block("if"), _)) => {},
+                            _ => return Err(LuaSyntaxError::Unexpected(word, line)),
+                        }
+                        "end" => match openers.pop() {
+                            Some((Opener::Block("function"), _)) |
+                            Some((Opener::Block("if"), _)) |
+                            Some((Opener::Block("do"), _)) => {},
+                            _ => return Err(LuaSyntaxError::Unexpected(word, line)),
+                        }
+                        "until" => match openers.pop() {
+                            Some((Opener::Block("repeat"), _)) => {},
+                            _ => return Err(LuaSyntaxError::Unexpected(word, line)),
+                        }
+                        _ => {},
+                    }
+                }
+                continue;
+            }
+            _ => {},
+        }
+
+        index += 1;
+    }
+
+    match openers.pop() {
+        Some((Opener::Bracket(opener), line)) => Err(LuaSyntaxError::Unclosed(opener.to_string(), line)),
+        Some((Opener::Block(opener), line)) => Err(LuaSyntaxError::Unclosed(opener.to_owned(), line)),
+        None => Ok(()),
+    }
+}
+
+/// This function returns the level of the long bracket (`[[`, `[=[`, `[==[`,...) starting at the provided index, if there is one.
+fn get_long_bracket_level(chars: &[char], index: usize) -> Option<usize> {
+    if chars.get(index) != Some(&'[') { return None; }
+    let level = chars[index + 1..].iter().take_while(|x| **x == '=').count();
+    if chars.get(index + 1 + level) == Some(&'[') { Some(level) } else { None }
+}
+
+/// This function returns the index right after the end of the long bracket starting at the provided index, or None if it's never closed.
+fn skip_long_bracket(chars: &[char], index: usize, level: usize, line: &mut usize) -> Option<usize> {
+    let mut index = index + level + 2;
+    while index < chars.len() {
+        match chars[index] {
+            '\n' => *line += 1,
+            ']' if chars[index + 1..].iter().take_while(|x| **x == '=').count() == level && chars.get(index + 1 + level) == Some(&']') => return Some(index + level + 2),
+            _ => {},
+        }
+        index += 1;
+    }
+    None
+}
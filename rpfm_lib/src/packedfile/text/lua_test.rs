@@ -0,0 +1,95 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the Lua syntax checker.
+!*/
+
+use super::lua::{check_syntax, LuaSyntaxError};
+
+/// Test to make sure valid scripts pass the check.
+#[test]
+fn test_check_syntax_valid() {
+    let script = "
+local function on_turn_start(context)
+    local faction = context:faction()
+    if faction:is_human() then
+        for i = 1, 10 do
+            out(\"turn \" .. i)
+        end
+    elseif faction:is_dead() then
+        repeat
+            i = i + 1
+        until i > 3
+    else
+        while true do break end
+    end
+    return { a = t[1], b = (2 + 3) }
+end
+";
+    assert_eq!(check_syntax(script), Ok(()));
+    assert_eq!(check_syntax(""), Ok(()));
+}
+
+/// Test to make sure brackets and keywords within strings, long strings and comments are ignored, and unclosed ones are reported.
+#[test]
+fn test_check_syntax_strings_and_comments() {
+    assert_eq!(check_syntax("x = \"end ) ]\" .. 'function ('"), Ok(()));
+    assert_eq!(check_syntax("x = \"escaped \\\" quote\""), Ok(()));
+    assert_eq!(check_syntax("x = \"escaped \\\nline break\""), Ok(()));
+    assert_eq!(check_syntax("x = [[\nend\n]]"), Ok(()));
+    assert_eq!(check_syntax("x = [==[ ]] ]=] end ]==]"), Ok(()));
+    assert_eq!(check_syntax("-- end )\nx = 1"), Ok(()));
+    assert_eq!(check_syntax("--[[\nend\n]] x = 1"), Ok(()));
+    assert_eq!(check_syntax("--[=[ ]] ]=] x = 1"), Ok(()));
+
+    // Brackets with equal signs not followed by another bracket are just indexes.
+    assert_eq!(check_syntax("x = t[ = ]"), Ok(()));
+
+    assert_eq!(check_syntax("\nx = \"unclosed\ny = 1\""), Err(LuaSyntaxError::UnclosedString(2)));
+    assert_eq!(check_syntax("x = 'unclosed"), Err(LuaSyntaxError::UnclosedString(1)));
+    assert_eq!(check_syntax("x = \"ends in escape\\"), Err(LuaSyntaxError::UnclosedString(1)));
+    assert_eq!(check_syntax("\n\nx = [==[ ]] ]=]"), Err(LuaSyntaxError::UnclosedString(3)));
+    assert_eq!(check_syntax("\n--[[\n"), Err(LuaSyntaxError::UnclosedComment(2)));
+}
+
+/// Test to make sure the lines of the errors are counted across strings and comments spanning multiple lines.
+#[test]
+fn test_check_syntax_lines() {
+    assert_eq!(check_syntax("x = [[\n\n]]\n--[[\n]]\ny = \"\\\n\"\n)"), Err(LuaSyntaxError::Unexpected(")".to_owned(), 8)));
+}
+
+/// Test to make sure mismatched brackets and blocks are reported where they're found.
+#[test]
+fn test_check_syntax_mismatches() {
+    assert_eq!(check_syntax("x = (1 + 2"), Err(LuaSyntaxError::Unclosed("(".to_owned(), 1)));
+    assert_eq!(check_syntax("x = {\n[1] = 2"), Err(LuaSyntaxError::Unclosed("{".to_owned(), 1)));
+    assert_eq!(check_syntax("x = (1 + 2]"), Err(LuaSyntaxError::Unexpected("]".to_owned(), 1)));
+    assert_eq!(check_syntax("x = 1)"), Err(LuaSyntaxError::Unexpected(")".to_owned(), 1)));
+    assert_eq!(check_syntax("function f()\n    if x then\nend"), Err(LuaSyntaxError::Unclosed("function".to_owned(), 1)));
+    assert_eq!(check_syntax("\nwhile x do"), Err(LuaSyntaxError::Unclosed("do".to_owned(), 2)));
+    assert_eq!(check_syntax("repeat\nx = 1"), Err(LuaSyntaxError::Unclosed("repeat".to_owned(), 1)));
+    assert_eq!(check_syntax("x = 1\nend"), Err(LuaSyntaxError::Unexpected("end".to_owned(), 2)));
+    assert_eq!(check_syntax("repeat\nend"), Err(LuaSyntaxError::Unexpected("end".to_owned(), 2)));
+    assert_eq!(check_syntax("do\nuntil x"), Err(LuaSyntaxError::Unexpected("until".to_owned(), 2)));
+    assert_eq!(check_syntax("function f()\nelse\nend"), Err(LuaSyntaxError::Unexpected("else".to_owned(), 2)));
+    assert_eq!(check_syntax("x = (function() end"), Err(LuaSyntaxError::Unclosed("(".to_owned(), 1)));
+    assert_eq!(check_syntax("if (x then end)"), Err(LuaSyntaxError::Unexpected("end".to_owned(), 1)));
+}
+
+/// Test to make sure keywords used as fields are not treated as blocks, but keywords after the concatenation operator are.
+#[test]
+fn test_check_syntax_fields() {
+    assert_eq!(check_syntax("x = t.end + t.function"), Ok(()));
+    assert_eq!(check_syntax("x = obj:repeat()"), Ok(()));
+    assert_eq!(check_syntax("x = t.if_active and end_turn and endx"), Ok(()));
+    assert_eq!(check_syntax("x = 'a'..end"), Err(LuaSyntaxError::Unexpected("end".to_owned(), 1)));
+    assert_eq!(check_syntax(".do"), Ok(()));
+}
@@ -21,6 +21,11 @@ use rpfm_error::{ErrorKind, Result};
 
 use crate::common::{decoder::Decoder, encoder::Encoder};
 
+pub mod lua;
+
+#[cfg(test)]
+mod lua_test;
+
 /// UTF-8 BOM (Byte Order Mark).
 const BOM_UTF_8: [u8;3] = [0xEF,0xBB,0xBF];
 
@@ -0,0 +1,265 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to interact with UnitVariant PackedFiles.
+
+UnitVariants are binary files that define, per category (body part, weapon,...), the list of meshes
+and texture folders a unit can randomly use. They're made of a header, followed by the list of categories,
+followed by the variants of every category, one category after another.
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::common::{decoder::Decoder, encoder::Encoder};
+
+#[cfg(test)]
+mod unit_variant_test;
+
+/// This represents the value that every UnitVariant PackedFile has in their 0-4 bytes. A.k.a it's signature or preamble.
+const PACKED_FILE_TYPE: &str = "VRNT";
+
+/// Extension used by UnitVariant PackedFiles.
+pub const EXTENSION: &str = ".unit_variant";
+
+/// Length of the header of a UnitVariant, including the signature.
+const HEADER_LENGTH: usize = 24;
+
+/// Length of each category, and of the name within it.
+const CATEGORY_LENGTH: usize = 528;
+const CATEGORY_NAME_LENGTH: usize = 512;
+
+/// Length of each variant, and of the paths within it.
+const VARIANT_LENGTH: usize = 1026;
+const VARIANT_PATH_LENGTH: usize = 512;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains a UnitVariant decoded in memory.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnitVariant {
+
+    /// The version of the UnitVariant.
+    version: u32,
+
+    /// Unknown value in the header. We keep it to save it back as it was.
+    unknown_1: u32,
+
+    /// The categories of the UnitVariant, with their variants.
+    categories: Vec<Category>,
+}
+
+/// This struct represents a category of a UnitVariant.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Category {
+
+    /// Name of the category.
+    name: String,
+
+    /// Id of the category.
+    id: u64,
+
+    /// Variants available for this category.
+    variants: Vec<Variant>,
+}
+
+/// This struct represents a variant of a category.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+
+    /// Path of the mesh file (usually a VariantMeshDefinition) used by this variant.
+    mesh_file: String,
+
+    /// Folder with the textures used by this variant.
+    texture_folder: String,
+
+    /// Unknown value. We keep it to save it back as it was.
+    unknown_value: u16,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `UnitVariant`.
+impl UnitVariant {
+
+    /// This function creates a `UnitVariant` from a `&[u8]`.
+    pub fn read(packed_file_data: &[u8]) -> Result<Self> {
+        if packed_file_data.decode_string_u8(0, 4)? != PACKED_FILE_TYPE {
+            return Err(ErrorKind::UnitVariantNotSupportedFile.into());
+        }
+
+        let version = packed_file_data.decode_integer_u32(4)?;
+        let categories_count = packed_file_data.decode_integer_u32(8)? as usize;
+        let unknown_1 = packed_file_data.decode_integer_u32(20)?;
+
+        // First, the categories, with the amount of variants each one has.
+        let mut categories = vec![];
+        let mut variants_counts = vec![];
+        let mut index = HEADER_LENGTH;
+        for _ in 0..categories_count {
+            let (name, _) = packed_file_data.decode_string_u16_0padded(index, CATEGORY_NAME_LENGTH)?;
+            let id = packed_file_data.decode_integer_u64(index + CATEGORY_NAME_LENGTH)?;
+            variants_counts.push(packed_file_data.decode_integer_u32(index + CATEGORY_NAME_LENGTH + 8)? as usize);
+            categories.push(Category {
+                name,
+                id,
+                variants: vec![],
+            });
+            index += CATEGORY_LENGTH;
+        }
+
+        // Then, the variants of every category, in the same order.
+        for (category, variants_count) in categories.iter_mut().zip(variants_counts.iter()) {
+            for _ in 0..*variants_count {
+                let (mesh_file, _) = packed_file_data.decode_string_u16_0padded(index, VARIANT_PATH_LENGTH)?;
+                let (texture_folder, _) = packed_file_data.decode_string_u16_0padded(index + VARIANT_PATH_LENGTH, VARIANT_PATH_LENGTH)?;
+                let unknown_value = packed_file_data.decode_integer_u16(index + VARIANT_PATH_LENGTH * 2)?;
+                category.variants.push(Variant {
+                    mesh_file,
+                    texture_folder,
+                    unknown_value,
+                });
+                index += VARIANT_LENGTH;
+            }
+        }
+
+        if index != packed_file_data.len() {
+            return Err(ErrorKind::UnitVariantDecode(format!("{} bytes expected, but the file has {} bytes.", index, packed_file_data.len())).into());
+        }
+
+        Ok(Self {
+            version,
+            unknown_1,
+            categories,
+        })
+    }
+
+    /// This function takes a `UnitVariant` and encodes it to `Vec<u8>`.
+    pub fn save(&self) -> Result<Vec<u8>> {
+        let mut data = vec![];
+        data.encode_string_u8(PACKED_FILE_TYPE);
+        data.encode_integer_u32(self.version);
+        data.encode_integer_u32(self.categories.len() as u32);
+
+        // Offsets of the categories and of the variants.
+        data.encode_integer_u32(HEADER_LENGTH as u32);
+        data.encode_integer_u32((HEADER_LENGTH + self.categories.len() * CATEGORY_LENGTH) as u32);
+        data.encode_integer_u32(self.unknown_1);
+
+        let mut variants_before = 0;
+        for category in &self.categories {
+            data.encode_string_u16_0padded(&(category.name.to_owned(), CATEGORY_NAME_LENGTH))?;
+            data.encode_integer_u64(category.id);
+            data.encode_integer_u32(category.variants.len() as u32);
+            data.encode_integer_u32(variants_before);
+            variants_before += category.variants.len() as u32;
+        }
+
+        for variant in self.categories.iter().flat_map(|category| category.variants.iter()) {
+            data.encode_string_u16_0padded(&(variant.mesh_file.to_owned(), VARIANT_PATH_LENGTH))?;
+            data.encode_string_u16_0padded(&(variant.texture_folder.to_owned(), VARIANT_PATH_LENGTH))?;
+            data.encode_integer_u16(variant.unknown_value);
+        }
+
+        Ok(data)
+    }
+
+    /// This function returns the version of the UnitVariant.
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    /// This function returns the categories of the UnitVariant.
+    pub fn get_ref_categories(&self) -> &[Category] {
+        &self.categories
+    }
+
+    /// This function replaces the categories of the UnitVariant with the provided ones.
+    pub fn set_categories(&mut self, categories: Vec<Category>) -> Result<()> {
+        for category in &categories {
+            check_length(&category.name, CATEGORY_NAME_LENGTH)?;
+            for variant in &category.variants {
+                check_length(&variant.mesh_file, VARIANT_PATH_LENGTH)?;
+                check_length(&variant.texture_folder, VARIANT_PATH_LENGTH)?;
+            }
+        }
+
+        self.categories = categories;
+        Ok(())
+    }
+}
+
+/// Implementation of `Category`.
+impl Category {
+
+    /// This function creates a new `Category` with the provided data.
+    pub fn new(name: &str, id: u64, variants: Vec<Variant>) -> Self {
+        Self {
+            name: name.to_owned(),
+            id,
+            variants,
+        }
+    }
+
+    /// This function returns the name of the category.
+    pub fn get_ref_name(&self) -> &str {
+        &self.name
+    }
+
+    /// This function returns the id of the category.
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    /// This function returns the variants of the category.
+    pub fn get_ref_variants(&self) -> &[Variant] {
+        &self.variants
+    }
+}
+
+/// Implementation of `Variant`.
+impl Variant {
+
+    /// This function creates a new `Variant` with the provided data.
+    pub fn new(mesh_file: &str, texture_folder: &str, unknown_value: u16) -> Self {
+        Self {
+            mesh_file: mesh_file.to_owned(),
+            texture_folder: texture_folder.to_owned(),
+            unknown_value,
+        }
+    }
+
+    /// This function returns the path of the mesh file of the variant.
+    pub fn get_ref_mesh_file(&self) -> &str {
+        &self.mesh_file
+    }
+
+    /// This function returns the texture folder of the variant.
+    pub fn get_ref_texture_folder(&self) -> &str {
+        &self.texture_folder
+    }
+
+    /// This function returns the unknown value of the variant.
+    pub fn get_unknown_value(&self) -> u16 {
+        self.unknown_value
+    }
+}
+
+/// This function checks that the provided string fits in a field of the provided length, once encoded as UTF-16.
+fn check_length(string: &str, length: usize) -> Result<()> {
+    if string.encode_utf16().count() * 2 <= length { Ok(()) }
+    else { Err(ErrorKind::UnitVariantDecode(format!("\"{}\" is too long. The maximum length is {} characters.", string, length / 2)).into()) }
+}
@@ -0,0 +1,66 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the UnitVariant decoder and encoder.
+!*/
+
+use super::{Category, UnitVariant, Variant};
+use super::{CATEGORY_LENGTH, HEADER_LENGTH, VARIANT_LENGTH};
+
+/// This function returns a UnitVariant with two categories, one of them with two variants and the other one empty.
+fn get_unit_variant() -> UnitVariant {
+    let mut unit_variant = UnitVariant::default();
+    unit_variant.set_categories(vec![
+        Category::new("head", 1, vec![
+            Variant::new("variantmeshes/variantmeshdefinitions/head_01.variantmeshdefinition", "variantmeshes/_variantmodels/man/tex/", 0),
+            Variant::new("variantmeshes/variantmeshdefinitions/head_02.variantmeshdefinition", "", 1),
+        ]),
+        Category::new("weapon", 2, vec![]),
+    ]).unwrap();
+    unit_variant
+}
+
+/// Test to make sure a UnitVariant is saved with the expected layout, and read back without changes.
+#[test]
+fn test_unit_variant_round_trip() {
+    let unit_variant = get_unit_variant();
+    let data = unit_variant.save().unwrap();
+    assert_eq!(data.len(), HEADER_LENGTH + CATEGORY_LENGTH * 2 + VARIANT_LENGTH * 2);
+    assert_eq!(&data[..4], b"VRNT");
+
+    let decoded = UnitVariant::read(&data).unwrap();
+    assert_eq!(decoded, unit_variant);
+    assert_eq!(decoded.get_ref_categories()[0].get_ref_variants()[1].get_unknown_value(), 1);
+    assert_eq!(decoded.save().unwrap(), data);
+}
+
+/// Test to make sure files with the wrong signature or the wrong size are rejected.
+#[test]
+fn test_unit_variant_invalid() {
+    let mut data = get_unit_variant().save().unwrap();
+    data.push(0);
+    assert!(UnitVariant::read(&data).is_err());
+
+    data.truncate(data.len() - 2);
+    assert!(UnitVariant::read(&data).is_err());
+
+    data[..4].copy_from_slice(b"ABCD");
+    assert!(UnitVariant::read(&data).is_err());
+}
+
+/// Test to make sure names and paths that don't fit in their fields are not accepted.
+#[test]
+fn test_unit_variant_too_long() {
+    let mut unit_variant = get_unit_variant();
+    assert!(unit_variant.set_categories(vec![Category::new(&"a".repeat(257), 1, vec![])]).is_err());
+    assert!(unit_variant.set_categories(vec![Category::new("head", 1, vec![Variant::new(&"a".repeat(257), "", 0)])]).is_err());
+    assert!(unit_variant.set_categories(vec![Category::new(&"a".repeat(256), 1, vec![])]).is_ok());
+}
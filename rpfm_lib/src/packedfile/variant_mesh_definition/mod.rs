@@ -0,0 +1,255 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to interact with VariantMeshDefinition PackedFiles.
+
+VariantMeshDefinitions are XML files that define the slots of a model (head, body, weapons,...) and the meshes
+that can go in each of them. In the PackFile they're still Text PackedFiles. This module just parses their text
+into a tree of elements and attributes so it can be edited in a structured way, and writes it back.
+
+Only elements and attributes are supported. Files with text content, comments or namespaces cannot be parsed,
+so they're not accidentally saved without them.
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+use rpfm_error::{ErrorKind, Result};
+
+#[cfg(test)]
+mod variant_mesh_definition_test;
+
+/// Extension used by VariantMeshDefinition PackedFiles.
+pub const EXTENSION: &str = ".variantmeshdefinition";
+
+/// Extensions of the files an attribute can reference for us to consider its value a path.
+const PATH_EXTENSIONS: [&str; 4] = [".rigid_model_v2", ".dds", ".variantmeshdefinition", ".wsmodel"];
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains a VariantMeshDefinition decoded in memory.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VariantMeshDefinition {
+
+    /// The XML declaration of the file, if it had one.
+    declaration: Option<String>,
+
+    /// The root element of the file.
+    root: XmlElement,
+}
+
+/// This struct represents an XML element, with its attributes and child elements.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct XmlElement {
+
+    /// Name of the element.
+    name: String,
+
+    /// Attributes of the element, in the order they were in the file.
+    attributes: Vec<(String, String)>,
+
+    /// Child elements of the element.
+    children: Vec<XmlElement>,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `VariantMeshDefinition`.
+impl VariantMeshDefinition {
+
+    /// This function creates a `VariantMeshDefinition` from the text of a VariantMeshDefinition PackedFile.
+    pub fn read(text: &str) -> Result<Self> {
+        let declaration = if text.trim_start().starts_with("<?xml") {
+            let text = text.trim_start();
+            text.find("?>").map(|end| text[..end + 2].to_owned())
+        } else { None };
+
+        let config = ParserConfig::new().trim_whitespace(true).ignore_comments(false);
+        let mut stack: Vec<XmlElement> = vec![];
+        let mut root = None;
+        for event in EventReader::new_with_config(text.as_bytes(), config) {
+            match event.map_err(|error| ErrorKind::VariantMeshDefinitionDecode(error.to_string()))? {
+                XmlEvent::StartElement { name, attributes, .. } => {
+                    if name.namespace.is_some() || attributes.iter().any(|attribute| attribute.name.namespace.is_some()) {
+                        return Err(ErrorKind::VariantMeshDefinitionDecode("Namespaces are not supported.".to_owned()).into());
+                    }
+
+                    stack.push(XmlElement {
+                        name: name.local_name,
+                        attributes: attributes.into_iter().map(|attribute| (attribute.name.local_name, attribute.value)).collect(),
+                        children: vec![],
+                    });
+                }
+
+                XmlEvent::EndElement { .. } => {
+                    let element = stack.pop().ok_or_else(|| ErrorKind::VariantMeshDefinitionDecode("Unexpected closing element.".to_owned()))?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => root = Some(element),
+                    }
+                }
+
+                XmlEvent::Characters(_) | XmlEvent::CData(_) => return Err(ErrorKind::VariantMeshDefinitionDecode("Text content is not supported.".to_owned()).into()),
+                XmlEvent::Comment(_) => return Err(ErrorKind::VariantMeshDefinitionDecode("Comments are not supported.".to_owned()).into()),
+                XmlEvent::ProcessingInstruction { .. } => return Err(ErrorKind::VariantMeshDefinitionDecode("Processing instructions are not supported.".to_owned()).into()),
+                XmlEvent::StartDocument { .. } | XmlEvent::Whitespace(_) => {},
+                XmlEvent::EndDocument => break,
+            }
+        }
+
+        match root {
+            Some(root) => Ok(Self {
+                declaration,
+                root,
+            }),
+            None => Err(ErrorKind::VariantMeshDefinitionDecode("No root element found.".to_owned()).into()),
+        }
+    }
+
+    /// This function takes a `VariantMeshDefinition` and encodes it back to text.
+    ///
+    /// It fails if any element or attribute has an invalid name, or if an element has the same attribute twice.
+    pub fn save(&self) -> Result<String> {
+        let mut text = String::new();
+        if let Some(ref declaration) = self.declaration {
+            text.push_str(declaration);
+            text.push('\n');
+        }
+
+        self.root.write(&mut text, 0)?;
+        Ok(text)
+    }
+
+    /// This function returns the root element of the VariantMeshDefinition.
+    pub fn get_ref_root(&self) -> &XmlElement {
+        &self.root
+    }
+
+    /// This function replaces the root element of the VariantMeshDefinition.
+    pub fn set_root(&mut self, root: XmlElement) {
+        self.root = root;
+    }
+
+    /// This function returns the values of all the attributes of the VariantMeshDefinition that look like paths to files.
+    pub fn get_referenced_paths(&self) -> Vec<String> {
+        let mut paths = vec![];
+        self.root.get_referenced_paths(&mut paths);
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+}
+
+/// Implementation of `XmlElement`.
+impl XmlElement {
+
+    /// This function creates a new `XmlElement` with the provided data.
+    pub fn new(name: &str, attributes: Vec<(String, String)>, children: Vec<XmlElement>) -> Self {
+        Self {
+            name: name.to_owned(),
+            attributes,
+            children,
+        }
+    }
+
+    /// This function returns the name of the element.
+    pub fn get_ref_name(&self) -> &str {
+        &self.name
+    }
+
+    /// This function returns the attributes of the element.
+    pub fn get_ref_attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    /// This function returns the child elements of the element.
+    pub fn get_ref_children(&self) -> &[XmlElement] {
+        &self.children
+    }
+
+    /// This function writes the element, and all its children, indented with tabs.
+    ///
+    /// It fails if the element, any of its attributes or any of its children cannot be written as valid XML.
+    fn write(&self, text: &mut String, depth: usize) -> Result<()> {
+        if !is_valid_name(&self.name) {
+            return Err(ErrorKind::VariantMeshDefinitionEncode(format!("\"{}\" is not a valid element name.", self.name)).into());
+        }
+
+        let indentation = "\t".repeat(depth);
+        text.push_str(&indentation);
+        text.push('<');
+        text.push_str(&self.name);
+        for (index, (name, value)) in self.attributes.iter().enumerate() {
+            if !is_valid_name(name) {
+                return Err(ErrorKind::VariantMeshDefinitionEncode(format!("\"{}\" is not a valid attribute name for the element \"{}\".", name, self.name)).into());
+            }
+
+            if self.attributes[..index].iter().any(|(other_name, _)| other_name == name) {
+                return Err(ErrorKind::VariantMeshDefinitionEncode(format!("The element \"{}\" has the attribute \"{}\" more than once.", self.name, name)).into());
+            }
+
+            text.push_str(&format!(" {}=\"{}\"", name, escape_attribute_value(value)));
+        }
+
+        if self.children.is_empty() {
+            text.push_str(" />\n");
+        }
+        else {
+            text.push_str(">\n");
+            for child in &self.children {
+                child.write(text, depth + 1)?;
+            }
+            text.push_str(&format!("{}</{}>\n", indentation, self.name));
+        }
+
+        Ok(())
+    }
+
+    /// This function adds the values of the attributes of this element and its children that look like paths to the provided list.
+    fn get_referenced_paths(&self, paths: &mut Vec<String>) {
+        for (_, value) in &self.attributes {
+            if is_path(value) {
+                paths.push(value.to_owned());
+            }
+        }
+
+        self.children.iter().for_each(|child| child.get_referenced_paths(paths));
+    }
+}
+
+/// This function returns if the provided attribute value looks like a path to a file we know.
+pub fn is_path(value: &str) -> bool {
+    let value = value.trim().to_lowercase();
+    PATH_EXTENSIONS.iter().any(|extension| value.ends_with(extension))
+}
+
+/// This function returns if the provided string can be used as the name of an element or attribute.
+///
+/// Namespaces are not supported, so colons are not allowed either.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => chars.all(|x| x.is_alphanumeric() || x == '_' || x == '-' || x == '.'),
+        _ => false,
+    }
+}
+
+/// This function escapes the characters that cannot be written as they are in an attribute value.
+fn escape_attribute_value(value: &str) -> String {
+    value.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,70 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the VariantMeshDefinition parser and writer.
+!*/
+
+use super::{VariantMeshDefinition, XmlElement};
+
+/// VariantMeshDefinition already written the same way we write them, so it must be saved back without changes.
+const VARIANT_MESH: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<VARIANT_MESH>
+\t<SLOT name=\"head\" probability=\"100\">
+\t\t<VARIANT_MESH model=\"VariantMeshes/_VariantModels/man/head/head_01.rigid_model_v2\" mask=\"a &amp; &quot;b&quot;\" />
+\t</SLOT>
+\t<SLOT name=\"body\" />
+</VARIANT_MESH>
+";
+
+/// Test to make sure a VariantMeshDefinition is read into its elements and attributes, and saved back without changes.
+#[test]
+fn test_variant_mesh_definition_round_trip() {
+    let variant_mesh = VariantMeshDefinition::read(VARIANT_MESH).unwrap();
+    let root = variant_mesh.get_ref_root();
+    assert_eq!(root.get_ref_name(), "VARIANT_MESH");
+    assert_eq!(root.get_ref_children().len(), 2);
+    assert_eq!(root.get_ref_children()[0].get_ref_children()[0].get_ref_attributes()[1], ("mask".to_owned(), "a & \"b\"".to_owned()));
+    assert_eq!(variant_mesh.get_referenced_paths(), vec!["VariantMeshes/_VariantModels/man/head/head_01.rigid_model_v2".to_owned()]);
+    assert_eq!(variant_mesh.save().unwrap(), VARIANT_MESH);
+}
+
+/// Test to make sure the parts of XML we cannot keep are rejected when reading.
+#[test]
+fn test_variant_mesh_definition_unsupported() {
+    assert!(VariantMeshDefinition::read("<VARIANT_MESH>text</VARIANT_MESH>").is_err());
+    assert!(VariantMeshDefinition::read("<VARIANT_MESH><!-- comment --></VARIANT_MESH>").is_err());
+    assert!(VariantMeshDefinition::read("<x:VARIANT_MESH xmlns:x=\"test\" />").is_err());
+    assert!(VariantMeshDefinition::read("<VARIANT_MESH>").is_err());
+    assert!(VariantMeshDefinition::read("").is_err());
+}
+
+/// Test to make sure elements and attributes that would result in invalid XML cannot be saved.
+#[test]
+fn test_variant_mesh_definition_invalid_names() {
+    let save_with_root = |root: XmlElement| {
+        let mut variant_mesh = VariantMeshDefinition::default();
+        variant_mesh.set_root(root);
+        variant_mesh.save()
+    };
+
+    let attribute = |name: &str| (name.to_owned(), String::new());
+    assert!(save_with_root(XmlElement::new("SLOT", vec![attribute("name"), attribute("_probability-2.0")], vec![])).is_ok());
+    assert!(save_with_root(XmlElement::new("", vec![], vec![])).is_err());
+    assert!(save_with_root(XmlElement::new("SLOT", vec![], vec![XmlElement::new("2SLOT", vec![], vec![])])).is_err());
+    assert!(save_with_root(XmlElement::new("SLOT", vec![attribute("")], vec![])).is_err());
+    assert!(save_with_root(XmlElement::new("SLOT", vec![attribute("x:name")], vec![])).is_err());
+    assert!(save_with_root(XmlElement::new("SLOT", vec![attribute("my name")], vec![])).is_err());
+    assert!(save_with_root(XmlElement::new("SLOT", vec![attribute("name"), attribute("name")], vec![])).is_err());
+
+    // The same attribute in different elements is fine.
+    let child = XmlElement::new("VARIANT_MESH", vec![attribute("name")], vec![]);
+    assert!(save_with_root(XmlElement::new("SLOT", vec![attribute("name")], vec![child])).is_ok());
+}
@@ -0,0 +1,158 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to generate and verify checksum manifests of PackFiles.
+
+A checksum manifest is a JSON file saved next to a PackFile, with the SHA256 of every PackedFile within it,
+and the SHA256 of the entire PackFile. Modders can distribute it along with their PackFiles, so anyone can check
+that the PackFile they have is the same one that was released, and if not, which PackedFiles are different.
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_error::Result;
+
+use crate::packfile::PackFile;
+
+/// Extension added to the path of a PackFile to get the path of its checksum manifest.
+pub const EXTENSION: &str = ".sha256.json";
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents a checksum manifest of a PackFile.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChecksumManifest {
+
+    /// Name of the PackFile this manifest was generated from.
+    pack_file_name: String,
+
+    /// SHA256 of the entire PackFile.
+    total_hash: String,
+
+    /// SHA256 of the data of each PackedFile, by path.
+    packed_files: BTreeMap<String, String>,
+}
+
+/// This struct contains the result of verifying a PackFile against a checksum manifest.
+#[derive(Clone, Debug, Default)]
+pub struct ChecksumReport {
+
+    /// Paths in the manifest that are not in the PackFile.
+    pub missing_paths: Vec<String>,
+
+    /// Paths in the PackFile that are not in the manifest.
+    pub extra_paths: Vec<String>,
+
+    /// Paths whose data is not the one in the manifest.
+    pub mismatched_paths: Vec<String>,
+
+    /// If the hash of the entire PackFile matches the one in the manifest.
+    pub total_hash_matches: bool,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `ChecksumManifest`.
+impl ChecksumManifest {
+
+    /// This function generates a checksum manifest from the PackFile in the provided path.
+    ///
+    /// The hashes are calculated over the data of the PackedFiles, not over their compressed/encrypted form.
+    pub fn new_from_packfile_path(pack_file_path: &Path) -> Result<Self> {
+        let mut data = vec![];
+        let mut file = BufReader::new(File::open(pack_file_path)?);
+        file.read_to_end(&mut data)?;
+        let total_hash = format!("{:x}", Sha256::digest(&data));
+
+        let pack_file = PackFile::read(&pack_file_path.to_path_buf(), true)?;
+        let mut packed_files = BTreeMap::new();
+        for packed_file in pack_file.get_ref_packed_files_all() {
+            let data = packed_file.get_raw_data()?;
+            packed_files.insert(packed_file.get_path().join("/"), format!("{:x}", Sha256::digest(&data)));
+        }
+
+        Ok(Self {
+            pack_file_name: pack_file.get_file_name(),
+            total_hash,
+            packed_files,
+        })
+    }
+
+    /// This function returns the path of the checksum manifest of the PackFile in the provided path.
+    pub fn get_manifest_path(pack_file_path: &Path) -> PathBuf {
+        let mut path = pack_file_path.as_os_str().to_owned();
+        path.push(EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// This function reads a checksum manifest from the provided path.
+    pub fn read(path: &Path) -> Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        serde_json::from_reader(file).map_err(From::from)
+    }
+
+    /// This function saves the checksum manifest to the provided path.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(serde_json::to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function checks the PackFile in the provided path against this manifest.
+    pub fn verify(&self, pack_file_path: &Path) -> Result<ChecksumReport> {
+        let current = Self::new_from_packfile_path(pack_file_path)?;
+        let mut report = ChecksumReport::default();
+        report.total_hash_matches = current.total_hash == self.total_hash;
+
+        for (path, hash) in &self.packed_files {
+            match current.packed_files.get(path) {
+                Some(current_hash) => if current_hash != hash { report.mismatched_paths.push(path.to_owned()); },
+                None => report.missing_paths.push(path.to_owned()),
+            }
+        }
+
+        report.extra_paths = current.packed_files.keys()
+            .filter(|path| !self.packed_files.contains_key(*path))
+            .cloned()
+            .collect();
+
+        Ok(report)
+    }
+
+    /// This function returns the name of the PackFile this manifest was generated from.
+    pub fn get_ref_pack_file_name(&self) -> &str {
+        &self.pack_file_name
+    }
+
+    /// This function returns the amount of PackedFiles in the manifest.
+    pub fn get_packed_files_count(&self) -> usize {
+        self.packed_files.len()
+    }
+}
+
+/// Implementation of `ChecksumReport`.
+impl ChecksumReport {
+
+    /// This function returns if the PackFile verified is exactly the one in the manifest.
+    pub fn is_valid(&self) -> bool {
+        self.total_hash_matches && self.missing_paths.is_empty() && self.extra_paths.is_empty() && self.mismatched_paths.is_empty()
+    }
+}
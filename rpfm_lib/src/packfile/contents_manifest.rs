@@ -0,0 +1,125 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to export the list of contents of a PackFile as a manifest.
+
+A contents manifest is a listing of every PackedFile within a PackFile, with its path, size, type and SHA256.
+It can be exported as JSON, for external tools, or as tab-separated plain text, to paste it wherever it's needed.
+Unlike checksum manifests, these are meant to be read by people and tools, not to verify PackFiles.
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use rpfm_error::Result;
+
+use crate::packedfile::PackedFileType;
+use crate::packfile::PackFile;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents the list of contents of a PackFile.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentsManifest {
+
+    /// Name of the PackFile this manifest was generated from.
+    pack_file_name: String,
+
+    /// One entry per PackedFile, sorted by path.
+    entries: Vec<ContentsManifestEntry>,
+}
+
+/// This struct represents a PackedFile within a contents manifest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentsManifestEntry {
+
+    /// Path of the PackedFile within the PackFile.
+    pub path: String,
+
+    /// Size of the data of the PackedFile, uncompressed and decrypted.
+    pub size: usize,
+
+    /// Type of the PackedFile, as RPFM sees it.
+    pub packed_file_type: String,
+
+    /// SHA256 of the data of the PackedFile.
+    pub sha256: String,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `ContentsManifest`.
+impl ContentsManifest {
+
+    /// This function generates a contents manifest from the provided PackFile.
+    ///
+    /// PackedFiles with cached data are saved first, so the manifest reflects what'll be in the PackFile once it's saved.
+    pub fn new_from_packfile(pack_file: &mut PackFile) -> Result<Self> {
+        let mut entries = vec![];
+        for packed_file in pack_file.get_ref_mut_packed_files_all() {
+            packed_file.encode()?;
+            let data = packed_file.get_raw_data()?;
+            let path = packed_file.get_path();
+            entries.push(ContentsManifestEntry {
+                path: path.join("/"),
+                size: data.len(),
+                packed_file_type: PackedFileType::get_packed_file_type(path).to_string(),
+                sha256: format!("{:x}", Sha256::digest(&data)),
+            });
+        }
+
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(Self {
+            pack_file_name: pack_file.get_file_name(),
+            entries,
+        })
+    }
+
+    /// This function saves the contents manifest to the provided path.
+    ///
+    /// If the path has a `.json` extension, it's saved as JSON. Otherwise, it's saved as tab-separated plain text.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        if path.extension().map_or(false, |extension| extension == "json") {
+            file.write_all(serde_json::to_string_pretty(&self)?.as_bytes())?;
+        } else {
+            file.write_all(self.to_text().as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// This function returns the contents manifest as tab-separated plain text, with a header line.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("# {}\npath\tsize\ttype\tsha256\n", self.pack_file_name);
+        for entry in &self.entries {
+            text.push_str(&format!("{}\t{}\t{}\t{}\n", entry.path, entry.size, entry.packed_file_type, entry.sha256));
+        }
+        text
+    }
+
+    /// This function returns the name of the PackFile this manifest was generated from.
+    pub fn get_ref_pack_file_name(&self) -> &str {
+        &self.pack_file_name
+    }
+
+    /// This function returns the entries of the manifest.
+    pub fn get_ref_entries(&self) -> &[ContentsManifestEntry] {
+        &self.entries
+    }
+}
@@ -0,0 +1,162 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to keep a PackFile in sync with the assets folder of a MyMod.
+
+When the sync starts, we take note of the files in the folder and when they were last modified. From then on, each sync
+adds to the PackFile the files created or modified since the last one, with their path within the folder. Files removed
+from the folder are not removed from the PackFile, as losing a PackedFile because of a misclick in a file manager is worse
+than having to delete it by hand.
+
+Hidden files and folders, like the `.git` folder of a MyMod under version control, are not assets, so they're never synced.
+!*/
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use rpfm_error::Result;
+
+use crate::common::get_files_from_subdir;
+use crate::packfile::PackFile;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct holds the state of the files of a folder being synced into a PackFile.
+#[derive(Clone, Debug)]
+pub struct LiveSync {
+
+    /// Path of the folder being synced.
+    assets_path: PathBuf,
+
+    /// Last modification time of each file of the folder, as of the last sync.
+    files: HashMap<PathBuf, SystemTime>,
+
+    /// Files with changes not synced yet because their PackedFile was being edited.
+    skipped_files: HashSet<PathBuf>,
+}
+
+/// This struct holds the result of a sync, with the paths of the affected PackedFiles.
+#[derive(Clone, Debug, Default)]
+pub struct LiveSyncReport {
+
+    /// PackedFiles created because their file is new.
+    pub added: Vec<Vec<String>>,
+
+    /// PackedFiles replaced because their file was modified.
+    pub updated: Vec<Vec<String>>,
+
+    /// PackedFiles not synced because they're being edited. They're synced once they're no longer skipped.
+    ///
+    /// Each one is only reported the first time it's skipped.
+    pub skipped: Vec<Vec<String>>,
+
+    /// PackedFiles that failed to sync, with the reason.
+    pub errors: Vec<(Vec<String>, String)>,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `LiveSync`.
+impl LiveSync {
+
+    /// This function starts syncing the provided folder, taking note of the current state of its files.
+    ///
+    /// The files already in the folder are not synced until they're modified.
+    pub fn new(assets_path: &Path) -> Result<Self> {
+        let files = get_asset_files(assets_path)?.into_iter()
+            .filter_map(|path| get_last_modified(&path).map(|last_modified| (path, last_modified)))
+            .collect();
+
+        Ok(Self {
+            assets_path: assets_path.to_path_buf(),
+            files,
+            skipped_files: HashSet::new(),
+        })
+    }
+
+    /// This function returns the path of the folder being synced.
+    pub fn get_assets_path(&self) -> &Path {
+        &self.assets_path
+    }
+
+    /// This function adds to the provided PackFile the files created or modified in the folder since the last sync.
+    ///
+    /// Files whose PackedFile is in the provided list are skipped, and retried on the next sync.
+    pub fn sync(&mut self, pack_file: &mut PackFile, skip_paths: &[Vec<String>]) -> Result<LiveSyncReport> {
+        let mut report = LiveSyncReport::default();
+        let paths = get_asset_files(&self.assets_path)?;
+
+        // Forget the files that no longer exist, so they're synced again if they're restored.
+        let existing_paths = paths.iter().collect::<HashSet<&PathBuf>>();
+        self.files.retain(|path, _| existing_paths.contains(path));
+        self.skipped_files.retain(|path| existing_paths.contains(path));
+
+        for path in paths {
+            let last_modified = match get_last_modified(&path) {
+                Some(last_modified) => last_modified,
+                None => continue,
+            };
+
+            if self.files.get(&path) == Some(&last_modified) { continue; }
+            let packed_file_path = match path.strip_prefix(&self.assets_path) {
+                Ok(relative_path) => relative_path.iter().map(|x| x.to_string_lossy().to_string()).collect::<Vec<String>>(),
+                Err(_) => continue,
+            };
+
+            if skip_paths.contains(&packed_file_path) {
+                if self.skipped_files.insert(path) {
+                    report.skipped.push(packed_file_path);
+                }
+                continue;
+            }
+            self.skipped_files.remove(&path);
+
+            // Mark it as synced even if it fails, so we don't report the same error over and over until the file changes again.
+            self.files.insert(path.to_path_buf(), last_modified);
+            let exists = pack_file.packedfile_exists(&packed_file_path);
+            match pack_file.add_from_file(&path, packed_file_path.to_vec(), true) {
+                Ok(_) => if exists { report.updated.push(packed_file_path) } else { report.added.push(packed_file_path) },
+                Err(error) => report.errors.push((packed_file_path, error.to_string())),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// Implementation of `LiveSyncReport`.
+impl LiveSyncReport {
+
+    /// This function returns if nothing happened during the sync.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.skipped.is_empty() && self.errors.is_empty()
+    }
+}
+
+/// This function returns the files of the provided folder, except the hidden ones or the ones within hidden folders.
+fn get_asset_files(assets_path: &Path) -> Result<Vec<PathBuf>> {
+    Ok(get_files_from_subdir(assets_path)?.into_iter()
+        .filter(|path| match path.strip_prefix(assets_path) {
+            Ok(relative_path) => !relative_path.iter().any(|x| x.to_string_lossy().starts_with('.')),
+            Err(_) => false,
+        })
+        .collect())
+}
+
+/// This function returns when the provided file was last modified, if it can be read.
+fn get_last_modified(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|metadata| metadata.modified()).ok()
+}
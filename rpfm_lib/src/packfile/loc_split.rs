@@ -0,0 +1,109 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to split the Loc PackedFiles of a multi-language PackFile into one PackFile per language.
+
+The languages are the ones of the game's own Loc PackFiles (`local_en.pack`, `local_fr.pack`,...). A Loc PackedFile belongs
+to a language if one of its folders is named like it (`text/fr/my_mod.loc`) or if its name ends with it (`text/db/my_mod_fr.loc`).
+The language is removed from the paths in the split PackFiles, so every language ships its Locs with the same paths.
+!*/
+
+use std::path::{Path, PathBuf};
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::games::GameInfo;
+use crate::packedfile::table::loc;
+use crate::packedfile::PackedFileType;
+use crate::packfile::PackFile;
+
+/// Prefix of the names of the Loc PackFiles of the games.
+const LOC_PACK_PREFIX: &str = "local_";
+
+/// Extension of PackFiles.
+const PACK_EXTENSION: &str = ".pack";
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function returns the codes of the languages of the provided game, taken from the names of its Loc PackFiles.
+///
+/// Patch PackFiles (`local_en_patch.pack`) are not languages by themselves, so they're skipped.
+pub fn get_languages(game_info: &GameInfo) -> Vec<String> {
+    game_info.loc_packs.iter()
+        .filter(|name| name.starts_with(LOC_PACK_PREFIX) && name.ends_with(PACK_EXTENSION))
+        .map(|name| name[LOC_PACK_PREFIX.len()..name.len() - PACK_EXTENSION.len()].to_owned())
+        .filter(|language| !language.is_empty() && !language.contains('_'))
+        .collect()
+}
+
+/// This function returns the path the provided Loc PackedFile path should have in the PackFile of the provided language,
+/// or None if it doesn't belong to that language.
+pub fn get_path_for_language(path: &[String], language: &str) -> Option<Vec<String>> {
+    if path.is_empty() { return None; }
+
+    // Language folders are just removed from the path.
+    let folders = &path[..path.len() - 1];
+    if let Some(position) = folders.iter().position(|folder| folder.eq_ignore_ascii_case(language)) {
+        let mut new_path = path.to_vec();
+        new_path.remove(position);
+        return Some(new_path);
+    }
+
+    // Language suffixes are removed from the name, keeping the extension.
+    let name = path.last().unwrap();
+    if name.ends_with(loc::EXTENSION) {
+        let stem = &name[..name.len() - loc::EXTENSION.len()];
+        let suffix = format!("_{}", language);
+        if stem.len() > suffix.len() && stem.to_lowercase().ends_with(&suffix.to_lowercase()) {
+            let mut new_path = path.to_vec();
+            *new_path.last_mut().unwrap() = format!("{}{}", &stem[..stem.len() - suffix.len()], loc::EXTENSION);
+            return Some(new_path);
+        }
+    }
+
+    None
+}
+
+/// This function splits the Loc PackedFiles of the provided PackFile into one PackFile per language, saved in the provided folder.
+///
+/// The new PackFiles are named after the provided one, with the game's suffix for the language (`my_mod_local_fr.pack`).
+/// Only languages with Loc PackedFiles get a PackFile, and the provided PackFile is not changed. It returns the paths of the new PackFiles.
+pub fn split_by_language(pack_file: &PackFile, languages: &[String], destination_path: &Path) -> Result<Vec<PathBuf>> {
+    let pack_file_name = pack_file.get_file_name();
+    let pack_file_name = if pack_file_name.ends_with(PACK_EXTENSION) { &pack_file_name[..pack_file_name.len() - PACK_EXTENSION.len()] } else { &pack_file_name };
+    let locs = pack_file.get_ref_packed_files_by_type(PackedFileType::Loc, false);
+
+    let mut new_paths = vec![];
+    for language in languages {
+        let mut language_pack_file = PackFile::new_with_name(&format!("{}_{}{}{}", pack_file_name, LOC_PACK_PREFIX, language, PACK_EXTENSION), pack_file.get_pfh_version());
+        for packed_file in &locs {
+            if let Some(path) = get_path_for_language(packed_file.get_path(), language) {
+                let mut packed_file = (*packed_file).clone();
+                packed_file.get_ref_mut_raw().set_path(&path)?;
+                language_pack_file.add_packed_file(&packed_file, true)?;
+            }
+        }
+
+        if !language_pack_file.get_ref_packed_files_all().is_empty() {
+            let path = destination_path.join(language_pack_file.get_file_name());
+            language_pack_file.save(Some(path.to_path_buf()))?;
+            new_paths.push(path);
+        }
+    }
+
+    if new_paths.is_empty() {
+        return Err(ErrorKind::PackFileHasNoLocLanguages.into());
+    }
+
+    Ok(new_paths)
+}
@@ -0,0 +1,105 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to rename multiple PackedFiles and folders at once following a pattern.
+
+The pattern is applied in order: find/replace (plain text or regex, with `$1`-like capture groups), then prefix and suffix,
+and then the case transform. Suffixes go before the extension of files, so `unit.loc` with the suffix `_fr` becomes `unit_fr.loc`.
+!*/
+
+use regex::{escape, NoExpand, RegexBuilder};
+
+use rpfm_error::{ErrorKind, Result};
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct holds the pattern used to rename PackedFiles and folders in bulk.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MassRename {
+
+    /// Text to find in the names. If empty, nothing is replaced.
+    pub find: String,
+
+    /// Text to replace the matches with. With regex, it can contain capture groups like `$1`.
+    pub replace: String,
+
+    /// If `find` is a regex instead of plain text.
+    pub use_regex: bool,
+
+    /// If the search for `find` is case sensitive.
+    pub case_sensitive: bool,
+
+    /// Text to add at the start of the names.
+    pub prefix: String,
+
+    /// Text to add at the end of the names, before the extension.
+    pub suffix: String,
+
+    /// Case transform to apply to the final names.
+    pub case_transform: CaseTransform,
+}
+
+/// This enum represents the case transforms that can be applied to the renamed names.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CaseTransform {
+    None,
+    Lower,
+    Upper,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `Default` for `CaseTransform`.
+impl Default for CaseTransform {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Implementation of `MassRename`.
+impl MassRename {
+
+    /// This function returns the new names of the provided names, in the same order, as `(name, is_file)`.
+    ///
+    /// It fails if `find` is not a valid regex.
+    pub fn get_new_names(&self, names: &[(String, bool)]) -> Result<Vec<String>> {
+        let pattern = if self.use_regex { self.find.to_owned() } else { escape(&self.find) };
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map_err(|error| ErrorKind::InvalidMassRenameRegex(error.to_string()))?;
+
+        Ok(names.iter().map(|(name, is_file)| {
+            let mut new_name = if self.find.is_empty() { name.to_owned() }
+            else if self.use_regex { regex.replace_all(name, &*self.replace).to_string() }
+
+            // Without regex, `$` in the replacement is just a `$`.
+            else { regex.replace_all(name, NoExpand(&self.replace)).to_string() };
+
+            let extension_position = if *is_file { new_name.find('.') } else { None };
+            match extension_position {
+                Some(position) => new_name.insert_str(position, &self.suffix),
+                None => new_name.push_str(&self.suffix),
+            }
+            new_name.insert_str(0, &self.prefix);
+
+            match self.case_transform {
+                CaseTransform::None => new_name,
+                CaseTransform::Lower => new_name.to_lowercase(),
+                CaseTransform::Upper => new_name.to_uppercase(),
+            }
+        }).collect())
+    }
+}
@@ -21,11 +21,12 @@ so you don't have to worry about that.
 
 use bitflags::bitflags;
 use csv::ReaderBuilder;
-use itertools::{Itertools, Either};
 use serde_derive::{Serialize, Deserialize};
 use rayon::prelude::*;
+use regex::Regex;
 
 use std::{fmt, fmt::Display};
+use std::collections::BTreeSet;
 use std::fs::{DirBuilder, File};
 use std::io::{prelude::*, BufReader, BufWriter, SeekFrom, Read, Write};
 use std::path::{Path, PathBuf};
@@ -47,7 +48,14 @@ use crate::packedfile::{DecodedPackedFile, PackedFileType};
 use crate::packedfile::table::DecodedData;
 use crate::packedfile::table::db::DB;
 use crate::packedfile::table::loc::{Loc, TSV_NAME_LOC};
-
+use crate::optimizer::{LocKeysIndex, OptimizerIndex, OptimizerReport};
+
+pub mod checksums;
+pub mod contents_manifest;
+pub mod live_sync;
+pub mod loc_split;
+pub mod mass_rename;
+pub mod orphaned_assets;
 mod compression;
 mod crypto;
 pub mod packedfile;
@@ -81,6 +89,9 @@ pub const RESERVED_NAME_EXTRA_PACKFILE: &str = "extra_packfile.rpfm_reserved";
 pub const RESERVED_NAME_SETTINGS: &str = "settings.rpfm_reserved";
 pub const RESERVED_NAME_NOTES: &str = "notes.rpfm_reserved";
 
+/// Name of the folder where the autosaves of the PackFiles are stored, within the config folder.
+pub const AUTOSAVES_FOLDER: &str = "autosaves";
+
 /// This is the list of ***Reserved PackedFile Names***. They're packedfile names used by RPFM for special porpouses.
 pub const RESERVED_PACKED_FILE_NAMES: [&str; 3] = [RESERVED_NAME_EXTRA_PACKFILE, RESERVED_NAME_SETTINGS, RESERVED_NAME_NOTES];
 
@@ -145,6 +156,23 @@ pub struct PackFile {
 
     /// Notes added to the PackFile. Exclusive of this lib.
     notes: Option<String>,
+
+    /// Settings stored within the PackFile. Exclusive of this lib.
+    settings: PackFileSettings,
+}
+
+/// This struct contains the settings stored within a PackFile, in the `settings.rpfm_reserved` PackedFile.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PackFileSettings {
+
+    /// Diagnostic findings the user doesn't want to be reported for this PackFile.
+    ///
+    /// Each entry is a path (a file or a folder, like `db/units_tables`), optionally followed by `:` and the name of a column.
+    diagnostics_ignored: Vec<String>,
+
+    /// PackFiles removed from the `PackFile List` by disabling them in the dependency manager, so they can be enabled back later.
+    #[serde(default)]
+    disabled_dependencies: Vec<String>,
 }
 
 /// This struct is a reduced version of the `PackFile` one, used to pass just the needed data to an UI.
@@ -461,7 +489,8 @@ impl PackFile {
             pack_files: vec![],
             packed_files: vec![],
 
-            notes: None
+            notes: None,
+            settings: PackFileSettings::default(),
         }
     }
 
@@ -478,7 +507,74 @@ impl PackFile {
             packed_files: vec![],
 
             notes: None,
+            settings: PackFileSettings::default(),
+        }
+    }
+
+    /// This function creates a new `PackFile` with a specific `PFHVersion` from the files of the provided folder, named after the folder.
+    ///
+    /// Each file becomes a `PackedFile` with its path within the folder. TSV files exported by RPFM (the ones with the table's type
+    /// and version in their first line) are imported back into the table they came from, losing their `.tsv` extension. Any other file,
+    /// TSV or not, is added as it is.
+    pub fn new_from_folder(folder: &Path, pfh_version: PFHVersion) -> Result<Self> {
+        let file_name = match folder.file_name() {
+            Some(name) => format!("{}.pack", name.to_string_lossy()),
+            None => "unknown.pack".to_owned(),
+        };
+
+        let mut pack_file = Self::new_with_name(&file_name, pfh_version);
+        let mut packed_files = vec![];
+        let mut error_files = vec![];
+        let schema = SCHEMA.read().unwrap();
+
+        for path in get_files_from_subdir(folder)? {
+            let mut path_as_packed_file = match path.strip_prefix(folder) {
+                Ok(relative_path) => relative_path.iter().map(|x| x.to_string_lossy().to_string()).collect::<Vec<String>>(),
+                Err(_) => continue,
+            };
+
+            let is_tsv = path.extension().map_or(false, |extension| extension.to_string_lossy().to_lowercase() == "tsv");
+            if let Some((table_type, table_version)) = if is_tsv { get_tsv_header(&path)? } else { None } {
+                let schema = schema.as_ref().ok_or_else(|| Error::from(ErrorKind::SchemaNotFound))?;
+                let decoded = match &*table_type {
+                    TSV_NAME_LOC => schema.get_ref_versioned_file_loc()
+                        .and_then(|versioned_file| versioned_file.get_version(table_version))
+                        .and_then(|definition| Loc::import_tsv(definition, &path, &table_type))
+                        .map(DecodedPackedFile::Loc),
+                    _ => schema.get_ref_versioned_file_db(&table_type)
+                        .and_then(|versioned_file| versioned_file.get_version(table_version))
+                        .and_then(|definition| DB::import_tsv(definition, &path, &table_type))
+                        .map(DecodedPackedFile::DB),
+                };
+
+                match decoded {
+                    Ok(decoded) => {
+                        if let Some(name) = path_as_packed_file.last_mut() {
+                            name.truncate(name.len() - 4);
+                        }
+
+                        let mut packed_file = PackedFile::new(path_as_packed_file, pack_file.get_file_name());
+                        packed_file.set_decoded(&decoded);
+                        packed_files.push(packed_file);
+                    }
+                    Err(_) => error_files.push(path.to_string_lossy().to_string()),
+                }
+            }
+
+            else {
+                let raw_data = RawPackedFile::read_from_path(&path, path_as_packed_file)?;
+                packed_files.push(PackedFile::new_from_raw(&raw_data));
+            }
         }
+
+        if !error_files.is_empty() {
+            let error_files_string = error_files.iter().map(|x| format!("<li>{}</li>", x)).collect::<String>();
+            return Err(ErrorKind::MassImport(error_files_string).into())
+        }
+
+        let packed_files = packed_files.iter().collect::<Vec<&PackedFile>>();
+        pack_file.add_packed_files(&packed_files, true)?;
+        Ok(pack_file)
     }
 
     /// This function returns a list of reserved PackedFile names, used by RPFM for special porpouses.
@@ -501,6 +597,34 @@ impl PackFile {
         self.pack_files = pack_files.to_vec();
     }
 
+    /// This function returns the entries of the dependency manager: the `PackFile List` of our `PackFile`, followed by the disabled PackFiles.
+    ///
+    /// The bool of each entry is if the PackFile is enabled or not.
+    pub fn get_dependency_manager_entries(&self) -> Vec<(String, bool)> {
+        let mut entries = self.pack_files.iter().map(|pack_file| (pack_file.to_owned(), true)).collect::<Vec<(String, bool)>>();
+        for pack_file in self.settings.get_ref_disabled_dependencies() {
+            if !self.pack_files.contains(pack_file) {
+                entries.push((pack_file.to_owned(), false));
+            }
+        }
+        entries
+    }
+
+    /// This function replaces the `PackFile List` of our `PackFile` with the enabled entries of the provided dependency manager entries,
+    /// keeping the disabled ones in the settings of the `PackFile`. Empty and duplicated entries are skipped.
+    pub fn set_dependency_manager_entries(&mut self, entries: &[(String, bool)]) {
+        let mut enabled: Vec<String> = vec![];
+        let mut disabled: Vec<String> = vec![];
+        for (pack_file, is_enabled) in entries {
+            let pack_file = pack_file.trim();
+            if pack_file.is_empty() || enabled.iter().chain(disabled.iter()).any(|x| x == pack_file) { continue; }
+            if *is_enabled { enabled.push(pack_file.to_owned()); } else { disabled.push(pack_file.to_owned()); }
+        }
+
+        self.pack_files = enabled;
+        self.settings.set_disabled_dependencies(&disabled);
+    }
+
     /// This function retuns the list of PackedFiles inside a `PackFile`.
     pub fn get_packedfiles_list(&self) -> Vec<Vec<String>> {
         self.packed_files.par_iter().map(|x| x.get_path().to_vec()).collect()
@@ -648,34 +772,34 @@ impl PackFile {
         paths_as_folder_and_destination: &[(PathBuf, Vec<String>)],
         overwrite: bool,
     ) -> Result<Vec<Vec<String>>> {
+        let paths_as_file_and_packed_file = Self::get_files_from_folders(paths_as_folder_and_destination)?;
+        self.add_from_files(&paths_as_file_and_packed_file, overwrite)
+    }
 
-        let mut packed_files_to_add = vec![];
+    /// This function returns the files within the provided folders, with the path they'll have as `PackedFiles` when added to a `PackFile`.
+    ///
+    /// Each folder is provided along with the path in the `PackFile` it's going to be added to.
+    pub fn get_files_from_folders(paths_as_folder_and_destination: &[(PathBuf, Vec<String>)]) -> Result<Vec<(PathBuf, Vec<String>)>> {
+        let mut paths_as_file_and_packed_file = vec![];
         for (path, base_path) in paths_as_folder_and_destination {
-            match get_files_from_subdir(path) {
-                Ok(file_paths) => {
-                    for file_path in &file_paths {
-
-                        // The stupid C: letter in paths causes problems when we're on windows.
-                        let drain_fix = if cfg!(target_os = "windows") { 1 } else { 0 };
-                        let new_path_filtered = file_path.to_string_lossy()
-                            .replace('\\', "/") // Fix for windows paths.
-                            .split('/')
-                            .collect::<Vec<&str>>()
-                            .drain(path.components().count() - 1 - drain_fix..)
-                            .map(|x| x.to_owned())
-                            .collect::<Vec<String>>();
-                        let mut new_path = base_path.to_vec();
-                        new_path.extend_from_slice(&new_path_filtered);
-                        let raw_data = RawPackedFile::read_from_path(file_path, new_path)?;
-                        let packed_file = PackedFile::new_from_raw(&raw_data);
-                        packed_files_to_add.push(packed_file);
-                    }
-                }
-                Err(error) => return Err(error)
+            for file_path in get_files_from_subdir(path)? {
+
+                // The stupid C: letter in paths causes problems when we're on windows.
+                let drain_fix = if cfg!(target_os = "windows") { 1 } else { 0 };
+                let new_path_filtered = file_path.to_string_lossy()
+                    .replace('\\', "/") // Fix for windows paths.
+                    .split('/')
+                    .collect::<Vec<&str>>()
+                    .drain(path.components().count() - 1 - drain_fix..)
+                    .map(|x| x.to_owned())
+                    .collect::<Vec<String>>();
+                let mut new_path = base_path.to_vec();
+                new_path.extend_from_slice(&new_path_filtered);
+                paths_as_file_and_packed_file.push((file_path, new_path));
             }
         }
 
-        self.add_packed_files(&packed_files_to_add.iter().map(|x|x).collect::<Vec<&PackedFile>>(), overwrite)
+        Ok(paths_as_file_and_packed_file)
     }
 
     /// This function is used to add a `PackedFile` from one `PackFile` into another.
@@ -821,6 +945,40 @@ impl PackFile {
         }
     }
 
+    /// This function returns if the provided path points to one of the vanilla PackFiles in the `/data` folder of the Game Selected.
+    ///
+    /// The list of vanilla files is taken from the game's `manifest.txt`. If there is no manifest, we fall back to the known db/loc PackFiles of the game.
+    pub fn is_vanilla_packfile_path(path: &Path) -> bool {
+        let data_path = match get_game_selected_data_path() {
+            Some(data_path) => data_path,
+            None => return false,
+        };
+
+        // Canonicalize the parent, as the file itself may not exist yet.
+        let parent_path = match path.parent() {
+            Some(parent_path) => parent_path,
+            None => return false,
+        };
+
+        match (parent_path.canonicalize(), data_path.canonicalize()) {
+            (Ok(parent_path), Ok(data_path)) => if parent_path != data_path { return false },
+            _ => return false,
+        }
+
+        let file_name = match path.file_name() {
+            Some(file_name) => file_name.to_string_lossy().to_lowercase(),
+            None => return false,
+        };
+
+        match Manifest::read_from_game_selected() {
+            Ok(manifest) => manifest.0.iter().any(|x| x.relative_path.to_lowercase() == file_name),
+            Err(_) => match SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()) {
+                Some(game) => game.db_packs.iter().chain(game.loc_packs.iter()).any(|x| x.to_lowercase() == file_name),
+                None => false,
+            }
+        }
+    }
+
     /// This function returns a copy to the `PackedFile` with the provided path, if exists.
     pub fn get_packed_file_by_path(&self, path: &[String]) -> Option<PackedFile> {
         self.packed_files.par_iter().filter(|x| x.get_path() == path).cloned().find_any(|x| x.get_path() == path)
@@ -1121,10 +1279,15 @@ impl PackFile {
     ///
     /// As this can fail for some files, and work for others, we return `Ok(amount_files_extracted)` only if all files were extracted correctly.
     /// If any of them failed, we return `Error` with a list of the paths that failed to get extracted.
+    ///
+    /// If a path rewrite rule (regex, replacement) is provided, it's applied to the path of each `PackedFile` (joined with `/`) to get where it's extracted to.
+    /// If `convert` is true, the `PackedFiles` we know how to convert are extracted in a format that can be opened outside the game (TSV, PNG,...).
     pub fn extract_packed_files_by_type(
         &mut self,
         item_types: &[PathType],
         extracted_path: &PathBuf,
+        path_rewrite: Option<&(Regex, String)>,
+        convert: bool,
     ) -> Result<u32> {
 
         // These variables are here to keep track of what we have extracted and what files failed.
@@ -1158,15 +1321,19 @@ impl PackFile {
 
                         // For individual `PackedFiles`, we extract them one by one.
                         PathType::File(path) => {
-                            match self.extract_packed_file_by_path(path, extracted_path) {
-                                Ok(_) => files_extracted += 1,
-                                Err(_) => error_files.push(format!("{:?}", path)),
+                            match self.get_ref_mut_packed_file_by_path(path) {
+                                Some(packed_file) => match extract_packed_file(packed_file, extracted_path, &rewrite_path(path, path_rewrite), convert) {
+                                    Ok(_) => files_extracted += 1,
+                                    Err(_) => error_files.push(format!("{:?}", path)),
+                                }
+                                None => error_files.push(format!("{:?}", path)),
                             }
                         },
 
                         PathType::Folder(path) => {
                             for packed_file in self.get_ref_mut_packed_files_by_path_start(path) {
-                                match packed_file.extract_packed_file(extracted_path) {
+                                let destination_path = rewrite_path(packed_file.get_path(), path_rewrite);
+                                match extract_packed_file(packed_file, extracted_path, &destination_path, convert) {
                                     Ok(_) => files_extracted += 1,
                                     Err(_) => error_files.push(format!("{:?}", path)),
                                 }
@@ -1183,7 +1350,8 @@ impl PackFile {
 
                 // For each PackedFile we have, just extracted in the folder we got, under the PackFile's folder.
                 for packed_file in self.get_ref_mut_packed_files_all() {
-                    match packed_file.extract_packed_file(extracted_path) {
+                    let destination_path = rewrite_path(packed_file.get_path(), path_rewrite);
+                    match extract_packed_file(packed_file, extracted_path, &destination_path, convert) {
                         Ok(_) => files_extracted += 1,
                         Err(_) => error_files.push(format!("{:?}", packed_file.get_path())),
                     }
@@ -1219,6 +1387,16 @@ impl PackFile {
         self.notes = notes.clone();
     }
 
+    /// This function returns the settings stored within the provided `PackFile`.
+    pub fn get_ref_settings(&self) -> &PackFileSettings {
+        &self.settings
+    }
+
+    /// This function returns a mutable reference to the settings stored within the provided `PackFile`.
+    pub fn get_ref_mut_settings(&mut self) -> &mut PackFileSettings {
+        &mut self.settings
+    }
+
     /// This function returns the timestamp of the provided `PackFile`.
     pub fn get_timestamp(&self) -> i64 {
         self.timestamp
@@ -1229,6 +1407,21 @@ impl PackFile {
         self.timestamp = timestamp;
     }
 
+    /// This function sets the timestamp of all the `PackedFiles` of the provided `PackFile` to the provided one.
+    ///
+    /// It returns the amount of `PackedFiles` whose timestamp changed.
+    pub fn set_packed_files_timestamp(&mut self, timestamp: i64) -> u32 {
+        let mut changed = 0;
+        for packed_file in &mut self.packed_files {
+            let raw_data = packed_file.get_ref_mut_raw();
+            if raw_data.get_timestamp() != timestamp {
+                raw_data.set_timestamp(timestamp);
+                changed += 1;
+            }
+        }
+        changed
+    }
+
     /// This function returns the `PFHVersion` of the provided `PackFile`.
     pub fn get_pfh_version(&self) -> PFHVersion {
         self.pfh_version
@@ -1454,17 +1647,75 @@ impl PackFile {
         successes
     }
 
+    /// This function is used to move one or more `PackedFile`/Folder inside a `PackFile` to new paths.
+    ///
+    /// Unlike `rename_packedfiles`, this takes the full destination path of each item, so items can be moved between folders.
+    /// It returns the list of "Original Path/New Path" of each moved PackedFile, and it doesn't stop on failure.
+    pub fn move_packedfiles(
+        &mut self,
+        moving_data: &[(PathType, Vec<String>)],
+        overwrite: bool
+    ) -> Vec<(PathType, Vec<String>)> {
+
+        let mut successes = vec![];
+        for (item_type, new_path) in moving_data {
+            match item_type {
+                PathType::File(ref path) => {
+                    if let Ok(destination_path) = self.move_packedfile(path, new_path, overwrite) {
+                        successes.push((item_type.clone(), destination_path));
+                    }
+                }
+
+                // Don't allow moving a folder into itself.
+                PathType::Folder(ref path) => {
+                    if new_path.starts_with(path) { continue; }
+                    if let Ok(result) = self.move_folder(path, new_path, overwrite) {
+                        result.iter().map(|(x, y)| (PathType::File(x.to_vec()), y.to_vec())).for_each(|x| successes.push(x));
+                    }
+                }
+
+                // PackFiles and errors are skipped.
+                PathType::PackFile | PathType::None => continue,
+            }
+        }
+
+        successes
+    }
+
     /// This function checks all the DB Tables of the provided PackFile for dependency errors.
     ///
-    /// TODO: Make this not throw warnings on references that point to a **localised** column.
-    /// TODO2: Make this return the name of the columns instead of their index.
+    /// Findings marked as ignored in the settings of the PackFile are not reported.
     pub fn check_table_integrity(&mut self) -> Result<()> {
+        let missing_references = self.get_missing_references()?;
+
+        // If all tables are Ok, return it. Otherwise, return an error with the list of broken tables.
+        if missing_references.is_empty() { Ok(()) }
+        else {
+            let mut broken_tables: Vec<(Vec<String>, Vec<String>)> = vec![];
+            for (path, column) in missing_references {
+                match broken_tables.iter_mut().find(|(broken_path, _)| *broken_path == path) {
+                    Some((_, columns)) => columns.push(column),
+                    None => broken_tables.push((path, vec![column])),
+                }
+            }
+
+            let broken_tables = broken_tables.iter().map(|(path, columns)| format!("Table: {}/{}, Column/s: {}", &path[1], &path[2], columns.join(", "))).collect();
+            Err(ErrorKind::DBMissingReferences(broken_tables).into())
+        }
+    }
+
+    /// This function returns the columns of the DB Tables of the provided PackFile that reference data that doesn't exist, as `(path, column name)`.
+    ///
+    /// Findings marked as ignored in the settings of the PackFile are not returned.
+    ///
+    /// TODO: Make this not throw warnings on references that point to a **localised** column.
+    pub fn get_missing_references(&mut self) -> Result<Vec<(Vec<String>, String)>> {
 
         let schema = &*SCHEMA.read().unwrap();
         match schema {
             Some(ref schema) => {
 
-                let mut broken_tables = vec![];
+                let mut missing_references = vec![];
                 let mut real_dep_db = DEPENDENCY_DATABASE.lock().unwrap();
                 let fake_dep_db = FAKE_DEPENDENCY_DATABASE.read().unwrap();
 
@@ -1504,23 +1755,22 @@ impl PackFile {
                                 }
                             }
 
-                            // If we got missing refs, sort the columns, dedup them and turn them into a nice string for the error message.
-                            // Columns + 1 is so we don't start counting on zero. Easier for the user to see.
-                            if !broken_columns.is_empty() {
-                                let path = packed_file.get_path();
-                                broken_columns.sort();
-                                broken_columns.dedup();
-                                let mut broken_columns = broken_columns.iter().map(|x| format!("{},", *x + 1)).collect::<String>();
-                                broken_columns.pop();
-                                broken_tables.push(format!("Table: {}/{}, Column/s: {}", &path[1], &path[2], broken_columns));
+                            // Sort and dedup the columns, and skip the ones the user doesn't want to know about.
+                            broken_columns.sort();
+                            broken_columns.dedup();
+                            let path = packed_file.get_path();
+                            let fields = table.get_ref_definition().get_fields_processed();
+                            for column in broken_columns {
+                                let column_name = fields[column as usize].get_name().to_owned();
+                                if !self.settings.is_diagnostic_ignored(path, &column_name) {
+                                    missing_references.push((path.to_vec(), column_name));
+                                }
                             }
                         }
                     }
                 }
 
-                // If all tables are Ok, return it. Otherwise, return an error with the list of broken tables.
-                if broken_tables.is_empty() { Ok(()) }
-                else { Err(ErrorKind::DBMissingReferences(broken_tables).into()) }
+                Ok(missing_references)
             }
             None => Err(ErrorKind::SchemaNotFound.into())
         }
@@ -1607,30 +1857,27 @@ impl PackFile {
     /// Currently, this function removes:
     /// - Unchanged data from DB tables (except if the table has the same name as his vanilla counterpart and certain setting is enabled).
     /// - Unchanged data from Loc tables (except if the table has the same name as his vanilla counterpart and certain setting is enabled).
+    /// - Loc rows for localised fields of rows that don't exist, neither in this PackFile nor in its dependencies. Which row a loc key
+    ///   belongs to is guessed from its prefix, so loc keys that don't match any localised field, or that may belong to a table we
+    ///   cannot index, are kept.
     /// - Empty DB tables (except if the table has the same name as his vanilla counterpart and certain setting is enabled).
     /// - Empty Loc tables (except if the table has the same name as his vanilla counterpart and certain setting is enabled).
     /// - XML files in map folders.
-    pub fn optimize(&mut self) -> Vec<Vec<String>> {
-
-        // List of PackedFiles to delete.
-        let mut files_to_delete: Vec<Vec<String>> = vec![];
-        let mut dependencies = DEPENDENCY_DATABASE.lock().unwrap();
-
-        // We get the entire list of paths from the dependency database, so we can check if each `PackedFile is trying to overwrite a vanilla one or not.
-        let database_path_list = dependencies.iter().map(|x| x.get_path().to_vec()).collect::<Vec<Vec<String>>>();
-
-        // Get a list of every Loc and DB PackedFiles in our dependency's files. For performance reasons, we decode every one of them here.
-        // Otherwise, they may have to be decoded multiple times, making this function take ages to finish.
-        let (game_dbs, game_locs): (Vec<&DB>, Vec<&Loc>) = dependencies.iter_mut()
-            .filter(|x| (x.get_path().len() == 3 && x.get_path()[0] == "db") || (x.get_path().last().unwrap().ends_with(".loc")))
-            .map(|x| x.decode_return_ref())
-            .filter(|x| x.is_ok())
-            .map(|x| x.unwrap())
-            .partition_map(|x| match x {
-                DecodedPackedFile::DB(db) => Either::Left(db),
-                DecodedPackedFile::Loc(loc) => Either::Right(loc),
-                _ => unreachable!()
-            });
+    ///
+    /// If the `optimize_sort_table_rows` setting is enabled, it also sorts the rows of the remaining tables by their keys.
+    /// The provided index must have been built from the current dependencies.
+    pub fn optimize(&mut self, index: &OptimizerIndex) -> OptimizerReport {
+        let mut report = OptimizerReport::default();
+        let optimize_not_renamed_packedfiles = SETTINGS.read().unwrap().settings_bool["optimize_not_renamed_packedfiles"];
+        let sort_table_rows = SETTINGS.read().unwrap().settings_bool["optimize_sort_table_rows"];
+
+        // Loc keys can belong to rows added by this PackFile, so we need to index our own tables too.
+        let mut own_loc_keys = LocKeysIndex::default();
+        for packed_file in self.get_ref_mut_packed_files_by_type(PackedFileType::DB, false) {
+            if let Ok(DecodedPackedFile::DB(db)) = packed_file.decode_return_ref() {
+                own_loc_keys.add_table(db);
+            }
+        }
 
         // We do this in two passes. First, we optimize the data inside the `PackedFiles`. Then, we do a *cleaning* pass, removing empty or useless `PackedFiles`.
         for packed_file in self.get_ref_mut_packed_files_all() {
@@ -1638,43 +1885,76 @@ impl PackFile {
 
             // Unless we specifically wanted to, ignore the same-name-as-vanilla files,
             // as those are probably intended to overwrite vanilla files, not to be optimized.
-            if database_path_list.contains(&path) && !SETTINGS.read().unwrap().settings_bool["optimize_not_renamed_packedfiles"] { continue; }
-
-            // If it's a DB table, try to optimize it.
-            if path.len() == 3 && path[0] == "db" && !game_dbs.is_empty() {
+            if index.contains_path(&path) && !optimize_not_renamed_packedfiles { continue; }
 
-                // Try to decode our table.
-                match packed_file.decode_return_ref_mut() {
-                    Ok(data) => if let DecodedPackedFile::DB(db) = data {
-                        let is_empty = db.optimize_table(&game_dbs);
-                        if is_empty { files_to_delete.push(path.to_vec()); }
-                    },
-                    Err(_) => continue,
-                };
+            // If it's an xml in a map folder, remove it.
+            if !path.is_empty() && path.starts_with(&Self::get_terry_map_path()) && path.last().unwrap().ends_with(".xml") {
+                report.bytes_saved += Self::get_packed_file_current_size(packed_file);
+                report.deleted_paths.push(path);
+                continue;
             }
 
-            // If it's a Loc table, try to optimize it.
-            else if path.last().unwrap().ends_with(".loc") && !game_locs.is_empty() {
-                match packed_file.decode_return_ref_mut() {
-                    Ok(data) => if let DecodedPackedFile::Loc(loc) = data {
-                        let is_empty = loc.optimize_table(&game_locs);
-                        if is_empty { files_to_delete.push(path.to_vec()); }
-                    },
-                    Err(_) => continue,
-                };
-            }
+            let is_db = path.len() == 3 && path[0] == "db";
+            let is_loc = path.last().map_or(false, |name| name.ends_with(".loc"));
+            if !is_db && !is_loc { continue; }
 
-            // If it's an xml in a map folder, remove it.
-            else if !path.is_empty() && path.starts_with(&Self::get_terry_map_path()) && path.last().unwrap().ends_with(".xml") {
-                files_to_delete.push(path.to_vec());
+            let size_before = Self::get_packed_file_current_size(packed_file);
+            let is_empty = match packed_file.decode_return_ref_mut() {
+                Ok(DecodedPackedFile::DB(db)) => {
+                    if let Some(vanilla_rows) = index.get_db_rows(&db.name, db.get_ref_definition().get_version()) {
+                        report.rows_removed += db.optimize_table(vanilla_rows);
+                    }
+
+                    if sort_table_rows && db.sort_by_key_fields() { report.tables_sorted += 1; }
+                    db.get_ref_table_data().is_empty()
+                }
+
+                Ok(DecodedPackedFile::Loc(loc)) => {
+                    if let Some(vanilla_rows) = index.get_loc_rows(loc.get_ref_definition().get_version()) {
+                        report.rows_removed += loc.optimize_table(vanilla_rows);
+                    }
+
+                    // Without dependencies we cannot know if a key is unused or it's for a vanilla row, so don't touch them.
+                    if !index.is_empty() {
+                        let rows = loc.get_ref_table_data().iter()
+                            .filter(|row| !index.get_ref_loc_keys().is_loc_key_unused(&row[0].data_to_string(), Some(&own_loc_keys)))
+                            .cloned()
+                            .collect::<Vec<Vec<DecodedData>>>();
+
+                        let loc_keys_removed = loc.get_ref_table_data().len() - rows.len();
+                        if loc_keys_removed > 0 && loc.set_table_data(&rows).is_ok() {
+                            report.loc_keys_removed += loc_keys_removed;
+                        }
+                    }
+
+                    if sort_table_rows && loc.sort_by_key_fields() { report.tables_sorted += 1; }
+                    loc.get_ref_table_data().is_empty()
+                }
+                _ => continue,
+            };
+
+            if is_empty {
+                report.bytes_saved += size_before;
+                report.deleted_paths.push(path);
+            }
+            else {
+                report.bytes_saved += size_before - Self::get_packed_file_current_size(packed_file);
             }
         }
 
         // Delete all the files marked for deletion.
-        files_to_delete.iter().for_each(|x| self.remove_packed_file_by_path(x));
+        report.deleted_paths.iter().for_each(|x| self.remove_packed_file_by_path(x));
+
+        // Return the report, so the caller can know what got removed.
+        report
+    }
 
-        // Return the deleted files, so the caller can know what got removed.
-        files_to_delete
+    /// This function returns the size the provided `PackedFile` would have if it was saved now.
+    fn get_packed_file_current_size(packed_file: &mut PackedFile) -> i64 {
+        match packed_file.encode_and_return() {
+            Ok(raw_data) => i64::from(raw_data.get_size()),
+            Err(_) => i64::from(packed_file.get_raw_data_size()),
+        }
     }
 
     /// This function is used to patch Warhammer Siege map packs so their AI actually works.
@@ -2134,11 +2414,48 @@ impl PackFile {
         packed_files
     }
 
+    /// This function returns the paths of every PackedFile in the vanilla PackFiles.
+    ///
+    /// Only the indexes of the PackFiles are read, not their PackedFiles. The paths are lowercased and joined with `/`,
+    /// so they can be checked with `PackFile::get_missing_paths()`. As this has to go through all the vanilla PackFiles,
+    /// the result should be kept until the game selected changes.
+    pub fn get_vanilla_packed_file_paths() -> BTreeSet<String> {
+        match Self::open_all_ca_packfiles() {
+            Ok(pack_file) => pack_file.get_ref_packed_files_all().iter().map(|x| x.get_path().join("/").to_lowercase()).collect(),
+            Err(_) => BTreeSet::new(),
+        }
+    }
+
+    /// This function returns the paths of every PackedFile in the dependency database, in the same format as `PackFile::get_vanilla_packed_file_paths()`.
+    pub fn get_dependency_packed_file_paths() -> BTreeSet<String> {
+        DEPENDENCY_DATABASE.lock().unwrap().iter().map(|x| x.get_path().join("/").to_lowercase()).collect()
+    }
+
+    /// This function returns the provided paths that cannot be found neither in this PackFile nor in any of the provided lists of known paths.
+    ///
+    /// Paths are checked case-insensitively, and a path is considered found if it's either a PackedFile or a folder with PackedFiles in it.
+    pub fn get_missing_paths(&self, paths: &[String], known_paths_lists: &[&BTreeSet<String>]) -> Vec<String> {
+        let own_paths = self.packed_files.iter().map(|x| x.get_path().join("/").to_lowercase()).collect::<BTreeSet<String>>();
+        let known_paths_lists = known_paths_lists.iter().copied().chain(std::iter::once(&own_paths)).collect::<Vec<&BTreeSet<String>>>();
+        paths.iter().filter(|path| {
+            let path = path.trim().replace('\\', "/").trim_matches('/').to_lowercase();
+            let folder = format!("{}/", path);
+            !known_paths_lists.iter().any(|known_paths| {
+                known_paths.contains(&path) || known_paths.range(folder.to_owned()..).next().map_or(false, |x| x.starts_with(&folder))
+            })
+        }).cloned().collect()
+    }
+
     /// This function allows you to open all CA PackFiles as one for the currently selected Game.
     ///
     /// This function tries to get the list of CA PackFile of the currently selected game from the manifest.txt on /data,
     /// then it tries to open them all as one. Simple and effective.
     pub fn open_all_ca_packfiles() -> Result<Self> {
+        Self::open_packfiles(&Self::get_all_ca_packfiles_paths()?, true, true, true)
+    }
+
+    /// This function returns the paths of all the PackFiles released by CA for the game selected, according to its manifest.
+    pub fn get_all_ca_packfiles_paths() -> Result<Vec<PathBuf>> {
         let data_path = get_game_selected_data_path().ok_or_else(|| ErrorKind::GameSelectedPathNotCorrectlyConfigured)?;
         let manifest = Manifest::read_from_game_selected()?;
         let pack_file_names = manifest.0.iter().filter_map(|x| if x.relative_path.ends_with(".pack") { Some(x.relative_path.to_owned()) } else { None }).collect::<Vec<String>>();
@@ -2147,7 +2464,7 @@ impl PackFile {
             pack_file_path.push(x);
             pack_file_path
         }).collect::<Vec<PathBuf>>();
-        Self::open_packfiles(&pack_file_paths, true, true, true)
+        Ok(pack_file_paths)
     }
 
     /// This function allows you to open one or more `PackFiles`.
@@ -2414,7 +2731,7 @@ impl PackFile {
 
             let mut packed_file = PackedFile::new_from_raw(&raw_data);
 
-            // If this is a notes PackedFile, save the notes and forget about the PackedFile. Otherwise, save the PackedFile.
+            // If this is a notes PackedFile, save the notes and forget about the PackedFile. Same with the settings. Otherwise, save the PackedFile.
             if packed_file.get_path() == ["notes.rpfm_reserved"] {
                 if let Ok(data) = packed_file.get_raw_data_and_keep_it() {
                     if let Ok(data) = data.decode_string_u8(0, data.len()) {
@@ -2422,6 +2739,13 @@ impl PackFile {
                     }
                 }
             }
+            else if packed_file.get_path() == [RESERVED_NAME_SETTINGS] {
+                if let Ok(data) = packed_file.get_raw_data_and_keep_it() {
+                    if let Ok(settings) = serde_json::from_slice(&data) {
+                        pack_file_decoded.settings = settings;
+                    }
+                }
+            }
             else {
                 pack_file_decoded.packed_files.push(packed_file);
             }
@@ -2460,6 +2784,13 @@ impl PackFile {
         // If any of the problematic masks in the header is set or is one of CA's, return an error.
         if !self.is_editable(*SETTINGS.read().unwrap().settings_bool.get("allow_editing_of_ca_packfiles").unwrap()) { return Err(ErrorKind::PackFileIsNonEditable.into()) }
 
+        // Unless the user explicitly allowed it, never overwrite one of the vanilla PackFiles of the game. That's a one-way ticket to a broken install.
+        let allow_overwriting_vanilla_packfiles = SETTINGS.read().unwrap().settings_bool["allow_overwriting_vanilla_packfiles"];
+        let destination_path = new_path.as_ref().unwrap_or(&self.file_path);
+        if !allow_overwriting_vanilla_packfiles && Self::is_vanilla_packfile_path(destination_path) {
+            return Err(ErrorKind::PackFileIsVanilla(destination_path.to_path_buf()).into())
+        }
+
         // If we receive a new path, update it. Otherwise, ensure the file actually exists on disk.
         if let Some(path) = new_path { self.set_file_path(&path)?; }
         else if !self.get_file_path().is_file() { return Err(ErrorKind::PackFileIsNotAFile.into()) }
@@ -2473,6 +2804,20 @@ impl PackFile {
             self.packed_files.push(packed_file);
         }
 
+        // Same with the settings, but only if they're not the default ones.
+        if self.settings != PackFileSettings::default() {
+            let data = serde_json::to_vec_pretty(&self.settings)?;
+            let raw_data = RawPackedFile::read_from_vec(vec![RESERVED_NAME_SETTINGS.to_owned()], self.get_file_name(), 0, false, data);
+            let packed_file = PackedFile::new_from_raw(&raw_data);
+            self.packed_files.push(packed_file);
+        }
+
+        // If we want reproducible PackFiles, the timestamps cannot change between saves, or saving the same contents twice will give us different files.
+        let normalize_timestamps = SETTINGS.read().unwrap().settings_bool["normalize_timestamps_on_save"];
+        if normalize_timestamps {
+            self.set_packed_files_timestamp(0);
+        }
+
         // For some bizarre reason, if the PackedFiles are not alphabetically sorted they may or may not crash the game for particular people.
         // So, to fix it, we have to sort all the PackedFiles here by path.
         // NOTE: This sorting has to be CASE INSENSITIVE. This means for "ac", "Ab" and "aa" it'll be "aa", "Ab", "ac".
@@ -2558,7 +2903,7 @@ impl PackFile {
         header.encode_integer_u32(packed_file_index.len() as u32);
 
         // Update the creation time, then save it. PFH0 files don't have timestamp in the headers.
-        self.timestamp = get_current_time();
+        self.timestamp = if normalize_timestamps { 0 } else { get_current_time() };
         match self.pfh_version {
             PFHVersion::PFH5 | PFHVersion::PFH4 => header.encode_integer_u32(self.timestamp as u32),
             PFHVersion::PFH3 | PFHVersion::PFH2 => header.encode_integer_i64((self.timestamp + SEC_TO_UNIX_EPOCH) * WINDOWS_TICK),
@@ -2574,12 +2919,81 @@ impl PackFile {
             file.write_all(&data)?;
         }
 
-        // Remove again the notes PackedFile, as that one is stored separated from the rest.
+        // Remove again the notes and settings PackedFiles, as those are stored separated from the rest.
         self.remove_packed_file_by_path(&["notes.rpfm_reserved".to_owned()]);
+        self.remove_packed_file_by_path(&[RESERVED_NAME_SETTINGS.to_owned()]);
 
         // If nothing has failed, return success.
         Ok(())
     }
+
+    /// This function saves a backup copy of the `PackFile` to the provided path, without changing the path of the `PackFile`.
+    ///
+    /// Used for autosaves, so a crash doesn't take away the unsaved changes with it.
+    pub fn save_backup(&mut self, backup_path: &Path) -> Result<()> {
+        let file_path = self.file_path.to_path_buf();
+        let result = self.save(Some(backup_path.to_path_buf()));
+        self.set_file_path(&file_path)?;
+        result
+    }
+}
+
+/// Implementation of `PackFileSettings`.
+impl PackFileSettings {
+
+    /// This function returns the list of ignored diagnostic findings.
+    pub fn get_ref_diagnostics_ignored(&self) -> &[String] {
+        &self.diagnostics_ignored
+    }
+
+    /// This function replaces the list of ignored diagnostic findings. Empty and duplicated entries are skipped.
+    pub fn set_diagnostics_ignored(&mut self, entries: &[String]) {
+        self.diagnostics_ignored.clear();
+        self.add_diagnostics_ignored(entries);
+    }
+
+    /// This function adds the provided entries to the list of ignored diagnostic findings. Empty and duplicated entries are skipped.
+    pub fn add_diagnostics_ignored(&mut self, entries: &[String]) {
+        for entry in entries {
+            let entry = entry.trim();
+            if !entry.is_empty() && !self.diagnostics_ignored.iter().any(|x| x == entry) {
+                self.diagnostics_ignored.push(entry.to_owned());
+            }
+        }
+    }
+
+    /// This function returns if a finding about the provided path and column has been marked as ignored.
+    ///
+    /// An entry ignores a finding if its path is the path of the finding, or one of its parent folders,
+    /// and it has either no column or the column of the finding. Paths are compared case-insensitively.
+    pub fn is_diagnostic_ignored(&self, path: &[String], column: &str) -> bool {
+        let path = path.join("/").to_lowercase();
+        self.diagnostics_ignored.iter().any(|entry| {
+            let (entry_path, entry_column) = match entry.rfind(':') {
+                Some(index) => (&entry[..index], Some(&entry[index + 1..])),
+                None => (&entry[..], None),
+            };
+
+            let entry_path = entry_path.trim_matches('/').to_lowercase();
+            let path_matches = path == entry_path || path.starts_with(&format!("{}/", entry_path));
+            path_matches && entry_column.map_or(true, |entry_column| entry_column == column)
+        })
+    }
+
+    /// This function returns the entry to use to ignore a finding about the provided path and column.
+    pub fn get_diagnostic_entry(path: &[String], column: &str) -> String {
+        format!("{}:{}", path.join("/"), column)
+    }
+
+    /// This function returns the list of PackFiles disabled in the dependency manager.
+    pub fn get_ref_disabled_dependencies(&self) -> &[String] {
+        &self.disabled_dependencies
+    }
+
+    /// This function replaces the list of PackFiles disabled in the dependency manager.
+    pub fn set_disabled_dependencies(&mut self, pack_files: &[String]) {
+        self.disabled_dependencies = pack_files.to_vec();
+    }
 }
 
 /// Implementaion of trait `Default` for `PackFile`.
@@ -2629,3 +3043,40 @@ impl Manifest {
         Ok(manifest)
     }
 }
+
+/// This function returns the type and version of the table in the provided TSV file, if it's a TSV exported by RPFM.
+fn get_tsv_header(path: &Path) -> Result<Option<(String, i32)>> {
+    let mut line = String::new();
+    BufReader::new(File::open(path)?).read_line(&mut line)?;
+
+    let tsv_info = line.trim_end_matches(&['\r', '\n'][..]).split('\t').collect::<Vec<&str>>();
+    if tsv_info.len() == 2 {
+        if let Ok(version) = tsv_info[1].parse::<i32>() {
+            return Ok(Some((tsv_info[0].to_owned(), version)));
+        }
+    }
+    Ok(None)
+}
+
+/// This function extracts the provided `PackedFile` with the provided internal path, converting it if requested.
+fn extract_packed_file(packed_file: &mut PackedFile, extracted_path: &Path, internal_path: &[String], convert: bool) -> Result<()> {
+    if convert {
+        packed_file.extract_packed_file_converted_as(extracted_path, internal_path)
+    } else {
+        packed_file.extract_packed_file_as(extracted_path, internal_path)
+    }
+}
+
+/// This function applies the provided path rewrite rule (regex, replacement), if any, to the provided path of a `PackedFile`.
+///
+/// Empty folders resulting from the rewrite are removed, so stripping a prefix like `ui/portraits/` doesn't leave a leading `/`.
+fn rewrite_path(path: &[String], path_rewrite: Option<&(Regex, String)>) -> Vec<String> {
+    match path_rewrite {
+        Some((regex, replacement)) => regex.replace(&path.join("/"), replacement.as_str())
+            .split('/')
+            .filter(|x| !x.is_empty())
+            .map(|x| x.to_owned())
+            .collect(),
+        None => path.to_vec(),
+    }
+}
@@ -0,0 +1,154 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to find the assets of a PackFile nothing uses.
+
+An asset (texture, model or sound) is orphaned if nothing in the PackFile or its dependencies references it, and it doesn't
+replace a file of the dependencies. References are searched in DB Tables, text files (scripts, variant meshes, xml,...) and
+in the binary files that point to other assets, like models.
+
+References are written in many ways (with or without extension, with `\` as separator, just the file name,...) so we collect
+every path-like string we find, and check the assets against them in all those forms. This means it's a heuristic: assets only
+used by the game's code will be reported as orphaned, so the list is meant to be reviewed before deleting anything. Also, assets
+only referenced by other orphaned assets are not reported until those are deleted.
+!*/
+
+use std::collections::HashSet;
+
+use crate::DEPENDENCY_DATABASE;
+use crate::packedfile::{DecodedPackedFile, PackedFileType};
+use crate::packedfile::table::DecodedData;
+use crate::packfile::PackFile;
+use crate::packfile::packedfile::PackedFile;
+
+/// Extensions of the PackedFiles we consider assets: textures, models and sounds.
+pub const ASSET_EXTENSIONS: [&str; 9] = [".dds", ".png", ".jpg", ".tga", ".rigid_model_v2", ".wsmodel", ".wav", ".wem", ".ogg"];
+
+/// Minimum length of a string for it to be considered a possible reference.
+const MIN_REFERENCE_LENGTH: usize = 3;
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function returns the paths of the assets of the provided PackFile nothing references, sorted.
+///
+/// This decodes every table and text file in the PackFile and in the dependencies, so it's slow.
+pub fn get_orphaned_assets(pack_file: &mut PackFile) -> Vec<Vec<String>> {
+    let mut references = HashSet::new();
+    let mut dependency_paths = HashSet::new();
+    {
+        let mut dependencies = DEPENDENCY_DATABASE.lock().unwrap();
+        for packed_file in dependencies.iter_mut() {
+            dependency_paths.insert(normalize_reference(&packed_file.get_path().join("/")));
+            add_references(packed_file, &mut references);
+        }
+    }
+
+    for packed_file in pack_file.get_ref_mut_packed_files_all() {
+        add_references(packed_file, &mut references);
+    }
+
+    let mut orphaned_assets = pack_file.get_ref_packed_files_all_paths().into_iter()
+        .filter(|path| is_asset(path))
+        .filter(|path| !dependency_paths.contains(&normalize_reference(&path.join("/"))))
+        .filter(|path| !is_referenced(path, &references))
+        .map(|path| path.to_vec())
+        .collect::<Vec<Vec<String>>>();
+
+    orphaned_assets.sort();
+    orphaned_assets
+}
+
+/// This function returns if the provided path is the path of an asset.
+pub fn is_asset(path: &[String]) -> bool {
+    path.last().map_or(false, |name| {
+        let name = name.to_lowercase();
+        ASSET_EXTENSIONS.iter().any(|extension| name.ends_with(extension))
+    })
+}
+
+/// This function adds the possible references to other PackedFiles found in the provided PackedFile to the provided list.
+///
+/// PackedFiles that fail to decode are searched as binary files.
+fn add_references(packed_file: &mut PackedFile, references: &mut HashSet<String>) {
+    match PackedFileType::get_packed_file_type(packed_file.get_path()) {
+        PackedFileType::DB | PackedFileType::Text(_) => {
+            match packed_file.decode_return_ref() {
+                Ok(DecodedPackedFile::DB(table)) => {
+                    for row in table.get_ref_table_data() {
+                        for cell in row {
+                            match cell {
+                                DecodedData::StringU8(data) |
+                                DecodedData::StringU16(data) |
+                                DecodedData::OptionalStringU8(data) |
+                                DecodedData::OptionalStringU16(data) => add_references_from_text(data, references),
+                                _ => {}
+                            }
+                        }
+                    }
+                    return;
+                }
+                Ok(DecodedPackedFile::Text(text)) => return add_references_from_text(text.get_ref_contents(), references),
+                _ => {}
+            }
+
+            if let Ok(data) = packed_file.get_raw_data() {
+                add_references_from_binary(&data, references);
+            }
+        }
+
+        // Models point to their textures, and unit variants to their variant meshes.
+        PackedFileType::RigidModel | PackedFileType::UnitVariant => if let Ok(data) = packed_file.get_raw_data() {
+            add_references_from_binary(&data, references);
+        }
+        _ => {}
+    }
+}
+
+/// This function adds the path-like strings in the provided text to the provided list of references.
+fn add_references_from_text(text: &str, references: &mut HashSet<String>) {
+    text.split(|character: char| !is_reference_character(character))
+        .filter(|reference| reference.len() >= MIN_REFERENCE_LENGTH)
+        .for_each(|reference| { references.insert(normalize_reference(reference)); });
+}
+
+/// This function adds the path-like strings in the provided binary data to the provided list of references.
+fn add_references_from_binary(data: &[u8], references: &mut HashSet<String>) {
+    data.split(|byte| !is_reference_character(*byte as char))
+        .filter(|reference| reference.len() >= MIN_REFERENCE_LENGTH)
+        .for_each(|reference| { references.insert(normalize_reference(&String::from_utf8_lossy(reference))); });
+}
+
+/// This function returns if the provided character can be part of a reference to a PackedFile.
+fn is_reference_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_' || character == '-' || character == '.' || character == '/' || character == '\\'
+}
+
+/// This function returns the provided reference in lowercase, with `/` as separator and without leading separators.
+fn normalize_reference(reference: &str) -> String {
+    reference.to_lowercase().replace('\\', "/").trim_start_matches('/').to_owned()
+}
+
+/// This function returns if the asset with the provided path is referenced, with or without its folders or its extension.
+fn is_referenced(path: &[String], references: &HashSet<String>) -> bool {
+    let full_path = normalize_reference(&path.join("/"));
+    let name = path.last().map(|name| normalize_reference(name)).unwrap_or_default();
+    let without_extension = |reference: &str| match reference.rfind('.') {
+        Some(position) if !reference[position..].contains('/') => reference[..position].to_owned(),
+        _ => reference.to_owned(),
+    };
+
+    references.contains(&full_path) ||
+        references.contains(&without_extension(&full_path)) ||
+        references.contains(&name) ||
+        references.contains(&without_extension(&name))
+}
@@ -30,7 +30,8 @@ use crate::packedfile::animpack::AnimPacked;
 use crate::packfile::*;
 use crate::packfile::compression::decompress_data;
 use crate::packedfile::{DecodedPackedFile, PackedFileType};
-use crate::packedfile::table::{db::DB, loc::Loc};
+use crate::packedfile::image::{dds::DDS, png};
+use crate::packedfile::table::{db::DB, loc::{Loc, TSV_NAME_LOC}};
 use crate::schema::Schema;
 use crate::SCHEMA;
 
@@ -373,6 +374,16 @@ impl PackedFile {
         self.raw.get_size()
     }
 
+    /// This function returns the amount of bytes of the `RawPackedFile` data currently loaded in memory.
+    pub fn get_loaded_data_size(&self) -> usize {
+        self.raw.get_loaded_data_size()
+    }
+
+    /// This function returns if the `PackedFile` has been decoded and its decoded data is cached.
+    pub fn is_decoded(&self) -> bool {
+        self.decoded != DecodedPackedFile::Unknown
+    }
+
     /// This function returns the data of a PackedFile.
     pub fn get_raw_data(&self) -> Result<Vec<u8>> {
         self.raw.get_data()
@@ -398,12 +409,22 @@ impl PackedFile {
 
     /// This function extracts the provided PackedFile into the provided path.
     pub fn extract_packed_file(&mut self, destination_path: &Path) -> Result<()> {
+        let internal_path = self.get_path().to_vec();
+        self.extract_packed_file_as(destination_path, &internal_path)
+    }
+
+    /// This function extracts the provided PackedFile into the provided path, using the provided internal path instead of its own one.
+    pub fn extract_packed_file_as(&mut self, destination_path: &Path, internal_path: &[String]) -> Result<()> {
+        // Empty paths or paths going up are not allowed, as they could end outside the destination folder.
+        if internal_path.is_empty() || internal_path.iter().any(|x| x.is_empty() || x == "..") {
+            return Err(ErrorKind::ExtractError(self.get_path().to_vec()).into());
+        }
 
         // Save it, in case it's cached.
         self.encode()?;
 
         // We get his internal path without his name.
-        let mut internal_path = self.get_path().to_vec();
+        let mut internal_path = internal_path.to_vec();
         let file_name = internal_path.pop().unwrap();
 
         // Then, we join his internal path with his destination path, so we have his almost-full path (his final path without his name).
@@ -420,6 +441,56 @@ impl PackedFile {
         Ok(())
     }
 
+    /// This function extracts the provided PackedFile into the provided path, using the provided internal path instead of its own one,
+    /// and converting it to a format that can be opened outside the game if we know how.
+    ///
+    /// DB and Loc Tables are extracted as TSV, DDS images as PNG and text files as UTF-8 with BOM. Everything else,
+    /// or anything that fails to convert (like tables without a definition), is extracted as it is.
+    pub fn extract_packed_file_converted_as(&mut self, destination_path: &Path, internal_path: &[String]) -> Result<()> {
+        if internal_path.is_empty() || internal_path.iter().any(|x| x.is_empty() || x == "..") {
+            return Err(ErrorKind::ExtractError(self.get_path().to_vec()).into());
+        }
+
+        let file_path = destination_path.join(internal_path.iter().collect::<PathBuf>());
+        if let Some(folder) = file_path.parent() {
+            DirBuilder::new().recursive(true).create(folder)?;
+        }
+
+        // Tables keep their name, with the TSV extension added to it, as DB Tables have no extension.
+        let mut tsv_path = file_path.clone().into_os_string();
+        tsv_path.push(".tsv");
+        let tsv_path = PathBuf::from(tsv_path);
+        let table_name = self.get_path().get(1).cloned().unwrap_or_default();
+
+        let converted = match self.get_packed_file_type_by_path() {
+            PackedFileType::DB | PackedFileType::Loc | PackedFileType::Text(_) => match self.decode_return_ref() {
+                Ok(DecodedPackedFile::DB(data)) => data.export_tsv(&tsv_path, &table_name).is_ok(),
+                Ok(DecodedPackedFile::Loc(data)) => data.export_tsv(&tsv_path, TSV_NAME_LOC).is_ok(),
+                Ok(DecodedPackedFile::Text(data)) => {
+                    let contents = format!("\u{FEFF}{}", data.get_ref_contents());
+                    File::create(&file_path).and_then(|mut file| file.write_all(contents.as_bytes())).is_ok()
+                }
+                _ => false,
+            }
+
+            PackedFileType::Image => {
+                self.encode()?;
+                let data = self.get_raw_data()?;
+                match DDS::read(&data) {
+                    Ok(dds) => {
+                        let mipmap = &dds.get_ref_mipmaps()[0];
+                        let png = png::encode_rgba(mipmap.get_width(), mipmap.get_height(), mipmap.get_ref_data());
+                        File::create(file_path.with_extension("png")).and_then(|mut file| file.write_all(&png)).is_ok()
+                    }
+                    Err(_) => false,
+                }
+            }
+            _ => false,
+        };
+
+        if converted { Ok(()) } else { self.extract_packed_file_as(destination_path, internal_path) }
+    }
+
     /// This function returns the type of the Provided PackedFile, according to it's path.
     pub fn get_packed_file_type_by_path(&self) -> PackedFileType {
         PackedFileType::get_packed_file_type(self.get_path())
@@ -599,6 +670,14 @@ impl RawPackedFile {
         }
     }
 
+    /// This function returns the size of the data of the provided `RawPackedFile`, only if it's loaded in memory.
+    pub fn get_loaded_data_size(&self) -> usize {
+        match self.data {
+            PackedFileData::OnMemory(ref data, _, _) => data.len(),
+            PackedFileData::OnDisk(_) => 0,
+        }
+    }
+
     /// This function returns the current compression state of the provided `RawPackedFile`.
     pub fn get_compression_state(&self) -> bool {
         match self.data {
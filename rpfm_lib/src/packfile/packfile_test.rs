@@ -12,9 +12,19 @@
 Module containing test for the `PackFile` module, just to make sure we don't break it... again...
 !*/
 
+use regex::Regex;
+
+use std::collections::BTreeSet;
+use std::env::temp_dir;
+use std::fs::{remove_dir_all, DirBuilder, File};
+use std::io::Write;
 use std::path::PathBuf;
 
-use super::PackFile;
+use super::{PackFile, PFHVersion, rewrite_path};
+use super::packedfile::{PackedFile, RawPackedFile};
+use super::live_sync::LiveSync;
+use super::loc_split::get_path_for_language;
+use super::mass_rename::{CaseTransform, MassRename};
 
 #[test]
 fn test_decode_pfh5() {
@@ -121,3 +131,182 @@ fn test_encode_pfh0() {
 
 	assert_eq!(pack_file_base, pack_file_new);
 }
+
+#[test]
+fn test_rewrite_path() {
+    let path = vec!["ui".to_owned(), "portraits".to_owned(), "units".to_owned(), "unit.png".to_owned()];
+    let path_rewrite = (Regex::new("^ui/portraits/").unwrap(), String::new());
+    assert_eq!(rewrite_path(&path, Some(&path_rewrite)), vec!["units".to_owned(), "unit.png".to_owned()]);
+    assert_eq!(rewrite_path(&path, None), path);
+}
+
+#[test]
+fn test_get_path_for_language() {
+    let path_folder = vec!["text".to_owned(), "fr".to_owned(), "my_mod.loc".to_owned()];
+    let path_suffix = vec!["text".to_owned(), "db".to_owned(), "my_mod_fr.loc".to_owned()];
+    let path_none = vec!["text".to_owned(), "db".to_owned(), "my_mod.loc".to_owned()];
+    assert_eq!(get_path_for_language(&path_folder, "fr"), Some(vec!["text".to_owned(), "my_mod.loc".to_owned()]));
+    assert_eq!(get_path_for_language(&path_suffix, "fr"), Some(path_none.to_vec()));
+    assert_eq!(get_path_for_language(&path_none, "fr"), None);
+    assert_eq!(get_path_for_language(&path_suffix, "en"), None);
+}
+
+#[test]
+fn test_mass_rename() {
+    let names = vec![("unit_01.loc".to_owned(), true), ("Units".to_owned(), false)];
+
+    let mut mass_rename = MassRename::default();
+    mass_rename.find = "unit".to_owned();
+    mass_rename.replace = "troop".to_owned();
+    mass_rename.suffix = "_fr".to_owned();
+    assert_eq!(mass_rename.get_new_names(&names).unwrap(), vec!["troop_01_fr.loc".to_owned(), "troops_fr".to_owned()]);
+
+    mass_rename.case_sensitive = true;
+    mass_rename.prefix = "x_".to_owned();
+    mass_rename.case_transform = CaseTransform::Upper;
+    assert_eq!(mass_rename.get_new_names(&names).unwrap(), vec!["X_TROOP_01_FR.LOC".to_owned(), "X_UNITS_FR".to_owned()]);
+
+    let mut mass_rename = MassRename::default();
+    mass_rename.find = r"^(\w+)_(\d+)".to_owned();
+    mass_rename.replace = "${2}_$1".to_owned();
+    mass_rename.use_regex = true;
+    mass_rename.case_sensitive = true;
+    assert_eq!(mass_rename.get_new_names(&names).unwrap(), vec!["01_unit.loc".to_owned(), "Units".to_owned()]);
+
+    mass_rename.find = "(".to_owned();
+    assert!(mass_rename.get_new_names(&names).is_err());
+}
+
+#[test]
+fn test_live_sync() {
+    let assets_path = temp_dir().join("rpfm_test_live_sync");
+    if assets_path.is_dir() {
+        remove_dir_all(&assets_path).unwrap();
+    }
+    DirBuilder::new().recursive(true).create(assets_path.join("text")).unwrap();
+    File::create(assets_path.join("old.txt")).unwrap().write_all(b"old").unwrap();
+
+    // Files already in the folder are not synced until they change.
+    let mut pack_file = PackFile::new();
+    let mut live_sync = LiveSync::new(&assets_path).unwrap();
+    assert!(live_sync.sync(&mut pack_file, &[]).unwrap().is_empty());
+
+    let path = vec!["text".to_owned(), "new.txt".to_owned()];
+    File::create(assets_path.join("text").join("new.txt")).unwrap().write_all(b"new").unwrap();
+
+    // Skipped files are reported only once, and synced when they stop being skipped.
+    let report = live_sync.sync(&mut pack_file, &[path.to_vec()]).unwrap();
+    assert_eq!(report.skipped, vec![path.to_vec()]);
+    assert!(live_sync.sync(&mut pack_file, &[path.to_vec()]).unwrap().is_empty());
+    assert!(!pack_file.packedfile_exists(&path));
+
+    let report = live_sync.sync(&mut pack_file, &[]).unwrap();
+    assert_eq!(report.added, vec![path.to_vec()]);
+    assert!(pack_file.packedfile_exists(&path));
+    assert!(live_sync.sync(&mut pack_file, &[]).unwrap().is_empty());
+
+    remove_dir_all(&assets_path).unwrap();
+}
+
+#[test]
+fn test_live_sync_hidden_files() {
+    let assets_path = temp_dir().join("rpfm_test_live_sync_hidden_files");
+    if assets_path.is_dir() {
+        remove_dir_all(&assets_path).unwrap();
+    }
+    DirBuilder::new().recursive(true).create(assets_path.join(".git").join("objects")).unwrap();
+    DirBuilder::new().recursive(true).create(assets_path.join("text")).unwrap();
+
+    let mut pack_file = PackFile::new();
+    let mut live_sync = LiveSync::new(&assets_path).unwrap();
+
+    // Neither the files of the `.git` folder nor hidden files in other folders are synced.
+    File::create(assets_path.join(".git").join("index")).unwrap().write_all(b"index").unwrap();
+    File::create(assets_path.join(".git").join("objects").join("object")).unwrap().write_all(b"object").unwrap();
+    File::create(assets_path.join("text").join(".hidden.txt")).unwrap().write_all(b"hidden").unwrap();
+    File::create(assets_path.join("text").join("new.txt")).unwrap().write_all(b"new").unwrap();
+
+    let report = live_sync.sync(&mut pack_file, &[]).unwrap();
+    assert_eq!(report.added, vec![vec!["text".to_owned(), "new.txt".to_owned()]]);
+    assert!(report.errors.is_empty());
+    assert_eq!(pack_file.get_packedfiles_list().len(), 1);
+
+    remove_dir_all(&assets_path).unwrap();
+}
+
+#[test]
+fn test_new_from_folder() {
+    let folder = temp_dir().join("rpfm_test_new_from_folder");
+    if folder.is_dir() {
+        remove_dir_all(&folder).unwrap();
+    }
+    DirBuilder::new().recursive(true).create(folder.join("text")).unwrap();
+    File::create(folder.join("text").join("readme.txt")).unwrap().write_all(b"readme").unwrap();
+
+    // TSV files without RPFM's header are added as they are.
+    File::create(folder.join("notes.tsv")).unwrap().write_all(b"name\tvalue\tcomment\n").unwrap();
+
+    let pack_file = PackFile::new_from_folder(&folder, PFHVersion::PFH5).unwrap();
+    assert_eq!(pack_file.get_file_name(), "rpfm_test_new_from_folder.pack");
+    assert!(pack_file.packedfile_exists(&["text".to_owned(), "readme.txt".to_owned()]));
+    assert!(pack_file.packedfile_exists(&["notes.tsv".to_owned()]));
+
+    remove_dir_all(&folder).unwrap();
+}
+
+#[test]
+fn test_set_packed_files_timestamp() {
+    let mut pack_file = PackFile::new();
+    let raw_data = RawPackedFile::read_from_vec(vec!["readme.txt".to_owned()], "test.pack".to_owned(), 1_600_000_000, false, b"readme".to_vec());
+    pack_file.add_packed_file(&PackedFile::new_from_raw(&raw_data), true).unwrap();
+
+    // Only the PackedFiles whose timestamp actually changes are counted.
+    assert_eq!(pack_file.set_packed_files_timestamp(0), 1);
+    assert_eq!(pack_file.set_packed_files_timestamp(0), 0);
+}
+
+#[test]
+fn test_dependency_manager_entries() {
+    let mut pack_file = PackFile::new();
+    let entries = vec![
+        ("parent.pack".to_owned(), true),
+        ("disabled.pack".to_owned(), false),
+        ("grandparent.pack".to_owned(), true),
+        ("parent.pack".to_owned(), false),
+        ("".to_owned(), true),
+    ];
+
+    // Disabled PackFiles are not in the PackFile List, but they're not lost either.
+    pack_file.set_dependency_manager_entries(&entries);
+    assert_eq!(pack_file.get_packfiles_list(), &["parent.pack".to_owned(), "grandparent.pack".to_owned()]);
+    assert_eq!(pack_file.get_dependency_manager_entries(), vec![
+        ("parent.pack".to_owned(), true),
+        ("grandparent.pack".to_owned(), true),
+        ("disabled.pack".to_owned(), false),
+    ]);
+}
+
+#[test]
+fn test_get_missing_paths() {
+    let mut pack_file = PackFile::new();
+    let raw_data = RawPackedFile::read_from_vec(vec!["variantmeshes".to_owned(), "Mod".to_owned(), "head.rigid_model_v2".to_owned()], "test.pack".to_owned(), 0, false, vec![]);
+    pack_file.add_packed_file(&PackedFile::new_from_raw(&raw_data), true).unwrap();
+
+    let vanilla_paths = vec!["variantmeshes/vanilla/body.rigid_model_v2".to_owned()].into_iter().collect::<BTreeSet<String>>();
+    let dependency_paths = vec!["variantmeshes/dependency/legs.rigid_model_v2".to_owned()].into_iter().collect::<BTreeSet<String>>();
+
+    // Paths are found case-insensitively, with any kind of slash, and both as PackedFiles and as folders.
+    let paths = vec![
+        "VariantMeshes\\mod\\head.rigid_model_v2".to_owned(),
+        "variantmeshes/vanilla/body.rigid_model_v2".to_owned(),
+        "variantmeshes/dependency/legs.rigid_model_v2 ".to_owned(),
+        "/variantmeshes/vanilla/".to_owned(),
+        "variantmeshes/vanilla/missing.rigid_model_v2".to_owned(),
+        "variantmeshes/vani".to_owned(),
+    ];
+    assert_eq!(pack_file.get_missing_paths(&paths, &[&vanilla_paths, &dependency_paths]), vec![
+        "variantmeshes/vanilla/missing.rigid_model_v2".to_owned(),
+        "variantmeshes/vani".to_owned(),
+    ]);
+    assert_eq!(pack_file.get_missing_paths(&paths[1..3], &[]), paths[1..3].to_vec());
+}
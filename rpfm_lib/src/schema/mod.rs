@@ -54,6 +54,7 @@ The basic structure of an `Schema` is:
                     ),
                 ],
                 localised_fields: [],
+                is_ordered: false,
             ),
         ]),
     ],
@@ -63,20 +64,24 @@ The basic structure of an `Schema` is:
 Inside the schema there are `VersionedFile` variants of different types, with a Vec of `Definition`, one for each version of that PackedFile supported.
 !*/
 
+use csv::{ReaderBuilder, WriterBuilder};
 use git2::Repository;
 use itertools::Itertools;
 use rayon::prelude::*;
-use ron::de::from_reader;
+use ron::de::{from_reader, from_str};
 use ron::ser::{to_string_pretty, PrettyConfig};
 use serde_derive::{Serialize, Deserialize};
+use xz2::read::XzDecoder;
+use xz2::write::XzEncoder;
 
 use std::collections::BTreeMap;
 use std::cmp::Ordering;
 use std::fs::{DirBuilder, File};
 use std::{fmt, fmt::Display};
-use std::io::{BufReader, Write};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
 
-use rpfm_error::{ErrorKind, Result};
+use rpfm_error::{Error, ErrorKind, Result};
 
 use crate::assembly_kit::localisable_fields::RawLocalisableField;
 use crate::assembly_kit::table_definition::{RawDefinition, RawField};
@@ -87,13 +92,23 @@ use crate::config::get_config_path;
 use crate::packedfile::table::db::DB;
 
 // Legacy Schemas, to keep backwards compatibility during updates.
+pub mod patch;
 pub(crate) mod v2;
 pub(crate) mod v1;
 pub(crate) mod v0;
 
+#[cfg(test)]
+mod schema_test;
+
 /// Name of the folder containing all the schemas.
 pub const SCHEMA_FOLDER: &str = "schemas";
 
+/// Extension of the files with the schemas of all games, used to share them without access to the schema repository.
+pub const SCHEMA_BUNDLE_EXTENSION: &str = "rpfm_schemas";
+
+/// Name of the folder where the schemas are copied before updating them, so we can know what changed with the update.
+pub const SCHEMA_BACKUP_FOLDER: &str = "schemas_backup";
+
 const BINARY_EXTENSION: &str = ".bin";
 
 pub const SCHEMA_REPO: &str = "https://github.com/Frodo45127/rpfm-schemas";
@@ -156,6 +171,10 @@ pub struct Definition {
 
     /// This is a list of all the fields from this definition that are moved to a Loc PackedFile on exporting.
     localised_fields: Vec<Field>,
+
+    /// If the order of the rows of the PackedFile matters to the game, so they can be reordered in the UI.
+    #[serde(default)]
+    is_ordered: bool,
 }
 
 /// This struct holds all the relevant data do properly decode a field from a versioned PackedFile.
@@ -199,7 +218,11 @@ pub struct Field {
     is_bitwise: i32,
 
     /// Variable that specifies the "Enum" values for each value in this field.
-    enum_values: BTreeMap<i32, String>
+    enum_values: BTreeMap<i32, String>,
+
+    /// Optional descriptions of the "Enum" values of this field, by their index.
+    #[serde(default)]
+    enum_descriptions: BTreeMap<i32, String>,
 }
 
 /// This enum defines every type of field the lib can encode/decode.
@@ -218,6 +241,34 @@ pub enum FieldType {
     SequenceU32(Definition)
 }
 
+/// This struct holds the changes an schema update did to the definitions of a DB Table.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct TableDefinitionChanges {
+
+    /// Name of the table.
+    pub table_name: String,
+
+    /// If the table was not in the old schema.
+    pub is_new_table: bool,
+
+    /// Versions of the table not in the old schema.
+    pub new_versions: Vec<i32>,
+
+    /// Fields of the last version of the table not in the last version of the old schema.
+    pub new_fields: Vec<String>,
+}
+
+/// This struct represents a bundle with the schemas of all games, used to share them without access to the schema repository.
+#[derive(Debug, Serialize, Deserialize)]
+struct SchemaBundle {
+
+    /// The structural version of the schemas in the bundle.
+    version: u16,
+
+    /// The contents of each schema file, by file name.
+    schemas: BTreeMap<String, String>,
+}
+
 /// This enum controls the possible responses from the server when asking if there is a new Schema update.
 #[derive(Debug, Serialize, Deserialize)]
 pub enum APIResponseSchema {
@@ -563,6 +614,315 @@ impl Schema {
         Ok(())
     }
 
+    /// This function exports the DB part of the provided `Schema` as a documentation-friendly `.json` file.
+    ///
+    /// Unlike `export_to_json`, this doesn't dump the raw schema, but a simplified structure (tables, versions,
+    /// fields, descriptions and references) meant to be consumed by tools that generate documentation sites.
+    pub fn export_docs_to_json(&self, path: &Path) -> Result<()> {
+        let tables = self.get_docs_tables().iter()
+            .map(|(table_name, definitions)| {
+                let versions = definitions.iter().map(|definition| {
+                    let fields = definition.get_ref_fields().iter().map(|field| {
+                        serde_json::json!({
+                            "name": field.get_name(),
+                            "type": field.get_ref_field_type().to_string(),
+                            "is_key": field.get_is_key(),
+                            "default_value": field.get_default_value(),
+                            "description": field.get_description(),
+                            "reference": field.get_is_reference().as_ref().map(|(table, column)| serde_json::json!({ "table": table, "column": column })),
+                            "lookup": field.get_lookup(),
+                            "enum_values": field.get_enum_values(),
+                            "enum_descriptions": field.get_enum_descriptions(),
+                        })
+                    }).collect::<Vec<serde_json::Value>>();
+
+                    serde_json::json!({
+                        "version": definition.get_version(),
+                        "fields": fields,
+                        "localised_fields": definition.get_localised_fields().iter().map(|x| x.get_name()).collect::<Vec<&str>>(),
+                    })
+                }).collect::<Vec<serde_json::Value>>();
+
+                serde_json::json!({
+                    "name": table_name,
+                    "versions": versions,
+                })
+            }).collect::<Vec<serde_json::Value>>();
+
+        let mut file = File::create(path)?;
+        file.write_all(serde_json::to_string_pretty(&serde_json::json!({ "tables": tables }))?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function exports the DB part of the provided `Schema` as a standalone `.html` file, with one section per table.
+    ///
+    /// References are exported as links to the section of the referenced table, so the result can be browsed as-is.
+    pub fn export_docs_to_html(&self, path: &Path) -> Result<()> {
+        let tables = self.get_docs_tables();
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>DB Tables</title>\n</head>\n<body>\n");
+
+        // Index first, so people can jump directly to the table they want.
+        html.push_str("<h1>DB Tables</h1>\n<ul>\n");
+        for (table_name, _) in &tables {
+            html.push_str(&format!("<li><a href=\"#{0}\">{0}</a></li>\n", escape_html(table_name)));
+        }
+        html.push_str("</ul>\n");
+
+        for (table_name, definitions) in &tables {
+            html.push_str(&format!("<h2 id=\"{0}\">{0}</h2>\n", escape_html(table_name)));
+            for definition in definitions {
+                html.push_str(&format!("<h3>Version {}</h3>\n", definition.get_version()));
+                html.push_str("<table>\n<tr><th>Name</th><th>Type</th><th>Key</th><th>Default Value</th><th>Reference</th><th>Description</th></tr>\n");
+                for field in definition.get_ref_fields() {
+                    let reference = match field.get_is_reference() {
+                        Some((table, column)) => format!("<a href=\"#{0}_tables\">{0}</a>/{1}", escape_html(table), escape_html(column)),
+                        None => String::new(),
+                    };
+
+                    html.push_str(&format!("<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                        escape_html(field.get_name()),
+                        escape_html(&field.get_ref_field_type().to_string()),
+                        field.get_is_key(),
+                        escape_html(field.get_default_value().as_deref().unwrap_or("")),
+                        reference,
+                        escape_html(field.get_description()),
+                    ));
+                }
+                html.push_str("</table>\n");
+            }
+        }
+
+        html.push_str("</body>\n</html>\n");
+
+        let mut file = File::create(path)?;
+        file.write_all(html.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the DB tables of the provided `Schema`, sorted by name, with their definitions sorted from newer to older.
+    fn get_docs_tables(&self) -> Vec<(&str, Vec<&Definition>)> {
+        let mut tables = self.versioned_files.iter()
+            .filter_map(|x| if let VersionedFile::DB(table_name, definitions) = x {
+                let mut definitions = definitions.iter().collect::<Vec<&Definition>>();
+                definitions.sort_by(|a, b| b.get_version().cmp(&a.get_version()));
+                Some((&**table_name, definitions))
+            } else { None })
+            .collect::<Vec<(&str, Vec<&Definition>)>>();
+        tables.sort_by(|a, b| a.0.cmp(b.0));
+        tables
+    }
+
+    /// This function exports the descriptions of the fields of the DB and Loc tables of the provided `Schema` to a `.csv` file.
+    ///
+    /// Each row is a (table, version, field, description) tuple, so the descriptions can be edited in a spreadsheet and imported back.
+    pub fn export_descriptions_to_csv(&self, path: &Path) -> Result<()> {
+        let mut writer = WriterBuilder::new().from_path(path)?;
+        writer.write_record(&["table", "version", "field", "description"])?;
+
+        let mut tables = self.get_docs_tables();
+        if let Ok(VersionedFile::Loc(definitions)) = self.get_ref_versioned_file_loc() {
+            tables.push(("loc", definitions.iter().collect()));
+        }
+
+        for (table_name, definitions) in &tables {
+            for definition in definitions {
+                for field in definition.get_ref_fields() {
+                    writer.write_record(&[*table_name, &definition.get_version().to_string(), field.get_name(), field.get_description()])?;
+                }
+            }
+        }
+
+        writer.flush().map_err(From::from)
+    }
+
+    /// This function imports the descriptions of a `.csv` file made with `export_descriptions_to_csv` into the provided `Schema`.
+    ///
+    /// Rows whose table, version or field don't exist in the `Schema` are ignored. It returns the amount of descriptions changed.
+    pub fn import_descriptions_from_csv(&mut self, path: &Path) -> Result<usize> {
+        let mut reader = ReaderBuilder::new().has_headers(true).from_path(path)?;
+        let mut changes = 0;
+        for record in reader.records() {
+            let record = record?;
+            if record.len() < 4 {
+                return Err(ErrorKind::ImportCSVWrongFormat(record.position().map_or(0, |position| position.line())).into());
+            }
+
+            let version = match record[1].parse::<i32>() {
+                Ok(version) => version,
+                Err(_) => return Err(ErrorKind::ImportCSVWrongFormat(record.position().map_or(0, |position| position.line())).into()),
+            };
+
+            let versioned_file = if &record[0] == "loc" { self.get_ref_mut_versioned_file_loc() } else { self.get_ref_mut_versioned_file_db(&record[0]) };
+            if let Ok(versioned_file) = versioned_file {
+                if let Ok(definition) = versioned_file.get_ref_mut_version(version) {
+                    if let Some(field) = definition.get_ref_mut_fields().iter_mut().find(|field| field.get_name() == &record[2]) {
+                        if field.description != record[3] {
+                            field.description = record[3].to_owned();
+                            changes += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// This function changes the description of a field of the provided table and version. Use `loc` as table name for Loc tables.
+    pub fn set_field_description(&mut self, table_name: &str, version: i32, field_name: &str, description: &str) -> Result<()> {
+        let versioned_file = if table_name == "loc" { self.get_ref_mut_versioned_file_loc()? } else { self.get_ref_mut_versioned_file_db(table_name)? };
+        let definition = versioned_file.get_ref_mut_version(version)?;
+        match definition.get_ref_mut_fields().iter_mut().find(|field| field.get_name() == field_name) {
+            Some(field) => {
+                field.set_description(description);
+                Ok(())
+            }
+            None => Err(ErrorKind::SchemaDefinitionNotFound.into()),
+        }
+    }
+
+    /// This function loads a `Schema` to memory from a file in the `schemas_backup/` folder.
+    pub fn load_backup(schema_file: &str) -> Result<Self> {
+        let mut file_path = get_config_path()?.join(SCHEMA_BACKUP_FOLDER);
+        file_path.push(schema_file);
+
+        let file = BufReader::new(File::open(&file_path)?);
+        from_reader(file).map_err(From::from)
+    }
+
+    /// This function copies the schema files in the `schemas/` folder to the `schemas_backup/` folder, replacing the previous backup.
+    pub fn backup() -> Result<()> {
+        let schema_path = get_schemas_path()?;
+        let backup_path = get_config_path()?.join(SCHEMA_BACKUP_FOLDER);
+
+        let _ = std::fs::remove_dir_all(&backup_path);
+        DirBuilder::new().recursive(true).create(&backup_path)?;
+
+        for entry in std::fs::read_dir(&schema_path)? {
+            let path = entry?.path();
+            if path.is_file() {
+                if let Some(file_name) = path.file_name() {
+                    std::fs::copy(&path, backup_path.join(file_name))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// This function returns the changes done to the DB Table definitions in the provided `Schema` compared to this one.
+    ///
+    /// Only new tables, versions and fields are reported.
+    pub fn get_db_definition_changes(&self, new_schema: &Self) -> Vec<TableDefinitionChanges> {
+        let old_tables = self.versioned_files.iter().filter_map(|versioned_file| match versioned_file {
+            VersionedFile::DB(table_name, definitions) => Some((table_name, definitions)),
+            _ => None,
+        }).collect::<BTreeMap<&String, &Vec<Definition>>>();
+
+        new_schema.versioned_files.iter().filter_map(|versioned_file| match versioned_file {
+            VersionedFile::DB(table_name, definitions) => {
+                let mut changes = TableDefinitionChanges {
+                    table_name: table_name.to_owned(),
+                    ..Default::default()
+                };
+
+                match old_tables.get(table_name) {
+                    Some(old_definitions) => {
+                        changes.new_versions = definitions.iter()
+                            .map(|definition| definition.version)
+                            .filter(|version| !old_definitions.iter().any(|old_definition| old_definition.version == *version))
+                            .collect();
+
+                        if let (Some(last_definition), Some(old_last_definition)) = (
+                            definitions.iter().max_by_key(|definition| definition.version),
+                            old_definitions.iter().max_by_key(|definition| definition.version)
+                        ) {
+                            changes.new_fields = last_definition.fields.iter()
+                                .filter(|field| !old_last_definition.fields.iter().any(|old_field| old_field.name == field.name))
+                                .map(|field| field.name.to_owned())
+                                .collect();
+                        }
+                    }
+                    None => {
+                        changes.is_new_table = true;
+                        changes.new_versions = definitions.iter().map(|definition| definition.version).collect();
+                    }
+                }
+
+                if changes.is_new_table || !changes.new_versions.is_empty() || !changes.new_fields.is_empty() { Some(changes) } else { None }
+            }
+            _ => None,
+        }).collect()
+    }
+
+    /// This function returns the changes done to the DB Table definitions of each game by the last schema update, by game key.
+    ///
+    /// Games whose schema is missing, either in the `schemas/` or in the `schemas_backup/` folder, or has no changes, are not returned.
+    pub fn get_last_update_changes() -> BTreeMap<String, Vec<TableDefinitionChanges>> {
+        SUPPORTED_GAMES.iter().filter_map(|(game_key, game_info)| {
+            let old_schema = Self::load_backup(&game_info.schema).ok()?;
+            let new_schema = Self::load(&game_info.schema).ok()?;
+            let changes = old_schema.get_db_definition_changes(&new_schema);
+            if changes.is_empty() { None } else { Some((game_key.to_string(), changes)) }
+        }).collect()
+    }
+
+    /// This function exports the schemas of all games in the `schemas/` folder to a compressed bundle in the provided path.
+    ///
+    /// It returns the amount of schemas exported.
+    pub fn export_bundle(path: &Path) -> Result<usize> {
+        let schema_path = get_schemas_path()?;
+        let schemas = SUPPORTED_GAMES.iter()
+            .filter_map(|(_, game_info)| std::fs::read_to_string(schema_path.join(&game_info.schema)).ok().map(|data| (game_info.schema.to_owned(), data)))
+            .collect::<BTreeMap<String, String>>();
+
+        if schemas.is_empty() {
+            return Err(ErrorKind::SchemaNotFound.into());
+        }
+
+        let bundle = SchemaBundle {
+            version: CURRENT_STRUCTURAL_VERSION,
+            schemas,
+        };
+
+        let mut encoder = XzEncoder::new(BufWriter::new(File::create(path)?), 9);
+        bincode::serialize_into(&mut encoder, &bundle)?;
+        encoder.finish()?;
+        Ok(bundle.schemas.len())
+    }
+
+    /// This function imports the schemas of a bundle made with `export_bundle` into the `schemas/` folder.
+    ///
+    /// All the schemas of the bundle are checked before importing any of them, and the current schemas are backed up to the `schemas_backup/` folder.
+    /// It returns the amount of schemas imported.
+    pub fn import_bundle(path: &Path) -> Result<usize> {
+        let decoder = XzDecoder::new(BufReader::new(File::open(path)?));
+        let bundle: SchemaBundle = bincode::deserialize_from(decoder).map_err(|_| Error::from(ErrorKind::SchemaBundleInvalid))?;
+        if bundle.version != CURRENT_STRUCTURAL_VERSION {
+            return Err(ErrorKind::SchemaBundleInvalid.into());
+        }
+
+        // Only accept schemas of the games we support, and only if they can be loaded.
+        let schema_files = SUPPORTED_GAMES.iter().map(|(_, game_info)| &game_info.schema).collect::<Vec<&String>>();
+        for (schema_file, data) in &bundle.schemas {
+            if !schema_files.contains(&schema_file) || from_str::<Self>(data).is_err() {
+                return Err(ErrorKind::SchemaBundleInvalid.into());
+            }
+        }
+
+        let schema_path = get_schemas_path()?;
+        if schema_path.is_dir() {
+            Self::backup()?;
+        }
+
+        DirBuilder::new().recursive(true).create(&schema_path)?;
+        for (schema_file, data) in &bundle.schemas {
+            File::create(schema_path.join(schema_file))?.write_all(data.as_bytes())?;
+        }
+
+        Ok(bundle.schemas.len())
+    }
+
     /// This function allow us to update all Schemas from any legacy version into the current one.
     ///
     /// NOTE FOR DEV: If you make a new Schema Version, add its update function here.
@@ -602,14 +962,26 @@ impl Schema {
         }
     }
 
+    /// This function returns the revision (commit hash) of the local schema repository.
+    pub fn get_schema_revision() -> Result<String> {
+        let repo = Repository::open(get_schemas_path()?)?;
+        let commit = repo.head()?.peel_to_commit()?;
+        Ok(commit.id().to_string())
+    }
+
     /// This function downloads the latest revision of the schema repository.
+    ///
+    /// The current schemas are backed up to the `schemas_backup/` folder before updating them.
     pub fn update_schema_repo() -> Result<()> {
         let schema_path = get_schemas_path()?;
         let repo = match Repository::open(&schema_path) {
             Ok(repo) => repo,
             Err(_) => {
 
-                // Make sure we remnove the folder if exists.
+                // Keep the old schemas, if any, before replacing them. Then make sure we remnove the folder if exists.
+                if schema_path.is_dir() {
+                    Self::backup()?;
+                }
                 let _ = std::fs::remove_dir_all(&schema_path);
                 DirBuilder::new().recursive(true).create(&schema_path)?;
                 match Repository::clone(SCHEMA_REPO, &schema_path) {
@@ -630,6 +1002,7 @@ impl Schema {
         }
 
         else if analysis.0.is_fast_forward() {
+            Self::backup()?;
             let refname = format!("refs/heads/{}", BRANCH);
             let mut reference = repo.find_reference(&refname)?;
             reference.set_target(fetch_commit.id(), "Fast-Forward")?;
@@ -761,6 +1134,64 @@ impl VersionedFile {
         }
     }
 
+    /// This function propagates the metadata of the fields of the provided definition to the rest of versions in this `VersionedFile`.
+    ///
+    /// Fields are matched by name and type. If the fields of the definition before being edited are provided, they're used to find
+    /// the fields in the other versions, so renamed fields are propagated too. Only the following data is propagated:
+    /// - Name.
+    /// - Description.
+    /// - Is Reference.
+    /// - Lookup.
+    ///
+    /// It returns the list of versions that got updated.
+    pub fn propagate_metadata(&mut self, definition: &Definition, old_fields: Option<&[Field]>) -> Vec<i32> {
+
+        // Only use the old fields to match if the structure of the definition didn't change.
+        let old_fields = old_fields.filter(|old_fields| old_fields.len() == definition.fields.len() &&
+            old_fields.iter().zip(definition.fields.iter()).all(|(old_field, field)| old_field.field_type == field.field_type));
+
+        let versions = match self {
+            VersionedFile::AnimFragment(versions) |
+            VersionedFile::AnimTable(versions) |
+            VersionedFile::DB(_, versions) |
+            VersionedFile::DepManager(versions) |
+            VersionedFile::Loc(versions) |
+            VersionedFile::MatchedCombat(versions) => versions,
+        };
+
+        let mut versions_updated = vec![];
+        for version in versions.iter_mut().filter(|x| x.version != definition.version) {
+            let mut updated = false;
+            for (index, field) in definition.fields.iter().enumerate() {
+                let name = match old_fields {
+                    Some(old_fields) => &old_fields[index].name,
+                    None => &field.name,
+                };
+
+                // Do not rename a field if the version already has another field with the new name.
+                if *name != field.name && version.fields.iter().any(|x| x.name == field.name) {
+                    continue;
+                }
+
+                if let Some(target) = version.fields.iter_mut().find(|x| x.name == *name && x.field_type == field.field_type) {
+                    if target.name != field.name || target.description != field.description || target.is_reference != field.is_reference || target.lookup != field.lookup {
+                        target.name = field.name.to_owned();
+                        target.description = field.description.to_owned();
+                        target.is_reference = field.is_reference.clone();
+                        target.lookup = field.lookup.clone();
+                        updated = true;
+                    }
+                }
+            }
+
+            if updated {
+                versions_updated.push(version.version);
+            }
+        }
+
+        versions_updated
+    }
+
     /// This function tries to remove a specific version from the provided `VersionedFile`.
     ///
     /// If the version doesn't exist, it does nothing.
@@ -774,6 +1205,16 @@ impl VersionedFile {
             VersionedFile::MatchedCombat(versions) => if let Some(position) = versions.iter_mut().position(|x| x.version == version) { versions.remove(position); }
         }
     }
+
+    /// This function copies the provided version as a new version, replacing the new version if it already exists.
+    ///
+    /// Useful when a game update adds a field or two to a table, as the new version is mostly the old one.
+    pub fn clone_version(&mut self, version: i32, new_version: i32) -> Result<()> {
+        let mut definition = self.get_version(version)?.clone();
+        definition.version = new_version;
+        self.add_version(&definition);
+        Ok(())
+    }
 }
 
 /// Implementation of `Definition`.
@@ -785,6 +1226,7 @@ impl Definition {
             version,
             localised_fields: vec![],
             fields: vec![],
+            is_ordered: false,
         }
     }
 
@@ -817,6 +1259,21 @@ impl Definition {
         &self.localised_fields
     }
 
+    /// This function returns a mutable reference to the localised fields of the provided definition.
+    pub fn get_ref_mut_localised_fields(&mut self) -> &mut Vec<Field> {
+        &mut self.localised_fields
+    }
+
+    /// This function returns if the order of the rows of the PackedFiles using this definition matters.
+    pub fn get_is_ordered(&self) -> bool {
+        self.is_ordered
+    }
+
+    /// This function sets if the order of the rows of the PackedFiles using this definition matters.
+    pub fn set_is_ordered(&mut self, is_ordered: bool) {
+        self.is_ordered = is_ordered;
+    }
+
     /// This function returns the list of fields a table contains, after it has been expanded/changed due to the attributes of each field.
     pub fn get_fields_processed(&self) -> Vec<Field> {
         self.get_ref_fields().iter()
@@ -1062,7 +1519,8 @@ impl Field {
             description,
             ca_order,
             is_bitwise,
-            enum_values
+            enum_values,
+            enum_descriptions: BTreeMap::new(),
         }
     }
 
@@ -1131,6 +1589,11 @@ impl Field {
         &self.lookup
     }
 
+    /// Setter for the `description` field.
+    pub fn set_description(&mut self, description: &str) {
+        self.description = description.to_owned();
+    }
+
     /// Getter for the `description` field.
     pub fn get_description(&self) -> &str {
         &self.description
@@ -1161,6 +1624,26 @@ impl Field {
     pub fn get_enum_values_to_string(&self) -> String {
         self.enum_values.iter().map(|(x, y)| format!("{},{}", x, y)).join(";")
     }
+
+    /// Getter for the `enum_descriptions` field.
+    pub fn get_enum_descriptions(&self) -> &BTreeMap<i32, String> {
+        &self.enum_descriptions
+    }
+
+    /// Setter for the `enum_descriptions` field. Descriptions of indexes without an "Enum" value are ignored.
+    pub fn set_enum_descriptions(&mut self, enum_descriptions: BTreeMap<i32, String>) {
+        self.enum_descriptions = enum_descriptions.into_iter()
+            .filter(|(index, description)| self.enum_values.contains_key(index) && !description.is_empty())
+            .collect();
+    }
+
+    /// This function returns the description of the "Enum" value with the provided name, if it has one.
+    pub fn get_enum_description_by_name(&self, name: &str) -> Option<&str> {
+        self.enum_values.iter()
+            .find(|(_, value)| *value == name)
+            .and_then(|(index, _)| self.enum_descriptions.get(index))
+            .map(|description| &**description)
+    }
 }
 
 /// Default implementation of `Schema`.
@@ -1190,6 +1673,7 @@ impl Default for Field {
             ca_order: -1,
             is_bitwise: 0,
             enum_values: BTreeMap::new(),
+            enum_descriptions: BTreeMap::new(),
         }
     }
 }
@@ -1266,3 +1750,15 @@ impl From<&RawField> for Field {
         field
     }
 }
+
+//---------------------------------------------------------------------------//
+//                              Utility functions
+//---------------------------------------------------------------------------//
+
+/// This function escapes the characters of the provided string that have special meaning in HTML.
+fn escape_html(string: &str) -> String {
+    string.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,185 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to deal with the local patches of the schemas.
+
+A schema patch holds info the user adds to the columns of the tables of a game, like the units of their values.
+They're kept in their own files, in the `schema_patches/` folder, so they're not lost when the schemas are updated.
+!*/
+
+use regex::{Captures, Regex};
+use ron::de::from_reader;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, File};
+use std::{fmt, fmt::Display};
+use std::io::{BufReader, Write};
+
+use rpfm_error::{ErrorKind, Result};
+
+use crate::config::get_config_path;
+
+/// Name of the folder containing all the schema patches.
+pub const SCHEMA_PATCHES_FOLDER: &str = "schema_patches";
+
+/// List of units a column can have, in the order they should be shown to the user.
+pub const FIELD_UNITS: [FieldUnit; 5] = [
+    FieldUnit::Percentage,
+    FieldUnit::PercentageAsFraction,
+    FieldUnit::Seconds,
+    FieldUnit::Milliseconds,
+    FieldUnit::Meters,
+];
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents the local patch of the schema of a game.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaPatch {
+
+    /// Units of the columns, by table name and column name.
+    units: BTreeMap<String, BTreeMap<String, FieldUnit>>,
+}
+
+/// This enum represents the logical units the values of a numeric column can be in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldUnit {
+
+    /// Percentages stored as they're shown, so `20%` is stored as `20`.
+    Percentage,
+
+    /// Percentages stored as fractions of one, so `20%` is stored as `0.2`.
+    PercentageAsFraction,
+    Seconds,
+    Milliseconds,
+    Meters,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+/// Implementation of `SchemaPatch`.
+impl SchemaPatch {
+
+    /// This function loads the patch of the provided schema file from the `schema_patches/` folder.
+    ///
+    /// If the schema has no patch yet, it returns an empty one.
+    pub fn load(schema_file: &str) -> Result<Self> {
+        let file_path = get_config_path()?.join(SCHEMA_PATCHES_FOLDER).join(schema_file);
+        if !file_path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let file = BufReader::new(File::open(&file_path)?);
+        from_reader(file).map_err(From::from)
+    }
+
+    /// This function saves the patch of the provided schema file to the `schema_patches/` folder.
+    pub fn save(&self, schema_file: &str) -> Result<()> {
+        let mut file_path = get_config_path()?.join(SCHEMA_PATCHES_FOLDER);
+        DirBuilder::new().recursive(true).create(&file_path)?;
+
+        file_path.push(schema_file);
+        let mut file = File::create(&file_path)?;
+        let config = PrettyConfig::default();
+        file.write_all(to_string_pretty(&self, config)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the unit of the provided column of the provided table, if it has one.
+    pub fn get_unit(&self, table_name: &str, field_name: &str) -> Option<FieldUnit> {
+        self.units.get(table_name).and_then(|fields| fields.get(field_name)).copied()
+    }
+
+    /// This function sets the unit of the provided column of the provided table. Pass `None` to remove it.
+    pub fn set_unit(&mut self, table_name: &str, field_name: &str, unit: Option<FieldUnit>) {
+        match unit {
+            Some(unit) => { self.units.entry(table_name.to_owned()).or_insert_with(BTreeMap::new).insert(field_name.to_owned(), unit); },
+            None => if let Some(fields) = self.units.get_mut(table_name) {
+                fields.remove(field_name);
+                if fields.is_empty() {
+                    self.units.remove(table_name);
+                }
+            }
+        }
+    }
+}
+
+/// Implementation of `FieldUnit`.
+impl FieldUnit {
+
+    /// This function returns the symbol used to show the unit in the headers of the columns.
+    pub fn get_symbol(self) -> &'static str {
+        match self {
+            Self::Percentage | Self::PercentageAsFraction => "%",
+            Self::Seconds => "s",
+            Self::Milliseconds => "ms",
+            Self::Meters => "m",
+        }
+    }
+
+    /// This function returns the factor to turn a value with the provided suffix into the stored representation of this unit.
+    ///
+    /// It returns None if the suffix is not compatible with this unit.
+    fn get_factor(self, suffix: &str) -> Option<f64> {
+        match (self, suffix) {
+            (Self::Percentage, "%") => Some(1.0),
+            (Self::PercentageAsFraction, "%") => Some(0.01),
+            (Self::Seconds, "ms") => Some(0.001),
+            (Self::Seconds, "s") => Some(1.0),
+            (Self::Seconds, "min") => Some(60.0),
+            (Self::Milliseconds, "ms") => Some(1.0),
+            (Self::Milliseconds, "s") => Some(1000.0),
+            (Self::Milliseconds, "min") => Some(60_000.0),
+            (Self::Meters, "cm") => Some(0.01),
+            (Self::Meters, "m") => Some(1.0),
+            (Self::Meters, "km") => Some(1000.0),
+            _ => None,
+        }
+    }
+
+    /// This function replaces every value with a unit in the provided text (like `1.5s` or `20%`) with its stored representation.
+    ///
+    /// Values with units not compatible with this one are left as they are. So, for a column in seconds, `1.5s + 500ms` becomes `1.5 + 0.5`.
+    pub fn convert_values(self, text: &str) -> String {
+        let regex = Regex::new(r"(-?\d+(?:\.\d+)?)\s*(%|(?:ms|min|s|cm|km|m)\b)").unwrap();
+        regex.replace_all(text, |captures: &Captures| {
+            match (captures[1].parse::<f64>(), self.get_factor(&captures[2])) {
+                (Ok(value), Some(factor)) => (value * factor).to_string(),
+                _ => captures[0].to_owned(),
+            }
+        }).to_string()
+    }
+
+    /// This function parses the provided value, with or without a unit, into the stored representation of this unit.
+    pub fn parse_value(self, text: &str) -> Result<f64> {
+        let text = text.trim();
+        self.convert_values(text).parse::<f64>().map_err(|_| ErrorKind::InvalidUnitValue(text.to_owned(), self.get_symbol().to_owned()).into())
+    }
+}
+
+/// Display implementation of `FieldUnit`.
+impl Display for FieldUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(match self {
+            Self::Percentage => "Percentage (20% = 20)",
+            Self::PercentageAsFraction => "Percentage (20% = 0.2)",
+            Self::Seconds => "Seconds",
+            Self::Milliseconds => "Milliseconds",
+            Self::Meters => "Meters",
+        }, f)
+    }
+}
@@ -0,0 +1,135 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module containing tests for the `Schema` module, to make sure the docs we export don't break, and that metadata propagates between versions.
+!*/
+
+use std::env::temp_dir;
+use std::fs::{read_to_string, remove_file};
+
+use super::{Definition, Field, FieldType, Schema, VersionedFile};
+
+/// This function returns a small `Schema` with two tables, one of them referencing the other.
+fn get_docs_test_schema() -> Schema {
+    let mut units = Definition::new(2);
+    units.fields.push(Field { name: "key".to_owned(), is_key: true, description: "Key of the <unit> & its data.".to_owned(), ..Field::default() });
+    units.fields.push(Field { name: "faction".to_owned(), is_reference: Some(("factions".to_owned(), "key".to_owned())), ..Field::default() });
+
+    let mut units_old = Definition::new(1);
+    units_old.fields.push(Field { name: "key".to_owned(), is_key: true, ..Field::default() });
+
+    let mut factions = Definition::new(1);
+    factions.fields.push(Field { name: "key".to_owned(), is_key: true, ..Field::default() });
+    factions.fields.push(Field { name: "playable".to_owned(), field_type: FieldType::Boolean, default_value: Some("false".to_owned()), ..Field::default() });
+
+    let mut schema = Schema::default();
+    schema.add_versioned_file(&VersionedFile::DB("units_tables".to_owned(), vec![units_old, units]));
+    schema.add_versioned_file(&VersionedFile::DB("factions_tables".to_owned(), vec![factions]));
+    schema
+}
+
+#[test]
+fn test_export_docs_to_json() {
+    let path = temp_dir().join("rpfm_test_export_docs.json");
+    get_docs_test_schema().export_docs_to_json(&path).unwrap();
+    let docs: serde_json::Value = serde_json::from_str(&read_to_string(&path).unwrap()).unwrap();
+    remove_file(&path).unwrap();
+
+    // Tables are sorted by name, and their versions from newer to older.
+    let tables = docs["tables"].as_array().unwrap();
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[0]["name"], "factions_tables");
+    assert_eq!(tables[1]["name"], "units_tables");
+    assert_eq!(tables[1]["versions"][0]["version"], 2);
+    assert_eq!(tables[1]["versions"][1]["version"], 1);
+
+    let fields = &tables[1]["versions"][0]["fields"];
+    assert_eq!(fields[0]["name"], "key");
+    assert_eq!(fields[0]["is_key"], true);
+    assert_eq!(fields[0]["description"], "Key of the <unit> & its data.");
+    assert_eq!(fields[1]["reference"]["table"], "factions");
+    assert_eq!(fields[1]["reference"]["column"], "key");
+    assert!(fields[0]["reference"].is_null());
+
+    let fields = &tables[0]["versions"][0]["fields"];
+    assert_eq!(fields[1]["type"], "Boolean");
+    assert_eq!(fields[1]["default_value"], "false");
+}
+
+#[test]
+fn test_export_docs_to_html() {
+    let path = temp_dir().join("rpfm_test_export_docs.html");
+    get_docs_test_schema().export_docs_to_html(&path).unwrap();
+    let docs = read_to_string(&path).unwrap();
+    remove_file(&path).unwrap();
+
+    // Every table has an entry in the index, and a section with one table per version.
+    assert!(docs.contains("<li><a href=\"#factions_tables\">factions_tables</a></li>"));
+    assert!(docs.contains("<h2 id=\"units_tables\">units_tables</h2>"));
+
+    // Within a table, versions go from newer to older.
+    let units = &docs[docs.find("<h2 id=\"units_tables\">").unwrap()..];
+    assert!(units.find("<h3>Version 2</h3>").unwrap() < units.find("<h3>Version 1</h3>").unwrap());
+    assert_eq!(docs.matches("<table>").count(), 3);
+
+    // References link to the section of the referenced table, and the text is escaped.
+    assert!(docs.contains("<a href=\"#factions_tables\">factions</a>/key"));
+    assert!(docs.contains("Key of the &lt;unit&gt; &amp; its data."));
+    assert!(!docs.contains("<unit>"));
+}
+
+#[test]
+fn test_propagate_metadata() {
+    let mut old_version = Definition::new(1);
+    old_version.fields.push(Field { name: "key".to_owned(), is_key: true, ..Field::default() });
+    old_version.fields.push(Field { name: "old_name".to_owned(), field_type: FieldType::I32, ..Field::default() });
+    old_version.fields.push(Field { name: "other".to_owned(), ..Field::default() });
+
+    let mut edited_version = Definition::new(2);
+    edited_version.fields.push(Field { name: "key".to_owned(), is_key: true, ..Field::default() });
+    edited_version.fields.push(Field { name: "old_name".to_owned(), field_type: FieldType::I32, ..Field::default() });
+    let old_fields = edited_version.fields.to_vec();
+
+    // This version already has a field with the new name, and the new description.
+    let mut new_version = Definition::new(3);
+    new_version.fields.push(Field { name: "key".to_owned(), is_key: true, description: "Key of the unit.".to_owned(), ..Field::default() });
+    new_version.fields.push(Field { name: "old_name".to_owned(), field_type: FieldType::I32, ..Field::default() });
+    new_version.fields.push(Field { name: "renamed".to_owned(), field_type: FieldType::I32, ..Field::default() });
+
+    let mut versioned_file = VersionedFile::DB("units_tables".to_owned(), vec![old_version, edited_version.clone(), new_version.clone()]);
+    edited_version.fields[0].description = "Key of the unit.".to_owned();
+    edited_version.fields[1].name = "renamed".to_owned();
+    edited_version.fields[1].lookup = Some(vec!["onscreen_name".to_owned()]);
+
+    // Renamed fields are only found through the old fields if the structure of the definition didn't change.
+    // Otherwise, fields are matched by their new name.
+    let mut versioned_file_new_structure = versioned_file.clone();
+    let mut old_fields_new_structure = old_fields.clone();
+    old_fields_new_structure[1].field_type = FieldType::I64;
+    assert_eq!(versioned_file_new_structure.propagate_metadata(&edited_version, Some(&old_fields_new_structure)), vec![1, 3]);
+    if let VersionedFile::DB(_, versions) = versioned_file_new_structure {
+        assert_eq!(versions[0].fields[0].description, "Key of the unit.");
+        assert_eq!(versions[0].fields[1].name, "old_name");
+        assert_eq!(versions[2].fields[2].lookup, Some(vec!["onscreen_name".to_owned()]));
+    }
+
+    assert_eq!(versioned_file.propagate_metadata(&edited_version, Some(&old_fields)), vec![1]);
+    if let VersionedFile::DB(_, versions) = versioned_file {
+        assert_eq!(versions[0].fields[0].description, "Key of the unit.");
+        assert_eq!(versions[0].fields[1].name, "renamed");
+        assert_eq!(versions[0].fields[1].lookup, Some(vec!["onscreen_name".to_owned()]));
+        assert_eq!(versions[0].fields[2].name, "other");
+
+        // The edited version is left as it was, and the newer one keeps both fields.
+        assert_eq!(versions[1].fields, old_fields);
+        assert_eq!(versions[2], new_version);
+    }
+}
@@ -40,6 +40,47 @@ pub const ZIP_PATH: &str = "7zip_path";
 /// Key of the MyMod path in the settings";
 pub const MYMOD_BASE_PATH: &str = "mymods_base_path";
 
+/// Key of the SteamCMD path in the settings.
+pub const STEAMCMD_PATH: &str = "steamcmd_path";
+
+/// Key of the Steam user used to upload to the Workshop in the settings.
+pub const WORKSHOP_STEAM_USER: &str = "workshop_steam_user";
+
+/// Keys of the last path rewrite rule (regex and replacement) used when extracting, in the settings.
+pub const PATH_REWRITE_REGEX: &str = "path_rewrite_regex";
+pub const PATH_REWRITE_REPLACEMENT: &str = "path_rewrite_replacement";
+
+/// Keys of the last folders used in file dialogs, by action, in the settings.
+pub const LAST_PATH_OPEN_PACKFILE: &str = "last_path_open_packfile";
+pub const LAST_PATH_ADD_FILE: &str = "last_path_add_file";
+pub const LAST_PATH_EXTRACT: &str = "last_path_extract";
+pub const LAST_PATH_IMPORT_TSV: &str = "last_path_import_tsv";
+pub const LAST_PATH_RUN_JOB: &str = "last_path_run_job";
+pub const LAST_PATHS: [&str; 5] = [LAST_PATH_OPEN_PACKFILE, LAST_PATH_ADD_FILE, LAST_PATH_EXTRACT, LAST_PATH_IMPORT_TSV, LAST_PATH_RUN_JOB];
+
+/// Key of the favorite folders shown in file dialogs in the settings. They're stored as a list separated by `;`.
+pub const FAVORITE_FOLDERS: &str = "favorite_folders";
+
+/// Key of the actions used from the Command Palette in the settings, with how many times each one has been used.
+pub const COMMAND_PALETTE_RECENT_ACTIONS: &str = "command_palette_recent_actions";
+
+/// Key of the Game Selected each PackFile was last saved with, by path, in the settings.
+pub const PACKFILE_GAMES_SELECTED: &str = "packfile_games_selected";
+
+/// Key of the hours between automatic schema updates in the settings. `0` disables them.
+pub const SCHEMA_UPDATE_INTERVAL: &str = "schema_update_interval";
+
+/// Key of the last time the schemas were automatically checked for updates in the settings, as a unix timestamp.
+pub const SCHEMA_LAST_UPDATE_CHECK: &str = "schema_last_update_check";
+
+/// Key of the external tools used to open each file extension in the settings. They're stored one per line, as `extension=command`.
+pub const EXTERNAL_TOOLS: &str = "external_tools";
+
+/// Keys of the zoom level of each kind of view in the settings, as points added to (or removed from) the size of their default font.
+pub const ZOOM_TABLE: &str = "zoom_table";
+pub const ZOOM_TEXT: &str = "zoom_text";
+pub const ZOOM_DECODER: &str = "zoom_decoder";
+
 /// This struct hold every setting of the lib and of RPFM_UI/CLI.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Settings {
@@ -60,6 +101,10 @@ impl Settings {
         let mut settings_bool = BTreeMap::new();
         paths.insert(MYMOD_BASE_PATH.to_owned(), None);
         paths.insert(ZIP_PATH.to_owned(), None);
+        paths.insert(STEAMCMD_PATH.to_owned(), None);
+        for key in LAST_PATHS.iter() {
+            paths.insert((*key).to_string(), None);
+        }
         for (folder_name, _) in SUPPORTED_GAMES.iter() {
             paths.insert((*folder_name).to_string(), None);
         }
@@ -69,6 +114,24 @@ impl Settings {
         settings_string.insert("language".to_owned(), "English_en".to_owned());
         settings_string.insert("font_name".to_owned(), "".to_owned());
         settings_string.insert("font_size".to_owned(), "".to_owned());
+        settings_string.insert("loc_variant_suffixes".to_owned(), "_plural,_female,_female_plural".to_owned());
+        settings_string.insert("tables_f32_decimals".to_owned(), "3".to_owned());
+        settings_string.insert("tables_max_undo_steps".to_owned(), "200".to_owned());
+        settings_string.insert("tables_max_undo_memory_mb".to_owned(), "512".to_owned());
+        settings_string.insert("autosave_interval".to_owned(), "5".to_owned());
+        settings_string.insert("autosave_amount".to_owned(), "10".to_owned());
+        settings_string.insert(FAVORITE_FOLDERS.to_owned(), "".to_owned());
+        settings_string.insert(COMMAND_PALETTE_RECENT_ACTIONS.to_owned(), "".to_owned());
+        settings_string.insert(PACKFILE_GAMES_SELECTED.to_owned(), "".to_owned());
+        settings_string.insert(SCHEMA_UPDATE_INTERVAL.to_owned(), "0".to_owned());
+        settings_string.insert(SCHEMA_LAST_UPDATE_CHECK.to_owned(), "0".to_owned());
+        settings_string.insert(EXTERNAL_TOOLS.to_owned(), "".to_owned());
+        settings_string.insert(ZOOM_TABLE.to_owned(), "0".to_owned());
+        settings_string.insert(ZOOM_TEXT.to_owned(), "0".to_owned());
+        settings_string.insert(ZOOM_DECODER.to_owned(), "0".to_owned());
+        settings_string.insert(WORKSHOP_STEAM_USER.to_owned(), "".to_owned());
+        settings_string.insert(PATH_REWRITE_REGEX.to_owned(), "".to_owned());
+        settings_string.insert(PATH_REWRITE_REPLACEMENT.to_owned(), "".to_owned());
 
         // UI Settings.
         settings_bool.insert("adjust_columns_to_content".to_owned(), true);
@@ -78,15 +141,22 @@ impl Settings {
         settings_bool.insert("use_dark_theme".to_owned(), false);
         settings_bool.insert("tight_table_mode".to_owned(), false);
         settings_bool.insert("hide_background_icon".to_owned(), false);
+        settings_bool.insert("restore_last_session_on_start".to_owned(), false);
+        settings_bool.insert("tour_completed".to_owned(), false);
+        settings_bool.insert("tables_show_column_descriptions".to_owned(), false);
 
         // Behavioral Settings.
         settings_bool.insert("allow_editing_of_ca_packfiles".to_owned(), false);
         settings_bool.insert("check_updates_on_start".to_owned(), true);
         settings_bool.insert("check_schema_updates_on_start".to_owned(), true);
-        settings_bool.insert("use_dependency_checker".to_owned(), false);
+        settings_bool.insert("use_dependency_checker".to_owned(), true);
         settings_bool.insert("use_lazy_loading".to_owned(), true);
         settings_bool.insert("optimize_not_renamed_packedfiles".to_owned(), false);
+        settings_bool.insert("optimize_sort_table_rows".to_owned(), false);
         settings_bool.insert("disable_uuid_regeneration_on_db_tables".to_owned(), false);
+        settings_bool.insert("allow_overwriting_vanilla_packfiles".to_owned(), false);
+        settings_bool.insert("use_git_on_mymods".to_owned(), false);
+        settings_bool.insert("normalize_timestamps_on_save".to_owned(), false);
 
         // Debug Settings.
         settings_bool.insert("check_for_missing_table_definitions".to_owned(), false);
@@ -95,6 +165,9 @@ impl Settings {
         // TableView Specific Settings.
         settings_bool.insert("remember_column_sorting".to_owned(), true);
         settings_bool.insert("remember_column_visual_order".to_owned(), true);
+        settings_bool.insert("tables_use_thousands_separator".to_owned(), false);
+        settings_bool.insert("tables_show_timestamps_as_dates".to_owned(), false);
+        settings_bool.insert("tables_complete_with_vanilla_values".to_owned(), false);
 
         Self {
             paths,
@@ -134,6 +207,20 @@ impl Settings {
         Ok(settings)
     }
 
+    /// This function returns the command of the external tool configured to open files with the provided extension, if any.
+    ///
+    /// Extensions are not case-sensitive, and can be written with or without the leading dot.
+    pub fn get_external_tool(&self, extension: &str) -> Option<String> {
+        let extension = extension.trim_start_matches('.').to_lowercase();
+        self.settings_string.get(EXTERNAL_TOOLS)?.lines()
+            .filter_map(|line| {
+                let mut data = line.splitn(2, '=');
+                Some((data.next()?.trim().trim_start_matches('.').to_lowercase(), data.next()?.trim()))
+            })
+            .find(|(tool_extension, command)| *tool_extension == extension && !command.is_empty())
+            .map(|(_, command)| command.to_owned())
+    }
+
     /// This function tries to save the provided `Settings` to disk.
     pub fn save(&self) -> Result<()> {
         let file_path = get_config_path()?.join(SETTINGS_FILE);
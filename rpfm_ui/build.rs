@@ -101,6 +101,7 @@ fn common_config() {
     println!("cargo:rustc-link-search=native=./libs");
     println!("cargo:rustc-link-lib=dylib=qt_subclasses");
     println!("cargo:rustc-link-lib=dylib=KF5TextEditor");
+    println!("cargo:rustc-link-lib=dylib=Qt5Multimedia");
 
     // Force cargo to rerun this script if any of these files is changed.
     println!("cargo:rerun-if-changed=./libs/*");
@@ -0,0 +1,11 @@
+#ifndef AUDIO_PLAYER_H
+#define AUDIO_PLAYER_H
+
+#include "qt_subclasses_global.h"
+#include <QMediaPlayer>
+
+extern "C" QObject* new_audio_player(QObject *parent = nullptr);
+extern "C" void play_audio_file(QObject *player = nullptr, QString *path = nullptr);
+extern "C" void stop_audio(QObject *player = nullptr);
+
+#endif // AUDIO_PLAYER_H
@@ -6,7 +6,7 @@
 #include <QAbstractItemDelegate>
 #include <QDoubleSpinBox>
 
-extern "C" void new_double_spinbox_item_delegate(QObject *parent = nullptr, const int column = 0);
+extern "C" void new_doublespinbox_item_delegate(QObject *parent = nullptr, const int column = 0, const int decimals = 3, const bool use_thousands_separator = false);
 
 class QDoubleSpinBoxItemDelegate : public QStyledItemDelegate
 {
@@ -14,16 +14,19 @@ class QDoubleSpinBoxItemDelegate : public QStyledItemDelegate
 
 public:
 
-    explicit QDoubleSpinBoxItemDelegate(QObject *parent = nullptr);
+    explicit QDoubleSpinBoxItemDelegate(QObject *parent = nullptr, const int decimals = 3, const bool use_thousands_separator = false);
 
     QWidget* createEditor(QWidget *parent, const QStyleOptionViewItem &, const QModelIndex &) const;
     void setEditorData(QWidget *editor, const QModelIndex &index) const;
     void setModelData(QWidget *editor, QAbstractItemModel *model, const QModelIndex &index) const;
     void updateEditorGeometry(QWidget *editor, const QStyleOptionViewItem &option, const QModelIndex &) const;
+    QString displayText(const QVariant &value, const QLocale &locale) const;
 
 signals:
 
 private:
+    int display_decimals;
+    bool thousands_separator;
 };
 
 #endif // DOUBLESPINBOX_ITEM_DELEGATE_H
@@ -0,0 +1,36 @@
+#ifndef PACKED_FILE_TREEVIEW_H
+#define PACKED_FILE_TREEVIEW_H
+
+#include "qt_subclasses_global.h"
+#include <QTreeView>
+#include <QMimeData>
+#include <QStringList>
+
+extern "C" QTreeView* new_packed_file_treeview(QWidget* parent = nullptr);
+extern "C" QStringList* get_dropped_paths(QTreeView* view = nullptr);
+extern "C" void set_drag_out_paths(QTreeView* view = nullptr, QStringList* paths = nullptr);
+
+class PackedFileTreeView : public QTreeView {
+    Q_OBJECT
+
+public:
+    explicit PackedFileTreeView(QWidget* parent = nullptr);
+    QStringList droppedPaths;
+    QStringList dragOutPaths;
+
+signals:
+    void filesDropped(QModelIndex const &index);
+    void dragOutRequested();
+    void itemsMoved(QModelIndex const &index);
+
+protected:
+    void dragEnterEvent(QDragEnterEvent* event) override;
+    void dragMoveEvent(QDragMoveEvent* event) override;
+    void dropEvent(QDropEvent* event) override;
+    void startDrag(Qt::DropActions supportedActions) override;
+
+private:
+    bool hasLocalFiles(const QMimeData* mimeData) const;
+};
+
+#endif // PACKED_FILE_TREEVIEW_H
@@ -6,7 +6,7 @@
 #include <QAbstractItemDelegate>
 #include <QSpinBox>
 
-extern "C" void new_spinbox_item_delegate(QObject *parent = nullptr, const int column = 0, const int integer_type = 0, const bool is_optional = false);
+extern "C" void new_spinbox_item_delegate(QObject *parent = nullptr, const int column = 0, const int integer_type = 0, const bool is_optional = false, const bool use_thousands_separator = false, const bool is_timestamp = false);
 
 class QSpinBoxItemDelegate : public QStyledItemDelegate
 {
@@ -14,18 +14,21 @@ class QSpinBoxItemDelegate : public QStyledItemDelegate
 
 public:
 
-    explicit QSpinBoxItemDelegate(QObject *parent = nullptr, const int integer_type = 0, const bool is_optional = false);
+    explicit QSpinBoxItemDelegate(QObject *parent = nullptr, const int integer_type = 0, const bool is_optional = false, const bool use_thousands_separator = false, const bool is_timestamp = false);
 
     QWidget* createEditor(QWidget *parent, const QStyleOptionViewItem &option, const QModelIndex &index) const;
     void setEditorData(QWidget *editor, const QModelIndex &index) const;
     void setModelData(QWidget *editor, QAbstractItemModel *model, const QModelIndex &index) const;
     void updateEditorGeometry(QWidget *editor, const QStyleOptionViewItem &option, const QModelIndex &index) const;
+    QString displayText(const QVariant &value, const QLocale &locale) const;
 
 signals:
 
 private:
     int type;
     bool optional;
+    bool thousands_separator;
+    bool timestamp;
 };
 
 #endif // SPINBOX_ITEM_DELEGATE_H
@@ -0,0 +1,34 @@
+#ifndef TABLEVIEW_FILTER_H
+#define TABLEVIEW_FILTER_H
+
+#include "qt_subclasses_global.h"
+#include <QList>
+#include <QSortFilterProxyModel>
+#include <QStringList>
+
+extern "C" QSortFilterProxyModel* new_tableview_filter(QObject *parent = nullptr);
+extern "C" void trigger_tableview_filter(QSortFilterProxyModel *filter = nullptr, const QList<int>* columns = nullptr, const QList<int>* operators = nullptr, const QStringList* values = nullptr, const bool match_all = true, const bool case_sensitive = false);
+
+class QTableViewSortFilterProxyModel : public QSortFilterProxyModel
+{
+    Q_OBJECT
+
+public:
+
+    explicit QTableViewSortFilterProxyModel(QObject *parent = nullptr);
+    bool filterAcceptsRow(int source_row, const QModelIndex & source_parent) const;
+    void setConditions(const QList<int> &new_columns, const QList<int> &new_operators, const QStringList &new_values, const bool new_match_all, const bool case_sensitive);
+
+signals:
+
+private:
+    bool conditionMatches(int source_row, const QModelIndex &source_parent, int condition) const;
+
+    QList<int> columns;
+    QList<int> operators;
+    QStringList values;
+    bool match_all;
+    Qt::CaseSensitivity case_sensitivity;
+};
+
+#endif // TABLEVIEW_FILTER_H
@@ -3,12 +3,16 @@
 
 #include "qt_subclasses_global.h"
 #ifdef _WIN32
+#include <KF5/KTextEditor/ktexteditor/ConfigInterface>
 #include <KF5/KTextEditor/ktexteditor/Document>
 #include <KF5/KTextEditor/ktexteditor/Editor>
+#include <KF5/KTextEditor/ktexteditor/MovingInterface>
 #include <KF5/KTextEditor/ktexteditor/View>
 #else
+#include <KTextEditor/ConfigInterface>
 #include <KTextEditor/Document>
 #include <KTextEditor/Editor>
+#include <KTextEditor/MovingInterface>
 #include <KTextEditor/View>
 #endif
 #include <QWidget>
@@ -19,6 +23,12 @@ extern "C" QString* get_text(QWidget* parent = nullptr);
 
 extern "C" void set_text(QWidget* view = nullptr, QString* text = nullptr, QString* highlighting_mode = nullptr);
 
+extern "C" qint64 get_text_revision(QWidget* view = nullptr);
+
 extern "C" void open_text_editor_config(QWidget* parent);
 
+extern "C" int get_text_editor_font_size(QWidget* view = nullptr);
+
+extern "C" void set_text_editor_font_size(QWidget* view = nullptr, int size = 0);
+
 #endif // TEXT_EDITOR_H
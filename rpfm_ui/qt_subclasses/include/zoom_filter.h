@@ -0,0 +1,25 @@
+#ifndef ZOOM_FILTER_H
+#define ZOOM_FILTER_H
+
+#include "qt_subclasses_global.h"
+#include <QAction>
+#include <QEvent>
+#include <QObject>
+
+extern "C" void new_zoom_filter(QObject* widget = nullptr, QAction* zoom_in = nullptr, QAction* zoom_out = nullptr);
+
+class ZoomFilter : public QObject {
+    Q_OBJECT
+
+    public:
+        explicit ZoomFilter(QObject* parent = nullptr, QAction* zoom_in = nullptr, QAction* zoom_out = nullptr);
+
+    protected:
+        bool eventFilter(QObject* watched, QEvent* event) override;
+
+    private:
+        QAction* zoom_in;
+        QAction* zoom_out;
+};
+
+#endif // ZOOM_FILTER_H
@@ -5,6 +5,7 @@
 #-------------------------------------------------
 
 QT       += widgets
+QT       += multimedia
 QT       += KTextEditor
 
 TARGET = qt_subclasses
@@ -28,7 +29,9 @@ DEFINES += QT_DEPRECATED_WARNINGS
 DEFINES += QT_DISABLE_DEPRECATED_BEFORE=0x060000    # disables all the APIs deprecated before Qt 6.0.0
 
 SOURCES += \
+    src/audio_player.cpp \
     src/packed_file_model.cpp \
+    src/packed_file_treeview.cpp \
     src/q_list_of_q_standard_item.cpp \
     src/qstring_item_delegate.cpp \
     src/combobox_item_delegate.cpp \
@@ -36,16 +39,20 @@ SOURCES += \
     src/spinbox_item_delegate.cpp \
     src/doublespinbox_item_delegate.cpp \
     src/tableview_command_palette.cpp \
+    src/tableview_filter.cpp \
     src/tableview_frozen.cpp \
     src/text_editor.cpp \
-    src/treeview_filter.cpp
+    src/treeview_filter.cpp \
+    src/zoom_filter.cpp
 
 INCLUDEPATH += include
 INCLUDEPATH += C:\CraftRoot\include
 
 HEADERS += \
     include/qt_subclasses_global.h \
+    include/audio_player.h \
     include/tableview_command_palette.h \
+    include/tableview_filter.h \
     include/tableview_frozen.h \
     include/combobox_item_delegate.h \
     include/spinbox_item_delegate.h \
@@ -55,7 +62,9 @@ HEADERS += \
     include/qstring_item_delegate.h \
     include/q_list_of_q_standard_item.h \
     include/packed_file_model.h \
-    include/resizable_label.h
+    include/packed_file_treeview.h \
+    include/resizable_label.h \
+    include/zoom_filter.h
 
 release:DESTDIR = release
 release:OBJECTS_DIR = release/.obj
@@ -0,0 +1,22 @@
+#include "audio_player.h"
+#include <QUrl>
+
+// Function to create the audio player from Rust.
+extern "C" QObject* new_audio_player(QObject *parent) {
+    QMediaPlayer* player = new QMediaPlayer(parent);
+    return dynamic_cast<QObject*>(player);
+}
+
+// Function to play the audio file at the provided path, stopping whatever was playing before.
+extern "C" void play_audio_file(QObject *player, QString *path) {
+    QMediaPlayer* media_player = dynamic_cast<QMediaPlayer*>(player);
+    media_player->stop();
+    media_player->setMedia(QUrl::fromLocalFile(*path));
+    media_player->play();
+}
+
+// Function to stop the audio being played.
+extern "C" void stop_audio(QObject *player) {
+    QMediaPlayer* media_player = dynamic_cast<QMediaPlayer*>(player);
+    media_player->stop();
+}
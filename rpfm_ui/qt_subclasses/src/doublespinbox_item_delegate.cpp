@@ -3,15 +3,20 @@
 #include <QDebug>
 #include <QAbstractItemView>
 #include <QDoubleSpinBox>
+#include <QLocale>
 
 // Function to be called from any other language. This assing to the provided column of the provided TableView a QDoubleSpinBoxItemDelegate.
-extern "C" void new_doublespinbox_item_delegate(QObject *parent, const int column) {
-    QDoubleSpinBoxItemDelegate* delegate = new QDoubleSpinBoxItemDelegate(parent);
+extern "C" void new_doublespinbox_item_delegate(QObject *parent, const int column, const int decimals, const bool use_thousands_separator) {
+    QDoubleSpinBoxItemDelegate* delegate = new QDoubleSpinBoxItemDelegate(parent, decimals, use_thousands_separator);
     dynamic_cast<QAbstractItemView*>(parent)->setItemDelegateForColumn(column, delegate);
 }
 
-// Constructor of the QDoubleSpinBoxItemDelegate. Empty, as we don't need to do anything special with it.
-QDoubleSpinBoxItemDelegate::QDoubleSpinBoxItemDelegate(QObject *parent): QStyledItemDelegate(parent) {}
+// Constructor of the QDoubleSpinBoxItemDelegate. We use it to store how the value should be displayed.
+QDoubleSpinBoxItemDelegate::QDoubleSpinBoxItemDelegate(QObject *parent, const int decimals, const bool use_thousands_separator): QStyledItemDelegate(parent)
+{
+    display_decimals = decimals;
+    thousands_separator = use_thousands_separator;
+}
 
 // Function called when the spinbox it's created. Here we configure the limits and decimals of the spinbox.
 QWidget* QDoubleSpinBoxItemDelegate::createEditor(QWidget *parent, const QStyleOptionViewItem &, const QModelIndex &) const {
@@ -39,3 +44,18 @@ void QDoubleSpinBoxItemDelegate::setModelData(QWidget *editor, QAbstractItemMode
 void QDoubleSpinBoxItemDelegate::updateEditorGeometry(QWidget *editor, const QStyleOptionViewItem &option, const QModelIndex &) const {
     editor->setGeometry(option.rect);
 }
+
+// Function called to get the text shown in the cell. Only the displayed text changes, the spinbox still works with the raw value.
+QString QDoubleSpinBoxItemDelegate::displayText(const QVariant &value, const QLocale &locale) const {
+    bool ok;
+    double number = value.toDouble(&ok);
+    if (!ok) {
+        return QStyledItemDelegate::displayText(value, locale);
+    }
+
+    if (thousands_separator) {
+        return locale.toString(number, 'f', display_decimals);
+    }
+
+    return QString::number(number, 'f', display_decimals);
+}
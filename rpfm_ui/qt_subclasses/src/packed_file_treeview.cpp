@@ -0,0 +1,115 @@
+#include "packed_file_treeview.h"
+#include <QDrag>
+#include <QDragEnterEvent>
+#include <QDragMoveEvent>
+#include <QDropEvent>
+#include <QMimeData>
+#include <QUrl>
+
+// Function to create the PackedFileTreeView from Rust.
+extern "C" QTreeView* new_packed_file_treeview(QWidget* parent) {
+    PackedFileTreeView* view = new PackedFileTreeView(parent);
+    return dynamic_cast<QTreeView*>(view);
+}
+
+// Function to get the paths of the files/folders dropped last into the view.
+extern "C" QStringList* get_dropped_paths(QTreeView* view) {
+    PackedFileTreeView* packed_file_view = dynamic_cast<PackedFileTreeView*>(view);
+    return new QStringList(packed_file_view->droppedPaths);
+}
+
+// Function to set the paths of the files/folders on disk the current drag out of the view represents.
+extern "C" void set_drag_out_paths(QTreeView* view, QStringList* paths) {
+    PackedFileTreeView* packed_file_view = dynamic_cast<PackedFileTreeView*>(view);
+    packed_file_view->dragOutPaths = *paths;
+}
+
+PackedFileTreeView::PackedFileTreeView(QWidget* parent): QTreeView(parent) {
+    setDragEnabled(true);
+    setAcceptDrops(true);
+    setDropIndicatorShown(true);
+    setDragDropMode(QAbstractItemView::DragDrop);
+}
+
+// From outside the view, we only accept drops of files or folders. From inside, we accept the items being dragged around.
+bool PackedFileTreeView::hasLocalFiles(const QMimeData* mimeData) const {
+    if (!mimeData->hasUrls()) {
+        return false;
+    }
+
+    for (const QUrl &url: mimeData->urls()) {
+        if (!url.isLocalFile()) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+void PackedFileTreeView::dragEnterEvent(QDragEnterEvent* event) {
+    if (event->source() == this) {
+        event->setDropAction(Qt::MoveAction);
+        event->accept();
+    } else if (hasLocalFiles(event->mimeData())) {
+        event->setDropAction(Qt::CopyAction);
+        event->accept();
+    } else {
+        event->ignore();
+    }
+}
+
+void PackedFileTreeView::dragMoveEvent(QDragMoveEvent* event) {
+    if (event->source() == this) {
+        QTreeView::dragMoveEvent(event);
+        event->setDropAction(Qt::MoveAction);
+        event->accept();
+    } else if (hasLocalFiles(event->mimeData())) {
+        QTreeView::dragMoveEvent(event);
+        event->setDropAction(Qt::CopyAction);
+        event->accept();
+    } else {
+        event->ignore();
+    }
+}
+
+// On drop, we let Rust know where the items were dropped. If they come from outside, we also store their paths so it can add them to the PackFile.
+// If they come from the view itself, Rust moves the selected items to the folder they were dropped on.
+void PackedFileTreeView::dropEvent(QDropEvent* event) {
+    if (event->source() == this) {
+        event->setDropAction(Qt::MoveAction);
+        event->accept();
+        emit itemsMoved(indexAt(event->pos()));
+    } else if (hasLocalFiles(event->mimeData())) {
+        droppedPaths.clear();
+        for (const QUrl &url: event->mimeData()->urls()) {
+            droppedPaths.append(url.toLocalFile());
+        }
+
+        event->setDropAction(Qt::CopyAction);
+        event->accept();
+        emit filesDropped(indexAt(event->pos()));
+    } else {
+        event->ignore();
+    }
+}
+
+// On drag start, we ask Rust to extract the selected items, and then drag the extracted files.
+// If nothing got extracted, the drag can still be used to move the items within the view.
+void PackedFileTreeView::startDrag(Qt::DropActions supportedActions) {
+    Q_UNUSED(supportedActions);
+
+    dragOutPaths.clear();
+    emit dragOutRequested();
+
+    QList<QUrl> urls;
+    for (const QString &path: dragOutPaths) {
+        urls.append(QUrl::fromLocalFile(path));
+    }
+
+    QMimeData* mimeData = new QMimeData();
+    mimeData->setUrls(urls);
+
+    QDrag* drag = new QDrag(this);
+    drag->setMimeData(mimeData);
+    drag->exec(Qt::CopyAction | Qt::MoveAction, Qt::CopyAction);
+}
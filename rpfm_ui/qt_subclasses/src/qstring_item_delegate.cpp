@@ -1,24 +1,61 @@
 #include "qstring_item_delegate.h"
 #include <QAbstractItemView>
+#include <QCompleter>
 #include <QLineEdit>
+#include <QSet>
+#include <QSortFilterProxyModel>
 
 // Function to be called from any other language. This assing to the provided column of the provided TableView a QStringItemDelegate.
-extern "C" void new_qstring_item_delegate(QObject *parent, const int column, const int max_lenght) {
-    QStringItemDelegate* delegate = new QStringItemDelegate(parent, max_lenght);
+extern "C" void new_qstring_item_delegate(QObject *parent, const int column, const int max_lenght, QStringList* completion_values) {
+    QStringItemDelegate* delegate = new QStringItemDelegate(parent, max_lenght, completion_values);
     dynamic_cast<QAbstractItemView*>(parent)->setItemDelegateForColumn(column, delegate);
 }
 
-// Constructor of the QStringItemDelegate. We use it to store the max lenght allowed for the delegate.
-QStringItemDelegate::QStringItemDelegate(QObject *parent, const int lenght): QStyledItemDelegate(parent) {
+// Constructor of the QStringItemDelegate. We use it to store the max lenght allowed for the delegate, and the extra values to offer as completions.
+QStringItemDelegate::QStringItemDelegate(QObject *parent, const int lenght, QStringList* values): QStyledItemDelegate(parent) {
     max_lenght = lenght;
+    if (values != nullptr) {
+        completion_values = *values;
+    }
 }
 
 // Function called when the widget it's created. Here we configure the QLinEdit.
-QWidget* QStringItemDelegate::createEditor(QWidget *parent, const QStyleOptionViewItem &, const QModelIndex &) const {
+//
+// The completer offers the distinct values already in the column (filtered or not), plus the extra ones we got on creation.
+QWidget* QStringItemDelegate::createEditor(QWidget *parent, const QStyleOptionViewItem &, const QModelIndex &index) const {
     QLineEdit *editor = new QLineEdit(parent);
     if (this->max_lenght > 0) {
         //editor->setMaxLength(max_lenght);
     }
+
+    const QAbstractItemModel* model = index.model();
+    QModelIndex source_index = index;
+    const QSortFilterProxyModel* filter = qobject_cast<const QSortFilterProxyModel*>(model);
+    if (filter != nullptr) {
+        model = filter->sourceModel();
+        source_index = filter->mapToSource(index);
+    }
+
+    QSet<QString> values = QSet<QString>(completion_values.begin(), completion_values.end());
+    for (int row = 0; row < model->rowCount(); ++row) {
+        if (row != source_index.row()) {
+            QString value = model->data(model->index(row, source_index.column()), Qt::EditRole).toString();
+            if (!value.isEmpty()) {
+                values.insert(value);
+            }
+        }
+    }
+
+    if (!values.isEmpty()) {
+        QStringList list = values.values();
+        list.sort(Qt::CaseInsensitive);
+
+        QCompleter* completer = new QCompleter(list, editor);
+        completer->setCaseSensitivity(Qt::CaseInsensitive);
+        completer->setFilterMode(Qt::MatchContains);
+        editor->setCompleter(completer);
+    }
+
     return editor;
 }
 
@@ -4,19 +4,23 @@
 #include <QAbstractItemView>
 #include <QSpinBox>
 #include <QLineEdit>
+#include <QDateTime>
+#include <QLocale>
 
 // Function to be called from any other language. This assing to the provided column of the provided TableView a QSpinBoxItemDelegate.
-// We have to pass it the integer type (16, 32 or 64) too for later checks.
-extern "C" void new_spinbox_item_delegate(QObject *parent, const int column, const int integer_type, const bool is_optional) {
-    QSpinBoxItemDelegate* delegate = new QSpinBoxItemDelegate(parent, integer_type, is_optional);
+// We have to pass it the integer type (16, 32 or 64) too for later checks, and how the value should be displayed.
+extern "C" void new_spinbox_item_delegate(QObject *parent, const int column, const int integer_type, const bool is_optional, const bool use_thousands_separator, const bool is_timestamp) {
+    QSpinBoxItemDelegate* delegate = new QSpinBoxItemDelegate(parent, integer_type, is_optional, use_thousands_separator, is_timestamp);
     dynamic_cast<QAbstractItemView*>(parent)->setItemDelegateForColumn(column, delegate);
 }
 
-// Constructor of QSpinBoxItemDelegate. We use it to store the integer type of the value in the delegate.
-QSpinBoxItemDelegate::QSpinBoxItemDelegate(QObject *parent, const int integer_type, const bool is_optional): QStyledItemDelegate(parent)
+// Constructor of QSpinBoxItemDelegate. We use it to store the integer type of the value and its display options in the delegate.
+QSpinBoxItemDelegate::QSpinBoxItemDelegate(QObject *parent, const int integer_type, const bool is_optional, const bool use_thousands_separator, const bool is_timestamp): QStyledItemDelegate(parent)
 {
     type = integer_type;
     optional = is_optional;
+    thousands_separator = use_thousands_separator;
+    timestamp = is_timestamp;
 }
 
 // Function called when the widget it's created. Here we configure the spinbox/linedit.
@@ -98,3 +102,22 @@ void QSpinBoxItemDelegate::setModelData(QWidget *editor, QAbstractItemModel *mod
 void QSpinBoxItemDelegate::updateEditorGeometry(QWidget *editor, const QStyleOptionViewItem &option, const QModelIndex &) const {
     editor->setGeometry(option.rect);
 }
+
+// Function called to get the text shown in the cell. Only the displayed text changes, the editors still work with the raw value.
+QString QSpinBoxItemDelegate::displayText(const QVariant &value, const QLocale &locale) const {
+    bool ok;
+    qlonglong number = value.toLongLong(&ok);
+    if (!ok) {
+        return QStyledItemDelegate::displayText(value, locale);
+    }
+
+    if (timestamp) {
+        return QDateTime::fromSecsSinceEpoch(number, Qt::UTC).toString("yyyy-MM-dd hh:mm:ss");
+    }
+
+    if (thousands_separator) {
+        return locale.toString(number);
+    }
+
+    return QString::number(number);
+}
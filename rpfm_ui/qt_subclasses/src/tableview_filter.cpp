@@ -0,0 +1,138 @@
+#include "tableview_filter.h"
+#include <QSortFilterProxyModel>
+#include <QRegExp>
+#include <QMetaType>
+#include <QVariant>
+
+// Operators of the filter conditions. These must be kept in sync with the `FilterOperator` enum of the table views.
+enum FilterOperator {
+    Contains = 0,
+    NotContains = 1,
+    Equals = 2,
+    NotEquals = 3,
+    StartsWith = 4,
+    EndsWith = 5,
+    GreaterThan = 6,
+    GreaterOrEqual = 7,
+    LessThan = 8,
+    LessOrEqual = 9,
+};
+
+// Function to get the boolean a filter value represents, so "= true" and "= 1" both work on boolean cells.
+static QString normalizeBoolean(const QString &value) {
+    QString lower = value.trimmed().toLower();
+    if (lower == "true" || lower == "1" || lower == "yes" || lower == "on") {
+        return QString("true");
+    } else if (lower == "false" || lower == "0" || lower == "no" || lower == "off") {
+        return QString("false");
+    }
+    return value;
+}
+
+// Function to know if the data of a cell is a number. Text cells are never numbers, even if their text looks like one.
+static bool isNumeric(const QVariant &data) {
+    switch (static_cast<QMetaType::Type>(data.type())) {
+        case QMetaType::Short:
+        case QMetaType::Int:
+        case QMetaType::LongLong:
+        case QMetaType::Float:
+        case QMetaType::Double:
+            return true;
+        default:
+            return false;
+    }
+}
+
+// Function to create the filter in a way that we don't need to bother Rust with new types.
+extern "C" QSortFilterProxyModel* new_tableview_filter(QObject *parent) {
+    QTableViewSortFilterProxyModel* filter = new QTableViewSortFilterProxyModel(parent);
+    return dynamic_cast<QSortFilterProxyModel*>(filter);
+}
+
+// Function to set the conditions of the filter from Rust. Each condition is made of the items with the same index in the three lists.
+extern "C" void trigger_tableview_filter(QSortFilterProxyModel* filter, const QList<int>* columns, const QList<int>* operators, const QStringList* values, const bool match_all, const bool case_sensitive) {
+    QTableViewSortFilterProxyModel* filter2 = static_cast<QTableViewSortFilterProxyModel*>(filter);
+    filter2->setConditions(*columns, *operators, *values, match_all, case_sensitive);
+}
+
+// Constructor of QTableViewSortFilterProxyModel. By default, there are no conditions, so every row is accepted.
+QTableViewSortFilterProxyModel::QTableViewSortFilterProxyModel(QObject *parent): QSortFilterProxyModel(parent) {
+    match_all = true;
+    case_sensitivity = Qt::CaseInsensitive;
+}
+
+// Function to replace the conditions of the filter, and refilter the rows with them.
+void QTableViewSortFilterProxyModel::setConditions(const QList<int> &new_columns, const QList<int> &new_operators, const QStringList &new_values, const bool new_match_all, const bool case_sensitive) {
+    columns = new_columns;
+    operators = new_operators;
+    values = new_values;
+    match_all = new_match_all;
+    case_sensitivity = case_sensitive ? Qt::CaseSensitive : Qt::CaseInsensitive;
+    invalidateFilter();
+}
+
+// Function called when the filter changes. Rows must match all the conditions, or just one of them, depending on the filter's mode.
+bool QTableViewSortFilterProxyModel::filterAcceptsRow(int source_row, const QModelIndex &source_parent) const {
+    if (columns.isEmpty()) {
+        return true;
+    }
+
+    for (int condition = 0; condition < columns.size(); ++condition) {
+        bool matches = conditionMatches(source_row, source_parent, condition);
+        if (match_all && !matches) {
+            return false;
+        }
+
+        if (!match_all && matches) {
+            return true;
+        }
+    }
+
+    return match_all;
+}
+
+// Function to check if a row matches one of the conditions of the filter.
+bool QTableViewSortFilterProxyModel::conditionMatches(int source_row, const QModelIndex &source_parent, int condition) const {
+    QModelIndex index = sourceModel()->index(source_row, columns.at(condition), source_parent);
+    const QString &value = values.at(condition);
+
+    // Boolean cells have no text, so we use their check state as text.
+    QString data;
+    QString compared_value = value;
+    QVariant check_state = index.data(Qt::CheckStateRole);
+    if (check_state.isValid()) {
+        data = check_state.toInt() == Qt::Checked ? QString("true") : QString("false");
+        compared_value = normalizeBoolean(value);
+    } else {
+        data = index.data(Qt::DisplayRole).toString();
+    }
+
+    // Numeric cells are compared by their value, not by their text, so "= 1" matches "1.0" and "> 9" doesn't match "10" by accident.
+    QVariant edit_data = index.data(Qt::EditRole);
+    bool data_is_number = isNumeric(edit_data);
+    bool value_is_number = false;
+    double data_number = data_is_number ? edit_data.toDouble() : 0.0;
+    double value_number = compared_value.trimmed().toDouble(&value_is_number);
+    bool are_numbers = data_is_number && value_is_number;
+
+    // Float cells lose precision, so the value has to lose it too, or "= 0.1" would never match them.
+    if (static_cast<QMetaType::Type>(edit_data.type()) == QMetaType::Float) {
+        value_number = static_cast<double>(static_cast<float>(value_number));
+    }
+
+    switch (operators.at(condition)) {
+        case Contains: return QRegExp(compared_value, case_sensitivity).indexIn(data) != -1;
+        case NotContains: return QRegExp(compared_value, case_sensitivity).indexIn(data) == -1;
+        case Equals: return are_numbers ? data_number == value_number : data.compare(compared_value, case_sensitivity) == 0;
+        case NotEquals: return are_numbers ? data_number != value_number : data.compare(compared_value, case_sensitivity) != 0;
+        case StartsWith: return data.startsWith(compared_value, case_sensitivity);
+        case EndsWith: return data.endsWith(compared_value, case_sensitivity);
+
+        // Ranges only make sense with numbers, so they never match anything else.
+        case GreaterThan: return are_numbers && data_number > value_number;
+        case GreaterOrEqual: return are_numbers && data_number >= value_number;
+        case LessThan: return are_numbers && data_number < value_number;
+        case LessOrEqual: return are_numbers && data_number <= value_number;
+        default: return true;
+    }
+}
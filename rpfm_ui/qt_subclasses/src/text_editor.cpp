@@ -8,6 +8,19 @@ extern "C" QWidget* new_text_editor(QWidget* parent) {
 
     // Disable the status bar.
     view->setStatusBarEnabled(false);
+
+    // Enable line numbers and code folding in the view, and auto-closing brackets in the document.
+    KTextEditor::ConfigInterface* view_config = qobject_cast<KTextEditor::ConfigInterface*>(view);
+    if (view_config) {
+        view_config->setConfigValue("line-numbers", true);
+        view_config->setConfigValue("folding-bar", true);
+    }
+
+    KTextEditor::ConfigInterface* doc_config = qobject_cast<KTextEditor::ConfigInterface*>(doc);
+    if (doc_config) {
+        doc_config->setConfigValue("auto-brackets", true);
+    }
+
     return dynamic_cast<QWidget*>(view);
 }
 
@@ -34,6 +47,14 @@ extern "C" void set_text(QWidget* view, QString* text, QString* highlighting_mod
     doc->setHighlightingMode(highlight_mode);
 }
 
+// Function to get the current revision of the text editor's document. It changes every time the text is edited.
+extern "C" qint64 get_text_revision(QWidget* view) {
+
+    KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
+    KTextEditor::MovingInterface* moving = qobject_cast<KTextEditor::MovingInterface*>(doc_view->document());
+    return moving ? moving->revision() : 0;
+}
+
 // Function to trigger the config dialog of the text editor.
 extern "C" void open_text_editor_config(QWidget* parent) {
 
@@ -41,3 +62,26 @@ extern "C" void open_text_editor_config(QWidget* parent) {
     editor->configDialog(parent);
 }
 
+
+// Function to get the point size of the font of the text editor. It changes when the view is zoomed.
+extern "C" int get_text_editor_font_size(QWidget* view) {
+
+    KTextEditor::ConfigInterface* view_config = qobject_cast<KTextEditor::ConfigInterface*>(view);
+    if (view_config) {
+        QFont font = view_config->configValue("font").value<QFont>();
+        return font.pointSize();
+    }
+
+    return 0;
+}
+
+// Function to set the point size of the font of the text editor, without affecting other text editors.
+extern "C" void set_text_editor_font_size(QWidget* view, int size) {
+
+    KTextEditor::ConfigInterface* view_config = qobject_cast<KTextEditor::ConfigInterface*>(view);
+    if (view_config && size > 0) {
+        QFont font = view_config->configValue("font").value<QFont>();
+        font.setPointSize(size);
+        view_config->setConfigValue("font", font);
+    }
+}
@@ -0,0 +1,32 @@
+#include "zoom_filter.h"
+#include <QWheelEvent>
+
+// Function to install the zoom filter in a widget from Rust. The filter is owned by the widget, so it dies with it.
+extern "C" void new_zoom_filter(QObject* widget, QAction* zoom_in, QAction* zoom_out) {
+    ZoomFilter* filter = new ZoomFilter(widget, zoom_in, zoom_out);
+    widget->installEventFilter(filter);
+}
+
+ZoomFilter::ZoomFilter(QObject* parent, QAction* zoom_in, QAction* zoom_out): QObject(parent) {
+    this->zoom_in = zoom_in;
+    this->zoom_out = zoom_out;
+}
+
+// Ctrl + Wheel triggers the zoom actions instead of scrolling.
+bool ZoomFilter::eventFilter(QObject* watched, QEvent* event) {
+    if (event->type() == QEvent::Wheel) {
+        QWheelEvent* wheel_event = static_cast<QWheelEvent*>(event);
+        if (wheel_event->modifiers() & Qt::ControlModifier) {
+            int delta = wheel_event->angleDelta().y();
+            if (delta > 0) {
+                zoom_in->trigger();
+            } else if (delta < 0) {
+                zoom_out->trigger();
+            }
+
+            return true;
+        }
+    }
+
+    return QObject::eventFilter(watched, event);
+}
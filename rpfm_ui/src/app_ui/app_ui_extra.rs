@@ -23,51 +23,67 @@ use qt_widgets::QFileDialog;
 use qt_widgets::QLineEdit;
 use qt_widgets::{q_message_box, QMessageBox};
 use qt_widgets::QPushButton;
+use qt_widgets::QTextEdit;
 use qt_widgets::QTreeView;
 use qt_widgets::QLabel;
 
+use qt_gui::QDesktopServices;
+use qt_gui::QKeySequence;
 use qt_gui::QStandardItemModel;
 
 use qt_core::QFlags;
+use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QRegExp;
-use qt_core::{SlotOfBool, SlotOfQString};
+use qt_core::{Slot, SlotOfBool, SlotOfQString};
 use qt_core::QSortFilterProxyModel;
+use qt_core::ShortcutContext;
+use qt_core::QUrl;
 
-use cpp_core::MutPtr;
+use cpp_core::{CppBox, MutPtr};
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::fmt::Display;
+use std::fs::{read_to_string, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use rpfm_error::{ErrorKind, Result};
 
-use rpfm_lib::common::{get_game_selected_data_path, get_game_selected_content_packfiles_paths, get_game_selected_data_packfiles_paths, get_game_selected_template_definitions_paths};
+use rpfm_lib::common::{get_autosaves, get_current_time, get_last_modified_time_from_file, get_game_selected_data_path, get_game_selected_content_packfiles_paths, get_game_selected_data_packfiles_paths, get_game_selected_template_definitions_paths};
+use rpfm_lib::config::get_config_path;
 use rpfm_lib::GAME_SELECTED;
 use rpfm_lib::games::*;
-use rpfm_lib::packedfile::{PackedFileType, table::loc, text, text::TextType};
-use rpfm_lib::packfile::{PFHFileType, PFHFlags, CompressionState, PFHVersion, RESERVED_NAME_EXTRA_PACKFILE};
-use rpfm_lib::schema::{APIResponseSchema, VersionedFile};
+use rpfm_lib::jobs::Job;
+use rpfm_lib::mymod::MyModConfig;
+use rpfm_lib::packedfile::{PackedFileType, table::{db, loc}, text, text::TextType, variant_mesh_definition};
+use rpfm_lib::packfile::{PackFile, PackFileInfo, PFHFileType, PFHFlags, CompressionState, PFHVersion, RESERVED_NAME_EXTRA_PACKFILE, RESERVED_NAME_SETTINGS, RESERVED_PACKED_FILE_NAMES};
+use rpfm_lib::schema::{APIResponseSchema, SCHEMA_BUNDLE_EXTENSION, TableDefinitionChanges, VersionedFile};
 use rpfm_lib::SCHEMA;
 use rpfm_lib::SETTINGS;
 use rpfm_lib::SUPPORTED_GAMES;
-use rpfm_lib::settings::MYMOD_BASE_PATH;
+use rpfm_lib::settings::{MYMOD_BASE_PATH, SCHEMA_LAST_UPDATE_CHECK, SCHEMA_UPDATE_INTERVAL, WORKSHOP_STEAM_USER};
 use rpfm_lib::template::Template;
 
 use super::AppUI;
+use super::RUNNING_MARKER_FILE;
 use super::NewPackedFile;
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR, network::APIResponse};
 use crate::global_search_ui::GlobalSearchUI;
 use crate::locale::{qtr, qtre, tr, tre};
 use crate::pack_tree::{icons::IconType, new_pack_file_tooltip, PackTree, TreePathType, TreeViewOperation};
-use crate::packedfile_views::{anim_fragment::*, animpack::*, ca_vp8::*, decoder::*, external::*, image::*, PackedFileView, table::*, TheOneSlot, text::*};
+use crate::packedfile_views::{anim_fragment::*, animpack::*, ca_vp8::*, decoder::*, dependency_manager::*, external::*, hex::*, image::*, key_value::*, packfile::PackFileExtraView, PackedFileView, rigidmodel::*, sound_bank::*, table::*, TheOneSlot, text::*, unit_variant::*, variant_mesh::*, View, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::QString;
 use crate::UI_STATE;
+use crate::ui_state::op_mode::OperationalMode;
+use crate::ui_state::session::{Session, SessionPackedFile};
 use crate::ui::GameSelectedIcons;
-use crate::utils::{create_grid_layout, show_dialog};
+use crate::utils::{catch_panic_in_slot, create_grid_layout, get_game_selected_for_packfile, log_to_status_bar, Problems, save_game_selected_for_packfile, save_setting_string, setup_file_dialog, show_dialog};
+use crate::views::table::utils::{get_path_within_packfile, is_path_from_packfile_extra};
 
 //-------------------------------------------------------------------------------//
 //                             Implementations
@@ -143,6 +159,22 @@ impl AppUI {
             if save_before_deleting && !packed_file_view.get_path().starts_with(&[RESERVED_NAME_EXTRA_PACKFILE.to_owned()]) {
                 packed_file_view.save(self, global_search_ui, &mut pack_file_contents_ui)?;
             }
+
+            // Remember the state of the columns of the tables, so they're restored the next time we open them.
+            if let ViewType::Internal(View::Table(view)) = packed_file_view.get_view() {
+                view.get_ref_table().save_table_state();
+            }
+
+            // Remember the zoom of text views, so it's applied to the next ones we open.
+            if let ViewType::Internal(View::Text(view)) = packed_file_view.get_view() {
+                view.save_zoom_level();
+            }
+
+            // Stop watching the files open in external programs, so the background thread forgets about them.
+            if let ViewType::External(view) = packed_file_view.get_view() {
+                view.stop_watching();
+            }
+
             let mut widget = packed_file_view.get_mut_widget();
             let index = self.tab_bar_packed_file.index_of(widget);
             if index != -1 {
@@ -189,6 +221,22 @@ impl AppUI {
                 if save_before_deleting && !path.starts_with(&[RESERVED_NAME_EXTRA_PACKFILE.to_owned()]) {
                     did_it_worked = packed_file_view.save(self, global_search_ui, &mut pack_file_contents_ui);
                 }
+
+                // Remember the state of the columns of the table, so it's restored the next time we open it.
+                if let ViewType::Internal(View::Table(view)) = packed_file_view.get_view() {
+                    view.get_ref_table().save_table_state();
+                }
+
+                // Remember the zoom of text views, so it's applied to the next ones we open.
+                if let ViewType::Internal(View::Text(view)) = packed_file_view.get_view() {
+                    view.save_zoom_level();
+                }
+
+                // Stop watching the files open in external programs, so the background thread forgets about them.
+                if let ViewType::External(view) = packed_file_view.get_view() {
+                    view.stop_watching();
+                }
+
                 let mut widget = packed_file_view.get_mut_widget();
                 let index = self.tab_bar_packed_file.index_of(widget);
                 if index != -1 {
@@ -250,6 +298,9 @@ impl AppUI {
         // Destroy whatever it's in the PackedFile's view, to avoid data corruption. We don't care about this result.
         let _ = self.purge_them_all(*global_search_ui, *pack_file_contents_ui, slot_holder, false);
 
+        // The live sync is tied to the PackFile we're closing. If the new one is a MyMod with it enabled, it's resumed once it's open.
+        self.mymod_live_sync.set_checked(false);
+
         // Tell the Background Thread to create a new PackFile with the data of one or more from the disk.
         self.main_window.set_enabled(false);
         CENTRAL_COMMAND.send_message_qt(Command::OpenPackFiles(pack_file_paths.to_vec()));
@@ -261,31 +312,12 @@ impl AppUI {
             // If it's success....
             Response::PackFileInfo(ui_data) => {
 
-                // We choose the right option, depending on our PackFile.
-                match ui_data.pfh_file_type {
-                    PFHFileType::Boot => self.change_packfile_type_boot.set_checked(true),
-                    PFHFileType::Release => self.change_packfile_type_release.set_checked(true),
-                    PFHFileType::Patch => self.change_packfile_type_patch.set_checked(true),
-                    PFHFileType::Mod => self.change_packfile_type_mod.set_checked(true),
-                    PFHFileType::Movie => self.change_packfile_type_movie.set_checked(true),
-                    PFHFileType::Other(_) => self.change_packfile_type_other.set_checked(true),
-                }
-
-                // Enable or disable these, depending on what data we have in the header.
-                self.change_packfile_type_data_is_encrypted.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_ENCRYPTED_DATA));
-                self.change_packfile_type_index_includes_timestamp.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_INDEX_WITH_TIMESTAMPS));
-                self.change_packfile_type_index_is_encrypted.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_ENCRYPTED_INDEX));
-                self.change_packfile_type_header_is_extended.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_EXTENDED_HEADER));
-
-                // Set the compression level correctly, because otherwise we may fuckup some files.
-                let compression_state = match ui_data.compression_state {
-                    CompressionState::Enabled => true,
-                    CompressionState::Partial | CompressionState::Disabled => false,
-                };
-                self.change_packfile_type_data_is_compressed.set_checked(compression_state);
+                // We choose the right options, depending on our PackFile.
+                self.update_packfile_type_menu(&ui_data);
 
-                // Update the TreeView.
+                // Update the TreeView. Moves done in the previous PackFile cannot be undone in this one.
                 pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Build(None));
+                UI_STATE.clear_packfile_contents_moves();
 
                 // Re-enable the Main Window.
                 self.main_window.set_enabled(true);
@@ -316,6 +348,26 @@ impl AppUI {
                     UI_STATE.set_operational_mode(self, Some(&pack_file_paths[0]));
                 }
 
+                // If we remember the game this PackFile was last saved with, and it can open it, we go back to that game.
+                // This avoids editing it with the schema of another game just because we were working on another game's mod.
+                else if let Some(game) = get_game_selected_for_packfile(&pack_file_paths[0])
+                    .filter(|game| pack_file_paths.len() == 1 && SUPPORTED_GAMES.get(&**game).map_or(false, |game| game.pfh_version.contains(&ui_data.pfh_version))) {
+                    match &*game {
+                        KEY_TROY => self.game_selected_troy.trigger(),
+                        KEY_THREE_KINGDOMS => self.game_selected_three_kingdoms.trigger(),
+                        KEY_WARHAMMER_2 => self.game_selected_warhammer_2.trigger(),
+                        KEY_WARHAMMER => self.game_selected_warhammer.trigger(),
+                        KEY_THRONES_OF_BRITANNIA => self.game_selected_thrones_of_britannia.trigger(),
+                        KEY_ATTILA => self.game_selected_attila.trigger(),
+                        KEY_ROME_2 => self.game_selected_rome_2.trigger(),
+                        KEY_SHOGUN_2 => self.game_selected_shogun_2.trigger(),
+                        KEY_NAPOLEON => self.game_selected_napoleon.trigger(),
+                        KEY_EMPIRE => self.game_selected_empire.trigger(),
+                        KEY_ARENA => self.game_selected_arena.trigger(),
+                        _ => unreachable!(),
+                    }
+                }
+
                 // If it's not a "MyMod", we choose the new Game Selected depending on what the open mod id is.
                 else {
 
@@ -388,6 +440,12 @@ impl AppUI {
 
                 UI_STATE.set_is_modified(false, self, pack_file_contents_ui);
                 pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Clean);
+                self.update_mymod_git_status(pack_file_contents_ui);
+
+                // Resume the live sync if it was enabled the last time this MyMod was open.
+                if !game_folder.is_empty() && pack_file_paths.len() == 1 && MyModConfig::read(&pack_file_paths[0]).map(|config| config.live_sync).unwrap_or(false) {
+                    self.mymod_live_sync.set_checked(true);
+                }
             }
 
             // If we got an error...
@@ -404,6 +462,324 @@ impl AppUI {
         Ok(())
     }
 
+    /// This function (re)starts the autosave timer with the interval in the settings, or stops it if autosaves are disabled.
+    pub unsafe fn update_autosave_timer(&mut self) {
+        let minutes = SETTINGS.read().unwrap().settings_string["autosave_interval"].parse::<i32>().unwrap_or(0);
+        if minutes > 0 {
+            self.autosave_timer.set_interval(minutes * 60_000);
+            self.autosave_timer.start_0a();
+        } else {
+            self.autosave_timer.stop();
+        }
+    }
+
+    /// This function saves a backup of the open PackFile to the autosaves folder, if it has unsaved changes.
+    ///
+    /// If the UI is busy with another operation, the autosave is skipped until the next time.
+    pub unsafe fn autosave_packfile(&mut self, global_search_ui: GlobalSearchUI, pack_file_contents_ui: PackFileContentsUI) {
+        if !UI_STATE.get_is_modified() || !self.main_window.is_enabled() {
+            return;
+        }
+
+        // Make sure the backend has the changes of the open PackedFiles before saving it.
+        if let Err(error) = self.back_to_back_end_all(global_search_ui, pack_file_contents_ui) {
+            return log_to_status_bar(&tre("autosave_error", &[&error.to_string()]));
+        }
+
+        log_to_status_bar(&tr("autosave_in_progress"));
+        self.main_window.set_enabled(false);
+        CENTRAL_COMMAND.send_message_qt(Command::AutosavePackFile);
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        match response {
+            Response::Success => log_to_status_bar(&tr("autosave_success")),
+            Response::Error(error) => log_to_status_bar(&tre("autosave_error", &[&error.to_string()])),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+        self.main_window.set_enabled(true);
+    }
+
+    /// This function checks if the program was closed properly the last time and, if it wasn't,
+    /// offers to open the last autosave done in that session.
+    pub unsafe fn check_autosave_recovery(
+        &mut self,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &mut GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) {
+        let marker_path = match get_config_path() {
+            Ok(path) => path.join(RUNNING_MARKER_FILE),
+            Err(_) => return,
+        };
+
+        // The marker contains the time the last session started. If it's still there, that session crashed.
+        let crashed_session_start = read_to_string(&marker_path).ok().and_then(|time| time.trim().parse::<i64>().ok());
+        if let Ok(mut file) = File::create(&marker_path) {
+            let _ = file.write_all(get_current_time().to_string().as_bytes());
+        }
+
+        if let Some(crashed_session_start) = crashed_session_start {
+            let autosave = get_autosaves().ok()
+                .and_then(|autosaves| autosaves.last().cloned())
+                .filter(|path| File::open(path).map(|file| get_last_modified_time_from_file(&file) >= crashed_session_start).unwrap_or(false));
+
+            if let Some(autosave) = autosave {
+                let file_name = autosave.file_name().unwrap().to_string_lossy().to_string();
+                let recover = QMessageBox::from_2_q_string_icon3_int_q_widget(
+                    &qtr("rpfm_title"),
+                    &qtre("autosave_recover", &[&file_name]),
+                    q_message_box::Icon::Warning,
+                    65536, // No
+                    16384, // Yes
+                    1, // By default, select yes.
+                    self.main_window,
+                ).exec() == 3;
+
+                if recover {
+                    if let Err(error) = self.open_packfile(pack_file_contents_ui, global_search_ui, &[autosave], "", slot_holder) {
+                        show_dialog(self.main_window, error, false);
+                    }
+                }
+            }
+        }
+    }
+
+    /// This function saves the current session (open PackFile, open PackedFiles, active tab,...) to disk, so it can be restored on start.
+    pub unsafe fn save_session(&self) {
+        let mut session = Session::default();
+
+        // PackFiles that have never been saved cannot be restored, so we ignore them.
+        CENTRAL_COMMAND.send_message_qt(Command::GetPackFilePath);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let path = if let Response::PathBuf(path) = response { path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+        if path.is_file() {
+            session.pack_file_path = Some(path);
+            if let OperationalMode::MyMod(game_folder, _) = UI_STATE.get_operational_mode() {
+                session.game_folder = game_folder;
+            }
+
+            // Keep the order of the tabs. Hidden views and views not linked to a PackedFile are not remembered.
+            let current_index = self.tab_bar_packed_file.current_index();
+            let mut open_packedfiles = vec![];
+            for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+                let index = self.tab_bar_packed_file.index_of(packed_file_view.get_mut_widget());
+                let path = packed_file_view.get_path();
+                if index == -1 || path.is_empty() || RESERVED_PACKED_FILE_NAMES.contains(&&*path[0]) {
+                    continue;
+                }
+
+                let scroll_position = match packed_file_view.get_view() {
+                    ViewType::Internal(View::Table(view)) => match view.get_ref_table().get_mut_ptr_table_view_primary() {
+                        Some(table_view) => (table_view.horizontal_scroll_bar().value(), table_view.vertical_scroll_bar().value()),
+                        None => continue,
+                    }
+                    ViewType::Internal(View::Decoder(_)) | ViewType::External(_) => continue,
+                    _ => (0, 0),
+                };
+
+                open_packedfiles.push((index, SessionPackedFile { path, scroll_position }));
+            }
+
+            open_packedfiles.sort_by_key(|(index, _)| *index);
+            session.active_tab = open_packedfiles.iter().position(|(index, _)| *index == current_index);
+            session.open_packedfiles = open_packedfiles.into_iter().map(|(_, packed_file)| packed_file).collect();
+        }
+
+        if let Err(error) = UI_STATE.set_session(session) {
+            log_to_status_bar(&error.to_string());
+        }
+    }
+
+    /// This function restores the last session saved to disk, reopening its PackFile and PackedFiles.
+    ///
+    /// PackedFiles that no longer exist in the PackFile are ignored.
+    pub unsafe fn restore_session(
+        &mut self,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &mut GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) {
+        let session = UI_STATE.get_session();
+        let pack_file_path = match session.pack_file_path {
+            Some(ref path) if path.is_file() => path.to_owned(),
+            _ => return,
+        };
+
+        if let Err(error) = self.open_packfile(pack_file_contents_ui, global_search_ui, &[pack_file_path], &session.game_folder, slot_holder) {
+            return show_dialog(self.main_window, error, false);
+        }
+
+        let mut tree_view = pack_file_contents_ui.packfile_contents_tree_view;
+        let mut restored_widgets = vec![];
+        for session_packed_file in &session.open_packedfiles {
+            if let Some(model_index) = tree_view.expand_treeview_to_item(&session_packed_file.path) {
+                let model_index = model_index.as_ref().unwrap();
+                if model_index.is_valid() {
+                    let mut selection_model = tree_view.selection_model();
+                    selection_model.select_q_model_index_q_flags_selection_flag(model_index, QFlags::from(SelectionFlag::ClearAndSelect));
+                    self.open_packedfile(pack_file_contents_ui, global_search_ui, slot_holder, false, false);
+                }
+            }
+
+            let widget = UI_STATE.get_open_packedfiles().iter()
+                .find(|x| *x.get_ref_path() == session_packed_file.path)
+                .map(|x| x.get_mut_widget());
+            restored_widgets.push(widget);
+        }
+
+        if let Some(Some(widget)) = session.active_tab.and_then(|index| restored_widgets.get(index)) {
+            self.tab_bar_packed_file.set_current_widget(*widget);
+        }
+
+        // Scroll the tables to where they were. We do it at the end, once their tabs have been laid out.
+        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+            if let ViewType::Internal(View::Table(view)) = packed_file_view.get_view() {
+                if let (Some(session_packed_file), Some(table_view)) = (session.open_packedfiles.iter().find(|x| x.path == *packed_file_view.get_ref_path()), view.get_ref_table().get_mut_ptr_table_view_primary()) {
+                    table_view.horizontal_scroll_bar().set_value(session_packed_file.scroll_position.0);
+                    table_view.vertical_scroll_bar().set_value(session_packed_file.scroll_position.1);
+                }
+            }
+        }
+    }
+
+    /// This function checks the options of the "Change PackFile Type" menu that correspond to the provided PackFile.
+    pub unsafe fn update_packfile_type_menu(&mut self, ui_data: &PackFileInfo) {
+        match ui_data.pfh_file_type {
+            PFHFileType::Boot => self.change_packfile_type_boot.set_checked(true),
+            PFHFileType::Release => self.change_packfile_type_release.set_checked(true),
+            PFHFileType::Patch => self.change_packfile_type_patch.set_checked(true),
+            PFHFileType::Mod => self.change_packfile_type_mod.set_checked(true),
+            PFHFileType::Movie => self.change_packfile_type_movie.set_checked(true),
+            PFHFileType::Other(_) => self.change_packfile_type_other.set_checked(true),
+        }
+
+        // Enable or disable these, depending on what data we have in the header.
+        self.change_packfile_type_data_is_encrypted.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_ENCRYPTED_DATA));
+        self.change_packfile_type_index_includes_timestamp.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_INDEX_WITH_TIMESTAMPS));
+        self.change_packfile_type_index_is_encrypted.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_ENCRYPTED_INDEX));
+        self.change_packfile_type_header_is_extended.set_checked(ui_data.bitmask.contains(PFHFlags::HAS_EXTENDED_HEADER));
+
+        // Set the compression level correctly, because otherwise we may fuckup some files.
+        let compression_state = match ui_data.compression_state {
+            CompressionState::Enabled => true,
+            CompressionState::Partial | CompressionState::Disabled => false,
+        };
+        self.change_packfile_type_data_is_compressed.set_checked(compression_state);
+    }
+
+    /// This function runs the provided Job over the open PackFile, reporting the progress of each step in the status bar.
+    ///
+    /// The Job can replace the open PackFile, so the views are closed and the TreeView rebuilt whether it succeeds or not.
+    pub unsafe fn run_job(
+        &mut self,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &mut GlobalSearchUI,
+        job: Job,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) -> Result<Vec<String>> {
+
+        // Destroy whatever it's in the PackedFile's view, to avoid data corruption. We don't care about this result.
+        let _ = self.purge_them_all(*global_search_ui, *pack_file_contents_ui, slot_holder, false);
+
+        let is_saved_at_the_end = job.is_saved_at_the_end();
+        self.main_window.set_enabled(false);
+        CENTRAL_COMMAND.send_message_qt(Command::RunJob(job));
+
+        // We get one message per step before the final one, so keep receiving until we get the report or an error.
+        let result = loop {
+            let response = CENTRAL_COMMAND.recv_message_qt_try();
+            match response {
+                Response::UsizeUsizeString((step, steps, description)) => log_to_status_bar(&tre("run_job_file_step", &[&step.to_string(), &steps.to_string(), &description])),
+                Response::VecStringPackFileInfo((report, ui_data)) => {
+                    self.update_packfile_type_menu(&ui_data);
+                    break Ok(report);
+                }
+                Response::Error(error) => break Err(error),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        };
+
+        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Build(None));
+        UI_STATE.clear_packfile_contents_moves();
+        self.main_window.set_enabled(true);
+        global_search_ui.clear();
+
+        // If the Job failed, we don't know what got changed, so we consider the PackFile modified.
+        UI_STATE.set_is_modified(result.is_err() || !is_saved_at_the_end, self, pack_file_contents_ui);
+        if result.is_ok() && is_saved_at_the_end {
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Clean);
+        }
+
+        result
+    }
+
+    /// This function reads the Job File in the provided path and runs it over the open PackFile, reporting the result in a dialog.
+    pub unsafe fn run_job_file(
+        &mut self,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &mut GlobalSearchUI,
+        path: &Path,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) {
+        let job = match Job::read(path) {
+            Ok(job) => job,
+            Err(error) => return show_dialog(self.main_window, error, false),
+        };
+
+        match self.run_job(pack_file_contents_ui, global_search_ui, job, slot_holder) {
+            Ok(report) => {
+                let report = report.iter().map(|line| format!("<li>{}</li>", line)).collect::<String>();
+                show_dialog(self.main_window, tre("run_job_file_success", &[&report]), true);
+            }
+            Err(error) => show_dialog(self.main_window, error, false),
+        }
+    }
+
+    /// This function takes a snapshot of the open PackFile, including the unsaved changes of the open PackedFiles.
+    pub unsafe fn create_snapshot(
+        &mut self,
+        pack_file_contents_ui: &PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+    ) -> Result<()> {
+        self.back_to_back_end_all(*global_search_ui, *pack_file_contents_ui)?;
+
+        CENTRAL_COMMAND.send_message_qt(Command::CreateSnapshot);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::Success => Ok(()),
+            Response::Error(error) => Err(error),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function rolls back the open PackFile to the snapshot with the provided index.
+    ///
+    /// The open PackedFiles are closed without saving their changes, and the TreeView is rebuilt from the snapshot.
+    pub unsafe fn restore_snapshot(
+        &mut self,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &mut GlobalSearchUI,
+        index: usize,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) -> Result<()> {
+
+        // Destroy whatever it's in the PackedFile's view, as its data is going to be replaced. We don't care about this result.
+        let _ = self.purge_them_all(*global_search_ui, *pack_file_contents_ui, slot_holder, false);
+
+        CENTRAL_COMMAND.send_message_qt(Command::RestoreSnapshot(index));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        let ui_data = match response {
+            Response::PackFileInfo(ui_data) => ui_data,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.update_packfile_type_menu(&ui_data);
+        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Build(None));
+        UI_STATE.clear_packfile_contents_moves();
+        UI_STATE.set_is_modified(true, self, pack_file_contents_ui);
+        global_search_ui.clear();
+        Ok(())
+    }
 
     /// This function is used to save the currently open `PackFile` to disk.
     ///
@@ -432,6 +808,7 @@ impl AppUI {
                 self.main_window,
                 &qtr("save_packfile"),
             );
+            setup_file_dialog(&mut file_dialog, None);
             file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
             file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
             file_dialog.set_confirm_overwrite(true);
@@ -462,6 +839,7 @@ impl AppUI {
                         let mut packfile_item = pack_file_contents_ui.packfile_contents_tree_model.item_1a(0);
                         packfile_item.set_tool_tip(&QString::from_std_str(new_pack_file_tooltip(&pack_file_info)));
                         packfile_item.set_text(&QString::from_std_str(&file_name));
+                        save_game_selected_for_packfile(&pack_file_info.file_path, &GAME_SELECTED.read().unwrap());
 
                         UI_STATE.set_operational_mode(self, None);
                         UI_STATE.set_is_modified(false, self, pack_file_contents_ui);
@@ -475,6 +853,36 @@ impl AppUI {
         }
 
         else {
+
+            // If the game is running with this PackFile, saving it may break the game, so ask first.
+            CENTRAL_COMMAND.send_message_qt(Command::IsGameRunningWithPackFile);
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            let is_game_running = if let Response::Bool(is_running) = response { is_running } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+            if is_game_running {
+                let mut dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                    q_message_box::Icon::Warning,
+                    &qtr("rpfm_title"),
+                    &qtr("save_game_running"),
+                    QFlags::from(q_message_box::StandardButton::Cancel),
+                    self.main_window,
+                );
+
+                dialog.add_button_q_string_button_role(&qtr("save_game_running_save"), q_message_box::ButtonRole::AcceptRole);
+                dialog.add_button_q_string_button_role(&qtr("save_game_running_queue"), q_message_box::ButtonRole::ActionRole);
+                match dialog.exec() {
+                    0 => {},
+                    1 => {
+                        self.save_queue_timer.start_0a();
+                        self.main_window.set_enabled(true);
+                        return Ok(());
+                    }
+                    _ => {
+                        self.main_window.set_enabled(true);
+                        return Ok(());
+                    }
+                }
+            }
+
             CENTRAL_COMMAND.send_message_qt(Command::SavePackFile);
             let response = CENTRAL_COMMAND.recv_message_qt_try();
             match response {
@@ -482,7 +890,9 @@ impl AppUI {
                     pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Clean);
                     let mut packfile_item = pack_file_contents_ui.packfile_contents_tree_model.item_1a(0);
                     packfile_item.set_tool_tip(&QString::from_std_str(new_pack_file_tooltip(&pack_file_info)));
+                    save_game_selected_for_packfile(&pack_file_info.file_path, &GAME_SELECTED.read().unwrap());
                     UI_STATE.set_is_modified(false, self, pack_file_contents_ui);
+                    self.update_mymod_git_status(pack_file_contents_ui);
                 }
                 Response::Error(error) => result = Err(error),
 
@@ -506,6 +916,7 @@ impl AppUI {
 
             // Disable the actions that allow to create and save PackFiles.
             self.packfile_new_packfile.set_enabled(false);
+            self.packfile_new_packfile_from_folder.set_enabled(false);
             self.packfile_save_packfile.set_enabled(false);
             self.packfile_save_packfile_as.set_enabled(false);
 
@@ -518,6 +929,7 @@ impl AppUI {
 
             // Enable or disable the actions from "PackFile" Submenu.
             self.packfile_new_packfile.set_enabled(true);
+            self.packfile_new_packfile_from_folder.set_enabled(true);
             self.packfile_save_packfile.set_enabled(enable);
             self.packfile_save_packfile_as.set_enabled(enable);
 
@@ -536,6 +948,8 @@ impl AppUI {
         }
 
         // These actions are common, no matter what game we have.
+        self.packfile_create_snapshot.set_enabled(enable);
+        self.packfile_snapshots.set_enabled(enable);
         self.change_packfile_type_group.set_enabled(enable);
         self.change_packfile_type_index_includes_timestamp.set_enabled(enable);
 
@@ -666,11 +1080,13 @@ impl AppUI {
     pub unsafe fn build_open_from_submenus(mut self, mut pack_file_contents_ui: PackFileContentsUI, mut global_search_ui: GlobalSearchUI, slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>) -> Vec<SlotOfBool<'static>> {
         let mut packfile_open_from_content = self.packfile_open_from_content;
         let mut packfile_open_from_data = self.packfile_open_from_data;
+        let mut packfile_open_game_packfile = self.packfile_open_game_packfile;
         let mut packfile_load_template = self.packfile_load_template;
 
         // First, we clear both menus, so we can rebuild them properly.
         packfile_open_from_content.clear();
         packfile_open_from_data.clear();
+        packfile_open_game_packfile.clear();
         packfile_load_template.clear();
 
         // And we create the slots.
@@ -734,6 +1150,27 @@ impl AppUI {
             }
         }
 
+        // Get the path of every PackFile released by CA (if the game's path it's configured) and make an action to browse each one of them read-only.
+        if let Ok(mut paths) = PackFile::get_all_ca_packfiles_paths() {
+            paths.retain(|path| path.is_file());
+            paths.sort_unstable_by_key(|x| x.file_name().unwrap().to_string_lossy().as_ref().to_owned());
+            for path in paths {
+                let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
+                let open_pack_action = packfile_open_game_packfile.add_action_q_string(&QString::from_std_str(pack_name));
+
+                // These are opened as extra PackFiles, so the open PackFile is not replaced and the game's one cannot be edited.
+                let slot_open_pack = SlotOfBool::new(clone!(
+                    path,
+                    slot_holder => move |_| {
+                        self.open_packfile_extra_view(&pack_file_contents_ui, &global_search_ui, &slot_holder, &path);
+                    }
+                ));
+
+                open_pack_action.triggered().connect(&slot_open_pack);
+                open_from_slots.push(slot_open_pack);
+            }
+        }
+
         // Get the path of every PackFile in the data folder (if the game's path it's configured) and make an action for each one of them.
         let mut template_paths = get_game_selected_template_definitions_paths();
         if let Some(ref mut paths) = template_paths {
@@ -765,16 +1202,18 @@ impl AppUI {
                                                     global_search_ui.search_on_path(&mut pack_file_contents_ui, paths.iter().map(From::from).collect());
 
                                                     // Try to reload all open files which data we altered, and close those that failed.
+                                                    let mut problems = Problems::default();
                                                     let mut open_packedfiles = UI_STATE.set_open_packedfiles();
                                                     packed_file_paths.iter().for_each(|path| {
                                                         if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path) {
                                                             if packed_file_view.reload(path, &mut pack_file_contents_ui).is_err() {
                                                                 if let Err(error) = self.purge_that_one_specifically(global_search_ui, pack_file_contents_ui, path, false) {
-                                                                    show_dialog(self.main_window, error, false);
+                                                                    problems.push(&path.join("/"), error);
                                                                 }
                                                             }
                                                         }
                                                     });
+                                                    problems.report(self.main_window);
                                                 }
                                                 Response::Error(error) => show_dialog(self.main_window, error, false),
 
@@ -801,6 +1240,7 @@ impl AppUI {
         // Only if the submenu has items, we enable it.
         packfile_open_from_content.menu_action().set_visible(!packfile_open_from_content.actions().is_empty());
         packfile_open_from_data.menu_action().set_visible(!packfile_open_from_data.actions().is_empty());
+        packfile_open_game_packfile.menu_action().set_visible(!packfile_open_game_packfile.actions().is_empty());
         packfile_load_template.menu_action().set_visible(!packfile_load_template.actions().is_empty());
 
         // Return the slots.
@@ -900,6 +1340,60 @@ impl AppUI {
         slots
     }
 
+    /// This function takes care of recreating the `Scripts` menu and toolbar from the User Scripts in the config folder.
+    ///
+    /// The scripts are only read here to get their name, shortcut and toolbar button. Their steps are read again when running them,
+    /// so changes to them don't need a reload.
+    pub unsafe fn build_scripts_menu(mut self, mut pack_file_contents_ui: PackFileContentsUI, mut global_search_ui: GlobalSearchUI, slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>) -> Vec<SlotOfBool<'static>> {
+        let mut menu_bar_scripts = self.menu_bar_scripts;
+        let mut scripts_tool_bar = self.scripts_tool_bar;
+
+        // Clear the toolbar first, as clearing the menu deletes the actions of the scripts.
+        scripts_tool_bar.clear();
+        menu_bar_scripts.clear();
+        menu_bar_scripts.add_action(self.scripts_reload);
+        menu_bar_scripts.add_action(self.scripts_open_scripts_folder);
+        menu_bar_scripts.add_separator();
+
+        let mut slots = vec![];
+        for path in Job::get_user_scripts_paths().unwrap_or_default() {
+
+            // Broken scripts are still listed, so running them tells the user what's wrong with them.
+            let job = Job::read(&path).unwrap_or_default();
+            let script_name = if job.get_ref_name().is_empty() {
+                path.file_stem().unwrap().to_string_lossy().as_ref().to_owned()
+            } else { job.get_ref_name().to_owned() };
+
+            let mut script_action = menu_bar_scripts.add_action_q_string(&QString::from_std_str(&script_name));
+            script_action.set_status_tip(&QString::from_std_str(path.to_string_lossy()));
+            if !job.get_ref_shortcut().is_empty() {
+                script_action.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(job.get_ref_shortcut())));
+                script_action.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+            }
+
+            if job.is_in_toolbar() {
+                scripts_tool_bar.add_action(script_action);
+            }
+
+            let slot_run_script = SlotOfBool::new(clone!(
+                path,
+                slot_holder => move |_| {
+                    if self.are_you_sure(false) {
+                        self.run_job_file(&mut pack_file_contents_ui, &mut global_search_ui, &path, &slot_holder);
+                    }
+                }
+            ));
+
+            script_action.triggered().connect(&slot_run_script);
+            slots.push(slot_run_script);
+        }
+
+        // Only if there are scripts with buttons, we show the toolbar.
+        scripts_tool_bar.set_visible(!scripts_tool_bar.actions().is_empty());
+
+        slots
+    }
+
     /// This function checks if there is any newer version of RPFM released.
     ///
     /// If the `use_dialog` is false, we make the checks in the background, and pop up a dialog only in case there is an update available.
@@ -1026,7 +1520,7 @@ impl AppUI {
                 update_button.set_enabled(false);
 
                 match CENTRAL_COMMAND.recv_message_qt_try() {
-                    Response::Success => show_dialog(self.main_window, tr("schema_update_success"), true),
+                    Response::BTreeMapStringVecTableDefinitionChanges(changes) => self.show_schema_update_changes(&changes),
                     Response::Error(error) => show_dialog(self.main_window, error, false),
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response_thread),
                 }
@@ -1075,7 +1569,7 @@ impl AppUI {
                 update_button.set_enabled(false);
 
                 match CENTRAL_COMMAND.recv_message_qt_try() {
-                    Response::Success => show_dialog(self.main_window, tr("schema_update_success"), true),
+                    Response::BTreeMapStringVecTableDefinitionChanges(changes) => self.show_schema_update_changes(&changes),
                     Response::Error(error) => show_dialog(self.main_window, error, false),
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response_thread),
                 }
@@ -1083,6 +1577,179 @@ impl AppUI {
         }
     }
 
+    /// This function (re)starts the automatic schema update timer, or stops it if automatic schema updates are disabled.
+    pub unsafe fn update_schema_update_timer(&mut self) {
+        let hours = SETTINGS.read().unwrap().settings_string[SCHEMA_UPDATE_INTERVAL].parse::<i32>().unwrap_or(0);
+        if hours > 0 {
+            self.schema_update_timer.start_0a();
+        } else {
+            self.schema_update_timer.stop();
+        }
+    }
+
+    /// This function checks for schema updates and applies them without asking, if it's time to do it according to the settings.
+    ///
+    /// Errors are ignored, as with the checks at start. If the UI is busy with another operation, the check is skipped until the next time.
+    pub unsafe fn auto_update_schemas(&self) {
+        let (hours, last_check) = {
+            let settings = SETTINGS.read().unwrap();
+            (
+                settings.settings_string[SCHEMA_UPDATE_INTERVAL].parse::<i64>().unwrap_or(0),
+                settings.settings_string[SCHEMA_LAST_UPDATE_CHECK].parse::<i64>().unwrap_or(0),
+            )
+        };
+
+        let current_time = get_current_time();
+        if hours <= 0 || current_time - last_check < hours * 3600 || !self.main_window.is_enabled() {
+            return;
+        }
+
+        save_setting_string(SCHEMA_LAST_UPDATE_CHECK, &current_time.to_string());
+        CENTRAL_COMMAND.send_message_qt_to_network(Command::AutoUpdateSchemas);
+        let response = CENTRAL_COMMAND.recv_message_network_to_qt_try();
+        match response {
+            Response::BTreeMapStringVecTableDefinitionChanges(changes) => self.show_schema_update_changes(&changes),
+            Response::APIResponseSchema(_) | Response::Error(_) => {},
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function asks for a path and exports the schemas of all games to a bundle there, so they can be imported in machines without access to the schema repository.
+    pub unsafe fn export_schema_bundle(&self) {
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window,
+            &qtr("schema_bundle_export"),
+        );
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&QString::from_std_str(&format!("RPFM Schemas (*.{})", SCHEMA_BUNDLE_EXTENSION)));
+        file_dialog.set_confirm_overwrite(true);
+        file_dialog.set_default_suffix(&QString::from_std_str(SCHEMA_BUNDLE_EXTENSION));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            self.main_window.set_enabled(false);
+            CENTRAL_COMMAND.send_message_qt(Command::ExportSchemaBundle(path));
+            let response = CENTRAL_COMMAND.recv_message_qt_try();
+            match response {
+                Response::U32(exported) => show_dialog(self.main_window, tre("schema_bundle_export_success", &[&exported.to_string()]), true),
+                Response::Error(error) => show_dialog(self.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+            self.main_window.set_enabled(true);
+        }
+    }
+
+    /// This function asks for a bundle made with `export_schema_bundle` and replaces our schemas with the ones in it.
+    pub unsafe fn import_schema_bundle(&self) {
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window,
+            &qtr("schema_bundle_import"),
+        );
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_name_filter(&QString::from_std_str(&format!("RPFM Schemas (*.{})", SCHEMA_BUNDLE_EXTENSION)));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            self.main_window.set_enabled(false);
+            CENTRAL_COMMAND.send_message_qt(Command::ImportSchemaBundle(path));
+            let response = CENTRAL_COMMAND.recv_message_qt_try();
+            match response {
+                Response::BTreeMapStringVecTableDefinitionChanges(changes) => self.show_schema_update_changes(&changes),
+                Response::Error(error) => show_dialog(self.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+            self.main_window.set_enabled(true);
+        }
+    }
+
+    /// This function shows a dialog with the changes a schema update did to the table definitions of each game.
+    unsafe fn show_schema_update_changes(&self, changes: &BTreeMap<String, Vec<TableDefinitionChanges>>) {
+        if changes.is_empty() {
+            return show_dialog(self.main_window, tr("schema_update_success"), true);
+        }
+
+        let mut details = vec![];
+        for (game_key, tables) in changes {
+            let game_name = SUPPORTED_GAMES.get(&**game_key).map_or(&**game_key, |game| game.display_name);
+            details.push(format!("{}:", game_name));
+            for table in tables {
+                let mut table_changes = vec![];
+                if table.is_new_table {
+                    table_changes.push(tr("schema_update_changes_new_table"));
+                }
+                else if !table.new_versions.is_empty() {
+                    table_changes.push(tre("schema_update_changes_new_versions", &[&table.new_versions.iter().map(|version| version.to_string()).collect::<Vec<String>>().join(", ")]));
+                }
+
+                if !table.new_fields.is_empty() {
+                    table_changes.push(tre("schema_update_changes_new_fields", &[&table.new_fields.join(", ")]));
+                }
+
+                details.push(format!("  - {}: {}", table.table_name, table_changes.join("; ")));
+            }
+            details.push(String::new());
+        }
+
+        let changes_count = changes.values().map(|tables| tables.len()).sum::<usize>();
+        let mut dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+            q_message_box::Icon::Information,
+            &qtr("update_schema_checker"),
+            &qtr("schema_update_success"),
+            QFlags::from(q_message_box::StandardButton::Ok),
+            self.main_window,
+        );
+
+        dialog.set_informative_text(&qtre("schema_update_changes", &[&changes_count.to_string()]));
+        dialog.set_detailed_text(&QString::from_std_str(details.join("\n")));
+        dialog.set_modal(true);
+        dialog.exec();
+    }
+
+    /// This function shows the error of a DB Table that failed to decode.
+    ///
+    /// If the table comes from a vanilla PackFile, the dialog also offers to report it in the schema repository, with the info needed to fix it already filled.
+    pub unsafe fn show_decode_failure_dialog<T: Display>(&self, path: &[String], error: T) {
+        CENTRAL_COMMAND.send_message_qt(Command::GetDecodeFailureReport(path.to_vec()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let report = match response {
+            Response::OptionDecodeFailureReport(report) => report,
+
+            // If we cannot get a report, just show the error as usual.
+            Response::Error(_) => None,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let report = match report {
+            Some(report) => report,
+            None => return show_dialog(self.main_window, error, false),
+        };
+
+        let mut dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+            q_message_box::Icon::Critical,
+            &qtr("title_error"),
+            &QString::from_std_str(error.to_string()),
+            QFlags::from(q_message_box::StandardButton::Close),
+            self.main_window,
+        );
+
+        dialog.add_button_q_string_button_role(&qtr("report_broken_decode"), q_message_box::ButtonRole::AcceptRole);
+        dialog.set_informative_text(&qtr("report_broken_decode_explanation"));
+        dialog.set_detailed_text(&QString::from_std_str(report.get_issue_body()));
+        dialog.set_modal(true);
+
+        // If we hit "Report", check the schemas are up to date and open the pre-filled issue in the browser.
+        if dialog.exec() == 0 {
+            CENTRAL_COMMAND.send_message_qt_to_network(Command::ReportBrokenDecode(report));
+            let response = CENTRAL_COMMAND.recv_message_network_to_qt_try();
+            match response {
+                Response::String(url) => { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(url))); },
+                Response::Error(error) => show_dialog(self.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
     /// This function is used to open ANY supported PackedFiles in a DockWidget, docked in the Main Window.
     pub unsafe fn open_packedfile(
         &mut self,
@@ -1172,7 +1839,7 @@ impl AppUI {
 
                         // If the file is an AnimPack PackedFile...
                         PackedFileType::AnimPack => {
-                            match PackedFileAnimPackView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
+                            match PackedFileAnimPackView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui, slot_holder) {
                                 Ok((slots, packed_file_info)) => {
                                     slot_holder.borrow_mut().push(slots);
 
@@ -1244,7 +1911,19 @@ impl AppUI {
 
                         // If the file is a DB PackedFile...
                         PackedFileType::DB => {
-                            match PackedFileTableView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
+
+                            // Key-value tables get their own view, unless they don't have the key and value columns. Then they're just tables.
+                            let is_key_value = path.get(1).map_or(false, |table_name| table_name.starts_with(db::KEY_VALUE_TABLE_PREFIX));
+                            let key_value_view = if is_key_value {
+                                PackedFileKeyValueView::new_view(&mut tab, self, pack_file_contents_ui).ok()
+                            } else { None };
+
+                            let view = match key_value_view {
+                                Some((slots, packed_file_info)) => Ok((slots, Some(packed_file_info))),
+                                None => PackedFileTableView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui),
+                            };
+
+                            match view {
                                 Ok((slots, packed_file_info)) => {
                                     slot_holder.borrow_mut().push(slots);
 
@@ -1257,7 +1936,7 @@ impl AppUI {
                                         pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
                                     }
                                 },
-                                Err(error) => return show_dialog(self.main_window, ErrorKind::DBTableDecode(format!("{}", error)), false),
+                                Err(error) => return self.show_decode_failure_dialog(&path, ErrorKind::DBTableDecode(format!("{}", error))),
                             }
                         }
 
@@ -1282,7 +1961,19 @@ impl AppUI {
 
                         // If the file is a Text PackedFile...
                         PackedFileType::Text(_) => {
-                            match PackedFileTextView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
+
+                            // VariantMeshDefinitions get their own view, unless they cannot be parsed. Then they're just text.
+                            let is_variant_mesh = path.last().map_or(false, |name| name.to_lowercase().ends_with(variant_mesh_definition::EXTENSION));
+                            let variant_mesh_view = if is_variant_mesh {
+                                PackedFileVariantMeshView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui).ok()
+                            } else { None };
+
+                            let view = match variant_mesh_view {
+                                Some((slots, packed_file_info)) => Ok((slots, Some(packed_file_info))),
+                                None => PackedFileTextView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui),
+                            };
+
+                            match view {
                                 Ok((slots, packed_file_info)) => {
                                     slot_holder.borrow_mut().push(slots);
 
@@ -1298,7 +1989,7 @@ impl AppUI {
                                 Err(error) => return show_dialog(self.main_window, ErrorKind::TextDecode(format!("{}", error)), false),
                             }
                         }
-                        /*
+
                         // If the file is a RigidModel PackedFile...
                         PackedFileType::RigidModel => {
                             match PackedFileRigidModelView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
@@ -1306,7 +1997,7 @@ impl AppUI {
                                     slot_holder.borrow_mut().push(slots);
 
                                     // Add the file to the 'Currently open' list and make it visible.
-                                    self.tab_bar_packed_file.add_tab_3a(tab_widget, icon, &QString::from_std_str(&name));
+                                    self.tab_bar_packed_file.add_tab_3a(tab_widget, icon, &QString::from_std_str(""));
                                     self.tab_bar_packed_file.set_current_widget(tab_widget);
                                     let mut open_list = UI_STATE.set_open_packedfiles();
                                     open_list.push(tab);
@@ -1315,7 +2006,41 @@ impl AppUI {
                                 Err(error) => return show_dialog(self.main_window, ErrorKind::RigidModelDecode(format!("{}", error)), false),
                             }
                         }
-                        */
+
+                        // If the file is a SoundBank PackedFile...
+                        PackedFileType::SoundBank => {
+                            match PackedFileSoundBankView::new_view(&mut tab) {
+                                Ok((slots, packed_file_info)) => {
+                                    slot_holder.borrow_mut().push(slots);
+
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    self.tab_bar_packed_file.add_tab_3a(tab_widget, icon, &QString::from_std_str(""));
+                                    self.tab_bar_packed_file.set_current_widget(tab_widget);
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
+                                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                                },
+                                Err(error) => return show_dialog(self.main_window, ErrorKind::SoundBankDecode(format!("{}", error)), false),
+                            }
+                        }
+
+                        // If the file is a UnitVariant PackedFile...
+                        PackedFileType::UnitVariant => {
+                            match PackedFileUnitVariantView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
+                                Ok((slots, packed_file_info)) => {
+                                    slot_holder.borrow_mut().push(slots);
+
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    self.tab_bar_packed_file.add_tab_3a(tab_widget, icon, &QString::from_std_str(""));
+                                    self.tab_bar_packed_file.set_current_widget(tab_widget);
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
+                                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                                },
+                                Err(error) => return show_dialog(self.main_window, ErrorKind::UnitVariantDecode(format!("{}", error)), false),
+                            }
+                        }
+
                         // If the file is a Image PackedFile, ignore failures while opening.
                         PackedFileType::Image => {
                             if let Ok((slots, packed_file_info)) = PackedFileImageView::new_view(&mut tab) {
@@ -1330,10 +2055,21 @@ impl AppUI {
                             }
                         }
 
-                        // For any other PackedFile, just restore the display tips.
+                        // For any other PackedFile, open it in the Hex Editor.
                         _ => {
-                            //purge_them_all(&app_ui, &packedfiles_open_in_packedfile_view);
-                            //display_help_tips(&app_ui);
+                            match PackedFileHexView::new_view(&mut tab, self, pack_file_contents_ui) {
+                                Ok((slots, packed_file_info)) => {
+                                    slot_holder.borrow_mut().push(slots);
+
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    self.tab_bar_packed_file.add_tab_3a(tab_widget, icon, &QString::from_std_str(""));
+                                    self.tab_bar_packed_file.set_current_widget(tab_widget);
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
+                                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                                },
+                                Err(error) => return show_dialog(self.main_window, error, false),
+                            }
                         }
                     }
                 }
@@ -1446,7 +2182,6 @@ impl AppUI {
     pub unsafe fn open_dependency_manager(
         &mut self,
         pack_file_contents_ui: &PackFileContentsUI,
-        global_search_ui: &GlobalSearchUI,
         slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
     ) {
 
@@ -1485,8 +2220,8 @@ impl AppUI {
             let icon_type = IconType::PackFile(true);
             let icon = icon_type.get_icon_from_path();
 
-            match PackedFileTableView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
-                Ok((slots, _)) => {
+            match PackedFileDependencyManagerView::new_view(&mut tab, self, pack_file_contents_ui) {
+                Ok(slots) => {
                     slot_holder.borrow_mut().push(slots);
 
                     // Add the manager to the 'Currently open' list and make it visible.
@@ -1508,13 +2243,33 @@ impl AppUI {
         global_search_ui: &GlobalSearchUI,
         slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
     ) {
+        self.open_reserved_text(vec!["notes.rpfm_reserved".to_owned()], qtr("notes"), pack_file_contents_ui, global_search_ui, slot_holder);
+    }
+
+    /// This function is used to open the list of diagnostic findings ignored for the PackFile, which is stored within its settings.
+    pub unsafe fn open_diagnostics_ignore_list(
+        &mut self,
+        pack_file_contents_ui: &PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) {
+        self.open_reserved_text(vec![RESERVED_NAME_SETTINGS.to_owned()], qtr("diagnostics_ignore_list"), pack_file_contents_ui, global_search_ui, slot_holder);
+    }
+
+    /// This function is used to open, as text, data stored within the PackFile in a reserved path.
+    unsafe fn open_reserved_text(
+        &mut self,
+        path: Vec<String>,
+        name: CppBox<QString>,
+        pack_file_contents_ui: &PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+    ) {
 
         // Before anything else, we need to check if the TreeView is unlocked. Otherwise we don't do anything from here on.
         if !UI_STATE.get_packfile_contents_read_only() {
 
-            // Close all preview views except the file we're opening. The path used for the notes is reserved.
-            let path = vec!["notes.rpfm_reserved".to_owned()];
-            let name = qtr("notes");
+            // Close all preview views except the file we're opening.
             for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
                 let open_path = packed_file_view.get_ref_path();
                 let index = self.tab_bar_packed_file.index_of(packed_file_view.get_mut_widget());
@@ -1523,7 +2278,7 @@ impl AppUI {
                 }
             }
 
-            // If the notes are already open, or are hidden, we show them/focus them, instead of opening them again.
+            // If it's already open, or is hidden, we show it/focus it, instead of opening it again.
             if let Some(tab_widget) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == path) {
                 let index = self.tab_bar_packed_file.index_of(tab_widget.get_mut_widget());
 
@@ -1821,10 +2576,10 @@ impl AppUI {
         }
 
         // What happens when we search in the filter.
-        let slot_table_filter_change_text = SlotOfQString::new(move |_| {
+        let slot_table_filter_change_text = SlotOfQString::new(move |_| catch_panic_in_slot(|| {
             let pattern = QRegExp::new_1a(&table_filter_line_edit.text());
             table_filter.set_filter_reg_exp_q_reg_exp(&pattern);
-        });
+        }));
 
         // What happens when we hit the "Create" button.
         create_button.released().connect(dialog.slot_accept());
@@ -1963,6 +2718,251 @@ impl AppUI {
         else { None }
     }
 
+    /// This function creates the entire "Upload to Workshop" dialog. It returns the updated config of the MyMod, the changelog and the Steam user to upload with.
+    pub unsafe fn workshop_upload_dialog(&self, config: &MyModConfig) -> Option<(MyModConfig, String, String)> {
+
+        let mut dialog = QDialog::new_1a(self.main_window).into_ptr();
+        dialog.set_window_title(&qtr("workshop_upload_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 500);
+
+        // Create the main Grid.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+
+        let mut title_label = QLabel::from_q_string(&qtr("workshop_upload_item_title"));
+        let mut title_line_edit = QLineEdit::from_q_string(&QString::from_std_str(&config.workshop_title));
+
+        let mut description_label = QLabel::from_q_string(&qtr("workshop_upload_description"));
+        let mut description_text_edit = QTextEdit::new();
+        description_text_edit.set_plain_text(&QString::from_std_str(&config.workshop_description));
+
+        let mut preview_image_label = QLabel::from_q_string(&qtr("workshop_upload_preview_image"));
+        let mut preview_image_line_edit = QLineEdit::new();
+        let mut preview_image_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+        if let Some(ref preview_image) = config.workshop_preview_image {
+            preview_image_line_edit.set_text(&QString::from_std_str(preview_image.to_string_lossy()));
+        }
+
+        let mut changelog_label = QLabel::from_q_string(&qtr("workshop_upload_changelog"));
+        let mut changelog_text_edit = QTextEdit::new();
+
+        let mut visibility_label = QLabel::from_q_string(&qtr("workshop_upload_visibility"));
+        let mut visibility_combobox = QComboBox::new_0a();
+        visibility_combobox.add_item_q_string(&qtr("workshop_upload_visibility_public"));
+        visibility_combobox.add_item_q_string(&qtr("workshop_upload_visibility_friends"));
+        visibility_combobox.add_item_q_string(&qtr("workshop_upload_visibility_private"));
+        visibility_combobox.add_item_q_string(&qtr("workshop_upload_visibility_unlisted"));
+        visibility_combobox.set_current_index(config.workshop_visibility as i32);
+
+        let mut steam_user_label = QLabel::from_q_string(&qtr("workshop_upload_steam_user"));
+        let mut steam_user_line_edit = QLineEdit::from_q_string(&QString::from_std_str(&SETTINGS.read().unwrap().settings_string[WORKSHOP_STEAM_USER]));
+
+        let mut accept_button = QPushButton::from_q_string(&qtr("workshop_upload_accept"));
+
+        main_grid.add_widget_5a(&mut title_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut title_line_edit, 0, 1, 1, 2);
+        main_grid.add_widget_5a(&mut description_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut description_text_edit, 1, 1, 1, 2);
+        main_grid.add_widget_5a(&mut preview_image_label, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&mut preview_image_line_edit, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&mut preview_image_button, 2, 2, 1, 1);
+        main_grid.add_widget_5a(&mut changelog_label, 3, 0, 1, 1);
+        main_grid.add_widget_5a(&mut changelog_text_edit, 3, 1, 1, 2);
+        main_grid.add_widget_5a(&mut visibility_label, 4, 0, 1, 1);
+        main_grid.add_widget_5a(&mut visibility_combobox, 4, 1, 1, 2);
+        main_grid.add_widget_5a(&mut steam_user_label, 5, 0, 1, 1);
+        main_grid.add_widget_5a(&mut steam_user_line_edit, 5, 1, 1, 2);
+        main_grid.add_widget_5a(&mut accept_button, 6, 0, 1, 3);
+
+        // What happens when we hit the "..." button of the preview image.
+        let mut preview_image_line_edit_ptr = preview_image_line_edit.as_mut_ptr();
+        let select_preview_image = Slot::new(move || {
+            let mut file_dialog = QFileDialog::from_q_widget_q_string(dialog, &qtr("settings_select_file"));
+            file_dialog.set_name_filter(&QString::from_std_str("Images (*.png *.jpg *.jpeg *.gif)"));
+            setup_file_dialog(&mut file_dialog, None);
+            if file_dialog.exec() == 1 {
+                let selected_files = file_dialog.selected_files();
+                preview_image_line_edit_ptr.set_text(selected_files.at(0));
+            }
+        });
+
+        preview_image_button.released().connect(&select_preview_image);
+        accept_button.released().connect(dialog.slot_accept());
+
+        // Execute the dialog.
+        if dialog.exec() == 1 {
+            let steam_user = steam_user_line_edit.text().to_std_string();
+            if steam_user.is_empty() {
+                show_dialog(self.main_window, ErrorKind::WorkshopUploadNoSteamUser, false);
+                return None;
+            }
+
+            let preview_image = preview_image_line_edit.text().to_std_string();
+            let mut config = config.clone();
+            config.workshop_title = title_line_edit.text().to_std_string();
+            config.workshop_description = description_text_edit.to_plain_text().to_std_string();
+            config.workshop_preview_image = if preview_image.is_empty() { None } else { Some(PathBuf::from(preview_image)) };
+            config.workshop_visibility = visibility_combobox.current_index() as u32;
+
+            let changelog = changelog_text_edit.to_plain_text().to_std_string();
+            Some((config, changelog, steam_user))
+        }
+
+        // Otherwise, return None.
+        else { None }
+    }
+
+    /// This function creates the "Commit MyMod" dialog. It returns the commit message, or None if the dialog is canceled or the message is empty.
+    pub unsafe fn mymod_git_commit_dialog(&self) -> Option<String> {
+
+        let mut dialog = QDialog::new_1a(self.main_window).into_ptr();
+        dialog.set_window_title(&qtr("mymod_git_commit_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 200);
+
+        // Create the main Grid.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+
+        let mut message_label = QLabel::from_q_string(&qtr("mymod_git_commit_message"));
+        let mut message_text_edit = QTextEdit::new();
+        let mut accept_button = QPushButton::from_q_string(&qtr("mymod_git_commit_accept"));
+
+        main_grid.add_widget_5a(&mut message_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut message_text_edit, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 2, 0, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        // Execute the dialog.
+        if dialog.exec() == 1 {
+            let message = message_text_edit.to_plain_text().to_std_string();
+            if !message.trim().is_empty() { Some(message) }
+            else { None }
+        }
+
+        // Otherwise, return None.
+        else { None }
+    }
+
+    /// This function updates the Git status shown in the PackFile Contents TreeView, if we have a MyMod open and Git is enabled for MyMods.
+    ///
+    /// If the MyMod doesn't have a repository yet, nothing is shown.
+    pub unsafe fn update_mymod_git_status(&self, pack_file_contents_ui: &mut PackFileContentsUI) {
+        if !SETTINGS.read().unwrap().settings_bool["use_git_on_mymods"] { return }
+        if let Some(private_path) = UI_STATE.get_operational_mode().get_mymod_private_path() {
+            CENTRAL_COMMAND.send_message_qt(Command::GetMyModGitStatus(private_path));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::VecVecStringGitStatus(status) => pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateGitStatus(status)),
+                Response::Error(_) => {},
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
+    /// This function opens the PackFile in the provided path as an extra PackFile, in a read-only view within a tab.
+    ///
+    /// From there, its PackedFiles can be added to the open PackFile, extracted, or its tables opened read-only. The PackFile itself is never modified.
+    pub unsafe fn open_packfile_extra_view(
+        &mut self,
+        pack_file_contents_ui: &PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+        pack_file_path: &Path,
+    ) {
+        let path_str = pack_file_path.to_string_lossy().to_string();
+        let fake_path = vec![RESERVED_NAME_EXTRA_PACKFILE.to_owned(), path_str.to_owned()];
+
+        // Close all preview views except the file we're opening.
+        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+            let open_path = packed_file_view.get_ref_path();
+            let index = self.tab_bar_packed_file.index_of(packed_file_view.get_mut_widget());
+            if *open_path != fake_path && packed_file_view.get_is_preview() && index != -1 {
+                self.tab_bar_packed_file.remove_tab(index);
+            }
+        }
+
+        // If the PackFile is already open, or it's hidden, we show it/focus it, instead of opening it again.
+        if let Some(tab_widget) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == fake_path) {
+            let index = self.tab_bar_packed_file.index_of(tab_widget.get_mut_widget());
+
+            if index == -1 {
+                let icon_type = IconType::PackFile(true);
+                let icon = icon_type.get_icon_from_path();
+                self.tab_bar_packed_file.add_tab_3a(tab_widget.get_mut_widget(), icon, &QString::from_std_str(&path_str));
+            }
+
+            self.tab_bar_packed_file.set_current_widget(tab_widget.get_mut_widget());
+            return;
+        }
+
+        let mut tab = PackedFileView::default();
+        let icon_type = IconType::PackFile(false);
+        let icon = icon_type.get_icon_from_path();
+        tab.set_is_preview(false);
+        tab.set_path(&fake_path);
+
+        self.main_window.set_enabled(false);
+        match PackFileExtraView::new_view(&mut tab, self, pack_file_contents_ui, global_search_ui, slot_holder, pack_file_path.to_path_buf()) {
+            Ok(slots) => {
+                slot_holder.borrow_mut().push(slots);
+
+                self.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(&path_str));
+                self.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
+                UI_STATE.set_open_packedfiles().push(tab);
+            }
+            Err(error) => show_dialog(self.main_window, error, false),
+        }
+        self.main_window.set_enabled(true);
+    }
+
+    /// This function opens a table from an extra PackFile in a read-only view, so it can be compared with the ones of the open PackFile and copied from.
+    pub unsafe fn open_packedfile_extra(
+        &mut self,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+        pack_file_path: &Path,
+        path: &[String],
+    ) {
+        let mut fake_path = vec![RESERVED_NAME_EXTRA_PACKFILE.to_owned(), pack_file_path.to_string_lossy().to_string()];
+        fake_path.extend_from_slice(path);
+        let icon_type = IconType::File(path.to_vec());
+
+        // If the table is already open, or it's hidden, we show it/focus it, instead of opening it again.
+        if let Some(tab_widget) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == fake_path) {
+            if self.tab_bar_packed_file.index_of(tab_widget.get_mut_widget()) == -1 {
+                self.tab_bar_packed_file.add_tab_3a(tab_widget.get_mut_widget(), icon_type.get_icon_from_path(), &QString::from_std_str(""));
+            }
+
+            self.tab_bar_packed_file.set_current_widget(tab_widget.get_mut_widget());
+            self.update_views_names();
+            return;
+        }
+
+        match PackedFileType::get_packed_file_type(path) {
+            PackedFileType::DB | PackedFileType::Loc => {
+                let mut tab = PackedFileView::default();
+                let tab_widget = tab.get_mut_widget();
+                tab.set_is_preview(false);
+                tab.set_path(&fake_path);
+
+                match PackedFileTableView::new_view(&mut tab, self, global_search_ui, pack_file_contents_ui) {
+                    Ok((slots, _)) => {
+                        slot_holder.borrow_mut().push(slots);
+
+                        self.tab_bar_packed_file.add_tab_3a(tab_widget, icon_type.get_icon_from_path(), &QString::from_std_str(""));
+                        self.tab_bar_packed_file.set_current_widget(tab_widget);
+                        UI_STATE.set_open_packedfiles().push(tab);
+                        self.update_views_names();
+                    },
+                    Err(error) => self.show_decode_failure_dialog(&fake_path, error),
+                }
+            }
+            _ => show_dialog(self.main_window, ErrorKind::PackedFileIsNotATable, false),
+        }
+    }
+
     /// Update the PackedFileView names, to ensure we have no collisions.
     pub unsafe fn update_views_names(&mut self) {
 
@@ -1988,12 +2988,19 @@ impl AppUI {
             let widget = packed_file_view.get_mut_widget();
             if let Some(widget_name) = packed_file_view.get_ref_path().last() {
                 if let Some(count) = names.get(widget_name) {
+                    let path = packed_file_view.get_ref_path();
                     let mut name = if count > &1 {
-                        packed_file_view.get_ref_path().join("/")
+                        get_path_within_packfile(path).join("/")
                     } else {
                         widget_name.to_owned()
                     };
 
+                    // Tables from extra PackFiles get the name of their PackFile, so they're not mistaken for the ones of the open PackFile.
+                    if is_path_from_packfile_extra(path) {
+                        let pack_file_name = Path::new(&path[1]).file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_default();
+                        name.push_str(&format!(" [{}]", pack_file_name));
+                    }
+
                     if packed_file_view.get_is_preview() {
                         name.push_str(" (Preview)");
                     }
@@ -28,16 +28,32 @@ pub unsafe fn set_connections(app_ui: &AppUI, slots: &AppUISlots) {
     app_ui.command_palette_show.triggered().connect(&slots.command_palette_show);
     app_ui.command_palette_hide.triggered().connect(&slots.command_palette_hide);
 
+    app_ui.command_palette_line_edit.text_edited().connect(&slots.command_palette_filter);
     app_ui.command_palette_completer.activated().connect(&slots.command_palette_trigger);
 
     //-----------------------------------------------//
     // `PackFile` menu connections.
     //-----------------------------------------------//
     app_ui.packfile_new_packfile.triggered().connect(&slots.packfile_new_packfile);
+    app_ui.packfile_new_packfile_from_folder.triggered().connect(&slots.packfile_new_packfile_from_folder);
     app_ui.packfile_open_packfile.triggered().connect(&slots.packfile_open_packfile);
     app_ui.packfile_save_packfile.triggered().connect(&slots.packfile_save_packfile);
     app_ui.packfile_save_packfile_as.triggered().connect(&slots.packfile_save_packfile_as);
+    app_ui.packfile_browse_workshop.triggered().connect(&slots.packfile_browse_workshop);
     app_ui.packfile_load_all_ca_packfiles.triggered().connect(&slots.packfile_load_all_ca_packfiles);
+    app_ui.packfile_compare_packfiles.triggered().connect(&slots.packfile_compare_packfiles);
+    app_ui.packfile_merge_tables_from_packfiles.triggered().connect(&slots.packfile_merge_tables_from_packfiles);
+    app_ui.packfile_run_job_file.triggered().connect(&slots.packfile_run_job_file);
+    app_ui.packfile_trash.triggered().connect(&slots.packfile_trash);
+    app_ui.packfile_create_snapshot.triggered().connect(&slots.packfile_create_snapshot);
+    app_ui.packfile_snapshots.triggered().connect(&slots.packfile_snapshots);
+    app_ui.packfile_diagnostics_ignore_list.triggered().connect(&slots.packfile_diagnostics_ignore_list);
+    app_ui.packfile_generate_checksum_manifest.triggered().connect(&slots.packfile_generate_checksum_manifest);
+    app_ui.packfile_verify_checksum_manifest.triggered().connect(&slots.packfile_verify_checksum_manifest);
+    app_ui.packfile_export_contents_manifest.triggered().connect(&slots.packfile_export_contents_manifest);
+    app_ui.packfile_split_loc_by_language.triggered().connect(&slots.packfile_split_loc_by_language);
+    app_ui.packfile_normalize_timestamps.triggered().connect(&slots.packfile_normalize_timestamps);
+    app_ui.packfile_find_orphaned_assets.triggered().connect(&slots.packfile_find_orphaned_assets);
 
     app_ui.change_packfile_type_boot.triggered().connect(&slots.packfile_change_packfile_type);
     app_ui.change_packfile_type_release.triggered().connect(&slots.packfile_change_packfile_type);
@@ -58,6 +74,8 @@ pub unsafe fn set_connections(app_ui: &AppUI, slots: &AppUISlots) {
     app_ui.mymod_delete_selected.triggered().connect(&slots.mymod_delete_selected);
     app_ui.mymod_install.triggered().connect(&slots.mymod_install);
     app_ui.mymod_uninstall.triggered().connect(&slots.mymod_uninstall);
+    app_ui.mymod_upload_to_workshop.triggered().connect(&slots.mymod_upload_to_workshop);
+    app_ui.mymod_git_commit.triggered().connect(&slots.mymod_git_commit);
 
     //-----------------------------------------------//
     // `View` menu connections.
@@ -69,10 +87,12 @@ pub unsafe fn set_connections(app_ui: &AppUI, slots: &AppUISlots) {
     // `Game Selected` menu connections.
     //-----------------------------------------------//
     app_ui.game_selected_launch_game.triggered().connect(&slots.game_selected_launch_game);
+    app_ui.game_selected_launch_game_with_packfile.triggered().connect(&slots.game_selected_launch_game_with_packfile);
 
     app_ui.game_selected_open_game_data_folder.triggered().connect(&slots.game_selected_open_game_data_folder);
     app_ui.game_selected_open_game_assembly_kit_folder.triggered().connect(&slots.game_selected_open_game_assembly_kit_folder);
     app_ui.game_selected_open_config_folder.triggered().connect(&slots.game_selected_open_config_folder);
+    app_ui.game_selected_search_vanilla_files.triggered().connect(&slots.game_selected_search_vanilla_files);
 
     app_ui.game_selected_troy.triggered().connect(&slots.change_game_selected);
     app_ui.game_selected_three_kingdoms.triggered().connect(&slots.change_game_selected);
@@ -115,21 +135,31 @@ pub unsafe fn set_connections(app_ui: &AppUI, slots: &AppUISlots) {
     app_ui.special_stuff_rom2_generate_pak_file.triggered().connect(&slots.special_stuff_generate_pak_file);
     app_ui.special_stuff_sho2_generate_pak_file.triggered().connect(&slots.special_stuff_generate_pak_file);
 
+    //-----------------------------------------------//
+    // `Scripts` menu connections.
+    //-----------------------------------------------//
+    app_ui.scripts_reload.triggered().connect(&slots.scripts_reload);
+    app_ui.scripts_open_scripts_folder.triggered().connect(&slots.scripts_open_scripts_folder);
+
     //-----------------------------------------------//
     // `About` menu connections.
     //-----------------------------------------------//
     app_ui.about_about_qt.triggered().connect(&slots.about_about_qt);
     app_ui.about_about_rpfm.triggered().connect(&slots.about_about_rpfm);
     app_ui.about_open_manual.triggered().connect(&slots.about_open_manual);
+    app_ui.about_start_tour.triggered().connect(&slots.about_start_tour);
     app_ui.about_patreon_link.triggered().connect(&slots.about_patreon_link);
     app_ui.about_check_updates.triggered().connect(&slots.about_check_updates);
     app_ui.about_check_schema_updates.triggered().connect(&slots.about_check_schema_updates);
+    app_ui.about_export_schema_bundle.triggered().connect(&slots.about_export_schema_bundle);
+    app_ui.about_import_schema_bundle.triggered().connect(&slots.about_import_schema_bundle);
     app_ui.about_update_templates.triggered().connect(&slots.about_update_templates);
 
     //-----------------------------------------------//
     // `Debug` menu connections.
     //-----------------------------------------------//
     app_ui.debug_update_current_schema_from_asskit.triggered().connect(&slots.debug_update_current_schema_from_asskit);
+    app_ui.debug_diagnostics.triggered().connect(&slots.debug_diagnostics);
 
     //-----------------------------------------------//
     // `PackedFileView` connections.
@@ -137,4 +167,11 @@ pub unsafe fn set_connections(app_ui: &AppUI, slots: &AppUISlots) {
     app_ui.tab_bar_packed_file.tab_close_requested().connect(&slots.packed_file_hide);
     app_ui.tab_bar_packed_file.current_changed().connect(&slots.packed_file_update);
     app_ui.tab_bar_packed_file.tab_bar_double_clicked().connect(&slots.packed_file_unpreview);
+
+    //-----------------------------------------------//
+    // Save queue connections.
+    //-----------------------------------------------//
+    app_ui.save_queue_timer.timeout().connect(&slots.save_queue_timer_timeout);
+    app_ui.autosave_timer.timeout().connect(&slots.autosave_timer_timeout);
+    app_ui.schema_update_timer.timeout().connect(&slots.schema_update_timer_timeout);
 }
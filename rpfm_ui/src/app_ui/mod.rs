@@ -15,6 +15,7 @@ This module contains all the code needed to initialize the main Window and its m
 !*/
 
 use qt_widgets::q_abstract_item_view::SelectionBehavior;
+use qt_widgets::q_completer::CompletionMode;
 use qt_widgets::QAction;
 use qt_widgets::QActionGroup;
 use qt_widgets::QApplication;
@@ -27,6 +28,7 @@ use qt_widgets::QMenuBar;
 use qt_widgets::QStatusBar;
 use qt_widgets::QTabWidget;
 use qt_widgets::QTableView;
+use qt_widgets::QToolBar;
 use qt_widgets::QWidget;
 use qt_widgets::q_dock_widget::DockWidgetFeature;
 
@@ -34,10 +36,9 @@ use qt_gui::QIcon;
 use qt_gui::QStandardItemModel;
 
 use qt_core::QFlags;
-use qt_core::CaseSensitivity;
 use qt_core::QString;
+use qt_core::QTimer;
 use qt_core::WindowType;
-use qt_core::MatchFlag;
 
 use cpp_core::MutPtr;
 
@@ -73,6 +74,15 @@ const GAME_SELECTED_NAPOLEON: &str = "Napoleon";
 const GAME_SELECTED_EMPIRE: &str = "Empire";
 const GAME_SELECTED_ARENA: &str = "Arena";
 
+/// Interval, in milliseconds, between checks of the game's process while a save is waiting for the game to close.
+const SAVE_QUEUE_CHECK_INTERVAL: i32 = 5000;
+
+/// Interval, in milliseconds, between checks to see if it's time to automatically update the schemas.
+const SCHEMA_UPDATE_CHECK_INTERVAL: i32 = 3_600_000;
+
+/// Name of the file used to know if the program was closed properly. It exists while the program is running.
+const RUNNING_MARKER_FILE: &str = "rpfm_ui.running";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -102,6 +112,9 @@ pub struct AppUI {
     pub tab_bar_packed_file: MutPtr<QTabWidget>,
     pub menu_bar: MutPtr<QMenuBar>,
     pub status_bar: MutPtr<QStatusBar>,
+    pub save_queue_timer: MutPtr<QTimer>,
+    pub autosave_timer: MutPtr<QTimer>,
+    pub schema_update_timer: MutPtr<QTimer>,
 
     //-------------------------------------------------------------------------------//
     // `MenuBar` menus.
@@ -111,6 +124,7 @@ pub struct AppUI {
     pub menu_bar_view: MutPtr<QMenu>,
     pub menu_bar_game_selected: MutPtr<QMenu>,
     pub menu_bar_special_stuff: MutPtr<QMenu>,
+    pub menu_bar_scripts: MutPtr<QMenu>,
     pub menu_bar_about: MutPtr<QMenu>,
     pub menu_bar_debug: MutPtr<QMenu>,
 
@@ -118,13 +132,29 @@ pub struct AppUI {
     // `PackFile` menu.
     //-------------------------------------------------------------------------------//
     pub packfile_new_packfile: MutPtr<QAction>,
+    pub packfile_new_packfile_from_folder: MutPtr<QAction>,
     pub packfile_open_packfile: MutPtr<QAction>,
     pub packfile_save_packfile: MutPtr<QAction>,
     pub packfile_save_packfile_as: MutPtr<QAction>,
     pub packfile_open_from_content: MutPtr<QMenu>,
     pub packfile_open_from_data: MutPtr<QMenu>,
+    pub packfile_browse_workshop: MutPtr<QAction>,
+    pub packfile_open_game_packfile: MutPtr<QMenu>,
     pub packfile_change_packfile_type: MutPtr<QMenu>,
     pub packfile_load_all_ca_packfiles: MutPtr<QAction>,
+    pub packfile_compare_packfiles: MutPtr<QAction>,
+    pub packfile_merge_tables_from_packfiles: MutPtr<QAction>,
+    pub packfile_run_job_file: MutPtr<QAction>,
+    pub packfile_trash: MutPtr<QAction>,
+    pub packfile_create_snapshot: MutPtr<QAction>,
+    pub packfile_snapshots: MutPtr<QAction>,
+    pub packfile_diagnostics_ignore_list: MutPtr<QAction>,
+    pub packfile_generate_checksum_manifest: MutPtr<QAction>,
+    pub packfile_verify_checksum_manifest: MutPtr<QAction>,
+    pub packfile_export_contents_manifest: MutPtr<QAction>,
+    pub packfile_split_loc_by_language: MutPtr<QAction>,
+    pub packfile_normalize_timestamps: MutPtr<QAction>,
+    pub packfile_find_orphaned_assets: MutPtr<QAction>,
     pub packfile_load_template: MutPtr<QMenu>,
     pub packfile_preferences: MutPtr<QAction>,
     pub packfile_quit: MutPtr<QAction>,
@@ -155,6 +185,9 @@ pub struct AppUI {
     pub mymod_delete_selected: MutPtr<QAction>,
     pub mymod_install: MutPtr<QAction>,
     pub mymod_uninstall: MutPtr<QAction>,
+    pub mymod_upload_to_workshop: MutPtr<QAction>,
+    pub mymod_git_commit: MutPtr<QAction>,
+    pub mymod_live_sync: MutPtr<QAction>,
 
     pub mymod_open_troy: MutPtr<QMenu>,
     pub mymod_open_three_kingdoms: MutPtr<QMenu>,
@@ -177,10 +210,12 @@ pub struct AppUI {
     // `Game Selected` menu.
     //-------------------------------------------------------------------------------//
     pub game_selected_launch_game: MutPtr<QAction>,
+    pub game_selected_launch_game_with_packfile: MutPtr<QAction>,
 
     pub game_selected_open_game_data_folder: MutPtr<QAction>,
     pub game_selected_open_game_assembly_kit_folder: MutPtr<QAction>,
     pub game_selected_open_config_folder: MutPtr<QAction>,
+    pub game_selected_search_vanilla_files: MutPtr<QAction>,
 
     pub game_selected_troy: MutPtr<QAction>,
     pub game_selected_three_kingdoms: MutPtr<QAction>,
@@ -242,21 +277,32 @@ pub struct AppUI {
     // Empire's actions.
     pub special_stuff_emp_optimize_packfile: MutPtr<QAction>,
 
+    //-------------------------------------------------------------------------------//
+    // `Scripts` menu.
+    //-------------------------------------------------------------------------------//
+    pub scripts_reload: MutPtr<QAction>,
+    pub scripts_open_scripts_folder: MutPtr<QAction>,
+    pub scripts_tool_bar: MutPtr<QToolBar>,
+
     //-------------------------------------------------------------------------------//
     // `About` menu.
     //-------------------------------------------------------------------------------//
     pub about_about_qt: MutPtr<QAction>,
     pub about_about_rpfm: MutPtr<QAction>,
     pub about_open_manual: MutPtr<QAction>,
+    pub about_start_tour: MutPtr<QAction>,
     pub about_patreon_link: MutPtr<QAction>,
     pub about_check_updates: MutPtr<QAction>,
     pub about_check_schema_updates: MutPtr<QAction>,
+    pub about_export_schema_bundle: MutPtr<QAction>,
+    pub about_import_schema_bundle: MutPtr<QAction>,
     pub about_update_templates: MutPtr<QAction>,
 
     //-------------------------------------------------------------------------------//
     // "Debug" menu.
     //-------------------------------------------------------------------------------//
     pub debug_update_current_schema_from_asskit: MutPtr<QAction>,
+    pub debug_diagnostics: MutPtr<QAction>,
 }
 
 /// This enum contains the data needed to create a new PackedFile.
@@ -300,6 +346,17 @@ impl AppUI {
         layout.add_widget_5a(&mut tab_bar_packed_file, 0, 0, 1, 1);
         STATUS_BAR.store(status_bar.as_mut_raw_ptr(), Ordering::SeqCst);
 
+        // Timer to check if the game has been closed, when a save is waiting for it.
+        let mut save_queue_timer = QTimer::new_1a(main_window);
+        save_queue_timer.set_interval(SAVE_QUEUE_CHECK_INTERVAL);
+
+        // Timer to autosave the open PackFile. Its interval depends on the settings.
+        let autosave_timer = QTimer::new_1a(main_window);
+
+        // Timer to automatically update the schemas. It only runs if automatic schema updates are enabled in the settings.
+        let mut schema_update_timer = QTimer::new_1a(main_window);
+        schema_update_timer.set_interval(SCHEMA_UPDATE_CHECK_INTERVAL);
+
         //-----------------------------------------------//
         // `Command Palette` DockWidget.
         //-----------------------------------------------//
@@ -320,8 +377,8 @@ impl AppUI {
         let mut command_palette_completer_model = QStandardItemModel::new_0a();
 
         // This means our completer search with case-insensitive and contains filters.
-        command_palette_completer.set_filter_mode(QFlags::from(MatchFlag::MatchContains));
-        command_palette_completer.set_case_sensitivity(CaseSensitivity::CaseInsensitive);
+        // The filtering is done by us, as the completer cannot match the PackedFile paths fuzzily.
+        command_palette_completer.set_completion_mode(CompletionMode::UnfilteredPopupCompletion);
         command_palette_completer.set_max_visible_items(8);
 
         command_palette_completer_view.set_show_grid(false);
@@ -347,6 +404,7 @@ impl AppUI {
         let mut menu_bar_view = menu_bar.add_menu_q_string(&qtr("menu_bar_view"));
         let mut menu_bar_game_selected = menu_bar.add_menu_q_string(&qtr("menu_bar_game_selected"));
         let mut menu_bar_special_stuff = menu_bar.add_menu_q_string(&qtr("menu_bar_special_stuff"));
+        let menu_bar_scripts = menu_bar.add_menu_q_string(&qtr("menu_bar_scripts"));
         let mut menu_bar_about = menu_bar.add_menu_q_string(&qtr("menu_bar_about"));
 
         // This menu is hidden unless you enable it.
@@ -361,13 +419,29 @@ impl AppUI {
 
         // Populate the `PackFile` menu.
         let packfile_new_packfile = menu_bar_packfile.add_action_q_string(&qtr("new_packfile"));
+        let packfile_new_packfile_from_folder = menu_bar_packfile.add_action_q_string(&qtr("new_packfile_from_folder"));
         let packfile_open_packfile = menu_bar_packfile.add_action_q_string(&qtr("open_packfile"));
         let packfile_save_packfile = menu_bar_packfile.add_action_q_string(&qtr("save_packfile"));
         let packfile_save_packfile_as = menu_bar_packfile.add_action_q_string(&qtr("save_packfile_as"));
+        let packfile_browse_workshop = menu_bar_packfile.add_action_q_string(&qtr("browse_workshop"));
         let packfile_menu_open_from_content = QMenu::from_q_string(&qtr("open_from_content")).into_ptr();
         let packfile_menu_open_from_data = QMenu::from_q_string(&qtr("open_from_data")).into_ptr();
+        let packfile_menu_open_game_packfile = QMenu::from_q_string(&qtr("open_game_packfile")).into_ptr();
         let mut packfile_menu_change_packfile_type = QMenu::from_q_string(&qtr("change_packfile_type")).into_ptr();
         let packfile_load_all_ca_packfiles = menu_bar_packfile.add_action_q_string(&qtr("load_all_ca_packfiles"));
+        let packfile_compare_packfiles = menu_bar_packfile.add_action_q_string(&qtr("compare_packfiles"));
+        let packfile_merge_tables_from_packfiles = menu_bar_packfile.add_action_q_string(&qtr("merge_tables_from_packfiles"));
+        let packfile_run_job_file = menu_bar_packfile.add_action_q_string(&qtr("run_job_file"));
+        let packfile_trash = menu_bar_packfile.add_action_q_string(&qtr("trash"));
+        let packfile_create_snapshot = menu_bar_packfile.add_action_q_string(&qtr("create_snapshot"));
+        let packfile_snapshots = menu_bar_packfile.add_action_q_string(&qtr("snapshots"));
+        let packfile_diagnostics_ignore_list = menu_bar_packfile.add_action_q_string(&qtr("diagnostics_ignore_list"));
+        let packfile_generate_checksum_manifest = menu_bar_packfile.add_action_q_string(&qtr("generate_checksum_manifest"));
+        let packfile_verify_checksum_manifest = menu_bar_packfile.add_action_q_string(&qtr("verify_checksum_manifest"));
+        let packfile_export_contents_manifest = menu_bar_packfile.add_action_q_string(&qtr("export_contents_manifest"));
+        let packfile_split_loc_by_language = menu_bar_packfile.add_action_q_string(&qtr("split_loc_by_language"));
+        let packfile_normalize_timestamps = menu_bar_packfile.add_action_q_string(&qtr("normalize_timestamps"));
+        let packfile_find_orphaned_assets = menu_bar_packfile.add_action_q_string(&qtr("find_orphaned_assets"));
         let packfile_menu_load_template = QMenu::from_q_string(&qtr("load_template")).into_ptr();
         let packfile_preferences = menu_bar_packfile.add_action_q_string(&qtr("preferences"));
         let packfile_quit = menu_bar_packfile.add_action_q_string(&qtr("quit"));
@@ -375,6 +449,7 @@ impl AppUI {
         // Add the "Open..." submenus. These needs to be here because they have to be inserted in specific positions of the menu.
         menu_bar_packfile.insert_menu(packfile_load_all_ca_packfiles, packfile_menu_open_from_content);
         menu_bar_packfile.insert_menu(packfile_load_all_ca_packfiles, packfile_menu_open_from_data);
+        menu_bar_packfile.insert_menu(packfile_load_all_ca_packfiles, packfile_menu_open_game_packfile);
 
         menu_bar_packfile.insert_separator(packfile_menu_open_from_content.menu_action());
         menu_bar_packfile.insert_separator(packfile_preferences);
@@ -437,6 +512,10 @@ impl AppUI {
         let mut mymod_delete_selected = menu_bar_mymod.add_action_q_string(&qtr("mymod_delete_selected"));
         let mut mymod_install = menu_bar_mymod.add_action_q_string(&qtr("mymod_install"));
         let mut mymod_uninstall = menu_bar_mymod.add_action_q_string(&qtr("mymod_uninstall"));
+        let mut mymod_upload_to_workshop = menu_bar_mymod.add_action_q_string(&qtr("mymod_upload_to_workshop"));
+        let mut mymod_git_commit = menu_bar_mymod.add_action_q_string(&qtr("mymod_git_commit"));
+        let mut mymod_live_sync = menu_bar_mymod.add_action_q_string(&qtr("mymod_live_sync"));
+        mymod_live_sync.set_checkable(true);
 
         menu_bar_mymod.add_separator();
 
@@ -458,6 +537,9 @@ impl AppUI {
         mymod_delete_selected.set_enabled(false);
         mymod_install.set_enabled(false);
         mymod_uninstall.set_enabled(false);
+        mymod_upload_to_workshop.set_enabled(false);
+        mymod_git_commit.set_enabled(false);
+        mymod_live_sync.set_enabled(false);
 
         mymod_open_troy.menu_action().set_visible(false);
         mymod_open_three_kingdoms.menu_action().set_visible(false);
@@ -484,10 +566,12 @@ impl AppUI {
 
         // Populate the `Game Selected` menu.
         let game_selected_launch_game = menu_bar_game_selected.add_action_q_string(&qtr("game_selected_launch_game"));
+        let game_selected_launch_game_with_packfile = menu_bar_game_selected.add_action_q_string(&qtr("game_selected_launch_game_with_packfile"));
 
         let game_selected_open_game_data_folder = menu_bar_game_selected.add_action_q_string(&qtr("game_selected_open_game_data_folder"));
         let game_selected_open_game_assembly_kit_folder = menu_bar_game_selected.add_action_q_string(&qtr("game_selected_open_game_assembly_kit_folder"));
         let game_selected_open_config_folder = menu_bar_game_selected.add_action_q_string(&qtr("game_selected_open_config_folder"));
+        let game_selected_search_vanilla_files = menu_bar_game_selected.add_action_q_string(&qtr("game_selected_search_vanilla_files"));
 
         let mut game_selected_troy = menu_bar_game_selected.add_action_q_string(&QString::from_std_str(GAME_SELECTED_TROY));
         let mut game_selected_three_kingdoms = menu_bar_game_selected.add_action_q_string(&QString::from_std_str(GAME_SELECTED_THREE_KINGDOMS));
@@ -581,6 +665,16 @@ impl AppUI {
         let special_stuff_nap_optimize_packfile = menu_napoleon.add_action_q_string(&qtr("special_stuff_optimize_packfile"));
         let special_stuff_emp_optimize_packfile = menu_empire.add_action_q_string(&qtr("special_stuff_optimize_packfile"));
 
+        //-----------------------------------------------//
+        // `Scripts` Menu.
+        //-----------------------------------------------//
+
+        // The `Scripts` menu is rebuilt every time the scripts are reloaded, so its fixed actions cannot be owned by it.
+        let scripts_reload = QAction::from_q_string(&qtr("scripts_reload"));
+        let scripts_open_scripts_folder = QAction::from_q_string(&qtr("scripts_open_scripts_folder"));
+        let mut scripts_tool_bar = main_window.add_tool_bar_q_string(&qtr("scripts_tool_bar"));
+        scripts_tool_bar.set_visible(false);
+
         //-----------------------------------------------//
         // `About` Menu.
         //-----------------------------------------------//
@@ -589,9 +683,12 @@ impl AppUI {
         let about_about_qt = menu_bar_about.add_action_q_string(&qtr("about_about_qt"));
         let about_about_rpfm = menu_bar_about.add_action_q_string(&qtr("about_about_rpfm"));
         let about_open_manual = menu_bar_about.add_action_q_string(&qtr("about_open_manual"));
+        let about_start_tour = menu_bar_about.add_action_q_string(&qtr("about_start_tour"));
         let about_patreon_link = menu_bar_about.add_action_q_string(&qtr("about_patreon_link"));
         let about_check_updates = menu_bar_about.add_action_q_string(&qtr("about_check_updates"));
         let about_check_schema_updates = menu_bar_about.add_action_q_string(&qtr("about_check_schema_updates"));
+        let about_export_schema_bundle = menu_bar_about.add_action_q_string(&qtr("about_export_schema_bundle"));
+        let about_import_schema_bundle = menu_bar_about.add_action_q_string(&qtr("about_import_schema_bundle"));
         let about_update_templates = menu_bar_about.add_action_q_string(&qtr("about_update_templates"));
 
         //-----------------------------------------------//
@@ -600,6 +697,7 @@ impl AppUI {
 
         // Populate the `Debug` menu.
         let debug_update_current_schema_from_asskit = menu_bar_debug.add_action_q_string(&qtr("update_current_schema_from_asskit"));
+        let debug_diagnostics = menu_bar_debug.add_action_q_string(&qtr("debug_diagnostics"));
 
         command_palette_widget.hide();
 
@@ -613,6 +711,9 @@ impl AppUI {
             tab_bar_packed_file: tab_bar_packed_file.into_ptr(),
             menu_bar,
             status_bar,
+            save_queue_timer: save_queue_timer.into_ptr(),
+            autosave_timer: autosave_timer.into_ptr(),
+            schema_update_timer: schema_update_timer.into_ptr(),
 
             //-------------------------------------------------------------------------------//
             // `Command Palette` DockWidget.
@@ -634,6 +735,7 @@ impl AppUI {
             menu_bar_view,
             menu_bar_game_selected,
             menu_bar_special_stuff,
+            menu_bar_scripts,
             menu_bar_about,
             menu_bar_debug,
 
@@ -643,13 +745,29 @@ impl AppUI {
 
             // Menus.
             packfile_new_packfile,
+            packfile_new_packfile_from_folder,
             packfile_open_packfile,
             packfile_save_packfile,
             packfile_save_packfile_as,
             packfile_open_from_content: packfile_menu_open_from_content,
             packfile_open_from_data: packfile_menu_open_from_data,
+            packfile_browse_workshop,
+            packfile_open_game_packfile: packfile_menu_open_game_packfile,
             packfile_change_packfile_type: packfile_menu_change_packfile_type,
             packfile_load_all_ca_packfiles,
+            packfile_compare_packfiles,
+            packfile_merge_tables_from_packfiles,
+            packfile_run_job_file,
+            packfile_trash,
+            packfile_create_snapshot,
+            packfile_snapshots,
+            packfile_diagnostics_ignore_list,
+            packfile_generate_checksum_manifest,
+            packfile_verify_checksum_manifest,
+            packfile_export_contents_manifest,
+            packfile_split_loc_by_language,
+            packfile_normalize_timestamps,
+            packfile_find_orphaned_assets,
             packfile_load_template: packfile_menu_load_template,
             packfile_preferences,
             packfile_quit,
@@ -680,6 +798,9 @@ impl AppUI {
             mymod_delete_selected,
             mymod_install,
             mymod_uninstall,
+            mymod_upload_to_workshop,
+            mymod_git_commit,
+            mymod_live_sync,
 
             mymod_open_troy,
             mymod_open_three_kingdoms,
@@ -702,10 +823,12 @@ impl AppUI {
             // "Game Selected" menu.
             //-------------------------------------------------------------------------------//
             game_selected_launch_game,
+            game_selected_launch_game_with_packfile,
 
             game_selected_open_game_data_folder,
             game_selected_open_game_assembly_kit_folder,
             game_selected_open_config_folder,
+            game_selected_search_vanilla_files,
 
             game_selected_troy,
             game_selected_three_kingdoms,
@@ -767,21 +890,32 @@ impl AppUI {
             // Empire's actions.
             special_stuff_emp_optimize_packfile,
 
+            //-------------------------------------------------------------------------------//
+            // "Scripts" menu.
+            //-------------------------------------------------------------------------------//
+            scripts_reload: scripts_reload.into_ptr(),
+            scripts_open_scripts_folder: scripts_open_scripts_folder.into_ptr(),
+            scripts_tool_bar,
+
             //-------------------------------------------------------------------------------//
             // "About" menu.
             //-------------------------------------------------------------------------------//
             about_about_qt,
             about_about_rpfm,
             about_open_manual,
+            about_start_tour,
             about_patreon_link,
             about_check_updates,
             about_check_schema_updates,
+            about_export_schema_bundle,
+            about_import_schema_bundle,
             about_update_templates,
 
             //-------------------------------------------------------------------------------//
             // "Debug" menu.
             //-------------------------------------------------------------------------------//
             debug_update_current_schema_from_asskit,
+            debug_diagnostics,
         }
     }
 }
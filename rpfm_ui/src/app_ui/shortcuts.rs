@@ -33,6 +33,7 @@ pub unsafe fn set_shortcuts(app_ui: &mut AppUI) {
 
     // Set the shortcuts for these actions.
     app_ui.packfile_new_packfile.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_packfile["new_packfile"])));
+    app_ui.packfile_new_packfile_from_folder.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_packfile["new_packfile_from_folder"])));
     app_ui.packfile_open_packfile.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_packfile["open_packfile"])));
     app_ui.packfile_save_packfile.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_packfile["save_packfile"])));
     app_ui.packfile_save_packfile_as.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_packfile["save_packfile_as"])));
@@ -44,14 +45,19 @@ pub unsafe fn set_shortcuts(app_ui: &mut AppUI) {
     app_ui.mymod_delete_selected.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_mymod["mymod_delete_selected"])));
     app_ui.mymod_install.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_mymod["mymod_install"])));
     app_ui.mymod_uninstall.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_mymod["mymod_uninstall"])));
+    app_ui.mymod_upload_to_workshop.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_mymod["mymod_upload_to_workshop"])));
+    app_ui.mymod_git_commit.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_mymod["mymod_git_commit"])));
+    app_ui.mymod_live_sync.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_mymod["mymod_live_sync"])));
 
     app_ui.view_toggle_packfile_contents.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_view["view_toggle_packfile_contents"])));
     app_ui.view_toggle_global_search_panel.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_view["view_toggle_global_search_panel"])));
 
     app_ui.game_selected_launch_game.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_game_selected["launch_game"])));
+    app_ui.game_selected_launch_game_with_packfile.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_game_selected["launch_game_with_packfile"])));
     app_ui.game_selected_open_game_data_folder.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_game_selected["open_game_data_folder"])));
     app_ui.game_selected_open_game_assembly_kit_folder.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_game_selected["open_game_assembly_kit_folder"])));
     app_ui.game_selected_open_config_folder.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_game_selected["open_config_folder"])));
+    app_ui.game_selected_search_vanilla_files.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_game_selected["search_vanilla_files"])));
 
     app_ui.special_stuff_three_k_generate_pak_file.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_special_stuff["generate_pak"])));
     app_ui.special_stuff_three_k_optimize_packfile.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_special_stuff["optimize_packfile"])));
@@ -84,6 +90,7 @@ pub unsafe fn set_shortcuts(app_ui: &mut AppUI) {
     app_ui.about_about_qt.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["about_qt"])));
     app_ui.about_about_rpfm.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["about_rpfm"])));
     app_ui.about_open_manual.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["open_manual"])));
+    app_ui.about_start_tour.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["start_tour"])));
     app_ui.about_patreon_link.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["support_me_on_patreon"])));
     app_ui.about_check_updates.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["check_updates"])));
     app_ui.about_check_schema_updates.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.menu_bar_about["check_schema_updates"])));
@@ -100,14 +107,19 @@ pub unsafe fn set_shortcuts(app_ui: &mut AppUI) {
     app_ui.mymod_delete_selected.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.mymod_install.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.mymod_uninstall.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+    app_ui.mymod_upload_to_workshop.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+    app_ui.mymod_git_commit.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+    app_ui.mymod_live_sync.set_shortcut_context(ShortcutContext::ApplicationShortcut);
 
     app_ui.view_toggle_packfile_contents.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.view_toggle_global_search_panel.set_shortcut_context(ShortcutContext::ApplicationShortcut);
 
     app_ui.game_selected_launch_game.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+    app_ui.game_selected_launch_game_with_packfile.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.game_selected_open_game_data_folder.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.game_selected_open_game_assembly_kit_folder.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.game_selected_open_config_folder.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+    app_ui.game_selected_search_vanilla_files.set_shortcut_context(ShortcutContext::ApplicationShortcut);
 
     app_ui.special_stuff_three_k_generate_pak_file.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.special_stuff_three_k_optimize_packfile.set_shortcut_context(ShortcutContext::ApplicationShortcut);
@@ -140,6 +152,7 @@ pub unsafe fn set_shortcuts(app_ui: &mut AppUI) {
     app_ui.about_about_qt.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.about_about_rpfm.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.about_open_manual.set_shortcut_context(ShortcutContext::ApplicationShortcut);
+    app_ui.about_start_tour.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.about_patreon_link.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.about_check_updates.set_shortcut_context(ShortcutContext::ApplicationShortcut);
     app_ui.about_check_schema_updates.set_shortcut_context(ShortcutContext::ApplicationShortcut);
@@ -14,20 +14,19 @@ Module with all the code related to the main `AppUISlot`.
 
 use qt_widgets::QAction;
 use qt_widgets::QCompleter;
-use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
+use qt_widgets::{QFileDialog, q_file_dialog::{AcceptMode, FileMode, Option as QFileDialogOption}};
 use qt_widgets::QMessageBox;
 
 use qt_gui::QDesktopServices;
 
 use qt_core::FocusReason;
 use qt_core::{SlotOfBool, SlotOfInt, Slot, SlotOfQString};
-use qt_core::QFlags;
 use qt_core::QString;
 use qt_core::QUrl;
 
 
 use std::cell::RefCell;
-use std::fs::{DirBuilder, copy, remove_file, remove_dir_all};
+use std::fs::{DirBuilder, copy, remove_file};
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -38,10 +37,14 @@ use rpfm_lib::config::get_config_path;
 use rpfm_lib::DOCS_BASE_URL;
 use rpfm_lib::GAME_SELECTED;
 use rpfm_lib::games::*;
-use rpfm_lib::packfile::{PFHFileType, CompressionState, RESERVED_NAME_EXTRA_PACKFILE};
+use rpfm_lib::jobs::USER_SCRIPTS_FOLDER;
+use rpfm_lib::mymod::{MyModConfig, template::MyModTemplate};
+use rpfm_lib::packfile::{PathType, PFHFileType, CompressionState, RESERVED_NAME_EXTRA_PACKFILE};
+use rpfm_lib::packfile::checksums::ChecksumManifest;
 use rpfm_lib::packedfile::animpack;
 use rpfm_lib::PATREON_URL;
 use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::{LAST_PATH_ADD_FILE, LAST_PATH_OPEN_PACKFILE, LAST_PATH_RUN_JOB, SCHEMA_UPDATE_INTERVAL, WORKSHOP_STEAM_USER};
 use rpfm_lib::SCHEMA;
 use rpfm_lib::SUPPORTED_GAMES;
 
@@ -49,19 +52,28 @@ use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::command_palette;
 use crate::communications::{THREADS_COMMUNICATION_ERROR, Command, Response};
+use crate::compare_ui::CompareUI;
+use crate::diagnostics_ui::DiagnosticsUI;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::locale::{qtr, tr, tre};
+use crate::merge_ui::MergeUI;
 use crate::mymod_ui::MyModUI;
+use crate::orphaned_assets_ui::OrphanedAssetsUI;
 use crate::pack_tree::{new_pack_file_tooltip, PackTree, TreeViewOperation};
 use crate::packedfile_views::{TheOneSlot, View, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::pack_tree::TreePathType;
 use crate::settings_ui::SettingsUI;
+use crate::tour_ui::TourUI;
+use crate::snapshots_ui::SnapshotsUI;
+use crate::trash_ui::TrashUI;
 use crate::ui::GameSelectedIcons;
 use crate::{ui_state::op_mode::OperationalMode, UI_STATE};
-use crate::utils::show_dialog;
+use crate::utils::{catch_panic_in_slot, log_to_status_bar, Problems, save_last_path, save_setting_string, setup_file_dialog, show_dialog};
+use crate::vanilla_search_ui::VanillaSearchUI;
 use crate::VERSION;
-use crate::views::table::utils::{check_table_for_errors, get_reference_data, setup_item_delegates};
+use crate::workshop_ui::WorkshopUI;
+use super::RUNNING_MARKER_FILE;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -78,21 +90,38 @@ pub struct AppUISlots {
     pub command_palette_show: Slot<'static>,
     pub command_palette_hide: Slot<'static>,
     pub command_palette_trigger: SlotOfQString<'static>,
+    pub command_palette_filter: SlotOfQString<'static>,
 
     //-----------------------------------------------//
     // `PackFile` menu slots.
     //-----------------------------------------------//
     pub packfile_new_packfile: SlotOfBool<'static>,
+    pub packfile_new_packfile_from_folder: SlotOfBool<'static>,
     pub packfile_open_packfile: SlotOfBool<'static>,
     pub packfile_save_packfile: SlotOfBool<'static>,
     pub packfile_save_packfile_as: SlotOfBool<'static>,
+    pub packfile_browse_workshop: SlotOfBool<'static>,
     pub packfile_open_from: Vec<SlotOfBool<'static>>,
     pub packfile_load_all_ca_packfiles: SlotOfBool<'static>,
+    pub packfile_compare_packfiles: SlotOfBool<'static>,
+    pub packfile_merge_tables_from_packfiles: SlotOfBool<'static>,
+    pub packfile_run_job_file: SlotOfBool<'static>,
+    pub packfile_trash: SlotOfBool<'static>,
+    pub packfile_create_snapshot: SlotOfBool<'static>,
+    pub packfile_snapshots: SlotOfBool<'static>,
+    pub packfile_diagnostics_ignore_list: SlotOfBool<'static>,
+    pub packfile_generate_checksum_manifest: SlotOfBool<'static>,
+    pub packfile_verify_checksum_manifest: SlotOfBool<'static>,
+    pub packfile_export_contents_manifest: SlotOfBool<'static>,
+    pub packfile_split_loc_by_language: SlotOfBool<'static>,
+    pub packfile_normalize_timestamps: SlotOfBool<'static>,
+    pub packfile_find_orphaned_assets: SlotOfBool<'static>,
     pub packfile_change_packfile_type: SlotOfBool<'static>,
     pub packfile_index_includes_timestamp: SlotOfBool<'static>,
     pub packfile_data_is_compressed: SlotOfBool<'static>,
     pub packfile_preferences: SlotOfBool<'static>,
     pub packfile_quit: SlotOfBool<'static>,
+    pub about_to_quit: Slot<'static>,
 
     //-----------------------------------------------//
     // `MyMod` menu slots.
@@ -101,6 +130,8 @@ pub struct AppUISlots {
     pub mymod_delete_selected: SlotOfBool<'static>,
     pub mymod_install: SlotOfBool<'static>,
     pub mymod_uninstall: SlotOfBool<'static>,
+    pub mymod_upload_to_workshop: SlotOfBool<'static>,
+    pub mymod_git_commit: SlotOfBool<'static>,
     pub mymod_open: Vec<SlotOfBool<'static>>,
 
     //-----------------------------------------------//
@@ -113,9 +144,11 @@ pub struct AppUISlots {
     // `Game Selected` menu slots.
     //-----------------------------------------------//
     pub game_selected_launch_game: SlotOfBool<'static>,
+    pub game_selected_launch_game_with_packfile: SlotOfBool<'static>,
     pub game_selected_open_game_data_folder: SlotOfBool<'static>,
     pub game_selected_open_game_assembly_kit_folder: SlotOfBool<'static>,
     pub game_selected_open_config_folder: SlotOfBool<'static>,
+    pub game_selected_search_vanilla_files: SlotOfBool<'static>,
     pub change_game_selected: SlotOfBool<'static>,
 
     //-----------------------------------------------//
@@ -126,21 +159,31 @@ pub struct AppUISlots {
     pub special_stuff_optimize_packfile: SlotOfBool<'static>,
     pub special_stuff_patch_siege_ai: SlotOfBool<'static>,
 
+    //-----------------------------------------------//
+    // `Scripts` menu slots.
+    //-----------------------------------------------//
+    pub scripts_reload: SlotOfBool<'static>,
+    pub scripts_open_scripts_folder: SlotOfBool<'static>,
+
     //-----------------------------------------------//
     // `About` menu slots.
     //-----------------------------------------------//
     pub about_about_qt: SlotOfBool<'static>,
     pub about_about_rpfm: SlotOfBool<'static>,
     pub about_open_manual: SlotOfBool<'static>,
+    pub about_start_tour: SlotOfBool<'static>,
     pub about_patreon_link: SlotOfBool<'static>,
     pub about_check_updates: SlotOfBool<'static>,
     pub about_check_schema_updates: SlotOfBool<'static>,
+    pub about_export_schema_bundle: SlotOfBool<'static>,
+    pub about_import_schema_bundle: SlotOfBool<'static>,
     pub about_update_templates: SlotOfBool<'static>,
 
     //-----------------------------------------------//
     // `Debug` menu slots.
     //-----------------------------------------------//
     pub debug_update_current_schema_from_asskit: SlotOfBool<'static>,
+    pub debug_diagnostics: SlotOfBool<'static>,
 
     //-----------------------------------------------//
     // `PackedFileView` slots.
@@ -148,11 +191,19 @@ pub struct AppUISlots {
     pub packed_file_hide: SlotOfInt<'static>,
     pub packed_file_update: SlotOfInt<'static>,
     pub packed_file_unpreview: SlotOfInt<'static>,
+
+    //-----------------------------------------------//
+    // Save queue slots.
+    //-----------------------------------------------//
+    pub save_queue_timer_timeout: Slot<'static>,
+    pub autosave_timer_timeout: Slot<'static>,
+    pub schema_update_timer_timeout: Slot<'static>,
 }
 
 pub struct AppUITempSlots {
     pub packfile_open_from: Vec<SlotOfBool<'static>>,
     pub mymod_open: Vec<SlotOfBool<'static>>,
+    pub scripts: Vec<SlotOfBool<'static>>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -175,8 +226,12 @@ impl AppUISlots {
         // Command Palette logic.
         //-----------------------------------------------//
 
+        // The paths of the PackedFiles are loaded when showing the command palette, so we don't have to ask for them on every keystroke.
+        let command_palette_packed_file_paths: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+
         // This one puts the command palette in the top center part of the window, make it appear and gives it the focus.
-		let command_palette_show = Slot::new(move || {
+		let command_palette_show = Slot::new(clone!(
+            command_palette_packed_file_paths => move || {
             let mut line_edit = app_ui.command_palette_line_edit;
             let mut command_palette = app_ui.command_palette;
             let mut completer = app_ui.command_palette_completer;
@@ -187,26 +242,35 @@ impl AppUISlots {
             command_palette.move_2a(width, height);
             line_edit.set_completer(app_ui.command_palette_completer);
 
-            command_palette::load_actions(&mut app_ui, &pack_file_contents_ui);
+            *command_palette_packed_file_paths.borrow_mut() = command_palette::get_packed_file_paths();
+            command_palette::load_actions(&mut app_ui, &pack_file_contents_ui, &command_palette_packed_file_paths.borrow(), "");
             command_palette.show();
 			line_edit.set_focus_1a(FocusReason::ShortcutFocusReason);
             line_edit.set_text(&QString::from_std_str(""));
 
             line_edit.completer();
             completer.complete_0a();
-        });
+        }));
 
 		// This one hides the command palette.
-        let command_palette_hide = Slot::new(move || {
+        let command_palette_hide = Slot::new(move || catch_panic_in_slot(|| {
             app_ui.command_palette_line_edit.set_completer(QCompleter::new().into_ptr());
             app_ui.command_palette.hide();
-        });
+        }));
 
         // This is the fun one. This one triggers any command you type in the command palette.
-        let command_palette_trigger = SlotOfQString::new(move |command| {
+        let command_palette_trigger = SlotOfQString::new(clone!(
+            command_palette_packed_file_paths => move |command| {
         	app_ui.command_palette.hide();
-            command_palette::exec_action(&app_ui, &pack_file_contents_ui, command);
-        });
+            command_palette::exec_action(&app_ui, &pack_file_contents_ui, &mut global_search_ui, &command_palette_packed_file_paths.borrow(), command);
+        }));
+
+        // This one reloads the list of the command palette with the actions and PackedFiles matching what we wrote.
+        let command_palette_filter = SlotOfQString::new(clone!(
+            command_palette_packed_file_paths => move |filter| {
+            command_palette::load_actions(&mut app_ui, &pack_file_contents_ui, &command_palette_packed_file_paths.borrow(), &filter.to_std_string());
+            app_ui.command_palette_completer.complete_0a();
+        }));
 
         //-----------------------------------------------//
         // `PackFile` menu logic.
@@ -248,6 +312,7 @@ impl AppUISlots {
 
                     // Update the TreeView.
                     pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Build(None));
+                    UI_STATE.clear_packfile_contents_moves();
 
                     // Re-enable the Main Window.
                     app_ui.main_window.set_enabled(true);
@@ -262,6 +327,60 @@ impl AppUISlots {
             }
         ));
 
+        // What happens when we trigger the "New PackFile from Folder" action.
+        let packfile_new_packfile_from_folder = SlotOfBool::new(clone!(
+            mut global_search_ui,
+            mut slot_holder => move |_| {
+
+                // Check first if there has been changes in the PackFile.
+                if app_ui.are_you_sure(false) {
+
+                    // Create the FileDialog to get the folder to pack, and configure it.
+                    let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                        app_ui.main_window,
+                        &qtr("new_packfile_from_folder"),
+                    );
+                    setup_file_dialog(&mut file_dialog, Some(LAST_PATH_ADD_FILE));
+                    file_dialog.set_file_mode(FileMode::Directory);
+                    file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+
+                    if file_dialog.exec() == 1 {
+                        let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                        save_last_path(LAST_PATH_ADD_FILE, &path);
+
+                        // Close any open PackedFile. The live sync is tied to the PackFile we're closing, so stop it too.
+                        let _ = app_ui.purge_them_all(global_search_ui, pack_file_contents_ui, &slot_holder, false);
+                        app_ui.mymod_live_sync.set_checked(false);
+
+                        app_ui.main_window.set_enabled(false);
+                        CENTRAL_COMMAND.send_message_qt(Command::NewPackFileFromFolder(path));
+                        let response = CENTRAL_COMMAND.recv_message_qt_try();
+                        match response {
+                            Response::PackFileInfo(pack_file_info) => {
+                                global_search_ui.clear();
+                                app_ui.update_packfile_type_menu(&pack_file_info);
+
+                                // Update the TreeView.
+                                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Build(None));
+                                UI_STATE.clear_packfile_contents_moves();
+
+                                // Enable the actions available for the PackFile from the `MenuBar`.
+                                app_ui.enable_packfile_actions(true);
+
+                                // It's a new PackFile, so it's not a MyMod, and it's not saved yet.
+                                UI_STATE.set_operational_mode(&mut app_ui, None);
+                                UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+                            }
+                            Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                        }
+
+                        app_ui.main_window.set_enabled(true);
+                    }
+                }
+            }
+        ));
+
         let packfile_open_packfile = SlotOfBool::new(clone!(
             slot_holder => move |_| {
 
@@ -273,6 +392,7 @@ impl AppUISlots {
                         app_ui.main_window,
                         &qtr("open_packfiles"),
                     );
+                    setup_file_dialog(&mut file_dialog, Some(LAST_PATH_OPEN_PACKFILE));
                     file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
                     file_dialog.set_file_mode(FileMode::ExistingFiles);
 
@@ -286,6 +406,8 @@ impl AppUISlots {
                             paths.push(PathBuf::from(file_dialog.selected_files().at(index).to_std_string()));
                         }
 
+                        if let Some(folder) = paths.first().and_then(|path| path.parent()) { save_last_path(LAST_PATH_OPEN_PACKFILE, folder); }
+
                         // Try to open it, and report it case of error.
                         if let Err(error) = app_ui.open_packfile(&mut pack_file_contents_ui, &mut global_search_ui, &paths, "", &slot_holder) { show_dialog(app_ui.main_window, error, false); }
                     }
@@ -294,21 +416,34 @@ impl AppUISlots {
         ));
 
         // What happens when we trigger the "Save PackFile" action.
-        let packfile_save_packfile = SlotOfBool::new(move |_| {
+        let packfile_save_packfile = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
                 if let Err(error) = app_ui.save_packfile(&mut pack_file_contents_ui, &global_search_ui, false) {
                     show_dialog(app_ui.main_window, error, false);
                 }
-            }
+            })
         );
 
         // What happens when we trigger the "Save PackFile As" action.
-        let packfile_save_packfile_as = SlotOfBool::new(move |_| {
+        let packfile_save_packfile_as = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
                 if let Err(error) = app_ui.save_packfile(&mut pack_file_contents_ui, &global_search_ui, true) {
                     show_dialog(app_ui.main_window, error, false);
                 }
-            }
+            })
         );
 
+        // What happens when we trigger the "Browse Workshop" action.
+        let packfile_browse_workshop = SlotOfBool::new(clone!(
+            slot_holder => move |_| {
+                if let Some(path) = WorkshopUI::new(app_ui.main_window) {
+                    if app_ui.are_you_sure(false) {
+                        if let Err(error) = app_ui.open_packfile(&mut pack_file_contents_ui, &mut global_search_ui, &[path], "", &slot_holder) {
+                            show_dialog(app_ui.main_window, error, false);
+                        }
+                    }
+                }
+            }
+        ));
+
         let packfile_open_from = vec![];
 
         // What happens when we trigger the "Load All CA PackFiles" action.
@@ -352,6 +487,7 @@ impl AppUISlots {
 
                         // Update the TreeView.
                         pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Build(None));
+                        UI_STATE.clear_packfile_contents_moves();
 
                         let game_selected = GAME_SELECTED.read().unwrap().to_owned();
                         match &*game_selected {
@@ -394,7 +530,7 @@ impl AppUISlots {
         }));
 
         // What happens when we trigger the "Change PackFile Type" action.
-        let packfile_change_packfile_type = SlotOfBool::new(move |_| {
+        let packfile_change_packfile_type = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Get the currently selected PackFile's Type.
                 let packfile_type = match &*(app_ui.change_packfile_type_group
@@ -410,23 +546,318 @@ impl AppUISlots {
                 // Send the type to the Background Thread, and update the UI.
                 CENTRAL_COMMAND.send_message_qt(Command::SetPackFileType(packfile_type));
                 UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
-            }
+            })
         );
 
         // What happens when we change the value of "Include Last Modified Date" action.
-        let packfile_index_includes_timestamp = SlotOfBool::new(move |_| {
+        let packfile_index_includes_timestamp = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
                 let state = app_ui.change_packfile_type_index_includes_timestamp.is_checked();
                 CENTRAL_COMMAND.send_message_qt(Command::ChangeIndexIncludesTimestamp(state));
                 UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
-            }
+            })
         );
 
         // What happens when we enable/disable compression on the current PackFile.
-        let packfile_data_is_compressed = SlotOfBool::new(move |_| {
+        let packfile_data_is_compressed = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
                 let state = app_ui.change_packfile_type_data_is_compressed.is_checked();
                 CENTRAL_COMMAND.send_message_qt(Command::ChangeDataIsCompressed(state));
                 UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+            })
+        );
+
+        // What happens when we trigger the "Compare PackFiles" action.
+        let packfile_compare_packfiles = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                CompareUI::new(app_ui.main_window);
+            })
+        );
+
+        // What happens when we trigger the "Merge Tables from PackFiles" action.
+        let packfile_merge_tables_from_packfiles = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                let merged_paths = MergeUI::new(app_ui.main_window);
+                if merged_paths.is_empty() { return; }
+
+                // The merged tables replaced whatever we had in these paths, so close their views without saving them.
+                let mut problems = Problems::default();
+                for path in &merged_paths {
+                    if let Err(error) = app_ui.purge_that_one_specifically(global_search_ui, pack_file_contents_ui, path, false) {
+                        problems.push(&path.join("/"), error);
+                    }
+                }
+                problems.report(app_ui.main_window);
+
+                let paths_to_add = merged_paths.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths_to_add.to_vec()));
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths_to_add));
+                UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+
+                // Update the global search stuff, if needed.
+                global_search_ui.search_on_path(&mut pack_file_contents_ui, merged_paths.into_iter().map(PathType::File).collect());
+            })
+        );
+
+        // What happens when we trigger the "Run Job File" action.
+        let packfile_run_job_file = {
+            let slot_holder = slot_holder.clone();
+            SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+
+                    // The Job may replace the open PackFile, so check first if there has been changes in it.
+                    if app_ui.are_you_sure(false) {
+                        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                            app_ui.main_window,
+                            &qtr("run_job_file"),
+                        );
+                        setup_file_dialog(&mut file_dialog, Some(LAST_PATH_RUN_JOB));
+                        file_dialog.set_name_filter(&QString::from_std_str("Job Files (*.json *.yaml *.yml)"));
+                        file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                        if file_dialog.exec() == 1 {
+                            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                            if let Some(folder) = path.parent() { save_last_path(LAST_PATH_RUN_JOB, folder); }
+
+                            app_ui.run_job_file(&mut pack_file_contents_ui, &mut global_search_ui, &path, &slot_holder);
+                        }
+                    }
+                })
+            )
+        };
+
+        // What happens when we trigger the "Trash" action.
+        let packfile_trash = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                let restored_paths = TrashUI::new(app_ui.main_window);
+                if restored_paths.is_empty() { return; }
+
+                let paths_to_add = restored_paths.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths_to_add.to_vec()));
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths_to_add));
+                UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+
+                // Update the global search stuff, if needed.
+                global_search_ui.search_on_path(&mut pack_file_contents_ui, restored_paths.into_iter().map(PathType::File).collect());
+            })
+        );
+
+        // What happens when we trigger the "Create Snapshot" action.
+        let packfile_create_snapshot = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                match app_ui.create_snapshot(&pack_file_contents_ui, &global_search_ui) {
+                    Ok(_) => log_to_status_bar(&tr("create_snapshot_success")),
+                    Err(error) => show_dialog(app_ui.main_window, error, false),
+                }
+            })
+        );
+
+        // What happens when we trigger the "Snapshots" action.
+        let packfile_snapshots = SlotOfBool::new(clone!(
+            slot_holder => move |_| {
+                if let Some(index) = SnapshotsUI::new(app_ui.main_window) {
+                    if let Err(error) = app_ui.restore_snapshot(&mut pack_file_contents_ui, &mut global_search_ui, index, &slot_holder) {
+                        show_dialog(app_ui.main_window, error, false);
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Diagnostics Ignore List" action.
+        let packfile_diagnostics_ignore_list = SlotOfBool::new(clone!(
+            slot_holder => move |_| {
+                app_ui.open_diagnostics_ignore_list(&pack_file_contents_ui, &global_search_ui, &slot_holder);
             }
+        ));
+
+        // What happens when we trigger the "Generate Checksum Manifest" action.
+        let packfile_generate_checksum_manifest = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+
+                // The manifest is generated from the PackFile on disk, so unsaved changes will not be in it.
+                if UI_STATE.get_is_modified() {
+                    return show_dialog(app_ui.main_window, tr("checksum_manifest_unsaved_changes"), false);
+                }
+
+                app_ui.main_window.set_enabled(false);
+                CENTRAL_COMMAND.send_message_qt(Command::GenerateChecksumManifest);
+                let response = CENTRAL_COMMAND.recv_message_qt_try();
+                match response {
+                    Response::PathBuf(path) => show_dialog(app_ui.main_window, tre("generate_checksum_manifest_success", &[&path.to_string_lossy()]), true),
+                    Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                app_ui.main_window.set_enabled(true);
+            })
+        );
+
+        // What happens when we trigger the "Verify Checksum Manifest" action.
+        let packfile_verify_checksum_manifest = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+
+                // As with the generation, we verify the PackFile on disk, not the one in memory.
+                if UI_STATE.get_is_modified() {
+                    return show_dialog(app_ui.main_window, tr("checksum_manifest_unsaved_changes"), false);
+                }
+
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window,
+                    &qtr("verify_checksum_manifest"),
+                );
+                setup_file_dialog(&mut file_dialog, None);
+                file_dialog.set_name_filter(&QString::from_std_str("Checksum Manifests (*.sha256.json)"));
+                file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                // By default, we point the dialog to the manifest next to the PackFile.
+                CENTRAL_COMMAND.send_message_qt(Command::GetPackFilePath);
+                let response = CENTRAL_COMMAND.recv_message_qt();
+                match response {
+                    Response::PathBuf(pack_file_path) => {
+                        if let Some(folder) = pack_file_path.parent() {
+                            file_dialog.set_directory_q_string(&QString::from_std_str(folder.to_string_lossy()));
+                        }
+
+                        let manifest_path = ChecksumManifest::get_manifest_path(&pack_file_path);
+                        if manifest_path.is_file() {
+                            file_dialog.select_file(&QString::from_std_str(manifest_path.to_string_lossy()));
+                        }
+                    }
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    app_ui.main_window.set_enabled(false);
+                    CENTRAL_COMMAND.send_message_qt(Command::VerifyChecksumManifest(path));
+                    let response = CENTRAL_COMMAND.recv_message_qt_try();
+                    match response {
+                        Response::ChecksumReport(report) => {
+                            if report.is_valid() {
+                                show_dialog(app_ui.main_window, tr("verify_checksum_manifest_valid"), true);
+                            }
+                            else {
+                                let mut problems = String::new();
+                                if !report.total_hash_matches {
+                                    problems.push_str(&format!("<li>{}</li>", tr("verify_checksum_manifest_total_hash")));
+                                }
+
+                                let lists = [
+                                    (&report.missing_paths, "verify_checksum_manifest_missing"),
+                                    (&report.extra_paths, "verify_checksum_manifest_extra"),
+                                    (&report.mismatched_paths, "verify_checksum_manifest_mismatched"),
+                                ];
+                                for (paths, key) in &lists {
+                                    for path in paths.iter() {
+                                        problems.push_str(&format!("<li>{}: <i>{}</i></li>", tr(key), path));
+                                    }
+                                }
+
+                                show_dialog(app_ui.main_window, tre("verify_checksum_manifest_invalid", &[&problems]), false);
+                            }
+                        }
+                        Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+
+                    app_ui.main_window.set_enabled(true);
+                }
+            })
+        );
+
+        // What happens when we trigger the "Export Contents Manifest" action.
+        let packfile_export_contents_manifest = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window,
+                    &qtr("export_contents_manifest"),
+                );
+                setup_file_dialog(&mut file_dialog, None);
+
+                file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+                file_dialog.set_confirm_overwrite(true);
+                file_dialog.set_name_filter(&QString::from_std_str("JSON Files (*.json);;Text Files (*.txt)"));
+                file_dialog.set_default_suffix(&QString::from_std_str("json"));
+
+                if file_dialog.exec() == 1 {
+                    let mut path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    let as_json = file_dialog.selected_name_filter().to_std_string().starts_with("JSON");
+                    if as_json && path.extension().map_or(true, |x| x != "json") {
+                        path.set_extension("json");
+                    }
+
+                    app_ui.main_window.set_enabled(false);
+                    CENTRAL_COMMAND.send_message_qt(Command::ExportContentsManifest(path.clone()));
+                    let response = CENTRAL_COMMAND.recv_message_qt_try();
+                    match response {
+                        Response::Success => show_dialog(app_ui.main_window, tre("export_contents_manifest_success", &[&path.to_string_lossy()]), true),
+                        Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+
+                    app_ui.main_window.set_enabled(true);
+                }
+            })
+        );
+
+        // What happens when we trigger the "Split Loc by Language" action.
+        let packfile_split_loc_by_language = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window,
+                    &qtr("split_loc_by_language"),
+                );
+                setup_file_dialog(&mut file_dialog, None);
+
+                // Set it to only search Folders.
+                file_dialog.set_file_mode(FileMode::Directory);
+                file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    app_ui.main_window.set_enabled(false);
+                    CENTRAL_COMMAND.send_message_qt(Command::SplitLocByLanguage(path));
+                    let response = CENTRAL_COMMAND.recv_message_qt_try();
+                    match response {
+                        Response::VecPathBuf(paths) => {
+                            let paths = paths.iter().map(|x| format!("<li>{}</li>", x.to_string_lossy())).collect::<String>();
+                            show_dialog(app_ui.main_window, tre("split_loc_by_language_success", &[&paths]), true);
+                        }
+                        Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+
+                    app_ui.main_window.set_enabled(true);
+                }
+            })
+        );
+
+        // What happens when we trigger the "Normalize Timestamps" action.
+        let packfile_normalize_timestamps = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                CENTRAL_COMMAND.send_message_qt(Command::NormalizeTimestamps);
+                let response = CENTRAL_COMMAND.recv_message_qt();
+                match response {
+                    Response::U32(changed) => {
+                        if changed > 0 {
+                            UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+                        }
+                        show_dialog(app_ui.main_window, tre("normalize_timestamps_success", &[&changed.to_string()]), true);
+                    }
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            })
+        );
+
+        // What happens when we trigger the "Find Orphaned Assets" action.
+        let packfile_find_orphaned_assets = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                let deleted_paths = OrphanedAssetsUI::new(app_ui.main_window);
+                if deleted_paths.is_empty() { return; }
+
+                let paths_to_delete = deleted_paths.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Delete(paths_to_delete.to_vec()));
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths_to_delete));
+                UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+
+                // Close the views of the deleted PackedFiles, if they're open.
+                let mut problems = Problems::default();
+                for path in &deleted_paths {
+                    if let Err(error) = app_ui.purge_that_one_specifically(global_search_ui, pack_file_contents_ui, path, false) {
+                        problems.push(&path.join("/"), error);
+                    }
+                }
+                problems.report(app_ui.main_window);
+            })
         );
 
         // What happens when we trigger the "Preferences" action.
@@ -453,6 +884,18 @@ impl AppUISlots {
                                 app_temp_slots.borrow_mut().mymod_open = app_ui.build_open_mymod_submenus(pack_file_contents_ui, global_search_ui, &slot_holder);
                             }
 
+                            // If we toggled Git for MyMods, update the commit action and the status shown in the TreeView.
+                            else if settings.settings_bool["use_git_on_mymods"] != old_settings.settings_bool["use_git_on_mymods"] {
+                                let use_git = settings.settings_bool["use_git_on_mymods"];
+                                let is_mymod = UI_STATE.get_operational_mode().get_mymod_private_path().is_some();
+                                app_ui.mymod_git_commit.set_enabled(use_git && is_mymod);
+                                if use_git {
+                                    app_ui.update_mymod_git_status(&mut pack_file_contents_ui);
+                                } else {
+                                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateGitStatus(vec![]));
+                                }
+                            }
+
                             // If we have changed the path of any of the games, and that game is the current `GameSelected`,
                             // re-select the current `GameSelected` to force it to reload the game's files.
                             let has_game_selected_path_changed = settings.paths.iter()
@@ -462,6 +905,26 @@ impl AppUISlots {
                             if has_game_selected_path_changed {
                                 QAction::trigger(&mut app_ui.game_selected_group.checked_action());
                             }
+
+                            if settings.settings_string["autosave_interval"] != old_settings.settings_string["autosave_interval"] {
+                                app_ui.update_autosave_timer();
+                            }
+
+                            if settings.settings_string[SCHEMA_UPDATE_INTERVAL] != old_settings.settings_string[SCHEMA_UPDATE_INTERVAL] {
+                                app_ui.update_schema_update_timer();
+                            }
+
+                            // If we toggled the dependency checker, recheck or clean the open tables so they reflect it right away.
+                            if settings.settings_bool["use_dependency_checker"] != old_settings.settings_bool["use_dependency_checker"] {
+                                for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+                                    if let ViewType::Internal(View::Table(table)) = packed_file_view.get_view() {
+                                        if let Err(error) = table.get_ref_table().refresh_dependency_data() {
+                                            show_dialog(app_ui.main_window, error, false);
+                                            break;
+                                        }
+                                    }
+                                }
+                            }
                         }
 
                         // If we got an error, report it.
@@ -483,6 +946,24 @@ impl AppUISlots {
             }
         ));
 
+        // What happens when the program is about to close. If we get here, it means it didn't crash.
+        let about_to_quit = Slot::new(clone!(
+            app_ui => move || {
+                app_ui.save_session();
+
+                // Remember the state of the columns of the open tables, as they're not closed through the usual paths on exit.
+                for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+                    if let ViewType::Internal(View::Table(view)) = packed_file_view.get_view() {
+                        view.get_ref_table().save_table_state();
+                    }
+                }
+
+                if let Ok(path) = get_config_path() {
+                    let _ = remove_file(path.join(RUNNING_MARKER_FILE));
+                }
+            }
+        ));
+
         //-----------------------------------------------//
         // `MyMod` menu logic.
         //-----------------------------------------------//
@@ -495,7 +976,7 @@ impl AppUISlots {
             mut slot_holder => move |_| {
 
                 // Trigger the `New MyMod` Dialog, and get the result.
-                if let Some((mod_name, mod_game)) = MyModUI::new(&mut app_ui) {
+                if let Some((mod_name, mod_game, template)) = MyModUI::new(&mut app_ui) {
                     let full_mod_name = format!("{}.pack", mod_name);
 
                     // Change the Game Selected to match the one we chose for the new "MyMod".
@@ -543,6 +1024,26 @@ impl AppUISlots {
                     global_search_ui.clear();
 
                     CENTRAL_COMMAND.send_message_qt(Command::NewPackFile);
+
+                    // If we chose a template, apply it before saving the new PackFile, so the PackFile is saved with its files.
+                    if let Some(template) = template {
+                        let template = match MyModTemplate::load(&template) {
+                            Ok(template) => template,
+                            Err(error) => {
+                                app_ui.main_window.set_enabled(true);
+                                return show_dialog(app_ui.main_window, error, false);
+                            }
+                        };
+
+                        CENTRAL_COMMAND.send_message_qt(Command::ApplyMyModTemplate(template, mod_name.to_owned(), mymod_path_private.to_path_buf()));
+                        let response = CENTRAL_COMMAND.recv_message_qt();
+                        match response {
+                            Response::Success => {},
+                            Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                        }
+                    }
+
                     CENTRAL_COMMAND.send_message_qt(Command::SavePackFileAs(mymod_path.to_path_buf()));
                     let response = CENTRAL_COMMAND.recv_message_qt_try();
                     match response {
@@ -571,6 +1072,17 @@ impl AppUISlots {
                             // Show the "Tips".
                             //display_help_tips(&app_ui);
 
+                            // If we use Git for MyMods, the new MyMod gets its repository from the start.
+                            if SETTINGS.read().unwrap().settings_bool["use_git_on_mymods"] {
+                                CENTRAL_COMMAND.send_message_qt(Command::InitMyModGitRepo(mymod_path_private.to_path_buf()));
+                                let response = CENTRAL_COMMAND.recv_message_qt();
+                                match response {
+                                    Response::Success => app_ui.update_mymod_git_status(&mut pack_file_contents_ui),
+                                    Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                                }
+                            }
+
                             app_temp_slots.borrow_mut().mymod_open = app_ui.build_open_mymod_submenus(pack_file_contents_ui, global_search_ui, &slot_holder);
                             app_ui.main_window.set_enabled(true);
                         }
@@ -622,6 +1134,12 @@ impl AppUISlots {
                                     return show_dialog(app_ui.main_window, ErrorKind::IOGenericDelete(vec![mymod_path; 1]), false);
                                 }
 
+                                // If it has a config, we try to delete it too. This is optional, so it should not stop the deleting process.
+                                let mymod_config_path = MyModConfig::get_config_path(&mymod_path);
+                                if mymod_config_path.is_file() && remove_file(&mymod_config_path).is_err() {
+                                    show_dialog(app_ui.main_window, ErrorKind::IOGenericDelete(vec![mymod_config_path; 1]), false);
+                                }
+
                                 // Now we get his assets folder.
                                 let mut mymod_assets_path = mymod_path.to_path_buf();
                                 mymod_assets_path.pop();
@@ -634,7 +1152,8 @@ impl AppUISlots {
                                 }
 
                                 // If the assets folder exists, we try to delete it. Again, this is optional, so it should not stop the deleting process.
-                                else if remove_dir_all(&mymod_assets_path).is_err() {
+                                // If it is a symlink or a junction, only the link is deleted, not the folder it points to.
+                                else if remove_folder(&mymod_assets_path).is_err() {
                                     show_dialog(app_ui.main_window, ErrorKind::IOGenericDelete(vec![mymod_assets_path; 1]), false);
                                 }
 
@@ -664,7 +1183,7 @@ impl AppUISlots {
         ));
 
         // This slot is used for the "Install MyMod" action.
-        let mymod_install = SlotOfBool::new(move |_| {
+        let mymod_install = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Depending on our current "Mode", we choose what to do.
                 match UI_STATE.get_operational_mode() {
@@ -705,11 +1224,11 @@ impl AppUISlots {
                     OperationalMode::Normal => show_dialog(app_ui.main_window, ErrorKind::MyModDeleteWithoutMyModSelected, false),
                 }
 
-            }
+            })
         );
 
         // This slot is used for the "Uninstall MyMod" action.
-        let mymod_uninstall = SlotOfBool::new(move |_| {
+        let mymod_uninstall = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Depending on our current "Mode", we choose what to do.
                 match UI_STATE.get_operational_mode() {
@@ -734,69 +1253,178 @@ impl AppUISlots {
                    // If we have no "MyMod" selected, return an error.
                     OperationalMode::Normal => show_dialog(app_ui.main_window, ErrorKind::MyModDeleteWithoutMyModSelected, false),
                 }
-            }
+            })
         );
 
+        // This slot is used for the "Upload MyMod to Workshop" action.
+        let mymod_upload_to_workshop = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+
+                // Depending on our current "Mode", we choose what to do.
+                match UI_STATE.get_operational_mode() {
+
+                    // If we have a "MyMod" selected, and everything we need it's configured, upload its PackFile to the Workshop.
+                    OperationalMode::MyMod(ref game_folder_name, ref mod_name) => {
+                        let mymods_base_path = SETTINGS.read().unwrap().paths["mymods_base_path"].clone();
+                        if let Some(mymods_base_path) = mymods_base_path {
+
+                            // We get the "MyMod"s PackFile path.
+                            let mut mymod_path = mymods_base_path.to_path_buf();
+                            mymod_path.push(&game_folder_name);
+                            mymod_path.push(&mod_name);
+
+                            if !mymod_path.is_file() {
+                                return show_dialog(app_ui.main_window, ErrorKind::MyModPackFileDoesntExist, false);
+                            }
+
+                            // The Workshop gets the PackFile from disk, so unsaved changes will not be uploaded.
+                            if UI_STATE.get_is_modified() {
+                                return show_dialog(app_ui.main_window, tr("workshop_upload_unsaved_changes"), false);
+                            }
+
+                            let config = match MyModConfig::read(&mymod_path) {
+                                Ok(config) => config,
+                                Err(error) => return show_dialog(app_ui.main_window, error, false),
+                            };
+
+                            if let Some((config, changelog, steam_user)) = app_ui.workshop_upload_dialog(&config) {
+                                save_setting_string(WORKSHOP_STEAM_USER, &steam_user);
+
+                                app_ui.main_window.set_enabled(false);
+                                CENTRAL_COMMAND.send_message_qt_to_network(Command::UploadMyModToWorkshop(mymod_path, config, changelog));
+                                let response = CENTRAL_COMMAND.recv_message_network_to_qt_try();
+                                app_ui.main_window.set_enabled(true);
+                                match response {
+                                    Response::MyModConfig(config) => show_dialog(app_ui.main_window, tre("workshop_upload_success", &[&config.published_file_id.unwrap_or(0).to_string()]), true),
+                                    Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                                }
+                            }
+                        }
+                        else { show_dialog(app_ui.main_window, ErrorKind::MyModPathNotConfigured, false); }
+                    }
+
+                    // If we have no "MyMod" selected, return an error.
+                    OperationalMode::Normal => show_dialog(app_ui.main_window, ErrorKind::MyModUploadWithoutMyModSelected, false),
+                }
+            })
+        );
+
+        // This slot is used for the "Commit MyMod" action.
+        let mymod_git_commit = SlotOfBool::new(clone!(
+            mut global_search_ui,
+            mut pack_file_contents_ui => move |_| {
+                match UI_STATE.get_operational_mode().get_mymod_private_path() {
+                    Some(private_path) => {
+
+                        // We commit what's in the backend, so make sure the open PackedFiles are there first.
+                        if let Err(error) = app_ui.back_to_back_end_all(global_search_ui, pack_file_contents_ui) {
+                            return show_dialog(app_ui.main_window, error, false);
+                        }
+
+                        if let Some(message) = app_ui.mymod_git_commit_dialog() {
+                            app_ui.main_window.set_enabled(false);
+                            CENTRAL_COMMAND.send_message_qt(Command::CommitMyModGit(private_path, message));
+                            let response = CENTRAL_COMMAND.recv_message_qt_try();
+                            app_ui.main_window.set_enabled(true);
+                            match response {
+                                Response::Success => {
+                                    app_ui.update_mymod_git_status(&mut pack_file_contents_ui);
+                                    show_dialog(app_ui.main_window, tr("mymod_git_commit_success"), true);
+                                }
+                                Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                            }
+                        }
+                    }
+
+                    // If we have no "MyMod" selected, or we don't have the MyMod path configured, return an error.
+                    None => show_dialog(app_ui.main_window, ErrorKind::MyModPathNotConfigured, false),
+                }
+            }
+        ));
+
         let mymod_open = vec![];
 
         //-----------------------------------------------//
         // `View` menu logic.
         //-----------------------------------------------//
-        let view_toggle_packfile_contents = SlotOfBool::new(move |_| {
+        let view_toggle_packfile_contents = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             let is_visible = pack_file_contents_ui.packfile_contents_dock_widget.is_visible();
             if is_visible { pack_file_contents_ui.packfile_contents_dock_widget.hide(); }
             else { pack_file_contents_ui.packfile_contents_dock_widget.show();}
-        });
+        }));
 
-        let view_toggle_global_search_panel = SlotOfBool::new(move |_| {
+        let view_toggle_global_search_panel = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             let is_visible = global_search_ui.global_search_dock_widget.is_visible();
             if is_visible { global_search_ui.global_search_dock_widget.hide(); }
             else { global_search_ui.global_search_dock_widget.show(); }
-        });
+        }));
 
         //-----------------------------------------------//
         // `Game Selected` menu logic.
         //-----------------------------------------------//
 
         // What happens when we trigger the "Launch Game" action.
-        let game_selected_launch_game = SlotOfBool::new(move |_| {
+        let game_selected_launch_game = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             if let Some(steam_id) = SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap().steam_id {
                 if open::that(format!("steam://rungameid/{}", steam_id)).is_err() {
                     show_dialog(app_ui.main_window, ErrorKind::IOFolderCannotBeOpened, false);
                 };
             }
             else { show_dialog(app_ui.main_window, ErrorKind::LaunchNotSupportedForThisGame, false); }
-        });
+        }));
+
+        // What happens when we trigger the "Launch Game Selected with this PackFile" action.
+        let game_selected_launch_game_with_packfile = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+
+            // The game loads the PackFile from disk, so unsaved changes will not be in the game.
+            if UI_STATE.get_is_modified() {
+                return show_dialog(app_ui.main_window, tr("launch_game_unsaved_changes"), false);
+            }
+
+            CENTRAL_COMMAND.send_message_qt(Command::LaunchGameWithPackFile);
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::Success => {},
+                Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }));
 
         // What happens when we trigger the "Open Game's Data Folder" action.
-        let game_selected_open_game_data_folder = SlotOfBool::new(move |_| {
+        let game_selected_open_game_data_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             if let Some(path) = get_game_selected_data_path() {
                 if open::that(&path).is_err() {
                     show_dialog(app_ui.main_window, ErrorKind::IOFolderCannotBeOpened, false);
                 };
             }
             else { show_dialog(app_ui.main_window, ErrorKind::GamePathNotConfigured, false); }
-        });
+        }));
 
         // What happens when we trigger the "Open Game's Assembly Kit Folder" action.
-        let game_selected_open_game_assembly_kit_folder = SlotOfBool::new(move |_| {
+        let game_selected_open_game_assembly_kit_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             if let Some(path) = get_game_selected_assembly_kit_path() {
                 if open::that(&path).is_err() {
                     show_dialog(app_ui.main_window, ErrorKind::IOFolderCannotBeOpened, false);
                 };
             }
             else { show_dialog(app_ui.main_window, ErrorKind::GamePathNotConfigured, false); }
-        });
+        }));
 
         // What happens when we trigger the "Open Config Folder" action.
-        let game_selected_open_config_folder = SlotOfBool::new(move |_| {
+        let game_selected_open_config_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             if let Ok(path) = get_config_path() {
                 if open::that(&path).is_err() {
                     show_dialog(app_ui.main_window, ErrorKind::IOFolderCannotBeOpened, false);
                 };
             }
             else { show_dialog(app_ui.main_window, ErrorKind::ConfigFolderCouldNotBeOpened, false); }
-        });
+        }));
+
+        // What happens when we trigger the "Search Vanilla Files" action.
+        let game_selected_search_vanilla_files = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            VanillaSearchUI::new(app_ui.main_window);
+        }));
 
         // What happens when we trigger the "Change Game Selected" action.
         let change_game_selected = SlotOfBool::new(clone!(
@@ -881,7 +1509,7 @@ impl AppUISlots {
         ));
 
         // What happens when we trigger the "Generate Pak File" action.
-        let special_stuff_generate_pak_file = SlotOfBool::new(move |_| {
+        let special_stuff_generate_pak_file = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // For Rome 2+, we need the game path set. For other games, we have to ask for a path.
                 let version = SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap().raw_db_version;
@@ -904,10 +1532,11 @@ impl AppUISlots {
                             app_ui.main_window,
                             &qtr("special_stuff_select_ak_folder"),
                         );
+                        setup_file_dialog(&mut file_dialog, None);
 
                         // Set it to only search Folders.
                         file_dialog.set_file_mode(FileMode::Directory);
-                        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+                        file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
 
                         // Run it and expect a response (1 => Accept, 0 => Cancel).
                         let mut path = if file_dialog.exec() == 1 { PathBuf::from(file_dialog.selected_files().at(0).to_std_string())
@@ -925,10 +1554,11 @@ impl AppUISlots {
                             app_ui.main_window,
                             &qtr("special_stuff_select_raw_db_folder"),
                         );
+                        setup_file_dialog(&mut file_dialog, None);
 
                         // Set it to only search Folders.
                         file_dialog.set_file_mode(FileMode::Directory);
-                        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+                        file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
 
                         // Run it and expect a response (1 => Accept, 0 => Cancel).
                         if file_dialog.exec() == 1 { PathBuf::from(file_dialog.selected_files().at(0).to_std_string())
@@ -957,7 +1587,7 @@ impl AppUISlots {
                 else {
                     show_dialog(app_ui.main_window, tr("game_selected_unsupported_operation"), false);
                 }
-            }
+            })
         );
 
         // What happens when we trigger the "Optimize PackFile" action.
@@ -977,11 +1607,20 @@ impl AppUISlots {
                 CENTRAL_COMMAND.send_message_qt(Command::OptimizePackFile);
                 let response = CENTRAL_COMMAND.recv_message_qt_try();
                 match response {
-                    Response::VecVecString(response) => {
-                        let response = response.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                    Response::OptimizerReport(report) => {
+                        let deleted_paths = report.deleted_paths.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                        if !deleted_paths.is_empty() {
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Delete(deleted_paths));
+                        }
 
-                        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Delete(response));
-                        show_dialog(app_ui.main_window, tr("optimize_packfile_success"), true);
+                        let message = tre("optimize_packfile_success", &[
+                            &report.rows_removed.to_string(),
+                            &report.loc_keys_removed.to_string(),
+                            &report.deleted_paths.len().to_string(),
+                            &report.tables_sorted.to_string(),
+                            &report.bytes_saved.to_string(),
+                        ]);
+                        show_dialog(app_ui.main_window, message, true);
                     }
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                 }
@@ -1025,15 +1664,39 @@ impl AppUISlots {
             }
         ));
 
+        //-----------------------------------------------//
+        // `Scripts` menu logic.
+        //-----------------------------------------------//
+
+        // What happens when we trigger the "Reload Scripts" action.
+        let scripts_reload = SlotOfBool::new(clone!(
+            slot_holder,
+            app_temp_slots => move |_| {
+                app_temp_slots.borrow_mut().scripts = app_ui.build_scripts_menu(pack_file_contents_ui, global_search_ui, &slot_holder);
+                log_to_status_bar(&tr("scripts_reload_success"));
+            }
+        ));
+
+        // What happens when we trigger the "Open Scripts Folder" action.
+        let scripts_open_scripts_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            if let Ok(path) = get_config_path() {
+                let path = path.join(USER_SCRIPTS_FOLDER);
+                if DirBuilder::new().recursive(true).create(&path).is_err() || open::that(&path).is_err() {
+                    show_dialog(app_ui.main_window, ErrorKind::IOFolderCannotBeOpened, false);
+                };
+            }
+            else { show_dialog(app_ui.main_window, ErrorKind::ConfigFolderCouldNotBeOpened, false); }
+        }));
+
 		//-----------------------------------------------//
         // `About` menu logic.
         //-----------------------------------------------//
 
         // What happens when we trigger the "About Qt" action.
-        let about_about_qt = SlotOfBool::new(move |_| { QMessageBox::about_qt_1a(app_ui.main_window); });
+        let about_about_qt = SlotOfBool::new(move |_| catch_panic_in_slot(|| { QMessageBox::about_qt_1a(app_ui.main_window); }));
 
         // What happens when we trigger the "About RPFM" action.
-        let about_about_rpfm = SlotOfBool::new(move |_| {
+        let about_about_rpfm = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             QMessageBox::about(
                 app_ui.main_window,
                 &qtr("about_about_rpfm"),
@@ -1075,23 +1738,34 @@ impl AppUISlots {
                     </ul>
                     ", &VERSION))
                 );
-            }
+            })
         );
 
         // What happens when we trigger the "Open Manual" action.
         let about_open_manual = SlotOfBool::new(|_| { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(DOCS_BASE_URL))); });
 
+        // What happens when we trigger the "Start Tour" action.
+        let about_start_tour = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            TourUI::new(&app_ui, &pack_file_contents_ui, &global_search_ui);
+        }));
+
         // What happens when we trigger the "Support me on Patreon" action.
         let about_patreon_link = SlotOfBool::new(|_| { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(PATREON_URL))); });
 
         // What happens when we trigger the "Check Update" action.
-        let about_check_updates = SlotOfBool::new(move |_| { app_ui.check_updates(true); });
+        let about_check_updates = SlotOfBool::new(move |_| catch_panic_in_slot(|| { app_ui.check_updates(true); }));
 
         // What happens when we trigger the "Check Schema Update" action.
-        let about_check_schema_updates = SlotOfBool::new(move |_| { app_ui.check_schema_updates(true); });
+        let about_check_schema_updates = SlotOfBool::new(move |_| catch_panic_in_slot(|| { app_ui.check_schema_updates(true); }));
+
+        // What happens when we trigger the "Export Schemas" action.
+        let about_export_schema_bundle = SlotOfBool::new(move |_| catch_panic_in_slot(|| { app_ui.export_schema_bundle(); }));
+
+        // What happens when we trigger the "Import Schemas" action.
+        let about_import_schema_bundle = SlotOfBool::new(move |_| catch_panic_in_slot(|| { app_ui.import_schema_bundle(); }));
 
         // What happens when we trigger the "Update Templates" action.
-        let about_update_templates = SlotOfBool::new(move |_| {
+        let about_update_templates = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
                 app_ui.main_window.set_enabled(false);
 
                 CENTRAL_COMMAND.send_message_qt(Command::UpdateTemplates);
@@ -1104,11 +1778,11 @@ impl AppUISlots {
 
                 // Re-enable the Main Window.
                 app_ui.main_window.set_enabled(true);
-            }
+            })
         );
 
         // What happens when we trigger the "Update from AssKit" action.
-        let debug_update_current_schema_from_asskit = SlotOfBool::new(move |_| {
+        let debug_update_current_schema_from_asskit = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // For Rome 2+, we need the game path set. For other games, we have to ask for a path.
                 let version = SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap().raw_db_version;
@@ -1120,10 +1794,11 @@ impl AppUISlots {
                             app_ui.main_window,
                             &qtr("special_stuff_select_raw_db_folder"),
                         );
+                        setup_file_dialog(&mut file_dialog, None);
 
                         // Set it to only search Folders.
                         file_dialog.set_file_mode(FileMode::Directory);
-                        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+                        file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
 
                         // Run it and expect a response (1 => Accept, 0 => Cancel).
                         if file_dialog.exec() == 1 { Some(PathBuf::from(file_dialog.selected_files().at(0).to_std_string()))
@@ -1144,13 +1819,19 @@ impl AppUISlots {
                 }
 
                 app_ui.main_window.set_enabled(true);
-            }
+            })
+        );
+
+        // What happens when we trigger the "Diagnostics" action.
+        let debug_diagnostics = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                DiagnosticsUI::new(app_ui.main_window);
+            })
         );
 
         //-----------------------------------------------//
         // `PackedFileView` logic.
         //-----------------------------------------------//
-        let packed_file_hide = SlotOfInt::new(move |index| {
+        let packed_file_hide = SlotOfInt::new(move |index| catch_panic_in_slot(|| {
 
             // PackFile Views must be deleted on close.
             let mut purge_on_delete = vec![];
@@ -1162,7 +1843,11 @@ impl AppUISlots {
                     tab_index = index;
                     if !path.is_empty() && path.starts_with(&[RESERVED_NAME_EXTRA_PACKFILE.to_owned()]) {
                         purge_on_delete = path.to_vec();
-                        CENTRAL_COMMAND.send_message_qt(Command::RemovePackFileExtra(PathBuf::from(&path[1])));
+
+                        // Read-only tables of the extra PackFile are purged too, but they don't close the PackFile.
+                        if path.len() == 2 {
+                            CENTRAL_COMMAND.send_message_qt(Command::RemovePackFileExtra(PathBuf::from(&path[1])));
+                        }
                     }
                     break;
                 }
@@ -1179,9 +1864,42 @@ impl AppUISlots {
 
             // Update the background icon.
             GameSelectedIcons::set_game_selected_icon(&mut app_ui);
-        });
+        }));
+
+        //-----------------------------------------------//
+        // Save queue logic.
+        //-----------------------------------------------//
+
+        // What happens when we check if the game we're waiting for to save has been closed.
+        let save_queue_timer_timeout = Slot::new(move || catch_panic_in_slot(|| {
+            CENTRAL_COMMAND.send_message_qt(Command::IsGameRunningWithPackFile);
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::Bool(is_running) => if !is_running {
+                    app_ui.save_queue_timer.stop();
+
+                    // If it has already been saved in the meantime, there is nothing to do.
+                    if UI_STATE.get_is_modified() {
+                        if let Err(error) = app_ui.save_packfile(&mut pack_file_contents_ui, &global_search_ui, false) {
+                            show_dialog(app_ui.main_window, error, false);
+                        }
+                    }
+                }
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }));
+
+        // What happens when it's time to autosave the open PackFile.
+        let autosave_timer_timeout = Slot::new(move || catch_panic_in_slot(|| {
+            app_ui.autosave_packfile(global_search_ui, pack_file_contents_ui);
+        }));
+
+        // What happens when it's time to check if we have to update the schemas.
+        let schema_update_timer_timeout = Slot::new(move || catch_panic_in_slot(|| {
+            app_ui.auto_update_schemas();
+        }));
 
-        let packed_file_update = SlotOfInt::new(move |index| {
+        let packed_file_update = SlotOfInt::new(move |index| catch_panic_in_slot(|| {
             if index == -1 { return; }
 
             for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
@@ -1189,27 +1907,12 @@ impl AppUISlots {
                 if app_ui.tab_bar_packed_file.index_of(widget) == index {
                     if let ViewType::Internal(view) = packed_file_view.get_view() {
 
-                        // For tables, we have to update the dependency data, reset the dropdown's data, and recheck the entire table for errors.
+                        // For tables, if the PackFile changed since we got their dependency data, we have to update it,
+                        // reset the dropdown's data, and recheck the entire table for errors.
                         if let View::Table(table) = view {
                             let table = table.get_ref_table();
-                            if let Ok(data) = get_reference_data(&table.get_ref_table_definition()) {
-                                table.set_dependency_data(&data);
-
-                                setup_item_delegates(
-                                    table.get_mut_ptr_table_view_primary(),
-                                    table.get_mut_ptr_table_view_frozen(),
-                                    &table.get_ref_table_definition(),
-                                    &data
-                                );
-
-                                if SETTINGS.read().unwrap().settings_bool["use_dependency_checker"] {
-                                    check_table_for_errors(
-                                        table.get_mut_ptr_table_model(),
-                                        &table.get_ref_table_definition(),
-                                        &data,
-                                        table.get_packed_file_type()
-                                    );
-                                }
+                            if table.get_dependency_data_is_stale() {
+                                let _ = table.refresh_dependency_data();
                             }
                         }
                     }
@@ -1222,9 +1925,9 @@ impl AppUISlots {
 
             // Update the background icon.
             GameSelectedIcons::set_game_selected_icon(&mut app_ui);
-        });
+        }));
 
-        let packed_file_unpreview = SlotOfInt::new(move |index| {
+        let packed_file_unpreview = SlotOfInt::new(move |index| catch_panic_in_slot(|| {
             if index == -1 { return; }
 
             for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
@@ -1239,7 +1942,7 @@ impl AppUISlots {
                     break;
                 }
             }
-        });
+        }));
 
         // And here... we return all the slots.
 		Self {
@@ -1250,21 +1953,38 @@ impl AppUISlots {
 			command_palette_show,
     		command_palette_hide,
     		command_palette_trigger,
+    		command_palette_filter,
 
             //-----------------------------------------------//
             // `PackFile` menu slots.
             //-----------------------------------------------//
             packfile_new_packfile,
+            packfile_new_packfile_from_folder,
             packfile_open_packfile,
             packfile_save_packfile,
             packfile_save_packfile_as,
+            packfile_browse_workshop,
             packfile_open_from,
             packfile_load_all_ca_packfiles,
+            packfile_compare_packfiles,
+            packfile_merge_tables_from_packfiles,
+            packfile_run_job_file,
+            packfile_trash,
+            packfile_create_snapshot,
+            packfile_snapshots,
+            packfile_diagnostics_ignore_list,
+            packfile_generate_checksum_manifest,
+            packfile_verify_checksum_manifest,
+            packfile_export_contents_manifest,
+            packfile_split_loc_by_language,
+            packfile_normalize_timestamps,
+            packfile_find_orphaned_assets,
             packfile_change_packfile_type,
             packfile_index_includes_timestamp,
             packfile_data_is_compressed,
             packfile_preferences,
             packfile_quit,
+            about_to_quit,
 
             //-----------------------------------------------//
             // `MyMod` menu slots.
@@ -1273,6 +1993,8 @@ impl AppUISlots {
             mymod_delete_selected,
             mymod_install,
             mymod_uninstall,
+            mymod_upload_to_workshop,
+            mymod_git_commit,
             mymod_open,
 
             //-----------------------------------------------//
@@ -1285,9 +2007,11 @@ impl AppUISlots {
             // `Game Selected` menu slots.
             //-----------------------------------------------//
             game_selected_launch_game,
+            game_selected_launch_game_with_packfile,
             game_selected_open_game_data_folder,
             game_selected_open_game_assembly_kit_folder,
             game_selected_open_config_folder,
+            game_selected_search_vanilla_files,
             change_game_selected,
 
             //-----------------------------------------------//
@@ -1298,28 +2022,45 @@ impl AppUISlots {
             special_stuff_optimize_packfile,
             special_stuff_patch_siege_ai,
 
+            //-----------------------------------------------//
+            // `Scripts` menu slots.
+            //-----------------------------------------------//
+            scripts_reload,
+            scripts_open_scripts_folder,
+
     		//-----------------------------------------------//
 	        // `About` menu slots.
 	        //-----------------------------------------------//
     		about_about_qt,
             about_about_rpfm,
             about_open_manual,
+            about_start_tour,
             about_patreon_link,
             about_check_updates,
             about_check_schema_updates,
+            about_export_schema_bundle,
+            about_import_schema_bundle,
             about_update_templates,
 
             //-----------------------------------------------//
             // `Debug` menu slots.
             //-----------------------------------------------//
             debug_update_current_schema_from_asskit,
+            debug_diagnostics,
 
             //-----------------------------------------------//
             // `PackedFileView` slots.
             //-----------------------------------------------//
             packed_file_hide,
             packed_file_update,
-            packed_file_unpreview
+            packed_file_unpreview,
+
+            //-----------------------------------------------//
+            // Save queue slots.
+            //-----------------------------------------------//
+            save_queue_timer_timeout,
+            autosave_timer_timeout,
+            schema_update_timer_timeout,
 		}
 	}
 }
@@ -1329,6 +2070,7 @@ impl AppUITempSlots {
         Self {
             packfile_open_from: app_ui.build_open_from_submenus(pack_file_contents_ui, global_search_ui, slot_holder),
             mymod_open: app_ui.build_open_mymod_submenus(pack_file_contents_ui, global_search_ui, slot_holder),
+            scripts: app_ui.build_scripts_menu(pack_file_contents_ui, global_search_ui, slot_holder),
         }
     }
 }
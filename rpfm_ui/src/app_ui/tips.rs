@@ -22,10 +22,25 @@ pub unsafe fn set_tips(app_ui: &mut AppUI) {
     // `PackFile` menu tips.
     //-----------------------------------------------//
     app_ui.packfile_new_packfile.set_status_tip(&qtr("tt_packfile_new_packfile"));
+    app_ui.packfile_new_packfile_from_folder.set_status_tip(&qtr("tt_packfile_new_packfile_from_folder"));
     app_ui.packfile_open_packfile.set_status_tip(&qtr("tt_packfile_open_packfile"));
     app_ui.packfile_save_packfile.set_status_tip(&qtr("tt_packfile_save_packfile"));
     app_ui.packfile_save_packfile_as.set_status_tip(&qtr("tt_packfile_save_packfile_as"));
+    app_ui.packfile_browse_workshop.set_status_tip(&qtr("tt_packfile_browse_workshop"));
     app_ui.packfile_load_all_ca_packfiles.set_status_tip(&qtr("tt_packfile_load_all_ca_packfiles"));
+    app_ui.packfile_compare_packfiles.set_status_tip(&qtr("tt_packfile_compare_packfiles"));
+    app_ui.packfile_merge_tables_from_packfiles.set_status_tip(&qtr("tt_packfile_merge_tables_from_packfiles"));
+    app_ui.packfile_run_job_file.set_status_tip(&qtr("tt_packfile_run_job_file"));
+    app_ui.packfile_trash.set_status_tip(&qtr("tt_packfile_trash"));
+    app_ui.packfile_create_snapshot.set_status_tip(&qtr("tt_packfile_create_snapshot"));
+    app_ui.packfile_snapshots.set_status_tip(&qtr("tt_packfile_snapshots"));
+    app_ui.packfile_diagnostics_ignore_list.set_status_tip(&qtr("tt_packfile_diagnostics_ignore_list"));
+    app_ui.packfile_generate_checksum_manifest.set_status_tip(&qtr("tt_packfile_generate_checksum_manifest"));
+    app_ui.packfile_verify_checksum_manifest.set_status_tip(&qtr("tt_packfile_verify_checksum_manifest"));
+    app_ui.packfile_export_contents_manifest.set_status_tip(&qtr("tt_packfile_export_contents_manifest"));
+    app_ui.packfile_split_loc_by_language.set_status_tip(&qtr("tt_packfile_split_loc_by_language"));
+    app_ui.packfile_normalize_timestamps.set_status_tip(&qtr("tt_packfile_normalize_timestamps"));
+    app_ui.packfile_find_orphaned_assets.set_status_tip(&qtr("tt_packfile_find_orphaned_assets"));
     app_ui.packfile_preferences.set_status_tip(&qtr("tt_packfile_preferences"));
     app_ui.packfile_quit.set_status_tip(&qtr("tt_packfile_quit"));
 
@@ -49,14 +64,19 @@ pub unsafe fn set_tips(app_ui: &mut AppUI) {
     app_ui.mymod_delete_selected.set_status_tip(&qtr("tt_mymod_delete_selected"));
     app_ui.mymod_install.set_status_tip(&qtr("tt_mymod_install"));
     app_ui.mymod_uninstall.set_status_tip(&qtr("tt_mymod_uninstall"));
+    app_ui.mymod_upload_to_workshop.set_status_tip(&qtr("tt_mymod_upload_to_workshop"));
+    app_ui.mymod_git_commit.set_status_tip(&qtr("tt_mymod_git_commit"));
+    app_ui.mymod_live_sync.set_status_tip(&qtr("tt_mymod_live_sync"));
 
     //-----------------------------------------------//
     // `Game Selected` menu tips.
     //-----------------------------------------------//
     app_ui.game_selected_launch_game.set_status_tip(&qtr("tt_game_selected_launch_game"));
+    app_ui.game_selected_launch_game_with_packfile.set_status_tip(&qtr("tt_game_selected_launch_game_with_packfile"));
     app_ui.game_selected_open_game_data_folder.set_status_tip(&qtr("tt_game_selected_open_game_data_folder"));
     app_ui.game_selected_open_game_assembly_kit_folder.set_status_tip(&qtr("tt_game_selected_open_game_assembly_kit_folder"));
     app_ui.game_selected_open_config_folder.set_status_tip(&qtr("tt_game_selected_open_config_folder"));
+    app_ui.game_selected_search_vanilla_files.set_status_tip(&qtr("tt_game_selected_search_vanilla_files"));
 
     app_ui.game_selected_troy.set_status_tip(&qtr("tt_game_selected_troy"));
     app_ui.game_selected_three_kingdoms.set_status_tip(&qtr("tt_game_selected_three_kingdoms"));
@@ -100,14 +120,28 @@ pub unsafe fn set_tips(app_ui: &mut AppUI) {
     app_ui.special_stuff_nap_optimize_packfile.set_status_tip(&optimize_packfile);
     app_ui.special_stuff_emp_optimize_packfile.set_status_tip(&optimize_packfile);
 
+    //-----------------------------------------------//
+    // `Scripts` menu tips.
+    //-----------------------------------------------//
+    app_ui.scripts_reload.set_status_tip(&qtr("tt_scripts_reload"));
+    app_ui.scripts_open_scripts_folder.set_status_tip(&qtr("tt_scripts_open_scripts_folder"));
+
     //-----------------------------------------------//
     // `About` menu tips.
     //-----------------------------------------------//
     app_ui.about_about_qt.set_status_tip(&qtr("tt_about_about_qt"));
     app_ui.about_about_rpfm.set_status_tip(&qtr("tt_about_about_rpfm"));
     app_ui.about_open_manual.set_status_tip(&qtr("tt_about_open_manual"));
+    app_ui.about_start_tour.set_status_tip(&qtr("tt_about_start_tour"));
     app_ui.about_patreon_link.set_status_tip(&qtr("tt_about_patreon_link"));
     app_ui.about_check_updates.set_status_tip(&qtr("tt_about_check_updates"));
     app_ui.about_check_schema_updates.set_status_tip(&qtr("tt_about_check_schema_updates"));
+    app_ui.about_export_schema_bundle.set_status_tip(&qtr("tt_about_export_schema_bundle"));
+    app_ui.about_import_schema_bundle.set_status_tip(&qtr("tt_about_import_schema_bundle"));
     app_ui.about_update_templates.set_status_tip(&qtr("tt_uodate_templates"));
+
+    //-----------------------------------------------//
+    // `Debug` menu tips.
+    //-----------------------------------------------//
+    app_ui.debug_diagnostics.set_status_tip(&qtr("tt_debug_diagnostics"));
 }
@@ -16,38 +16,61 @@ Basically, this does the heavy load of the program.
 
 use open::that_in_background;
 use rayon::prelude::*;
+use regex::Regex;
 use uuid::Uuid;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::env::temp_dir;
 use std::fs::File;
 use std::io::{BufWriter, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command as ProcessCommand;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
-use rpfm_error::{Error, ErrorKind};
+use rpfm_error::{Error, ErrorKind, Result};
 use rpfm_lib::assembly_kit::*;
+use rpfm_lib::common::{get_current_time, get_game_selected_data_path, get_next_autosave_path};
 use rpfm_lib::DEPENDENCY_DATABASE;
+use rpfm_lib::diff::{PackFileDiff, TableDiff};
 use rpfm_lib::FAKE_DEPENDENCY_DATABASE;
 use rpfm_lib::GAME_SELECTED;
+use rpfm_lib::global_search::PackedFileMatches;
+use rpfm_lib::mymod::git;
+use rpfm_lib::optimizer::OptimizerIndex;
 use rpfm_lib::packedfile::*;
 use rpfm_lib::packedfile::animpack::AnimPack;
 use rpfm_lib::packedfile::table::db::DB;
+use rpfm_lib::packedfile::table::Table;
 use rpfm_lib::packedfile::table::loc::{Loc, TSV_NAME_LOC};
 use rpfm_lib::packedfile::text::{Text, TextType};
-use rpfm_lib::packfile::{PackFile, PackFileInfo, packedfile::PackedFile, PathType, PFHFlags};
+use rpfm_lib::packfile::{PackFile, PackFileInfo, PackFileSettings, packedfile::PackedFile, PathType, PFHFlags, RESERVED_NAME_EXTRA_PACKFILE, RESERVED_NAME_SETTINGS};
+use rpfm_lib::packfile::checksums::ChecksumManifest;
+use rpfm_lib::packfile::contents_manifest::ContentsManifest;
+use rpfm_lib::packfile::live_sync::{LiveSync, LiveSyncReport};
+use rpfm_lib::packfile::loc_split;
+use rpfm_lib::packfile::orphaned_assets;
 use rpfm_lib::schema::*;
+use rpfm_lib::schema::patch::SchemaPatch;
 use rpfm_lib::SCHEMA;
+use rpfm_lib::SCHEMA_PATCH;
 use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::MYMOD_BASE_PATH;
 use rpfm_lib::SUPPORTED_GAMES;
 use rpfm_lib::template::Template;
 
 use crate::app_ui::NewPackedFile;
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::diagnostics_ui::{Diagnostics, DIAGNOSTICS_LAST_OPERATIONS_LIMIT};
 use crate::locale::tre;
 use crate::RPFM_PATH;
 use crate::views::table::TableType;
 
+/// Time between checks of the watcher of the files open in external programs.
+const EXTERNAL_FILES_WATCHER_INTERVAL: Duration = Duration::from_secs(1);
+
 /// This is the background loop that's going to be executed in a parallel thread to the UI. No UI or "Unsafe" stuff here.
 ///
 /// All communication between this and the UI thread is done use the `CENTRAL_COMMAND` static.
@@ -63,6 +86,40 @@ pub fn background_loop() {
     let mut pack_file_decoded = PackFile::new();
     let mut pack_files_decoded_extra = BTreeMap::new();
 
+    // Tables of the groups that differ between the PackFiles of the last "Compare PackFiles" operation, so we can diff and merge them later.
+    // Only those tables are kept, with their data in memory, as they were when the PackFiles were compared.
+    let mut compared_pack_files: Option<(PackFile, PackFile)> = None;
+
+    // Files extracted to be edited in external programs, with the last modification time we know of, and if they have been saved since
+    // they were last imported. They're checked by their own watcher thread, so the UI only has to ask which ones need to be reimported.
+    let external_files_watched: Arc<Mutex<BTreeMap<PathBuf, (SystemTime, bool)>>> = Arc::new(Mutex::new(BTreeMap::new()));
+    spawn_external_files_watcher(&external_files_watched);
+
+    // PackedFiles deleted from the open PackFile since it was last saved, so they can be restored.
+    let mut trash: Vec<PackedFile> = vec![];
+
+    // Snapshots of the open PackFile taken since it was last saved, with the time they were taken, so we can roll back to them.
+    // PackedFiles not loaded to memory only keep a handle to their data on disk, so only the ones loaded or changed are actually copied.
+    // That handle is no longer valid once the PackFile is saved, so, like the trash, they're discarded on save.
+    let mut snapshots: Vec<(i64, PackFile)> = vec![];
+
+    // PackFiles other than the open one searched by the last Global Search. They're opened lazily the first time a search needs them,
+    // and kept for the next searches, as opening all the PackFiles of a game takes a while. If they change on disk, they're reopened.
+    let mut global_search_pack_files: Vec<(Option<SystemTime>, PackFile)> = vec![];
+
+    // Timings of the last operations executed in this thread, for the diagnostics panel. Newest first.
+    let mut last_operations = VecDeque::with_capacity(DIAGNOSTICS_LAST_OPERATIONS_LIMIT);
+
+    // Paths of the PackedFiles in the vanilla PackFiles. The ones of the dependencies come from the dependency database,
+    // but building this one means reading the index of every vanilla PackFile, so we only rebuild it when the game selected changes.
+    let mut vanilla_paths: Option<BTreeSet<String>> = None;
+
+    // Data from the dependencies needed by the optimizer. Same as before, building it is slow, so we only rebuild it when the dependencies change.
+    let mut optimizer_index: Option<OptimizerIndex> = None;
+
+    // Assets folder of a MyMod being synced into the open PackFile, with the state of its files as of the last sync.
+    let mut live_sync: Option<LiveSync> = None;
+
     //---------------------------------------------------------------------------------------//
     // Looping forever and ever...
     //---------------------------------------------------------------------------------------//
@@ -71,10 +128,16 @@ pub fn background_loop() {
         // Wait until you get something through the channel. This hangs the thread until we got something,
         // so it doesn't use processing power until we send it a message.
         let response = CENTRAL_COMMAND.recv_message_rust();
+        let operation_name = response.get_name();
+        let operation_start = Instant::now();
         match response {
 
             // In case we want to reset the PackFile to his original state (dummy)...
-            Command::ResetPackFile => pack_file_decoded = PackFile::new(),
+            Command::ResetPackFile => {
+                pack_file_decoded = PackFile::new();
+                trash.clear();
+                snapshots.clear();
+            }
 
             // In case we want to remove a Secondary Packfile from memory...
             Command::RemovePackFileExtra(path) => { pack_files_decoded_extra.remove(&path); },
@@ -84,6 +147,23 @@ pub fn background_loop() {
                 let game_selected = GAME_SELECTED.read().unwrap();
                 let pack_version = SUPPORTED_GAMES.get(&**game_selected).unwrap().pfh_version[0];
                 pack_file_decoded = PackFile::new_with_name("unknown.pack", pack_version);
+                trash.clear();
+                snapshots.clear();
+            }
+
+            // In case we want to create a "New PackFile from Folder"...
+            Command::NewPackFileFromFolder(path) => {
+                let game_selected = GAME_SELECTED.read().unwrap();
+                let pack_version = SUPPORTED_GAMES.get(&**game_selected).unwrap().pfh_version[0];
+                match PackFile::new_from_folder(&path, pack_version) {
+                    Ok(pack_file) => {
+                        pack_file_decoded = pack_file;
+                        trash.clear();
+                        snapshots.clear();
+                        CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(PackFileInfo::from(&pack_file_decoded)));
+                    }
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
             }
 
             // In case we want to "Open one or more PackFiles"...
@@ -91,6 +171,8 @@ pub fn background_loop() {
                 match PackFile::open_packfiles(&paths, SETTINGS.read().unwrap().settings_bool["use_lazy_loading"], false, false) {
                     Ok(pack_file) => {
                         pack_file_decoded = pack_file;
+                        trash.clear();
+                        snapshots.clear();
                         CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(PackFileInfo::from(&pack_file_decoded)));
                     }
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
@@ -116,6 +198,8 @@ pub fn background_loop() {
                 match PackFile::open_all_ca_packfiles() {
                     Ok(pack_file) => {
                         pack_file_decoded = pack_file;
+                        trash.clear();
+                        snapshots.clear();
                         CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(PackFileInfo::from(&pack_file_decoded)));
                     }
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
@@ -125,7 +209,11 @@ pub fn background_loop() {
             // In case we want to "Save a PackFile"...
             Command::SavePackFile => {
                 match pack_file_decoded.save(None) {
-                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(From::from(&pack_file_decoded))),
+                    Ok(_) => {
+                        trash.clear();
+                        snapshots.clear();
+                        CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(From::from(&pack_file_decoded)));
+                    }
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(Error::from(ErrorKind::SavePackFileGeneric(error.to_string())))),
                 }
             }
@@ -133,11 +221,24 @@ pub fn background_loop() {
             // In case we want to "Save a PackFile As"...
             Command::SavePackFileAs(path) => {
                 match pack_file_decoded.save(Some(path.to_path_buf())) {
-                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(From::from(&pack_file_decoded))),
+                    Ok(_) => {
+                        trash.clear();
+                        snapshots.clear();
+                        CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(From::from(&pack_file_decoded)));
+                    }
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(Error::from(ErrorKind::SavePackFileGeneric(error.to_string())))),
                 }
             }
 
+            // In case we want to save a backup of the PackFile, so its changes can be recovered after a crash...
+            Command::AutosavePackFile => {
+                let max_autosaves = SETTINGS.read().unwrap().settings_string["autosave_amount"].parse::<usize>().unwrap_or(10);
+                match get_next_autosave_path(&pack_file_decoded.get_file_name(), max_autosaves).and_then(|path| pack_file_decoded.save_backup(&path)) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
             // In case we want to change the current settings...
             Command::SetSettings(settings) => {
                 *SETTINGS.write().unwrap() = settings;
@@ -195,7 +296,29 @@ pub fn background_loop() {
 
             // In case we want to launch a global search on a `PackFile`...
             Command::GlobalSearch(mut global_search) => {
-                global_search.search(&mut pack_file_decoded);
+
+                // The matches of each PackedFile are sent as soon as they're found, so the UI can show them while the search goes on.
+                let send_matches = |matches: &PackedFileMatches| CENTRAL_COMMAND.send_message_rust(Response::PackedFileMatches(matches.clone()));
+                global_search.search(&mut pack_file_decoded, &send_matches);
+
+                // If we have to search on other PackFiles, only open the ones we don't have yet, and forget the ones we no longer need.
+                let paths = global_search.scope.get_pack_file_paths(&pack_file_decoded);
+                global_search_pack_files.retain(|(last_modified, pack_file)| {
+                    paths.contains(pack_file.get_file_path()) && *last_modified == get_last_modified(pack_file.get_file_path())
+                });
+
+                // The missing ones are opened in parallel, as opening all the PackFiles of a game one by one takes a while.
+                let missing_paths = paths.iter()
+                    .filter(|path| !global_search_pack_files.iter().any(|(_, pack_file)| pack_file.get_file_path() == *path))
+                    .collect::<Vec<&PathBuf>>();
+
+                let mut new_pack_files = missing_paths.par_iter()
+                    .filter_map(|path| PackFile::open_packfiles(&[path.to_path_buf()], true, false, true).ok().map(|pack_file| (get_last_modified(path), pack_file)))
+                    .collect();
+                global_search_pack_files.append(&mut new_pack_files);
+
+                let mut pack_files = global_search_pack_files.iter_mut().map(|(_, pack_file)| pack_file).collect::<Vec<&mut PackFile>>();
+                global_search.search_on_pack_files(&mut pack_files, &send_matches);
                 let packed_files_info = global_search.get_results_packed_file_info(&mut pack_file_decoded);
                 CENTRAL_COMMAND.send_message_rust(Response::GlobalSearchVecPackedFileInfo((global_search, packed_files_info)));
             }
@@ -207,6 +330,12 @@ pub fn background_loop() {
                 CENTRAL_COMMAND.send_message_rust(Response::GlobalSearchVecPackedFileInfo((global_search, packed_files_info)));
             }
 
+            // In case we want to search on the dependency database, without touching the open `PackFile`...
+            Command::GlobalSearchDependencies(mut global_search) => {
+                global_search.search_on_dependencies(&mut DEPENDENCY_DATABASE.lock().unwrap());
+                CENTRAL_COMMAND.send_message_rust(Response::GlobalSearch(global_search));
+            }
+
             // In case we want to change the current `Game Selected`...
             Command::SetGameSelected(game_selected) => {
                 *GAME_SELECTED.write().unwrap() = game_selected.to_owned();
@@ -214,12 +343,15 @@ pub fn background_loop() {
                 // Try to load the Schema for this game but, before it, PURGE THE DAMN SCHEMA-RELATED CACHE.
                 pack_file_decoded.get_ref_mut_packed_files_by_type(PackedFileType::DB, false).iter_mut().for_each(|x| { let _ = x.encode_and_clean_cache(); });
                 *SCHEMA.write().unwrap() = Schema::load(&SUPPORTED_GAMES.get(&*game_selected).unwrap().schema).ok();
+                *SCHEMA_PATCH.write().unwrap() = SchemaPatch::load(&SUPPORTED_GAMES.get(&*game_selected).unwrap().schema).unwrap_or_default();
 
                 // Send a response, so we can unlock the UI.
                 CENTRAL_COMMAND.send_message_rust(Response::Success);
 
                 // Change the `dependency_database` for that game.
                 *DEPENDENCY_DATABASE.lock().unwrap() = PackFile::load_all_dependency_packfiles(&pack_file_decoded.get_packfiles_list());
+                vanilla_paths = None;
+                optimizer_index = None;
 
                 // Change the `fake dependency_database` for that game.
                 *FAKE_DEPENDENCY_DATABASE.write().unwrap() = DB::read_pak_file();
@@ -276,9 +408,18 @@ pub fn background_loop() {
                 }
             }
 
+            // In case we want to get the definition of a table from the Assembly Kit of our Game Selected...
+            Command::GetDefinitionFromAssKit(table_name) => {
+                match get_definition_from_raw_files(&table_name) {
+                    Ok(definition) => CENTRAL_COMMAND.send_message_rust(Response::Definition(definition)),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
             // In case we want to optimize our PackFile...
             Command::OptimizePackFile => {
-                CENTRAL_COMMAND.send_message_rust(Response::VecVecString(pack_file_decoded.optimize()));
+                let index = optimizer_index.get_or_insert_with(OptimizerIndex::from_dependency_database);
+                CENTRAL_COMMAND.send_message_rust(Response::OptimizerReport(pack_file_decoded.optimize(index)));
             }
 
             // In case we want to Patch the SiegeAI of a PackFile...
@@ -304,8 +445,11 @@ pub fn background_loop() {
             // In case we want to get the Dependency PackFiles of our PackFile...
             Command::GetDependencyPackFilesList => CENTRAL_COMMAND.send_message_rust(Response::VecString(pack_file_decoded.get_packfiles_list().to_vec())),
 
-            // In case we want to set the Dependency PackFiles of our PackFile...
-            Command::SetDependencyPackFilesList(pack_files) => pack_file_decoded.set_packfiles_list(&pack_files),
+            // In case we want to get the entries of the dependency manager of our PackFile...
+            Command::GetDependencyManagerEntries => CENTRAL_COMMAND.send_message_rust(Response::VecStringBool(pack_file_decoded.get_dependency_manager_entries())),
+
+            // In case we want to set the entries of the dependency manager of our PackFile...
+            Command::SetDependencyManagerEntries(entries) => pack_file_decoded.set_dependency_manager_entries(&entries),
 
             // In case we want to check if there is a Dependency Database loaded...
             Command::IsThereADependencyDatabase => CENTRAL_COMMAND.send_message_rust(Response::Bool(!DEPENDENCY_DATABASE.lock().unwrap().is_empty())),
@@ -358,29 +502,19 @@ pub fn background_loop() {
             }
 
             // When we want to add one or more PackedFiles to our PackFile...
-            Command::AddPackedFiles((source_paths, destination_paths)) => {
-                let mut broke = false;
-                for (source_path, destination_path) in source_paths.iter().zip(destination_paths.iter()) {
-                    if let Err(error) = pack_file_decoded.add_from_file(source_path, destination_path.to_vec(), true) {
-                        CENTRAL_COMMAND.send_message_rust(Response::Error(error));
-                        broke = true;
-                        break;
+            Command::AddPackedFiles((source_paths, destination_paths, overwrite)) => {
+
+                // Files that fail to be added don't stop the rest. We send back where the files got added, and why the rest failed.
+                let mut added_paths = vec![];
+                let mut errors = vec![];
+                for ((source_path, destination_path), overwrite) in source_paths.iter().zip(destination_paths.iter()).zip(overwrite.iter()) {
+                    match pack_file_decoded.add_from_file(source_path, destination_path.to_vec(), *overwrite) {
+                        Ok(path) => added_paths.push(path),
+                        Err(error) => errors.push((source_path.to_string_lossy().to_string(), error)),
                     }
                 }
 
-                // If nothing failed, send back success.
-                if !broke {
-                    CENTRAL_COMMAND.send_message_rust(Response::Success);
-                }
-            }
-
-            // In case we want to add one or more entire folders to our PackFile...
-            Command::AddPackedFilesFromFolder(paths) => {
-                match pack_file_decoded.add_from_folders(&paths, true) {
-                    Ok(paths) => CENTRAL_COMMAND.send_message_rust(Response::VecPathType(paths.iter().map(|x| PathType::File(x.to_vec())).collect())),
-                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
-
-                }
+                CENTRAL_COMMAND.send_message_rust(Response::VecVecStringVecStringError((added_paths, errors)));
             }
 
             // In case we want to move stuff from one PackFile to another...
@@ -412,6 +546,14 @@ pub fn background_loop() {
                         None => CENTRAL_COMMAND.send_message_rust(Response::Text(note)),
                     }
                 }
+
+                // The settings of the PackFile are edited as text, with one ignored diagnostic finding per line.
+                else if path == [RESERVED_NAME_SETTINGS.to_owned()] {
+                    let mut diagnostics_ignored = Text::new();
+                    diagnostics_ignored.set_text_type(TextType::Plain);
+                    diagnostics_ignored.set_contents(&pack_file_decoded.get_ref_settings().get_ref_diagnostics_ignored().join("\n"));
+                    CENTRAL_COMMAND.send_message_rust(Response::Text(diagnostics_ignored));
+                }
                 else {
 
                     // Find the PackedFile we want and send back the response.
@@ -429,7 +571,9 @@ pub fn background_loop() {
                                         DecodedPackedFile::Loc(table) => CENTRAL_COMMAND.send_message_rust(Response::LocPackedFileInfo((table.clone(), From::from(&**packed_file)))),
                                         DecodedPackedFile::MatchedCombat(data) => CENTRAL_COMMAND.send_message_rust(Response::MatchedCombatPackedFileInfo((data.clone(), From::from(&**packed_file)))),
                                         DecodedPackedFile::RigidModel(rigid_model) => CENTRAL_COMMAND.send_message_rust(Response::RigidModelPackedFileInfo((rigid_model.clone(), From::from(&**packed_file)))),
+                                        DecodedPackedFile::SoundBank(data) => CENTRAL_COMMAND.send_message_rust(Response::SoundBankPackedFileInfo((data.clone(), From::from(&**packed_file)))),
                                         DecodedPackedFile::Text(text) => CENTRAL_COMMAND.send_message_rust(Response::TextPackedFileInfo((text.clone(), From::from(&**packed_file)))),
+                                        DecodedPackedFile::UnitVariant(data) => CENTRAL_COMMAND.send_message_rust(Response::UnitVariantPackedFileInfo((data.clone(), From::from(&**packed_file)))),
                                         _ => CENTRAL_COMMAND.send_message_rust(Response::Unknown),
 
                                     }
@@ -442,6 +586,51 @@ pub fn background_loop() {
                 }
             }
 
+            // When we want to decode a PackedFile from one of the extra PackFiles, to show it in a read-only view...
+            Command::DecodePackedFileExtra(pack_file_path, path) => {
+                match pack_files_decoded_extra.get_mut(&pack_file_path) {
+                    Some(pack_file) => match pack_file.get_ref_mut_packed_file_by_path(&path) {
+                        Some(ref mut packed_file) => {
+                            match packed_file.decode_return_ref() {
+                                Ok(packed_file_data) => {
+                                    match packed_file_data {
+                                        DecodedPackedFile::DB(table) => CENTRAL_COMMAND.send_message_rust(Response::DBPackedFileInfo((table.clone(), From::from(&**packed_file)))),
+                                        DecodedPackedFile::Loc(table) => CENTRAL_COMMAND.send_message_rust(Response::LocPackedFileInfo((table.clone(), From::from(&**packed_file)))),
+                                        _ => CENTRAL_COMMAND.send_message_rust(Response::Unknown),
+                                    }
+                                }
+                                Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                            }
+                        }
+                        None => CENTRAL_COMMAND.send_message_rust(Response::Error(Error::from(ErrorKind::PackedFileNotFound))),
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::CannotFindExtraPackFile(pack_file_path).into())),
+                }
+            }
+
+            // When we want to know why a DB Table fails to decode, so it can be reported. Only tables from vanilla PackFiles get a report.
+            Command::GetDecodeFailureReport(path) => {
+
+                // Tables from the extra PackFiles have the path of their PackFile before their own path.
+                let (pack_file, path) = if path.len() > 2 && path[0] == RESERVED_NAME_EXTRA_PACKFILE {
+                    (pack_files_decoded_extra.get(&PathBuf::from(&path[1])), path[2..].to_vec())
+                } else { (Some(&pack_file_decoded), path) };
+
+                match pack_file {
+                    Some(pack_file) if PackedFileType::get_packed_file_type(&path) == PackedFileType::DB && PackFile::is_vanilla_packfile_path(pack_file.get_file_path()) => match pack_file.get_ref_packed_file_by_path(&path) {
+                        Some(packed_file) => match *SCHEMA.read().unwrap() {
+                            Some(ref schema) => match packed_file.get_raw_data().and_then(|data| DB::get_decode_failure_report(&data, &path[1], schema)) {
+                                Ok(report) => CENTRAL_COMMAND.send_message_rust(Response::OptionDecodeFailureReport(report)),
+                                Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                            }
+                            None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::SchemaNotFound.into())),
+                        }
+                        None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackedFileNotFound.into())),
+                    }
+                    _ => CENTRAL_COMMAND.send_message_rust(Response::OptionDecodeFailureReport(None)),
+                }
+            }
+
             // When we want to save a PackedFile from the view....
             Command::SavePackedFileFromView(path, decoded_packed_file) => {
                 if path == ["notes.rpfm_reserved".to_owned()] {
@@ -450,6 +639,12 @@ pub fn background_loop() {
                         pack_file_decoded.set_notes(&note);
                     }
                 }
+                else if path == [RESERVED_NAME_SETTINGS.to_owned()] {
+                    if let DecodedPackedFile::Text(data) = decoded_packed_file {
+                        let entries = data.get_ref_contents().lines().map(|x| x.to_owned()).collect::<Vec<String>>();
+                        pack_file_decoded.get_ref_mut_settings().set_diagnostics_ignored(&entries);
+                    }
+                }
                 else if let Some(packed_file) = pack_file_decoded.get_ref_mut_packed_file_by_path(&path) {
                     *packed_file.get_ref_mut_decoded() = decoded_packed_file;
                 }
@@ -458,22 +653,155 @@ pub fn background_loop() {
 
             // In case we want to delete PackedFiles from a PackFile...
             Command::DeletePackedFiles(item_types) => {
+
+                // Keep a copy of the deleted PackedFiles in the trash, so they can be restored until the PackFile is saved.
+                let mut deleted = item_types.iter().flat_map(|item_type| match item_type {
+                    PathType::File(path) => pack_file_decoded.get_ref_packed_file_by_path(path).into_iter().collect(),
+                    PathType::Folder(path) => pack_file_decoded.get_ref_packed_files_by_path_start(path),
+                    PathType::PackFile => pack_file_decoded.get_ref_packed_files_all(),
+                    PathType::None => vec![],
+                }).cloned().collect::<Vec<PackedFile>>();
+                deleted.sort_by(|x, y| x.get_path().cmp(y.get_path()));
+                deleted.dedup_by(|x, y| x.get_path() == y.get_path());
+
+                trash.retain(|packed_file| deleted.iter().all(|x| x.get_path() != packed_file.get_path()));
+                trash.append(&mut deleted);
+
                 CENTRAL_COMMAND.send_message_rust(Response::VecPathType(pack_file_decoded.remove_packed_files_by_type(&item_types)));
             }
 
+            // In case we want to bind the PackedFiles of a folder to the rows of a table...
+            Command::BindFolderToTable((folder_path, table_path, key_column, value_column, key_pattern, value_pattern)) => {
+                let mut paths = pack_file_decoded.get_ref_packed_files_by_path_start(&folder_path).iter()
+                    .map(|packed_file| packed_file.get_path().to_vec())
+                    .collect::<Vec<Vec<String>>>();
+                paths.sort();
+
+                let result = match pack_file_decoded.get_ref_mut_packed_file_by_path(&table_path) {
+                    Some(packed_file) => match packed_file.decode_return_ref_mut() {
+                        Ok(DecodedPackedFile::DB(table)) => {
+                            let mut rows = table.get_table_data();
+                            Table::bind_paths(table.get_ref_definition(), &mut rows, &paths, key_column, value_column, &key_pattern, &value_pattern)
+                                .and_then(|result| table.set_table_data(&rows).map(|_| result))
+                        }
+                        Ok(DecodedPackedFile::Loc(table)) => {
+                            let mut rows = table.get_table_data();
+                            Table::bind_paths(table.get_ref_definition(), &mut rows, &paths, key_column, value_column, &key_pattern, &value_pattern)
+                                .and_then(|result| table.set_table_data(&rows).map(|_| result))
+                        }
+                        Ok(_) => Err(ErrorKind::PackedFileIsNotATable.into()),
+                        Err(error) => Err(error),
+                    }
+                    None => Err(ErrorKind::PackedFileNotFound.into()),
+                };
+
+                match result {
+                    Ok((added, updated)) => CENTRAL_COMMAND.send_message_rust(Response::I32I32((added as i32, updated as i32))),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // In case we want to get the paths of the PackedFiles in the trash...
+            Command::GetTrash => {
+                let mut paths = trash.iter().map(|packed_file| packed_file.get_path().to_vec()).collect::<Vec<Vec<String>>>();
+                paths.sort();
+                CENTRAL_COMMAND.send_message_rust(Response::VecVecString(paths));
+            }
+
+            // In case we want to restore PackedFiles from the trash...
+            Command::RestoreFromTrash(paths) => {
+
+                // If any of them fails to be restored, it stays in the trash. That includes the ones whose path
+                // has been taken by another PackedFile since they were deleted, so we don't overwrite it.
+                let mut restored = vec![];
+                for path in &paths {
+                    if pack_file_decoded.packedfile_exists(path) {
+                        continue;
+                    }
+
+                    if let Some(position) = trash.iter().position(|packed_file| packed_file.get_path() == &**path) {
+                        if let Ok(path) = pack_file_decoded.add_packed_file(&trash[position], true) {
+                            trash.remove(position);
+                            restored.push(path);
+                        }
+                    }
+                }
+
+                CENTRAL_COMMAND.send_message_rust(Response::VecVecString(restored));
+            }
+
+            // In case we want to get the orphaned assets of the open PackFile...
+            Command::GetOrphanedAssets => CENTRAL_COMMAND.send_message_rust(Response::VecVecString(orphaned_assets::get_orphaned_assets(&mut pack_file_decoded))),
+
+            // In case we want to take a snapshot of the open PackFile...
+            Command::CreateSnapshot => {
+                snapshots.push((get_current_time(), pack_file_decoded.clone()));
+                CENTRAL_COMMAND.send_message_rust(Response::Success);
+            }
+
+            // In case we want to get the time and amount of PackedFiles of each snapshot of the open PackFile...
+            Command::GetSnapshots => {
+                let snapshots = snapshots.iter().map(|(time, pack_file)| (*time, pack_file.get_ref_packed_files_all().len())).collect();
+                CENTRAL_COMMAND.send_message_rust(Response::VecI64Usize(snapshots));
+            }
+
+            // In case we want to roll back the open PackFile to one of its snapshots. The snapshot is kept, so we can roll back to it again.
+            Command::RestoreSnapshot(index) => {
+                match snapshots.get(index) {
+                    Some((_, pack_file)) => {
+                        pack_file_decoded = pack_file.clone();
+                        CENTRAL_COMMAND.send_message_rust(Response::PackFileInfo(PackFileInfo::from(&pack_file_decoded)));
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackFileSnapshotNotFound.into())),
+                }
+            }
+
+            // In case we want to delete one of the snapshots of the open PackFile...
+            Command::DeleteSnapshot(index) => {
+                if index < snapshots.len() {
+                    snapshots.remove(index);
+                    CENTRAL_COMMAND.send_message_rust(Response::Success);
+                } else {
+                    CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackFileSnapshotNotFound.into()));
+                }
+            }
+
             // In case we want to extract PackedFiles from a PackFile...
-            Command::ExtractPackedFiles(item_types, path) => {
-                match pack_file_decoded.extract_packed_files_by_type(&item_types, &path) {
+            Command::ExtractPackedFiles(item_types, path, path_rewrite, convert) => {
+                let path_rewrite: Result<Option<(Regex, String)>> = match path_rewrite {
+                    Some((regex, replacement)) => Regex::new(&regex)
+                        .map(|regex| Some((regex, replacement)))
+                        .map_err(|error| ErrorKind::InvalidPathRewriteRegex(error.to_string()).into()),
+                    None => Ok(None),
+                };
+
+                match path_rewrite.and_then(|path_rewrite| pack_file_decoded.extract_packed_files_by_type(&item_types, &path, path_rewrite.as_ref(), convert)) {
                     Ok(result) => CENTRAL_COMMAND.send_message_rust(Response::String(tre("files_extracted_success", &[&result.to_string()]))),
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
                 }
             }
 
+            // In case we want to extract PackedFiles from an extra PackFile...
+            Command::ExtractPackedFilesExtra(pack_file_path, item_types, path) => {
+                match pack_files_decoded_extra.get_mut(&pack_file_path) {
+                    Some(pack_file) => match pack_file.extract_packed_files_by_type(&item_types, &path, None, false) {
+                        Ok(result) => CENTRAL_COMMAND.send_message_rust(Response::String(tre("files_extracted_success", &[&result.to_string()]))),
+                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::CannotFindExtraPackFile(pack_file_path).into())),
+                }
+            }
+
             // In case we want to rename one or more PackedFiles...
             Command::RenamePackedFiles(renaming_data) => {
                 CENTRAL_COMMAND.send_message_rust(Response::VecPathTypeVecString(pack_file_decoded.rename_packedfiles(&renaming_data, false)));
             }
 
+            // In case we want to move one or more PackedFiles...
+            Command::MovePackedFiles(moving_data) => {
+                CENTRAL_COMMAND.send_message_rust(Response::VecPathTypeVecString(pack_file_decoded.move_packedfiles(&moving_data, false)));
+            }
+
             // In case we want to Mass-Import TSV Files...
             Command::MassImportTSV(paths, name) => {
                 match pack_file_decoded.mass_import_tsv(&paths, name, true) {
@@ -500,6 +828,11 @@ pub fn background_loop() {
                 CENTRAL_COMMAND.send_message_rust(Response::Bool(pack_file_decoded.packedfile_exists(&path)));
             }
 
+            // In case we want to know which of the provided paths are already in use...
+            Command::GetExistingPackedFilePaths(paths) => {
+                CENTRAL_COMMAND.send_message_rust(Response::VecVecString(paths.into_iter().filter(|path| pack_file_decoded.packedfile_exists(path)).collect()));
+            }
+
             // In case we want to get the list of tables in the dependency database...
             Command::GetTableListFromDependencyPackFile => {
                 let tables = (*DEPENDENCY_DATABASE.lock().unwrap()).par_iter().filter(|x| x.get_path().len() > 2).filter(|x| x.get_path()[1].ends_with("_tables")).map(|x| x.get_path()[1].to_owned()).collect::<Vec<String>>();
@@ -518,12 +851,21 @@ pub fn background_loop() {
 
             // In case we want to check the DB tables for dependency errors...
             Command::DBCheckTableIntegrity => {
-                match pack_file_decoded.check_table_integrity() {
-                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                match pack_file_decoded.get_missing_references() {
+                    Ok(missing_references) => {
+                        let entries = missing_references.iter().map(|(path, column)| PackFileSettings::get_diagnostic_entry(path, column)).collect();
+                        CENTRAL_COMMAND.send_message_rust(Response::VecString(entries));
+                    }
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
                 }
             }
 
+            // In case we want to stop getting reported about some diagnostic findings...
+            Command::AddDiagnosticsIgnored(entries) => {
+                pack_file_decoded.get_ref_mut_settings().add_diagnostics_ignored(&entries);
+                CENTRAL_COMMAND.send_message_rust(Response::Success);
+            }
+
             // In case we want to merge DB or Loc Tables from a PackFile...
             Command::MergeTables(paths, name, delete_source_files) => {
                 match pack_file_decoded.merge_tables(&paths, &name, delete_source_files) {
@@ -582,6 +924,23 @@ pub fn background_loop() {
                 CENTRAL_COMMAND.send_message_rust(Response::BTreeMapI32BTreeMapStringString(dependency_data));
             }
 
+            // In case we want the values used in vanilla for the string columns of a table...
+            Command::GetVanillaColumnValues(table_name, definition) => {
+                let values = match &*SCHEMA.read().unwrap() {
+                    Some(ref schema) => DB::get_vanilla_column_values(&table_name, &definition, &mut DEPENDENCY_DATABASE.lock().unwrap(), schema),
+                    None => BTreeMap::new(),
+                };
+                CENTRAL_COMMAND.send_message_rust(Response::BTreeMapI32VecString(values));
+            }
+
+            Command::GetVanillaKeyValues(table_name) => {
+                let values = match &*SCHEMA.read().unwrap() {
+                    Some(ref schema) => DB::get_vanilla_key_values(&table_name, &mut DEPENDENCY_DATABASE.lock().unwrap(), schema),
+                    None => BTreeMap::new(),
+                };
+                CENTRAL_COMMAND.send_message_rust(Response::BTreeMapStringString(values));
+            }
+
             // In case we want to return an entire PackedFile to the UI.
             Command::GetPackedFile(path) => CENTRAL_COMMAND.send_message_rust(Response::OptionPackedFile(pack_file_decoded.get_packed_file_by_path(&path))),
 
@@ -603,17 +962,54 @@ pub fn background_loop() {
                 }
             },
 
+            // In case we want to replace the data of a PackedFile with raw data, like from the hex editor...
+            Command::SetPackedFileRawData((path, data)) => {
+                match pack_file_decoded.get_ref_mut_packed_file_by_path(&path) {
+                    Some(packed_file) => {
+                        packed_file.set_raw_data(&data);
+                        CENTRAL_COMMAND.send_message_rust(Response::Success);
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(Error::from(ErrorKind::PackedFileNotFound))),
+                }
+            },
+
             // In case we want to save an schema to disk...
             Command::SaveSchema(mut schema) => {
                 match schema.save(&SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap().schema) {
                     Ok(_) => {
                         *SCHEMA.write().unwrap() = Some(schema);
+                        optimizer_index = None;
                         CENTRAL_COMMAND.send_message_rust(Response::Success);
                     },
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
                 }
             }
 
+            // In case we want to change the unit of a column in the local patch of the schema...
+            Command::SetColumnUnit((table_name, field_name, unit)) => {
+                let mut schema_patch = SCHEMA_PATCH.write().unwrap();
+                schema_patch.set_unit(&table_name, &field_name, unit);
+                match schema_patch.save(&SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap().schema) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // In case we want to contribute a description for a field of the schema...
+            Command::SetFieldDescription((table_name, version, field_name, description)) => {
+                match *SCHEMA.write().unwrap() {
+                    Some(ref mut schema) => {
+                        let result = schema.set_field_description(&table_name, version, &field_name, &description)
+                            .and_then(|_| schema.save(&SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap().schema));
+                        match result {
+                            Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                            Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                        }
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::SchemaNotFound.into())),
+                }
+            }
+
             // In case we want to clean the cache of one or more PackedFiles...
             Command::CleanCache(paths) => {
                 let mut packed_files = pack_file_decoded.get_ref_mut_packed_files_by_paths(paths.iter().map(|x| x.as_ref()).collect::<Vec<&[String]>>());
@@ -703,8 +1099,11 @@ pub fn background_loop() {
                                             temporal_file_path.set_extension("tsv");
                                             match data.export_tsv(&temporal_file_path, &path[1]) {
                                                 Ok(_) => {
-                                                    that_in_background(&temporal_file_path);
-                                                    CENTRAL_COMMAND.send_message_rust(Response::PathBuf(temporal_file_path));
+                                                    watch_external_file(&mut external_files_watched.lock().unwrap(), &temporal_file_path);
+                                                    match open_in_external_program(&temporal_file_path) {
+                                                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::PathBuf(temporal_file_path)),
+                                                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                                                    }
                                                 }
                                                 Err(error) =>  CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
                                             }
@@ -721,8 +1120,11 @@ pub fn background_loop() {
                                             temporal_file_path.set_extension("tsv");
                                             match data.export_tsv(&temporal_file_path, &TSV_NAME_LOC) {
                                                 Ok(_) => {
-                                                    that_in_background(&temporal_file_path);
-                                                    CENTRAL_COMMAND.send_message_rust(Response::PathBuf(temporal_file_path));
+                                                    watch_external_file(&mut external_files_watched.lock().unwrap(), &temporal_file_path);
+                                                    match open_in_external_program(&temporal_file_path) {
+                                                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::PathBuf(temporal_file_path)),
+                                                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                                                    }
                                                 }
                                                 Err(error) =>  CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
                                             }
@@ -739,8 +1141,11 @@ pub fn background_loop() {
                                         match File::create(&temporal_file_path) {
                                             Ok(mut file) => {
                                                 if file.write_all(&data).is_ok() {
-                                                    that_in_background(&temporal_file_path);
-                                                    CENTRAL_COMMAND.send_message_rust(Response::PathBuf(temporal_file_path));
+                                                    watch_external_file(&mut external_files_watched.lock().unwrap(), &temporal_file_path);
+                                                    match open_in_external_program(&temporal_file_path) {
+                                                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::PathBuf(temporal_file_path)),
+                                                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                                                    }
                                                 }
                                                 else {
                                                     CENTRAL_COMMAND.send_message_rust(Response::Error(Error::from(ErrorKind::IOGenericWrite(vec![temporal_file_path.display().to_string();1]))));
@@ -760,67 +1165,44 @@ pub fn background_loop() {
 
             // When we want to save a PackedFile from the external view....
             Command::SavePackedFileFromExternalView((path, external_path)) => {
-                match pack_file_decoded.get_ref_mut_packed_file_by_path(&path) {
-                    Some(packed_file) => {
-                        match packed_file.get_packed_file_type_by_path() {
-
-                            // Tables we extract them as TSV.
-                            PackedFileType::DB | PackedFileType::Loc => {
-                                match packed_file.decode_return_ref_mut() {
-                                    Ok(data) => {
-                                        if let DecodedPackedFile::DB(ref mut data) = data {
-                                            match DB::import_tsv(&data.get_definition(), &external_path, &path[1]) {
-                                                Ok(new_data) => {
-                                                    *data = new_data;
-                                                    match packed_file.encode_and_clean_cache() {
-                                                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
-                                                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
-                                                    }
-                                                }
-                                                Err(error) =>  CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
-                                            }
-                                        }
-                                        else if let DecodedPackedFile::Loc(ref mut data) = data {
-                                            match Loc::import_tsv(&data.get_definition(), &external_path, &TSV_NAME_LOC) {
-                                                Ok(new_data) => {
-                                                    *data = new_data;
-                                                    match packed_file.encode_and_clean_cache() {
-                                                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
-                                                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
-                                                    }
-                                                }
-                                                Err(error) =>  CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
-                                            }
-                                        }
-                                        else {
-                                            unimplemented!()
-                                        }
-                                    },
-                                    Err(error) =>  CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
-                                }
-                            },
+                watch_external_file(&mut external_files_watched.lock().unwrap(), &external_path);
+                match import_external_file(&mut pack_file_decoded, &path, &external_path) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
 
-                            _ => {
-                                match File::open(external_path) {
-                                    Ok(mut file) => {
-                                        let mut data = vec![];
-                                        match file.read_to_end(&mut data) {
-                                            Ok(_) => {
-                                                packed_file.set_raw_data(&data);
-                                                CENTRAL_COMMAND.send_message_rust(Response::Success);
-                                            }
-                                            Err(_) => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::IOGeneric.into())),
-                                        }
-                                    }
-                                    Err(_) => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::IOGeneric.into())),
-                                }
-                            }
+            // When we want to check if a PackedFile open in an external program has been saved, and reimport it if it has been...
+            Command::ReimportExternalFileIfModified((path, external_path)) => {
+                let modified = {
+                    let mut external_files_watched = external_files_watched.lock().unwrap();
+                    match external_files_watched.get_mut(&external_path) {
+                        Some((_, modified)) => std::mem::take(modified),
+
+                        // If we were not watching the file, start watching it now.
+                        None => {
+                            watch_external_file(&mut external_files_watched, &external_path);
+                            false
                         }
                     }
-                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackedFileNotFound.into())),
+                };
+
+                if modified {
+                    match import_external_file(&mut pack_file_decoded, &path, &external_path) {
+                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Bool(true)),
+                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                    }
+                } else {
+                    CENTRAL_COMMAND.send_message_rust(Response::Bool(false));
                 }
             }
 
+            // When we close the view of a PackedFile open in an external program, stop watching its file.
+            Command::StopWatchingExternalFile(external_path) => {
+                external_files_watched.lock().unwrap().remove(&external_path);
+                CENTRAL_COMMAND.send_message_rust(Response::Success);
+            }
+
             // When we want to unpack an AnimPack...
             Command::AnimPackUnpack(path) => {
                 let data = match pack_file_decoded.get_ref_mut_packed_file_by_path(&path) {
@@ -844,6 +1226,87 @@ pub fn background_loop() {
                 }
             }
 
+            // When we want to unpack some files of an AnimPack...
+            Command::AnimPackUnpackFiles((path, paths)) => {
+                let data = match get_anim_pack_mut(&mut pack_file_decoded, &path) {
+                    Ok(data) => data.clone(),
+                    Err(error) => { CENTRAL_COMMAND.send_message_rust(Response::Error(error)); continue },
+                };
+
+                match data.unpack_packed_files(&paths, &mut pack_file_decoded) {
+                    Ok(result) => CENTRAL_COMMAND.send_message_rust(Response::VecVecString(result)),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to add files from disk to an AnimPack...
+            Command::AnimPackAddFiles((path, files)) => {
+                let new_packed_files = files.into_iter().map(|(file_path, packed_file_path)| {
+                    let mut data = vec![];
+                    File::open(&file_path).and_then(|mut file| file.read_to_end(&mut data)).map(|_| (packed_file_path, data)).map_err(Error::from)
+                }).collect::<Result<Vec<(Vec<String>, Vec<u8>)>>>();
+
+                let new_packed_files = match new_packed_files {
+                    Ok(new_packed_files) => new_packed_files,
+                    Err(error) => { CENTRAL_COMMAND.send_message_rust(Response::Error(error)); continue },
+                };
+
+                match get_anim_pack_mut(&mut pack_file_decoded, &path) {
+                    Ok(data) => {
+                        new_packed_files.into_iter().for_each(|(packed_file_path, packed_file_data)| data.add_packed_file(packed_file_path, packed_file_data));
+                        CENTRAL_COMMAND.send_message_rust(Response::VecString(data.get_file_list()));
+                    }
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to remove files from an AnimPack...
+            Command::AnimPackRemoveFiles((path, paths)) => {
+                match get_anim_pack_mut(&mut pack_file_decoded, &path) {
+                    Ok(data) => {
+                        data.remove_packed_files(&paths);
+                        CENTRAL_COMMAND.send_message_rust(Response::VecString(data.get_file_list()));
+                    }
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to extract files from an AnimPack to disk...
+            Command::AnimPackExtractFiles((path, paths, folder)) => {
+                match get_anim_pack_mut(&mut pack_file_decoded, &path).and_then(|data| data.extract_packed_files(&paths, &folder)) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to put back into an AnimPack the files unpacked from it...
+            Command::AnimPackRepack(path) => {
+                let paths = match get_anim_pack_mut(&mut pack_file_decoded, &path) {
+                    Ok(data) => data.get_ref_packed_files().iter().map(|packed_file| packed_file.get_ref_path().to_vec()).collect::<Vec<Vec<String>>>(),
+                    Err(error) => { CENTRAL_COMMAND.send_message_rust(Response::Error(error)); continue },
+                };
+
+                // First get the data from the PackFile, then put it into the AnimPack, as we cannot borrow both at the same time.
+                let new_packed_files = paths.into_iter().filter_map(|packed_file_path| {
+                    let packed_file = pack_file_decoded.get_ref_mut_packed_file_by_path(&packed_file_path)?;
+                    Some(packed_file.encode_and_return().and_then(|raw_packed_file| raw_packed_file.get_raw_data()).map(|packed_file_data| (packed_file_path, packed_file_data)))
+                }).collect::<Result<Vec<(Vec<String>, Vec<u8>)>>>();
+
+                let new_packed_files = match new_packed_files {
+                    Ok(new_packed_files) => new_packed_files,
+                    Err(error) => { CENTRAL_COMMAND.send_message_rust(Response::Error(error)); continue },
+                };
+
+                match get_anim_pack_mut(&mut pack_file_decoded, &path) {
+                    Ok(data) => {
+                        let repacked_paths = new_packed_files.iter().map(|(packed_file_path, _)| packed_file_path.to_vec()).collect();
+                        new_packed_files.into_iter().for_each(|(packed_file_path, packed_file_data)| data.add_packed_file(packed_file_path, packed_file_data));
+                        CENTRAL_COMMAND.send_message_rust(Response::VecVecString(repacked_paths));
+                    }
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
             // When we want to generate a dummy AnimPack...
             Command::GenerateDummyAnimPack => {
                 match AnimPack::repack_anim_table(&mut pack_file_decoded) {
@@ -867,6 +1330,63 @@ pub fn background_loop() {
                 }
             }
 
+            // When we want to apply a MyMod template over the open PackFile...
+            Command::ApplyMyModTemplate(template, mod_name, private_path) => {
+                match template.apply_template(&mod_name, &private_path, &mut pack_file_decoded) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to create the Git repository of a MyMod...
+            Command::InitMyModGitRepo(private_path) => {
+                match git::init_repo(&private_path) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to know what changed in the open MyMod since its last commit...
+            Command::GetMyModGitStatus(private_path) => {
+                match git::get_status(&private_path, &mut pack_file_decoded) {
+                    Ok(status) => CENTRAL_COMMAND.send_message_rust(Response::VecVecStringGitStatus(status)),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to commit the open MyMod...
+            Command::CommitMyModGit(private_path, message) => {
+                match git::init_repo(&private_path).and_then(|_| git::commit(&private_path, &mut pack_file_decoded, &message)) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to start syncing the assets folder of a MyMod...
+            Command::LiveSyncStart(assets_path) => {
+                match LiveSync::new(&assets_path) {
+                    Ok(sync) => {
+                        live_sync = Some(sync);
+                        CENTRAL_COMMAND.send_message_rust(Response::Success);
+                    }
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to sync the changes done to the assets folder since the last sync...
+            Command::LiveSyncAssets(skip_paths) => {
+                match live_sync {
+                    Some(ref mut live_sync) => match live_sync.sync(&mut pack_file_decoded, &skip_paths) {
+                        Ok(report) => CENTRAL_COMMAND.send_message_rust(Response::LiveSyncReport(report)),
+                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::LiveSyncReport(LiveSyncReport::default())),
+                }
+            }
+
+            // When we want to stop syncing the assets folder...
+            Command::LiveSyncStop => { live_sync = None; },
+
             // When we want to update the templates..
             Command::UpdateTemplates => {
                 match Template::update() {
@@ -878,13 +1398,336 @@ pub fn background_loop() {
             // When we want to update our schemas...
             Command::UpdateSchemas => {
                 match Schema::update_schema_repo() {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::BTreeMapStringVecTableDefinitionChanges(Schema::get_last_update_changes())),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to export our schemas to share them...
+            Command::ExportSchemaBundle(path) => {
+                match Schema::export_bundle(&path) {
+                    Ok(exported) => CENTRAL_COMMAND.send_message_rust(Response::U32(exported as u32)),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to import the schemas someone shared with us...
+            Command::ImportSchemaBundle(path) => {
+                match Schema::import_bundle(&path) {
+                    Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::BTreeMapStringVecTableDefinitionChanges(Schema::get_last_update_changes())),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to know what's eating our memory and time...
+            Command::GetDiagnostics => {
+                let packed_files = pack_file_decoded.get_ref_packed_files_all();
+                let dependency_database = DEPENDENCY_DATABASE.lock().unwrap();
+                let diagnostics = Diagnostics {
+                    packed_files_count: packed_files.len(),
+                    packed_files_loaded_count: packed_files.iter().filter(|x| x.get_loaded_data_size() > 0).count(),
+                    packed_files_decoded_count: packed_files.iter().filter(|x| x.is_decoded()).count(),
+                    packed_files_loaded_size: packed_files.iter().map(|x| x.get_loaded_data_size()).sum(),
+                    extra_packfiles_count: pack_files_decoded_extra.len(),
+                    dependency_packed_files_count: dependency_database.len(),
+                    dependency_packed_files_loaded_size: dependency_database.iter().map(|x| x.get_loaded_data_size()).sum(),
+                    fake_dependency_tables_count: FAKE_DEPENDENCY_DATABASE.read().unwrap().len(),
+                    last_operations: last_operations.iter().cloned().collect(),
+                };
+                CENTRAL_COMMAND.send_message_rust(Response::Diagnostics(diagnostics));
+            }
+
+            // When we want to compare two PackFiles...
+            Command::ComparePackFiles((base_path, other_path)) => {
+                let pack_files = match base_path {
+                    Some(base_path) => PackFile::open_packfiles(&[base_path], true, false, true).map(Some),
+                    None => Ok(None),
+                }.and_then(|base| PackFile::open_packfiles(&[other_path], true, false, true).map(|other| (base, other)));
+
+                let result = pack_files.and_then(|(base, other)| {
+                    let base = base.as_ref().unwrap_or(&pack_file_decoded);
+                    let diff = PackFileDiff::new(base, &other)?;
+                    let base_tables = get_table_groups_pack_file(base, diff.get_ref_tables())?;
+                    let other_tables = get_table_groups_pack_file(&other, diff.get_ref_tables())?;
+                    Ok((diff, base_tables, other_tables))
+                });
+
+                match result {
+                    Ok((diff, base_tables, other_tables)) => {
+                        compared_pack_files = Some((base_tables, other_tables));
+                        CENTRAL_COMMAND.send_message_rust(Response::PackFileDiff(diff));
+                    }
+                    Err(error) => {
+                        compared_pack_files = None;
+                        CENTRAL_COMMAND.send_message_rust(Response::Error(error));
+                    }
+                }
+            }
+
+            // When we want to compare the rows of a table from the last compared PackFiles...
+            Command::GetTableDiff(group) => {
+                match compared_pack_files {
+                    Some((ref base, ref other)) => {
+                        match TableDiff::new_from_table_group(base, other, &group) {
+                            Ok(diff) => CENTRAL_COMMAND.send_message_rust(Response::TableDiff(diff)),
+                            Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                        }
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackedFileNotFound.into())),
+                }
+            }
+
+            // When we want to merge a table from the last compared PackFiles into our PackFile...
+            Command::MergeTableDiff((group, other_wins)) => {
+                match compared_pack_files {
+                    Some((ref base, ref other)) => {
+                        match TableDiff::merge_table_group(base, other, &group, &other_wins).and_then(|packed_file| pack_file_decoded.add_packed_file(&packed_file, true)) {
+                            Ok(path) => CENTRAL_COMMAND.send_message_rust(Response::VecString(path)),
+                            Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                        }
+                    }
+                    None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackedFileNotFound.into())),
+                }
+            }
+
+            // When we want to compare a table with its version in another PackFile, or in the dependencies...
+            Command::CompareTable((path, table, other_path)) => {
+                let diff = match other_path {
+                    Some(other_path) => PackFile::open_packfiles(&[other_path], true, false, false)
+                        .and_then(|other| TableDiff::new_from_table(&table, &path, &other.get_ref_packed_files_all())),
+                    None => {
+                        let dependency_database = DEPENDENCY_DATABASE.lock().unwrap();
+                        TableDiff::new_from_table(&table, &path, &dependency_database.iter().collect::<Vec<&PackedFile>>())
+                    }
+                };
+
+                match diff {
+                    Ok(diff) => CENTRAL_COMMAND.send_message_rust(Response::TableDiff(diff)),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to run a Job over our PackFile...
+            Command::RunJob(job) => {
+                let result = job.run(&mut pack_file_decoded, |step, steps, job_step| {
+                    CENTRAL_COMMAND.send_message_rust(Response::UsizeUsizeString((step, steps, job_step.to_string())));
+                });
+
+                // The Job may have replaced or saved the PackFile, so whatever is in the trash and the snapshots is no longer valid.
+                trash.clear();
+                snapshots.clear();
+                match result {
+                    Ok(report) => CENTRAL_COMMAND.send_message_rust(Response::VecStringPackFileInfo((report, PackFileInfo::from(&pack_file_decoded)))),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to know which of the paths referenced by a PackedFile don't exist...
+            Command::GetMissingPaths(paths) => {
+                let vanilla_paths = vanilla_paths.get_or_insert_with(PackFile::get_vanilla_packed_file_paths);
+                let dependency_paths = PackFile::get_dependency_packed_file_paths();
+                CENTRAL_COMMAND.send_message_rust(Response::VecString(pack_file_decoded.get_missing_paths(&paths, &[&*vanilla_paths, &dependency_paths])));
+            }
+
+            // When we want to generate a checksum manifest of our PackFile...
+            Command::GenerateChecksumManifest => {
+                let pack_file_path = pack_file_decoded.get_file_path().to_path_buf();
+                if pack_file_path.is_file() {
+                    let manifest_path = ChecksumManifest::get_manifest_path(&pack_file_path);
+                    match ChecksumManifest::new_from_packfile_path(&pack_file_path).and_then(|manifest| manifest.save(&manifest_path)) {
+                        Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::PathBuf(manifest_path)),
+                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                    }
+                } else { CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackFileIsNotAFile.into())); }
+            }
+
+            // When we want to check our PackFile against a checksum manifest...
+            Command::VerifyChecksumManifest(manifest_path) => {
+                let pack_file_path = pack_file_decoded.get_file_path().to_path_buf();
+                if pack_file_path.is_file() {
+                    match ChecksumManifest::read(&manifest_path).and_then(|manifest| manifest.verify(&pack_file_path)) {
+                        Ok(report) => CENTRAL_COMMAND.send_message_rust(Response::ChecksumReport(report)),
+                        Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                    }
+                } else { CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackFileIsNotAFile.into())); }
+            }
+
+            // When we want to export the list of contents of our PackFile...
+            Command::ExportContentsManifest(path) => {
+                match ContentsManifest::new_from_packfile(&mut pack_file_decoded).and_then(|manifest| manifest.save(&path)) {
                     Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
                     Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
                 }
             }
 
-            // These two belong to the network thread, not to this one!!!!
-            Command::CheckUpdates | Command::CheckSchemaUpdates => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            // When we want to split the Locs of our PackFile into one PackFile per language...
+            Command::SplitLocByLanguage(path) => {
+                let languages = loc_split::get_languages(SUPPORTED_GAMES.get(&**GAME_SELECTED.read().unwrap()).unwrap());
+                match loc_split::split_by_language(&pack_file_decoded, &languages, &path) {
+                    Ok(paths) => CENTRAL_COMMAND.send_message_rust(Response::VecPathBuf(paths)),
+                    Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                }
+            }
+
+            // When we want to normalize the timestamps of the PackedFiles of our PackFile...
+            Command::NormalizeTimestamps => CENTRAL_COMMAND.send_message_rust(Response::U32(pack_file_decoded.set_packed_files_timestamp(0))),
+
+            // When we want to launch the game with our PackFile, and its MyMod siblings if it's a MyMod...
+            Command::LaunchGameWithPackFile => {
+                let pack_file_path = pack_file_decoded.get_file_path().to_path_buf();
+                if pack_file_path.is_file() {
+                    let game_selected = GAME_SELECTED.read().unwrap().to_owned();
+                    let (game_path, mymod_base_path) = {
+                        let settings = SETTINGS.read().unwrap();
+                        (settings.paths.get(&game_selected).cloned().flatten(), settings.paths.get(MYMOD_BASE_PATH).cloned().flatten())
+                    };
+
+                    match game_path {
+                        Some(game_path) => {
+                            let mut pack_file_paths = vec![pack_file_path.to_path_buf()];
+                            if let Some(mymod_base_path) = mymod_base_path {
+                                if pack_file_path.starts_with(&mymod_base_path) {
+                                    if let Some(Ok(entries)) = pack_file_path.parent().map(|folder| folder.read_dir()) {
+                                        let mut siblings = entries.flatten()
+                                            .map(|entry| entry.path())
+                                            .filter(|path| path.is_file() && path != &pack_file_path && path.extension().map_or(false, |extension| extension == "pack"))
+                                            .collect::<Vec<PathBuf>>();
+                                        siblings.sort();
+                                        pack_file_paths.append(&mut siblings);
+                                    }
+                                }
+                            }
+
+                            match SUPPORTED_GAMES[&*game_selected].launch_with_mods(&game_path, &pack_file_paths) {
+                                Ok(_) => CENTRAL_COMMAND.send_message_rust(Response::Success),
+                                Err(error) => CENTRAL_COMMAND.send_message_rust(Response::Error(error)),
+                            }
+                        }
+                        None => CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::GamePathNotConfigured.into())),
+                    }
+                } else { CENTRAL_COMMAND.send_message_rust(Response::Error(ErrorKind::PackFileIsNotAFile.into())); }
+            }
+
+            // When we want to know if the game is running with our PackFile in its data folder...
+            Command::IsGameRunningWithPackFile => {
+                let pack_file_path = pack_file_decoded.get_file_path();
+                let is_in_data_folder = match get_game_selected_data_path() {
+                    Some(data_path) => pack_file_path.parent().map_or(false, |folder| folder == data_path),
+                    None => false,
+                };
+
+                let is_running = is_in_data_folder && SUPPORTED_GAMES[&**GAME_SELECTED.read().unwrap()].is_running();
+                CENTRAL_COMMAND.send_message_rust(Response::Bool(is_running));
+            }
+
+            // These belong to the network thread, not to this one!!!!
+            Command::CheckUpdates | Command::CheckSchemaUpdates | Command::AutoUpdateSchemas | Command::UploadMyModToWorkshop(..) | Command::ReportBrokenDecode(_) => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        // Keep the timing of the operation, so we can find out what's slow.
+        last_operations.push_front((operation_name, operation_start.elapsed()));
+        last_operations.truncate(DIAGNOSTICS_LAST_OPERATIONS_LIMIT);
+    }
+}
+
+/// This function marks the provided external file as watched and up to date, remembering its current modification time.
+fn watch_external_file(external_files_watched: &mut BTreeMap<PathBuf, (SystemTime, bool)>, external_path: &Path) {
+    if let Some(last_modified) = get_last_modified(external_path) {
+        external_files_watched.insert(external_path.to_path_buf(), (last_modified, false));
+    }
+}
+
+/// This function starts the thread that watches the files open in external programs.
+///
+/// Every second, it marks as modified the files whose modification time changed since the last check. Files that cannot
+/// be found are skipped instead of forgotten, as some programs replace the file when saving it.
+fn spawn_external_files_watcher(external_files_watched: &Arc<Mutex<BTreeMap<PathBuf, (SystemTime, bool)>>>) {
+    let external_files_watched = external_files_watched.clone();
+    thread::spawn(move || loop {
+        thread::sleep(EXTERNAL_FILES_WATCHER_INTERVAL);
+        for (external_path, (watched_modified, modified)) in external_files_watched.lock().unwrap().iter_mut() {
+            if let Some(last_modified) = get_last_modified(external_path) {
+                if last_modified != *watched_modified {
+                    *watched_modified = last_modified;
+                    *modified = true;
+                }
+            }
+        }
+    });
+}
+
+/// This function opens the provided file in the external tool configured for its extension or, if there is none, in the default program for it.
+fn open_in_external_program(path: &Path) -> Result<()> {
+    let tool = path.extension().and_then(|extension| SETTINGS.read().unwrap().get_external_tool(&extension.to_string_lossy()));
+    match tool {
+        Some(tool) => match ProcessCommand::new(&tool).arg(path).spawn() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ErrorKind::ExternalToolCouldNotBeLaunched(tool).into()),
+        }
+        None => {
+            that_in_background(path);
+            Ok(())
+        }
+    }
+}
+
+/// This function returns the last modification time of the provided file, if it can be found.
+fn get_last_modified(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|metadata| metadata.modified()).ok()
+}
+
+/// This function reimports the data of a PackedFile from the file it was extracted to for editing in an external program.
+fn import_external_file(pack_file: &mut PackFile, path: &[String], external_path: &Path) -> Result<()> {
+    match pack_file.get_ref_mut_packed_file_by_path(path) {
+        Some(packed_file) => {
+            match packed_file.get_packed_file_type_by_path() {
+
+                // Tables we import them from TSV.
+                PackedFileType::DB | PackedFileType::Loc => {
+                    match packed_file.decode_return_ref_mut()? {
+                        DecodedPackedFile::DB(ref mut data) => *data = DB::import_tsv(&data.get_definition(), &external_path.to_path_buf(), &path[1])?,
+                        DecodedPackedFile::Loc(ref mut data) => *data = Loc::import_tsv(&data.get_definition(), &external_path.to_path_buf(), &TSV_NAME_LOC)?,
+                        _ => return Err(ErrorKind::PackedFileTypeUnknown.into()),
+                    }
+                    packed_file.encode_and_clean_cache()
+                },
+
+                _ => {
+                    let mut file = File::open(external_path).map_err(|_| Error::from(ErrorKind::IOGeneric))?;
+                    let mut data = vec![];
+                    file.read_to_end(&mut data).map_err(|_| Error::from(ErrorKind::IOGeneric))?;
+                    packed_file.set_raw_data(&data);
+                    Ok(())
+                }
+            }
+        }
+        None => Err(ErrorKind::PackedFileNotFound.into()),
+    }
+}
+
+/// This function returns a new PackFile with copies of the tables of the provided groups in the provided PackFile.
+///
+/// Their data is loaded to memory, so they don't depend on the file of the PackFile they came from.
+fn get_table_groups_pack_file(pack_file: &PackFile, groups: &[Vec<String>]) -> Result<PackFile> {
+    let mut tables = PackFile::new();
+    for packed_file in pack_file.get_ref_packed_files_all() {
+        if TableDiff::get_table_group(packed_file.get_path()).map_or(false, |group| groups.contains(&group)) {
+            let mut packed_file = packed_file.clone();
+            packed_file.encode()?;
+            packed_file.get_raw_data_and_keep_it()?;
+            tables.add_packed_file(&packed_file, true)?;
+        }
+    }
+    Ok(tables)
+}
+
+/// This function returns a mutable reference to the decoded AnimPack with the provided path.
+fn get_anim_pack_mut<'a>(pack_file: &'a mut PackFile, path: &[String]) -> Result<&'a mut AnimPack> {
+    match pack_file.get_ref_mut_packed_file_by_path(path) {
+        Some(packed_file) => match packed_file.decode_return_ref_mut()? {
+            DecodedPackedFile::AnimPack(data) => Ok(data),
+            _ => Err(ErrorKind::PackedFileTypeUnknown.into()),
         }
+        None => Err(ErrorKind::PackedFileNotFound.into()),
     }
 }
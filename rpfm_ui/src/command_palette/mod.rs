@@ -10,28 +10,74 @@
 
 /*!
 Module with all the code related to the command palette.
+
+Apart of actions, the command palette can be used to open PackedFiles of the open PackFile by writing part of their path.
+The paths are matched fuzzily, so writing the start of each folder (like `db/lnd/wh2`) is enough to find a file.
+
+It also exposes the actions of the view in the current tab, and keeps track of how many times each action has been used,
+so the most used ones can be shown first as "recently used". The pinned and recent searches of the Global Search can be repeated from it too.
 !*/
 
 use qt_widgets::QAction;
+use qt_widgets::QCheckBox;
+use qt_widgets::QPushButton;
+use qt_widgets::QTableView;
 
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 
 use qt_core::QFlags;
 use qt_core::AlignmentFlag;
+use qt_core::q_item_selection_model::SelectionFlag;
 
 use cpp_core::MutPtr;
 use cpp_core::Ref;
 
+use rpfm_error::ErrorKind;
+
+use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::COMMAND_PALETTE_RECENT_ACTIONS;
+
 use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{THREADS_COMMUNICATION_ERROR, Command, Response};
 use crate::ffi::add_to_q_list_safe;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::{tr, tre};
+use crate::pack_tree::PackTree;
+use crate::packedfile_views::{View, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::QString;
+use crate::ui_state::search_history::SavedSearch;
 use crate::UI_STATE;
+use crate::utils::{save_setting_string, show_dialog};
+use crate::views::table::utils::get_columns_in_sidebar_order;
 
 /// This is the character we always have to remove from the action names while comparing them.
 const THE_UNHOLY_ONE: &str = "&";
 
+/// Max amount of PackedFiles shown in the Command Palette at once. More than that is just noise.
+const MAX_PACKED_FILE_MATCHES: usize = 50;
+
+/// Max amount of actions shown in the "recently used" section of the Command Palette.
+const MAX_RECENT_ACTIONS: usize = 5;
+
+/// This enum represents the actions of the view in the current tab available for the Command Palette.
+enum ViewAction {
+
+    /// Select a cell of the column and scroll to it.
+    GoTo(MutPtr<QTableView>, i32),
+
+    /// Toggle one of the checkboxes of the column in the sidebar (hidden or frozen).
+    Toggle(MutPtr<QCheckBox>),
+
+    /// Trigger one of the actions of the view, like the ones in its contextual menu.
+    Trigger(MutPtr<QAction>),
+
+    /// Click one of the buttons of the view.
+    Click(MutPtr<QPushButton>),
+}
+
 /// This function returns the complete list of actions available for the Command Palette.
 pub unsafe fn get_actions(
 	app_ui: &AppUI,
@@ -45,6 +91,7 @@ pub unsafe fn get_actions(
     // `PackFile` menu.
     //-------------------------------------------------------------------------------//
 	actions.push((app_ui.packfile_new_packfile, shortcuts.menu_bar_packfile["new_packfile"].to_owned()));
+	actions.push((app_ui.packfile_new_packfile_from_folder, shortcuts.menu_bar_packfile["new_packfile_from_folder"].to_owned()));
 	actions.push((app_ui.packfile_open_packfile, shortcuts.menu_bar_packfile["open_packfile"].to_owned()));
 	actions.push((app_ui.packfile_save_packfile, shortcuts.menu_bar_packfile["save_packfile"].to_owned()));
 	actions.push((app_ui.packfile_save_packfile_as, shortcuts.menu_bar_packfile["save_packfile_as"].to_owned()));
@@ -59,6 +106,9 @@ pub unsafe fn get_actions(
     actions.push((app_ui.mymod_delete_selected, shortcuts.menu_bar_mymod["mymod_delete_selected"].to_owned()));
     actions.push((app_ui.mymod_install, shortcuts.menu_bar_mymod["mymod_install"].to_owned()));
     actions.push((app_ui.mymod_uninstall, shortcuts.menu_bar_mymod["mymod_uninstall"].to_owned()));
+    actions.push((app_ui.mymod_upload_to_workshop, shortcuts.menu_bar_mymod["mymod_upload_to_workshop"].to_owned()));
+    actions.push((app_ui.mymod_git_commit, shortcuts.menu_bar_mymod["mymod_git_commit"].to_owned()));
+    actions.push((app_ui.mymod_live_sync, shortcuts.menu_bar_mymod["mymod_live_sync"].to_owned()));
 
     //-------------------------------------------------------------------------------//
     // `View` menu.
@@ -70,9 +120,11 @@ pub unsafe fn get_actions(
     // `Game Selected` menu.
     //-------------------------------------------------------------------------------//
 	actions.push((app_ui.game_selected_launch_game, shortcuts.menu_bar_game_selected["launch_game"].to_owned()));
+    actions.push((app_ui.game_selected_launch_game_with_packfile, shortcuts.menu_bar_game_selected["launch_game_with_packfile"].to_owned()));
     actions.push((app_ui.game_selected_open_game_data_folder, shortcuts.menu_bar_game_selected["open_game_data_folder"].to_owned()));
 	actions.push((app_ui.game_selected_open_game_assembly_kit_folder, shortcuts.menu_bar_game_selected["open_game_assembly_kit_folder"].to_owned()));
     actions.push((app_ui.game_selected_open_config_folder, shortcuts.menu_bar_game_selected["open_config_folder"].to_owned()));
+    actions.push((app_ui.game_selected_search_vanilla_files, shortcuts.menu_bar_game_selected["search_vanilla_files"].to_owned()));
 
 	//-------------------------------------------------------------------------------//
     // `Special Stuff` menu.
@@ -110,6 +162,7 @@ pub unsafe fn get_actions(
 	actions.push((app_ui.about_about_qt, shortcuts.menu_bar_about["about_qt"].to_owned()));
 	actions.push((app_ui.about_about_rpfm, shortcuts.menu_bar_about["about_rpfm"].to_owned()));
 	actions.push((app_ui.about_open_manual, shortcuts.menu_bar_about["open_manual"].to_owned()));
+	actions.push((app_ui.about_start_tour, shortcuts.menu_bar_about["start_tour"].to_owned()));
 	actions.push((app_ui.about_patreon_link, shortcuts.menu_bar_about["support_me_on_patreon"].to_owned()));
 	actions.push((app_ui.about_check_updates, shortcuts.menu_bar_about["check_updates"].to_owned()));
 	actions.push((app_ui.about_check_schema_updates, shortcuts.menu_bar_about["check_schema_updates"].to_owned()));
@@ -127,8 +180,12 @@ pub unsafe fn get_actions(
 	actions.push((pack_file_contents_ui.context_menu_mass_import_tsv, shortcuts.packfile_contents_tree_view["mass_import_tsv"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_mass_export_tsv, shortcuts.packfile_contents_tree_view["mass_export_tsv"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_rename, shortcuts.packfile_contents_tree_view["rename"].to_owned()));
+	actions.push((pack_file_contents_ui.context_menu_mass_rename, shortcuts.packfile_contents_tree_view["mass_rename"].to_owned()));
+	actions.push((pack_file_contents_ui.context_menu_undo_move, shortcuts.packfile_contents_tree_view["undo_move"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_delete, shortcuts.packfile_contents_tree_view["delete"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_extract, shortcuts.packfile_contents_tree_view["extract"].to_owned()));
+	actions.push((pack_file_contents_ui.context_menu_extract_with_path_rewrite, shortcuts.packfile_contents_tree_view["extract_with_path_rewrite"].to_owned()));
+	actions.push((pack_file_contents_ui.context_menu_extract_converted, shortcuts.packfile_contents_tree_view["extract_converted"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_open_decoder, shortcuts.packfile_contents_tree_view["open_in_decoder"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_open_dependency_manager, shortcuts.packfile_contents_tree_view["open_packfiles_list"].to_owned()));
 	actions.push((pack_file_contents_ui.context_menu_open_containing_folder, shortcuts.packfile_contents_tree_view["open_containing_folder"].to_owned()));
@@ -140,40 +197,366 @@ pub unsafe fn get_actions(
 	actions
 }
 
-/// This function loads the entire set of available and enabled actions to the Command Palette.
-pub unsafe fn load_actions(app_ui: &mut AppUI, pack_file_contents_ui: &PackFileContentsUI) {
+/// This function returns the list of actions available for the view in the current tab, if it has any.
+///
+/// For tables, these are the actions of its contextual menu and the actions over its columns. For the decoder, its buttons.
+unsafe fn get_view_actions(app_ui: &AppUI) -> Vec<(String, ViewAction)> {
+	let mut actions = vec![];
+	let current_index = app_ui.tab_bar_packed_file.current_index();
+	if current_index == -1 {
+		return actions;
+	}
+
+	if let Some(packed_file_view) = UI_STATE.get_open_packedfiles().iter().find(|x| app_ui.tab_bar_packed_file.index_of(x.get_mut_widget()) == current_index) {
+		match packed_file_view.get_view() {
+			ViewType::Internal(View::Table(view)) => {
+				let table = view.get_ref_table();
+				let context_menu_actions = vec![
+					table.get_mut_ptr_context_menu_add_rows(),
+					table.get_mut_ptr_context_menu_insert_rows(),
+					table.get_mut_ptr_context_menu_delete_rows(),
+					table.get_mut_ptr_context_menu_move_rows_up(),
+					table.get_mut_ptr_context_menu_move_rows_down(),
+					table.get_mut_ptr_context_menu_clone_and_append(),
+					table.get_mut_ptr_context_menu_clone_and_insert(),
+					table.get_mut_ptr_context_menu_copy(),
+					table.get_mut_ptr_context_menu_copy_as_lua_table(),
+					table.get_mut_ptr_context_menu_paste(),
+					table.get_mut_ptr_context_menu_invert_selection(),
+					table.get_mut_ptr_context_menu_reset_selection(),
+					table.get_mut_ptr_context_menu_rewrite_selection(),
+					table.get_mut_ptr_context_menu_generate_loc_variants(),
+					table.get_mut_ptr_context_menu_undo(),
+					table.get_mut_ptr_context_menu_redo(),
+					table.get_mut_ptr_context_menu_import_tsv(),
+					table.get_mut_ptr_context_menu_export_tsv(),
+					table.get_mut_ptr_context_menu_export_visible_rows(),
+					table.get_mut_ptr_context_menu_resize_columns(),
+					table.get_mut_ptr_context_menu_sidebar(),
+					table.get_mut_ptr_context_menu_history(),
+					table.get_mut_ptr_context_menu_summary_row(),
+					table.get_mut_ptr_context_menu_column_statistics(),
+					table.get_mut_ptr_context_menu_compare_with(),
+					table.get_mut_ptr_context_menu_set_column_unit(),
+					table.get_mut_ptr_context_menu_edit_in_popup(),
+					table.get_mut_ptr_context_menu_word_wrap(),
+					table.get_mut_ptr_context_menu_refresh_reference_data(),
+					table.get_mut_ptr_context_menu_search(),
+				];
+
+				for action in context_menu_actions.into_iter().flatten().filter(|x| x.is_enabled()) {
+					let name = action.text().to_std_string().replace(THE_UNHOLY_ONE, "");
+					actions.push((tre("command_palette_view_action", &[&name]), ViewAction::Trigger(action)));
+				}
+
+				let table_view_primary = match table.get_mut_ptr_table_view_primary() {
+					Some(table_view_primary) => table_view_primary,
+					None => return actions,
+				};
+
+				let definition = table.get_ref_table_definition();
+				let fields = definition.get_fields_processed();
+				let hide_show_checkboxes = table.get_hide_show_checkboxes();
+				let freeze_checkboxes = table.get_freeze_checkboxes();
+
+				// The checkboxes are in the same order as the columns in the sidebar.
+				for (sidebar_index, column) in get_columns_in_sidebar_order(&definition).iter().enumerate() {
+					let name = fields[*column as usize].get_name();
+					actions.push((tre("command_palette_column_go_to", &[name]), ViewAction::GoTo(table_view_primary, *column)));
+
+					if let Some(checkbox) = hide_show_checkboxes.get(sidebar_index) {
+						actions.push((tre("command_palette_column_toggle_hidden", &[name]), ViewAction::Toggle(*checkbox)));
+					}
+
+					if let Some(checkbox) = freeze_checkboxes.get(sidebar_index) {
+						actions.push((tre("command_palette_column_toggle_frozen", &[name]), ViewAction::Toggle(*checkbox)));
+					}
+				}
+			}
+
+			ViewType::Internal(View::Decoder(view)) => {
+				let buttons = vec![
+					view.get_mut_ptr_test_definition_button(),
+					view.get_mut_ptr_import_from_assembly_kit_button(),
+					view.get_mut_ptr_clear_definition_button(),
+					view.get_mut_ptr_save_button(),
+				];
+
+				for button in buttons.into_iter().flatten().filter(|x| x.is_enabled()) {
+					let name = button.text().to_std_string().replace(THE_UNHOLY_ONE, "");
+					actions.push((tre("command_palette_view_action", &[&name]), ViewAction::Click(button)));
+				}
+			}
+
+			ViewType::Internal(View::DependencyManager(view)) => {
+				let buttons = vec![
+					view.get_mut_ptr_add_button(),
+					view.get_mut_ptr_browse_button(),
+					view.get_mut_ptr_remove_button(),
+				];
+
+				for button in buttons {
+					let name = button.text().to_std_string().replace(THE_UNHOLY_ONE, "");
+					actions.push((tre("command_palette_view_action", &[&name]), ViewAction::Click(button)));
+				}
+			}
+
+			ViewType::Internal(View::Hex(view)) => {
+				let buttons = vec![
+					view.get_mut_ptr_overwrite_button(),
+					view.get_mut_ptr_insert_button(),
+					view.get_mut_ptr_delete_button(),
+					view.get_mut_ptr_search_button(),
+				];
+
+				for button in buttons {
+					let name = button.text().to_std_string().replace(THE_UNHOLY_ONE, "");
+					actions.push((tre("command_palette_view_action", &[&name]), ViewAction::Click(button)));
+				}
+			}
+
+			_ => {}
+		}
+	}
+
+	actions
+}
+
+/// This function returns the searches of the Global Search available for the Command Palette, pinned ones first, with their names.
+///
+/// The boolean is true if the search is pinned.
+fn get_saved_searches() -> Vec<(String, SavedSearch, bool)> {
+	let search_history = UI_STATE.get_search_history();
+	let mut searches = search_history.get_pinned().iter()
+		.map(|search| (tre("command_palette_search", &[&search.get_name()]), search.clone(), true))
+		.collect::<Vec<(String, SavedSearch, bool)>>();
+
+	for search in search_history.get_recent() {
+		if !search_history.is_pinned(search) {
+			searches.push((tre("command_palette_search", &[&search.get_name()]), search.clone(), false));
+		}
+	}
+
+	searches
+}
+
+/// This function returns the paths of all the PackedFiles in the open PackFile, joined with `/`.
+pub unsafe fn get_packed_file_paths() -> Vec<String> {
+	CENTRAL_COMMAND.send_message_qt(Command::GetPackFileDataForTreeView);
+	let response = CENTRAL_COMMAND.recv_message_qt();
+	match response {
+		Response::PackFileInfoVecPackedFileInfo((_, packed_files_info)) => packed_files_info.iter()
+			.map(|packed_file_info| packed_file_info.path.join("/"))
+			.collect(),
+		_ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+	}
+}
+
+/// This function loads the available and enabled actions matching the provided filter to the Command Palette.
+///
+/// If the filter is not empty, the PackedFiles whose path fuzzy-matches it are loaded too, best matches first.
+pub unsafe fn load_actions(app_ui: &mut AppUI, pack_file_contents_ui: &PackFileContentsUI, packed_file_paths: &[String], filter: &str) {
 	app_ui.command_palette_completer_model.clear();
 	let and = QString::from_std_str(THE_UNHOLY_ONE);
+	let filter_lower = filter.to_lowercase();
 
-	for (mut action_name, action_shortcut) in get_actions(app_ui, pack_file_contents_ui).iter_mut()
+	let mut available_actions = get_actions(app_ui, pack_file_contents_ui).iter_mut()
 		.filter(|x| x.0.is_enabled())
-		.map(|x| (x.0.text(), x.1.to_owned())) {
+		.map(|x| {
+			let mut action_name = x.0.text();
+			action_name.remove_q_string(&and);
+			(action_name.to_std_string(), x.1.to_owned())
+		})
+		.collect::<Vec<(String, String)>>();
+
+	// If we have a view open in the current tab, add its actions too.
+	available_actions.append(&mut get_view_actions(app_ui).into_iter().map(|(name, _)| (name, String::new())).collect());
+
+	// Before writing anything, show the most used actions first.
+	if filter.is_empty() {
+		let recently_used_label = tr("command_palette_recently_used");
+		for (action_name, _) in get_recent_actions().iter()
+			.filter_map(|(action_name, _)| available_actions.iter().find(|(name, _)| name == action_name))
+			.take(MAX_RECENT_ACTIONS) {
+			add_action_to_model(app_ui, &QString::from_std_str(action_name), &recently_used_label);
+		}
+	}
 
-		let action_data = QListOfQStandardItem::new().into_ptr();
-		action_name.remove_q_string(&and);
+	for (action_name, action_shortcut) in &available_actions {
+		if action_name.to_lowercase().contains(&filter_lower) {
+			add_action_to_model(app_ui, &QString::from_std_str(action_name), action_shortcut);
+		}
+	}
 
-		let mut action_name = QStandardItem::from_q_string(&action_name).into_ptr();
-		action_name.set_text_alignment(QFlags::from(AlignmentFlag::AlignVCenter));
+	let pinned_search_label = tr("command_palette_pinned_search");
+	let recent_search_label = tr("command_palette_recent_search");
+	for (name, _, is_pinned) in get_saved_searches() {
+		if name.to_lowercase().contains(&filter_lower) {
+			add_action_to_model(app_ui, &QString::from_std_str(&name), if is_pinned { &pinned_search_label } else { &recent_search_label });
+		}
+	}
 
-		let mut action_shortcut = QStandardItem::from_q_string(&QString::from_std_str(&action_shortcut)).into_ptr();
-		action_shortcut.set_text_alignment(AlignmentFlag::AlignVCenter | AlignmentFlag::AlignRight);
+	// Only show PackedFiles once the user starts writing, as there may be thousands of them.
+	if !filter.is_empty() {
+		let mut matches = packed_file_paths.iter()
+			.filter_map(|path| get_fuzzy_score(&filter_lower, path).map(|score| (score, path)))
+			.collect::<Vec<(i64, &String)>>();
+		matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
 
-		add_to_q_list_safe(action_data, action_name);
-		add_to_q_list_safe(action_data, action_shortcut);
-		app_ui.command_palette_completer_model.append_row_q_list_of_q_standard_item(action_data.as_ref().unwrap());
+		let packed_file_label = tr("command_palette_packed_file");
+		for (_, path) in matches.iter().take(MAX_PACKED_FILE_MATCHES) {
+			add_action_to_model(app_ui, &QString::from_std_str(path), &packed_file_label);
+		}
 	}
 
 	app_ui.command_palette_completer_view.set_column_width(0, 360);
 }
 
-/// This function executes the action provided (if exists).
-pub unsafe fn exec_action(app_ui: &AppUI, pack_file_contents_ui: &PackFileContentsUI, action_name: Ref<QString>) {
+/// This function adds a row with the provided action name and shortcut to the model of the Command Palette.
+unsafe fn add_action_to_model(app_ui: &mut AppUI, action_name: &QString, action_shortcut: &str) {
+	let action_data = QListOfQStandardItem::new().into_ptr();
+
+	let mut action_name = QStandardItem::from_q_string(action_name).into_ptr();
+	action_name.set_text_alignment(QFlags::from(AlignmentFlag::AlignVCenter));
+
+	let mut action_shortcut = QStandardItem::from_q_string(&QString::from_std_str(action_shortcut)).into_ptr();
+	action_shortcut.set_text_alignment(AlignmentFlag::AlignVCenter | AlignmentFlag::AlignRight);
+
+	add_to_q_list_safe(action_data, action_name);
+	add_to_q_list_safe(action_data, action_shortcut);
+	app_ui.command_palette_completer_model.append_row_q_list_of_q_standard_item(action_data.as_ref().unwrap());
+}
+
+/// This function executes the action provided (if exists), repeats the search provided (if exists), or opens the PackedFile with the provided path (if exists).
+pub unsafe fn exec_action(
+	app_ui: &AppUI,
+	pack_file_contents_ui: &PackFileContentsUI,
+	global_search_ui: &mut GlobalSearchUI,
+	packed_file_paths: &[String],
+	action_name: Ref<QString>
+) {
 	let and = QString::from_std_str(THE_UNHOLY_ONE);
 	for (mut action, _) in get_actions(app_ui, pack_file_contents_ui) {
 		let mut name = action.text();
 		name.remove_q_string(&and);
 		if QString::compare_2_q_string(name.as_ref(), action_name) == 0 {
+			add_recent_action(&name.to_std_string());
 			action.trigger();
+			return;
+		}
+	}
+
+	let action_name = action_name.to_std_string();
+	for (name, view_action) in get_view_actions(app_ui) {
+		if name == action_name {
+			add_recent_action(&name);
+			match view_action {
+				ViewAction::GoTo(mut table_view, column) => {
+					let current_index = table_view.current_index();
+					let row = if current_index.is_valid() { current_index.row() } else { 0 };
+					let index = table_view.model().index_2a(row, column);
+					if index.is_valid() {
+						table_view.set_current_index(&index);
+						table_view.scroll_to_1a(&index);
+					}
+					table_view.set_focus_0a();
+				}
+
+				// Toggling the checkboxes triggers the same slots as clicking them in the sidebar.
+				ViewAction::Toggle(mut checkbox) => checkbox.set_checked(!checkbox.is_checked()),
+				ViewAction::Trigger(mut action) => action.trigger(),
+				ViewAction::Click(mut button) => button.click(),
+			}
+			return;
+		}
+	}
+
+	if let Some((_, search, _)) = get_saved_searches().into_iter().find(|(name, _, _)| *name == action_name) {
+		let mut pack_file_contents_ui = *pack_file_contents_ui;
+		global_search_ui.global_search_dock_widget.show();
+		global_search_ui.load_saved_search(&search);
+		global_search_ui.search(&mut pack_file_contents_ui);
+		return;
+	}
+
+	if packed_file_paths.contains(&action_name) {
+		let path = action_name.split('/').map(|x| x.to_owned()).collect::<Vec<String>>();
+		let mut tree_view = pack_file_contents_ui.packfile_contents_tree_view;
+
+		// Note: the selection already triggers the open PackedFile action.
+		match tree_view.expand_treeview_to_item(&path) {
+			Some(model_index) => {
+				let model_index = model_index.as_ref().unwrap();
+				if model_index.is_valid() {
+					let mut selection_model = tree_view.selection_model();
+					tree_view.scroll_to_1a(model_index);
+					selection_model.select_q_model_index_q_flags_selection_flag(model_index, QFlags::from(SelectionFlag::ClearAndSelect));
+				}
+			}
+			None => show_dialog(app_ui.main_window, ErrorKind::PackedFileNotInFilter, false),
+		}
+	}
+}
+
+/// This function returns the actions used from the Command Palette, with the amount of times each one has been used, most used first.
+///
+/// They're stored in the settings, one per line, as the amount of uses and the name of the action separated by a tab.
+fn get_recent_actions() -> Vec<(String, u32)> {
+	let mut recent_actions = SETTINGS.read().unwrap().settings_string[COMMAND_PALETTE_RECENT_ACTIONS].lines()
+		.filter_map(|line| {
+			let mut data = line.splitn(2, '\t');
+			let uses = data.next()?.parse::<u32>().ok()?;
+			let name = data.next()?;
+			Some((name.to_owned(), uses))
+		})
+		.collect::<Vec<(String, u32)>>();
+
+	recent_actions.sort_by(|a, b| b.1.cmp(&a.1));
+	recent_actions
+}
+
+/// This function adds one use to the provided action in the list of actions used from the Command Palette, and saves it.
+fn add_recent_action(action_name: &str) {
+	let mut recent_actions = get_recent_actions();
+	match recent_actions.iter_mut().find(|(name, _)| name == action_name) {
+		Some((_, uses)) => *uses += 1,
+		None => recent_actions.push((action_name.to_owned(), 1)),
+	}
+
+	let recent_actions = recent_actions.iter()
+		.map(|(name, uses)| format!("{}\t{}", uses, name))
+		.collect::<Vec<String>>()
+		.join("\n");
+	save_setting_string(COMMAND_PALETTE_RECENT_ACTIONS, &recent_actions);
+}
+
+/// This function returns how well the provided path matches the provided filter, or `None` if it doesn't match at all.
+///
+/// The filter must be lowercase. It matches if all its characters are in the path in the same order, and the score is higher
+/// the more of them are consecutive or at the start of a folder/word, and the shorter the path is.
+fn get_fuzzy_score(filter: &str, path: &str) -> Option<i64> {
+	let path = path.to_lowercase().chars().collect::<Vec<char>>();
+	let mut score = 0;
+	let mut last_match: Option<usize> = None;
+	let mut index = 0;
+
+	for character in filter.chars() {
+		let position = path[index..].iter().position(|x| *x == character)? + index;
+
+		score += 1;
+		if position == 0 || path[position - 1] == '/' || path[position - 1] == '_' || path[position - 1] == '.' {
+			score += 8;
 		}
+
+		if let Some(last_match) = last_match {
+			if last_match + 1 == position {
+				score += 5;
+			}
+		}
+
+		last_match = Some(position);
+		index = position + 1;
 	}
+
+	Some(score * 100 - path.len() as i64)
 }
@@ -17,26 +17,40 @@ use qt_core::QEventLoop;
 use crossbeam::{Receiver, Sender, unbounded};
 
 use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::ops::Range;
 use std::path::PathBuf;
 use std::process::exit;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use rpfm_error::Error;
 
+use rpfm_lib::diff::{PackFileDiff, TableDiff};
 use rpfm_lib::global_search::GlobalSearch;
-use rpfm_lib::global_search::MatchHolder;
+use rpfm_lib::global_search::{MatchHolder, PackedFileMatches};
+use rpfm_lib::jobs::Job;
+use rpfm_lib::mymod::{MyModConfig, git::GitStatus, template::MyModTemplate};
+use rpfm_lib::optimizer::OptimizerReport;
 use rpfm_lib::packedfile::ca_vp8::{CaVp8, SupportedFormats};
 use rpfm_lib::packedfile::DecodedPackedFile;
 use rpfm_lib::packedfile::image::Image;
-use rpfm_lib::packedfile::table::{anim_fragment::AnimFragment, animtable::AnimTable, db::DB, loc::Loc, matched_combat::MatchedCombat};
+use rpfm_lib::packedfile::table::{anim_fragment::AnimFragment, animtable::AnimTable, db::{DB, DecodeFailureReport}, loc::Loc, matched_combat::MatchedCombat, Table};
 use rpfm_lib::packedfile::text::Text;
 use rpfm_lib::packedfile::rigidmodel::RigidModel;
+use rpfm_lib::packedfile::sound_bank::SoundBank;
+use rpfm_lib::packedfile::unit_variant::UnitVariant;
 use rpfm_lib::packfile::{PackFileInfo, PathType, PFHFileType};
+use rpfm_lib::packfile::checksums::ChecksumReport;
+use rpfm_lib::packfile::live_sync::LiveSyncReport;
 use rpfm_lib::packfile::packedfile::{PackedFile, PackedFileInfo};
-use rpfm_lib::schema::{APIResponseSchema, Definition, Schema};
+use rpfm_lib::schema::{APIResponseSchema, Definition, Schema, TableDefinitionChanges};
+use rpfm_lib::schema::patch::FieldUnit;
 use rpfm_lib::settings::*;
 use rpfm_lib::template::Template;
 
 use crate::app_ui::NewPackedFile;
+use crate::diagnostics_ui::Diagnostics;
 use crate::views::table::TableType;
 use crate::ui_state::shortcuts::Shortcuts;
 use self::network::*;
@@ -54,15 +68,27 @@ pub const THREADS_SENDER_ERROR: &str = "Error in thread communication system. Se
 /// This struct contains the senders and receivers necessary to communicate both, backend and frontend threads.
 ///
 /// You can use them by using the send/recv functions implemented for it.
+///
+/// Commands sent to the background thread are tagged with an id, and its responses are tagged with the id of the command
+/// that caused them. This allows us to discard the responses to commands nobody is waiting for anymore.
 pub struct CentralCommand {
-    sender_qt: Sender<Command>,
-    sender_rust: Sender<Response>,
+    sender_qt: Sender<(u64, Command)>,
+    sender_rust: Sender<(u64, Response)>,
     sender_qt_to_network: Sender<Command>,
     sender_network_to_qt: Sender<Response>,
-    receiver_qt: Receiver<Response>,
-    receiver_rust: Receiver<Command>,
+    receiver_qt: Receiver<(u64, Response)>,
+    receiver_rust: Receiver<(u64, Command)>,
     receiver_qt_to_network: Receiver<Command>,
     receiver_network_to_qt: Receiver<Response>,
+
+    /// Id of the next command sent to the background thread.
+    next_command_id: AtomicU64,
+
+    /// Id of the command the background thread is currently processing.
+    current_command_id: AtomicU64,
+
+    /// Ranges of ids of the commands whose responses must be discarded.
+    discarded_command_ids: Mutex<Vec<Range<u64>>>,
 }
 
 /// This enum defines the commands (messages) you can send to the background thread in order to execute actions.
@@ -81,12 +107,18 @@ pub enum Command {
     /// This command is used when we want to create a new `PackFile`.
     NewPackFile,
 
+    /// This command is used when we want to create a new `PackFile` from the files of a folder.
+    NewPackFileFromFolder(PathBuf),
+
     /// This command is used when we want to save our currently open `PackFile`.
     SavePackFile,
 
     /// This command is used when we want to save our currently open `PackFile` as another `PackFile`.
     SavePackFileAs(PathBuf),
 
+    /// This command is used when we want to save a backup of our currently open `PackFile` to the autosaves folder.
+    AutosavePackFile,
+
     /// This command is used when we want to save our settings to disk. It requires the settings to save.
     SetSettings(Settings),
 
@@ -117,6 +149,9 @@ pub enum Command {
     /// This command is used when we want to perform an update over a `Global Search`. It requires the search info.
     GlobalSearchUpdate(GlobalSearch, Vec<PathType>),
 
+    /// This command is used when we want to perform a read-only search over the dependency database. It requires the search info.
+    GlobalSearchDependencies(GlobalSearch),
+
     /// This command is used when we want to change the `Game Selected`. It contains the name of the game to select.
     SetGameSelected(String),
 
@@ -131,6 +166,9 @@ pub enum Command {
     /// It contains the path of the source files, if needed.
     UpdateCurrentSchemaFromAssKit(Option<PathBuf>),
 
+    /// This command is used when we want to get the definition of a table from the game selected's Assembly Kit. It contains the name of the table.
+    GetDefinitionFromAssKit(String),
+
     /// This command is used when we want to trigger an optimization pass over the currently open `PackFile`.
     OptimizePackFile,
 
@@ -158,6 +196,24 @@ pub enum Command {
     /// This command is used when we want to update our schemas.
     UpdateSchemas,
 
+    /// This command is used when we want to check if there is an Schema update available and, if there is one, update our schemas.
+    AutoUpdateSchemas,
+
+    /// This command is used when we want to export the schemas of all games to a bundle in the provided path.
+    ExportSchemaBundle(PathBuf),
+
+    /// This command is used when we want to replace our schemas with the ones of the bundle in the provided path.
+    ImportSchemaBundle(PathBuf),
+
+    /// This command is used when we want to publish or update the MyMod with the provided PackFile path in the Workshop, with the provided config and changelog.
+    UploadMyModToWorkshop(PathBuf, MyModConfig, String),
+
+    /// This command is used when we want to know why the DB Table with the provided path fails to decode. Only tables from vanilla PackFiles are reported.
+    GetDecodeFailureReport(Vec<String>),
+
+    /// This command is used when we want to get the url of a pre-filled issue in the schema repository for the provided decode failure.
+    ReportBrokenDecode(DecodeFailureReport),
+
     /// This command is used when we want to know if there is a Dependency Database loaded in memory.
     IsThereADependencyDatabase,
 
@@ -171,12 +227,17 @@ pub enum Command {
 
     /// This command is used when we want to add one or more Files to our currently open `PackFile`.
     ///
-    /// It requires the list of filesystem paths to add, and their path once they're inside the `PackFile`.
-    AddPackedFiles((Vec<PathBuf>, Vec<Vec<String>>)),
+    /// It requires the list of filesystem paths to add, their path once they're inside the `PackFile`, and if each one should overwrite
+    /// the `PackedFile` already in its path, if any, or be added with a numeric suffix instead. It returns the paths the files got added to.
+    AddPackedFiles((Vec<PathBuf>, Vec<Vec<String>>, Vec<bool>)),
 
     /// This command is used when we want to decode a PackedFile to be shown on the UI.
     DecodePackedFile(Vec<String>),
 
+    /// This command is used when we want to decode a PackedFile of an extra `PackFile` to be shown, read-only, on the UI.
+    /// It requires the path of the extra `PackFile` and the path of the PackedFile within it. Only tables are supported.
+    DecodePackedFileExtra(PathBuf, Vec<String>),
+
     /// This command is used when we want to save an edited `PackedFile` back to the `PackFile`.
     SavePackedFileFromView(Vec<String>, DecodedPackedFile),
 
@@ -186,12 +247,45 @@ pub enum Command {
     /// This command is used when we want to delete one or more PackedFiles from a PackFile. It contains the PathType of each PackedFile to delete.
     DeletePackedFiles(Vec<PathType>),
 
-    /// This command is used when we want to extract one or more PackedFiles from a PackFile. It contains the PathTypes to extract and the extraction path.
-    ExtractPackedFiles(Vec<PathType>, PathBuf),
+    /// This command is used when we want to bind the PackedFiles of a folder to the rows of a table. Requires the path of the folder,
+    /// the path of the table, the key and value columns, and the patterns to generate the key and the value from each path.
+    BindFolderToTable((Vec<String>, Vec<String>, usize, usize, String, String)),
+
+    /// This command is used when we want to get the paths of the PackedFiles deleted since the last save of the open PackFile.
+    GetTrash,
+
+    /// This command is used when we want to restore PackedFiles from the trash into the open PackFile. Requires the paths of the PackedFiles.
+    RestoreFromTrash(Vec<Vec<String>>),
+
+    /// This command is used when we want to get the paths of the assets of the open PackFile nothing references.
+    GetOrphanedAssets,
+
+    /// This command is used when we want to take a snapshot of the open PackFile, so we can roll back to it later in the same session.
+    CreateSnapshot,
+
+    /// This command is used when we want to get the snapshots taken of the open PackFile.
+    GetSnapshots,
+
+    /// This command is used when we want to roll back the open PackFile to one of its snapshots. Requires the index of the snapshot.
+    RestoreSnapshot(usize),
+
+    /// This command is used when we want to delete one of the snapshots of the open PackFile. Requires the index of the snapshot.
+    DeleteSnapshot(usize),
+
+    /// This command is used when we want to extract one or more PackedFiles from a PackFile. It contains the PathTypes to extract, the extraction path,
+    /// an optional path rewrite rule (regex, replacement), and if the PackedFiles should be converted to formats usable outside the game.
+    ExtractPackedFiles(Vec<PathType>, PathBuf, Option<(String, String)>, bool),
+
+    /// This command is used when we want to extract one or more PackedFiles from an extra `PackFile`.
+    /// It contains the path of the extra `PackFile`, the PathTypes to extract and the extraction path.
+    ExtractPackedFilesExtra(PathBuf, Vec<PathType>, PathBuf),
 
     /// This command is used when we want to rename one or more PackedFiles in a PackFile. It contains a Vec with their original PathType and their new name.
     RenamePackedFiles(Vec<(PathType, String)>),
 
+    /// This command is used when we want to move one or more PackedFiles in a PackFile. It contains a Vec with their original PathType and their new full path.
+    MovePackedFiles(Vec<(PathType, Vec<String>)>),
+
     /// This command is used when we want to import a large amount of table-like files from TSV files.
     MassImportTSV(Vec<PathBuf>, Option<String>),
 
@@ -204,15 +298,21 @@ pub enum Command {
     /// This command is used when we want to know if a PackedFile exists in the currently open PackFile.
     PackedFileExists(Vec<String>),
 
+    /// This command is used when we want to know which of the provided paths are already used by PackedFiles in the currently open PackFile.
+    GetExistingPackedFilePaths(Vec<Vec<String>>),
+
     /// This command is used when we want to get the table names (the folder of the tables) of all DB files in our dependency PackFiles.
     GetTableListFromDependencyPackFile,
 
     /// This command is used when we want to get the version of the table provided that's compatible with the version of the game we currently have installed.
     GetTableVersionFromDependencyPackFile(String),
 
-    /// This command is used when we want to check the integrity of all the DB Tables in the PackFile.
+    /// This command is used when we want to check the integrity of all the DB Tables in the PackFile. It returns the findings not ignored, as ignore list entries.
     DBCheckTableIntegrity,
 
+    /// This command is used when we want to add entries to the list of ignored diagnostic findings of the PackFile.
+    AddDiagnosticsIgnored(Vec<String>),
+
     /// This command is used when we want to merge multiple compatible tables into one. The contents of this are as follows:
     /// - Vec<Vec<String>>: List of paths to merge.
     /// - String: Name of the new merged table.
@@ -228,18 +328,25 @@ pub enum Command {
     /// This command is used when we want to replace all matches in a Global Search.
     GlobalSearchReplaceAll(GlobalSearch),
 
-    /// This command is used when we want to add entire folders to the PackFile. The tuples contains their path in disk and their starting path in the PackFile.
-    AddPackedFilesFromFolder(Vec<(PathBuf, Vec<String>)>),
 
     /// This command is used to decode all tables referenced by columns in the provided definition and return their data.
     /// It requires the definition of the table to get the reference data from and the list of PackedFiles to ignore.
     GetReferenceDataFromDefinition(Definition, Vec<Vec<String>>),
 
+    /// This command is used when we want to get the distinct values each string column of the provided table has in the dependency database.
+    GetVanillaColumnValues(String, Definition),
+
+    /// This command is used to get the value each key of the provided key-value table has in the dependency database.
+    GetVanillaKeyValues(String),
+
     /// This command is used to get the list of PackFiles that are marked as dependency of our PackFile.
     GetDependencyPackFilesList,
 
-    /// This command is used to set the list of PackFiles that are marked as dependency of our PackFile.
-    SetDependencyPackFilesList(Vec<String>),
+    /// This command is used to get the entries of the dependency manager of our PackFile, enabled and disabled.
+    GetDependencyManagerEntries,
+
+    /// This command is used to set the entries of the dependency manager of our PackFile. The enabled ones become the list of PackFiles marked as dependency.
+    SetDependencyManagerEntries(Vec<(String, bool)>),
 
     /// This command is used to get a full PackedFile to the UI. Requires the path of the PackedFile.
     GetPackedFile(Vec<String>),
@@ -247,9 +354,18 @@ pub enum Command {
     /// This command is used to change the format of a ca_vp8 video packedfile. Requires the path of the PackedFile and the new format.
     SetCaVp8Format((Vec<String>, SupportedFormats)),
 
+    /// This command is used to replace the data of a PackedFile with the provided raw data. Requires the path of the PackedFile and the new data.
+    SetPackedFileRawData((Vec<String>, Vec<u8>)),
+
     /// This command is used to save the provided schema to disk.
     SaveSchema(Schema),
 
+    /// This command is used to set the unit of a column in the local patch of the schema, and save it. Requires the table name, the column name and the unit, or None to remove it.
+    SetColumnUnit((String, String, Option<FieldUnit>)),
+
+    /// This command is used to change the description of a field in the schema, and save it. Requires the table name (`loc` for Loc tables), its version, the field name and the description.
+    SetFieldDescription((String, i32, String, String)),
+
     /// This command is used to save to encoded data the cache of the provided paths, and then clean up the cache.
     CleanCache(Vec<Vec<String>>),
 
@@ -268,9 +384,30 @@ pub enum Command {
     /// This command is used to save a PackedFile from an external program. Requires both, internal and external paths of the PackedFile.
     SavePackedFileFromExternalView((Vec<String>, PathBuf)),
 
+    /// This command is used to reimport a PackedFile open in an external program if its external file has been saved since the last check. Requires both, internal and external paths of the PackedFile.
+    ReimportExternalFileIfModified((Vec<String>, PathBuf)),
+
+    /// This command is used to stop watching the external file of a PackedFile once its view is closed. Requires the external path of the PackedFile.
+    StopWatchingExternalFile(PathBuf),
+
     /// This command is used to unpack an AnimPack into the current PackFile. Requires the path of the PackedFile to unpack.
     AnimPackUnpack(Vec<String>),
 
+    /// This command is used to unpack some of the PackedFiles of an AnimPack into the current PackFile. Requires the path of the AnimPack, and the paths of the PackedFiles within it.
+    AnimPackUnpackFiles((Vec<String>, Vec<Vec<String>>)),
+
+    /// This command is used to add files from disk to an AnimPack, replacing the ones with the same path. Requires the path of the AnimPack, and the path of each file on disk and within the AnimPack.
+    AnimPackAddFiles((Vec<String>, Vec<(PathBuf, Vec<String>)>)),
+
+    /// This command is used to remove PackedFiles from an AnimPack. Requires the path of the AnimPack, and the paths of the PackedFiles within it.
+    AnimPackRemoveFiles((Vec<String>, Vec<Vec<String>>)),
+
+    /// This command is used to extract PackedFiles from an AnimPack to disk. Requires the path of the AnimPack, the paths of the PackedFiles within it, and the folder to extract them to.
+    AnimPackExtractFiles((Vec<String>, Vec<Vec<String>>, PathBuf)),
+
+    /// This command is used to put back into an AnimPack the PackedFiles of the current PackFile with the same path as the ones inside it. Requires the path of the AnimPack.
+    AnimPackRepack(Vec<String>),
+
     /// This command is used to create a dummy AnimPack, so the game loads it's anim files from loose files instead of from the big animpack.
     GenerateDummyAnimPack,
 
@@ -279,6 +416,70 @@ pub enum Command {
 
     /// This command is used to update the templates.
     UpdateTemplates,
+
+    /// This command is used to apply a MyMod template to the currently open PackFile. Requires the name of the MyMod and the path of its private folder.
+    ApplyMyModTemplate(MyModTemplate, String, PathBuf),
+
+    /// This command is used to create a Git repository in the private folder of a MyMod, if it doesn't have one. Requires the path of its private folder.
+    InitMyModGitRepo(PathBuf),
+
+    /// This command is used to get the Git status of the PackedFiles of the currently open MyMod. Requires the path of its private folder.
+    GetMyModGitStatus(PathBuf),
+
+    /// This command is used to commit the currently open MyMod to its Git repository. Requires the path of its private folder and the commit message.
+    CommitMyModGit(PathBuf, String),
+
+    /// This command is used to start syncing the provided assets folder of a MyMod into the open PackFile.
+    LiveSyncStart(PathBuf),
+
+    /// This command is used to sync into the open PackFile the changes done to the assets folder being synced. Requires the paths of the PackedFiles open in the UI, which are not synced.
+    LiveSyncAssets(Vec<Vec<String>>),
+
+    /// This command is used to stop syncing the assets folder of a MyMod into the open PackFile.
+    LiveSyncStop,
+
+    /// This command is used to get the diagnostics info (memory, caches and timings) of the background thread.
+    GetDiagnostics,
+
+    /// This command is used to compare two PackFiles. Requires the path of the base PackFile (None for the open one) and the path of the PackFile to compare against.
+    ComparePackFiles((Option<PathBuf>, PathBuf)),
+
+    /// This command is used to get the row-level diff of a group of tables from the last compared PackFiles. Requires the group of the tables.
+    GetTableDiff(Vec<String>),
+
+    /// This command is used to merge a group of tables from the last compared PackFiles into the open PackFile. Requires the group of the tables and the rows of its diff where the second PackFile wins.
+    MergeTableDiff((Vec<String>, Vec<usize>)),
+
+    /// This command is used to get the row-level diff of a table against its version in a PackFile, or in the dependencies if no PackFile is provided.
+    /// Requires the path of the table, its data as it's in the UI, and the path of the PackFile.
+    CompareTable((Vec<String>, Table, Option<PathBuf>)),
+
+    /// This command is used to run a Job over the open PackFile. It returns the progress of each step as it goes, and a report at the end.
+    RunJob(Job),
+
+    /// This command is used to get which of the provided paths cannot be found neither in the open PackFile nor in its dependencies.
+    GetMissingPaths(Vec<String>),
+
+    /// This command is used to generate a checksum manifest of the open PackFile, as it is on disk. It returns the path of the manifest.
+    GenerateChecksumManifest,
+
+    /// This command is used to check the open PackFile, as it is on disk, against the checksum manifest in the provided path.
+    VerifyChecksumManifest(PathBuf),
+
+    /// This command is used to export the list of contents of the open PackFile, as it is in memory, to the provided path, as JSON or plain text depending on its extension.
+    ExportContentsManifest(PathBuf),
+
+    /// This command is used to split the Loc PackedFiles of the open PackFile into one PackFile per language of the Game Selected, saved in the provided folder.
+    SplitLocByLanguage(PathBuf),
+
+    /// This command is used to set the timestamp of all the PackedFiles of the open PackFile to 0, so they don't change between saves.
+    NormalizeTimestamps,
+
+    /// This command is used to launch the Game Selected with the open PackFile, as it is on disk, and the other MyMods of the game if it's a MyMod.
+    LaunchGameWithPackFile,
+
+    /// This command is used to know if the Game Selected is running and the open PackFile is in its data folder, so saving it may break the running game.
+    IsGameRunningWithPackFile,
 }
 
 /// This enum defines the responses (messages) you can send to the to the UI thread as result of a command.
@@ -299,12 +500,24 @@ pub enum Response {
     /// Response to return (i32).
     I32(i32),
 
+    /// Response to return (u32).
+    U32(u32),
+
     /// Response to return (PathBuf).
     PathBuf(PathBuf),
 
+    /// Response to return (Vec<PathBuf>).
+    VecPathBuf(Vec<PathBuf>),
+
     /// Response to return (String)
     String(String),
 
+    /// Response to return (MyModConfig).
+    MyModConfig(MyModConfig),
+
+    /// Response to return (Vec<(Vec<String>, GitStatus)>).
+    VecVecStringGitStatus(Vec<(Vec<String>, GitStatus)>),
+
     /// Response to return (PackFileInfo, Vec<PackedFileInfo>).
     PackFileInfoVecPackedFileInfo((PackFileInfo, Vec<PackedFileInfo>)),
 
@@ -317,15 +530,24 @@ pub enum Response {
     /// Response to return (Vec<Option<PackedFileInfo>>).
     VecOptionPackedFileInfo(Vec<Option<PackedFileInfo>>),
 
+    /// Response to return (GlobalSearch).
+    GlobalSearch(GlobalSearch),
+
     /// Response to return (GlobalSearch, Vec<PackedFileInfo>).
     GlobalSearchVecPackedFileInfo((GlobalSearch, Vec<PackedFileInfo>)),
 
+    /// Response to return (PackedFileMatches).
+    PackedFileMatches(PackedFileMatches),
+
     /// Response to return (Vec<Vec<String>>).
     VecVecString(Vec<Vec<String>>),
 
     /// Response to return (Vec<PathType>).
     VecPathType(Vec<PathType>),
 
+    /// Response to return (Vec<(i64, usize)>).
+    VecI64Usize(Vec<(i64, usize)>),
+
     /// Response to return (Vec<(PathType, Vec<String>)>).
     VecPathTypeVecString(Vec<(PathType, Vec<String>)>),
 
@@ -368,6 +590,21 @@ pub enum Response {
     /// Response to return `(RigidModel, PackedFileInfo)`.
     RigidModelPackedFileInfo((RigidModel, PackedFileInfo)),
 
+    /// Response to return `(SoundBank, PackedFileInfo)`.
+    SoundBankPackedFileInfo((SoundBank, PackedFileInfo)),
+
+    /// Response to return `(UnitVariant, PackedFileInfo)`.
+    UnitVariantPackedFileInfo((UnitVariant, PackedFileInfo)),
+
+    /// Response to return `OptimizerReport`.
+    OptimizerReport(OptimizerReport),
+
+    /// Response to return `ChecksumReport`.
+    ChecksumReport(ChecksumReport),
+
+    /// Response to return `LiveSyncReport`.
+    LiveSyncReport(LiveSyncReport),
+
     /// Response to return `Text`.
     Text(Text),
 
@@ -377,26 +614,94 @@ pub enum Response {
     /// Response to return `(Vec<Vec<String>>, Vec<Vec<String>>)`.
     VecVecStringVecVecString((Vec<Vec<String>>, Vec<Vec<String>>)),
 
+    /// Response to return `(Vec<Vec<String>>, Vec<(String, Error)>)`.
+    VecVecStringVecStringError((Vec<Vec<String>>, Vec<(String, Error)>)),
+
     /// Response to return `Vec<String>`.
     VecString(Vec<String>),
 
+    /// Response to return `Vec<(String, bool)>`.
+    VecStringBool(Vec<(String, bool)>),
+
     /// Response to return `(i32, i32)`.
     I32I32((i32, i32)),
 
     /// Response to return `BTreeMap<i32, BTreeMap<String, String>>`.
     BTreeMapI32BTreeMapStringString(BTreeMap<i32, BTreeMap<String, String>>),
 
+    /// Response to return `BTreeMap<i32, Vec<String>>`.
+    BTreeMapI32VecString(BTreeMap<i32, Vec<String>>),
+
+    /// Response to return `BTreeMap<String, String>`.
+    BTreeMapStringString(BTreeMap<String, String>),
+
+    /// Response to return `BTreeMap<String, Vec<TableDefinitionChanges>>`.
+    BTreeMapStringVecTableDefinitionChanges(BTreeMap<String, Vec<TableDefinitionChanges>>),
+
     /// Response to return `Option<PackedFile>`.
     OptionPackedFile(Option<PackedFile>),
 
+    /// Response to return `Option<DecodeFailureReport>`.
+    OptionDecodeFailureReport(Option<DecodeFailureReport>),
+
     /// Response to return `TableType`.
     TableType(TableType),
+
+    /// Response to return `Definition`.
+    Definition(Definition),
+
+    /// Response to return `Diagnostics`.
+    Diagnostics(Diagnostics),
+
+    /// Response to return `PackFileDiff`.
+    PackFileDiff(PackFileDiff),
+
+    /// Response to return `TableDiff`.
+    TableDiff(TableDiff),
+
+    /// Response to return `(usize, usize, String)`.
+    UsizeUsizeString((usize, usize, String)),
+
+    /// Response to return `(Vec<String>, PackFileInfo)`.
+    VecStringPackFileInfo((Vec<String>, PackFileInfo)),
 }
 
+/// Writer used to get the name of a `Command` from its `Debug` output, without formatting the data it carries.
+struct CommandNameWriter(String);
+
 //-------------------------------------------------------------------------------//
 //                              Implementations
 //-------------------------------------------------------------------------------//
 
+/// Implementation of `Command`.
+impl Command {
+
+    /// This function returns the name of the variant of the provided `Command`, without the data it carries.
+    pub fn get_name(&self) -> String {
+        let mut writer = CommandNameWriter(String::new());
+
+        // The writer stops the formatting as soon as the data starts, so the error here is expected.
+        let _ = write!(writer, "{:?}", self);
+        writer.0
+    }
+}
+
+/// Implementation of `Write` for `CommandNameWriter`.
+impl Write for CommandNameWriter {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        match string.find(|character| character == '(' || character == ' ' || character == '{') {
+            Some(position) => {
+                self.0.push_str(&string[..position]);
+                Err(fmt::Error)
+            }
+            None => {
+                self.0.push_str(string);
+                Ok(())
+            }
+        }
+    }
+}
+
 /// Default implementation of `CentralCommand`.
 impl Default for CentralCommand {
     fn default() -> Self {
@@ -413,6 +718,9 @@ impl Default for CentralCommand {
             receiver_rust: command_channel.1,
             receiver_qt_to_network: network_command_channel.1,
             receiver_network_to_qt: network_response_channel.1,
+            next_command_id: AtomicU64::new(0),
+            current_command_id: AtomicU64::new(0),
+            discarded_command_ids: Mutex::new(vec![]),
         }
     }
 }
@@ -423,7 +731,8 @@ impl CentralCommand {
     /// This function serves to send message from the main thread to the background thread.
     #[allow(dead_code)]
     pub fn send_message_qt(&self, data: Command) {
-        if self.sender_qt.send(data).is_err() {
+        let id = self.next_command_id.fetch_add(1, Ordering::SeqCst);
+        if self.sender_qt.send((id, data)).is_err() {
             panic!(THREADS_SENDER_ERROR);
         }
     }
@@ -431,7 +740,8 @@ impl CentralCommand {
     /// This function serves to send message from the background thread to the main thread.
     #[allow(dead_code)]
     pub fn send_message_rust(&self, data: Response) {
-        if self.sender_rust.send(data).is_err() {
+        let id = self.current_command_id.load(Ordering::SeqCst);
+        if self.sender_rust.send((id, data)).is_err() {
             panic!(THREADS_SENDER_ERROR);
         }
     }
@@ -456,7 +766,10 @@ impl CentralCommand {
     #[allow(dead_code)]
     pub fn recv_message_rust(&self) -> Command {
         match self.receiver_rust.recv() {
-            Ok(data) => data,
+            Ok((id, data)) => {
+                self.current_command_id.store(id, Ordering::SeqCst);
+                data
+            }
 
             // If we hit an error here, it means the main thread is dead. So... report it and exit.
             Err(_) => {
@@ -485,13 +798,36 @@ impl CentralCommand {
     /// This function does only try once, and it locks the thread. Use it only in small stuff.
     #[allow(dead_code)]
     pub fn recv_message_qt(&self) -> Response {
-        let response = self.receiver_qt.recv() ;
-        match response {
-            Ok(data) => data,
-            Err(_) => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response)
+        loop {
+            let response = self.receiver_qt.recv() ;
+            match response {
+                Ok((id, data)) => if !self.is_command_discarded(id) { return data },
+                Err(_) => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response)
+            }
         }
     }
 
+    /// This function returns the id the next command sent to the background thread will have.
+    pub fn get_next_command_id(&self) -> u64 {
+        self.next_command_id.load(Ordering::SeqCst)
+    }
+
+    /// This functions discards the responses to every command sent to the background thread since the one with the provided id,
+    /// including the ones the background thread has not sent yet.
+    ///
+    /// This is only meant to recover from errors, like a slot panicking before receiving the response to his command.
+    pub fn discard_responses_since(&self, first_command_id: u64) {
+        let last_command_id = self.get_next_command_id();
+        if first_command_id < last_command_id {
+            self.discarded_command_ids.lock().unwrap().push(first_command_id..last_command_id);
+        }
+    }
+
+    /// This function returns if the responses to the command with the provided id must be discarded.
+    fn is_command_discarded(&self, id: u64) -> bool {
+        self.discarded_command_ids.lock().unwrap().iter().any(|range| range.contains(&id))
+    }
+
     /// This functions serves to receive messages from the network thread into the main thread.
     ///
     /// This function does only try once, and it locks the thread. Use it only in small stuff.
@@ -515,7 +851,7 @@ impl CentralCommand {
             // Check the response and, in case of error, try again. If the error is "Disconnected", CTD.
             let response = self.receiver_qt.try_recv() ;
             match response {
-                Ok(data) => return data,
+                Ok((id, data)) => if !self.is_command_discarded(id) { return data },
                 Err(error) => if error.is_disconnected() { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) }
             }
             unsafe { event_loop.process_events_0a() };
@@ -539,4 +875,19 @@ impl CentralCommand {
             unsafe { event_loop.process_events_0a(); }
         }
     }
+
+    /// This function returns the amount of commands waiting to be processed by the background thread.
+    pub fn get_pending_commands_count(&self) -> usize {
+        self.receiver_rust.len()
+    }
+
+    /// This function returns the amount of responses waiting to be received by the main thread.
+    pub fn get_pending_responses_count(&self) -> usize {
+        self.receiver_qt.len()
+    }
+
+    /// This function returns the amount of commands waiting to be processed by the network thread.
+    pub fn get_pending_network_commands_count(&self) -> usize {
+        self.receiver_qt_to_network.len()
+    }
 }
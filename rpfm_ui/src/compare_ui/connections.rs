@@ -0,0 +1,29 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `CompareUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `CompareUI` and `CompareUISlots` structs.
+!*/
+
+use super::{CompareUI, slots::CompareUISlots};
+
+/// This function connects all the actions from the provided `CompareUI` with their slots in `CompareUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not polute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &CompareUI, slots: &CompareUISlots) {
+    ui.base_button.released().connect(&slots.browse_base);
+    ui.other_button.released().connect(&slots.browse_other);
+    ui.compare_button.released().connect(&slots.compare);
+    ui.diff_tree_view.double_clicked().connect(&slots.load_table_diff);
+    ui.close_button.released().connect(ui.dialog.slot_close());
+}
@@ -0,0 +1,313 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Compare PackFiles*** UI.
+
+This is a dialog to compare two PackFiles (or the open one against another one, like a vanilla PackFile),
+showing the PackedFiles added, removed and modified, and a side-by-side row diff for the modified DB/Loc Tables.
+!*/
+
+use qt_widgets::q_abstract_item_view::EditTrigger;
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QFileDialog;
+use qt_widgets::q_file_dialog::FileMode;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::QPushButton;
+use qt_widgets::QSplitter;
+use qt_widgets::QTableView;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QBrush;
+use qt_gui::QColor;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QModelIndex;
+use qt_core::QString;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+use cpp_core::Ref;
+
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::common::get_game_selected_data_path;
+use rpfm_lib::diff::RowDiffState;
+use rpfm_lib::packedfile::table::DecodedData;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::locale::{qtr, tr};
+use crate::pack_tree::{get_color_added, get_color_deleted, get_color_modified};
+use crate::utils::{create_grid_layout, setup_file_dialog, show_dialog};
+use crate::views::table::utils::{clean_column_names, get_item_from_decoded_data};
+use self::slots::CompareUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Compare PackFiles Window.
+#[derive(Clone)]
+pub struct CompareUI {
+    dialog: MutPtr<QDialog>,
+
+    base_line_edit: MutPtr<QLineEdit>,
+    base_button: MutPtr<QPushButton>,
+    other_line_edit: MutPtr<QLineEdit>,
+    other_button: MutPtr<QPushButton>,
+
+    diff_tree_view: MutPtr<QTreeView>,
+    diff_tree_model: MutPtr<QStandardItemModel>,
+
+    base_table_view: MutPtr<QTableView>,
+    base_table_model: MutPtr<QStandardItemModel>,
+    other_table_view: MutPtr<QTableView>,
+    other_table_model: MutPtr<QStandardItemModel>,
+
+    compare_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `CompareUI`.
+impl CompareUI {
+
+    /// This function creates a ***CompareUI*** dialog and executes it.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) {
+        let ui = Self::new_with_parent(parent);
+        let slots = CompareUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.dialog.exec();
+    }
+
+    /// This function creates the entire `CompareUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Compare Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("compare_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(1000, 700);
+
+        // Create the main Grid and the path selectors.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut base_label = QLabel::from_q_string(&qtr("compare_base"));
+        let mut base_line_edit = QLineEdit::new();
+        let mut base_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+        base_line_edit.set_placeholder_text(&qtr("compare_base_placeholder"));
+
+        let mut other_label = QLabel::from_q_string(&qtr("compare_other"));
+        let mut other_line_edit = QLineEdit::new();
+        let mut other_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+
+        main_grid.add_widget_5a(&mut base_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut base_line_edit, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&mut base_button, 0, 2, 1, 1);
+        main_grid.add_widget_5a(&mut other_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut other_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&mut other_button, 1, 2, 1, 1);
+
+        // Create the TreeView with the changed PackedFiles, and the two tables for the row diff.
+        let mut splitter = QSplitter::new();
+        let mut diff_tree_view = QTreeView::new_0a();
+        let mut diff_tree_model = QStandardItemModel::new_0a();
+        diff_tree_view.set_model(&mut diff_tree_model);
+        diff_tree_view.set_header_hidden(true);
+        diff_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        let mut base_table_view = QTableView::new_0a();
+        let mut base_table_model = QStandardItemModel::new_0a();
+        base_table_view.set_model(&mut base_table_model);
+        base_table_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        let mut other_table_view = QTableView::new_0a();
+        let mut other_table_model = QStandardItemModel::new_0a();
+        other_table_view.set_model(&mut other_table_model);
+        other_table_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        // Both tables have the same rows, so keep them scrolled together.
+        base_table_view.vertical_scroll_bar().value_changed().connect(other_table_view.vertical_scroll_bar().slot_set_value());
+        other_table_view.vertical_scroll_bar().value_changed().connect(base_table_view.vertical_scroll_bar().slot_set_value());
+
+        splitter.add_widget(&mut diff_tree_view);
+        splitter.add_widget(&mut base_table_view);
+        splitter.add_widget(&mut other_table_view);
+        main_grid.add_widget_5a(splitter.into_ptr(), 2, 0, 1, 3);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut compare_button = button_box.add_button_q_string_button_role(&qtr("compare_compare"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        compare_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 3, 0, 1, 3);
+
+        base_label.into_ptr();
+        other_label.into_ptr();
+
+        Self {
+            dialog,
+            base_line_edit: base_line_edit.into_ptr(),
+            base_button: base_button.into_ptr(),
+            other_line_edit: other_line_edit.into_ptr(),
+            other_button: other_button.into_ptr(),
+            diff_tree_view: diff_tree_view.into_ptr(),
+            diff_tree_model: diff_tree_model.into_ptr(),
+            base_table_view: base_table_view.into_ptr(),
+            base_table_model: base_table_model.into_ptr(),
+            other_table_view: other_table_view.into_ptr(),
+            other_table_model: other_table_model.into_ptr(),
+            compare_button,
+            close_button,
+        }
+    }
+
+    /// This function opens a FileDialog to pick a PackFile, and puts its path in the provided LineEdit.
+    pub unsafe fn browse(&self, mut line_edit: MutPtr<QLineEdit>) {
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.dialog,
+            &qtr("open_packfiles"),
+        );
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+
+        // Start in the folder of the current path if there is one. If not, in the data folder of the game selected.
+        let old_path = line_edit.text().to_std_string();
+        if let Some(parent) = Path::new(&old_path).parent().filter(|parent| parent.is_dir()) {
+            file_dialog.set_directory_q_string(&QString::from_std_str(parent.to_string_lossy()));
+        }
+        else if let Some(ref path) = get_game_selected_data_path() {
+            if path.is_dir() { file_dialog.set_directory_q_string(&QString::from_std_str(path.to_string_lossy())); }
+        }
+
+        if file_dialog.exec() == 1 {
+            line_edit.set_text(file_dialog.selected_files().at(0));
+        }
+    }
+
+    /// This function asks the background thread to compare the selected PackFiles, and loads the result into the TreeView.
+    pub unsafe fn compare(&mut self) {
+        let base_path = self.base_line_edit.text().to_std_string();
+        let other_path = self.other_line_edit.text().to_std_string();
+        if other_path.is_empty() {
+            return show_dialog(self.dialog, tr("compare_no_other_packfile"), false);
+        }
+
+        let base_path = if base_path.is_empty() { None } else { Some(PathBuf::from(base_path)) };
+
+        self.dialog.set_enabled(false);
+        CENTRAL_COMMAND.send_message_qt(Command::ComparePackFiles((base_path, PathBuf::from(other_path))));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        self.dialog.set_enabled(true);
+
+        let diff = match response {
+            Response::PackFileDiff(diff) => diff,
+            Response::Error(error) => return show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.diff_tree_model.clear();
+        self.base_table_model.clear();
+        self.other_table_model.clear();
+
+        if diff.is_empty() {
+            return show_dialog(self.dialog, tr("compare_no_differences"), true);
+        }
+
+        self.add_section(&tr("compare_section_added"), diff.get_ref_added(), get_color_added());
+        self.add_section(&tr("compare_section_removed"), diff.get_ref_removed(), get_color_deleted());
+        self.add_section(&tr("compare_section_modified"), diff.get_ref_modified(), get_color_modified());
+        self.diff_tree_view.expand_all();
+    }
+
+    /// This function adds a section with the provided paths to the TreeView.
+    unsafe fn add_section(&mut self, name: &str, paths: &[Vec<String>], color: MutPtr<QColor>) {
+        let mut section = QStandardItem::from_q_string(&QString::from_std_str(format!("{} ({})", name, paths.len()))).into_ptr();
+        for path in paths {
+            let mut item = QStandardItem::from_q_string(&QString::from_std_str(path.join("/"))).into_ptr();
+            item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+            section.append_row_q_standard_item(item);
+        }
+
+        self.diff_tree_model.append_row_q_standard_item(section);
+    }
+
+    /// This function loads the row diff of the table under the provided index, if it's a modified table.
+    pub unsafe fn load_table_diff(&mut self, index: Ref<QModelIndex>) {
+
+        // Only modified PackedFiles exist in both PackFiles, so only those can be diffed.
+        let parent = index.parent();
+        if !parent.is_valid() || parent.row() != 2 { return; }
+
+        let path = index.data_0a().to_string().to_std_string().split('/').map(|x| x.to_owned()).collect::<Vec<String>>();
+        CENTRAL_COMMAND.send_message_qt(Command::GetTableDiff(path));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        let diff = match response {
+            Response::TableDiff(diff) => diff,
+            Response::Error(error) => return show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.base_table_model.clear();
+        self.other_table_model.clear();
+
+        for (column, field) in diff.get_ref_definition().get_ref_fields().iter().enumerate() {
+            let name = QString::from_std_str(clean_column_names(&field.get_name()));
+            self.base_table_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&name).into_ptr());
+            self.other_table_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&name).into_ptr());
+        }
+
+        let columns = diff.get_ref_definition().get_ref_fields().len();
+        for (base_row, other_row, state) in diff.get_ref_rows() {
+            let color = match state {
+                RowDiffState::Unchanged => None,
+                RowDiffState::Added => Some(get_color_added()),
+                RowDiffState::Removed => Some(get_color_deleted()),
+                RowDiffState::Modified => Some(get_color_modified()),
+            };
+
+            Self::append_row(self.base_table_model, base_row.as_ref(), columns, color);
+            Self::append_row(self.other_table_model, other_row.as_ref(), columns, color);
+        }
+
+        self.base_table_view.resize_columns_to_contents();
+        self.other_table_view.resize_columns_to_contents();
+    }
+
+    /// This function appends a row to the provided model, or an empty one if there is no row, so both sides stay aligned.
+    unsafe fn append_row(mut model: MutPtr<QStandardItemModel>, row: Option<&Vec<DecodedData>>, columns: usize, color: Option<MutPtr<QColor>>) {
+        let row_index = model.row_count_0a();
+        for column in 0..columns {
+            let mut item = match row {
+                Some(row) => get_item_from_decoded_data(&row[column]),
+                None => QStandardItem::new(),
+            };
+
+            if let Some(color) = color {
+                item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+            }
+
+            model.set_item_3a(row_index, column as i32, item.into_ptr());
+        }
+    }
+}
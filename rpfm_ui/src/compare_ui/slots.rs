@@ -0,0 +1,75 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `CompareUISlots`.
+!*/
+
+use qt_core::{Slot, SlotOfQModelIndex};
+
+use crate::compare_ui::CompareUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `CompareUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `CompareUI` goes here.
+pub struct CompareUISlots {
+    pub browse_base: Slot<'static>,
+    pub browse_other: Slot<'static>,
+    pub compare: Slot<'static>,
+    pub load_table_diff: SlotOfQModelIndex<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `CompareUISlots`.
+impl CompareUISlots {
+
+    /// This function creates a new `CompareUISlots`.
+    pub unsafe fn new(ui: &CompareUI) -> Self {
+
+        // What happens when we hit the browse button of the base PackFile.
+        let ui_browse = ui.clone();
+        let browse_base = Slot::new(move || catch_panic_in_slot(|| {
+            ui_browse.browse(ui_browse.base_line_edit)
+        }));
+
+        // What happens when we hit the browse button of the PackFile to compare against.
+        let ui_browse = ui.clone();
+        let browse_other = Slot::new(move || catch_panic_in_slot(|| {
+            ui_browse.browse(ui_browse.other_line_edit)
+        }));
+
+        // What happens when we hit the "Compare" button.
+        let mut ui_compare = ui.clone();
+        let compare = Slot::new(move || catch_panic_in_slot(|| {
+            ui_compare.compare()
+        }));
+
+        // What happens when we double-click a PackedFile in the diff tree.
+        let mut ui = ui.clone();
+        let load_table_diff = SlotOfQModelIndex::new(move |index| catch_panic_in_slot(|| {
+            ui.load_table_diff(index)
+        }));
+
+        CompareUISlots {
+            browse_base,
+            browse_other,
+            compare,
+            load_table_diff,
+        }
+    }
+}
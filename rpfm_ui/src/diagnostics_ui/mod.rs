@@ -0,0 +1,211 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Diagnostics*** UI.
+
+This is an internal panel that shows the memory used by the open PackFile and the dependencies,
+the queues of the threads, and the timings of the last operations done by the background thread.
+Useful to track down (and report) performance problems.
+!*/
+
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QString;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use std::time::Duration;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::ffi::add_to_q_list_safe;
+use crate::locale::{qtr, tr};
+use crate::utils::create_grid_layout;
+use self::slots::DiagnosticsUISlots;
+
+mod connections;
+mod slots;
+
+/// Amount of operations we keep the timings of in the background thread.
+pub const DIAGNOSTICS_LAST_OPERATIONS_LIMIT: usize = 20;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Diagnostics Window.
+#[derive(Clone)]
+pub struct DiagnosticsUI {
+    dialog: MutPtr<QDialog>,
+
+    diagnostics_table: MutPtr<QTreeView>,
+    diagnostics_model: MutPtr<QStandardItemModel>,
+
+    refresh_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+}
+
+/// This struct holds the diagnostics data collected by the background thread.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    pub packed_files_count: usize,
+    pub packed_files_loaded_count: usize,
+    pub packed_files_decoded_count: usize,
+    pub packed_files_loaded_size: usize,
+    pub extra_packfiles_count: usize,
+    pub dependency_packed_files_count: usize,
+    pub dependency_packed_files_loaded_size: usize,
+    pub fake_dependency_tables_count: usize,
+    pub last_operations: Vec<(String, Duration)>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `DiagnosticsUI`.
+impl DiagnosticsUI {
+
+    /// This function creates a ***DiagnosticsUI*** dialog, loads the current diagnostics on it, and executes it.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) {
+        let mut ui = Self::new_with_parent(parent);
+        let slots = DiagnosticsUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.refresh();
+        ui.dialog.exec();
+    }
+
+    /// This function creates the entire `DiagnosticsUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Diagnostics Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("diagnostics_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 500);
+
+        // Create the main Grid and add the diagnostics TreeView.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut diagnostics_table = QTreeView::new_0a();
+        let mut diagnostics_model = QStandardItemModel::new_0a();
+        diagnostics_table.set_model(&mut diagnostics_model);
+        diagnostics_table.set_sorting_enabled(false);
+        diagnostics_table.header().set_stretch_last_section(true);
+        main_grid.add_widget_5a(&mut diagnostics_table, 0, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut refresh_button = button_box.add_button_q_string_button_role(&qtr("diagnostics_refresh"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        refresh_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 1, 0, 1, 1);
+
+        Self {
+            dialog,
+            diagnostics_table: diagnostics_table.into_ptr(),
+            diagnostics_model: diagnostics_model.into_ptr(),
+            refresh_button,
+            close_button,
+        }
+    }
+
+    /// This function asks the background thread for the current diagnostics and loads them into the `DiagnosticsUI`.
+    pub unsafe fn refresh(&mut self) {
+
+        // Get the queues before asking for the rest, so our own command doesn't count.
+        let pending_commands = CENTRAL_COMMAND.get_pending_commands_count();
+        let pending_responses = CENTRAL_COMMAND.get_pending_responses_count();
+        let pending_network_commands = CENTRAL_COMMAND.get_pending_network_commands_count();
+
+        CENTRAL_COMMAND.send_message_qt(Command::GetDiagnostics);
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        let diagnostics = match response {
+            Response::Diagnostics(diagnostics) => diagnostics,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.diagnostics_model.clear();
+        self.diagnostics_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("diagnostics_metric")).into_ptr());
+        self.diagnostics_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("diagnostics_value")).into_ptr());
+
+        self.add_section(&tr("diagnostics_section_memory"), &[
+            (tr("diagnostics_packed_files_count"), diagnostics.packed_files_count.to_string()),
+            (tr("diagnostics_packed_files_loaded_count"), diagnostics.packed_files_loaded_count.to_string()),
+            (tr("diagnostics_packed_files_decoded_count"), diagnostics.packed_files_decoded_count.to_string()),
+            (tr("diagnostics_packed_files_loaded_size"), format_size(diagnostics.packed_files_loaded_size)),
+            (tr("diagnostics_extra_packfiles_count"), diagnostics.extra_packfiles_count.to_string()),
+        ]);
+
+        self.add_section(&tr("diagnostics_section_dependencies"), &[
+            (tr("diagnostics_dependency_packed_files_count"), diagnostics.dependency_packed_files_count.to_string()),
+            (tr("diagnostics_dependency_packed_files_loaded_size"), format_size(diagnostics.dependency_packed_files_loaded_size)),
+            (tr("diagnostics_fake_dependency_tables_count"), diagnostics.fake_dependency_tables_count.to_string()),
+        ]);
+
+        self.add_section(&tr("diagnostics_section_threads"), &[
+            (tr("diagnostics_pending_commands"), pending_commands.to_string()),
+            (tr("diagnostics_pending_responses"), pending_responses.to_string()),
+            (tr("diagnostics_pending_network_commands"), pending_network_commands.to_string()),
+        ]);
+
+        let last_operations = diagnostics.last_operations.iter()
+            .map(|(name, time)| (name.to_owned(), format!("{:.3} ms", time.as_secs_f64() * 1000.0)))
+            .collect::<Vec<(String, String)>>();
+        self.add_section(&tr("diagnostics_section_last_operations"), &last_operations);
+
+        self.diagnostics_table.expand_all();
+        self.diagnostics_table.resize_column_to_contents(0);
+    }
+
+    /// This function adds a section with the provided metrics to the model.
+    unsafe fn add_section(&mut self, name: &str, metrics: &[(String, String)]) {
+        let mut section = QStandardItem::from_q_string(&QString::from_std_str(name)).into_ptr();
+        let mut fill = QStandardItem::new().into_ptr();
+        section.set_editable(false);
+        fill.set_editable(false);
+
+        for (metric, value) in metrics {
+            let mut row_list = QListOfQStandardItem::new().into_ptr();
+            let mut metric = QStandardItem::from_q_string(&QString::from_std_str(metric)).into_ptr();
+            let mut value = QStandardItem::from_q_string(&QString::from_std_str(value)).into_ptr();
+            metric.set_editable(false);
+            value.set_editable(false);
+
+            add_to_q_list_safe(row_list, metric);
+            add_to_q_list_safe(row_list, value);
+            section.append_row_q_list_of_q_standard_item(row_list.as_ref().unwrap());
+        }
+
+        let section_row = QListOfQStandardItem::new().into_ptr();
+        add_to_q_list_safe(section_row, section);
+        add_to_q_list_safe(section_row, fill);
+        self.diagnostics_model.append_row_q_list_of_q_standard_item(section_row.as_ref().unwrap());
+    }
+}
+
+/// This function returns the provided amount of bytes in a human-readable way.
+fn format_size(size: usize) -> String {
+    let size = size as f64;
+    if size >= 1024.0 * 1024.0 * 1024.0 { format!("{:.2} GiB", size / (1024.0 * 1024.0 * 1024.0)) }
+    else if size >= 1024.0 * 1024.0 { format!("{:.2} MiB", size / (1024.0 * 1024.0)) }
+    else if size >= 1024.0 { format!("{:.2} KiB", size / 1024.0) }
+    else { format!("{} B", size) }
+}
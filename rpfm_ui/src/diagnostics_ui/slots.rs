@@ -0,0 +1,51 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `DiagnosticsUISlots`.
+!*/
+
+use qt_core::Slot;
+
+use crate::diagnostics_ui::DiagnosticsUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `DiagnosticsUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `DiagnosticsUI` goes here.
+pub struct DiagnosticsUISlots {
+    pub refresh: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `DiagnosticsUISlots`.
+impl DiagnosticsUISlots {
+
+    /// This function creates a new `DiagnosticsUISlots`.
+    pub unsafe fn new(ui: &DiagnosticsUI) -> Self {
+
+        // What happens when we hit the "Refresh" button.
+        let mut ui = ui.clone();
+        let refresh = Slot::new(move || catch_panic_in_slot(|| {
+            ui.refresh()
+        }));
+
+        DiagnosticsUISlots {
+            refresh
+        }
+    }
+}
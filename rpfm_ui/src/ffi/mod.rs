@@ -12,8 +12,10 @@
 Module containing the ffi functions used for custom widgets.
 !*/
 
+use qt_widgets::QAction;
 use qt_widgets::QLabel;
 use qt_widgets::QTableView;
+use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
 
 use qt_gui::QListOfQStandardItem;
@@ -22,14 +24,21 @@ use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::QAbstractItemModel;
+use qt_core::QModelIndex;
+use qt_core::QListOfInt;
 use qt_core::QObject;
 use qt_core::QRegExp;
 use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
 use qt_core::QStringList;
+use qt_core::Signal;
 
+use cpp_core::CppBox;
 use cpp_core::MutPtr;
 use cpp_core::Ptr;
+use cpp_core::Ref;
+
+use std::ffi::CStr;
 
 /// This function replaces the default editor widget for reference columns with a combobox, so you can select the reference data.
 extern "C" { fn new_combobox_item_delegate(table_view: *mut QObject, column: i32, list: *const QStringList, is_editable: bool, max_lenght: i32); }
@@ -38,21 +47,39 @@ pub fn new_combobox_item_delegate_safe(table_view: &mut QObject, column: i32, li
 }
 
 /// This function changes the default editor widget for I32/64 cells on tables with a numeric one.
-extern "C" { fn new_spinbox_item_delegate(table_view: *mut QObject, column: i32, integer_type: i32); }
-pub fn new_spinbox_item_delegate_safe(table_view: &mut QObject, column: i32, integer_type: i32) {
-    unsafe { new_spinbox_item_delegate(table_view, column, integer_type) }
+///
+/// The display options only affect how the value is shown in the cell. The editor always works with the raw value.
+extern "C" { fn new_spinbox_item_delegate(table_view: *mut QObject, column: i32, integer_type: i32, is_optional: bool, use_thousands_separator: bool, is_timestamp: bool); }
+pub fn new_spinbox_item_delegate_safe(table_view: &mut QObject, column: i32, integer_type: i32, use_thousands_separator: bool, is_timestamp: bool) {
+    unsafe { new_spinbox_item_delegate(table_view, column, integer_type, false, use_thousands_separator, is_timestamp) }
 }
 
 /// This function changes the default editor widget for F32 cells on tables with a numeric one.
-extern "C" { fn new_doublespinbox_item_delegate(table_view: *mut QObject, column: i32); }
-pub fn new_doublespinbox_item_delegate_safe(table_view: &mut QObject, column: i32) {
-    unsafe { new_doublespinbox_item_delegate(table_view, column) }
+extern "C" { fn new_doublespinbox_item_delegate(table_view: *mut QObject, column: i32, decimals: i32, use_thousands_separator: bool); }
+pub fn new_doublespinbox_item_delegate_safe(table_view: &mut QObject, column: i32, decimals: i32, use_thousands_separator: bool) {
+    unsafe { new_doublespinbox_item_delegate(table_view, column, decimals, use_thousands_separator) }
 }
 
 /// This function changes the default editor widget for String cells, to ensure the provided data is valid for the schema..
-extern "C" { fn new_qstring_item_delegate(table_view: *mut QObject, column: i32, max_lenght: i32); }
-pub fn new_qstring_item_delegate_safe(table_view: &mut QObject, column: i32, max_lenght: i32) {
-    unsafe { new_qstring_item_delegate(table_view, column, max_lenght) }
+///
+/// The editor offers as completions the values already in the column, plus the provided ones.
+extern "C" { fn new_qstring_item_delegate(table_view: *mut QObject, column: i32, max_lenght: i32, completion_values: *const QStringList); }
+pub fn new_qstring_item_delegate_safe(table_view: &mut QObject, column: i32, max_lenght: i32, completion_values: Ptr<QStringList>) {
+    unsafe { new_qstring_item_delegate(table_view, column, max_lenght, completion_values.as_raw_ptr()) }
+}
+
+/// This function setup the special filter used for the `TableView` of tables, which supports multiple conditions.
+extern "C" { fn new_tableview_filter(parent: *mut QObject) -> *mut QSortFilterProxyModel; }
+pub fn new_tableview_filter_safe(parent: &mut QObject) -> MutPtr<QSortFilterProxyModel> {
+    unsafe { MutPtr::from_raw(new_tableview_filter(parent)) }
+}
+
+/// This function sets the conditions of the special filter used for the `TableView` of tables, and triggers it.
+///
+/// Each condition is made of the column, operator and value with the same index in the provided lists.
+extern "C" { fn trigger_tableview_filter(filter: *mut QSortFilterProxyModel, columns: *const QListOfInt, operators: *const QListOfInt, values: *const QStringList, match_all: bool, case_sensitive: bool); }
+pub fn trigger_tableview_filter_safe(filter: &mut QSortFilterProxyModel, columns: Ptr<QListOfInt>, operators: Ptr<QListOfInt>, values: Ptr<QStringList>, match_all: bool, case_sensitive: bool) {
+    unsafe { trigger_tableview_filter(filter, columns.as_raw_ptr(), operators.as_raw_ptr(), values.as_raw_ptr(), match_all, case_sensitive) }
 }
 
 /// This function setup the special filter used for the PackFile Contents `TreeView`.
@@ -73,6 +100,41 @@ pub fn new_packed_file_model_safe() -> MutPtr<QStandardItemModel> {
     unsafe { MutPtr::from_raw(new_packed_file_model()) }
 }
 
+/// This function allow us to create the TreeView used for the PackFile Contents, which supports dropping files from and dragging files to the OS, and moving items within it.
+extern "C" { fn new_packed_file_treeview(parent: *mut QWidget) -> *mut QTreeView; }
+pub fn new_packed_file_treeview_safe(parent: &mut QWidget) -> MutPtr<QTreeView> {
+    unsafe { MutPtr::from_raw(new_packed_file_treeview(parent)) }
+}
+
+/// This function returns the paths of the files and folders last dropped into the provided PackFile Contents TreeView.
+extern "C" { fn get_dropped_paths(view: *mut QTreeView) -> *mut QStringList; }
+pub fn get_dropped_paths_safe(view: &mut QTreeView) -> CppBox<QStringList> {
+    unsafe { CppBox::from_raw(get_dropped_paths(view)).unwrap() }
+}
+
+/// This function sets the paths of the files and folders on disk that get dragged out of the provided PackFile Contents TreeView.
+extern "C" { fn set_drag_out_paths(view: *mut QTreeView, paths: *mut QStringList); }
+pub fn set_drag_out_paths_safe(view: &mut QTreeView, paths: &mut QStringList) {
+    unsafe { set_drag_out_paths(view, paths) }
+}
+
+/// This function returns the signal emitted when files or folders from the OS are dropped into the provided PackFile Contents TreeView.
+pub unsafe fn packed_file_treeview_files_dropped_signal(view: MutPtr<QTreeView>) -> Signal<(Ref<QModelIndex>,)> {
+    Signal::new(view.static_upcast::<QObject>().as_ref().unwrap(), CStr::from_bytes_with_nul_unchecked(b"2filesDropped(QModelIndex)\0"))
+}
+
+/// This function returns the signal emitted when the selection of the provided PackFile Contents TreeView starts being dragged out.
+///
+/// It's emitted before the drag begins, so its slots can set the paths to drag with `set_drag_out_paths_safe`.
+pub unsafe fn packed_file_treeview_drag_out_requested_signal(view: MutPtr<QTreeView>) -> Signal<()> {
+    Signal::new(view.static_upcast::<QObject>().as_ref().unwrap(), CStr::from_bytes_with_nul_unchecked(b"2dragOutRequested()\0"))
+}
+
+/// This function returns the signal emitted when items of the provided PackFile Contents TreeView are dragged and dropped within it.
+pub unsafe fn packed_file_treeview_items_moved_signal(view: MutPtr<QTreeView>) -> Signal<(Ref<QModelIndex>,)> {
+    Signal::new(view.static_upcast::<QObject>().as_ref().unwrap(), CStr::from_bytes_with_nul_unchecked(b"2itemsMoved(QModelIndex)\0"))
+}
+
 /// This function allow us to create a properly sized TableView for the Command Palette.
 extern "C" { fn new_tableview_command_palette() -> *mut QTableView; }
 pub fn new_tableview_command_palette_safe() -> MutPtr<QTableView> {
@@ -134,12 +196,30 @@ pub fn set_text_safe(document: &mut QWidget, string: &mut QString, highlighting_
     unsafe { set_text(document, string, highlighting_mode) }
 }
 
+/// This function allow us to get the revision of the text of the provided KTextEditor, which changes with every edit.
+extern "C" { fn get_text_revision(document: *mut QWidget) -> i64; }
+pub fn get_text_revision_safe(document: &mut QWidget) -> i64 {
+    unsafe { get_text_revision(document) }
+}
+
 /// This function triggers the config dialog for the KTextEditor.
 extern "C" { fn open_text_editor_config(parent: *mut QWidget); }
 pub fn open_text_editor_config_safe(parent: &mut QWidget) {
     unsafe { open_text_editor_config(parent) }
 }
 
+/// This function allow us to get the point size of the font of the provided KTextEditor, which changes when it's zoomed.
+extern "C" { fn get_text_editor_font_size(document: *mut QWidget) -> i32; }
+pub fn get_text_editor_font_size_safe(document: &mut QWidget) -> i32 {
+    unsafe { get_text_editor_font_size(document) }
+}
+
+/// This function allow us to set the point size of the font of the provided KTextEditor.
+extern "C" { fn set_text_editor_font_size(document: *mut QWidget, size: i32); }
+pub fn set_text_editor_font_size_safe(document: &mut QWidget, size: i32) {
+    unsafe { set_text_editor_font_size(document, size) }
+}
+
 //---------------------------------------------------------------------------//
 // Image stuff.
 //---------------------------------------------------------------------------//
@@ -154,3 +234,37 @@ extern "C" { fn set_pixmap_on_resizable_label(label: *mut QLabel, pixmap: *mut Q
 pub fn set_pixmap_on_resizable_label_safe(label: &mut QLabel, pixmap: &mut QPixmap) {
     unsafe { set_pixmap_on_resizable_label(label, pixmap); }
 }
+
+//---------------------------------------------------------------------------//
+// Audio stuff.
+//---------------------------------------------------------------------------//
+
+/// This function allow us to create an audio player, to play audio files from disk.
+extern "C" { fn new_audio_player(parent: *mut QObject) -> *mut QObject; }
+pub fn new_audio_player_safe(parent: &mut QObject) -> MutPtr<QObject> {
+    unsafe { MutPtr::from_raw(new_audio_player(parent)) }
+}
+
+/// This function plays the audio file at the provided path with the provided audio player, stopping whatever it was playing.
+extern "C" { fn play_audio_file(player: *mut QObject, path: *mut QString); }
+pub fn play_audio_file_safe(player: &mut QObject, path: &mut QString) {
+    unsafe { play_audio_file(player, path) }
+}
+
+/// This function stops the audio being played by the provided audio player.
+extern "C" { fn stop_audio(player: *mut QObject); }
+pub fn stop_audio_safe(player: &mut QObject) {
+    unsafe { stop_audio(player) }
+}
+
+//---------------------------------------------------------------------------//
+// Zoom stuff.
+//---------------------------------------------------------------------------//
+
+/// This function allow us to trigger the provided zoom actions with Ctrl + Wheel over the provided widget.
+///
+/// For scroll areas, like tables or text edits, the widget must be their viewport, as it's the one receiving the wheel events.
+extern "C" { fn new_zoom_filter(widget: *mut QObject, zoom_in: *mut QAction, zoom_out: *mut QAction); }
+pub fn new_zoom_filter_safe(widget: &mut QObject, zoom_in: &mut QAction, zoom_out: &mut QAction) {
+    unsafe { new_zoom_filter(widget, zoom_in, zoom_out) }
+}
@@ -26,8 +26,14 @@ pub unsafe fn set_connections(global_search_ui: &GlobalSearchUI, slots: &GlobalS
     global_search_ui.global_search_replace_button.released().connect(&slots.global_search_replace_current);
     global_search_ui.global_search_replace_all_button.released().connect(&slots.global_search_replace_all);
     global_search_ui.global_search_search_line_edit.return_pressed().connect(&slots.global_search_search);
+    global_search_ui.global_search_path_filter_line_edit.return_pressed().connect(&slots.global_search_search);
     global_search_ui.global_search_search_line_edit.text_changed().connect(&slots.global_search_check_regex);
 
+    global_search_ui.global_search_history_menu.about_to_show().connect(&slots.global_search_history_load_menu);
+    global_search_ui.global_search_history_menu.triggered().connect(&slots.global_search_history_open);
+    global_search_ui.global_search_history_pin.triggered().connect(&slots.global_search_history_pin);
+    global_search_ui.global_search_history_clear.triggered().connect(&slots.global_search_history_clear);
+
     global_search_ui.global_search_matches_db_tree_view.double_clicked().connect(&slots.global_search_open_match);
     global_search_ui.global_search_matches_loc_tree_view.double_clicked().connect(&slots.global_search_open_match);
     global_search_ui.global_search_matches_text_tree_view.double_clicked().connect(&slots.global_search_open_match);
@@ -15,6 +15,7 @@ This module contains all the code needed to initialize the Global Search Panel.
 !*/
 
 use qt_widgets::q_abstract_item_view::{ScrollHint, ScrollMode};
+use qt_widgets::QAction;
 use qt_widgets::QCheckBox;
 use qt_widgets::QComboBox;
 use qt_widgets::QDockWidget;
@@ -22,6 +23,7 @@ use qt_widgets::QGroupBox;
 use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::QLineEdit;
 use qt_widgets::QMainWindow;
+use qt_widgets::QMenu;
 use qt_widgets::QPushButton;
 use qt_widgets::QTabWidget;
 use qt_widgets::QTreeView;
@@ -45,18 +47,19 @@ use cpp_core::Ptr;
 use rpfm_error::ErrorKind;
 
 use rpfm_lib::packfile::PathType;
-use rpfm_lib::global_search::{GlobalSearch, MatchHolder, schema::SchemaMatches, table::{TableMatches, TableMatch}, text::TextMatches};
+use rpfm_lib::global_search::{GlobalSearch, MatchHolder, PackedFileMatches, SearchScope, schema::SchemaMatches, table::{TableMatches, TableMatch}, text::TextMatches};
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::ffi::{add_to_q_list_safe, new_treeview_filter_safe, trigger_treeview_filter_safe};
-use crate::locale::qtr;
+use crate::locale::{qtr, tre};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{View, ViewType};
 use crate::pack_tree::{PackTree, TreeViewOperation};
 use crate::QString;
-use crate::utils::{create_grid_layout, show_dialog};
+use crate::ui_state::search_history::SavedSearch;
+use crate::utils::{create_grid_layout, log_to_status_bar, Problems, show_dialog};
 use crate::UI_STATE;
 
 pub mod connections;
@@ -64,6 +67,16 @@ pub mod shortcuts;
 pub mod slots;
 pub mod tips;
 
+/// Role of the PackedFile items of the matches where we store the name of the PackFile they're from, if it's not the open one.
+const MATCH_SOURCE: i32 = 40;
+
+/// List of scopes of the search, in the order they're shown in the scope selector.
+const SEARCH_SCOPES: [SearchScope; 3] = [
+    SearchScope::PackFile,
+    SearchScope::PackFileAndParents,
+    SearchScope::AllGamePacks,
+];
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -82,6 +95,13 @@ pub struct GlobalSearchUI {
     pub global_search_clear_button: MutPtr<QPushButton>,
     pub global_search_case_sensitive_checkbox: MutPtr<QCheckBox>,
     pub global_search_use_regex_checkbox: MutPtr<QCheckBox>,
+    pub global_search_path_filter_line_edit: MutPtr<QLineEdit>,
+    pub global_search_scope_combobox: MutPtr<QComboBox>,
+
+    pub global_search_history_button: MutPtr<QPushButton>,
+    pub global_search_history_menu: MutPtr<QMenu>,
+    pub global_search_history_pin: MutPtr<QAction>,
+    pub global_search_history_clear: MutPtr<QAction>,
 
     pub global_search_search_on_all_checkbox: MutPtr<QCheckBox>,
     pub global_search_search_on_dbs_checkbox: MutPtr<QCheckBox>,
@@ -154,6 +174,19 @@ impl GlobalSearchUI {
         let mut global_search_clear_button = QPushButton::from_q_string(&qtr("global_search_clear"));
         let mut global_search_case_sensitive_checkbox = QCheckBox::from_q_string(&qtr("global_search_case_sensitive"));
         let mut global_search_use_regex_checkbox = QCheckBox::from_q_string(&qtr("global_search_use_regex"));
+        let mut global_search_path_filter_line_edit = QLineEdit::new();
+        global_search_path_filter_line_edit.set_placeholder_text(&qtr("global_search_path_filter"));
+        let mut global_search_scope_combobox = QComboBox::new_0a();
+        global_search_scope_combobox.add_item_q_string(&qtr("global_search_scope_packfile"));
+        global_search_scope_combobox.add_item_q_string(&qtr("global_search_scope_packfile_and_parents"));
+        global_search_scope_combobox.add_item_q_string(&qtr("global_search_scope_all_game_packs"));
+
+        // The history menu is filled with the pinned and recent searches each time it's shown.
+        let mut global_search_history_button = QPushButton::from_q_string(&qtr("global_search_history"));
+        let mut global_search_history_menu = QMenu::new();
+        let global_search_history_pin = QAction::from_q_string_q_object(&qtr("global_search_history_pin"), &mut global_search_history_button).into_ptr();
+        let global_search_history_clear = QAction::from_q_string_q_object(&qtr("global_search_history_clear"), &mut global_search_history_button).into_ptr();
+        global_search_history_button.set_menu(&mut global_search_history_menu);
 
         let global_search_search_on_group_box = QGroupBox::from_q_string(&qtr("global_search_search_on")).into_ptr();
         let mut global_search_search_on_grid = create_grid_layout(global_search_search_on_group_box.static_upcast_mut());
@@ -180,8 +213,11 @@ impl GlobalSearchUI {
 
         global_search_search_grid.add_widget_5a(&mut global_search_clear_button, 0, 3, 1, 1);
         global_search_search_grid.add_widget_5a(&mut global_search_case_sensitive_checkbox, 0, 4, 1, 1);
+        global_search_search_grid.add_widget_5a(&mut global_search_history_button, 0, 5, 1, 1);
         global_search_search_grid.add_widget_5a(&mut global_search_use_regex_checkbox, 1, 4, 1, 1);
-        global_search_search_grid.add_widget_5a(global_search_search_on_group_box, 2, 0, 1, 10);
+        global_search_search_grid.add_widget_5a(&mut global_search_path_filter_line_edit, 2, 0, 1, 2);
+        global_search_search_grid.add_widget_5a(&mut global_search_scope_combobox, 2, 2, 1, 3);
+        global_search_search_grid.add_widget_5a(global_search_search_on_group_box, 3, 0, 1, 10);
 
         global_search_search_on_grid.add_widget_5a(&mut global_search_search_on_all_checkbox, 0, 0, 1, 1);
         global_search_search_on_grid.add_widget_5a(&mut global_search_search_on_dbs_checkbox, 0, 1, 1, 1);
@@ -349,6 +385,13 @@ impl GlobalSearchUI {
             global_search_clear_button: global_search_clear_button.into_ptr(),
             global_search_case_sensitive_checkbox: global_search_case_sensitive_checkbox.into_ptr(),
             global_search_use_regex_checkbox: global_search_use_regex_checkbox.into_ptr(),
+            global_search_path_filter_line_edit: global_search_path_filter_line_edit.into_ptr(),
+            global_search_scope_combobox: global_search_scope_combobox.into_ptr(),
+
+            global_search_history_button: global_search_history_button.into_ptr(),
+            global_search_history_menu: global_search_history_menu.into_ptr(),
+            global_search_history_pin,
+            global_search_history_clear,
 
             global_search_search_on_all_checkbox: global_search_search_on_all_checkbox.into_ptr(),
             global_search_search_on_dbs_checkbox: global_search_search_on_dbs_checkbox.into_ptr(),
@@ -398,10 +441,16 @@ impl GlobalSearchUI {
         global_search.pattern = self.global_search_search_line_edit.text().to_std_string();
         global_search.case_sensitive = self.global_search_case_sensitive_checkbox.is_checked();
         global_search.use_regex = self.global_search_use_regex_checkbox.is_checked();
+        global_search.path_filter = self.global_search_path_filter_line_edit.text().to_std_string();
+        global_search.scope = SEARCH_SCOPES[self.global_search_scope_combobox.current_index() as usize];
 
         // If we don't have text to search, return.
         if global_search.pattern.is_empty() { return; }
 
+        if let Err(error) = UI_STATE.add_recent_search(self.get_saved_search()) {
+            log_to_status_bar(&error.to_string());
+        }
+
         if self.global_search_search_on_all_checkbox.is_checked() {
             global_search.search_on_dbs = true;
             global_search.search_on_locs = true;
@@ -433,14 +482,47 @@ impl GlobalSearchUI {
         model_text.clear();
         model_schema.clear();
 
-        let response = CENTRAL_COMMAND.recv_message_qt();
+        // The matches of each PackedFile arrive as soon as they're found, so we add them to the panels while the search goes on.
+        // To keep the UI responsive we process events while waiting, so the window is disabled until the search ends.
+        let mut main_window = self.global_search_dock_widget.parent_widget();
+        main_window.set_enabled(false);
+
+        let mut matches_db = vec![];
+        let mut matches_loc = vec![];
+        let mut matches_text = vec![];
+        let response = loop {
+            let response = CENTRAL_COMMAND.recv_message_qt_try();
+            match response {
+                Response::PackedFileMatches(matches) => {
+                    match matches {
+                        PackedFileMatches::DB(matches) => matches_db.push(matches),
+                        PackedFileMatches::Loc(matches) => matches_loc.push(matches),
+                        PackedFileMatches::Text(matches) => matches_text.push(matches),
+                    }
+
+                    // Load them in batches, once we have caught up with the search, so we don't resize the panels for every PackedFile.
+                    if CENTRAL_COMMAND.get_pending_responses_count() == 0 {
+                        Self::load_table_matches_to_ui(&mut model_db, &mut tree_view_db, &matches_db);
+                        Self::load_table_matches_to_ui(&mut model_loc, &mut tree_view_loc, &matches_loc);
+                        Self::load_text_matches_to_ui(&mut model_text, &mut tree_view_text, &matches_text);
+                        matches_db.clear();
+                        matches_loc.clear();
+                        matches_text.clear();
+                    }
+                }
+                _ => break response,
+            }
+        };
+
+        main_window.set_enabled(true);
+
         match response {
             Response::GlobalSearchVecPackedFileInfo((global_search, packed_files_info)) => {
 
-                // Load the results to their respective models. Then, store the GlobalSearch for future checks.
-                Self::load_table_matches_to_ui(&mut model_db, &mut tree_view_db, &global_search.matches_db);
-                Self::load_table_matches_to_ui(&mut model_loc, &mut tree_view_loc, &global_search.matches_loc);
-                Self::load_text_matches_to_ui(&mut model_text, &mut tree_view_text, &global_search.matches_text);
+                // Load the matches we have not loaded yet, and the schema ones, which are not sent while searching. Then, store the GlobalSearch for future checks.
+                Self::load_table_matches_to_ui(&mut model_db, &mut tree_view_db, &matches_db);
+                Self::load_table_matches_to_ui(&mut model_loc, &mut tree_view_loc, &matches_loc);
+                Self::load_text_matches_to_ui(&mut model_text, &mut tree_view_text, &matches_text);
                 Self::load_schema_matches_to_ui(&mut model_schema, &mut tree_view_schema, &global_search.matches_schema);
                 UI_STATE.set_global_search(&global_search);
                 pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(packed_files_info));
@@ -451,6 +533,106 @@ impl GlobalSearchUI {
         }
     }
 
+    /// This function returns the search currently set in the panel, so it can be saved in the search history.
+    pub unsafe fn get_saved_search(&self) -> SavedSearch {
+        SavedSearch {
+            pattern: self.global_search_search_line_edit.text().to_std_string(),
+            case_sensitive: self.global_search_case_sensitive_checkbox.is_checked(),
+            use_regex: self.global_search_use_regex_checkbox.is_checked(),
+            path_filter: self.global_search_path_filter_line_edit.text().to_std_string(),
+            scope: SEARCH_SCOPES[self.global_search_scope_combobox.current_index() as usize],
+            search_on_all: self.global_search_search_on_all_checkbox.is_checked(),
+            search_on_dbs: self.global_search_search_on_dbs_checkbox.is_checked(),
+            search_on_locs: self.global_search_search_on_locs_checkbox.is_checked(),
+            search_on_texts: self.global_search_search_on_texts_checkbox.is_checked(),
+            search_on_schema: self.global_search_search_on_schemas_checkbox.is_checked(),
+        }
+    }
+
+    /// This function sets the provided saved search in the panel, so it can be repeated.
+    pub unsafe fn load_saved_search(&mut self, search: &SavedSearch) {
+        self.global_search_search_line_edit.set_text(&QString::from_std_str(&search.pattern));
+        self.global_search_case_sensitive_checkbox.set_checked(search.case_sensitive);
+        self.global_search_use_regex_checkbox.set_checked(search.use_regex);
+        self.global_search_path_filter_line_edit.set_text(&QString::from_std_str(&search.path_filter));
+        self.global_search_scope_combobox.set_current_index(SEARCH_SCOPES.iter().position(|scope| *scope == search.scope).unwrap_or(0) as i32);
+
+        // The "All" checkbox enables/disables the other ones when toggled.
+        self.global_search_search_on_all_checkbox.set_checked(search.search_on_all);
+        self.global_search_search_on_dbs_checkbox.set_checked(search.search_on_dbs);
+        self.global_search_search_on_locs_checkbox.set_checked(search.search_on_locs);
+        self.global_search_search_on_texts_checkbox.set_checked(search.search_on_texts);
+        self.global_search_search_on_schemas_checkbox.set_checked(search.search_on_schema);
+    }
+
+    /// This function fills the search history menu with the pinned searches, followed by the recent ones.
+    ///
+    /// Each search stores in its action its position within that list.
+    pub unsafe fn load_search_history_menu(&mut self) {
+        let search_history = UI_STATE.get_search_history();
+        self.global_search_history_menu.clear();
+
+        if !search_history.get_pinned().is_empty() {
+            self.global_search_history_menu.add_section_q_string(&qtr("global_search_history_pinned"));
+        }
+
+        for (index, search) in search_history.get_pinned().iter().chain(search_history.get_recent()).enumerate() {
+            if index == search_history.get_pinned().len() {
+                self.global_search_history_menu.add_section_q_string(&qtr("global_search_history_recent"));
+            }
+
+            let mut action = self.global_search_history_menu.add_action_q_string(&QString::from_std_str(&search.get_name()));
+            action.set_data(&QVariant::from_int(index as i32));
+        }
+
+        if search_history.get_pinned().is_empty() && search_history.get_recent().is_empty() {
+            let mut action = self.global_search_history_menu.add_action_q_string(&qtr("global_search_history_empty"));
+            action.set_enabled(false);
+        }
+
+        // Pinning works over the search currently set in the panel.
+        let current_search = self.get_saved_search();
+        if search_history.is_pinned(&current_search) { self.global_search_history_pin.set_text(&qtr("global_search_history_unpin")); }
+        else { self.global_search_history_pin.set_text(&qtr("global_search_history_pin")); }
+        self.global_search_history_pin.set_enabled(!current_search.pattern.is_empty());
+        self.global_search_history_clear.set_enabled(!search_history.get_recent().is_empty());
+
+        self.global_search_history_menu.add_separator();
+        self.global_search_history_menu.add_action(self.global_search_history_pin);
+        self.global_search_history_menu.add_action(self.global_search_history_clear);
+    }
+
+    /// This function repeats the search of the provided action of the search history menu.
+    ///
+    /// Actions without a search, like the pin one, are ignored.
+    pub unsafe fn open_search_history_entry(&mut self, pack_file_contents_ui: &mut PackFileContentsUI, action: MutPtr<QAction>) {
+        let data = action.data();
+        if !data.is_valid() { return; }
+
+        let search_history = UI_STATE.get_search_history();
+        if let Some(search) = search_history.get_pinned().iter().chain(search_history.get_recent()).nth(data.to_int_0a() as usize) {
+            self.load_saved_search(search);
+            self.search(pack_file_contents_ui);
+        }
+    }
+
+    /// This function pins the search currently set in the panel, or unpins it if it was already pinned.
+    pub unsafe fn toggle_pinned_search(&mut self) {
+        let search = self.get_saved_search();
+        if search.pattern.is_empty() { return; }
+
+        if let Err(error) = UI_STATE.toggle_pinned_search(search) {
+            show_dialog(self.global_search_dock_widget, error, false);
+        }
+    }
+
+    /// This function clears the search history. Pinned searches are kept.
+    pub unsafe fn clear_search_history(&mut self) {
+        if let Err(error) = UI_STATE.clear_recent_searches() {
+            show_dialog(self.global_search_dock_widget, error, false);
+        }
+    }
+
     /// This function takes care of updating the results of a global search for the provided paths.
     ///
     /// NOTE: This only works in the `editable` search results, which are DB Tables, Locs and Text PackedFiles.
@@ -537,6 +719,7 @@ impl GlobalSearchUI {
                 pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(packed_files_info));
 
                 // Update the views of the updated PackedFiles.
+                let mut problems = Problems::default();
                 for replace_match in matches {
                     let path = match replace_match {
                         MatchHolder::Table(matches) => matches.path,
@@ -546,12 +729,13 @@ impl GlobalSearchUI {
 
                     if let Some(packed_file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == path) {
                         if let Err(error) = packed_file_view.reload(&path, pack_file_contents_ui) {
-                            show_dialog(app_ui.main_window, error, false);
+                            problems.push(&path.join("/"), error);
                         }
                     }
 
                     // Set them as modified in the UI.
                 }
+                problems.report(app_ui.main_window);
             },
             _ => unimplemented!()
         }
@@ -602,13 +786,15 @@ impl GlobalSearchUI {
                 UI_STATE.set_global_search(&global_search);
                 self.search(pack_file_contents_ui);
 
+                let mut problems = Problems::default();
                 for path in packed_files_info.iter().map(|x| &x.path) {
                     if let Some(packed_file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| &*x.get_ref_path() == path) {
                         if let Err(error) = packed_file_view.reload(&path, pack_file_contents_ui) {
-                            show_dialog(app_ui.main_window, error, false);
+                            problems.push(&path.join("/"), error);
                         }
                     }
                 }
+                problems.report(app_ui.main_window);
 
                 pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(packed_files_info));
             },
@@ -636,6 +822,13 @@ impl GlobalSearchUI {
         let gidhora = model.item_from_index(&model_index);
         let is_match = !gidhora.has_children();
 
+        // Matches from other PackFiles are read-only, so there is nothing to open.
+        let file_item = if is_match { gidhora.parent() } else { gidhora };
+        let source = file_item.data_1a(MATCH_SOURCE).to_string().to_std_string();
+        if !source.is_empty() {
+            return show_dialog(app_ui.main_window, tre("global_search_match_read_only", &[&source]), false);
+        }
+
         // If it's a match, get the path, the position data of the match, and open the PackedFile, scrolling it down.
         if is_match {
             let parent = gidhora.parent();
@@ -658,8 +851,10 @@ impl GlobalSearchUI {
 
                             // In case of tables, we have to get the logical row/column of the match and select it.
                             ViewType::Internal(view) => if let View::Table(view) = view {
-                                let table_view = view.get_ref_table();
-                                let mut table_view = table_view.get_mut_ptr_table_view_primary();
+                                let mut table_view = match view.get_ref_table().get_mut_ptr_table_view_primary() {
+                                    Some(table_view) => table_view,
+                                    None => return,
+                                };
                                 let table_filter: MutPtr<QSortFilterProxyModel> = table_view.model().static_downcast_mut();
                                 let table_model: MutPtr<QStandardItemModel> = table_filter.source_model().static_downcast_mut();
                                 let mut table_selection_model = table_view.selection_model();
@@ -717,6 +912,7 @@ impl GlobalSearchUI {
                     let mut fill2 = QStandardItem::new().into_ptr();
                     let mut fill3 = QStandardItem::new().into_ptr();
                     file.set_text(&QString::from_std_str(&path));
+                    Self::set_match_source(file, &match_table.source);
                     file.set_editable(false);
                     fill1.set_editable(false);
                     fill2.set_editable(false);
@@ -786,6 +982,7 @@ impl GlobalSearchUI {
                     let mut fill2 = QStandardItem::new().into_ptr();
                     let mut fill3 = QStandardItem::new().into_ptr();
                     file.set_text(&QString::from_std_str(&path));
+                    Self::set_match_source(file, &match_text.source);
                     file.set_editable(false);
                     fill1.set_editable(false);
                     fill2.set_editable(false);
@@ -843,6 +1040,16 @@ impl GlobalSearchUI {
         }
     }
 
+    /// This function flags the provided PackedFile item of the matches with the PackFile they're from, if it's not the open one.
+    unsafe fn set_match_source(mut file: MutPtr<QStandardItem>, source: &Option<String>) {
+        if let Some(source) = source {
+            let path = file.text().to_std_string();
+            file.set_text(&QString::from_std_str(&format!("{} ({})", path, source)));
+            file.set_tool_tip(&QString::from_std_str(&tre("global_search_match_source", &[source])));
+            file.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(source)), MATCH_SOURCE);
+        }
+    }
+
     /// This function takes care of loading the results of a global search of `SchemaMatches` into a model.
     unsafe fn load_schema_matches_to_ui(model: &mut QStandardItemModel, tree_view: &mut QTreeView, matches: &[SchemaMatches]) {
         if !matches.is_empty() {
@@ -950,6 +1157,12 @@ impl GlobalSearchUI {
         for item in items {
             let is_match = !item.has_children();
 
+            // Matches from other PackFiles are read-only, so they cannot be replaced.
+            let file_item = if is_match { item.parent() } else { item };
+            if !file_item.data_1a(MATCH_SOURCE).to_string().is_empty() {
+                continue;
+            }
+
             // If it's a match (not an entire file), get the entry and add it to the tablematches of that table.
             if is_match {
                 let parent = item.parent();
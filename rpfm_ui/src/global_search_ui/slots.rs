@@ -12,6 +12,8 @@
 Module with all the code related to the main `GlobalSearchSlots`.
 !*/
 
+use qt_widgets::SlotOfQAction;
+
 use qt_gui::QColor;
 use qt_gui::q_palette::ColorRole;
 use qt_gui::QPalette;
@@ -24,6 +26,7 @@ use regex::Regex;
 use crate::app_ui::AppUI;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::catch_panic_in_slot;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -42,6 +45,10 @@ pub struct GlobalSearchSlots {
     pub global_search_filter_locs: Slot<'static>,
     pub global_search_filter_texts: Slot<'static>,
     pub global_search_filter_schemas: Slot<'static>,
+    pub global_search_history_load_menu: Slot<'static>,
+    pub global_search_history_open: SlotOfQAction<'static>,
+    pub global_search_history_pin: SlotOfBool<'static>,
+    pub global_search_history_clear: SlotOfBool<'static>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -64,9 +71,9 @@ impl GlobalSearchSlots {
         }));
 
         // What happens when we trigger the "Clear Search" action.
-        let global_search_clear = Slot::new(move || {
+        let global_search_clear = Slot::new(move || catch_panic_in_slot(|| {
             global_search_ui.clear();
-        });
+        }));
 
         // What happens when we trigger the "Replace Current" action.
         let global_search_replace_current = Slot::new(clone!(
@@ -81,7 +88,7 @@ impl GlobalSearchSlots {
         }));
 
         // What happens when we trigger the "Check Regex" action.
-        let global_search_check_regex = SlotOfQString::new(move |string| {
+        let global_search_check_regex = SlotOfQString::new(move |string| catch_panic_in_slot(|| {
             let mut palette = QPalette::new();
             if global_search_ui.global_search_use_regex_checkbox.is_checked() {
                 if Regex::new(&string.to_std_string()).is_ok() {
@@ -96,57 +103,78 @@ impl GlobalSearchSlots {
                 palette.set_color_2a(ColorRole::Base, &QColor::from_global_color(GlobalColor::Transparent));
             }
             global_search_ui.global_search_search_line_edit.set_palette(&palette);
-        });
+        }));
 
         // What happens when we try to open the file corresponding to one of the matches.
-        let global_search_open_match = SlotOfQModelIndex::new(move |model_index_filter| {
+        let global_search_open_match = SlotOfQModelIndex::new(move |model_index_filter| catch_panic_in_slot(|| {
             GlobalSearchUI::open_match(app_ui, pack_file_contents_ui, model_index_filter.as_ptr());
-        });
+        }));
 
         // What happens when we toggle the "All" checkbox we have to disable/enable the rest ot the checkboxes..
-        let global_search_toggle_all = SlotOfBool::new(move |state| {
+        let global_search_toggle_all = SlotOfBool::new(move |state| catch_panic_in_slot(|| {
             global_search_ui.global_search_search_on_dbs_checkbox.set_enabled(!state);
             global_search_ui.global_search_search_on_locs_checkbox.set_enabled(!state);
             global_search_ui.global_search_search_on_texts_checkbox.set_enabled(!state);
             global_search_ui.global_search_search_on_schemas_checkbox.set_enabled(!state);
-        });
+        }));
 
         // What happens when we filter the different result TreeViews
-        let global_search_filter_dbs = Slot::new(move || {
+        let global_search_filter_dbs = Slot::new(move || catch_panic_in_slot(|| {
             GlobalSearchUI::filter_results(
                 global_search_ui.global_search_matches_db_tree_view,
                 global_search_ui.global_search_matches_filter_db_line_edit,
                 global_search_ui.global_search_matches_column_selector_db_combobox,
                 global_search_ui.global_search_matches_case_sensitive_db_button,
             );
-        });
+        }));
 
-        let global_search_filter_locs = Slot::new(move || {
+        let global_search_filter_locs = Slot::new(move || catch_panic_in_slot(|| {
             GlobalSearchUI::filter_results(
                 global_search_ui.global_search_matches_loc_tree_view,
                 global_search_ui.global_search_matches_filter_loc_line_edit,
                 global_search_ui.global_search_matches_column_selector_loc_combobox,
                 global_search_ui.global_search_matches_case_sensitive_loc_button,
             );
-        });
+        }));
 
-        let global_search_filter_texts = Slot::new(move || {
+        let global_search_filter_texts = Slot::new(move || catch_panic_in_slot(|| {
             GlobalSearchUI::filter_results(
                 global_search_ui.global_search_matches_text_tree_view,
                 global_search_ui.global_search_matches_filter_text_line_edit,
                 global_search_ui.global_search_matches_column_selector_text_combobox,
                 global_search_ui.global_search_matches_case_sensitive_text_button,
             );
-        });
+        }));
 
-        let global_search_filter_schemas = Slot::new(move || {
+        let global_search_filter_schemas = Slot::new(move || catch_panic_in_slot(|| {
             GlobalSearchUI::filter_results(
                 global_search_ui.global_search_matches_schema_tree_view,
                 global_search_ui.global_search_matches_filter_schema_line_edit,
                 global_search_ui.global_search_matches_column_selector_schema_combobox,
                 global_search_ui.global_search_matches_case_sensitive_schema_button,
             );
-        });
+        }));
+
+        // What happens when we open the search history menu.
+        let global_search_history_load_menu = Slot::new(move || catch_panic_in_slot(|| {
+            global_search_ui.load_search_history_menu();
+        }));
+
+        // What happens when we pick a search from the search history menu.
+        let global_search_history_open = SlotOfQAction::new(clone!(
+            mut pack_file_contents_ui => move |action| {
+            global_search_ui.open_search_history_entry(&mut pack_file_contents_ui, action);
+        }));
+
+        // What happens when we pin/unpin the current search.
+        let global_search_history_pin = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            global_search_ui.toggle_pinned_search();
+        }));
+
+        // What happens when we clear the search history.
+        let global_search_history_clear = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            global_search_ui.clear_search_history();
+        }));
 
         // And here... we return all the slots.
 		Self {
@@ -161,6 +189,10 @@ impl GlobalSearchSlots {
             global_search_filter_locs,
             global_search_filter_texts,
             global_search_filter_schemas,
+            global_search_history_load_menu,
+            global_search_history_open,
+            global_search_history_pin,
+            global_search_history_clear,
 		}
 	}
 }
@@ -23,6 +23,9 @@ pub unsafe fn set_tips(global_search_ui: &mut GlobalSearchUI) {
     //---------------------------------------------------//
     global_search_ui.global_search_use_regex_checkbox.set_status_tip(&qtr("tt_global_search_use_regex_checkbox"));
     global_search_ui.global_search_case_sensitive_checkbox.set_status_tip(&qtr("tt_global_search_case_sensitive_checkbox"));
+    global_search_ui.global_search_path_filter_line_edit.set_status_tip(&qtr("tt_global_search_path_filter_line_edit"));
+    global_search_ui.global_search_scope_combobox.set_status_tip(&qtr("tt_global_search_scope_combobox"));
+    global_search_ui.global_search_history_button.set_status_tip(&qtr("tt_global_search_history_button"));
     global_search_ui.global_search_search_on_all_checkbox.set_status_tip(&qtr("tt_global_search_search_on_all_checkbox"));
     global_search_ui.global_search_search_on_dbs_checkbox.set_status_tip(&qtr("tt_global_search_search_on_dbs_checkbox"));
     global_search_ui.global_search_search_on_locs_checkbox.set_status_tip(&qtr("tt_global_search_search_on_locs_checkbox"));
@@ -0,0 +1,32 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `LiveSyncUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `LiveSyncUI` and `LiveSyncSlots` structs.
+!*/
+
+use crate::app_ui::AppUI;
+use super::{LiveSyncUI, slots::LiveSyncSlots};
+
+/// This function connects all the actions from the provided `LiveSyncUI` with their slots in `LiveSyncSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not polute the other modules with a ton of connections.
+pub unsafe fn set_connections(app_ui: &AppUI, ui: &LiveSyncUI, slots: &LiveSyncSlots) {
+    app_ui.mymod_live_sync.toggled().connect(&slots.toggle);
+    app_ui.mymod_live_sync.triggered().connect(&slots.remember);
+
+    ui.pause_button.toggled().connect(&slots.pause);
+    ui.sync_timer.timeout().connect(&slots.sync);
+    ui.stop_button.released().connect(ui.dialog.slot_close());
+    ui.dialog.finished().connect(&slots.close);
+}
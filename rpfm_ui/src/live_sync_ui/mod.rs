@@ -0,0 +1,267 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Live Sync*** UI.
+
+While the live sync of a MyMod is on, the files created or modified in its assets folder are added to the open PackFile
+periodically, so the changes done in external programs show up in the PackFile without having to add them by hand.
+This is a non-modal dialog with the log of the synced files, and a button to pause the sync.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionBehavior};
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QLabel;
+use qt_widgets::QMainWindow;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QTimer;
+
+use cpp_core::MutPtr;
+
+use chrono::Local;
+
+use rpfm_error::ErrorKind;
+use rpfm_lib::packfile::PathType;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::ffi::add_to_q_list_safe;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::{qtr, tr, tre};
+use crate::pack_tree::{PackTree, TreePathType, TreeViewOperation};
+use crate::packedfile_views::utils::set_modified;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::UI_STATE;
+use crate::utils::{create_grid_layout, show_dialog};
+
+pub mod connections;
+pub mod slots;
+
+/// Time between syncs, in milliseconds.
+const LIVE_SYNC_INTERVAL: i32 = 1000;
+
+/// Maximum time between syncs, in milliseconds. Each sync without changes doubles the time until the next one up to this.
+const LIVE_SYNC_MAX_INTERVAL: i32 = 16000;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Live Sync Window.
+#[derive(Copy, Clone)]
+pub struct LiveSyncUI {
+    pub dialog: MutPtr<QDialog>,
+    pub status_label: MutPtr<QLabel>,
+
+    pub log_tree_view: MutPtr<QTreeView>,
+    pub log_tree_model: MutPtr<QStandardItemModel>,
+
+    pub pause_button: MutPtr<QPushButton>,
+    pub stop_button: MutPtr<QPushButton>,
+
+    pub sync_timer: MutPtr<QTimer>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `LiveSyncUI`.
+impl LiveSyncUI {
+
+    /// This function creates the entire `LiveSyncUI` Window. It's hidden until a live sync starts.
+    pub unsafe fn new(main_window: MutPtr<QMainWindow>) -> Self {
+
+        // Create the Live Sync Dialog and configure it. It's not modal, so we can keep working while it syncs.
+        let mut dialog = QDialog::new_1a(main_window).into_ptr();
+        dialog.set_window_title(&qtr("live_sync_title"));
+        dialog.set_modal(false);
+        dialog.resize_2a(700, 400);
+
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut status_label = QLabel::new();
+        status_label.set_word_wrap(true);
+        main_grid.add_widget_5a(&mut status_label, 0, 0, 1, 1);
+
+        // Create the log of synced files.
+        let mut log_tree_view = QTreeView::new_0a();
+        let mut log_tree_model = QStandardItemModel::new_0a();
+        log_tree_view.set_model(&mut log_tree_model);
+        log_tree_view.set_root_is_decorated(false);
+        log_tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        log_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        main_grid.add_widget_5a(&mut log_tree_view, 1, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut pause_button = button_box.add_button_q_string_button_role(&qtr("live_sync_pause"), q_dialog_button_box::ButtonRole::ActionRole);
+        pause_button.set_checkable(true);
+        pause_button.set_tool_tip(&qtr("tt_live_sync_pause"));
+
+        let mut stop_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        stop_button.set_text(&qtr("live_sync_stop"));
+        stop_button.set_tool_tip(&qtr("tt_live_sync_stop"));
+        main_grid.add_widget_5a(button_box.into_ptr(), 2, 0, 1, 1);
+
+        let mut sync_timer = QTimer::new_1a(dialog).into_ptr();
+        sync_timer.set_interval(LIVE_SYNC_INTERVAL);
+
+        Self {
+            dialog,
+            status_label: status_label.into_ptr(),
+            log_tree_view: log_tree_view.into_ptr(),
+            log_tree_model: log_tree_model.into_ptr(),
+            pause_button,
+            stop_button,
+            sync_timer,
+        }
+    }
+
+    /// This function starts syncing the assets folder of the open MyMod into the open PackFile, and shows the log.
+    ///
+    /// If it cannot start, the live sync action gets unchecked.
+    pub unsafe fn start(&mut self, app_ui: &mut AppUI) {
+        let assets_path = match UI_STATE.get_operational_mode().get_mymod_private_path() {
+            Some(assets_path) => assets_path,
+            None => {
+                app_ui.mymod_live_sync.set_checked(false);
+                return show_dialog(app_ui.main_window, ErrorKind::MyModPathNotConfigured, false);
+            }
+        };
+
+        CENTRAL_COMMAND.send_message_qt(Command::LiveSyncStart(assets_path.to_path_buf()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::Success => {
+                self.log_tree_model.clear();
+                let mut headers = QStringList::new();
+                headers.append_q_string(&qtr("live_sync_time"));
+                headers.append_q_string(&qtr("gen_loc_packedfile"));
+                headers.append_q_string(&qtr("live_sync_result"));
+                self.log_tree_model.set_horizontal_header_labels(&headers);
+
+                self.status_label.set_text(&QString::from_std_str(tre("live_sync_status_syncing", &[&assets_path.to_string_lossy()])));
+                self.pause_button.set_checked(false);
+                self.sync_timer.start_1a(LIVE_SYNC_INTERVAL);
+                self.dialog.show();
+            }
+            Response::Error(error) => {
+                app_ui.mymod_live_sync.set_checked(false);
+                show_dialog(app_ui.main_window, error, false);
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function stops the live sync, and hides the log.
+    pub unsafe fn stop(&mut self) {
+        self.sync_timer.stop();
+        self.dialog.hide();
+        CENTRAL_COMMAND.send_message_qt(Command::LiveSyncStop);
+    }
+
+    /// This function pauses or resumes the live sync. The changes done while paused are synced when it resumes.
+    pub unsafe fn pause(&mut self, paused: bool) {
+        if paused {
+            self.sync_timer.stop();
+            self.status_label.set_text(&qtr("live_sync_status_paused"));
+        }
+        else {
+            self.sync_timer.start_1a(LIVE_SYNC_INTERVAL);
+            if let Some(assets_path) = UI_STATE.get_operational_mode().get_mymod_private_path() {
+                self.status_label.set_text(&QString::from_std_str(tre("live_sync_status_syncing", &[&assets_path.to_string_lossy()])));
+            }
+        }
+    }
+
+    /// This function syncs the changes done to the assets folder since the last sync, and logs them.
+    ///
+    /// PackedFiles open in the UI are not synced until they're closed, so we don't overwrite the changes done in their views.
+    pub unsafe fn sync(&mut self, app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI, global_search_ui: &mut GlobalSearchUI) {
+
+        // Don't interrupt the main thread if it's waiting for another response from the background thread.
+        if !app_ui.main_window.is_enabled() || CENTRAL_COMMAND.get_pending_commands_count() > 0 || CENTRAL_COMMAND.get_pending_responses_count() > 0 {
+            return;
+        }
+
+        let open_paths = UI_STATE.get_open_packedfiles().iter().map(|packed_file_view| packed_file_view.get_path()).collect();
+        CENTRAL_COMMAND.send_message_qt(Command::LiveSyncAssets(open_paths));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let report = match response {
+            Response::LiveSyncReport(report) => report,
+
+            // If the folder cannot be read anymore, there is nothing else to sync.
+            Response::Error(error) => {
+                app_ui.mymod_live_sync.set_checked(false);
+                return show_dialog(app_ui.main_window, error, false);
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        // If nothing changed, wait longer for the next sync, so we don't walk the entire folder every second while nobody is touching it.
+        if report.is_empty() {
+            let interval = (self.sync_timer.interval() * 2).min(LIVE_SYNC_MAX_INTERVAL);
+            self.sync_timer.set_interval(interval);
+            return;
+        }
+        self.sync_timer.set_interval(LIVE_SYNC_INTERVAL);
+
+        if !report.added.is_empty() {
+            let paths = report.added.iter().map(|path| TreePathType::File(path.to_vec())).collect::<Vec<TreePathType>>();
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()));
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths));
+            UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+        }
+
+        for path in &report.updated {
+            set_modified(true, path, app_ui, pack_file_contents_ui);
+        }
+
+        // If we have a GlobalSearch on, update the results for the synced PackedFiles.
+        let global_search = UI_STATE.get_global_search();
+        if !global_search.pattern.is_empty() && (!report.added.is_empty() || !report.updated.is_empty()) {
+            let path_types = report.added.iter().chain(report.updated.iter()).map(|path| PathType::File(path.to_vec())).collect();
+            global_search_ui.search_on_path(pack_file_contents_ui, path_types);
+            UI_STATE.set_global_search(&global_search);
+        }
+
+        report.added.iter().for_each(|path| self.add_log_entry(path, &tr("live_sync_result_added")));
+        report.updated.iter().for_each(|path| self.add_log_entry(path, &tr("live_sync_result_updated")));
+        report.skipped.iter().for_each(|path| self.add_log_entry(path, &tr("live_sync_result_skipped")));
+        report.errors.iter().for_each(|(path, error)| self.add_log_entry(path, &tre("live_sync_result_error", &[error])));
+
+        for column in 0..self.log_tree_model.column_count_0a() - 1 {
+            self.log_tree_view.resize_column_to_contents(column);
+        }
+        self.log_tree_view.scroll_to_bottom();
+    }
+
+    /// This function adds a row to the log with the provided PackedFile and what happened to it.
+    unsafe fn add_log_entry(&mut self, path: &[String], result: &str) {
+        let mut qlist = QListOfQStandardItem::new();
+        let time = Local::now().format("%H:%M:%S").to_string();
+        add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&time)).into_ptr());
+        add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&path.join("/"))).into_ptr());
+        add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(result)).into_ptr());
+        self.log_tree_model.append_row_q_list_of_q_standard_item(&qlist);
+    }
+}
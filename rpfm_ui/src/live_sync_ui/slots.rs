@@ -0,0 +1,103 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `LiveSyncSlots`.
+!*/
+
+use qt_core::{Slot, SlotOfBool, SlotOfInt};
+
+use rpfm_lib::mymod::MyModConfig;
+use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::MYMOD_BASE_PATH;
+
+use crate::app_ui::AppUI;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::live_sync_ui::LiveSyncUI;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::ui_state::op_mode::OperationalMode;
+use crate::UI_STATE;
+use crate::utils::{catch_panic_in_slot, log_to_status_bar};
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of the Live Sync Window, and of the action to toggle it.
+pub struct LiveSyncSlots {
+    pub toggle: SlotOfBool<'static>,
+    pub remember: SlotOfBool<'static>,
+    pub pause: SlotOfBool<'static>,
+    pub sync: Slot<'static>,
+    pub close: SlotOfInt<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `LiveSyncSlots`.
+impl LiveSyncSlots {
+
+    /// This function creates an entire `LiveSyncSlots` struct.
+    pub unsafe fn new(
+        mut app_ui: AppUI,
+        mut live_sync_ui: LiveSyncUI,
+        mut pack_file_contents_ui: PackFileContentsUI,
+        mut global_search_ui: GlobalSearchUI,
+    ) -> Self {
+
+        // What happens when the live sync gets enabled or disabled, either by the user or because the open MyMod changed.
+        let toggle = SlotOfBool::new(move |enabled| catch_panic_in_slot(|| {
+            if enabled { live_sync_ui.start(&mut app_ui); }
+            else { live_sync_ui.stop(); }
+        }));
+
+        // What happens when the user enables or disables the live sync. It's remembered per-MyMod, so it resumes when the MyMod is opened again.
+        let remember = SlotOfBool::new(move |enabled| catch_panic_in_slot(|| {
+            if let OperationalMode::MyMod(ref game_folder_name, ref mod_name) = UI_STATE.get_operational_mode() {
+                if let Some(ref mymods_base_path) = SETTINGS.read().unwrap().paths[MYMOD_BASE_PATH] {
+                    let mymod_path = mymods_base_path.join(game_folder_name).join(mod_name);
+                    let result = MyModConfig::read(&mymod_path).and_then(|mut config| {
+                        config.live_sync = enabled;
+                        config.save(&mymod_path)
+                    });
+
+                    if let Err(error) = result {
+                        log_to_status_bar(&error.to_string());
+                    }
+                }
+            }
+        }));
+
+        // What happens when we hit the "Pause" button.
+        let pause = SlotOfBool::new(move |paused| catch_panic_in_slot(|| {
+            live_sync_ui.pause(paused);
+        }));
+
+        // What happens every time the timer ticks.
+        let sync = Slot::new(move || catch_panic_in_slot(|| {
+            live_sync_ui.sync(&mut app_ui, &mut pack_file_contents_ui, &mut global_search_ui);
+        }));
+
+        // Closing the log stops the live sync.
+        let close = SlotOfInt::new(move |_| catch_panic_in_slot(|| {
+            app_ui.mymod_live_sync.set_checked(false);
+        }));
+
+        Self {
+            toggle,
+            remember,
+            pause,
+            sync,
+            close,
+        }
+    }
+}
@@ -42,8 +42,10 @@ use log::info;
 use simplelog::{CombinedLogger, LevelFilter, TerminalMode, TermLogger, WriteLogger};
 
 use std::cell::RefCell;
+use std::env;
 use std::fs::File;
 use std::path::PathBuf;
+use std::process::exit;
 use std::rc::Rc;
 use std::sync::atomic::AtomicPtr;
 use std::thread;
@@ -65,31 +67,33 @@ use crate::utils::atomic_from_cpp_box;
 
 /// This macro is used to clone the variables into the closures without the compiler complaining.
 /// This should be BEFORE the `mod xxx` stuff, so submodules can use it too.
+///
+/// As this is used to build the closures of the slots, it also catches any panic within them, so it doesn't abort the program.
 macro_rules! clone {
     (@param _) => ( _ );
     (@param $x:ident) => ( $x );
     ($($n:ident),+ => move || $body:expr) => (
         {
             $( let $n = $n.clone(); )+
-            move || $body
+            move || crate::utils::catch_panic_in_slot(|| $body)
         }
     );
     ($($y:ident $n:ident),+ => move || $body:expr) => (
         {
             $( #[allow(unused_mut)] let mut $n = $n.clone(); )+
-            move || $body
+            move || crate::utils::catch_panic_in_slot(|| $body)
         }
     );
     ($($n:ident),+ => move |$($p:tt),+| $body:expr) => (
         {
             $( let $n = $n.clone(); )+
-            move |$(clone!(@param $p),)+| $body
+            move |$(clone!(@param $p),)+| crate::utils::catch_panic_in_slot(|| $body)
         }
     );
     ($($y:ident $n:ident),+ => move |$($p:tt),+| $body:expr) => (
         {
             $( #[allow(unused_mut)] let mut $n = $n.clone(); )+
-            move |$(clone!(@param $p),)+| $body
+            move |$(clone!(@param $p),)+| crate::utils::catch_panic_in_slot(|| $body)
         }
     );
 }
@@ -98,20 +102,31 @@ mod app_ui;
 mod background_thread;
 mod command_palette;
 mod communications;
+mod compare_ui;
+mod diagnostics_ui;
 mod ffi;
 mod global_search_ui;
+mod live_sync_ui;
 mod locale;
+mod merge_ui;
 mod mymod_ui;
 mod network_thread;
+mod orphaned_assets_ui;
 mod pack_tree;
 mod packfile_contents_ui;
 mod packedfile_views;
 mod shortcuts_ui;
+mod snapshots_ui;
 mod settings_ui;
+mod table_compare_ui;
+mod tour_ui;
+mod trash_ui;
 mod ui;
 mod ui_state;
 mod utils;
+mod vanilla_search_ui;
 mod views;
+mod workshop_ui;
 
 // Statics, so we don't need to pass them everywhere to use them.
 lazy_static! {
@@ -250,9 +265,18 @@ lazy_static! {
 /// in two different places in every update.
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// If this is the first argument, RPFM runs in headless mode, as the CLI, without initializing Qt.
+const CLI_FLAG: &str = "--cli";
+
 /// Main function.
 fn main() {
 
+    // In headless mode we never initialize Qt. The arguments after the flag are processed exactly like in the CLI.
+    if env::args_os().nth(1).map_or(false, |arg| arg == CLI_FLAG) {
+        let args = env::args_os().enumerate().filter(|(index, _)| *index != 1).map(|(_, arg)| arg).collect();
+        exit(rpfm_cli::run(args));
+    }
+
     // Log the crashes so the user can send them himself.
     if !cfg!(debug_assertions) && CrashReport::init().is_err() {
         let _ = CombinedLogger::init(
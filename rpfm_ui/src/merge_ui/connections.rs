@@ -0,0 +1,30 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `MergeUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `MergeUI` and `MergeUISlots` structs.
+!*/
+
+use super::{MergeUI, slots::MergeUISlots};
+
+/// This function connects all the actions from the provided `MergeUI` with their slots in `MergeUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not polute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &MergeUI, slots: &MergeUISlots) {
+    ui.first_button.released().connect(&slots.browse_first);
+    ui.second_button.released().connect(&slots.browse_second);
+    ui.find_button.released().connect(&slots.find_tables);
+    ui.tables_tree_view.clicked().connect(&slots.load_conflicts);
+    ui.merge_button.released().connect(&slots.merge);
+    ui.close_button.released().connect(ui.dialog.slot_close());
+}
@@ -0,0 +1,331 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Merge Tables*** UI.
+
+This is a dialog to merge the DB/Loc Tables edited by two PackFiles into the open PackFile, choosing per conflicting
+row (same keys, different values) which version wins. Meant to make compatibility patches between mods less painful.
+!*/
+
+use qt_widgets::q_abstract_item_view::EditTrigger;
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QFileDialog;
+use qt_widgets::q_file_dialog::FileMode;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::QPushButton;
+use qt_widgets::QSplitter;
+use qt_widgets::QTableView;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QBrush;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::CheckState;
+use qt_core::QFlags;
+use qt_core::QModelIndex;
+use qt_core::QString;
+use qt_core::QVariant;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+use cpp_core::Ref;
+
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use rpfm_lib::common::get_game_selected_data_path;
+use rpfm_lib::diff::RowDiffState;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::locale::{qtr, tr, tre};
+use crate::pack_tree::get_color_modified;
+use crate::utils::{create_grid_layout, setup_file_dialog, show_dialog};
+use crate::views::table::utils::clean_column_names;
+use self::slots::MergeUISlots;
+
+mod connections;
+mod slots;
+
+/// Role used to store the index of each conflicting row in the diff of its table.
+const DIFF_ROW_INDEX: i32 = 40;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Merge Tables Window.
+#[derive(Clone)]
+pub struct MergeUI {
+    dialog: MutPtr<QDialog>,
+
+    first_line_edit: MutPtr<QLineEdit>,
+    first_button: MutPtr<QPushButton>,
+    second_line_edit: MutPtr<QLineEdit>,
+    second_button: MutPtr<QPushButton>,
+
+    tables_tree_view: MutPtr<QTreeView>,
+    tables_tree_model: MutPtr<QStandardItemModel>,
+
+    conflicts_table_view: MutPtr<QTableView>,
+    conflicts_table_model: MutPtr<QStandardItemModel>,
+
+    find_button: MutPtr<QPushButton>,
+    merge_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+
+    /// Group of the tables which conflicts are currently loaded.
+    current_table: Rc<RefCell<Option<Vec<String>>>>,
+
+    /// Paths of the tables merged into the open PackFile.
+    merged_paths: Rc<RefCell<Vec<Vec<String>>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `MergeUI`.
+impl MergeUI {
+
+    /// This function creates a ***MergeUI*** dialog, executes it, and returns the paths of the tables merged into the open PackFile.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) -> Vec<Vec<String>> {
+        let ui = Self::new_with_parent(parent);
+        let slots = MergeUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.dialog.exec();
+        ui.merged_paths.replace(vec![])
+    }
+
+    /// This function creates the entire `MergeUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Merge Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("merge_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(1000, 700);
+
+        // Create the main Grid and the path selectors.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut first_label = QLabel::from_q_string(&qtr("merge_first"));
+        let mut first_line_edit = QLineEdit::new();
+        let mut first_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+
+        let mut second_label = QLabel::from_q_string(&qtr("merge_second"));
+        let mut second_line_edit = QLineEdit::new();
+        let mut second_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+
+        main_grid.add_widget_5a(&mut first_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut first_line_edit, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&mut first_button, 0, 2, 1, 1);
+        main_grid.add_widget_5a(&mut second_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut second_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&mut second_button, 1, 2, 1, 1);
+
+        // Create the list of tables edited by both PackFiles, and the table with the conflicts of the selected one.
+        let mut splitter = QSplitter::new();
+        let mut tables_tree_view = QTreeView::new_0a();
+        let mut tables_tree_model = QStandardItemModel::new_0a();
+        tables_tree_view.set_model(&mut tables_tree_model);
+        tables_tree_view.set_header_hidden(true);
+        tables_tree_view.set_root_is_decorated(false);
+        tables_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        let mut conflicts_table_view = QTableView::new_0a();
+        let mut conflicts_table_model = QStandardItemModel::new_0a();
+        conflicts_table_view.set_model(&mut conflicts_table_model);
+        conflicts_table_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        splitter.add_widget(&mut tables_tree_view);
+        splitter.add_widget(&mut conflicts_table_view);
+        splitter.set_stretch_factor(1, 3);
+        main_grid.add_widget_5a(splitter.into_ptr(), 2, 0, 1, 3);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut find_button = button_box.add_button_q_string_button_role(&qtr("merge_find"), q_dialog_button_box::ButtonRole::ActionRole);
+        let mut merge_button = button_box.add_button_q_string_button_role(&qtr("merge_merge"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        find_button.set_auto_default(false);
+        merge_button.set_auto_default(false);
+        merge_button.set_enabled(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 3, 0, 1, 3);
+
+        first_label.into_ptr();
+        second_label.into_ptr();
+
+        Self {
+            dialog,
+            first_line_edit: first_line_edit.into_ptr(),
+            first_button: first_button.into_ptr(),
+            second_line_edit: second_line_edit.into_ptr(),
+            second_button: second_button.into_ptr(),
+            tables_tree_view: tables_tree_view.into_ptr(),
+            tables_tree_model: tables_tree_model.into_ptr(),
+            conflicts_table_view: conflicts_table_view.into_ptr(),
+            conflicts_table_model: conflicts_table_model.into_ptr(),
+            find_button,
+            merge_button,
+            close_button,
+            current_table: Rc::new(RefCell::new(None)),
+            merged_paths: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    /// This function opens a FileDialog to pick a PackFile, and puts its path in the provided LineEdit.
+    pub unsafe fn browse(&self, mut line_edit: MutPtr<QLineEdit>) {
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.dialog,
+            &qtr("open_packfiles"),
+        );
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+
+        // Start in the folder of the current path if there is one. If not, in the data folder of the game selected.
+        let old_path = line_edit.text().to_std_string();
+        if let Some(parent) = Path::new(&old_path).parent().filter(|parent| parent.is_dir()) {
+            file_dialog.set_directory_q_string(&QString::from_std_str(parent.to_string_lossy()));
+        }
+        else if let Some(ref path) = get_game_selected_data_path() {
+            if path.is_dir() { file_dialog.set_directory_q_string(&QString::from_std_str(path.to_string_lossy())); }
+        }
+
+        if file_dialog.exec() == 1 {
+            line_edit.set_text(file_dialog.selected_files().at(0));
+        }
+    }
+
+    /// This function asks the background thread for the DB/Loc Tables both PackFiles have with different data, and loads them into the list.
+    ///
+    /// DB Tables are listed by type (`db/table_name`), so tables of the same type with different names are merged together.
+    pub unsafe fn find_tables(&mut self) {
+        let first_path = self.first_line_edit.text().to_std_string();
+        let second_path = self.second_line_edit.text().to_std_string();
+        if first_path.is_empty() || second_path.is_empty() {
+            return show_dialog(self.dialog, tr("merge_no_packfiles"), false);
+        }
+
+        self.dialog.set_enabled(false);
+        CENTRAL_COMMAND.send_message_qt(Command::ComparePackFiles((Some(PathBuf::from(first_path)), PathBuf::from(second_path))));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        self.dialog.set_enabled(true);
+
+        let diff = match response {
+            Response::PackFileDiff(diff) => diff,
+            Response::Error(error) => return show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.tables_tree_model.clear();
+        self.conflicts_table_model.clear();
+        self.merge_button.set_enabled(false);
+        *self.current_table.borrow_mut() = None;
+
+        // DB Tables are grouped by type, as mods rarely use the same name for their tables.
+        let tables = diff.get_ref_tables();
+        if tables.is_empty() {
+            return show_dialog(self.dialog, tr("merge_no_tables"), true);
+        }
+
+        for path in tables {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(path.join("/")));
+            self.tables_tree_model.append_row_q_standard_item(item.into_ptr());
+        }
+    }
+
+    /// This function loads the conflicting rows of the table under the provided index.
+    pub unsafe fn load_conflicts(&mut self, index: Ref<QModelIndex>) {
+        let path = index.data_0a().to_string().to_std_string().split('/').map(|x| x.to_owned()).collect::<Vec<String>>();
+        CENTRAL_COMMAND.send_message_qt(Command::GetTableDiff(path.to_vec()));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        let diff = match response {
+            Response::TableDiff(diff) => diff,
+            Response::Error(error) => return show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.conflicts_table_model.clear();
+        self.conflicts_table_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("merge_second_wins")).into_ptr());
+        for (column, field) in diff.get_ref_definition().get_ref_fields().iter().enumerate() {
+            let name = QString::from_std_str(clean_column_names(&field.get_name()));
+            self.conflicts_table_model.set_horizontal_header_item(column as i32 + 1, QStandardItem::from_q_string(&name).into_ptr());
+        }
+
+        // Only the rows with the same keys but different data are conflicts. The rest are merged as they are.
+        let color = get_color_modified();
+        for (index, (first_row, second_row, state)) in diff.get_ref_rows().iter().enumerate() {
+            if *state != RowDiffState::Modified { continue; }
+            let (first_row, second_row) = match (first_row, second_row) {
+                (Some(first_row), Some(second_row)) => (first_row, second_row),
+                _ => continue,
+            };
+
+            let row = self.conflicts_table_model.row_count_0a();
+            let mut winner = QStandardItem::new();
+            winner.set_checkable(true);
+            winner.set_check_state(CheckState::Unchecked);
+            winner.set_data_2a(&QVariant::from_int(index as i32), DIFF_ROW_INDEX);
+            self.conflicts_table_model.set_item_3a(row, 0, winner.into_ptr());
+
+            for (column, (first_cell, second_cell)) in first_row.iter().zip(second_row.iter()).enumerate() {
+                let mut item = if first_cell == second_cell {
+                    QStandardItem::from_q_string(&QString::from_std_str(first_cell.data_to_string()))
+                } else {
+                    let mut item = QStandardItem::from_q_string(&QString::from_std_str(format!("{} → {}", first_cell.data_to_string(), second_cell.data_to_string())));
+                    item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+                    item
+                };
+                item.set_editable(false);
+                self.conflicts_table_model.set_item_3a(row, column as i32 + 1, item.into_ptr());
+            }
+        }
+
+        self.conflicts_table_view.resize_columns_to_contents();
+        self.merge_button.set_enabled(true);
+        *self.current_table.borrow_mut() = Some(path);
+    }
+
+    /// This function merges the currently loaded table into the open PackFile, using the versions of the conflicting rows selected by the user.
+    pub unsafe fn merge(&mut self) {
+        let path = match *self.current_table.borrow() {
+            Some(ref path) => path.to_vec(),
+            None => return,
+        };
+
+        let other_wins = (0..self.conflicts_table_model.row_count_0a())
+            .map(|row| self.conflicts_table_model.item_2a(row, 0))
+            .filter(|item| item.check_state() == CheckState::Checked)
+            .map(|item| item.data_1a(DIFF_ROW_INDEX).to_int_0a() as usize)
+            .collect::<Vec<usize>>();
+
+        CENTRAL_COMMAND.send_message_qt(Command::MergeTableDiff((path, other_wins)));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        match response {
+            Response::VecString(path) => {
+                show_dialog(self.dialog, tre("merge_merged", &[&path.join("/")]), true);
+                let mut merged_paths = self.merged_paths.borrow_mut();
+                if !merged_paths.contains(&path) {
+                    merged_paths.push(path);
+                }
+            }
+            Response::Error(error) => show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+}
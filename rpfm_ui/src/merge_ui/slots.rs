@@ -0,0 +1,83 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `MergeUISlots`.
+!*/
+
+use qt_core::{Slot, SlotOfQModelIndex};
+
+use crate::merge_ui::MergeUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `MergeUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `MergeUI` goes here.
+pub struct MergeUISlots {
+    pub browse_first: Slot<'static>,
+    pub browse_second: Slot<'static>,
+    pub find_tables: Slot<'static>,
+    pub load_conflicts: SlotOfQModelIndex<'static>,
+    pub merge: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `MergeUISlots`.
+impl MergeUISlots {
+
+    /// This function creates a new `MergeUISlots`.
+    pub unsafe fn new(ui: &MergeUI) -> Self {
+
+        // What happens when we hit the browse button of the first PackFile.
+        let ui_browse = ui.clone();
+        let browse_first = Slot::new(move || catch_panic_in_slot(|| {
+            ui_browse.browse(ui_browse.first_line_edit)
+        }));
+
+        // What happens when we hit the browse button of the second PackFile.
+        let ui_browse = ui.clone();
+        let browse_second = Slot::new(move || catch_panic_in_slot(|| {
+            ui_browse.browse(ui_browse.second_line_edit)
+        }));
+
+        // What happens when we hit the "Find Tables" button.
+        let mut ui_find = ui.clone();
+        let find_tables = Slot::new(move || catch_panic_in_slot(|| {
+            ui_find.find_tables()
+        }));
+
+        // What happens when we select a table in the list.
+        let mut ui_conflicts = ui.clone();
+        let load_conflicts = SlotOfQModelIndex::new(move |index| catch_panic_in_slot(|| {
+            ui_conflicts.load_conflicts(index)
+        }));
+
+        // What happens when we hit the "Merge" button.
+        let mut ui = ui.clone();
+        let merge = Slot::new(move || catch_panic_in_slot(|| {
+            ui.merge()
+        }));
+
+        MergeUISlots {
+            browse_first,
+            browse_second,
+            find_tables,
+            load_conflicts,
+            merge,
+        }
+    }
+}
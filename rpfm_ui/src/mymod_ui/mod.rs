@@ -27,6 +27,7 @@ use qt_core::QString;
 use cpp_core::MutPtr;
 
 use rpfm_lib::GAME_SELECTED;
+use rpfm_lib::mymod::template::MyModTemplate;
 use rpfm_lib::SETTINGS;
 use rpfm_lib::SUPPORTED_GAMES;
 
@@ -48,6 +49,7 @@ pub struct MyModUI {
     pub mymod_dialog: MutPtr<QDialog>,
     pub mymod_game_combobox: MutPtr<QComboBox>,
     pub mymod_name_line_edit: MutPtr<QLineEdit>,
+    pub mymod_template_combobox: MutPtr<QComboBox>,
     pub mymod_cancel_button: MutPtr<QPushButton>,
     pub mymod_accept_button: MutPtr<QPushButton>,
 }
@@ -61,8 +63,8 @@ pub struct MyModUI {
 impl MyModUI {
 
     /// This function creates the entire "New Mod" dialog and executes it. It returns
-    /// the name of the mod and the folder_name of the game.
-    pub unsafe fn new(app_ui: &mut AppUI) -> Option<(String, String)> {
+    /// the name of the mod, the folder_name of the game and the MyMod template to use, if any.
+    pub unsafe fn new(app_ui: &mut AppUI) -> Option<(String, String, Option<String>)> {
 
         // Create the "New MyMod" Dialog and configure it.
         let mut dialog = QDialog::new_1a(app_ui.main_window).into_ptr();
@@ -109,6 +111,19 @@ impl MyModUI {
         }
         mymod_game_combobox.set_current_index(selected_index as i32);
 
+        // Create the "MyMod's Template" Label and ComboBox and configure them.
+        let mut mymod_template_label = QLabel::from_q_string(&qtr("mymod_template"));
+        let mut mymod_template_combobox = QComboBox::new_0a();
+        mymod_template_combobox.add_item_q_string(&qtr("mymod_template_none"));
+        let mut template_descriptions = vec![];
+        for template in MyModTemplate::get_available_templates().unwrap_or_else(|_| vec![]) {
+            mymod_template_combobox.add_item_q_string(&QString::from_std_str(&template));
+            if let Ok(template_data) = MyModTemplate::load(&template) {
+                template_descriptions.push(format!("{}: {}", template, template_data.description));
+            }
+        }
+        mymod_template_combobox.set_tool_tip(&QString::from_std_str(template_descriptions.join("\n")));
+
         // Add all the widgets to the main grid.
         main_grid.add_widget_5a(&mut mymod_name_label, 1, 0, 1, 1);
         main_grid.add_widget_5a(&mut mymod_name_line_edit, 1, 1, 1, 1);
@@ -116,11 +131,14 @@ impl MyModUI {
         main_grid.add_widget_5a(&mut mymod_game_label, 2, 0, 1, 1);
         main_grid.add_widget_5a(&mut mymod_game_combobox, 2, 1, 1, 1);
 
+        main_grid.add_widget_5a(&mut mymod_template_label, 3, 0, 1, 1);
+        main_grid.add_widget_5a(&mut mymod_template_combobox, 3, 1, 1, 1);
+
         // Create the bottom ButtonBox and configure it
         let mut button_box = QDialogButtonBox::new();
         let mymod_cancel_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Cancel);
         let mut mymod_accept_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Save);
-        main_grid.add_widget_5a(&mut button_box, 3, 0, 1, 2);
+        main_grid.add_widget_5a(&mut button_box, 4, 0, 1, 2);
 
         // Disable the "Accept" button by default.
         mymod_accept_button.set_enabled(false);
@@ -130,6 +148,7 @@ impl MyModUI {
             mymod_dialog: dialog,
             mymod_game_combobox: mymod_game_combobox.into_ptr(),
             mymod_name_line_edit: mymod_name_line_edit.into_ptr(),
+            mymod_template_combobox: mymod_template_combobox.into_ptr(),
             mymod_cancel_button,
             mymod_accept_button,
         };
@@ -143,7 +162,10 @@ impl MyModUI {
             let mut game = mymod_ui.mymod_game_combobox.current_text().to_std_string();
             if let Some(index) = game.find('&') { game.remove(index); }
             let mod_game = game.replace(' ', "_").to_lowercase();
-            Some((mod_name, mod_game))
+            let template = if mymod_ui.mymod_template_combobox.current_index() > 0 {
+                Some(mymod_ui.mymod_template_combobox.current_text().to_std_string())
+            } else { None };
+            Some((mod_name, mod_game, template))
         }
 
         // If we cancelled/closed it, return `None`.
@@ -15,6 +15,7 @@ Module with all the code related to the `MyModUISlots`.
 use qt_core::Slot;
 
 use crate::mymod_ui::MyModUI;
+use crate::utils::catch_panic_in_slot;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -37,14 +38,14 @@ impl MyModUISlots {
     pub unsafe fn new(mut mymod_ui: MyModUI) -> Self {
 
         // What happens when we change the name of the MyMod.
-        let mymod_name_change = Slot::new(move || {
+        let mymod_name_change = Slot::new(move || catch_panic_in_slot(|| {
             mymod_ui.check_my_mod_validity();
-        });
+        }));
 
         // What happens when we change the game the Mymod is for.
-        let mymod_game_change = Slot::new(move || {
+        let mymod_game_change = Slot::new(move || catch_panic_in_slot(|| {
             mymod_ui.check_my_mod_validity();
-        });
+        }));
 
         // And here... we return all the slots.
         Self {
@@ -14,9 +14,15 @@ Module with the network loop.
 Basically, this does the network checks of the program.
 !*/
 
+use reqwest::Url;
 use restson::RestClient;
 
-use rpfm_lib::schema::Schema;
+use rpfm_error::ErrorKind;
+use rpfm_lib::GAME_SELECTED;
+use rpfm_lib::schema::{APIResponseSchema, Schema, SCHEMA_REPO};
+use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::{STEAMCMD_PATH, WORKSHOP_STEAM_USER};
+use rpfm_lib::SUPPORTED_GAMES;
 
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, network::LastestRelease, network::APIResponse, THREADS_COMMUNICATION_ERROR};
@@ -117,6 +123,55 @@ pub fn network_loop() {
                 }
             }
 
+            // When we want to check if there is a schema's update available, and update the schemas if there is one...
+            Command::AutoUpdateSchemas => {
+                match Schema::check_update() {
+                    Ok(APIResponseSchema::NoUpdate) => CENTRAL_COMMAND.send_message_network_to_qt(Response::APIResponseSchema(APIResponseSchema::NoUpdate)),
+                    Ok(_) => match Schema::update_schema_repo() {
+                        Ok(_) => CENTRAL_COMMAND.send_message_network_to_qt(Response::BTreeMapStringVecTableDefinitionChanges(Schema::get_last_update_changes())),
+                        Err(error) => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(error)),
+                    }
+                    Err(error) => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(error)),
+                }
+            }
+
+            // When we want to report a vanilla table that fails to decode...
+            Command::ReportBrokenDecode(report) => {
+
+                // Reports done with outdated schemas are just noise, as the table may have been already fixed upstream.
+                match Schema::check_update() {
+                    Ok(APIResponseSchema::NoUpdate) => {
+                        let params = [("title", report.get_issue_title()), ("body", report.get_issue_body())];
+                        let url = Url::parse_with_params(&format!("{}/issues/new", SCHEMA_REPO), &params).unwrap();
+                        CENTRAL_COMMAND.send_message_network_to_qt(Response::String(url.to_string()));
+                    }
+                    Ok(_) => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(ErrorKind::SchemaOutdatedForDecodeReport.into())),
+                    Err(error) => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(error)),
+                }
+            }
+
+            // When we want to publish or update a MyMod in the Workshop...
+            Command::UploadMyModToWorkshop(pack_file_path, mut config, changelog) => {
+                let (steamcmd_path, steam_user) = {
+                    let settings = SETTINGS.read().unwrap();
+                    (settings.paths.get(STEAMCMD_PATH).cloned().flatten(), settings.settings_string[WORKSHOP_STEAM_USER].to_owned())
+                };
+
+                match steamcmd_path {
+                    Some(steamcmd_path) => match SUPPORTED_GAMES[&**GAME_SELECTED.read().unwrap()].steam_id {
+                        Some(steam_id) => match config.upload_to_workshop(&steamcmd_path, &steam_user, steam_id, &pack_file_path, &changelog) {
+                            Ok(_) => match config.save(&pack_file_path) {
+                                Ok(_) => CENTRAL_COMMAND.send_message_network_to_qt(Response::MyModConfig(config)),
+                                Err(error) => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(error)),
+                            }
+                            Err(error) => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(error)),
+                        }
+                        None => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(ErrorKind::WorkshopNotSupportedForThisGame.into())),
+                    }
+                    None => CENTRAL_COMMAND.send_message_network_to_qt(Response::Error(ErrorKind::SteamCMDPathNotConfigured.into())),
+                }
+            }
+
             // If you hit this, you fucked it up somewhere else.
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         }
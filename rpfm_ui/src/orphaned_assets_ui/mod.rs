@@ -0,0 +1,171 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Orphaned Assets*** UI.
+
+This is a dialog listing the assets (textures, models and sounds) of the open PackFile nothing references, so they can be deleted before releasing it.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionMode};
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QLabel;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rpfm_lib::packfile::PathType;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::locale::{qtr, qtre, tr};
+use crate::utils::{create_grid_layout, show_dialog};
+use self::slots::OrphanedAssetsUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Orphaned Assets Window.
+#[derive(Clone)]
+pub struct OrphanedAssetsUI {
+    dialog: MutPtr<QDialog>,
+    count_label: MutPtr<QLabel>,
+
+    assets_tree_view: MutPtr<QTreeView>,
+    assets_tree_model: MutPtr<QStandardItemModel>,
+
+    delete_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+
+    /// Paths of the PackedFiles deleted from the open PackFile.
+    deleted_paths: Rc<RefCell<Vec<Vec<String>>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `OrphanedAssetsUI`.
+impl OrphanedAssetsUI {
+
+    /// This function creates a ***OrphanedAssetsUI*** dialog, executes it, and returns the paths of the PackedFiles deleted from the open PackFile.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) -> Vec<Vec<String>> {
+        let mut ui = Self::new_with_parent(parent);
+        let slots = OrphanedAssetsUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.load_orphaned_assets();
+        ui.dialog.exec();
+        ui.deleted_paths.replace(vec![])
+    }
+
+    /// This function creates the entire `OrphanedAssetsUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Orphaned Assets Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("orphaned_assets_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(700, 500);
+
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut explanation_label = QLabel::from_q_string(&qtr("orphaned_assets_explanation"));
+        explanation_label.set_word_wrap(true);
+        let mut count_label = QLabel::new();
+        main_grid.add_widget_5a(explanation_label.into_ptr(), 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut count_label, 1, 0, 1, 1);
+
+        // Create the list of orphaned assets.
+        let mut assets_tree_view = QTreeView::new_0a();
+        let mut assets_tree_model = QStandardItemModel::new_0a();
+        assets_tree_view.set_model(&mut assets_tree_model);
+        assets_tree_view.set_header_hidden(true);
+        assets_tree_view.set_root_is_decorated(false);
+        assets_tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
+        assets_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        main_grid.add_widget_5a(&mut assets_tree_view, 2, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut delete_button = button_box.add_button_q_string_button_role(&qtr("orphaned_assets_delete"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        delete_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 3, 0, 1, 1);
+
+        Self {
+            dialog,
+            count_label: count_label.into_ptr(),
+            assets_tree_view: assets_tree_view.into_ptr(),
+            assets_tree_model: assets_tree_model.into_ptr(),
+            delete_button,
+            close_button,
+            deleted_paths: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    /// This function asks the background thread for the orphaned assets of the open PackFile, and loads them into the list.
+    pub unsafe fn load_orphaned_assets(&mut self) {
+        CENTRAL_COMMAND.send_message_qt(Command::GetOrphanedAssets);
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        let paths = match response {
+            Response::VecVecString(paths) => paths,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.assets_tree_model.clear();
+        for path in &paths {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(path.join("/")));
+            self.assets_tree_model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        self.count_label.set_text(&qtre("orphaned_assets_count", &[&paths.len().to_string()]));
+        self.delete_button.set_enabled(!paths.is_empty());
+    }
+
+    /// This function deletes the selected assets from the open PackFile. They go to the trash, so they can be restored until the PackFile is saved.
+    pub unsafe fn delete(&mut self) {
+        let indexes = self.assets_tree_view.selection_model().selected_rows_0a();
+        let paths = (0..indexes.count_0a())
+            .map(|index| PathType::File(indexes.at(index).data_0a().to_string().to_std_string().split('/').map(|x| x.to_owned()).collect()))
+            .collect::<Vec<PathType>>();
+
+        if paths.is_empty() {
+            return show_dialog(self.dialog, tr("orphaned_assets_none_selected"), false);
+        }
+
+        CENTRAL_COMMAND.send_message_qt(Command::DeletePackedFiles(paths));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::VecPathType(deleted) => {
+                self.deleted_paths.borrow_mut().extend(deleted.into_iter().filter_map(|path_type| if let PathType::File(path) = path_type { Some(path) } else { None }));
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        self.load_orphaned_assets();
+    }
+}
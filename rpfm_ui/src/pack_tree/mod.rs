@@ -21,6 +21,7 @@ use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 use qt_gui::QListOfQStandardItem;
 
+use qt_core::ItemDataRole;
 use qt_core::ItemFlag;
 use qt_core::QFlags;
 use qt_core::QModelIndex;
@@ -43,6 +44,7 @@ use std::cmp::Ordering;
 use std::path::PathBuf;
 
 use rpfm_lib::common::get_files_from_subdir;
+use rpfm_lib::mymod::git::GitStatus;
 use rpfm_lib::packfile::packedfile::PackedFileInfo;
 use rpfm_lib::packfile::{CompressionState, PackFileInfo, PathType, PFHFlags};
 use rpfm_lib::SETTINGS;
@@ -53,7 +55,7 @@ use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::ffi::add_to_q_list_safe;
 use crate::pack_tree::icons::IconType;
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::{YELLOW_BRIGHT, YELLOW_DARK, GREEN_BRIGHT, GREEN_DARK};
+use crate::{YELLOW_BRIGHT, YELLOW_DARK, GREEN_BRIGHT, GREEN_DARK, RED_BRIGHT, RED_DARK};
 
 // This one is needed for initialization on boot, so it has to be public.
 pub mod icons;
@@ -67,6 +69,9 @@ const ITEM_STATUS: i32 = 21;
 /// This const is the key of the QVariant that holds if the item changed state should be *undoable* or not.
 const ITEM_IS_FOREVER_MODIFIED: i32 = 22;
 
+/// This const is the key of the QVariant that holds the Git status of each StandardItem in a `TreeView`, for MyMods with Git enabled.
+const ITEM_GIT_STATUS: i32 = 23;
+
 /// This const is used to identify an item as a PackedFile.
 const ITEM_TYPE_FILE: i32 = 1;
 
@@ -88,6 +93,15 @@ const ITEM_STATUS_MODIFIED: i32 = 2;
 // Used to specify that a PackedFile inside it has been deleted. Unused for now.
 //const ITEM_STATUS_DELETED: i32 = 4;
 
+/// Used to specify that it or any of its contents is the same as in the last commit of the MyMod.
+const ITEM_GIT_STATUS_UNCHANGED: i32 = 0;
+
+/// Used to specify that it or any of its contents is not in the last commit of the MyMod.
+const ITEM_GIT_STATUS_UNTRACKED: i32 = 1;
+
+/// Used to specify that it or any of its contents has been modified since the last commit of the MyMod.
+const ITEM_GIT_STATUS_MODIFIED: i32 = 2;
+
 //-------------------------------------------------------------------------------//
 //                          Enums & Structs (and trait)
 //-------------------------------------------------------------------------------//
@@ -202,6 +216,9 @@ pub enum TreeViewOperation {
 
     /// Updates the tooltip of the PackedFiles with the provided info.
     UpdateTooltip(Vec<PackedFileInfo>),
+
+    /// Replaces the Git status of the entire `TreeView` with the provided one. PackedFiles not provided are marked as unchanged.
+    UpdateGitStatus(Vec<(Vec<String>, GitStatus)>),
 }
 
 /// This enum represents the different basic types of an element in the TreeView.
@@ -1461,6 +1478,36 @@ impl PackTree for MutPtr<QTreeView> {
                     item.set_tool_tip(&tooltip);
                 }
             },
+
+            // If we want to show what changed since the last commit of the MyMod...
+            TreeViewOperation::UpdateGitStatus(git_status) => {
+                if model.row_count_0a() > 0 {
+                    clean_git_status(model.item_1a(0));
+
+                    let color_untracked = get_color_git_untracked();
+                    let color_modified = get_color_git_modified();
+                    for (path, status) in git_status {
+                        let (status, color) = match status {
+                            GitStatus::Untracked => (ITEM_GIT_STATUS_UNTRACKED, color_untracked),
+                            GitStatus::Modified => (ITEM_GIT_STATUS_MODIFIED, color_modified),
+
+                            // If we don't know if it changed, better show it as modified, so the user checks it before committing.
+                            GitStatus::Unknown => (ITEM_GIT_STATUS_MODIFIED, color_modified),
+                        };
+
+                        // Mark the PackedFile and all its parents. Modified wins over untracked, so folders with both show as modified.
+                        let mut item = Self::get_item_from_type(&TreePathType::File(path), model);
+                        while !item.is_null() {
+                            let current_status = item.data_1a(ITEM_GIT_STATUS).to_int_0a();
+                            if current_status < status {
+                                item.set_data_2a(&QVariant::from_int(status), ITEM_GIT_STATUS);
+                                item.set_foreground(&QBrush::from_q_color(color.as_ref().unwrap()));
+                            }
+                            item = item.parent();
+                        }
+                    }
+                }
+            }
         }
         //*IS_MODIFIED.lock().unwrap() = update_packfile_state(None, &app_ui);
     }
@@ -1533,6 +1580,18 @@ unsafe fn clean_treeview(item: Option<MutPtr<QStandardItem>>, model: &mut QStand
     }
 }
 
+/// This function removes the Git status of the provided item and all its children.
+unsafe fn clean_git_status(mut item: MutPtr<QStandardItem>) {
+    if item.data_1a(ITEM_GIT_STATUS).to_int_0a() != ITEM_GIT_STATUS_UNCHANGED {
+        item.set_data_2a(&QVariant::from_int(ITEM_GIT_STATUS_UNCHANGED), ITEM_GIT_STATUS);
+        item.set_data_2a(&QVariant::new(), ItemDataRole::ForegroundRole.to_int());
+    }
+
+    for row in 0..item.row_count() {
+        clean_git_status(item.child_1a(row));
+    }
+}
+
 /// This function returns the currently visible childs of the given parent, and add them as `TreePathType`s to the provided list.
 unsafe fn get_visible_childs_of_item(parent: &QStandardItem, tree_view: &QTreeView, filter: &QSortFilterProxyModel, model: MutPtr<QStandardItemModel>, item_types: &mut Vec<TreePathType>) {
     for row in 0..parent.row_count() {
@@ -1756,17 +1815,34 @@ pub unsafe fn get_color_added_modified() -> MutPtr<QColor> {
     color.into_ptr()
 }
 
+/// Git status is shown as the text color, so we use the background colors of the opposite theme for it.
+pub unsafe fn get_color_git_untracked() -> MutPtr<QColor> {
+    if SETTINGS.read().unwrap().settings_bool["use_dark_theme"] {
+        QColor::from_q_string(&QString::from_std_str(*GREEN_BRIGHT)).into_ptr()
+    } else {
+        QColor::from_q_string(&QString::from_std_str(*GREEN_DARK)).into_ptr()
+    }
+}
+
+pub unsafe fn get_color_git_modified() -> MutPtr<QColor> {
+    if SETTINGS.read().unwrap().settings_bool["use_dark_theme"] {
+        QColor::from_q_string(&QString::from_std_str(*YELLOW_BRIGHT)).into_ptr()
+    } else {
+        QColor::from_q_string(&QString::from_std_str(*YELLOW_DARK)).into_ptr()
+    }
+}
+
 pub unsafe fn get_color_unmodified() -> MutPtr<QColor> {
     QColor::from_global_color(GlobalColor::Transparent).into_ptr()
 }
 
-/*pub unsafe fn get_color_deleted() -> MutPtr<QColor> {
+pub unsafe fn get_color_deleted() -> MutPtr<QColor> {
     if SETTINGS.read().unwrap().settings_bool["use_dark_theme"] {
         QColor::from_q_string(&QString::from_std_str(*RED_DARK)).into_ptr()
     } else {
         QColor::from_q_string(&QString::from_std_str(*RED_BRIGHT)).into_ptr()
     }
-}*/
+}
 
 unsafe fn get_status_item_from_item(item: MutPtr<QStandardItem>) -> MutPtr<QStandardItem> {
     if !item.is_null() {
@@ -217,11 +217,11 @@ impl PackedFileAnimFragmentView {
         let i1 = DecodedData::I32(mut_ptr_from_atomic(&self.integer_1).text().to_std_string().parse::<i32>()?);
         let i2 = DecodedData::I32(mut_ptr_from_atomic(&self.integer_2).text().to_std_string().parse::<i32>()?);
 
-        let filter: MutPtr<QSortFilterProxyModel> = self.table_view_1.get_mut_ptr_table_view_primary().model().static_downcast_mut();
+        let filter: MutPtr<QSortFilterProxyModel> = self.table_view_1.get_mut_ptr_table_view_primary().ok_or(ErrorKind::PackedFileViewClosed)?.model().static_downcast_mut();
         let table_model: MutPtr<QStandardItemModel> = filter.source_model().static_downcast_mut();
         let data_1 = get_table_from_view(table_model, &self.table_view_1.get_ref_table_definition())?;
 
-        let filter: MutPtr<QSortFilterProxyModel> = self.table_view_2.get_mut_ptr_table_view_primary().model().static_downcast_mut();
+        let filter: MutPtr<QSortFilterProxyModel> = self.table_view_2.get_mut_ptr_table_view_primary().ok_or(ErrorKind::PackedFileViewClosed)?.model().static_downcast_mut();
         let table_model: MutPtr<QStandardItemModel> = filter.source_model().static_downcast_mut();
         let data_2 = get_table_from_view(table_model, &self.table_view_2.get_ref_table_definition())?;
 
@@ -22,5 +22,12 @@ use super::{PackedFileAnimPackView, slots::PackedFileAnimPackViewSlots};
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &PackedFileAnimPackView, slots: &PackedFileAnimPackViewSlots) {
     ui.get_mut_ptr_unpack_button().released().connect(&slots.unpack);
+    ui.get_mut_ptr_open_button().released().connect(&slots.open);
+    ui.get_mut_ptr_file_list_tree_view().double_clicked().connect(&slots.open_double_click);
+    ui.get_mut_ptr_extract_button().released().connect(&slots.extract);
+    ui.get_mut_ptr_repack_button().released().connect(&slots.repack);
+    ui.get_mut_ptr_add_button().released().connect(&slots.add);
+    ui.get_mut_ptr_replace_button().released().connect(&slots.replace);
+    ui.get_mut_ptr_remove_button().released().connect(&slots.remove);
 }
 
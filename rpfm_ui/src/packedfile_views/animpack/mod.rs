@@ -10,38 +10,60 @@
 
 /*!
 Module with all the code for managing the view for AnimPack PackedFiles.
+
+The files inside the AnimPack are shown in a tree, and can be added, replaced, removed and extracted from here.
+Opening one unpacks it into the PackFile and opens it there. Once edited, `Repack` puts it back into the AnimPack.
 !*/
 
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionMode};
+use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
 use qt_widgets::QPushButton;
-use qt_widgets::QPlainTextEdit;
+use qt_widgets::QTreeView;
+
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
 
+use qt_core::QFlags;
+use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QVariant;
 
 use cpp_core::MutPtr;
 
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::AtomicPtr;
 
 use rpfm_error::{Result, ErrorKind};
 use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::packfile::packedfile::PackedFileInfo;
+use rpfm_lib::settings::{LAST_PATH_ADD_FILE, LAST_PATH_EXTRACT};
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::global_search_ui::GlobalSearchUI;
-use crate::locale::qtr;
+use crate::locale::{qtr, tr, tre};
 use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packedfile_views::utils::set_modified;
+use crate::pack_tree::{PackTree, TreePathType, TreeViewOperation};
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::utils::atomic_from_mut_ptr;
-use crate::utils::mut_ptr_from_atomic;
+use crate::UI_STATE;
+use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic, save_last_path, setup_file_dialog, show_dialog};
 use self::slots::PackedFileAnimPackViewSlots;
 
 mod connections;
 pub mod slots;
 
+/// Role of the file items of the tree where we store their full path within the AnimPack. Folders don't have it.
+const PATH_ROLE: i32 = 40;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -49,9 +71,16 @@ pub mod slots;
 /// This struct contains the view of an AnimPack PackedFile.
 pub struct PackedFileAnimPackView {
     file_count_data_label: AtomicPtr<QLabel>,
-    file_list_data_text: AtomicPtr<QPlainTextEdit>,
+    file_list_tree_view: AtomicPtr<QTreeView>,
+    file_list_tree_model: AtomicPtr<QStandardItemModel>,
 
     unpack_button: AtomicPtr<QPushButton>,
+    open_button: AtomicPtr<QPushButton>,
+    extract_button: AtomicPtr<QPushButton>,
+    repack_button: AtomicPtr<QPushButton>,
+    add_button: AtomicPtr<QPushButton>,
+    replace_button: AtomicPtr<QPushButton>,
+    remove_button: AtomicPtr<QPushButton>,
 }
 
 /// This struct contains the raw version of each pointer in `PackedFileAnimPackView`, to be used when building the slots.
@@ -60,7 +89,17 @@ pub struct PackedFileAnimPackView {
 /// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileAnimPackView`.
 #[derive(Clone)]
 pub struct PackedFileAnimPackViewRaw {
+    pub file_count_data_label: MutPtr<QLabel>,
+    pub file_list_tree_view: MutPtr<QTreeView>,
+    pub file_list_tree_model: MutPtr<QStandardItemModel>,
+
     pub unpack_button: MutPtr<QPushButton>,
+    pub open_button: MutPtr<QPushButton>,
+    pub extract_button: MutPtr<QPushButton>,
+    pub repack_button: MutPtr<QPushButton>,
+    pub add_button: MutPtr<QPushButton>,
+    pub replace_button: MutPtr<QPushButton>,
+    pub remove_button: MutPtr<QPushButton>,
     pub path: Arc<RwLock<Vec<String>>>,
 }
 
@@ -77,6 +116,7 @@ impl PackedFileAnimPackView {
         app_ui: &AppUI,
         global_search_ui: &GlobalSearchUI,
         pack_file_contents_ui: &PackFileContentsUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
     ) -> Result<(TheOneSlot, PackedFileInfo)> {
 
         CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path()));
@@ -92,40 +132,75 @@ impl PackedFileAnimPackView {
 
         let file_count_label = QLabel::from_q_string(&qtr("file_count"));
         let file_list_label = QLabel::from_q_string(&qtr("file_paths"));
+        let mut file_count_data_label = QLabel::new();
 
-        let mut file_count_data_label = QLabel::from_q_string(&QString::from_std_str(format!("{}", data.len())));
-        let mut file_list_data_text = QPlainTextEdit::from_q_string(&QString::from_std_str(format!("{:#?}", data)));
-        file_list_data_text.set_read_only(true);
+        let mut file_list_tree_view = QTreeView::new_0a();
+        let mut file_list_tree_model = QStandardItemModel::new_0a();
+        file_list_tree_view.set_model(&mut file_list_tree_model);
+        file_list_tree_view.set_header_hidden(true);
+        file_list_tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
+        file_list_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
 
         let mut unpack_button = QPushButton::from_q_string(&qtr("animpack_unpack"));
-
-        layout.add_widget_5a(&mut unpack_button, 0, 0, 1, 2);
-
-        layout.add_widget_5a(file_count_label.into_ptr(), 2, 0, 1, 1);
-        layout.add_widget_5a(file_list_label.into_ptr(), 3, 0, 1, 1);
-
-        layout.add_widget_5a(&mut file_count_data_label, 2, 1, 1, 1);
-        layout.add_widget_5a(&mut file_list_data_text, 4, 0, 1, 2);
-
-        layout.set_column_stretch(1, 10);
-
-        let packed_file_animpack_view_raw = PackedFileAnimPackViewRaw {
+        let mut open_button = QPushButton::from_q_string(&qtr("animpack_open"));
+        let mut extract_button = QPushButton::from_q_string(&qtr("animpack_extract"));
+        let mut repack_button = QPushButton::from_q_string(&qtr("animpack_repack"));
+        let mut add_button = QPushButton::from_q_string(&qtr("animpack_add"));
+        let mut replace_button = QPushButton::from_q_string(&qtr("animpack_replace"));
+        let mut remove_button = QPushButton::from_q_string(&qtr("animpack_remove"));
+        open_button.set_tool_tip(&qtr("tt_animpack_open"));
+        repack_button.set_tool_tip(&qtr("tt_animpack_repack"));
+        add_button.set_tool_tip(&qtr("tt_animpack_add"));
+
+        layout.add_widget_5a(file_count_label.into_ptr(), 0, 0, 1, 1);
+        layout.add_widget_5a(&mut file_count_data_label, 0, 1, 1, 3);
+        layout.add_widget_5a(file_list_label.into_ptr(), 1, 0, 1, 4);
+        layout.add_widget_5a(&mut file_list_tree_view, 2, 0, 1, 4);
+
+        layout.add_widget_5a(&mut unpack_button, 3, 0, 1, 1);
+        layout.add_widget_5a(&mut open_button, 3, 1, 1, 1);
+        layout.add_widget_5a(&mut extract_button, 3, 2, 1, 1);
+        layout.add_widget_5a(&mut repack_button, 3, 3, 1, 1);
+        layout.add_widget_5a(&mut add_button, 4, 0, 1, 1);
+        layout.add_widget_5a(&mut replace_button, 4, 1, 1, 1);
+        layout.add_widget_5a(&mut remove_button, 4, 2, 1, 1);
+
+        let mut packed_file_animpack_view_raw = PackedFileAnimPackViewRaw {
+            file_count_data_label: file_count_data_label.into_ptr(),
+            file_list_tree_view: file_list_tree_view.into_ptr(),
+            file_list_tree_model: file_list_tree_model.into_ptr(),
             unpack_button: unpack_button.into_ptr(),
+            open_button: open_button.into_ptr(),
+            extract_button: extract_button.into_ptr(),
+            repack_button: repack_button.into_ptr(),
+            add_button: add_button.into_ptr(),
+            replace_button: replace_button.into_ptr(),
+            remove_button: remove_button.into_ptr(),
             path: packed_file_view.get_path_raw()
         };
 
+        packed_file_animpack_view_raw.load_data(&data);
+
         let packed_file_animpack_view_slots = PackedFileAnimPackViewSlots::new(
             packed_file_animpack_view_raw.clone(),
             *app_ui,
             *pack_file_contents_ui,
             *global_search_ui,
+            slot_holder,
         );
 
         let packed_file_animpack_view = Self {
-            file_count_data_label: atomic_from_mut_ptr(file_count_data_label.into_ptr()),
-            file_list_data_text: atomic_from_mut_ptr(file_list_data_text.into_ptr()),
+            file_count_data_label: atomic_from_mut_ptr(packed_file_animpack_view_raw.file_count_data_label),
+            file_list_tree_view: atomic_from_mut_ptr(packed_file_animpack_view_raw.file_list_tree_view),
+            file_list_tree_model: atomic_from_mut_ptr(packed_file_animpack_view_raw.file_list_tree_model),
 
             unpack_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.unpack_button),
+            open_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.open_button),
+            extract_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.extract_button),
+            repack_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.repack_button),
+            add_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.add_button),
+            replace_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.replace_button),
+            remove_button: atomic_from_mut_ptr(packed_file_animpack_view_raw.remove_button),
         };
 
         connections::set_connections(&packed_file_animpack_view, &packed_file_animpack_view_slots);
@@ -137,8 +212,10 @@ impl PackedFileAnimPackView {
 
     /// Function to reload the data of the view without having to delete the view itself.
     pub unsafe fn reload_view(&mut self, data: &[String]) {
-        self.get_mut_ptr_file_count_data_label().set_text(&QString::from_std_str(format!("{}", data.len())));
-        self.get_mut_ptr_file_list_data_text().set_plain_text(&QString::from_std_str(format!("{:#?}", data)));
+        let mut file_count_data_label = self.get_mut_ptr_file_count_data_label();
+        let mut file_list_tree_view = self.get_mut_ptr_file_list_tree_view();
+        let mut file_list_tree_model = mut_ptr_from_atomic(&self.file_list_tree_model);
+        load_file_list(&mut file_count_data_label, &mut file_list_tree_view, &mut file_list_tree_model, data);
     }
 
     /// This function returns a pointer to the file count label.
@@ -147,12 +224,331 @@ impl PackedFileAnimPackView {
     }
 
     /// This function returns a pointer to the file list view.
-    pub fn get_mut_ptr_file_list_data_text(&self) -> MutPtr<QPlainTextEdit> {
-        mut_ptr_from_atomic(&self.file_list_data_text)
+    pub fn get_mut_ptr_file_list_tree_view(&self) -> MutPtr<QTreeView> {
+        mut_ptr_from_atomic(&self.file_list_tree_view)
     }
 
     /// This function returns a pointer to the `Unpack` button.
     pub fn get_mut_ptr_unpack_button(&self) -> MutPtr<QPushButton> {
         mut_ptr_from_atomic(&self.unpack_button)
     }
+
+    /// This function returns a pointer to the `Open` button.
+    pub fn get_mut_ptr_open_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.open_button)
+    }
+
+    /// This function returns a pointer to the `Extract` button.
+    pub fn get_mut_ptr_extract_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.extract_button)
+    }
+
+    /// This function returns a pointer to the `Repack` button.
+    pub fn get_mut_ptr_repack_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.repack_button)
+    }
+
+    /// This function returns a pointer to the `Add Files` button.
+    pub fn get_mut_ptr_add_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_button)
+    }
+
+    /// This function returns a pointer to the `Replace` button.
+    pub fn get_mut_ptr_replace_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.replace_button)
+    }
+
+    /// This function returns a pointer to the `Remove` button.
+    pub fn get_mut_ptr_remove_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.remove_button)
+    }
+}
+
+/// Implementation for `PackedFileAnimPackViewRaw`.
+impl PackedFileAnimPackViewRaw {
+
+    /// This function loads the provided list of files into the view.
+    pub unsafe fn load_data(&mut self, data: &[String]) {
+        load_file_list(&mut self.file_count_data_label, &mut self.file_list_tree_view, &mut self.file_list_tree_model, data);
+    }
+
+    /// This function returns the paths within the AnimPack of the selected files, and of the files within the selected folders.
+    pub unsafe fn get_selected_paths(&self) -> Vec<Vec<String>> {
+        let indexes = self.file_list_tree_view.selection_model().selected_indexes();
+        let mut paths = vec![];
+        for index in 0..indexes.count_0a() {
+            let item = self.file_list_tree_model.item_from_index(indexes.at(index));
+            get_file_paths_from_item(item, &mut paths);
+        }
+
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    /// This function returns the path within the AnimPack of the selected folder, or of the folder of the selected file.
+    ///
+    /// If there is nothing or more than one thing selected, it returns the root of the AnimPack.
+    unsafe fn get_selected_folder(&self) -> Vec<String> {
+        let indexes = self.file_list_tree_view.selection_model().selected_indexes();
+        if indexes.count_0a() != 1 { return vec![]; }
+
+        let mut item = self.file_list_tree_model.item_from_index(indexes.at(0));
+        if !item.data_1a(PATH_ROLE).is_null() {
+            item = item.parent();
+        }
+
+        let mut folder = vec![];
+        while !item.is_null() {
+            folder.insert(0, item.text().to_std_string());
+            item = item.parent();
+        }
+        folder
+    }
+
+    /// This function unpacks the selected files into the PackFile, or the entire AnimPack if `all` is true.
+    ///
+    /// It returns the paths of the unpacked PackedFiles, or None if nothing was unpacked.
+    pub unsafe fn unpack(
+        &mut self,
+        app_ui: &mut AppUI,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+        all: bool,
+    ) -> Option<Vec<Vec<String>>> {
+        let path = self.path.read().unwrap().to_vec();
+        if all {
+            CENTRAL_COMMAND.send_message_qt(Command::AnimPackUnpack(path));
+        } else {
+            let paths = self.get_selected_paths();
+            if paths.is_empty() {
+                show_dialog(self.file_list_tree_view, tr("animpack_none_selected"), false);
+                return None;
+            }
+            CENTRAL_COMMAND.send_message_qt(Command::AnimPackUnpackFiles((path, paths)));
+        }
+
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::VecVecString(paths_packedfile) => {
+                let paths = paths_packedfile.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()));
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()));
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+                // Try to reload all open files which data we altered, and close those that failed.
+                let mut open_packedfiles = UI_STATE.set_open_packedfiles();
+                paths_packedfile.iter().for_each(|path| {
+                    if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path) {
+                        if packed_file_view.reload(path, pack_file_contents_ui).is_err() {
+                            let _ = app_ui.purge_that_one_specifically(*global_search_ui, *pack_file_contents_ui, path, false);
+                        }
+                    }
+                });
+
+                Some(paths_packedfile)
+            }
+
+            Response::Error(error) => {
+                show_dialog(app_ui.main_window, error, false);
+                None
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function unpacks the selected file into the PackFile, and opens it.
+    pub unsafe fn open(
+        &mut self,
+        app_ui: &mut AppUI,
+        pack_file_contents_ui: &mut PackFileContentsUI,
+        global_search_ui: &GlobalSearchUI,
+        slot_holder: &Weak<RefCell<Vec<TheOneSlot>>>,
+    ) {
+        if self.get_selected_paths().len() != 1 {
+            return show_dialog(self.file_list_tree_view, tr("animpack_open_only_one"), false);
+        }
+
+        if let Some(paths) = self.unpack(app_ui, pack_file_contents_ui, global_search_ui, false) {
+            if let (Some(path), Some(slot_holder)) = (paths.first(), slot_holder.upgrade()) {
+                let mut tree_view = pack_file_contents_ui.packfile_contents_tree_view;
+                if let Some(model_index) = tree_view.expand_treeview_to_item(path) {
+                    let model_index = model_index.as_ref().unwrap();
+                    if model_index.is_valid() {
+                        tree_view.selection_model().select_q_model_index_q_flags_selection_flag(model_index, QFlags::from(SelectionFlag::ClearAndSelect));
+                        app_ui.open_packedfile(pack_file_contents_ui, global_search_ui, &slot_holder, false, false);
+                    }
+                }
+            }
+        }
+    }
+
+    /// This function extracts the selected files to a folder chosen by the user.
+    pub unsafe fn extract(&mut self) {
+        let paths = self.get_selected_paths();
+        if paths.is_empty() {
+            return show_dialog(self.file_list_tree_view, tr("animpack_none_selected"), false);
+        }
+
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.file_list_tree_view,
+            &qtr("animpack_extract"),
+        );
+        setup_file_dialog(&mut file_dialog, Some(LAST_PATH_EXTRACT));
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+
+        if file_dialog.exec() == 1 {
+            let extract_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            save_last_path(LAST_PATH_EXTRACT, &extract_path);
+
+            let count = paths.len();
+            CENTRAL_COMMAND.send_message_qt(Command::AnimPackExtractFiles((self.path.read().unwrap().to_vec(), paths, extract_path.to_path_buf())));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::Success => show_dialog(self.file_list_tree_view, tre("animpack_extracted", &[&count.to_string(), &extract_path.to_string_lossy()]), true),
+                Response::Error(error) => show_dialog(self.file_list_tree_view, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
+    /// This function puts back into the AnimPack the PackedFiles of the PackFile with the same path as the ones inside it.
+    pub unsafe fn repack(&mut self, app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI) {
+        let path = self.path.read().unwrap().to_vec();
+        CENTRAL_COMMAND.send_message_qt(Command::AnimPackRepack(path.to_vec()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::VecVecString(paths) => {
+                if paths.is_empty() {
+                    show_dialog(self.file_list_tree_view, tr("animpack_repack_none"), false);
+                } else {
+                    set_modified(true, &path, app_ui, pack_file_contents_ui);
+                    show_dialog(self.file_list_tree_view, tre("animpack_repacked", &[&paths.len().to_string()]), true);
+                }
+            }
+            Response::Error(error) => show_dialog(self.file_list_tree_view, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function adds files from disk to the AnimPack, or replaces the selected file with one from disk if `replace` is true.
+    ///
+    /// New files are added to the selected folder, or to the folder of the selected file.
+    pub unsafe fn add_files(&mut self, app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI, replace: bool) {
+        let selected_paths = self.get_selected_paths();
+        if replace && selected_paths.len() != 1 {
+            return show_dialog(self.file_list_tree_view, tr("animpack_replace_only_one"), false);
+        }
+
+        let title = if replace { qtr("animpack_replace") } else { qtr("animpack_add") };
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(self.file_list_tree_view, &title);
+        setup_file_dialog(&mut file_dialog, Some(LAST_PATH_ADD_FILE));
+        file_dialog.set_file_mode(if replace { FileMode::ExistingFile } else { FileMode::ExistingFiles });
+
+        if file_dialog.exec() == 1 {
+            let paths_qt = file_dialog.selected_files();
+            let paths = (0..paths_qt.size()).map(|index| PathBuf::from(paths_qt.at(index).to_std_string())).collect::<Vec<PathBuf>>();
+            if let Some(folder) = paths.first().and_then(|path| path.parent()) {
+                save_last_path(LAST_PATH_ADD_FILE, folder);
+            }
+
+            let files = if replace {
+                paths.into_iter().map(|path| (path, selected_paths[0].to_vec())).collect::<Vec<(PathBuf, Vec<String>)>>()
+            } else {
+                let folder = self.get_selected_folder();
+                paths.into_iter().filter_map(|path| {
+                    let name = path.file_name()?.to_string_lossy().to_string();
+                    let mut packed_file_path = folder.to_vec();
+                    packed_file_path.push(name);
+                    Some((path, packed_file_path))
+                }).collect::<Vec<(PathBuf, Vec<String>)>>()
+            };
+
+            let path = self.path.read().unwrap().to_vec();
+            CENTRAL_COMMAND.send_message_qt(Command::AnimPackAddFiles((path.to_vec(), files)));
+            self.update_after_edit(&path, app_ui, pack_file_contents_ui);
+        }
+    }
+
+    /// This function removes the selected files from the AnimPack.
+    pub unsafe fn remove_files(&mut self, app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI) {
+        let paths = self.get_selected_paths();
+        if paths.is_empty() {
+            return show_dialog(self.file_list_tree_view, tr("animpack_none_selected"), false);
+        }
+
+        let path = self.path.read().unwrap().to_vec();
+        CENTRAL_COMMAND.send_message_qt(Command::AnimPackRemoveFiles((path.to_vec(), paths)));
+        self.update_after_edit(&path, app_ui, pack_file_contents_ui);
+    }
+
+    /// This function reloads the list of files after an edit of the AnimPack, and marks the AnimPack as modified.
+    unsafe fn update_after_edit(&mut self, path: &[String], app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI) {
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::VecString(file_list) => {
+                self.load_data(&file_list);
+                set_modified(true, path, app_ui, pack_file_contents_ui);
+            }
+            Response::Error(error) => show_dialog(self.file_list_tree_view, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+}
+
+/// This function loads the provided list of files into the provided tree, with a node for each folder.
+unsafe fn load_file_list(
+    file_count_data_label: &mut MutPtr<QLabel>,
+    file_list_tree_view: &mut MutPtr<QTreeView>,
+    file_list_tree_model: &mut MutPtr<QStandardItemModel>,
+    data: &[String],
+) {
+    file_count_data_label.set_text(&QString::from_std_str(format!("{}", data.len())));
+    file_list_tree_model.clear();
+    let mut headers = QStringList::new();
+    headers.append_q_string(&qtr("file_paths"));
+    file_list_tree_model.set_horizontal_header_labels(&headers);
+
+    // Keep track of the folders we already created, so their files go under them.
+    let mut folders: BTreeMap<Vec<String>, MutPtr<QStandardItem>> = BTreeMap::new();
+    let mut root = file_list_tree_model.invisible_root_item();
+    for path in data {
+        let path_split = path.split('/').collect::<Vec<&str>>();
+        let mut parent = root;
+        let mut folder = vec![];
+        for name in &path_split[..path_split.len() - 1] {
+            folder.push((*name).to_owned());
+            parent = match folders.get(&folder) {
+                Some(item) => *item,
+                None => {
+                    let item = QStandardItem::from_q_string(&QString::from_std_str(name)).into_ptr();
+                    parent.append_row_q_standard_item(item);
+                    folders.insert(folder.to_vec(), item);
+                    item
+                }
+            };
+        }
+
+        let mut item = QStandardItem::from_q_string(&QString::from_std_str(path_split[path_split.len() - 1]));
+        item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(path)), PATH_ROLE);
+        parent.append_row_q_standard_item(item.into_ptr());
+    }
+
+    root.sort_children_1a(0);
+    file_list_tree_view.expand_to_depth(0);
+}
+
+/// This function adds to the provided list the path of the provided item if it's a file, or the paths of all the files within it if it's a folder.
+unsafe fn get_file_paths_from_item(item: MutPtr<QStandardItem>, paths: &mut Vec<Vec<String>>) {
+    let path = item.data_1a(PATH_ROLE);
+    if !path.is_null() {
+        paths.push(path.to_string().to_std_string().split('/').map(|x| x.to_owned()).collect());
+    }
+
+    else {
+        for row in 0..item.row_count() {
+            get_file_paths_from_item(item.child_1a(row), paths);
+        }
+    }
 }
@@ -12,17 +12,16 @@
 Module with the slots for AnimPack Views.
 !*/
 
-use qt_core::Slot;
+use qt_core::{Slot, SlotOfQModelIndex};
+
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use crate::app_ui::AppUI;
-use crate::CENTRAL_COMMAND;
-use crate::communications::*;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packedfile_views::animpack::PackedFileAnimPackViewRaw;
-use crate::pack_tree::{PackTree, TreePathType, TreeViewOperation};
+use crate::packedfile_views::TheOneSlot;
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::UI_STATE;
-use crate::utils::show_dialog;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -31,6 +30,13 @@ use crate::utils::show_dialog;
 /// This struct contains the slots of the view of a AnimPack PackedFile.
 pub struct PackedFileAnimPackViewSlots {
     pub unpack: Slot<'static>,
+    pub open: Slot<'static>,
+    pub open_double_click: SlotOfQModelIndex<'static>,
+    pub extract: Slot<'static>,
+    pub repack: Slot<'static>,
+    pub add: Slot<'static>,
+    pub replace: Slot<'static>,
+    pub remove: Slot<'static>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -45,44 +51,80 @@ impl PackedFileAnimPackViewSlots {
         view: PackedFileAnimPackViewRaw,
         mut app_ui: AppUI,
         mut pack_file_contents_ui: PackFileContentsUI,
-        global_search_ui: GlobalSearchUI
+        global_search_ui: GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
     )  -> Self {
 
         // Slot to unpack the entire AnimPack.
         let unpack = Slot::new(clone!(
-            mut view,
             mut view => move || {
+                view.unpack(&mut app_ui, &mut pack_file_contents_ui, &global_search_ui, true);
+            }
+        ));
+
+        // Slot to unpack the selected file and open it. We keep a weak reference to the slot holder, as these slots are stored in it.
+        let slot_holder = Rc::downgrade(slot_holder);
+        let open = Slot::new(clone!(
+            mut slot_holder,
+            mut view => move || {
+                view.open(&mut app_ui, &mut pack_file_contents_ui, &global_search_ui, &slot_holder);
+            }
+        ));
 
-                CENTRAL_COMMAND.send_message_qt(Command::AnimPackUnpack(view.path.read().unwrap().to_vec()));
-                let response = CENTRAL_COMMAND.recv_message_qt();
-                match response {
-                    Response::VecVecString(paths_packedfile) => {
-                        let paths = paths_packedfile.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
-                        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()));
-                        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()));
-                        UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
-
-                        // Try to reload all open files which data we altered, and close those that failed.
-                        let mut open_packedfiles = UI_STATE.set_open_packedfiles();
-                        paths_packedfile.iter().for_each(|path| {
-                            if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path) {
-                                if packed_file_view.reload(path, &mut pack_file_contents_ui).is_err() {
-                                    let _ = app_ui.purge_that_one_specifically(global_search_ui, pack_file_contents_ui, path, false);
-                                }
-                            }
-                        });
-                    }
-
-                    Response::Error(error) => show_dialog(app_ui.main_window, error, false),
-                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        // Slot to open a file when double-clicking it. Double-clicking a folder just expands it.
+        let open_double_click = SlotOfQModelIndex::new(clone!(
+            mut view => move |index| {
+                if !index.data_1a(super::PATH_ROLE).is_null() {
+                    view.open(&mut app_ui, &mut pack_file_contents_ui, &global_search_ui, &slot_holder);
                 }
             }
         ));
 
+        // Slot to extract the selected files to disk.
+        let extract = Slot::new(clone!(
+            mut view => move || {
+                view.extract();
+            }
+        ));
+
+        // Slot to put back into the AnimPack the files unpacked from it.
+        let repack = Slot::new(clone!(
+            mut view => move || {
+                view.repack(&mut app_ui, &mut pack_file_contents_ui);
+            }
+        ));
+
+        // Slot to add files from disk to the AnimPack.
+        let add = Slot::new(clone!(
+            mut view => move || {
+                view.add_files(&mut app_ui, &mut pack_file_contents_ui, false);
+            }
+        ));
+
+        // Slot to replace the selected file with one from disk.
+        let replace = Slot::new(clone!(
+            mut view => move || {
+                view.add_files(&mut app_ui, &mut pack_file_contents_ui, true);
+            }
+        ));
+
+        // Slot to remove the selected files from the AnimPack.
+        let remove = Slot::new(clone!(
+            mut view => move || {
+                view.remove_files(&mut app_ui, &mut pack_file_contents_ui);
+            }
+        ));
+
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             unpack,
+            open,
+            open_double_click,
+            extract,
+            repack,
+            add,
+            replace,
+            remove,
         }
     }
 }
-
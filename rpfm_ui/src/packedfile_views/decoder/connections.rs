@@ -23,43 +23,52 @@ use super::{PackedFileDecoderView, slots::PackedFileDecoderViewSlots};
 pub unsafe fn set_connections(ui: &PackedFileDecoderView, slots: &PackedFileDecoderViewSlots) {
 
     // Sync the scroll bars of the three hex data views.
-    ui.get_mut_ptr_hex_view_index().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
-    ui.get_mut_ptr_hex_view_raw().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
-    ui.get_mut_ptr_hex_view_decoded().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
+    ui.hex_view_index.get_unchecked().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
+    ui.hex_view_raw.get_unchecked().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
+    ui.hex_view_decoded.get_unchecked().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
 
-    ui.get_mut_ptr_bool_button().released().connect(&slots.use_this_bool);
-    ui.get_mut_ptr_f32_button().released().connect(&slots.use_this_f32);
-    ui.get_mut_ptr_i16_button().released().connect(&slots.use_this_i16);
-    ui.get_mut_ptr_i32_button().released().connect(&slots.use_this_i32);
-    ui.get_mut_ptr_i64_button().released().connect(&slots.use_this_i64);
-    ui.get_mut_ptr_string_u8_button().released().connect(&slots.use_this_string_u8);
-    ui.get_mut_ptr_string_u16_button().released().connect(&slots.use_this_string_u16);
-    ui.get_mut_ptr_optional_string_u8_button().released().connect(&slots.use_this_optional_string_u8);
-    ui.get_mut_ptr_optional_string_u16_button().released().connect(&slots.use_this_optional_string_u16);
-    ui.get_mut_ptr_sequence_u32_button().released().connect(&slots.use_this_sequence_u32);
+    // Zoom the three hex data views at the same time.
+    ui.hex_view_zoom_in.get_unchecked().triggered().connect(&slots.hex_view_zoom_in);
+    ui.hex_view_zoom_out.get_unchecked().triggered().connect(&slots.hex_view_zoom_out);
+    ui.hex_view_zoom_reset.get_unchecked().triggered().connect(&slots.hex_view_zoom_reset);
+
+    ui.bool_button.get_unchecked().released().connect(&slots.use_this_bool);
+    ui.f32_button.get_unchecked().released().connect(&slots.use_this_f32);
+    ui.i16_button.get_unchecked().released().connect(&slots.use_this_i16);
+    ui.i32_button.get_unchecked().released().connect(&slots.use_this_i32);
+    ui.i64_button.get_unchecked().released().connect(&slots.use_this_i64);
+    ui.string_u8_button.get_unchecked().released().connect(&slots.use_this_string_u8);
+    ui.string_u16_button.get_unchecked().released().connect(&slots.use_this_string_u16);
+    ui.optional_string_u8_button.get_unchecked().released().connect(&slots.use_this_optional_string_u8);
+    ui.optional_string_u16_button.get_unchecked().released().connect(&slots.use_this_optional_string_u16);
+    ui.sequence_u32_button.get_unchecked().released().connect(&slots.use_this_sequence_u32);
 
     // Signal to sync the selection between both HexViews.
-    ui.get_mut_ptr_hex_view_raw().selection_changed().connect(&slots.hex_view_selection_raw_sync);
-    ui.get_mut_ptr_hex_view_decoded().selection_changed().connect(&slots.hex_view_selection_decoded_sync);
+    ui.hex_view_raw.get_unchecked().selection_changed().connect(&slots.hex_view_selection_raw_sync);
+    ui.hex_view_decoded.get_unchecked().selection_changed().connect(&slots.hex_view_selection_decoded_sync);
 
-    ui.get_mut_ptr_table_model().data_changed().connect(&slots.table_change_field_type);
+    ui.table_model.get_unchecked().data_changed().connect(&slots.table_change_field_type);
 
-    ui.get_mut_ptr_table_view_context_menu_move_up().triggered().connect(&slots.table_view_context_menu_move_up);
-    ui.get_mut_ptr_table_view_context_menu_move_down().triggered().connect(&slots.table_view_context_menu_move_down);
-    ui.get_mut_ptr_table_view_context_menu_move_left().triggered().connect(&slots.table_view_context_menu_move_left);
-    ui.get_mut_ptr_table_view_context_menu_move_rigth().triggered().connect(&slots.table_view_context_menu_move_right);
-    ui.get_mut_ptr_table_view_context_menu_delete().triggered().connect(&slots.table_view_context_menu_delete);
+    ui.table_view_context_menu_move_up.get_unchecked().triggered().connect(&slots.table_view_context_menu_move_up);
+    ui.table_view_context_menu_move_down.get_unchecked().triggered().connect(&slots.table_view_context_menu_move_down);
+    ui.table_view_context_menu_move_left.get_unchecked().triggered().connect(&slots.table_view_context_menu_move_left);
+    ui.table_view_context_menu_move_right.get_unchecked().triggered().connect(&slots.table_view_context_menu_move_right);
+    ui.table_view_context_menu_delete.get_unchecked().triggered().connect(&slots.table_view_context_menu_delete);
+    ui.table_view_context_menu_edit_enum_values.get_unchecked().triggered().connect(&slots.table_view_context_menu_edit_enum_values);
 
-    ui.get_mut_ptr_table_view().custom_context_menu_requested().connect(&slots.table_view_context_menu);
-    ui.get_mut_ptr_table_view().selection_model().selection_changed().connect(&slots.table_view_context_menu_enabler);
+    ui.table_view.get_unchecked().clicked().connect(&slots.table_view_select_field_bytes);
+    ui.table_view.get_unchecked().custom_context_menu_requested().connect(&slots.table_view_context_menu);
+    ui.table_view.get_unchecked().selection_model().selection_changed().connect(&slots.table_view_context_menu_enabler);
 
-    ui.get_mut_ptr_table_view_old_versions().custom_context_menu_requested().connect(&slots.table_view_versions_context_menu);
-    ui.get_mut_ptr_table_view_old_versions().selection_model().selection_changed().connect(&slots.table_view_versions_context_menu_enabler);
+    ui.table_view_old_versions.get_unchecked().custom_context_menu_requested().connect(&slots.table_view_versions_context_menu);
+    ui.table_view_old_versions.get_unchecked().selection_model().selection_changed().connect(&slots.table_view_versions_context_menu_enabler);
 
-    ui.get_mut_ptr_table_view_old_versions_context_menu_load().triggered().connect(&slots.table_view_old_versions_context_menu_load);
-    ui.get_mut_ptr_table_view_old_versions_context_menu_delete().triggered().connect(&slots.table_view_old_versions_context_menu_delete);
+    ui.table_view_old_versions_context_menu_load.get_unchecked().triggered().connect(&slots.table_view_old_versions_context_menu_load);
+    ui.table_view_old_versions_context_menu_delete.get_unchecked().triggered().connect(&slots.table_view_old_versions_context_menu_delete);
+    ui.table_view_old_versions_context_menu_clone.get_unchecked().triggered().connect(&slots.table_view_old_versions_context_menu_clone);
 
-    ui.get_mut_ptr_test_definition_button().released().connect(&slots.test_definition);
-    ui.get_mut_ptr_clear_definition_button().released().connect(&slots.remove_all_fields);
-    ui.get_mut_ptr_save_button().released().connect(&slots.save_definition);
+    ui.test_definition_button.get_unchecked().released().connect(&slots.test_definition);
+    ui.import_from_assembly_kit_button.get_unchecked().released().connect(&slots.import_from_assembly_kit);
+    ui.clear_definition_button.get_unchecked().released().connect(&slots.remove_all_fields);
+    ui.save_button.get_unchecked().released().connect(&slots.save_definition);
 }
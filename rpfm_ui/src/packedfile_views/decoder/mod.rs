@@ -14,6 +14,8 @@ Module with all the code for managing the PackedFile decoder.
 
 use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionMode};
 use qt_widgets::q_header_view::ResizeMode;
+use qt_widgets::QCheckBox;
+use qt_widgets::QDialog;
 use qt_widgets::QFrame;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
@@ -24,14 +26,16 @@ use qt_widgets::QGroupBox;
 use qt_widgets::QTableView;
 use qt_widgets::QTreeView;
 use qt_widgets::QPushButton;
+use qt_widgets::QSpinBox;
 use qt_widgets::QTextEdit;
 
 use qt_gui::QBrush;
-use qt_gui::QFontMetrics;
+use qt_gui::QColor;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 use qt_gui::QTextCharFormat;
+use qt_gui::q_text_char_format::UnderlineStyle;
 use qt_gui::q_text_cursor::{MoveOperation, MoveMode};
 
 use qt_core::ContextMenuPolicy;
@@ -46,11 +50,18 @@ use qt_core::QObject;
 use qt_core::CheckState;
 use qt_core::QStringList;
 use qt_core::QModelIndex;
+use qt_core::Slot;
 
-use cpp_core::{CppBox, MutPtr};
+use cpp_core::{CppBox, MutPtr, Ref};
+
+use ron::ser::{to_string_pretty, PrettyConfig};
 
 use std::collections::BTreeMap;
-use std::sync::{Arc, atomic::AtomicPtr, Mutex};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use rpfm_error::{ErrorKind, Result};
 
@@ -63,6 +74,7 @@ use rpfm_lib::packedfile::table::{loc, loc::Loc};
 use rpfm_lib::packedfile::table::{matched_combat, matched_combat::MatchedCombat};
 use rpfm_lib::schema::{Definition, Field, FieldType, Schema, VersionedFile};
 use rpfm_lib::SCHEMA;
+use rpfm_lib::settings::ZOOM_DECODER;
 use rpfm_lib::SETTINGS;
 
 use crate::app_ui::AppUI;
@@ -71,14 +83,18 @@ use crate::communications::*;
 use crate::ffi::add_to_q_list_safe;
 use crate::ffi::new_combobox_item_delegate_safe;
 use crate::ffi::new_spinbox_item_delegate_safe;
+use crate::ffi::new_zoom_filter_safe;
 use crate::FONT_MONOSPACE;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
-use crate::utils::atomic_from_mut_ptr;
+use crate::packedfile_views::hex::{hex_selection_sync, load_hex_data};
+use crate::utils::{change_zoom_level, get_zoom_level, get_zoomed_font};
 use crate::utils::create_grid_layout;
+use crate::utils::new_help_label;
 use crate::utils::ref_from_atomic;
-use crate::utils::mut_ptr_from_atomic;
+use crate::utils::show_dialog;
+use crate::utils::QtPtr;
 use self::slots::PackedFileDecoderViewSlots;
 
 pub mod connections;
@@ -96,50 +112,75 @@ const SUPPORTED_PACKED_FILE_TYPES: [PackedFileType; 5] = [
 
 pub const DECODER_EXTENSION: &str = "-rpfm-decoder";
 
+/// Page of the manual explaining how to use the decoder.
+const DOCS_PAGE: &str = "chapter_editors_decoder.html";
+
+/// Roles used to store, in the first column of each field, the bytes that field decoded in the first row.
+const FIELD_BYTE_START: i32 = 40;
+const FIELD_BYTE_END: i32 = 41;
+
+/// Role of the "Enum Values" items with the descriptions of the values, in JSON.
+const ENUM_DESCRIPTIONS: i32 = 42;
+
+/// Column of the "Enum Values" in the fields table.
+const ENUM_VALUES_COLUMN: i32 = 15;
+
+/// Colors used to paint each field's bytes in the hex views. If there are more fields than colors, we cycle through them.
+const FIELD_COLORS_BRIGHT: [&str; 8] = ["#FFF59D", "#B3E5FC", "#C8E6C9", "#FFCCBC", "#E1BEE7", "#B2DFDB", "#F8BBD0", "#DCEDC8"];
+const FIELD_COLORS_DARK: [&str; 8] = ["#6B6420", "#1E4F66", "#2E5730", "#6E3A26", "#553060", "#1F5550", "#6A2A40", "#4A5A2A"];
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
 
 /// This struct contains the view of the PackedFile Decoder.
 pub struct PackedFileDecoderView {
-    hex_view_index: AtomicPtr<QTextEdit>,
-    hex_view_raw: AtomicPtr<QTextEdit>,
-    hex_view_decoded: AtomicPtr<QTextEdit>,
-
-    table_view: AtomicPtr<QTreeView>,
-    table_model: AtomicPtr<QStandardItemModel>,
-
-    table_view_context_menu_move_up: AtomicPtr<QAction>,
-    table_view_context_menu_move_down: AtomicPtr<QAction>,
-    table_view_context_menu_move_left: AtomicPtr<QAction>,
-    table_view_context_menu_move_right: AtomicPtr<QAction>,
-    table_view_context_menu_delete: AtomicPtr<QAction>,
-
-    bool_button: AtomicPtr<QPushButton>,
-    f32_button: AtomicPtr<QPushButton>,
-    i16_button: AtomicPtr<QPushButton>,
-    i32_button: AtomicPtr<QPushButton>,
-    i64_button: AtomicPtr<QPushButton>,
-    string_u8_button: AtomicPtr<QPushButton>,
-    string_u16_button: AtomicPtr<QPushButton>,
-    optional_string_u8_button: AtomicPtr<QPushButton>,
-    optional_string_u16_button: AtomicPtr<QPushButton>,
-    sequence_u32_button: AtomicPtr<QPushButton>,
-
-    packed_file_info_version_decoded_label: AtomicPtr<QLabel>,
-    packed_file_info_entry_count_decoded_label: AtomicPtr<QLabel>,
-
-    table_view_old_versions: AtomicPtr<QTableView>,
-    table_view_old_versions_context_menu_load: AtomicPtr<QAction>,
-    table_view_old_versions_context_menu_delete: AtomicPtr<QAction>,
-
-    test_definition_button: AtomicPtr<QPushButton>,
-    clear_definition_button: AtomicPtr<QPushButton>,
-    save_button: AtomicPtr<QPushButton>,
+    hex_view_index: QtPtr<QTextEdit>,
+    hex_view_raw: QtPtr<QTextEdit>,
+    hex_view_decoded: QtPtr<QTextEdit>,
+    hex_view_zoom_in: QtPtr<QAction>,
+    hex_view_zoom_out: QtPtr<QAction>,
+    hex_view_zoom_reset: QtPtr<QAction>,
+
+    table_view: QtPtr<QTreeView>,
+    table_model: QtPtr<QStandardItemModel>,
+
+    table_view_context_menu_move_up: QtPtr<QAction>,
+    table_view_context_menu_move_down: QtPtr<QAction>,
+    table_view_context_menu_move_left: QtPtr<QAction>,
+    table_view_context_menu_move_right: QtPtr<QAction>,
+    table_view_context_menu_delete: QtPtr<QAction>,
+    table_view_context_menu_edit_enum_values: QtPtr<QAction>,
+
+    bool_button: QtPtr<QPushButton>,
+    f32_button: QtPtr<QPushButton>,
+    i16_button: QtPtr<QPushButton>,
+    i32_button: QtPtr<QPushButton>,
+    i64_button: QtPtr<QPushButton>,
+    string_u8_button: QtPtr<QPushButton>,
+    string_u16_button: QtPtr<QPushButton>,
+    optional_string_u8_button: QtPtr<QPushButton>,
+    optional_string_u16_button: QtPtr<QPushButton>,
+    sequence_u32_button: QtPtr<QPushButton>,
+
+    packed_file_info_version_decoded_label: QtPtr<QLabel>,
+    packed_file_info_entry_count_decoded_label: QtPtr<QLabel>,
+
+    table_view_old_versions: QtPtr<QTableView>,
+    table_view_old_versions_context_menu_load: QtPtr<QAction>,
+    table_view_old_versions_context_menu_delete: QtPtr<QAction>,
+    table_view_old_versions_context_menu_clone: QtPtr<QAction>,
+
+    test_definition_button: QtPtr<QPushButton>,
+    import_from_assembly_kit_button: QtPtr<QPushButton>,
+    clear_definition_button: QtPtr<QPushButton>,
+    save_button: QtPtr<QPushButton>,
 
     packed_file_type: PackedFileType,
     packed_file_path: Vec<String>,
     packed_file_data: Arc<Vec<u8>>,
+
+    alive: Arc<AtomicBool>,
 }
 
 /// This struct contains the raw version of each pointer in `PackedFileDecoderViewRaw`, to be used when building the slots.
@@ -151,6 +192,9 @@ pub struct PackedFileDecoderViewRaw {
     pub hex_view_index: MutPtr<QTextEdit>,
     pub hex_view_raw: MutPtr<QTextEdit>,
     pub hex_view_decoded: MutPtr<QTextEdit>,
+    pub hex_view_zoom_in: MutPtr<QAction>,
+    pub hex_view_zoom_out: MutPtr<QAction>,
+    pub hex_view_zoom_reset: MutPtr<QAction>,
 
     pub table_view: MutPtr<QTreeView>,
     pub table_model: MutPtr<QStandardItemModel>,
@@ -161,6 +205,7 @@ pub struct PackedFileDecoderViewRaw {
     pub table_view_context_menu_move_left: MutPtr<QAction>,
     pub table_view_context_menu_move_right: MutPtr<QAction>,
     pub table_view_context_menu_delete: MutPtr<QAction>,
+    pub table_view_context_menu_edit_enum_values: MutPtr<QAction>,
 
     pub bool_line_edit: MutPtr<QLineEdit>,
     pub f32_line_edit: MutPtr<QLineEdit>,
@@ -186,6 +231,7 @@ pub struct PackedFileDecoderViewRaw {
 
     pub packed_file_info_version_decoded_label: MutPtr<QLabel>,
     pub packed_file_info_entry_count_decoded_label: MutPtr<QLabel>,
+    pub packed_file_info_row_size_decoded_label: MutPtr<QLabel>,
 
     pub table_view_old_versions: MutPtr<QTableView>,
     pub table_model_old_versions: MutPtr<QStandardItemModel>,
@@ -193,14 +239,20 @@ pub struct PackedFileDecoderViewRaw {
     pub table_view_old_versions_context_menu: MutPtr<QMenu>,
     pub table_view_old_versions_context_menu_load: MutPtr<QAction>,
     pub table_view_old_versions_context_menu_delete: MutPtr<QAction>,
+    pub table_view_old_versions_context_menu_clone: MutPtr<QAction>,
 
     pub test_definition_button: MutPtr<QPushButton>,
+    pub import_from_assembly_kit_button: MutPtr<QPushButton>,
     pub clear_definition_button: MutPtr<QPushButton>,
     pub save_button: MutPtr<QPushButton>,
+    pub apply_to_all_versions_checkbox: MutPtr<QCheckBox>,
+    pub is_ordered_checkbox: MutPtr<QCheckBox>,
 
     pub packed_file_type: PackedFileType,
     pub packed_file_path: Vec<String>,
     pub packed_file_data: Arc<Vec<u8>>,
+
+    pub alive: Arc<AtomicBool>,
 }
 
 /// This struct contains data we need to keep separated from the other two due to mutability issues.
@@ -238,10 +290,9 @@ impl PackedFileDecoderView {
 
         let packed_file_type = PackedFileType::get_packed_file_type_by_data(&packed_file);
 
-        // If the PackedFileType is not one of the ones supported by the schema system, get out.
-        if !SUPPORTED_PACKED_FILE_TYPES.iter().any(|x| x == &packed_file_type)  {
-            return Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into());
-        }
+        // If the PackedFileType is not one of the ones supported by the schema system, we open it in "Raw Mode":
+        // no header, no schema, and the resulting definition can only be exported to a research file.
+        let raw_mode = is_raw_mode(packed_file_type);
 
         // Create the hex view on the left side.
         let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
@@ -256,9 +307,18 @@ impl PackedFileDecoderView {
         let mut hex_view_decoded = QTextEdit::new();
         let mut hex_view_layout = create_grid_layout(hex_view_group.static_upcast_mut());
 
-        hex_view_index.set_font(ref_from_atomic(&*FONT_MONOSPACE));
-        hex_view_raw.set_font(ref_from_atomic(&*FONT_MONOSPACE));
-        hex_view_decoded.set_font(ref_from_atomic(&*FONT_MONOSPACE));
+        let hex_view_font = get_zoomed_font(ref_from_atomic(&*FONT_MONOSPACE), get_zoom_level(ZOOM_DECODER));
+        hex_view_index.set_font(&hex_view_font);
+        hex_view_raw.set_font(&hex_view_font);
+        hex_view_decoded.set_font(&hex_view_font);
+
+        // Actions to zoom the hex views. They're not in any menu, just triggered by their shortcuts or Ctrl + Wheel.
+        let mut hex_view_zoom_in = QAction::from_q_string_q_object(&QString::from_std_str("Zoom In"), hex_view_group).into_ptr();
+        let mut hex_view_zoom_out = QAction::from_q_string_q_object(&QString::from_std_str("Zoom Out"), hex_view_group).into_ptr();
+        let hex_view_zoom_reset = QAction::from_q_string_q_object(&QString::from_std_str("Reset Zoom"), hex_view_group).into_ptr();
+        new_zoom_filter_safe(&mut hex_view_index.viewport(), &mut hex_view_zoom_in, &mut hex_view_zoom_out);
+        new_zoom_filter_safe(&mut hex_view_raw.viewport(), &mut hex_view_zoom_in, &mut hex_view_zoom_out);
+        new_zoom_filter_safe(&mut hex_view_decoded.viewport(), &mut hex_view_zoom_in, &mut hex_view_zoom_out);
 
         hex_view_layout.add_widget_5a(&mut hex_view_index, 0, 0, 1, 1);
         hex_view_layout.add_widget_5a(&mut hex_view_raw, 0, 1, 1, 1);
@@ -286,6 +346,7 @@ impl PackedFileDecoderView {
         let mut table_view_context_menu_move_left = table_view_context_menu.add_action_q_string(&QString::from_std_str("Move Left"));
         let mut table_view_context_menu_move_right = table_view_context_menu.add_action_q_string(&QString::from_std_str("Move Right"));
         let mut table_view_context_menu_delete = table_view_context_menu.add_action_q_string(&QString::from_std_str("Delete"));
+        let mut table_view_context_menu_edit_enum_values = table_view_context_menu.add_action_q_string(&QString::from_std_str("Edit Enum Values..."));
 
         // Disable them by default.
         table_view_context_menu_move_up.set_enabled(false);
@@ -293,6 +354,7 @@ impl PackedFileDecoderView {
         table_view_context_menu_move_left.set_enabled(false);
         table_view_context_menu_move_right.set_enabled(false);
         table_view_context_menu_delete.set_enabled(false);
+        table_view_context_menu_edit_enum_values.set_enabled(false);
 
         layout.add_widget_5a(table_view.as_mut_ptr(), 0, 1, 1, 2);
 
@@ -384,6 +446,8 @@ impl PackedFileDecoderView {
         let packed_file_info_type_label = QLabel::from_q_string(&QString::from_std_str("PackedFile Type:"));
         let packed_file_info_version_label = QLabel::from_q_string(&QString::from_std_str("PackedFile version:"));
         let packed_file_info_entry_count_label = QLabel::from_q_string(&QString::from_std_str("PackedFile entry count:"));
+        let packed_file_info_header_size_label = QLabel::from_q_string(&QString::from_std_str("Header size:"));
+        let packed_file_info_row_size_label = QLabel::from_q_string(&QString::from_std_str("Min. row size:"));
 
         let packed_file_info_type_decoded_label = QLabel::from_q_string(&QString::from_std_str(match packed_file_type {
             PackedFileType::DB => format!("DB/{}", packed_file_view.get_path()[1]),
@@ -391,6 +455,14 @@ impl PackedFileDecoderView {
         }));
         let mut packed_file_info_version_decoded_label = QLabel::new();
         let mut packed_file_info_entry_count_decoded_label = QLabel::new();
+        let mut packed_file_info_row_size_decoded_label = QLabel::new();
+
+        // The header is known from the start and it cannot be decoded as fields, so we only show its size.
+        let header_size = get_header_size(
+            packed_file_type,
+            &packed_file.get_raw_data()?
+        )?;
+        let packed_file_info_header_size_decoded_label = QLabel::from_q_string(&QString::from_std_str(format!("{} bytes (locked)", header_size)));
 
         info_layout.add_widget_5a(packed_file_info_type_label.into_ptr(), 0, 0, 1, 1);
         info_layout.add_widget_5a(packed_file_info_version_label.into_ptr(), 1, 0, 1, 1);
@@ -401,6 +473,12 @@ impl PackedFileDecoderView {
         info_layout.add_widget_5a(packed_file_info_entry_count_label.into_ptr(), 2, 0, 1, 1);
         info_layout.add_widget_5a(&mut packed_file_info_entry_count_decoded_label, 2, 1, 1, 1);
 
+        info_layout.add_widget_5a(packed_file_info_header_size_label.into_ptr(), 3, 0, 1, 1);
+        info_layout.add_widget_5a(packed_file_info_header_size_decoded_label.into_ptr(), 3, 1, 1, 1);
+
+        info_layout.add_widget_5a(packed_file_info_row_size_label.into_ptr(), 4, 0, 1, 1);
+        info_layout.add_widget_5a(&mut packed_file_info_row_size_decoded_label, 4, 1, 1, 1);
+
         layout.add_widget_5a(info_frame.into_ptr(), 1, 2, 1, 1);
 
         //---------------------------------------------//
@@ -421,8 +499,10 @@ impl PackedFileDecoderView {
         let mut table_view_old_versions_context_menu = QMenu::new();
         let mut table_view_old_versions_context_menu_load = table_view_old_versions_context_menu.add_action_q_string(&QString::from_std_str("&Load"));
         let mut table_view_old_versions_context_menu_delete = table_view_old_versions_context_menu.add_action_q_string(&QString::from_std_str("&Delete"));
+        let mut table_view_old_versions_context_menu_clone = table_view_old_versions_context_menu.add_action_q_string(&QString::from_std_str("&Clone to Version..."));
         table_view_old_versions_context_menu_load.set_enabled(false);
         table_view_old_versions_context_menu_delete.set_enabled(false);
+        table_view_old_versions_context_menu_clone.set_enabled(false);
 
         layout.add_widget_5a(&mut table_view_old_versions, 2, 2, 1, 1);
 
@@ -435,13 +515,30 @@ impl PackedFileDecoderView {
 
         // Create the bottom Buttons.
         let mut test_definition_button = QPushButton::from_q_string(&QString::from_std_str("Test Definition"));
+        let mut import_from_assembly_kit_button = QPushButton::from_q_string(&QString::from_std_str("Import from Assembly Kit"));
         let mut clear_definition_button = QPushButton::from_q_string(&QString::from_std_str("Remove all fields"));
-        let mut save_button = QPushButton::from_q_string(&QString::from_std_str("Finish it!"));
+        let mut save_button = QPushButton::from_q_string(&QString::from_std_str(if raw_mode { "Export to Research File" } else { "Finish it!" }));
+        let mut apply_to_all_versions_checkbox = QCheckBox::from_q_string(&QString::from_std_str("Apply names, descriptions and references to all versions"));
+        apply_to_all_versions_checkbox.set_tool_tip(&QString::from_std_str("If checked, when saving, the names, descriptions and references of the fields will also be applied to the same fields in the other versions of this table."));
+        apply_to_all_versions_checkbox.set_enabled(!raw_mode);
+        let mut is_ordered_checkbox = QCheckBox::from_q_string(&QString::from_std_str("Rows are ordered"));
+        is_ordered_checkbox.set_tool_tip(&QString::from_std_str("If checked, the order of the rows of this table matters to the game, so its rows can be reordered in the table view."));
+        is_ordered_checkbox.set_enabled(!raw_mode);
+
+        // In Raw Mode there is nothing in the schema to test against.
+        test_definition_button.set_enabled(!raw_mode);
+
+        // Only DB Tables have their definitions in the Assembly Kit.
+        import_from_assembly_kit_button.set_enabled(packed_file_type == PackedFileType::DB);
 
         // Add them to the Dialog.
         button_box_layout.add_widget_5a(&mut test_definition_button, 0, 0, 1, 1);
-        button_box_layout.add_widget_5a(&mut clear_definition_button, 0, 1, 1, 1);
-        button_box_layout.add_widget_5a(&mut save_button, 0, 2, 1, 1);
+        button_box_layout.add_widget_5a(&mut import_from_assembly_kit_button, 0, 1, 1, 1);
+        button_box_layout.add_widget_5a(&mut clear_definition_button, 0, 2, 1, 1);
+        button_box_layout.add_widget_5a(&mut save_button, 0, 3, 1, 1);
+        button_box_layout.add_widget_5a(new_help_label(DOCS_PAGE).into_ptr(), 0, 4, 1, 1);
+        button_box_layout.add_widget_5a(&mut apply_to_all_versions_checkbox, 1, 0, 1, 3);
+        button_box_layout.add_widget_5a(&mut is_ordered_checkbox, 1, 3, 1, 2);
 
         layout.add_widget_5a(button_box.into_ptr(), 4, 1, 1, 2);
 
@@ -449,15 +546,13 @@ impl PackedFileDecoderView {
         layout.set_row_stretch(0, 10);
         layout.set_row_stretch(2, 5);
 
-        let header_size = get_header_size(
-            packed_file_type,
-            &packed_file.get_raw_data()?
-        )?;
-
         let mut packed_file_decoder_view_raw = PackedFileDecoderViewRaw {
             hex_view_index: hex_view_index.into_ptr(),
             hex_view_raw: hex_view_raw.into_ptr(),
             hex_view_decoded: hex_view_decoded.into_ptr(),
+            hex_view_zoom_in,
+            hex_view_zoom_out,
+            hex_view_zoom_reset,
 
             table_view: table_view.into_ptr(),
             table_model: table_model.into_ptr(),
@@ -468,6 +563,7 @@ impl PackedFileDecoderView {
             table_view_context_menu_move_left,
             table_view_context_menu_move_right,
             table_view_context_menu_delete,
+            table_view_context_menu_edit_enum_values,
 
             bool_line_edit: bool_line_edit.into_ptr(),
             f32_line_edit: f32_line_edit.into_ptr(),
@@ -493,6 +589,7 @@ impl PackedFileDecoderView {
 
             packed_file_info_version_decoded_label: packed_file_info_version_decoded_label.into_ptr(),
             packed_file_info_entry_count_decoded_label: packed_file_info_entry_count_decoded_label.into_ptr(),
+            packed_file_info_row_size_decoded_label: packed_file_info_row_size_decoded_label.into_ptr(),
 
             table_view_old_versions: table_view_old_versions.into_ptr(),
             table_model_old_versions: table_model_old_versions.into_ptr(),
@@ -500,14 +597,20 @@ impl PackedFileDecoderView {
             table_view_old_versions_context_menu: table_view_old_versions_context_menu.into_ptr(),
             table_view_old_versions_context_menu_load,
             table_view_old_versions_context_menu_delete,
+            table_view_old_versions_context_menu_clone,
 
             test_definition_button: test_definition_button.into_ptr(),
+            import_from_assembly_kit_button: import_from_assembly_kit_button.into_ptr(),
             clear_definition_button: clear_definition_button.into_ptr(),
             save_button: save_button.into_ptr(),
+            apply_to_all_versions_checkbox: apply_to_all_versions_checkbox.into_ptr(),
+            is_ordered_checkbox: is_ordered_checkbox.into_ptr(),
 
             packed_file_type,
             packed_file_path: packed_file.get_path().to_vec(),
             packed_file_data: Arc::new(packed_file.get_raw_data()?),
+
+            alive: Arc::new(AtomicBool::new(true)),
         };
 
         let packed_file_decoder_mutable_data = PackedFileDecoderMutableData {
@@ -522,45 +625,54 @@ impl PackedFileDecoderView {
             *global_search_ui,
         );
 
+        let alive = packed_file_decoder_view_raw.alive.clone();
         let mut packed_file_decoder_view = Self {
-            hex_view_index: atomic_from_mut_ptr(packed_file_decoder_view_raw.hex_view_index),
-            hex_view_raw: atomic_from_mut_ptr(packed_file_decoder_view_raw.hex_view_raw),
-            hex_view_decoded: atomic_from_mut_ptr(packed_file_decoder_view_raw.hex_view_decoded),
-
-            table_view: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view),
-            table_model: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_model),
-
-            table_view_context_menu_move_up: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_context_menu_move_up),
-            table_view_context_menu_move_down: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_context_menu_move_down),
-            table_view_context_menu_move_left: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_context_menu_move_left),
-            table_view_context_menu_move_right: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_context_menu_move_right),
-            table_view_context_menu_delete: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_context_menu_delete),
-
-            bool_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.bool_button),
-            f32_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.f32_button),
-            i16_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.i16_button),
-            i32_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.i32_button),
-            i64_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.i64_button),
-            string_u8_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.string_u8_button),
-            string_u16_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.string_u16_button),
-            optional_string_u8_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.optional_string_u8_button),
-            optional_string_u16_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.optional_string_u16_button),
-            sequence_u32_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.sequence_u32_button),
-
-            packed_file_info_version_decoded_label: atomic_from_mut_ptr(packed_file_decoder_view_raw.packed_file_info_version_decoded_label),
-            packed_file_info_entry_count_decoded_label: atomic_from_mut_ptr(packed_file_decoder_view_raw.packed_file_info_entry_count_decoded_label),
-
-            table_view_old_versions: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_old_versions),
-            table_view_old_versions_context_menu_load: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_old_versions_context_menu_load),
-            table_view_old_versions_context_menu_delete: atomic_from_mut_ptr(packed_file_decoder_view_raw.table_view_old_versions_context_menu_delete),
-
-            test_definition_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.test_definition_button),
-            clear_definition_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.clear_definition_button),
-            save_button: atomic_from_mut_ptr(packed_file_decoder_view_raw.save_button),
+            hex_view_index: QtPtr::new(packed_file_decoder_view_raw.hex_view_index, &alive),
+            hex_view_raw: QtPtr::new(packed_file_decoder_view_raw.hex_view_raw, &alive),
+            hex_view_decoded: QtPtr::new(packed_file_decoder_view_raw.hex_view_decoded, &alive),
+            hex_view_zoom_in: QtPtr::new(packed_file_decoder_view_raw.hex_view_zoom_in, &alive),
+            hex_view_zoom_out: QtPtr::new(packed_file_decoder_view_raw.hex_view_zoom_out, &alive),
+            hex_view_zoom_reset: QtPtr::new(packed_file_decoder_view_raw.hex_view_zoom_reset, &alive),
+
+            table_view: QtPtr::new(packed_file_decoder_view_raw.table_view, &alive),
+            table_model: QtPtr::new(packed_file_decoder_view_raw.table_model, &alive),
+
+            table_view_context_menu_move_up: QtPtr::new(packed_file_decoder_view_raw.table_view_context_menu_move_up, &alive),
+            table_view_context_menu_move_down: QtPtr::new(packed_file_decoder_view_raw.table_view_context_menu_move_down, &alive),
+            table_view_context_menu_move_left: QtPtr::new(packed_file_decoder_view_raw.table_view_context_menu_move_left, &alive),
+            table_view_context_menu_move_right: QtPtr::new(packed_file_decoder_view_raw.table_view_context_menu_move_right, &alive),
+            table_view_context_menu_delete: QtPtr::new(packed_file_decoder_view_raw.table_view_context_menu_delete, &alive),
+            table_view_context_menu_edit_enum_values: QtPtr::new(packed_file_decoder_view_raw.table_view_context_menu_edit_enum_values, &alive),
+
+            bool_button: QtPtr::new(packed_file_decoder_view_raw.bool_button, &alive),
+            f32_button: QtPtr::new(packed_file_decoder_view_raw.f32_button, &alive),
+            i16_button: QtPtr::new(packed_file_decoder_view_raw.i16_button, &alive),
+            i32_button: QtPtr::new(packed_file_decoder_view_raw.i32_button, &alive),
+            i64_button: QtPtr::new(packed_file_decoder_view_raw.i64_button, &alive),
+            string_u8_button: QtPtr::new(packed_file_decoder_view_raw.string_u8_button, &alive),
+            string_u16_button: QtPtr::new(packed_file_decoder_view_raw.string_u16_button, &alive),
+            optional_string_u8_button: QtPtr::new(packed_file_decoder_view_raw.optional_string_u8_button, &alive),
+            optional_string_u16_button: QtPtr::new(packed_file_decoder_view_raw.optional_string_u16_button, &alive),
+            sequence_u32_button: QtPtr::new(packed_file_decoder_view_raw.sequence_u32_button, &alive),
+
+            packed_file_info_version_decoded_label: QtPtr::new(packed_file_decoder_view_raw.packed_file_info_version_decoded_label, &alive),
+            packed_file_info_entry_count_decoded_label: QtPtr::new(packed_file_decoder_view_raw.packed_file_info_entry_count_decoded_label, &alive),
+
+            table_view_old_versions: QtPtr::new(packed_file_decoder_view_raw.table_view_old_versions, &alive),
+            table_view_old_versions_context_menu_load: QtPtr::new(packed_file_decoder_view_raw.table_view_old_versions_context_menu_load, &alive),
+            table_view_old_versions_context_menu_delete: QtPtr::new(packed_file_decoder_view_raw.table_view_old_versions_context_menu_delete, &alive),
+            table_view_old_versions_context_menu_clone: QtPtr::new(packed_file_decoder_view_raw.table_view_old_versions_context_menu_clone, &alive),
+
+            test_definition_button: QtPtr::new(packed_file_decoder_view_raw.test_definition_button, &alive),
+            import_from_assembly_kit_button: QtPtr::new(packed_file_decoder_view_raw.import_from_assembly_kit_button, &alive),
+            clear_definition_button: QtPtr::new(packed_file_decoder_view_raw.clear_definition_button, &alive),
+            save_button: QtPtr::new(packed_file_decoder_view_raw.save_button, &alive),
 
             packed_file_type,
             packed_file_path: packed_file.get_path().to_vec(),
             packed_file_data: packed_file_decoder_view_raw.packed_file_data.clone(),
+
+            alive,
         };
 
         let definition = get_definition(
@@ -571,6 +683,7 @@ impl PackedFileDecoderView {
         );
 
         let fields = if let Some(definition) = definition {
+            packed_file_decoder_view_raw.is_ordered_checkbox.set_checked(definition.get_is_ordered());
             definition.get_ref_fields().to_vec()
         } else { vec![] };
 
@@ -593,73 +706,12 @@ impl PackedFileDecoderView {
 
     /// This function loads the raw data of a PackedFile into the UI and prepare it to be updated later on.
     pub unsafe fn load_packed_file_data(&self) -> Result<()> {
-
-        // We need to set up the fonts in a specific way, so the scroll/sizes are kept correct.
-        let font = self.get_mut_ptr_hex_view_index().document().default_font();
-        let font_metrics = QFontMetrics::new_1a(&font);
-
-        //---------------------------------------------//
-        // Index section.
-        //---------------------------------------------//
-
-        // This creates the "index" column at the left of the hex data. The logic behind this, because
-        // even I have problems to understand it:
-        // - Lines are 4 packs of 4 bytes => 16 bytes + 3 spaces + 1 line jump.
-        // - Amount of lines is "bytes we have / 16 + 1" (+ 1 because we want to show incomplete lines too).
-        // - Then, for the zeroes, we default to 4, meaning all lines are 00XX.
-        let mut hex_index = String::new();
-        let hex_lines = (self.packed_file_data.len() / 16) + 1;
-        (0..hex_lines).for_each(|x| hex_index.push_str(&format!("{:>0count$X}\n", x * 16, count = 4)));
-
-        let qhex_index = QString::from_std_str(&hex_index);
-        let text_size = font_metrics.size_2a(0, &qhex_index);
-        self.get_mut_ptr_hex_view_index().set_text(&qhex_index);
-        self.get_mut_ptr_hex_view_index().set_fixed_width(text_size.width() + 34);
-
-        //---------------------------------------------//
-        // Raw data section.
-        //---------------------------------------------//
-
-        // Prepare the Hex Raw Data string, looking like:
-        // 01 0a 02 0f 0d 02 04 06 01 0a 02 0f 0d 02 04 06
-        let mut hex_raw_data = format!("{:02X?}", self.packed_file_data);
-        hex_raw_data.remove(0);
-        hex_raw_data.pop();
-        hex_raw_data.retain(|c| c != ',');
-
-        // Note: this works on BYTES, NOT CHARACTERS. Which means some characters may use multiple bytes,
-        // and if you pass these functions a range thats not a character, they panic!
-        // For reference, everything is one byte except the thin whitespace that's three bytes.
-        (2..hex_raw_data.len() - 1).rev().step_by(3).filter(|x| x % 4 != 0).for_each(|x| hex_raw_data.replace_range(x - 1..x, " "));
-        if hex_raw_data.len() > 70 {
-            (70..hex_raw_data.len() - 1).rev().filter(|x| x % 72 == 0).for_each(|x| hex_raw_data.replace_range(x - 1..x, "\n"));
-        }
-
-        let qhex_raw_data = QString::from_std_str(&hex_raw_data);
-        let text_size = font_metrics.size_2a(0, &qhex_raw_data);
-        self.get_mut_ptr_hex_view_raw().set_text(&qhex_raw_data);
-        self.get_mut_ptr_hex_view_raw().set_fixed_width(text_size.width() + 34);
-
-        //---------------------------------------------//
-        // Decoded data section.
-        //---------------------------------------------//
-
-        // This pushes a newline after 16 characters.
-        let mut hex_decoded_data = String::new();
-        for (j, i) in self.packed_file_data.iter().enumerate() {
-            if j % 16 == 0 && j != 0 { hex_decoded_data.push('\n'); }
-            let character = *i as char;
-
-            // If is a valid UTF-8 char, show it. Otherwise, default to '.'.
-            if character.is_alphanumeric() { hex_decoded_data.push(character); }
-            else { hex_decoded_data.push('.'); }
-        }
-
-        // Add all the "Decoded" lines to the TextEdit.
-        let qhex_decoded_data = QString::from_std_str(&hex_decoded_data);
-        let text_size = font_metrics.size_2a(0, &qhex_decoded_data);
-        self.get_mut_ptr_hex_view_decoded().set_text(&qhex_decoded_data);
-        self.get_mut_ptr_hex_view_decoded().set_fixed_width(text_size.width() + 34);
+        load_hex_data(
+            self.hex_view_index.get_unchecked(),
+            self.hex_view_raw.get_unchecked(),
+            self.hex_view_decoded.get_unchecked(),
+            &self.packed_file_data
+        );
 
         //---------------------------------------------//
         // Header Marking section.
@@ -671,27 +723,31 @@ impl PackedFileDecoderView {
         let mut header_format = QTextCharFormat::new();
         header_format.set_background(&brush);
 
+        // Underline the header too, so there is a visible divider between it and the data, even when the fields are painted next to it.
+        header_format.set_underline_style(UnderlineStyle::SingleUnderline);
+        header_format.set_underline_color(&QColor::from_global_color(if use_dark_theme { GlobalColor::White } else { GlobalColor::Black }));
+
         // Block the signals during this, so we don't mess things up.
-        let mut blocker = QSignalBlocker::from_q_object(self.get_mut_ptr_hex_view_raw().static_upcast_mut::<QObject>());
-        let mut cursor = self.get_mut_ptr_hex_view_raw().text_cursor();
+        let mut blocker = QSignalBlocker::from_q_object(self.hex_view_raw.get_unchecked().static_upcast_mut::<QObject>());
+        let mut cursor = self.hex_view_raw.get_unchecked().text_cursor();
         cursor.move_position_1a(MoveOperation::Start);
         cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (header_size * 3) as i32);
-        self.get_mut_ptr_hex_view_raw().set_text_cursor(&cursor);
-        self.get_mut_ptr_hex_view_raw().set_current_char_format(&header_format);
+        self.hex_view_raw.get_unchecked().set_text_cursor(&cursor);
+        self.hex_view_raw.get_unchecked().set_current_char_format(&header_format);
         cursor.clear_selection();
-        self.get_mut_ptr_hex_view_raw().set_text_cursor(&cursor);
+        self.hex_view_raw.get_unchecked().set_text_cursor(&cursor);
 
         blocker.unblock();
 
         // Block the signals during this, so we don't mess things up.
-        let mut blocker = QSignalBlocker::from_q_object(self.get_mut_ptr_hex_view_decoded().static_upcast_mut::<QObject>());
-        let mut cursor = self.get_mut_ptr_hex_view_decoded().text_cursor();
+        let mut blocker = QSignalBlocker::from_q_object(self.hex_view_decoded.get_unchecked().static_upcast_mut::<QObject>());
+        let mut cursor = self.hex_view_decoded.get_unchecked().text_cursor();
         cursor.move_position_1a(MoveOperation::Start);
         cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (header_size + (header_size as f32 / 16.0).floor() as usize) as i32);
-        self.get_mut_ptr_hex_view_decoded().set_text_cursor(&cursor);
-        self.get_mut_ptr_hex_view_decoded().set_current_char_format(&header_format);
+        self.hex_view_decoded.get_unchecked().set_text_cursor(&cursor);
+        self.hex_view_decoded.get_unchecked().set_current_char_format(&header_format);
         cursor.clear_selection();
-        self.get_mut_ptr_hex_view_decoded().set_text_cursor(&cursor);
+        self.hex_view_decoded.get_unchecked().set_text_cursor(&cursor);
 
         blocker.unblock();
 
@@ -699,189 +755,80 @@ impl PackedFileDecoderView {
         // Info section.
         //---------------------------------------------//
 
-        // Load the "Info" data to the view.
+        // Load the "Info" data to the view. In Raw Mode we have no header to get it from.
+        if is_raw_mode(self.packed_file_type) {
+            self.packed_file_info_version_decoded_label.get_unchecked().set_text(&QString::from_std_str("-"));
+            self.packed_file_info_entry_count_decoded_label.get_unchecked().set_text(&QString::from_std_str("-"));
+            return Ok(());
+        }
+
         let (version, entry_count) = match self.packed_file_type {
-            PackedFileType::AnimTable => {
-                if let Ok((version, entry_count)) = AnimTable::read_header(&self.packed_file_data) { (version, entry_count ) } else { unimplemented!() }
-            }
-            PackedFileType::AnimFragment => {
-                if let Ok((version, entry_count)) = AnimFragment::read_header(&self.packed_file_data) { (version, entry_count ) } else { unimplemented!() }
-            }
-            PackedFileType::DB => {
-                if let Ok((version, _, _, entry_count, _)) = DB::read_header(&self.packed_file_data) { (version, entry_count ) } else { unimplemented!() }
-            }
-            PackedFileType::Loc => {
-                if let Ok((version, entry_count)) = Loc::read_header(&self.packed_file_data) { (version, entry_count ) } else { unimplemented!() }
-            }
-            PackedFileType::MatchedCombat => {
-                if let Ok((version, entry_count)) = MatchedCombat::read_header(&self.packed_file_data) { (version, entry_count ) } else { unimplemented!() }
-            }
-            _ => unimplemented!()
+            PackedFileType::AnimTable => AnimTable::read_header(&self.packed_file_data)?,
+            PackedFileType::AnimFragment => AnimFragment::read_header(&self.packed_file_data)?,
+            PackedFileType::DB => DB::read_header(&self.packed_file_data).map(|(version, _, _, entry_count, _)| (version, entry_count))?,
+            PackedFileType::Loc => Loc::read_header(&self.packed_file_data)?,
+            PackedFileType::MatchedCombat => MatchedCombat::read_header(&self.packed_file_data)?,
+            _ => return Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
         };
 
-        self.get_mut_ptr_packed_file_info_version_decoded_label().set_text(&QString::from_std_str(format!("{}", version)));
-        self.get_mut_ptr_packed_file_info_entry_count_decoded_label().set_text(&QString::from_std_str(format!("{}", entry_count)));
+        self.packed_file_info_version_decoded_label.get_unchecked().set_text(&QString::from_std_str(format!("{}", version)));
+        self.packed_file_info_entry_count_decoded_label.get_unchecked().set_text(&QString::from_std_str(format!("{}", entry_count)));
 
         Ok(())
     }
 
-    fn get_mut_ptr_hex_view_index(&self) -> MutPtr<QTextEdit> {
-        mut_ptr_from_atomic(&self.hex_view_index)
-    }
-
-    fn get_mut_ptr_hex_view_raw(&self) -> MutPtr<QTextEdit> {
-        mut_ptr_from_atomic(&self.hex_view_raw)
+    pub fn get_mut_ptr_test_definition_button(&self) -> Option<MutPtr<QPushButton>> {
+        self.test_definition_button.get()
     }
 
-    fn get_mut_ptr_hex_view_decoded(&self) -> MutPtr<QTextEdit> {
-        mut_ptr_from_atomic(&self.hex_view_decoded)
+    pub fn get_mut_ptr_import_from_assembly_kit_button(&self) -> Option<MutPtr<QPushButton>> {
+        self.import_from_assembly_kit_button.get()
     }
 
-    fn get_mut_ptr_bool_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.bool_button)
+    pub fn get_mut_ptr_clear_definition_button(&self) -> Option<MutPtr<QPushButton>> {
+        self.clear_definition_button.get()
     }
 
-    fn get_mut_ptr_f32_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.f32_button)
+    pub fn get_mut_ptr_save_button(&self) -> Option<MutPtr<QPushButton>> {
+        self.save_button.get()
     }
 
-    fn get_mut_ptr_i16_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.i16_button)
-    }
-
-    fn get_mut_ptr_i32_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.i32_button)
-    }
-
-    fn get_mut_ptr_i64_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.i64_button)
-    }
-
-    fn get_mut_ptr_string_u8_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.string_u8_button)
-    }
-
-    fn get_mut_ptr_string_u16_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.string_u16_button)
-    }
-
-    fn get_mut_ptr_optional_string_u8_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.optional_string_u8_button)
-    }
-
-    fn get_mut_ptr_optional_string_u16_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.optional_string_u16_button)
-    }
-
-    fn get_mut_ptr_sequence_u32_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.sequence_u32_button)
-    }
-
-    fn get_mut_ptr_packed_file_info_version_decoded_label(&self) -> MutPtr<QLabel> {
-        mut_ptr_from_atomic(&self.packed_file_info_version_decoded_label)
-    }
-
-    fn get_mut_ptr_packed_file_info_entry_count_decoded_label(&self) -> MutPtr<QLabel> {
-        mut_ptr_from_atomic(&self.packed_file_info_entry_count_decoded_label)
-    }
-
-    fn get_mut_ptr_table_model(&self) -> MutPtr<QStandardItemModel> {
-        mut_ptr_from_atomic(&self.table_model)
-    }
-
-    fn get_mut_ptr_table_view(&self) -> MutPtr<QTreeView> {
-        mut_ptr_from_atomic(&self.table_view)
-    }
-
-    fn get_mut_ptr_table_view_old_versions(&self) -> MutPtr<QTableView> {
-        mut_ptr_from_atomic(&self.table_view_old_versions)
-    }
-
-    fn get_mut_ptr_table_view_context_menu_move_up(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_context_menu_move_up)
-    }
-
-    fn get_mut_ptr_table_view_context_menu_move_down(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_context_menu_move_down)
-    }
-
-    fn get_mut_ptr_table_view_context_menu_move_left(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_context_menu_move_left)
-    }
-
-    fn get_mut_ptr_table_view_context_menu_move_rigth(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_context_menu_move_right)
-    }
-
-    fn get_mut_ptr_table_view_context_menu_delete(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_context_menu_delete)
-    }
-
-    fn get_mut_ptr_table_view_old_versions_context_menu_load(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_old_versions_context_menu_load)
-    }
-
-    fn get_mut_ptr_table_view_old_versions_context_menu_delete(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.table_view_old_versions_context_menu_delete)
-    }
-
-    fn get_mut_ptr_test_definition_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.test_definition_button)
-    }
-
-    fn get_mut_ptr_clear_definition_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.clear_definition_button)
-    }
+}
 
-    fn get_mut_ptr_save_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.save_button)
+/// Drop implementation for `PackedFileDecoderView`.
+///
+/// Views are dropped when they're closed, so this marks their widgets as no longer usable.
+impl Drop for PackedFileDecoderView {
+    fn drop(&mut self) {
+        self.alive.store(false, Ordering::SeqCst);
     }
 }
 
 /// Implementation of `PackedFileDecoderViewRaw`.
 impl PackedFileDecoderViewRaw {
 
+    /// This function returns if the view is still open.
+    ///
+    /// Use it after anything that processes events while waiting, like `recv_message_qt_try` or a dialog, as the view may have been closed in the meantime.
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
     /// This function syncronize the selection between the Hex View and the Decoded View of the PackedFile Data.
     /// Pass `hex = true` if the selected view is the Hex View. Otherwise, pass false.
     pub unsafe fn hex_selection_sync(&mut self, hex: bool) {
+        hex_selection_sync(self.hex_view_raw, self.hex_view_decoded, hex);
+    }
 
-        let cursor = if hex { self.hex_view_raw.text_cursor() } else { self.hex_view_decoded.text_cursor() };
-        let mut cursor_dest = if !hex { self.hex_view_raw.text_cursor() } else { self.hex_view_decoded.text_cursor() };
-
-        let mut selection_start = cursor.selection_start();
-        let mut selection_end = cursor.selection_end();
-
-        // Translate the selection from one view to the other, doing some maths.
-        if hex {
-            selection_start = ((selection_start + 1) / 3) + (selection_start / 48);
-            selection_end = ((selection_end + 2) / 3) + (selection_end / 48);
-        }
-        else {
-            selection_start = (selection_start - (selection_start / 17)) * 3;
-            selection_end = (selection_end - (selection_end / 17)) * 3;
-        }
-
-        // Fix for the situation where you select less than what in the decoded view will be one character, being the change:
-        // 3 chars in raw = 1 in decoded.
-        if hex && selection_start == selection_end && cursor.selection_start() != cursor.selection_end() {
-            selection_end += 1;
-        }
-
-        cursor_dest.move_position_1a(MoveOperation::Start);
-        cursor_dest.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, selection_start as i32);
-        cursor_dest.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (selection_end - selection_start) as i32);
-
-        // Block the signals during this, so we don't trigger an infinite loop.
-        if hex {
-            let mut blocker = QSignalBlocker::from_q_object(self.hex_view_decoded);
-            self.hex_view_decoded.set_text_cursor(&cursor_dest);
-            blocker.unblock();
-        }
-        else {
-            let mut blocker = QSignalBlocker::from_q_object(self.hex_view_raw);
-            self.hex_view_raw.set_text_cursor(&cursor_dest);
-            blocker.unblock();
-        }
+    /// This function zooms the hex views in or out by the provided amount of points, or resets their zoom if no amount is provided.
+    ///
+    /// The zoom level is shared by all decoders, so the ones opened later use it too.
+    pub unsafe fn zoom_hex_views(&mut self, amount: Option<i32>) {
+        let level = change_zoom_level(ZOOM_DECODER, amount);
+        let font = get_zoomed_font(ref_from_atomic(&*FONT_MONOSPACE), level);
+        self.hex_view_index.set_font(&font);
+        self.hex_view_raw.set_font(&font);
+        self.hex_view_decoded.set_font(&font);
     }
 
     /// This function is used to update the state of the decoder view every time a change it's done.
@@ -892,6 +839,9 @@ impl PackedFileDecoderViewRaw {
         mut index: &mut usize,
     ) -> Result<()> {
 
+        // Never let the index get into the header. Otherwise, the fields would consume its bytes.
+        let header_size = self.lock_index_to_data(&mut index)?;
+
         // If it's the first load, we have to prepare the table's column data.
         if is_initial_load {
 
@@ -941,7 +891,6 @@ impl PackedFileDecoderViewRaw {
         //---------------------------------------------//
 
         // Prepare to paint the changes in the hex data views.
-        let header_size = get_header_size(self.packed_file_type, &self.packed_file_data)?;
         let use_dark_theme = SETTINGS.read().unwrap().settings_bool["use_dark_theme"];
         let mut index_format = QTextCharFormat::new();
         let mut decoded_format = QTextCharFormat::new();
@@ -981,67 +930,147 @@ impl PackedFileDecoderViewRaw {
         // Raw data painting decoded data section.
         //---------------------------------------------//
 
-        let mut blocker = QSignalBlocker::from_q_object(self.hex_view_raw.static_upcast_mut::<QObject>());
-        let mut cursor = self.hex_view_raw.text_cursor();
-        cursor.move_position_1a(MoveOperation::Start);
-        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, (header_size * 3) as i32);
-        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, ((*index - header_size) * 3) as i32);
+        // First, paint the entire decoded range. Then, paint over it each field's bytes with its own color,
+        // so it's easy to see where each field starts and ends. Bytes not covered by any field (like the
+        // entries of a sequence past the first one) keep the generic decoded color.
+        self.paint_hex_range(header_size, *index, &decoded_format);
 
-        self.hex_view_raw.set_text_cursor(&cursor);
-        self.hex_view_raw.set_current_char_format(&decoded_format);
-        cursor.clear_selection();
-        self.hex_view_raw.set_text_cursor(&cursor);
+        let field_colors = if use_dark_theme { FIELD_COLORS_DARK } else { FIELD_COLORS_BRIGHT };
+        for (position, (start, end)) in self.get_field_byte_ranges(self.table_model.invisible_root_item()).iter().enumerate() {
+            let mut field_format = QTextCharFormat::new();
+            field_format.set_background(&QBrush::from_q_color(&QColor::from_q_string(&QString::from_std_str(field_colors[position % field_colors.len()]))));
+            self.paint_hex_range((*start).max(header_size), *end, &field_format);
+        }
 
-        blocker.unblock();
+        //---------------------------------------------//
+        // Raw data painting current index section.
+        //---------------------------------------------//
 
-        let mut blocker = QSignalBlocker::from_q_object(self.hex_view_decoded.static_upcast_mut::<QObject>());
-        let mut cursor = self.hex_view_decoded.text_cursor();
+        self.paint_hex_range(*index, *index + 1, &index_format);
 
-        // Create the "Selection" for the decoded row.
-        let positions_to_move_end = *index / 16;
-        let positions_to_move_start = header_size / 16;
-        let positions_to_move_vertical = positions_to_move_end - positions_to_move_start;
-        let positions_to_move_horizontal = *index - header_size;
-        let positions_to_move = positions_to_move_horizontal + positions_to_move_vertical;
+        //---------------------------------------------//
+        // Row size check section.
+        //---------------------------------------------//
 
-        cursor.move_position_1a(MoveOperation::Start);
-        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, (header_size + (header_size as f32 / 16.0).floor() as usize) as i32);
-        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, positions_to_move as i32);
+        self.update_row_size_check(header_size);
 
-        self.hex_view_decoded.set_text_cursor(&cursor);
-        self.hex_view_decoded.set_current_char_format(&decoded_format);
-        cursor.clear_selection();
-        self.hex_view_decoded.set_text_cursor(&cursor);
+        Ok(())
+    }
 
-        blocker.unblock();
+    /// This function moves the provided index out of the header, if it's in it, and returns the header size.
+    unsafe fn lock_index_to_data(&self, index: &mut usize) -> Result<usize> {
+        let header_size = get_header_size(self.packed_file_type, &self.packed_file_data)?;
+        if *index < header_size {
+            *index = header_size;
+        }
+        Ok(header_size)
+    }
 
-        //---------------------------------------------//
-        // Raw data painting current index section.
-        //---------------------------------------------//
+    /// This function checks if the definition in the view needs more bytes per row than the PackedFile has, and shows the result in the Info section.
+    ///
+    /// As strings and sequences have variable length, this uses the minimum size of each field, so a definition flagged here is always wrong.
+    unsafe fn update_row_size_check(&mut self, header_size: usize) {
+        let min_row_size = get_min_row_size(&self.get_fields_from_view(None));
+        let data_size = self.packed_file_data.len().saturating_sub(header_size);
+
+        let (text, is_overrun) = match get_entry_count(self.packed_file_type, &self.packed_file_data) {
+            Ok(entry_count) => {
+                let needed_size = min_row_size * entry_count as usize;
+                if needed_size > data_size {
+                    (format!("{} bytes. Overruns the data: {} rows need at least {} bytes, but there are only {}.", min_row_size, entry_count, needed_size, data_size), true)
+                } else {
+                    (format!("{} bytes", min_row_size), false)
+                }
+            }
+
+            // In Raw Mode we don't know the amount of rows, so we can only check one.
+            Err(_) => {
+                if min_row_size > data_size {
+                    (format!("{} bytes. Overruns the data: there are only {} bytes.", min_row_size, data_size), true)
+                } else {
+                    (format!("{} bytes", min_row_size), false)
+                }
+            }
+        };
 
+        self.packed_file_info_row_size_decoded_label.set_text(&QString::from_std_str(&text));
+        self.packed_file_info_row_size_decoded_label.set_style_sheet(&QString::from_std_str(if is_overrun { "QLabel { color: red; }" } else { "" }));
+    }
+
+    /// This function paints the provided byte range in both hex views with the provided format.
+    unsafe fn paint_hex_range(&mut self, start: usize, end: usize, format: &QTextCharFormat) {
+        if end <= start { return; }
+
+        // In the raw view, each byte is two characters plus a separator.
         let mut blocker = QSignalBlocker::from_q_object(self.hex_view_raw.static_upcast_mut::<QObject>());
         let mut cursor = self.hex_view_raw.text_cursor();
-        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, 3);
+        cursor.move_position_1a(MoveOperation::Start);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, (start * 3) as i32);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, ((end - start) * 3) as i32);
 
         self.hex_view_raw.set_text_cursor(&cursor);
-        self.hex_view_raw.set_current_char_format(&index_format);
+        self.hex_view_raw.set_current_char_format(format);
         cursor.clear_selection();
         self.hex_view_raw.set_text_cursor(&cursor);
 
         blocker.unblock();
 
+        // In the decoded view, each byte is one character, with a line jump every 16 bytes.
         let mut blocker = QSignalBlocker::from_q_object(self.hex_view_decoded.static_upcast_mut::<QObject>());
         let mut cursor = self.hex_view_decoded.text_cursor();
-        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, 1);
+        let start_decoded = start + start / 16;
+        let end_decoded = end + end / 16;
+        cursor.move_position_1a(MoveOperation::Start);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, start_decoded as i32);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (end_decoded - start_decoded) as i32);
 
         self.hex_view_decoded.set_text_cursor(&cursor);
-        self.hex_view_decoded.set_current_char_format(&index_format);
+        self.hex_view_decoded.set_current_char_format(format);
         cursor.clear_selection();
         self.hex_view_decoded.set_text_cursor(&cursor);
 
         blocker.unblock();
+    }
 
-        Ok(())
+    /// This function returns the byte ranges decoded by the first row of each field under the provided item, in tree order.
+    unsafe fn get_field_byte_ranges(&self, parent: MutPtr<QStandardItem>) -> Vec<(usize, usize)> {
+        let mut ranges = vec![];
+        for row in 0..parent.row_count() {
+            let item = parent.child_1a(row);
+            let start = item.data_1a(FIELD_BYTE_START);
+            let end = item.data_1a(FIELD_BYTE_END);
+            if start.is_valid() && end.is_valid() {
+                ranges.push((start.to_int_0a() as usize, end.to_int_0a() as usize));
+            }
+
+            if item.has_children() {
+                ranges.append(&mut self.get_field_byte_ranges(item));
+            }
+        }
+        ranges
+    }
+
+    /// This function selects the bytes decoded by the field in the provided row of the fields tree, scrolling the hex views to them.
+    pub unsafe fn select_field_bytes(&mut self, model_index: Ref<QModelIndex>) {
+        let item = self.table_model.item_from_index(&model_index.sibling_at_column(0));
+        if item.is_null() { return; }
+
+        let start = item.data_1a(FIELD_BYTE_START);
+        let end = item.data_1a(FIELD_BYTE_END);
+        if !start.is_valid() || !end.is_valid() { return; }
+
+        let start = start.to_int_0a();
+        let end = end.to_int_0a();
+        if end <= start { return; }
+
+        // Selecting the bytes in the raw view triggers the selection sync, so the decoded view gets selected too.
+        let mut cursor = self.hex_view_raw.text_cursor();
+        cursor.move_position_1a(MoveOperation::Start);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, start * 3);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (end - start) * 3 - 1);
+        self.hex_view_raw.set_text_cursor(&cursor);
+        self.hex_view_raw.ensure_cursor_visible();
+        self.hex_view_decoded.ensure_cursor_visible();
     }
 
     /// This function adds fields to the decoder's table, so we can do this without depending on the
@@ -1056,12 +1085,19 @@ impl PackedFileDecoderViewRaw {
         parent: Option<CppBox<QModelIndex>>,
     ) {
 
-        // Decode the data from the field.
+        // Decode the data from the field. If the index is somehow in the header, move it out first.
+        if let Ok(header_size) = get_header_size(self.packed_file_type, &self.packed_file_data) {
+            if *index < header_size {
+                *index = header_size;
+            }
+        }
+        let byte_start = *index;
         let decoded_data = Self::decode_data_by_fieldtype(
             &self.packed_file_data,
             field.get_ref_field_type(),
             &mut index
         );
+        let byte_end = *index;
 
         // Get the type of the data we are going to put into the Table.
         let field_type = match field.get_ref_field_type() {
@@ -1116,13 +1152,16 @@ impl PackedFileDecoderViewRaw {
 
         let field_ca_order = QStandardItem::from_q_string(&QString::from_std_str(&format!("{}", field.get_ca_order())));
         let field_description = QStandardItem::from_q_string(&QString::from_std_str(field.get_description()));
-        let field_enum_values = QStandardItem::from_q_string(&QString::from_std_str(field.get_enum_values_to_string()));
+        let mut field_enum_values = QStandardItem::from_q_string(&QString::from_std_str(field.get_enum_values_to_string()));
+        field_enum_values.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(&serde_json::to_string(field.get_enum_descriptions()).unwrap())), ENUM_DESCRIPTIONS);
 
         let mut field_is_bitwise = QStandardItem::new();
         field_is_bitwise.set_data_2a(&QVariant::from_int(field.get_is_bitwise()), 2);
 
         let mut field_number = QStandardItem::from_q_string(&QString::from_std_str(&format!("{}", 1 + 1)));
         field_number.set_editable(false);
+        field_number.set_data_2a(&QVariant::from_int(byte_start as i32), FIELD_BYTE_START);
+        field_number.set_data_2a(&QVariant::from_int(byte_end as i32), FIELD_BYTE_END);
 
 
         // The first one is the row number, to be updated later.
@@ -1198,81 +1237,34 @@ impl PackedFileDecoderViewRaw {
     }
 
     /// This function is the one that takes care of actually decoding the provided data based on the field type.
+    ///
+    /// If the data cannot be decoded, it returns "Error".
     fn decode_data_by_fieldtype(
         packed_file_data: &[u8],
         field_type: &FieldType,
-        mut index: &mut usize
+        index: &mut usize
     ) -> String {
+        Self::try_decode_data_by_fieldtype(packed_file_data, field_type, index).unwrap_or_else(|_| "Error".to_owned())
+    }
+
+    /// This function tries to decode the provided data based on the field type, returning an error if it cannot be decoded.
+    fn try_decode_data_by_fieldtype(
+        packed_file_data: &[u8],
+        field_type: &FieldType,
+        mut index: &mut usize
+    ) -> Result<String> {
         match field_type {
-            FieldType::Boolean => {
-                match packed_file_data.decode_packedfile_bool(*index, &mut index) {
-                    Ok(result) => {
-                        if result { "True".to_string() }
-                        else { "False".to_string() }
-                    }
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::F32 => {
-                match packed_file_data.decode_packedfile_float_f32(*index, &mut index) {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::I16 => {
-                match packed_file_data.decode_packedfile_integer_i16(*index, &mut index) {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::I32 => {
-                match packed_file_data.decode_packedfile_integer_i32(*index, &mut index) {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::I64 => {
-                match packed_file_data.decode_packedfile_integer_i64(*index, &mut index) {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::StringU8 => {
-                match packed_file_data.decode_packedfile_string_u8(*index, &mut index) {
-                    Ok(result) => result,
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::StringU16 => {
-                match packed_file_data.decode_packedfile_string_u16(*index, &mut index) {
-                    Ok(result) => result,
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::OptionalStringU8 => {
-                match packed_file_data.decode_packedfile_optional_string_u8(*index, &mut index) {
-                    Ok(result) => result,
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::OptionalStringU16 => {
-                match packed_file_data.decode_packedfile_optional_string_u16(*index, &mut index) {
-                    Ok(result) => result,
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::SequenceU16(_) => {
-                match packed_file_data.decode_packedfile_integer_i16(*index, &mut index) {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "Error".to_owned(),
-                }
-            },
-            FieldType::SequenceU32(_) => {
-                match packed_file_data.decode_packedfile_integer_i32(*index, &mut index) {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "Error".to_owned(),
-                }
-            },
+            FieldType::Boolean => packed_file_data.decode_packedfile_bool(*index, &mut index).map(|result| if result { "True".to_owned() } else { "False".to_owned() }),
+            FieldType::F32 => packed_file_data.decode_packedfile_float_f32(*index, &mut index).map(|result| result.to_string()),
+            FieldType::I16 => packed_file_data.decode_packedfile_integer_i16(*index, &mut index).map(|result| result.to_string()),
+            FieldType::I32 => packed_file_data.decode_packedfile_integer_i32(*index, &mut index).map(|result| result.to_string()),
+            FieldType::I64 => packed_file_data.decode_packedfile_integer_i64(*index, &mut index).map(|result| result.to_string()),
+            FieldType::StringU8 => packed_file_data.decode_packedfile_string_u8(*index, &mut index),
+            FieldType::StringU16 => packed_file_data.decode_packedfile_string_u16(*index, &mut index),
+            FieldType::OptionalStringU8 => packed_file_data.decode_packedfile_optional_string_u8(*index, &mut index),
+            FieldType::OptionalStringU16 => packed_file_data.decode_packedfile_optional_string_u16(*index, &mut index),
+            FieldType::SequenceU16(_) => packed_file_data.decode_packedfile_integer_i16(*index, &mut index).map(|result| result.to_string()),
+            FieldType::SequenceU32(_) => packed_file_data.decode_packedfile_integer_i32(*index, &mut index).map(|result| result.to_string()),
         }
     }
 
@@ -1329,6 +1321,7 @@ impl PackedFileDecoderViewRaw {
                     };
 
                     // Get the decoded data using it's type...
+                    let byte_start = *index;
                     let decoded_data = Self::decode_data_by_fieldtype(
                         &self.packed_file_data,
                         &field_type,
@@ -1340,8 +1333,11 @@ impl PackedFileDecoderViewRaw {
                         let mut item = self.table_model.item_from_index(&model_index.sibling_at_column(3));
                         item.set_text(&QString::from_std_str(&decoded_data));
 
+                        // Keep the bytes this field decoded, so we can paint them in the hex views.
                         let mut item = self.table_model.item_from_index(&model_index.sibling_at_column(0));
                         item.set_text(&QString::from_std_str(&format!("{}", row + 1)));
+                        item.set_data_2a(&QVariant::from_int(byte_start as i32), FIELD_BYTE_START);
+                        item.set_data_2a(&QVariant::from_int(*index as i32), FIELD_BYTE_END);
                     }
 
                     // If it's a sequence,decode also it's internal first row, then move the index to skip the rest.
@@ -1372,7 +1368,7 @@ impl PackedFileDecoderViewRaw {
                 PackedFileType::DB => schema.get_ref_versioned_file_db(&self.packed_file_path[1]),
                 PackedFileType::Loc => schema.get_ref_versioned_file_loc(),
                 PackedFileType::MatchedCombat => schema.get_ref_versioned_file_matched_combat(),
-                _ => unimplemented!(),
+                _ => Err(ErrorKind::SchemaVersionedFileNotFound.into()),
             };
 
             // And get all the versions of this table, and list them in their TreeView, if we have any.
@@ -1388,6 +1384,49 @@ impl PackedFileDecoderViewRaw {
         self.table_view_old_versions.horizontal_header().set_section_resize_mode_1a(ResizeMode::Stretch);
     }
 
+    /// This function creates the dialog to choose the version a definition should be cloned to. It returns the chosen version, or None if the dialog was cancelled.
+    ///
+    /// The default version is the one of the PackedFile, if it's not decoded yet. Otherwise, the one after the last decoded version.
+    unsafe fn create_clone_version_dialog(&self, version: i32) -> Option<i32> {
+        let versions = (0..self.table_model_old_versions.row_count_0a())
+            .filter_map(|row| self.table_model_old_versions.item_1a(row).text().to_std_string().parse::<i32>().ok())
+            .collect::<Vec<i32>>();
+
+        let default_version = match get_packed_file_version(self.packed_file_type, &self.packed_file_data) {
+            Some(packed_file_version) if !versions.contains(&packed_file_version) => packed_file_version,
+            _ => versions.iter().max().map_or(0, |version| version + 1),
+        };
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_old_versions);
+        dialog.set_window_title(&QString::from_std_str(&format!("Clone Version {}", version)));
+        dialog.set_modal(true);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut instructions_label = QLabel::from_q_string(&QString::from_std_str("Version the definition should be cloned to:"));
+        let mut version_spinbox = QSpinBox::new_0a();
+        version_spinbox.set_range(0, i32::max_value());
+        version_spinbox.set_value(default_version);
+        let mut accept_button = QPushButton::from_q_string(&QString::from_std_str("Clone"));
+
+        main_grid.add_widget_5a(&mut instructions_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&mut version_spinbox, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 1, 1, 1, 1);
+        accept_button.released().connect(dialog.slot_accept());
+
+        // Keep the dialog open until we get a version we don't have yet, or the user gives up.
+        while dialog.exec() == 1 && self.is_alive() {
+            let new_version = version_spinbox.value();
+            if versions.contains(&new_version) {
+                show_dialog(self.table_view_old_versions, format!("<p>There is already a definition for the version {}. Delete it first if you want to replace it.</p>", new_version), false);
+            } else {
+                return Some(new_version);
+            }
+        }
+
+        None
+    }
+
     /// This function is used to update the decoder view when we try to add a new field to
     /// the definition with one of the "Use this" buttons.
     pub unsafe fn use_this(
@@ -1398,6 +1437,7 @@ impl PackedFileDecoderViewRaw {
         let mut field = Field::default();
         *field.get_ref_mut_field_type() = field_type;
 
+        self.lock_index_to_data(&mut index)?;
         self.add_field_to_view(&field, &mut index, false, None);
         self.update_view(&[], false, &mut index)
     }
@@ -1434,7 +1474,9 @@ impl PackedFileDecoderViewRaw {
                 let field_is_bitwise = self.table_model.item_from_index(model_index.sibling_at_column(14).as_ref()).text().to_std_string().parse::<i32>().unwrap();
 
                 let mut field_enum_values = BTreeMap::new();
-                let enmu_types = self.table_model.item_from_index(model_index.sibling_at_column(15).as_ref())
+                let field_enum_values_item = self.table_model.item_from_index(model_index.sibling_at_column(ENUM_VALUES_COLUMN).as_ref());
+                let field_enum_descriptions = serde_json::from_str::<BTreeMap<i32, String>>(&field_enum_values_item.data_1a(ENUM_DESCRIPTIONS).to_string().to_std_string()).unwrap_or_default();
+                let enmu_types = field_enum_values_item
                     .text()
                     .to_std_string()
                     .split(';')
@@ -1480,42 +1522,301 @@ impl PackedFileDecoderViewRaw {
                     Some(field_lookup.split(',').map(|x| x.to_owned()).collect::<Vec<String>>())
                 } else { None };
 
-                fields.push(
-                    Field::new(
-                        field_name,
-                        field_type,
-                        field_is_key,
-                        if field_default_value.is_empty() { None } else { Some(field_default_value) },
-                        field_max_length,
-                        field_is_filename,
-                        if field_filename_relative_path.is_empty() { None } else { Some(field_filename_relative_path) },
-                        field_is_reference,
-                        field_lookup,
-                        field_description,
-                        field_ca_order,
-                        field_is_bitwise,
-                        field_enum_values
-                    )
+                let mut field = Field::new(
+                    field_name,
+                    field_type,
+                    field_is_key,
+                    if field_default_value.is_empty() { None } else { Some(field_default_value) },
+                    field_max_length,
+                    field_is_filename,
+                    if field_filename_relative_path.is_empty() { None } else { Some(field_filename_relative_path) },
+                    field_is_reference,
+                    field_lookup,
+                    field_description,
+                    field_ca_order,
+                    field_is_bitwise,
+                    field_enum_values
                 );
+                field.set_enum_descriptions(field_enum_descriptions);
+                fields.push(field);
             }
         }
 
         fields
     }
 
+    /// This function opens the editor of the "Enum" values of the field of the current row, and saves them back to the row if it's accepted.
+    pub unsafe fn edit_enum_values(&mut self) {
+        let current_index = self.table_view.current_index();
+        if !current_index.is_valid() { return; }
+
+        let mut item = self.table_model.item_from_index(current_index.sibling_at_column(ENUM_VALUES_COLUMN).as_ref());
+        if item.is_null() { return; }
+
+        let parent = current_index.parent();
+        let fields = self.get_fields_from_view(if parent.is_valid() { Some(parent) } else { None });
+        let field = match fields.get(current_index.row() as usize) {
+            Some(field) => field,
+            None => return,
+        };
+
+        if let Some((enum_values, enum_descriptions)) = self.create_enum_values_dialog(field) {
+            item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(&serde_json::to_string(&enum_descriptions).unwrap())), ENUM_DESCRIPTIONS);
+            item.set_text(&QString::from_std_str(enum_values.iter().map(|(index, name)| format!("{},{}", index, name)).collect::<Vec<String>>().join(";")));
+        }
+    }
+
+    /// This function creates the editor of the "Enum" values of a field. It returns the values and their descriptions, or None if the dialog was cancelled.
+    ///
+    /// The dialog cannot be accepted while there are invalid or duplicated indexes, or invalid names.
+    unsafe fn create_enum_values_dialog(&self, field: &Field) -> Option<(BTreeMap<i32, String>, BTreeMap<i32, String>)> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view);
+        dialog.set_window_title(&QString::from_std_str(&format!("Enum Values of {}", field.get_name())));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 400);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut table_view = QTableView::new_0a();
+        let mut table_model = QStandardItemModel::new_0a();
+        table_view.set_model(table_model.as_mut_ptr());
+        table_view.horizontal_header().set_stretch_last_section(true);
+        table_view.vertical_header().set_visible(false);
+
+        let mut headers = QStringList::new();
+        headers.append_q_string(&QString::from_std_str("Index"));
+        headers.append_q_string(&QString::from_std_str("Name"));
+        headers.append_q_string(&QString::from_std_str("Description"));
+        table_model.set_horizontal_header_labels(&headers);
+
+        for (index, name) in field.get_enum_values() {
+            let mut qlist = QListOfQStandardItem::new();
+            let description = field.get_enum_descriptions().get(index).map(|x| &**x).unwrap_or("");
+            add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&index.to_string())).into_ptr());
+            add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(name)).into_ptr());
+            add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(description)).into_ptr());
+            table_model.append_row_q_list_of_q_standard_item(&qlist);
+        }
+
+        let mut add_button = QPushButton::from_q_string(&QString::from_std_str("Add"));
+        let mut remove_button = QPushButton::from_q_string(&QString::from_std_str("Remove"));
+        let mut accept_button = QPushButton::from_q_string(&QString::from_std_str("Accept"));
+        let mut cancel_button = QPushButton::from_q_string(&QString::from_std_str("Cancel"));
+
+        main_grid.add_widget_5a(&mut table_view, 0, 0, 1, 5);
+        main_grid.add_widget_5a(&mut add_button, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut remove_button, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 1, 3, 1, 1);
+        main_grid.add_widget_5a(&mut cancel_button, 1, 4, 1, 1);
+        main_grid.set_column_stretch(2, 10);
+
+        // New values get the index after the last one, as they're usually consecutive.
+        let mut table_model_ptr = table_model.as_mut_ptr();
+        let table_view_ptr = table_view.as_mut_ptr();
+        let slot_add = Slot::new(move || {
+            let next_index = (0..table_model_ptr.row_count_0a())
+                .filter_map(|row| table_model_ptr.item_2a(row, 0).text().to_std_string().parse::<i32>().ok())
+                .max()
+                .map_or(0, |index| index + 1);
+
+            let mut qlist = QListOfQStandardItem::new();
+            add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&next_index.to_string())).into_ptr());
+            add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::new().into_ptr());
+            add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::new().into_ptr());
+            table_model_ptr.append_row_q_list_of_q_standard_item(&qlist);
+        });
+
+        let slot_remove = Slot::new(move || {
+            let indexes = table_view_ptr.selection_model().selected_indexes();
+            let mut rows = (0..indexes.count_0a()).map(|x| indexes.at(x).row()).collect::<Vec<i32>>();
+            rows.sort_unstable();
+            rows.dedup();
+            rows.iter().rev().for_each(|row| { table_model_ptr.remove_row_1a(*row); });
+        });
+
+        add_button.released().connect(&slot_add);
+        remove_button.released().connect(&slot_remove);
+        accept_button.released().connect(dialog.slot_accept());
+        cancel_button.released().connect(dialog.slot_reject());
+
+        // Keep the dialog open until what we have is valid, or the user gives up.
+        while dialog.exec() == 1 && self.is_alive() {
+            let mut enum_values = BTreeMap::new();
+            let mut enum_descriptions = BTreeMap::new();
+            let mut error = None;
+            for row in 0..table_model.row_count_0a() {
+                let index_text = table_model.item_2a(row, 0).text().to_std_string();
+                let name = table_model.item_2a(row, 1).text().to_std_string();
+                let description = table_model.item_2a(row, 2).text().to_std_string();
+
+                let index = match index_text.trim().parse::<i32>() {
+                    Ok(index) => index,
+                    Err(_) => {
+                        error = Some(format!("<p>The index \"{}\" of the row {} is not a valid number.</p>", index_text, row + 1));
+                        break;
+                    }
+                };
+
+                if name.is_empty() || name.contains(',') || name.contains(';') {
+                    error = Some(format!("<p>The name of the row {} is empty or contains a \",\" or a \";\".</p>", row + 1));
+                    break;
+                }
+
+                if enum_values.insert(index, name).is_some() {
+                    error = Some(format!("<p>The index {} is used by more than one value.</p>", index));
+                    break;
+                }
+
+                if !description.is_empty() {
+                    enum_descriptions.insert(index, description);
+                }
+            }
+
+            match error {
+                Some(error) => show_dialog(self.table_view, error, false),
+                None => return Some((enum_values, enum_descriptions)),
+            }
+        }
+
+        None
+    }
+
+    /// This function tries to decode the PackedFile with the definition in the view, and returns a report with the result.
+    ///
+    /// Whether the definition works or not is decided by decoding the PackedFile like RPFM does when opening it. If that fails,
+    /// the report also says at which row, field and byte the decoding failed, how many bytes were left undecoded, and the values
+    /// decoded in the failing row up to that point.
+    pub unsafe fn get_test_definition_report(&self) -> Result<String> {
+        let fields = self.get_fields_from_view(None);
+        let header_size = get_header_size(self.packed_file_type, &self.packed_file_data)?;
+        let entry_count = get_entry_count(self.packed_file_type, &self.packed_file_data)?;
+
+        let mut report = String::new();
+        report.push_str(&format!("Entry count: {}\n", entry_count));
+        report.push_str(&format!("Header size: {} bytes\n", header_size));
+        report.push_str(&format!("Data size: {} bytes\n", self.packed_file_data.len()));
+        report.push_str(&format!("Min. row size: {} bytes\n\n", get_min_row_size(&fields)));
+
+        let (schema, _) = self.add_definition_to_schema()?;
+        let result = match self.packed_file_type {
+            PackedFileType::AnimTable => AnimTable::read(&self.packed_file_data, &schema, false).map(|_| ()),
+            PackedFileType::AnimFragment => AnimFragment::read(&self.packed_file_data, &schema, false).map(|_| ()),
+            PackedFileType::DB => DB::read(&self.packed_file_data, &self.packed_file_path[1], &schema, false).map(|_| ()),
+            PackedFileType::Loc => Loc::read(&self.packed_file_data, &schema, false).map(|_| ()),
+            PackedFileType::MatchedCombat => MatchedCombat::read(&self.packed_file_data, &schema, false).map(|_| ()),
+            _ => return Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
+        };
+
+        match result {
+            Ok(_) => {
+                report.push_str("Result: OK\n\n");
+                report.push_str(&format!("Rows decoded: {} of {}\n", entry_count, entry_count));
+                return Ok(report);
+            }
+            Err(error) => {
+                report.push_str("Result: FAILED\n\n");
+                report.push_str(&format!("Error: {}\n\n", error));
+            }
+        }
+
+        // From here on, we only try to find where the decoding went wrong. If the definition cannot fit in the data
+        // even with the minimum size of each field, don't bother decoding it.
+        let min_size = get_min_row_size(&fields) * entry_count as usize;
+        let data_size = self.packed_file_data.len().saturating_sub(header_size);
+        if min_size > data_size {
+            report.push_str(&format!("The definition needs at least {} bytes for {} rows, but there are only {} bytes after the header.\n", min_size, entry_count, data_size));
+            return Ok(report);
+        }
+
+        let mut index = header_size;
+        for row in 0..entry_count {
+            let mut values = vec![];
+            if let Err((field_name, field_type, offset)) = Self::test_decode_row(&self.packed_file_data, &fields, &mut index, &mut values, "") {
+                report.push_str(&format!("Rows decoded: {} of {}\n", row, entry_count));
+                report.push_str(&format!("Failing row: {}\n", row + 1));
+                report.push_str(&format!("Failing field: {} ({})\n", field_name, field_type));
+                report.push_str(&format!("Failing byte offset: {} (0x{:X})\n", offset, offset));
+                report.push_str(&format!("Bytes left undecoded: {}\n\n", self.packed_file_data.len().saturating_sub(offset)));
+                report.push_str("Values decoded in the failing row:\n");
+                for (name, offset, value) in &values {
+                    report.push_str(&format!("  [0x{:08X}] {}: {:?}\n", offset, name, value));
+                }
+                return Ok(report);
+            }
+        }
+
+        report.push_str(&format!("Rows decoded: {} of {}\n", entry_count, entry_count));
+        if index != self.packed_file_data.len() {
+            report.push_str(&format!("All the rows have been decoded, but the decoding stopped at byte offset {} (0x{:X}), leaving {} bytes undecoded.\n", index, index, self.packed_file_data.len().saturating_sub(index)));
+        }
+        else {
+            report.push_str("All the rows have been decoded field by field, but the definition is not valid for the table as a whole.\n");
+        }
+
+        Ok(report)
+    }
+
+    /// This function decodes a row of the provided fields, storing the decoded values with their offsets. Sequences are decoded recursively.
+    ///
+    /// If it fails, it returns the name and type of the failing field, and the byte offset where it failed.
+    fn test_decode_row(
+        packed_file_data: &[u8],
+        fields: &[Field],
+        mut index: &mut usize,
+        values: &mut Vec<(String, usize, String)>,
+        prefix: &str,
+    ) -> std::result::Result<(), (String, String, usize)> {
+        for field in fields {
+            let field_name = format!("{}{}", prefix, field.get_name());
+            let offset = *index;
+            match Self::try_decode_data_by_fieldtype(packed_file_data, field.get_ref_field_type(), &mut index) {
+                Ok(value) => {
+                    values.push((field_name.clone(), offset, value.clone()));
+
+                    match field.get_ref_field_type() {
+                        FieldType::SequenceU16(definition) | FieldType::SequenceU32(definition) => {
+                            // Every entry needs at least one byte, so never try to decode more entries than bytes are left.
+                            // Otherwise, a bad length could keep us looping over nothing for a very long time.
+                            let entries = value.parse::<i64>().unwrap_or(0).max(0) as usize;
+                            let entries = entries.min(packed_file_data.len().saturating_sub(*index));
+                            for entry in 0..entries {
+                                let prefix = format!("{}[{}]/", field_name, entry + 1);
+                                Self::test_decode_row(packed_file_data, definition.get_ref_fields(), &mut index, values, &prefix)?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Err(_) => return Err((field_name, field.get_ref_field_type().to_string(), offset)),
+            }
+        }
+        Ok(())
+    }
+
+    /// This function exports the definition currently in the view to a research file, so it can be shared or continued later.
+    ///
+    /// This is meant for the "Raw Mode", where we cannot save the definition to the schema.
+    unsafe fn export_definition_to_research_file(&self, path: &Path) -> Result<()> {
+        let mut definition = Definition::new(0);
+        *definition.get_ref_mut_fields() = self.get_fields_from_view(None);
+
+        let mut file = BufWriter::new(File::create(path)?);
+        let config = PrettyConfig::default();
+        file.write_all(to_string_pretty(&definition, config)?.as_bytes())?;
+        Ok(())
+    }
+
     /// This function adds the definition currently in the view to a temporal schema, and returns it.
-    unsafe fn add_definition_to_schema(&self) -> Schema {
+    ///
+    /// If the "Apply to all versions" checkbox is checked, the metadata of the fields is also propagated to the other versions
+    /// of the same table. The versions updated that way are returned along with the schema.
+    unsafe fn add_definition_to_schema(&self) -> Result<(Schema, Vec<i32>)> {
         let mut schema = SCHEMA.read().unwrap().clone().unwrap();
         let fields = self.get_fields_from_view(None);
+        let is_ordered = self.is_ordered_checkbox.is_checked();
 
-        let version = match self.packed_file_type {
-            PackedFileType::AnimTable => AnimTable::read_header(&self.packed_file_data).unwrap().0,
-            PackedFileType::AnimFragment => AnimFragment::read_header(&self.packed_file_data).unwrap().0,
-            PackedFileType::DB => DB::read_header(&self.packed_file_data).unwrap().0,
-            PackedFileType::Loc => Loc::read_header(&self.packed_file_data).unwrap().0,
-            PackedFileType::MatchedCombat => MatchedCombat::read_header(&self.packed_file_data).unwrap().0,
-            _ => unimplemented!(),
-        };
+        let version = get_packed_file_version(self.packed_file_type, &self.packed_file_data).ok_or(ErrorKind::PackedFileNotDecodeableWithDecoder)?;
 
         let versioned_file = match self.packed_file_type {
             PackedFileType::AnimTable => schema.get_ref_mut_versioned_file_animtable(),
@@ -1523,23 +1824,36 @@ impl PackedFileDecoderViewRaw {
             PackedFileType::DB => schema.get_ref_mut_versioned_file_db(&self.packed_file_path[1]),
             PackedFileType::Loc => schema.get_ref_mut_versioned_file_loc(),
             PackedFileType::MatchedCombat => schema.get_ref_mut_versioned_file_matched_combat(),
-            _ => unimplemented!(),
+            _ => return Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
         };
 
+        let mut versions_updated = vec![];
         match versioned_file {
             Ok(versioned_file) => {
-                match versioned_file.get_ref_mut_version(version) {
-                    Ok(definition) => *definition.get_ref_mut_fields() = fields,
+                let old_fields = match versioned_file.get_ref_mut_version(version) {
+                    Ok(definition) => {
+                        definition.set_is_ordered(is_ordered);
+                        Some(std::mem::replace(definition.get_ref_mut_fields(), fields.to_vec()))
+                    },
                     Err(_) => {
                         let mut definition = Definition::new(version);
-                        *definition.get_ref_mut_fields() = fields;
+                        *definition.get_ref_mut_fields() = fields.to_vec();
+                        definition.set_is_ordered(is_ordered);
                         versioned_file.add_version(&definition);
+                        None
                     }
+                };
+
+                if self.apply_to_all_versions_checkbox.is_checked() {
+                    let mut definition = Definition::new(version);
+                    *definition.get_ref_mut_fields() = fields;
+                    versions_updated = versioned_file.propagate_metadata(&definition, old_fields.as_deref());
                 }
             }
             Err(_) => {
                 let mut definition = Definition::new(version);
                 *definition.get_ref_mut_fields() = fields;
+                definition.set_is_ordered(is_ordered);
 
                 let definitions = vec![definition];
                 let versioned_file = match self.packed_file_type {
@@ -1549,17 +1863,22 @@ impl PackedFileDecoderViewRaw {
                     PackedFileType::Loc => VersionedFile::Loc(definitions),
                     PackedFileType::MatchedCombat => VersionedFile::MatchedCombat(definitions),
                     PackedFileType::DependencyPackFilesList => VersionedFile::DepManager(definitions),
-                    _ => unimplemented!()
+                    _ => return Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
                 };
 
                 schema.add_versioned_file(&versioned_file);
             }
         }
 
-        schema
+        Ok((schema, versions_updated))
     }
 }
 
+/// This function returns if the provided PackedFileType has to be decoded in "Raw Mode", because it's not supported by the schema system.
+fn is_raw_mode(packed_file_type: PackedFileType) -> bool {
+    !SUPPORTED_PACKED_FILE_TYPES.contains(&packed_file_type)
+}
+
 /// This function returns the header size (or first byte after the header) of the provided PackedFile.
 fn get_header_size(
     packed_file_type: PackedFileType,
@@ -1571,7 +1890,43 @@ fn get_header_size(
         PackedFileType::DB => Ok(DB::read_header(packed_file_data)?.4),
         PackedFileType::Loc => Ok(loc::HEADER_SIZE),
         PackedFileType::MatchedCombat => Ok(matched_combat::HEADER_SIZE),
-        _ => unimplemented!()
+
+        // Raw Mode. We know nothing about the file, so we start decoding from the first byte.
+        _ => Ok(0),
+    }
+}
+
+/// This function returns the minimum amount of bytes a row of the provided fields needs. Strings are counted as empty and sequences as having no entries.
+fn get_min_row_size(fields: &[Field]) -> usize {
+    fields.iter().map(|field| match field.get_ref_field_type() {
+        FieldType::Boolean => 1,
+        FieldType::F32 => 4,
+        FieldType::I16 => 2,
+        FieldType::I32 => 4,
+        FieldType::I64 => 8,
+        FieldType::StringU8 => 2,
+        FieldType::StringU16 => 2,
+        FieldType::OptionalStringU8 => 1,
+        FieldType::OptionalStringU16 => 1,
+        FieldType::SequenceU16(_) => 2,
+        FieldType::SequenceU32(_) => 4,
+    }).sum()
+}
+
+/// This function returns the amount of entries the PackedFile says it has in its header.
+fn get_entry_count(
+    packed_file_type: PackedFileType,
+    packed_file_data: &[u8],
+) -> Result<u32> {
+    match packed_file_type {
+        PackedFileType::AnimTable => Ok(AnimTable::read_header(packed_file_data)?.1),
+        PackedFileType::AnimFragment => Ok(AnimFragment::read_header(packed_file_data)?.1),
+        PackedFileType::DB => Ok(DB::read_header(packed_file_data)?.3),
+        PackedFileType::Loc => Ok(Loc::read_header(packed_file_data)?.1),
+        PackedFileType::MatchedCombat => Ok(MatchedCombat::read_header(packed_file_data)?.1),
+
+        // Raw Mode. There is no header to get the entry count from.
+        _ => Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
     }
 }
 
@@ -1591,20 +1946,12 @@ fn get_definition(
             PackedFileType::DB => schema.get_ref_versioned_file_db(&packed_file_path[1]),
             PackedFileType::Loc => schema.get_ref_versioned_file_loc(),
             PackedFileType::MatchedCombat => schema.get_ref_versioned_file_matched_combat(),
-            _ => unimplemented!(),
+            _ => return None,
         };
 
         // And get all the versions of this table, and list them in their TreeView, if we have any.
         if let Ok(versioned_file) = versioned_file {
-            let version = if let Some(version) = version { version } else { match packed_file_type {
-                PackedFileType::AnimTable => AnimTable::read_header(packed_file_data).ok()?.0,
-                PackedFileType::AnimFragment => AnimFragment::read_header(packed_file_data).ok()?.0,
-                PackedFileType::DB => DB::read_header(packed_file_data).ok()?.0,
-                PackedFileType::Loc => Loc::read_header(packed_file_data).ok()?.0,
-                PackedFileType::MatchedCombat => MatchedCombat::read_header(packed_file_data).ok()?.0,
-                _ => unimplemented!(),
-            }};
-
+            let version = if let Some(version) = version { version } else { get_packed_file_version(packed_file_type, packed_file_data)? };
             return versioned_file.get_version(version).ok().cloned()
         }
     }
@@ -1612,6 +1959,20 @@ fn get_definition(
     None
 }
 
+/// This function returns the version of the provided PackedFile, read from its header.
+///
+/// If the PackedFile is not of a type with a versioned header, or its header cannot be read, it returns None.
+fn get_packed_file_version(packed_file_type: PackedFileType, packed_file_data: &[u8]) -> Option<i32> {
+    match packed_file_type {
+        PackedFileType::AnimTable => AnimTable::read_header(packed_file_data).ok().map(|header| header.0),
+        PackedFileType::AnimFragment => AnimFragment::read_header(packed_file_data).ok().map(|header| header.0),
+        PackedFileType::DB => DB::read_header(packed_file_data).ok().map(|header| header.0),
+        PackedFileType::Loc => Loc::read_header(packed_file_data).ok().map(|header| header.0),
+        PackedFileType::MatchedCombat => MatchedCombat::read_header(packed_file_data).ok().map(|header| header.0),
+        _ => None,
+    }
+}
+
 /// This function configures the provided TableView, so it has the right columns and it's resized to the right size.
 unsafe fn configure_table_view(table_view: MutPtr<QTreeView>) {
     let mut table_model = table_view.model();
@@ -1650,6 +2011,6 @@ unsafe fn configure_table_view(table_view: MutPtr<QTreeView>) {
     new_combobox_item_delegate_safe(&mut table_view.static_upcast_mut(), 2, list.into_ptr().as_ptr(), false, 0);
 
     // Fields Max lenght and CA Order must be numeric.
-    new_spinbox_item_delegate_safe(&mut table_view.static_upcast_mut(), 9, 32);
-    new_spinbox_item_delegate_safe(&mut table_view.static_upcast_mut(), 12, 16);
+    new_spinbox_item_delegate_safe(&mut table_view.static_upcast_mut(), 9, 32, false, false);
+    new_spinbox_item_delegate_safe(&mut table_view.static_upcast_mut(), 12, 16, false, false);
 }
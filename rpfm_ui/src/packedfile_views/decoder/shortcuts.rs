@@ -26,27 +26,40 @@ use crate::UI_STATE;
 pub unsafe fn set_shortcuts(ui: &mut PackedFileDecoderView) {
     let shortcuts = UI_STATE.get_shortcuts_no_lock();
 
-    ui.get_mut_ptr_table_view_context_menu_move_up().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_up"])));
-    ui.get_mut_ptr_table_view_context_menu_move_down().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_down"])));
-    ui.get_mut_ptr_table_view_context_menu_move_left().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_left"])));
-    ui.get_mut_ptr_table_view_context_menu_move_rigth().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_right"])));
-    ui.get_mut_ptr_table_view_context_menu_delete().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["delete"])));
-    ui.get_mut_ptr_table_view_old_versions_context_menu_load().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["load"])));
-    ui.get_mut_ptr_table_view_old_versions_context_menu_delete().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["delete"])));
-
-    ui.get_mut_ptr_table_view_context_menu_move_up().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_table_view_context_menu_move_down().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_table_view_context_menu_move_left().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_table_view_context_menu_move_rigth().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_table_view_context_menu_delete().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_table_view_old_versions_context_menu_load().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_table_view_old_versions_context_menu_delete().set_shortcut_context(ShortcutContext::WidgetShortcut);
-
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_context_menu_move_up());
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_context_menu_move_down());
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_context_menu_move_left());
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_context_menu_move_rigth());
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_context_menu_delete());
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_old_versions_context_menu_load());
-    ui.get_mut_ptr_table_view().add_action(ui.get_mut_ptr_table_view_old_versions_context_menu_delete());
+    ui.table_view_context_menu_move_up.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_up"])));
+    ui.table_view_context_menu_move_down.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_down"])));
+    ui.table_view_context_menu_move_left.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_left"])));
+    ui.table_view_context_menu_move_right.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["move_right"])));
+    ui.table_view_context_menu_delete.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["delete"])));
+    ui.table_view_old_versions_context_menu_load.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["load"])));
+    ui.table_view_old_versions_context_menu_delete.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["delete"])));
+    ui.hex_view_zoom_in.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["zoom_in"])));
+    ui.hex_view_zoom_out.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["zoom_out"])));
+    ui.hex_view_zoom_reset.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_decoder["zoom_reset"])));
+
+    ui.table_view_context_menu_move_up.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.table_view_context_menu_move_down.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.table_view_context_menu_move_left.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.table_view_context_menu_move_right.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.table_view_context_menu_delete.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.table_view_old_versions_context_menu_load.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.table_view_old_versions_context_menu_delete.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.hex_view_zoom_in.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.hex_view_zoom_out.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.hex_view_zoom_reset.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+
+    ui.table_view.get_unchecked().add_action(ui.table_view_context_menu_move_up.get_unchecked());
+    ui.table_view.get_unchecked().add_action(ui.table_view_context_menu_move_down.get_unchecked());
+    ui.table_view.get_unchecked().add_action(ui.table_view_context_menu_move_left.get_unchecked());
+    ui.table_view.get_unchecked().add_action(ui.table_view_context_menu_move_right.get_unchecked());
+    ui.table_view.get_unchecked().add_action(ui.table_view_context_menu_delete.get_unchecked());
+    ui.table_view.get_unchecked().add_action(ui.table_view_old_versions_context_menu_load.get_unchecked());
+    ui.table_view.get_unchecked().add_action(ui.table_view_old_versions_context_menu_delete.get_unchecked());
+
+    // The zoom actions work from any of the hex views.
+    for mut hex_view in vec![ui.hex_view_index.get_unchecked(), ui.hex_view_raw.get_unchecked(), ui.hex_view_decoded.get_unchecked()] {
+        hex_view.add_action(ui.hex_view_zoom_in.get_unchecked());
+        hex_view.add_action(ui.hex_view_zoom_out.get_unchecked());
+        hex_view.add_action(ui.hex_view_zoom_reset.get_unchecked());
+    }
 }
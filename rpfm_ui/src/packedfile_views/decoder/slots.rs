@@ -12,25 +12,21 @@
 Module with the slots for Decoder Views.
 !*/
 
+use qt_widgets::QFileDialog;
+use qt_widgets::q_file_dialog::AcceptMode;
 use qt_widgets::SlotOfQPoint;
 
 use qt_gui::QCursor;
 
 use qt_core::QModelIndex;
-use qt_core::{SlotOfBool, SlotOfInt, SlotOfQItemSelectionQItemSelection, Slot, SlotOfQModelIndexQModelIndexQVectorOfInt};
+use qt_core::QString;
+use qt_core::{SlotOfBool, SlotOfInt, SlotOfQItemSelectionQItemSelection, Slot, SlotOfQModelIndex, SlotOfQModelIndexQModelIndexQVectorOfInt};
 
 use cpp_core::Ref;
 
-use bincode::deserialize;
+use std::path::PathBuf;
 
 use rpfm_error::ErrorKind;
-
-use rpfm_lib::packedfile::table::animtable::AnimTable;
-use rpfm_lib::packedfile::table::anim_fragment::AnimFragment;
-use rpfm_lib::packedfile::table::db::DB;
-use rpfm_lib::packedfile::table::loc::Loc;
-use rpfm_lib::packedfile::table::matched_combat::MatchedCombat;
-use rpfm_lib::packedfile::table::Table;
 use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::SCHEMA;
 use rpfm_lib::schema::{Definition, FieldType};
@@ -40,12 +36,15 @@ use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::Problems;
+use crate::utils::setup_file_dialog;
 use crate::utils::show_dialog;
 use crate::utils::show_debug_dialog;
 use crate::UI_STATE;
 
 use super::get_definition;
 use super::get_header_size;
+use super::is_raw_mode;
 use super::PackedFileDecoderViewRaw;
 use super::PackedFileDecoderMutableData;
 use super::DECODER_EXTENSION;
@@ -59,6 +58,10 @@ pub struct PackedFileDecoderViewSlots {
     pub hex_view_scroll_sync: SlotOfInt<'static>,
     pub hex_view_selection_raw_sync: Slot<'static>,
     pub hex_view_selection_decoded_sync: Slot<'static>,
+    pub hex_view_zoom_in: SlotOfBool<'static>,
+    pub hex_view_zoom_out: SlotOfBool<'static>,
+    pub hex_view_zoom_reset: SlotOfBool<'static>,
+    pub table_view_select_field_bytes: SlotOfQModelIndex<'static>,
 
     pub use_this_bool: Slot<'static>,
     pub use_this_f32: Slot<'static>,
@@ -78,6 +81,7 @@ pub struct PackedFileDecoderViewSlots {
     pub table_view_context_menu_move_left: SlotOfBool<'static>,
     pub table_view_context_menu_move_right: SlotOfBool<'static>,
     pub table_view_context_menu_delete: SlotOfBool<'static>,
+    pub table_view_context_menu_edit_enum_values: SlotOfBool<'static>,
 
     pub table_view_context_menu: SlotOfQPoint<'static>,
     pub table_view_context_menu_enabler: SlotOfQItemSelectionQItemSelection<'static>,
@@ -87,8 +91,10 @@ pub struct PackedFileDecoderViewSlots {
 
     pub table_view_old_versions_context_menu_load: SlotOfBool<'static>,
     pub table_view_old_versions_context_menu_delete: SlotOfBool<'static>,
+    pub table_view_old_versions_context_menu_clone: SlotOfBool<'static>,
 
     pub test_definition: Slot<'static>,
+    pub import_from_assembly_kit: Slot<'static>,
     pub remove_all_fields: Slot<'static>,
     pub save_definition: Slot<'static>,
 }
@@ -129,6 +135,28 @@ impl PackedFileDecoderViewSlots {
             view.hex_selection_sync(false);
         }));
 
+        // Slots to zoom the hex views in, out, or back to their normal size.
+        let hex_view_zoom_in = SlotOfBool::new(clone!(
+            mut view => move |_| {
+            view.zoom_hex_views(Some(1));
+        }));
+
+        let hex_view_zoom_out = SlotOfBool::new(clone!(
+            mut view => move |_| {
+            view.zoom_hex_views(Some(-1));
+        }));
+
+        let hex_view_zoom_reset = SlotOfBool::new(clone!(
+            mut view => move |_| {
+            view.zoom_hex_views(None);
+        }));
+
+        // Slot to select the bytes of a field in the hex views when clicking it.
+        let table_view_select_field_bytes = SlotOfQModelIndex::new(clone!(
+            mut view => move |model_index| {
+            view.select_field_bytes(model_index);
+        }));
+
         // Slot to use a boolean value.
         let use_this_bool = Slot::new(clone!(
             mut mutable_data,
@@ -389,6 +417,12 @@ impl PackedFileDecoderViewSlots {
             }
         ));
 
+        // Slot for the "Edit Enum Values" contextual action of the TableView.
+        let table_view_context_menu_edit_enum_values = SlotOfBool::new(clone!(
+            mut view => move |_| {
+            view.edit_enum_values();
+        }));
+
         // Slot to show the Contextual Menu for the fields table view.
         let table_view_context_menu = SlotOfQPoint::new(clone!(
             mut view => move |_| {
@@ -406,6 +440,7 @@ impl PackedFileDecoderViewSlots {
                     view.table_view_context_menu_move_left.set_enabled(true);
                     view.table_view_context_menu_move_right.set_enabled(true);
                     view.table_view_context_menu_delete.set_enabled(true);
+                    view.table_view_context_menu_edit_enum_values.set_enabled(true);
                 }
 
                 // Otherwise, disable everything.
@@ -415,6 +450,7 @@ impl PackedFileDecoderViewSlots {
                     view.table_view_context_menu_move_left.set_enabled(false);
                     view.table_view_context_menu_move_right.set_enabled(false);
                     view.table_view_context_menu_delete.set_enabled(false);
+                    view.table_view_context_menu_edit_enum_values.set_enabled(false);
                 }
             }
         ));
@@ -433,12 +469,14 @@ impl PackedFileDecoderViewSlots {
                 if !selection.indexes().is_empty() {
                     view.table_view_old_versions_context_menu_load.set_enabled(true);
                     view.table_view_old_versions_context_menu_delete.set_enabled(true);
+                    view.table_view_old_versions_context_menu_clone.set_enabled(true);
                 }
 
                 // Otherwise, disable everything.
                 else {
                     view.table_view_old_versions_context_menu_load.set_enabled(false);
                     view.table_view_old_versions_context_menu_delete.set_enabled(false);
+                    view.table_view_old_versions_context_menu_clone.set_enabled(false);
                 }
             }
         ));
@@ -489,74 +527,95 @@ impl PackedFileDecoderViewSlots {
                             PackedFileType::DB => schema.get_ref_mut_versioned_file_db(&view.packed_file_path[1]),
                             PackedFileType::Loc => schema.get_ref_mut_versioned_file_loc(),
                             PackedFileType::MatchedCombat => schema.get_ref_mut_versioned_file_matched_combat(),
-                            _ => unimplemented!(),
-                        }.unwrap();
+                            _ => Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
+                        };
 
-                        versioned_file.remove_version(version);
-                        view.load_versions_list();
+                        match versioned_file {
+                            Ok(versioned_file) => {
+                                versioned_file.remove_version(version);
+                                view.load_versions_list();
+                            }
+                            Err(error) => show_dialog(view.table_view, error, false),
+                        }
                     }
                 }
             }
         ));
 
-        // Slot for the "Test Definition" button.
-        let test_definition = Slot::new(clone!(
-            mut view => move || {
-                let schema = view.add_definition_to_schema();
+        // Slots for the "Clone to Version" contextual action of the Version's TableView.
+        let table_view_old_versions_context_menu_clone = SlotOfBool::new(clone!(
+            mut mutable_data,
+            mut view => move |_| {
 
-                match view.packed_file_type {
+                let selection = view.table_view_old_versions.selection_model().selection();
+                let indexes = selection.indexes();
+                if indexes.count_0a() == 1 {
+                    let model_index = indexes.at(0);
+                    let version = view.table_model_old_versions.item_from_index(model_index).text().to_std_string().parse::<i32>().unwrap();
 
-                    PackedFileType::AnimTable => match AnimTable::read(&view.packed_file_data, &schema, true) {
-                        Ok(_) => show_dialog(view.table_view, "Seems ok.", true),
-                        Err(error) => {
-                            if let ErrorKind::TableIncompleteError(_, data) = error.kind() {
-                                let data: Table = deserialize(data).unwrap();
-                                show_debug_dialog(&format!("{:#?}", data.get_table_data()));
-                            }
-                        }
-                    }
+                    if let Some(new_version) = view.create_clone_version_dialog(version) {
+                        let result = match *SCHEMA.write().unwrap() {
+                            Some(ref mut schema) => match view.packed_file_type {
+                                PackedFileType::AnimTable => schema.get_ref_mut_versioned_file_animtable(),
+                                PackedFileType::AnimFragment => schema.get_ref_mut_versioned_file_anim_fragment(),
+                                PackedFileType::DB => schema.get_ref_mut_versioned_file_db(&view.packed_file_path[1]),
+                                PackedFileType::Loc => schema.get_ref_mut_versioned_file_loc(),
+                                PackedFileType::MatchedCombat => schema.get_ref_mut_versioned_file_matched_combat(),
+                                _ => Err(ErrorKind::PackedFileNotDecodeableWithDecoder.into()),
+                            }.and_then(|versioned_file| versioned_file.clone_version(version, new_version)),
+                            None => Err(ErrorKind::SchemaNotFound.into()),
+                        };
 
-                    PackedFileType::AnimFragment => match AnimFragment::read(&view.packed_file_data, &schema, true) {
-                        Ok(_) => show_dialog(view.table_view, "Seems ok.", true),
-                        Err(error) => {
-                            if let ErrorKind::TableIncompleteError(_, data) = error.kind() {
-                                let data: Table = deserialize(data).unwrap();
-                                show_debug_dialog(&format!("{:#?}", data.get_table_data()));
-                            }
-                        }
-                    }
+                        match result {
+                            Ok(_) => {
+                                view.load_versions_list();
 
-                    PackedFileType::DB => match DB::read(&view.packed_file_data, &view.packed_file_path[1], &schema, true) {
-                        Ok(_) => show_dialog(view.table_view, "Seems ok.", true),
-                        Err(error) => {
-                            if let ErrorKind::TableIncompleteError(_, data) = error.kind() {
-                                let data: Table = deserialize(data).unwrap();
-                                show_debug_dialog(&format!("{:#?}", data.get_table_data()));
+                                // Load the new version, so we can start adding to it what changed.
+                                if let Some(definition) = get_definition(view.packed_file_type, &view.packed_file_path, &view.packed_file_data, Some(new_version)) {
+                                    view.table_model.clear();
+                                    *mutable_data.index.lock().unwrap() = get_header_size(view.packed_file_type, &view.packed_file_data).unwrap();
+                                    let _ = view.update_view(definition.get_ref_fields(), true, &mut mutable_data.index.lock().unwrap());
+                                }
                             }
+                            Err(error) => show_dialog(view.table_view, error, false),
                         }
                     }
+                }
+            }
+        ));
 
-                    PackedFileType::Loc => match Loc::read(&view.packed_file_data, &schema, true) {
-                        Ok(_) => show_dialog(view.table_view, "Seems ok.", true),
-                        Err(error) => {
-                            if let ErrorKind::TableIncompleteError(_, data) = error.kind() {
-                                let data: Table = deserialize(data).unwrap();
-                                show_debug_dialog(&format!("{:#?}", data.get_table_data()));
-                            }
-                        }
-                    }
+        // Slot for the "Test Definition" button.
+        let test_definition = Slot::new(clone!(
+            view => move || {
+                match view.get_test_definition_report() {
+                    Ok(report) => show_debug_dialog(&report),
+                    Err(error) => show_dialog(view.table_view, error, false),
+                }
+            }
+        ));
 
-                   PackedFileType::MatchedCombat => match MatchedCombat::read(&view.packed_file_data, &schema, true) {
-                        Ok(_) => show_dialog(view.table_view, "Seems ok.", true),
-                        Err(error) => {
-                            if let ErrorKind::TableIncompleteError(_, data) = error.kind() {
-                                let data: Table = deserialize(data).unwrap();
-                                show_debug_dialog(&format!("{:#?}", data.get_table_data()));
-                            }
-                        }
-                    }
+        // Slot for the "Import from Assembly Kit" button.
+        let import_from_assembly_kit = Slot::new(clone!(
+            mut mutable_data,
+            mut view => move || {
+                CENTRAL_COMMAND.send_message_qt(Command::GetDefinitionFromAssKit(view.packed_file_path[1].to_owned()));
+                let response = CENTRAL_COMMAND.recv_message_qt();
+
+                // The view may have been closed while we were waiting for the response.
+                if !view.is_alive() { return; }
+
+                match response {
+                    Response::Definition(definition) => {
 
-                    _ => unimplemented!()
+                        // Reset the definition we have.
+                        view.table_model.clear();
+                        *mutable_data.index.lock().unwrap() = get_header_size(view.packed_file_type, &view.packed_file_data).unwrap();
+
+                        // Update the decoder view.
+                        let _ = view.update_view(definition.get_ref_fields(), true, &mut mutable_data.index.lock().unwrap());
+                    }
+                    Response::Error(error) => show_dialog(view.table_view, error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                 }
             }
         ));
@@ -574,7 +633,34 @@ impl PackedFileDecoderViewSlots {
         // Slot for the "Finish it!" button.
         let save_definition = Slot::new(clone!(
             mut view => move || {
-                let schema = view.add_definition_to_schema();
+
+                // In Raw Mode we cannot save to the schema, so we export the definition to a research file instead.
+                if is_raw_mode(view.packed_file_type) {
+                    let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                        view.table_view,
+                        &QString::from_std_str("Export Definition to Research File")
+                    );
+                    setup_file_dialog(&mut file_dialog, None);
+
+                    file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+                    file_dialog.set_confirm_overwrite(true);
+                    file_dialog.set_name_filter(&QString::from_std_str("RON Files (*.ron)"));
+                    file_dialog.set_default_suffix(&QString::from_std_str("ron"));
+
+                    if file_dialog.exec() == 1 && view.is_alive() {
+                        let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                        match view.export_definition_to_research_file(&path) {
+                            Ok(_) => show_dialog(view.table_view, "Definition successfully exported.", true),
+                            Err(error) => show_dialog(view.table_view, error, false),
+                        }
+                    }
+                    return;
+                }
+
+                let (schema, versions_updated) = match view.add_definition_to_schema() {
+                    Ok(data) => data,
+                    Err(error) => return show_dialog(view.table_view, error, false),
+                };
 
                 // Save and close all PackedFiles that use our definition.
                 let mut packed_files_to_save = vec![];
@@ -587,6 +673,7 @@ impl PackedFileDecoderViewSlots {
                     }
                 }
 
+                let mut problems = Problems::default();
                 for path in &packed_files_to_save {
                     if let Err(error) = app_ui.purge_that_one_specifically(
                         global_search_ui,
@@ -594,15 +681,27 @@ impl PackedFileDecoderViewSlots {
                         path,
                         true,
                     ) {
-                        show_dialog(view.table_view, error, false);
+                        problems.push(&path.join("/"), error);
                     }
                 }
+                problems.report(view.table_view);
 
                 CENTRAL_COMMAND.send_message_qt(Command::CleanCache(packed_files_to_save));
                 CENTRAL_COMMAND.send_message_qt(Command::SaveSchema(schema));
                 let response = CENTRAL_COMMAND.recv_message_qt();
+
+                // The view may have been closed while we were waiting for the response.
+                if !view.is_alive() { return; }
+
                 match response {
-                    Response::Success => show_dialog(view.table_view, "Schema successfully saved.", true),
+                    Response::Success => {
+                        if versions_updated.is_empty() {
+                            show_dialog(view.table_view, "Schema successfully saved.", true)
+                        } else {
+                            let versions = versions_updated.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(", ");
+                            show_dialog(view.table_view, format!("Schema successfully saved. The metadata of the fields has also been applied to the following versions: {}.", versions), true)
+                        }
+                    },
                     Response::Error(error) => show_dialog(view.table_view, error, false),
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                 }
@@ -616,6 +715,10 @@ impl PackedFileDecoderViewSlots {
             hex_view_scroll_sync,
             hex_view_selection_raw_sync,
             hex_view_selection_decoded_sync,
+            hex_view_zoom_in,
+            hex_view_zoom_out,
+            hex_view_zoom_reset,
+            table_view_select_field_bytes,
 
             use_this_bool,
             use_this_f32,
@@ -635,6 +738,7 @@ impl PackedFileDecoderViewSlots {
             table_view_context_menu_move_left,
             table_view_context_menu_move_right,
             table_view_context_menu_delete,
+            table_view_context_menu_edit_enum_values,
 
             table_view_context_menu,
             table_view_context_menu_enabler,
@@ -644,8 +748,10 @@ impl PackedFileDecoderViewSlots {
 
             table_view_old_versions_context_menu_load,
             table_view_old_versions_context_menu_delete,
+            table_view_old_versions_context_menu_clone,
 
             test_definition,
+            import_from_assembly_kit,
             remove_all_fields,
             save_definition,
         }
@@ -0,0 +1,29 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileDependencyManagerView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileDependencyManagerView` and `PackedFileDependencyManagerViewSlots` structs.
+!*/
+
+use super::{PackedFileDependencyManagerView, slots::PackedFileDependencyManagerViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileDependencyManagerView` with their slots in `PackedFileDependencyManagerViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &PackedFileDependencyManagerView, slots: &PackedFileDependencyManagerViewSlots) {
+    ui.get_mut_ptr_tree_model().item_changed().connect(&slots.modified);
+    ui.get_mut_ptr_tree_model().rows_removed().connect(&slots.reordered);
+    ui.get_mut_ptr_add_button().released().connect(&slots.add);
+    ui.get_mut_ptr_browse_button().released().connect(&slots.browse);
+    ui.get_mut_ptr_remove_button().released().connect(&slots.remove);
+}
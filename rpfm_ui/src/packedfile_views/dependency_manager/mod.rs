@@ -0,0 +1,358 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the view for the Dependency Manager.
+
+The dependency manager edits the list of PackFiles our PackFile depends on. The order of the list is the order the game
+loads them, so they can be reordered by dragging them. They can be disabled without losing them, and the ones that
+cannot be found in the data folder of the Game Selected are painted in red.
+!*/
+
+use qt_widgets::q_abstract_item_view::{DragDropMode, SelectionMode};
+use qt_widgets::q_file_dialog::FileMode;
+use qt_widgets::QFileDialog;
+use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+
+use qt_gui::QBrush;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::CheckState;
+use qt_core::DropAction;
+use qt_core::GlobalColor;
+use qt_core::ItemDataRole;
+use qt_core::QSignalBlocker;
+use qt_core::QString;
+use qt_core::QVariant;
+
+use cpp_core::MutPtr;
+
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
+
+use rpfm_error::Result;
+use rpfm_lib::common::{get_game_selected_data_packfiles_paths, get_game_selected_data_path};
+use rpfm_lib::packedfile::PackedFileType;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+use crate::locale::{qtr, tr, tre};
+use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic, new_help_label, setup_file_dialog};
+
+use self::slots::PackedFileDependencyManagerViewSlots;
+
+mod connections;
+pub mod slots;
+
+/// Page of the manual explaining how to use the dependency manager.
+const DOCS_PAGE_DEPENDENCY_MANAGER: &str = "chapter_editors_dependency_manager.html";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of the Dependency Manager.
+pub struct PackedFileDependencyManagerView {
+    tree_view: AtomicPtr<QTreeView>,
+    tree_model: AtomicPtr<QStandardItemModel>,
+    missing_label: AtomicPtr<QLabel>,
+    add_button: AtomicPtr<QPushButton>,
+    browse_button: AtomicPtr<QPushButton>,
+    remove_button: AtomicPtr<QPushButton>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileDependencyManagerView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileDependencyManagerView`.
+#[derive(Clone)]
+pub struct PackedFileDependencyManagerViewRaw {
+    pub path: Arc<RwLock<Vec<String>>>,
+    pub tree_view: MutPtr<QTreeView>,
+    pub tree_model: MutPtr<QStandardItemModel>,
+    pub missing_label: MutPtr<QLabel>,
+    pub add_button: MutPtr<QPushButton>,
+    pub browse_button: MutPtr<QPushButton>,
+    pub remove_button: MutPtr<QPushButton>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileDependencyManagerView`.
+impl PackedFileDependencyManagerView {
+
+    /// This function creates a new Dependency Manager View, and sets up his slots and connections.
+    ///
+    /// NOTE: The view must have an empty path, as the dependency list is not a real PackedFile.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        app_ui: &AppUI,
+        pack_file_contents_ui: &PackFileContentsUI,
+    ) -> Result<TheOneSlot> {
+
+        CENTRAL_COMMAND.send_message_qt(Command::GetDependencyManagerEntries);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let entries = match response {
+            Response::VecStringBool(entries) => entries,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        // Items are dropped between rows, never into other rows, so the list stays flat.
+        let mut tree_view = QTreeView::new_0a();
+        let mut tree_model = QStandardItemModel::new_0a();
+        tree_view.set_model(&mut tree_model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
+        tree_view.set_drag_drop_mode(DragDropMode::InternalMove);
+        tree_view.set_default_drop_action(DropAction::MoveAction);
+        tree_view.set_drag_drop_overwrite_mode(false);
+        tree_view.header().set_stretch_last_section(true);
+
+        let mut missing_label = QLabel::new();
+        missing_label.set_word_wrap(true);
+
+        let mut add_button = QPushButton::from_q_string(&qtr("dependency_manager_add"));
+        let mut browse_button = QPushButton::from_q_string(&qtr("dependency_manager_browse"));
+        let mut remove_button = QPushButton::from_q_string(&qtr("dependency_manager_remove"));
+        browse_button.set_tool_tip(&qtr("tt_dependency_manager_browse"));
+
+        layout.add_widget_5a(&mut tree_view, 0, 0, 1, 4);
+        layout.add_widget_5a(&mut missing_label, 1, 0, 1, 4);
+        layout.add_widget_5a(&mut add_button, 2, 0, 1, 1);
+        layout.add_widget_5a(&mut browse_button, 2, 1, 1, 1);
+        layout.add_widget_5a(&mut remove_button, 2, 2, 1, 1);
+
+        // The dependency manager is not obvious to use, so give it a link to its explanation in the manual.
+        layout.add_widget_5a(new_help_label(DOCS_PAGE_DEPENDENCY_MANAGER).into_ptr(), 2, 3, 1, 1);
+
+        let mut packed_file_dependency_manager_view_raw = PackedFileDependencyManagerViewRaw {
+            path: packed_file_view.get_path_raw(),
+            tree_view: tree_view.into_ptr(),
+            tree_model: tree_model.into_ptr(),
+            missing_label: missing_label.into_ptr(),
+            add_button: add_button.into_ptr(),
+            browse_button: browse_button.into_ptr(),
+            remove_button: remove_button.into_ptr(),
+        };
+
+        packed_file_dependency_manager_view_raw.load_data(&entries);
+
+        let packed_file_dependency_manager_view_slots = PackedFileDependencyManagerViewSlots::new(
+            &packed_file_dependency_manager_view_raw,
+            *app_ui,
+            *pack_file_contents_ui,
+        );
+
+        let packed_file_dependency_manager_view = Self {
+            tree_view: atomic_from_mut_ptr(packed_file_dependency_manager_view_raw.tree_view),
+            tree_model: atomic_from_mut_ptr(packed_file_dependency_manager_view_raw.tree_model),
+            missing_label: atomic_from_mut_ptr(packed_file_dependency_manager_view_raw.missing_label),
+            add_button: atomic_from_mut_ptr(packed_file_dependency_manager_view_raw.add_button),
+            browse_button: atomic_from_mut_ptr(packed_file_dependency_manager_view_raw.browse_button),
+            remove_button: atomic_from_mut_ptr(packed_file_dependency_manager_view_raw.remove_button),
+        };
+
+        connections::set_connections(&packed_file_dependency_manager_view, &packed_file_dependency_manager_view_slots);
+        packed_file_view.view = ViewType::Internal(View::DependencyManager(packed_file_dependency_manager_view));
+        packed_file_view.packed_file_type = PackedFileType::DependencyPackFilesList;
+
+        Ok(TheOneSlot::DependencyManager(packed_file_dependency_manager_view_slots))
+    }
+
+    /// This function returns the entries of the view, in order, with their enabled state.
+    pub unsafe fn save_data(&self) -> Vec<(String, bool)> {
+        let tree_model = self.get_mut_ptr_tree_model();
+        (0..tree_model.row_count_0a())
+            .map(|row| tree_model.item_1a(row))
+            .map(|item| (item.text().to_std_string(), item.check_state() == CheckState::Checked))
+            .collect()
+    }
+
+    /// This function returns a pointer to the TreeView.
+    pub fn get_mut_ptr_tree_view(&self) -> MutPtr<QTreeView> {
+        mut_ptr_from_atomic(&self.tree_view)
+    }
+
+    /// This function returns a pointer to the model of the TreeView.
+    pub fn get_mut_ptr_tree_model(&self) -> MutPtr<QStandardItemModel> {
+        mut_ptr_from_atomic(&self.tree_model)
+    }
+
+    /// This function returns a pointer to the label with the amount of PackFiles not found.
+    pub fn get_mut_ptr_missing_label(&self) -> MutPtr<QLabel> {
+        mut_ptr_from_atomic(&self.missing_label)
+    }
+
+    /// This function returns a pointer to the "Add" button.
+    pub fn get_mut_ptr_add_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_button)
+    }
+
+    /// This function returns a pointer to the "Add from Disk" button.
+    pub fn get_mut_ptr_browse_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.browse_button)
+    }
+
+    /// This function returns a pointer to the "Remove" button.
+    pub fn get_mut_ptr_remove_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.remove_button)
+    }
+}
+
+/// Implementation for `PackedFileDependencyManagerViewRaw`.
+impl PackedFileDependencyManagerViewRaw {
+
+    /// This function loads the provided entries into the tree, and checks them.
+    pub unsafe fn load_data(&mut self, entries: &[(String, bool)]) {
+        let mut blocker = QSignalBlocker::from_q_object(self.tree_model);
+        self.tree_model.clear();
+        self.tree_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("dependency_manager_packfile")).into_ptr());
+
+        for (pack_file, enabled) in entries {
+            self.append_row(pack_file, *enabled);
+        }
+
+        blocker.unblock();
+        self.check_rows();
+    }
+
+    /// This function adds a new empty row to the tree, and starts editing it.
+    pub unsafe fn add_row(&mut self) {
+        self.append_row("", true);
+        let row = self.tree_model.row_count_0a() - 1;
+
+        let index = self.tree_model.index_2a(row, 0);
+        self.tree_view.set_current_index(&index);
+        self.tree_view.scroll_to_1a(&index);
+        self.tree_view.edit(&index);
+    }
+
+    /// This function opens a FileDialog to pick PackFiles, starting in the data folder of the Game Selected, and adds them to the tree.
+    ///
+    /// PackFiles already in the list are skipped. It returns false if nothing was added.
+    pub unsafe fn browse(&mut self) -> bool {
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.tree_view,
+            &qtr("dependency_manager_browse"),
+        );
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+        file_dialog.set_file_mode(FileMode::ExistingFiles);
+        if let Some(ref path) = get_game_selected_data_path() {
+            if path.is_dir() { file_dialog.set_directory_q_string(&QString::from_std_str(path.to_string_lossy())); }
+        }
+
+        if file_dialog.exec() != 1 { return false; }
+
+        // Dependencies are referenced by file name, not by path.
+        let mut current = (0..self.tree_model.row_count_0a()).map(|row| self.tree_model.item_1a(row).text().to_std_string()).collect::<Vec<String>>();
+        let selected_files = file_dialog.selected_files();
+        let mut added = false;
+        for index in 0..selected_files.count_0a() {
+            let path = selected_files.at(index).to_std_string();
+            if let Some(name) = Path::new(&path).file_name().map(|name| name.to_string_lossy().to_string()) {
+                if !current.contains(&name) {
+                    self.append_row(&name, true);
+                    current.push(name);
+                    added = true;
+                }
+            }
+        }
+
+        self.check_rows();
+        added
+    }
+
+    /// This function removes the selected rows from the tree.
+    ///
+    /// It returns false if there was nothing selected.
+    pub unsafe fn remove_selected(&mut self) -> bool {
+        let indexes = self.tree_view.selection_model().selected_rows_0a();
+        let mut rows = (0..indexes.count_0a()).map(|index| indexes.at(index).row()).collect::<Vec<i32>>();
+        if rows.is_empty() { return false; }
+
+        rows.sort_unstable();
+        rows.dedup();
+        rows.reverse();
+        for row in rows {
+            self.tree_model.remove_row_1a(row);
+        }
+
+        self.check_rows();
+        true
+    }
+
+    /// This function checks if the PackFiles of the tree exist in the data folder of the Game Selected, and paints them accordingly.
+    ///
+    /// Disabled PackFiles are not checked, as the game doesn't load them. If the data folder cannot be found, nothing is checked.
+    pub unsafe fn check_rows(&mut self) {
+        let data_pack_files = get_game_selected_data_packfiles_paths().map(|paths| paths.iter()
+            .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().to_lowercase()))
+            .collect::<Vec<String>>());
+
+        // Painting the items is not an edit, so don't let the view think it is.
+        let mut blocker = QSignalBlocker::from_q_object(self.tree_model);
+        let mut missing_count = 0;
+        for row in 0..self.tree_model.row_count_0a() {
+            let mut item = self.tree_model.item_1a(row);
+            let pack_file = item.text().to_std_string();
+            let enabled = item.check_state() == CheckState::Checked;
+
+            let tooltip = if pack_file.is_empty() { None }
+                else if !pack_file.to_lowercase().ends_with(".pack") { Some(tr("dependency_manager_not_a_packfile")) }
+                else if let Some(ref data_pack_files) = data_pack_files {
+                    if enabled && !data_pack_files.contains(&pack_file.to_lowercase()) { Some(tr("dependency_manager_not_found")) } else { None }
+                } else { None };
+
+            match tooltip {
+                Some(tooltip) => {
+                    missing_count += 1;
+                    item.set_foreground(&QBrush::from_global_color(GlobalColor::Red));
+                    item.set_tool_tip(&QString::from_std_str(&tooltip));
+                }
+                None => {
+                    if enabled { item.set_data_2a(&QVariant::new(), ItemDataRole::ForegroundRole.to_int()); }
+                    else { item.set_foreground(&QBrush::from_global_color(GlobalColor::Gray)); }
+                    item.set_tool_tip(&QString::new());
+                }
+            }
+        }
+        blocker.unblock();
+
+        if missing_count > 0 {
+            self.missing_label.set_text(&QString::from_std_str(tre("dependency_manager_missing_count", &[&missing_count.to_string()])));
+            self.missing_label.show();
+        }
+        else {
+            self.missing_label.hide();
+        }
+    }
+
+    /// This function adds a row with the provided PackFile at the end of the tree.
+    unsafe fn append_row(&mut self, pack_file: &str, enabled: bool) {
+        let mut item = QStandardItem::from_q_string(&QString::from_std_str(pack_file));
+        item.set_checkable(true);
+        item.set_check_state(if enabled { CheckState::Checked } else { CheckState::Unchecked });
+        item.set_drop_enabled(false);
+        self.tree_model.append_row_q_standard_item(item.into_ptr());
+    }
+}
@@ -0,0 +1,98 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for the Dependency Manager View.
+!*/
+
+use qt_gui::SlotOfQStandardItem;
+
+use qt_core::Slot;
+
+use crate::app_ui::AppUI;
+use crate::packedfile_views::dependency_manager::PackedFileDependencyManagerViewRaw;
+use crate::packedfile_views::utils::set_modified;
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of the Dependency Manager.
+pub struct PackedFileDependencyManagerViewSlots {
+    pub modified: SlotOfQStandardItem<'static>,
+    pub reordered: Slot<'static>,
+    pub add: Slot<'static>,
+    pub browse: Slot<'static>,
+    pub remove: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileDependencyManagerViewSlots`.
+impl PackedFileDependencyManagerViewSlots {
+
+    /// This function creates the entire slot pack for the Dependency Manager View.
+    pub unsafe fn new(packed_file_view: &PackedFileDependencyManagerViewRaw, app_ui: AppUI, pack_file_contents_ui: PackFileContentsUI) -> Self {
+
+        // When a PackFile gets renamed, enabled or disabled, check it again and mark the PackFile as modified.
+        let modified = SlotOfQStandardItem::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move |_| {
+            packed_file_view.check_rows();
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Dragging a row moves it by inserting a copy and removing the original, so we only need to know when one gets removed.
+        let reordered = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to add a new empty PackFile.
+        let add = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            packed_file_view.add_row();
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to add PackFiles from disk.
+        let browse = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            if packed_file_view.browse() {
+                set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+            }
+        }));
+
+        // Slot to remove the selected PackFiles. Removing rows already marks the PackFile as modified.
+        let remove = Slot::new(clone!(
+            mut packed_file_view => move || {
+            packed_file_view.remove_selected();
+        }));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            modified,
+            reordered,
+            add,
+            browse,
+            remove,
+        }
+    }
+}
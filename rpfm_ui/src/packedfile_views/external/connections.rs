@@ -23,4 +23,5 @@ use super::{PackedFileExternalView, slots::PackedFileExternalViewSlots};
 pub unsafe fn set_connections(ui: &PackedFileExternalView, slots: &PackedFileExternalViewSlots) {
     ui.get_mut_ptr_stop_watching_button().released().connect(&slots.stop_watching);
     ui.get_mut_ptr_open_folder_button().released().connect(&slots.open_folder);
+    ui.get_mut_ptr_watcher_timer().timeout().connect(&slots.check_external_changes);
 }
@@ -17,6 +17,7 @@ use qt_widgets::QLabel;
 use qt_widgets::QPushButton;
 
 use qt_core::QString;
+use qt_core::QTimer;
 
 use cpp_core::MutPtr;
 
@@ -44,6 +45,9 @@ use self::slots::PackedFileExternalViewSlots;
 mod connections;
 pub mod slots;
 
+/// Interval, in milliseconds, between checks for changes found in the external file by the watcher of the background thread.
+const EXTERNAL_FILE_CHECK_INTERVAL: i32 = 1000;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -53,6 +57,7 @@ pub struct PackedFileExternalView {
     external_path: Arc<PathBuf>,
     stop_watching_button: AtomicPtr<QPushButton>,
     open_folder_button: AtomicPtr<QPushButton>,
+    watcher_timer: AtomicPtr<QTimer>,
 }
 
 /// This struct contains the raw version of each pointer in `PackedFileExternalView`, to be used when building the slots.
@@ -63,6 +68,7 @@ pub struct PackedFileExternalView {
 pub struct PackedFileExternalViewRaw {
     pub stop_watching_button: MutPtr<QPushButton>,
     pub open_folder_button: MutPtr<QPushButton>,
+    pub watcher_timer: MutPtr<QTimer>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -101,25 +107,34 @@ impl PackedFileExternalView {
         layout.add_widget_5a(&mut stop_watching_button, 1, 0, 1, 1);
         layout.add_widget_5a(&mut open_folder_button, 1, 1, 1, 1);
 
+        // Timer to periodically ask the background thread if the external program has saved the file. It's parented to the view, so it dies with it.
+        let mut watcher_timer = QTimer::new_1a(packed_file_view.get_mut_widget());
+        watcher_timer.set_interval(EXTERNAL_FILE_CHECK_INTERVAL);
+
         let packed_file_external_view_raw = PackedFileExternalViewRaw {
             stop_watching_button: stop_watching_button.into_ptr(),
             open_folder_button: open_folder_button.into_ptr(),
+            watcher_timer: watcher_timer.into_ptr(),
         };
 
+        let external_path = Arc::new(external_path);
         let packed_file_external_view_slots = PackedFileExternalViewSlots::new(
             *app_ui,
             *pack_file_contents_ui,
             *global_search_ui,
-            &packed_file_path
+            &packed_file_path,
+            &external_path,
         );
 
         let packed_file_external_view = Self {
-            external_path: Arc::new(external_path),
+            external_path,
             stop_watching_button: atomic_from_mut_ptr(packed_file_external_view_raw.stop_watching_button),
             open_folder_button: atomic_from_mut_ptr(packed_file_external_view_raw.open_folder_button),
+            watcher_timer: atomic_from_mut_ptr(packed_file_external_view_raw.watcher_timer),
         };
 
         connections::set_connections(&packed_file_external_view, &packed_file_external_view_slots);
+        packed_file_external_view.get_mut_ptr_watcher_timer().start_0a();
         packed_file_view.view = ViewType::External(packed_file_external_view);
         packed_file_view.packed_file_type = PackedFileType::Unknown;
 
@@ -131,6 +146,16 @@ impl PackedFileExternalView {
         self.external_path.to_path_buf()
     }
 
+    /// This function tells the background thread to stop watching the external file of the PackedFile. To be used when closing the view.
+    pub fn stop_watching(&self) {
+        CENTRAL_COMMAND.send_message_qt(Command::StopWatchingExternalFile(self.get_external_path()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::Success => {},
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
     /// This function returns a pointer to the `Stop Waching` button.
     pub fn get_mut_ptr_stop_watching_button(&self) -> MutPtr<QPushButton> {
         mut_ptr_from_atomic(&self.stop_watching_button)
@@ -140,4 +165,9 @@ impl PackedFileExternalView {
     pub fn get_mut_ptr_open_folder_button(&self) -> MutPtr<QPushButton> {
         mut_ptr_from_atomic(&self.open_folder_button)
     }
+
+    /// This function returns a pointer to the timer used to check for changes in the external file.
+    pub fn get_mut_ptr_watcher_timer(&self) -> MutPtr<QTimer> {
+        mut_ptr_from_atomic(&self.watcher_timer)
+    }
 }
@@ -18,12 +18,20 @@ use open::that_in_background;
 
 use std::cell::RefCell;
 use std::env::temp_dir;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::Arc;
+
+use rpfm_lib::packfile::PathType;
 
 use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::global_search_ui::GlobalSearchUI;
+use crate::packedfile_views::utils::set_modified;
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::utils::show_dialog;
+use crate::UI_STATE;
+use crate::utils::{catch_panic_in_slot, show_dialog};
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -33,6 +41,7 @@ use crate::utils::show_dialog;
 pub struct PackedFileExternalViewSlots {
     pub stop_watching: Slot<'static>,
     pub open_folder: Slot<'static>,
+    pub check_external_changes: Slot<'static>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -45,9 +54,10 @@ impl PackedFileExternalViewSlots {
     /// This function creates the entire slot pack for External PackedFile Views.
     pub unsafe fn new(
         mut app_ui: AppUI,
-        pack_file_contents_ui: PackFileContentsUI,
-        global_search_ui: GlobalSearchUI,
-        packed_file_path: &Rc<RefCell<Vec<String>>>
+        mut pack_file_contents_ui: PackFileContentsUI,
+        mut global_search_ui: GlobalSearchUI,
+        packed_file_path: &Rc<RefCell<Vec<String>>>,
+        external_path: &Arc<PathBuf>,
     )  -> Self {
 
         // Slot to close the open view.
@@ -60,14 +70,45 @@ impl PackedFileExternalViewSlots {
         ));
 
         // Slot to open the folder of the current PackedFile in the file manager.
-        let open_folder = Slot::new(move || {
+        let open_folder = Slot::new(move || catch_panic_in_slot(|| {
             let _ = that_in_background(temp_dir());
-        });
+        }));
+
+        // Slot to reimport the PackedFile when the external program saves it.
+        let packed_file_path = packed_file_path.clone();
+        let external_path = external_path.clone();
+        let check_external_changes = Slot::new(move || catch_panic_in_slot(|| {
+
+            // Don't interrupt the main thread if it's waiting for another response from the background thread.
+            if !app_ui.main_window.is_enabled() || CENTRAL_COMMAND.get_pending_commands_count() > 0 || CENTRAL_COMMAND.get_pending_responses_count() > 0 {
+                return;
+            }
+
+            let path = packed_file_path.borrow().to_vec();
+            CENTRAL_COMMAND.send_message_qt(Command::ReimportExternalFileIfModified((path.to_vec(), external_path.to_path_buf())));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::Bool(reimported) => if reimported {
+                    set_modified(true, &path, &mut app_ui, &mut pack_file_contents_ui);
+
+                    // If we have a GlobalSearch on, update the results for this specific PackedFile.
+                    let global_search = UI_STATE.get_global_search();
+                    if !global_search.pattern.is_empty() {
+                        let path_types = vec![PathType::File(path)];
+                        global_search_ui.search_on_path(&mut pack_file_contents_ui, path_types);
+                        UI_STATE.set_global_search(&global_search);
+                    }
+                }
+                Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }));
 
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             stop_watching,
             open_folder,
+            check_external_changes,
         }
     }
 }
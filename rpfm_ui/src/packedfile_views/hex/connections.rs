@@ -0,0 +1,40 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileHexView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileHexView` and `PackedFileHexViewSlots` structs.
+!*/
+
+use super::{PackedFileHexView, slots::PackedFileHexViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileHexView` with their slots in `PackedFileHexViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &PackedFileHexView, slots: &PackedFileHexViewSlots) {
+
+    // Sync the scroll bars of the three hex data views.
+    ui.get_mut_ptr_hex_view_index().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
+    ui.get_mut_ptr_hex_view_raw().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
+    ui.get_mut_ptr_hex_view_decoded().vertical_scroll_bar().value_changed().connect(&slots.hex_view_scroll_sync);
+
+    // Sync the selection of the hex data views.
+    ui.get_mut_ptr_hex_view_raw().selection_changed().connect(&slots.hex_view_selection_raw_sync);
+    ui.get_mut_ptr_hex_view_decoded().selection_changed().connect(&slots.hex_view_selection_decoded_sync);
+
+    ui.get_mut_ptr_offset_spinbox().value_changed().connect(&slots.goto_offset);
+    ui.get_mut_ptr_overwrite_button().released().connect(&slots.overwrite);
+    ui.get_mut_ptr_insert_button().released().connect(&slots.insert);
+    ui.get_mut_ptr_delete_button().released().connect(&slots.delete);
+    ui.get_mut_ptr_search_button().released().connect(&slots.find_next);
+    ui.get_mut_ptr_search_line_edit().return_pressed().connect(&slots.find_next);
+}
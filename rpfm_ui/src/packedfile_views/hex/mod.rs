@@ -0,0 +1,574 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the Hex Editor view, used for PackedFiles we cannot decode.
+
+It shows the data the same way the decoder does (offsets, bytes and their characters), and lets you overwrite, insert and
+delete bytes, jump to an offset, and search for a sequence of bytes or a text. The hex panes are read-only: edits are done
+through the controls under them, at the selected offset.
+
+The functions to fill and sync the hex panes are also used by the decoder.
+!*/
+
+use qt_widgets::QCheckBox;
+use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::QPushButton;
+use qt_widgets::QSpinBox;
+use qt_widgets::QTextEdit;
+use qt_widgets::QWidget;
+
+use qt_gui::QFontMetrics;
+use qt_gui::q_text_cursor::{MoveOperation, MoveMode};
+
+use qt_core::QSignalBlocker;
+use qt_core::QString;
+
+use cpp_core::MutPtr;
+
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
+
+use rpfm_error::{ErrorKind, Result};
+use rpfm_lib::packedfile::PackedFileType;
+use rpfm_lib::packfile::packedfile::PackedFileInfo;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+use crate::FONT_MONOSPACE;
+use crate::locale::{qtr, tr, tre};
+use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packedfile_views::utils::set_modified;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{atomic_from_mut_ptr, create_grid_layout, mut_ptr_from_atomic, ref_from_atomic, show_dialog};
+use self::slots::PackedFileHexViewSlots;
+
+mod connections;
+pub mod slots;
+
+/// Amount of bytes shown in each line of the hex panes.
+const BYTES_PER_LINE: usize = 16;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of the Hex Editor.
+pub struct PackedFileHexView {
+    hex_view_index: AtomicPtr<QTextEdit>,
+    hex_view_raw: AtomicPtr<QTextEdit>,
+    hex_view_decoded: AtomicPtr<QTextEdit>,
+    offset_spinbox: AtomicPtr<QSpinBox>,
+    bytes_line_edit: AtomicPtr<QLineEdit>,
+    overwrite_button: AtomicPtr<QPushButton>,
+    insert_button: AtomicPtr<QPushButton>,
+    delete_button: AtomicPtr<QPushButton>,
+    search_line_edit: AtomicPtr<QLineEdit>,
+    search_as_text_checkbox: AtomicPtr<QCheckBox>,
+    search_button: AtomicPtr<QPushButton>,
+    size_label: AtomicPtr<QLabel>,
+    data: Arc<RwLock<Vec<u8>>>,
+    path: Arc<RwLock<Vec<String>>>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileHexView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileHexView`.
+#[derive(Clone)]
+pub struct PackedFileHexViewRaw {
+    pub hex_view_index: MutPtr<QTextEdit>,
+    pub hex_view_raw: MutPtr<QTextEdit>,
+    pub hex_view_decoded: MutPtr<QTextEdit>,
+    pub offset_spinbox: MutPtr<QSpinBox>,
+    pub bytes_line_edit: MutPtr<QLineEdit>,
+    pub overwrite_button: MutPtr<QPushButton>,
+    pub insert_button: MutPtr<QPushButton>,
+    pub delete_button: MutPtr<QPushButton>,
+    pub search_line_edit: MutPtr<QLineEdit>,
+    pub search_as_text_checkbox: MutPtr<QCheckBox>,
+    pub search_button: MutPtr<QPushButton>,
+    pub size_label: MutPtr<QLabel>,
+    pub data: Arc<RwLock<Vec<u8>>>,
+    pub path: Arc<RwLock<Vec<String>>>,
+}
+
+/// This enum represents the edits the Hex Editor can do at the selected offset.
+#[derive(Clone, Copy, Debug)]
+pub enum HexEdit {
+    Overwrite,
+    Insert,
+    Delete,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileHexView`.
+impl PackedFileHexView {
+
+    /// This function creates a new Hex Editor View, and sets up his slots and connections.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        app_ui: &AppUI,
+        pack_file_contents_ui: &PackFileContentsUI,
+    ) -> Result<(TheOneSlot, PackedFileInfo)> {
+
+        // Get the raw data of the PackedFile.
+        CENTRAL_COMMAND.send_message_qt(Command::GetPackedFile(packed_file_view.get_path()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let (data, packed_file_info) = match response {
+            Response::OptionPackedFile(packed_file) => match packed_file {
+                Some(packed_file) => (packed_file.get_raw_data()?, PackedFileInfo::from(&packed_file)),
+                None => return Err(ErrorKind::PackedFileNotFound.into()),
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        // Create the hex panes, the same ones the decoder uses.
+        let mut hex_view_index = QTextEdit::new();
+        let mut hex_view_raw = QTextEdit::new();
+        let mut hex_view_decoded = QTextEdit::new();
+        hex_view_index.set_font(ref_from_atomic(&*FONT_MONOSPACE));
+        hex_view_raw.set_font(ref_from_atomic(&*FONT_MONOSPACE));
+        hex_view_decoded.set_font(ref_from_atomic(&*FONT_MONOSPACE));
+        hex_view_index.set_read_only(true);
+        hex_view_raw.set_read_only(true);
+        hex_view_decoded.set_read_only(true);
+
+        layout.add_widget_5a(&mut hex_view_index, 0, 0, 1, 1);
+        layout.add_widget_5a(&mut hex_view_raw, 0, 1, 1, 1);
+        layout.add_widget_5a(&mut hex_view_decoded, 0, 2, 1, 1);
+        layout.set_column_stretch(3, 10);
+
+        // Create the controls to edit the data.
+        let edit_widget = QWidget::new_0a().into_ptr();
+        let mut edit_layout = create_grid_layout(edit_widget);
+        let offset_label = QLabel::from_q_string(&qtr("hex_offset"));
+        let mut offset_spinbox = QSpinBox::new_0a();
+        offset_spinbox.set_display_integer_base(16);
+        offset_spinbox.set_prefix(&QString::from_std_str("0x"));
+        offset_spinbox.set_tool_tip(&qtr("tt_hex_offset"));
+
+        let mut bytes_line_edit = QLineEdit::new();
+        bytes_line_edit.set_placeholder_text(&qtr("hex_bytes"));
+        let mut overwrite_button = QPushButton::from_q_string(&qtr("hex_overwrite"));
+        let mut insert_button = QPushButton::from_q_string(&qtr("hex_insert"));
+        let mut delete_button = QPushButton::from_q_string(&qtr("hex_delete"));
+        delete_button.set_tool_tip(&qtr("tt_hex_delete"));
+
+        let mut search_line_edit = QLineEdit::new();
+        search_line_edit.set_placeholder_text(&qtr("hex_search"));
+        let mut search_as_text_checkbox = QCheckBox::from_q_string(&qtr("hex_search_as_text"));
+        let mut search_button = QPushButton::from_q_string(&qtr("hex_find_next"));
+        let mut size_label = QLabel::new();
+
+        edit_layout.add_widget_5a(offset_label.into_ptr(), 0, 0, 1, 1);
+        edit_layout.add_widget_5a(&mut offset_spinbox, 0, 1, 1, 1);
+        edit_layout.add_widget_5a(&mut bytes_line_edit, 0, 2, 1, 1);
+        edit_layout.add_widget_5a(&mut overwrite_button, 0, 3, 1, 1);
+        edit_layout.add_widget_5a(&mut insert_button, 0, 4, 1, 1);
+        edit_layout.add_widget_5a(&mut delete_button, 0, 5, 1, 1);
+        edit_layout.add_widget_5a(&mut search_line_edit, 1, 0, 1, 3);
+        edit_layout.add_widget_5a(&mut search_as_text_checkbox, 1, 3, 1, 1);
+        edit_layout.add_widget_5a(&mut search_button, 1, 4, 1, 1);
+        edit_layout.add_widget_5a(&mut size_label, 1, 5, 1, 1);
+        edit_layout.set_column_stretch(2, 10);
+        layout.add_widget_5a(edit_widget, 1, 0, 1, 4);
+
+        let mut packed_file_hex_view_raw = PackedFileHexViewRaw {
+            hex_view_index: hex_view_index.into_ptr(),
+            hex_view_raw: hex_view_raw.into_ptr(),
+            hex_view_decoded: hex_view_decoded.into_ptr(),
+            offset_spinbox: offset_spinbox.into_ptr(),
+            bytes_line_edit: bytes_line_edit.into_ptr(),
+            overwrite_button: overwrite_button.into_ptr(),
+            insert_button: insert_button.into_ptr(),
+            delete_button: delete_button.into_ptr(),
+            search_line_edit: search_line_edit.into_ptr(),
+            search_as_text_checkbox: search_as_text_checkbox.into_ptr(),
+            search_button: search_button.into_ptr(),
+            size_label: size_label.into_ptr(),
+            data: Arc::new(RwLock::new(data)),
+            path: packed_file_view.get_path_raw(),
+        };
+
+        packed_file_hex_view_raw.load_data();
+
+        let packed_file_hex_view_slots = PackedFileHexViewSlots::new(packed_file_hex_view_raw.clone(), *app_ui, *pack_file_contents_ui);
+
+        let packed_file_hex_view = Self {
+            hex_view_index: atomic_from_mut_ptr(packed_file_hex_view_raw.hex_view_index),
+            hex_view_raw: atomic_from_mut_ptr(packed_file_hex_view_raw.hex_view_raw),
+            hex_view_decoded: atomic_from_mut_ptr(packed_file_hex_view_raw.hex_view_decoded),
+            offset_spinbox: atomic_from_mut_ptr(packed_file_hex_view_raw.offset_spinbox),
+            bytes_line_edit: atomic_from_mut_ptr(packed_file_hex_view_raw.bytes_line_edit),
+            overwrite_button: atomic_from_mut_ptr(packed_file_hex_view_raw.overwrite_button),
+            insert_button: atomic_from_mut_ptr(packed_file_hex_view_raw.insert_button),
+            delete_button: atomic_from_mut_ptr(packed_file_hex_view_raw.delete_button),
+            search_line_edit: atomic_from_mut_ptr(packed_file_hex_view_raw.search_line_edit),
+            search_as_text_checkbox: atomic_from_mut_ptr(packed_file_hex_view_raw.search_as_text_checkbox),
+            search_button: atomic_from_mut_ptr(packed_file_hex_view_raw.search_button),
+            size_label: atomic_from_mut_ptr(packed_file_hex_view_raw.size_label),
+            data: packed_file_hex_view_raw.data,
+            path: packed_file_hex_view_raw.path,
+        };
+
+        connections::set_connections(&packed_file_hex_view, &packed_file_hex_view_slots);
+        packed_file_view.packed_file_type = PackedFileType::Unknown;
+        packed_file_view.view = ViewType::Internal(View::Hex(packed_file_hex_view));
+
+        // Return success.
+        Ok((TheOneSlot::Hex(packed_file_hex_view_slots), packed_file_info))
+    }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    pub unsafe fn reload_view(&self, data: &[u8]) {
+        *self.data.write().unwrap() = data.to_vec();
+        let mut view = PackedFileHexViewRaw {
+            hex_view_index: self.get_mut_ptr_hex_view_index(),
+            hex_view_raw: self.get_mut_ptr_hex_view_raw(),
+            hex_view_decoded: self.get_mut_ptr_hex_view_decoded(),
+            offset_spinbox: self.get_mut_ptr_offset_spinbox(),
+            bytes_line_edit: self.get_mut_ptr_bytes_line_edit(),
+            overwrite_button: self.get_mut_ptr_overwrite_button(),
+            insert_button: self.get_mut_ptr_insert_button(),
+            delete_button: self.get_mut_ptr_delete_button(),
+            search_line_edit: self.get_mut_ptr_search_line_edit(),
+            search_as_text_checkbox: self.get_mut_ptr_search_as_text_checkbox(),
+            search_button: self.get_mut_ptr_search_button(),
+            size_label: mut_ptr_from_atomic(&self.size_label),
+            data: self.data.clone(),
+            path: self.path.clone(),
+        };
+
+        view.load_data();
+    }
+
+    /// This function returns a copy of the data currently in the view.
+    pub fn get_data(&self) -> Vec<u8> {
+        self.data.read().unwrap().to_vec()
+    }
+
+    /// This function returns a pointer to the index pane.
+    pub fn get_mut_ptr_hex_view_index(&self) -> MutPtr<QTextEdit> {
+        mut_ptr_from_atomic(&self.hex_view_index)
+    }
+
+    /// This function returns a pointer to the raw data pane.
+    pub fn get_mut_ptr_hex_view_raw(&self) -> MutPtr<QTextEdit> {
+        mut_ptr_from_atomic(&self.hex_view_raw)
+    }
+
+    /// This function returns a pointer to the decoded data pane.
+    pub fn get_mut_ptr_hex_view_decoded(&self) -> MutPtr<QTextEdit> {
+        mut_ptr_from_atomic(&self.hex_view_decoded)
+    }
+
+    /// This function returns a pointer to the offset SpinBox.
+    pub fn get_mut_ptr_offset_spinbox(&self) -> MutPtr<QSpinBox> {
+        mut_ptr_from_atomic(&self.offset_spinbox)
+    }
+
+    /// This function returns a pointer to the bytes LineEdit.
+    pub fn get_mut_ptr_bytes_line_edit(&self) -> MutPtr<QLineEdit> {
+        mut_ptr_from_atomic(&self.bytes_line_edit)
+    }
+
+    /// This function returns a pointer to the overwrite button.
+    pub fn get_mut_ptr_overwrite_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.overwrite_button)
+    }
+
+    /// This function returns a pointer to the insert button.
+    pub fn get_mut_ptr_insert_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.insert_button)
+    }
+
+    /// This function returns a pointer to the delete button.
+    pub fn get_mut_ptr_delete_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.delete_button)
+    }
+
+    /// This function returns a pointer to the search LineEdit.
+    pub fn get_mut_ptr_search_line_edit(&self) -> MutPtr<QLineEdit> {
+        mut_ptr_from_atomic(&self.search_line_edit)
+    }
+
+    /// This function returns a pointer to the "Search as Text" CheckBox.
+    pub fn get_mut_ptr_search_as_text_checkbox(&self) -> MutPtr<QCheckBox> {
+        mut_ptr_from_atomic(&self.search_as_text_checkbox)
+    }
+
+    /// This function returns a pointer to the search button.
+    pub fn get_mut_ptr_search_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.search_button)
+    }
+}
+
+/// Implementation for `PackedFileHexViewRaw`.
+impl PackedFileHexViewRaw {
+
+    /// This function loads the data into the hex panes, and updates the limits of the offset.
+    pub unsafe fn load_data(&mut self) {
+        let data = self.data.read().unwrap();
+        load_hex_data(self.hex_view_index, self.hex_view_raw, self.hex_view_decoded, &data);
+
+        // The offset can be the end of the data, so we can insert bytes at the end.
+        self.offset_spinbox.set_maximum(data.len() as i32);
+        self.size_label.set_text(&QString::from_std_str(tre("hex_size", &[&data.len().to_string()])));
+    }
+
+    /// This function selects the provided range of bytes in the hex panes, and scrolls to it.
+    pub unsafe fn select_bytes(&mut self, offset: usize, len: usize) {
+        let mut cursor = self.hex_view_raw.text_cursor();
+        cursor.move_position_1a(MoveOperation::Start);
+        cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, (offset * 3) as i32);
+        if len > 0 {
+            cursor.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (len * 3 - 1) as i32);
+        }
+        self.hex_view_raw.set_text_cursor(&cursor);
+        self.hex_view_raw.ensure_cursor_visible();
+    }
+
+    /// This function updates the offset with the start of the selection in the raw data pane.
+    pub unsafe fn update_offset_from_selection(&mut self) {
+        let offset = self.hex_view_raw.text_cursor().selection_start() as usize / 3;
+        let mut blocker = QSignalBlocker::from_q_object(self.offset_spinbox);
+        self.offset_spinbox.set_value(offset as i32);
+        blocker.unblock();
+    }
+
+    /// This function returns the amount of bytes selected in the raw data pane.
+    unsafe fn get_selection_len(&self) -> usize {
+        let cursor = self.hex_view_raw.text_cursor();
+        let start = cursor.selection_start() as usize / 3;
+        let end = (cursor.selection_end() as usize + 2) / 3;
+        end.saturating_sub(start)
+    }
+
+    /// This function applies the provided edit at the selected offset, marking the PackedFile as modified if it worked.
+    ///
+    /// Overwrites and inserts use the bytes written in the bytes LineEdit. Deletes remove the selected bytes, or the one at the offset if there is no selection.
+    pub unsafe fn edit(&mut self, edit: HexEdit, app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI) {
+        let offset = self.offset_spinbox.value() as usize;
+        let bytes = match edit {
+            HexEdit::Overwrite | HexEdit::Insert => match parse_hex_bytes(&self.bytes_line_edit.text().to_std_string()) {
+                Some(bytes) => bytes,
+                None => return show_dialog(self.hex_view_raw, tr("hex_invalid_bytes"), false),
+            }
+            HexEdit::Delete => vec![],
+        };
+
+        let len = {
+            let mut data = self.data.write().unwrap();
+            match edit {
+                HexEdit::Overwrite => {
+                    let end = offset + bytes.len();
+                    if end > data.len() { data.resize(end, 0); }
+                    data[offset..end].copy_from_slice(&bytes);
+                    bytes.len()
+                }
+                HexEdit::Insert => {
+                    data.splice(offset..offset, bytes.iter().copied());
+                    bytes.len()
+                }
+                HexEdit::Delete => {
+                    if offset >= data.len() { return; }
+                    let end = (offset + self.get_selection_len().max(1)).min(data.len());
+                    data.drain(offset..end);
+                    0
+                }
+            }
+        };
+
+        self.load_data();
+        self.select_bytes(offset, len);
+        self.update_offset_from_selection();
+        set_modified(true, &self.path.read().unwrap(), app_ui, pack_file_contents_ui);
+    }
+
+    /// This function selects the next match of the pattern in the search LineEdit, starting after the selected offset.
+    ///
+    /// If we reach the end of the data, it continues from the start.
+    pub unsafe fn find_next(&mut self) {
+        let text = self.search_line_edit.text().to_std_string();
+        if text.is_empty() { return; }
+
+        let pattern = if self.search_as_text_checkbox.is_checked() { text.as_bytes().to_vec() }
+        else {
+            match parse_hex_bytes(&text) {
+                Some(pattern) => pattern,
+                None => return show_dialog(self.hex_view_raw, tr("hex_invalid_bytes"), false),
+            }
+        };
+
+        let position = find_bytes(&self.data.read().unwrap(), &pattern, self.offset_spinbox.value() as usize + 1);
+        match position {
+            Some(position) => {
+                self.select_bytes(position, pattern.len());
+                self.update_offset_from_selection();
+            }
+            None => show_dialog(self.hex_view_raw, tr("hex_search_no_matches"), false),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------//
+//                             Functions
+//-------------------------------------------------------------------------------//
+
+/// This function loads the provided data into the provided hex panes: the offsets of each line, the bytes, and their characters.
+pub unsafe fn load_hex_data(
+    mut hex_view_index: MutPtr<QTextEdit>,
+    mut hex_view_raw: MutPtr<QTextEdit>,
+    mut hex_view_decoded: MutPtr<QTextEdit>,
+    data: &[u8],
+) {
+
+    // We need to set up the fonts in a specific way, so the scroll/sizes are kept correct.
+    let font = hex_view_index.document().default_font();
+    let font_metrics = QFontMetrics::new_1a(&font);
+
+    //---------------------------------------------//
+    // Index section.
+    //---------------------------------------------//
+
+    // This creates the "index" column at the left of the hex data. The logic behind this, because
+    // even I have problems to understand it:
+    // - Lines are 4 packs of 4 bytes => 16 bytes + 3 spaces + 1 line jump.
+    // - Amount of lines is "bytes we have / 16 + 1" (+ 1 because we want to show incomplete lines too).
+    // - Then, for the zeroes, we default to 4, meaning all lines are 00XX.
+    let mut hex_index = String::new();
+    let hex_lines = (data.len() / BYTES_PER_LINE) + 1;
+    (0..hex_lines).for_each(|x| hex_index.push_str(&format!("{:>0count$X}\n", x * BYTES_PER_LINE, count = 4)));
+
+    let qhex_index = QString::from_std_str(&hex_index);
+    let text_size = font_metrics.size_2a(0, &qhex_index);
+    hex_view_index.set_text(&qhex_index);
+    hex_view_index.set_fixed_width(text_size.width() + 34);
+
+    //---------------------------------------------//
+    // Raw data section.
+    //---------------------------------------------//
+
+    // Prepare the Hex Raw Data string, looking like:
+    // 01 0a 02 0f 0d 02 04 06 01 0a 02 0f 0d 02 04 06
+    let mut hex_raw_data = format!("{:02X?}", data);
+    hex_raw_data.remove(0);
+    hex_raw_data.pop();
+    hex_raw_data.retain(|c| c != ',');
+
+    // Note: this works on BYTES, NOT CHARACTERS. Which means some characters may use multiple bytes,
+    // and if you pass these functions a range thats not a character, they panic!
+    // For reference, everything is one byte except the thin whitespace that's three bytes.
+    if !hex_raw_data.is_empty() {
+        (2..hex_raw_data.len() - 1).rev().step_by(3).filter(|x| x % 4 != 0).for_each(|x| hex_raw_data.replace_range(x - 1..x, " "));
+    }
+    if hex_raw_data.len() > 70 {
+        (70..hex_raw_data.len() - 1).rev().filter(|x| x % 72 == 0).for_each(|x| hex_raw_data.replace_range(x - 1..x, "\n"));
+    }
+
+    let qhex_raw_data = QString::from_std_str(&hex_raw_data);
+    let text_size = font_metrics.size_2a(0, &qhex_raw_data);
+    hex_view_raw.set_text(&qhex_raw_data);
+    hex_view_raw.set_fixed_width(text_size.width() + 34);
+
+    //---------------------------------------------//
+    // Decoded data section.
+    //---------------------------------------------//
+
+    // This pushes a newline after 16 characters.
+    let mut hex_decoded_data = String::new();
+    for (j, i) in data.iter().enumerate() {
+        if j % BYTES_PER_LINE == 0 && j != 0 { hex_decoded_data.push('\n'); }
+        let character = *i as char;
+
+        // If is a valid UTF-8 char, show it. Otherwise, default to '.'.
+        if character.is_alphanumeric() { hex_decoded_data.push(character); }
+        else { hex_decoded_data.push('.'); }
+    }
+
+    // Add all the "Decoded" lines to the TextEdit.
+    let qhex_decoded_data = QString::from_std_str(&hex_decoded_data);
+    let text_size = font_metrics.size_2a(0, &qhex_decoded_data);
+    hex_view_decoded.set_text(&qhex_decoded_data);
+    hex_view_decoded.set_fixed_width(text_size.width() + 34);
+}
+
+/// This function syncronize the selection between the Hex View and the Decoded View of the provided hex panes.
+/// Pass `hex = true` if the selected view is the Hex View. Otherwise, pass false.
+pub unsafe fn hex_selection_sync(mut hex_view_raw: MutPtr<QTextEdit>, mut hex_view_decoded: MutPtr<QTextEdit>, hex: bool) {
+
+    let cursor = if hex { hex_view_raw.text_cursor() } else { hex_view_decoded.text_cursor() };
+    let mut cursor_dest = if !hex { hex_view_raw.text_cursor() } else { hex_view_decoded.text_cursor() };
+
+    let mut selection_start = cursor.selection_start();
+    let mut selection_end = cursor.selection_end();
+
+    // Translate the selection from one view to the other, doing some maths.
+    if hex {
+        selection_start = ((selection_start + 1) / 3) + (selection_start / 48);
+        selection_end = ((selection_end + 2) / 3) + (selection_end / 48);
+    }
+    else {
+        selection_start = (selection_start - (selection_start / 17)) * 3;
+        selection_end = (selection_end - (selection_end / 17)) * 3;
+    }
+
+    // Fix for the situation where you select less than what in the decoded view will be one character, being the change:
+    // 3 chars in raw = 1 in decoded.
+    if hex && selection_start == selection_end && cursor.selection_start() != cursor.selection_end() {
+        selection_end += 1;
+    }
+
+    cursor_dest.move_position_1a(MoveOperation::Start);
+    cursor_dest.move_position_3a(MoveOperation::NextCharacter, MoveMode::MoveAnchor, selection_start as i32);
+    cursor_dest.move_position_3a(MoveOperation::NextCharacter, MoveMode::KeepAnchor, (selection_end - selection_start) as i32);
+
+    // Block the signals during this, so we don't trigger an infinite loop.
+    if hex {
+        let mut blocker = QSignalBlocker::from_q_object(hex_view_decoded);
+        hex_view_decoded.set_text_cursor(&cursor_dest);
+        blocker.unblock();
+    }
+    else {
+        let mut blocker = QSignalBlocker::from_q_object(hex_view_raw);
+        hex_view_raw.set_text_cursor(&cursor_dest);
+        blocker.unblock();
+    }
+}
+
+/// This function parses a sequence of bytes written in hex, like `0A FF 10` or `0aff10`.
+///
+/// It returns None if the text is empty or it's not valid hex.
+fn parse_hex_bytes(text: &str) -> Option<Vec<u8>> {
+    let text = text.chars().filter(|character| !character.is_whitespace()).collect::<String>();
+    if text.is_empty() || text.len() % 2 != 0 || !text.is_ascii() { return None; }
+
+    (0..text.len()).step_by(2).map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok()).collect()
+}
+
+/// This function returns the position of the first match of the pattern in the data, starting at the provided position.
+///
+/// If there are no matches after that position, it continues from the start of the data.
+fn find_bytes(data: &[u8], pattern: &[u8], from: usize) -> Option<usize> {
+    if pattern.is_empty() || pattern.len() > data.len() { return None; }
+
+    let positions = 0..=data.len() - pattern.len();
+    let from = from.min(*positions.end() + 1);
+    positions.clone().skip(from).chain(positions.take(from)).find(|position| data[*position..*position + pattern.len()] == *pattern)
+}
@@ -0,0 +1,122 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for Hex Editor Views.
+!*/
+
+use qt_core::{Slot, SlotOfInt};
+
+use crate::app_ui::AppUI;
+use crate::packedfile_views::hex::{hex_selection_sync, HexEdit, PackedFileHexViewRaw};
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of the Hex Editor.
+pub struct PackedFileHexViewSlots {
+    pub hex_view_scroll_sync: SlotOfInt<'static>,
+    pub hex_view_selection_raw_sync: Slot<'static>,
+    pub hex_view_selection_decoded_sync: Slot<'static>,
+    pub goto_offset: SlotOfInt<'static>,
+    pub overwrite: Slot<'static>,
+    pub insert: Slot<'static>,
+    pub delete: Slot<'static>,
+    pub find_next: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileHexViewSlots`.
+impl PackedFileHexViewSlots {
+
+    /// This function creates the entire slot pack for Hex Editor Views.
+    pub unsafe fn new(
+        view: PackedFileHexViewRaw,
+        app_ui: AppUI,
+        pack_file_contents_ui: PackFileContentsUI,
+    ) -> Self {
+
+        // Slot to keep scroll in views in sync.
+        let hex_view_scroll_sync = SlotOfInt::new(clone!(
+            mut view => move |value| {
+            view.hex_view_index.vertical_scroll_bar().set_value(value);
+            view.hex_view_raw.vertical_scroll_bar().set_value(value);
+            view.hex_view_decoded.vertical_scroll_bar().set_value(value);
+        }));
+
+        // Slot to keep selection in views in sync, and the offset pointing to the start of the selection.
+        let hex_view_selection_raw_sync = Slot::new(clone!(
+            mut view => move || {
+            hex_selection_sync(view.hex_view_raw, view.hex_view_decoded, true);
+            view.update_offset_from_selection();
+        }));
+
+        // Slot to keep selection in views in sync, and the offset pointing to the start of the selection.
+        let hex_view_selection_decoded_sync = Slot::new(clone!(
+            mut view => move || {
+            hex_selection_sync(view.hex_view_raw, view.hex_view_decoded, false);
+            view.update_offset_from_selection();
+        }));
+
+        // Slot to select the byte at the offset when we change it by hand.
+        let goto_offset = SlotOfInt::new(clone!(
+            mut view => move |offset| {
+            let len = if (offset as usize) < view.data.read().unwrap().len() { 1 } else { 0 };
+            view.select_bytes(offset as usize, len);
+        }));
+
+        // Slot to overwrite the bytes at the offset.
+        let overwrite = Slot::new(clone!(
+            mut app_ui,
+            mut pack_file_contents_ui,
+            mut view => move || {
+            view.edit(HexEdit::Overwrite, &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to insert bytes at the offset.
+        let insert = Slot::new(clone!(
+            mut app_ui,
+            mut pack_file_contents_ui,
+            mut view => move || {
+            view.edit(HexEdit::Insert, &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to delete the selected bytes.
+        let delete = Slot::new(clone!(
+            mut app_ui,
+            mut pack_file_contents_ui,
+            mut view => move || {
+            view.edit(HexEdit::Delete, &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to search the next match of the pattern.
+        let find_next = Slot::new(clone!(
+            mut view => move || {
+            view.find_next();
+        }));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            hex_view_scroll_sync,
+            hex_view_selection_raw_sync,
+            hex_view_selection_decoded_sync,
+            goto_offset,
+            overwrite,
+            insert,
+            delete,
+            find_next,
+        }
+    }
+}
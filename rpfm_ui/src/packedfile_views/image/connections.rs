@@ -0,0 +1,29 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileImageView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileImageView` and `PackedFileImageViewSlots` structs.
+!*/
+
+use super::{PackedFileImageView, slots::PackedFileImageViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileImageView` with their slots in `PackedFileImageViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &PackedFileImageView, slots: &PackedFileImageViewSlots) {
+    ui.get_mut_ptr_mipmap_combo_box().current_index_changed().connect(&slots.change_mipmap);
+    ui.get_mut_ptr_red_check_box().toggled().connect(&slots.toggle_channel);
+    ui.get_mut_ptr_green_check_box().toggled().connect(&slots.toggle_channel);
+    ui.get_mut_ptr_blue_check_box().toggled().connect(&slots.toggle_channel);
+    ui.get_mut_ptr_alpha_check_box().toggled().connect(&slots.toggle_channel);
+}
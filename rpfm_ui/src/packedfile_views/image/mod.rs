@@ -12,31 +12,40 @@
 Module with all the code for managing the view for Images.
 !*/
 
+use qt_widgets::QCheckBox;
+use qt_widgets::QComboBox;
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
+use qt_widgets::QWidget;
 
+use qt_gui::QImage;
+use qt_gui::q_image::Format;
 use qt_gui::QPixmap;
 
 use qt_core::QFlags;
 use qt_core::AlignmentFlag;
 use qt_core::QByteArray;
+use qt_core::QString;
 
 use cpp_core::MutPtr;
 
+use std::sync::{Arc, RwLock};
 use std::sync::atomic::AtomicPtr;
 
 use rpfm_error::{Result, ErrorKind};
-use rpfm_lib::packedfile::image::Image;
+use rpfm_lib::packedfile::image::{dds::DDS, Image};
 use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::packfile::packedfile::PackedFileInfo;
 
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::ffi::{new_resizable_label_safe, set_pixmap_on_resizable_label_safe};
+use crate::locale::qtr;
 use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
-use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic};
+use crate::utils::{atomic_from_mut_ptr, create_grid_layout, mut_ptr_from_atomic};
 use self::slots::PackedFileImageViewSlots;
 
+mod connections;
 pub mod slots;
 
 //-------------------------------------------------------------------------------//
@@ -47,6 +56,30 @@ pub mod slots;
 pub struct PackedFileImageView {
     label: AtomicPtr<QLabel>,
     image: AtomicPtr<QPixmap>,
+    dds_controls: AtomicPtr<QWidget>,
+    mipmap_combo_box: AtomicPtr<QComboBox>,
+    red_check_box: AtomicPtr<QCheckBox>,
+    green_check_box: AtomicPtr<QCheckBox>,
+    blue_check_box: AtomicPtr<QCheckBox>,
+    alpha_check_box: AtomicPtr<QCheckBox>,
+    dds: Arc<RwLock<Option<DDS>>>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileImageView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileImageView`.
+#[derive(Clone)]
+pub struct PackedFileImageViewRaw {
+    pub label: MutPtr<QLabel>,
+    pub image: MutPtr<QPixmap>,
+    pub dds_controls: MutPtr<QWidget>,
+    pub mipmap_combo_box: MutPtr<QComboBox>,
+    pub red_check_box: MutPtr<QCheckBox>,
+    pub green_check_box: MutPtr<QCheckBox>,
+    pub blue_check_box: MutPtr<QCheckBox>,
+    pub alpha_check_box: MutPtr<QCheckBox>,
+    pub dds: Arc<RwLock<Option<DDS>>>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -71,36 +104,176 @@ impl PackedFileImageView {
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         };
 
-        // Create the image in the UI.
-        let byte_array = QByteArray::from_slice(image.get_data());
-        let mut image = QPixmap::new().into_ptr();
-        if !image.load_from_data_q_byte_array(byte_array.into_ptr().as_ref().unwrap()) {
-           return Err(ErrorKind::ImageDecode("The image is not supported by the previsualizer.".to_owned()).into());
-        }
-
         // Get the size of the holding widget.
         let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
-        let mut label = new_resizable_label_safe(&mut packed_file_view.get_mut_widget(), &mut image);
+        let mut pixmap = QPixmap::new().into_ptr();
+        let mut label = new_resizable_label_safe(&mut packed_file_view.get_mut_widget(), &mut pixmap);
         label.set_alignment(QFlags::from(AlignmentFlag::AlignCenter));
         layout.add_widget_5a(label.as_mut_raw_ptr(), 0, 0, 1, 1);
 
+        // Controls to check the mipmaps and channels of DDS images. Only visible for DDS images.
+        let dds_controls = QWidget::new_0a().into_ptr();
+        let mut dds_controls_layout = create_grid_layout(dds_controls);
+        let mipmap_label = QLabel::from_q_string(&qtr("image_mipmap"));
+        let mut mipmap_combo_box = QComboBox::new_0a();
+        let mut red_check_box = QCheckBox::from_q_string(&qtr("image_channel_red"));
+        let mut green_check_box = QCheckBox::from_q_string(&qtr("image_channel_green"));
+        let mut blue_check_box = QCheckBox::from_q_string(&qtr("image_channel_blue"));
+        let mut alpha_check_box = QCheckBox::from_q_string(&qtr("image_channel_alpha"));
+        red_check_box.set_checked(true);
+        green_check_box.set_checked(true);
+        blue_check_box.set_checked(true);
+        alpha_check_box.set_checked(true);
+
+        dds_controls_layout.add_widget_5a(mipmap_label.into_ptr(), 0, 0, 1, 1);
+        dds_controls_layout.add_widget_5a(&mut mipmap_combo_box, 0, 1, 1, 1);
+        dds_controls_layout.add_widget_5a(&mut red_check_box, 0, 2, 1, 1);
+        dds_controls_layout.add_widget_5a(&mut green_check_box, 0, 3, 1, 1);
+        dds_controls_layout.add_widget_5a(&mut blue_check_box, 0, 4, 1, 1);
+        dds_controls_layout.add_widget_5a(&mut alpha_check_box, 0, 5, 1, 1);
+        dds_controls_layout.set_column_stretch(1, 10);
+        layout.add_widget_5a(dds_controls, 1, 0, 1, 1);
+
+        let mut packed_file_image_view_raw = PackedFileImageViewRaw {
+            label,
+            image: pixmap,
+            dds_controls,
+            mipmap_combo_box: mipmap_combo_box.into_ptr(),
+            red_check_box: red_check_box.into_ptr(),
+            green_check_box: green_check_box.into_ptr(),
+            blue_check_box: blue_check_box.into_ptr(),
+            alpha_check_box: alpha_check_box.into_ptr(),
+            dds: Arc::new(RwLock::new(None)),
+        };
+
+        packed_file_image_view_raw.load_image(&image)?;
+
+        let packed_file_image_view_slots = PackedFileImageViewSlots::new(packed_file_image_view_raw.clone());
+
+        let packed_file_image_view = Self {
+            label: atomic_from_mut_ptr(packed_file_image_view_raw.label),
+            image: atomic_from_mut_ptr(packed_file_image_view_raw.image),
+            dds_controls: atomic_from_mut_ptr(packed_file_image_view_raw.dds_controls),
+            mipmap_combo_box: atomic_from_mut_ptr(packed_file_image_view_raw.mipmap_combo_box),
+            red_check_box: atomic_from_mut_ptr(packed_file_image_view_raw.red_check_box),
+            green_check_box: atomic_from_mut_ptr(packed_file_image_view_raw.green_check_box),
+            blue_check_box: atomic_from_mut_ptr(packed_file_image_view_raw.blue_check_box),
+            alpha_check_box: atomic_from_mut_ptr(packed_file_image_view_raw.alpha_check_box),
+            dds: packed_file_image_view_raw.dds,
+        };
+
+        connections::set_connections(&packed_file_image_view, &packed_file_image_view_slots);
         packed_file_view.packed_file_type = PackedFileType::Image;
-        packed_file_view.view = ViewType::Internal(View::Image(Self {
-            image: atomic_from_mut_ptr(image),
-            label: atomic_from_mut_ptr(label)
-        }));
+        packed_file_view.view = ViewType::Internal(View::Image(packed_file_image_view));
 
         // Return success.
-        Ok((TheOneSlot::Image(PackedFileImageViewSlots {}), packed_file_info))
+        Ok((TheOneSlot::Image(packed_file_image_view_slots), packed_file_info))
     }
 
     /// Function to reload the data of the view without having to delete the view itself.
     pub unsafe fn reload_view(&self, data: &Image) {
-        let mut image = mut_ptr_from_atomic(&self.image);
-        let mut label = mut_ptr_from_atomic(&self.label);
+        let mut view = PackedFileImageViewRaw {
+            label: self.get_mut_ptr_label(),
+            image: mut_ptr_from_atomic(&self.image),
+            dds_controls: mut_ptr_from_atomic(&self.dds_controls),
+            mipmap_combo_box: self.get_mut_ptr_mipmap_combo_box(),
+            red_check_box: self.get_mut_ptr_red_check_box(),
+            green_check_box: self.get_mut_ptr_green_check_box(),
+            blue_check_box: self.get_mut_ptr_blue_check_box(),
+            alpha_check_box: self.get_mut_ptr_alpha_check_box(),
+            dds: self.dds.clone(),
+        };
+
+        // If the new data cannot be loaded, keep the old image.
+        let _ = view.load_image(data);
+    }
+
+    /// This function returns a pointer to the label holding the image.
+    pub fn get_mut_ptr_label(&self) -> MutPtr<QLabel> {
+        mut_ptr_from_atomic(&self.label)
+    }
+
+    /// This function returns a pointer to the mipmap ComboBox.
+    pub fn get_mut_ptr_mipmap_combo_box(&self) -> MutPtr<QComboBox> {
+        mut_ptr_from_atomic(&self.mipmap_combo_box)
+    }
 
-        let byte_array = QByteArray::from_slice(data.get_data());
-        image.load_from_data_q_byte_array(byte_array.into_ptr().as_ref().unwrap());
-        set_pixmap_on_resizable_label_safe(&mut label, &mut image);
+    /// This function returns a pointer to the red channel CheckBox.
+    pub fn get_mut_ptr_red_check_box(&self) -> MutPtr<QCheckBox> {
+        mut_ptr_from_atomic(&self.red_check_box)
+    }
+
+    /// This function returns a pointer to the green channel CheckBox.
+    pub fn get_mut_ptr_green_check_box(&self) -> MutPtr<QCheckBox> {
+        mut_ptr_from_atomic(&self.green_check_box)
+    }
+
+    /// This function returns a pointer to the blue channel CheckBox.
+    pub fn get_mut_ptr_blue_check_box(&self) -> MutPtr<QCheckBox> {
+        mut_ptr_from_atomic(&self.blue_check_box)
+    }
+
+    /// This function returns a pointer to the alpha channel CheckBox.
+    pub fn get_mut_ptr_alpha_check_box(&self) -> MutPtr<QCheckBox> {
+        mut_ptr_from_atomic(&self.alpha_check_box)
+    }
+}
+
+/// Implementation for `PackedFileImageViewRaw`.
+impl PackedFileImageViewRaw {
+
+    /// This function loads the provided image into the view.
+    ///
+    /// DDS images are decoded by us, so we can show their mipmaps and channels. If we cannot decode them, or if the image
+    /// is not a DDS, we fallback to whatever Qt supports.
+    pub unsafe fn load_image(&mut self, data: &Image) -> Result<()> {
+        let dds = if DDS::is_dds(data.get_data()) { DDS::read(data.get_data()).ok() } else { None };
+        match dds {
+            Some(dds) => {
+                self.mipmap_combo_box.block_signals(true);
+                self.mipmap_combo_box.clear();
+                for (index, mipmap) in dds.get_ref_mipmaps().iter().enumerate() {
+                    self.mipmap_combo_box.add_item_q_string(&QString::from_std_str(format!("{}: {}x{}", index, mipmap.get_width(), mipmap.get_height())));
+                }
+                self.mipmap_combo_box.block_signals(false);
+
+                *self.dds.write().unwrap() = Some(dds);
+                self.dds_controls.set_visible(true);
+                self.update_image();
+            }
+            None => {
+                let byte_array = QByteArray::from_slice(data.get_data());
+                if !self.image.load_from_data_q_byte_array(&byte_array) {
+                    return Err(ErrorKind::ImageDecode("The image is not supported by the previsualizer.".to_owned()).into());
+                }
+
+                *self.dds.write().unwrap() = None;
+                self.dds_controls.set_visible(false);
+                set_pixmap_on_resizable_label_safe(&mut self.label, &mut self.image);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function updates the image shown with the mipmap and channels currently selected. Only used for DDS images.
+    pub unsafe fn update_image(&mut self) {
+        if let Some(ref dds) = *self.dds.read().unwrap() {
+            let index = self.mipmap_combo_box.current_index().max(0) as usize;
+            if let Some(mipmap) = dds.get_ref_mipmaps().get(index) {
+                let channels = [
+                    self.red_check_box.is_checked(),
+                    self.green_check_box.is_checked(),
+                    self.blue_check_box.is_checked(),
+                    self.alpha_check_box.is_checked(),
+                ];
+
+                // QImage doesn't own the data, so it needs to stay alive until the pixmap copies it.
+                let data = mipmap.get_data_by_channels(channels);
+                let image = QImage::from_uchar2_int_format(data.as_ptr(), mipmap.get_width() as i32, mipmap.get_height() as i32, Format::FormatRGBA8888);
+                self.image.convert_from_image_1a(&image);
+                set_pixmap_on_resizable_label_safe(&mut self.label, &mut self.image);
+            }
+        }
     }
 }
@@ -12,9 +12,48 @@
 Module with the slots for Image Views.
 !*/
 
+use qt_core::{SlotOfBool, SlotOfInt};
+
+use crate::packedfile_views::image::PackedFileImageViewRaw;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
 
 /// This struct contains the slots of the view of an Image PackedFile.
-pub struct PackedFileImageViewSlots {}
+pub struct PackedFileImageViewSlots {
+    pub change_mipmap: SlotOfInt<'static>,
+    pub toggle_channel: SlotOfBool<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileImageViewSlots`.
+impl PackedFileImageViewSlots {
+
+    /// This function creates the entire slot pack for Image PackedFile Views.
+    pub unsafe fn new(view: PackedFileImageViewRaw) -> Self {
+
+        // Slot to show another mipmap of a DDS image.
+        let change_mipmap = SlotOfInt::new(clone!(
+            mut view => move |_| {
+                view.update_image();
+            }
+        ));
+
+        // Slot to show/hide a channel of a DDS image.
+        let toggle_channel = SlotOfBool::new(clone!(
+            mut view => move |_| {
+                view.update_image();
+            }
+        ));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            change_mipmap,
+            toggle_channel,
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the view for key-value DB Tables.
+
+Key-value tables (the `_kv_` ones) are config tables, with a row for each setting. Here they're shown as a list of keys
+and values, next to the value each key has in vanilla, so it's easy to see what the mod changes. Values are checked
+against the type of the value column as they're edited. The rest of the columns of the table are kept as they are.
+!*/
+
+use qt_widgets::q_abstract_item_view::SelectionBehavior;
+use qt_widgets::QCheckBox;
+use qt_widgets::QGridLayout;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+
+use qt_gui::QBrush;
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::GlobalColor;
+use qt_core::ItemDataRole;
+use qt_core::QSignalBlocker;
+use qt_core::QString;
+use qt_core::QVariant;
+use qt_core::SortOrder;
+
+use cpp_core::MutPtr;
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
+
+use rpfm_error::{Error, ErrorKind, Result};
+use rpfm_lib::packedfile::{DecodedPackedFile, PackedFileType};
+use rpfm_lib::packedfile::table::{DecodedData, Table};
+use rpfm_lib::packedfile::table::db::DB;
+use rpfm_lib::packfile::packedfile::PackedFileInfo;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+use crate::ffi::add_to_q_list_safe;
+use crate::locale::{qtr, tr, tre};
+use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic};
+
+use self::slots::PackedFileKeyValueViewSlots;
+
+mod connections;
+pub mod slots;
+
+/// Role of the key items where we store the state of their row.
+const ROW_STATE: i32 = 40;
+
+/// Columns of the tree.
+const COLUMN_KEY: i32 = 0;
+const COLUMN_VALUE: i32 = 1;
+const COLUMN_VANILLA_VALUE: i32 = 2;
+
+/// States of a row, compared with vanilla.
+const STATE_UNCHANGED: i32 = 0;
+const STATE_MODIFIED: i32 = 1;
+const STATE_NOT_IN_VANILLA: i32 = 2;
+const STATE_INVALID: i32 = 3;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of a key-value DB Table.
+pub struct PackedFileKeyValueView {
+    tree_view: AtomicPtr<QTreeView>,
+    tree_model: AtomicPtr<QStandardItemModel>,
+    add_button: AtomicPtr<QPushButton>,
+    remove_button: AtomicPtr<QPushButton>,
+    revert_button: AtomicPtr<QPushButton>,
+    only_modified_checkbox: AtomicPtr<QCheckBox>,
+
+    table: Arc<RwLock<DB>>,
+    vanilla_values: Arc<BTreeMap<String, String>>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileKeyValueView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileKeyValueView`.
+#[derive(Clone)]
+pub struct PackedFileKeyValueViewRaw {
+    pub path: Arc<RwLock<Vec<String>>>,
+    pub tree_view: MutPtr<QTreeView>,
+    pub tree_model: MutPtr<QStandardItemModel>,
+    pub add_button: MutPtr<QPushButton>,
+    pub remove_button: MutPtr<QPushButton>,
+    pub revert_button: MutPtr<QPushButton>,
+    pub only_modified_checkbox: MutPtr<QCheckBox>,
+
+    pub table: Arc<RwLock<DB>>,
+    pub vanilla_values: Arc<BTreeMap<String, String>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileKeyValueView`.
+impl PackedFileKeyValueView {
+
+    /// This function creates a new key-value View, and sets up his slots and connections.
+    ///
+    /// If the table is not a key-value table, it returns an error without touching the `PackedFileView`.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        app_ui: &AppUI,
+        pack_file_contents_ui: &PackFileContentsUI,
+    ) -> Result<(TheOneSlot, PackedFileInfo)> {
+
+        CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let (table, packed_file_info) = match response {
+            Response::DBPackedFileInfo((table, packed_file_info)) => (table, packed_file_info),
+            Response::Error(error) => return Err(error),
+            Response::Unknown => return Err(ErrorKind::PackedFileTypeUnknown.into()),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        if !table.is_key_value() {
+            return Err(ErrorKind::DBTableIsNotADBTable.into());
+        }
+
+        CENTRAL_COMMAND.send_message_qt(Command::GetVanillaKeyValues(table.get_table_name()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let vanilla_values = match response {
+            Response::BTreeMapStringString(vanilla_values) => vanilla_values,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        let mut tree_view = QTreeView::new_0a();
+        let mut tree_model = QStandardItemModel::new_0a();
+        tree_view.set_model(&mut tree_model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_sorting_enabled(true);
+        tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        tree_view.header().set_stretch_last_section(true);
+
+        let mut add_button = QPushButton::from_q_string(&qtr("key_value_add"));
+        let mut remove_button = QPushButton::from_q_string(&qtr("key_value_remove"));
+        let mut revert_button = QPushButton::from_q_string(&qtr("key_value_revert"));
+        let mut only_modified_checkbox = QCheckBox::from_q_string(&qtr("key_value_only_modified"));
+        revert_button.set_tool_tip(&qtr("tt_key_value_revert"));
+
+        layout.add_widget_5a(&mut tree_view, 0, 0, 1, 4);
+        layout.add_widget_5a(&mut add_button, 1, 0, 1, 1);
+        layout.add_widget_5a(&mut remove_button, 1, 1, 1, 1);
+        layout.add_widget_5a(&mut revert_button, 1, 2, 1, 1);
+        layout.add_widget_5a(&mut only_modified_checkbox, 1, 3, 1, 1);
+
+        let mut packed_file_key_value_view_raw = PackedFileKeyValueViewRaw {
+            path: packed_file_view.get_path_raw(),
+            tree_view: tree_view.into_ptr(),
+            tree_model: tree_model.into_ptr(),
+            add_button: add_button.into_ptr(),
+            remove_button: remove_button.into_ptr(),
+            revert_button: revert_button.into_ptr(),
+            only_modified_checkbox: only_modified_checkbox.into_ptr(),
+            table: Arc::new(RwLock::new(table)),
+            vanilla_values: Arc::new(vanilla_values),
+        };
+
+        packed_file_key_value_view_raw.load_data();
+
+        let packed_file_key_value_view_slots = PackedFileKeyValueViewSlots::new(
+            &packed_file_key_value_view_raw,
+            *app_ui,
+            *pack_file_contents_ui,
+        );
+
+        let packed_file_key_value_view = Self {
+            tree_view: atomic_from_mut_ptr(packed_file_key_value_view_raw.tree_view),
+            tree_model: atomic_from_mut_ptr(packed_file_key_value_view_raw.tree_model),
+            add_button: atomic_from_mut_ptr(packed_file_key_value_view_raw.add_button),
+            remove_button: atomic_from_mut_ptr(packed_file_key_value_view_raw.remove_button),
+            revert_button: atomic_from_mut_ptr(packed_file_key_value_view_raw.revert_button),
+            only_modified_checkbox: atomic_from_mut_ptr(packed_file_key_value_view_raw.only_modified_checkbox),
+            table: packed_file_key_value_view_raw.table,
+            vanilla_values: packed_file_key_value_view_raw.vanilla_values,
+        };
+
+        connections::set_connections(&packed_file_key_value_view, &packed_file_key_value_view_slots);
+        packed_file_view.view = ViewType::Internal(View::KeyValue(packed_file_key_value_view));
+        packed_file_view.packed_file_type = PackedFileType::DB;
+
+        Ok((TheOneSlot::KeyValue(packed_file_key_value_view_slots), packed_file_info))
+    }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    pub unsafe fn reload_view(&mut self, data: &DB) -> Result<()> {
+        if !data.is_key_value() {
+            return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
+        }
+
+        *self.table.write().unwrap() = data.clone();
+        self.get_raw().load_data();
+        Ok(())
+    }
+
+    /// This function saves the data of the view to a `DecodedPackedFile`.
+    ///
+    /// Rows whose key was already in the table keep the rest of their columns. New rows get the default values of the other columns.
+    pub unsafe fn save_data(&self) -> Result<DecodedPackedFile> {
+        let mut table = self.table.read().unwrap().clone();
+        let (key_column, value_column) = table.get_key_value_columns().ok_or_else(|| Error::from(ErrorKind::DBTableIsNotADBTable))?;
+        let definition = table.get_definition();
+        let fields = definition.get_ref_fields();
+        let old_rows = table.get_table_data();
+
+        let tree_model = self.get_mut_ptr_tree_model();
+        let mut rows = vec![];
+        for row in 0..tree_model.row_count_0a() {
+            let key = tree_model.item_2a(row, COLUMN_KEY).text().to_std_string();
+            let value = tree_model.item_2a(row, COLUMN_VALUE).text().to_std_string();
+
+            let mut new_row = match old_rows.iter().find(|old_row| old_row[key_column].data_to_string() == key) {
+                Some(old_row) => old_row.to_vec(),
+                None => Table::get_new_row(&definition),
+            };
+
+            new_row[key_column] = DecodedData::StringU8(key.to_owned()).convert_between_types(fields[key_column].get_ref_field_type())
+                .map_err(|_| Error::from(ErrorKind::KeyValueTableInvalidValue(key.to_owned(), key.to_owned())))?;
+            new_row[value_column] = DecodedData::StringU8(value.to_owned()).convert_between_types(fields[value_column].get_ref_field_type())
+                .map_err(|_| Error::from(ErrorKind::KeyValueTableInvalidValue(value.to_owned(), key.to_owned())))?;
+            rows.push(new_row);
+        }
+
+        table.set_table_data(&rows)?;
+        Ok(DecodedPackedFile::DB(table))
+    }
+
+    /// This function returns a copy of the raw pointers of the view.
+    fn get_raw(&self) -> PackedFileKeyValueViewRaw {
+        PackedFileKeyValueViewRaw {
+            path: Arc::new(RwLock::new(vec![])),
+            tree_view: self.get_mut_ptr_tree_view(),
+            tree_model: self.get_mut_ptr_tree_model(),
+            add_button: self.get_mut_ptr_add_button(),
+            remove_button: self.get_mut_ptr_remove_button(),
+            revert_button: self.get_mut_ptr_revert_button(),
+            only_modified_checkbox: self.get_mut_ptr_only_modified_checkbox(),
+            table: self.table.clone(),
+            vanilla_values: self.vanilla_values.clone(),
+        }
+    }
+
+    /// This function returns a pointer to the TreeView.
+    pub fn get_mut_ptr_tree_view(&self) -> MutPtr<QTreeView> {
+        mut_ptr_from_atomic(&self.tree_view)
+    }
+
+    /// This function returns a pointer to the model of the TreeView.
+    pub fn get_mut_ptr_tree_model(&self) -> MutPtr<QStandardItemModel> {
+        mut_ptr_from_atomic(&self.tree_model)
+    }
+
+    /// This function returns a pointer to the "Add" button.
+    pub fn get_mut_ptr_add_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_button)
+    }
+
+    /// This function returns a pointer to the "Remove" button.
+    pub fn get_mut_ptr_remove_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.remove_button)
+    }
+
+    /// This function returns a pointer to the "Revert to Vanilla" button.
+    pub fn get_mut_ptr_revert_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.revert_button)
+    }
+
+    /// This function returns a pointer to the "Only Modified" checkbox.
+    pub fn get_mut_ptr_only_modified_checkbox(&self) -> MutPtr<QCheckBox> {
+        mut_ptr_from_atomic(&self.only_modified_checkbox)
+    }
+}
+
+/// Implementation for `PackedFileKeyValueViewRaw`.
+impl PackedFileKeyValueViewRaw {
+
+    /// This function loads the keys and values of the table into the tree, next to their vanilla values.
+    pub unsafe fn load_data(&mut self) {
+        let mut blocker = QSignalBlocker::from_q_object(self.tree_model);
+        self.tree_model.clear();
+        self.tree_model.set_horizontal_header_item(COLUMN_KEY, QStandardItem::from_q_string(&qtr("key_value_key")).into_ptr());
+        self.tree_model.set_horizontal_header_item(COLUMN_VALUE, QStandardItem::from_q_string(&qtr("key_value_value")).into_ptr());
+        self.tree_model.set_horizontal_header_item(COLUMN_VANILLA_VALUE, QStandardItem::from_q_string(&qtr("key_value_vanilla_value")).into_ptr());
+
+        {
+            let table = self.table.read().unwrap();
+            if let Some((key_column, value_column)) = table.get_key_value_columns() {
+                for row in table.get_ref_table_data() {
+                    self.append_row(&row[key_column].data_to_string(), &row[value_column].data_to_string());
+                }
+            }
+        }
+
+        blocker.unblock();
+        for row in 0..self.tree_model.row_count_0a() {
+            self.update_row_state(row);
+        }
+
+        self.tree_view.sort_by_column_2a(COLUMN_KEY, SortOrder::AscendingOrder);
+        self.tree_view.resize_column_to_contents(COLUMN_KEY);
+        self.tree_view.resize_column_to_contents(COLUMN_VALUE);
+        self.filter_rows();
+    }
+
+    /// This function adds a new empty row to the tree, and starts editing its key.
+    pub unsafe fn add_row(&mut self) {
+        self.append_row("", "");
+        let row = self.tree_model.row_count_0a() - 1;
+        self.update_row_state(row);
+
+        let index = self.tree_model.index_2a(row, COLUMN_KEY);
+        self.tree_view.set_current_index(&index);
+        self.tree_view.scroll_to_1a(&index);
+        self.tree_view.edit(&index);
+    }
+
+    /// This function removes the selected rows from the tree.
+    ///
+    /// It returns false if there was nothing selected.
+    pub unsafe fn remove_selected(&mut self) -> bool {
+        let mut rows = self.get_selected_rows();
+        if rows.is_empty() { return false; }
+
+        rows.reverse();
+        for row in rows {
+            self.tree_model.remove_row_1a(row);
+        }
+        true
+    }
+
+    /// This function sets the value of the selected keys back to their vanilla value. Keys not in vanilla are left as they are.
+    pub unsafe fn revert_selected(&mut self) {
+        for row in self.get_selected_rows() {
+            let key = self.tree_model.item_2a(row, COLUMN_KEY).text().to_std_string();
+            if let Some(vanilla_value) = self.vanilla_values.get(&key) {
+                let mut value_item = self.tree_model.item_2a(row, COLUMN_VALUE);
+                if value_item.text().to_std_string() != *vanilla_value {
+                    value_item.set_text(&QString::from_std_str(vanilla_value));
+                }
+            }
+        }
+    }
+
+    /// This function checks the value of the provided row against the type of the value column, and against its vanilla value, and paints the row accordingly.
+    pub unsafe fn update_row_state(&mut self, row: i32) {
+        let mut key_item = self.tree_model.item_2a(row, COLUMN_KEY);
+        let mut value_item = self.tree_model.item_2a(row, COLUMN_VALUE);
+        let mut vanilla_item = self.tree_model.item_2a(row, COLUMN_VANILLA_VALUE);
+        if key_item.is_null() || value_item.is_null() || vanilla_item.is_null() { return; }
+
+        let key = key_item.text().to_std_string();
+        let value = value_item.text().to_std_string();
+
+        let value_type = {
+            let table = self.table.read().unwrap();
+            let fields = table.get_ref_definition().get_ref_fields();
+            table.get_key_value_columns().map(|(_, value_column)| fields[value_column].get_ref_field_type().clone())
+        };
+
+        // Compare the values once converted to the type of the column, so things like "1.50" and "1.5" are the same.
+        let value = match value_type.map(|value_type| DecodedData::StringU8(value.to_owned()).convert_between_types(&value_type)) {
+            Some(Ok(value)) => Some(value.data_to_string()),
+            _ => None,
+        };
+
+        let vanilla_value = self.vanilla_values.get(&key);
+        let state = match (value, vanilla_value) {
+            (None, _) => STATE_INVALID,
+            (Some(_), None) => STATE_NOT_IN_VANILLA,
+            (Some(value), Some(vanilla_value)) => if value == *vanilla_value { STATE_UNCHANGED } else { STATE_MODIFIED },
+        };
+
+        // Painting the items is not an edit, so don't let the view think it is.
+        let mut blocker = QSignalBlocker::from_q_object(self.tree_model);
+        key_item.set_data_2a(&QVariant::from_int(state), ROW_STATE);
+        vanilla_item.set_text(&QString::from_std_str(vanilla_value.map(|x| &**x).unwrap_or("")));
+
+        let (color, tooltip) = match state {
+            STATE_MODIFIED => (Some(GlobalColor::DarkYellow), tr("key_value_state_modified")),
+            STATE_NOT_IN_VANILLA => (Some(GlobalColor::DarkGreen), tr("key_value_state_not_in_vanilla")),
+            STATE_INVALID => (Some(GlobalColor::Red), tre("key_value_state_invalid", &[&self.get_value_type_name()])),
+            _ => (None, String::new()),
+        };
+
+        match color {
+            Some(color) => value_item.set_foreground(&QBrush::from_global_color(color)),
+            None => value_item.set_data_2a(&QVariant::new(), ItemDataRole::ForegroundRole.to_int()),
+        }
+        value_item.set_tool_tip(&QString::from_std_str(&tooltip));
+        blocker.unblock();
+    }
+
+    /// This function hides the rows with the same value as in vanilla, if only the modified ones should be shown.
+    pub unsafe fn filter_rows(&mut self) {
+        let only_modified = self.only_modified_checkbox.is_checked();
+        let root = self.tree_view.root_index();
+        for row in 0..self.tree_model.row_count_0a() {
+            let state = self.tree_model.item_2a(row, COLUMN_KEY).data_1a(ROW_STATE).to_int_0a();
+            self.tree_view.set_row_hidden(row, &root, only_modified && state == STATE_UNCHANGED);
+        }
+    }
+
+    /// This function returns the name of the type of the value column.
+    fn get_value_type_name(&self) -> String {
+        let table = self.table.read().unwrap();
+        let fields = table.get_ref_definition().get_ref_fields();
+        table.get_key_value_columns().map(|(_, value_column)| fields[value_column].get_ref_field_type().to_string()).unwrap_or_default()
+    }
+
+    /// This function returns the selected rows, sorted.
+    unsafe fn get_selected_rows(&self) -> Vec<i32> {
+        let indexes = self.tree_view.selection_model().selected_rows_0a();
+        let mut rows = (0..indexes.count_0a()).map(|index| indexes.at(index).row()).collect::<Vec<i32>>();
+        rows.sort_unstable();
+        rows.dedup();
+        rows
+    }
+
+    /// This function adds a row with the provided key and value at the end of the tree.
+    unsafe fn append_row(&mut self, key: &str, value: &str) {
+        let mut row = QListOfQStandardItem::new();
+        let key = QStandardItem::from_q_string(&QString::from_std_str(key)).into_ptr();
+        let value = QStandardItem::from_q_string(&QString::from_std_str(value)).into_ptr();
+        let mut vanilla_value = QStandardItem::new().into_ptr();
+        vanilla_value.set_editable(false);
+
+        add_to_q_list_safe(row.as_mut_ptr(), key);
+        add_to_q_list_safe(row.as_mut_ptr(), value);
+        add_to_q_list_safe(row.as_mut_ptr(), vanilla_value);
+        self.tree_model.append_row_q_list_of_q_standard_item(&row);
+    }
+}
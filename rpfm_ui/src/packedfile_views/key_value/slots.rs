@@ -0,0 +1,96 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for key-value DB Table Views.
+!*/
+
+use qt_gui::SlotOfQStandardItem;
+
+use qt_core::{Slot, SlotOfBool};
+
+use crate::app_ui::AppUI;
+use crate::packedfile_views::key_value::PackedFileKeyValueViewRaw;
+use crate::packedfile_views::utils::set_modified;
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of a key-value DB Table.
+pub struct PackedFileKeyValueViewSlots {
+    pub modified: SlotOfQStandardItem<'static>,
+    pub add: Slot<'static>,
+    pub remove: Slot<'static>,
+    pub revert: Slot<'static>,
+    pub only_modified: SlotOfBool<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileKeyValueViewSlots`.
+impl PackedFileKeyValueViewSlots {
+
+    /// This function creates the entire slot pack for key-value DB Table Views.
+    pub unsafe fn new(packed_file_view: &PackedFileKeyValueViewRaw, app_ui: AppUI, pack_file_contents_ui: PackFileContentsUI) -> Self {
+
+        // When a key or a value gets edited, check it again and mark the PackedFile as modified.
+        let modified = SlotOfQStandardItem::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move |item| {
+            packed_file_view.update_row_state(item.row());
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to add a new key.
+        let add = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            packed_file_view.add_row();
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to remove the selected keys.
+        let remove = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            if packed_file_view.remove_selected() {
+                set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+            }
+        }));
+
+        // Slot to set the selected keys back to their vanilla values. The edits themselves mark the PackedFile as modified.
+        let revert = Slot::new(clone!(
+            mut packed_file_view => move || {
+            packed_file_view.revert_selected();
+        }));
+
+        // Slot to show only the keys with values different from vanilla.
+        let only_modified = SlotOfBool::new(clone!(
+            mut packed_file_view => move |_| {
+            packed_file_view.filter_rows();
+        }));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            modified,
+            add,
+            remove,
+            revert,
+            only_modified,
+        }
+    }
+}
@@ -26,7 +26,7 @@ use rpfm_error::{ErrorKind, Result};
 use rpfm_lib::packedfile::{DecodedPackedFile, PackedFileType};
 use rpfm_lib::packedfile::table::{animtable::AnimTable, db::DB, loc::Loc, matched_combat::MatchedCombat};
 use rpfm_lib::packedfile::text::Text;
-use rpfm_lib::packfile::PathType;
+use rpfm_lib::packfile::{PathType, RESERVED_NAME_EXTRA_PACKFILE};
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
@@ -47,23 +47,35 @@ use self::anim_fragment::{PackedFileAnimFragmentView, slots::PackedFileAnimFragm
 use self::animpack::{PackedFileAnimPackView, slots::PackedFileAnimPackViewSlots};
 use self::ca_vp8::{PackedFileCaVp8View, slots::PackedFileCaVp8ViewSlots};
 use self::decoder::{PackedFileDecoderView, slots::PackedFileDecoderViewSlots};
+use self::dependency_manager::{PackedFileDependencyManagerView, slots::PackedFileDependencyManagerViewSlots};
 use self::external::{PackedFileExternalView, slots::PackedFileExternalViewSlots};
+use self::hex::{PackedFileHexView, slots::PackedFileHexViewSlots};
 use self::image::{PackedFileImageView, slots::PackedFileImageViewSlots};
+use self::key_value::{PackedFileKeyValueView, slots::PackedFileKeyValueViewSlots};
 use self::table::{PackedFileTableView, slots::PackedFileTableViewSlots};
 use self::text::{PackedFileTextView, slots::PackedFileTextViewSlots};
 use self::packfile::{PackFileExtraView, slots::PackFileExtraViewSlots};
-//use self::rigidmodel::{PackedFileRigidModelView, slots::PackedFileRigidModelViewSlots};
+use self::rigidmodel::{PackedFileRigidModelView, slots::PackedFileRigidModelViewSlots};
+use self::sound_bank::{PackedFileSoundBankView, slots::PackedFileSoundBankViewSlots};
+use self::unit_variant::{PackedFileUnitVariantView, slots::PackedFileUnitVariantViewSlots};
+use self::variant_mesh::{PackedFileVariantMeshView, slots::PackedFileVariantMeshViewSlots};
 
 pub mod anim_fragment;
 pub mod animpack;
 pub mod ca_vp8;
 pub mod decoder;
+pub mod dependency_manager;
 pub mod external;
+pub mod hex;
 pub mod image;
+pub mod key_value;
 pub mod packfile;
-//pub mod rigidmodel;
+pub mod rigidmodel;
+pub mod sound_bank;
 pub mod table;
 pub mod text;
+pub mod unit_variant;
+pub mod variant_mesh;
 
 pub mod utils;
 
@@ -96,11 +108,17 @@ pub enum View {
     AnimPack(PackedFileAnimPackView),
     CaVp8(PackedFileCaVp8View),
     Decoder(PackedFileDecoderView),
+    DependencyManager(PackedFileDependencyManagerView),
+    Hex(PackedFileHexView),
     Image(PackedFileImageView),
+    KeyValue(PackedFileKeyValueView),
     PackFile(PackFileExtraView),
-    //RigidModel(PackedFileRigidModelView),
+    RigidModel(PackedFileRigidModelView),
+    SoundBank(PackedFileSoundBankView),
     Table(PackedFileTableView),
     Text(PackedFileTextView),
+    UnitVariant(PackedFileUnitVariantView),
+    VariantMesh(PackedFileVariantMeshView),
     None,
 }
 
@@ -113,12 +131,18 @@ pub enum TheOneSlot {
     AnimPack(PackedFileAnimPackViewSlots),
     CaVp8(PackedFileCaVp8ViewSlots),
     Decoder(PackedFileDecoderViewSlots),
+    DependencyManager(PackedFileDependencyManagerViewSlots),
     External(PackedFileExternalViewSlots),
+    Hex(PackedFileHexViewSlots),
     Image(PackedFileImageViewSlots),
+    KeyValue(PackedFileKeyValueViewSlots),
     PackFile(PackFileExtraViewSlots),
-    //RigidModel(PackedFileRigidModelViewSlots),
+    RigidModel(PackedFileRigidModelViewSlots),
+    SoundBank(PackedFileSoundBankViewSlots),
     Table(PackedFileTableViewSlots),
     Text(PackedFileTextViewSlots),
+    UnitVariant(PackedFileUnitVariantViewSlots),
+    VariantMesh(PackedFileVariantMeshViewSlots),
 }
 
 //-------------------------------------------------------------------------------//
@@ -196,9 +220,34 @@ impl PackedFileView {
     /// This function allows you to save a `PackedFileView` to his corresponding `PackedFile`.
     pub unsafe fn save(&self, app_ui: &mut AppUI, mut global_search_ui: GlobalSearchUI, mut pack_file_contents_ui: &mut PackFileContentsUI) -> Result<()> {
 
+        // Extra PackFiles and their read-only tables are not part of the open PackFile, so there is nothing to save.
+        if self.get_ref_path().starts_with(&[RESERVED_NAME_EXTRA_PACKFILE.to_owned()]) {
+            return Ok(());
+        }
+
         match self.get_view() {
             ViewType::Internal(view) => {
 
+                // The Hex Editor works with the raw data of any PackedFile, so we just send it back as it is.
+                if let View::Hex(view) = view {
+                    CENTRAL_COMMAND.send_message_qt(Command::SetPackedFileRawData((self.get_path(), view.get_data())));
+                    let response = CENTRAL_COMMAND.recv_message_qt_try();
+                    return match response {
+                        Response::Success => {
+                            let global_search = UI_STATE.get_global_search();
+                            if !global_search.pattern.is_empty() {
+                                let path_types = vec![PathType::File(self.get_path())];
+                                global_search_ui.search_on_path(&mut pack_file_contents_ui, path_types);
+                                UI_STATE.set_global_search(&global_search);
+                            }
+
+                            Ok(())
+                        }
+                        Response::Error(error) => Err(error),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+
                 // This is a two-step process. First, we take the data from the view into a `DecodedPackedFile` format.
                 // Then, we send that `DecodedPackedFile` to the backend to replace the older one. We need no response.
                 let data = match self.packed_file_type {
@@ -207,7 +256,7 @@ impl PackedFileView {
                     PackedFileType::Loc |
                     PackedFileType::MatchedCombat => if let View::Table(view) = view {
 
-                        let new_table = get_table_from_view(view.get_ref_table().get_mut_ptr_table_model(), &view.get_ref_table().get_ref_table_definition())?;
+                        let new_table = get_table_from_view(view.get_ref_table().get_mut_ptr_table_model().ok_or(ErrorKind::PackedFileViewClosed)?, &view.get_ref_table().get_ref_table_definition())?;
                         match self.packed_file_type {
                             PackedFileType::AnimTable => {
                                 let table = AnimTable::from(new_table);
@@ -233,10 +282,16 @@ impl PackedFileView {
                             }
                             _ => return Err(ErrorKind::PackedFileSaveError(self.get_path()).into())
                         }
+                    }
+
+                    // Key-value tables are DB Tables, but they may be open in their own view.
+                    else if let View::KeyValue(view) = view {
+                        view.save_data()?
                     } else { return Err(ErrorKind::PackedFileSaveError(self.get_path()).into()) },
 
-                    // Images are read-only.
+                    // Images and SoundBanks are read-only.
                     PackedFileType::Image => return Ok(()),
+                    PackedFileType::SoundBank => return Ok(()),
                     PackedFileType::AnimPack => return Ok(()),
 
                     PackedFileType::AnimFragment => {
@@ -252,7 +307,11 @@ impl PackedFileView {
                             return Ok(())
                         } else { return Err(ErrorKind::PackedFileSaveError(self.get_path()).into()) }
                     },
-                    PackedFileType::RigidModel => return Err(ErrorKind::PackedFileSaveError(self.get_path()).into()),
+                    PackedFileType::RigidModel => {
+                        if let View::RigidModel(view) = view {
+                            view.save_data()?
+                        } else { return Err(ErrorKind::PackedFileSaveError(self.get_path()).into()) }
+                    },
 
                     PackedFileType::Text(_) => {
                         if let View::Text(view) = view {
@@ -261,20 +320,24 @@ impl PackedFileView {
                             let string = get_text_safe(&mut widget).to_std_string();
                             text.set_contents(&string);
                             DecodedPackedFile::Text(text)
+                        }
+
+                        // VariantMeshDefinitions are Text PackedFiles, but they may be open in their own view.
+                        else if let View::VariantMesh(view) = view {
+                            view.save_data()?
                         } else { return Err(ErrorKind::PackedFileSaveError(self.get_path()).into()) }
                     },
 
-                    // These ones are like very reduced tables.
-                    PackedFileType::DependencyPackFilesList => if let View::Table(view) = view {
-                        let mut entries = vec![];
-                        let model = view.get_ref_table().get_mut_ptr_table_model();
-                        for row in 0..model.row_count_0a() {
-                            let item = model.item_1a(row as i32).text().to_std_string();
-                            entries.push(item);
-                        }
+                    PackedFileType::UnitVariant => {
+                        if let View::UnitVariant(view) = view {
+                            view.save_data()?
+                        } else { return Err(ErrorKind::PackedFileSaveError(self.get_path()).into()) }
+                    },
+
+                    PackedFileType::DependencyPackFilesList => if let View::DependencyManager(view) = view {
 
                         // Save the new list and return Ok.
-                        CENTRAL_COMMAND.send_message_qt(Command::SetDependencyPackFilesList(entries));
+                        CENTRAL_COMMAND.send_message_qt(Command::SetDependencyManagerEntries(view.save_data()));
 
                         // Set the packfile as modified. This one is special, as this is a "simulated PackedFile", so we have to mark the PackFile manually.
                         pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(vec![TreePathType::PackFile]));
@@ -328,6 +391,22 @@ impl PackedFileView {
         pack_file_contents_ui: &mut PackFileContentsUI
     ) -> Result<()> {
          match self.get_ref_mut_view() {
+
+            // The Hex Editor can show any PackedFile, so we just reload its raw data.
+            ViewType::Internal(View::Hex(view)) => {
+                CENTRAL_COMMAND.send_message_qt(Command::GetPackedFile(path.to_vec()));
+                let response = CENTRAL_COMMAND.recv_message_qt();
+                match response {
+                    Response::OptionPackedFile(packed_file) => match packed_file {
+                        Some(packed_file) => view.reload_view(&packed_file.get_raw_data()?),
+                        None => return Err(ErrorKind::PackedFileNotFound.into()),
+                    }
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                Ok(())
+            },
+
             ViewType::Internal(view) => {
 
                 CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(path.to_vec()));
@@ -389,6 +468,10 @@ impl PackedFileView {
                             pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
 
                         }
+                        else if let View::KeyValue(old_key_value) = view {
+                            old_key_value.reload_view(&table)?;
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                        }
                         else {
                             return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
                         }
@@ -428,12 +511,46 @@ impl PackedFileView {
                         }
                     },
 
+                    Response::RigidModelPackedFileInfo((rigid_model, packed_file_info)) => {
+                        if let View::RigidModel(old_rigid_model) = view {
+                            old_rigid_model.reload_view(&rigid_model)?;
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                        }
+                        else {
+                            return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
+                        }
+                    },
+
+                    Response::SoundBankPackedFileInfo((sound_bank, packed_file_info)) => {
+                        if let View::SoundBank(old_sound_bank) = view {
+                            old_sound_bank.reload_view(&sound_bank);
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                        }
+                        else {
+                            return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
+                        }
+                    },
+
                     Response::TextPackedFileInfo((text, packed_file_info)) => {
                         if let View::Text(old_text) = view {
                             old_text.reload_view(&text);
                             pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
 
                         }
+                        else if let View::VariantMesh(old_variant_mesh) = view {
+                            old_variant_mesh.reload_view(&text)?;
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                        }
+                        else {
+                            return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
+                        }
+                    },
+
+                    Response::UnitVariantPackedFileInfo((unit_variant, packed_file_info)) => {
+                        if let View::UnitVariant(old_unit_variant) = view {
+                            old_unit_variant.reload_view(&unit_variant);
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]));
+                        }
                         else {
                             return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
                         }
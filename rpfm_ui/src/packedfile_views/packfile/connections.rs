@@ -22,6 +22,8 @@ use super::{PackFileExtraView, slots::PackFileExtraViewSlots};
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &PackFileExtraView, slots: &PackFileExtraViewSlots) {
     ui.get_mut_ptr_tree_view().double_clicked().connect(&slots.import);
+    ui.get_mut_ptr_open().triggered().connect(&slots.open);
+    ui.get_mut_ptr_extract().triggered().connect(&slots.extract);
     ui.get_mut_ptr_filter_line_edit().text_changed().connect(&slots.filter_change_text);
 
     ui.get_mut_ptr_autoexpand_matches_button().toggled().connect(&slots.filter_change_autoexpand_matches);
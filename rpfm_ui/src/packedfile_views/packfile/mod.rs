@@ -11,6 +11,8 @@
 /*!
 Module with all the code for managing the temporal PackFile TreeView used when adding PackedFiles from another PackFile.
 
+It's also used to browse the game's PackFiles read-only: their contents can be filtered, extracted and their tables opened, but never edited.
+
 This is here because we're going to treat it as another PackedFileView, though it isn't.
 But this allow us to integrate it into the main PackedFileView system, so it's ok.
 !*/
@@ -25,12 +27,15 @@ use qt_widgets::QTreeView;
 use qt_gui::QStandardItemModel;
 
 use qt_core::CaseSensitivity;
+use qt_core::ContextMenuPolicy;
 use qt_core::QRegExp;
 use qt_core::QSortFilterProxyModel;
 
 use cpp_core::MutPtr;
 
+use std::cell::RefCell;
 use std::path::PathBuf;
+use std::rc::Rc;
 use std::sync::{Arc, RwLock, atomic::AtomicPtr};
 
 use rpfm_error::Result;
@@ -65,6 +70,8 @@ pub struct PackFileExtraView {
     filter_autoexpand_matches_button: AtomicPtr<QPushButton>,
     filter_case_sensitive_button: AtomicPtr<QPushButton>,
 
+    open: AtomicPtr<QAction>,
+    extract: AtomicPtr<QAction>,
     expand_all: AtomicPtr<QAction>,
     collapse_all: AtomicPtr<QAction>,
 }
@@ -83,6 +90,8 @@ pub struct PackFileExtraViewRaw {
     filter_autoexpand_matches_button: MutPtr<QPushButton>,
     filter_case_sensitive_button: MutPtr<QPushButton>,
 
+    open: MutPtr<QAction>,
+    extract: MutPtr<QAction>,
     expand_all: MutPtr<QAction>,
     collapse_all: MutPtr<QAction>,
 }
@@ -100,6 +109,7 @@ impl PackFileExtraView {
         app_ui: &AppUI,
         pack_file_contents_ui: &PackFileContentsUI,
         global_search_ui: &GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
         pack_file_path: PathBuf,
     ) -> Result<TheOneSlot> {
 
@@ -125,7 +135,7 @@ impl PackFileExtraView {
         tree_view.set_uniform_row_heights(true);
         tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
         tree_view.set_expands_on_double_click(false);
-        //tree_view.set_context_menu_policy(ContextMenuPolicy::Custom);
+        tree_view.set_context_menu_policy(ContextMenuPolicy::ActionsContextMenu);
         tree_view.update_treeview(true, TreeViewOperation::Build(Some(pack_file_path.to_path_buf())));
 
         // Create and configure the widgets to control the `TreeView`s filter.
@@ -137,8 +147,12 @@ impl PackFileExtraView {
         filter_case_sensitive_button.set_checkable(true);
 
         // Create the extra actions for the TreeView.
+        let open = QAction::from_q_string(&qtr("packfile_extra_open_read_only")).into_ptr();
+        let extract = QAction::from_q_string(&qtr("context_menu_extract")).into_ptr();
         let expand_all = QAction::from_q_string(&qtr("treeview_expand_all")).into_ptr();
         let collapse_all = QAction::from_q_string(&qtr("treeview_collapse_all")).into_ptr();
+        tree_view.add_action(open);
+        tree_view.add_action(extract);
         tree_view.add_action(expand_all);
         tree_view.add_action(collapse_all);
 
@@ -159,11 +173,13 @@ impl PackFileExtraView {
             filter_autoexpand_matches_button: filter_autoexpand_matches_button.into_ptr(),
             filter_case_sensitive_button: filter_case_sensitive_button.into_ptr(),
 
+            open,
+            extract,
             expand_all,
             collapse_all,
         };
 
-        let slots = PackFileExtraViewSlots::new(*app_ui, *pack_file_contents_ui, *global_search_ui, raw.clone());
+        let slots = PackFileExtraViewSlots::new(*app_ui, *pack_file_contents_ui, *global_search_ui, slot_holder, raw.clone());
         let mut view = Self {
             tree_view: atomic_from_mut_ptr(raw.tree_view),
 
@@ -171,6 +187,8 @@ impl PackFileExtraView {
             filter_autoexpand_matches_button: atomic_from_mut_ptr(raw.filter_autoexpand_matches_button),
             filter_case_sensitive_button: atomic_from_mut_ptr(raw.filter_case_sensitive_button),
 
+            open: atomic_from_mut_ptr(raw.open),
+            extract: atomic_from_mut_ptr(raw.extract),
             expand_all: atomic_from_mut_ptr(raw.expand_all),
             collapse_all: atomic_from_mut_ptr(raw.collapse_all),
         };
@@ -188,6 +206,16 @@ impl PackFileExtraView {
         mut_ptr_from_atomic(&self.tree_view)
     }
 
+    /// This function returns a mutable reference to the `Open Read-Only` Action.
+    pub fn get_mut_ptr_open(&self) -> MutPtr<QAction> {
+        mut_ptr_from_atomic(&self.open)
+    }
+
+    /// This function returns a mutable reference to the `Extract` Action.
+    pub fn get_mut_ptr_extract(&self) -> MutPtr<QAction> {
+        mut_ptr_from_atomic(&self.extract)
+    }
+
     /// This function returns a mutable reference to the `Expand All` Action.
     pub fn get_mut_ptr_expand_all(&self) -> MutPtr<QAction> {
         mut_ptr_from_atomic(&self.expand_all)
@@ -26,9 +26,11 @@ use crate::UI_STATE;
 pub unsafe fn set_shortcuts(ui: &mut PackFileExtraView) {
     let shortcuts = UI_STATE.get_shortcuts_no_lock();
 
+    ui.get_mut_ptr_extract().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["extract"])));
     ui.get_mut_ptr_expand_all().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["expand_all"])));
     ui.get_mut_ptr_collapse_all().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["collapse_all"])));
 
+    ui.get_mut_ptr_extract().set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.get_mut_ptr_expand_all().set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.get_mut_ptr_collapse_all().set_shortcut_context(ShortcutContext::WidgetShortcut);
 }
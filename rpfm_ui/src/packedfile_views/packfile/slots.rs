@@ -12,17 +12,26 @@
 Module with the slots for PackFile Views.
 !*/
 
+use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
+
 use qt_core::{SlotOfBool, SlotOfQModelIndex, Slot, SlotOfQString};
 
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
 use rpfm_lib::packfile::PathType;
+use rpfm_lib::settings::LAST_PATH_EXTRACT;
 
 use crate::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::qtr;
+use crate::packedfile_views::TheOneSlot;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::pack_tree::{PackTree, TreePathType, TreeViewOperation};
-use crate::utils::show_dialog;
+use crate::utils::{catch_panic_in_slot, save_last_path, setup_file_dialog, show_dialog};
 use super::{PackFileExtraView, PackFileExtraViewRaw};
 use crate::UI_STATE;
 
@@ -33,6 +42,8 @@ use crate::UI_STATE;
 /// This struct contains the slots of the view of the extra PackFile.
 pub struct PackFileExtraViewSlots {
     pub import: SlotOfQModelIndex<'static>,
+    pub open: SlotOfBool<'static>,
+    pub extract: SlotOfBool<'static>,
 
     pub filter_change_text: SlotOfQString<'static>,
     pub filter_change_autoexpand_matches: SlotOfBool<'static>,
@@ -50,7 +61,13 @@ pub struct PackFileExtraViewSlots {
 impl PackFileExtraViewSlots {
 
     /// This function builds the entire slot set for the provided PackFileExtraView.
-    pub unsafe fn new(mut app_ui: AppUI, mut pack_file_contents_ui: PackFileContentsUI, global_search_ui: GlobalSearchUI, mut pack_file_view: PackFileExtraViewRaw) -> Self {
+    pub unsafe fn new(
+        mut app_ui: AppUI,
+        mut pack_file_contents_ui: PackFileContentsUI,
+        global_search_ui: GlobalSearchUI,
+        slot_holder: &Rc<RefCell<Vec<TheOneSlot>>>,
+        mut pack_file_view: PackFileExtraViewRaw
+    ) -> Self {
 
         // When we want to import the selected PackedFile...
         let import = SlotOfQModelIndex::new(clone!(
@@ -121,6 +138,51 @@ impl PackFileExtraViewSlots {
             }
         ));
 
+        // When we want to open the selected table read-only, to compare it with the ones of the open PackFile or copy from it...
+        // We keep a weak reference to the slot holder, as these slots are stored in it.
+        let slot_holder = Rc::downgrade(slot_holder);
+        let open = SlotOfBool::new(clone!(
+            pack_file_view => move |_| {
+                let selected_items = pack_file_view.tree_view.get_item_types_from_selection_filtered();
+                if let [TreePathType::File(path)] = &*selected_items {
+                    if let Some(slot_holder) = slot_holder.upgrade() {
+                        app_ui.open_packedfile_extra(&mut pack_file_contents_ui, &global_search_ui, &slot_holder, &pack_file_view.get_pack_file_path(), path);
+                    }
+                }
+            }
+        ));
+
+        // When we want to extract the selected PackedFiles to disk. The extra PackFile is not touched, so this works with the game's PackFiles too.
+        let extract = SlotOfBool::new(clone!(
+            pack_file_view => move |_| {
+                let item_types = pack_file_view.tree_view.get_item_types_from_selection_filtered().iter().map(From::from).collect::<Vec<PathType>>();
+                if item_types.is_empty() { return; }
+
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window,
+                    &qtr("context_menu_extract_packfile"),
+                );
+                setup_file_dialog(&mut file_dialog, Some(LAST_PATH_EXTRACT));
+                file_dialog.set_file_mode(FileMode::Directory);
+                file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+
+                if file_dialog.exec() == 1 {
+                    let extraction_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    save_last_path(LAST_PATH_EXTRACT, &extraction_path);
+
+                    app_ui.main_window.set_enabled(false);
+                    CENTRAL_COMMAND.send_message_qt(Command::ExtractPackedFilesExtra(pack_file_view.get_pack_file_path(), item_types, extraction_path));
+                    let response = CENTRAL_COMMAND.recv_message_qt();
+                    match response {
+                        Response::String(result) => show_dialog(app_ui.main_window, result, true),
+                        Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                    app_ui.main_window.set_enabled(true);
+                }
+            }
+        ));
+
         // What happens when we trigger one of the filter events for the PackFile Contents TreeView.
         let filter_change_text = SlotOfQString::new(clone!(pack_file_view => move |_| {
             PackFileExtraView::filter_files(&pack_file_view);
@@ -134,11 +196,13 @@ impl PackFileExtraViewSlots {
 
         // Actions without buttons for the TreeView.
         let expand_all = Slot::new(clone!(mut pack_file_view => move || { pack_file_view.tree_view.expand_all(); }));
-        let collapse_all = Slot::new(move || { pack_file_view.tree_view.collapse_all(); });
+        let collapse_all = Slot::new(move || catch_panic_in_slot(|| { pack_file_view.tree_view.collapse_all(); }));
 
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             import,
+            open,
+            extract,
 
             filter_change_text,
             filter_change_autoexpand_matches,
@@ -10,45 +10,87 @@
 
 /*!
 Module with all the code for managing the view for RigidModel PackedFiles.
+
+This view doesn't show the model itself. It's a form with the metadata of the RigidModel,
+so things like the texture paths can be edited without having to touch the binary data.
 !*/
 
+use qt_widgets::QGridLayout;
+use qt_widgets::QGroupBox;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::QScrollArea;
 use qt_widgets::QWidget;
 
+use qt_core::QString;
+
 use cpp_core::MutPtr;
 
 use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
 
 use rpfm_error::{Result, ErrorKind};
+use rpfm_lib::packedfile::{DecodedPackedFile, PackedFileType};
+use rpfm_lib::packedfile::rigidmodel::RigidModel;
 use rpfm_lib::packfile::packedfile::PackedFileInfo;
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
-use crate::ffi::new_text_editor_safe;
 use crate::global_search_ui::GlobalSearchUI;
-use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::locale::{qtr, qtre};
 use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{atomic_from_mut_ptr, create_grid_layout, mut_ptr_from_atomic};
 
 use self::slots::PackedFileRigidModelViewSlots;
 
+mod connections;
 pub mod slots;
 
+/// Max length of the skeleton id of a RigidModel.
+const SKELETON_ID_MAX_LENGTH: i32 = 128;
+
+/// Max length of the name of a mesh.
+const MESH_NAME_MAX_LENGTH: i32 = 32;
+
+/// Max length of the texture directory and the texture paths of a mesh.
+const TEXTURE_PATH_MAX_LENGTH: i32 = 256;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
 
 /// This struct contains the view of a RigidModel PackedFile.
 pub struct PackedFileRigidModelView {
-    //editor: AtomicPtr<QWidget>,
+    version_data_label: AtomicPtr<QLabel>,
+    skeleton_id_line_edit: AtomicPtr<QLineEdit>,
+    lods: Vec<LodLineEdits>,
+
+    rigid_model: Arc<RwLock<RigidModel>>,
+}
+
+/// This struct contains the editable fields of a lod.
+struct LodLineEdits {
+    visibility_distance: AtomicPtr<QLineEdit>,
+
+    /// The editable fields of each mesh of the lod. None for meshes with a material we cannot decode.
+    meshes: Vec<Option<MeshLineEdits>>,
+}
+
+/// This struct contains the editable fields of a mesh.
+struct MeshLineEdits {
+    name: AtomicPtr<QLineEdit>,
+    texture_directory: AtomicPtr<QLineEdit>,
+    textures: Vec<AtomicPtr<QLineEdit>>,
 }
 
-/// This struct contains the raw version of each pointer in `PackedFileRigidViewRaw`, to be used when building the slots.
+/// This struct contains the raw version of each pointer in `PackedFileRigidModelView`, to be used when building the slots.
 ///
 /// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
 /// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileRigidModelView`.
 #[derive(Clone)]
 pub struct PackedFileRigidModelViewRaw {
-    pub editor: MutPtr<QWidget>,
     pub path: Arc<RwLock<Vec<String>>>,
 }
 
@@ -59,33 +101,228 @@ pub struct PackedFileRigidModelViewRaw {
 /// Implementation for `PackedFileRigidModelView`.
 impl PackedFileRigidModelView {
 
-    /// This function creates a new Text View, and sets up his slots and connections.
+    /// This function creates a new RigidModel View, and sets up his slots and connections.
     pub unsafe fn new_view(
         packed_file_view: &mut PackedFileView,
         app_ui: &AppUI,
-        global_search_ui: &GlobalSearchUI,
+        _global_search_ui: &GlobalSearchUI,
         pack_file_contents_ui: &PackFileContentsUI,
     ) -> Result<(TheOneSlot, PackedFileInfo)> {
 
-        // Get the decoded Text.
         CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path()));
         let response = CENTRAL_COMMAND.recv_message_qt();
-        let (_rigid_model, packed_file_info) = match response {
+        let (rigid_model, packed_file_info) = match response {
             Response::RigidModelPackedFileInfo((rigid_model, packed_file_info)) => (rigid_model, packed_file_info),
             Response::Error(error) => return Err(error),
             Response::Unknown => return Err(ErrorKind::PackedFileTypeUnknown.into()),
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         };
 
-        let editor = new_text_editor_safe(&mut packed_file_view.get_mut_widget());
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        // The form goes in a scroll area, as models with many lods and meshes can get quite long.
+        let form_widget = QWidget::new_0a().into_ptr();
+        let mut scroll_area = QScrollArea::new_0a().into_ptr();
+        let mut form_grid = create_grid_layout(form_widget);
+        scroll_area.set_widget(form_widget);
+        scroll_area.set_widget_resizable(true);
+        layout.add_widget_5a(scroll_area, 0, 0, 1, 1);
+
+        let version_label = QLabel::from_q_string(&qtr("rigidmodel_version"));
+        let skeleton_id_label = QLabel::from_q_string(&qtr("rigidmodel_skeleton_id"));
+        let mut version_data_label = QLabel::from_q_string(&QString::from_std_str(rigid_model.get_version().to_string()));
+        let mut skeleton_id_line_edit = QLineEdit::new();
+        skeleton_id_line_edit.set_max_length(SKELETON_ID_MAX_LENGTH);
+        skeleton_id_line_edit.set_text(&QString::from_std_str(rigid_model.get_ref_skeleton_id()));
+
+        form_grid.add_widget_5a(version_label.into_ptr(), 0, 0, 1, 1);
+        form_grid.add_widget_5a(skeleton_id_label.into_ptr(), 1, 0, 1, 1);
+        form_grid.add_widget_5a(&mut version_data_label, 0, 1, 1, 1);
+        form_grid.add_widget_5a(&mut skeleton_id_line_edit, 1, 1, 1, 1);
+
+        // One frame per lod, with one frame per mesh inside.
+        let mut lods = vec![];
+        for (lod_index, lod) in rigid_model.get_ref_lods().iter().enumerate() {
+            let lod_frame = QGroupBox::from_q_string(&qtre("rigidmodel_lod", &[&lod_index.to_string()])).into_ptr();
+            let mut lod_grid = create_grid_layout(lod_frame.static_upcast_mut());
+
+            let visibility_distance_label = QLabel::from_q_string(&qtr("rigidmodel_visibility_distance"));
+            let mut visibility_distance_line_edit = QLineEdit::new();
+            visibility_distance_line_edit.set_text(&QString::from_std_str(lod.get_visibility_distance().to_string()));
+            lod_grid.add_widget_5a(visibility_distance_label.into_ptr(), 0, 0, 1, 1);
+            lod_grid.add_widget_5a(&mut visibility_distance_line_edit, 0, 1, 1, 1);
+
+            let mut meshes = vec![];
+            for (mesh_index, mesh) in lod.get_ref_meshes().iter().enumerate() {
+                let mesh_frame = QGroupBox::from_q_string(&qtre("rigidmodel_mesh", &[&mesh_index.to_string()])).into_ptr();
+                let mut mesh_grid = create_grid_layout(mesh_frame.static_upcast_mut());
+
+                let info_label = QLabel::from_q_string(&qtre("rigidmodel_mesh_info", &[
+                    &mesh.get_material_type().to_string(),
+                    mesh.get_ref_shader_name(),
+                    &mesh.get_vertices_count().to_string(),
+                    &mesh.get_indices_count().to_string(),
+                ]));
+                mesh_grid.add_widget_5a(info_label.into_ptr(), 0, 0, 1, 2);
+
+                match mesh.get_ref_material() {
+                    Some(material) => {
+                        let name_label = QLabel::from_q_string(&qtr("rigidmodel_mesh_name"));
+                        let texture_directory_label = QLabel::from_q_string(&qtr("rigidmodel_texture_directory"));
+                        let mut name_line_edit = QLineEdit::new();
+                        let mut texture_directory_line_edit = QLineEdit::new();
+                        name_line_edit.set_max_length(MESH_NAME_MAX_LENGTH);
+                        texture_directory_line_edit.set_max_length(TEXTURE_PATH_MAX_LENGTH);
+                        name_line_edit.set_text(&QString::from_std_str(material.get_ref_name()));
+                        texture_directory_line_edit.set_text(&QString::from_std_str(material.get_ref_texture_directory()));
+
+                        mesh_grid.add_widget_5a(name_label.into_ptr(), 1, 0, 1, 1);
+                        mesh_grid.add_widget_5a(texture_directory_label.into_ptr(), 2, 0, 1, 1);
+                        mesh_grid.add_widget_5a(&mut name_line_edit, 1, 1, 1, 1);
+                        mesh_grid.add_widget_5a(&mut texture_directory_line_edit, 2, 1, 1, 1);
+
+                        let mut textures = vec![];
+                        for (texture_index, texture) in material.get_ref_textures().iter().enumerate() {
+                            let texture_type = texture.get_texture_type_name().map(|x| x.to_owned()).unwrap_or_else(|| texture.get_texture_type().to_string());
+                            let texture_label = QLabel::from_q_string(&qtre("rigidmodel_texture", &[&texture_type]));
+                            let mut texture_line_edit = QLineEdit::new();
+                            texture_line_edit.set_max_length(TEXTURE_PATH_MAX_LENGTH);
+                            texture_line_edit.set_text(&QString::from_std_str(texture.get_ref_path()));
+
+                            mesh_grid.add_widget_5a(texture_label.into_ptr(), texture_index as i32 + 3, 0, 1, 1);
+                            mesh_grid.add_widget_5a(&mut texture_line_edit, texture_index as i32 + 3, 1, 1, 1);
+                            textures.push(atomic_from_mut_ptr(texture_line_edit.into_ptr()));
+                        }
 
-        let packed_file_rigid_model_view_raw = PackedFileRigidModelViewRaw {editor, path: packed_file_view.get_path_raw()};
-        let packed_file_rigid_model_view_slots = PackedFileRigidModelViewSlots::new(&packed_file_rigid_model_view_raw, *app_ui, *global_search_ui, *pack_file_contents_ui);
-        let packed_file_rigid_model_view = Self { /*editor: atomic_from_mut_ptr(packed_file_rigid_model_view_raw.editor)*/ };
+                        meshes.push(Some(MeshLineEdits {
+                            name: atomic_from_mut_ptr(name_line_edit.into_ptr()),
+                            texture_directory: atomic_from_mut_ptr(texture_directory_line_edit.into_ptr()),
+                            textures,
+                        }));
+                    }
 
+                    // If we don't know how to decode the material, just say so.
+                    None => {
+                        let not_supported_label = QLabel::from_q_string(&qtr("rigidmodel_material_not_supported"));
+                        mesh_grid.add_widget_5a(not_supported_label.into_ptr(), 1, 0, 1, 2);
+                        meshes.push(None);
+                    }
+                }
+
+                lod_grid.add_widget_5a(mesh_frame, mesh_index as i32 + 1, 0, 1, 2);
+            }
+
+            form_grid.add_widget_5a(lod_frame, lod_index as i32 + 2, 0, 1, 2);
+            lods.push(LodLineEdits {
+                visibility_distance: atomic_from_mut_ptr(visibility_distance_line_edit.into_ptr()),
+                meshes,
+            });
+        }
+        form_grid.set_row_stretch(lods.len() as i32 + 2, 10);
+
+        let packed_file_rigid_model_view_raw = PackedFileRigidModelViewRaw {
+            path: packed_file_view.get_path_raw(),
+        };
+
+        let packed_file_rigid_model_view_slots = PackedFileRigidModelViewSlots::new(
+            &packed_file_rigid_model_view_raw,
+            *app_ui,
+            *pack_file_contents_ui,
+        );
+
+        let packed_file_rigid_model_view = Self {
+            version_data_label: atomic_from_mut_ptr(version_data_label.into_ptr()),
+            skeleton_id_line_edit: atomic_from_mut_ptr(skeleton_id_line_edit.into_ptr()),
+            lods,
+            rigid_model: Arc::new(RwLock::new(rigid_model)),
+        };
+
+        connections::set_connections(&packed_file_rigid_model_view, &packed_file_rigid_model_view_slots);
         packed_file_view.view = ViewType::Internal(View::RigidModel(packed_file_rigid_model_view));
+        packed_file_view.packed_file_type = PackedFileType::RigidModel;
 
-        // Return success.
         Ok((TheOneSlot::RigidModel(packed_file_rigid_model_view_slots), packed_file_info))
     }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    ///
+    /// This only works if the new RigidModel has the same lods, meshes and textures as the old one.
+    pub unsafe fn reload_view(&mut self, data: &RigidModel) -> Result<()> {
+        let same_structure = data.get_ref_lods().len() == self.lods.len() &&
+            data.get_ref_lods().iter().zip(self.lods.iter()).all(|(lod, lod_line_edits)|
+                lod.get_ref_meshes().len() == lod_line_edits.meshes.len() &&
+                lod.get_ref_meshes().iter().zip(lod_line_edits.meshes.iter()).all(|(mesh, mesh_line_edits)| match (mesh.get_ref_material(), mesh_line_edits) {
+                    (Some(material), Some(mesh_line_edits)) => material.get_ref_textures().len() == mesh_line_edits.textures.len(),
+                    (None, None) => true,
+                    _ => false,
+                })
+            );
+
+        if !same_structure {
+            return Err(ErrorKind::NewDataIsNotDecodeableTheSameWayAsOldDAta.into());
+        }
+
+        self.get_mut_ptr_version_data_label().set_text(&QString::from_std_str(data.get_version().to_string()));
+        self.get_mut_ptr_skeleton_id_line_edit().set_text(&QString::from_std_str(data.get_ref_skeleton_id()));
+        for (lod, lod_line_edits) in data.get_ref_lods().iter().zip(self.lods.iter()) {
+            mut_ptr_from_atomic(&lod_line_edits.visibility_distance).set_text(&QString::from_std_str(lod.get_visibility_distance().to_string()));
+            for (mesh, mesh_line_edits) in lod.get_ref_meshes().iter().zip(lod_line_edits.meshes.iter()) {
+                if let (Some(material), Some(mesh_line_edits)) = (mesh.get_ref_material(), mesh_line_edits) {
+                    mut_ptr_from_atomic(&mesh_line_edits.name).set_text(&QString::from_std_str(material.get_ref_name()));
+                    mut_ptr_from_atomic(&mesh_line_edits.texture_directory).set_text(&QString::from_std_str(material.get_ref_texture_directory()));
+                    for (texture, texture_line_edit) in material.get_ref_textures().iter().zip(mesh_line_edits.textures.iter()) {
+                        mut_ptr_from_atomic(texture_line_edit).set_text(&QString::from_std_str(texture.get_ref_path()));
+                    }
+                }
+            }
+        }
+
+        *self.rigid_model.write().unwrap() = data.clone();
+        Ok(())
+    }
+
+    /// This function saves the data of the view to a `DecodedPackedFile`.
+    pub unsafe fn save_data(&self) -> Result<DecodedPackedFile> {
+        let mut rigid_model = self.rigid_model.read().unwrap().clone();
+        rigid_model.set_skeleton_id(&self.get_mut_ptr_skeleton_id_line_edit().text().to_std_string())?;
+
+        for (lod, lod_line_edits) in rigid_model.get_ref_mut_lods().iter_mut().zip(self.lods.iter()) {
+            lod.set_visibility_distance(mut_ptr_from_atomic(&lod_line_edits.visibility_distance).text().to_std_string().parse::<f32>()?);
+            for (mesh, mesh_line_edits) in lod.get_ref_mut_meshes().iter_mut().zip(lod_line_edits.meshes.iter()) {
+                if let (Some(material), Some(mesh_line_edits)) = (mesh.get_ref_mut_material(), mesh_line_edits) {
+                    material.set_name(&mut_ptr_from_atomic(&mesh_line_edits.name).text().to_std_string())?;
+                    material.set_texture_directory(&mut_ptr_from_atomic(&mesh_line_edits.texture_directory).text().to_std_string())?;
+                    for (texture, texture_line_edit) in material.get_ref_mut_textures().iter_mut().zip(mesh_line_edits.textures.iter()) {
+                        texture.set_path(&mut_ptr_from_atomic(texture_line_edit).text().to_std_string())?;
+                    }
+                }
+            }
+        }
+
+        Ok(DecodedPackedFile::RigidModel(rigid_model))
+    }
+
+    /// This function returns a pointer to the version_data Label.
+    pub fn get_mut_ptr_version_data_label(&self) -> MutPtr<QLabel> {
+        mut_ptr_from_atomic(&self.version_data_label)
+    }
+
+    /// This function returns a pointer to the skeleton id LineEdit.
+    pub fn get_mut_ptr_skeleton_id_line_edit(&self) -> MutPtr<QLineEdit> {
+        mut_ptr_from_atomic(&self.skeleton_id_line_edit)
+    }
+
+    /// This function returns pointers to all the editable LineEdits of the view.
+    pub fn get_mut_ptr_line_edits(&self) -> Vec<MutPtr<QLineEdit>> {
+        let mut line_edits = vec![self.get_mut_ptr_skeleton_id_line_edit()];
+        for lod_line_edits in &self.lods {
+            line_edits.push(mut_ptr_from_atomic(&lod_line_edits.visibility_distance));
+            for mesh_line_edits in lod_line_edits.meshes.iter().flatten() {
+                line_edits.push(mut_ptr_from_atomic(&mesh_line_edits.name));
+                line_edits.push(mut_ptr_from_atomic(&mesh_line_edits.texture_directory));
+                line_edits.extend(mesh_line_edits.textures.iter().map(|x| mut_ptr_from_atomic(x)));
+            }
+        }
+        line_edits
+    }
 }
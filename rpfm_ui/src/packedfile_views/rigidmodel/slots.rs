@@ -12,13 +12,12 @@
 Module with the slots for RigidModel Views.
 !*/
 
-use qt_core::Slot;
+use qt_core::SlotOfQString;
 
 use crate::app_ui::AppUI;
-use crate::global_search_ui::GlobalSearchUI;
-use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::rigidmodel::PackedFileRigidModelViewRaw;
-use crate::UI_STATE;
+use crate::packedfile_views::utils::set_modified;
+use crate::packfile_contents_ui::PackFileContentsUI;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -26,7 +25,7 @@ use crate::UI_STATE;
 
 /// This struct contains the slots of the view of an RigidModel PackedFile.
 pub struct PackedFileRigidModelViewSlots {
-    pub save: Slot<'static>,
+    pub modified: SlotOfQString<'static>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -36,22 +35,17 @@ pub struct PackedFileRigidModelViewSlots {
 /// Implementation for `PackedFileRigidModelViewSlots`.
 impl PackedFileRigidModelViewSlots {
 
-    /// This function creates the entire slot pack for images.
-    pub unsafe fn new(packed_file_view: &PackedFileRigidModelViewRaw, mut app_ui: AppUI, global_search_ui: GlobalSearchUI, mut pack_file_contents_ui: PackFileContentsUI) -> Self {
+    /// This function creates the entire slot pack for RigidModel Views.
+    pub unsafe fn new(packed_file_view: &PackedFileRigidModelViewRaw, mut app_ui: AppUI, mut pack_file_contents_ui: PackFileContentsUI) -> Self {
 
-        // When we want to save the contents of the UI to the backend...
-        let save = Slot::new(clone!(packed_file_view => move || {
-            if let Some(packed_file) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == *packed_file_view.path.read().unwrap()) {
-                if let Err(_error) = packed_file.save(&mut app_ui, global_search_ui, &mut pack_file_contents_ui) {
-                    //show_dialog(packed_file_view.get_table_view_primary(), error, false);
-                }
-            }
+        // When any of the fields of the RigidModel gets edited, mark the PackedFile as modified.
+        let modified = SlotOfQString::new(clone!(packed_file_view => move |_| {
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
         }));
 
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
-            save,
+            modified,
         }
     }
 }
-
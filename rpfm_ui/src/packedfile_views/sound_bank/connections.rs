@@ -0,0 +1,31 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileSoundBankView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileSoundBankView` and `PackedFileSoundBankViewSlots` structs.
+!*/
+
+use super::{PackedFileSoundBankView, slots::PackedFileSoundBankViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileSoundBankView` with their slots in `PackedFileSoundBankViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &PackedFileSoundBankView, slots: &PackedFileSoundBankViewSlots) {
+    ui.get_mut_ptr_export_selected_button().released().connect(&slots.export_selected);
+    ui.get_mut_ptr_export_all_button().released().connect(&slots.export_all);
+    ui.get_mut_ptr_play_button().released().connect(&slots.play);
+    ui.get_mut_ptr_stop_button().released().connect(&slots.stop);
+
+    // Double-clicking a WEM plays it.
+    ui.get_mut_ptr_wems_tree_view().double_clicked().connect(&slots.play_double_click);
+}
@@ -0,0 +1,362 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the view for SoundBank PackedFiles.
+
+It lists the WEMs embedded in the SoundBank and the ids of its events, and lets you export the WEMs to disk and preview them.
+Only WEMs using PCM can be previewed, as the rest use Wwise codecs no common player can decode. SoundBanks are read-only.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionBehavior, SelectionMode};
+use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
+use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QObject;
+use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QVariant;
+
+use cpp_core::MutPtr;
+
+use std::env::temp_dir;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
+
+use rpfm_error::{Error, ErrorKind, Result};
+use rpfm_lib::packedfile::PackedFileType;
+use rpfm_lib::packedfile::sound_bank::SoundBank;
+use rpfm_lib::packfile::packedfile::PackedFileInfo;
+use rpfm_lib::settings::LAST_PATH_EXTRACT;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+use crate::ffi::{add_to_q_list_safe, new_audio_player_safe, play_audio_file_safe, stop_audio_safe};
+use crate::locale::{qtr, tr, tre};
+use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic, save_last_path, setup_file_dialog, show_dialog};
+use self::slots::PackedFileSoundBankViewSlots;
+
+mod connections;
+pub mod slots;
+
+/// Prefix of the temporary files where we put the WEMs to preview, converted to WAV.
+const PREVIEW_FILE_PREFIX: &str = "rpfm_wem_preview_";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of a SoundBank PackedFile.
+pub struct PackedFileSoundBankView {
+    info_label: AtomicPtr<QLabel>,
+    wems_tree_view: AtomicPtr<QTreeView>,
+    wems_tree_model: AtomicPtr<QStandardItemModel>,
+    events_tree_model: AtomicPtr<QStandardItemModel>,
+    export_selected_button: AtomicPtr<QPushButton>,
+    export_all_button: AtomicPtr<QPushButton>,
+    play_button: AtomicPtr<QPushButton>,
+    stop_button: AtomicPtr<QPushButton>,
+    audio_player: AtomicPtr<QObject>,
+    sound_bank: Arc<RwLock<SoundBank>>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileSoundBankView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileSoundBankView`.
+#[derive(Clone)]
+pub struct PackedFileSoundBankViewRaw {
+    pub info_label: MutPtr<QLabel>,
+    pub wems_tree_view: MutPtr<QTreeView>,
+    pub wems_tree_model: MutPtr<QStandardItemModel>,
+    pub events_tree_model: MutPtr<QStandardItemModel>,
+    pub export_selected_button: MutPtr<QPushButton>,
+    pub export_all_button: MutPtr<QPushButton>,
+    pub play_button: MutPtr<QPushButton>,
+    pub stop_button: MutPtr<QPushButton>,
+    pub audio_player: MutPtr<QObject>,
+    pub sound_bank: Arc<RwLock<SoundBank>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileSoundBankView`.
+impl PackedFileSoundBankView {
+
+    /// This function creates a new SoundBank View, and sets up his slots and connections.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+    ) -> Result<(TheOneSlot, PackedFileInfo)> {
+
+        CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let (sound_bank, packed_file_info) = match response {
+            Response::SoundBankPackedFileInfo((sound_bank, packed_file_info)) => (sound_bank, packed_file_info),
+            Response::Error(error) => return Err(error),
+            Response::Unknown => return Err(ErrorKind::PackedFileTypeUnknown.into()),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        let mut info_label = QLabel::new();
+        let wems_label = QLabel::from_q_string(&qtr("sound_bank_wems"));
+        let events_label = QLabel::from_q_string(&qtr("sound_bank_events"));
+
+        // The WEMs can be exported, so they can be selected. The events are just informative.
+        let mut wems_tree_view = QTreeView::new_0a();
+        let mut wems_tree_model = QStandardItemModel::new_0a();
+        wems_tree_view.set_model(&mut wems_tree_model);
+        wems_tree_view.set_root_is_decorated(false);
+        wems_tree_view.set_sorting_enabled(true);
+        wems_tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        wems_tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
+        wems_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        let mut events_tree_view = QTreeView::new_0a();
+        let mut events_tree_model = QStandardItemModel::new_0a();
+        events_tree_view.set_model(&mut events_tree_model);
+        events_tree_view.set_root_is_decorated(false);
+        events_tree_view.set_sorting_enabled(true);
+        events_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        let mut export_selected_button = QPushButton::from_q_string(&qtr("sound_bank_export_selected"));
+        let mut export_all_button = QPushButton::from_q_string(&qtr("sound_bank_export_all"));
+        let mut play_button = QPushButton::from_q_string(&qtr("sound_bank_play"));
+        let mut stop_button = QPushButton::from_q_string(&qtr("sound_bank_stop"));
+        play_button.set_tool_tip(&qtr("tt_sound_bank_play"));
+
+        // The player is owned by the view's widget, so it stops and gets deleted when the view is closed.
+        let mut widget = packed_file_view.get_mut_widget();
+        let audio_player = new_audio_player_safe(&mut widget);
+
+        layout.add_widget_5a(&mut info_label, 0, 0, 1, 2);
+        layout.add_widget_5a(wems_label.into_ptr(), 1, 0, 1, 1);
+        layout.add_widget_5a(events_label.into_ptr(), 1, 1, 1, 1);
+        layout.add_widget_5a(&mut wems_tree_view, 2, 0, 1, 1);
+        layout.add_widget_5a(events_tree_view.into_ptr(), 2, 1, 1, 1);
+        layout.add_widget_5a(&mut export_selected_button, 3, 0, 1, 1);
+        layout.add_widget_5a(&mut export_all_button, 3, 1, 1, 1);
+        layout.add_widget_5a(&mut play_button, 4, 0, 1, 1);
+        layout.add_widget_5a(&mut stop_button, 4, 1, 1, 1);
+        layout.set_column_stretch(0, 2);
+        layout.set_column_stretch(1, 1);
+
+        let mut packed_file_sound_bank_view_raw = PackedFileSoundBankViewRaw {
+            info_label: info_label.into_ptr(),
+            wems_tree_view: wems_tree_view.into_ptr(),
+            wems_tree_model: wems_tree_model.into_ptr(),
+            events_tree_model: events_tree_model.into_ptr(),
+            export_selected_button: export_selected_button.into_ptr(),
+            export_all_button: export_all_button.into_ptr(),
+            play_button: play_button.into_ptr(),
+            stop_button: stop_button.into_ptr(),
+            audio_player,
+            sound_bank: Arc::new(RwLock::new(sound_bank)),
+        };
+
+        packed_file_sound_bank_view_raw.load_data();
+
+        let packed_file_sound_bank_view_slots = PackedFileSoundBankViewSlots::new(packed_file_sound_bank_view_raw.clone());
+
+        let packed_file_sound_bank_view = Self {
+            info_label: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.info_label),
+            wems_tree_view: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.wems_tree_view),
+            wems_tree_model: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.wems_tree_model),
+            events_tree_model: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.events_tree_model),
+            export_selected_button: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.export_selected_button),
+            export_all_button: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.export_all_button),
+            play_button: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.play_button),
+            stop_button: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.stop_button),
+            audio_player: atomic_from_mut_ptr(packed_file_sound_bank_view_raw.audio_player),
+            sound_bank: packed_file_sound_bank_view_raw.sound_bank,
+        };
+
+        connections::set_connections(&packed_file_sound_bank_view, &packed_file_sound_bank_view_slots);
+        packed_file_view.packed_file_type = PackedFileType::SoundBank;
+        packed_file_view.view = ViewType::Internal(View::SoundBank(packed_file_sound_bank_view));
+
+        // Return success.
+        Ok((TheOneSlot::SoundBank(packed_file_sound_bank_view_slots), packed_file_info))
+    }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    pub unsafe fn reload_view(&self, data: &SoundBank) {
+        *self.sound_bank.write().unwrap() = data.clone();
+        let mut view = PackedFileSoundBankViewRaw {
+            info_label: mut_ptr_from_atomic(&self.info_label),
+            wems_tree_view: mut_ptr_from_atomic(&self.wems_tree_view),
+            wems_tree_model: mut_ptr_from_atomic(&self.wems_tree_model),
+            events_tree_model: mut_ptr_from_atomic(&self.events_tree_model),
+            export_selected_button: self.get_mut_ptr_export_selected_button(),
+            export_all_button: self.get_mut_ptr_export_all_button(),
+            play_button: self.get_mut_ptr_play_button(),
+            stop_button: self.get_mut_ptr_stop_button(),
+            audio_player: mut_ptr_from_atomic(&self.audio_player),
+            sound_bank: self.sound_bank.clone(),
+        };
+
+        view.load_data();
+    }
+
+    /// This function returns a pointer to the `Export Selected WEMs` button.
+    pub fn get_mut_ptr_export_selected_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.export_selected_button)
+    }
+
+    /// This function returns a pointer to the `Export All WEMs` button.
+    pub fn get_mut_ptr_export_all_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.export_all_button)
+    }
+
+    /// This function returns a pointer to the `Play` button.
+    pub fn get_mut_ptr_play_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.play_button)
+    }
+
+    /// This function returns a pointer to the `Stop` button.
+    pub fn get_mut_ptr_stop_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.stop_button)
+    }
+
+    /// This function returns a pointer to the WEMs TreeView.
+    pub fn get_mut_ptr_wems_tree_view(&self) -> MutPtr<QTreeView> {
+        mut_ptr_from_atomic(&self.wems_tree_view)
+    }
+}
+
+/// Implementation for `PackedFileSoundBankViewRaw`.
+impl PackedFileSoundBankViewRaw {
+
+    /// This function loads the contents of the SoundBank into the view.
+    pub unsafe fn load_data(&mut self) {
+        let sound_bank = self.sound_bank.read().unwrap();
+        let info = tre("sound_bank_info", &[&sound_bank.get_id().to_string(), &sound_bank.get_version().to_string()]);
+        self.info_label.set_text(&QString::from_std_str(info));
+
+        self.wems_tree_model.clear();
+        let mut headers = QStringList::new();
+        headers.append_q_string(&qtr("sound_bank_id"));
+        headers.append_q_string(&qtr("sound_bank_size"));
+        self.wems_tree_model.set_horizontal_header_labels(&headers);
+
+        // Store the numbers as numbers, so they're sorted properly.
+        for wem in sound_bank.get_ref_wems() {
+            let mut qlist = QListOfQStandardItem::new();
+            let mut id = QStandardItem::new();
+            let mut size = QStandardItem::new();
+            id.set_data_2a(&QVariant::from_uint(wem.get_id()), 2);
+            size.set_data_2a(&QVariant::from_uint(wem.get_ref_data().len() as u32), 2);
+            add_to_q_list_safe(qlist.as_mut_ptr(), id.into_ptr());
+            add_to_q_list_safe(qlist.as_mut_ptr(), size.into_ptr());
+            self.wems_tree_model.append_row_q_list_of_q_standard_item(&qlist);
+        }
+
+        self.events_tree_model.clear();
+        let mut headers = QStringList::new();
+        headers.append_q_string(&qtr("sound_bank_id"));
+        self.events_tree_model.set_horizontal_header_labels(&headers);
+
+        for event in sound_bank.get_ref_events() {
+            let mut item = QStandardItem::new();
+            item.set_data_2a(&QVariant::from_uint(*event), 2);
+            self.events_tree_model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        let has_wems = !sound_bank.get_ref_wems().is_empty();
+        self.export_selected_button.set_enabled(has_wems);
+        self.export_all_button.set_enabled(has_wems);
+        self.play_button.set_enabled(has_wems);
+        self.stop_button.set_enabled(has_wems);
+        stop_audio_safe(&mut self.audio_player);
+    }
+
+    /// This function plays the selected WEM. If there are multiple ones selected, it plays the first one.
+    ///
+    /// The WEM is converted to WAV and written to a temporary file, as the player can only play files from disk.
+    pub unsafe fn play_wem(&mut self) {
+        let indexes = self.wems_tree_view.selection_model().selected_rows_0a();
+        if indexes.count_0a() == 0 {
+            return show_dialog(self.wems_tree_view, tr("sound_bank_none_selected"), false);
+        }
+
+        let id = indexes.at(0).data_1a(2).to_u_int_0a();
+        let wav = {
+            let sound_bank = self.sound_bank.read().unwrap();
+            match sound_bank.get_ref_wems().iter().find(|wem| wem.get_id() == id) {
+                Some(wem) => wem.to_wav(),
+                None => return show_dialog(self.wems_tree_view, ErrorKind::SoundBankWemNotFound(id), false),
+            }
+        };
+
+        let wav = match wav {
+            Ok(wav) => wav,
+            Err(error) => return show_dialog(self.wems_tree_view, error, false),
+        };
+
+        let preview_path = temp_dir().join(format!("{}{}.wav", PREVIEW_FILE_PREFIX, id));
+        let result = File::create(&preview_path).and_then(|file| BufWriter::new(file).write_all(&wav));
+        match result {
+            Ok(_) => play_audio_file_safe(&mut self.audio_player, &mut QString::from_std_str(preview_path.to_string_lossy())),
+            Err(error) => show_dialog(self.wems_tree_view, Error::from(error), false),
+        }
+    }
+
+    /// This function stops the WEM being played, if any.
+    pub unsafe fn stop_wem(&mut self) {
+        stop_audio_safe(&mut self.audio_player);
+    }
+
+    /// This function exports the selected WEMs, or all of them if `all` is true, to a folder chosen by the user.
+    pub unsafe fn export_wems(&mut self, all: bool) {
+        let ids = if all {
+            self.sound_bank.read().unwrap().get_ref_wems().iter().map(|wem| wem.get_id()).collect::<Vec<u32>>()
+        } else {
+            let indexes = self.wems_tree_view.selection_model().selected_rows_0a();
+            (0..indexes.count_0a()).map(|index| indexes.at(index).data_1a(2).to_u_int_0a()).collect::<Vec<u32>>()
+        };
+
+        if ids.is_empty() {
+            return show_dialog(self.wems_tree_view, tr("sound_bank_none_selected"), false);
+        }
+
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.wems_tree_view,
+            &qtr("sound_bank_export_title"),
+        );
+        setup_file_dialog(&mut file_dialog, Some(LAST_PATH_EXTRACT));
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+
+        if file_dialog.exec() == 1 {
+            let export_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            save_last_path(LAST_PATH_EXTRACT, &export_path);
+
+            match self.sound_bank.read().unwrap().export_wems(&ids, &export_path) {
+                Ok(paths) => show_dialog(self.wems_tree_view, tre("sound_bank_exported", &[&paths.len().to_string(), &export_path.to_string_lossy()]), true),
+                Err(error) => show_dialog(self.wems_tree_view, error, false),
+            }
+        }
+    }
+}
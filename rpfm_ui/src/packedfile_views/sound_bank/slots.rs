@@ -0,0 +1,86 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for SoundBank Views.
+!*/
+
+use qt_core::{Slot, SlotOfQModelIndex};
+
+use crate::packedfile_views::sound_bank::PackedFileSoundBankViewRaw;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of a SoundBank PackedFile.
+pub struct PackedFileSoundBankViewSlots {
+    pub export_selected: Slot<'static>,
+    pub export_all: Slot<'static>,
+    pub play: Slot<'static>,
+    pub play_double_click: SlotOfQModelIndex<'static>,
+    pub stop: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileSoundBankViewSlots`.
+impl PackedFileSoundBankViewSlots {
+
+    /// This function creates the entire slot pack for SoundBank PackedFile Views.
+    pub unsafe fn new(view: PackedFileSoundBankViewRaw) -> Self {
+
+        // Slot to export the selected WEMs.
+        let export_selected = Slot::new(clone!(
+            mut view => move || {
+                view.export_wems(false);
+            }
+        ));
+
+        // Slot to export all the WEMs.
+        let export_all = Slot::new(clone!(
+            mut view => move || {
+                view.export_wems(true);
+            }
+        ));
+
+        // Slot to play the selected WEM.
+        let play = Slot::new(clone!(
+            mut view => move || {
+                view.play_wem();
+            }
+        ));
+
+        // Slot to play a WEM when double-clicking it.
+        let play_double_click = SlotOfQModelIndex::new(clone!(
+            mut view => move |_| {
+                view.play_wem();
+            }
+        ));
+
+        // Slot to stop the WEM being played.
+        let stop = Slot::new(clone!(
+            mut view => move || {
+                view.stop_wem();
+            }
+        ));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            export_selected,
+            export_all,
+            play,
+            play_double_click,
+            stop,
+        }
+    }
+}
@@ -12,10 +12,11 @@
 Module with all the code for managing the view for Table PackedFiles.
 !*/
 
+use std::path::PathBuf;
+
 use rpfm_error::{ErrorKind, Result};
 
 use rpfm_lib::packedfile::PackedFileType;
-use rpfm_lib::packedfile::table::DecodedData;
 use rpfm_lib::packfile::packedfile::PackedFileInfo;
 
 use crate::app_ui::AppUI;
@@ -24,6 +25,7 @@ use crate::communications::*;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::views::table::utils::is_path_from_packfile_extra;
 
 use crate::views::table::{TableView, TableType};
 
@@ -47,7 +49,7 @@ impl PackedFileTableView {
 
     /// This function creates a new Table View, and sets up his slots and connections.
     ///
-    /// NOTE: To open the dependency list, make sure the view has an empty path.
+    /// NOTE: To open a table from an extra PackFile, make sure the path starts with `RESERVED_NAME_EXTRA_PACKFILE` and the path of the extra PackFile.
     pub unsafe fn new_view(
         packed_file_view: &mut PackedFileView,
         app_ui: &AppUI,
@@ -56,8 +58,10 @@ impl PackedFileTableView {
     ) -> Result<(TheOneSlot, Option<PackedFileInfo>)> {
 
         // Get the decoded Table.
-        if packed_file_view.get_ref_path().is_empty() { CENTRAL_COMMAND.send_message_qt(Command::GetDependencyPackFilesList); }
-        else { CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path())); }
+        let path = packed_file_view.get_path();
+        let is_from_packfile_extra = is_path_from_packfile_extra(&path);
+        if is_from_packfile_extra { CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFileExtra(PathBuf::from(&path[1]), path[2..].to_vec())); }
+        else { CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(path)); }
 
         let response = CENTRAL_COMMAND.recv_message_qt();
         let (table_data, packed_file_info) = match response {
@@ -65,18 +69,19 @@ impl PackedFileTableView {
             Response::DBPackedFileInfo((table, packed_file_info)) => (TableType::DB(table), Some(packed_file_info)),
             Response::LocPackedFileInfo((table, packed_file_info)) => (TableType::Loc(table), Some(packed_file_info)),
             Response::MatchedCombatPackedFileInfo((table, packed_file_info)) => (TableType::MatchedCombat(table), Some(packed_file_info)),
-            Response::VecString(table) => (TableType::DependencyManager(table.iter().map(|x| vec![DecodedData::StringU8(x.to_owned()); 1]).collect::<Vec<Vec<DecodedData>>>()), None),
             Response::Error(error) => return Err(error),
             Response::Unknown => return Err(ErrorKind::PackedFileTypeUnknown.into()),
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         };
 
+        // The info of PackedFiles from extra PackFiles is useless for the TreeView of the open PackFile.
+        let packed_file_info = if is_from_packfile_extra { None } else { packed_file_info };
+
         let packed_file_type = match table_data {
 
             // This one should never happen.
             TableType::AnimFragment(_) => PackedFileType::AnimFragment,
             TableType::AnimTable(_) => PackedFileType::AnimTable,
-            TableType::DependencyManager(_) => PackedFileType::DependencyPackFilesList,
             TableType::DB(_) => PackedFileType::DB,
             TableType::Loc(_) => PackedFileType::Loc,
             TableType::MatchedCombat(_) => PackedFileType::MatchedCombat,
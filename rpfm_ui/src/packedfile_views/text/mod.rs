@@ -15,6 +15,8 @@ Module with all the code for managing the view for Text PackedFiles.
 use qt_widgets::QGridLayout;
 use qt_widgets::QWidget;
 
+use qt_core::QTimer;
+
 use cpp_core::MutPtr;
 
 use std::sync::atomic::AtomicPtr;
@@ -24,19 +26,22 @@ use rpfm_error::{Result, ErrorKind};
 use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::packedfile::text::{Text, TextType};
 use rpfm_lib::packfile::packedfile::PackedFileInfo;
+use rpfm_lib::settings::ZOOM_TEXT;
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
-use crate::ffi::{new_text_editor_safe, set_text_safe};
+use crate::ffi::{get_text_editor_font_size_safe, new_text_editor_safe, set_text_editor_font_size_safe, set_text_safe};
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
 use crate::QString;
 use crate::utils::atomic_from_mut_ptr;
+use crate::utils::{get_zoom_level, save_setting_string};
 use crate::utils::mut_ptr_from_atomic;
 use self::slots::PackedFileTextViewSlots;
 
+mod connections;
 pub mod slots;
 
 const CPP: &str = "C++";
@@ -47,6 +52,9 @@ const PLAIN: &str = "Normal";
 const MARKDOWN: &str = "Markdown";
 const JSON: &str = "JSON";
 
+/// Interval, in milliseconds, between checks for edits to lint in Lua scripts.
+const LINT_CHECK_INTERVAL: i32 = 500;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -54,6 +62,10 @@ const JSON: &str = "JSON";
 /// This struct contains the view of a Text PackedFile.
 pub struct PackedFileTextView {
     editor: AtomicPtr<QWidget>,
+    lint_timer: AtomicPtr<QTimer>,
+
+    /// Size of the font of the editor before applying any zoom, to know how zoomed it is when it's closed.
+    base_font_size: i32,
 }
 
 /// This struct contains the raw version of each pointer in `PackedFileTextViewRaw`, to be used when building the slots.
@@ -63,6 +75,7 @@ pub struct PackedFileTextView {
 #[derive(Clone)]
 pub struct PackedFileTextViewRaw {
     pub editor: MutPtr<QWidget>,
+    pub lint_timer: MutPtr<QTimer>,
     pub path: Arc<RwLock<Vec<String>>>,
 }
 
@@ -110,9 +123,29 @@ impl PackedFileTextView {
 
         set_text_safe(&mut editor, &mut QString::from_std_str(text.get_ref_contents()), &mut highlighting_mode);
 
-        let packed_file_text_view_raw = PackedFileTextViewRaw {editor, path: packed_file_view.get_path_raw() };
+        // The editor can zoom by itself, so we only need to apply the zoom level of the text views, and remember it when it's closed.
+        let base_font_size = get_text_editor_font_size_safe(&mut editor);
+        let zoom_level = get_zoom_level(ZOOM_TEXT);
+        if zoom_level != 0 && base_font_size > 0 {
+            set_text_editor_font_size_safe(&mut editor, (base_font_size + zoom_level).max(1));
+        }
+
+        // Timer to check the syntax of the script after each edit. Only Lua scripts are checked.
+        let mut lint_timer = QTimer::new_1a(packed_file_view.get_mut_widget());
+        lint_timer.set_interval(LINT_CHECK_INTERVAL);
+
+        let packed_file_text_view_raw = PackedFileTextViewRaw {editor, lint_timer: lint_timer.into_ptr(), path: packed_file_view.get_path_raw() };
         let packed_file_text_view_slots = PackedFileTextViewSlots::new(&packed_file_text_view_raw, *app_ui, *pack_file_contents_ui, *global_search_ui);
-        let packed_file_text_view = Self { editor: atomic_from_mut_ptr(packed_file_text_view_raw.editor)};
+        let packed_file_text_view = Self {
+            editor: atomic_from_mut_ptr(packed_file_text_view_raw.editor),
+            lint_timer: atomic_from_mut_ptr(packed_file_text_view_raw.lint_timer),
+            base_font_size,
+        };
+
+        connections::set_connections(&packed_file_text_view, &packed_file_text_view_slots);
+        if text.get_text_type() == TextType::Lua {
+            packed_file_text_view.get_mut_ptr_lint_timer().start_0a();
+        }
 
         packed_file_view.packed_file_type = PackedFileType::Text(text.get_text_type());
         packed_file_view.view = ViewType::Internal(View::Text(packed_file_text_view));
@@ -126,6 +159,19 @@ impl PackedFileTextView {
         mut_ptr_from_atomic(&self.editor)
     }
 
+    /// This function returns a pointer to the timer used to lint the text.
+    pub fn get_mut_ptr_lint_timer(&self) -> MutPtr<QTimer> {
+        mut_ptr_from_atomic(&self.lint_timer)
+    }
+
+    /// This function remembers the zoom level of the editor, so it's applied to the text views opened later.
+    pub unsafe fn save_zoom_level(&self) {
+        let font_size = get_text_editor_font_size_safe(&mut self.get_mut_editor());
+        if font_size > 0 && self.base_font_size > 0 {
+            save_setting_string(ZOOM_TEXT, &(font_size - self.base_font_size).to_string());
+        }
+    }
+
     /// Function to reload the data of the view without having to delete the view itself.
     pub unsafe fn reload_view(&self, data: &Text) {
         let mut editor = mut_ptr_from_atomic(&self.editor);
@@ -14,12 +14,19 @@ Module with the slots for Text Views.
 
 use qt_core::Slot;
 
+use std::cell::Cell;
+use std::rc::Rc;
+
+use rpfm_lib::packedfile::text::lua;
+
 use crate::app_ui::AppUI;
+use crate::ffi::{get_text_safe, get_text_revision_safe};
 use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::{tr, tre};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::text::PackedFileTextViewRaw;
 use crate::UI_STATE;
-use crate::utils::show_dialog;
+use crate::utils::{log_to_status_bar, show_dialog};
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -28,6 +35,7 @@ use crate::utils::show_dialog;
 /// This struct contains the slots of the view of an Text PackedFile.
 pub struct PackedFileTextViewSlots {
     pub save: Slot<'static>,
+    pub check_syntax: Slot<'static>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -53,9 +61,27 @@ impl PackedFileTextViewSlots {
             }
         }));
 
+        // When we want to check the syntax of the script, if it has been edited since the last check...
+        let last_revision = Rc::new(Cell::new(-1));
+        let check_syntax = Slot::new(clone!(
+            packed_file_view,
+            last_revision => move || {
+                let mut editor = packed_file_view.get_mut_editor();
+                let revision = get_text_revision_safe(&mut editor);
+                if revision != last_revision.get() {
+                    last_revision.set(revision);
+                    match lua::check_syntax(&get_text_safe(&mut editor).to_std_string()) {
+                        Ok(_) => log_to_status_bar(&tr("lua_syntax_ok")),
+                        Err(error) => log_to_status_bar(&tre("lua_syntax_error", &[&error.to_string()])),
+                    }
+                }
+            }
+        ));
+
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             save,
+            check_syntax,
         }
     }
 }
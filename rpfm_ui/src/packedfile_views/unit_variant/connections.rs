@@ -0,0 +1,29 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileUnitVariantView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileUnitVariantView` and `PackedFileUnitVariantViewSlots` structs.
+!*/
+
+use super::{PackedFileUnitVariantView, slots::PackedFileUnitVariantViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileUnitVariantView` with their slots in `PackedFileUnitVariantViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &PackedFileUnitVariantView, slots: &PackedFileUnitVariantViewSlots) {
+    ui.get_mut_ptr_tree_model().item_changed().connect(&slots.modified);
+    ui.get_mut_ptr_add_category_button().released().connect(&slots.add_category);
+    ui.get_mut_ptr_add_variant_button().released().connect(&slots.add_variant);
+    ui.get_mut_ptr_remove_button().released().connect(&slots.remove);
+    ui.get_mut_ptr_check_paths_button().released().connect(&slots.check_paths);
+}
@@ -0,0 +1,355 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the view for UnitVariant PackedFiles.
+
+The view is a tree with the categories of the UnitVariant as top-level rows, and their variants as children.
+Categories use the first two columns, and variants the last three.
+!*/
+
+use qt_widgets::QGridLayout;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QSignalBlocker;
+use qt_core::QString;
+
+use cpp_core::MutPtr;
+
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
+
+use rpfm_error::{Result, ErrorKind};
+use rpfm_lib::packedfile::{DecodedPackedFile, PackedFileType};
+use rpfm_lib::packedfile::unit_variant::{Category, UnitVariant, Variant};
+use rpfm_lib::packfile::packedfile::PackedFileInfo;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+use crate::ffi::add_to_q_list_safe;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::{qtr, tre};
+use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packedfile_views::utils::check_paths_in_items;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{atomic_from_mut_ptr, create_grid_layout, log_to_status_bar, mut_ptr_from_atomic};
+
+use self::slots::PackedFileUnitVariantViewSlots;
+
+mod connections;
+pub mod slots;
+
+/// Columns of the tree. Categories use the first two, variants the rest.
+const COLUMN_NAME: i32 = 0;
+const COLUMN_ID: i32 = 1;
+const COLUMN_MESH_FILE: i32 = 2;
+const COLUMN_TEXTURE_FOLDER: i32 = 3;
+const COLUMN_UNKNOWN: i32 = 4;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of a UnitVariant PackedFile.
+pub struct PackedFileUnitVariantView {
+    tree_view: AtomicPtr<QTreeView>,
+    tree_model: AtomicPtr<QStandardItemModel>,
+    add_category_button: AtomicPtr<QPushButton>,
+    add_variant_button: AtomicPtr<QPushButton>,
+    remove_button: AtomicPtr<QPushButton>,
+    check_paths_button: AtomicPtr<QPushButton>,
+
+    unit_variant: Arc<RwLock<UnitVariant>>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileUnitVariantView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileUnitVariantView`.
+#[derive(Clone)]
+pub struct PackedFileUnitVariantViewRaw {
+    pub path: Arc<RwLock<Vec<String>>>,
+    pub tree_view: MutPtr<QTreeView>,
+    pub tree_model: MutPtr<QStandardItemModel>,
+    pub add_category_button: MutPtr<QPushButton>,
+    pub add_variant_button: MutPtr<QPushButton>,
+    pub remove_button: MutPtr<QPushButton>,
+    pub check_paths_button: MutPtr<QPushButton>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileUnitVariantView`.
+impl PackedFileUnitVariantView {
+
+    /// This function creates a new UnitVariant View, and sets up his slots and connections.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        app_ui: &AppUI,
+        _global_search_ui: &GlobalSearchUI,
+        pack_file_contents_ui: &PackFileContentsUI,
+    ) -> Result<(TheOneSlot, PackedFileInfo)> {
+
+        CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let (unit_variant, packed_file_info) = match response {
+            Response::UnitVariantPackedFileInfo((unit_variant, packed_file_info)) => (unit_variant, packed_file_info),
+            Response::Error(error) => return Err(error),
+            Response::Unknown => return Err(ErrorKind::PackedFileTypeUnknown.into()),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        let mut tree_view = QTreeView::new_0a();
+        let mut tree_model = QStandardItemModel::new_0a();
+        tree_view.set_model(&mut tree_model);
+        tree_view.set_sorting_enabled(false);
+        tree_view.header().set_stretch_last_section(false);
+
+        let mut add_category_button = QPushButton::from_q_string(&qtr("unit_variant_add_category"));
+        let mut add_variant_button = QPushButton::from_q_string(&qtr("unit_variant_add_variant"));
+        let mut remove_button = QPushButton::from_q_string(&qtr("unit_variant_remove"));
+        let mut check_paths_button = QPushButton::from_q_string(&qtr("check_paths"));
+
+        layout.add_widget_5a(&mut tree_view, 0, 0, 1, 4);
+        layout.add_widget_5a(&mut add_category_button, 1, 0, 1, 1);
+        layout.add_widget_5a(&mut add_variant_button, 1, 1, 1, 1);
+        layout.add_widget_5a(&mut remove_button, 1, 2, 1, 1);
+        layout.add_widget_5a(&mut check_paths_button, 1, 3, 1, 1);
+
+        let mut packed_file_unit_variant_view_raw = PackedFileUnitVariantViewRaw {
+            path: packed_file_view.get_path_raw(),
+            tree_view: tree_view.into_ptr(),
+            tree_model: tree_model.into_ptr(),
+            add_category_button: add_category_button.into_ptr(),
+            add_variant_button: add_variant_button.into_ptr(),
+            remove_button: remove_button.into_ptr(),
+            check_paths_button: check_paths_button.into_ptr(),
+        };
+
+        packed_file_unit_variant_view_raw.load_data(&unit_variant);
+
+        let packed_file_unit_variant_view_slots = PackedFileUnitVariantViewSlots::new(
+            &packed_file_unit_variant_view_raw,
+            *app_ui,
+            *pack_file_contents_ui,
+        );
+
+        let packed_file_unit_variant_view = Self {
+            tree_view: atomic_from_mut_ptr(packed_file_unit_variant_view_raw.tree_view),
+            tree_model: atomic_from_mut_ptr(packed_file_unit_variant_view_raw.tree_model),
+            add_category_button: atomic_from_mut_ptr(packed_file_unit_variant_view_raw.add_category_button),
+            add_variant_button: atomic_from_mut_ptr(packed_file_unit_variant_view_raw.add_variant_button),
+            remove_button: atomic_from_mut_ptr(packed_file_unit_variant_view_raw.remove_button),
+            check_paths_button: atomic_from_mut_ptr(packed_file_unit_variant_view_raw.check_paths_button),
+            unit_variant: Arc::new(RwLock::new(unit_variant)),
+        };
+
+        connections::set_connections(&packed_file_unit_variant_view, &packed_file_unit_variant_view_slots);
+        packed_file_view.view = ViewType::Internal(View::UnitVariant(packed_file_unit_variant_view));
+        packed_file_view.packed_file_type = PackedFileType::UnitVariant;
+
+        Ok((TheOneSlot::UnitVariant(packed_file_unit_variant_view_slots), packed_file_info))
+    }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    pub unsafe fn reload_view(&mut self, data: &UnitVariant) {
+        self.get_raw().load_data(data);
+        *self.unit_variant.write().unwrap() = data.clone();
+    }
+
+    /// This function saves the data of the view to a `DecodedPackedFile`.
+    pub unsafe fn save_data(&self) -> Result<DecodedPackedFile> {
+        let mut unit_variant = self.unit_variant.read().unwrap().clone();
+        unit_variant.set_categories(self.get_raw().get_data()?)?;
+        Ok(DecodedPackedFile::UnitVariant(unit_variant))
+    }
+
+    /// This function returns a copy of the raw pointers of the view.
+    fn get_raw(&self) -> PackedFileUnitVariantViewRaw {
+        PackedFileUnitVariantViewRaw {
+            path: Arc::new(RwLock::new(vec![])),
+            tree_view: self.get_mut_ptr_tree_view(),
+            tree_model: self.get_mut_ptr_tree_model(),
+            add_category_button: self.get_mut_ptr_add_category_button(),
+            add_variant_button: self.get_mut_ptr_add_variant_button(),
+            remove_button: self.get_mut_ptr_remove_button(),
+            check_paths_button: self.get_mut_ptr_check_paths_button(),
+        }
+    }
+
+    /// This function returns a pointer to the TreeView.
+    pub fn get_mut_ptr_tree_view(&self) -> MutPtr<QTreeView> {
+        mut_ptr_from_atomic(&self.tree_view)
+    }
+
+    /// This function returns a pointer to the model of the TreeView.
+    pub fn get_mut_ptr_tree_model(&self) -> MutPtr<QStandardItemModel> {
+        mut_ptr_from_atomic(&self.tree_model)
+    }
+
+    /// This function returns a pointer to the "Add Category" button.
+    pub fn get_mut_ptr_add_category_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_category_button)
+    }
+
+    /// This function returns a pointer to the "Add Variant" button.
+    pub fn get_mut_ptr_add_variant_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_variant_button)
+    }
+
+    /// This function returns a pointer to the "Remove" button.
+    pub fn get_mut_ptr_remove_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.remove_button)
+    }
+
+    /// This function returns a pointer to the "Check Paths" button.
+    pub fn get_mut_ptr_check_paths_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.check_paths_button)
+    }
+}
+
+/// Implementation for `PackedFileUnitVariantViewRaw`.
+impl PackedFileUnitVariantViewRaw {
+
+    /// This function loads the categories and variants of the provided UnitVariant into the tree.
+    pub unsafe fn load_data(&mut self, data: &UnitVariant) {
+        self.tree_model.clear();
+        for (column, key) in ["unit_variant_name", "unit_variant_id", "unit_variant_mesh_file", "unit_variant_texture_folder", "unit_variant_unknown"].iter().enumerate() {
+            self.tree_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&qtr(key)).into_ptr());
+        }
+
+        for category in data.get_ref_categories() {
+            let mut category_item = self.add_category_row(category.get_ref_name(), category.get_id());
+            for variant in category.get_ref_variants() {
+                add_variant_row(&mut category_item, variant.get_ref_mesh_file(), variant.get_ref_texture_folder(), variant.get_unknown_value());
+            }
+        }
+
+        self.tree_view.expand_all();
+        for column in 0..COLUMN_UNKNOWN {
+            self.tree_view.resize_column_to_contents(column);
+        }
+    }
+
+    /// This function returns the categories and variants in the tree.
+    pub unsafe fn get_data(&self) -> Result<Vec<Category>> {
+        let mut categories = vec![];
+        for row in 0..self.tree_model.row_count_0a() {
+            let category_item = self.tree_model.item_2a(row, COLUMN_NAME);
+            let id = self.tree_model.item_2a(row, COLUMN_ID).text().to_std_string().parse::<u64>()?;
+
+            let mut variants = vec![];
+            for variant_row in 0..category_item.row_count() {
+                let mesh_file = category_item.child_2a(variant_row, COLUMN_MESH_FILE).text().to_std_string();
+                let texture_folder = category_item.child_2a(variant_row, COLUMN_TEXTURE_FOLDER).text().to_std_string();
+                let unknown_value = category_item.child_2a(variant_row, COLUMN_UNKNOWN).text().to_std_string().parse::<u16>()?;
+                variants.push(Variant::new(&mesh_file, &texture_folder, unknown_value));
+            }
+
+            categories.push(Category::new(&category_item.text().to_std_string(), id, variants));
+        }
+
+        Ok(categories)
+    }
+
+    /// This function adds a new empty category at the end of the tree.
+    pub unsafe fn add_category(&mut self) {
+        let category_item = self.add_category_row("", 0);
+        self.tree_view.set_current_index(&category_item.index());
+        self.tree_view.scroll_to_1a(&category_item.index());
+    }
+
+    /// This function adds a new empty variant to the category of the selected item.
+    ///
+    /// It returns false if there was no category selected.
+    pub unsafe fn add_variant(&mut self) -> bool {
+        let index = self.tree_view.current_index();
+        if !index.is_valid() { return false; }
+
+        let category_index = if index.parent().is_valid() { index.parent() } else { index.sibling(index.row(), COLUMN_NAME) };
+        let mut category_item = self.tree_model.item_from_index(category_index.as_ref());
+        let variant_item = add_variant_row(&mut category_item, "", "", 0);
+        self.tree_view.expand(category_index.as_ref());
+        self.tree_view.set_current_index(&variant_item.index());
+        self.tree_view.scroll_to_1a(&variant_item.index());
+        true
+    }
+
+    /// This function removes the selected category or variant from the tree.
+    ///
+    /// It returns false if there was nothing selected.
+    pub unsafe fn remove_selected(&mut self) -> bool {
+        let index = self.tree_view.current_index();
+        if !index.is_valid() { return false; }
+
+        self.tree_model.remove_row_2a(index.row(), index.parent().as_ref());
+        true
+    }
+
+    /// This function paints the mesh files and texture folders of the tree depending on if they exist or not.
+    pub unsafe fn check_paths(&mut self) {
+        let mut items = vec![];
+        for row in 0..self.tree_model.row_count_0a() {
+            let category_item = self.tree_model.item_2a(row, COLUMN_NAME);
+            for variant_row in 0..category_item.row_count() {
+                items.push(category_item.child_2a(variant_row, COLUMN_MESH_FILE));
+                items.push(category_item.child_2a(variant_row, COLUMN_TEXTURE_FOLDER));
+            }
+        }
+
+        // Painting the items is not an edit, so don't let the view think it is.
+        let mut blocker = QSignalBlocker::from_q_object(self.tree_model);
+        let missing_count = check_paths_in_items(&mut items);
+        blocker.unblock();
+
+        log_to_status_bar(&tre("check_paths_result", &[&missing_count.to_string()]));
+    }
+
+    /// This function adds a category row at the end of the tree, returning the item of its first column.
+    unsafe fn add_category_row(&mut self, name: &str, id: u64) -> MutPtr<QStandardItem> {
+        let row = new_row(&[Some(name), Some(&id.to_string()), None, None, None]);
+        self.tree_model.append_row_q_list_of_q_standard_item(row.as_ref().unwrap());
+        self.tree_model.item_2a(self.tree_model.row_count_0a() - 1, COLUMN_NAME)
+    }
+}
+
+/// This function adds a variant row at the end of the provided category, returning the item of its first column.
+unsafe fn add_variant_row(category_item: &mut MutPtr<QStandardItem>, mesh_file: &str, texture_folder: &str, unknown_value: u16) -> MutPtr<QStandardItem> {
+    let row = new_row(&[None, None, Some(mesh_file), Some(texture_folder), Some(&unknown_value.to_string())]);
+    category_item.append_row_q_list_of_q_standard_item(row.as_ref().unwrap());
+    category_item.child_2a(category_item.row_count() - 1, COLUMN_NAME)
+}
+
+/// This function creates a row of items with the provided values. Columns without value are left empty and not editable.
+unsafe fn new_row(values: &[Option<&str>]) -> MutPtr<QListOfQStandardItem> {
+    let row = QListOfQStandardItem::new().into_ptr();
+    for value in values {
+        let item = match value {
+            Some(value) => QStandardItem::from_q_string(&QString::from_std_str(value)).into_ptr(),
+            None => {
+                let mut item = QStandardItem::new().into_ptr();
+                item.set_editable(false);
+                item
+            }
+        };
+        add_to_q_list_safe(row, item);
+    }
+    row
+}
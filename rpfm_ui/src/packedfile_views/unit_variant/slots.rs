@@ -0,0 +1,99 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for UnitVariant Views.
+!*/
+
+use qt_gui::SlotOfQStandardItem;
+
+use qt_core::Slot;
+
+use crate::app_ui::AppUI;
+use crate::packedfile_views::unit_variant::PackedFileUnitVariantViewRaw;
+use crate::packedfile_views::utils::set_modified;
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of an UnitVariant PackedFile.
+pub struct PackedFileUnitVariantViewSlots {
+    pub modified: SlotOfQStandardItem<'static>,
+    pub add_category: Slot<'static>,
+    pub add_variant: Slot<'static>,
+    pub remove: Slot<'static>,
+    pub check_paths: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileUnitVariantViewSlots`.
+impl PackedFileUnitVariantViewSlots {
+
+    /// This function creates the entire slot pack for UnitVariant Views.
+    pub unsafe fn new(packed_file_view: &PackedFileUnitVariantViewRaw, app_ui: AppUI, pack_file_contents_ui: PackFileContentsUI) -> Self {
+
+        // When any of the items of the tree gets edited, mark the PackedFile as modified.
+        let modified = SlotOfQStandardItem::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move |_| {
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to add a new category at the end of the tree.
+        let add_category = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            packed_file_view.add_category();
+            set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+        }));
+
+        // Slot to add a new variant to the selected category.
+        let add_variant = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            if packed_file_view.add_variant() {
+                set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+            }
+        }));
+
+        // Slot to remove the selected category or variant.
+        let remove = Slot::new(clone!(
+            mut packed_file_view,
+            mut app_ui,
+            mut pack_file_contents_ui => move || {
+            if packed_file_view.remove_selected() {
+                set_modified(true, &packed_file_view.path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+            }
+        }));
+
+        // Slot to highlight the mesh files and texture folders that don't exist.
+        let check_paths = Slot::new(clone!(
+            mut packed_file_view => move || {
+            packed_file_view.check_paths();
+        }));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            modified,
+            add_category,
+            add_variant,
+            remove,
+            check_paths,
+        }
+    }
+}
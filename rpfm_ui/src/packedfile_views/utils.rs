@@ -12,10 +12,18 @@
 Module with extra functions for `PackedFileView`.
 !*/
 
+use qt_gui::QBrush;
+use qt_gui::QStandardItem;
+
+use cpp_core::MutPtr;
+
 use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::pack_tree::*;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::UI_STATE;
+use crate::views::table::utils::{get_color_correct_key, get_color_wrong_key, is_path_from_packfile_extra};
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -23,6 +31,10 @@ use crate::UI_STATE;
 
 /// This function sets the `is_modified` state of the open PackFile, setting also the visual state of the provided PackedFile in the process.
 pub unsafe fn set_modified(is_modified: bool, path: &[String], app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI) {
+
+    // Tables of extra PackFiles are not part of the open PackFile, so they never modify it.
+    if is_path_from_packfile_extra(path) { return; }
+
     let path = if path.is_empty() { TreePathType::PackFile } else { TreePathType::File(path.to_vec()) };
     if is_modified {
         pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(vec![path; 1]));
@@ -32,3 +44,32 @@ pub unsafe fn set_modified(is_modified: bool, path: &[String], app_ui: &mut AppU
         pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Undo(vec![path; 1]));
     }
 }
+
+/// This function checks if the paths in the provided items exist in the open PackFile or in its dependencies, and paints the items accordingly.
+///
+/// Empty items are ignored. It returns the amount of items with paths that couldn't be found.
+pub unsafe fn check_paths_in_items(items: &mut [MutPtr<QStandardItem>]) -> usize {
+    let paths = items.iter().map(|item| item.text().to_std_string()).filter(|path| !path.is_empty()).collect::<Vec<String>>();
+    CENTRAL_COMMAND.send_message_qt(Command::GetMissingPaths(paths));
+    let response = CENTRAL_COMMAND.recv_message_qt_try();
+    let missing_paths = match response {
+        Response::VecString(missing_paths) => missing_paths,
+        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+    };
+
+    let mut missing_count = 0;
+    for item in items.iter_mut() {
+        let path = item.text().to_std_string();
+        if path.is_empty() { continue; }
+
+        if missing_paths.contains(&path) {
+            item.set_foreground(&QBrush::from_q_color(get_color_wrong_key().as_ref().unwrap()));
+            missing_count += 1;
+        }
+        else {
+            item.set_foreground(&QBrush::from_q_color(get_color_correct_key().as_ref().unwrap()));
+        }
+    }
+
+    missing_count
+}
@@ -0,0 +1,29 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileVariantMeshView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileVariantMeshView` and `PackedFileVariantMeshViewSlots` structs.
+!*/
+
+use super::{PackedFileVariantMeshView, slots::PackedFileVariantMeshViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileVariantMeshView` with their slots in `PackedFileVariantMeshViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &PackedFileVariantMeshView, slots: &PackedFileVariantMeshViewSlots) {
+    ui.get_mut_ptr_tree_model().item_changed().connect(&slots.modified);
+    ui.get_mut_ptr_add_element_button().released().connect(&slots.add_element);
+    ui.get_mut_ptr_add_attribute_button().released().connect(&slots.add_attribute);
+    ui.get_mut_ptr_remove_button().released().connect(&slots.remove);
+    ui.get_mut_ptr_check_paths_button().released().connect(&slots.check_paths);
+}
@@ -0,0 +1,412 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the structured view for VariantMeshDefinition PackedFiles.
+
+VariantMeshDefinitions are Text PackedFiles, so if they cannot be parsed into elements and attributes,
+they're opened in the normal Text View instead.
+!*/
+
+use qt_widgets::QGridLayout;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QSignalBlocker;
+use qt_core::QString;
+use qt_core::QVariant;
+
+use cpp_core::MutPtr;
+
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicPtr;
+
+use rpfm_error::{Result, ErrorKind};
+use rpfm_lib::packedfile::{DecodedPackedFile, PackedFileType};
+use rpfm_lib::packedfile::text::{Text, TextType};
+use rpfm_lib::packedfile::variant_mesh_definition::{is_path, VariantMeshDefinition, XmlElement};
+use rpfm_lib::packfile::packedfile::PackedFileInfo;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+use crate::ffi::add_to_q_list_safe;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::{qtr, tre};
+use crate::packedfile_views::{PackedFileView, TheOneSlot, View, ViewType};
+use crate::packedfile_views::utils::check_paths_in_items;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{atomic_from_mut_ptr, log_to_status_bar, mut_ptr_from_atomic};
+
+use self::slots::PackedFileVariantMeshViewSlots;
+
+mod connections;
+pub mod slots;
+
+/// Role used to mark the rows that are attributes, instead of elements.
+const ITEM_IS_ATTRIBUTE: i32 = 40;
+
+/// Columns of the tree.
+const COLUMN_NAME: i32 = 0;
+const COLUMN_VALUE: i32 = 1;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the structured view of a VariantMeshDefinition PackedFile.
+pub struct PackedFileVariantMeshView {
+    tree_view: AtomicPtr<QTreeView>,
+    tree_model: AtomicPtr<QStandardItemModel>,
+    add_element_button: AtomicPtr<QPushButton>,
+    add_attribute_button: AtomicPtr<QPushButton>,
+    remove_button: AtomicPtr<QPushButton>,
+    check_paths_button: AtomicPtr<QPushButton>,
+
+    variant_mesh: Arc<RwLock<VariantMeshDefinition>>,
+}
+
+/// This struct contains the raw version of each pointer in `PackedFileVariantMeshView`, to be used when building the slots.
+///
+/// This is kinda a hack, because AtomicPtr cannot be copied, and we need a copy of the entire set of pointers available
+/// for the construction of the slots. So we build this one, copy it for the slots, then move it into the `PackedFileVariantMeshView`.
+#[derive(Clone)]
+pub struct PackedFileVariantMeshViewRaw {
+    pub path: Arc<RwLock<Vec<String>>>,
+    pub tree_view: MutPtr<QTreeView>,
+    pub tree_model: MutPtr<QStandardItemModel>,
+    pub add_element_button: MutPtr<QPushButton>,
+    pub add_attribute_button: MutPtr<QPushButton>,
+    pub remove_button: MutPtr<QPushButton>,
+    pub check_paths_button: MutPtr<QPushButton>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileVariantMeshView`.
+impl PackedFileVariantMeshView {
+
+    /// This function creates a new VariantMeshDefinition View, and sets up his slots and connections.
+    ///
+    /// If the VariantMeshDefinition cannot be parsed, it returns an error without touching the `PackedFileView`.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        app_ui: &AppUI,
+        _global_search_ui: &GlobalSearchUI,
+        pack_file_contents_ui: &PackFileContentsUI,
+    ) -> Result<(TheOneSlot, PackedFileInfo)> {
+
+        CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(packed_file_view.get_path()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let (text, packed_file_info) = match response {
+            Response::TextPackedFileInfo((text, packed_file_info)) => (text, packed_file_info),
+            Response::Error(error) => return Err(error),
+            Response::Unknown => return Err(ErrorKind::PackedFileTypeUnknown.into()),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let variant_mesh = VariantMeshDefinition::read(text.get_ref_contents())?;
+
+        let mut layout: MutPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast_mut();
+
+        let mut tree_view = QTreeView::new_0a();
+        let mut tree_model = QStandardItemModel::new_0a();
+        tree_view.set_model(&mut tree_model);
+        tree_view.set_sorting_enabled(false);
+        tree_view.header().set_stretch_last_section(true);
+
+        let mut add_element_button = QPushButton::from_q_string(&qtr("variant_mesh_add_element"));
+        let mut add_attribute_button = QPushButton::from_q_string(&qtr("variant_mesh_add_attribute"));
+        let mut remove_button = QPushButton::from_q_string(&qtr("variant_mesh_remove"));
+        let mut check_paths_button = QPushButton::from_q_string(&qtr("check_paths"));
+
+        layout.add_widget_5a(&mut tree_view, 0, 0, 1, 4);
+        layout.add_widget_5a(&mut add_element_button, 1, 0, 1, 1);
+        layout.add_widget_5a(&mut add_attribute_button, 1, 1, 1, 1);
+        layout.add_widget_5a(&mut remove_button, 1, 2, 1, 1);
+        layout.add_widget_5a(&mut check_paths_button, 1, 3, 1, 1);
+
+        let mut packed_file_variant_mesh_view_raw = PackedFileVariantMeshViewRaw {
+            path: packed_file_view.get_path_raw(),
+            tree_view: tree_view.into_ptr(),
+            tree_model: tree_model.into_ptr(),
+            add_element_button: add_element_button.into_ptr(),
+            add_attribute_button: add_attribute_button.into_ptr(),
+            remove_button: remove_button.into_ptr(),
+            check_paths_button: check_paths_button.into_ptr(),
+        };
+
+        packed_file_variant_mesh_view_raw.load_data(&variant_mesh);
+
+        let packed_file_variant_mesh_view_slots = PackedFileVariantMeshViewSlots::new(
+            &packed_file_variant_mesh_view_raw,
+            *app_ui,
+            *pack_file_contents_ui,
+        );
+
+        let packed_file_variant_mesh_view = Self {
+            tree_view: atomic_from_mut_ptr(packed_file_variant_mesh_view_raw.tree_view),
+            tree_model: atomic_from_mut_ptr(packed_file_variant_mesh_view_raw.tree_model),
+            add_element_button: atomic_from_mut_ptr(packed_file_variant_mesh_view_raw.add_element_button),
+            add_attribute_button: atomic_from_mut_ptr(packed_file_variant_mesh_view_raw.add_attribute_button),
+            remove_button: atomic_from_mut_ptr(packed_file_variant_mesh_view_raw.remove_button),
+            check_paths_button: atomic_from_mut_ptr(packed_file_variant_mesh_view_raw.check_paths_button),
+            variant_mesh: Arc::new(RwLock::new(variant_mesh)),
+        };
+
+        connections::set_connections(&packed_file_variant_mesh_view, &packed_file_variant_mesh_view_slots);
+        packed_file_view.view = ViewType::Internal(View::VariantMesh(packed_file_variant_mesh_view));
+        packed_file_view.packed_file_type = PackedFileType::Text(TextType::Xml);
+
+        Ok((TheOneSlot::VariantMesh(packed_file_variant_mesh_view_slots), packed_file_info))
+    }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    pub unsafe fn reload_view(&mut self, data: &Text) -> Result<()> {
+        let variant_mesh = VariantMeshDefinition::read(data.get_ref_contents())?;
+        self.get_raw().load_data(&variant_mesh);
+        *self.variant_mesh.write().unwrap() = variant_mesh;
+        Ok(())
+    }
+
+    /// This function saves the data of the view to a `DecodedPackedFile`.
+    pub unsafe fn save_data(&self) -> Result<DecodedPackedFile> {
+        let mut variant_mesh = self.variant_mesh.read().unwrap().clone();
+        variant_mesh.set_root(self.get_raw().get_data());
+
+        let mut text = Text::default();
+        text.set_text_type(TextType::Xml);
+        text.set_contents(&variant_mesh.save()?);
+        Ok(DecodedPackedFile::Text(text))
+    }
+
+    /// This function returns a copy of the raw pointers of the view.
+    fn get_raw(&self) -> PackedFileVariantMeshViewRaw {
+        PackedFileVariantMeshViewRaw {
+            path: Arc::new(RwLock::new(vec![])),
+            tree_view: self.get_mut_ptr_tree_view(),
+            tree_model: self.get_mut_ptr_tree_model(),
+            add_element_button: self.get_mut_ptr_add_element_button(),
+            add_attribute_button: self.get_mut_ptr_add_attribute_button(),
+            remove_button: self.get_mut_ptr_remove_button(),
+            check_paths_button: self.get_mut_ptr_check_paths_button(),
+        }
+    }
+
+    /// This function returns a pointer to the TreeView.
+    pub fn get_mut_ptr_tree_view(&self) -> MutPtr<QTreeView> {
+        mut_ptr_from_atomic(&self.tree_view)
+    }
+
+    /// This function returns a pointer to the model of the TreeView.
+    pub fn get_mut_ptr_tree_model(&self) -> MutPtr<QStandardItemModel> {
+        mut_ptr_from_atomic(&self.tree_model)
+    }
+
+    /// This function returns a pointer to the "Add Element" button.
+    pub fn get_mut_ptr_add_element_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_element_button)
+    }
+
+    /// This function returns a pointer to the "Add Attribute" button.
+    pub fn get_mut_ptr_add_attribute_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.add_attribute_button)
+    }
+
+    /// This function returns a pointer to the "Remove" button.
+    pub fn get_mut_ptr_remove_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.remove_button)
+    }
+
+    /// This function returns a pointer to the "Check Paths" button.
+    pub fn get_mut_ptr_check_paths_button(&self) -> MutPtr<QPushButton> {
+        mut_ptr_from_atomic(&self.check_paths_button)
+    }
+}
+
+/// Implementation for `PackedFileVariantMeshViewRaw`.
+impl PackedFileVariantMeshViewRaw {
+
+    /// This function loads the elements and attributes of the provided VariantMeshDefinition into the tree.
+    pub unsafe fn load_data(&mut self, data: &VariantMeshDefinition) {
+        self.tree_model.clear();
+        self.tree_model.set_horizontal_header_item(COLUMN_NAME, QStandardItem::from_q_string(&qtr("variant_mesh_name")).into_ptr());
+        self.tree_model.set_horizontal_header_item(COLUMN_VALUE, QStandardItem::from_q_string(&qtr("variant_mesh_value")).into_ptr());
+
+        let row = new_element_row(data.get_ref_root().get_ref_name());
+        self.tree_model.append_row_q_list_of_q_standard_item(row.as_ref().unwrap());
+        let mut root_item = self.tree_model.item_2a(0, COLUMN_NAME);
+        load_element(&mut root_item, data.get_ref_root());
+
+        self.tree_view.expand_all();
+        self.tree_view.resize_column_to_contents(COLUMN_NAME);
+    }
+
+    /// This function returns the root element in the tree, with all its attributes and children.
+    pub unsafe fn get_data(&self) -> XmlElement {
+        get_element(self.tree_model.item_2a(0, COLUMN_NAME))
+    }
+
+    /// This function adds a new empty element to the selected element, or to the element of the selected attribute.
+    ///
+    /// It returns false if there was nothing selected.
+    pub unsafe fn add_element(&mut self) -> bool {
+        let mut element_item = match self.get_selected_element() {
+            Some(element_item) => element_item,
+            None => return false,
+        };
+
+        let row = new_element_row("");
+        element_item.append_row_q_list_of_q_standard_item(row.as_ref().unwrap());
+        let new_item = element_item.child_2a(element_item.row_count() - 1, COLUMN_NAME);
+        self.tree_view.expand(&element_item.index());
+        self.tree_view.set_current_index(&new_item.index());
+        self.tree_view.scroll_to_1a(&new_item.index());
+        true
+    }
+
+    /// This function adds a new empty attribute to the selected element, or to the element of the selected attribute.
+    ///
+    /// It returns false if there was nothing selected.
+    pub unsafe fn add_attribute(&mut self) -> bool {
+        let mut element_item = match self.get_selected_element() {
+            Some(element_item) => element_item,
+            None => return false,
+        };
+
+        // Attributes go after the other attributes, but before the child elements.
+        let position = (0..element_item.row_count()).take_while(|row| is_attribute(element_item.child_2a(*row, COLUMN_NAME))).count() as i32;
+        let row = new_attribute_row("", "");
+        element_item.insert_row_int_q_list_of_q_standard_item(position, row.as_ref().unwrap());
+        let new_item = element_item.child_2a(position, COLUMN_NAME);
+        self.tree_view.expand(&element_item.index());
+        self.tree_view.set_current_index(&new_item.index());
+        self.tree_view.scroll_to_1a(&new_item.index());
+        true
+    }
+
+    /// This function removes the selected element or attribute from the tree. The root element cannot be removed.
+    ///
+    /// It returns false if there was nothing removable selected.
+    pub unsafe fn remove_selected(&mut self) -> bool {
+        let index = self.tree_view.current_index();
+        if !index.is_valid() || !index.parent().is_valid() { return false; }
+
+        self.tree_model.remove_row_2a(index.row(), index.parent().as_ref());
+        true
+    }
+
+    /// This function paints the attribute values that look like paths depending on if they exist or not.
+    pub unsafe fn check_paths(&mut self) {
+        let mut items = vec![];
+        get_path_items(self.tree_model.item_2a(0, COLUMN_NAME), &mut items);
+
+        // Painting the items is not an edit, so don't let the view think it is.
+        let mut blocker = QSignalBlocker::from_q_object(self.tree_model);
+        let missing_count = check_paths_in_items(&mut items);
+        blocker.unblock();
+
+        log_to_status_bar(&tre("check_paths_result", &[&missing_count.to_string()]));
+    }
+
+    /// This function returns the item of the selected element, or of the element of the selected attribute, if any.
+    unsafe fn get_selected_element(&self) -> Option<MutPtr<QStandardItem>> {
+        let index = self.tree_view.current_index();
+        if !index.is_valid() { return None; }
+
+        let index = index.sibling(index.row(), COLUMN_NAME);
+        let item = self.tree_model.item_from_index(index.as_ref());
+        if is_attribute(item) {
+            Some(self.tree_model.item_from_index(index.parent().as_ref()))
+        } else { Some(item) }
+    }
+}
+
+/// This function adds the attributes and children of the provided element as children of its item.
+unsafe fn load_element(element_item: &mut MutPtr<QStandardItem>, element: &XmlElement) {
+    for (name, value) in element.get_ref_attributes() {
+        let row = new_attribute_row(name, value);
+        element_item.append_row_q_list_of_q_standard_item(row.as_ref().unwrap());
+    }
+
+    for child in element.get_ref_children() {
+        let row = new_element_row(child.get_ref_name());
+        element_item.append_row_q_list_of_q_standard_item(row.as_ref().unwrap());
+        let mut child_item = element_item.child_2a(element_item.row_count() - 1, COLUMN_NAME);
+        load_element(&mut child_item, child);
+    }
+}
+
+/// This function builds an element from its item in the tree.
+unsafe fn get_element(element_item: MutPtr<QStandardItem>) -> XmlElement {
+    let mut attributes = vec![];
+    let mut children = vec![];
+    for row in 0..element_item.row_count() {
+        let item = element_item.child_2a(row, COLUMN_NAME);
+        if is_attribute(item) {
+            attributes.push((item.text().to_std_string(), element_item.child_2a(row, COLUMN_VALUE).text().to_std_string()));
+        } else {
+            children.push(get_element(item));
+        }
+    }
+
+    XmlElement::new(&element_item.text().to_std_string(), attributes, children)
+}
+
+/// This function adds the value items of the attributes of the provided element and its children that look like paths to the provided list.
+unsafe fn get_path_items(element_item: MutPtr<QStandardItem>, items: &mut Vec<MutPtr<QStandardItem>>) {
+    for row in 0..element_item.row_count() {
+        let item = element_item.child_2a(row, COLUMN_NAME);
+        if is_attribute(item) {
+            let value_item = element_item.child_2a(row, COLUMN_VALUE);
+            if is_path(&value_item.text().to_std_string()) {
+                items.push(value_item);
+            }
+        } else {
+            get_path_items(item, items);
+        }
+    }
+}
+
+/// This function returns if the provided item is the name of an attribute.
+unsafe fn is_attribute(item: MutPtr<QStandardItem>) -> bool {
+    item.data_1a(ITEM_IS_ATTRIBUTE).to_bool()
+}
+
+/// This function creates the items of an element row.
+unsafe fn new_element_row(name: &str) -> MutPtr<QListOfQStandardItem> {
+    let row = QListOfQStandardItem::new().into_ptr();
+    let mut name = QStandardItem::from_q_string(&QString::from_std_str(name)).into_ptr();
+    let mut value = QStandardItem::new().into_ptr();
+    name.set_data_2a(&QVariant::from_bool(false), ITEM_IS_ATTRIBUTE);
+    value.set_editable(false);
+
+    add_to_q_list_safe(row, name);
+    add_to_q_list_safe(row, value);
+    row
+}
+
+/// This function creates the items of an attribute row.
+unsafe fn new_attribute_row(name: &str, value: &str) -> MutPtr<QListOfQStandardItem> {
+    let row = QListOfQStandardItem::new().into_ptr();
+    let mut name = QStandardItem::from_q_string(&QString::from_std_str(name)).into_ptr();
+    let value = QStandardItem::from_q_string(&QString::from_std_str(value)).into_ptr();
+    name.set_data_2a(&QVariant::from_bool(true), ITEM_IS_ATTRIBUTE);
+
+    add_to_q_list_safe(row, name);
+    add_to_q_list_safe(row, value);
+    row
+}
@@ -14,6 +14,8 @@ Module with all the code to connect `PackFileContentsUI` signals with their corr
 This module is, and should stay, private, as it's only glue between the `PackFileContentsUI` and `PackFileContentsSlots` structs.
 !*/
 
+use crate::ffi::{packed_file_treeview_drag_out_requested_signal, packed_file_treeview_files_dropped_signal, packed_file_treeview_items_moved_signal};
+
 use super::{PackFileContentsUI, slots::PackFileContentsSlots};
 
 /// This function connects all the actions from the provided `PackFileContentsUI` with their slots in `PackFileContentsSlots`.
@@ -42,7 +44,11 @@ pub unsafe fn set_connections(ui: &PackFileContentsUI, slots: &PackFileContentsS
     ui.context_menu_add_from_packfile.triggered().connect(&slots.contextual_menu_add_from_packfile);
     ui.context_menu_delete.triggered().connect(&slots.contextual_menu_delete);
     ui.context_menu_extract.triggered().connect(&slots.contextual_menu_extract);
+    ui.context_menu_extract_with_path_rewrite.triggered().connect(&slots.contextual_menu_extract_with_path_rewrite);
+    ui.context_menu_extract_converted.triggered().connect(&slots.contextual_menu_extract_converted);
     ui.context_menu_rename.triggered().connect(&slots.contextual_menu_rename);
+    ui.context_menu_mass_rename.triggered().connect(&slots.contextual_menu_mass_rename);
+    ui.context_menu_undo_move.triggered().connect(&slots.contextual_menu_undo_move);
 
     ui.context_menu_new_folder.triggered().connect(&slots.contextual_menu_new_folder);
     ui.context_menu_new_packed_file_db.triggered().connect(&slots.contextual_menu_new_packed_file_db);
@@ -59,10 +65,15 @@ pub unsafe fn set_connections(ui: &PackFileContentsUI, slots: &PackFileContentsS
     ui.context_menu_check_tables.triggered().connect(&slots.contextual_menu_tables_check_integrity);
     ui.context_menu_merge_tables.triggered().connect(&slots.contextual_menu_tables_merge_tables);
     ui.context_menu_update_table.triggered().connect(&slots.contextual_menu_tables_update_table);
+    ui.context_menu_bind_folder_to_table.triggered().connect(&slots.contextual_menu_bind_folder_to_table);
 
     ui.context_menu_mass_import_tsv.triggered().connect(&slots.contextual_menu_mass_import_tsv);
     ui.context_menu_mass_export_tsv.triggered().connect(&slots.contextual_menu_mass_export_tsv);
 
     ui.packfile_contents_tree_view_expand_all.triggered().connect(&slots.packfile_contents_tree_view_expand_all);
     ui.packfile_contents_tree_view_collapse_all.triggered().connect(&slots.packfile_contents_tree_view_collapse_all);
+
+    packed_file_treeview_files_dropped_signal(ui.packfile_contents_tree_view).connect(&slots.packfile_contents_tree_view_files_dropped);
+    packed_file_treeview_drag_out_requested_signal(ui.packfile_contents_tree_view).connect(&slots.packfile_contents_tree_view_drag_out);
+    packed_file_treeview_items_moved_signal(ui.packfile_contents_tree_view).connect(&slots.packfile_contents_tree_view_items_moved);
 }
@@ -17,34 +17,65 @@ that one, as it's mostly meant for initialization and configuration.
 !*/
 
 use qt_widgets::QCheckBox;
+use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
-use qt_widgets::{QFileDialog, q_file_dialog::FileMode};
+use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
 use qt_widgets::QGroupBox;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
+use qt_widgets::{q_message_box, QMessageBox};
 use qt_widgets::QPushButton;
+use qt_widgets::{QTableView, q_abstract_item_view::EditTrigger};
+use qt_widgets::QTreeView;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
 
 use qt_core::CaseSensitivity;
+use qt_core::QFlags;
+use qt_core::QModelIndex;
+use qt_core::QObject;
 use qt_core::QRegExp;
+use qt_core::QSignalBlocker;
 use qt_core::QString;
+use qt_core::QStringList;
 use qt_core::Slot;
+use qt_core::{SlotOfBool, SlotOfInt, SlotOfQString};
+
+use cpp_core::MutPtr;
+use cpp_core::Ref;
 
 use std::cell::RefCell;
+use std::env::temp_dir;
+use std::fs::{read_dir, remove_dir_all, DirBuilder};
 use std::path::PathBuf;
 use std::rc::Rc;
 
-use rpfm_lib::packfile::PathType;
+use rpfm_error::ErrorKind;
+use rpfm_lib::common::get_files_from_subdir;
+use rpfm_lib::packedfile::PackedFileType;
+use rpfm_lib::packfile::{PackFile, PathType};
+use rpfm_lib::packfile::mass_rename::{CaseTransform, MassRename};
+use rpfm_lib::schema::Definition;
+use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::{LAST_PATH_EXTRACT, LAST_PATH_IMPORT_TSV, PATH_REWRITE_REGEX, PATH_REWRITE_REPLACEMENT};
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
-use crate::ffi::trigger_treeview_filter_safe;
+use crate::ffi::{add_to_q_list_safe, get_dropped_paths_safe, set_drag_out_paths_safe, trigger_treeview_filter_safe};
 use crate::global_search_ui::GlobalSearchUI;
-use crate::locale::{qtr, qtre};
+use crate::locale::{qtr, qtre, tr};
 use crate::pack_tree::{PackTree, TreePathType, TreeViewOperation};
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::utils::{create_grid_layout, show_dialog};
+use crate::utils::{catch_panic_in_slot, create_grid_layout, Problems, save_last_path, save_setting_string, setup_file_dialog, show_dialog};
+use crate::views::table::utils::clean_column_names;
 use crate::UI_STATE;
+use crate::ui_state::op_mode::OperationalMode;
+
+/// Name of the temporal folder where the PackedFiles dragged out of the PackFile Contents TreeView are extracted to.
+const DRAG_OUT_FOLDER: &str = "rpfm_drag_out";
 
 //-------------------------------------------------------------------------------//
 //                             Implementations
@@ -54,6 +85,8 @@ use crate::UI_STATE;
 impl PackFileContentsUI {
 
     /// This function is a helper to add PackedFiles to the UI, keeping the UI updated.
+    ///
+    /// If any of the PackedFiles already exists in the PackFile, it asks the user what to do with it first.
     pub unsafe fn add_packedfiles(
         &mut self,
         app_ui: &mut AppUI,
@@ -61,32 +94,47 @@ impl PackFileContentsUI {
         paths: &[PathBuf],
         paths_packedfile: &[Vec<String>]
     ) {
+
+        // Ask what to do with the files that'll conflict with existing ones. If the user cancels, we add nothing.
+        let (paths, paths_packedfile, overwrite) = match Self::resolve_add_conflicts(app_ui, paths, paths_packedfile) {
+            Some(data) => data,
+            None => return,
+        };
+
+        if paths.is_empty() { return; }
+
         app_ui.main_window.set_enabled(false);
 
-        CENTRAL_COMMAND.send_message_qt(Command::AddPackedFiles((paths.to_vec(), paths_packedfile.to_vec())));
+        CENTRAL_COMMAND.send_message_qt(Command::AddPackedFiles((paths, paths_packedfile, overwrite)));
         let response = CENTRAL_COMMAND.recv_message_qt();
         match response {
-            Response::Success => {
-                let paths = paths_packedfile.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
-                self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()));
-                self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()));
-                UI_STATE.set_is_modified(true, app_ui, self);
+            Response::VecVecStringVecStringError((paths_packedfile, errors)) => {
+                if !paths_packedfile.is_empty() {
+                    let paths = paths_packedfile.iter().map(|x| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+                    self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()));
+                    self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()));
+                    UI_STATE.set_is_modified(true, app_ui, self);
 
-                // Update the global search stuff, if needed.
-                global_search_ui.search_on_path(self, paths.iter().map(From::from).collect());
+                    // Update the global search stuff, if needed.
+                    global_search_ui.search_on_path(self, paths.iter().map(From::from).collect());
 
-                // Try to reload all open files which data we altered, and close those that failed.
-                let mut open_packedfiles = UI_STATE.set_open_packedfiles();
-                paths_packedfile.iter().for_each(|path| {
-                    if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path) {
-                        if packed_file_view.reload(path, self).is_err() {
-                            let _ = app_ui.purge_that_one_specifically(*global_search_ui, *self, path, false);
+                    // Try to reload all open files which data we altered, and close those that failed.
+                    let mut open_packedfiles = UI_STATE.set_open_packedfiles();
+                    paths_packedfile.iter().for_each(|path| {
+                        if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path) {
+                            if packed_file_view.reload(path, self).is_err() {
+                                let _ = app_ui.purge_that_one_specifically(*global_search_ui, *self, path, false);
+                            }
                         }
-                    }
-                });
+                    });
+                }
+
+                // Report the files we couldn't add all at once, after adding the rest.
+                let mut problems = Problems::default();
+                errors.iter().for_each(|(path, error)| problems.push(path, error));
+                problems.report(app_ui.main_window);
             }
 
-            Response::Error(error) => show_dialog(app_ui.main_window, error, false),
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         }
 
@@ -96,39 +144,106 @@ impl PackFileContentsUI {
 
     /// This function is a helper to add entire folders with subfolders to the UI, keeping the UI updated.
     pub unsafe fn add_packed_files_from_folders(&mut self, app_ui: &mut AppUI, global_search_ui: &mut GlobalSearchUI, paths: &[PathBuf], paths_packedfile: &[Vec<String>]) {
-        app_ui.main_window.set_enabled(false);
-        let paths_to_send = paths.iter().cloned().zip(paths_packedfile.iter().cloned()).collect();
-        CENTRAL_COMMAND.send_message_qt(Command::AddPackedFilesFromFolder(paths_to_send));
+        let paths_to_expand = paths.iter().cloned().zip(paths_packedfile.iter().cloned()).collect::<Vec<(PathBuf, Vec<String>)>>();
+        match PackFile::get_files_from_folders(&paths_to_expand) {
+            Ok(files) => {
+                let (paths, paths_packedfile): (Vec<PathBuf>, Vec<Vec<String>>) = files.into_iter().unzip();
+                self.add_packedfiles(app_ui, global_search_ui, &paths, &paths_packedfile);
+            }
+            Err(error) => show_dialog(app_ui.main_window, error, false),
+        }
+    }
+
+    /// This function asks the user what to do with each file to add whose path is already in use in the open PackFile.
+    ///
+    /// It returns the files to add, their paths within the PackFile and if they should overwrite the existing PackedFiles,
+    /// or None if the user cancelled the operation.
+    unsafe fn resolve_add_conflicts(
+        app_ui: &mut AppUI,
+        paths: &[PathBuf],
+        paths_packedfile: &[Vec<String>]
+    ) -> Option<(Vec<PathBuf>, Vec<Vec<String>>, Vec<bool>)> {
+        CENTRAL_COMMAND.send_message_qt(Command::GetExistingPackedFilePaths(paths_packedfile.to_vec()));
         let response = CENTRAL_COMMAND.recv_message_qt();
-        match response {
-            Response::VecPathType(paths_packedfile) => {
-                let paths = paths_packedfile.iter().map(From::from).collect::<Vec<TreePathType>>();
-                self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()));
-                self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()));
-                UI_STATE.set_is_modified(true, app_ui, self);
+        let existing_paths = if let Response::VecVecString(paths) = response { paths } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
 
-                // Update the global search stuff, if needed.
-                global_search_ui.search_on_path(self, paths.iter().map(From::from).collect());
+        let mut paths_to_add = vec![];
+        let mut paths_packedfile_to_add = vec![];
+        let mut overwrite = vec![];
+        let mut choice_for_all = None;
+        for (path, path_packedfile) in paths.iter().zip(paths_packedfile.iter()) {
+            if !existing_paths.contains(path_packedfile) {
+                paths_to_add.push(path.to_path_buf());
+                paths_packedfile_to_add.push(path_packedfile.to_vec());
+                overwrite.push(true);
+                continue;
+            }
 
-                // Try to reload all open files which data we altered, and close those that failed.
-                let mut open_packedfiles = UI_STATE.set_open_packedfiles();
-                paths_packedfile.iter().for_each(|path| {
-                    if let PathType::File(path) = path {
-                        if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path) {
-                            if packed_file_view.reload(path, self).is_err() {
-                                let _ = app_ui.purge_that_one_specifically(*global_search_ui, *self, path, false);
-                            }
-                        }
+            let choice = match choice_for_all {
+                Some(choice) => choice,
+                None => {
+                    let mut dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                        q_message_box::Icon::Warning,
+                        &qtr("rpfm_title"),
+                        &qtre("add_conflict_text", &[&path_packedfile.join("/")]),
+                        QFlags::from(q_message_box::StandardButton::Cancel),
+                        app_ui.main_window,
+                    );
+
+                    dialog.add_button_q_string_button_role(&qtr("add_conflict_overwrite"), q_message_box::ButtonRole::AcceptRole);
+                    dialog.add_button_q_string_button_role(&qtr("add_conflict_skip"), q_message_box::ButtonRole::RejectRole);
+                    dialog.add_button_q_string_button_role(&qtr("add_conflict_rename"), q_message_box::ButtonRole::ActionRole);
+                    dialog.add_button_q_string_button_role(&qtr("add_conflict_keep_both"), q_message_box::ButtonRole::ActionRole);
+
+                    let apply_to_all = QCheckBox::from_q_string(&qtr("add_conflict_apply_to_all")).into_ptr();
+                    dialog.set_check_box(apply_to_all);
+
+                    let choice = dialog.exec();
+                    if apply_to_all.is_checked() {
+                        choice_for_all = Some(choice);
                     }
-                });
-            }
+                    choice
+                }
+            };
 
-            Response::Error(error) => show_dialog(app_ui.main_window, error, false),
-            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            match choice {
+
+                // Overwrite.
+                0 => {
+                    paths_to_add.push(path.to_path_buf());
+                    paths_packedfile_to_add.push(path_packedfile.to_vec());
+                    overwrite.push(true);
+                }
+
+                // Skip.
+                1 => {},
+
+                // Rename. If the user doesn't give us a name, we skip the file.
+                2 => {
+                    if let Some(name) = Self::create_rename_dialog(app_ui, &[TreePathType::File(path_packedfile.to_vec())]) {
+                        let mut new_path = path_packedfile.to_vec();
+                        let new_name = name.replace("{x}", new_path.last().unwrap());
+                        *new_path.last_mut().unwrap() = new_name;
+
+                        paths_to_add.push(path.to_path_buf());
+                        paths_packedfile_to_add.push(new_path);
+                        overwrite.push(false);
+                    }
+                }
+
+                // Keep both. The lib will add a numeric suffix to the new one.
+                3 => {
+                    paths_to_add.push(path.to_path_buf());
+                    paths_packedfile_to_add.push(path_packedfile.to_vec());
+                    overwrite.push(false);
+                }
+
+                // Cancel.
+                _ => return None,
+            }
         }
 
-        // Re-enable the Main Window.
-        app_ui.main_window.set_enabled(true);
+        Some((paths_to_add, paths_packedfile_to_add, overwrite))
     }
 
     /// Function to filter the PackFile Contents TreeView.
@@ -151,6 +266,414 @@ impl PackFileContentsUI {
         }
     }
 
+    /// This function extracts the selected items of the TreeView, applying the provided path rewrite rule (regex, replacement) if any.
+    ///
+    /// In MyMod mode they're extracted to the MyMod's folder. In normal mode, we ask the user where to extract them.
+    pub unsafe fn extract_packed_files(&mut self, app_ui: &mut AppUI, global_search_ui: GlobalSearchUI, path_rewrite: Option<(String, String)>, convert: bool) {
+
+        // Get the currently selected paths (and visible) paths.
+        let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(self);
+        let selected_items = selected_items.iter().map(From::from).collect::<Vec<PathType>>();
+        let extraction_path = match UI_STATE.get_operational_mode() {
+
+            // In MyMod mode we extract directly to the folder of the selected MyMod, keeping the folder structure.
+            OperationalMode::MyMod(ref game_folder_name, ref mod_name) => {
+                if let Some(ref mymods_base_path) = SETTINGS.read().unwrap().paths["mymods_base_path"] {
+
+                    // We get the assets folder of our mod (without .pack extension). This mess removes the .pack.
+                    let mut mod_name = mod_name.to_owned();
+                    mod_name.pop();
+                    mod_name.pop();
+                    mod_name.pop();
+                    mod_name.pop();
+                    mod_name.pop();
+
+                    let mut assets_folder = mymods_base_path.to_path_buf();
+                    assets_folder.push(&game_folder_name);
+                    assets_folder.push(&mod_name);
+                    assets_folder
+                }
+
+                // If there is no MyMod path configured, report it.
+                else { return show_dialog(app_ui.main_window, ErrorKind::MyModPathNotConfigured, true); }
+            }
+
+            // In normal mode, we ask the user to provide us with a path.
+            OperationalMode::Normal => {
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window,
+                    &qtr("context_menu_extract_packfile"),
+                );
+                setup_file_dialog(&mut file_dialog, Some(LAST_PATH_EXTRACT));
+                file_dialog.set_file_mode(FileMode::Directory);
+                file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+
+                if file_dialog.exec() == 1 {
+                    let extraction_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    save_last_path(LAST_PATH_EXTRACT, &extraction_path);
+                    extraction_path
+                }
+                else { return }
+            }
+        };
+
+        // We have to save our data from cache to the backend before extracting it. Otherwise we would extract outdated data.
+        // TODO: Make this more... optimal.
+        if let Err(error) = UI_STATE.get_open_packedfiles().iter().try_for_each(|packed_file| packed_file.save(app_ui, global_search_ui, self)) {
+            show_dialog(app_ui.main_window, error, false);
+        }
+
+        else {
+            CENTRAL_COMMAND.send_message_qt(Command::ExtractPackedFiles(selected_items, extraction_path, path_rewrite, convert));
+            app_ui.main_window.set_enabled(false);
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::String(result) => show_dialog(app_ui.main_window, result, true),
+                Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+            app_ui.main_window.set_enabled(true);
+        }
+    }
+
+    /// This function adds the files and folders dropped from the OS into the PackFile Contents TreeView.
+    ///
+    /// They're added to the folder of the item they were dropped on. Conflicts are resolved like with any other addition.
+    pub unsafe fn add_dropped_files(&mut self, app_ui: &mut AppUI, global_search_ui: &mut GlobalSearchUI, index: Ref<QModelIndex>) {
+
+        // Without a PackFile open there is nothing to drop into.
+        if self.packfile_contents_tree_model.row_count_0a() == 0 { return; }
+
+        let base_path = self.get_drop_target_path(index);
+        let dropped_paths = get_dropped_paths_safe(&mut self.packfile_contents_tree_view);
+        let mut paths = vec![];
+        let mut paths_packedfile = vec![];
+        for index in 0..dropped_paths.size() {
+            let dropped_path = PathBuf::from(dropped_paths.at(index).to_std_string());
+            let name = match dropped_path.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => continue,
+            };
+
+            if dropped_path.is_dir() {
+                let files = match get_files_from_subdir(&dropped_path) {
+                    Ok(files) => files,
+                    Err(error) => return show_dialog(app_ui.main_window, error, false),
+                };
+
+                for file in files {
+                    let mut path_packedfile = base_path.to_vec();
+                    path_packedfile.push(name.to_owned());
+                    path_packedfile.extend(file.strip_prefix(&dropped_path).unwrap().iter().map(|x| x.to_string_lossy().to_string()));
+                    paths.push(file);
+                    paths_packedfile.push(path_packedfile);
+                }
+            }
+
+            else {
+                let mut path_packedfile = base_path.to_vec();
+                path_packedfile.push(name);
+                paths.push(dropped_path);
+                paths_packedfile.push(path_packedfile);
+            }
+        }
+
+        self.add_packedfiles(app_ui, global_search_ui, &paths, &paths_packedfile);
+    }
+
+    /// This function renames the provided items of the PackFile Contents TreeView, updating the TreeView and the open PackedFiles.
+    ///
+    /// It takes the path of each item and its new name. The rename is recorded, so it can be undone with the "Undo Move" action.
+    pub unsafe fn rename_packed_files(&mut self, app_ui: &mut AppUI, global_search_ui: &mut GlobalSearchUI, renaming_data: Vec<(PathType, String)>) {
+
+        // Send the renaming data to the Background Thread, wait for a response.
+        CENTRAL_COMMAND.send_message_qt(Command::RenamePackedFiles(renaming_data.to_vec()));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::VecPathTypeVecString(renamed_items) => {
+                let renamed_items = renamed_items.iter().map(|x| (From::from(&x.0), x.1.to_owned())).collect::<Vec<(TreePathType, Vec<String>)>>();
+                let mut path_changes = vec![];
+                for path in UI_STATE.get_open_packedfiles().iter().map(|x| x.get_ref_path()) {
+                    if !path.is_empty() {
+                        for (item_type, new_path) in &renamed_items {
+
+                            // Due to how the backend is built (doing a Per-PackedFile movement) we will always receive here individual PackedFiles.
+                            // So we don't need to check the rest. But the name change can be in any place of the path, so we have to take that into account.
+                            if let TreePathType::File(ref current_path) = item_type {
+                                if *current_path == *path {
+                                    path_changes.push((current_path.to_vec(), new_path.to_vec()));
+
+                                    // Update the global search stuff, if needed.
+                                    global_search_ui.search_on_path(self, vec![PathType::File(new_path.to_vec()); 1]);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                for (path_before, path_after) in &path_changes {
+                    let mut open_packedfiles = UI_STATE.set_open_packedfiles();
+                    let position = open_packedfiles.iter().position(|x| *x.get_ref_path() == *path_before).unwrap();
+                    let data = open_packedfiles.remove(position);
+                    let widget = data.get_mut_widget();
+                    let index = app_ui.tab_bar_packed_file.index_of(widget);
+                    let old_name = path_before.last().unwrap();
+                    let new_name = path_after.last().unwrap();
+                    if old_name != new_name {
+                        app_ui.tab_bar_packed_file.set_tab_text(index, &QString::from_std_str(new_name));
+                    }
+
+                    data.set_path(path_after);
+                    open_packedfiles.push(data);
+                }
+
+                // Ok, problem here: the view expects you pass the exact items renamed, NOT THE GODDAM FILES!!!!
+                // which menas in case of folders we have turn all those "renamed items" into a big "renamed folder".
+                // What a fucking planning mess.
+                let renamed_items_view: Vec<(TreePathType, Vec<String>)> = renaming_data.iter().map(|(x, y)| {
+                    let path = if let PathType::File(path) | PathType::Folder(path) = x {
+                        let mut path = path.to_vec();
+                        *path.last_mut().unwrap() = y.to_owned();
+                        path
+                    } else { unimplemented!() };
+                    (TreePathType::from(x), path)
+                }).collect();
+
+                let mut blocker = QSignalBlocker::from_q_object(self.packfile_contents_tree_view.selection_model().static_upcast_mut::<QObject>());
+                self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Move(renamed_items_view));
+                self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(renamed_items.iter().map(|x| match x.0 {
+                    TreePathType::File(_) => TreePathType::File(x.1.to_vec()),
+                    TreePathType::Folder(_) => TreePathType::Folder(x.1.to_vec()),
+                    _ => unimplemented!()
+                }).collect()));
+                blocker.unblock();
+                UI_STATE.set_is_modified(true, app_ui, self);
+
+                // Renames are moves within the same folder, so they can be undone the same way.
+                UI_STATE.push_packfile_contents_move(renamed_items.into_iter().filter_map(|(x, y)| if let TreePathType::File(x) = x { Some((x, y)) } else { None }).collect());
+                self.context_menu_undo_move.set_enabled(UI_STATE.has_packfile_contents_moves());
+            },
+            Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function moves the selected items of the PackFile Contents TreeView to the folder of the item they were dropped on.
+    ///
+    /// The move is recorded, so it can be undone later with the "Undo Move" action.
+    pub unsafe fn move_dropped_items(&mut self, app_ui: &mut AppUI, global_search_ui: &mut GlobalSearchUI, index: Ref<QModelIndex>) {
+        if self.packfile_contents_tree_model.row_count_0a() == 0 || UI_STATE.get_packfile_contents_read_only() { return; }
+
+        let base_path = self.get_drop_target_path(index);
+        let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(self);
+        let mut moving_data: Vec<(PathType, Vec<String>)> = vec![];
+        for item_type in &selected_items {
+            if let TreePathType::File(path) | TreePathType::Folder(path) = item_type {
+
+                // Skip items already in the target folder, folders dropped into themselves, and items moved along with a selected parent folder.
+                if path[..path.len() - 1] == base_path[..] || base_path.starts_with(path) { continue; }
+                if selected_items.iter().any(|x| if let TreePathType::Folder(folder) = x { folder != path && path.starts_with(folder) } else { false }) { continue; }
+
+                let mut new_path = base_path.to_vec();
+                new_path.push(path.last().unwrap().to_owned());
+                moving_data.push((From::from(item_type), new_path));
+            }
+        }
+
+        if moving_data.is_empty() { return; }
+
+        CENTRAL_COMMAND.send_message_qt(Command::MovePackedFiles(moving_data));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::VecPathTypeVecString(moved_items) => {
+                let moved_paths = moved_items.into_iter().filter_map(|(x, y)| if let PathType::File(x) = x { Some((x, y)) } else { None }).collect::<Vec<(Vec<String>, Vec<String>)>>();
+                self.update_moved_packed_files(app_ui, global_search_ui, &moved_paths);
+                UI_STATE.push_packfile_contents_move(moved_paths);
+                self.context_menu_undo_move.set_enabled(UI_STATE.has_packfile_contents_moves());
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function undoes the last move (or rename) done in the PackFile Contents TreeView, moving the PackedFiles back to their previous paths.
+    pub unsafe fn undo_last_move(&mut self, app_ui: &mut AppUI, global_search_ui: &mut GlobalSearchUI) {
+        if let Some(moved_paths) = UI_STATE.pop_packfile_contents_move() {
+            let moving_data = moved_paths.iter().map(|(before, after)| (PathType::File(after.to_vec()), before.to_vec())).collect();
+            CENTRAL_COMMAND.send_message_qt(Command::MovePackedFiles(moving_data));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::VecPathTypeVecString(moved_items) => {
+                    let moved_paths = moved_items.into_iter().filter_map(|(x, y)| if let PathType::File(x) = x { Some((x, y)) } else { None }).collect::<Vec<(Vec<String>, Vec<String>)>>();
+                    self.update_moved_packed_files(app_ui, global_search_ui, &moved_paths);
+                }
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+
+        self.context_menu_undo_move.set_enabled(UI_STATE.has_packfile_contents_moves());
+    }
+
+    /// This function updates the TreeView, the open PackedFiles and the global search after moving the provided PackedFiles.
+    ///
+    /// It expects the "Original Path/New Path" of each moved PackedFile, as returned by the backend.
+    unsafe fn update_moved_packed_files(&mut self, app_ui: &mut AppUI, global_search_ui: &mut GlobalSearchUI, moved_paths: &[(Vec<String>, Vec<String>)]) {
+        if moved_paths.is_empty() { return; }
+
+        // Open PackedFiles keep their views, but their paths (and maybe their tab names) change.
+        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+            let path = packed_file_view.get_ref_path().to_vec();
+            if let Some((_, new_path)) = moved_paths.iter().find(|(old_path, _)| *old_path == path) {
+                if path.last() != new_path.last() {
+                    let index = app_ui.tab_bar_packed_file.index_of(packed_file_view.get_mut_widget());
+                    app_ui.tab_bar_packed_file.set_tab_text(index, &QString::from_std_str(new_path.last().unwrap()));
+                }
+                packed_file_view.set_path(new_path);
+            }
+        }
+
+        // Deleting the old paths also removes the folders left empty by the move.
+        let old_paths = moved_paths.iter().map(|(x, _)| TreePathType::File(x.to_vec())).collect::<Vec<TreePathType>>();
+        let new_paths = moved_paths.iter().map(|(_, y)| TreePathType::File(y.to_vec())).collect::<Vec<TreePathType>>();
+        self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Delete(old_paths));
+        self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(new_paths.to_vec()));
+        self.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(new_paths));
+
+        let search_paths = moved_paths.iter().flat_map(|(x, y)| vec![PathType::File(x.to_vec()), PathType::File(y.to_vec())]).collect();
+        global_search_ui.search_on_path(self, search_paths);
+        UI_STATE.set_is_modified(true, app_ui, self);
+    }
+
+    /// This function returns the path of the folder an item dropped on the provided index of the PackFile Contents TreeView should go to.
+    ///
+    /// That's the folder itself for folders, the parent folder for files, and the root of the PackFile for anything else.
+    unsafe fn get_drop_target_path(&self, index: Ref<QModelIndex>) -> Vec<String> {
+        let source_index = self.packfile_contents_tree_model_filter.map_to_source(index);
+        if source_index.is_valid() {
+            let item = self.packfile_contents_tree_model.item_from_index(&source_index);
+            match <MutPtr<QTreeView> as PackTree>::get_type_from_item(item, self.packfile_contents_tree_model) {
+                TreePathType::File(mut path) => {
+                    path.pop();
+                    path
+                }
+                TreePathType::Folder(path) => path,
+                _ => vec![],
+            }
+        } else { vec![] }
+    }
+
+    /// This function extracts the selected PackedFiles to a temporal folder, so they can be dragged out of the PackFile Contents TreeView.
+    ///
+    /// If the extraction fails, the error is reported and nothing gets dragged.
+    pub unsafe fn prepare_drag_out(&mut self, app_ui: &mut AppUI, global_search_ui: GlobalSearchUI) {
+        let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(self);
+        if selected_items.is_empty() { return; }
+
+        // Start from a clean folder, so we don't drag out leftovers from previous drags.
+        let drag_out_path = temp_dir().join(DRAG_OUT_FOLDER);
+        if drag_out_path.is_dir() && remove_dir_all(&drag_out_path).is_err() {
+            return show_dialog(app_ui.main_window, ErrorKind::IOGenericDelete(vec![drag_out_path]), false);
+        }
+        if DirBuilder::new().recursive(true).create(&drag_out_path).is_err() {
+            return show_dialog(app_ui.main_window, ErrorKind::IOGeneric, false);
+        }
+
+        // Same as with normal extractions, we need the data of the open PackedFiles in the backend first.
+        if let Err(error) = UI_STATE.get_open_packedfiles().iter().try_for_each(|packed_file| packed_file.save(app_ui, global_search_ui, self)) {
+            return show_dialog(app_ui.main_window, error, false);
+        }
+
+        let selected_paths = selected_items.iter().map(From::from).collect::<Vec<PathType>>();
+        app_ui.main_window.set_enabled(false);
+        CENTRAL_COMMAND.send_message_qt(Command::ExtractPackedFiles(selected_paths, drag_out_path.to_path_buf(), None, false));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        app_ui.main_window.set_enabled(true);
+        match response {
+            Response::String(_) => {},
+            Response::Error(error) => return show_dialog(app_ui.main_window, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        // Drag only the top-most items, as their children are already inside them.
+        let mut extracted_paths = vec![];
+        for item in &selected_items {
+            match item {
+                TreePathType::File(path) | TreePathType::Folder(path) => {
+                    let is_child = selected_items.iter().any(|other| match other {
+                        TreePathType::Folder(other_path) => other_path.len() < path.len() && path.starts_with(other_path),
+                        TreePathType::PackFile => true,
+                        _ => false,
+                    });
+
+                    if !is_child {
+                        extracted_paths.push(drag_out_path.join(path.iter().collect::<PathBuf>()));
+                    }
+                }
+                TreePathType::PackFile => {
+                    if let Ok(entries) = read_dir(&drag_out_path) {
+                        extracted_paths.extend(entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()));
+                    }
+                }
+                TreePathType::None => {},
+            }
+        }
+
+        let mut paths_to_drag = QStringList::new();
+        extracted_paths.iter().filter(|path| path.exists()).for_each(|path| paths_to_drag.append_q_string(&QString::from_std_str(path.to_string_lossy())));
+        set_drag_out_paths_safe(&mut self.packfile_contents_tree_view, &mut paths_to_drag);
+    }
+
+    /// This function creates the "Extract with Path Rewrite" dialog.
+    ///
+    /// It returns the path rewrite rule (regex, replacement), or `None` if the dialog is canceled or the regex is empty.
+    pub unsafe fn create_path_rewrite_dialog(app_ui: &AppUI) -> Option<(String, String)> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(app_ui.main_window).into_ptr();
+        dialog.set_window_title(&qtr("path_rewrite_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 50);
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+
+        // Create a little frame with some instructions.
+        let instructions_frame = QGroupBox::from_q_string(&qtr("path_rewrite_instructions_title")).into_ptr();
+        let mut instructions_grid = create_grid_layout(instructions_frame.static_upcast_mut());
+        let mut instructions_label = QLabel::from_q_string(&qtr("path_rewrite_instructions"));
+        instructions_label.set_word_wrap(true);
+        instructions_grid.add_widget_5a(instructions_label.into_ptr(), 0, 0, 1, 1);
+
+        let mut regex_label = QLabel::from_q_string(&qtr("path_rewrite_regex"));
+        let mut replacement_label = QLabel::from_q_string(&qtr("path_rewrite_replacement"));
+
+        // Use the last rule by default, as this is usually done over and over with the same rule.
+        let settings = SETTINGS.read().unwrap();
+        let mut regex_line_edit = QLineEdit::from_q_string(&QString::from_std_str(&settings.settings_string[PATH_REWRITE_REGEX]));
+        let mut replacement_line_edit = QLineEdit::from_q_string(&QString::from_std_str(&settings.settings_string[PATH_REWRITE_REPLACEMENT]));
+        drop(settings);
+
+        regex_line_edit.set_placeholder_text(&QString::from_std_str("^ui/portraits/"));
+        let mut accept_button = QPushButton::from_q_string(&qtr("path_rewrite_accept"));
+
+        main_grid.add_widget_5a(instructions_frame, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&mut regex_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut regex_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&mut replacement_label, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&mut replacement_line_edit, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 3, 0, 1, 2);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let regex = regex_line_edit.text().to_std_string();
+            let replacement = replacement_line_edit.text().to_std_string();
+            if regex.is_empty() { None }
+            else {
+                save_setting_string(PATH_REWRITE_REGEX, &regex);
+                save_setting_string(PATH_REWRITE_REPLACEMENT, &replacement);
+                Some((regex, replacement))
+            }
+        } else { None }
+    }
+
     /// This function creates the entire "Rename" dialog.
     ///
     ///It returns the new name of the Item, or `None` if the dialog is canceled or closed.
@@ -192,6 +715,144 @@ impl PackFileContentsUI {
         } else { None }
     }
 
+    /// This function creates the "Mass Rename" dialog, with a live preview of the new names.
+    ///
+    /// It returns the path of each item to rename with its new name, or None if the dialog was closed. Unchanged items are not returned.
+    pub unsafe fn create_mass_rename_dialog(app_ui: &mut AppUI, selected_items: &[TreePathType]) -> Option<Vec<(PathType, String)>> {
+
+        // Rename the deepest items first, so renaming a folder doesn't change the path of the items within it before they're renamed.
+        let mut items = selected_items.iter()
+            .filter_map(|item| match item {
+                TreePathType::File(path) => Some((PathType::File(path.to_vec()), path.last().unwrap().to_owned(), true, path.len())),
+                TreePathType::Folder(path) => Some((PathType::Folder(path.to_vec()), path.last().unwrap().to_owned(), false, path.len())),
+                _ => None,
+            })
+            .collect::<Vec<(PathType, String, bool, usize)>>();
+        items.sort_by(|a, b| b.3.cmp(&a.3));
+        if items.is_empty() { return None; }
+        let names = items.iter().map(|(_, name, is_file, _)| (name.to_owned(), *is_file)).collect::<Vec<(String, bool)>>();
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(app_ui.main_window).into_ptr();
+        dialog.set_window_title(&qtr("mass_rename_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 500);
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+
+        let mut find_label = QLabel::from_q_string(&qtr("mass_rename_find"));
+        let mut replace_label = QLabel::from_q_string(&qtr("mass_rename_replace"));
+        let mut prefix_label = QLabel::from_q_string(&qtr("mass_rename_prefix"));
+        let mut suffix_label = QLabel::from_q_string(&qtr("mass_rename_suffix"));
+        let mut case_label = QLabel::from_q_string(&qtr("mass_rename_case"));
+        let mut find_line_edit = QLineEdit::new().into_ptr();
+        let mut replace_line_edit = QLineEdit::new().into_ptr();
+        let mut prefix_line_edit = QLineEdit::new().into_ptr();
+        let mut suffix_line_edit = QLineEdit::new().into_ptr();
+        let mut use_regex_checkbox = QCheckBox::from_q_string(&qtr("mass_rename_use_regex")).into_ptr();
+        let mut case_sensitive_checkbox = QCheckBox::from_q_string(&qtr("mass_rename_case_sensitive")).into_ptr();
+        let mut case_combobox = QComboBox::new_0a().into_ptr();
+        let mut preview_table_view = QTableView::new_0a().into_ptr();
+        let preview_model = QStandardItemModel::new_0a().into_ptr();
+        let mut error_label = QLabel::new().into_ptr();
+        let mut accept_button = QPushButton::from_q_string(&qtr("mass_rename_accept")).into_ptr();
+
+        find_line_edit.set_placeholder_text(&qtr("mass_rename_find_placeholder"));
+        replace_line_edit.set_placeholder_text(&qtr("mass_rename_replace_placeholder"));
+        case_combobox.add_item_q_string(&qtr("mass_rename_case_none"));
+        case_combobox.add_item_q_string(&qtr("mass_rename_case_lower"));
+        case_combobox.add_item_q_string(&qtr("mass_rename_case_upper"));
+        preview_table_view.set_model(preview_model);
+        preview_table_view.horizontal_header().set_stretch_last_section(true);
+        preview_table_view.vertical_header().set_visible(false);
+        preview_table_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        error_label.set_word_wrap(true);
+
+        main_grid.add_widget_5a(&mut find_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(find_line_edit, 0, 1, 1, 1);
+        main_grid.add_widget_5a(use_regex_checkbox, 0, 2, 1, 1);
+        main_grid.add_widget_5a(&mut replace_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(replace_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(case_sensitive_checkbox, 1, 2, 1, 1);
+        main_grid.add_widget_5a(&mut prefix_label, 2, 0, 1, 1);
+        main_grid.add_widget_5a(prefix_line_edit, 2, 1, 1, 2);
+        main_grid.add_widget_5a(&mut suffix_label, 3, 0, 1, 1);
+        main_grid.add_widget_5a(suffix_line_edit, 3, 1, 1, 2);
+        main_grid.add_widget_5a(&mut case_label, 4, 0, 1, 1);
+        main_grid.add_widget_5a(case_combobox, 4, 1, 1, 2);
+        main_grid.add_widget_5a(preview_table_view, 5, 0, 1, 3);
+        main_grid.add_widget_5a(error_label, 6, 0, 1, 3);
+        main_grid.add_widget_5a(accept_button, 7, 0, 1, 3);
+
+        // Get the rename pattern from the dialog's widgets.
+        let get_mass_rename = move || MassRename {
+            find: find_line_edit.text().to_std_string(),
+            replace: replace_line_edit.text().to_std_string(),
+            use_regex: use_regex_checkbox.is_checked(),
+            case_sensitive: case_sensitive_checkbox.is_checked(),
+            prefix: prefix_line_edit.text().to_std_string(),
+            suffix: suffix_line_edit.text().to_std_string(),
+            case_transform: match case_combobox.current_index() {
+                1 => CaseTransform::Lower,
+                2 => CaseTransform::Upper,
+                _ => CaseTransform::None,
+            },
+        };
+
+        // Refill the preview with the new names. If the pattern is invalid, show why and don't let the user accept it.
+        let preview_names = names.clone();
+        let update_preview = move || {
+            let mut preview_model = preview_model;
+            let mut error_label = error_label;
+            let mut accept_button = accept_button;
+            preview_model.clear();
+            let mut headers = QStringList::new();
+            headers.append_q_string(&qtr("mass_rename_current_name"));
+            headers.append_q_string(&qtr("mass_rename_new_name"));
+            preview_model.set_horizontal_header_labels(&headers);
+
+            match get_mass_rename().get_new_names(&preview_names) {
+                Ok(new_names) => {
+                    for ((name, _), new_name) in preview_names.iter().zip(new_names.iter()) {
+                        let mut row = QListOfQStandardItem::new();
+                        add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(name)).into_ptr());
+                        add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(new_name)).into_ptr());
+                        preview_model.append_row_q_list_of_q_standard_item(&row);
+                    }
+                    error_label.set_text(&QString::new());
+                    accept_button.set_enabled(true);
+                }
+                Err(error) => {
+                    error_label.set_text(&QString::from_std_str(error.to_string()));
+                    accept_button.set_enabled(false);
+                }
+            }
+        };
+
+        let slot_text_changed = SlotOfQString::new(clone!(update_preview => move |_| { update_preview(); }));
+        let slot_toggled = SlotOfBool::new(clone!(update_preview => move |_| { update_preview(); }));
+        let slot_index_changed = SlotOfInt::new(clone!(update_preview => move |_| { update_preview(); }));
+        find_line_edit.text_changed().connect(&slot_text_changed);
+        replace_line_edit.text_changed().connect(&slot_text_changed);
+        prefix_line_edit.text_changed().connect(&slot_text_changed);
+        suffix_line_edit.text_changed().connect(&slot_text_changed);
+        use_regex_checkbox.toggled().connect(&slot_toggled);
+        case_sensitive_checkbox.toggled().connect(&slot_toggled);
+        case_combobox.current_index_changed().connect(&slot_index_changed);
+        accept_button.released().connect(dialog.slot_accept());
+        update_preview();
+
+        if dialog.exec() == 1 {
+            let new_names = get_mass_rename().get_new_names(&names).ok()?;
+            let renaming_data = items.into_iter()
+                .zip(new_names.into_iter())
+                .filter(|((_, name, _, _), new_name)| name != new_name && !new_name.is_empty())
+                .map(|((path_type, _, _, _), new_name)| (path_type, new_name))
+                .collect::<Vec<(PathType, String)>>();
+
+            if renaming_data.is_empty() { None } else { Some(renaming_data) }
+        } else { None }
+    }
+
     /// This function creates the "Mass-Import TSV" dialog. Nothing too massive.
     ///
     /// It returns the name of the new imported PackedFiles & their Paths, or None in case of closing the dialog.
@@ -239,6 +900,7 @@ impl PackFileContentsUI {
                     dialog,
                     &qtr("mass_import_select"),
                 );
+                setup_file_dialog(&mut file_dialog, Some(LAST_PATH_IMPORT_TSV));
 
                 file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv)"));
                 file_dialog.set_file_mode(FileMode::ExistingFiles);
@@ -250,6 +912,8 @@ impl PackFileContentsUI {
                         files_to_import.borrow_mut().push(PathBuf::from(file_dialog.selected_files().at(index).to_std_string()));
                     }
 
+                    if let Some(folder) = files_to_import.borrow().first().and_then(|path| path.parent()) { save_last_path(LAST_PATH_IMPORT_TSV, folder); }
+
                     files_to_import_label.set_text(&qtre("files_to_import", &[&selected_files.count_0a().to_string()]));
                 }
             }
@@ -272,4 +936,117 @@ impl PackFileContentsUI {
         // In any other case, we return None.
         else { None }
     }
+
+    /// This function creates the "Bind Folder to Table" dialog. It returns the table, the key and value columns and their patterns, if we accept it.
+    pub unsafe fn create_bind_folder_to_table_dialog(app_ui: &AppUI, folder_path: &[String]) -> Option<(Vec<String>, usize, usize, String, String)> {
+
+        // Get the list of tables in the PackFile, so we can choose one.
+        CENTRAL_COMMAND.send_message_qt(Command::GetPackFileDataForTreeView);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let mut tables = match response {
+            Response::PackFileInfoVecPackedFileInfo((_, packed_files_info)) => packed_files_info.into_iter()
+                .map(|packed_file_info| packed_file_info.path)
+                .filter(|path| match PackedFileType::get_packed_file_type(path) {
+                    PackedFileType::DB | PackedFileType::Loc => true,
+                    _ => false,
+                })
+                .collect::<Vec<Vec<String>>>(),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        if tables.is_empty() {
+            show_dialog(app_ui.main_window, tr("bind_folder_no_tables"), false);
+            return None;
+        }
+        tables.sort();
+
+        // Create the "Bind Folder to Table" Dialog and configure it.
+        let mut dialog = QDialog::new_1a(app_ui.main_window).into_ptr();
+        dialog.set_window_title(&qtr("bind_folder_to_table"));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 100);
+
+        // Create the main Grid and his stuff.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let folder_label = QLabel::from_q_string(&qtre("bind_folder_folder", &[&folder_path.join("/")]));
+        let table_label = QLabel::from_q_string(&qtr("bind_folder_table"));
+        let key_label = QLabel::from_q_string(&qtr("bind_folder_key"));
+        let value_label = QLabel::from_q_string(&qtr("bind_folder_value"));
+        let patterns_label = QLabel::from_q_string(&qtr("bind_folder_patterns"));
+
+        let mut table_combobox = QComboBox::new_0a();
+        let mut key_column_combobox = QComboBox::new_0a();
+        let mut value_column_combobox = QComboBox::new_0a();
+        let mut key_pattern_line_edit = QLineEdit::from_q_string(&QString::from_std_str("{name}"));
+        let mut value_pattern_line_edit = QLineEdit::from_q_string(&QString::from_std_str("{path}"));
+        let mut bind_button = QPushButton::from_q_string(&qtr("bind_folder_bind"));
+
+        // Add all the widgets to the main grid, and the main grid to the dialog.
+        main_grid.add_widget_5a(folder_label.into_ptr(), 0, 0, 1, 3);
+        main_grid.add_widget_5a(table_label.into_ptr(), 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut table_combobox, 1, 1, 1, 2);
+        main_grid.add_widget_5a(key_label.into_ptr(), 2, 0, 1, 1);
+        main_grid.add_widget_5a(&mut key_column_combobox, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&mut key_pattern_line_edit, 2, 2, 1, 1);
+        main_grid.add_widget_5a(value_label.into_ptr(), 3, 0, 1, 1);
+        main_grid.add_widget_5a(&mut value_column_combobox, 3, 1, 1, 1);
+        main_grid.add_widget_5a(&mut value_pattern_line_edit, 3, 2, 1, 1);
+        main_grid.add_widget_5a(patterns_label.into_ptr(), 4, 0, 1, 3);
+        main_grid.add_widget_5a(&mut bind_button, 5, 2, 1, 1);
+
+        //-------------------------------------------------------------------------------------------//
+        // Actions for the Bind Folder to Table Dialog...
+        //-------------------------------------------------------------------------------------------//
+
+        // What happens when we change the table: load its columns, guessing which ones are the key and the path.
+        let mut key_column_combobox_ptr = key_column_combobox.as_mut_ptr();
+        let mut value_column_combobox_ptr = value_column_combobox.as_mut_ptr();
+        let slot_load_columns = SlotOfQString::new(move |table| catch_panic_in_slot(|| {
+            let path = table.to_std_string().split('/').map(|x| x.to_owned()).collect::<Vec<String>>();
+            CENTRAL_COMMAND.send_message_qt(Command::DecodePackedFile(path));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            let definition: Definition = match response {
+                Response::DBPackedFileInfo((table, _)) => table.get_definition(),
+                Response::LocPackedFileInfo((table, _)) => table.get_definition(),
+                Response::Error(error) => return show_dialog(dialog, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            };
+
+            key_column_combobox_ptr.clear();
+            value_column_combobox_ptr.clear();
+            for field in definition.get_ref_fields() {
+                let name = QString::from_std_str(clean_column_names(field.get_name()));
+                key_column_combobox_ptr.add_item_q_string(&name);
+                value_column_combobox_ptr.add_item_q_string(&name);
+            }
+
+            let fields = definition.get_ref_fields();
+            let key_column = fields.iter().position(|field| field.get_is_key()).unwrap_or(0);
+            let value_column = fields.iter().position(|field| field.get_name().contains("path") || field.get_name().contains("image"))
+                .unwrap_or_else(|| if fields.len() > 1 { 1 } else { 0 });
+            key_column_combobox_ptr.set_current_index(key_column as i32);
+            value_column_combobox_ptr.set_current_index(value_column as i32);
+        }));
+
+        table_combobox.current_text_changed().connect(&slot_load_columns);
+        bind_button.released().connect(dialog.slot_accept());
+
+        for table in &tables {
+            table_combobox.add_item_q_string(&QString::from_std_str(table.join("/")));
+        }
+
+        // If we hit the "Bind" button, send the info back.
+        if dialog.exec() == 1 && key_column_combobox.current_index() != -1 && value_column_combobox.current_index() != -1 {
+            Some((
+                tables[table_combobox.current_index() as usize].to_vec(),
+                key_column_combobox.current_index() as usize,
+                value_column_combobox.current_index() as usize,
+                key_pattern_line_edit.text().to_std_string(),
+                value_pattern_line_edit.text().to_std_string(),
+            ))
+        }
+
+        // In any other case, we return None.
+        else { None }
+    }
 }
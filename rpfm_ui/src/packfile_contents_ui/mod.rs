@@ -29,7 +29,7 @@ use qt_core::QSortFilterProxyModel;
 
 use cpp_core::MutPtr;
 
-use crate::ffi::{new_packed_file_model_safe, new_treeview_filter_safe};
+use crate::ffi::{new_packed_file_model_safe, new_packed_file_treeview_safe, new_treeview_filter_safe};
 use crate::locale::qtr;
 use crate::utils::create_grid_layout;
 
@@ -74,8 +74,12 @@ pub struct PackFileContentsUI {
     pub context_menu_mass_import_tsv: MutPtr<QAction>,
     pub context_menu_mass_export_tsv: MutPtr<QAction>,
     pub context_menu_rename: MutPtr<QAction>,
+    pub context_menu_mass_rename: MutPtr<QAction>,
+    pub context_menu_undo_move: MutPtr<QAction>,
     pub context_menu_delete: MutPtr<QAction>,
     pub context_menu_extract: MutPtr<QAction>,
+    pub context_menu_extract_with_path_rewrite: MutPtr<QAction>,
+    pub context_menu_extract_converted: MutPtr<QAction>,
     pub context_menu_open_decoder: MutPtr<QAction>,
     pub context_menu_open_dependency_manager: MutPtr<QAction>,
     pub context_menu_open_containing_folder: MutPtr<QAction>,
@@ -84,6 +88,7 @@ pub struct PackFileContentsUI {
     pub context_menu_check_tables: MutPtr<QAction>,
     pub context_menu_merge_tables: MutPtr<QAction>,
     pub context_menu_update_table: MutPtr<QAction>,
+    pub context_menu_bind_folder_to_table: MutPtr<QAction>,
 
     //-------------------------------------------------------------------------------//
     // Actions not in the UI.
@@ -108,14 +113,14 @@ impl PackFileContentsUI {
 
         // Create and configure the 'TreeView` Dock Widget and all his contents.
         let mut packfile_contents_dock_widget = QDockWidget::from_q_widget(main_window).into_ptr();
-        let packfile_contents_dock_inner_widget = QWidget::new_0a().into_ptr();
+        let mut packfile_contents_dock_inner_widget = QWidget::new_0a().into_ptr();
         let mut packfile_contents_dock_layout = create_grid_layout(packfile_contents_dock_inner_widget);
         packfile_contents_dock_widget.set_widget(packfile_contents_dock_inner_widget);
         main_window.add_dock_widget_2a(DockWidgetArea::LeftDockWidgetArea, packfile_contents_dock_widget);
         packfile_contents_dock_widget.set_window_title(&qtr("gen_loc_packfile_contents"));
 
         // Create and configure the `TreeView` itself.
-        let mut packfile_contents_tree_view = QTreeView::new_0a();
+        let mut packfile_contents_tree_view = new_packed_file_treeview_safe(&mut packfile_contents_dock_inner_widget);
         let packfile_contents_tree_model = new_packed_file_model_safe();
         let mut packfile_contents_tree_model_filter = new_treeview_filter_safe(&mut packfile_contents_dock_widget);
         packfile_contents_tree_model_filter.set_source_model(packfile_contents_tree_model);
@@ -137,7 +142,7 @@ impl PackFileContentsUI {
         filter_case_sensitive_button.set_checkable(true);
 
         // Add everything to the `TreeView`s Dock Layout.
-        packfile_contents_dock_layout.add_widget_5a(&mut packfile_contents_tree_view, 0, 0, 1, 2);
+        packfile_contents_dock_layout.add_widget_5a(packfile_contents_tree_view, 0, 0, 1, 2);
         packfile_contents_dock_layout.add_widget_5a(&mut filter_line_edit, 1, 0, 1, 2);
         packfile_contents_dock_layout.add_widget_5a(&mut filter_autoexpand_matches_button, 2, 0, 1, 1);
         packfile_contents_dock_layout.add_widget_5a(&mut filter_case_sensitive_button, 2, 1, 1, 1);
@@ -163,8 +168,12 @@ impl PackFileContentsUI {
         let mut context_menu_mass_import_tsv = menu_create.add_action_q_string(&qtr("context_menu_mass_import_tsv"));
         let mut context_menu_mass_export_tsv = menu_create.add_action_q_string(&qtr("context_menu_mass_export_tsv"));
         let mut context_menu_rename = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_rename"));
+        let mut context_menu_mass_rename = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_mass_rename"));
+        let mut context_menu_undo_move = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_undo_move"));
         let mut context_menu_delete = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_delete"));
         let mut context_menu_extract = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_extract"));
+        let mut context_menu_extract_with_path_rewrite = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_extract_with_path_rewrite"));
+        let mut context_menu_extract_converted = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_extract_converted"));
         let mut context_menu_open_decoder = menu_open.add_action_q_string(&qtr("context_menu_open_decoder"));
         let mut context_menu_open_dependency_manager = menu_open.add_action_q_string(&qtr("context_menu_open_dependency_manager"));
         let mut context_menu_open_containing_folder = menu_open.add_action_q_string(&qtr("context_menu_open_containing_folder"));
@@ -173,6 +182,7 @@ impl PackFileContentsUI {
         let context_menu_check_tables = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_check_tables"));
         let context_menu_merge_tables = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_merge_tables"));
         let context_menu_update_table = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_update_table"));
+        let mut context_menu_bind_folder_to_table = packfile_contents_tree_view_context_menu.add_action_q_string(&qtr("context_menu_bind_folder_to_table"));
         let packfile_contents_tree_view_expand_all = QAction::from_q_string(&qtr("treeview_expand_all"));
         let packfile_contents_tree_view_collapse_all = QAction::from_q_string(&qtr("treeview_collapse_all"));
 
@@ -194,10 +204,15 @@ impl PackFileContentsUI {
         context_menu_mass_export_tsv.set_enabled(false);
         context_menu_delete.set_enabled(false);
         context_menu_extract.set_enabled(false);
+        context_menu_extract_with_path_rewrite.set_enabled(false);
+        context_menu_extract_converted.set_enabled(false);
         context_menu_rename.set_enabled(false);
+        context_menu_mass_rename.set_enabled(false);
+        context_menu_undo_move.set_enabled(false);
         context_menu_open_decoder.set_enabled(false);
         context_menu_open_dependency_manager.set_enabled(false);
         context_menu_open_containing_folder.set_enabled(false);
+        context_menu_bind_folder_to_table.set_enabled(false);
         context_menu_open_with_external_program.set_enabled(false);
         context_menu_open_notes.set_enabled(false);
 
@@ -208,7 +223,7 @@ impl PackFileContentsUI {
             // `PackFile TreeView` Dock Widget.
             //-------------------------------------------------------------------------------//
             packfile_contents_dock_widget,
-            packfile_contents_tree_view: packfile_contents_tree_view.into_ptr(),
+            packfile_contents_tree_view,
             packfile_contents_tree_model_filter,
             packfile_contents_tree_model,
             filter_line_edit: filter_line_edit.into_ptr(),
@@ -235,8 +250,12 @@ impl PackFileContentsUI {
             context_menu_mass_export_tsv,
 
             context_menu_rename,
+            context_menu_mass_rename,
+            context_menu_undo_move,
             context_menu_delete,
             context_menu_extract,
+            context_menu_extract_with_path_rewrite,
+            context_menu_extract_converted,
 
             context_menu_open_decoder,
             context_menu_open_dependency_manager,
@@ -247,6 +266,7 @@ impl PackFileContentsUI {
             context_menu_check_tables,
             context_menu_merge_tables,
             context_menu_update_table,
+            context_menu_bind_folder_to_table,
 
             //-------------------------------------------------------------------------------//
             // "Special" Actions for the TreeView.
@@ -48,7 +48,11 @@ pub unsafe fn set_shortcuts(ui: &mut PackFileContentsUI) {
     ui.context_menu_update_table.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["update_tables"])));
     ui.context_menu_delete.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["delete"])));
     ui.context_menu_extract.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["extract"])));
+    ui.context_menu_extract_with_path_rewrite.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["extract_with_path_rewrite"])));
+    ui.context_menu_extract_converted.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["extract_converted"])));
     ui.context_menu_rename.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["rename"])));
+    ui.context_menu_mass_rename.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["mass_rename"])));
+    ui.context_menu_undo_move.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["undo_move"])));
     ui.context_menu_open_decoder.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["open_in_decoder"])));
     ui.context_menu_open_dependency_manager.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["open_packfiles_list"])));
     ui.context_menu_open_containing_folder.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packfile_contents_tree_view["open_containing_folder"])));
@@ -73,7 +77,11 @@ pub unsafe fn set_shortcuts(ui: &mut PackFileContentsUI) {
     ui.context_menu_update_table.set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.context_menu_delete.set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.context_menu_extract.set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_extract_with_path_rewrite.set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_extract_converted.set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.context_menu_rename.set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_mass_rename.set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_undo_move.set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.context_menu_open_decoder.set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.context_menu_open_dependency_manager.set_shortcut_context(ShortcutContext::WidgetShortcut);
     ui.context_menu_open_containing_folder.set_shortcut_context(ShortcutContext::WidgetShortcut);
@@ -98,7 +106,11 @@ pub unsafe fn set_shortcuts(ui: &mut PackFileContentsUI) {
     ui.packfile_contents_tree_view.add_action(ui.context_menu_update_table);
     ui.packfile_contents_tree_view.add_action(ui.context_menu_delete);
     ui.packfile_contents_tree_view.add_action(ui.context_menu_extract);
+    ui.packfile_contents_tree_view.add_action(ui.context_menu_extract_with_path_rewrite);
+    ui.packfile_contents_tree_view.add_action(ui.context_menu_extract_converted);
     ui.packfile_contents_tree_view.add_action(ui.context_menu_rename);
+    ui.packfile_contents_tree_view.add_action(ui.context_menu_mass_rename);
+    ui.packfile_contents_tree_view.add_action(ui.context_menu_undo_move);
     ui.packfile_contents_tree_view.add_action(ui.context_menu_open_decoder);
     ui.packfile_contents_tree_view.add_action(ui.context_menu_open_dependency_manager);
     ui.packfile_contents_tree_view.add_action(ui.context_menu_open_containing_folder);
@@ -12,16 +12,15 @@
 Module with all the code related to the main `PackFileContentsSlots`.
 !*/
 
-use qt_widgets::{QFileDialog, q_file_dialog::FileMode};
+use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
+use qt_widgets::{q_message_box, QMessageBox};
 use qt_widgets::SlotOfQPoint;
 use qt_widgets::QTreeView;
 
 use qt_gui::QCursor;
 use qt_gui::SlotOfQStandardItem;
 
-use qt_core::{SlotOfBool, Slot, SlotOfQString};
-use qt_core::QSignalBlocker;
-use qt_core::QObject;
+use qt_core::{SlotOfBool, Slot, SlotOfQModelIndex, SlotOfQString};
 
 use cpp_core::MutPtr;
 
@@ -31,23 +30,23 @@ use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use rpfm_error::ErrorKind;
-use rpfm_lib::common::get_files_from_subdir;
+use rpfm_lib::common::{get_files_from_subdir, get_path_relative_to_folder};
 use rpfm_lib::packedfile::PackedFileType;
 use rpfm_lib::packedfile::text::TextType;
-use rpfm_lib::packfile::{PathType, RESERVED_NAME_EXTRA_PACKFILE};
+use rpfm_lib::packfile::PathType;
 use rpfm_lib::SETTINGS;
+use rpfm_lib::settings::{LAST_PATH_ADD_FILE, LAST_PATH_OPEN_PACKFILE};
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::global_search_ui::GlobalSearchUI;
 use crate::locale::{qtr, tr, tre};
-use crate::pack_tree::{icons::IconType, PackTree, TreePathType, TreeViewOperation};
+use crate::pack_tree::{PackTree, TreePathType, TreeViewOperation};
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::packedfile_views::packfile::PackFileExtraView;
-use crate::packedfile_views::{PackedFileView, TheOneSlot};
+use crate::packedfile_views::TheOneSlot;
 use crate::QString;
-use crate::utils::show_dialog;
+use crate::utils::{catch_panic_in_slot, Problems, save_last_path, setup_file_dialog, show_dialog};
 use crate::UI_STATE;
 use crate::ui_state::op_mode::OperationalMode;
 
@@ -74,7 +73,11 @@ pub struct PackFileContentsSlots {
     pub contextual_menu_add_from_packfile: SlotOfBool<'static>,
     pub contextual_menu_delete: SlotOfBool<'static>,
     pub contextual_menu_extract: SlotOfBool<'static>,
+    pub contextual_menu_extract_with_path_rewrite: SlotOfBool<'static>,
+    pub contextual_menu_extract_converted: SlotOfBool<'static>,
     pub contextual_menu_rename: SlotOfBool<'static>,
+    pub contextual_menu_mass_rename: SlotOfBool<'static>,
+    pub contextual_menu_undo_move: SlotOfBool<'static>,
 
     pub contextual_menu_new_packed_file_db: SlotOfBool<'static>,
     pub contextual_menu_new_packed_file_loc: SlotOfBool<'static>,
@@ -91,12 +94,17 @@ pub struct PackFileContentsSlots {
     pub contextual_menu_tables_check_integrity: SlotOfBool<'static>,
     pub contextual_menu_tables_merge_tables: SlotOfBool<'static>,
     pub contextual_menu_tables_update_table: SlotOfBool<'static>,
+    pub contextual_menu_bind_folder_to_table: SlotOfBool<'static>,
 
     pub contextual_menu_mass_import_tsv: SlotOfBool<'static>,
     pub contextual_menu_mass_export_tsv: SlotOfBool<'static>,
 
     pub packfile_contents_tree_view_expand_all: Slot<'static>,
     pub packfile_contents_tree_view_collapse_all: Slot<'static>,
+
+    pub packfile_contents_tree_view_files_dropped: SlotOfQModelIndex<'static>,
+    pub packfile_contents_tree_view_drag_out: Slot<'static>,
+    pub packfile_contents_tree_view_items_moved: SlotOfQModelIndex<'static>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -125,27 +133,27 @@ impl PackFileContentsSlots {
         }));
 
         // What happens when we trigger one of the filter events for the PackFile Contents TreeView.
-        let filter_change_text = SlotOfQString::new(move |_| {
+        let filter_change_text = SlotOfQString::new(move |_| catch_panic_in_slot(|| {
             pack_file_contents_ui.filter_files();
-        });
-        let filter_change_autoexpand_matches = SlotOfBool::new(move |_| {
+        }));
+        let filter_change_autoexpand_matches = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             pack_file_contents_ui.filter_files();
-        });
-        let filter_change_case_sensitive = SlotOfBool::new(move |_| {
+        }));
+        let filter_change_case_sensitive = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             pack_file_contents_ui.filter_files();
-        });
+        }));
 
         // Slot to show the Contextual Menu for the TreeView.
-        let contextual_menu = SlotOfQPoint::new(move |_| {
+        let contextual_menu = SlotOfQPoint::new(move |_| catch_panic_in_slot(|| {
             pack_file_contents_ui.packfile_contents_tree_view_context_menu.exec_1a_mut(&QCursor::pos_0a());
-        });
+        }));
 
-        let update_packfile_state = SlotOfQStandardItem::new(move |item| {
+        let update_packfile_state = SlotOfQStandardItem::new(move |item| catch_panic_in_slot(|| {
             <MutPtr<QTreeView> as PackTree>::paint_specific_item_treeview(item);
-        });
+        }));
 
         // Slot to enable/disable contextual actions depending on the selected item.
-        let contextual_menu_enabler = Slot::new(move || {
+        let contextual_menu_enabler = Slot::new(move || catch_panic_in_slot(|| {
                 let (contents, files, folders) = <MutPtr<QTreeView> as PackTree>::get_combination_from_main_treeview_selection(&pack_file_contents_ui);
                 match contents {
 
@@ -165,7 +173,10 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_mass_export_tsv.set_enabled(true);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(true);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
@@ -177,6 +188,7 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(enabled);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
 
                         // Only if we have multiple files selected, we give the option to merge. Further checks are done when clicked.
                         let enabled = files > 1;
@@ -195,7 +207,10 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(true);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
@@ -211,6 +226,7 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(enabled);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(enabled);
                     },
 
                     // One or more files and one or more folders selected.
@@ -229,13 +245,17 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     },
 
                     // One PackFile (you cannot have two in the same TreeView) selected.
@@ -254,13 +274,17 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     },
 
                     // PackFile and one or more files selected.
@@ -279,13 +303,17 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     },
 
                     // PackFile and one or more folders selected.
@@ -303,13 +331,17 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_mass_export_tsv.set_enabled(true);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     },
 
                     // PackFile, one or more files, and one or more folders selected.
@@ -328,13 +360,17 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(true);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     },
 
                     // No paths selected, none selected, invalid path selected, or invalid value.
@@ -353,13 +389,17 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(false);
                         pack_file_contents_ui.context_menu_extract.set_enabled(false);
+                        pack_file_contents_ui.context_menu_extract_with_path_rewrite.set_enabled(false);
+                        pack_file_contents_ui.context_menu_extract_converted.set_enabled(false);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_mass_rename.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(false);
                         pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                        pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     },
                 }
 
@@ -382,21 +422,26 @@ impl PackFileContentsSlots {
                 if !is_there_a_dependency_database || !is_there_a_schema {
                     pack_file_contents_ui.context_menu_check_tables.set_enabled(false);
                     pack_file_contents_ui.context_menu_update_table.set_enabled(false);
+                    pack_file_contents_ui.context_menu_bind_folder_to_table.set_enabled(false);
                     pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(false);
                     pack_file_contents_ui.context_menu_mass_import_tsv.set_enabled(false);
                     pack_file_contents_ui.context_menu_mass_export_tsv.set_enabled(false);
                 }
-            }
+
+                // Moves can be undone whatever is selected, as long as there is one to undo.
+                pack_file_contents_ui.context_menu_undo_move.set_enabled(UI_STATE.has_packfile_contents_moves());
+            })
         );
 
         // What happens when we trigger the "Add File/s" action in the Contextual Menu.
-        let contextual_menu_add_file = SlotOfBool::new(move |_| {
+        let contextual_menu_add_file = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Create the FileDialog to get the file/s to add and configure it.
                 let mut file_dialog = QFileDialog::from_q_widget_q_string(
                     app_ui.main_window,
                     &qtr("context_menu_add_files"),
                 );
+                setup_file_dialog(&mut file_dialog, Some(LAST_PATH_ADD_FILE));
                 file_dialog.set_file_mode(FileMode::ExistingFiles);
                 match UI_STATE.get_operational_mode() {
 
@@ -426,24 +471,16 @@ impl PackFileContentsSlots {
                                 let paths_qt = file_dialog.selected_files();
                                 for index in 0..paths_qt.size() { paths.push(PathBuf::from(paths_qt.at(index).to_std_string())); }
 
-                                // Check if the files are in the Assets Folder. The file chooser kinda guarantees that
-                                // all are in the same folder, so we can just check the first one.
-                                let paths_packedfile: Vec<Vec<String>> = if paths[0].starts_with(&assets_folder) {
-                                    let mut paths_packedfile: Vec<Vec<String>> = vec![];
-                                    for path in &paths {
-                                        let filtered_path = path.strip_prefix(&assets_folder).unwrap();
-                                        paths_packedfile.push(filtered_path.iter().map(|x| x.to_string_lossy().as_ref().to_owned()).collect::<Vec<String>>());
+                                // Files in the Assets Folder (even if reached through a symlink or junction) keep their path within it.
+                                // Otherwise, they are added like normal files.
+                                let mut paths_packedfile: Vec<Vec<String>> = vec![];
+                                for path in &paths {
+                                    match get_path_relative_to_folder(path, &assets_folder) {
+                                        Some(filtered_path) => paths_packedfile.push(filtered_path.iter().map(|x| x.to_string_lossy().as_ref().to_owned()).collect::<Vec<String>>()),
+                                        None => paths_packedfile.append(&mut <MutPtr<QTreeView> as PackTree>::get_path_from_pathbuf(&pack_file_contents_ui, &path, true)),
                                     }
-                                    paths_packedfile
                                 }
 
-                                // Otherwise, they are added like normal files.
-                                else {
-                                    let mut paths_packedfile: Vec<Vec<String>> = vec![];
-                                    for path in &paths { paths_packedfile.append(&mut <MutPtr<QTreeView> as PackTree>::get_path_from_pathbuf(&pack_file_contents_ui, &path, true)); }
-                                    paths_packedfile
-                                };
-
                                 pack_file_contents_ui.add_packedfiles(&mut app_ui, &mut global_search_ui, &paths, &paths_packedfile);
                             }
                         }
@@ -462,6 +499,7 @@ impl PackFileContentsSlots {
                             let mut paths: Vec<PathBuf> = vec![];
                             let paths_qt = file_dialog.selected_files();
                             for index in 0..paths_qt.size() { paths.push(PathBuf::from(paths_qt.at(index).to_std_string())); }
+                            if let Some(folder) = paths.first().and_then(|path| path.parent()) { save_last_path(LAST_PATH_ADD_FILE, folder); }
 
                             // Get their final paths in the PackFile and only proceed if all of them are closed.
                             let mut paths_packedfile: Vec<Vec<String>> = vec![];
@@ -471,17 +509,18 @@ impl PackFileContentsSlots {
                         }
                     }
                 }
-            }
+            })
         );
 
         // What happens when we trigger the "Add Folder/s" action in the Contextual Menu.
-        let contextual_menu_add_folder = SlotOfBool::new(move |_| {
+        let contextual_menu_add_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Create the FileDialog to get the folder/s to add and configure it.
                 let mut file_dialog = QFileDialog::from_q_widget_q_string(
                     app_ui.main_window,
                     &qtr("context_menu_add_folders"),
                 );
+                setup_file_dialog(&mut file_dialog, Some(LAST_PATH_ADD_FILE));
                 file_dialog.set_file_mode(FileMode::Directory);
                 match UI_STATE.get_operational_mode() {
 
@@ -515,23 +554,16 @@ impl PackFileContentsSlots {
                                 let mut paths: Vec<PathBuf> = vec![];
                                 for path in &folder_paths { paths.append(&mut get_files_from_subdir(&path).unwrap()); }
 
-                                // Check if the files are in the Assets Folder. All are in the same folder, so we can just check the first one.
-                                let paths_packedfile = if paths[0].starts_with(&assets_folder) {
-                                    let mut paths_packedfile: Vec<Vec<String>> = vec![];
-                                    for path in &paths {
-                                        let filtered_path = path.strip_prefix(&assets_folder).unwrap();
-                                        paths_packedfile.push(filtered_path.iter().map(|x| x.to_string_lossy().as_ref().to_owned()).collect::<Vec<String>>());
+                                // Files in the Assets Folder (even if reached through a symlink or junction) keep their path within it.
+                                // Otherwise, they are added like normal files.
+                                let mut paths_packedfile: Vec<Vec<String>> = vec![];
+                                for path in &paths {
+                                    match get_path_relative_to_folder(path, &assets_folder) {
+                                        Some(filtered_path) => paths_packedfile.push(filtered_path.iter().map(|x| x.to_string_lossy().as_ref().to_owned()).collect::<Vec<String>>()),
+                                        None => paths_packedfile.append(&mut <MutPtr<QTreeView> as PackTree>::get_path_from_pathbuf(&pack_file_contents_ui, &path, true)),
                                     }
-                                    paths_packedfile
                                 }
 
-                                // Otherwise, they are added like normal files.
-                                else {
-                                    let mut paths_packedfile: Vec<Vec<String>> = vec![];
-                                    for path in &paths { paths_packedfile.append(&mut <MutPtr<QTreeView> as PackTree>::get_path_from_pathbuf(&pack_file_contents_ui, &path, true)); }
-                                    paths_packedfile
-                                };
-
                                 pack_file_contents_ui.add_packedfiles(&mut app_ui, &mut global_search_ui, &paths, &paths_packedfile);
                             }
                         }
@@ -550,6 +582,7 @@ impl PackFileContentsSlots {
                             let mut folder_paths: Vec<PathBuf> = vec![];
                             let paths_qt = file_dialog.selected_files();
                             for index in 0..paths_qt.size() { folder_paths.push(PathBuf::from(paths_qt.at(index).to_std_string())); }
+                            if let Some(folder) = folder_paths.first().and_then(|path| path.parent()) { save_last_path(LAST_PATH_ADD_FILE, folder); }
 
                             // Get the Paths of the files inside the folders we want to add.
                             let ui_base_path: Vec<String> = <MutPtr<QTreeView> as PackTree>::get_path_from_main_treeview_selection(&pack_file_contents_ui)[0].to_vec();
@@ -557,7 +590,7 @@ impl PackFileContentsSlots {
                         }
                     }
                 }
-            }
+            })
         );
 
         // What happens when we trigger the "Add From PackFile" action in the Contextual Menu.
@@ -569,55 +602,13 @@ impl PackFileContentsSlots {
                     app_ui.main_window,
                     &qtr("context_menu_select_packfile"),
                 );
+                setup_file_dialog(&mut file_dialog, Some(LAST_PATH_OPEN_PACKFILE));
 
                 file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
                 if file_dialog.exec() == 1 {
-                    let path_str = file_dialog.selected_files().at(0).to_std_string();
-                    let path = PathBuf::from(path_str.to_owned());
-                    app_ui.main_window.set_enabled(false);
-
-                    let fake_path = vec![RESERVED_NAME_EXTRA_PACKFILE.to_owned(), path_str.to_owned()];
-
-                    // Close all preview views except the file we're opening.
-                    for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
-                        let open_path = packed_file_view.get_ref_path();
-                        let index = app_ui.tab_bar_packed_file.index_of(packed_file_view.get_mut_widget());
-                        if *open_path != fake_path && packed_file_view.get_is_preview() && index != -1 {
-                            app_ui.tab_bar_packed_file.remove_tab(index);
-                        }
-                    }
-
-                    // If the PackFile is already open, or it's hidden, we show it/focus it, instead of opening it again.
-                    if let Some(tab_widget) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == fake_path) {
-                        let index = app_ui.tab_bar_packed_file.index_of(tab_widget.get_mut_widget());
-
-                        if index == -1 {
-                            let icon_type = IconType::PackFile(true);
-                            let icon = icon_type.get_icon_from_path();
-                            app_ui.tab_bar_packed_file.add_tab_3a(tab_widget.get_mut_widget(), icon, &QString::from_std_str(&path_str));
-                        }
-
-                        app_ui.tab_bar_packed_file.set_current_widget(tab_widget.get_mut_widget());
-                        return;
-                    }
-
-                    let mut tab = PackedFileView::default();
-                    let icon_type = IconType::PackFile(false);
-                    let icon = icon_type.get_icon_from_path();
-                    tab.set_is_preview(false);
-                    tab.set_path(&fake_path);
-
-                    match PackFileExtraView::new_view(&mut tab, &app_ui, &pack_file_contents_ui, &global_search_ui, path) {
-                        Ok(slots) => {
-                            slot_holder.borrow_mut().push(slots);
-
-                            app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(&path_str));
-                            app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
-                            UI_STATE.set_open_packedfiles().push(tab);
-                        }
-                        Err(error) => show_dialog(app_ui.main_window, error, false),
-                    }
-                    app_ui.main_window.set_enabled(true);
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    if let Some(folder) = path.parent() { save_last_path(LAST_PATH_OPEN_PACKFILE, folder); }
+                    app_ui.open_packfile_extra_view(&pack_file_contents_ui, &global_search_ui, &slot_holder, &path);
                 }
             }
         ));
@@ -638,10 +629,11 @@ impl PackFileContentsSlots {
                         UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
 
                         // Remove all the deleted PackedFiles from the cache.
+                        let mut problems = Problems::default();
                         for item in &items {
                             match item {
                                 TreePathType::File(path) => if let Err(error) = app_ui.purge_that_one_specifically(global_search_ui, pack_file_contents_ui, &path, false) {
-                                    show_dialog(app_ui.main_window, error, false);
+                                    problems.push(&path.join("/"), error);
                                 }
                                 TreePathType::Folder(path) => {
                                     let mut paths_to_remove = vec![];
@@ -663,6 +655,7 @@ impl PackFileContentsSlots {
                                 TreePathType::None => unreachable!(),
                             }
                         }
+                        problems.report(app_ui.main_window);
                     },
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                 };
@@ -670,70 +663,27 @@ impl PackFileContentsSlots {
         ));
 
         // What happens when we trigger the "Extract" action in the Contextual Menu.
-        let contextual_menu_extract = SlotOfBool::new(move |_| {
-
-                // Get the currently selected paths (and visible) paths.
-                let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
-                let selected_items = selected_items.iter().map(From::from).collect::<Vec<PathType>>();
-                let extraction_path = match UI_STATE.get_operational_mode() {
-
-                    // In MyMod mode we extract directly to the folder of the selected MyMod, keeping the folder structure.
-                    OperationalMode::MyMod(ref game_folder_name, ref mod_name) => {
-                        if let Some(ref mymods_base_path) = SETTINGS.read().unwrap().paths["mymods_base_path"] {
-
-                            // We get the assets folder of our mod (without .pack extension). This mess removes the .pack.
-                            let mut mod_name = mod_name.to_owned();
-                            mod_name.pop();
-                            mod_name.pop();
-                            mod_name.pop();
-                            mod_name.pop();
-                            mod_name.pop();
-
-                            let mut assets_folder = mymods_base_path.to_path_buf();
-                            assets_folder.push(&game_folder_name);
-                            assets_folder.push(&mod_name);
-                            assets_folder
-                        }
-
-                        // If there is no MyMod path configured, report it.
-                        else { return show_dialog(app_ui.main_window, ErrorKind::MyModPathNotConfigured, true); }
-                    }
-
-                    // In normal mode, we ask the user to provide us with a path.
-                    OperationalMode::Normal => {
-                        let extraction_path = QFileDialog::get_existing_directory_2a(
-                            app_ui.main_window,
-                            &qtr("context_menu_extract_packfile"),
-                        );
-
-                        if !extraction_path.is_empty() { PathBuf::from(extraction_path.to_std_string()) }
-                        else { return }
-                    }
-                };
-
-                // We have to save our data from cache to the backend before extracting it. Otherwise we would extract outdated data.
-                // TODO: Make this more... optimal.
-                if let Err(error) = UI_STATE.get_open_packedfiles().iter().try_for_each(|packed_file| packed_file.save(&mut app_ui, global_search_ui, &mut pack_file_contents_ui)) {
-                    show_dialog(app_ui.main_window, error, false);
-                }
+        let contextual_menu_extract = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                pack_file_contents_ui.extract_packed_files(&mut app_ui, global_search_ui, None, false);
+            })
+        );
 
-                else {
-                    CENTRAL_COMMAND.send_message_qt(Command::ExtractPackedFiles(selected_items, extraction_path));
-                    app_ui.main_window.set_enabled(false);
-                    let response = CENTRAL_COMMAND.recv_message_qt();
-                    match response {
-                        Response::String(result) => show_dialog(app_ui.main_window, result, true),
-                        Response::Error(error) => show_dialog(app_ui.main_window, error, false),
-                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
-                    }
-                    app_ui.main_window.set_enabled(true);
+        // What happens when we trigger the "Extract with Path Rewrite" action in the Contextual Menu.
+        let contextual_menu_extract_with_path_rewrite = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                if let Some(path_rewrite) = PackFileContentsUI::create_path_rewrite_dialog(&app_ui) {
+                    pack_file_contents_ui.extract_packed_files(&mut app_ui, global_search_ui, Some(path_rewrite), false);
                 }
-            }
+            })
         );
 
+        // What happens when we trigger the "Extract Converted" action in the Contextual Menu.
+        let contextual_menu_extract_converted = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+                pack_file_contents_ui.extract_packed_files(&mut app_ui, global_search_ui, None, true);
+            })
+        );
 
         // What happens when we trigger the "Rename" Action.
-        let contextual_menu_rename = SlotOfBool::new(move |_| {
+        let contextual_menu_rename = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Get the currently selected items, and check how many of them are valid before trying to rewrite them.
                 // Why? Because I'm sure there is an asshole out there that it's going to try to give the files duplicated
@@ -754,93 +704,41 @@ impl PackFileContentsSlots {
                         }
                     }
 
-                    // Send the renaming data to the Background Thread, wait for a response.
-                    CENTRAL_COMMAND.send_message_qt(Command::RenamePackedFiles(renaming_data_background.to_vec()));
-                    let response = CENTRAL_COMMAND.recv_message_qt();
-                    match response {
-                        Response::VecPathTypeVecString(renamed_items) => {
-                            let renamed_items = renamed_items.iter().map(|x| (From::from(&x.0), x.1.to_owned())).collect::<Vec<(TreePathType, Vec<String>)>>();
-                            let mut path_changes = vec![];
-                            for path in UI_STATE.get_open_packedfiles().iter().map(|x| x.get_ref_path()) {
-                                if !path.is_empty() {
-                                    for (item_type, new_path) in &renamed_items {
-
-                                        // Due to how the backend is built (doing a Per-PackedFile movement) we will always receive here individual PackedFiles.
-                                        // So we don't need to check the rest. But the name change can be in any place of the path, so we have to take that into account.
-                                        if let TreePathType::File(ref current_path) = item_type {
-                                            if *current_path == *path {
-                                                path_changes.push((current_path.to_vec(), new_path.to_vec()));
-
-                                                // Update the global search stuff, if needed.
-                                                global_search_ui.search_on_path(&mut pack_file_contents_ui, vec![PathType::File(new_path.to_vec()); 1]);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-
-                            for (path_before, path_after) in &path_changes {
-                                let mut open_packedfiles = UI_STATE.set_open_packedfiles();
-                                let position = open_packedfiles.iter().position(|x| *x.get_ref_path() == *path_before).unwrap();
-                                let data = open_packedfiles.remove(position);
-                                let widget = data.get_mut_widget();
-                                let index = app_ui.tab_bar_packed_file.index_of(widget);
-                                let old_name = path_before.last().unwrap();
-                                let new_name = path_after.last().unwrap();
-                                if old_name != new_name {
-                                    app_ui.tab_bar_packed_file.set_tab_text(index, &QString::from_std_str(new_name));
-                                }
-
-                                data.set_path(path_after);
-                                open_packedfiles.push(data);
-                            }
-
-                            // Ok, problem here: the view expects you pass the exact items renamed, NOT THE GODDAM FILES!!!!
-                            // which menas in case of folders we have turn all those "renamed items" into a big "renamed folder".
-                            // What a fucking planning mess.
-                            let renamed_items_view: Vec<(TreePathType, Vec<String>)> = renaming_data_background.iter().map(|(x, y)| {
-                                let path = if let PathType::File(path) | PathType::Folder(path) = x {
-                                    let mut path = path.to_vec();
-                                    *path.last_mut().unwrap() = y.to_owned();
-                                    path
-                                } else { unimplemented!() };
-                                (TreePathType::from(x), path)
-                            }).collect();
-
-                            let mut blocker = QSignalBlocker::from_q_object(pack_file_contents_ui.packfile_contents_tree_view.selection_model().static_upcast_mut::<QObject>());
-                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Move(renamed_items_view));
-                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(renamed_items.iter().map(|x| match x.0 {
-                                TreePathType::File(_) => TreePathType::File(x.1.to_vec()),
-                                TreePathType::Folder(_) => TreePathType::Folder(x.1.to_vec()),
-                                _ => unimplemented!()
-                            }).collect()));
-                            blocker.unblock();
-                            UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
-                        },
-                        Response::Error(error) => show_dialog(app_ui.main_window, error, false),
-                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
-                    }
+                    pack_file_contents_ui.rename_packed_files(&mut app_ui, &mut global_search_ui, renaming_data_background);
                 }
-            }
+            })
         );
 
+        // What happens when we trigger the "Mass Rename" Action.
+        let contextual_menu_mass_rename = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+            if let Some(renaming_data) = PackFileContentsUI::create_mass_rename_dialog(&mut app_ui, &selected_items) {
+                pack_file_contents_ui.rename_packed_files(&mut app_ui, &mut global_search_ui, renaming_data);
+            }
+        }));
+
+        // What happens when we trigger the "Undo Move" Action.
+        let contextual_menu_undo_move = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            pack_file_contents_ui.undo_last_move(&mut app_ui, &mut global_search_ui);
+        }));
+
         // What happens when we trigger the "Create DB PackedFile" Action.
-        let contextual_menu_new_packed_file_db = SlotOfBool::new(move |_| {
+        let contextual_menu_new_packed_file_db = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             app_ui.new_packed_file(&mut pack_file_contents_ui, PackedFileType::DB);
-        });
+        }));
 
         // What happens when we trigger the "Create Loc PackedFile" Action.
-        let contextual_menu_new_packed_file_loc = SlotOfBool::new(move |_| {
+        let contextual_menu_new_packed_file_loc = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             app_ui.new_packed_file(&mut pack_file_contents_ui, PackedFileType::Loc);
-        });
+        }));
 
         // What happens when we trigger the "Create Text PackedFile" Action.
-        let contextual_menu_new_packed_file_text = SlotOfBool::new(move |_| {
+        let contextual_menu_new_packed_file_text = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             app_ui.new_packed_file(&mut pack_file_contents_ui, PackedFileType::Text(TextType::Plain));
-        });
+        }));
 
         // What happens when we trigger the "New Folder" Action.
-        let contextual_menu_new_folder = SlotOfBool::new(move |_| {
+        let contextual_menu_new_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Create the "New Folder" dialog and wait for a new name (or a cancelation).
                 if let Some(new_folder_name) = app_ui.new_folder_dialog() {
@@ -865,13 +763,13 @@ impl PackFileContentsSlots {
                         UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
                     }
                 }
-            }
+            })
         );
 
         // What happens when we trigger the "Create Text PackedFile" Action.
-        let contextual_menu_new_queek_packed_file = SlotOfBool::new(move |_| {
+        let contextual_menu_new_queek_packed_file = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             app_ui.new_queek_packed_file(&mut pack_file_contents_ui);
-        });
+        }));
 
         // What happens when we trigger the "Open Decoder" Action.
         let contextual_menu_open_decoder = SlotOfBool::new(clone!(slot_holder => move |_| {
@@ -880,11 +778,11 @@ impl PackFileContentsSlots {
 
         // What happens when we trigger the "Open Dependency Table" Action.
         let contextual_menu_open_dependency_manager = SlotOfBool::new(clone!(slot_holder => move |_| {
-            app_ui.open_dependency_manager(&pack_file_contents_ui, &global_search_ui, &slot_holder);
+            app_ui.open_dependency_manager(&pack_file_contents_ui, &slot_holder);
         }));
 
         // What happens when we trigger the "Open Containing Folder" Action.
-        let contextual_menu_open_containing_folder = SlotOfBool::new(move |_| {
+        let contextual_menu_open_containing_folder = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
             CENTRAL_COMMAND.send_message_qt(Command::OpenContainingFolder);
             let response = CENTRAL_COMMAND.recv_message_qt();
             match response {
@@ -892,7 +790,7 @@ impl PackFileContentsSlots {
                 Response::Error(error) => show_dialog(app_ui.main_window, error, false),
                 _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
             }
-        });
+        }));
 
         // What happens when we trigger the "Open In External Program" Action.
         let contextual_menu_open_in_external_program = SlotOfBool::new(clone!(
@@ -907,22 +805,47 @@ impl PackFileContentsSlots {
         }));
 
         // What happens when we trigger the "Check Tables" action in the Contextual Menu.
-        let contextual_menu_tables_check_integrity = SlotOfBool::new(move |_| {
+        let contextual_menu_tables_check_integrity = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
             // Disable the window and trigger the check for all tables in the PackFile.
             app_ui.main_window.set_enabled(false);
             CENTRAL_COMMAND.send_message_qt(Command::DBCheckTableIntegrity);
             let response = CENTRAL_COMMAND.recv_message_qt();
             match response {
-                Response::Success => show_dialog(app_ui.main_window, tr("no_errors_detected"), true),
+                Response::VecString(entries) => {
+                    if entries.is_empty() { show_dialog(app_ui.main_window, tr("no_errors_detected"), true); }
+
+                    // If we found errors, give the user the chance to ignore them in future checks, as some of them may be intentional.
+                    else {
+                        let message = format!("{}{}", ErrorKind::DBMissingReferences(entries.to_vec()), tr("check_tables_ignore_findings"));
+                        let ignore = QMessageBox::from_2_q_string_icon3_int_q_widget(
+                            &qtr("context_menu_check_tables"),
+                            &QString::from_std_str(&message),
+                            q_message_box::Icon::Warning,
+                            65536, // No
+                            16384, // Yes
+                            1, // By default, select yes.
+                            app_ui.main_window,
+                        ).exec() == 3;
+
+                        if ignore {
+                            CENTRAL_COMMAND.send_message_qt(Command::AddDiagnosticsIgnored(entries));
+                            let response = CENTRAL_COMMAND.recv_message_qt();
+                            match response {
+                                Response::Success => UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui),
+                                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                            }
+                        }
+                    }
+                }
                 Response::Error(error) => show_dialog(app_ui.main_window, error, false),
                 _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
             }
             app_ui.main_window.set_enabled(true);
-        });
+        }));
 
         // What happens when we trigger the "Merge Tables" action in the Contextual Menu.
-        let contextual_menu_tables_merge_tables = SlotOfBool::new(move |_| {
+        let contextual_menu_tables_merge_tables = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
             // Get the currently selected paths, and get how many we have of each type.
             let selected_paths = <MutPtr<QTreeView> as PackTree>::get_path_from_main_treeview_selection(&pack_file_contents_ui);
@@ -1018,7 +941,7 @@ impl PackFileContentsSlots {
             }
 
             else { show_dialog(app_ui.main_window, ErrorKind::InvalidFilesForMerging, false); }
-        });
+        }));
 
 
         // What happens when we trigger the "Update Table" action in the Contextual Menu.
@@ -1056,10 +979,47 @@ impl PackFileContentsSlots {
             }
         }));
 
+        // What happens when we trigger the "Bind Folder to Table" action in the Contextual Menu.
+        let contextual_menu_bind_folder_to_table = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+            let folder_path = match selected_items.as_slice() {
+                [TreePathType::Folder(path)] => path.to_vec(),
+                _ => return,
+            };
+
+            if let Some((table_path, key_column, value_column, key_pattern, value_pattern)) = PackFileContentsUI::create_bind_folder_to_table_dialog(&app_ui, &folder_path) {
+
+                // First, if the table is open, save it.
+                if let Err(error) = app_ui.purge_that_one_specifically(global_search_ui, pack_file_contents_ui, &table_path, true) {
+                    return show_dialog(app_ui.main_window, error, false);
+                }
+
+                CENTRAL_COMMAND.send_message_qt(Command::BindFolderToTable((folder_path, table_path.to_vec(), key_column, value_column, key_pattern, value_pattern)));
+                let response = CENTRAL_COMMAND.recv_message_qt();
+                match response {
+                    Response::I32I32((added, updated)) => {
+                        let message = tre("bind_folder_success", &[&added.to_string(), &updated.to_string()]);
+                        show_dialog(app_ui.main_window, message, true);
+
+                        if added > 0 || updated > 0 {
+                            let item_type = TreePathType::File(table_path.to_vec());
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(vec![item_type; 1]));
+                            UI_STATE.set_is_modified(true, &mut app_ui, &mut pack_file_contents_ui);
+
+                            global_search_ui.search_on_path(&mut pack_file_contents_ui, vec![PathType::File(table_path); 1]);
+                        }
+                    }
+
+                    Response::Error(error) => show_dialog(app_ui.main_window, error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+        }));
+
         // What happens when we trigger the "Mass-Import TSV" Action.
         //
         // TODO: Make it so the name of the table is split off when importing keeping the original name.
-        let contextual_menu_mass_import_tsv = SlotOfBool::new(move |_| {
+        let contextual_menu_mass_import_tsv = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Don't do anything if there is a PackedFile open. This fixes the situation where you could overwrite data already in the UI.
                 //if !packedfiles_open_in_packedfile_view.borrow().is_empty() { return show_dialog(app_ui.window, false, ErrorKind::PackedFileIsOpen) }
@@ -1105,21 +1065,24 @@ impl PackFileContentsSlots {
                         app_ui.main_window.set_enabled(true);
                     }
                 }
-            }
+            })
         );
 
         // What happens when we trigger the "Mass-Export TSV" Action.
-        let contextual_menu_mass_export_tsv = SlotOfBool::new(move |_| {
+        let contextual_menu_mass_export_tsv = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
 
                 // Get a "Folder-only" FileDialog.
-                let export_path = QFileDialog::get_existing_directory_2a(
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
                     app_ui.main_window,
                     &qtr("context_menu_mass_export_tsv_folder")
                 );
+                setup_file_dialog(&mut file_dialog, None);
+                file_dialog.set_file_mode(FileMode::Directory);
+                file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
 
-                // If we got an export path and it's not empty, try to export all selected files there.
-                if !export_path.is_empty() {
-                    let export_path = PathBuf::from(export_path.to_std_string());
+                // If we got an export path, try to export all selected files there.
+                if file_dialog.exec() == 1 {
+                    let export_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
                     if export_path.is_dir() {
                         app_ui.main_window.set_enabled(false);
                         let selected_items = <MutPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
@@ -1135,11 +1098,26 @@ impl PackFileContentsSlots {
                         app_ui.main_window.set_enabled(true);
                     }
                 }
-            }
+            })
         );
 
-        let packfile_contents_tree_view_expand_all = Slot::new(move || { pack_file_contents_ui.packfile_contents_tree_view.expand_all(); });
-        let packfile_contents_tree_view_collapse_all = Slot::new(move || { pack_file_contents_ui.packfile_contents_tree_view.collapse_all(); });
+        let packfile_contents_tree_view_expand_all = Slot::new(move || catch_panic_in_slot(|| { pack_file_contents_ui.packfile_contents_tree_view.expand_all(); }));
+        let packfile_contents_tree_view_collapse_all = Slot::new(move || catch_panic_in_slot(|| { pack_file_contents_ui.packfile_contents_tree_view.collapse_all(); }));
+
+        // What happens when we drop files or folders from the OS into the TreeView.
+        let packfile_contents_tree_view_files_dropped = SlotOfQModelIndex::new(move |index| catch_panic_in_slot(|| {
+            pack_file_contents_ui.add_dropped_files(&mut app_ui, &mut global_search_ui, index);
+        }));
+
+        // What happens when we start dragging the selection out of the TreeView.
+        let packfile_contents_tree_view_drag_out = Slot::new(move || catch_panic_in_slot(|| {
+            pack_file_contents_ui.prepare_drag_out(&mut app_ui, global_search_ui);
+        }));
+
+        // What happens when we drag and drop items within the TreeView.
+        let packfile_contents_tree_view_items_moved = SlotOfQModelIndex::new(move |index| catch_panic_in_slot(|| {
+            pack_file_contents_ui.move_dropped_items(&mut app_ui, &mut global_search_ui, index);
+        }));
 
         // And here... we return all the slots.
 		Self {
@@ -1160,7 +1138,11 @@ impl PackFileContentsSlots {
             contextual_menu_add_from_packfile,
             contextual_menu_delete,
             contextual_menu_extract,
+            contextual_menu_extract_with_path_rewrite,
+            contextual_menu_extract_converted,
             contextual_menu_rename,
+            contextual_menu_mass_rename,
+            contextual_menu_undo_move,
 
             contextual_menu_new_packed_file_db,
             contextual_menu_new_packed_file_loc,
@@ -1177,12 +1159,16 @@ impl PackFileContentsSlots {
             contextual_menu_tables_check_integrity,
             contextual_menu_tables_merge_tables,
             contextual_menu_tables_update_table,
+            contextual_menu_bind_folder_to_table,
 
             contextual_menu_mass_import_tsv,
             contextual_menu_mass_export_tsv,
 
             packfile_contents_tree_view_expand_all,
             packfile_contents_tree_view_collapse_all,
+            packfile_contents_tree_view_files_dropped,
+            packfile_contents_tree_view_drag_out,
+            packfile_contents_tree_view_items_moved,
 		}
 	}
 }
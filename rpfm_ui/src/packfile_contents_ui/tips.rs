@@ -34,9 +34,14 @@ pub unsafe fn set_tips(ui: &mut PackFileContentsUI) {
     ui.context_menu_mass_export_tsv.set_status_tip(&qtr("tt_context_menu_mass_export_tsv"));
     ui.context_menu_merge_tables.set_status_tip(&qtr("tt_context_menu_merge_tables"));
     ui.context_menu_update_table.set_status_tip(&qtr("tt_context_menu_update_tables"));
+    ui.context_menu_bind_folder_to_table.set_status_tip(&qtr("tt_context_menu_bind_folder_to_table"));
     ui.context_menu_delete.set_status_tip(&qtr("tt_context_menu_delete"));
     ui.context_menu_extract.set_status_tip(&qtr("tt_context_menu_extract"));
+    ui.context_menu_extract_with_path_rewrite.set_status_tip(&qtr("tt_context_menu_extract_with_path_rewrite"));
+    ui.context_menu_extract_converted.set_status_tip(&qtr("tt_context_menu_extract_converted"));
     ui.context_menu_rename.set_status_tip(&qtr("tt_context_menu_rename"));
+    ui.context_menu_mass_rename.set_status_tip(&qtr("tt_context_menu_mass_rename"));
+    ui.context_menu_undo_move.set_status_tip(&qtr("tt_context_menu_undo_move"));
     ui.context_menu_open_decoder.set_status_tip(&qtr("tt_context_menu_open_decoder"));
     ui.context_menu_open_dependency_manager.set_status_tip(&qtr("tt_context_menu_open_dependency_manager"));
     ui.context_menu_open_containing_folder.set_status_tip(&qtr("tt_context_menu_open_containing_folder"));
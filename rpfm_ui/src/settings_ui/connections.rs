@@ -23,6 +23,7 @@ use super::{SettingsUI, slots::SettingsUISlots};
 pub unsafe fn set_connections(settings_ui: &SettingsUI, slots: &SettingsUISlots) {
     settings_ui.paths_mymod_button.released().connect(&slots.select_mymod_path);
     settings_ui.paths_zip_button.released().connect(&slots.select_zip_path);
+    settings_ui.paths_steamcmd_button.released().connect(&slots.select_steamcmd_path);
 
     for (key, button) in settings_ui.paths_games_buttons.iter() {
         button.released().connect(&slots.select_game_paths[key]);
@@ -20,13 +20,14 @@ use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOpt
 use qt_widgets::QGroupBox;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
+use qt_widgets::QPlainTextEdit;
 use qt_widgets::QPushButton;
+use qt_widgets::QSpinBox;
 use qt_widgets::QWidget;
 
 use qt_gui::QGuiApplication;
 use qt_gui::QStandardItemModel;
 
-use qt_core::QFlags;
 use qt_core::QString;
 
 use cpp_core::CastInto;
@@ -36,12 +37,12 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use rpfm_lib::SUPPORTED_GAMES;
-use rpfm_lib::settings::{Settings, MYMOD_BASE_PATH, ZIP_PATH};
+use rpfm_lib::settings::{Settings, EXTERNAL_TOOLS, FAVORITE_FOLDERS, MYMOD_BASE_PATH, SCHEMA_UPDATE_INTERVAL, STEAMCMD_PATH, ZIP_PATH};
 
 use crate::AppUI;
 use crate::{Locale, locale::{qtr, qtre}};
 use crate::SETTINGS;
-use crate::utils::create_grid_layout;
+use crate::utils::{create_grid_layout, setup_file_dialog};
 use self::slots::SettingsUISlots;
 
 mod connections;
@@ -67,9 +68,16 @@ pub struct SettingsUI {
     pub paths_zip_label: MutPtr<QLabel>,
     pub paths_zip_line_edit: MutPtr<QLineEdit>,
     pub paths_zip_button: MutPtr<QPushButton>,
+    pub paths_steamcmd_label: MutPtr<QLabel>,
+    pub paths_steamcmd_line_edit: MutPtr<QLineEdit>,
+    pub paths_steamcmd_button: MutPtr<QPushButton>,
     pub paths_mymod_label: MutPtr<QLabel>,
     pub paths_mymod_line_edit: MutPtr<QLineEdit>,
     pub paths_mymod_button: MutPtr<QPushButton>,
+    pub paths_favorite_folders_label: MutPtr<QLabel>,
+    pub paths_favorite_folders_line_edit: MutPtr<QLineEdit>,
+    pub paths_external_tools_label: MutPtr<QLabel>,
+    pub paths_external_tools_text_edit: MutPtr<QPlainTextEdit>,
     pub paths_games_labels: BTreeMap<String, MutPtr<QLabel>>,
     pub paths_games_line_edits: BTreeMap<String, MutPtr<QLineEdit>>,
     pub paths_games_buttons: BTreeMap<String, MutPtr<QPushButton>>,
@@ -83,8 +91,16 @@ pub struct SettingsUI {
     pub ui_table_disable_combos_label: MutPtr<QLabel>,
     pub ui_table_extend_last_column_label: MutPtr<QLabel>,
     pub ui_table_tight_table_mode_label: MutPtr<QLabel>,
+    pub ui_table_loc_variant_suffixes_label: MutPtr<QLabel>,
+    pub ui_table_f32_decimals_label: MutPtr<QLabel>,
+    pub ui_table_use_thousands_separator_label: MutPtr<QLabel>,
+    pub ui_table_show_timestamps_as_dates_label: MutPtr<QLabel>,
+    pub ui_table_complete_with_vanilla_values_label: MutPtr<QLabel>,
+    pub ui_table_max_undo_steps_label: MutPtr<QLabel>,
+    pub ui_table_max_undo_memory_label: MutPtr<QLabel>,
     pub ui_window_start_maximized_label: MutPtr<QLabel>,
     pub ui_window_hide_background_icon_label: MutPtr<QLabel>,
+    pub ui_window_restore_last_session_label: MutPtr<QLabel>,
 
     pub ui_language_combobox: MutPtr<QComboBox>,
     pub ui_global_use_dark_theme_checkbox: MutPtr<QCheckBox>,
@@ -92,8 +108,16 @@ pub struct SettingsUI {
     pub ui_table_disable_combos_checkbox: MutPtr<QCheckBox>,
     pub ui_table_extend_last_column_checkbox: MutPtr<QCheckBox>,
     pub ui_table_tight_table_mode_checkbox: MutPtr<QCheckBox>,
+    pub ui_table_loc_variant_suffixes_line_edit: MutPtr<QLineEdit>,
+    pub ui_table_f32_decimals_spinbox: MutPtr<QSpinBox>,
+    pub ui_table_use_thousands_separator_checkbox: MutPtr<QCheckBox>,
+    pub ui_table_show_timestamps_as_dates_checkbox: MutPtr<QCheckBox>,
+    pub ui_table_complete_with_vanilla_values_checkbox: MutPtr<QCheckBox>,
+    pub ui_table_max_undo_steps_spinbox: MutPtr<QSpinBox>,
+    pub ui_table_max_undo_memory_spinbox: MutPtr<QSpinBox>,
     pub ui_window_start_maximized_checkbox: MutPtr<QCheckBox>,
     pub ui_window_hide_background_icon_checkbox: MutPtr<QCheckBox>,
+    pub ui_window_restore_last_session_checkbox: MutPtr<QCheckBox>,
 
     //-------------------------------------------------------------------------------//
     // `Extra` section of the `Settings` dialog.
@@ -106,6 +130,13 @@ pub struct SettingsUI {
     pub extra_packfile_use_dependency_checker_label: MutPtr<QLabel>,
     pub extra_packfile_use_lazy_loading_label: MutPtr<QLabel>,
     pub extra_disable_uuid_regeneration_on_db_tables_label: MutPtr<QLabel>,
+    pub extra_packfile_allow_overwriting_vanilla_packfiles_label: MutPtr<QLabel>,
+    pub extra_packfile_optimize_sort_table_rows_label: MutPtr<QLabel>,
+    pub extra_mymod_use_git_label: MutPtr<QLabel>,
+    pub extra_packfile_normalize_timestamps_on_save_label: MutPtr<QLabel>,
+    pub extra_packfile_autosave_interval_label: MutPtr<QLabel>,
+    pub extra_network_schema_update_interval_label: MutPtr<QLabel>,
+    pub extra_packfile_autosave_amount_label: MutPtr<QLabel>,
 
     pub extra_global_default_game_combobox: MutPtr<QComboBox>,
     pub extra_network_check_updates_on_start_checkbox: MutPtr<QCheckBox>,
@@ -115,6 +146,13 @@ pub struct SettingsUI {
     pub extra_packfile_use_dependency_checker_checkbox: MutPtr<QCheckBox>,
     pub extra_packfile_use_lazy_loading_checkbox: MutPtr<QCheckBox>,
     pub extra_disable_uuid_regeneration_on_db_tables_checkbox: MutPtr<QCheckBox>,
+    pub extra_packfile_allow_overwriting_vanilla_packfiles_checkbox: MutPtr<QCheckBox>,
+    pub extra_packfile_optimize_sort_table_rows_checkbox: MutPtr<QCheckBox>,
+    pub extra_mymod_use_git_checkbox: MutPtr<QCheckBox>,
+    pub extra_packfile_normalize_timestamps_on_save_checkbox: MutPtr<QCheckBox>,
+    pub extra_packfile_autosave_interval_spinbox: MutPtr<QSpinBox>,
+    pub extra_network_schema_update_interval_spinbox: MutPtr<QSpinBox>,
+    pub extra_packfile_autosave_amount_spinbox: MutPtr<QSpinBox>,
 
     //-------------------------------------------------------------------------------//
     // `Debug` section of the `Settings` dialog.
@@ -225,6 +263,33 @@ impl SettingsUI {
         paths_grid.add_widget_5a(&mut paths_zip_line_edit, 1, 1, 1, 1);
         paths_grid.add_widget_5a(&mut paths_zip_button, 1, 2, 1, 1);
 
+        // Create the SteamCMD path stuff.
+        let mut paths_steamcmd_label = QLabel::from_q_string(&qtr("settings_paths_steamcmd"));
+        let mut paths_steamcmd_line_edit = QLineEdit::new();
+        let mut paths_steamcmd_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+        paths_steamcmd_line_edit.set_placeholder_text(&qtr("settings_paths_steamcmd_ph"));
+
+        paths_grid.add_widget_5a(&mut paths_steamcmd_label, 2, 0, 1, 1);
+        paths_grid.add_widget_5a(&mut paths_steamcmd_line_edit, 2, 1, 1, 1);
+        paths_grid.add_widget_5a(&mut paths_steamcmd_button, 2, 2, 1, 1);
+
+        // Create the favorite folders stuff.
+        let mut paths_favorite_folders_label = QLabel::from_q_string(&qtr("settings_paths_favorite_folders"));
+        let mut paths_favorite_folders_line_edit = QLineEdit::new();
+        paths_favorite_folders_line_edit.set_placeholder_text(&qtr("settings_paths_favorite_folders_ph"));
+
+        paths_grid.add_widget_5a(&mut paths_favorite_folders_label, 3, 0, 1, 1);
+        paths_grid.add_widget_5a(&mut paths_favorite_folders_line_edit, 3, 1, 1, 2);
+
+        // Create the external tools stuff.
+        let mut paths_external_tools_label = QLabel::from_q_string(&qtr("settings_paths_external_tools"));
+        let mut paths_external_tools_text_edit = QPlainTextEdit::new();
+        paths_external_tools_text_edit.set_placeholder_text(&qtr("settings_paths_external_tools_ph"));
+        paths_external_tools_text_edit.set_maximum_height(80);
+
+        paths_grid.add_widget_5a(&mut paths_external_tools_label, 4, 0, 1, 1);
+        paths_grid.add_widget_5a(&mut paths_external_tools_text_edit, 4, 1, 1, 2);
+
         main_grid.add_widget_5a(extra_paths_frame, 1, 0, 1, 2);
 
         //-----------------------------------------------//
@@ -249,8 +314,16 @@ impl SettingsUI {
         let mut ui_table_disable_combos_label = QLabel::from_q_string(&qtr("settings_ui_table_disable_combos"));
         let mut ui_table_extend_last_column_label = QLabel::from_q_string(&qtr("settings_ui_table_extend_last_column_label"));
         let mut ui_table_tight_table_mode_label = QLabel::from_q_string(&qtr("settings_ui_table_tight_table_mode_label"));
+        let mut ui_table_loc_variant_suffixes_label = QLabel::from_q_string(&qtr("settings_ui_table_loc_variant_suffixes_label"));
+        let mut ui_table_f32_decimals_label = QLabel::from_q_string(&qtr("settings_ui_table_f32_decimals_label"));
+        let mut ui_table_use_thousands_separator_label = QLabel::from_q_string(&qtr("settings_ui_table_use_thousands_separator_label"));
+        let mut ui_table_show_timestamps_as_dates_label = QLabel::from_q_string(&qtr("settings_ui_table_show_timestamps_as_dates_label"));
+        let mut ui_table_complete_with_vanilla_values_label = QLabel::from_q_string(&qtr("settings_ui_table_complete_with_vanilla_values_label"));
+        let mut ui_table_max_undo_steps_label = QLabel::from_q_string(&qtr("settings_ui_table_max_undo_steps_label"));
+        let mut ui_table_max_undo_memory_label = QLabel::from_q_string(&qtr("settings_ui_table_max_undo_memory_label"));
         let mut ui_window_start_maximized_label = QLabel::from_q_string(&qtr("settings_ui_window_start_maximized_label"));
         let mut ui_window_hide_background_icon_label = QLabel::from_q_string(&qtr("settings_ui_window_hide_background_icon"));
+        let mut ui_window_restore_last_session_label = QLabel::from_q_string(&qtr("settings_ui_window_restore_last_session_label"));
 
         let mut ui_language_combobox = QComboBox::new_0a();
         let mut ui_global_use_dark_theme_checkbox = QCheckBox::new();
@@ -258,8 +331,20 @@ impl SettingsUI {
         let mut ui_table_disable_combos_checkbox = QCheckBox::new();
         let mut ui_table_extend_last_column_checkbox = QCheckBox::new();
         let mut ui_table_tight_table_mode_checkbox = QCheckBox::new();
+        let mut ui_table_loc_variant_suffixes_line_edit = QLineEdit::new();
+        let mut ui_table_f32_decimals_spinbox = QSpinBox::new_0a();
+        let mut ui_table_use_thousands_separator_checkbox = QCheckBox::new();
+        let mut ui_table_show_timestamps_as_dates_checkbox = QCheckBox::new();
+        let mut ui_table_complete_with_vanilla_values_checkbox = QCheckBox::new();
+        let mut ui_table_max_undo_steps_spinbox = QSpinBox::new_0a();
+        let mut ui_table_max_undo_memory_spinbox = QSpinBox::new_0a();
+        ui_table_max_undo_steps_spinbox.set_range(0, 100_000);
+        ui_table_max_undo_memory_spinbox.set_range(0, 65_536);
+        ui_table_max_undo_memory_spinbox.set_suffix(&QString::from_std_str(" MB"));
+        ui_table_f32_decimals_spinbox.set_range(0, 6);
         let mut ui_window_start_maximized_checkbox = QCheckBox::new();
         let mut ui_window_hide_background_icon_checkbox = QCheckBox::new();
+        let mut ui_window_restore_last_session_checkbox = QCheckBox::new();
 
         let ui_language_model = QStandardItemModel::new_0a().into_ptr();
         ui_language_combobox.set_model(ui_language_model);
@@ -281,8 +366,11 @@ impl SettingsUI {
         ui_grid.add_widget_5a(&mut ui_window_hide_background_icon_label, 2, 0, 1, 1);
         ui_grid.add_widget_5a(&mut ui_window_hide_background_icon_checkbox, 2, 1, 1, 1);
 
-        ui_grid.add_widget_5a(&mut ui_language_label, 3, 0, 1, 1);
-        ui_grid.add_widget_5a(&mut ui_language_combobox, 3, 1, 1, 1);
+        ui_grid.add_widget_5a(&mut ui_window_restore_last_session_label, 3, 0, 1, 1);
+        ui_grid.add_widget_5a(&mut ui_window_restore_last_session_checkbox, 3, 1, 1, 1);
+
+        ui_grid.add_widget_5a(&mut ui_language_label, 4, 0, 1, 1);
+        ui_grid.add_widget_5a(&mut ui_language_combobox, 4, 1, 1, 1);
 
         ui_table_view_grid.add_widget_5a(&mut ui_table_adjust_columns_to_content_label, 0, 0, 1, 1);
         ui_table_view_grid.add_widget_5a(&mut ui_table_adjust_columns_to_content_checkbox, 0, 1, 1, 1);
@@ -296,6 +384,27 @@ impl SettingsUI {
         ui_table_view_grid.add_widget_5a(&mut ui_table_tight_table_mode_label, 3, 0, 1, 1);
         ui_table_view_grid.add_widget_5a(&mut ui_table_tight_table_mode_checkbox, 3, 1, 1, 1);
 
+        ui_table_view_grid.add_widget_5a(&mut ui_table_loc_variant_suffixes_label, 4, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_loc_variant_suffixes_line_edit, 4, 1, 1, 1);
+
+        ui_table_view_grid.add_widget_5a(&mut ui_table_f32_decimals_label, 5, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_f32_decimals_spinbox, 5, 1, 1, 1);
+
+        ui_table_view_grid.add_widget_5a(&mut ui_table_use_thousands_separator_label, 6, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_use_thousands_separator_checkbox, 6, 1, 1, 1);
+
+        ui_table_view_grid.add_widget_5a(&mut ui_table_show_timestamps_as_dates_label, 7, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_show_timestamps_as_dates_checkbox, 7, 1, 1, 1);
+
+        ui_table_view_grid.add_widget_5a(&mut ui_table_complete_with_vanilla_values_label, 8, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_complete_with_vanilla_values_checkbox, 8, 1, 1, 1);
+
+        ui_table_view_grid.add_widget_5a(&mut ui_table_max_undo_steps_label, 9, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_max_undo_steps_spinbox, 9, 1, 1, 1);
+
+        ui_table_view_grid.add_widget_5a(&mut ui_table_max_undo_memory_label, 10, 0, 1, 1);
+        ui_table_view_grid.add_widget_5a(&mut ui_table_max_undo_memory_spinbox, 10, 1, 1, 1);
+
         ui_grid.add_widget_5a(ui_table_view_frame, 99, 0, 1, 2);
         main_grid.add_widget_5a(ui_frame, 2, 0, 2, 1);
 
@@ -323,6 +432,13 @@ impl SettingsUI {
         let mut extra_packfile_use_dependency_checker_label = QLabel::from_q_string(&qtr("settings_use_dependency_checker"));
         let mut extra_packfile_use_lazy_loading_label = QLabel::from_q_string(&qtr("settings_use_lazy_loading"));
         let mut extra_disable_uuid_regeneration_on_db_tables_label = QLabel::from_q_string(&qtr("settings_disable_uuid_regeneration_tables"));
+        let mut extra_packfile_allow_overwriting_vanilla_packfiles_label = QLabel::from_q_string(&qtr("settings_allow_overwriting_vanilla_packfiles"));
+        let mut extra_packfile_optimize_sort_table_rows_label = QLabel::from_q_string(&qtr("settings_optimize_sort_table_rows"));
+        let mut extra_mymod_use_git_label = QLabel::from_q_string(&qtr("settings_use_git_on_mymods"));
+        let mut extra_packfile_normalize_timestamps_on_save_label = QLabel::from_q_string(&qtr("settings_normalize_timestamps_on_save"));
+        let mut extra_packfile_autosave_interval_label = QLabel::from_q_string(&qtr("settings_autosave_interval"));
+        let mut extra_packfile_autosave_amount_label = QLabel::from_q_string(&qtr("settings_autosave_amount"));
+        let mut extra_network_schema_update_interval_label = QLabel::from_q_string(&qtr("settings_schema_update_interval"));
 
         let mut extra_network_check_updates_on_start_checkbox = QCheckBox::new();
         let mut extra_network_check_schema_updates_on_start_checkbox = QCheckBox::new();
@@ -331,6 +447,16 @@ impl SettingsUI {
         let mut extra_packfile_use_dependency_checker_checkbox = QCheckBox::new();
         let mut extra_packfile_use_lazy_loading_checkbox = QCheckBox::new();
         let mut extra_disable_uuid_regeneration_on_db_tables_checkbox = QCheckBox::new();
+        let mut extra_packfile_allow_overwriting_vanilla_packfiles_checkbox = QCheckBox::new();
+        let mut extra_packfile_optimize_sort_table_rows_checkbox = QCheckBox::new();
+        let mut extra_mymod_use_git_checkbox = QCheckBox::new();
+        let mut extra_packfile_normalize_timestamps_on_save_checkbox = QCheckBox::new();
+        let mut extra_packfile_autosave_interval_spinbox = QSpinBox::new_0a();
+        let mut extra_packfile_autosave_amount_spinbox = QSpinBox::new_0a();
+        extra_packfile_autosave_interval_spinbox.set_range(0, 120);
+        let mut extra_network_schema_update_interval_spinbox = QSpinBox::new_0a();
+        extra_packfile_autosave_amount_spinbox.set_range(1, 100);
+        extra_network_schema_update_interval_spinbox.set_range(0, 720);
 
         extra_grid.add_widget_5a(&mut extra_global_default_game_label, 0, 0, 1, 1);
         extra_grid.add_widget_5a(&mut extra_global_default_game_combobox, 0, 1, 1, 1);
@@ -356,6 +482,27 @@ impl SettingsUI {
         extra_grid.add_widget_5a(&mut extra_disable_uuid_regeneration_on_db_tables_label, 7, 0, 1, 1);
         extra_grid.add_widget_5a(&mut extra_disable_uuid_regeneration_on_db_tables_checkbox, 7, 1, 1, 1);
 
+        extra_grid.add_widget_5a(&mut extra_packfile_allow_overwriting_vanilla_packfiles_label, 8, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_packfile_allow_overwriting_vanilla_packfiles_checkbox, 8, 1, 1, 1);
+
+        extra_grid.add_widget_5a(&mut extra_packfile_optimize_sort_table_rows_label, 9, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_packfile_optimize_sort_table_rows_checkbox, 9, 1, 1, 1);
+
+        extra_grid.add_widget_5a(&mut extra_mymod_use_git_label, 10, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_mymod_use_git_checkbox, 10, 1, 1, 1);
+
+        extra_grid.add_widget_5a(&mut extra_packfile_autosave_interval_label, 11, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_packfile_autosave_interval_spinbox, 11, 1, 1, 1);
+
+        extra_grid.add_widget_5a(&mut extra_packfile_autosave_amount_label, 12, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_packfile_autosave_amount_spinbox, 12, 1, 1, 1);
+
+        extra_grid.add_widget_5a(&mut extra_packfile_normalize_timestamps_on_save_label, 13, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_packfile_normalize_timestamps_on_save_checkbox, 13, 1, 1, 1);
+
+        extra_grid.add_widget_5a(&mut extra_network_schema_update_interval_label, 14, 0, 1, 1);
+        extra_grid.add_widget_5a(&mut extra_network_schema_update_interval_spinbox, 14, 1, 1, 1);
+
         main_grid.add_widget_5a(extra_frame, 2, 1, 1, 1);
 
         //-----------------------------------------------//
@@ -412,9 +559,16 @@ impl SettingsUI {
             paths_zip_label: paths_zip_label.into_ptr(),
             paths_zip_line_edit: paths_zip_line_edit.into_ptr(),
             paths_zip_button: paths_zip_button.into_ptr(),
+            paths_steamcmd_label: paths_steamcmd_label.into_ptr(),
+            paths_steamcmd_line_edit: paths_steamcmd_line_edit.into_ptr(),
+            paths_steamcmd_button: paths_steamcmd_button.into_ptr(),
             paths_mymod_label: paths_mymod_label.into_ptr(),
             paths_mymod_line_edit: paths_mymod_line_edit.into_ptr(),
             paths_mymod_button: paths_mymod_button.into_ptr(),
+            paths_favorite_folders_label: paths_favorite_folders_label.into_ptr(),
+            paths_favorite_folders_line_edit: paths_favorite_folders_line_edit.into_ptr(),
+            paths_external_tools_label: paths_external_tools_label.into_ptr(),
+            paths_external_tools_text_edit: paths_external_tools_text_edit.into_ptr(),
             paths_games_labels,
             paths_games_line_edits,
             paths_games_buttons,
@@ -428,8 +582,16 @@ impl SettingsUI {
             ui_table_disable_combos_label: ui_table_disable_combos_label.into_ptr(),
             ui_table_extend_last_column_label: ui_table_extend_last_column_label.into_ptr(),
             ui_table_tight_table_mode_label: ui_table_tight_table_mode_label.into_ptr(),
+            ui_table_loc_variant_suffixes_label: ui_table_loc_variant_suffixes_label.into_ptr(),
+            ui_table_f32_decimals_label: ui_table_f32_decimals_label.into_ptr(),
+            ui_table_use_thousands_separator_label: ui_table_use_thousands_separator_label.into_ptr(),
+            ui_table_show_timestamps_as_dates_label: ui_table_show_timestamps_as_dates_label.into_ptr(),
+            ui_table_complete_with_vanilla_values_label: ui_table_complete_with_vanilla_values_label.into_ptr(),
+            ui_table_max_undo_steps_label: ui_table_max_undo_steps_label.into_ptr(),
+            ui_table_max_undo_memory_label: ui_table_max_undo_memory_label.into_ptr(),
             ui_window_start_maximized_label: ui_window_start_maximized_label.into_ptr(),
             ui_window_hide_background_icon_label: ui_window_hide_background_icon_label.into_ptr(),
+            ui_window_restore_last_session_label: ui_window_restore_last_session_label.into_ptr(),
 
             ui_language_combobox: ui_language_combobox.into_ptr(),
             ui_global_use_dark_theme_checkbox: ui_global_use_dark_theme_checkbox.into_ptr(),
@@ -437,8 +599,16 @@ impl SettingsUI {
             ui_table_disable_combos_checkbox: ui_table_disable_combos_checkbox.into_ptr(),
             ui_table_extend_last_column_checkbox: ui_table_extend_last_column_checkbox.into_ptr(),
             ui_table_tight_table_mode_checkbox: ui_table_tight_table_mode_checkbox.into_ptr(),
+            ui_table_loc_variant_suffixes_line_edit: ui_table_loc_variant_suffixes_line_edit.into_ptr(),
+            ui_table_f32_decimals_spinbox: ui_table_f32_decimals_spinbox.into_ptr(),
+            ui_table_use_thousands_separator_checkbox: ui_table_use_thousands_separator_checkbox.into_ptr(),
+            ui_table_show_timestamps_as_dates_checkbox: ui_table_show_timestamps_as_dates_checkbox.into_ptr(),
+            ui_table_complete_with_vanilla_values_checkbox: ui_table_complete_with_vanilla_values_checkbox.into_ptr(),
+            ui_table_max_undo_steps_spinbox: ui_table_max_undo_steps_spinbox.into_ptr(),
+            ui_table_max_undo_memory_spinbox: ui_table_max_undo_memory_spinbox.into_ptr(),
             ui_window_start_maximized_checkbox: ui_window_start_maximized_checkbox.into_ptr(),
             ui_window_hide_background_icon_checkbox: ui_window_hide_background_icon_checkbox.into_ptr(),
+            ui_window_restore_last_session_checkbox: ui_window_restore_last_session_checkbox.into_ptr(),
 
             //-------------------------------------------------------------------------------//
             // `Extra` section of the `Settings` dialog.
@@ -451,6 +621,13 @@ impl SettingsUI {
             extra_packfile_use_dependency_checker_label: extra_packfile_use_dependency_checker_label.into_ptr(),
             extra_packfile_use_lazy_loading_label: extra_packfile_use_lazy_loading_label.into_ptr(),
             extra_disable_uuid_regeneration_on_db_tables_label: extra_disable_uuid_regeneration_on_db_tables_label.into_ptr(),
+            extra_packfile_allow_overwriting_vanilla_packfiles_label: extra_packfile_allow_overwriting_vanilla_packfiles_label.into_ptr(),
+            extra_packfile_optimize_sort_table_rows_label: extra_packfile_optimize_sort_table_rows_label.into_ptr(),
+            extra_mymod_use_git_label: extra_mymod_use_git_label.into_ptr(),
+            extra_packfile_normalize_timestamps_on_save_label: extra_packfile_normalize_timestamps_on_save_label.into_ptr(),
+            extra_packfile_autosave_interval_label: extra_packfile_autosave_interval_label.into_ptr(),
+            extra_network_schema_update_interval_label: extra_network_schema_update_interval_label.into_ptr(),
+            extra_packfile_autosave_amount_label: extra_packfile_autosave_amount_label.into_ptr(),
 
             extra_global_default_game_combobox: extra_global_default_game_combobox.into_ptr(),
             extra_network_check_updates_on_start_checkbox: extra_network_check_updates_on_start_checkbox.into_ptr(),
@@ -460,6 +637,13 @@ impl SettingsUI {
             extra_packfile_use_dependency_checker_checkbox: extra_packfile_use_dependency_checker_checkbox.into_ptr(),
             extra_packfile_use_lazy_loading_checkbox: extra_packfile_use_lazy_loading_checkbox.into_ptr(),
             extra_disable_uuid_regeneration_on_db_tables_checkbox: extra_disable_uuid_regeneration_on_db_tables_checkbox.into_ptr(),
+            extra_packfile_allow_overwriting_vanilla_packfiles_checkbox: extra_packfile_allow_overwriting_vanilla_packfiles_checkbox.into_ptr(),
+            extra_packfile_optimize_sort_table_rows_checkbox: extra_packfile_optimize_sort_table_rows_checkbox.into_ptr(),
+            extra_mymod_use_git_checkbox: extra_mymod_use_git_checkbox.into_ptr(),
+            extra_packfile_normalize_timestamps_on_save_checkbox: extra_packfile_normalize_timestamps_on_save_checkbox.into_ptr(),
+            extra_packfile_autosave_interval_spinbox: extra_packfile_autosave_interval_spinbox.into_ptr(),
+            extra_network_schema_update_interval_spinbox: extra_network_schema_update_interval_spinbox.into_ptr(),
+            extra_packfile_autosave_amount_spinbox: extra_packfile_autosave_amount_spinbox.into_ptr(),
 
             //-------------------------------------------------------------------------------//
             // `Debug` section of the `Settings` dialog.
@@ -486,6 +670,9 @@ impl SettingsUI {
         // Load the MyMod and 7Zip paths, if exists.
         self.paths_mymod_line_edit.set_text(&QString::from_std_str(settings.paths[MYMOD_BASE_PATH].clone().unwrap_or_else(PathBuf::new).to_string_lossy()));
         self.paths_zip_line_edit.set_text(&QString::from_std_str(settings.paths[ZIP_PATH].clone().unwrap_or_else(PathBuf::new).to_string_lossy()));
+        self.paths_steamcmd_line_edit.set_text(&QString::from_std_str(settings.paths[STEAMCMD_PATH].clone().unwrap_or_else(PathBuf::new).to_string_lossy()));
+        self.paths_favorite_folders_line_edit.set_text(&QString::from_std_str(&settings.settings_string[FAVORITE_FOLDERS]));
+        self.paths_external_tools_text_edit.set_plain_text(&QString::from_std_str(&settings.settings_string[EXTERNAL_TOOLS]));
 
         // Load the Game Paths, if they exists.
         for (key, path) in self.paths_games_line_edits.iter_mut() {
@@ -516,6 +703,14 @@ impl SettingsUI {
         self.ui_table_tight_table_mode_checkbox.set_checked(settings.settings_bool["tight_table_mode"]);
         self.ui_window_start_maximized_checkbox.set_checked(settings.settings_bool["start_maximized"]);
         self.ui_window_hide_background_icon_checkbox.set_checked(settings.settings_bool["hide_background_icon"]);
+        self.ui_window_restore_last_session_checkbox.set_checked(settings.settings_bool["restore_last_session_on_start"]);
+        self.ui_table_loc_variant_suffixes_line_edit.set_text(&QString::from_std_str(&settings.settings_string["loc_variant_suffixes"]));
+        self.ui_table_f32_decimals_spinbox.set_value(settings.settings_string["tables_f32_decimals"].parse::<i32>().unwrap_or(3));
+        self.ui_table_max_undo_steps_spinbox.set_value(settings.settings_string["tables_max_undo_steps"].parse::<i32>().unwrap_or(200));
+        self.ui_table_max_undo_memory_spinbox.set_value(settings.settings_string["tables_max_undo_memory_mb"].parse::<i32>().unwrap_or(512));
+        self.ui_table_use_thousands_separator_checkbox.set_checked(settings.settings_bool["tables_use_thousands_separator"]);
+        self.ui_table_show_timestamps_as_dates_checkbox.set_checked(settings.settings_bool["tables_show_timestamps_as_dates"]);
+        self.ui_table_complete_with_vanilla_values_checkbox.set_checked(settings.settings_bool["tables_complete_with_vanilla_values"]);
 
         // Load the Extra Stuff.
         self.extra_network_check_updates_on_start_checkbox.set_checked(settings.settings_bool["check_updates_on_start"]);
@@ -525,6 +720,13 @@ impl SettingsUI {
         self.extra_packfile_use_dependency_checker_checkbox.set_checked(settings.settings_bool["use_dependency_checker"]);
         self.extra_packfile_use_lazy_loading_checkbox.set_checked(settings.settings_bool["use_lazy_loading"]);
         self.extra_disable_uuid_regeneration_on_db_tables_checkbox.set_checked(settings.settings_bool["disable_uuid_regeneration_on_db_tables"]);
+        self.extra_packfile_allow_overwriting_vanilla_packfiles_checkbox.set_checked(settings.settings_bool["allow_overwriting_vanilla_packfiles"]);
+        self.extra_packfile_optimize_sort_table_rows_checkbox.set_checked(settings.settings_bool["optimize_sort_table_rows"]);
+        self.extra_mymod_use_git_checkbox.set_checked(settings.settings_bool["use_git_on_mymods"]);
+        self.extra_packfile_normalize_timestamps_on_save_checkbox.set_checked(settings.settings_bool["normalize_timestamps_on_save"]);
+        self.extra_packfile_autosave_interval_spinbox.set_value(settings.settings_string["autosave_interval"].parse::<i32>().unwrap_or(5));
+        self.extra_network_schema_update_interval_spinbox.set_value(settings.settings_string[SCHEMA_UPDATE_INTERVAL].parse::<i32>().unwrap_or(0));
+        self.extra_packfile_autosave_amount_spinbox.set_value(settings.settings_string["autosave_amount"].parse::<i32>().unwrap_or(10));
 
         // Load the Debug Stuff.
         self.debug_check_for_missing_table_definitions_checkbox.set_checked(settings.settings_bool["check_for_missing_table_definitions"]);
@@ -534,8 +736,8 @@ impl SettingsUI {
     /// This function saves the data from our `SettingsUI` into a `Settings` and return it.
     pub unsafe fn save(&self) -> Settings {
 
-        // Create a new Settings.
-        let mut settings = Settings::new();
+        // Start from the current Settings, so the ones not in this dialog are kept as they are.
+        let mut settings = SETTINGS.read().unwrap().clone();
 
         // Only if we have a valid directory, we save it. Otherwise we wipe it out.
         let mymod_new_path = PathBuf::from(self.paths_mymod_line_edit.text().to_std_string());
@@ -544,6 +746,11 @@ impl SettingsUI {
         let zip_new_path = PathBuf::from(self.paths_zip_line_edit.text().to_std_string());
         settings.paths.insert(ZIP_PATH.to_owned(), if zip_new_path.is_file() { Some(zip_new_path) } else { None });
 
+        let steamcmd_new_path = PathBuf::from(self.paths_steamcmd_line_edit.text().to_std_string());
+        settings.paths.insert(STEAMCMD_PATH.to_owned(), if steamcmd_new_path.is_file() { Some(steamcmd_new_path) } else { None });
+        settings.settings_string.insert(FAVORITE_FOLDERS.to_owned(), self.paths_favorite_folders_line_edit.text().to_std_string());
+        settings.settings_string.insert(EXTERNAL_TOOLS.to_owned(), self.paths_external_tools_text_edit.to_plain_text().to_std_string());
+
         // For each entry, we check if it's a valid directory and save it into Settings.
         for (key, line_edit) in self.paths_games_line_edits.iter() {
             let new_path = PathBuf::from(line_edit.text().to_std_string());
@@ -576,6 +783,14 @@ impl SettingsUI {
         settings.settings_bool.insert("tight_table_mode".to_owned(), self.ui_table_tight_table_mode_checkbox.is_checked());
         settings.settings_bool.insert("start_maximized".to_owned(), self.ui_window_start_maximized_checkbox.is_checked());
         settings.settings_bool.insert("hide_background_icon".to_owned(), self.ui_window_hide_background_icon_checkbox.is_checked());
+        settings.settings_bool.insert("restore_last_session_on_start".to_owned(), self.ui_window_restore_last_session_checkbox.is_checked());
+        settings.settings_string.insert("loc_variant_suffixes".to_owned(), self.ui_table_loc_variant_suffixes_line_edit.text().to_std_string());
+        settings.settings_string.insert("tables_f32_decimals".to_owned(), self.ui_table_f32_decimals_spinbox.value().to_string());
+        settings.settings_string.insert("tables_max_undo_steps".to_owned(), self.ui_table_max_undo_steps_spinbox.value().to_string());
+        settings.settings_string.insert("tables_max_undo_memory_mb".to_owned(), self.ui_table_max_undo_memory_spinbox.value().to_string());
+        settings.settings_bool.insert("tables_use_thousands_separator".to_owned(), self.ui_table_use_thousands_separator_checkbox.is_checked());
+        settings.settings_bool.insert("tables_show_timestamps_as_dates".to_owned(), self.ui_table_show_timestamps_as_dates_checkbox.is_checked());
+        settings.settings_bool.insert("tables_complete_with_vanilla_values".to_owned(), self.ui_table_complete_with_vanilla_values_checkbox.is_checked());
 
         // Get the Extra Settings.
         settings.settings_bool.insert("check_updates_on_start".to_owned(), self.extra_network_check_updates_on_start_checkbox.is_checked());
@@ -585,6 +800,13 @@ impl SettingsUI {
         settings.settings_bool.insert("use_dependency_checker".to_owned(), self.extra_packfile_use_dependency_checker_checkbox.is_checked());
         settings.settings_bool.insert("use_lazy_loading".to_owned(), self.extra_packfile_use_lazy_loading_checkbox.is_checked());
         settings.settings_bool.insert("disable_uuid_regeneration_on_db_tables".to_owned(), self.extra_disable_uuid_regeneration_on_db_tables_checkbox.is_checked());
+        settings.settings_bool.insert("allow_overwriting_vanilla_packfiles".to_owned(), self.extra_packfile_allow_overwriting_vanilla_packfiles_checkbox.is_checked());
+        settings.settings_bool.insert("optimize_sort_table_rows".to_owned(), self.extra_packfile_optimize_sort_table_rows_checkbox.is_checked());
+        settings.settings_bool.insert("use_git_on_mymods".to_owned(), self.extra_mymod_use_git_checkbox.is_checked());
+        settings.settings_bool.insert("normalize_timestamps_on_save".to_owned(), self.extra_packfile_normalize_timestamps_on_save_checkbox.is_checked());
+        settings.settings_string.insert("autosave_interval".to_owned(), self.extra_packfile_autosave_interval_spinbox.value().to_string());
+        settings.settings_string.insert(SCHEMA_UPDATE_INTERVAL.to_owned(), self.extra_network_schema_update_interval_spinbox.value().to_string());
+        settings.settings_string.insert("autosave_amount".to_owned(), self.extra_packfile_autosave_amount_spinbox.value().to_string());
 
         // Get the Debug Settings.
         settings.settings_bool.insert("check_for_missing_table_definitions".to_owned(), self.debug_check_for_missing_table_definitions_checkbox.is_checked());
@@ -605,6 +827,7 @@ impl SettingsUI {
             None => match game {
                 MYMOD_BASE_PATH => (self.paths_mymod_line_edit, false),
                 ZIP_PATH => (self.paths_zip_line_edit, true),
+                STEAMCMD_PATH => (self.paths_steamcmd_line_edit, true),
                 _ => return,
             }
         };
@@ -615,10 +838,11 @@ impl SettingsUI {
             self.dialog,
             &title,
         );
+        setup_file_dialog(&mut file_dialog, None);
 
         if !is_file {
             file_dialog.set_file_mode(FileMode::Directory);
-            file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+            file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
         }
 
         // Get the old Path, if exists.
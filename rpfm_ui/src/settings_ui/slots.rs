@@ -24,7 +24,7 @@ use cpp_core::MutPtr;
 
 use std::collections::BTreeMap;
 
-use rpfm_lib::settings::{Settings, MYMOD_BASE_PATH, ZIP_PATH};
+use rpfm_lib::settings::{Settings, MYMOD_BASE_PATH, STEAMCMD_PATH, ZIP_PATH};
 
 use crate::CENTRAL_COMMAND;
 use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
@@ -45,6 +45,7 @@ pub struct SettingsUISlots {
     pub restore_default: Slot<'static>,
     pub select_mymod_path: Slot<'static>,
     pub select_zip_path: Slot<'static>,
+    pub select_steamcmd_path: Slot<'static>,
     pub select_game_paths: BTreeMap<String, Slot<'static>>,
     pub shortcuts: Slot<'static>,
     pub text_editor: Slot<'static>,
@@ -79,6 +80,12 @@ impl SettingsUISlots {
             ui.update_entry_path(ZIP_PATH);
         }));
 
+        // What happens when we hit the "..." button for SteamCMD.
+        let select_steamcmd_path = Slot::new(clone!(
+            ui => move || {
+            ui.update_entry_path(STEAMCMD_PATH);
+        }));
+
         // What happens when we hit any of the "..." buttons for the games.
         let mut select_game_paths = BTreeMap::new();
         for key in ui.paths_games_line_edits.keys() {
@@ -127,6 +134,7 @@ impl SettingsUISlots {
             restore_default,
             select_mymod_path,
             select_zip_path,
+            select_steamcmd_path,
             select_game_paths,
             shortcuts,
             text_editor,
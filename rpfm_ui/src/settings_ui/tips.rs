@@ -18,6 +18,13 @@ use crate::settings_ui::SettingsUI;
 /// This function sets the status bar tip for all the actions in the provided `SettingsUI`.
 pub unsafe fn set_tips(settings_ui: &mut SettingsUI) {
 
+    //-----------------------------------------------//
+    // `Paths` tips.
+    //-----------------------------------------------//
+    let paths_external_tools_tip = qtr("tt_paths_external_tools_tip");
+    settings_ui.paths_external_tools_label.set_tool_tip(&paths_external_tools_tip);
+    settings_ui.paths_external_tools_text_edit.set_tool_tip(&paths_external_tools_tip);
+
     //-----------------------------------------------//
     // `UI` tips.
     //-----------------------------------------------//
@@ -27,8 +34,16 @@ pub unsafe fn set_tips(settings_ui: &mut SettingsUI) {
     let ui_table_disable_combos_tip = qtr("tt_ui_table_disable_combos_tip");
     let ui_table_extend_last_column_tip = qtr("tt_ui_table_extend_last_column_tip");
     let ui_table_tight_table_mode_tip = qtr("tt_ui_table_tight_table_mode_tip");
+    let ui_table_loc_variant_suffixes_tip = qtr("tt_ui_table_loc_variant_suffixes_tip");
+    let ui_table_f32_decimals_tip = qtr("tt_ui_table_f32_decimals_tip");
+    let ui_table_use_thousands_separator_tip = qtr("tt_ui_table_use_thousands_separator_tip");
+    let ui_table_show_timestamps_as_dates_tip = qtr("tt_ui_table_show_timestamps_as_dates_tip");
+    let ui_table_complete_with_vanilla_values_tip = qtr("tt_ui_table_complete_with_vanilla_values_tip");
+    let ui_table_max_undo_steps_tip = qtr("tt_ui_table_max_undo_steps_tip");
+    let ui_table_max_undo_memory_tip = qtr("tt_ui_table_max_undo_memory_tip");
 
     let ui_window_start_maximized_tip = qtr("tt_ui_window_start_maximized_tip");
+    let ui_window_restore_last_session_tip = qtr("tt_ui_window_restore_last_session_tip");
 
     settings_ui.ui_global_use_dark_theme_label.set_tool_tip(&ui_global_use_dark_theme_tip);
     settings_ui.ui_global_use_dark_theme_checkbox.set_tool_tip(&ui_global_use_dark_theme_tip);
@@ -40,8 +55,24 @@ pub unsafe fn set_tips(settings_ui: &mut SettingsUI) {
     settings_ui.ui_table_extend_last_column_checkbox.set_tool_tip(&ui_table_extend_last_column_tip);
     settings_ui.ui_table_tight_table_mode_label.set_tool_tip(&ui_table_tight_table_mode_tip);
     settings_ui.ui_table_tight_table_mode_checkbox.set_tool_tip(&ui_table_tight_table_mode_tip);
+    settings_ui.ui_table_loc_variant_suffixes_label.set_tool_tip(&ui_table_loc_variant_suffixes_tip);
+    settings_ui.ui_table_loc_variant_suffixes_line_edit.set_tool_tip(&ui_table_loc_variant_suffixes_tip);
+    settings_ui.ui_table_f32_decimals_label.set_tool_tip(&ui_table_f32_decimals_tip);
+    settings_ui.ui_table_f32_decimals_spinbox.set_tool_tip(&ui_table_f32_decimals_tip);
+    settings_ui.ui_table_use_thousands_separator_label.set_tool_tip(&ui_table_use_thousands_separator_tip);
+    settings_ui.ui_table_use_thousands_separator_checkbox.set_tool_tip(&ui_table_use_thousands_separator_tip);
+    settings_ui.ui_table_show_timestamps_as_dates_label.set_tool_tip(&ui_table_show_timestamps_as_dates_tip);
+    settings_ui.ui_table_show_timestamps_as_dates_checkbox.set_tool_tip(&ui_table_show_timestamps_as_dates_tip);
+    settings_ui.ui_table_complete_with_vanilla_values_label.set_tool_tip(&ui_table_complete_with_vanilla_values_tip);
+    settings_ui.ui_table_complete_with_vanilla_values_checkbox.set_tool_tip(&ui_table_complete_with_vanilla_values_tip);
+    settings_ui.ui_table_max_undo_steps_label.set_tool_tip(&ui_table_max_undo_steps_tip);
+    settings_ui.ui_table_max_undo_steps_spinbox.set_tool_tip(&ui_table_max_undo_steps_tip);
+    settings_ui.ui_table_max_undo_memory_label.set_tool_tip(&ui_table_max_undo_memory_tip);
+    settings_ui.ui_table_max_undo_memory_spinbox.set_tool_tip(&ui_table_max_undo_memory_tip);
     settings_ui.ui_window_start_maximized_label.set_tool_tip(&ui_window_start_maximized_tip);
     settings_ui.ui_window_start_maximized_checkbox.set_tool_tip(&ui_window_start_maximized_tip);
+    settings_ui.ui_window_restore_last_session_label.set_tool_tip(&ui_window_restore_last_session_tip);
+    settings_ui.ui_window_restore_last_session_checkbox.set_tool_tip(&ui_window_restore_last_session_tip);
 
     //-----------------------------------------------//
     // `Extra` tips.
@@ -54,6 +85,13 @@ pub unsafe fn set_tips(settings_ui: &mut SettingsUI) {
     let extra_packfile_use_dependency_checker_tip = qtr("tt_extra_packfile_use_dependency_checker_tip");
     let extra_packfile_use_lazy_loading_tip = qtr("tt_extra_packfile_use_lazy_loading_tip");
     let extra_disable_uuid_regeneration_on_db_tables_label_tip = qtr("tt_extra_disable_uuid_regeneration_on_db_tables_label_tip");
+    let extra_packfile_allow_overwriting_vanilla_packfiles_tip = qtr("tt_extra_packfile_allow_overwriting_vanilla_packfiles_tip");
+    let extra_packfile_optimize_sort_table_rows_tip = qtr("tt_extra_packfile_optimize_sort_table_rows_tip");
+    let extra_mymod_use_git_tip = qtr("tt_extra_mymod_use_git_tip");
+    let extra_packfile_autosave_interval_tip = qtr("tt_extra_packfile_autosave_interval_tip");
+    let extra_network_schema_update_interval_tip = qtr("tt_extra_network_schema_update_interval_tip");
+    let extra_packfile_autosave_amount_tip = qtr("tt_extra_packfile_autosave_amount_tip");
+    let extra_packfile_normalize_timestamps_on_save_tip = qtr("tt_extra_packfile_normalize_timestamps_on_save_tip");
 
     settings_ui.extra_network_check_updates_on_start_label.set_tool_tip(&extra_network_check_updates_on_start_tip);
     settings_ui.extra_network_check_updates_on_start_checkbox.set_tool_tip(&extra_network_check_updates_on_start_tip);
@@ -69,6 +107,20 @@ pub unsafe fn set_tips(settings_ui: &mut SettingsUI) {
     settings_ui.extra_packfile_use_lazy_loading_checkbox.set_tool_tip(&extra_packfile_use_lazy_loading_tip);
     settings_ui.extra_disable_uuid_regeneration_on_db_tables_label.set_tool_tip(&extra_disable_uuid_regeneration_on_db_tables_label_tip);
     settings_ui.extra_disable_uuid_regeneration_on_db_tables_checkbox.set_tool_tip(&extra_disable_uuid_regeneration_on_db_tables_label_tip);
+    settings_ui.extra_packfile_allow_overwriting_vanilla_packfiles_label.set_tool_tip(&extra_packfile_allow_overwriting_vanilla_packfiles_tip);
+    settings_ui.extra_packfile_allow_overwriting_vanilla_packfiles_checkbox.set_tool_tip(&extra_packfile_allow_overwriting_vanilla_packfiles_tip);
+    settings_ui.extra_packfile_optimize_sort_table_rows_label.set_tool_tip(&extra_packfile_optimize_sort_table_rows_tip);
+    settings_ui.extra_packfile_optimize_sort_table_rows_checkbox.set_tool_tip(&extra_packfile_optimize_sort_table_rows_tip);
+    settings_ui.extra_mymod_use_git_label.set_tool_tip(&extra_mymod_use_git_tip);
+    settings_ui.extra_mymod_use_git_checkbox.set_tool_tip(&extra_mymod_use_git_tip);
+    settings_ui.extra_packfile_autosave_interval_label.set_tool_tip(&extra_packfile_autosave_interval_tip);
+    settings_ui.extra_packfile_autosave_interval_spinbox.set_tool_tip(&extra_packfile_autosave_interval_tip);
+    settings_ui.extra_network_schema_update_interval_label.set_tool_tip(&extra_network_schema_update_interval_tip);
+    settings_ui.extra_network_schema_update_interval_spinbox.set_tool_tip(&extra_network_schema_update_interval_tip);
+    settings_ui.extra_packfile_autosave_amount_label.set_tool_tip(&extra_packfile_autosave_amount_tip);
+    settings_ui.extra_packfile_autosave_amount_spinbox.set_tool_tip(&extra_packfile_autosave_amount_tip);
+    settings_ui.extra_packfile_normalize_timestamps_on_save_label.set_tool_tip(&extra_packfile_normalize_timestamps_on_save_tip);
+    settings_ui.extra_packfile_normalize_timestamps_on_save_checkbox.set_tool_tip(&extra_packfile_normalize_timestamps_on_save_tip);
 
     //-----------------------------------------------//
     // `Debug` tips.
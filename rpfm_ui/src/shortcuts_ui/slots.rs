@@ -16,6 +16,7 @@ use qt_core::Slot;
 
 use crate::shortcuts_ui::ShortcutsUI;
 use crate::ui_state::shortcuts::Shortcuts;
+use crate::utils::catch_panic_in_slot;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -40,9 +41,9 @@ impl ShortcutsUISlots {
 
         // What happens when we hit the "Restore Default" action.
         let mut ui = ui.clone();
-        let restore_default = Slot::new(move || {
+        let restore_default = Slot::new(move || catch_panic_in_slot(|| {
             ui.load(&Shortcuts::new())
-        });
+        }));
 
         ShortcutsUISlots {
             restore_default
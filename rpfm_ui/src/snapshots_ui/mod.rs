@@ -0,0 +1,195 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Snapshots*** UI.
+
+This is a dialog listing the snapshots taken of the open PackFile since it was last saved, so it can be rolled back to one of them.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionBehavior, SelectionMode};
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+use qt_core::QStringList;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use chrono::NaiveDateTime;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::ffi::add_to_q_list_safe;
+use crate::locale::{qtr, tr};
+use crate::utils::{create_grid_layout, show_dialog};
+use self::slots::SnapshotsUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Snapshots Window.
+#[derive(Clone)]
+pub struct SnapshotsUI {
+    dialog: MutPtr<QDialog>,
+
+    snapshots_tree_view: MutPtr<QTreeView>,
+    snapshots_tree_model: MutPtr<QStandardItemModel>,
+
+    restore_button: MutPtr<QPushButton>,
+    delete_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+
+    /// Index of the snapshot the user chose to roll back to, if any.
+    snapshot_to_restore: Rc<RefCell<Option<usize>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `SnapshotsUI`.
+impl SnapshotsUI {
+
+    /// This function creates a ***SnapshotsUI*** dialog, executes it, and returns the index of the snapshot to roll back to, if any.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) -> Option<usize> {
+        let mut ui = Self::new_with_parent(parent);
+        let slots = SnapshotsUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.load_snapshots();
+        ui.dialog.exec();
+        ui.snapshot_to_restore.replace(None)
+    }
+
+    /// This function creates the entire `SnapshotsUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Snapshots Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("snapshots_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 350);
+
+        // Create the list of snapshots.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut snapshots_tree_view = QTreeView::new_0a();
+        let mut snapshots_tree_model = QStandardItemModel::new_0a();
+        snapshots_tree_view.set_model(&mut snapshots_tree_model);
+        snapshots_tree_view.set_root_is_decorated(false);
+        snapshots_tree_view.set_selection_mode(SelectionMode::SingleSelection);
+        snapshots_tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        snapshots_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        main_grid.add_widget_5a(&mut snapshots_tree_view, 0, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut restore_button = button_box.add_button_q_string_button_role(&qtr("snapshots_restore"), q_dialog_button_box::ButtonRole::ActionRole);
+        let mut delete_button = button_box.add_button_q_string_button_role(&qtr("snapshots_delete"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        restore_button.set_auto_default(false);
+        delete_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 1, 0, 1, 1);
+
+        Self {
+            dialog,
+            snapshots_tree_view: snapshots_tree_view.into_ptr(),
+            snapshots_tree_model: snapshots_tree_model.into_ptr(),
+            restore_button,
+            delete_button,
+            close_button,
+            snapshot_to_restore: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// This function asks the background thread for the snapshots of the open PackFile, and loads them into the list, oldest first.
+    pub unsafe fn load_snapshots(&mut self) {
+        CENTRAL_COMMAND.send_message_qt(Command::GetSnapshots);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let snapshots = match response {
+            Response::VecI64Usize(snapshots) => snapshots,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.snapshots_tree_model.clear();
+        let mut headers = QStringList::new();
+        headers.append_q_string(&qtr("snapshots_number"));
+        headers.append_q_string(&qtr("snapshots_time"));
+        headers.append_q_string(&qtr("snapshots_packed_files"));
+        self.snapshots_tree_model.set_horizontal_header_labels(&headers);
+
+        for (index, (time, packed_files)) in snapshots.iter().enumerate() {
+            let mut row = QListOfQStandardItem::new();
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&(index + 1).to_string())).into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&NaiveDateTime::from_timestamp(*time, 0).to_string())).into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&packed_files.to_string())).into_ptr());
+            self.snapshots_tree_model.append_row_q_list_of_q_standard_item(&row);
+        }
+
+        for column in 0..self.snapshots_tree_model.column_count_0a() {
+            self.snapshots_tree_view.resize_column_to_contents(column);
+        }
+
+        let has_snapshots = !snapshots.is_empty();
+        self.restore_button.set_enabled(has_snapshots);
+        self.delete_button.set_enabled(has_snapshots);
+    }
+
+    /// This function returns the index of the snapshot selected in the list, if any.
+    unsafe fn get_selected_snapshot(&self) -> Option<usize> {
+        let indexes = self.snapshots_tree_view.selection_model().selected_rows_0a();
+        if indexes.count_0a() == 1 { Some(indexes.at(0).row() as usize) } else { None }
+    }
+
+    /// This function marks the selected snapshot to be restored, and closes the dialog.
+    pub unsafe fn restore(&mut self) {
+        match self.get_selected_snapshot() {
+            Some(index) => {
+                *self.snapshot_to_restore.borrow_mut() = Some(index);
+                self.dialog.accept();
+            }
+            None => show_dialog(self.dialog, tr("snapshots_none_selected"), false),
+        }
+    }
+
+    /// This function deletes the selected snapshot.
+    pub unsafe fn delete(&mut self) {
+        let index = match self.get_selected_snapshot() {
+            Some(index) => index,
+            None => return show_dialog(self.dialog, tr("snapshots_none_selected"), false),
+        };
+
+        CENTRAL_COMMAND.send_message_qt(Command::DeleteSnapshot(index));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::Success => {},
+            Response::Error(error) => show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        self.load_snapshots();
+    }
+}
@@ -0,0 +1,59 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `SnapshotsUISlots`.
+!*/
+
+use qt_core::Slot;
+
+use crate::snapshots_ui::SnapshotsUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `SnapshotsUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `SnapshotsUI` goes here.
+pub struct SnapshotsUISlots {
+    pub restore: Slot<'static>,
+    pub delete: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `SnapshotsUISlots`.
+impl SnapshotsUISlots {
+
+    /// This function creates a new `SnapshotsUISlots`.
+    pub unsafe fn new(ui: &SnapshotsUI) -> Self {
+
+        // What happens when we hit the "Restore" button.
+        let mut ui_restore = ui.clone();
+        let restore = Slot::new(move || catch_panic_in_slot(|| {
+            ui_restore.restore()
+        }));
+
+        // What happens when we hit the "Delete" button.
+        let mut ui_delete = ui.clone();
+        let delete = Slot::new(move || catch_panic_in_slot(|| {
+            ui_delete.delete()
+        }));
+
+        Self {
+            restore,
+            delete,
+        }
+    }
+}
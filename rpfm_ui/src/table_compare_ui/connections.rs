@@ -0,0 +1,28 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `TableCompareUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `TableCompareUI` and `TableCompareUISlots` structs.
+!*/
+
+use super::{TableCompareUI, slots::TableCompareUISlots};
+
+/// This function connects all the actions from the provided `TableCompareUI` with their slots in `TableCompareUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &TableCompareUI, slots: &TableCompareUISlots) {
+    ui.other_button.released().connect(&slots.browse);
+    ui.compare_button.released().connect(&slots.compare);
+    ui.only_differences_checkbox.toggled().connect(&slots.only_differences);
+    ui.close_button.released().connect(ui.dialog.slot_close());
+}
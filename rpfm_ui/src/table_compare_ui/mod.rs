@@ -0,0 +1,295 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Compare Table*** UI.
+
+This is a dialog to compare an open table side-by-side with its version in another PackFile, or in the game files. Rows are
+aligned by their key, both sides scroll together, and the cells with different data are highlighted. It's a focused version
+of the ***Compare PackFiles*** UI, for when we only care about one table.
+!*/
+
+use qt_widgets::q_abstract_item_view::EditTrigger;
+use qt_widgets::QCheckBox;
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QFileDialog;
+use qt_widgets::q_file_dialog::FileMode;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::QPushButton;
+use qt_widgets::QSplitter;
+use qt_widgets::QTableView;
+use qt_widgets::QWidget;
+
+use qt_gui::QBrush;
+use qt_gui::QColor;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use rpfm_lib::common::get_game_selected_data_path;
+use rpfm_lib::diff::{RowDiffState, TableDiff};
+use rpfm_lib::packedfile::table::{DecodedData, Table};
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::locale::{qtr, tre};
+use crate::pack_tree::{get_color_added, get_color_deleted, get_color_modified};
+use crate::utils::{create_grid_layout, setup_file_dialog, show_dialog};
+use crate::views::table::utils::{clean_column_names, get_item_from_decoded_data};
+use self::slots::TableCompareUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Compare Table Window.
+#[derive(Clone)]
+pub struct TableCompareUI {
+    dialog: MutPtr<QDialog>,
+
+    other_line_edit: MutPtr<QLineEdit>,
+    other_button: MutPtr<QPushButton>,
+    summary_label: MutPtr<QLabel>,
+    only_differences_checkbox: MutPtr<QCheckBox>,
+
+    base_table_view: MutPtr<QTableView>,
+    base_table_model: MutPtr<QStandardItemModel>,
+    other_table_view: MutPtr<QTableView>,
+    other_table_model: MutPtr<QStandardItemModel>,
+
+    compare_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+
+    path: Rc<Vec<String>>,
+    table: Rc<Table>,
+    diff: Rc<RefCell<Option<TableDiff>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TableCompareUI`.
+impl TableCompareUI {
+
+    /// This function creates a ***TableCompareUI*** dialog for the table with the provided path and data, and executes it.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>, path: Vec<String>, table: Table) {
+        let ui = Self::new_with_parent(parent, path, table);
+        let slots = TableCompareUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.dialog.exec();
+    }
+
+    /// This function creates the entire `TableCompareUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>, path: Vec<String>, table: Table) -> Self {
+
+        // Create the Compare Table Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&QString::from_std_str(tre("table_compare_title", &[&path.join("/")])));
+        dialog.set_modal(true);
+        dialog.resize_2a(1200, 700);
+
+        // Create the main Grid and the selector of the PackFile to compare against.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut other_label = QLabel::from_q_string(&qtr("table_compare_other"));
+        let mut other_line_edit = QLineEdit::new();
+        let mut other_button = QPushButton::from_q_string(&QString::from_std_str("..."));
+        other_line_edit.set_placeholder_text(&qtr("table_compare_other_placeholder"));
+
+        main_grid.add_widget_5a(&mut other_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut other_line_edit, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&mut other_button, 0, 2, 1, 1);
+
+        let mut summary_label = QLabel::new();
+        let mut only_differences_checkbox = QCheckBox::from_q_string(&qtr("table_compare_only_differences"));
+        main_grid.add_widget_5a(&mut summary_label, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&mut only_differences_checkbox, 1, 2, 1, 1);
+
+        // Create the two tables, each one with a label above it.
+        let mut splitter = QSplitter::new();
+        let (base_widget, base_table_view, base_table_model) = Self::new_side(&qtr("table_compare_base"));
+        let (other_widget, other_table_view, other_table_model) = Self::new_side(&qtr("table_compare_other_table"));
+
+        // Both tables have the same rows and columns, so keep them scrolled together.
+        base_table_view.vertical_scroll_bar().value_changed().connect(other_table_view.vertical_scroll_bar().slot_set_value());
+        other_table_view.vertical_scroll_bar().value_changed().connect(base_table_view.vertical_scroll_bar().slot_set_value());
+        base_table_view.horizontal_scroll_bar().value_changed().connect(other_table_view.horizontal_scroll_bar().slot_set_value());
+        other_table_view.horizontal_scroll_bar().value_changed().connect(base_table_view.horizontal_scroll_bar().slot_set_value());
+
+        splitter.add_widget(base_widget);
+        splitter.add_widget(other_widget);
+        main_grid.add_widget_5a(splitter.into_ptr(), 2, 0, 1, 3);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut compare_button = button_box.add_button_q_string_button_role(&qtr("table_compare_compare"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        compare_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 3, 0, 1, 3);
+
+        other_label.into_ptr();
+
+        Self {
+            dialog,
+            other_line_edit: other_line_edit.into_ptr(),
+            other_button: other_button.into_ptr(),
+            summary_label: summary_label.into_ptr(),
+            only_differences_checkbox: only_differences_checkbox.into_ptr(),
+            base_table_view,
+            base_table_model,
+            other_table_view,
+            other_table_model,
+            compare_button,
+            close_button,
+            path: Rc::new(path),
+            table: Rc::new(table),
+            diff: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// This function creates one of the sides of the comparison: a label with the provided text and a read-only table.
+    unsafe fn new_side(name: &QString) -> (MutPtr<QWidget>, MutPtr<QTableView>, MutPtr<QStandardItemModel>) {
+        let mut widget = QWidget::new_0a().into_ptr();
+        let mut grid = create_grid_layout(widget);
+        let mut label = QLabel::from_q_string(name);
+
+        let mut table_view = QTableView::new_0a();
+        let mut table_model = QStandardItemModel::new_0a();
+        table_view.set_model(&mut table_model);
+        table_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        grid.add_widget_5a(&mut label, 0, 0, 1, 1);
+        grid.add_widget_5a(&mut table_view, 1, 0, 1, 1);
+        label.into_ptr();
+
+        (widget, table_view.into_ptr(), table_model.into_ptr())
+    }
+
+    /// This function opens a FileDialog to pick the PackFile to compare against.
+    pub unsafe fn browse(&mut self) {
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(
+            self.dialog,
+            &qtr("open_packfiles"),
+        );
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+
+        // Start in the folder of the current path if there is one. If not, in the data folder of the game selected.
+        let old_path = self.other_line_edit.text().to_std_string();
+        if let Some(parent) = Path::new(&old_path).parent().filter(|parent| parent.is_dir()) {
+            file_dialog.set_directory_q_string(&QString::from_std_str(parent.to_string_lossy()));
+        }
+        else if let Some(ref path) = get_game_selected_data_path() {
+            if path.is_dir() { file_dialog.set_directory_q_string(&QString::from_std_str(path.to_string_lossy())); }
+        }
+
+        if file_dialog.exec() == 1 {
+            self.other_line_edit.set_text(file_dialog.selected_files().at(0));
+        }
+    }
+
+    /// This function asks the background thread to compare our table with its version in the selected PackFile,
+    /// or in the dependencies if there is none selected, and loads the result into both tables.
+    pub unsafe fn compare(&mut self) {
+        let other_path = self.other_line_edit.text().to_std_string();
+        let other_path = if other_path.is_empty() { None } else { Some(PathBuf::from(other_path)) };
+
+        self.dialog.set_enabled(false);
+        CENTRAL_COMMAND.send_message_qt(Command::CompareTable(((*self.path).to_vec(), (*self.table).clone(), other_path)));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        self.dialog.set_enabled(true);
+
+        let diff = match response {
+            Response::TableDiff(diff) => diff,
+            Response::Error(error) => return show_dialog(self.dialog, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.base_table_model.clear();
+        self.other_table_model.clear();
+
+        for (column, field) in diff.get_ref_definition().get_ref_fields().iter().enumerate() {
+            let name = QString::from_std_str(clean_column_names(&field.get_name()));
+            self.base_table_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&name).into_ptr());
+            self.other_table_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&name).into_ptr());
+        }
+
+        // Added and removed rows are painted entirely. In modified rows, only the cells that changed are painted.
+        let columns = diff.get_ref_definition().get_ref_fields().len();
+        let (mut added, mut removed, mut modified) = (0, 0, 0);
+        for (index, (base_row, other_row, state)) in diff.get_ref_rows().iter().enumerate() {
+            let colors = match state {
+                RowDiffState::Unchanged => vec![None; columns],
+                RowDiffState::Added => { added += 1; vec![Some(get_color_added()); columns] },
+                RowDiffState::Removed => { removed += 1; vec![Some(get_color_deleted()); columns] },
+                RowDiffState::Modified => {
+                    modified += 1;
+                    let modified_columns = diff.get_modified_columns(index);
+                    (0..columns).map(|column| if modified_columns.contains(&column) { Some(get_color_modified()) } else { None }).collect()
+                },
+            };
+
+            Self::append_row(self.base_table_model, base_row.as_ref(), &colors);
+            Self::append_row(self.other_table_model, other_row.as_ref(), &colors);
+        }
+
+        self.summary_label.set_text(&QString::from_std_str(tre("table_compare_summary", &[&added.to_string(), &removed.to_string(), &modified.to_string()])));
+        self.base_table_view.resize_columns_to_contents();
+        self.other_table_view.resize_columns_to_contents();
+
+        *self.diff.borrow_mut() = Some(diff);
+        self.filter_rows();
+    }
+
+    /// This function hides the rows that are the same in both tables, if only the differences should be shown.
+    pub unsafe fn filter_rows(&mut self) {
+        let only_differences = self.only_differences_checkbox.is_checked();
+        if let Some(ref diff) = *self.diff.borrow() {
+            for (row, (_, _, state)) in diff.get_ref_rows().iter().enumerate() {
+                let hidden = only_differences && *state == RowDiffState::Unchanged;
+                self.base_table_view.set_row_hidden(row as i32, hidden);
+                self.other_table_view.set_row_hidden(row as i32, hidden);
+            }
+        }
+    }
+
+    /// This function appends a row to the provided model, or an empty one if there is no row, so both sides stay aligned.
+    unsafe fn append_row(mut model: MutPtr<QStandardItemModel>, row: Option<&Vec<DecodedData>>, colors: &[Option<MutPtr<QColor>>]) {
+        let row_index = model.row_count_0a();
+        for (column, color) in colors.iter().enumerate() {
+            let mut item = match row {
+                Some(row) => get_item_from_decoded_data(&row[column]),
+                None => QStandardItem::new(),
+            };
+
+            if let Some(color) = color {
+                item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+            }
+
+            model.set_item_3a(row_index, column as i32, item.into_ptr());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `TableCompareUISlots`.
+!*/
+
+use qt_core::{Slot, SlotOfBool};
+
+use crate::table_compare_ui::TableCompareUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of the Compare Table Window.
+pub struct TableCompareUISlots {
+    pub browse: Slot<'static>,
+    pub compare: Slot<'static>,
+    pub only_differences: SlotOfBool<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TableCompareUISlots`.
+impl TableCompareUISlots {
+
+    /// This function creates a new `TableCompareUISlots`.
+    pub unsafe fn new(ui: &TableCompareUI) -> Self {
+
+        // What happens when we hit the browse button of the PackFile to compare against.
+        let mut ui_browse = ui.clone();
+        let browse = Slot::new(move || catch_panic_in_slot(|| {
+            ui_browse.browse()
+        }));
+
+        // What happens when we hit the "Compare" button.
+        let mut ui_compare = ui.clone();
+        let compare = Slot::new(move || catch_panic_in_slot(|| {
+            ui_compare.compare()
+        }));
+
+        // What happens when we toggle the "Only Differences" checkbox.
+        let mut ui = ui.clone();
+        let only_differences = SlotOfBool::new(move |_| catch_panic_in_slot(|| {
+            ui.filter_rows()
+        }));
+
+        TableCompareUISlots {
+            browse,
+            compare,
+            only_differences,
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Tour*** UI.
+
+This is a guided tour through the main parts of the UI, shown the first time RPFM starts, and available later from the `About` menu.
+Each step highlights a part of the Main Window with a frame over it, and explains what it's for.
+!*/
+
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QFrame;
+use qt_widgets::QLabel;
+use qt_widgets::QMainWindow;
+use qt_widgets::QMenu;
+use qt_widgets::QMenuBar;
+use qt_widgets::QPushButton;
+use qt_widgets::QWidget;
+
+use qt_core::QPoint;
+use qt_core::QString;
+use qt_core::WidgetAttribute;
+
+use cpp_core::MutPtr;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app_ui::AppUI;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::{qtr, tr, tre};
+use crate::ORANGE;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::{create_grid_layout, save_setting_bool};
+use self::slots::TourUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This enum represents the part of the Main Window highlighted in a step of the tour.
+#[derive(Clone, Copy)]
+enum TourTarget {
+
+    /// An entire widget, like a dock widget.
+    Widget(MutPtr<QWidget>),
+
+    /// The title of a menu in the menu bar.
+    MenuBarItem(MutPtr<QMenu>),
+}
+
+/// This struct represents a step of the tour, with the part of the window it highlights and the locale keys of its texts.
+#[derive(Clone, Copy)]
+struct TourStep {
+    target: TourTarget,
+    title: &'static str,
+    description: &'static str,
+}
+
+/// This struct holds all the widgets used in the Tour Window.
+#[derive(Clone)]
+pub struct TourUI {
+    dialog: MutPtr<QDialog>,
+    main_window: MutPtr<QMainWindow>,
+    menu_bar: MutPtr<QMenuBar>,
+    highlight: MutPtr<QFrame>,
+
+    title_label: MutPtr<QLabel>,
+    description_label: MutPtr<QLabel>,
+
+    back_button: MutPtr<QPushButton>,
+    next_button: MutPtr<QPushButton>,
+    skip_button: MutPtr<QPushButton>,
+
+    steps: Rc<Vec<TourStep>>,
+    current_step: Rc<RefCell<usize>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TourUI`.
+impl TourUI {
+
+    /// This function creates a ***TourUI*** dialog and executes it. Once it's closed, the tour is marked as completed.
+    pub unsafe fn new(app_ui: &AppUI, pack_file_contents_ui: &PackFileContentsUI, global_search_ui: &GlobalSearchUI) {
+        let steps = vec![
+            TourStep { target: TourTarget::Widget(pack_file_contents_ui.packfile_contents_dock_widget.static_upcast_mut()), title: "tour_packfile_contents_title", description: "tour_packfile_contents_description" },
+            TourStep { target: TourTarget::Widget(app_ui.tab_bar_packed_file.static_upcast_mut()), title: "tour_tab_bar_title", description: "tour_tab_bar_description" },
+            TourStep { target: TourTarget::Widget(global_search_ui.global_search_dock_widget.static_upcast_mut()), title: "tour_global_search_title", description: "tour_global_search_description" },
+            TourStep { target: TourTarget::MenuBarItem(app_ui.menu_bar_game_selected), title: "tour_game_selected_title", description: "tour_game_selected_description" },
+            TourStep { target: TourTarget::MenuBarItem(app_ui.menu_bar_about), title: "tour_about_title", description: "tour_about_description" },
+        ];
+
+        let mut ui = Self::new_with_parent(app_ui.main_window, app_ui.menu_bar, steps);
+        let slots = TourUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.load_step(0);
+        ui.dialog.exec();
+
+        ui.highlight.hide();
+        ui.highlight.delete_later();
+        save_setting_bool("tour_completed", true);
+    }
+
+    /// This function creates the entire `TourUI` Window.
+    unsafe fn new_with_parent(main_window: MutPtr<QMainWindow>, menu_bar: MutPtr<QMenuBar>, steps: Vec<TourStep>) -> Self {
+
+        // Create the Tour Dialog and configure it.
+        let mut dialog = QDialog::new_1a(main_window).into_ptr();
+        dialog.set_window_title(&qtr("tour_title"));
+        dialog.set_modal(true);
+        dialog.set_minimum_width(400);
+
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut title_label = QLabel::new();
+        let mut description_label = QLabel::new();
+        description_label.set_word_wrap(true);
+        main_grid.add_widget_5a(&mut title_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut description_label, 1, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let skip_button = button_box.add_button_q_string_button_role(&qtr("tour_skip"), q_dialog_button_box::ButtonRole::RejectRole);
+        let mut back_button = button_box.add_button_q_string_button_role(&qtr("tour_back"), q_dialog_button_box::ButtonRole::ActionRole);
+        let mut next_button = button_box.add_button_q_string_button_role(&qtr("tour_next"), q_dialog_button_box::ButtonRole::ActionRole);
+        back_button.set_auto_default(false);
+        next_button.set_default(true);
+        main_grid.add_widget_5a(button_box.into_ptr(), 2, 0, 1, 1);
+
+        // The highlight is a frame over the Main Window, that lets the mouse go through it.
+        let mut highlight = QFrame::new_1a(main_window).into_ptr();
+        highlight.set_style_sheet(&QString::from_std_str(&format!("QFrame {{ border: 3px solid {}; background: transparent; }}", *ORANGE)));
+        highlight.set_attribute_1a(WidgetAttribute::WATransparentForMouseEvents);
+        highlight.hide();
+
+        Self {
+            dialog,
+            main_window,
+            menu_bar,
+            highlight,
+            title_label: title_label.into_ptr(),
+            description_label: description_label.into_ptr(),
+            back_button,
+            next_button,
+            skip_button,
+            steps: Rc::new(steps),
+            current_step: Rc::new(RefCell::new(0)),
+        }
+    }
+
+    /// This function loads the step with the provided index, highlighting its part of the window and moving the dialog next to it.
+    unsafe fn load_step(&mut self, index: usize) {
+        let step = self.steps[index];
+        *self.current_step.borrow_mut() = index;
+
+        self.title_label.set_text(&QString::from_std_str(&format!("<b>{}</b>", tr(step.title))));
+        self.description_label.set_text(&qtr(step.description));
+        self.dialog.set_window_title(&QString::from_std_str(&tre("tour_step", &[&(index + 1).to_string(), &self.steps.len().to_string()])));
+        self.back_button.set_enabled(index > 0);
+        self.next_button.set_text(&if index + 1 == self.steps.len() { qtr("tour_finish") } else { qtr("tour_next") });
+
+        // Get the area to highlight, relative to the Main Window. Hidden widgets are not highlighted.
+        let area = match step.target {
+            TourTarget::Widget(widget) => {
+                if widget.is_visible() {
+                    let position = widget.map_to(self.main_window, &QPoint::new_2a(0, 0));
+                    Some((position.x(), position.y(), widget.width(), widget.height()))
+                } else { None }
+            }
+            TourTarget::MenuBarItem(menu) => {
+                let geometry = self.menu_bar.action_geometry(menu.menu_action());
+                let position = self.menu_bar.map_to(self.main_window, &geometry.top_left());
+                Some((position.x(), position.y(), geometry.width(), geometry.height()))
+            }
+        };
+
+        match area {
+            Some((x, y, width, height)) => {
+                self.highlight.set_geometry_4a(x, y, width, height);
+                self.highlight.raise();
+                self.highlight.show();
+
+                // Put the dialog under the highlighted area if it's small, or in its center if it's big enough to hold it.
+                self.dialog.adjust_size();
+                let (dialog_x, dialog_y) = if height < self.dialog.height() * 2 {
+                    (x, y + height + 10)
+                } else {
+                    (x + (width - self.dialog.width()) / 2, y + (height - self.dialog.height()) / 2)
+                };
+
+                let position = self.main_window.map_to_global(&QPoint::new_2a(dialog_x.max(0), dialog_y.max(0)));
+                self.dialog.move_1a(&position);
+            }
+            None => self.highlight.hide(),
+        }
+    }
+
+    /// This function moves the tour to the next step, or closes it if it's in the last one.
+    pub unsafe fn next_step(&mut self) {
+        let index = *self.current_step.borrow() + 1;
+        if index < self.steps.len() {
+            self.load_step(index);
+        } else {
+            self.dialog.accept();
+        }
+    }
+
+    /// This function moves the tour to the previous step.
+    pub unsafe fn previous_step(&mut self) {
+        let index = *self.current_step.borrow();
+        if index > 0 {
+            self.load_step(index - 1);
+        }
+    }
+}
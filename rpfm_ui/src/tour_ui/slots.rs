@@ -0,0 +1,59 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `TourUISlots`.
+!*/
+
+use qt_core::Slot;
+
+use crate::tour_ui::TourUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `TourUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `TourUI` goes here.
+pub struct TourUISlots {
+    pub next: Slot<'static>,
+    pub back: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TourUISlots`.
+impl TourUISlots {
+
+    /// This function creates a new `TourUISlots`.
+    pub unsafe fn new(ui: &TourUI) -> Self {
+
+        // What happens when we hit the "Next" button.
+        let mut ui_next = ui.clone();
+        let next = Slot::new(move || catch_panic_in_slot(|| {
+            ui_next.next_step()
+        }));
+
+        // What happens when we hit the "Back" button.
+        let mut ui_back = ui.clone();
+        let back = Slot::new(move || catch_panic_in_slot(|| {
+            ui_back.previous_step()
+        }));
+
+        Self {
+            next,
+            back,
+        }
+    }
+}
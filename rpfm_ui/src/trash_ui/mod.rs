@@ -0,0 +1,162 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Trash*** UI.
+
+This is a dialog listing the PackedFiles deleted from the open PackFile since it was last saved, so they can be restored.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionMode};
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::locale::{qtr, tr};
+use crate::utils::{create_grid_layout, show_dialog};
+use self::slots::TrashUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Trash Window.
+#[derive(Clone)]
+pub struct TrashUI {
+    dialog: MutPtr<QDialog>,
+
+    trash_tree_view: MutPtr<QTreeView>,
+    trash_tree_model: MutPtr<QStandardItemModel>,
+
+    restore_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+
+    /// Paths of the PackedFiles restored into the open PackFile.
+    restored_paths: Rc<RefCell<Vec<Vec<String>>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TrashUI`.
+impl TrashUI {
+
+    /// This function creates a ***TrashUI*** dialog, executes it, and returns the paths of the PackedFiles restored into the open PackFile.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) -> Vec<Vec<String>> {
+        let mut ui = Self::new_with_parent(parent);
+        let slots = TrashUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        ui.load_trash();
+        ui.dialog.exec();
+        ui.restored_paths.replace(vec![])
+    }
+
+    /// This function creates the entire `TrashUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Trash Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("trash_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 400);
+
+        // Create the list of deleted PackedFiles.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut trash_tree_view = QTreeView::new_0a();
+        let mut trash_tree_model = QStandardItemModel::new_0a();
+        trash_tree_view.set_model(&mut trash_tree_model);
+        trash_tree_view.set_header_hidden(true);
+        trash_tree_view.set_root_is_decorated(false);
+        trash_tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
+        trash_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        main_grid.add_widget_5a(&mut trash_tree_view, 0, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut restore_button = button_box.add_button_q_string_button_role(&qtr("trash_restore"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        restore_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 1, 0, 1, 1);
+
+        Self {
+            dialog,
+            trash_tree_view: trash_tree_view.into_ptr(),
+            trash_tree_model: trash_tree_model.into_ptr(),
+            restore_button,
+            close_button,
+            restored_paths: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    /// This function asks the background thread for the PackedFiles in the trash, and loads them into the list.
+    pub unsafe fn load_trash(&mut self) {
+        CENTRAL_COMMAND.send_message_qt(Command::GetTrash);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let paths = match response {
+            Response::VecVecString(paths) => paths,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.trash_tree_model.clear();
+        for path in &paths {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(path.join("/")));
+            self.trash_tree_model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        self.restore_button.set_enabled(!paths.is_empty());
+    }
+
+    /// This function restores the selected PackedFiles into the open PackFile.
+    pub unsafe fn restore(&mut self) {
+        let indexes = self.trash_tree_view.selection_model().selected_rows_0a();
+        let paths = (0..indexes.count_0a())
+            .map(|index| indexes.at(index).data_0a().to_string().to_std_string().split('/').map(|x| x.to_owned()).collect::<Vec<String>>())
+            .collect::<Vec<Vec<String>>>();
+
+        if paths.is_empty() {
+            return show_dialog(self.dialog, tr("trash_none_selected"), false);
+        }
+
+        CENTRAL_COMMAND.send_message_qt(Command::RestoreFromTrash(paths.to_vec()));
+        let response = CENTRAL_COMMAND.recv_message_qt_try();
+        match response {
+            Response::VecVecString(restored) => {
+                if restored.len() != paths.len() {
+                    show_dialog(self.dialog, tr("trash_restore_failed"), false);
+                }
+                self.restored_paths.borrow_mut().extend(restored);
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        self.load_trash();
+    }
+}
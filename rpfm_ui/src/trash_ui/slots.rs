@@ -0,0 +1,51 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `TrashUISlots`.
+!*/
+
+use qt_core::Slot;
+
+use crate::trash_ui::TrashUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `TrashUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `TrashUI` goes here.
+pub struct TrashUISlots {
+    pub restore: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TrashUISlots`.
+impl TrashUISlots {
+
+    /// This function creates a new `TrashUISlots`.
+    pub unsafe fn new(ui: &TrashUI) -> Self {
+
+        // What happens when we hit the "Restore" button.
+        let mut ui_restore = ui.clone();
+        let restore = Slot::new(move || catch_panic_in_slot(|| {
+            ui_restore.restore()
+        }));
+
+        Self {
+            restore,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with a library of reusable dialogs.
+
+These are the dialogs that keep being needed all over the program (pick PackedFiles from the open PackFile,
+pick rows from a list, show the progress of a long operation,...), so new views and features can use them
+instead of building their own one-off dialog each time.
+!*/
+
+// Not every dialog of the library is used yet, and that's fine.
+#![allow(dead_code)]
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionBehavior, SelectionMode};
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QLineEdit;
+use qt_widgets::QProgressDialog;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::CaseSensitivity;
+use qt_core::QFlags;
+use qt_core::QRegExp;
+use qt_core::QSortFilterProxyModel;
+use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QVariant;
+use qt_core::{SlotOfQModelIndex, SlotOfQString};
+use qt_core::WindowModality;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::ffi::add_to_q_list_safe;
+use crate::locale::{qtr, tr};
+use crate::utils::{catch_panic_in_slot, create_grid_layout, show_dialog};
+
+/// Role of the first item of each row of a picker where we store the index of the row in the provided list.
+const ROW_INDEX: i32 = 40;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct represents a progress dialog for long operations, optionally with a button to cancel them.
+///
+/// The dialog is modal to its parent, and it's closed when this struct is finished.
+pub struct ProgressDialog {
+    dialog: MutPtr<QProgressDialog>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `ProgressDialog`.
+impl ProgressDialog {
+
+    /// This function creates and shows a progress dialog with the provided amount of steps.
+    ///
+    /// If `can_cancel` is false, the dialog has no cancel button.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>, title: &str, steps: usize, can_cancel: bool) -> Self {
+        let mut dialog = QProgressDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&QString::from_std_str(title));
+        dialog.set_window_modality(WindowModality::WindowModal);
+        dialog.set_minimum_duration(0);
+        dialog.set_auto_close(false);
+        dialog.set_auto_reset(false);
+        dialog.set_range(0, steps as i32);
+        dialog.set_minimum_width(400);
+
+        // A null text removes the cancel button.
+        if can_cancel { dialog.set_cancel_button_text(&qtr("dialogs_cancel")); }
+        else { dialog.set_cancel_button_text(&QString::new()); }
+
+        dialog.set_value(0);
+        Self { dialog }
+    }
+
+    /// This function updates the progress shown in the dialog with the provided step and its description.
+    ///
+    /// It returns false if the user cancelled the operation, in which case the caller should stop it.
+    pub unsafe fn set_progress(&mut self, step: usize, text: &str) -> bool {
+        self.dialog.set_label_text(&QString::from_std_str(text));
+        self.dialog.set_value(step as i32);
+        !self.dialog.was_canceled()
+    }
+
+    /// This function returns if the user cancelled the operation.
+    pub unsafe fn was_canceled(&self) -> bool {
+        self.dialog.was_canceled()
+    }
+
+    /// This function closes the dialog. Use it once the operation is done, whether it was cancelled or not.
+    pub unsafe fn finish(mut self) {
+        self.dialog.close();
+        self.dialog.delete_later();
+    }
+}
+
+//-------------------------------------------------------------------------------//
+//                             Functions
+//-------------------------------------------------------------------------------//
+
+/// This function shows a dialog to pick PackedFiles from the open PackFile, and returns their paths.
+///
+/// Only the PackedFiles whose path passes the provided filter are shown. If `multiple` is true, more than one PackedFile can be picked.
+/// It returns None if there is nothing to pick or the dialog is cancelled.
+pub unsafe fn pick_pack_paths<F: Fn(&[String]) -> bool>(
+    parent: impl CastInto<MutPtr<QWidget>>,
+    title: &str,
+    filter: F,
+    multiple: bool,
+) -> Option<Vec<Vec<String>>> {
+    let parent = parent.cast_into();
+
+    CENTRAL_COMMAND.send_message_qt(Command::GetPackFileDataForTreeView);
+    let response = CENTRAL_COMMAND.recv_message_qt();
+    let mut paths = match response {
+        Response::PackFileInfoVecPackedFileInfo((_, packed_files_info)) => packed_files_info.into_iter()
+            .map(|packed_file_info| packed_file_info.path)
+            .filter(|path| filter(path))
+            .collect::<Vec<Vec<String>>>(),
+        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+    };
+
+    if paths.is_empty() {
+        show_dialog(parent, tr("dialogs_no_packed_files"), false);
+        return None;
+    }
+    paths.sort();
+
+    let headers = vec![tr("gen_loc_packedfile")];
+    let rows = paths.iter().map(|path| vec![path.join("/")]).collect::<Vec<Vec<String>>>();
+    pick_table_rows(parent, title, &headers, &rows, multiple).map(|indexes| indexes.iter().map(|index| paths[*index].to_vec()).collect())
+}
+
+/// This function shows a dialog to pick rows from the provided list, with a column for each of the provided headers.
+///
+/// If `multiple` is true, more than one row can be picked. It returns the indexes of the picked rows in the provided list,
+/// or None if the dialog is cancelled or nothing was picked.
+pub unsafe fn pick_table_rows(
+    parent: impl CastInto<MutPtr<QWidget>>,
+    title: &str,
+    headers: &[String],
+    rows: &[Vec<String>],
+    multiple: bool,
+) -> Option<Vec<usize>> {
+
+    // Create and configure the dialog.
+    let mut dialog = QDialog::new_1a(parent).into_ptr();
+    dialog.set_window_title(&QString::from_std_str(title));
+    dialog.set_modal(true);
+    dialog.resize_2a(600, 400);
+
+    let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+    let mut filter_line_edit = QLineEdit::new().into_ptr();
+    filter_line_edit.set_placeholder_text(&qtr("dialogs_filter"));
+
+    let mut tree_view = QTreeView::new_0a().into_ptr();
+    let mut tree_model = QStandardItemModel::new_0a().into_ptr();
+    let mut tree_filter = QSortFilterProxyModel::new_0a().into_ptr();
+    tree_filter.set_source_model(tree_model);
+    tree_filter.set_filter_key_column(-1);
+    tree_view.set_model(tree_filter);
+    tree_view.set_root_is_decorated(false);
+    tree_view.set_sorting_enabled(true);
+    tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+    tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+    if multiple { tree_view.set_selection_mode(SelectionMode::ExtendedSelection); }
+    else { tree_view.set_selection_mode(SelectionMode::SingleSelection); }
+
+    let mut button_box = QDialogButtonBox::new();
+    let mut accept_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Ok);
+    let cancel_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Cancel);
+    accept_button.set_default(true);
+
+    main_grid.add_widget_5a(filter_line_edit, 0, 0, 1, 1);
+    main_grid.add_widget_5a(tree_view, 1, 0, 1, 1);
+    main_grid.add_widget_5a(button_box.into_ptr(), 2, 0, 1, 1);
+
+    // Load the rows, remembering their position in the provided list, as the view can be sorted and filtered.
+    let mut header_list = QStringList::new();
+    headers.iter().for_each(|header| header_list.append_q_string(&QString::from_std_str(header)));
+    tree_model.set_horizontal_header_labels(&header_list);
+
+    for (index, row) in rows.iter().enumerate() {
+        let mut qlist = QListOfQStandardItem::new();
+        for (column, value) in row.iter().enumerate() {
+            let mut item = QStandardItem::from_q_string(&QString::from_std_str(value));
+            if column == 0 {
+                item.set_data_2a(&QVariant::from_u64(index as u64), ROW_INDEX);
+            }
+            add_to_q_list_safe(qlist.as_mut_ptr(), item.into_ptr());
+        }
+        tree_model.append_row_q_list_of_q_standard_item(&qlist);
+    }
+
+    for column in 0..tree_model.column_count_0a() - 1 {
+        tree_view.resize_column_to_contents(column);
+    }
+
+    //-------------------------------------------------------------------------------------------//
+    // Actions for the dialog...
+    //-------------------------------------------------------------------------------------------//
+
+    // What happens when we write in the filter.
+    let slot_filter_change_text = SlotOfQString::new(move |text| catch_panic_in_slot(|| {
+        let mut pattern = QRegExp::new_1a(text);
+        pattern.set_case_sensitivity(CaseSensitivity::CaseInsensitive);
+        tree_filter.set_filter_reg_exp_q_reg_exp(&pattern);
+    }));
+
+    // Double-clicking a row picks it.
+    let slot_double_click = SlotOfQModelIndex::new(move |_| catch_panic_in_slot(|| {
+        dialog.accept();
+    }));
+
+    filter_line_edit.text_changed().connect(&slot_filter_change_text);
+    tree_view.double_clicked().connect(&slot_double_click);
+    accept_button.released().connect(dialog.slot_accept());
+    cancel_button.released().connect(dialog.slot_reject());
+
+    if dialog.exec() == 1 {
+        let indexes = tree_view.selection_model().selected_rows_0a();
+        let mut picked = (0..indexes.count_0a())
+            .map(|index| indexes.at(index).data_1a(ROW_INDEX).to_u_long_long_0a() as usize)
+            .collect::<Vec<usize>>();
+        picked.sort();
+        if picked.is_empty() { None } else { Some(picked) }
+    } else { None }
+}
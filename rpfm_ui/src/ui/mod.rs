@@ -47,15 +47,21 @@ use crate::global_search_ui;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::global_search_ui::slots::GlobalSearchSlots;
 use crate::LIGHT_PALETTE;
+use crate::live_sync_ui;
+use crate::live_sync_ui::LiveSyncUI;
+use crate::live_sync_ui::slots::LiveSyncSlots;
 use crate::packedfile_views::TheOneSlot;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packfile_contents_ui;
 use crate::packfile_contents_ui::slots::PackFileContentsSlots;
+use crate::tour_ui::TourUI;
 use crate::UI_STATE;
 use crate::utils::atomic_from_cpp_box;
 use crate::utils::show_dialog;
 use crate::utils::ref_from_atomic;
 
+pub mod dialogs;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -68,6 +74,7 @@ pub struct UI {
     pub app_ui: AppUI,
     pub pack_file_contents_ui: PackFileContentsUI,
     pub global_search_ui: GlobalSearchUI,
+    pub live_sync_ui: LiveSyncUI,
 }
 
 /// This struct contains all the slots of the main UI, so we got all of them in one place.
@@ -76,6 +83,7 @@ pub struct Slots {
     pub app_temp_slots: Rc<RefCell<AppUITempSlots>>,
     pub pack_file_contents_slots: PackFileContentsSlots,
     pub global_search_slots: GlobalSearchSlots,
+    pub live_sync_slots: LiveSyncSlots,
 }
 
 /// This struct is used to hold all the Icons used for the window's titlebar.
@@ -106,11 +114,13 @@ impl UI {
         let mut app_ui = AppUI::new();
         let mut global_search_ui = GlobalSearchUI::new(app_ui.main_window);
         let mut pack_file_contents_ui = PackFileContentsUI::new(app_ui.main_window);
+        let live_sync_ui = LiveSyncUI::new(app_ui.main_window);
 
         let app_temp_slots = Rc::new(RefCell::new(AppUITempSlots::new(app_ui, pack_file_contents_ui, global_search_ui, &slot_holder)));
         let app_slots = AppUISlots::new(app_ui, global_search_ui, pack_file_contents_ui, &app_temp_slots, &slot_holder);
         let pack_file_contents_slots = PackFileContentsSlots::new(app_ui, pack_file_contents_ui, global_search_ui, slot_holder);
         let global_search_slots = GlobalSearchSlots::new(app_ui, global_search_ui, pack_file_contents_ui);
+        let live_sync_slots = LiveSyncSlots::new(app_ui, live_sync_ui, pack_file_contents_ui, global_search_ui);
 
         app_ui::connections::set_connections(&app_ui, &app_slots);
         app_ui::tips::set_tips(&mut app_ui);
@@ -124,6 +134,8 @@ impl UI {
         packfile_contents_ui::tips::set_tips(&mut pack_file_contents_ui);
         packfile_contents_ui::shortcuts::set_shortcuts(&mut pack_file_contents_ui);
 
+        live_sync_ui::connections::set_connections(&app_ui, &live_sync_ui, &live_sync_slots);
+
         // Here we also initialize the UI.
         UI_STATE.set_operational_mode(&mut app_ui, None);
 
@@ -159,6 +171,18 @@ impl UI {
             }
         }
 
+        // Otherwise, if we have it enabled in the prefs, reopen what we had open when we last closed the program.
+        else if SETTINGS.read().unwrap().settings_bool["restore_last_session_on_start"] {
+            app_ui.restore_session(&mut pack_file_contents_ui, &mut global_search_ui, &slot_holder);
+        }
+
+        // Remember what we have open when closing the program, so it can be restored later, and that we closed it properly.
+        app.about_to_quit().connect(&app_slots.about_to_quit);
+
+        // If the program crashed the last time, offer to recover the last autosave. Then start autosaving.
+        app_ui.check_autosave_recovery(&mut pack_file_contents_ui, &mut global_search_ui, &slot_holder);
+        app_ui.update_autosave_timer();
+
         // If we want the window to start maximized...
         if SETTINGS.read().unwrap().settings_bool["start_maximized"] {
             app_ui.main_window.set_window_state(QFlags::from(WindowState::WindowMaximized));
@@ -193,22 +217,33 @@ impl UI {
             }
         }
 
+        // If it's the first time RPFM starts, show the new user the tour.
+        if !SETTINGS.read().unwrap().settings_bool["tour_completed"] {
+            TourUI::new(&app_ui, &pack_file_contents_ui, &global_search_ui);
+        }
+
         // If we have it enabled in the prefs, check if there are updates.
         if SETTINGS.read().unwrap().settings_bool["check_updates_on_start"] { app_ui.check_updates(false) };
 
+        // If we have automatic schema updates enabled in the prefs, start checking for them, and update them now if it's time.
+        app_ui.update_schema_update_timer();
+        app_ui.auto_update_schemas();
+
         // If we have it enabled in the prefs, check if there are schema updates.
         if SETTINGS.read().unwrap().settings_bool["check_schema_updates_on_start"] { app_ui.check_schema_updates(false) };
 
         (Self {
             app_ui,
             global_search_ui,
-            pack_file_contents_ui
+            pack_file_contents_ui,
+            live_sync_ui,
         },
         Slots {
             app_slots,
             app_temp_slots,
             global_search_slots,
             pack_file_contents_slots,
+            live_sync_slots,
         })
     }
 }
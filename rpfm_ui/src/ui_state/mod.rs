@@ -18,16 +18,23 @@ use std::path::PathBuf;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use rpfm_error::Result;
 use rpfm_lib::global_search::GlobalSearch;
 
 use crate::app_ui::AppUI;
-use crate::packedfile_views::PackedFileView;
+use crate::packedfile_views::{PackedFileView, View, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use self::op_mode::OperationalMode;
+use self::search_history::{SavedSearch, SearchHistory};
+use self::session::Session;
 use self::shortcuts::Shortcuts;
+use self::table_state::{TableState, TableStates};
 
 pub mod op_mode;
+pub mod search_history;
+pub mod session;
 pub mod shortcuts;
+pub mod table_state;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -53,6 +60,18 @@ pub struct UIState {
 
     /// This stores the current `GlobalSearch`.
     global_search: Arc<RwLock<GlobalSearch>>,
+
+    /// This stores the last searches done in the Global Search, and the pinned ones.
+    search_history: Arc<RwLock<SearchHistory>>,
+
+    /// This stores the remembered state (hidden/frozen columns, widths,...) of the tables.
+    table_states: Arc<RwLock<TableStates>>,
+
+    /// This stores the last session (open PackFile, open PackedFiles,...), so it can be restored on start.
+    session: Arc<RwLock<Session>>,
+
+    /// This stores the moves done in the `PackFile Contents` view, as lists of "Original Path/New Path" of each moved PackedFile, so they can be undone.
+    packfile_contents_move_history: Arc<RwLock<Vec<Vec<(Vec<String>, Vec<String>)>>>>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -71,6 +90,10 @@ impl Default for UIState {
             open_packedfiles: Arc::new(RwLock::new(vec![])),
             operational_mode: Arc::new(RwLock::new(OperationalMode::Normal)),
             global_search: Arc::new(RwLock::new(GlobalSearch::default())),
+            search_history: Arc::new(RwLock::new(SearchHistory::load().unwrap_or_else(|_| SearchHistory::default()))),
+            table_states: Arc::new(RwLock::new(TableStates::load().unwrap_or_else(|_| TableStates::default()))),
+            session: Arc::new(RwLock::new(Session::load().unwrap_or_else(|_| Session::default()))),
+            packfile_contents_move_history: Arc::new(RwLock::new(vec![])),
         }
     }
 }
@@ -87,6 +110,18 @@ impl UIState {
     pub unsafe fn set_is_modified(&self, is_modified: bool, app_ui: &mut AppUI, pack_file_contents_ui: &mut PackFileContentsUI) {
         self.is_modified.store(is_modified, Ordering::SeqCst);
         app_ui.update_window_title(&pack_file_contents_ui);
+
+        // Any change in the PackFile may change the data the open tables reference, so their dependency data is no longer reliable.
+        // If the list of open PackedFiles is being edited, skip it, as it'll be reloaded on tab change anyway.
+        if is_modified {
+            if let Ok(open_packedfiles) = self.open_packedfiles.try_read() {
+                for packed_file_view in open_packedfiles.iter() {
+                    if let ViewType::Internal(View::Table(table)) = packed_file_view.get_view() {
+                        table.get_ref_table().set_dependency_data_is_stale(true);
+                    }
+                }
+            }
+        }
     }
 
     /// This function returns the current Shortcuts.
@@ -150,4 +185,81 @@ impl UIState {
     pub fn set_global_search(&self, global_search: &GlobalSearch) {
         *self.global_search.write().unwrap() = global_search.clone();
     }
+
+    /// This function returns the search history of the Global Search.
+    pub fn get_search_history(&self) -> SearchHistory {
+        self.search_history.read().unwrap().clone()
+    }
+
+    /// This function adds the provided search to the search history of the Global Search, and saves it to disk.
+    pub fn add_recent_search(&self, search: SavedSearch) -> Result<()> {
+        let mut search_history = self.search_history.write().unwrap();
+        search_history.add_recent(search);
+        search_history.save()
+    }
+
+    /// This function pins the provided search if it's not pinned, or unpins it if it is, and saves the search history to disk.
+    pub fn toggle_pinned_search(&self, search: SavedSearch) -> Result<()> {
+        let mut search_history = self.search_history.write().unwrap();
+        search_history.toggle_pinned(search);
+        search_history.save()
+    }
+
+    /// This function clears the search history of the Global Search, except the pinned searches, and saves it to disk.
+    pub fn clear_recent_searches(&self) -> Result<()> {
+        let mut search_history = self.search_history.write().unwrap();
+        search_history.clear_recent();
+        search_history.save()
+    }
+
+    /// This function returns the remembered state of the provided table, if any.
+    pub fn get_table_state(&self, table_name: &str) -> Option<TableState> {
+        self.table_states.read().unwrap().get_table_state(table_name).cloned()
+    }
+
+    /// This function replaces the remembered state of the provided table, saving it to disk if it changed.
+    pub fn set_table_state(&self, table_name: &str, table_state: TableState) -> Result<()> {
+        let mut table_states = self.table_states.write().unwrap();
+        if table_states.set_table_state(table_name, table_state) {
+            table_states.save()?;
+        }
+        Ok(())
+    }
+
+    /// This function returns the last session.
+    pub fn get_session(&self) -> Session {
+        self.session.read().unwrap().clone()
+    }
+
+    /// This function replaces the last session with the provided one, saving it to disk if it changed.
+    pub fn set_session(&self, session: Session) -> Result<()> {
+        let mut old_session = self.session.write().unwrap();
+        if *old_session != session {
+            session.save()?;
+            *old_session = session;
+        }
+        Ok(())
+    }
+
+    /// This function adds a move of one or more PackedFiles to the move history of the `PackFile Contents` view.
+    pub fn push_packfile_contents_move(&self, moved_paths: Vec<(Vec<String>, Vec<String>)>) {
+        if !moved_paths.is_empty() {
+            self.packfile_contents_move_history.write().unwrap().push(moved_paths);
+        }
+    }
+
+    /// This function removes the last move from the move history of the `PackFile Contents` view, and returns it.
+    pub fn pop_packfile_contents_move(&self) -> Option<Vec<(Vec<String>, Vec<String>)>> {
+        self.packfile_contents_move_history.write().unwrap().pop()
+    }
+
+    /// This function returns if there is any move that can be undone in the `PackFile Contents` view.
+    pub fn has_packfile_contents_moves(&self) -> bool {
+        !self.packfile_contents_move_history.read().unwrap().is_empty()
+    }
+
+    /// This function clears the move history of the `PackFile Contents` view. To be used when the open PackFile changes.
+    pub fn clear_packfile_contents_moves(&self) {
+        self.packfile_contents_move_history.write().unwrap().clear();
+    }
 }
@@ -16,6 +16,9 @@ This module contains the code needed to keep track of the current `Operational M
 
 use std::path::PathBuf;
 
+use rpfm_lib::settings::MYMOD_BASE_PATH;
+use rpfm_lib::SETTINGS;
+
 use crate::app_ui::AppUI;
 
 //-------------------------------------------------------------------------------//
@@ -60,6 +63,12 @@ impl OperationalMode {
                 unsafe { app_ui.mymod_delete_selected.set_enabled(true); }
                 unsafe { app_ui.mymod_install.set_enabled(true); }
                 unsafe { app_ui.mymod_uninstall.set_enabled(true); }
+                unsafe { app_ui.mymod_upload_to_workshop.set_enabled(true); }
+                unsafe { app_ui.mymod_git_commit.set_enabled(SETTINGS.read().unwrap().settings_bool["use_git_on_mymods"]); }
+                unsafe { app_ui.mymod_live_sync.set_enabled(true); }
+
+                // The live sync is per-MyMod, so stop the one of the previous MyMod, if any.
+                unsafe { app_ui.mymod_live_sync.set_checked(false); }
             }
 
             // If `None` has been provided, we disable the MyMod mode.
@@ -69,10 +78,27 @@ impl OperationalMode {
                 unsafe { app_ui.mymod_delete_selected.set_enabled(false); }
                 unsafe { app_ui.mymod_install.set_enabled(false); }
                 unsafe { app_ui.mymod_uninstall.set_enabled(false); }
+                unsafe { app_ui.mymod_upload_to_workshop.set_enabled(false); }
+                unsafe { app_ui.mymod_git_commit.set_enabled(false); }
+                unsafe { app_ui.mymod_live_sync.set_enabled(false); }
+                unsafe { app_ui.mymod_live_sync.set_checked(false); }
             }
         }
     }
 
     /// This function returns a reference to the current `Operational Mode`.
     pub fn get_ref_operational_mode(&self) -> &Self { &self }
+
+    /// This function returns the path of the private folder of the current MyMod, if we're in MyMod mode and the MyMod path is configured.
+    pub fn get_mymod_private_path(&self) -> Option<PathBuf> {
+        match self {
+            OperationalMode::MyMod(game_folder_name, mod_name) => {
+                let mut path = SETTINGS.read().unwrap().paths[MYMOD_BASE_PATH].clone()?;
+                path.push(game_folder_name);
+                path.push(PathBuf::from(mod_name).file_stem()?);
+                Some(path)
+            }
+            OperationalMode::Normal => None,
+        }
+    }
 }
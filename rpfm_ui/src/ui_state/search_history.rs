@@ -0,0 +1,133 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code related to the ***Search History*** of the Global Search.
+
+The last searches done in the Global Search are remembered, with all their settings, so they can be repeated later.
+Searches can also be pinned, so they're kept even if they fall out of the history. It's saved in the config folder,
+in a file called `search_history.ron`.
+!*/
+
+use ron::de::from_reader;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde_derive::{Serialize, Deserialize};
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+
+use rpfm_error::Result;
+use rpfm_lib::config::get_config_path;
+use rpfm_lib::global_search::SearchScope;
+
+/// Name of the file which contains the search history.
+const SEARCH_HISTORY_FILE: &str = "search_history.ron";
+
+/// Max amount of searches we keep in the history. Pinned searches don't count.
+const MAX_RECENT_SEARCHES: usize = 20;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds the searches done in the Global Search, most recent first, and the ones the user pinned.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SearchHistory {
+    recent: Vec<SavedSearch>,
+    pinned: Vec<SavedSearch>,
+}
+
+/// This struct holds a search of the Global Search, with all the settings needed to repeat it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub pattern: String,
+    pub case_sensitive: bool,
+    pub use_regex: bool,
+    pub path_filter: String,
+    pub scope: SearchScope,
+
+    /// If we search on all PackedFile types. If false, we only search on the ones enabled below.
+    pub search_on_all: bool,
+    pub search_on_dbs: bool,
+    pub search_on_locs: bool,
+    pub search_on_texts: bool,
+    pub search_on_schema: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `SearchHistory`.
+impl SearchHistory {
+
+    /// This function tries to load the `search_history.ron` from disk, if exist, and return it.
+    pub fn load() -> Result<Self> {
+        let file_path = get_config_path()?.join(SEARCH_HISTORY_FILE);
+        let file = BufReader::new(File::open(file_path)?);
+        from_reader(file).map_err(From::from)
+    }
+
+    /// This function tries to save the provided `SearchHistory` to disk.
+    pub fn save(&self) -> Result<()> {
+        let file_path = get_config_path()?.join(SEARCH_HISTORY_FILE);
+        let mut file = BufWriter::new(File::create(file_path)?);
+        let config = PrettyConfig::default();
+        file.write_all(to_string_pretty(&self, config)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the searches in the history, most recent first.
+    pub fn get_recent(&self) -> &[SavedSearch] {
+        &self.recent
+    }
+
+    /// This function returns the pinned searches, in the order they were pinned.
+    pub fn get_pinned(&self) -> &[SavedSearch] {
+        &self.pinned
+    }
+
+    /// This function returns if the provided search is pinned.
+    pub fn is_pinned(&self, search: &SavedSearch) -> bool {
+        self.pinned.contains(search)
+    }
+
+    /// This function adds the provided search to the top of the history, removing the oldest ones if there are too many.
+    ///
+    /// If the search was already in the history, it's moved to the top.
+    pub fn add_recent(&mut self, search: SavedSearch) {
+        self.recent.retain(|recent| *recent != search);
+        self.recent.insert(0, search);
+        self.recent.truncate(MAX_RECENT_SEARCHES);
+    }
+
+    /// This function pins the provided search if it's not pinned, or unpins it if it is.
+    pub fn toggle_pinned(&mut self, search: SavedSearch) {
+        match self.pinned.iter().position(|pinned| *pinned == search) {
+            Some(index) => { self.pinned.remove(index); },
+            None => self.pinned.push(search),
+        }
+    }
+
+    /// This function clears the history. Pinned searches are kept.
+    pub fn clear_recent(&mut self) {
+        self.recent.clear();
+    }
+}
+
+/// Implementation of `SavedSearch`.
+impl SavedSearch {
+
+    /// This function returns the name of the search to show in lists: its pattern, and the path filter if it has one.
+    pub fn get_name(&self) -> String {
+        if self.path_filter.is_empty() { self.pattern.to_owned() }
+        else { format!("{} ({})", self.pattern, self.path_filter) }
+    }
+}
@@ -0,0 +1,86 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code related to the ***Session*** of the UI.
+
+The session is what we had open when we last closed RPFM: the PackFile, the PackedFiles open in tabs, the active tab
+and where the tables were scrolled. It's saved in the config folder, in a file called `session.ron`, so it can be restored on start.
+!*/
+
+use ron::de::from_reader;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde_derive::{Serialize, Deserialize};
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use rpfm_error::Result;
+use rpfm_lib::config::get_config_path;
+
+/// Name of the file which contains the last session.
+const SESSION_FILE: &str = "session.ron";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds the state of the UI we need to restore a session.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+
+    /// Path of the open PackFile. None if there was no PackFile open, or it was never saved to disk.
+    pub pack_file_path: Option<PathBuf>,
+
+    /// Game folder of the open MyMod. Empty if the PackFile was not a MyMod.
+    pub game_folder: String,
+
+    /// PackedFiles open in tabs, in the order of their tabs.
+    pub open_packedfiles: Vec<SessionPackedFile>,
+
+    /// Index in `open_packedfiles` of the active tab, if any.
+    pub active_tab: Option<usize>,
+}
+
+/// This struct holds the state of an open PackedFile within a session.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionPackedFile {
+
+    /// Path of the PackedFile within the PackFile.
+    pub path: Vec<String>,
+
+    /// Horizontal and vertical scroll positions of the view. Only used by tables.
+    pub scroll_position: (i32, i32),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `Session`.
+impl Session {
+
+    /// This function tries to load the `session.ron` from disk, if exist, and return it.
+    pub fn load() -> Result<Self> {
+        let file_path = get_config_path()?.join(SESSION_FILE);
+        let file = BufReader::new(File::open(file_path)?);
+        from_reader(file).map_err(From::from)
+    }
+
+    /// This function tries to save the provided `Session` to disk.
+    pub fn save(&self) -> Result<()> {
+        let file_path = get_config_path()?.join(SESSION_FILE);
+        let mut file = BufWriter::new(File::create(file_path)?);
+        let config = PrettyConfig::default();
+        file.write_all(to_string_pretty(&self, config)?.as_bytes())?;
+        Ok(())
+    }
+}
@@ -30,8 +30,9 @@ use rpfm_lib::config::get_config_path;
 const SHORTCUTS_FILE: &str = "shortcuts.ron";
 
 /// List of shortcuts for the `PackFile` Menu.
-const SHORTCUTS_MENU_BAR_PACKFILE: [(&str, &str); 7] = [
+const SHORTCUTS_MENU_BAR_PACKFILE: [(&str, &str); 8] = [
     ("new_packfile", "Ctrl+N"),
+    ("new_packfile_from_folder", ""),
     ("open_packfile", "Ctrl+O"),
     ("save_packfile", "Ctrl+S"),
     ("save_packfile_as", "Ctrl+Shift+S"),
@@ -41,11 +42,14 @@ const SHORTCUTS_MENU_BAR_PACKFILE: [(&str, &str); 7] = [
 ];
 
 /// List of shortcuts for the `MyMod` Menu.
-const SHORTCUTS_MENU_BAR_MYMOD: [(&str, &str); 4] = [
+const SHORTCUTS_MENU_BAR_MYMOD: [(&str, &str); 7] = [
     ("mymod_new", ""),
     ("mymod_delete_selected", ""),
     ("mymod_install", "Ctrl+Shift+I"),
     ("mymod_uninstall", ""),
+    ("mymod_upload_to_workshop", ""),
+    ("mymod_git_commit", ""),
+    ("mymod_live_sync", ""),
 ];
 
 /// List of shortcuts for the `View` Menu.
@@ -55,11 +59,13 @@ const SHORTCUTS_MENU_BAR_VIEW: [(&str, &str); 2] = [
 ];
 
 /// List of shortcuts for the `Game Selected` Menu.
-const SHORTCUTS_MENU_BAR_GAME_SELECTED: [(&str, &str); 4] = [
+const SHORTCUTS_MENU_BAR_GAME_SELECTED: [(&str, &str); 6] = [
     ("launch_game", ""),
+    ("launch_game_with_packfile", ""),
     ("open_game_data_folder", ""),
     ("open_game_assembly_kit_folder", ""),
     ("open_config_folder", ""),
+    ("search_vanilla_files", "Ctrl+Alt+F"),
 ];
 
 /// List of shortcuts for the `Special Stuff` Menu.
@@ -71,17 +77,18 @@ const SHORTCUTS_MENU_BAR_SPECIAL_STUFF: [(&str, &str); 4] = [
 ];
 
 /// List of shortcuts for the `About` Menu.
-const SHORTCUTS_MENU_BAR_ABOUT: [(&str, &str); 6] = [
+const SHORTCUTS_MENU_BAR_ABOUT: [(&str, &str); 7] = [
     ("about_qt", ""),
     ("about_rpfm", ""),
     ("open_manual", "Ctrl+H"),
+    ("start_tour", ""),
     ("support_me_on_patreon", ""),
     ("check_updates", "Ctrl+U"),
     ("check_schema_updates", "Ctrl+Shift+U"),
 ];
 
 /// List of shortcuts for the PackFile Contents Contextual Menu.
-const SHORTCUTS_PACKFILE_CONTENTS_TREE_VIEW: [(&str, &str); 23] = [
+const SHORTCUTS_PACKFILE_CONTENTS_TREE_VIEW: [(&str, &str); 27] = [
     ("add_file", "Ctrl+A"),
     ("add_folder", "Ctrl+Shift+A"),
     ("add_from_packfile", "Ctrl+Alt+A"),
@@ -97,7 +104,11 @@ const SHORTCUTS_PACKFILE_CONTENTS_TREE_VIEW: [(&str, &str); 23] = [
     ("update_tables", ""),
     ("delete", "Del"),
     ("extract", "Ctrl+E"),
+    ("extract_with_path_rewrite", "Ctrl+Shift+E"),
+    ("extract_converted", "Ctrl+Alt+E"),
     ("rename", "Ctrl+R"),
+    ("mass_rename", "Ctrl+Shift+R"),
+    ("undo_move", "Ctrl+Z"),
     ("open_in_decoder", "Ctrl+J"),
     ("open_packfiles_list", ""),
     ("open_with_external_program", "Ctrl+K"),
@@ -108,36 +119,57 @@ const SHORTCUTS_PACKFILE_CONTENTS_TREE_VIEW: [(&str, &str); 23] = [
 ];
 
 /// List of shortcuts for the Table PackedFile's Contextual Menu.
-const SHORTCUTS_PACKED_FILE_TABLE: [(&str, &str); 19] = [
+const SHORTCUTS_PACKED_FILE_TABLE: [(&str, &str); 35] = [
     ("add_row", "Ctrl+Shift+A"),
     ("insert_row", "Ctrl+I"),
     ("delete_row", "Ctrl+Del"),
+    ("move_rows_up", "Ctrl+Alt+Up"),
+    ("move_rows_down", "Ctrl+Alt+Down"),
     ("clone_and_insert_row", "Ctrl+D"),
     ("clone_and_append_row", "Ctrl+Shift+D"),
+    ("clone_and_edit_row", "Ctrl+Alt+D"),
     ("copy", "Ctrl+C"),
     ("copy_as_lua_table", "Ctrl+Shift+C"),
+    ("copy_to_row_clipboard", "Ctrl+Alt+C"),
     ("paste", "Ctrl+V"),
+    ("paste_from_row_clipboard", "Ctrl+Alt+V"),
     ("rewrite_selection", "Ctrl+Y"),
-    ("selection_invert", "Ctrl+-"),
+    ("generate_loc_variants", ""),
+    ("selection_invert", "Ctrl+Alt+I"),
     ("revert_selection", ""),
     ("import_tsv", ""),
     ("export_tsv", ""),
+    ("export_visible_rows", ""),
     ("search", "Ctrl+F"),
     ("sidebar", ""),
+    ("history", ""),
+    ("summary_row", ""),
+    ("column_statistics", ""),
+    ("compare_with", ""),
+    ("set_column_unit", ""),
+    ("edit_in_popup", ""),
+    ("word_wrap", ""),
+    ("refresh_reference_data", ""),
     ("undo", "Ctrl+Z"),
     ("redo", "Ctrl+Shift+Z"),
     ("smart_delete", "Del"),
     ("resize_columns", ""),
+    ("zoom_in", "Ctrl++"),
+    ("zoom_out", "Ctrl+-"),
+    ("zoom_reset", "Ctrl+0"),
 ];
 
 /// List of shortcuts for the Table Decoder.
-const SHORTCUTS_PACKED_FILE_DECODER: [(&str, &str); 6] = [
+const SHORTCUTS_PACKED_FILE_DECODER: [(&str, &str); 9] = [
     ("move_up", "Ctrl+Up"),
     ("move_down", "Ctrl+Down"),
     ("move_left", "Ctrl+Left"),
     ("move_right", "Ctrl+Right"),
     ("delete", "Ctrl+Del"),
     ("load", "Ctrl+L"),
+    ("zoom_in", "Ctrl++"),
+    ("zoom_out", "Ctrl+-"),
+    ("zoom_reset", "Ctrl+0"),
 ];
 
 //-------------------------------------------------------------------------------//
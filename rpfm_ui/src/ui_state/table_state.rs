@@ -0,0 +1,108 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code related to the ***Table State*** of the table views.
+
+The state of a table (hidden/frozen columns, column widths, word wrap and filter presets) is remembered per-table, so when you reopen
+a table you get it just as you left it. It's saved in the config folder, in a file called `table_state.ron`.
+!*/
+
+use ron::de::from_reader;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+
+use rpfm_error::Result;
+use rpfm_lib::config::get_config_path;
+
+use crate::views::table::FilterCondition;
+
+/// Name of the file which contains the state of the tables.
+const TABLE_STATE_FILE: &str = "table_state.ron";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds the state of every table we have a state for, by table name.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TableStates {
+    tables: BTreeMap<String, TableState>,
+}
+
+/// This struct holds the visual state of a table. Columns are stored by name, so changes in the definition don't mess them up.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableState {
+    pub hidden_columns: Vec<String>,
+    pub frozen_columns: Vec<String>,
+    pub column_widths: BTreeMap<String, i32>,
+
+    #[serde(default)]
+    pub word_wrap: bool,
+
+    #[serde(default)]
+    pub filter_presets: BTreeMap<String, FilterPreset>,
+}
+
+/// This struct holds a named set of filter conditions of a table, so they can be re-applied later.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FilterPreset {
+    pub conditions: Vec<FilterCondition>,
+
+    /// If the rows must match all the conditions, or just one of them.
+    pub match_all: bool,
+    pub case_sensitive: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `TableStates`.
+impl TableStates {
+
+    /// This function tries to load the `table_state.ron` from disk, if exist, and return it.
+    pub fn load() -> Result<Self> {
+        let file_path = get_config_path()?.join(TABLE_STATE_FILE);
+        let file = BufReader::new(File::open(file_path)?);
+        from_reader(file).map_err(From::from)
+    }
+
+    /// This function tries to save the provided `TableStates` to disk.
+    pub fn save(&self) -> Result<()> {
+        let file_path = get_config_path()?.join(TABLE_STATE_FILE);
+        let mut file = BufWriter::new(File::create(file_path)?);
+        let config = PrettyConfig::default();
+        file.write_all(to_string_pretty(&self, config)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the state of the provided table, if we have one.
+    pub fn get_table_state(&self, table_name: &str) -> Option<&TableState> {
+        self.tables.get(table_name)
+    }
+
+    /// This function sets the state of the provided table, replacing the old one if exists.
+    ///
+    /// Returns true if the state has changed.
+    pub fn set_table_state(&mut self, table_name: &str, table_state: TableState) -> bool {
+        match self.tables.get(table_name) {
+            Some(old_state) if *old_state == table_state => false,
+            _ => {
+                self.tables.insert(table_name.to_owned(), table_state);
+                true
+            }
+        }
+    }
+}
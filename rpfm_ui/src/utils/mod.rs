@@ -13,12 +13,18 @@ Module with all the utility functions, to make our programming lives easier.
 !*/
 
 use qt_widgets::QApplication;
+use qt_widgets::{QFileDialog, q_file_dialog::Option as QFileDialogOption};
 use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
 use qt_widgets::{QMessageBox, q_message_box::{Icon, StandardButton}};
 use qt_widgets::QWidget;
 
+use qt_gui::QFont;
+
 use qt_core::QFlags;
 use qt_core::QString;
+use qt_core::QUrl;
+use qt_core::WidgetAttribute;
 
 use cpp_core::CastInto;
 use cpp_core::CppBox;
@@ -26,17 +32,25 @@ use cpp_core::CppDeletable;
 use cpp_core::MutPtr;
 use cpp_core::Ref;
 
-use log::info;
+use log::{error, info, warn};
 
 use std::convert::AsRef;
 use std::fmt::Display;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
 
 use crate::ASSETS_PATH;
+use crate::CENTRAL_COMMAND;
 use crate::ffi::new_text_editor_safe;
 use crate::ffi::set_text_safe;
-use crate::locale::qtr;
+use crate::locale::{qtr, tre};
+use rpfm_lib::DOCS_BASE_URL;
+use rpfm_lib::settings::{FAVORITE_FOLDERS, PACKFILE_GAMES_SELECTED};
+
 use crate::ORANGE;
+use crate::SETTINGS;
 use crate::SLIGHTLY_DARKER_GREY;
 use crate::MEDIUM_DARKER_GREY;
 use crate::DARK_GREY;
@@ -44,6 +58,108 @@ use crate::KINDA_WHITY_GREY;
 use crate::EVEN_MORE_WHITY_GREY;
 use crate::STATUS_BAR;
 
+/// Amount of PackFiles whose last Game Selected we remember.
+const PACKFILE_GAMES_SELECTED_MAX: usize = 200;
+
+/// Max amount of points the font of a view can be zoomed in or out.
+const ZOOM_MAX_LEVEL: i32 = 20;
+
+/// Min size of the font of a zoomed view, in points.
+const ZOOM_MIN_FONT_SIZE: i32 = 4;
+
+//----------------------------------------------------------------------------//
+//                              Enums & Structs
+//----------------------------------------------------------------------------//
+
+/// This struct is a pointer to a Qt object that belongs to a view, and knows if that view is still alive.
+///
+/// Views are closed (and their widgets deleted) while slots and responses that use them may still be around. Using a
+/// raw pointer of a closed view is an use-after-free, which usually crashes without a trace. A `QtPtr` of a closed view
+/// returns `None` instead, so the code using it can just stop.
+pub(crate) struct QtPtr<T> {
+    ptr: AtomicPtr<T>,
+    alive: Arc<AtomicBool>,
+}
+
+/// This struct collects the problems found while running an operation over many items.
+///
+/// Instead of stopping for a dialog on each failed item, batch operations keep going, and report all their problems
+/// at once at the end in a single non-modal dialog. Each problem is also logged when found.
+#[derive(Clone, Debug, Default)]
+pub struct Problems {
+    problems: Vec<String>,
+}
+
+//----------------------------------------------------------------------------//
+//                             Implementations
+//----------------------------------------------------------------------------//
+
+/// Implementation of `QtPtr`.
+impl<T: Sized> QtPtr<T> {
+
+    /// This function creates a new `QtPtr`, tied to the provided alive flag of the view that owns the object.
+    pub fn new(ptr: MutPtr<T>, alive: &Arc<AtomicBool>) -> Self {
+        Self {
+            ptr: atomic_from_mut_ptr(ptr),
+            alive: alive.clone(),
+        }
+    }
+
+    /// This function returns if the view that owns the object is still alive.
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    /// This function returns the pointer, or `None` if the view that owns it has been closed.
+    pub fn get(&self) -> Option<MutPtr<T>> {
+        if self.is_alive() { Some(mut_ptr_from_atomic(&self.ptr)) } else { None }
+    }
+
+    /// This function returns the pointer, without checking if the view that owns it is still alive.
+    ///
+    /// Only use it when the view is known to be open, like while building it, or when it comes from the list of open views.
+    /// Anywhere else, and especially after anything that waits, use `get` instead.
+    pub fn get_unchecked(&self) -> MutPtr<T> {
+        mut_ptr_from_atomic(&self.ptr)
+    }
+}
+
+/// Implementation of `Problems`.
+impl Problems {
+
+    /// This function adds a problem with the provided item to the list.
+    pub fn push<T: Display>(&mut self, item: &str, error: T) {
+        let problem = format!("{}: {}", item, strip_html_tags(&error.to_string()));
+        warn!("{}", problem);
+        self.problems.push(problem);
+    }
+
+    /// This function returns if no problems have been found.
+    pub fn is_empty(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// This function shows the problems found in a non-modal dialog, if there are any.
+    ///
+    /// The dialog deletes itself when closed, so it doesn't block nor leak.
+    pub unsafe fn report(&self, parent: impl CastInto<MutPtr<QWidget>>) {
+        if self.problems.is_empty() { return; }
+
+        let mut dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+            Icon::Warning,
+            &qtr("title_problems"),
+            &QString::from_std_str(tre("problems_found", &[&self.problems.len().to_string()])),
+            QFlags::from(StandardButton::Ok),
+            parent,
+        ).into_ptr();
+
+        dialog.set_detailed_text(&QString::from_std_str(self.problems.join("\n")));
+        dialog.set_modal(false);
+        dialog.set_attribute_1a(WidgetAttribute::WADeleteOnClose);
+        dialog.show();
+    }
+}
+
 //----------------------------------------------------------------------------//
 //              Utility functions (helpers and stuff like that)
 //----------------------------------------------------------------------------//
@@ -74,6 +190,33 @@ pub(crate) fn log_to_status_bar(text: &str) {
     info!("{}", text);
 }
 
+/// This function runs the body of a slot, catching any panic that happens within it.
+///
+/// Panics cannot unwind through Qt, so without this any panic in a slot aborts the program, taking any unsaved work with it.
+/// Instead, we report the panic with a dialog and try to leave the UI in an usable state.
+pub(crate) fn catch_panic_in_slot<F: FnOnce()>(slot: F) {
+    let first_command_id = CENTRAL_COMMAND.get_next_command_id();
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(slot)) {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() { message.to_string() }
+        else if let Some(message) = payload.downcast_ref::<String>() { message.to_owned() }
+        else { String::new() };
+        error!("Panic caught in slot: {}", message);
+
+        unsafe {
+
+            // If the slot sent commands and died before receiving their responses, discard them, even the ones not yet sent
+            // by the background thread, so they're not received by the next slot as its own.
+            CENTRAL_COMMAND.discard_responses_since(first_command_id);
+
+            // Many slots disable the main window while working. Make sure it's enabled again.
+            let mut main_window = mut_ptr_from_atomic(&STATUS_BAR).window();
+            main_window.set_enabled(true);
+
+            show_dialog(main_window, tre("slot_panic_error", &[&message]), false);
+        }
+    }
+}
+
 /// This function creates a modal dialog, for showing successes or errors.
 ///
 /// It requires:
@@ -96,6 +239,16 @@ pub unsafe fn show_dialog<T: Display>(parent: impl CastInto<MutPtr<QWidget>>, te
     ).exec();
 }
 
+/// This function removes the html tags of the provided text, so messages meant for dialogs can be used as plain text.
+fn strip_html_tags(text: &str) -> String {
+    let mut in_tag = false;
+    text.chars().filter(|character| match character {
+        '<' => { in_tag = true; false }
+        '>' => { in_tag = false; false }
+        _ => !in_tag,
+    }).collect()
+}
+
 /// This function creates a non-modal dialog, for debugging purpouses.
 ///
 /// It requires:
@@ -151,6 +304,134 @@ pub unsafe fn create_grid_layout(mut widget: MutPtr<QWidget>) -> MutPtr<QGridLay
     widget_layout.into_ptr()
 }
 
+/// This function prepares the provided `FileDialog` with the favorite folders in its sidebar and, if we pass it the key
+/// of an action, it makes it start in the last folder used for that action.
+///
+/// Native dialogs don't support custom sidebars, so if there are favorite folders, Qt's own dialog is used instead.
+pub unsafe fn setup_file_dialog(file_dialog: &mut QFileDialog, last_path_key: Option<&str>) {
+    let settings = SETTINGS.read().unwrap();
+    if let Some(last_path_key) = last_path_key {
+        if let Some(Some(ref last_path)) = settings.paths.get(last_path_key) {
+            if last_path.is_dir() {
+                file_dialog.set_directory_q_string(&QString::from_std_str(last_path.to_string_lossy()));
+            }
+        }
+    }
+
+    let favorite_folders = settings.settings_string[FAVORITE_FOLDERS].split(';')
+        .map(|folder| folder.trim())
+        .filter(|folder| !folder.is_empty() && Path::new(folder).is_dir())
+        .collect::<Vec<&str>>();
+
+    if !favorite_folders.is_empty() {
+        file_dialog.set_option_1a(QFileDialogOption::DontUseNativeDialog);
+        let mut sidebar_urls = file_dialog.sidebar_urls();
+        for folder in &favorite_folders {
+            sidebar_urls.append_q_url(&QUrl::from_local_file(&QString::from_std_str(folder)));
+        }
+        file_dialog.set_sidebar_urls(&sidebar_urls);
+    }
+}
+
+/// This function remembers the provided folder as the last folder used for the action with the provided key.
+pub fn save_last_path(last_path_key: &str, folder: &Path) {
+    let mut settings = SETTINGS.write().unwrap();
+    settings.paths.insert(last_path_key.to_owned(), Some(folder.to_path_buf()));
+
+    // This is not critical, so if it fails we just log it.
+    if let Err(error) = settings.save() {
+        error!("Error while saving the last path used: {}", error);
+    }
+}
+
+/// This function remembers the provided value for the string setting with the provided key.
+pub fn save_setting_string(key: &str, value: &str) {
+    let mut settings = SETTINGS.write().unwrap();
+    settings.settings_string.insert(key.to_owned(), value.to_owned());
+
+    // This is not critical, so if it fails we just log it.
+    if let Err(error) = settings.save() {
+        error!("Error while saving the setting {}: {}", key, error);
+    }
+}
+
+/// This function returns the Game Selected the PackFile in the provided path was last saved with, if we remember it.
+///
+/// They're stored in the settings, one per line, as the key of the game and the path of the PackFile separated by a tab, most recent first.
+pub fn get_game_selected_for_packfile(path: &Path) -> Option<String> {
+    let path = path.to_string_lossy();
+    SETTINGS.read().unwrap().settings_string[PACKFILE_GAMES_SELECTED].lines()
+        .filter_map(|line| {
+            let mut data = line.splitn(2, '\t');
+            Some((data.next()?, data.next()?))
+        })
+        .find(|(_, packfile_path)| *packfile_path == path)
+        .map(|(game, _)| game.to_owned())
+}
+
+/// This function remembers the provided Game Selected as the last one the PackFile in the provided path was saved with.
+///
+/// Only the last `PACKFILE_GAMES_SELECTED_MAX` PackFiles are remembered.
+pub fn save_game_selected_for_packfile(path: &Path, game: &str) {
+    let path = path.to_string_lossy();
+    let packfile_games = SETTINGS.read().unwrap().settings_string[PACKFILE_GAMES_SELECTED].lines()
+        .filter(|line| line.splitn(2, '\t').nth(1).map_or(false, |packfile_path| packfile_path != path))
+        .take(PACKFILE_GAMES_SELECTED_MAX - 1)
+        .map(|line| line.to_owned())
+        .collect::<Vec<String>>();
+
+    let mut lines = vec![format!("{}\t{}", game, path)];
+    lines.extend(packfile_games);
+    save_setting_string(PACKFILE_GAMES_SELECTED, &lines.join("\n"));
+}
+
+/// This function remembers the provided value for the bool setting with the provided key.
+pub fn save_setting_bool(key: &str, value: bool) {
+    let mut settings = SETTINGS.write().unwrap();
+    settings.settings_bool.insert(key.to_owned(), value);
+
+    // This is not critical, so if it fails we just log it.
+    if let Err(error) = settings.save() {
+        error!("Error while saving the setting {}: {}", key, error);
+    }
+}
+
+/// This function returns the zoom level remembered for the kind of view with the provided settings key.
+pub fn get_zoom_level(key: &str) -> i32 {
+    SETTINGS.read().unwrap().settings_string.get(key).and_then(|level| level.parse().ok()).unwrap_or(0)
+}
+
+/// This function changes by the provided amount the zoom level remembered for the kind of view with the provided settings key,
+/// and returns the new level. If no amount is provided, the zoom level is reset.
+pub fn change_zoom_level(key: &str, amount: Option<i32>) -> i32 {
+    let level = match amount {
+        Some(amount) => (get_zoom_level(key) + amount).max(-ZOOM_MAX_LEVEL).min(ZOOM_MAX_LEVEL),
+        None => 0,
+    };
+
+    save_setting_string(key, &level.to_string());
+    level
+}
+
+/// This function returns a copy of the provided font, zoomed to the provided level.
+///
+/// Fonts sized in pixels instead of points are returned as they are.
+pub unsafe fn get_zoomed_font(base_font: Ref<QFont>, level: i32) -> CppBox<QFont> {
+    let mut font = QFont::new_copy(base_font);
+    if font.point_size() > 0 {
+        font.set_point_size((font.point_size() + level).max(ZOOM_MIN_FONT_SIZE));
+    }
+    font
+}
+
+/// This function creates a "?" label linking to the provided page of the manual, to put it in complex views.
+pub unsafe fn new_help_label(page: &str) -> CppBox<QLabel> {
+    let mut label = QLabel::from_q_string(&QString::from_std_str(&format!("<a href=\"{}{}\">?</a>", DOCS_BASE_URL, page)));
+    label.set_open_external_links(true);
+    label.set_tool_tip(&qtr("help_label_tooltip"));
+    label
+}
+
 /// This function creates the stylesheet used for the dark theme in windows.
 pub fn create_dark_theme_stylesheet() -> String {
     format!("
@@ -0,0 +1,28 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `VanillaSearchUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `VanillaSearchUI` and `VanillaSearchUISlots` structs.
+!*/
+
+use super::{VanillaSearchUI, slots::VanillaSearchUISlots};
+
+/// This function connects all the actions from the provided `VanillaSearchUI` with their slots in `VanillaSearchUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not polute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &VanillaSearchUI, slots: &VanillaSearchUISlots) {
+    ui.search_button.released().connect(&slots.search);
+    ui.search_line_edit.return_pressed().connect(&slots.search);
+    ui.path_filter_line_edit.return_pressed().connect(&slots.search);
+    ui.close_button.released().connect(ui.dialog.slot_close());
+}
@@ -0,0 +1,218 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Search Vanilla Files*** UI.
+
+This is a dialog to quickly search a text in the game files we already have loaded as dependencies (vanilla DB and Loc Tables,
+and the PackFiles the open one depends on), without having to open them or to set up a Global Search. The matches are read-only.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionBehavior};
+use qt_widgets::QCheckBox;
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::QLineEdit;
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QVariant;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use rpfm_error::ErrorKind;
+use rpfm_lib::global_search::GlobalSearch;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::ffi::add_to_q_list_safe;
+use crate::locale::{qtr, tre};
+use crate::utils::{create_grid_layout, show_dialog};
+use self::slots::VanillaSearchUISlots;
+
+mod connections;
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Search Vanilla Files Window.
+#[derive(Clone)]
+pub struct VanillaSearchUI {
+    dialog: MutPtr<QDialog>,
+
+    search_line_edit: MutPtr<QLineEdit>,
+    path_filter_line_edit: MutPtr<QLineEdit>,
+    case_sensitive_checkbox: MutPtr<QCheckBox>,
+    use_regex_checkbox: MutPtr<QCheckBox>,
+    search_button: MutPtr<QPushButton>,
+
+    matches_tree_view: MutPtr<QTreeView>,
+    matches_tree_model: MutPtr<QStandardItemModel>,
+
+    close_button: MutPtr<QPushButton>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `VanillaSearchUI`.
+impl VanillaSearchUI {
+
+    /// This function creates a ***VanillaSearchUI*** dialog and executes it.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) {
+        let ui = Self::new_with_parent(parent);
+        let slots = VanillaSearchUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+
+        // There is nothing to search on if the game files are not loaded.
+        CENTRAL_COMMAND.send_message_qt(Command::IsThereADependencyDatabase);
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        match response {
+            Response::Bool(true) => { ui.dialog.exec(); },
+            Response::Bool(false) => show_dialog(ui.dialog, ErrorKind::DependencyDatabaseNotLoaded, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function creates the entire `VanillaSearchUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Search Vanilla Files Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("vanilla_search_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 500);
+
+        // Create the search widgets.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut search_line_edit = QLineEdit::new();
+        let mut path_filter_line_edit = QLineEdit::new();
+        let mut case_sensitive_checkbox = QCheckBox::from_q_string(&qtr("global_search_case_sensitive"));
+        let mut use_regex_checkbox = QCheckBox::from_q_string(&qtr("global_search_use_regex"));
+        let mut search_button = QPushButton::from_q_string(&qtr("global_search_search"));
+        search_line_edit.set_placeholder_text(&qtr("vanilla_search_placeholder"));
+        path_filter_line_edit.set_placeholder_text(&qtr("global_search_path_filter"));
+        path_filter_line_edit.set_tool_tip(&qtr("tt_global_search_path_filter_line_edit"));
+        search_button.set_auto_default(false);
+
+        main_grid.set_column_stretch(0, 10);
+        main_grid.add_widget_5a(&mut search_line_edit, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut case_sensitive_checkbox, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&mut use_regex_checkbox, 0, 2, 1, 1);
+        main_grid.add_widget_5a(&mut search_button, 0, 3, 1, 1);
+        main_grid.add_widget_5a(&mut path_filter_line_edit, 1, 0, 1, 4);
+
+        // Create the list of matches. It's read-only, as these files are not part of the open PackFile.
+        let mut matches_tree_view = QTreeView::new_0a();
+        let mut matches_tree_model = QStandardItemModel::new_0a();
+        matches_tree_view.set_model(&mut matches_tree_model);
+        matches_tree_view.set_root_is_decorated(false);
+        matches_tree_view.set_sorting_enabled(true);
+        matches_tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        matches_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        main_grid.add_widget_5a(&mut matches_tree_view, 2, 0, 1, 4);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        main_grid.add_widget_5a(button_box.into_ptr(), 3, 0, 1, 4);
+
+        Self {
+            dialog,
+            search_line_edit: search_line_edit.into_ptr(),
+            path_filter_line_edit: path_filter_line_edit.into_ptr(),
+            case_sensitive_checkbox: case_sensitive_checkbox.into_ptr(),
+            use_regex_checkbox: use_regex_checkbox.into_ptr(),
+            search_button: search_button.into_ptr(),
+            matches_tree_view: matches_tree_view.into_ptr(),
+            matches_tree_model: matches_tree_model.into_ptr(),
+            close_button,
+        }
+    }
+
+    /// This function searches the game files for the text in the search bar, and loads the matches into the list, one per row.
+    pub unsafe fn search(&mut self) {
+        let mut global_search = GlobalSearch::default();
+        global_search.pattern = self.search_line_edit.text().to_std_string();
+        global_search.path_filter = self.path_filter_line_edit.text().to_std_string();
+        global_search.case_sensitive = self.case_sensitive_checkbox.is_checked();
+        global_search.use_regex = self.use_regex_checkbox.is_checked();
+        if global_search.pattern.is_empty() { return; }
+
+        self.load_matches(global_search);
+    }
+
+    /// This function performs the provided search over the game files, and loads its matches into the list.
+    unsafe fn load_matches(&mut self, global_search: GlobalSearch) {
+        CENTRAL_COMMAND.send_message_qt(Command::GlobalSearchDependencies(global_search));
+        let response = CENTRAL_COMMAND.recv_message_qt();
+        let global_search = match response {
+            Response::GlobalSearch(global_search) => global_search,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        self.matches_tree_model.clear();
+        let mut headers = QStringList::new();
+        headers.append_q_string(&qtr("gen_loc_packedfile"));
+        headers.append_q_string(&qtr("gen_loc_column"));
+        headers.append_q_string(&qtr("gen_loc_row"));
+        headers.append_q_string(&qtr("gen_loc_match"));
+        self.matches_tree_model.set_horizontal_header_labels(&headers);
+
+        let mut matches_count = 0;
+        for table_matches in global_search.matches_db.iter().chain(global_search.matches_loc.iter()) {
+            let path = table_matches.path.join("/");
+            for table_match in &table_matches.matches {
+                self.add_match(&path, &table_match.column_name, table_match.row_number + 1, &table_match.contents);
+                matches_count += 1;
+            }
+        }
+
+        for text_matches in &global_search.matches_text {
+            let path = text_matches.path.join("/");
+            for text_match in &text_matches.matches {
+                self.add_match(&path, &(text_match.column + 1).to_string(), text_match.row as i64 + 1, text_match.text.trim());
+                matches_count += 1;
+            }
+        }
+
+        for column in 0..self.matches_tree_model.column_count_0a() - 1 {
+            self.matches_tree_view.resize_column_to_contents(column);
+        }
+
+        self.dialog.set_window_title(&QString::from_std_str(tre("vanilla_search_title_matches", &[&matches_count.to_string()])));
+    }
+
+    /// This function adds a row with the provided match to the list.
+    unsafe fn add_match(&mut self, path: &str, column: &str, row: i64, contents: &str) {
+        let mut qlist = QListOfQStandardItem::new();
+        let mut row_item = QStandardItem::new();
+        row_item.set_data_2a(&QVariant::from_i64(row), 2);
+
+        add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(path)).into_ptr());
+        add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(column)).into_ptr());
+        add_to_q_list_safe(qlist.as_mut_ptr(), row_item.into_ptr());
+        add_to_q_list_safe(qlist.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(contents)).into_ptr());
+        self.matches_tree_model.append_row_q_list_of_q_standard_item(&qlist);
+    }
+}
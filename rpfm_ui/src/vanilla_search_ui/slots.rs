@@ -0,0 +1,51 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `VanillaSearchUISlots`.
+!*/
+
+use qt_core::Slot;
+
+use crate::vanilla_search_ui::VanillaSearchUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `VanillaSearchUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `VanillaSearchUI` goes here.
+pub struct VanillaSearchUISlots {
+    pub search: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `VanillaSearchUISlots`.
+impl VanillaSearchUISlots {
+
+    /// This function creates a new `VanillaSearchUISlots`.
+    pub unsafe fn new(ui: &VanillaSearchUI) -> Self {
+
+        // What happens when we hit the "Search" button.
+        let mut ui_search = ui.clone();
+        let search = Slot::new(move || catch_panic_in_slot(|| {
+            ui_search.search()
+        }));
+
+        Self {
+            search,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code related to the ***Row Clipboard*** of the table views.
+
+Unlike the normal clipboard, which only holds text, the row clipboard holds entire rows with their field names and types,
+so they can be pasted in the same table of another PackFile, even if its columns are in a different order or its version is different.
+It's stored as a file in the temp folder, so it can be shared between different instances of the program.
+!*/
+
+use serde_derive::{Serialize, Deserialize};
+
+use std::env::temp_dir;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+
+use rpfm_error::{ErrorKind, Result};
+use rpfm_lib::packedfile::table::DecodedData;
+use rpfm_lib::schema::{Definition, FieldType};
+
+/// Name of the file which contains the row clipboard.
+const ROW_CLIPBOARD_FILE: &str = "rpfm_row_clipboard.json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds a bunch of rows copied from a table, with enough info to paste them into any version of the same table.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RowClipboard {
+    table_name: String,
+    version: i32,
+    fields: Vec<(String, FieldType)>,
+    rows: Vec<Vec<DecodedData>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `RowClipboard`.
+impl RowClipboard {
+
+    /// This function creates a new `RowClipboard` with the provided rows. The rows must follow the processed fields of the provided definition.
+    pub fn new(table_name: &str, definition: &Definition, rows: Vec<Vec<DecodedData>>) -> Self {
+        Self {
+            table_name: table_name.to_owned(),
+            version: definition.get_version(),
+            fields: definition.get_fields_processed().iter().map(|x| (x.get_name().to_owned(), x.get_ref_field_type().clone())).collect(),
+            rows,
+        }
+    }
+
+    /// This function tries to load the row clipboard from the temp folder.
+    pub fn load() -> Result<Self> {
+        let file_path = temp_dir().join(ROW_CLIPBOARD_FILE);
+        let file = BufReader::new(File::open(file_path).map_err(|_| ErrorKind::RowClipboardEmpty)?);
+        serde_json::from_reader(file).map_err(|error| ErrorKind::RowClipboardParse(error.to_string()).into())
+    }
+
+    /// This function tries to save the row clipboard to the temp folder, replacing the previous one.
+    pub fn save(&self) -> Result<()> {
+        let file_path = temp_dir().join(ROW_CLIPBOARD_FILE);
+        let mut file = BufWriter::new(File::create(file_path)?);
+        file.write_all(serde_json::to_string(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the amount of rows in the clipboard.
+    pub fn get_row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// This function returns the rows of the clipboard, adapted to the provided table and definition.
+    ///
+    /// Columns are matched by name, and their data is converted to the type of the destination column.
+    /// Columns that don't exist in the clipboard are returned as `None`, so they can be filled with their default value.
+    pub fn get_rows_for_definition(&self, table_name: &str, definition: &Definition) -> Result<Vec<Vec<Option<DecodedData>>>> {
+        if self.table_name != table_name {
+            return Err(ErrorKind::RowClipboardTableMismatch(self.table_name.to_owned(), table_name.to_owned()).into());
+        }
+
+        let fields = definition.get_fields_processed();
+        let columns = fields.iter()
+            .map(|field| self.fields.iter().position(|(name, _)| name == field.get_name()))
+            .collect::<Vec<Option<usize>>>();
+
+        // The clipboard file can be edited by hand, so make sure every row has a value for each field before indexing it.
+        if let Some((index, row)) = self.rows.iter().enumerate().find(|(_, row)| row.len() != self.fields.len()) {
+            return Err(ErrorKind::RowClipboardWrongRowLength(index, row.len(), self.fields.len()).into());
+        }
+
+        self.rows.iter().map(|row| {
+            fields.iter().zip(columns.iter()).map(|(field, column)| match column {
+                Some(column) if &self.fields[*column].1 == field.get_ref_field_type() => Ok(Some(row[*column].clone())),
+                Some(column) => match row[*column].convert_between_types(field.get_ref_field_type()) {
+                    Ok(data) => Ok(Some(data)),
+                    Err(_) => Err(ErrorKind::RowClipboardIncompatibleField(field.get_name().to_owned(), format!("{:?}", self.fields[*column].1), format!("{:?}", field.get_ref_field_type())).into()),
+                }
+                None => Ok(None),
+            }).collect()
+        }).collect()
+    }
+}
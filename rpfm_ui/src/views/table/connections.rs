@@ -21,35 +21,60 @@ use super::{TableView, slots::TableViewSlots};
 /// This function is just glue to trigger after initializing both, the actions and the slots. It's here
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &TableView, slots: &TableViewSlots) {
-    ui.get_mut_ptr_filter_line_edit().text_changed().connect(&slots.filter_line_edit);
-    ui.get_mut_ptr_filter_column_selector().current_index_changed().connect(&slots.filter_column_selector);
-    ui.get_mut_ptr_filter_case_sensitive_button().toggled().connect(&slots.filter_case_sensitive_button);
-    ui.get_mut_ptr_table_view_primary().horizontal_header().sort_indicator_changed().connect(&slots.sort_order_column_changed);
+    ui.filter_line_edit.get_unchecked().text_changed().connect(&slots.filter_line_edit);
+    ui.filter_column_selector.get_unchecked().current_index_changed().connect(&slots.filter_column_selector);
+    ui.filter_case_sensitive_button.get_unchecked().toggled().connect(&slots.filter_case_sensitive_button);
+    ui.filter_operator_selector.get_unchecked().current_index_changed().connect(&slots.filter_operator_selector);
+    ui.filter_builder_button.get_unchecked().released().connect(&slots.filter_builder_button);
+    ui.filter_preset_selector.get_unchecked().current_index_changed().connect(&slots.filter_preset_selector);
+    ui.filter_save_preset_button.get_unchecked().released().connect(&slots.filter_save_preset_button);
+    ui.filter_delete_preset_button.get_unchecked().released().connect(&slots.filter_delete_preset_button);
+    ui.table_view_primary.get_unchecked().horizontal_header().sort_indicator_changed().connect(&slots.sort_order_column_changed);
 
-    ui.get_mut_ptr_table_view_primary().custom_context_menu_requested().connect(&slots.show_context_menu);
-    ui.get_mut_ptr_table_view_frozen().custom_context_menu_requested().connect(&slots.show_context_menu);
+    ui.table_view_primary.get_unchecked().custom_context_menu_requested().connect(&slots.show_context_menu);
+    ui.table_view_frozen.get_unchecked().custom_context_menu_requested().connect(&slots.show_context_menu);
 
-    ui.get_mut_ptr_table_model().item_changed().connect(&slots.item_changed);
-    ui.get_mut_ptr_table_view_primary().selection_model().selection_changed().connect(&slots.context_menu_enabler);
-    ui.get_mut_ptr_context_menu_add_rows().triggered().connect(&slots.add_rows);
-    ui.get_mut_ptr_context_menu_insert_rows().triggered().connect(&slots.insert_rows);
-    ui.get_mut_ptr_context_menu_delete_rows().triggered().connect(&slots.delete_rows);
-    ui.get_mut_ptr_context_menu_clone_and_append().triggered().connect(&slots.clone_and_append);
-    ui.get_mut_ptr_context_menu_clone_and_insert().triggered().connect(&slots.clone_and_insert);
-    ui.get_mut_ptr_context_menu_copy().triggered().connect(&slots.copy);
-    ui.get_mut_ptr_context_menu_copy_as_lua_table().triggered().connect(&slots.copy_as_lua_table);
-    ui.get_mut_ptr_context_menu_paste().triggered().connect(&slots.paste);
-    ui.get_mut_ptr_context_menu_invert_selection().triggered().connect(&slots.invert_selection);
-    ui.get_mut_ptr_context_menu_reset_selection().triggered().connect(&slots.reset_selection);
-    ui.get_mut_ptr_context_menu_rewrite_selection().triggered().connect(&slots.rewrite_selection);
-    ui.get_mut_ptr_context_menu_undo().triggered().connect(&slots.undo);
-    ui.get_mut_ptr_context_menu_redo().triggered().connect(&slots.redo);
-    ui.get_mut_ptr_context_menu_import_tsv().triggered().connect(&slots.import_tsv);
-    ui.get_mut_ptr_context_menu_export_tsv().triggered().connect(&slots.export_tsv);
-    ui.get_mut_ptr_context_menu_resize_columns().triggered().connect(&slots.resize_columns);
-    ui.get_mut_ptr_context_menu_sidebar().triggered().connect(&slots.sidebar);
-    ui.get_mut_ptr_context_menu_search().triggered().connect(&slots.search);
-    ui.get_mut_ptr_smart_delete().triggered().connect(&slots.smart_delete);
+    ui.table_model.get_unchecked().item_changed().connect(&slots.item_changed);
+    ui.table_view_primary.get_unchecked().selection_model().selection_changed().connect(&slots.context_menu_enabler);
+    ui.context_menu_add_rows.get_unchecked().triggered().connect(&slots.add_rows);
+    ui.context_menu_insert_rows.get_unchecked().triggered().connect(&slots.insert_rows);
+    ui.context_menu_delete_rows.get_unchecked().triggered().connect(&slots.delete_rows);
+    ui.context_menu_move_rows_up.get_unchecked().triggered().connect(&slots.move_rows_up);
+    ui.context_menu_move_rows_down.get_unchecked().triggered().connect(&slots.move_rows_down);
+    ui.context_menu_clone_and_append.get_unchecked().triggered().connect(&slots.clone_and_append);
+    ui.context_menu_clone_and_insert.get_unchecked().triggered().connect(&slots.clone_and_insert);
+    ui.context_menu_clone_and_edit.get_unchecked().triggered().connect(&slots.clone_and_edit);
+    ui.context_menu_copy.get_unchecked().triggered().connect(&slots.copy);
+    ui.context_menu_copy_as_lua_table.get_unchecked().triggered().connect(&slots.copy_as_lua_table);
+    ui.context_menu_copy_to_row_clipboard.get_unchecked().triggered().connect(&slots.copy_to_row_clipboard);
+    ui.context_menu_paste.get_unchecked().triggered().connect(&slots.paste);
+    ui.context_menu_paste_from_row_clipboard.get_unchecked().triggered().connect(&slots.paste_from_row_clipboard);
+    ui.context_menu_invert_selection.get_unchecked().triggered().connect(&slots.invert_selection);
+    ui.context_menu_reset_selection.get_unchecked().triggered().connect(&slots.reset_selection);
+    ui.context_menu_rewrite_selection.get_unchecked().triggered().connect(&slots.rewrite_selection);
+    ui.context_menu_generate_loc_variants.get_unchecked().triggered().connect(&slots.generate_loc_variants);
+    ui.context_menu_undo.get_unchecked().triggered().connect(&slots.undo);
+    ui.context_menu_redo.get_unchecked().triggered().connect(&slots.redo);
+    ui.context_menu_import_tsv.get_unchecked().triggered().connect(&slots.import_tsv);
+    ui.context_menu_export_tsv.get_unchecked().triggered().connect(&slots.export_tsv);
+    ui.context_menu_export_visible_rows.get_unchecked().triggered().connect(&slots.export_visible_rows);
+    ui.context_menu_resize_columns.get_unchecked().triggered().connect(&slots.resize_columns);
+    ui.context_menu_sidebar.get_unchecked().triggered().connect(&slots.sidebar);
+    ui.context_menu_history.get_unchecked().triggered().connect(&slots.history);
+    ui.context_menu_summary_row.get_unchecked().triggered().connect(&slots.summary_row);
+    ui.context_menu_column_statistics.get_unchecked().triggered().connect(&slots.column_statistics);
+    ui.context_menu_compare_with.get_unchecked().triggered().connect(&slots.compare_with);
+    ui.context_menu_set_column_unit.get_unchecked().triggered().connect(&slots.set_column_unit);
+    ui.context_menu_edit_column_description.get_unchecked().triggered().connect(&slots.edit_column_description);
+    ui.context_menu_show_column_descriptions.get_unchecked().toggled().connect(&slots.show_column_descriptions);
+    ui.context_menu_edit_in_popup.get_unchecked().triggered().connect(&slots.edit_in_popup);
+    ui.context_menu_word_wrap.get_unchecked().triggered().connect(&slots.word_wrap);
+    ui.context_menu_zoom_in.get_unchecked().triggered().connect(&slots.zoom_in);
+    ui.context_menu_zoom_out.get_unchecked().triggered().connect(&slots.zoom_out);
+    ui.context_menu_zoom_reset.get_unchecked().triggered().connect(&slots.zoom_reset);
+    ui.context_menu_refresh_reference_data.get_unchecked().triggered().connect(&slots.refresh_reference_data);
+    ui.context_menu_search.get_unchecked().triggered().connect(&slots.search);
+    ui.smart_delete.get_unchecked().triggered().connect(&slots.smart_delete);
 
     ui.get_hide_show_checkboxes().iter()
         .zip(slots.hide_show_columns.iter())
@@ -59,12 +84,38 @@ pub unsafe fn set_connections(ui: &TableView, slots: &TableViewSlots) {
         .zip(slots.freeze_columns.iter())
         .for_each(|(x, y)| { x.state_changed().connect(y); });
 
-    ui.get_mut_ptr_search_search_button().released().connect(&slots.search_search);
-    ui.get_mut_ptr_search_prev_match_button().released().connect(&slots.search_prev_match);
-    ui.get_mut_ptr_search_next_match_button().released().connect(&slots.search_next_match);
-    ui.get_mut_ptr_search_replace_current_button().released().connect(&slots.search_replace_current);
-    ui.get_mut_ptr_search_replace_all_button().released().connect(&slots.search_replace_all);
-    ui.get_mut_ptr_search_close_button().released().connect(&slots.search_close);
+    ui.search_search_button.get_unchecked().released().connect(&slots.search_search);
+    ui.search_prev_match_button.get_unchecked().released().connect(&slots.search_prev_match);
+    ui.search_next_match_button.get_unchecked().released().connect(&slots.search_next_match);
+    ui.search_replace_current_button.get_unchecked().released().connect(&slots.search_replace_current);
+    ui.search_replace_all_button.get_unchecked().released().connect(&slots.search_replace_all);
+    ui.search_close_button.get_unchecked().released().connect(&slots.search_close);
 
-    ui.get_mut_ptr_table_view_primary().double_clicked().connect(&slots.open_subtable);
+    ui.undo_model.get_unchecked().model_reset().connect(&slots.history_update);
+    ui.history_view.get_unchecked().selection_model().selection_changed().connect(&slots.history_selection_changed);
+    ui.history_view.get_unchecked().double_clicked().connect(&slots.history_jump_double_clicked);
+    ui.history_jump_button.get_unchecked().released().connect(&slots.history_jump);
+
+    ui.table_view_primary.get_unchecked().double_clicked().connect(&slots.open_subtable);
+
+    ui.table_view_primary.get_unchecked().model().data_changed().connect(&slots.summary_update);
+    ui.table_view_primary.get_unchecked().model().rows_inserted().connect(&slots.summary_update);
+    ui.table_view_primary.get_unchecked().model().rows_removed().connect(&slots.summary_update);
+    ui.table_view_primary.get_unchecked().model().model_reset().connect(&slots.summary_update);
+    ui.table_view_primary.get_unchecked().model().layout_changed().connect(&slots.summary_update);
+    ui.table_view_primary.get_unchecked().horizontal_header().section_resized().connect(&slots.summary_layout_update);
+    ui.table_view_primary.get_unchecked().horizontal_header().section_moved().connect(&slots.summary_layout_update);
+    ui.table_view_primary.get_unchecked().vertical_header().section_moved().connect(&slots.move_row_from_header);
+    ui.table_view_primary.get_unchecked().horizontal_scroll_bar().value_changed().connect(&slots.summary_layout_update);
+    ui.summary_view.get_unchecked().custom_context_menu_requested().connect(&slots.summary_show_context_menu);
+
+    ui.context_menu_pivot.get_unchecked().triggered().connect(&slots.pivot);
+    ui.pivot_group_column_selector.get_unchecked().current_index_changed().connect(&slots.pivot_update);
+    ui.pivot_value_column_selector.get_unchecked().current_index_changed().connect(&slots.pivot_update);
+    ui.pivot_aggregate_selector.get_unchecked().current_index_changed().connect(&slots.pivot_update);
+    ui.table_view_primary.get_unchecked().model().data_changed().connect(&slots.pivot_update);
+    ui.table_view_primary.get_unchecked().model().rows_inserted().connect(&slots.pivot_update);
+    ui.table_view_primary.get_unchecked().model().rows_removed().connect(&slots.pivot_update);
+    ui.table_view_primary.get_unchecked().model().model_reset().connect(&slots.pivot_update);
+    ui.table_view_primary.get_unchecked().model().layout_changed().connect(&slots.pivot_update);
 }
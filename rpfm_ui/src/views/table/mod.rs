@@ -19,10 +19,13 @@ use qt_widgets::QGridLayout;
 use qt_widgets::QLineEdit;
 use qt_widgets::QPushButton;
 use qt_widgets::QTableView;
+use qt_widgets::QTreeView;
 use qt_widgets::QMenu;
 use qt_widgets::QWidget;
 use qt_widgets::QScrollArea;
+use qt_widgets::QTabWidget;
 use qt_widgets::QLabel;
+use qt_widgets::q_abstract_item_view::EditTrigger;
 
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
@@ -38,20 +41,23 @@ use qt_core::QVariant;
 use qt_core::QString;
 use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::MatchFlag;
+use qt_core::{ContextMenuPolicy, ScrollBarPolicy};
 
 use cpp_core::MutPtr;
 
+use serde_derive::{Serialize, Deserialize};
+
 use std::collections::BTreeMap;
 use std::{fmt, fmt::Debug};
 use std::sync::{Arc, RwLock, RwLockReadGuard};
-use std::sync::atomic::{AtomicBool, AtomicPtr};
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
 
 use rpfm_error::{ErrorKind, Result};
 use rpfm_lib::common::parse_str_as_bool;
 use rpfm_lib::packedfile::PackedFileType;
-use rpfm_lib::packedfile::table::{anim_fragment::AnimFragment, animtable::AnimTable, DecodedData, db::DB, loc::Loc, matched_combat::MatchedCombat};
+use rpfm_lib::packedfile::table::{anim_fragment::AnimFragment, animtable::AnimTable, db::DB, loc::Loc, matched_combat::MatchedCombat};
 use rpfm_lib::schema::{Definition, FieldType, Schema, VersionedFile};
-use rpfm_lib::SCHEMA;
+use rpfm_lib::settings::ZOOM_TABLE;
 use rpfm_lib::SETTINGS;
 
 use crate::app_ui::AppUI;
@@ -59,17 +65,20 @@ use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::ffi::*;
 use crate::global_search_ui::GlobalSearchUI;
-use crate::locale::qtr;
+use crate::locale::{qtr, tr};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{View, ViewType};
-use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic};
+use crate::utils::{atomic_from_mut_ptr, mut_ptr_from_atomic, QtPtr};
 use crate::utils::create_grid_layout;
+use crate::utils::get_zoom_level;
 use crate::utils::show_dialog;
 
+use self::clipboard::RowClipboard;
 use self::slots::TableViewSlots;
 use self::raw::*;
 use self::utils::*;
 
+mod clipboard;
 mod connections;
 pub mod slots;
 mod raw;
@@ -82,11 +91,66 @@ pub static COLUMN_SIZE_BOOLEAN: i32 = 100;
 pub static COLUMN_SIZE_NUMBER: i32 = 140;
 pub static COLUMN_SIZE_STRING: i32 = 350;
 
+// Max length of each line of the descriptions shown in the column headers.
+pub static COLUMN_DESCRIPTION_LINE_LENGTH: usize = 40;
+
 pub static ITEM_HAS_SOURCE_VALUE: i32 = 30;
 pub static ITEM_SOURCE_VALUE: i32 = 31;
 pub static ITEM_IS_SEQUENCE: i32 = 35;
 pub static ITEM_SEQUENCE_DATA: i32 = 36;
 
+// Name of the column in the items of the header, as their text may include the unit of the column.
+pub static HEADER_COLUMN_NAME: i32 = 41;
+
+// Value used to sort the items of the pivot, so numbers are sorted as numbers.
+pub static PIVOT_SORT_VALUE: i32 = 42;
+
+// Aggregates available for the pivot, in the order they're shown to the user.
+pub static PIVOT_AGGREGATES: [PivotAggregate; 5] = [
+    PivotAggregate::Count,
+    PivotAggregate::Sum,
+    PivotAggregate::Average,
+    PivotAggregate::Min,
+    PivotAggregate::Max,
+];
+
+// Position in the undo history of each entry of the history panel, and max amount of details shown per entry.
+pub static HISTORY_POSITION: i32 = 40;
+pub static HISTORY_DETAILS_LIMIT: usize = 20;
+
+// Estimated memory used by each item stored in the undo history, in bytes. Items have several roles of data, so this is a rough guess.
+pub static HISTORY_ESTIMATED_ITEM_SIZE: usize = 256;
+
+// Length of the longest bar of the histogram of the column statistics, in characters.
+pub static HISTOGRAM_BAR_LENGTH: usize = 40;
+
+// Max height of a row when word wrap is enabled.
+pub static WORD_WRAP_MAX_ROW_HEIGHT: i32 = 300;
+
+// Operators available for the filter conditions, in the order they're shown to the user.
+pub static FILTER_OPERATORS: [FilterOperator; 10] = [
+    FilterOperator::Contains,
+    FilterOperator::NotContains,
+    FilterOperator::Equals,
+    FilterOperator::NotEquals,
+    FilterOperator::StartsWith,
+    FilterOperator::EndsWith,
+    FilterOperator::GreaterThan,
+    FilterOperator::GreaterOrEqual,
+    FilterOperator::LessThan,
+    FilterOperator::LessOrEqual,
+];
+
+// Prefixes that can be typed in the filter line to use an operator, instead of the one selected. Longest ones first, so `>=` is not read as `>`.
+pub static FILTER_OPERATOR_PREFIXES: [(&str, FilterOperator); 6] = [
+    (">=", FilterOperator::GreaterOrEqual),
+    ("<=", FilterOperator::LessOrEqual),
+    ("!=", FilterOperator::NotEquals),
+    (">", FilterOperator::GreaterThan),
+    ("<", FilterOperator::LessThan),
+    ("=", FilterOperator::Equals),
+];
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -96,7 +160,6 @@ pub static ITEM_SEQUENCE_DATA: i32 = 36;
 pub enum TableType {
     AnimFragment(AnimFragment),
     AnimTable(AnimTable),
-    DependencyManager(Vec<Vec<DecodedData>>),
     DB(DB),
     Loc(Loc),
     MatchedCombat(MatchedCombat),
@@ -117,6 +180,9 @@ pub enum TableOperations {
     /// It holds a copy of the entire table, before importing.
     ImportTSV(Vec<AtomicPtr<QListOfQStandardItem>>),
 
+    /// Intended for when moving rows in ordered tables. It holds the moves done, as (origin row, destination row), in the order they were done.
+    MoveRows(Vec<(i32, i32)>),
+
     /// A Jack-of-all-Trades. It holds a Vec<TableOperations>, for those situations one is not enough.
     Carolina(Vec<TableOperations>),
 }
@@ -135,6 +201,50 @@ pub struct TableSearch {
     current_item: Option<u64>,
 }
 
+/// This enum defines the aggregate shown for a column in the summary row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SummaryAggregate {
+    Sum,
+    Average,
+    Count,
+    None,
+}
+
+/// This enum defines the aggregate the pivot applies to the values of each group.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PivotAggregate {
+    Count,
+    Sum,
+    Average,
+    Min,
+    Max,
+}
+
+/// This enum defines the operators a filter condition can use to compare the cells of its column with its value.
+///
+/// Their values must be kept in sync with the ones of the table filter in `qt_subclasses`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilterOperator {
+    Contains = 0,
+    NotContains = 1,
+    Equals = 2,
+    NotEquals = 3,
+    StartsWith = 4,
+    EndsWith = 5,
+    GreaterThan = 6,
+    GreaterOrEqual = 7,
+    LessThan = 8,
+    LessOrEqual = 9,
+}
+
+/// This struct represents a condition of the table filter. The column is stored by name, so changes in the definition don't mess it up.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FilterCondition {
+    pub column: String,
+    pub operator: FilterOperator,
+    pub value: String,
+}
+
 /// This enum defines the operation to be done when updating something related to the TableSearch.
 pub enum TableSearchUpdate {
     Update,
@@ -145,55 +255,94 @@ pub enum TableSearchUpdate {
 
 /// This struct contains pointers to all the widgets in a Table View.
 pub struct TableView {
-    table_view_primary: AtomicPtr<QTableView>,
-    table_view_frozen: AtomicPtr<QTableView>,
-    table_model: AtomicPtr<QStandardItemModel>,
-    //table_enable_lookups_button: AtomicPtr<QPushButton>,
-    filter_case_sensitive_button: AtomicPtr<QPushButton>,
-    filter_column_selector: AtomicPtr<QComboBox>,
-    filter_line_edit: AtomicPtr<QLineEdit>,
-
-    context_menu_add_rows: AtomicPtr<QAction>,
-    context_menu_insert_rows: AtomicPtr<QAction>,
-    context_menu_delete_rows: AtomicPtr<QAction>,
-    context_menu_clone_and_append: AtomicPtr<QAction>,
-    context_menu_clone_and_insert: AtomicPtr<QAction>,
-    context_menu_copy: AtomicPtr<QAction>,
-    context_menu_copy_as_lua_table: AtomicPtr<QAction>,
-    context_menu_paste: AtomicPtr<QAction>,
-    context_menu_invert_selection: AtomicPtr<QAction>,
-    context_menu_reset_selection: AtomicPtr<QAction>,
-    context_menu_rewrite_selection: AtomicPtr<QAction>,
-    context_menu_undo: AtomicPtr<QAction>,
-    context_menu_redo: AtomicPtr<QAction>,
-    context_menu_import_tsv: AtomicPtr<QAction>,
-    context_menu_export_tsv: AtomicPtr<QAction>,
-    context_menu_resize_columns: AtomicPtr<QAction>,
-    context_menu_sidebar: AtomicPtr<QAction>,
-    context_menu_search: AtomicPtr<QAction>,
-    smart_delete: AtomicPtr<QAction>,
-
-    sidebar_hide_checkboxes: Arc<Vec<AtomicPtr<QCheckBox>>>,
-    sidebar_freeze_checkboxes: Arc<Vec<AtomicPtr<QCheckBox>>>,
-
-    search_search_button: AtomicPtr<QPushButton>,
-    search_replace_current_button: AtomicPtr<QPushButton>,
-    search_replace_all_button: AtomicPtr<QPushButton>,
-    search_close_button: AtomicPtr<QPushButton>,
-    search_prev_match_button: AtomicPtr<QPushButton>,
-    search_next_match_button: AtomicPtr<QPushButton>,
-    search_column_selector: AtomicPtr<QComboBox>,
+    table_view_primary: QtPtr<QTableView>,
+    table_view_frozen: QtPtr<QTableView>,
+    table_model: QtPtr<QStandardItemModel>,
+    //table_enable_lookups_button: QtPtr<QPushButton>,
+    filter_case_sensitive_button: QtPtr<QPushButton>,
+    filter_column_selector: QtPtr<QComboBox>,
+    filter_line_edit: QtPtr<QLineEdit>,
+    filter_operator_selector: QtPtr<QComboBox>,
+    filter_builder_button: QtPtr<QPushButton>,
+    filter_preset_selector: QtPtr<QComboBox>,
+    filter_save_preset_button: QtPtr<QPushButton>,
+    filter_delete_preset_button: QtPtr<QPushButton>,
+
+    context_menu_add_rows: QtPtr<QAction>,
+    context_menu_insert_rows: QtPtr<QAction>,
+    context_menu_delete_rows: QtPtr<QAction>,
+    context_menu_move_rows_up: QtPtr<QAction>,
+    context_menu_move_rows_down: QtPtr<QAction>,
+    context_menu_clone_and_append: QtPtr<QAction>,
+    context_menu_clone_and_insert: QtPtr<QAction>,
+    context_menu_clone_and_edit: QtPtr<QAction>,
+    context_menu_copy: QtPtr<QAction>,
+    context_menu_copy_as_lua_table: QtPtr<QAction>,
+    context_menu_copy_to_row_clipboard: QtPtr<QAction>,
+    context_menu_paste: QtPtr<QAction>,
+    context_menu_paste_from_row_clipboard: QtPtr<QAction>,
+    context_menu_invert_selection: QtPtr<QAction>,
+    context_menu_reset_selection: QtPtr<QAction>,
+    context_menu_rewrite_selection: QtPtr<QAction>,
+    context_menu_generate_loc_variants: QtPtr<QAction>,
+    context_menu_undo: QtPtr<QAction>,
+    context_menu_redo: QtPtr<QAction>,
+    context_menu_import_tsv: QtPtr<QAction>,
+    context_menu_export_tsv: QtPtr<QAction>,
+    context_menu_export_visible_rows: QtPtr<QAction>,
+    context_menu_resize_columns: QtPtr<QAction>,
+    context_menu_sidebar: QtPtr<QAction>,
+    context_menu_history: QtPtr<QAction>,
+    context_menu_summary_row: QtPtr<QAction>,
+    context_menu_column_statistics: QtPtr<QAction>,
+    context_menu_compare_with: QtPtr<QAction>,
+    context_menu_pivot: QtPtr<QAction>,
+    context_menu_set_column_unit: QtPtr<QAction>,
+    context_menu_edit_column_description: QtPtr<QAction>,
+    context_menu_show_column_descriptions: QtPtr<QAction>,
+    context_menu_edit_in_popup: QtPtr<QAction>,
+    context_menu_word_wrap: QtPtr<QAction>,
+    context_menu_zoom_in: QtPtr<QAction>,
+    context_menu_zoom_out: QtPtr<QAction>,
+    context_menu_zoom_reset: QtPtr<QAction>,
+    context_menu_refresh_reference_data: QtPtr<QAction>,
+    context_menu_search: QtPtr<QAction>,
+    smart_delete: QtPtr<QAction>,
+
+    sidebar_hide_checkboxes: Arc<Vec<QtPtr<QCheckBox>>>,
+    sidebar_freeze_checkboxes: Arc<Vec<QtPtr<QCheckBox>>>,
+
+    search_search_button: QtPtr<QPushButton>,
+    search_replace_current_button: QtPtr<QPushButton>,
+    search_replace_all_button: QtPtr<QPushButton>,
+    search_close_button: QtPtr<QPushButton>,
+    search_prev_match_button: QtPtr<QPushButton>,
+    search_next_match_button: QtPtr<QPushButton>,
+    search_column_selector: QtPtr<QComboBox>,
+
+    history_view: QtPtr<QTreeView>,
+    history_jump_button: QtPtr<QPushButton>,
+
+    summary_view: QtPtr<QTableView>,
+
+    pivot_group_column_selector: QtPtr<QComboBox>,
+    pivot_value_column_selector: QtPtr<QComboBox>,
+    pivot_aggregate_selector: QtPtr<QComboBox>,
 
     table_name: Option<String>,
+    table_state_name: Option<String>,
     table_uuid: Option<String>,
     packed_file_path: Option<Arc<RwLock<Vec<String>>>>,
     packed_file_type: Arc<PackedFileType>,
     table_definition: Arc<RwLock<Definition>>,
     dependency_data: Arc<RwLock<BTreeMap<i32, BTreeMap<String, String>>>>,
+    dependency_data_is_stale: Arc<AtomicBool>,
 
-    undo_model: AtomicPtr<QStandardItemModel>,
+    undo_model: QtPtr<QStandardItemModel>,
     history_undo: Arc<RwLock<Vec<TableOperations>>>,
     history_redo: Arc<RwLock<Vec<TableOperations>>>,
+
+    alive: Arc<AtomicBool>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -204,8 +353,6 @@ pub struct TableView {
 impl TableView {
 
     /// This function creates a new Table View, and sets up his slots and connections.
-    ///
-    /// NOTE: To open the dependency list, pass it an empty path.
     pub unsafe fn new_view(
         mut parent: MutPtr<QWidget>,
         app_ui: &AppUI,
@@ -216,10 +363,6 @@ impl TableView {
     ) -> Result<(Self, TableViewSlots)> {
 
         let (table_definition, table_name, table_uuid, packed_file_type) = match table_data {
-            TableType::DependencyManager(_) => {
-                let schema = SCHEMA.read().unwrap();
-                (schema.as_ref().unwrap().get_ref_versioned_file_dep_manager().unwrap().get_version_list()[0].clone(), None, None, PackedFileType::DependencyPackFilesList)
-            },
             TableType::DB(ref table) => (table.get_definition(), Some(table.get_table_name()), Some(table.get_uuid()), PackedFileType::DB),
             TableType::Loc(ref table) => (table.get_definition(), None, None, PackedFileType::Loc),
             TableType::MatchedCombat(ref table) => (table.get_definition(), None, None, PackedFileType::MatchedCombat),
@@ -233,9 +376,10 @@ impl TableView {
         // Create the locks for undoing and saving. These are needed to optimize the undo/saving process.
         let undo_lock = Arc::new(AtomicBool::new(false));
         let save_lock = Arc::new(AtomicBool::new(false));
+        let alive = Arc::new(AtomicBool::new(true));
 
         // Prepare the Table and its model.
-        let mut filter_model = QSortFilterProxyModel::new_0a();
+        let mut filter_model = new_tableview_filter_safe(&mut parent);
         let mut model = QStandardItemModel::new_0a();
         filter_model.set_source_model(&mut model);
         let (mut table_view_primary, table_view_frozen) = new_tableview_frozen_safe(&mut parent);
@@ -262,6 +406,11 @@ impl TableView {
         let mut row_filter_line_edit = QLineEdit::new();
         let mut row_filter_column_selector = QComboBox::new_0a();
         let mut row_filter_case_sensitive_button = QPushButton::from_q_string(&qtr("table_filter_case_sensitive"));
+        let mut row_filter_operator_selector = QComboBox::new_0a();
+        let mut row_filter_builder_button = QPushButton::from_q_string(&qtr("table_filter_builder"));
+        let mut row_filter_preset_selector = QComboBox::new_0a();
+        let mut row_filter_save_preset_button = QPushButton::from_q_string(&qtr("table_filter_save_preset"));
+        let mut row_filter_delete_preset_button = QPushButton::from_q_string(&qtr("table_filter_delete_preset"));
         let row_filter_column_list = QStandardItemModel::new_0a().into_ptr();
         let mut table_enable_lookups_button = QPushButton::from_q_string(&qtr("table_enable_lookups"));
 
@@ -271,20 +420,58 @@ impl TableView {
         fields.sort_by(|a, b| a.get_ca_order().cmp(&b.get_ca_order()));
         for field in &fields {
             let name = clean_column_names(&field.get_name());
-            row_filter_column_selector.add_item_q_string(&QString::from_std_str(&name));
+            row_filter_column_selector.add_item_q_string_q_variant(&QString::from_std_str(&name), &QVariant::from_q_string(&QString::from_std_str(field.get_name())));
         }
 
-        row_filter_line_edit.set_placeholder_text(&qtr("packedfile_filter"));
+        for operator in &FILTER_OPERATORS {
+            row_filter_operator_selector.add_item_q_string(&QString::from_std_str(&operator.to_string()));
+        }
+
+        row_filter_line_edit.set_placeholder_text(&qtr("table_filter"));
+        row_filter_line_edit.set_tool_tip(&qtr("tt_table_filter"));
         row_filter_case_sensitive_button.set_checkable(true);
         table_enable_lookups_button.set_checkable(true);
 
+        // Tables are remembered by name, or by type if they don't have one. Subtables are not remembered.
+        let table_state_name = if packed_file_path.is_some() {
+            Some(table_name.clone().unwrap_or_else(|| packed_file_type.to_string()))
+        } else { None };
+
+        // Presets are saved with the state of the table, so tables without one cannot have them.
+        load_filter_presets(row_filter_preset_selector.as_mut_ptr(), table_state_name.as_ref());
+        row_filter_preset_selector.set_enabled(table_state_name.is_some());
+        row_filter_save_preset_button.set_enabled(table_state_name.is_some());
+        row_filter_delete_preset_button.set_enabled(table_state_name.is_some());
+
+        // The filter's widgets go in their own widget, so they don't mess with the columns of the grid.
+        let mut filter_widget = QWidget::new_0a();
+        let mut filter_grid = create_grid_layout(filter_widget.as_mut_ptr());
+        filter_grid.set_contents_margins_4a(0, 0, 0, 0);
+        filter_grid.add_widget_5a(&mut row_filter_line_edit, 0, 0, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_operator_selector, 0, 1, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_column_selector, 0, 2, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_case_sensitive_button, 0, 3, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_builder_button, 0, 4, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_preset_selector, 0, 5, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_save_preset_button, 0, 6, 1, 1);
+        filter_grid.add_widget_5a(&mut row_filter_delete_preset_button, 0, 7, 1, 1);
+        filter_grid.set_column_stretch(0, 10);
+
+        // The table goes in a tab, so the pivot can be shown in another one. The tab bar is only visible while there is more than one.
+        let mut table_tabs = QTabWidget::new_0a();
+        table_tabs.set_document_mode(true);
+        table_tabs.set_tab_bar_auto_hide(true);
+        table_tabs.add_tab_2a(table_view_primary, &qtr("pivot_table_tab"));
+
         // Add everything to the grid.
         let mut layout: MutPtr<QGridLayout> = parent.layout().static_downcast_mut();
-        layout.add_widget_5a(table_view_primary, 0, 0, 1, 4);
-        layout.add_widget_5a(&mut row_filter_line_edit, 2, 0, 1, 1);
-        layout.add_widget_5a(&mut row_filter_case_sensitive_button, 2, 1, 1, 1);
-        layout.add_widget_5a(&mut row_filter_column_selector, 2, 2, 1, 1);
-        //layout.add_widget_5a(&mut table_enable_lookups_button, 2, 3, 1, 1);
+        layout.add_widget_5a(&mut table_tabs, 0, 0, 1, 4);
+        layout.add_widget_5a(filter_widget.into_ptr(), 3, 0, 1, 3);
+        //layout.add_widget_5a(&mut table_enable_lookups_button, 3, 3, 1, 1);
+
+        // Label to show how big the undo history is.
+        let mut history_size_label = QLabel::new();
+        layout.add_widget_5a(&mut history_size_label, 3, 3, 1, 1);
 
         // Action to make the delete button delete contents.
         let smart_delete = QAction::new().into_ptr();
@@ -296,26 +483,62 @@ impl TableView {
         let context_menu_insert_rows = context_menu.add_action_q_string(&qtr("context_menu_insert_rows"));
         let context_menu_delete_rows = context_menu.add_action_q_string(&qtr("context_menu_delete_rows"));
 
+        // Only tables whose row order matters can have their rows reordered, either with these actions or dragging their row headers.
+        let is_ordered = table_definition.get_is_ordered();
+        let mut context_menu_move_rows_up = context_menu.add_action_q_string(&qtr("context_menu_move_rows_up"));
+        let mut context_menu_move_rows_down = context_menu.add_action_q_string(&qtr("context_menu_move_rows_down"));
+        context_menu_move_rows_up.set_visible(is_ordered);
+        context_menu_move_rows_down.set_visible(is_ordered);
+        table_view_primary.vertical_header().set_sections_movable(is_ordered);
+
         let mut context_menu_clone_submenu = QMenu::from_q_string(&qtr("context_menu_clone_submenu"));
         let context_menu_clone_and_insert = context_menu_clone_submenu.add_action_q_string(&qtr("context_menu_clone_and_insert"));
         let context_menu_clone_and_append = context_menu_clone_submenu.add_action_q_string(&qtr("context_menu_clone_and_append"));
+        let context_menu_clone_and_edit = context_menu_clone_submenu.add_action_q_string(&qtr("context_menu_clone_and_edit"));
 
         let mut context_menu_copy_submenu = QMenu::from_q_string(&qtr("context_menu_copy_submenu"));
         let context_menu_copy = context_menu_copy_submenu.add_action_q_string(&qtr("context_menu_copy"));
         let context_menu_copy_as_lua_table = context_menu_copy_submenu.add_action_q_string(&qtr("context_menu_copy_as_lua_table"));
+        let context_menu_copy_to_row_clipboard = context_menu_copy_submenu.add_action_q_string(&qtr("context_menu_copy_to_row_clipboard"));
 
         let context_menu_paste = context_menu.add_action_q_string(&qtr("context_menu_paste"));
+        let context_menu_paste_from_row_clipboard = context_menu.add_action_q_string(&qtr("context_menu_paste_from_row_clipboard"));
 
         let context_menu_rewrite_selection = context_menu.add_action_q_string(&qtr("context_menu_rewrite_selection"));
+        let mut context_menu_generate_loc_variants = context_menu.add_action_q_string(&qtr("context_menu_generate_loc_variants"));
+        context_menu_generate_loc_variants.set_visible(packed_file_type == PackedFileType::Loc);
         let context_menu_invert_selection = context_menu.add_action_q_string(&qtr("context_menu_invert_selection"));
         let context_menu_reset_selection = context_menu.add_action_q_string(&qtr("context_menu_reset_selection"));
         let context_menu_resize_columns = context_menu.add_action_q_string(&qtr("context_menu_resize_columns"));
 
         let context_menu_import_tsv = context_menu.add_action_q_string(&qtr("context_menu_import_tsv"));
         let context_menu_export_tsv = context_menu.add_action_q_string(&qtr("context_menu_export_tsv"));
+        let context_menu_export_visible_rows = context_menu.add_action_q_string(&qtr("context_menu_export_visible_rows"));
 
         let context_menu_search = context_menu.add_action_q_string(&qtr("context_menu_search"));
         let context_menu_sidebar = context_menu.add_action_q_string(&qtr("context_menu_sidebar"));
+        let context_menu_history = context_menu.add_action_q_string(&qtr("context_menu_history"));
+        let context_menu_summary_row = context_menu.add_action_q_string(&qtr("context_menu_summary_row"));
+        let context_menu_column_statistics = context_menu.add_action_q_string(&qtr("context_menu_column_statistics"));
+        let context_menu_compare_with = context_menu.add_action_q_string(&qtr("context_menu_compare_with"));
+        context_menu_compare_with.set_visible(packed_file_path.is_some() && (packed_file_type == PackedFileType::DB || packed_file_type == PackedFileType::Loc));
+        let mut context_menu_pivot = context_menu.add_action_q_string(&qtr("context_menu_pivot"));
+        context_menu_pivot.set_checkable(true);
+        let context_menu_set_column_unit = context_menu.add_action_q_string(&qtr("context_menu_set_column_unit"));
+        let context_menu_edit_column_description = context_menu.add_action_q_string(&qtr("context_menu_edit_column_description"));
+        context_menu_edit_column_description.set_visible(packed_file_type == PackedFileType::DB || packed_file_type == PackedFileType::Loc);
+        let mut context_menu_show_column_descriptions = context_menu.add_action_q_string(&qtr("context_menu_show_column_descriptions"));
+        context_menu_show_column_descriptions.set_checkable(true);
+        context_menu_show_column_descriptions.set_checked(SETTINGS.read().unwrap().settings_bool["tables_show_column_descriptions"]);
+        let context_menu_edit_in_popup = context_menu.add_action_q_string(&qtr("context_menu_edit_in_popup"));
+        let mut context_menu_word_wrap = context_menu.add_action_q_string(&qtr("context_menu_word_wrap"));
+        context_menu_word_wrap.set_checkable(true);
+
+        let mut context_menu_zoom_submenu = QMenu::from_q_string(&qtr("context_menu_zoom_submenu"));
+        let context_menu_zoom_in = context_menu_zoom_submenu.add_action_q_string(&qtr("context_menu_zoom_in"));
+        let context_menu_zoom_out = context_menu_zoom_submenu.add_action_q_string(&qtr("context_menu_zoom_out"));
+        let context_menu_zoom_reset = context_menu_zoom_submenu.add_action_q_string(&qtr("context_menu_zoom_reset"));
+        let context_menu_refresh_reference_data = context_menu.add_action_q_string(&qtr("context_menu_refresh_reference_data"));
 
         let context_menu_undo = context_menu.add_action_q_string(&qtr("context_menu_undo"));
         let context_menu_redo = context_menu.add_action_q_string(&qtr("context_menu_redo"));
@@ -323,6 +546,7 @@ impl TableView {
         // Insert some separators to space the menu, and the paste submenu.
         context_menu.insert_menu(context_menu_paste, context_menu_clone_submenu.into_ptr());
         context_menu.insert_menu(context_menu_paste, context_menu_copy_submenu.into_ptr());
+        context_menu.insert_menu(context_menu_refresh_reference_data, context_menu_zoom_submenu.into_ptr());
         context_menu.insert_separator(context_menu_rewrite_selection);
         context_menu.insert_separator(context_menu_import_tsv);
         context_menu.insert_separator(context_menu_search);
@@ -380,7 +604,7 @@ impl TableView {
         search_grid.add_widget_5a(&mut search_column_selector, 2, 2, 1, 1);
         search_grid.add_widget_5a(&mut search_case_sensitive_button, 2, 3, 1, 1);
 
-        layout.add_widget_5a(search_widget, 1, 0, 1, 4);
+        layout.add_widget_5a(search_widget, 2, 0, 1, 4);
         layout.set_column_stretch(0, 10);
         search_widget.hide();
 
@@ -425,25 +649,145 @@ impl TableView {
             sidebar_grid.add_widget_5a(&mut hide_show_checkbox, (index + 1) as i32, 1, 1, 1);
             sidebar_grid.add_widget_5a(&mut freeze_unfreeze_checkbox, (index + 1) as i32, 2, 1, 1);
 
-            hide_show_checkboxes.push(atomic_from_mut_ptr(hide_show_checkbox.into_ptr()));
-            freeze_checkboxes.push(atomic_from_mut_ptr(freeze_unfreeze_checkbox.into_ptr()));
+            hide_show_checkboxes.push(QtPtr::new(hide_show_checkbox.into_ptr(), &alive));
+            freeze_checkboxes.push(QtPtr::new(freeze_unfreeze_checkbox.into_ptr(), &alive));
         }
 
         // Add all the stuff to the main grid and hide the search widget.
-        layout.add_widget_5a(sidebar_scroll_area, 0, 4, 3, 1);
+        layout.add_widget_5a(sidebar_scroll_area, 0, 4, 4, 1);
         sidebar_scroll_area.hide();
         sidebar_grid.set_row_stretch(999, 10);
 
+        //--------------------------------------------------//
+        // Undo History Section.
+        //--------------------------------------------------//
+
+        // Create the history panel. It lists the operations in the undo/redo history, and allows to jump between them.
+        let mut history_widget = QWidget::new_0a().into_ptr();
+        let mut history_grid = create_grid_layout(history_widget);
+        history_grid.set_contents_margins_4a(4, 0, 4, 4);
+        history_grid.set_spacing(4);
+
+        let history_title = QLabel::from_q_string(&qtr("history_title"));
+        let mut history_view = QTreeView::new_0a();
+        let mut history_model = QStandardItemModel::new_0a();
+        let mut history_label = QLabel::from_q_string(&qtr("history_hint"));
+        let mut history_jump_button = QPushButton::from_q_string(&qtr("history_jump"));
+
+        history_view.set_model(&mut history_model);
+        history_view.set_header_hidden(true);
+        history_view.set_root_is_decorated(false);
+        history_view.set_mouse_tracking(true);
+        history_label.set_word_wrap(true);
+        history_jump_button.set_enabled(false);
+
+        history_grid.add_widget_5a(history_title.into_ptr(), 0, 0, 1, 1);
+        history_grid.add_widget_5a(&mut history_view, 1, 0, 1, 1);
+        history_grid.add_widget_5a(&mut history_label, 2, 0, 1, 1);
+        history_grid.add_widget_5a(&mut history_jump_button, 3, 0, 1, 1);
+
+        layout.add_widget_5a(history_widget, 0, 5, 4, 1);
+        history_widget.hide();
+
+        //--------------------------------------------------//
+        // Summary Row Section.
+        //--------------------------------------------------//
+
+        // Create the summary row. It's a one-row table under the main one, kept in sync with its columns.
+        let mut summary_view = QTableView::new_0a();
+        let mut summary_model = QStandardItemModel::new_0a();
+        summary_view.set_model(&mut summary_model);
+        summary_model.set_row_count(1);
+        summary_model.set_column_count(table_definition.get_fields_processed().len() as i32);
+        let mut summary_header_labels = QStringList::new();
+        summary_header_labels.append_q_string(&qtr("summary_row_header"));
+        summary_model.set_vertical_header_labels(&summary_header_labels);
+
+        summary_view.horizontal_header().hide();
+        summary_view.set_horizontal_scroll_bar_policy(ScrollBarPolicy::ScrollBarAlwaysOff);
+        summary_view.set_vertical_scroll_bar_policy(ScrollBarPolicy::ScrollBarAlwaysOff);
+        summary_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        summary_view.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        summary_view.set_fixed_height(summary_view.vertical_header().default_section_size() + 2 * summary_view.frame_width());
+
+        let mut summary_context_menu = QMenu::new().into_ptr();
+        let summary_context_menu_sum = summary_context_menu.add_action_q_string(&qtr("summary_row_sum"));
+        let summary_context_menu_average = summary_context_menu.add_action_q_string(&qtr("summary_row_average"));
+        let summary_context_menu_count = summary_context_menu.add_action_q_string(&qtr("summary_row_count"));
+        let summary_context_menu_none = summary_context_menu.add_action_q_string(&qtr("summary_row_none"));
+
+        layout.add_widget_5a(&mut summary_view, 1, 0, 1, 4);
+        summary_view.hide();
+
+        //--------------------------------------------------//
+        // Pivot Section.
+        //--------------------------------------------------//
+
+        // Create the pivot. It groups the visible rows by a column and aggregates the values of another one in each group.
+        // It's read-only, and it's only added to the tabs while it's enabled.
+        let mut pivot_widget = QWidget::new_0a().into_ptr();
+        let mut pivot_grid = create_grid_layout(pivot_widget);
+        pivot_grid.set_contents_margins_4a(4, 4, 4, 4);
+        pivot_grid.set_spacing(4);
+
+        let pivot_group_column_label = QLabel::from_q_string(&qtr("pivot_group_column"));
+        let pivot_value_column_label = QLabel::from_q_string(&qtr("pivot_value_column"));
+        let pivot_aggregate_label = QLabel::from_q_string(&qtr("pivot_aggregate"));
+        let mut pivot_group_column_selector = QComboBox::new_0a();
+        let mut pivot_value_column_selector = QComboBox::new_0a();
+        let mut pivot_aggregate_selector = QComboBox::new_0a();
+        let mut pivot_view = QTableView::new_0a();
+        let mut pivot_model = QStandardItemModel::new_0a();
+
+        // Columns are stored by their position in the table. Only numeric columns can be aggregated.
+        for (index, field) in table_definition.get_fields_processed().iter().enumerate() {
+            let name = QString::from_std_str(&clean_column_names(&field.get_name()));
+            pivot_group_column_selector.add_item_q_string_q_variant(&name, &QVariant::from_int(index as i32));
+            match field.get_ref_field_type() {
+                FieldType::F32 | FieldType::I16 | FieldType::I32 | FieldType::I64 => pivot_value_column_selector.add_item_q_string_q_variant(&name, &QVariant::from_int(index as i32)),
+                _ => {},
+            }
+        }
+
+        for aggregate in &PIVOT_AGGREGATES {
+            pivot_aggregate_selector.add_item_q_string(&QString::from_std_str(&aggregate.to_string()));
+        }
+
+        pivot_view.set_model(&mut pivot_model);
+        pivot_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        pivot_view.set_sorting_enabled(true);
+        pivot_view.vertical_header().hide();
+        pivot_view.horizontal_header().set_stretch_last_section(true);
+        pivot_model.set_sort_role(PIVOT_SORT_VALUE);
+
+        pivot_grid.add_widget_5a(pivot_group_column_label.into_ptr(), 0, 0, 1, 1);
+        pivot_grid.add_widget_5a(&mut pivot_group_column_selector, 0, 1, 1, 1);
+        pivot_grid.add_widget_5a(pivot_value_column_label.into_ptr(), 0, 2, 1, 1);
+        pivot_grid.add_widget_5a(&mut pivot_value_column_selector, 0, 3, 1, 1);
+        pivot_grid.add_widget_5a(pivot_aggregate_label.into_ptr(), 0, 4, 1, 1);
+        pivot_grid.add_widget_5a(&mut pivot_aggregate_selector, 0, 5, 1, 1);
+        pivot_grid.add_widget_5a(&mut pivot_view, 1, 0, 1, 6);
+        pivot_grid.set_column_stretch(1, 10);
+        pivot_grid.set_column_stretch(3, 10);
+        pivot_widget.hide();
+
         // Create the raw Struct and begin
         let packed_file_table_view_raw = TableViewRaw {
             table_view_primary,
             table_view_frozen,
-            table_filter: filter_model.into_ptr(),
+            table_filter: filter_model,
             table_model: model.into_ptr(),
             //table_enable_lookups_button: table_enable_lookups_button.into_ptr(),
             filter_line_edit: row_filter_line_edit.into_ptr(),
             filter_case_sensitive_button: row_filter_case_sensitive_button.into_ptr(),
             filter_column_selector: row_filter_column_selector.into_ptr(),
+            filter_operator_selector: row_filter_operator_selector.into_ptr(),
+            filter_builder_button: row_filter_builder_button.into_ptr(),
+            filter_preset_selector: row_filter_preset_selector.into_ptr(),
+            filter_save_preset_button: row_filter_save_preset_button.into_ptr(),
+            filter_delete_preset_button: row_filter_delete_preset_button.into_ptr(),
+            filter_conditions: Arc::new(RwLock::new(vec![])),
+            filter_match_all: Arc::new(AtomicBool::new(true)),
             column_sort_state: Arc::new(RwLock::new((-1, 0))),
 
             context_menu,
@@ -451,20 +795,41 @@ impl TableView {
             context_menu_add_rows,
             context_menu_insert_rows,
             context_menu_delete_rows,
+            context_menu_move_rows_up,
+            context_menu_move_rows_down,
             context_menu_clone_and_append,
             context_menu_clone_and_insert,
+            context_menu_clone_and_edit,
             context_menu_copy,
             context_menu_copy_as_lua_table,
+            context_menu_copy_to_row_clipboard,
             context_menu_paste,
+            context_menu_paste_from_row_clipboard,
             context_menu_invert_selection,
             context_menu_reset_selection,
             context_menu_rewrite_selection,
+            context_menu_generate_loc_variants,
             context_menu_undo,
             context_menu_redo,
             context_menu_import_tsv,
             context_menu_export_tsv,
+            context_menu_export_visible_rows,
             context_menu_resize_columns,
             context_menu_sidebar,
+            context_menu_history,
+            context_menu_summary_row,
+            context_menu_column_statistics,
+            context_menu_compare_with,
+            context_menu_pivot,
+            context_menu_set_column_unit,
+            context_menu_edit_column_description,
+            context_menu_show_column_descriptions,
+            context_menu_edit_in_popup,
+            context_menu_word_wrap,
+            context_menu_zoom_in,
+            context_menu_zoom_out,
+            context_menu_zoom_reset,
+            context_menu_refresh_reference_data,
             context_menu_search,
             smart_delete,
 
@@ -484,13 +849,40 @@ impl TableView {
             sidebar_scroll_area,
             search_widget,
 
+            history_widget,
+            history_view: history_view.into_ptr(),
+            history_model: history_model.into_ptr(),
+            history_label: history_label.into_ptr(),
+            history_jump_button: history_jump_button.into_ptr(),
+            history_size_label: history_size_label.into_ptr(),
+
+            summary_view: summary_view.into_ptr(),
+            summary_model: summary_model.into_ptr(),
+            summary_context_menu,
+            summary_context_menu_sum,
+            summary_context_menu_average,
+            summary_context_menu_count,
+            summary_context_menu_none,
+            summary_aggregates: Arc::new(RwLock::new(BTreeMap::new())),
+
+            table_tabs: table_tabs.into_ptr(),
+            pivot_widget,
+            pivot_group_column_selector: pivot_group_column_selector.into_ptr(),
+            pivot_value_column_selector: pivot_value_column_selector.into_ptr(),
+            pivot_aggregate_selector: pivot_aggregate_selector.into_ptr(),
+            pivot_view: pivot_view.into_ptr(),
+            pivot_model: pivot_model.into_ptr(),
+
             dependency_data: Arc::new(RwLock::new(dependency_data)),
+            dependency_data_is_stale: Arc::new(AtomicBool::new(false)),
             table_definition: Arc::new(RwLock::new(table_definition)),
             packed_file_path: packed_file_path.clone(),
             packed_file_type: Arc::new(packed_file_type),
+            table_state_name: table_state_name.clone(),
 
             undo_lock,
             save_lock,
+            alive: alive.clone(),
 
             undo_model: QStandardItemModel::new_0a().into_ptr(),
             history_undo: Arc::new(RwLock::new(vec![])),
@@ -506,55 +898,94 @@ impl TableView {
         );
 
         let mut packed_file_table_view = Self {
-            table_view_primary: atomic_from_mut_ptr(packed_file_table_view_raw.table_view_primary),
-            table_view_frozen: atomic_from_mut_ptr(packed_file_table_view_raw.table_view_frozen),
-            table_model: atomic_from_mut_ptr(packed_file_table_view_raw.table_model),
-            //table_enable_lookups_button: atomic_from_mut_ptr(packed_file_table_view_raw.table_enable_lookups_button),
-            filter_line_edit: atomic_from_mut_ptr(packed_file_table_view_raw.filter_line_edit),
-            filter_case_sensitive_button: atomic_from_mut_ptr(packed_file_table_view_raw.filter_case_sensitive_button),
-            filter_column_selector: atomic_from_mut_ptr(packed_file_table_view_raw.filter_column_selector),
-
-            context_menu_add_rows: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_add_rows),
-            context_menu_insert_rows: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_insert_rows),
-            context_menu_delete_rows: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_delete_rows),
-            context_menu_clone_and_append: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_clone_and_append),
-            context_menu_clone_and_insert: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_clone_and_insert),
-            context_menu_copy: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_copy),
-            context_menu_copy_as_lua_table: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_copy_as_lua_table),
-            context_menu_paste: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_paste),
-            context_menu_invert_selection: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_invert_selection),
-            context_menu_reset_selection: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_reset_selection),
-            context_menu_rewrite_selection: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_rewrite_selection),
-            context_menu_undo: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_undo),
-            context_menu_redo: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_redo),
-            context_menu_import_tsv: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_import_tsv),
-            context_menu_export_tsv: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_export_tsv),
-            context_menu_resize_columns: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_resize_columns),
-            context_menu_sidebar: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_sidebar),
-            context_menu_search: atomic_from_mut_ptr(packed_file_table_view_raw.context_menu_search),
-            smart_delete: atomic_from_mut_ptr(packed_file_table_view_raw.smart_delete),
+            table_view_primary: QtPtr::new(packed_file_table_view_raw.table_view_primary, &alive),
+            table_view_frozen: QtPtr::new(packed_file_table_view_raw.table_view_frozen, &alive),
+            table_model: QtPtr::new(packed_file_table_view_raw.table_model, &alive),
+            //table_enable_lookups_button: QtPtr::new(packed_file_table_view_raw.table_enable_lookups_button, &alive),
+            filter_line_edit: QtPtr::new(packed_file_table_view_raw.filter_line_edit, &alive),
+            filter_case_sensitive_button: QtPtr::new(packed_file_table_view_raw.filter_case_sensitive_button, &alive),
+            filter_column_selector: QtPtr::new(packed_file_table_view_raw.filter_column_selector, &alive),
+            filter_operator_selector: QtPtr::new(packed_file_table_view_raw.filter_operator_selector, &alive),
+            filter_builder_button: QtPtr::new(packed_file_table_view_raw.filter_builder_button, &alive),
+            filter_preset_selector: QtPtr::new(packed_file_table_view_raw.filter_preset_selector, &alive),
+            filter_save_preset_button: QtPtr::new(packed_file_table_view_raw.filter_save_preset_button, &alive),
+            filter_delete_preset_button: QtPtr::new(packed_file_table_view_raw.filter_delete_preset_button, &alive),
+
+            context_menu_add_rows: QtPtr::new(packed_file_table_view_raw.context_menu_add_rows, &alive),
+            context_menu_insert_rows: QtPtr::new(packed_file_table_view_raw.context_menu_insert_rows, &alive),
+            context_menu_delete_rows: QtPtr::new(packed_file_table_view_raw.context_menu_delete_rows, &alive),
+            context_menu_move_rows_up: QtPtr::new(packed_file_table_view_raw.context_menu_move_rows_up, &alive),
+            context_menu_move_rows_down: QtPtr::new(packed_file_table_view_raw.context_menu_move_rows_down, &alive),
+            context_menu_clone_and_append: QtPtr::new(packed_file_table_view_raw.context_menu_clone_and_append, &alive),
+            context_menu_clone_and_insert: QtPtr::new(packed_file_table_view_raw.context_menu_clone_and_insert, &alive),
+            context_menu_clone_and_edit: QtPtr::new(packed_file_table_view_raw.context_menu_clone_and_edit, &alive),
+            context_menu_copy: QtPtr::new(packed_file_table_view_raw.context_menu_copy, &alive),
+            context_menu_copy_as_lua_table: QtPtr::new(packed_file_table_view_raw.context_menu_copy_as_lua_table, &alive),
+            context_menu_copy_to_row_clipboard: QtPtr::new(packed_file_table_view_raw.context_menu_copy_to_row_clipboard, &alive),
+            context_menu_paste: QtPtr::new(packed_file_table_view_raw.context_menu_paste, &alive),
+            context_menu_paste_from_row_clipboard: QtPtr::new(packed_file_table_view_raw.context_menu_paste_from_row_clipboard, &alive),
+            context_menu_invert_selection: QtPtr::new(packed_file_table_view_raw.context_menu_invert_selection, &alive),
+            context_menu_reset_selection: QtPtr::new(packed_file_table_view_raw.context_menu_reset_selection, &alive),
+            context_menu_rewrite_selection: QtPtr::new(packed_file_table_view_raw.context_menu_rewrite_selection, &alive),
+            context_menu_generate_loc_variants: QtPtr::new(packed_file_table_view_raw.context_menu_generate_loc_variants, &alive),
+            context_menu_undo: QtPtr::new(packed_file_table_view_raw.context_menu_undo, &alive),
+            context_menu_redo: QtPtr::new(packed_file_table_view_raw.context_menu_redo, &alive),
+            context_menu_import_tsv: QtPtr::new(packed_file_table_view_raw.context_menu_import_tsv, &alive),
+            context_menu_export_tsv: QtPtr::new(packed_file_table_view_raw.context_menu_export_tsv, &alive),
+            context_menu_export_visible_rows: QtPtr::new(packed_file_table_view_raw.context_menu_export_visible_rows, &alive),
+            context_menu_resize_columns: QtPtr::new(packed_file_table_view_raw.context_menu_resize_columns, &alive),
+            context_menu_sidebar: QtPtr::new(packed_file_table_view_raw.context_menu_sidebar, &alive),
+            context_menu_history: QtPtr::new(packed_file_table_view_raw.context_menu_history, &alive),
+            context_menu_summary_row: QtPtr::new(packed_file_table_view_raw.context_menu_summary_row, &alive),
+            context_menu_column_statistics: QtPtr::new(packed_file_table_view_raw.context_menu_column_statistics, &alive),
+            context_menu_compare_with: QtPtr::new(packed_file_table_view_raw.context_menu_compare_with, &alive),
+            context_menu_pivot: QtPtr::new(packed_file_table_view_raw.context_menu_pivot, &alive),
+            context_menu_set_column_unit: QtPtr::new(packed_file_table_view_raw.context_menu_set_column_unit, &alive),
+            context_menu_edit_column_description: QtPtr::new(packed_file_table_view_raw.context_menu_edit_column_description, &alive),
+            context_menu_show_column_descriptions: QtPtr::new(packed_file_table_view_raw.context_menu_show_column_descriptions, &alive),
+            context_menu_edit_in_popup: QtPtr::new(packed_file_table_view_raw.context_menu_edit_in_popup, &alive),
+            context_menu_word_wrap: QtPtr::new(packed_file_table_view_raw.context_menu_word_wrap, &alive),
+            context_menu_zoom_in: QtPtr::new(packed_file_table_view_raw.context_menu_zoom_in, &alive),
+            context_menu_zoom_out: QtPtr::new(packed_file_table_view_raw.context_menu_zoom_out, &alive),
+            context_menu_zoom_reset: QtPtr::new(packed_file_table_view_raw.context_menu_zoom_reset, &alive),
+            context_menu_refresh_reference_data: QtPtr::new(packed_file_table_view_raw.context_menu_refresh_reference_data, &alive),
+            context_menu_search: QtPtr::new(packed_file_table_view_raw.context_menu_search, &alive),
+            smart_delete: QtPtr::new(packed_file_table_view_raw.smart_delete, &alive),
 
             sidebar_hide_checkboxes: Arc::new(hide_show_checkboxes),
             sidebar_freeze_checkboxes: Arc::new(freeze_checkboxes),
 
-            search_search_button: atomic_from_mut_ptr(packed_file_table_view_raw.search_search_button),
-            search_replace_current_button: atomic_from_mut_ptr(packed_file_table_view_raw.search_replace_current_button),
-            search_replace_all_button: atomic_from_mut_ptr(packed_file_table_view_raw.search_replace_all_button),
-            search_close_button: atomic_from_mut_ptr(packed_file_table_view_raw.search_close_button),
-            search_prev_match_button: atomic_from_mut_ptr(packed_file_table_view_raw.search_prev_match_button),
-            search_next_match_button: atomic_from_mut_ptr(packed_file_table_view_raw.search_next_match_button),
-            search_column_selector: atomic_from_mut_ptr(packed_file_table_view_raw.search_column_selector),
+            search_search_button: QtPtr::new(packed_file_table_view_raw.search_search_button, &alive),
+            search_replace_current_button: QtPtr::new(packed_file_table_view_raw.search_replace_current_button, &alive),
+            search_replace_all_button: QtPtr::new(packed_file_table_view_raw.search_replace_all_button, &alive),
+            search_close_button: QtPtr::new(packed_file_table_view_raw.search_close_button, &alive),
+            search_prev_match_button: QtPtr::new(packed_file_table_view_raw.search_prev_match_button, &alive),
+            search_next_match_button: QtPtr::new(packed_file_table_view_raw.search_next_match_button, &alive),
+            search_column_selector: QtPtr::new(packed_file_table_view_raw.search_column_selector, &alive),
+
+            history_view: QtPtr::new(packed_file_table_view_raw.history_view, &alive),
+            history_jump_button: QtPtr::new(packed_file_table_view_raw.history_jump_button, &alive),
+
+            summary_view: QtPtr::new(packed_file_table_view_raw.summary_view, &alive),
+
+            pivot_group_column_selector: QtPtr::new(packed_file_table_view_raw.pivot_group_column_selector, &alive),
+            pivot_value_column_selector: QtPtr::new(packed_file_table_view_raw.pivot_value_column_selector, &alive),
+            pivot_aggregate_selector: QtPtr::new(packed_file_table_view_raw.pivot_aggregate_selector, &alive),
 
             table_name,
+            table_state_name,
             table_uuid,
             packed_file_path: packed_file_path.clone(),
             packed_file_type: packed_file_table_view_raw.packed_file_type.clone(),
             dependency_data: packed_file_table_view_raw.dependency_data.clone(),
+            dependency_data_is_stale: packed_file_table_view_raw.dependency_data_is_stale.clone(),
             table_definition: packed_file_table_view_raw.table_definition.clone(),
 
-            undo_model: atomic_from_mut_ptr(packed_file_table_view_raw.undo_model),
+            undo_model: QtPtr::new(packed_file_table_view_raw.undo_model, &alive),
             history_undo: packed_file_table_view_raw.history_undo.clone(),
             history_redo: packed_file_table_view_raw.history_redo.clone(),
+
+            alive,
         };
 
         // Load the data to the Table. For some reason, if we do this after setting the titles of
@@ -568,11 +999,11 @@ impl TableView {
         );
 
         // Initialize the undo model.
-        update_undo_model(mut_ptr_from_atomic(&packed_file_table_view.table_model), mut_ptr_from_atomic(&packed_file_table_view.undo_model));
+        update_undo_model(packed_file_table_view.table_model.get_unchecked(), packed_file_table_view.undo_model.get_unchecked());
 
         // Build the columns. If we have a model from before, use it to paint our cells as they were last time we painted them.
         let table_name = if let Some(ref path) = packed_file_path {
-            path.read().unwrap().get(1).cloned()
+            get_path_within_packfile(&path.read().unwrap()).get(1).cloned()
         } else { None };
 
         build_columns(
@@ -582,11 +1013,38 @@ impl TableView {
             table_name.as_ref()
         );
 
+        // Restore the hidden/frozen columns and their widths from the last time we had this table open.
+        load_table_state(
+            packed_file_table_view_raw.table_view_primary,
+            packed_file_table_view_raw.table_view_frozen,
+            packed_file_table_view_raw.context_menu_word_wrap,
+            &packed_file_table_view.get_hide_show_checkboxes(),
+            &packed_file_table_view.get_freeze_checkboxes(),
+            &packed_file_table_view_raw.table_definition.read().unwrap(),
+            packed_file_table_view.table_state_name.as_ref()
+        );
+
+        // Apply the zoom level of the tables, and allow changing it with Ctrl + Wheel.
+        let zoom_level = get_zoom_level(ZOOM_TABLE);
+        if zoom_level != 0 {
+            set_zoom(packed_file_table_view_raw.table_view_primary, packed_file_table_view_raw.table_view_frozen, zoom_level);
+        }
+
+        let mut zoom_in = packed_file_table_view_raw.context_menu_zoom_in;
+        let mut zoom_out = packed_file_table_view_raw.context_menu_zoom_out;
+        new_zoom_filter_safe(&mut packed_file_table_view_raw.table_view_primary.viewport(), &mut zoom_in, &mut zoom_out);
+        new_zoom_filter_safe(&mut packed_file_table_view_raw.table_view_frozen.viewport(), &mut zoom_in, &mut zoom_out);
+
         // Set the connections and return success.
         connections::set_connections(&packed_file_table_view, &packed_file_table_view_slots);
         shortcuts::set_shortcuts(&mut packed_file_table_view);
         tips::set_tips(&mut packed_file_table_view);
 
+        // Tables from extra PackFiles are only there to compare them and copy from them, so we disable everything that edits them.
+        if packed_file_table_view_raw.is_read_only() {
+            packed_file_table_view_raw.clone().disable_edits();
+        }
+
         Ok((packed_file_table_view, packed_file_table_view_slots))
     }
 
@@ -594,9 +1052,12 @@ impl TableView {
     ///
     /// NOTE: This allows for a table to change it's definition on-the-fly, so be carefull with that!
     pub unsafe fn reload_view(&mut self, data: TableType) {
-        let table_view_primary = mut_ptr_from_atomic(&self.table_view_primary);
-        let table_view_frozen = mut_ptr_from_atomic(&self.table_view_frozen);
-        let undo_model = mut_ptr_from_atomic(&self.undo_model);
+        let (table_view_primary, table_view_frozen, undo_model) = match (self.table_view_primary.get(), self.table_view_frozen.get(), self.undo_model.get()) {
+            (Some(table_view_primary), Some(table_view_frozen), Some(undo_model)) => (table_view_primary, table_view_frozen, undo_model),
+
+            // If the view has been closed, there is nothing to reload.
+            _ => return,
+        };
 
         let filter: MutPtr<QSortFilterProxyModel> = table_view_primary.model().static_downcast_mut();
         let model: MutPtr<QStandardItemModel> = filter.source_model().static_downcast_mut();
@@ -620,13 +1081,13 @@ impl TableView {
             &data
         );
 
-        // Reset the undo model and the undo/redo history.
-        update_undo_model(model, undo_model);
+        // Reset the undo/redo history and the undo model.
         self.history_undo.write().unwrap().clear();
         self.history_redo.write().unwrap().clear();
+        update_undo_model(model, undo_model);
 
         let table_name = if let Some(path) = self.get_packed_file_path() {
-            path.get(1).cloned()
+            get_path_within_packfile(&path).get(1).cloned()
         } else { None };
 
         // Rebuild the column's stuff.
@@ -637,15 +1098,25 @@ impl TableView {
             table_name.as_ref()
         );
 
+        load_table_state(
+            table_view_primary,
+            table_view_frozen,
+            self.context_menu_word_wrap.get_unchecked(),
+            &self.get_hide_show_checkboxes(),
+            &self.get_freeze_checkboxes(),
+            &self.get_ref_table_definition(),
+            self.table_state_name.as_ref()
+        );
+
         // Rebuild the column list of the filter and search panels, just in case the definition changed.
-        let mut filter_column_selector = mut_ptr_from_atomic(&self.filter_column_selector);
-        let mut search_column_selector = mut_ptr_from_atomic(&self.search_column_selector);
+        let mut filter_column_selector = self.filter_column_selector.get_unchecked();
+        let mut search_column_selector = self.search_column_selector.get_unchecked();
         filter_column_selector.clear();
         search_column_selector.clear();
         search_column_selector.add_item_q_string(&QString::from_std_str("* (All Columns)"));
         for column in self.table_definition.read().unwrap().get_fields_processed() {
             let name = QString::from_std_str(&utils::clean_column_names(&column.get_name()));
-            filter_column_selector.add_item_q_string(&name);
+            filter_column_selector.add_item_q_string_q_variant(&name, &QVariant::from_q_string(&QString::from_std_str(column.get_name())));
             search_column_selector.add_item_q_string(&name);
         }
 
@@ -654,178 +1125,198 @@ impl TableView {
         table_view_primary.horizontal_header().set_stretch_last_section(SETTINGS.read().unwrap().settings_bool["extend_last_column_on_tables"]);
     }
 
-    /// This function returns a reference to the StandardItemModel widget.
-    pub fn get_mut_ptr_table_model(&self) -> MutPtr<QStandardItemModel> {
-        mut_ptr_from_atomic(&self.table_model)
+    /// This function saves the state of the columns of this table, so it can be restored the next time we open it.
+    pub unsafe fn save_table_state(&self) {
+        let table_view_primary = match self.table_view_primary.get() {
+            Some(table_view_primary) => table_view_primary,
+            None => return,
+        };
+
+        save_table_state(
+            table_view_primary,
+            &self.get_freeze_checkboxes(),
+            &self.get_ref_table_definition(),
+            self.table_state_name.as_ref()
+        );
+    }
+
+    /// This function returns a reference to the StandardItemModel widget, or `None` if the view has been closed.
+    pub fn get_mut_ptr_table_model(&self) -> Option<MutPtr<QStandardItemModel>> {
+        self.table_model.get()
     }
 
     // This function returns a mutable reference to the `Enable Lookups` Pushbutton.
     //pub fn get_mut_ptr_enable_lookups_button(&self) -> MutPtr<QPushButton> {
-    //    mut_ptr_from_atomic(&self.table_enable_lookups_button)
+    //    self.table_enable_lookups_button.get()
     //}
 
-    /// This function returns a pointer to the Primary TableView widget.
-    pub fn get_mut_ptr_table_view_primary(&self) -> MutPtr<QTableView> {
-        mut_ptr_from_atomic(&self.table_view_primary)
+    /// This function returns a pointer to the Primary TableView widget, or `None` if the view has been closed.
+    pub fn get_mut_ptr_table_view_primary(&self) -> Option<MutPtr<QTableView>> {
+        self.table_view_primary.get()
     }
 
-    /// This function returns a pointer to the Frozen TableView widget.
-    pub fn get_mut_ptr_table_view_frozen(&self) -> MutPtr<QTableView> {
-        mut_ptr_from_atomic(&self.table_view_frozen)
+    /// This function returns a pointer to the add rows action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_add_rows(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_add_rows.get()
     }
 
-    /// This function returns a pointer to the filter's LineEdit widget.
-    pub fn get_mut_ptr_filter_line_edit(&self) -> MutPtr<QLineEdit> {
-        mut_ptr_from_atomic(&self.filter_line_edit)
+    /// This function returns a pointer to the insert rows action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_insert_rows(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_insert_rows.get()
     }
 
-    /// This function returns a pointer to the filter's column selector combobox.
-    pub fn get_mut_ptr_filter_column_selector(&self) -> MutPtr<QComboBox> {
-        mut_ptr_from_atomic(&self.filter_column_selector)
+    /// This function returns a pointer to the delete rows action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_delete_rows(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_delete_rows.get()
     }
 
-    /// This function returns a pointer to the filter's case sensitive button.
-    pub fn get_mut_ptr_filter_case_sensitive_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.filter_case_sensitive_button)
+    /// This function returns a pointer to the move rows up action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_move_rows_up(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_move_rows_up.get()
     }
 
-    /// This function returns a pointer to the add rows action.
-    pub fn get_mut_ptr_context_menu_add_rows(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_add_rows)
+    /// This function returns a pointer to the move rows down action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_move_rows_down(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_move_rows_down.get()
     }
 
-    /// This function returns a pointer to the insert rows action.
-    pub fn get_mut_ptr_context_menu_insert_rows(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_insert_rows)
+    /// This function returns a pointer to the clone_and_append action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_clone_and_append(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_clone_and_append.get()
     }
 
-    /// This function returns a pointer to the delete rows action.
-    pub fn get_mut_ptr_context_menu_delete_rows(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_delete_rows)
+    /// This function returns a pointer to the clone_and_insert action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_clone_and_insert(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_clone_and_insert.get()
     }
 
-    /// This function returns a pointer to the clone_and_append action.
-    pub fn get_mut_ptr_context_menu_clone_and_append(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_clone_and_append)
+    /// This function returns a pointer to the copy action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_copy(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_copy.get()
     }
 
-    /// This function returns a pointer to the clone_and_insert action.
-    pub fn get_mut_ptr_context_menu_clone_and_insert(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_clone_and_insert)
+    /// This function returns a pointer to the copy as lua table action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_copy_as_lua_table(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_copy_as_lua_table.get()
     }
 
-    /// This function returns a pointer to the copy action.
-    pub fn get_mut_ptr_context_menu_copy(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_copy)
+    /// This function returns a pointer to the paste action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_paste(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_paste.get()
     }
 
-    /// This function returns a pointer to the copy as lua table action.
-    pub fn get_mut_ptr_context_menu_copy_as_lua_table(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_copy_as_lua_table)
+    /// This function returns a pointer to the invert selection action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_invert_selection(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_invert_selection.get()
     }
 
-    /// This function returns a pointer to the paste action.
-    pub fn get_mut_ptr_context_menu_paste(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_paste)
+    /// This function returns a pointer to the reset selection action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_reset_selection(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_reset_selection.get()
     }
 
-    /// This function returns a pointer to the invert selection action.
-    pub fn get_mut_ptr_context_menu_invert_selection(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_invert_selection)
+    /// This function returns a pointer to the rewrite selection action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_rewrite_selection(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_rewrite_selection.get()
     }
 
-    /// This function returns a pointer to the reset selection action.
-    pub fn get_mut_ptr_context_menu_reset_selection(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_reset_selection)
+    /// This function returns a pointer to the generate loc variants action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_generate_loc_variants(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_generate_loc_variants.get()
     }
 
-    /// This function returns a pointer to the rewrite selection action.
-    pub fn get_mut_ptr_context_menu_rewrite_selection(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_rewrite_selection)
+    /// This function returns a pointer to the undo action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_undo(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_undo.get()
     }
 
-    /// This function returns a pointer to the undo action.
-    pub fn get_mut_ptr_context_menu_undo(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_undo)
+    /// This function returns a pointer to the redo action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_redo(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_redo.get()
     }
 
-    /// This function returns a pointer to the redo action.
-    pub fn get_mut_ptr_context_menu_redo(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_redo)
+    /// This function returns a pointer to the import TSV action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_import_tsv(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_import_tsv.get()
     }
 
-    /// This function returns a pointer to the import TSV action.
-    pub fn get_mut_ptr_context_menu_import_tsv(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_import_tsv)
+    /// This function returns a pointer to the export TSV action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_export_tsv(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_export_tsv.get()
     }
 
-    /// This function returns a pointer to the export TSV action.
-    pub fn get_mut_ptr_context_menu_export_tsv(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_export_tsv)
+    /// This function returns a pointer to the export visible rows action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_export_visible_rows(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_export_visible_rows.get()
     }
 
-    /// This function returns a pointer to the smart delete action.
-    pub fn get_mut_ptr_smart_delete(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.smart_delete)
+    /// This function returns a pointer to the resize columns action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_resize_columns(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_resize_columns.get()
     }
 
-    /// This function returns a pointer to the resize columns action.
-    pub fn get_mut_ptr_context_menu_resize_columns(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_resize_columns)
+    /// This function returns a pointer to the sidebar action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_sidebar(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_sidebar.get()
     }
 
-    /// This function returns a pointer to the sidebar action.
-    pub fn get_mut_ptr_context_menu_sidebar(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_sidebar)
+    /// This function returns a pointer to the undo history action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_history(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_history.get()
     }
 
-    /// This function returns a pointer to the search action.
-    pub fn get_mut_ptr_context_menu_search(&self) -> MutPtr<QAction> {
-        mut_ptr_from_atomic(&self.context_menu_search)
+    /// This function returns a pointer to the summary row action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_summary_row(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_summary_row.get()
     }
 
-    /// This function returns a vector with the entire hide/show checkbox list.
-    pub fn get_hide_show_checkboxes(&self) -> Vec<MutPtr<QCheckBox>> {
-        self.sidebar_hide_checkboxes.iter()
-            .map(|x| mut_ptr_from_atomic(x))
-            .collect()
+    /// This function returns a pointer to the column statistics action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_column_statistics(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_column_statistics.get()
     }
 
-    /// This function returns a vector with the entire freeze checkbox list.
-    pub fn get_freeze_checkboxes(&self) -> Vec<MutPtr<QCheckBox>> {
-        self.sidebar_freeze_checkboxes.iter()
-            .map(|x| mut_ptr_from_atomic(x))
-            .collect()
+    /// This function returns a pointer to the compare with action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_compare_with(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_compare_with.get()
     }
 
-    /// This function returns a pointer to the search button in the search panel.
-    pub fn get_mut_ptr_search_search_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.search_search_button)
+    /// This function returns a pointer to the set column unit action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_set_column_unit(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_set_column_unit.get()
     }
 
-    /// This function returns a pointer to the prev match button in the search panel.
-    pub fn get_mut_ptr_search_prev_match_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.search_prev_match_button)
+    /// This function returns a pointer to the edit in popup action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_edit_in_popup(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_edit_in_popup.get()
     }
 
-    /// This function returns a pointer to the next_match button in the search panel.
-    pub fn get_mut_ptr_search_next_match_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.search_next_match_button)
+    /// This function returns a pointer to the word wrap action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_word_wrap(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_word_wrap.get()
     }
 
-    /// This function returns a pointer to the replace current button in the search panel.
-    pub fn get_mut_ptr_search_replace_current_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.search_replace_current_button)
+    /// This function returns a pointer to the refresh reference data action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_refresh_reference_data(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_refresh_reference_data.get()
     }
 
-    /// This function returns a pointer to the replace all button in the search panel.
-    pub fn get_mut_ptr_search_replace_all_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.search_replace_all_button)
+    /// This function returns a pointer to the search action, or `None` if the view has been closed.
+    pub fn get_mut_ptr_context_menu_search(&self) -> Option<MutPtr<QAction>> {
+        self.context_menu_search.get()
     }
 
-    /// This function returns a pointer to the close button in the search panel.
-    pub fn get_mut_ptr_search_close_button(&self) -> MutPtr<QPushButton> {
-        mut_ptr_from_atomic(&self.search_close_button)
+    /// This function returns a vector with the entire hide/show checkbox list, or an empty one if the view has been closed.
+    pub fn get_hide_show_checkboxes(&self) -> Vec<MutPtr<QCheckBox>> {
+        self.sidebar_hide_checkboxes.iter()
+            .filter_map(|x| x.get())
+            .collect()
+    }
+
+    /// This function returns a vector with the entire freeze checkbox list, or an empty one if the view has been closed.
+    pub fn get_freeze_checkboxes(&self) -> Vec<MutPtr<QCheckBox>> {
+        self.sidebar_freeze_checkboxes.iter()
+            .filter_map(|x| x.get())
+            .collect()
     }
 
     /// This function returns a reference to this table's name.
@@ -843,9 +1334,36 @@ impl TableView {
         self.table_definition.read().unwrap()
     }
 
-    /// This function allows you to set a new dependency data to an already created table.
-    pub fn set_dependency_data(&self, data: &BTreeMap<i32, BTreeMap<String, String>>) {
-        *self.dependency_data.write().unwrap() = data.clone();
+    /// This function returns if the dependency data of this table may be outdated, due to changes in the PackFile since it was loaded.
+    pub fn get_dependency_data_is_stale(&self) -> bool {
+        self.dependency_data_is_stale.load(Ordering::SeqCst)
+    }
+
+    /// This function marks the dependency data of this table as outdated, or up-to-date.
+    pub fn set_dependency_data_is_stale(&self, is_stale: bool) {
+        self.dependency_data_is_stale.store(is_stale, Ordering::SeqCst);
+    }
+
+    /// This function reloads the dependency data of this table, and updates its dropdowns and its error checking with it.
+    pub unsafe fn refresh_dependency_data(&self) -> Result<()> {
+        let (table_view_primary, table_view_frozen, table_model) = match (self.table_view_primary.get(), self.table_view_frozen.get(), self.table_model.get()) {
+            (Some(table_view_primary), Some(table_view_frozen), Some(table_model)) => (table_view_primary, table_view_frozen, table_model),
+            _ => return Ok(()),
+        };
+
+        update_dependency_data(
+            table_view_primary,
+            table_view_frozen,
+            table_model,
+            &self.get_ref_table_definition(),
+            &self.dependency_data,
+            self.get_packed_file_type(),
+            self.table_name.as_deref(),
+            &self.alive,
+        )?;
+
+        self.set_dependency_data_is_stale(false);
+        Ok(())
     }
 
     /// This function returns the path of the PackedFile corresponding to this table, if exists.
@@ -862,6 +1380,15 @@ impl TableView {
     }
 }
 
+/// Drop implementation for `TableView`.
+///
+/// Views are dropped when they're closed, so this marks their widgets as no longer usable.
+impl Drop for TableView {
+    fn drop(&mut self) {
+        self.alive.store(false, Ordering::SeqCst);
+    }
+}
+
 //----------------------------------------------------------------//
 // Implementations of `TableOperation`.
 //----------------------------------------------------------------//
@@ -899,6 +1426,72 @@ impl Clone for TableOperations {
     }
 }
 
+//----------------------------------------------------------------//
+// Implementations of `FilterOperator`.
+//----------------------------------------------------------------//
+
+/// Display implementation of `FilterOperator`, using the current language.
+impl fmt::Display for FilterOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&tr(match self {
+            Self::Contains => "table_filter_operator_contains",
+            Self::NotContains => "table_filter_operator_not_contains",
+            Self::Equals => "table_filter_operator_equals",
+            Self::NotEquals => "table_filter_operator_not_equals",
+            Self::StartsWith => "table_filter_operator_starts_with",
+            Self::EndsWith => "table_filter_operator_ends_with",
+            Self::GreaterThan => "table_filter_operator_greater_than",
+            Self::GreaterOrEqual => "table_filter_operator_greater_or_equal",
+            Self::LessThan => "table_filter_operator_less_than",
+            Self::LessOrEqual => "table_filter_operator_less_or_equal",
+        }), f)
+    }
+}
+
+impl fmt::Display for PivotAggregate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&tr(match self {
+            Self::Count => "pivot_aggregate_count",
+            Self::Sum => "pivot_aggregate_sum",
+            Self::Average => "pivot_aggregate_average",
+            Self::Min => "pivot_aggregate_min",
+            Self::Max => "pivot_aggregate_max",
+        }), f)
+    }
+}
+
+/// Implementation of `PivotAggregate`.
+impl PivotAggregate {
+
+    /// This function applies the aggregate to the provided values. If there are no values, there is nothing to aggregate.
+    pub fn apply(self, values: &[f64]) -> Option<f64> {
+        if values.is_empty() && self != Self::Count {
+            return None;
+        }
+
+        match self {
+            Self::Count => Some(values.len() as f64),
+            Self::Sum => Some(values.iter().sum()),
+            Self::Average => Some(values.iter().sum::<f64>() / values.len() as f64),
+            Self::Min => Some(values.iter().cloned().fold(f64::INFINITY, f64::min)),
+            Self::Max => Some(values.iter().cloned().fold(f64::NEG_INFINITY, f64::max)),
+        }
+    }
+}
+
+/// Implementation of `FilterOperator`.
+impl FilterOperator {
+
+    /// This function splits the operator prefix (`>=`, `<`, `!=`,...) from the provided filter text, if it has one.
+    ///
+    /// It returns the operator and the rest of the text, without the spaces between them.
+    pub fn from_prefix(text: &str) -> Option<(Self, &str)> {
+        FILTER_OPERATOR_PREFIXES.iter()
+            .find(|(prefix, _)| text.starts_with(prefix))
+            .map(|(prefix, operator)| (*operator, text[prefix.len()..].trim_start()))
+    }
+}
+
 //----------------------------------------------------------------//
 // Implementations of `TableSearch`.
 //----------------------------------------------------------------//
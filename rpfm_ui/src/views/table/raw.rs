@@ -12,44 +12,64 @@
 Module with all the code to deal with the raw version of the tables.
 !*/
 
-use qt_widgets::q_abstract_item_view::ScrollHint;
+use qt_widgets::q_abstract_item_view::{EditTrigger, ScrollHint};
 use qt_widgets::QAction;
 use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
 use qt_widgets::QGroupBox;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
+use qt_widgets::QPlainTextEdit;
+use qt_widgets::q_plain_text_edit::LineWrapMode;
 use qt_widgets::QPushButton;
 use qt_widgets::QTableView;
+use qt_widgets::QTabWidget;
 use qt_widgets::QMenu;
 
 use qt_gui::QBrush;
+use qt_gui::QCursor;
 use qt_gui::QGuiApplication;
 use qt_gui::QStandardItemModel;
 
-use qt_core::CaseSensitivity;
+use qt_core::GlobalColor;
 use qt_core::QFlags;
 use qt_core::QItemSelection;
 use qt_core::QModelIndex;
-use qt_core::QRegExp;
+use qt_core::QListOfInt;
+use qt_core::QObject;
+use qt_core::QStringList;
 use qt_core::QSortFilterProxyModel;
 use qt_core::QVariant;
 use qt_core::QString;
 use qt_core::Orientation;
 use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QSignalBlocker;
+use qt_core::Slot;
 
+use cpp_core::CppBox;
 use cpp_core::MutPtr;
 use cpp_core::Ref;
 
-use std::collections::BTreeMap;
+use csv::{QuoteStyle, WriterBuilder};
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
 use std::sync::{Arc, RwLock, RwLockReadGuard};
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use rpfm_lib::schema::Definition;
+use rpfm_lib::schema::{Definition, Field};
+use rpfm_lib::schema::patch::{FieldUnit, FIELD_UNITS};
+use rpfm_lib::settings::ZOOM_TABLE;
+use rpfm_lib::SCHEMA_PATCH;
 
-use crate::utils::{atomic_from_mut_ptr, create_grid_layout, mut_ptr_from_atomic, log_to_status_bar};
+use crate::locale::{qtr, tr, tre};
+use crate::ui_state::table_state::FilterPreset;
+use crate::UI_STATE;
+use crate::utils::{atomic_from_mut_ptr, change_zoom_level, create_grid_layout, mut_ptr_from_atomic, log_to_status_bar, save_setting_bool};
 use crate::pack_tree::*;
+use crate::table_compare_ui::TableCompareUI;
 use super::*;
 
 //-------------------------------------------------------------------------------//
@@ -70,6 +90,15 @@ pub struct TableViewRaw {
     pub filter_case_sensitive_button: MutPtr<QPushButton>,
     pub filter_column_selector: MutPtr<QComboBox>,
     pub filter_line_edit: MutPtr<QLineEdit>,
+    pub filter_operator_selector: MutPtr<QComboBox>,
+    pub filter_builder_button: MutPtr<QPushButton>,
+    pub filter_preset_selector: MutPtr<QComboBox>,
+    pub filter_save_preset_button: MutPtr<QPushButton>,
+    pub filter_delete_preset_button: MutPtr<QPushButton>,
+
+    /// Conditions of the filter builder, applied along with the one of the filter line.
+    pub filter_conditions: Arc<RwLock<Vec<FilterCondition>>>,
+    pub filter_match_all: Arc<AtomicBool>,
     pub column_sort_state: Arc<RwLock<(i32, i8)>>,
 
     pub context_menu: MutPtr<QMenu>,
@@ -77,26 +106,71 @@ pub struct TableViewRaw {
     pub context_menu_add_rows: MutPtr<QAction>,
     pub context_menu_insert_rows: MutPtr<QAction>,
     pub context_menu_delete_rows: MutPtr<QAction>,
+    pub context_menu_move_rows_up: MutPtr<QAction>,
+    pub context_menu_move_rows_down: MutPtr<QAction>,
     pub context_menu_clone_and_append: MutPtr<QAction>,
     pub context_menu_clone_and_insert: MutPtr<QAction>,
+    pub context_menu_clone_and_edit: MutPtr<QAction>,
     pub context_menu_copy: MutPtr<QAction>,
     pub context_menu_copy_as_lua_table: MutPtr<QAction>,
+    pub context_menu_copy_to_row_clipboard: MutPtr<QAction>,
     pub context_menu_paste: MutPtr<QAction>,
+    pub context_menu_paste_from_row_clipboard: MutPtr<QAction>,
     pub context_menu_invert_selection: MutPtr<QAction>,
     pub context_menu_reset_selection: MutPtr<QAction>,
     pub context_menu_rewrite_selection: MutPtr<QAction>,
+    pub context_menu_generate_loc_variants: MutPtr<QAction>,
     pub context_menu_undo: MutPtr<QAction>,
     pub context_menu_redo: MutPtr<QAction>,
     pub context_menu_import_tsv: MutPtr<QAction>,
     pub context_menu_export_tsv: MutPtr<QAction>,
+    pub context_menu_export_visible_rows: MutPtr<QAction>,
     pub context_menu_resize_columns: MutPtr<QAction>,
     pub context_menu_sidebar: MutPtr<QAction>,
+    pub context_menu_history: MutPtr<QAction>,
+    pub context_menu_summary_row: MutPtr<QAction>,
+    pub context_menu_column_statistics: MutPtr<QAction>,
+    pub context_menu_compare_with: MutPtr<QAction>,
+    pub context_menu_pivot: MutPtr<QAction>,
+    pub context_menu_set_column_unit: MutPtr<QAction>,
+    pub context_menu_edit_column_description: MutPtr<QAction>,
+    pub context_menu_show_column_descriptions: MutPtr<QAction>,
+    pub context_menu_edit_in_popup: MutPtr<QAction>,
+    pub context_menu_word_wrap: MutPtr<QAction>,
+    pub context_menu_zoom_in: MutPtr<QAction>,
+    pub context_menu_zoom_out: MutPtr<QAction>,
+    pub context_menu_zoom_reset: MutPtr<QAction>,
+    pub context_menu_refresh_reference_data: MutPtr<QAction>,
     pub context_menu_search: MutPtr<QAction>,
     pub smart_delete: MutPtr<QAction>,
 
     pub sidebar_scroll_area: MutPtr<QScrollArea>,
     pub search_widget: MutPtr<QWidget>,
 
+    pub history_widget: MutPtr<QWidget>,
+    pub history_view: MutPtr<QTreeView>,
+    pub history_model: MutPtr<QStandardItemModel>,
+    pub history_label: MutPtr<QLabel>,
+    pub history_jump_button: MutPtr<QPushButton>,
+    pub history_size_label: MutPtr<QLabel>,
+
+    pub summary_view: MutPtr<QTableView>,
+    pub summary_model: MutPtr<QStandardItemModel>,
+    pub summary_context_menu: MutPtr<QMenu>,
+    pub summary_context_menu_sum: MutPtr<QAction>,
+    pub summary_context_menu_average: MutPtr<QAction>,
+    pub summary_context_menu_count: MutPtr<QAction>,
+    pub summary_context_menu_none: MutPtr<QAction>,
+    pub summary_aggregates: Arc<RwLock<BTreeMap<i32, SummaryAggregate>>>,
+
+    pub table_tabs: MutPtr<QTabWidget>,
+    pub pivot_widget: MutPtr<QWidget>,
+    pub pivot_group_column_selector: MutPtr<QComboBox>,
+    pub pivot_value_column_selector: MutPtr<QComboBox>,
+    pub pivot_aggregate_selector: MutPtr<QComboBox>,
+    pub pivot_view: MutPtr<QTableView>,
+    pub pivot_model: MutPtr<QStandardItemModel>,
+
     pub search_search_line_edit: MutPtr<QLineEdit>,
     pub search_replace_line_edit: MutPtr<QLineEdit>,
     pub search_search_button: MutPtr<QPushButton>,
@@ -111,12 +185,15 @@ pub struct TableViewRaw {
     pub search_data: Arc<RwLock<TableSearch>>,
 
     pub dependency_data: Arc<RwLock<BTreeMap<i32, BTreeMap<String, String>>>>,
+    pub dependency_data_is_stale: Arc<AtomicBool>,
     pub table_definition: Arc<RwLock<Definition>>,
     pub packed_file_path: Option<Arc<RwLock<Vec<String>>>>,
     pub packed_file_type: Arc<PackedFileType>,
+    pub table_state_name: Option<String>,
 
     pub save_lock: Arc<AtomicBool>,
     pub undo_lock: Arc<AtomicBool>,
+    pub alive: Arc<AtomicBool>,
 
     pub undo_model: MutPtr<QStandardItemModel>,
     pub history_undo: Arc<RwLock<Vec<TableOperations>>>,
@@ -135,6 +212,30 @@ impl TableViewRaw {
         self.table_definition.read().unwrap()
     }
 
+    /// This function returns if the view is still open.
+    ///
+    /// Use it after anything that processes events while waiting, like `recv_message_qt_try` or a dialog, as the view may have been closed in the meantime.
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    /// This function reloads the dependency data of this table, and updates its dropdowns and its error checking with it.
+    pub unsafe fn refresh_dependency_data(&self) -> Result<()> {
+        update_dependency_data(
+            self.table_view_primary,
+            self.table_view_frozen,
+            self.table_model,
+            &self.get_ref_table_definition(),
+            &self.dependency_data,
+            *self.packed_file_type,
+            self.get_table_type_name_if_db().as_deref(),
+            &self.alive,
+        )?;
+
+        self.dependency_data_is_stale.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
     /// This function updates the state of the actions in the context menu.
     pub unsafe fn context_menu_update(&mut self) {
 
@@ -145,49 +246,396 @@ impl TableViewRaw {
         if indexes.count_0a() > 0 {
             self.context_menu_clone_and_append.set_enabled(true);
             self.context_menu_clone_and_insert.set_enabled(true);
+            self.context_menu_clone_and_edit.set_enabled(true);
             self.context_menu_copy.set_enabled(true);
             self.context_menu_copy_as_lua_table.set_enabled(true);
+            self.context_menu_copy_to_row_clipboard.set_enabled(true);
             self.context_menu_delete_rows.set_enabled(true);
+            self.context_menu_column_statistics.set_enabled(true);
+            self.context_menu_set_column_unit.set_enabled(true);
+            self.context_menu_edit_column_description.set_enabled(true);
             self.context_menu_rewrite_selection.set_enabled(true);
+            self.context_menu_generate_loc_variants.set_enabled(true);
         }
 
         // Otherwise, disable them.
         else {
             self.context_menu_rewrite_selection.set_enabled(false);
+            self.context_menu_generate_loc_variants.set_enabled(false);
             self.context_menu_clone_and_append.set_enabled(false);
             self.context_menu_clone_and_insert.set_enabled(false);
+            self.context_menu_clone_and_edit.set_enabled(false);
             self.context_menu_copy.set_enabled(false);
             self.context_menu_copy_as_lua_table.set_enabled(false);
+            self.context_menu_copy_to_row_clipboard.set_enabled(false);
             self.context_menu_delete_rows.set_enabled(false);
+            self.context_menu_column_statistics.set_enabled(false);
+            self.context_menu_set_column_unit.set_enabled(false);
+            self.context_menu_edit_column_description.set_enabled(false);
         }
 
+        // Only single text cells can be edited in a popup.
+        self.context_menu_edit_in_popup.set_enabled(indexes.count_0a() == 1 && self.get_text_field(indexes.at(0).column()).is_some());
+
+        // Rows can only be moved if their order matters, and if we see them in their real order.
+        let can_move_rows = indexes.count_0a() > 0 && self.can_move_rows();
+        self.context_menu_move_rows_up.set_enabled(can_move_rows);
+        self.context_menu_move_rows_down.set_enabled(can_move_rows);
+
         if !self.undo_lock.load(Ordering::SeqCst) {
             self.context_menu_undo.set_enabled(!self.history_undo.read().unwrap().is_empty());
             self.context_menu_redo.set_enabled(!self.history_redo.read().unwrap().is_empty());
         }
+
+        if self.is_read_only() {
+            self.disable_edits();
+        }
     }
 
-    /// Function to filter the table.
-    pub unsafe fn filter_table(&mut self) {
+    /// This function returns if the table is read-only, which happens when it belongs to an extra PackFile instead of the open one.
+    pub fn is_read_only(&self) -> bool {
+        match self.packed_file_path {
+            Some(ref path) => is_path_from_packfile_extra(&path.read().unwrap()),
+            None => false,
+        }
+    }
 
-        let mut pattern = QRegExp::new_1a(&self.filter_line_edit.text());
+    /// This function disables everything that allows to edit the table, leaving only what allows to see it and copy from it.
+    pub unsafe fn disable_edits(&mut self) {
+        self.table_view_primary.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        self.table_view_frozen.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+
+        self.context_menu_add_rows.set_enabled(false);
+        self.context_menu_insert_rows.set_enabled(false);
+        self.context_menu_delete_rows.set_enabled(false);
+        self.context_menu_move_rows_up.set_enabled(false);
+        self.context_menu_move_rows_down.set_enabled(false);
+        self.context_menu_clone_and_append.set_enabled(false);
+        self.context_menu_clone_and_insert.set_enabled(false);
+        self.context_menu_clone_and_edit.set_enabled(false);
+        self.context_menu_paste.set_enabled(false);
+        self.context_menu_paste_from_row_clipboard.set_enabled(false);
+        self.context_menu_rewrite_selection.set_enabled(false);
+        self.context_menu_generate_loc_variants.set_enabled(false);
+        self.context_menu_edit_in_popup.set_enabled(false);
+        self.context_menu_undo.set_enabled(false);
+        self.context_menu_redo.set_enabled(false);
+        self.context_menu_import_tsv.set_enabled(false);
+        self.smart_delete.set_enabled(false);
+        self.table_view_primary.vertical_header().set_sections_movable(false);
+
+        self.search_replace_line_edit.set_visible(false);
+        self.search_replace_current_button.set_visible(false);
+        self.search_replace_all_button.set_visible(false);
+    }
 
-        let column_name = self.filter_column_selector.current_text();
-        for column in 0..self.table_model.column_count_0a() {
-            if self.table_model.header_data_2a(column, Orientation::Horizontal).to_string().compare_q_string_case_sensitivity(&column_name, CaseSensitivity::CaseSensitive) == 0 {
-                self.table_filter.set_filter_key_column(column);
-                break;
+    /// Function to filter the table, using the condition of the filter line and the ones of the filter builder.
+    pub unsafe fn filter_table(&mut self) {
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        let mut columns = QListOfInt::new();
+        let mut operators = QListOfInt::new();
+        let mut values = QStringList::new();
+
+        // Conditions with an empty value are ignored, except the ones of the builder looking for empty (or non-empty) cells.
+        let main_condition = self.get_main_filter_condition().filter(|condition| !condition.value.is_empty());
+        let builder_conditions = self.filter_conditions.read().unwrap().iter()
+            .filter(|condition| match condition.operator {
+                FilterOperator::Equals | FilterOperator::NotEquals => true,
+                _ => !condition.value.is_empty(),
+            })
+            .cloned()
+            .collect::<Vec<FilterCondition>>();
+
+        for condition in main_condition.iter().chain(builder_conditions.iter()) {
+            if let Some(column) = fields.iter().position(|field| field.get_name() == condition.column) {
+                columns.append_int(&(column as i32));
+                operators.append_int(&(condition.operator as i32));
+                values.append_q_string(&QString::from_std_str(&condition.value));
             }
         }
 
-        // Check if the filter should be "Case Sensitive".
+        let match_all = self.filter_match_all.load(Ordering::SeqCst);
         let case_sensitive = self.filter_case_sensitive_button.is_checked();
+        trigger_tableview_filter_safe(&mut self.table_filter, columns.as_ptr(), operators.as_ptr(), values.as_ptr(), match_all, case_sensitive);
+    }
+
+    /// This function returns the condition of the filter line, or None if there are no columns to filter.
+    ///
+    /// If the text starts with an operator (like `>= 100` or `!= foo`) that operator is used instead of the selected one.
+    unsafe fn get_main_filter_condition(&self) -> Option<FilterCondition> {
+        let column = self.filter_column_selector.current_data_0a().to_string().to_std_string();
+        let operator = FILTER_OPERATORS.get(self.filter_operator_selector.current_index() as usize)?;
+        if column.is_empty() {
+            return None;
+        }
+
+        let text = self.filter_line_edit.text().to_std_string();
+        let (operator, value) = match FilterOperator::from_prefix(&text) {
+            Some((operator, value)) => (operator, value.to_owned()),
+            None => (*operator, text),
+        };
+
+        Some(FilterCondition {
+            column,
+            operator,
+            value,
+        })
+    }
+
+    /// This function updates the text of the filter builder button, so it's visible when the builder has conditions.
+    unsafe fn update_filter_builder_button(&mut self) {
+        let conditions = self.filter_conditions.read().unwrap().len();
+        if conditions > 0 {
+            self.filter_builder_button.set_text(&QString::from_std_str(&tre("table_filter_builder_count", &[&conditions.to_string()])));
+        } else {
+            self.filter_builder_button.set_text(&qtr("table_filter_builder"));
+        }
+    }
+
+    /// This function opens the filter builder, to edit the extra conditions of the filter and how they're combined.
+    pub unsafe fn open_filter_builder(&mut self) {
+
+        // Columns are shown in the same order as in the filter line, and stored by name.
+        let mut field_names = vec![];
+        let mut column_list = QStringList::new();
+        for index in 0..self.filter_column_selector.count() {
+            field_names.push(self.filter_column_selector.item_data_1a(index).to_string().to_std_string());
+            column_list.append_q_string(&self.filter_column_selector.item_text(index));
+        }
+
+        let mut operator_list = QStringList::new();
+        for operator in &FILTER_OPERATORS {
+            operator_list.append_q_string(&QString::from_std_str(&operator.to_string()));
+        }
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&qtr("filter_builder_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(700, 350);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut instructions_label = QLabel::from_q_string(&qtr("filter_builder_instructions"));
+        instructions_label.set_word_wrap(true);
+
+        let mut conditions_view = QTableView::new_0a();
+        let mut conditions_model = QStandardItemModel::new_0a();
+        conditions_view.set_model(&mut conditions_model);
+        conditions_view.horizontal_header().set_stretch_last_section(true);
+        new_combobox_item_delegate_safe(&mut conditions_view, 0, column_list.as_ptr(), false, 0);
+        new_combobox_item_delegate_safe(&mut conditions_view, 1, operator_list.as_ptr(), false, 0);
+
+        let mut headers = QStringList::new();
+        headers.append_q_string(&qtr("filter_builder_column"));
+        headers.append_q_string(&qtr("filter_builder_operator"));
+        headers.append_q_string(&qtr("filter_builder_value"));
+        conditions_model.set_horizontal_header_labels(&headers);
+
+        for condition in self.filter_conditions.read().unwrap().iter() {
+            let column = match field_names.iter().position(|name| *name == condition.column) {
+                Some(column) => column as i32,
+                None => continue,
+            };
+
+            let operator = FILTER_OPERATORS.iter().position(|operator| *operator == condition.operator).unwrap_or(0) as i32;
+            let mut row = QListOfQStandardItem::new();
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(column_list.at(column)).into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(operator_list.at(operator)).into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&condition.value)).into_ptr());
+            conditions_model.append_row_q_list_of_q_standard_item(&row);
+        }
+
+        let mut match_selector = QComboBox::new_0a();
+        match_selector.add_item_q_string(&qtr("filter_builder_match_all"));
+        match_selector.add_item_q_string(&qtr("filter_builder_match_any"));
+        match_selector.set_current_index(if self.filter_match_all.load(Ordering::SeqCst) { 0 } else { 1 });
+
+        let mut add_button = QPushButton::from_q_string(&qtr("filter_builder_add"));
+        let mut remove_button = QPushButton::from_q_string(&qtr("filter_builder_remove"));
+        let mut accept_button = QPushButton::from_q_string(&qtr("filter_builder_accept"));
+
+        main_grid.add_widget_5a(&mut instructions_label, 0, 0, 1, 4);
+        main_grid.add_widget_5a(&mut conditions_view, 1, 0, 1, 4);
+        main_grid.add_widget_5a(&mut add_button, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&mut remove_button, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&mut match_selector, 2, 2, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 2, 3, 1, 1);
+
+        // New conditions start with the column of the filter line and the first operator.
+        let mut model = conditions_model.as_mut_ptr();
+        let first_column = column_list.at(self.filter_column_selector.current_index().max(0)).to_std_string();
+        let first_operator = operator_list.at(0).to_std_string();
+        let slot_add_condition = Slot::new(move || {
+            let mut row = QListOfQStandardItem::new();
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&first_column)).into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&first_operator)).into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::new().into_ptr());
+            model.append_row_q_list_of_q_standard_item(&row);
+        });
+
+        let view = conditions_view.as_mut_ptr();
+        let slot_remove_conditions = Slot::new(move || {
+            let indexes = view.selection_model().selected_indexes();
+            let mut rows = (0..indexes.count_0a()).map(|index| indexes.at(index).row()).collect::<Vec<i32>>();
+            rows.sort_unstable();
+            rows.dedup();
+            for row in rows.iter().rev() {
+                model.remove_row_1a(*row);
+            }
+        });
+
+        add_button.released().connect(&slot_add_condition);
+        remove_button.released().connect(&slot_remove_conditions);
+        accept_button.released().connect(dialog.slot_accept());
+
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
+            let mut conditions = vec![];
+            for row in 0..conditions_model.row_count_0a() {
+                let column_name = conditions_model.item_2a(row, 0).text();
+                let operator_name = conditions_model.item_2a(row, 1).text();
+                let column = (0..column_list.count_0a()).find(|index| column_list.at(*index).compare_q_string(&column_name) == 0);
+                let operator = (0..operator_list.count_0a()).find(|index| operator_list.at(*index).compare_q_string(&operator_name) == 0);
+                if let (Some(column), Some(operator)) = (column, operator) {
+                    conditions.push(FilterCondition {
+                        column: field_names[column as usize].to_owned(),
+                        operator: FILTER_OPERATORS[operator as usize],
+                        value: conditions_model.item_2a(row, 2).text().to_std_string(),
+                    });
+                }
+            }
+
+            *self.filter_conditions.write().unwrap() = conditions;
+            self.filter_match_all.store(match_selector.current_index() == 0, Ordering::SeqCst);
+            self.update_filter_builder_button();
+            self.filter_table();
+        }
+    }
+
+    /// This function applies the filter preset with the provided index in the preset selector.
+    ///
+    /// The first condition of the preset goes to the filter line, and the rest to the filter builder.
+    pub unsafe fn apply_filter_preset(&mut self, index: i32) {
+        if index < 1 {
+            return;
+        }
+
+        let preset_name = self.filter_preset_selector.item_text(index).to_std_string();
+        let preset = match self.table_state_name.as_ref()
+            .and_then(|table_name| UI_STATE.get_table_state(table_name))
+            .and_then(|table_state| table_state.filter_presets.get(&preset_name).cloned()) {
+            Some(preset) => preset,
+            None => return,
+        };
+
+        let mut conditions = preset.conditions;
+        let main_condition = if conditions.is_empty() { None } else { Some(conditions.remove(0)) };
+
+        // Block the signals of the filter line, so we only filter once, with the entire preset applied.
+        {
+            let _line_edit_blocker = QSignalBlocker::from_q_object(self.filter_line_edit.static_upcast_mut::<QObject>());
+            let _column_blocker = QSignalBlocker::from_q_object(self.filter_column_selector.static_upcast_mut::<QObject>());
+            let _operator_blocker = QSignalBlocker::from_q_object(self.filter_operator_selector.static_upcast_mut::<QObject>());
+            let _case_sensitive_blocker = QSignalBlocker::from_q_object(self.filter_case_sensitive_button.static_upcast_mut::<QObject>());
+
+            match main_condition {
+                Some(condition) => {
+                    let column = self.filter_column_selector.find_data_1a(&QVariant::from_q_string(&QString::from_std_str(&condition.column)));
+                    if column != -1 {
+                        self.filter_column_selector.set_current_index(column);
+                    }
+
+                    let operator = FILTER_OPERATORS.iter().position(|operator| *operator == condition.operator).unwrap_or(0);
+                    self.filter_operator_selector.set_current_index(operator as i32);
+                    self.filter_line_edit.set_text(&QString::from_std_str(&condition.value));
+                }
+                None => self.filter_line_edit.clear(),
+            }
+
+            self.filter_case_sensitive_button.set_checked(preset.case_sensitive);
+        }
+
+        *self.filter_conditions.write().unwrap() = conditions;
+        self.filter_match_all.store(preset.match_all, Ordering::SeqCst);
+        self.update_filter_builder_button();
+        self.filter_table();
+    }
+
+    /// This function saves the current filter as a preset of this table, with a name provided by the user.
+    ///
+    /// If there is already a preset with that name, it's replaced.
+    pub unsafe fn save_filter_preset(&mut self) {
+        let table_name = match self.table_state_name.clone() {
+            Some(table_name) => table_name,
+            None => return,
+        };
+
+        let current_name = if self.filter_preset_selector.current_index() > 0 { self.filter_preset_selector.current_text().to_std_string() } else { String::new() };
+        let preset_name = match self.create_filter_preset_name_dialog(&current_name) {
+            Some(preset_name) => preset_name,
+            None => return,
+        };
+
+        let mut conditions = self.get_main_filter_condition().into_iter().collect::<Vec<FilterCondition>>();
+        conditions.extend_from_slice(&self.filter_conditions.read().unwrap());
+        let preset = FilterPreset {
+            conditions,
+            match_all: self.filter_match_all.load(Ordering::SeqCst),
+            case_sensitive: self.filter_case_sensitive_button.is_checked(),
+        };
+
+        let mut table_state = UI_STATE.get_table_state(&table_name).unwrap_or_default();
+        table_state.filter_presets.insert(preset_name.to_owned(), preset);
+        if let Err(error) = UI_STATE.set_table_state(&table_name, table_state) {
+            return show_dialog(self.table_view_primary, error, false);
+        }
+
+        load_filter_presets(self.filter_preset_selector, Some(&table_name));
+        let _blocker = QSignalBlocker::from_q_object(self.filter_preset_selector.static_upcast_mut::<QObject>());
+        let index = self.filter_preset_selector.find_text_1a(&QString::from_std_str(&preset_name));
+        self.filter_preset_selector.set_current_index(index);
+    }
 
-        if case_sensitive { pattern.set_case_sensitivity(CaseSensitivity::CaseSensitive); }
-        else { pattern.set_case_sensitivity(CaseSensitivity::CaseInsensitive); }
+    /// This function deletes the filter preset currently selected in the preset selector. The current filter is not changed.
+    pub unsafe fn delete_filter_preset(&mut self) {
+        let table_name = match self.table_state_name.clone() {
+            Some(table_name) if self.filter_preset_selector.current_index() > 0 => table_name,
+            _ => return,
+        };
+
+        let preset_name = self.filter_preset_selector.current_text().to_std_string();
+        if let Some(mut table_state) = UI_STATE.get_table_state(&table_name) {
+            table_state.filter_presets.remove(&preset_name);
+            if let Err(error) = UI_STATE.set_table_state(&table_name, table_state) {
+                return show_dialog(self.table_view_primary, error, false);
+            }
+        }
+
+        load_filter_presets(self.filter_preset_selector, Some(&table_name));
+    }
+
+    /// This function creates the dialog to ask the user for the name of a filter preset. It returns None if the dialog was cancelled.
+    unsafe fn create_filter_preset_name_dialog(&self, current_name: &str) -> Option<String> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&qtr("filter_preset_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 50);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut name_line_edit = QLineEdit::from_q_string(&QString::from_std_str(current_name));
+        name_line_edit.set_placeholder_text(&qtr("filter_preset_name"));
+        let mut accept_button = QPushButton::from_q_string(&qtr("filter_preset_accept"));
+        main_grid.add_widget_5a(&mut name_line_edit, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 0, 1, 1, 1);
+        accept_button.released().connect(dialog.slot_accept());
 
-        // Filter whatever it's in that column by the text we got.
-        self.table_filter.set_filter_reg_exp_q_reg_exp(&pattern);
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
+            let name = name_line_edit.text().to_std_string().trim().to_owned();
+            if name.is_empty() { None } else { Some(name) }
+        } else { None }
     }
 
     /// This function enables/disables showing the lookup values instead of the real ones in the columns that support it.
@@ -274,10 +722,15 @@ impl TableViewRaw {
                     let column = model_index.column();
                     let row = model_index.row();
                     let current_value = item.text().to_std_string();
-                    let new_value = value.replace("{x}", &current_value)
+                    let mut new_value = value.replace("{x}", &current_value)
                         .replace("{y}", &column.to_string())
                         .replace("{z}", &row.to_string());
 
+                    // If the column has a unit, values like "1.5s" or "20%" are turned into what the column actually stores.
+                    if let Some(unit) = self.get_column_unit(column) {
+                        new_value = unit.convert_values(&new_value);
+                    }
+
                     let text = if is_math_operation {
                          if let Ok(result) = meval::eval_str(&new_value) {
 
@@ -919,6 +1372,99 @@ impl TableViewRaw {
         }
     }
 
+    /// This function returns if the rows of the table can be moved, which requires the table to be ordered, editable and not sorted.
+    pub fn can_move_rows(&self) -> bool {
+        self.get_ref_table_definition().get_is_ordered() && !self.is_read_only() && self.column_sort_state.read().unwrap().0 < 0
+    }
+
+    /// This function moves the row in the provided visual position to the position of the row in the other provided visual position,
+    /// in the source model. It returns the move done, as (origin row, destination row) in the source model.
+    unsafe fn move_row(&mut self, visual_row_from: i32, visual_row_to: i32) -> (i32, i32) {
+        let from = self.table_filter.map_to_source(&self.table_filter.index_2a(visual_row_from, 0)).row();
+        let to = self.table_filter.map_to_source(&self.table_filter.index_2a(visual_row_to, 0)).row();
+        let row = self.table_model.take_row(from);
+        self.table_model.insert_row_int_q_list_of_q_standard_item(to, &row);
+        (from, to)
+    }
+
+    /// This function moves the selected rows one position up or down, keeping the rows that cannot move where they are.
+    ///
+    /// Hidden rows are skipped, so the rows always move past the next visible one. It returns if any row was moved.
+    pub unsafe fn move_rows(&mut self, up: bool) -> bool {
+        if !self.can_move_rows() { return false; }
+
+        let indexes = self.table_view_primary.selection_model().selection().indexes();
+        let mut rows = (0..indexes.count_0a()).map(|x| indexes.at(x).row()).collect::<Vec<i32>>();
+        rows.sort_unstable();
+        rows.dedup();
+        if !up { rows.reverse(); }
+
+        // Rows blocked at the edge of the table block the selected rows right after them.
+        let mut limit = if up { 0 } else { self.table_filter.row_count_0a() - 1 };
+        let mut moves = vec![];
+        let mut new_rows = vec![];
+        for row in rows {
+            if (up && row > limit) || (!up && row < limit) {
+                let new_row = if up { row - 1 } else { row + 1 };
+                moves.push(self.move_row(row, new_row));
+                new_rows.push(new_row);
+            }
+            else {
+                limit = if up { row + 1 } else { row - 1 };
+                new_rows.push(row);
+            }
+        }
+
+        let mut selection_model = self.table_view_primary.selection_model();
+        selection_model.clear();
+        for row in &new_rows {
+            selection_model.select_q_model_index_q_flags_selection_flag(&self.table_filter.index_2a(*row, 0), SelectionFlag::Select | SelectionFlag::Rows);
+        }
+
+        let moved = !moves.is_empty();
+        if moved {
+            self.history_undo.write().unwrap().push(TableOperations::MoveRows(moves));
+            self.history_redo.write().unwrap().clear();
+            update_undo_model(self.table_model, self.undo_model);
+        }
+
+        self.context_menu_update();
+        moved
+    }
+
+    /// This function turns a row dragged by its header into a row moved in the model.
+    ///
+    /// The header only moves its sections visually, so we put them back where they were and move the row in the model instead.
+    /// It returns if the row was moved.
+    pub unsafe fn move_row_from_header(&mut self) -> bool {
+        let mut header = self.table_view_primary.vertical_header();
+        let moved_sections = (0..header.count()).filter(|visual| header.logical_index(*visual) != *visual).collect::<Vec<i32>>();
+        let (first, last) = match (moved_sections.first(), moved_sections.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return false,
+        };
+
+        // The section dragged is either the first one or the last one of the moved ones, depending on the direction of the drag.
+        let (from, to) = if header.logical_index(first) == last { (last, first) } else { (first, last) };
+        {
+            let _blocker = QSignalBlocker::from_q_object(header);
+            header.move_section(to, from);
+        }
+
+        if !self.can_move_rows() { return false; }
+
+        let moved = self.move_row(from, to);
+        let mut selection_model = self.table_view_primary.selection_model();
+        selection_model.clear();
+        selection_model.select_q_model_index_q_flags_selection_flag(&self.table_filter.index_2a(to, 0), SelectionFlag::Select | SelectionFlag::Rows);
+
+        self.history_undo.write().unwrap().push(TableOperations::MoveRows(vec![moved]));
+        self.history_redo.write().unwrap().clear();
+        update_undo_model(self.table_model, self.undo_model);
+        self.context_menu_update();
+        true
+    }
+
     /// Function to undo/redo an operation in the table.
     ///
     /// If undo = true we are undoing. Otherwise we are redoing.
@@ -1055,6 +1601,28 @@ impl TableViewRaw {
                     }
                 }
 
+                // Moves are undone in reverse, each one from where it left the row to where it took it from.
+                TableOperations::MoveRows(moves) => {
+                    self.undo_lock.store(true, Ordering::SeqCst);
+                    let moves = moves.iter().rev().map(|(from, to)| (*to, *from)).collect::<Vec<(i32, i32)>>();
+                    for (from, to) in &moves {
+                        let row = model.take_row(*from);
+                        model.insert_row_int_q_list_of_q_standard_item(*to, &row);
+                    }
+
+                    let mut selection_model = self.table_view_primary.selection_model();
+                    selection_model.clear();
+                    for (_, to) in &moves {
+                        let model_index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(*to, 0));
+                        if model_index_filtered.is_valid() {
+                            selection_model.select_q_model_index_q_flags_selection_flag(&model_index_filtered, SelectionFlag::Select | SelectionFlag::Rows);
+                        }
+                    }
+
+                    history_opposite.push(TableOperations::MoveRows(moves));
+                    self.undo_lock.store(false, Ordering::SeqCst);
+                }
+
                 TableOperations::Carolina(mut operations) => {
                     is_carolina = true;
                     repeat_x_times = operations.len();
@@ -1092,6 +1660,196 @@ impl TableViewRaw {
         }
     }
 
+    /// This function removes the oldest operations of the undo history until it fits the max steps and memory set in the settings.
+    ///
+    /// It also updates the label with the current size of the undo history. A limit of 0 means no limit.
+    pub unsafe fn enforce_history_limits(&mut self) {
+        let (max_steps, max_memory) = {
+            let settings = SETTINGS.read().unwrap();
+            (
+                settings.settings_string["tables_max_undo_steps"].parse::<usize>().unwrap_or(0),
+                settings.settings_string["tables_max_undo_memory_mb"].parse::<usize>().unwrap_or(0) * 1024 * 1024,
+            )
+        };
+
+        // If the history is locked, we're in the middle of an operation. We'll trim it the next time it changes.
+        let mut history_undo = match self.history_undo.try_write() {
+            Ok(history_undo) => history_undo,
+            Err(_) => return,
+        };
+
+        let mut sizes = history_undo.iter().map(|operation| get_operation_size(operation)).collect::<Vec<usize>>();
+        let mut total_size = sizes.iter().sum::<usize>();
+        while !history_undo.is_empty() && ((max_steps > 0 && history_undo.len() > max_steps) || (max_memory > 0 && total_size > max_memory)) {
+            total_size -= sizes.remove(0);
+            delete_operation_items(history_undo.remove(0));
+        }
+
+        let size = format!("{:.1} MB", total_size as f64 / (1024.0 * 1024.0));
+        self.history_size_label.set_text(&QString::from_std_str(&tre("history_size", &[&history_undo.len().to_string(), &size])));
+    }
+
+    /// This function reloads the operation list of the undo history panel, if it's visible.
+    ///
+    /// The list goes from the oldest operation to the newest one, followed by the operations that can be redone.
+    pub unsafe fn update_history_panel(&mut self) {
+        if !self.history_widget.is_visible() {
+            return;
+        }
+
+        self.history_model.clear();
+
+        let history_undo = self.history_undo.read().unwrap();
+        let history_redo = self.history_redo.read().unwrap();
+        let current_position = history_undo.len() as i32;
+
+        let mut original = QStandardItem::from_q_string(&qtr("history_original_state"));
+        original.set_data_2a(&QVariant::from_int(0), HISTORY_POSITION);
+        self.history_model.append_row_q_standard_item(original.into_ptr());
+
+        for (index, operation) in history_undo.iter().enumerate() {
+            let (description, details) = self.get_operation_description(operation, true);
+            self.add_history_entry(&description, &details, index as i32 + 1, false);
+        }
+
+        for (index, operation) in history_redo.iter().rev().enumerate() {
+            let (description, details) = self.get_operation_description(operation, false);
+            self.add_history_entry(&description, &details, current_position + index as i32 + 1, true);
+        }
+
+        // Mark the current state, so it's clear where we are.
+        let mut current = self.history_model.item_1a(current_position);
+        let mut font = current.font();
+        font.set_bold(true);
+        current.set_font(&font);
+
+        self.history_view.scroll_to_1a(self.history_model.index_2a(current_position, 0).as_ref());
+        self.history_jump_button.set_enabled(false);
+        self.history_label.set_text(&qtr("history_hint"));
+    }
+
+    /// This function adds an entry to the undo history panel.
+    unsafe fn add_history_entry(&mut self, description: &str, details: &[String], position: i32, is_redo: bool) {
+        let mut item = QStandardItem::from_q_string(&QString::from_std_str(description));
+        item.set_editable(false);
+        item.set_data_2a(&QVariant::from_int(position), HISTORY_POSITION);
+        item.set_tool_tip(&QString::from_std_str(&details.join("\n")));
+        if is_redo {
+            item.set_foreground(&QBrush::from_global_color(GlobalColor::Gray));
+        }
+        self.history_model.append_row_q_standard_item(item.into_ptr());
+    }
+
+    /// This function returns a human-readable description of the provided operation, and a list with what it touches.
+    ///
+    /// The `undo` flag is needed because an operation in the redo history reverts the one that was undone.
+    unsafe fn get_operation_description(&self, operation: &TableOperations, undo: bool) -> (String, Vec<String>) {
+        let mut details = vec![];
+        let description = match operation {
+            TableOperations::Editing(editions) => {
+                details.push(tr("history_details_editing"));
+                for ((row, column), item) in editions.iter().take(HISTORY_DETAILS_LIMIT) {
+                    let item = mut_ptr_from_atomic(item);
+                    let column_name = self.table_model.header_data_2a(*column, Orientation::Horizontal).to_string().to_std_string();
+                    let value = if item.is_checkable() {
+                        (item.check_state() == CheckState::Checked).to_string()
+                    } else { item.text().to_std_string() };
+                    details.push(tre("history_details_cell", &[&(row + 1).to_string(), &column_name, &value]));
+                }
+                if editions.len() > HISTORY_DETAILS_LIMIT {
+                    details.push(tre("history_details_more", &[&(editions.len() - HISTORY_DETAILS_LIMIT).to_string()]));
+                }
+                tre("history_editing", &[&editions.len().to_string()])
+            }
+
+            // Rows added in the undo history were removed in the redo one, and viceversa.
+            TableOperations::AddRows(rows) => {
+                let mut rows = rows.iter().map(|x| x + 1).collect::<Vec<i32>>();
+                rows.sort();
+                details.push(tre("history_details_rows", &[&rows.iter().take(HISTORY_DETAILS_LIMIT).map(|x| x.to_string()).collect::<Vec<String>>().join(", ")]));
+                if rows.len() > HISTORY_DETAILS_LIMIT {
+                    details.push(tre("history_details_more", &[&(rows.len() - HISTORY_DETAILS_LIMIT).to_string()]));
+                }
+                if undo { tre("history_add_rows", &[&rows.len().to_string()]) }
+                else { tre("history_remove_rows", &[&rows.len().to_string()]) }
+            }
+            TableOperations::RemoveRows(rows) => {
+                let rows = rows.iter()
+                    .map(|(index, row_pack)| (0..row_pack.len() as i32).map(|x| index + x + 1).collect::<Vec<i32>>())
+                    .flatten()
+                    .collect::<Vec<i32>>();
+                details.push(tre("history_details_rows", &[&rows.iter().take(HISTORY_DETAILS_LIMIT).map(|x| x.to_string()).collect::<Vec<String>>().join(", ")]));
+                if rows.len() > HISTORY_DETAILS_LIMIT {
+                    details.push(tre("history_details_more", &[&(rows.len() - HISTORY_DETAILS_LIMIT).to_string()]));
+                }
+                if undo { tre("history_remove_rows", &[&rows.len().to_string()]) }
+                else { tre("history_add_rows", &[&rows.len().to_string()]) }
+            }
+            TableOperations::ImportTSV(table_data) => {
+                details.push(tre("history_details_import_tsv", &[&table_data.len().to_string()]));
+                tr("history_import_tsv")
+            }
+            TableOperations::MoveRows(moves) => {
+                details.push(tre("history_details_rows", &[&moves.iter().take(HISTORY_DETAILS_LIMIT).map(|(from, to)| format!("{} -> {}", from + 1, to + 1)).collect::<Vec<String>>().join(", ")]));
+                if moves.len() > HISTORY_DETAILS_LIMIT {
+                    details.push(tre("history_details_more", &[&(moves.len() - HISTORY_DETAILS_LIMIT).to_string()]));
+                }
+                tre("history_move_rows", &[&moves.len().to_string()])
+            }
+            TableOperations::Carolina(operations) => {
+                for operation in operations {
+                    details.push(self.get_operation_description(operation, undo).0);
+                }
+                tre("history_carolina", &[&operations.len().to_string()])
+            }
+        };
+
+        (description, details)
+    }
+
+    /// This function returns the position in the undo history of the currently selected entry of the history panel, if any.
+    pub unsafe fn get_selected_history_position(&self) -> Option<i32> {
+        let indexes = self.history_view.selection_model().selected_indexes();
+        if indexes.count_0a() > 0 {
+            Some(indexes.at(0).data_1a(HISTORY_POSITION).to_int_0a())
+        } else { None }
+    }
+
+    /// This function updates the explanation of the undo history panel with what jumping to the selected entry will do.
+    pub unsafe fn update_history_label(&mut self) {
+        let current_position = self.history_undo.read().unwrap().len() as i32;
+        match self.get_selected_history_position() {
+            Some(position) if position < current_position => {
+                self.history_label.set_text(&QString::from_std_str(&tre("history_will_undo", &[&(current_position - position).to_string()])));
+                self.history_jump_button.set_enabled(true);
+            }
+            Some(position) if position > current_position => {
+                self.history_label.set_text(&QString::from_std_str(&tre("history_will_redo", &[&(position - current_position).to_string()])));
+                self.history_jump_button.set_enabled(true);
+            }
+            _ => {
+                self.history_label.set_text(&qtr("history_hint"));
+                self.history_jump_button.set_enabled(false);
+            }
+        }
+    }
+
+    /// This function undoes/redoes all the operations needed to get the table to the provided position of its undo history.
+    ///
+    /// Returns true if it had to undo something, false if it had to redo something, and None if it did nothing.
+    pub unsafe fn jump_in_history(&mut self, position: i32) -> Option<bool> {
+        let current_position = self.history_undo.read().unwrap().len() as i32;
+        let undo = if position < current_position { true }
+            else if position > current_position { false }
+            else { return None };
+
+        for _ in 0..(current_position - position).abs() {
+            self.undo_redo(undo, 0);
+        }
+
+        Some(undo)
+    }
+
     /// This function returns the provided indexes's data as a LUA table.
     unsafe fn get_indexes_as_lua_table(&self, indexes: &[Ref<QModelIndex>], has_keys: bool) -> String {
         let mut table_data: Vec<(Option<String>, Vec<String>)> = vec![];
@@ -1217,56 +1975,676 @@ impl TableViewRaw {
         }
     }
 
-    /// This function is used to append new rows to a table.
-    ///
-    /// If clone = true, the appended rows are copies of the selected ones.
-    pub unsafe fn append_rows(&mut self, clone: bool) {
+    /// This function updates the values of the summary row, using only the rows visible with the current filter.
+    pub unsafe fn update_summary_row(&mut self) {
+        if !self.summary_view.is_visible() {
+            return;
+        }
 
-        // Get the indexes ready for battle.
-        let selection = self.table_view_primary.selection_model().selection();
-        let indexes = self.table_filter.map_selection_to_source(&selection).indexes();
-        let mut indexes_sorted = (0..indexes.count_0a()).map(|x| indexes.at(x)).collect::<Vec<Ref<QModelIndex>>>();
-        sort_indexes_by_model(&mut indexes_sorted);
-        dedup_indexes_per_row(&mut indexes_sorted);
-        let mut row_numbers = vec![];
+        let decimals = SETTINGS.read().unwrap().settings_string["tables_f32_decimals"].parse::<usize>().unwrap_or(3);
+        let visible_rows = (0..self.table_filter.row_count_0a())
+            .map(|row| self.table_filter.map_to_source(&self.table_filter.index_2a(row, 0)).row())
+            .collect::<Vec<i32>>();
 
-        let rows = if clone {
-            let mut rows = vec![];
-            let color = get_color_added_modified();
-            for index in indexes_sorted.iter() {
-                row_numbers.push(index.row());
+        let aggregates = self.summary_aggregates.read().unwrap();
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        self.summary_model.set_column_count(fields.len() as i32);
 
-                let columns = self.table_model.column_count_0a();
-                let mut qlist = QListOfQStandardItem::new();
-                for column in 0..columns {
-                    let original_item = self.table_model.item_2a(index.row(), column);
-                    let mut item = (*original_item).clone();
-                    item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
-                    add_to_q_list_safe(qlist.as_mut_ptr(), item);
+        for (column, field) in fields.iter().enumerate() {
+            let column = column as i32;
+            let field_type = field.get_ref_field_type();
+            let is_number = match field_type {
+                FieldType::F32 | FieldType::I16 | FieldType::I32 | FieldType::I64 => true,
+                _ => false,
+            };
+            let aggregate = aggregates.get(&column).copied().unwrap_or(if is_number { SummaryAggregate::Sum } else { SummaryAggregate::None });
+
+            let text = match aggregate {
+                SummaryAggregate::Sum | SummaryAggregate::Average if is_number => {
+                    let sum = visible_rows.iter().map(|row| {
+                        let item = self.table_model.item_2a(*row, column);
+                        match field_type {
+                            FieldType::F32 => f64::from(item.data_1a(2).to_float_0a()),
+                            FieldType::I64 => item.data_1a(2).to_long_long_0a() as f64,
+                            _ => f64::from(item.data_1a(2).to_int_0a()),
+                        }
+                    }).sum::<f64>();
+
+                    if aggregate == SummaryAggregate::Sum {
+                        let sum = if *field_type == FieldType::F32 { format!("{:.*}", decimals, sum) } else { format!("{}", sum as i64) };
+                        tre("summary_row_sum_value", &[&sum])
+                    } else {
+                        let average = if visible_rows.is_empty() { 0.0 } else { sum / visible_rows.len() as f64 };
+                        tre("summary_row_average_value", &[&format!("{:.*}", decimals, average)])
+                    }
                 }
 
-                rows.push(qlist);
-            }
-            rows
-        } else {
-            let color = get_color_added();
-            let mut row = get_new_row(&self.get_ref_table_definition());
-            for index in 0..row.count() {
-                row.index(index).as_mut().unwrap().set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
-            }
-            vec![row]
-        };
+                // Count counts the non-empty cells, or the checked ones in boolean columns.
+                SummaryAggregate::Count => {
+                    let count = visible_rows.iter().filter(|row| {
+                        let item = self.table_model.item_2a(**row, column);
+                        if *field_type == FieldType::Boolean { item.check_state() == CheckState::Checked }
+                        else { !item.text().is_empty() }
+                    }).count();
+                    tre("summary_row_count_value", &[&count.to_string()])
+                }
+                _ => String::new(),
+            };
 
-        let mut selection_model = self.table_view_primary.selection_model();
-        selection_model.clear();
-        for row in &rows {
-            self.table_model.append_row_q_list_of_q_standard_item(row.as_ref());
+            let mut item = QStandardItem::from_q_string(&QString::from_std_str(&text));
+            item.set_editable(false);
+            self.summary_model.set_item_3a(0, column, item.into_ptr());
+        }
+    }
 
-            // Select the row and scroll to it.
-            let model_index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(self.table_filter.row_count_0a() - 1, 0));
-            if model_index_filtered.is_valid() {
-                selection_model.select_q_model_index_q_flags_selection_flag(
-                    &model_index_filtered,
+    /// This function shows a dialog with statistics of the column of the current cell, using only the rows visible with the current filter.
+    ///
+    /// Numeric columns get their count, distinct values, min, max and mean. Other columns get how many times each value appears.
+    pub unsafe fn show_column_statistics(&self) {
+        let current_index = self.table_view_primary.current_index();
+        if !current_index.is_valid() {
+            return;
+        }
+
+        let column = self.table_filter.map_to_source(&current_index).column();
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        let (column_name, field_type) = match fields.get(column as usize) {
+            Some(field) => (clean_column_names(field.get_name()), field.get_ref_field_type().clone()),
+            None => return,
+        };
+        let is_number = match field_type {
+            FieldType::F32 | FieldType::I16 | FieldType::I32 | FieldType::I64 => true,
+            _ => false,
+        };
+
+        let decimals = SETTINGS.read().unwrap().settings_string["tables_f32_decimals"].parse::<usize>().unwrap_or(3);
+        let visible_rows = (0..self.table_filter.row_count_0a())
+            .map(|row| self.table_filter.map_to_source(&self.table_filter.index_2a(row, 0)).row())
+            .collect::<Vec<i32>>();
+
+        let values = visible_rows.iter().map(|row| {
+            let item = self.table_model.item_2a(*row, column);
+            if field_type == FieldType::Boolean { (item.check_state() == CheckState::Checked).to_string() }
+            else { item.text().to_std_string() }
+        }).collect::<Vec<String>>();
+        let distinct = values.iter().collect::<HashSet<&String>>().len();
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&QString::from_std_str(&tre("column_statistics_title", &[&column_name])));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 50);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut stats = vec![
+            (tr("column_statistics_count"), values.len().to_string()),
+            (tr("column_statistics_distinct"), distinct.to_string()),
+        ];
+
+        if is_number {
+            let numbers = visible_rows.iter().map(|row| {
+                let item = self.table_model.item_2a(*row, column);
+                match field_type {
+                    FieldType::F32 => f64::from(item.data_1a(2).to_float_0a()),
+                    FieldType::I64 => item.data_1a(2).to_long_long_0a() as f64,
+                    _ => f64::from(item.data_1a(2).to_int_0a()),
+                }
+            }).collect::<Vec<f64>>();
+
+            let format_number = |number: f64| if field_type == FieldType::F32 { format!("{:.*}", decimals, number) } else { format!("{}", number as i64) };
+            let (min, max, mean) = if numbers.is_empty() { ("-".to_owned(), "-".to_owned(), "-".to_owned()) } else {(
+                format_number(numbers.iter().cloned().fold(f64::INFINITY, f64::min)),
+                format_number(numbers.iter().cloned().fold(f64::NEG_INFINITY, f64::max)),
+                format!("{:.*}", decimals, numbers.iter().sum::<f64>() / numbers.len() as f64),
+            )};
+
+            stats.push((tr("column_statistics_min"), min));
+            stats.push((tr("column_statistics_max"), max));
+            stats.push((tr("column_statistics_mean"), mean));
+        }
+
+        for (index, (name, value)) in stats.iter().enumerate() {
+            let mut name_label = QLabel::from_q_string(&QString::from_std_str(name));
+            let mut value_label = QLabel::from_q_string(&QString::from_std_str(value));
+            main_grid.add_widget_5a(&mut name_label, index as i32, 0, 1, 1);
+            main_grid.add_widget_5a(&mut value_label, index as i32, 1, 1, 1);
+            name_label.into_ptr();
+            value_label.into_ptr();
+        }
+
+        // For non-numeric columns, show the values from the most common to the least common, with a bar to compare them at a glance.
+        if !is_number {
+            let mut frequencies: BTreeMap<&String, usize> = BTreeMap::new();
+            values.iter().for_each(|value| *frequencies.entry(value).or_insert(0) += 1);
+            let mut frequencies = frequencies.into_iter().collect::<Vec<(&String, usize)>>();
+            frequencies.sort_by(|x, y| y.1.cmp(&x.1));
+            let max_frequency = frequencies.first().map(|(_, count)| *count).unwrap_or(1);
+
+            let mut histogram_view = QTableView::new_0a();
+            let mut histogram_model = QStandardItemModel::new_0a();
+            histogram_view.set_model(&mut histogram_model);
+            histogram_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+            histogram_view.vertical_header().hide();
+            histogram_view.horizontal_header().set_stretch_last_section(true);
+
+            let mut headers = QStringList::new();
+            headers.append_q_string(&qtr("column_statistics_value"));
+            headers.append_q_string(&qtr("column_statistics_count"));
+            headers.append_q_string(&qtr("column_statistics_histogram"));
+            histogram_model.set_horizontal_header_labels(&headers);
+
+            for (value, count) in &frequencies {
+                let mut row = QListOfQStandardItem::new();
+                let bar = "█".repeat((count * HISTOGRAM_BAR_LENGTH / max_frequency).max(1));
+                add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(value)).into_ptr());
+                add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&count.to_string())).into_ptr());
+                add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&bar)).into_ptr());
+                histogram_model.append_row_q_list_of_q_standard_item(&row);
+            }
+
+            histogram_view.resize_columns_to_contents();
+            main_grid.add_widget_5a(&mut histogram_view, stats.len() as i32, 0, 1, 2);
+            histogram_model.into_ptr();
+            histogram_view.into_ptr();
+            dialog.resize_2a(500, 400);
+        }
+
+        let mut close_button = QPushButton::from_q_string(&qtr("column_statistics_close"));
+        main_grid.add_widget_5a(&mut close_button, stats.len() as i32 + 1, 0, 1, 2);
+        close_button.released().connect(dialog.slot_accept());
+        dialog.exec();
+    }
+
+    /// This function opens a dialog to compare this table side-by-side with its version in another PackFile, or in the game files.
+    pub unsafe fn compare_with(&self) {
+        let path = match self.packed_file_path {
+            Some(ref path) => get_path_within_packfile(&path.read().unwrap()).to_vec(),
+            None => return,
+        };
+
+        match get_table_from_view(self.table_model, &self.get_ref_table_definition()) {
+            Ok(table) => TableCompareUI::new(self.table_view_primary, path, table),
+            Err(error) => show_dialog(self.table_view_primary, error, false),
+        }
+    }
+
+    /// This function adds the pivot tab and switches to it, or removes it.
+    pub unsafe fn toggle_pivot(&mut self, enable: bool) {
+        if enable {
+            let index = self.table_tabs.add_tab_2a(self.pivot_widget, &qtr("pivot_tab"));
+            self.table_tabs.set_current_index(index);
+            self.update_pivot();
+        }
+        else {
+            let index = self.table_tabs.index_of(self.pivot_widget);
+            if index != -1 {
+                self.table_tabs.remove_tab(index);
+            }
+        }
+    }
+
+    /// This function recalculates the pivot, using only the rows visible with the current filter.
+    ///
+    /// Rows are grouped by the text of the chosen group column, and the values of the chosen value column are aggregated for each group.
+    pub unsafe fn update_pivot(&mut self) {
+        if self.table_tabs.index_of(self.pivot_widget) == -1 {
+            return;
+        }
+
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        let group_column = self.pivot_group_column_selector.current_data_0a().to_int_0a();
+        let group_field = match fields.get(group_column as usize) {
+            Some(field) => field,
+            None => return,
+        };
+
+        // Tables without numeric columns can only count their rows.
+        let aggregate = PIVOT_AGGREGATES[self.pivot_aggregate_selector.current_index().max(0) as usize];
+        let value_column = self.pivot_value_column_selector.current_data_0a();
+        let value_field = if aggregate != PivotAggregate::Count && value_column.is_valid() {
+            fields.get(value_column.to_int_0a() as usize).map(|field| (value_column.to_int_0a(), field))
+        } else { None };
+
+        let decimals = SETTINGS.read().unwrap().settings_string["tables_f32_decimals"].parse::<usize>().unwrap_or(3);
+        let visible_rows = (0..self.table_filter.row_count_0a())
+            .map(|row| self.table_filter.map_to_source(&self.table_filter.index_2a(row, 0)).row())
+            .collect::<Vec<i32>>();
+
+        let mut groups: BTreeMap<String, (usize, Vec<f64>)> = BTreeMap::new();
+        for row in &visible_rows {
+            let item = self.table_model.item_2a(*row, group_column);
+            let group = if *group_field.get_ref_field_type() == FieldType::Boolean { (item.check_state() == CheckState::Checked).to_string() }
+            else { item.text().to_std_string() };
+
+            let (count, values) = groups.entry(group).or_insert_with(|| (0, vec![]));
+            *count += 1;
+            if let Some((column, field)) = value_field {
+                let item = self.table_model.item_2a(*row, column);
+                values.push(match field.get_ref_field_type() {
+                    FieldType::F32 => f64::from(item.data_1a(2).to_float_0a()),
+                    FieldType::I64 => item.data_1a(2).to_long_long_0a() as f64,
+                    _ => f64::from(item.data_1a(2).to_int_0a()),
+                });
+            }
+        }
+
+        self.pivot_model.clear();
+        let mut headers = QStringList::new();
+        headers.append_q_string(&QString::from_std_str(&clean_column_names(group_field.get_name())));
+        headers.append_q_string(&qtr("pivot_rows"));
+        if let Some((_, field)) = value_field {
+            headers.append_q_string(&QString::from_std_str(&tre("pivot_aggregate_header", &[&aggregate.to_string(), &clean_column_names(field.get_name())])));
+        }
+        self.pivot_model.set_horizontal_header_labels(&headers);
+
+        for (group, (count, values)) in &groups {
+            let mut row = QListOfQStandardItem::new();
+            let mut group_item = QStandardItem::from_q_string(&QString::from_std_str(group));
+            let mut count_item = QStandardItem::from_q_string(&QString::from_std_str(&count.to_string()));
+            group_item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(group)), PIVOT_SORT_VALUE);
+            count_item.set_data_2a(&QVariant::from_double(*count as f64), PIVOT_SORT_VALUE);
+            add_to_q_list_safe(row.as_mut_ptr(), group_item.into_ptr());
+            add_to_q_list_safe(row.as_mut_ptr(), count_item.into_ptr());
+
+            if let Some((_, field)) = value_field {
+                let value = aggregate.apply(values).unwrap_or(0.0);
+                let text = if *field.get_ref_field_type() == FieldType::F32 || aggregate == PivotAggregate::Average { format!("{:.*}", decimals, value) }
+                else { format!("{}", value as i64) };
+
+                let mut value_item = QStandardItem::from_q_string(&QString::from_std_str(&text));
+                value_item.set_data_2a(&QVariant::from_double(value), PIVOT_SORT_VALUE);
+                add_to_q_list_safe(row.as_mut_ptr(), value_item.into_ptr());
+            }
+
+            self.pivot_model.append_row_q_list_of_q_standard_item(&row);
+        }
+
+        // Keep the order the user chose for the pivot, if any.
+        let header = self.pivot_view.horizontal_header();
+        if header.sort_indicator_section() >= 0 && header.sort_indicator_section() < self.pivot_model.column_count_0a() {
+            self.pivot_model.sort_2a(header.sort_indicator_section(), header.sort_indicator_order());
+        }
+        self.pivot_view.resize_columns_to_contents();
+    }
+
+    /// This function returns the name of the table, used as key for the local patch of the schema, if the table is a PackedFile.
+    fn get_table_name(&self) -> Option<String> {
+        self.packed_file_path.as_ref().and_then(|path| get_path_within_packfile(&path.read().unwrap()).get(1).cloned())
+    }
+
+    /// This function returns the unit of the provided column, if it's a numeric column and it has one in the local patch of the schema.
+    pub fn get_column_unit(&self, column: i32) -> Option<FieldUnit> {
+        let table_name = self.get_table_name()?;
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        let field = fields.get(column as usize)?;
+        match field.get_ref_field_type() {
+            FieldType::F32 | FieldType::I16 | FieldType::I32 | FieldType::I64 => SCHEMA_PATCH.read().unwrap().get_unit(&table_name, field.get_name()),
+            _ => None,
+        }
+    }
+
+    /// This function asks the user for the unit of the numeric column of the current cell, and saves it to the local patch of the schema.
+    pub unsafe fn set_column_unit(&mut self) {
+        let current_index = self.table_view_primary.current_index();
+        let table_name = match self.get_table_name() {
+            Some(table_name) if current_index.is_valid() => table_name,
+            _ => return,
+        };
+
+        let column = self.table_filter.map_to_source(&current_index).column();
+        let field = match self.get_ref_table_definition().get_fields_processed().get(column as usize) {
+            Some(field) => field.clone(),
+            None => return,
+        };
+
+        match field.get_ref_field_type() {
+            FieldType::F32 | FieldType::I16 | FieldType::I32 | FieldType::I64 => {},
+            _ => return show_dialog(self.table_view_primary, tr("column_unit_not_numeric"), false),
+        }
+
+        if let Some(unit) = self.create_column_unit_dialog(&field, self.get_column_unit(column)) {
+            CENTRAL_COMMAND.send_message_qt(Command::SetColumnUnit((table_name.to_owned(), field.get_name().to_owned(), unit)));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+
+            // The view may have been closed while we were waiting for the response.
+            if !self.is_alive() { return; }
+
+            match response {
+                Response::Success => {
+                    let mut header_item = self.table_model.horizontal_header_item(column);
+                    header_item.set_text(&QString::from_std_str(&get_column_header_text(&field, Some(&table_name))));
+                }
+                Response::Error(error) => show_dialog(self.table_view_primary, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
+    /// This function creates the dialog to choose the unit of a column. It returns the chosen unit, `Some(None)` to remove it, or None if the dialog was cancelled.
+    unsafe fn create_column_unit_dialog(&self, field: &Field, current_unit: Option<FieldUnit>) -> Option<Option<FieldUnit>> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&QString::from_std_str(&tre("column_unit_title", &[&clean_column_names(field.get_name())])));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 50);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut instructions_label = QLabel::from_q_string(&qtr("column_unit_instructions"));
+        instructions_label.set_word_wrap(true);
+        let mut unit_combobox = QComboBox::new_0a();
+        unit_combobox.add_item_q_string(&qtr("column_unit_none"));
+        for unit in &FIELD_UNITS {
+            unit_combobox.add_item_q_string(&QString::from_std_str(&unit.to_string()));
+        }
+
+        if let Some(current_unit) = current_unit {
+            if let Some(index) = FIELD_UNITS.iter().position(|unit| *unit == current_unit) {
+                unit_combobox.set_current_index(index as i32 + 1);
+            }
+        }
+
+        let mut accept_button = QPushButton::from_q_string(&qtr("column_unit_accept"));
+        main_grid.add_widget_5a(&mut instructions_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&mut unit_combobox, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 1, 1, 1, 1);
+        accept_button.released().connect(dialog.slot_accept());
+
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
+            let index = unit_combobox.current_index();
+            Some(if index > 0 { FIELD_UNITS.get(index as usize - 1).copied() } else { None })
+        } else { None }
+    }
+
+    /// This function returns the name of the table in the schema, if the table is a DB or Loc PackedFile.
+    fn get_schema_table_name(&self) -> Option<String> {
+        match *self.packed_file_type {
+            PackedFileType::DB => self.get_table_name(),
+            PackedFileType::Loc if self.packed_file_path.is_some() => Some("loc".to_owned()),
+            _ => None,
+        }
+    }
+
+    /// This function asks the user for the description of the column of the current cell, and saves it to the schema.
+    pub unsafe fn edit_column_description(&mut self) {
+        let current_index = self.table_view_primary.current_index();
+        let table_name = match self.get_schema_table_name() {
+            Some(table_name) if current_index.is_valid() => table_name,
+            _ => return,
+        };
+
+        let column = self.table_filter.map_to_source(&current_index).column();
+        let (field, version) = {
+            let definition = self.get_ref_table_definition();
+            match definition.get_fields_processed().get(column as usize) {
+                Some(field) => (field.clone(), definition.get_version()),
+                None => return,
+            }
+        };
+
+        if let Some(description) = self.create_column_description_dialog(&field) {
+            if description == field.get_description() { return; }
+
+            CENTRAL_COMMAND.send_message_qt(Command::SetFieldDescription((table_name, version, field.get_name().to_owned(), description.to_owned())));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+
+            // The view may have been closed while we were waiting for the response.
+            if !self.is_alive() { return; }
+
+            match response {
+                Response::Success => {
+                    if let Some(field) = self.table_definition.write().unwrap().get_ref_mut_fields().iter_mut().find(|x| x.get_name() == field.get_name()) {
+                        field.set_description(&description);
+                    }
+                    self.update_column_headers();
+                }
+                Response::Error(error) => show_dialog(self.table_view_primary, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
+    /// This function creates the dialog to edit the description of a column. It returns the new description, or None if the dialog was cancelled.
+    unsafe fn create_column_description_dialog(&self, field: &Field) -> Option<String> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&QString::from_std_str(&tre("column_description_title", &[&clean_column_names(field.get_name())])));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 250);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut instructions_label = QLabel::from_q_string(&qtr("column_description_instructions"));
+        instructions_label.set_word_wrap(true);
+        let mut text_edit = QPlainTextEdit::from_q_string(&QString::from_std_str(field.get_description()));
+        text_edit.set_line_wrap_mode(LineWrapMode::WidgetWidth);
+        let mut accept_button = QPushButton::from_q_string(&qtr("column_description_accept"));
+        let mut cancel_button = QPushButton::from_q_string(&qtr("column_description_cancel"));
+
+        main_grid.add_widget_5a(&mut instructions_label, 0, 0, 1, 3);
+        main_grid.add_widget_5a(&mut text_edit, 1, 0, 1, 3);
+        main_grid.add_widget_5a(&mut accept_button, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&mut cancel_button, 2, 2, 1, 1);
+        main_grid.set_column_stretch(0, 10);
+
+        accept_button.released().connect(dialog.slot_accept());
+        cancel_button.released().connect(dialog.slot_reject());
+
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
+
+            // Descriptions are shown in a single line in tooltips and in the schema editor, so we don't keep line breaks.
+            Some(text_edit.to_plain_text().to_std_string().split_whitespace().collect::<Vec<&str>>().join(" "))
+        } else { None }
+    }
+
+    /// This function shows or hides the descriptions of the columns in their headers, and remembers it for the next tables we open.
+    pub unsafe fn toggle_column_descriptions(&mut self, enable: bool) {
+        save_setting_bool("tables_show_column_descriptions", enable);
+        self.update_column_headers();
+    }
+
+    /// This function zooms the table in or out by the provided amount of points, or resets its zoom if no amount is provided.
+    ///
+    /// The zoom level is shared by all tables, so the ones opened later use it too.
+    pub unsafe fn zoom(&mut self, amount: Option<i32>) {
+        let level = change_zoom_level(ZOOM_TABLE, amount);
+        set_zoom(self.table_view_primary, self.table_view_frozen, level);
+    }
+
+    /// This function updates the text and tooltips of the headers of the table, without touching their order or sizes.
+    pub unsafe fn update_column_headers(&mut self) {
+        let table_name = self.get_table_name();
+        let schema = SCHEMA.read().unwrap();
+        for (index, field) in self.get_ref_table_definition().get_fields_processed().iter().enumerate() {
+            let mut header_item = self.table_model.horizontal_header_item(index as i32);
+            if header_item.is_null() { continue; }
+
+            header_item.set_text(&QString::from_std_str(&get_column_header_text(field, table_name.as_ref())));
+            set_column_tooltip(&schema, field, table_name.as_ref(), &mut header_item);
+        }
+    }
+
+    /// This function returns the field of the provided column, if it's a text column.
+    fn get_text_field(&self, column: i32) -> Option<Field> {
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        let field = fields.get(column as usize)?;
+        match field.get_ref_field_type() {
+            FieldType::StringU8 | FieldType::StringU16 | FieldType::OptionalStringU8 | FieldType::OptionalStringU16 => Some(field.clone()),
+            _ => None,
+        }
+    }
+
+    /// This function opens the text of the current cell in a multi-line editor, and saves it back to the cell if the editor is accepted.
+    ///
+    /// The change goes through the normal edit path, so it can be undone like any other edit.
+    pub unsafe fn edit_in_popup(&mut self) {
+        if self.is_read_only() { return; }
+
+        let current_index = self.table_view_primary.current_index();
+        if !current_index.is_valid() { return; }
+
+        let source_index = self.table_filter.map_to_source(&current_index);
+        let field = match self.get_text_field(source_index.column()) {
+            Some(field) => field,
+            None => return,
+        };
+
+        let mut item = self.table_model.item_from_index(&source_index);
+        let current_text = item.text().to_std_string();
+        if let Some(text) = self.create_edit_in_popup_dialog(&field, &current_text) {
+            if text != current_text {
+                item.set_text(&QString::from_std_str(&text));
+            }
+        }
+    }
+
+    /// This function creates the multi-line editor for text cells. It returns the edited text, or None if the dialog was cancelled.
+    ///
+    /// If the field has a max length, the dialog can only be accepted while the text fits in it.
+    unsafe fn create_edit_in_popup_dialog(&self, field: &Field, text: &str) -> Option<String> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&QString::from_std_str(&tre("edit_in_popup_title", &[&clean_column_names(field.get_name())])));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 400);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut text_edit = QPlainTextEdit::from_q_string(&QString::from_std_str(text));
+        text_edit.set_line_wrap_mode(LineWrapMode::WidgetWidth);
+        let mut count_label = QLabel::new();
+        let mut accept_button = QPushButton::from_q_string(&qtr("edit_in_popup_accept"));
+        let mut cancel_button = QPushButton::from_q_string(&qtr("edit_in_popup_cancel"));
+
+        main_grid.add_widget_5a(&mut text_edit, 0, 0, 1, 3);
+        main_grid.add_widget_5a(&mut count_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&mut accept_button, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&mut cancel_button, 1, 2, 1, 1);
+        main_grid.set_column_stretch(0, 10);
+
+        // Keep the character count updated while typing.
+        let max_length = field.get_max_length();
+        let text_edit_ptr = text_edit.as_mut_ptr();
+        let mut count_label_ptr = count_label.as_mut_ptr();
+        let mut accept_button_ptr = accept_button.as_mut_ptr();
+        let mut update_count = move || {
+            let length = text_edit_ptr.to_plain_text().to_std_string().chars().count();
+            if max_length > 0 {
+                count_label_ptr.set_text(&QString::from_std_str(&tre("edit_in_popup_count_max", &[&length.to_string(), &max_length.to_string()])));
+                accept_button_ptr.set_enabled(length <= max_length as usize);
+            } else {
+                count_label_ptr.set_text(&QString::from_std_str(&tre("edit_in_popup_count", &[&length.to_string()])));
+            }
+        };
+        update_count();
+        let slot_update_count = Slot::new(update_count);
+
+        text_edit.text_changed().connect(&slot_update_count);
+        accept_button.released().connect(dialog.slot_accept());
+        cancel_button.released().connect(dialog.slot_reject());
+
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
+            Some(text_edit.to_plain_text().to_std_string())
+        } else { None }
+    }
+
+    /// This function syncs the size, order, visibility and scroll of the columns of the summary row with the ones of the table.
+    pub unsafe fn update_summary_row_layout(&mut self) {
+        if !self.summary_view.is_visible() {
+            return;
+        }
+
+        let header = self.table_view_primary.horizontal_header();
+        let mut summary_header = self.summary_view.horizontal_header();
+        for visual_index in 0..header.count() {
+            let logical_index = header.logical_index(visual_index);
+            let summary_visual_index = summary_header.visual_index(logical_index);
+            if summary_visual_index != visual_index {
+                summary_header.move_section(summary_visual_index, visual_index);
+            }
+
+            self.summary_view.set_column_hidden(logical_index, header.is_section_hidden(logical_index));
+            self.summary_view.set_column_width(logical_index, header.section_size(logical_index));
+        }
+
+        self.summary_view.vertical_header().set_fixed_width(self.table_view_primary.vertical_header().width());
+        self.summary_view.horizontal_scroll_bar().set_value(self.table_view_primary.horizontal_scroll_bar().value());
+    }
+
+    /// This function shows the context menu of the summary row, and changes the aggregate of the column under the cursor to the chosen one.
+    pub unsafe fn summary_row_context_menu(&mut self, column: i32) {
+        if column < 0 {
+            return;
+        }
+
+        let action = self.summary_context_menu.exec_1a_mut(&QCursor::pos_0a());
+        if action.is_null() || !self.is_alive() {
+            return;
+        }
+
+        let action = action.as_raw_ptr();
+        let aggregate = if action == self.summary_context_menu_sum.as_raw_ptr() { SummaryAggregate::Sum }
+            else if action == self.summary_context_menu_average.as_raw_ptr() { SummaryAggregate::Average }
+            else if action == self.summary_context_menu_count.as_raw_ptr() { SummaryAggregate::Count }
+            else { SummaryAggregate::None };
+
+        self.summary_aggregates.write().unwrap().insert(column, aggregate);
+        self.update_summary_row();
+    }
+
+    /// This function is used to append new rows to a table.
+    ///
+    /// If clone = true, the appended rows are copies of the selected ones.
+    pub unsafe fn append_rows(&mut self, clone: bool) {
+
+        // Get the indexes ready for battle.
+        let selection = self.table_view_primary.selection_model().selection();
+        let indexes = self.table_filter.map_selection_to_source(&selection).indexes();
+        let mut indexes_sorted = (0..indexes.count_0a()).map(|x| indexes.at(x)).collect::<Vec<Ref<QModelIndex>>>();
+        sort_indexes_by_model(&mut indexes_sorted);
+        dedup_indexes_per_row(&mut indexes_sorted);
+        let mut row_numbers = vec![];
+
+        let rows = if clone {
+            let mut rows = vec![];
+            let color = get_color_added_modified();
+            for index in indexes_sorted.iter() {
+                row_numbers.push(index.row());
+
+                let columns = self.table_model.column_count_0a();
+                let mut qlist = QListOfQStandardItem::new();
+                for column in 0..columns {
+                    let original_item = self.table_model.item_2a(index.row(), column);
+                    let mut item = (*original_item).clone();
+                    item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+                    add_to_q_list_safe(qlist.as_mut_ptr(), item);
+                }
+
+                rows.push(qlist);
+            }
+            rows
+        } else {
+            let color = get_color_added();
+            let mut row = get_new_row(&self.get_ref_table_definition());
+            for index in 0..row.count() {
+                row.index(index).as_mut().unwrap().set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+            }
+            vec![row]
+        };
+
+        let mut selection_model = self.table_view_primary.selection_model();
+        selection_model.clear();
+        for row in &rows {
+            self.table_model.append_row_q_list_of_q_standard_item(row.as_ref());
+
+            // Select the row and scroll to it.
+            let model_index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(self.table_filter.row_count_0a() - 1, 0));
+            if model_index_filtered.is_valid() {
+                selection_model.select_q_model_index_q_flags_selection_flag(
+                    &model_index_filtered,
                     SelectionFlag::Select | SelectionFlag::Rows
                 );
 
@@ -1350,6 +2728,357 @@ impl TableViewRaw {
         update_undo_model(self.table_model, self.undo_model);
     }
 
+    /// This function clones the first selected row, asks for new values for its key and name fields, and inserts it under the original one.
+    ///
+    /// It returns true if the row was added.
+    pub unsafe fn clone_and_edit_row(&mut self) -> bool {
+        let selection = self.table_view_primary.selection_model().selection();
+        let indexes = self.table_filter.map_selection_to_source(&selection).indexes();
+        let mut indexes_sorted = (0..indexes.count_0a()).map(|x| indexes.at(x)).collect::<Vec<Ref<QModelIndex>>>();
+        sort_indexes_by_model(&mut indexes_sorted);
+        let row = match indexes_sorted.first() {
+            Some(index) => index.row(),
+            None => return false,
+        };
+
+        // Booleans are not worth asking for, so they're always cloned as they are.
+        let fields = self.get_ref_table_definition().get_fields_processed();
+        let columns = fields.iter().enumerate()
+            .filter(|(_, field)| field.get_is_key() || field.get_name() == "name" || field.get_name().ends_with("_name"))
+            .filter(|(_, field)| field.get_ref_field_type() != &FieldType::Boolean)
+            .map(|(column, field)| (column as i32, field.get_name().to_owned(), self.table_model.item_2a(row, column as i32).text().to_std_string()))
+            .collect::<Vec<(i32, String, String)>>();
+
+        let new_values = match self.create_clone_and_edit_dialog(&columns) {
+            Some(new_values) => new_values,
+            None => return false,
+        };
+
+        let color = get_color_added_modified();
+        let mut qlist = QListOfQStandardItem::new();
+        for column in 0..self.table_model.column_count_0a() {
+            let original_item = self.table_model.item_2a(row, column);
+            let mut item = (*original_item).clone();
+            item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+
+            // The new row is not yet in the model, so we can edit its items without triggering an edition in the undo history.
+            if let Some((_, text)) = new_values.iter().find(|(new_column, _)| *new_column == column) {
+                match fields[column as usize].get_ref_field_type() {
+                    FieldType::F32 => if let Ok(value) = text.parse::<f32>() { item.set_data_2a(&QVariant::from_float(value), 2); },
+                    FieldType::I16 => if let Ok(value) = text.parse::<i16>() { item.set_data_2a(&QVariant::from_int(value.into()), 2); },
+                    FieldType::I32 => if let Ok(value) = text.parse::<i32>() { item.set_data_2a(&QVariant::from_int(value), 2); },
+                    FieldType::I64 => if let Ok(value) = text.parse::<i64>() { item.set_data_2a(&QVariant::from_i64(value), 2); },
+                    _ => item.set_text(&QString::from_std_str(text)),
+                }
+            }
+
+            add_to_q_list_safe(qlist.as_mut_ptr(), item);
+        }
+
+        self.table_model.insert_row_int_q_list_of_q_standard_item(row + 1, &qlist);
+
+        // Select the new row and scroll to it.
+        let mut selection_model = self.table_view_primary.selection_model();
+        selection_model.clear();
+        let model_index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(row + 1, 0));
+        if model_index_filtered.is_valid() {
+            selection_model.select_q_model_index_q_flags_selection_flag(
+                &model_index_filtered,
+                SelectionFlag::Select | SelectionFlag::Rows
+            );
+
+            self.table_view_primary.scroll_to_2a(
+                model_index_filtered.as_ref(),
+                ScrollHint::EnsureVisible
+            );
+        }
+
+        self.history_undo.write().unwrap().push(TableOperations::AddRows(vec![row + 1]));
+        self.history_redo.write().unwrap().clear();
+        update_undo_model(self.table_model, self.undo_model);
+        true
+    }
+
+    /// This function generates the missing plural/gender variant keys of the selected entries of a Loc PackedFile.
+    ///
+    /// The suffixes used are the ones in the settings. New variants are copies of their base entry, and they're inserted
+    /// right under the base entry and its already existing variants, so the entire group is kept together.
+    pub unsafe fn generate_loc_variants(&mut self) {
+        if *self.packed_file_type != PackedFileType::Loc { return; }
+
+        let suffixes = SETTINGS.read().unwrap().settings_string["loc_variant_suffixes"].split(',')
+            .map(|x| x.trim().to_owned())
+            .filter(|x| !x.is_empty())
+            .collect::<Vec<String>>();
+        if suffixes.is_empty() { return; }
+
+        let key_column = match self.get_ref_table_definition().get_fields_processed().iter().position(|x| x.get_name() == "key") {
+            Some(column) => column as i32,
+            None => return,
+        };
+
+        // Get the indexes ready for battle.
+        let selection = self.table_view_primary.selection_model().selection();
+        let indexes = self.table_filter.map_selection_to_source(&selection).indexes();
+        let mut indexes_sorted = (0..indexes.count_0a()).map(|x| indexes.at(x)).collect::<Vec<Ref<QModelIndex>>>();
+        sort_indexes_by_model(&mut indexes_sorted);
+        dedup_indexes_per_row(&mut indexes_sorted);
+
+        // If a variant is selected instead of the base entry, we use the base entry it belongs to.
+        let mut base_keys: Vec<String> = vec![];
+        for index in &indexes_sorted {
+            let key = self.table_model.item_2a(index.row(), key_column).text().to_std_string();
+            let base_key = match suffixes.iter().filter(|x| key.ends_with(x.as_str()) && key.len() > x.len()).max_by_key(|x| x.len()) {
+                Some(suffix) => key[..key.len() - suffix.len()].to_owned(),
+                None => key,
+            };
+
+            if !base_keys.contains(&base_key) {
+                base_keys.push(base_key);
+            }
+        }
+
+        let mut keys = (0..self.table_model.row_count_0a())
+            .map(|row| self.table_model.item_2a(row, key_column).text().to_std_string())
+            .collect::<HashSet<String>>();
+
+        let mut selection_model = self.table_view_primary.selection_model();
+        selection_model.clear();
+
+        let color = get_color_added();
+        let mut row_numbers: Vec<i32> = vec![];
+        for base_key in &base_keys {
+            let row_count = self.table_model.row_count_0a();
+            let base_row = match (0..row_count).find(|row| &self.table_model.item_2a(*row, key_column).text().to_std_string() == base_key) {
+                Some(row) => row,
+                None => continue,
+            };
+
+            // Skip the variants already under the base entry, so the new ones go after them.
+            let mut insert_row = base_row + 1;
+            while insert_row < row_count {
+                let key = self.table_model.item_2a(insert_row, key_column).text().to_std_string();
+                if suffixes.iter().any(|suffix| key == format!("{}{}", base_key, suffix)) { insert_row += 1; }
+                else { break; }
+            }
+
+            for suffix in &suffixes {
+                let variant_key = format!("{}{}", base_key, suffix);
+                if keys.contains(&variant_key) { continue; }
+
+                let mut qlist = QListOfQStandardItem::new();
+                for column in 0..self.table_model.column_count_0a() {
+                    let original_item = self.table_model.item_2a(base_row, column);
+                    let mut item = (*original_item).clone();
+                    if column == key_column {
+                        item.set_text(&QString::from_std_str(&variant_key));
+                    }
+                    item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+                    add_to_q_list_safe(qlist.as_mut_ptr(), item);
+                }
+                self.table_model.insert_row_int_q_list_of_q_standard_item(insert_row, &qlist);
+
+                // Rows we already added under this one are pushed down by the new row.
+                row_numbers.iter_mut().filter(|x| **x >= insert_row).for_each(|x| *x += 1);
+                row_numbers.push(insert_row);
+                keys.insert(variant_key);
+
+                let model_index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(insert_row, 0));
+                if model_index_filtered.is_valid() {
+                    selection_model.select_q_model_index_q_flags_selection_flag(
+                        &model_index_filtered,
+                        SelectionFlag::Select | SelectionFlag::Rows
+                    );
+                }
+
+                insert_row += 1;
+            }
+        }
+
+        if row_numbers.is_empty() {
+            log_to_status_bar(&tr("generate_loc_variants_none"));
+            return;
+        }
+
+        // The undo mode needs this reversed.
+        row_numbers.sort_by(|x, y| y.cmp(x));
+        self.history_undo.write().unwrap().push(TableOperations::AddRows(row_numbers));
+        self.history_redo.write().unwrap().clear();
+        update_undo_model(self.table_model, self.undo_model);
+    }
+
+    /// This function exports the rows and columns currently visible in the table to a TSV or JSON file.
+    ///
+    /// Rows filtered out and hidden columns are not exported. Rows and columns are exported in the order they're shown.
+    /// TSV exports of only part of the table get no table name/version line, so they cannot be imported back by mistake.
+    pub unsafe fn export_visible_rows(&self, path: &Path, as_json: bool) -> Result<()> {
+        let definition = self.get_ref_table_definition();
+        let fields = definition.get_fields_processed();
+
+        let header = self.table_view_primary.horizontal_header();
+        let columns = (0..header.count())
+            .map(|visual_index| header.logical_index(visual_index))
+            .filter(|column| !self.table_view_primary.is_column_hidden(*column))
+            .collect::<Vec<i32>>();
+
+        let rows = (0..self.table_filter.row_count_0a())
+            .map(|row| self.table_filter.map_to_source(&self.table_filter.index_2a(row, 0)).row())
+            .collect::<Vec<i32>>();
+
+        if as_json {
+            let entries = rows.iter().map(|row| {
+                let mut entry = serde_json::Map::new();
+                for column in &columns {
+                    let item = self.table_model.item_2a(*row, *column);
+                    let value = match fields[*column as usize].get_ref_field_type() {
+                        FieldType::Boolean => serde_json::Value::from(item.check_state() == CheckState::Checked),
+                        FieldType::F32 => serde_json::Value::from(item.data_1a(2).to_float_0a()),
+                        FieldType::I16 | FieldType::I32 => serde_json::Value::from(item.data_1a(2).to_int_0a()),
+                        FieldType::I64 => serde_json::Value::from(item.data_1a(2).to_long_long_0a()),
+                        FieldType::SequenceU16(_) | FieldType::SequenceU32(_) => serde_json::from_str(&item.data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string())?,
+                        _ => serde_json::Value::from(item.text().to_std_string()),
+                    };
+                    entry.insert(fields[*column as usize].get_name().to_owned(), value);
+                }
+                Ok(serde_json::Value::Object(entry))
+            }).collect::<Result<Vec<serde_json::Value>>>()?;
+
+            let mut file = BufWriter::new(File::create(path)?);
+            file.write_all(serde_json::to_string_pretty(&entries)?.as_bytes())?;
+        }
+
+        else {
+
+            // Same writer config as the normal TSV export, so other programs don't break the file on save.
+            let mut writer = WriterBuilder::new()
+                .delimiter(b'\t')
+                .quote_style(QuoteStyle::Never)
+                .has_headers(false)
+                .flexible(true)
+                .from_path(path)?;
+
+            // Only write the table name and version if we're exporting the full table. Otherwise, the file
+            // would be importable, and importing it would fail or, worse, replace the table with part of it.
+            let is_full_export = rows.len() as i32 == self.table_model.row_count_0a() &&
+                columns.iter().enumerate().all(|(index, column)| index as i32 == *column) &&
+                columns.len() == fields.len();
+
+            if is_full_export {
+                writer.serialize((self.get_table_type_name(), definition.get_version()))?;
+            }
+            writer.serialize(columns.iter().map(|column| fields[*column as usize].get_name()).collect::<Vec<&str>>())?;
+
+            for row in &rows {
+                let record = columns.iter().map(|column| {
+                    let item = self.table_model.item_2a(*row, *column);
+                    match fields[*column as usize].get_ref_field_type() {
+                        FieldType::Boolean => (item.check_state() == CheckState::Checked).to_string(),
+                        FieldType::SequenceU16(_) | FieldType::SequenceU32(_) => item.data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string(),
+                        _ => item.text().to_std_string(),
+                    }
+                }).collect::<Vec<String>>();
+                writer.serialize(record)?;
+            }
+
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the name of the type of table we have open, as used in the first line of the TSV files.
+    pub fn get_table_type_name(&self) -> String {
+        match *self.packed_file_type {
+            PackedFileType::DB => self.packed_file_path.as_ref().and_then(|x| get_path_within_packfile(&x.read().unwrap()).get(1).cloned()).unwrap_or_default(),
+            PackedFileType::Loc => rpfm_lib::packedfile::table::loc::TSV_NAME_LOC.to_owned(),
+            _ => self.packed_file_type.to_string(),
+        }
+    }
+
+    /// This function returns the name of the table we have open, if it's a DB Table.
+    fn get_table_type_name_if_db(&self) -> Option<String> {
+        if let PackedFileType::DB = *self.packed_file_type { Some(self.get_table_type_name()) } else { None }
+    }
+
+    /// This function copies the selected rows into the row clipboard, so they can be pasted in the same table of another PackFile.
+    ///
+    /// It returns the amount of rows copied.
+    pub unsafe fn copy_rows_to_row_clipboard(&self) -> Result<usize> {
+        let selection = self.table_view_primary.selection_model().selection();
+        let indexes = self.table_filter.map_selection_to_source(&selection).indexes();
+        let mut indexes_sorted = (0..indexes.count_0a()).map(|x| indexes.at(x)).collect::<Vec<Ref<QModelIndex>>>();
+        sort_indexes_by_model(&mut indexes_sorted);
+        dedup_indexes_per_row(&mut indexes_sorted);
+
+        let definition = self.get_ref_table_definition();
+        let fields = definition.get_fields_processed();
+        let rows = indexes_sorted.iter()
+            .filter(|index| index.is_valid())
+            .map(|index| get_decoded_row_from_view(self.table_model, &fields, index.row()))
+            .collect::<Vec<Vec<DecodedData>>>();
+
+        let clipboard = RowClipboard::new(&self.get_table_type_name(), &definition, rows);
+        clipboard.save()?;
+        Ok(clipboard.get_row_count())
+    }
+
+    /// This function pastes the rows in the row clipboard at the end of the table.
+    ///
+    /// Columns are matched by name, so it doesn't matter if the rows come from another version of the table.
+    /// Columns not present in the clipboard get their default value. It returns the amount of rows pasted.
+    pub unsafe fn paste_rows_from_row_clipboard(&mut self) -> Result<usize> {
+        let clipboard = RowClipboard::load()?;
+        let rows = {
+            let definition = self.get_ref_table_definition();
+            let fields = definition.get_fields_processed();
+            let rows = clipboard.get_rows_for_definition(&self.get_table_type_name(), &definition)?;
+
+            let color = get_color_added();
+            rows.iter().map(|row| {
+                let mut qlist = QListOfQStandardItem::new();
+                for (field, data) in fields.iter().zip(row.iter()) {
+                    let mut item = match data {
+                        Some(data) => get_item_from_decoded_data(data),
+                        None => get_default_item_from_field(field),
+                    };
+                    item.set_background(&QBrush::from_q_color(color.as_ref().unwrap()));
+                    add_to_q_list_safe(qlist.as_mut_ptr(), item.into_ptr());
+                }
+                qlist
+            }).collect::<Vec<CppBox<QListOfQStandardItem>>>()
+        };
+
+        if rows.is_empty() {
+            return Ok(0);
+        }
+
+        let mut selection_model = self.table_view_primary.selection_model();
+        selection_model.clear();
+        for row in &rows {
+            self.table_model.append_row_q_list_of_q_standard_item(row.as_ref());
+
+            let model_index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(self.table_model.row_count_0a() - 1, 0));
+            if model_index_filtered.is_valid() {
+                selection_model.select_q_model_index_q_flags_selection_flag(
+                    &model_index_filtered,
+                    SelectionFlag::Select | SelectionFlag::Rows
+                );
+
+                self.table_view_primary.scroll_to_2a(
+                    model_index_filtered.as_ref(),
+                    ScrollHint::EnsureVisible
+                );
+            }
+        }
+
+        let total_rows = self.table_model.row_count_0a();
+        let range = (total_rows - rows.len() as i32..total_rows).collect::<Vec<i32>>();
+        self.history_undo.write().unwrap().push(TableOperations::AddRows(range));
+        self.history_redo.write().unwrap().clear();
+        update_undo_model(self.table_model, self.undo_model);
+        Ok(rows.len())
+    }
+
     /// This function returns a copy of the entire model.
     pub unsafe fn get_copy_of_table(&self) -> Vec<AtomicPtr<QListOfQStandardItem>> {
         let mut old_data = vec![];
@@ -1392,12 +3121,51 @@ impl TableViewRaw {
 
         accept_button.released().connect(dialog.slot_accept());
 
-        if dialog.exec() == 1 {
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
             let new_text = rewrite_sequence_line_edit.text().to_std_string();
             if new_text.is_empty() { None } else { Some((is_math_op.is_checked(), rewrite_sequence_line_edit.text().to_std_string())) }
         } else { None }
     }
 
+    /// This function creates the "Clone and Edit" dialog, with a field for each of the provided columns (column, name, current value).
+    ///
+    /// It returns the new values of the columns, or None if the dialog was cancelled.
+    pub unsafe fn create_clone_and_edit_dialog(&self, columns: &[(i32, String, String)]) -> Option<Vec<(i32, String)>> {
+
+        // Create and configure the dialog.
+        let mut dialog = QDialog::new_1a(self.table_view_primary);
+        dialog.set_window_title(&qtr("clone_and_edit_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 50);
+        let mut main_grid = create_grid_layout(dialog.as_mut_ptr().static_upcast_mut());
+
+        let mut line_edits = vec![];
+        for (index, (column, name, value)) in columns.iter().enumerate() {
+            let mut label = QLabel::from_q_string(&QString::from_std_str(&clean_column_names(name)));
+            let mut line_edit = QLineEdit::from_q_string(&QString::from_std_str(value));
+            main_grid.add_widget_5a(&mut label, index as i32, 0, 1, 1);
+            main_grid.add_widget_5a(&mut line_edit, index as i32, 1, 1, 1);
+            label.into_ptr();
+            line_edits.push((*column, line_edit.into_ptr()));
+        }
+
+        // Preselect the first field, as it's usually the key we want to change.
+        if let Some((_, line_edit)) = line_edits.first_mut() {
+            line_edit.select_all();
+        }
+
+        let mut accept_button = QPushButton::from_q_string(&qtr("clone_and_edit_accept"));
+        main_grid.add_widget_5a(&mut accept_button, columns.len() as i32, 0, 1, 2);
+        accept_button.set_default(true);
+        accept_button.released().connect(dialog.slot_accept());
+
+        // The view may have been closed while the dialog was open.
+        if dialog.exec() == 1 && self.is_alive() {
+            Some(line_edits.iter().map(|(column, line_edit)| (*column, line_edit.text().to_std_string())).collect())
+        } else { None }
+    }
+
     /// This function takes care of the "Smart Delete" feature for tables.
     pub unsafe fn smart_delete(&mut self) {
 
@@ -1524,3 +3292,34 @@ impl TableViewRaw {
         }
     }
 }
+
+/// This function returns the estimated amount of memory, in bytes, the items stored in the provided operation use.
+unsafe fn get_operation_size(operation: &TableOperations) -> usize {
+    match operation {
+        TableOperations::Editing(editions) => editions.len() * HISTORY_ESTIMATED_ITEM_SIZE,
+        TableOperations::AddRows(_) | TableOperations::MoveRows(_) => 0,
+        TableOperations::RemoveRows(rows) => rows.iter().map(|(_, row_pack)| row_pack.iter().map(|row| row.len()).sum::<usize>()).sum::<usize>() * HISTORY_ESTIMATED_ITEM_SIZE,
+        TableOperations::ImportTSV(table_data) => table_data.iter().map(|row| mut_ptr_from_atomic(row).count_0a() as usize).sum::<usize>() * HISTORY_ESTIMATED_ITEM_SIZE,
+        TableOperations::Carolina(operations) => operations.iter().map(|operation| get_operation_size(operation)).sum(),
+    }
+}
+
+/// This function deletes the items stored in an operation removed from the undo history.
+///
+/// Items in the undo history are not in any model, so they're only deleted here. Rows of ImportTSV operations
+/// are kept in lists whose items we cannot delete one by one, so we leave those alone.
+unsafe fn delete_operation_items(operation: TableOperations) {
+    match operation {
+        TableOperations::Editing(editions) => editions.iter().for_each(|(_, item)| delete_item(item)),
+        TableOperations::RemoveRows(rows) => rows.iter().for_each(|(_, row_pack)| row_pack.iter().flatten().for_each(|item| delete_item(item))),
+        TableOperations::AddRows(_) | TableOperations::ImportTSV(_) | TableOperations::MoveRows(_) => {},
+        TableOperations::Carolina(operations) => operations.into_iter().for_each(|operation| delete_operation_items(operation)),
+    }
+}
+
+/// This function deletes the item the provided pointer points to.
+unsafe fn delete_item(item: &AtomicPtr<QStandardItem>) {
+    if let Some(item) = CppBox::from_raw(item.load(Ordering::SeqCst)) {
+        drop(item);
+    }
+}
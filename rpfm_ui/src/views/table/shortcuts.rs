@@ -27,65 +27,119 @@ pub unsafe fn set_shortcuts(ui: &mut TableView) {
     let shortcuts = UI_STATE.get_shortcuts_no_lock();
 
     // Set the shortcuts for these actions.
-    ui.get_mut_ptr_context_menu_add_rows().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["add_row"])));
-    ui.get_mut_ptr_context_menu_insert_rows().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["insert_row"])));
-    ui.get_mut_ptr_context_menu_delete_rows().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["delete_row"])));
-    ui.get_mut_ptr_context_menu_clone_and_insert().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["clone_and_insert_row"])));
-    ui.get_mut_ptr_context_menu_clone_and_append().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["clone_and_append_row"])));
-    ui.get_mut_ptr_context_menu_copy().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["copy"])));
-    ui.get_mut_ptr_context_menu_copy_as_lua_table().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["copy_as_lua_table"])));
-    ui.get_mut_ptr_context_menu_paste().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["paste"])));
-    ui.get_mut_ptr_context_menu_rewrite_selection().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["rewrite_selection"])));
-    ui.get_mut_ptr_context_menu_invert_selection().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["selection_invert"])));
-    ui.get_mut_ptr_context_menu_reset_selection().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["revert_selection"])));
-    ui.get_mut_ptr_context_menu_resize_columns().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["resize_columns"])));
-    ui.get_mut_ptr_context_menu_search().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["search"])));
-    ui.get_mut_ptr_context_menu_sidebar().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["sidebar"])));
-    ui.get_mut_ptr_context_menu_import_tsv().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["import_tsv"])));
-    ui.get_mut_ptr_context_menu_export_tsv().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["export_tsv"])));
-    ui.get_mut_ptr_smart_delete().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["smart_delete"])));
-    ui.get_mut_ptr_context_menu_undo().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["undo"])));
-    ui.get_mut_ptr_context_menu_redo().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["redo"])));
+    ui.context_menu_add_rows.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["add_row"])));
+    ui.context_menu_insert_rows.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["insert_row"])));
+    ui.context_menu_delete_rows.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["delete_row"])));
+    ui.context_menu_move_rows_up.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["move_rows_up"])));
+    ui.context_menu_move_rows_down.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["move_rows_down"])));
+    ui.context_menu_clone_and_insert.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["clone_and_insert_row"])));
+    ui.context_menu_clone_and_edit.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["clone_and_edit_row"])));
+    ui.context_menu_clone_and_append.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["clone_and_append_row"])));
+    ui.context_menu_copy.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["copy"])));
+    ui.context_menu_copy_as_lua_table.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["copy_as_lua_table"])));
+    ui.context_menu_copy_to_row_clipboard.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["copy_to_row_clipboard"])));
+    ui.context_menu_paste.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["paste"])));
+    ui.context_menu_paste_from_row_clipboard.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["paste_from_row_clipboard"])));
+    ui.context_menu_rewrite_selection.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["rewrite_selection"])));
+    ui.context_menu_generate_loc_variants.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["generate_loc_variants"])));
+    ui.context_menu_invert_selection.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["selection_invert"])));
+    ui.context_menu_reset_selection.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["revert_selection"])));
+    ui.context_menu_resize_columns.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["resize_columns"])));
+    ui.context_menu_search.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["search"])));
+    ui.context_menu_sidebar.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["sidebar"])));
+    ui.context_menu_history.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["history"])));
+    ui.context_menu_summary_row.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["summary_row"])));
+    ui.context_menu_column_statistics.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["column_statistics"])));
+    ui.context_menu_compare_with.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["compare_with"])));
+    ui.context_menu_set_column_unit.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["set_column_unit"])));
+    ui.context_menu_edit_in_popup.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["edit_in_popup"])));
+    ui.context_menu_word_wrap.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["word_wrap"])));
+    ui.context_menu_refresh_reference_data.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["refresh_reference_data"])));
+    ui.context_menu_import_tsv.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["import_tsv"])));
+    ui.context_menu_export_tsv.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["export_tsv"])));
+    ui.context_menu_export_visible_rows.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["export_visible_rows"])));
+    ui.smart_delete.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["smart_delete"])));
+    ui.context_menu_undo.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["undo"])));
+    ui.context_menu_redo.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["redo"])));
+    ui.context_menu_zoom_in.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["zoom_in"])));
+    ui.context_menu_zoom_out.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["zoom_out"])));
+    ui.context_menu_zoom_reset.get_unchecked().set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(&shortcuts.packed_file_table["zoom_reset"])));
 
     // Set the shortcuts to only trigger in the Table.
-    ui.get_mut_ptr_context_menu_add_rows().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_insert_rows().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_delete_rows().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_clone_and_insert().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_clone_and_append().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_copy().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_copy_as_lua_table().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_paste().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_rewrite_selection().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_invert_selection().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_reset_selection().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_search().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_sidebar().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_import_tsv().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_export_tsv().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_resize_columns().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_smart_delete().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_undo().set_shortcut_context(ShortcutContext::WidgetShortcut);
-    ui.get_mut_ptr_context_menu_redo().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_add_rows.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_insert_rows.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_delete_rows.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_move_rows_up.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_move_rows_down.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_clone_and_insert.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_clone_and_edit.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_clone_and_append.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_copy.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_copy_as_lua_table.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_copy_to_row_clipboard.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_paste.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_paste_from_row_clipboard.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_rewrite_selection.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_generate_loc_variants.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_invert_selection.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_reset_selection.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_search.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_sidebar.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_history.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_summary_row.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_column_statistics.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_compare_with.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_set_column_unit.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_edit_in_popup.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_word_wrap.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_refresh_reference_data.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_import_tsv.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_export_tsv.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_export_visible_rows.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_resize_columns.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.smart_delete.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_undo.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_redo.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_zoom_in.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_zoom_out.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
+    ui.context_menu_zoom_reset.get_unchecked().set_shortcut_context(ShortcutContext::WidgetShortcut);
 
     // Add the actions to the TableView, so the shortcuts work.
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_add_rows());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_insert_rows());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_delete_rows());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_clone_and_insert());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_clone_and_append());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_copy());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_copy_as_lua_table());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_paste());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_rewrite_selection());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_invert_selection());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_reset_selection());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_resize_columns());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_search());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_sidebar());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_import_tsv());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_export_tsv());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_smart_delete());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_undo());
-    ui.get_mut_ptr_table_view_primary().add_action(ui.get_mut_ptr_context_menu_redo());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_add_rows.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_insert_rows.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_delete_rows.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_move_rows_up.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_move_rows_down.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_clone_and_insert.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_clone_and_edit.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_clone_and_append.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_copy.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_copy_as_lua_table.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_copy_to_row_clipboard.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_paste.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_paste_from_row_clipboard.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_rewrite_selection.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_generate_loc_variants.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_invert_selection.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_reset_selection.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_resize_columns.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_search.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_sidebar.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_history.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_summary_row.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_column_statistics.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_compare_with.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_set_column_unit.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_edit_in_popup.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_word_wrap.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_refresh_reference_data.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_import_tsv.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_export_tsv.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_export_visible_rows.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.smart_delete.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_undo.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_redo.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_zoom_in.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_zoom_out.get_unchecked());
+    ui.table_view_primary.get_unchecked().add_action(ui.context_menu_zoom_reset.get_unchecked());
 }
@@ -33,14 +33,18 @@ use std::sync::atomic::Ordering;
 use std::path::PathBuf;
 
 use rpfm_lib::packedfile::table::Table;
+use rpfm_lib::settings::LAST_PATH_IMPORT_TSV;
 
 use crate::app_ui::AppUI;
 use crate::ffi::*;
 use crate::global_search_ui::GlobalSearchUI;
+use crate::locale::tre;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::utils::set_modified;
 use crate::pack_tree::*;
 use crate::utils::atomic_from_mut_ptr;
+use crate::utils::log_to_status_bar;
+use crate::utils::{save_last_path, setup_file_dialog};
 use crate::utils::show_dialog;
 use crate::UI_STATE;
 
@@ -56,6 +60,11 @@ pub struct TableViewSlots {
     pub filter_line_edit: SlotOfQString<'static>,
     pub filter_column_selector: SlotOfInt<'static>,
     pub filter_case_sensitive_button: Slot<'static>,
+    pub filter_operator_selector: SlotOfInt<'static>,
+    pub filter_builder_button: Slot<'static>,
+    pub filter_preset_selector: SlotOfInt<'static>,
+    pub filter_save_preset_button: Slot<'static>,
+    pub filter_delete_preset_button: Slot<'static>,
     pub toggle_lookups: SlotOfBool<'static>,
     pub sort_order_column_changed: SlotOfIntSortOrder<'static>,
     pub show_context_menu: SlotOfQPoint<'static>,
@@ -64,22 +73,52 @@ pub struct TableViewSlots {
     pub add_rows: Slot<'static>,
     pub insert_rows: Slot<'static>,
     pub delete_rows: Slot<'static>,
+    pub move_rows_up: Slot<'static>,
+    pub move_rows_down: Slot<'static>,
+    pub move_row_from_header: Slot<'static>,
     pub clone_and_append: Slot<'static>,
     pub clone_and_insert: Slot<'static>,
+    pub clone_and_edit: Slot<'static>,
     pub copy: Slot<'static>,
     pub copy_as_lua_table: Slot<'static>,
+    pub copy_to_row_clipboard: Slot<'static>,
     pub paste: Slot<'static>,
+    pub paste_from_row_clipboard: Slot<'static>,
     pub invert_selection: Slot<'static>,
     pub reset_selection: Slot<'static>,
     pub rewrite_selection: Slot<'static>,
+    pub generate_loc_variants: Slot<'static>,
     pub save: Slot<'static>,
     pub undo: Slot<'static>,
     pub redo: Slot<'static>,
     pub import_tsv: SlotOfBool<'static>,
     pub export_tsv: SlotOfBool<'static>,
+    pub export_visible_rows: SlotOfBool<'static>,
     pub smart_delete: Slot<'static>,
     pub resize_columns: Slot<'static>,
     pub sidebar: SlotOfBool<'static>,
+    pub history: SlotOfBool<'static>,
+    pub history_update: Slot<'static>,
+    pub history_selection_changed: SlotOfQItemSelectionQItemSelection<'static>,
+    pub history_jump: Slot<'static>,
+    pub history_jump_double_clicked: SlotOfQModelIndex<'static>,
+    pub summary_row: SlotOfBool<'static>,
+    pub column_statistics: Slot<'static>,
+    pub compare_with: Slot<'static>,
+    pub set_column_unit: Slot<'static>,
+    pub edit_column_description: Slot<'static>,
+    pub show_column_descriptions: SlotOfBool<'static>,
+    pub edit_in_popup: Slot<'static>,
+    pub word_wrap: SlotOfBool<'static>,
+    pub zoom_in: Slot<'static>,
+    pub zoom_out: Slot<'static>,
+    pub zoom_reset: Slot<'static>,
+    pub refresh_reference_data: SlotOfBool<'static>,
+    pub summary_update: Slot<'static>,
+    pub summary_layout_update: Slot<'static>,
+    pub summary_show_context_menu: SlotOfQPoint<'static>,
+    pub pivot: SlotOfBool<'static>,
+    pub pivot_update: Slot<'static>,
     pub search: SlotOfBool<'static>,
     pub hide_show_columns: Vec<SlotOfInt<'static>>,
     pub freeze_columns: Vec<SlotOfInt<'static>>,
@@ -125,6 +164,33 @@ impl TableViewSlots {
             view.filter_table();
         }));
 
+        let filter_operator_selector = SlotOfInt::new(clone!(
+            mut view => move |_| {
+            view.filter_table();
+        }));
+
+        // When we want to edit the extra conditions of the filter.
+        let filter_builder_button = Slot::new(clone!(
+            mut view => move || {
+            view.open_filter_builder();
+        }));
+
+        // When we want to apply, save or delete a filter preset.
+        let filter_preset_selector = SlotOfInt::new(clone!(
+            mut view => move |index| {
+            view.apply_filter_preset(index);
+        }));
+
+        let filter_save_preset_button = Slot::new(clone!(
+            mut view => move || {
+            view.save_filter_preset();
+        }));
+
+        let filter_delete_preset_button = Slot::new(clone!(
+            mut view => move || {
+            view.delete_filter_preset();
+        }));
+
         // When we want to toggle the lookups on and off.
         let toggle_lookups = SlotOfBool::new(clone!(
             view => move |_| {
@@ -203,6 +269,12 @@ impl TableViewSlots {
                     }
                     _ => {}
                 }
+
+                // Keep the description of the "Enum" value in the tooltip. The blocker is so this doesn't retrigger this slot.
+                if let Some(field) = view.get_ref_table_definition().get_fields_processed().get(column as usize) {
+                    let _blocker = QSignalBlocker::from_q_object(view.table_model);
+                    set_enum_tooltip(field, item);
+                }
             }
         ));
 
@@ -257,6 +329,39 @@ impl TableViewSlots {
             }
         ));
 
+        // When you want to move the selected rows up in an ordered table.
+        let move_rows_up = Slot::new(clone!(
+            mut pack_file_contents_ui,
+            mut view => move || {
+            if view.move_rows(true) {
+                if let Some(ref packed_file_path) = view.packed_file_path {
+                    set_modified(true, &packed_file_path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+                }
+            }
+        }));
+
+        // When you want to move the selected rows down in an ordered table.
+        let move_rows_down = Slot::new(clone!(
+            mut pack_file_contents_ui,
+            mut view => move || {
+            if view.move_rows(false) {
+                if let Some(ref packed_file_path) = view.packed_file_path {
+                    set_modified(true, &packed_file_path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+                }
+            }
+        }));
+
+        // When you drag a row by its header in an ordered table.
+        let move_row_from_header = Slot::new(clone!(
+            mut pack_file_contents_ui,
+            mut view => move || {
+            if view.move_row_from_header() {
+                if let Some(ref packed_file_path) = view.packed_file_path {
+                    set_modified(true, &packed_file_path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+                }
+            }
+        }));
+
         // When you want to clone and insert one or more rows.
         let clone_and_append = Slot::new(clone!(
             mut pack_file_contents_ui,
@@ -277,6 +382,17 @@ impl TableViewSlots {
             }
         }));
 
+        // When you want to clone a row and edit its key fields.
+        let clone_and_edit = Slot::new(clone!(
+            mut pack_file_contents_ui,
+            mut view => move || {
+            if view.clone_and_edit_row() {
+                if let Some(ref packed_file_path) = view.packed_file_path {
+                    set_modified(true, &packed_file_path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+                }
+            }
+        }));
+
         // When you want to copy one or more cells.
         let copy = Slot::new(clone!(
             view => move || {
@@ -289,12 +405,39 @@ impl TableViewSlots {
             view.copy_selection_as_lua_table();
         }));
 
+        // When you want to copy one or more rows to paste them in the same table of another PackFile.
+        let copy_to_row_clipboard = Slot::new(clone!(
+            view => move || {
+            match view.copy_rows_to_row_clipboard() {
+                Ok(rows) => log_to_status_bar(&tre("row_clipboard_copied", &[&rows.to_string()])),
+                Err(error) => show_dialog(view.table_view_primary, error, false),
+            }
+        }));
+
         // When you want to copy one or more cells.
         let paste = Slot::new(clone!(
             mut view => move || {
             view.paste();
         }));
 
+        // When you want to paste the rows in the row clipboard at the end of the table.
+        let paste_from_row_clipboard = Slot::new(clone!(
+            mut pack_file_contents_ui,
+            mut view => move || {
+            match view.paste_rows_from_row_clipboard() {
+                Ok(rows) => {
+                    if rows > 0 {
+                        if let Some(ref packed_file_path) = view.packed_file_path {
+                            set_modified(true, &packed_file_path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+                        }
+                        view.context_menu_update();
+                    }
+                    log_to_status_bar(&tre("row_clipboard_pasted", &[&rows.to_string()]));
+                }
+                Err(error) => show_dialog(view.table_view_primary, error, false),
+            }
+        }));
+
         // When we want to invert the selection of the table.
         let invert_selection = Slot::new(clone!(
             mut view => move || {
@@ -321,6 +464,12 @@ impl TableViewSlots {
             view.rewrite_selection();
         }));
 
+        // When we want to generate the plural/gender variants of the selected loc keys.
+        let generate_loc_variants = Slot::new(clone!(
+            mut view => move || {
+            view.generate_loc_variants();
+        }));
+
         // When we want to save the contents of the UI to the backend...
         //
         // NOTE: in-edition saves to backend are only triggered when the GlobalSearch has search data, to keep it updated.
@@ -330,7 +479,9 @@ impl TableViewSlots {
                 if let Some(ref packed_file_path) = view.packed_file_path {
                     if let Some(packed_file) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == *packed_file_path.read().unwrap()) {
                         if let Err(error) = packed_file.save(&mut app_ui, global_search_ui, &mut pack_file_contents_ui) {
-                            show_dialog(view.table_view_primary, error, false);
+                            if view.is_alive() {
+                                show_dialog(view.table_view_primary, error, false);
+                            }
                         }
                     }
                 }
@@ -378,15 +529,21 @@ impl TableViewSlots {
                         view.table_view_primary,
                         &qtr("tsv_select_title"),
                     );
+                    setup_file_dialog(&mut file_dialog, Some(LAST_PATH_IMPORT_TSV));
 
                     file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv)"));
 
                     // Run it and, if we receive 1 (Accept), try to import the TSV file.
-                    if file_dialog.exec() == 1 {
+                    if file_dialog.exec() == 1 && view.is_alive() {
                         let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                        if let Some(folder) = path.parent() { save_last_path(LAST_PATH_IMPORT_TSV, folder); }
 
                         CENTRAL_COMMAND.send_message_qt(Command::ImportTSV((packed_file_path.read().unwrap().to_vec(), path)));
                         let response = CENTRAL_COMMAND.recv_message_qt_try();
+
+                        // The view may have been closed while we were waiting for the response.
+                        if !view.is_alive() { return; }
+
                         match response {
                             Response::TableType(data) => {
                                 let old_data = view.get_copy_of_table();
@@ -441,6 +598,7 @@ impl TableViewSlots {
                         view.table_view_primary,
                         &qtr("tsv_export_title")
                     );
+                    setup_file_dialog(&mut file_dialog, None);
 
                     file_dialog.set_accept_mode(AcceptMode::AcceptSave);
                     file_dialog.set_confirm_overwrite(true);
@@ -448,17 +606,24 @@ impl TableViewSlots {
                     file_dialog.set_default_suffix(&QString::from_std_str("tsv"));
 
                     // Run it and, if we receive 1 (Accept), export the DB Table, saving it's contents first.
-                    if file_dialog.exec() == 1 {
+                    if file_dialog.exec() == 1 && view.is_alive() {
 
                         let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
                         if let Some(packed_file) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == *packed_file_path.read().unwrap()) {
                             if let Err(error) = packed_file.save(&mut app_ui, global_search_ui, &mut pack_file_contents_ui) {
-                                return show_dialog(view.table_view_primary, error, false);
+                                if view.is_alive() {
+                                    show_dialog(view.table_view_primary, error, false);
+                                }
+                                return;
                             }
                         }
 
                         CENTRAL_COMMAND.send_message_qt(Command::ExportTSV((packed_file_path.read().unwrap().to_vec(), path)));
                         let response = CENTRAL_COMMAND.recv_message_qt_try();
+
+                        // The view may have been closed while we were waiting for the response.
+                        if !view.is_alive() { return; }
+
                         match response {
                             Response::Success => return,
                             Response::Error(error) => return show_dialog(view.table_view_primary, error, false),
@@ -469,6 +634,36 @@ impl TableViewSlots {
             }
         ));
 
+        // When we want to export only what's visible in the table, as TSV or JSON.
+        let export_visible_rows = SlotOfBool::new(clone!(
+            view => move |_| {
+
+                // Create a File Chooser to get the destination path and configure it.
+                let mut file_dialog = QFileDialog::from_q_widget_q_string(
+                    view.table_view_primary,
+                    &qtr("export_visible_rows_title")
+                );
+                setup_file_dialog(&mut file_dialog, None);
+
+                file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+                file_dialog.set_confirm_overwrite(true);
+                file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv);;JSON Files (*.json)"));
+                file_dialog.set_default_suffix(&QString::from_std_str("tsv"));
+
+                if file_dialog.exec() == 1 && view.is_alive() {
+                    let mut path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    let as_json = file_dialog.selected_name_filter().to_std_string().starts_with("JSON");
+                    if as_json && path.extension().map_or(true, |x| x != "json") {
+                        path.set_extension("json");
+                    }
+
+                    if let Err(error) = view.export_visible_rows(&path, as_json) {
+                        show_dialog(view.table_view_primary, error, false);
+                    }
+                }
+            }
+        ));
+
         // When we want to resize the columns depending on their contents...
         let resize_columns = Slot::new(clone!(view => move || {
             view.table_view_primary.horizontal_header().resize_sections(ResizeMode::ResizeToContents);
@@ -497,6 +692,165 @@ impl TableViewSlots {
             }
         }));
 
+        // When we want to show/hide the undo history panel.
+        let history = SlotOfBool::new(clone!(
+            mut view => move |_| {
+            match view.history_widget.is_visible() {
+                true => view.history_widget.hide(),
+                false => {
+                    view.history_widget.show();
+                    view.update_history_panel();
+                }
+            }
+        }));
+
+        // When the undo model gets updated, it means the undo history has changed, so reload the history panel.
+        let history_update = Slot::new(clone!(
+            mut view => move || {
+            view.enforce_history_limits();
+            view.update_history_panel();
+        }));
+
+        // When we select an entry in the history panel, explain what jumping to it will do.
+        let history_selection_changed = SlotOfQItemSelectionQItemSelection::new(clone!(
+            mut view => move |_,_| {
+            view.update_history_label();
+        }));
+
+        // When we want to jump to the selected entry of the history panel.
+        let history_jump = Slot::new(clone!(
+            mut pack_file_contents_ui,
+            mut view => move || {
+                if let Some(position) = view.get_selected_history_position() {
+                    if let Some(undo) = view.jump_in_history(position) {
+                        update_undo_model(view.table_model, view.undo_model);
+                        view.context_menu_update();
+                        if let Some(ref packed_file_path) = view.packed_file_path {
+                            if !undo || view.history_undo.read().unwrap().is_empty() {
+                                set_modified(!undo, &packed_file_path.read().unwrap(), &mut app_ui, &mut pack_file_contents_ui);
+                            }
+                        }
+                    }
+                }
+            }
+        ));
+
+        // Double-clicking an entry of the history panel is the same as hitting the jump button.
+        let history_jump_double_clicked = SlotOfQModelIndex::new(clone!(
+            mut view => move |_| {
+            view.history_jump_button.click();
+        }));
+
+        // When we want to see the statistics of the column of the current cell.
+        let column_statistics = Slot::new(clone!(
+            view => move || {
+            view.show_column_statistics();
+        }));
+
+        // When we want to compare the table with its version in another PackFile or in the game files.
+        let compare_with = Slot::new(clone!(
+            view => move || {
+            view.compare_with();
+        }));
+
+        // When we want to change the unit of the column of the current cell.
+        let set_column_unit = Slot::new(clone!(
+            mut view => move || {
+            view.set_column_unit();
+        }));
+
+        // When we want to edit the description of the column of the current cell.
+        let edit_column_description = Slot::new(clone!(
+            mut view => move || {
+            view.edit_column_description();
+        }));
+
+        // When we want to show/hide the descriptions of the columns in their headers.
+        let show_column_descriptions = SlotOfBool::new(clone!(
+            mut view => move |state| {
+            view.toggle_column_descriptions(state);
+        }));
+
+        // When we want to edit the current cell in a multi-line editor.
+        let edit_in_popup = Slot::new(clone!(
+            mut view => move || {
+            view.edit_in_popup();
+        }));
+
+        // When we want to show/hide the summary row.
+        let summary_row = SlotOfBool::new(clone!(
+            mut view => move |_| {
+            match view.summary_view.is_visible() {
+                true => view.summary_view.hide(),
+                false => {
+                    view.summary_view.show();
+                    view.update_summary_row_layout();
+                    view.update_summary_row();
+                }
+            }
+        }));
+
+        // When we want to wrap/unwrap the text of the cells.
+        let word_wrap = SlotOfBool::new(clone!(
+            mut view => move |state| {
+            set_word_wrap(view.table_view_primary, view.table_view_frozen, state);
+        }));
+
+        // When we want to zoom the table in, out, or back to its normal size.
+        let zoom_in = Slot::new(clone!(
+            mut view => move || {
+            view.zoom(Some(1));
+        }));
+
+        let zoom_out = Slot::new(clone!(
+            mut view => move || {
+            view.zoom(Some(-1));
+        }));
+
+        let zoom_reset = Slot::new(clone!(
+            mut view => move || {
+            view.zoom(None);
+        }));
+
+        // When we want to reload the reference data of the table, to get what changed in the referenced tables.
+        let refresh_reference_data = SlotOfBool::new(clone!(
+            view => move |_| {
+            if let Err(error) = view.refresh_dependency_data() {
+                show_dialog(view.table_view_primary, error, false);
+            }
+        }));
+
+        // When the data or the visible rows of the table change, recalculate the summary row.
+        let summary_update = Slot::new(clone!(
+            mut view => move || {
+            view.update_summary_row();
+        }));
+
+        // When the columns of the table get resized, moved, hidden or scrolled, do the same with the summary row.
+        let summary_layout_update = Slot::new(clone!(
+            mut view => move || {
+            view.update_summary_row_layout();
+        }));
+
+        // When we want to change the aggregate of a column of the summary row.
+        let summary_show_context_menu = SlotOfQPoint::new(clone!(
+            mut view => move |pos| {
+            let column = view.summary_view.index_at(pos).column();
+            view.summary_row_context_menu(column);
+        }));
+
+        // When we want to show/hide the pivot tab.
+        let pivot = SlotOfBool::new(clone!(
+            mut view => move |state| {
+            view.toggle_pivot(state);
+        }));
+
+        // When the data or the visible rows of the table change, or we change what to pivot, recalculate the pivot.
+        let pivot_update = Slot::new(clone!(
+            mut view => move || {
+            view.update_pivot();
+        }));
+
         let search = SlotOfBool::new(clone!(
             mut view => move |_| {
             match view.search_widget.is_visible() {
@@ -593,6 +947,10 @@ impl TableViewSlots {
                         &pack_file_contents_ui,
                         table_data
                     ) {
+
+                        // The view may have been closed while the subtable was open.
+                        if !view.is_alive() { return; }
+
                         view.table_filter.set_data_3a(
                             model_index,
                             &QVariant::from_q_string(&QString::from_std_str(new_data)),
@@ -608,6 +966,11 @@ impl TableViewSlots {
             filter_line_edit,
             filter_column_selector,
             filter_case_sensitive_button,
+            filter_operator_selector,
+            filter_builder_button,
+            filter_preset_selector,
+            filter_save_preset_button,
+            filter_delete_preset_button,
             toggle_lookups,
             sort_order_column_changed,
             show_context_menu,
@@ -616,22 +979,52 @@ impl TableViewSlots {
             add_rows,
             insert_rows,
             delete_rows,
+            move_rows_up,
+            move_rows_down,
+            move_row_from_header,
             clone_and_append,
             clone_and_insert,
+            clone_and_edit,
             copy,
             copy_as_lua_table,
+            copy_to_row_clipboard,
             paste,
+            paste_from_row_clipboard,
             invert_selection,
             reset_selection,
             rewrite_selection,
+            generate_loc_variants,
             save,
             undo,
             redo,
             import_tsv,
             export_tsv,
+            export_visible_rows,
             smart_delete,
             resize_columns,
             sidebar,
+            history,
+            history_update,
+            summary_row,
+            column_statistics,
+            compare_with,
+            set_column_unit,
+            edit_column_description,
+            show_column_descriptions,
+            edit_in_popup,
+            word_wrap,
+            zoom_in,
+            zoom_out,
+            zoom_reset,
+            refresh_reference_data,
+            summary_update,
+            summary_layout_update,
+            summary_show_context_menu,
+            pivot,
+            pivot_update,
+            history_selection_changed,
+            history_jump,
+            history_jump_double_clicked,
             search,
             hide_show_columns,
             freeze_columns,
@@ -19,23 +19,44 @@ use super::TableView;
 pub unsafe fn set_tips(ui: &mut TableView) {
 
     // Status Tips for the actions.
-    ui.get_mut_ptr_context_menu_add_rows().set_status_tip(&qtr("Add an empty row at the end of the table."));
-    ui.get_mut_ptr_context_menu_insert_rows().set_status_tip(&qtr("Insert an empty row just above the one selected."));
-    ui.get_mut_ptr_context_menu_delete_rows().set_status_tip(&qtr("Delete all the selected rows."));
+    ui.context_menu_add_rows.get_unchecked().set_status_tip(&qtr("Add an empty row at the end of the table."));
+    ui.context_menu_insert_rows.get_unchecked().set_status_tip(&qtr("Insert an empty row just above the one selected."));
+    ui.context_menu_delete_rows.get_unchecked().set_status_tip(&qtr("Delete all the selected rows."));
+    ui.context_menu_move_rows_up.get_unchecked().set_status_tip(&qtr("tt_context_menu_move_rows_up"));
+    ui.context_menu_move_rows_down.get_unchecked().set_status_tip(&qtr("tt_context_menu_move_rows_down"));
     //ui.get_mut_ptr_context_menu_apply_maths_to_selection().set_status_tip(&qtr("Apply a simple mathematical operation to every cell in the selected cells."));
-    //ui.get_mut_ptr_context_menu_rewrite_selection().set_status_tip(&qtr("Rewrite the selected cells using a pattern."));
-    ui.get_mut_ptr_context_menu_clone_and_append().set_status_tip(&qtr("Duplicate the selected rows and append the new rows at the end of the table."));
-    ui.get_mut_ptr_context_menu_clone_and_insert().set_status_tip(&qtr("Duplicate the selected rows and insert the new rows under the original ones."));
-    ui.get_mut_ptr_context_menu_copy().set_status_tip(&qtr("Copy whatever is selected to the Clipboard."));
-    ui.get_mut_ptr_context_menu_copy_as_lua_table().set_status_tip(&qtr("Turns the entire DB Table into a LUA Table and copies it to the clipboard."));
-    ui.get_mut_ptr_context_menu_paste().set_status_tip(&qtr("Try to paste whatever is in the Clipboard. If the data of a cell is incompatible with the content to paste, the cell is ignored."));
+    //ui.context_menu_rewrite_selection.get_unchecked().set_status_tip(&qtr("Rewrite the selected cells using a pattern."));
+    ui.context_menu_generate_loc_variants.get_unchecked().set_status_tip(&qtr("tt_context_menu_generate_loc_variants"));
+    ui.context_menu_clone_and_append.get_unchecked().set_status_tip(&qtr("Duplicate the selected rows and append the new rows at the end of the table."));
+    ui.context_menu_clone_and_insert.get_unchecked().set_status_tip(&qtr("Duplicate the selected rows and insert the new rows under the original ones."));
+    ui.context_menu_clone_and_edit.get_unchecked().set_status_tip(&qtr("tt_context_menu_clone_and_edit"));
+    ui.context_menu_copy.get_unchecked().set_status_tip(&qtr("Copy whatever is selected to the Clipboard."));
+    ui.context_menu_copy_as_lua_table.get_unchecked().set_status_tip(&qtr("Turns the entire DB Table into a LUA Table and copies it to the clipboard."));
+    ui.context_menu_copy_to_row_clipboard.get_unchecked().set_status_tip(&qtr("tt_context_menu_copy_to_row_clipboard"));
+    ui.context_menu_paste_from_row_clipboard.get_unchecked().set_status_tip(&qtr("tt_context_menu_paste_from_row_clipboard"));
+    ui.context_menu_paste.get_unchecked().set_status_tip(&qtr("Try to paste whatever is in the Clipboard. If the data of a cell is incompatible with the content to paste, the cell is ignored."));
     //ui.get_mut_ptr_context_menu_paste_as_new_lines().set_status_tip(&qtr("Try to paste whatever is in the Clipboard as new lines at the end of the table. Does nothing if the data is not compatible with the cell."));
     //ui.get_mut_ptr_context_menu_paste_to_fill_selection().set_status_tip(&qtr("Try to paste whatever is in the Clipboard in EVERY CELL selected. Does nothing if the data is not compatible with the cell."));
     //ui.get_mut_ptr_context_menu_selection_invert().set_status_tip(&qtr("Inverts the current selection."));
-    //ui.get_mut_ptr_context_menu_search().set_status_tip(&qtr("Search what you want in the table. Also allows you to replace coincidences."));
-    //ui.get_mut_ptr_context_menu_sidebar().set_status_tip(&qtr("Open/Close the sidebar with the controls to hide/show/freeze columns."));
-    ui.get_mut_ptr_context_menu_import_tsv().set_status_tip(&qtr("Import a TSV file into this table, replacing all the data."));
-    ui.get_mut_ptr_context_menu_export_tsv().set_status_tip(&qtr("Export this table's data into a TSV file."));
-    ui.get_mut_ptr_context_menu_undo().set_status_tip(&qtr("A classic."));
-    ui.get_mut_ptr_context_menu_redo().set_status_tip(&qtr("Another classic."));
+    //ui.context_menu_search.get_unchecked().set_status_tip(&qtr("Search what you want in the table. Also allows you to replace coincidences."));
+    //ui.context_menu_sidebar.get_unchecked().set_status_tip(&qtr("Open/Close the sidebar with the controls to hide/show/freeze columns."));
+    ui.context_menu_import_tsv.get_unchecked().set_status_tip(&qtr("Import a TSV file into this table, replacing all the data."));
+    ui.context_menu_export_tsv.get_unchecked().set_status_tip(&qtr("Export this table's data into a TSV file."));
+    ui.context_menu_export_visible_rows.get_unchecked().set_status_tip(&qtr("tt_context_menu_export_visible_rows"));
+    ui.context_menu_history.get_unchecked().set_status_tip(&qtr("tt_context_menu_history"));
+    ui.context_menu_summary_row.get_unchecked().set_status_tip(&qtr("tt_context_menu_summary_row"));
+    ui.context_menu_column_statistics.get_unchecked().set_status_tip(&qtr("tt_context_menu_column_statistics"));
+    ui.context_menu_compare_with.get_unchecked().set_status_tip(&qtr("tt_context_menu_compare_with"));
+    ui.context_menu_pivot.get_unchecked().set_status_tip(&qtr("tt_context_menu_pivot"));
+    ui.context_menu_set_column_unit.get_unchecked().set_status_tip(&qtr("tt_context_menu_set_column_unit"));
+    ui.context_menu_edit_column_description.get_unchecked().set_status_tip(&qtr("tt_context_menu_edit_column_description"));
+    ui.context_menu_show_column_descriptions.get_unchecked().set_status_tip(&qtr("tt_context_menu_show_column_descriptions"));
+    ui.context_menu_edit_in_popup.get_unchecked().set_status_tip(&qtr("tt_context_menu_edit_in_popup"));
+    ui.context_menu_word_wrap.get_unchecked().set_status_tip(&qtr("tt_context_menu_word_wrap"));
+    ui.context_menu_zoom_in.get_unchecked().set_status_tip(&qtr("tt_context_menu_zoom_in"));
+    ui.context_menu_zoom_out.get_unchecked().set_status_tip(&qtr("tt_context_menu_zoom_out"));
+    ui.context_menu_zoom_reset.get_unchecked().set_status_tip(&qtr("tt_context_menu_zoom_reset"));
+    ui.context_menu_refresh_reference_data.get_unchecked().set_status_tip(&qtr("tt_context_menu_refresh_reference_data"));
+    ui.context_menu_undo.get_unchecked().set_status_tip(&qtr("A classic."));
+    ui.context_menu_redo.get_unchecked().set_status_tip(&qtr("Another classic."));
 }
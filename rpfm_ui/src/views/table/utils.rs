@@ -12,12 +12,17 @@
 In this file are all the utility functions we need for the tables to work.
 !*/
 
+use qt_widgets::QAction;
+use qt_widgets::QCheckBox;
+use qt_widgets::QApplication;
 use qt_widgets::QDialog;
 use qt_widgets::QTableView;
 use qt_widgets::q_header_view::ResizeMode;
+use qt_widgets::q_style::PixelMetric;
 
 use qt_gui::QBrush;
 use qt_gui::QColor;
+use qt_gui::QFontMetrics;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
@@ -28,6 +33,7 @@ use qt_core::QSortFilterProxyModel;
 use qt_core::QVariant;
 use qt_core::QObject;
 use qt_core::CheckState;
+use qt_core::ItemDataRole;
 use qt_core::QString;
 use qt_core::Orientation;
 use qt_core::SortOrder;
@@ -39,10 +45,12 @@ use cpp_core::Ref;
 use std::collections::BTreeMap;
 use std::cmp::Ordering;
 use std::sync::RwLock;
-use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::{self, AtomicBool, AtomicPtr};
 
 use rpfm_lib::packedfile::table::Table;
+use rpfm_lib::packfile::RESERVED_NAME_EXTRA_PACKFILE;
 use rpfm_lib::schema::{Definition, Field, FieldType};
+use rpfm_lib::SCHEMA_PATCH;
 use rpfm_lib::SETTINGS;
 
 use crate::DARK_RED;
@@ -51,6 +59,7 @@ use crate::ffi::*;
 use crate::LINK_BLUE;
 use crate::locale::{qtr, tr, tre};
 use crate::MEDIUM_DARK_GREY;
+use crate::ui_state::table_state::TableState;
 use crate::utils::*;
 use crate::UI_STATE;
 use super::*;
@@ -344,6 +353,18 @@ pub fn clean_column_names(field_name: &str) -> String {
     new_name
 }
 
+/// This function returns if the provided path is the one of a table of an extra PackFile, which are read-only.
+pub fn is_path_from_packfile_extra(path: &[String]) -> bool {
+    path.len() > 2 && path[0] == RESERVED_NAME_EXTRA_PACKFILE
+}
+
+/// This function returns the path of a table within its PackFile.
+///
+/// For tables of extra PackFiles, that means removing the prefix with the path of their PackFile.
+pub fn get_path_within_packfile(path: &[String]) -> &[String] {
+    if is_path_from_packfile_extra(path) { &path[2..] } else { path }
+}
+
 /// This function returns the color used for wrong referenced data in tables.
 pub unsafe fn get_color_wrong_key() -> MutPtr<QColor> {
     if SETTINGS.read().unwrap().settings_bool["use_dark_theme"] {
@@ -371,6 +392,15 @@ pub unsafe fn get_color_correct_key() -> MutPtr<QColor> {
     }
 }
 
+/// This function sets the description of the "Enum" value of the provided item as its tooltip, or clears it if the value has no description.
+pub unsafe fn set_enum_tooltip(field: &Field, mut item: MutPtr<QStandardItem>) {
+    if field.get_enum_descriptions().is_empty() { return; }
+    match field.get_enum_description_by_name(&item.text().to_std_string()) {
+        Some(description) => item.set_tool_tip(&QString::from_std_str(description)),
+        None => item.set_tool_tip(&QString::new()),
+    }
+}
+
 /// Function to check if an specific field's data is in their references.
 pub unsafe fn check_references(
     column: i32,
@@ -434,7 +464,6 @@ pub unsafe fn load_data(
     let (data, packed_file_type) = match data {
         TableType::AnimFragment(data) => (data.get_ref_table_data(), PackedFileType::AnimFragment),
         TableType::AnimTable(data) => (data.get_ref_table_data(), PackedFileType::AnimTable),
-        TableType::DependencyManager(data) => (&**data, PackedFileType::DependencyPackFilesList),
         TableType::DB(data) => (data.get_ref_table_data(), PackedFileType::DB),
         TableType::Loc(data) => (data.get_ref_table_data(), PackedFileType::Loc),
         TableType::MatchedCombat(data) => (data.get_ref_table_data(), PackedFileType::MatchedCombat),
@@ -444,10 +473,14 @@ pub unsafe fn load_data(
 
         // Load the data, row by row.
         let mut blocker = QSignalBlocker::from_q_object(table_model.static_upcast_mut::<QObject>());
+        let fields = definition.get_fields_processed();
         for (row, entry) in data.iter().enumerate() {
             let mut qlist = QListOfQStandardItem::new();
             for (index, field) in entry.iter().enumerate() {
                 let mut item = get_item_from_decoded_data(field);
+                if let Some(field) = fields.get(index) {
+                    set_enum_tooltip(field, item.as_mut_ptr());
+                }
 
                 match packed_file_type {
                     PackedFileType::DB => {
@@ -481,11 +514,13 @@ pub unsafe fn load_data(
         table_model.remove_rows_2a(0, 1);
     }
 
+    let table_name = if let TableType::DB(ref table) = data { Some(table.get_table_name()) } else { None };
     setup_item_delegates(
         table_view_primary,
         table_view_frozen,
         definition,
         &dependency_data.read().unwrap(),
+        table_name.as_deref(),
     )
 }
 
@@ -595,7 +630,8 @@ pub unsafe fn build_columns(
     for (index, field) in definition.get_fields_processed().iter().enumerate() {
 
         let name = clean_column_names(&field.get_name());
-        let mut item = QStandardItem::from_q_string(&QString::from_std_str(&name));
+        let mut item = QStandardItem::from_q_string(&QString::from_std_str(&get_column_header_text(field, table_name)));
+        item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(&name)), HEADER_COLUMN_NAME);
         set_column_tooltip(&schema, &field, table_name, &mut item);
         model.set_horizontal_header_item(index as i32, item.into_ptr());
 
@@ -659,6 +695,218 @@ pub unsafe fn build_columns(
     }
 }
 
+/// This function returns the text for the header of the provided column, which is its name followed by the symbol of its unit, if it has one.
+///
+/// If we're showing the column descriptions, its description goes below the name, wrapped so it doesn't widen the column too much.
+pub fn get_column_header_text(field: &Field, table_name: Option<&String>) -> String {
+    let name = clean_column_names(&field.get_name());
+    let text = match table_name.and_then(|table_name| SCHEMA_PATCH.read().unwrap().get_unit(table_name, field.get_name())) {
+        Some(unit) => format!("{} ({})", name, unit.get_symbol()),
+        None => name,
+    };
+
+    if SETTINGS.read().unwrap().settings_bool["tables_show_column_descriptions"] && !field.get_description().is_empty() {
+        format!("{}\n{}", text, wrap_column_description(field.get_description()))
+    } else { text }
+}
+
+/// This function splits the provided description in lines of up to `COLUMN_DESCRIPTION_LINE_LENGTH` characters, without breaking words.
+fn wrap_column_description(description: &str) -> String {
+    let mut lines: Vec<String> = vec![];
+    for word in description.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + word.chars().count() < COLUMN_DESCRIPTION_LINE_LENGTH => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_owned()),
+        }
+    }
+    lines.join("\n")
+}
+
+/// This function returns the columns of the provided definition in the same order they're in the sidebar (CA order).
+pub fn get_columns_in_sidebar_order(definition: &Definition) -> Vec<i32> {
+    let mut fields = definition.get_fields_processed().iter()
+        .enumerate()
+        .map(|(x, y)| (x as i32, y.get_ca_order()))
+        .collect::<Vec<(i32, i16)>>();
+    fields.sort_by(|(_, a), (_, b)| a.cmp(&b));
+    fields.iter().map(|x| x.0).collect()
+}
+
+/// This function saves the state (hidden/frozen columns, widths and word wrap) of the provided table to disk, so it can be restored later.
+///
+/// If the table has no name (for example, subtables) it does nothing.
+pub unsafe fn save_table_state(
+    table_view_primary: MutPtr<QTableView>,
+    freeze_checkboxes: &[MutPtr<QCheckBox>],
+    definition: &Definition,
+    table_name: Option<&String>,
+) {
+    let table_name = match table_name {
+        Some(table_name) => table_name,
+        None => return,
+    };
+
+    let header = table_view_primary.horizontal_header();
+    let fields = definition.get_fields_processed();
+    let mut table_state = TableState::default();
+    table_state.word_wrap = table_view_primary.word_wrap();
+
+    // Filter presets are not part of the view, so we keep the ones we already have.
+    if let Some(old_table_state) = UI_STATE.get_table_state(table_name) {
+        table_state.filter_presets = old_table_state.filter_presets;
+    }
+
+    for (sidebar_index, column) in get_columns_in_sidebar_order(definition).iter().enumerate() {
+        let name = fields[*column as usize].get_name().to_owned();
+        if let Some(checkbox) = freeze_checkboxes.get(sidebar_index) {
+            if checkbox.is_checked() {
+                table_state.frozen_columns.push(name.clone());
+            }
+        }
+
+        if table_view_primary.is_column_hidden(*column) {
+            table_state.hidden_columns.push(name);
+        } else {
+            table_state.column_widths.insert(name, header.section_size(*column));
+        }
+    }
+
+    if let Err(error) = UI_STATE.set_table_state(table_name, table_state) {
+        log_to_status_bar(&error.to_string());
+    }
+}
+
+/// This function restores the state (hidden/frozen columns, widths and word wrap) of the provided table, if we have one saved.
+pub unsafe fn load_table_state(
+    mut table_view_primary: MutPtr<QTableView>,
+    table_view_frozen: MutPtr<QTableView>,
+    mut word_wrap_action: MutPtr<QAction>,
+    hide_show_checkboxes: &[MutPtr<QCheckBox>],
+    freeze_checkboxes: &[MutPtr<QCheckBox>],
+    definition: &Definition,
+    table_name: Option<&String>,
+) {
+    let table_state = match table_name.and_then(|table_name| UI_STATE.get_table_state(table_name)) {
+        Some(table_state) => table_state,
+        None => return,
+    };
+
+    let fields = definition.get_fields_processed();
+    for (sidebar_index, column) in get_columns_in_sidebar_order(definition).iter().enumerate() {
+        let name = fields[*column as usize].get_name();
+        if let Some(width) = table_state.column_widths.get(name) {
+            table_view_primary.set_column_width(*column, *width);
+        }
+
+        // Block the signals of the checkboxes, so we don't save the state while we're still restoring it.
+        if table_state.hidden_columns.iter().any(|x| x == name) {
+            if let Some(checkbox) = hide_show_checkboxes.get(sidebar_index) {
+                let mut checkbox = *checkbox;
+                let _blocker = QSignalBlocker::from_q_object(checkbox.static_upcast_mut::<QObject>());
+                checkbox.set_checked(true);
+            }
+            table_view_primary.set_column_hidden(*column, true);
+        }
+
+        if table_state.frozen_columns.iter().any(|x| x == name) {
+            if let Some(checkbox) = freeze_checkboxes.get(sidebar_index) {
+                let mut checkbox = *checkbox;
+                let _blocker = QSignalBlocker::from_q_object(checkbox.static_upcast_mut::<QObject>());
+                checkbox.set_checked(true);
+            }
+
+            // The frozen view only shows the frozen columns, so skip the ones already frozen, or we'll unfreeze them.
+            if table_view_frozen.is_column_hidden(*column) {
+                toggle_freezer_safe(&mut table_view_primary, *column);
+            }
+        }
+    }
+
+    if table_state.word_wrap {
+        let _blocker = QSignalBlocker::from_q_object(word_wrap_action.static_upcast_mut::<QObject>());
+        word_wrap_action.set_checked(true);
+        set_word_wrap(table_view_primary, table_view_frozen, true);
+    }
+}
+
+/// This function fills the provided combobox with the names of the filter presets saved for the provided table.
+///
+/// The first item is always the "no preset" one. Signals are blocked, so this doesn't trigger any preset.
+pub unsafe fn load_filter_presets(mut preset_selector: MutPtr<QComboBox>, table_name: Option<&String>) {
+    let _blocker = QSignalBlocker::from_q_object(preset_selector.static_upcast_mut::<QObject>());
+    preset_selector.clear();
+    preset_selector.add_item_q_string(&qtr("table_filter_no_preset"));
+    if let Some(table_state) = table_name.and_then(|table_name| UI_STATE.get_table_state(table_name)) {
+        for preset_name in table_state.filter_presets.keys() {
+            preset_selector.add_item_q_string(&QString::from_std_str(preset_name));
+        }
+    }
+}
+
+/// This function enables/disables the word wrap of the cells of the provided table.
+///
+/// When enabled, rows grow to fit their contents (up to a limit, so huge texts don't take the entire view).
+/// When disabled, rows go back to their default height.
+pub unsafe fn set_word_wrap(table_view_primary: MutPtr<QTableView>, table_view_frozen: MutPtr<QTableView>, enable: bool) {
+    let tight_mode = SETTINGS.read().unwrap().settings_bool["tight_table_mode"];
+    for table_view in &[table_view_primary, table_view_frozen] {
+        let mut table_view = *table_view;
+        table_view.set_word_wrap(enable);
+
+        let mut header = table_view.vertical_header();
+        if enable {
+            header.set_maximum_section_size(WORD_WRAP_MAX_ROW_HEIGHT);
+            header.set_section_resize_mode_1a(ResizeMode::ResizeToContents);
+        } else {
+            header.set_section_resize_mode_1a(ResizeMode::Interactive);
+            if tight_mode {
+
+                // In tight mode the min size follows the zoom of the table, so we can reuse it here.
+                let row_height = header.minimum_section_size();
+                header.set_maximum_section_size(row_height);
+            }
+
+            // This resets the height of all the rows to the default one.
+            header.reset();
+        }
+    }
+}
+
+/// This function applies the provided zoom level to the font of the provided tables, resizing their rows to fit the new font.
+pub unsafe fn set_zoom(table_view_primary: MutPtr<QTableView>, table_view_frozen: MutPtr<QTableView>, level: i32) {
+    let tight_mode = SETTINGS.read().unwrap().settings_bool["tight_table_mode"];
+    let base_font = QApplication::font();
+    let font = get_zoomed_font(base_font.as_ref(), level);
+
+    // Rows grow and shrink with the font, starting from their size with the non-zoomed one.
+    let row_height_difference = QFontMetrics::new_1a(&font).height() - QFontMetrics::new_1a(&base_font).height();
+    for table_view in &[table_view_primary, table_view_frozen] {
+        let mut table_view = *table_view;
+        table_view.set_font(&font);
+
+        let mut header = table_view.vertical_header();
+        if tight_mode {
+            let row_height = 22 + row_height_difference;
+            header.set_minimum_section_size(row_height);
+            header.set_default_section_size(row_height);
+            if !table_view.word_wrap() {
+                header.set_maximum_section_size(row_height);
+            }
+        } else {
+            let row_height = table_view.style().pixel_metric_1a(PixelMetric::PMHeaderDefaultSectionSizeVertical) + row_height_difference;
+            header.set_default_section_size(row_height);
+        }
+
+        // This resets the height of all the rows to the default one. With word wrap, rows are resized to their contents instead.
+        if !table_view.word_wrap() {
+            header.reset();
+        }
+    }
+}
+
 /// This function sets the tooltip for the provided column header, if the column should have one.
 pub unsafe fn set_column_tooltip(schema: &Option<Schema>, field: &Field, table_name: Option<&String>, item: &mut QStandardItem) {
 
@@ -750,16 +998,16 @@ pub unsafe fn get_reference_data(definition: &Definition) -> Result<BTreeMap<i32
                     if let View::Table(table) = view {
                         let table = table.get_ref_table();
                         let column = clean_column_names(column);
-                        let table_model = mut_ptr_from_atomic(&table.table_model);
+                        let table_model = table.table_model.get_unchecked();
                         for column_index in 0..table_model.column_count_0a() {
-                            if table_model.header_data_2a(column_index, Orientation::Horizontal).to_string().to_std_string() == column {
+                            if table_model.header_data_3a(column_index, Orientation::Horizontal, HEADER_COLUMN_NAME).to_string().to_std_string() == column {
                                 for row in 0..table_model.row_count_0a() {
                                     let item = table_model.item_2a(row, column_index);
                                     let value = item.text().to_std_string();
                                     let lookup_value = match lookup {
                                         Some(columns) => {
                                             let data: Vec<String> = (0..table_model.column_count_0a()).filter(|x| {
-                                                columns.contains(&table_model.header_data_2a(*x, Orientation::Horizontal).to_string().to_std_string())
+                                                columns.contains(&table_model.header_data_3a(*x, Orientation::Horizontal, HEADER_COLUMN_NAME).to_string().to_std_string())
                                             }).map(|x| table_model.item_2a(row, x).text().to_std_string()).collect();
                                             data.join(" ")
                                         },
@@ -794,15 +1042,74 @@ pub unsafe fn get_reference_data(definition: &Definition) -> Result<BTreeMap<i32
     }
 }
 
+/// This function reloads the dependency data of a table, updating its dropdowns and its error checking with it.
+///
+/// If the view has been closed (`alive` is false) while we were getting the data, nothing is updated.
+pub unsafe fn update_dependency_data(
+    table_view_primary: MutPtr<QTableView>,
+    table_view_frozen: MutPtr<QTableView>,
+    model: MutPtr<QStandardItemModel>,
+    definition: &Definition,
+    dependency_data: &RwLock<BTreeMap<i32, BTreeMap<String, String>>>,
+    packed_file_type: PackedFileType,
+    table_name: Option<&str>,
+    alive: &AtomicBool,
+) -> Result<()> {
+    let data = get_reference_data(definition)?;
+    if !alive.load(atomic::Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    setup_item_delegates(table_view_primary, table_view_frozen, definition, &data, table_name);
+
+    if SETTINGS.read().unwrap().settings_bool["use_dependency_checker"] {
+        check_table_for_errors(model, definition, &data, packed_file_type);
+    } else {
+        clear_table_errors(model, definition, packed_file_type);
+    }
+
+    *dependency_data.write().unwrap() = data;
+    Ok(())
+}
+
+/// This function returns the values used in vanilla for each string column of the provided DB Table, if completing with them is enabled.
+unsafe fn get_vanilla_column_values(table_name: Option<&str>, definition: &Definition) -> BTreeMap<i32, Vec<String>> {
+    match table_name {
+        Some(table_name) if SETTINGS.read().unwrap().settings_bool["tables_complete_with_vanilla_values"] => {
+            CENTRAL_COMMAND.send_message_qt(Command::GetVanillaColumnValues(table_name.to_owned(), definition.clone()));
+            let response = CENTRAL_COMMAND.recv_message_qt();
+            match response {
+                Response::BTreeMapI32VecString(values) => values,
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+        _ => BTreeMap::new(),
+    }
+}
+
 /// This function sets up the item delegates for all columns in a table.
+///
+/// If a table name is provided, string columns also offer as completions the values used in vanilla for them, if enabled.
 pub unsafe fn setup_item_delegates(
     mut table_view_primary: MutPtr<QTableView>,
     mut table_view_frozen: MutPtr<QTableView>,
     definition: &Definition,
-    dependency_data: &BTreeMap<i32, BTreeMap<String, String>>
+    dependency_data: &BTreeMap<i32, BTreeMap<String, String>>,
+    table_name: Option<&str>,
 ) {
+    let vanilla_values = get_vanilla_column_values(table_name, definition);
     let enable_lookups = false; //table_enable_lookups_button.is_checked();
+    let (decimals, use_thousands_separator, show_timestamps_as_dates) = {
+        let settings = SETTINGS.read().unwrap();
+        (
+            settings.settings_string["tables_f32_decimals"].parse::<i32>().unwrap_or(3),
+            settings.settings_bool["tables_use_thousands_separator"],
+            settings.settings_bool["tables_show_timestamps_as_dates"],
+        )
+    };
+
     for (column, field) in definition.get_fields_processed().iter().enumerate() {
+        let is_timestamp = show_timestamps_as_dates && is_timestamp_field(field);
 
         // Combos are a bit special, as they may or may not replace other delegates. If we disable them, use the normal delegates.
         if !SETTINGS.read().unwrap().settings_bool["disable_combos_on_tables"] && dependency_data.get(&(column as i32)).is_some() || !field.get_enum_values().is_empty() {
@@ -823,29 +1130,34 @@ pub unsafe fn setup_item_delegates(
             match field.get_ref_field_type() {
                 FieldType::Boolean => {},
                 FieldType::F32 => {
-                    new_doublespinbox_item_delegate_safe(&mut table_view_primary, column as i32);
-                    new_doublespinbox_item_delegate_safe(&mut table_view_frozen, column as i32);
+                    new_doublespinbox_item_delegate_safe(&mut table_view_primary, column as i32, decimals, use_thousands_separator);
+                    new_doublespinbox_item_delegate_safe(&mut table_view_frozen, column as i32, decimals, use_thousands_separator);
                 },
                 FieldType::I16 => {
-                    new_spinbox_item_delegate_safe(&mut table_view_primary, column as i32, 16);
-                    new_spinbox_item_delegate_safe(&mut table_view_frozen, column as i32, 16);
+                    new_spinbox_item_delegate_safe(&mut table_view_primary, column as i32, 16, use_thousands_separator, false);
+                    new_spinbox_item_delegate_safe(&mut table_view_frozen, column as i32, 16, use_thousands_separator, false);
                 },
                 FieldType::I32 => {
-                    new_spinbox_item_delegate_safe(&mut table_view_primary, column as i32, 32);
-                    new_spinbox_item_delegate_safe(&mut table_view_frozen, column as i32, 32);
+                    new_spinbox_item_delegate_safe(&mut table_view_primary, column as i32, 32, use_thousands_separator, is_timestamp);
+                    new_spinbox_item_delegate_safe(&mut table_view_frozen, column as i32, 32, use_thousands_separator, is_timestamp);
                 },
 
                 // LongInteger uses normal string controls due to QSpinBox being limited to i32.
                 FieldType::I64 => {
-                    new_spinbox_item_delegate_safe(&mut table_view_primary, column as i32, 64);
-                    new_spinbox_item_delegate_safe(&mut table_view_frozen, column as i32, 64);
+                    new_spinbox_item_delegate_safe(&mut table_view_primary, column as i32, 64, use_thousands_separator, is_timestamp);
+                    new_spinbox_item_delegate_safe(&mut table_view_frozen, column as i32, 64, use_thousands_separator, is_timestamp);
                 },
                 FieldType::StringU8 |
                 FieldType::StringU16 |
                 FieldType::OptionalStringU8 |
                 FieldType::OptionalStringU16 => {
-                    new_qstring_item_delegate_safe(&mut table_view_primary, column as i32, field.get_max_length());
-                    new_qstring_item_delegate_safe(&mut table_view_frozen, column as i32, field.get_max_length());
+                    let mut list = QStringList::new();
+                    if let Some(values) = vanilla_values.get(&(column as i32)) {
+                        values.iter().for_each(|x| list.append_q_string(&QString::from_std_str(x)));
+                    }
+
+                    new_qstring_item_delegate_safe(&mut table_view_primary, column as i32, field.get_max_length(), list.as_ptr());
+                    new_qstring_item_delegate_safe(&mut table_view_frozen, column as i32, field.get_max_length(), list.as_ptr());
                 },
                 FieldType::SequenceU16(_) | FieldType::SequenceU32(_) => {}
             }
@@ -853,6 +1165,12 @@ pub unsafe fn setup_item_delegates(
     }
 }
 
+/// This function returns if the provided field looks like it contains timestamps (seconds since the Unix epoch), based on its name.
+fn is_timestamp_field(field: &Field) -> bool {
+    let name = field.get_name().to_lowercase();
+    name.contains("timestamp") || name.ends_with("_date") || name == "date"
+}
+
 /// This function checks an entire table for errors.
 pub unsafe fn check_table_for_errors(
     model: MutPtr<QStandardItemModel>,
@@ -885,6 +1203,27 @@ pub unsafe fn check_table_for_errors(
     }
 }
 
+/// This function removes the colors of the error checking from an entire table.
+pub unsafe fn clear_table_errors(
+    model: MutPtr<QStandardItemModel>,
+    definition: &Definition,
+    packed_file_type: PackedFileType,
+) {
+    let _blocker = QSignalBlocker::from_q_object(model.static_upcast_mut::<QObject>());
+    let columns = match packed_file_type {
+        PackedFileType::DB => definition.get_fields_processed().iter().enumerate().filter(|(_, field)| field.get_is_reference().is_some()).map(|(column, _)| column as i32).collect(),
+        PackedFileType::DependencyPackFilesList => vec![0],
+        _ => vec![],
+    };
+
+    for column in columns {
+        for row in 0..model.row_count_0a() {
+            let mut item = model.item_2a(row, column);
+            item.set_data_2a(&QVariant::new(), ItemDataRole::ForegroundRole.to_int());
+        }
+    }
+}
+
 /// This function is a generic way to toggle the sort order of a column.
 pub unsafe fn sort_column(table_view: MutPtr<QTableView>, column: i32, column_sort_state: Arc<RwLock<(i32, i8)>>) {
     let mut needs_cleaning = false;
@@ -911,44 +1250,48 @@ pub unsafe fn sort_column(table_view: MutPtr<QTableView>, column: i32, column_so
 
 /// This function is used to build a table struct with the data of a TableView and it's definition.
 pub unsafe fn get_table_from_view(model: MutPtr<QStandardItemModel>, definition: &Definition) -> Result<Table> {
-    let mut entries = vec![];
+    let fields = definition.get_fields_processed();
+    let entries = (0..model.row_count_0a())
+        .map(|row| get_decoded_row_from_view(model, &fields, row))
+        .collect::<Vec<Vec<DecodedData>>>();
 
-    for row in 0..model.row_count_0a() {
-        let mut new_row: Vec<DecodedData> = vec![];
+    let mut table = Table::new(definition);
+    table.set_table_data(&entries)?;
+    Ok(table)
+}
 
-        // Bitwise columns can span across multiple columns. That means we have to keep track of the column ourselfs.
-        for (column, field) in definition.get_fields_processed().iter().enumerate() {
+/// This function returns the data of the provided row of a TableView, decoded using the provided (processed) fields.
+pub unsafe fn get_decoded_row_from_view(model: MutPtr<QStandardItemModel>, fields: &[Field], row: i32) -> Vec<DecodedData> {
+    let mut new_row: Vec<DecodedData> = vec![];
 
-            // Create a new Item.
-            let item = match field.get_ref_field_type() {
+    // Bitwise columns can span across multiple columns. That means we have to keep track of the column ourselfs.
+    for (column, field) in fields.iter().enumerate() {
 
-                // This one needs a couple of changes before turning it into an item in the table.
-                FieldType::Boolean => DecodedData::Boolean(model.item_2a(row as i32, column as i32).check_state() == CheckState::Checked),
+        // Create a new Item.
+        let item = match field.get_ref_field_type() {
 
-                // Numbers need parsing, and this can fail.
-                FieldType::F32 => DecodedData::F32(model.item_2a(row as i32, column as i32).data_1a(2).to_float_0a()),
-                FieldType::I16 => DecodedData::I16(model.item_2a(row as i32, column as i32).data_1a(2).to_int_0a() as i16),
-                FieldType::I32 => DecodedData::I32(model.item_2a(row as i32, column as i32).data_1a(2).to_int_0a()),
-                FieldType::I64 => DecodedData::I64(model.item_2a(row as i32, column as i32).data_1a(2).to_long_long_0a()),
+            // This one needs a couple of changes before turning it into an item in the table.
+            FieldType::Boolean => DecodedData::Boolean(model.item_2a(row, column as i32).check_state() == CheckState::Checked),
 
-                // All these are just normal Strings.
-                FieldType::StringU8 => DecodedData::StringU8(QString::to_std_string(&model.item_2a(row as i32, column as i32).text())),
-                FieldType::StringU16 => DecodedData::StringU16(QString::to_std_string(&model.item_2a(row as i32, column as i32).text())),
-                FieldType::OptionalStringU8 => DecodedData::OptionalStringU8(QString::to_std_string(&model.item_2a(row as i32, column as i32).text())),
-                FieldType::OptionalStringU16 => DecodedData::OptionalStringU16(QString::to_std_string(&model.item_2a(row as i32, column as i32).text())),
+            // Numbers need parsing, and this can fail.
+            FieldType::F32 => DecodedData::F32(model.item_2a(row, column as i32).data_1a(2).to_float_0a()),
+            FieldType::I16 => DecodedData::I16(model.item_2a(row, column as i32).data_1a(2).to_int_0a() as i16),
+            FieldType::I32 => DecodedData::I32(model.item_2a(row, column as i32).data_1a(2).to_int_0a()),
+            FieldType::I64 => DecodedData::I64(model.item_2a(row, column as i32).data_1a(2).to_long_long_0a()),
 
-                // Sequences in the UI are not yet supported.
-                FieldType::SequenceU16(_) => DecodedData::SequenceU16(serde_json::from_str(&model.item_2a(row as i32, column as i32).data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string()).unwrap()),
-                FieldType::SequenceU32(_) => DecodedData::SequenceU32(serde_json::from_str(&model.item_2a(row as i32, column as i32).data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string()).unwrap()),
-            };
-            new_row.push(item);
-        }
-        entries.push(new_row);
-    }
+            // All these are just normal Strings.
+            FieldType::StringU8 => DecodedData::StringU8(QString::to_std_string(&model.item_2a(row, column as i32).text())),
+            FieldType::StringU16 => DecodedData::StringU16(QString::to_std_string(&model.item_2a(row, column as i32).text())),
+            FieldType::OptionalStringU8 => DecodedData::OptionalStringU8(QString::to_std_string(&model.item_2a(row, column as i32).text())),
+            FieldType::OptionalStringU16 => DecodedData::OptionalStringU16(QString::to_std_string(&model.item_2a(row, column as i32).text())),
 
-    let mut table = Table::new(definition);
-    table.set_table_data(&entries)?;
-    Ok(table)
+            // Sequences in the UI are not yet supported.
+            FieldType::SequenceU16(_) => DecodedData::SequenceU16(serde_json::from_str(&model.item_2a(row, column as i32).data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string()).unwrap()),
+            FieldType::SequenceU32(_) => DecodedData::SequenceU32(serde_json::from_str(&model.item_2a(row, column as i32).data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string()).unwrap()),
+        };
+        new_row.push(item);
+    }
+    new_row
 }
 
 /// This function creates a new subtable from the current table.
@@ -979,10 +1322,10 @@ pub unsafe fn open_subtable(
     accept_button.released().connect(dialog.slot_accept());
 
     if dialog.exec() == 1 {
-        if let Ok(table) = get_table_from_view(mut_ptr_from_atomic(&table_view.table_model), &table_view.get_ref_table_definition()) {
+        if let Ok(table) = get_table_from_view(table_view.table_model.get_unchecked(), &table_view.get_ref_table_definition()) {
             Some(serde_json::to_string(&table).unwrap())
         } else {
-            show_dialog(mut_ptr_from_atomic(&table_view.table_view_primary), ErrorKind::Generic, false);
+            show_dialog(table_view.table_view_primary.get_unchecked(), ErrorKind::Generic, false);
             None
         }
     } else { None }
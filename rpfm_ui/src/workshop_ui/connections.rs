@@ -0,0 +1,27 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `WorkshopUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `WorkshopUI` and `WorkshopUISlots` structs.
+!*/
+
+use super::{WorkshopUI, slots::WorkshopUISlots};
+
+/// This function connects all the actions from the provided `WorkshopUI` with their slots in `WorkshopUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not polute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &WorkshopUI, slots: &WorkshopUISlots) {
+    ui.open_button.released().connect(&slots.open);
+    ui.copy_button.released().connect(&slots.copy);
+    ui.close_button.released().connect(ui.dialog.slot_close());
+}
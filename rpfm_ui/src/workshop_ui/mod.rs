@@ -0,0 +1,238 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+This module contains the code to build/use the ***Workshop Browser*** UI.
+
+This is a dialog listing the PackFiles of the Workshop items downloaded for the Game Selected, with the names of their mods,
+so they can be opened or copied somewhere else without having to look for them by their numeric ids.
+!*/
+
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionBehavior, SelectionMode};
+use qt_widgets::QDialog;
+use qt_widgets::q_dialog_button_box;
+use qt_widgets::QDialogButtonBox;
+use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
+use qt_widgets::QPushButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::QFlags;
+use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QVariant;
+
+use cpp_core::CastInto;
+use cpp_core::MutPtr;
+
+use chrono::NaiveDateTime;
+
+use std::cell::RefCell;
+use std::fs::copy;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rpfm_error::{ErrorKind, Result};
+use rpfm_lib::common::get_game_selected_data_path;
+use rpfm_lib::games::workshop::get_workshop_items;
+use rpfm_lib::{GAME_SELECTED, SETTINGS, SUPPORTED_GAMES};
+
+use crate::ffi::add_to_q_list_safe;
+use crate::locale::{qtr, tr, tre};
+use crate::utils::{create_grid_layout, Problems, setup_file_dialog, show_dialog};
+use self::slots::WorkshopUISlots;
+
+mod connections;
+mod slots;
+
+/// Role of the first item of each row where we store the path of its PackFile.
+const PACK_FILE_PATH: i32 = 40;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds all the widgets used in the Workshop Browser Window.
+#[derive(Clone)]
+pub struct WorkshopUI {
+    dialog: MutPtr<QDialog>,
+
+    workshop_tree_view: MutPtr<QTreeView>,
+    workshop_tree_model: MutPtr<QStandardItemModel>,
+
+    open_button: MutPtr<QPushButton>,
+    copy_button: MutPtr<QPushButton>,
+    close_button: MutPtr<QPushButton>,
+
+    /// Path of the PackFile the user chose to open, if any.
+    path_to_open: Rc<RefCell<Option<PathBuf>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `WorkshopUI`.
+impl WorkshopUI {
+
+    /// This function creates a ***WorkshopUI*** dialog, executes it, and returns the path of the PackFile to open, if any.
+    pub unsafe fn new(parent: impl CastInto<MutPtr<QWidget>>) -> Option<PathBuf> {
+        let mut ui = Self::new_with_parent(parent);
+        let slots = WorkshopUISlots::new(&ui);
+        connections::set_connections(&ui, &slots);
+        if let Err(error) = ui.load_items() {
+            show_dialog(ui.dialog, error, false);
+            return None;
+        }
+
+        ui.dialog.exec();
+        ui.path_to_open.replace(None)
+    }
+
+    /// This function creates the entire `WorkshopUI` Window.
+    pub unsafe fn new_with_parent(parent: impl CastInto<MutPtr<QWidget>>) -> Self {
+
+        // Create the Workshop Browser Dialog and configure it.
+        let mut dialog = QDialog::new_1a(parent).into_ptr();
+        dialog.set_window_title(&qtr("workshop_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(800, 500);
+
+        // Create the list of Workshop PackFiles.
+        let mut main_grid = create_grid_layout(dialog.static_upcast_mut());
+        let mut workshop_tree_view = QTreeView::new_0a();
+        let mut workshop_tree_model = QStandardItemModel::new_0a();
+        workshop_tree_view.set_model(&mut workshop_tree_model);
+        workshop_tree_view.set_root_is_decorated(false);
+        workshop_tree_view.set_sorting_enabled(true);
+        workshop_tree_view.set_selection_mode(SelectionMode::ExtendedSelection);
+        workshop_tree_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        workshop_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        main_grid.add_widget_5a(&mut workshop_tree_view, 0, 0, 1, 1);
+
+        // Create the bottom buttons and add them to the Dialog.
+        let mut button_box = QDialogButtonBox::new();
+        let mut open_button = button_box.add_button_q_string_button_role(&qtr("workshop_open"), q_dialog_button_box::ButtonRole::ActionRole);
+        let mut copy_button = button_box.add_button_q_string_button_role(&qtr("workshop_copy"), q_dialog_button_box::ButtonRole::ActionRole);
+        let close_button = button_box.add_button_standard_button(q_dialog_button_box::StandardButton::Close);
+        open_button.set_auto_default(false);
+        copy_button.set_auto_default(false);
+        main_grid.add_widget_5a(button_box.into_ptr(), 1, 0, 1, 1);
+
+        Self {
+            dialog,
+            workshop_tree_view: workshop_tree_view.into_ptr(),
+            workshop_tree_model: workshop_tree_model.into_ptr(),
+            open_button,
+            copy_button,
+            close_button,
+            path_to_open: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// This function loads the PackFiles of the Workshop items of the Game Selected into the list, one per row.
+    pub unsafe fn load_items(&mut self) -> Result<()> {
+        let game_selected: &str = &*GAME_SELECTED.read().unwrap();
+        let steam_id = SUPPORTED_GAMES[game_selected].steam_id.ok_or(ErrorKind::WorkshopNotSupportedForThisGame)?;
+        let game_path = SETTINGS.read().unwrap().paths[game_selected].clone().ok_or(ErrorKind::GamePathNotConfigured)?;
+        let items = get_workshop_items(&game_path, steam_id)?;
+
+        self.workshop_tree_model.clear();
+        let mut headers = QStringList::new();
+        headers.append_q_string(&qtr("workshop_name"));
+        headers.append_q_string(&qtr("workshop_packfile"));
+        headers.append_q_string(&qtr("workshop_id"));
+        headers.append_q_string(&qtr("workshop_time_updated"));
+        self.workshop_tree_model.set_horizontal_header_labels(&headers);
+
+        for item in &items {
+            let time_updated = item.time_updated.map(|time| NaiveDateTime::from_timestamp(time, 0).to_string()).unwrap_or_default();
+            for path in &item.pack_file_paths {
+                let mut row = QListOfQStandardItem::new();
+                let mut name = QStandardItem::from_q_string(&QString::from_std_str(&item.name));
+                name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(path.to_string_lossy())), PACK_FILE_PATH);
+                name.set_tool_tip(&QString::from_std_str(path.to_string_lossy()));
+
+                add_to_q_list_safe(row.as_mut_ptr(), name.into_ptr());
+                add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(path.file_name().unwrap().to_string_lossy())).into_ptr());
+                add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&item.id)).into_ptr());
+                add_to_q_list_safe(row.as_mut_ptr(), QStandardItem::from_q_string(&QString::from_std_str(&time_updated)).into_ptr());
+                self.workshop_tree_model.append_row_q_list_of_q_standard_item(&row);
+            }
+        }
+
+        for column in 0..self.workshop_tree_model.column_count_0a() {
+            self.workshop_tree_view.resize_column_to_contents(column);
+        }
+
+        let has_items = !items.is_empty();
+        self.open_button.set_enabled(has_items);
+        self.copy_button.set_enabled(has_items);
+        Ok(())
+    }
+
+    /// This function returns the paths of the PackFiles selected in the list.
+    unsafe fn get_selected_paths(&self) -> Vec<PathBuf> {
+        let indexes = self.workshop_tree_view.selection_model().selected_rows_0a();
+        (0..indexes.count_0a())
+            .map(|index| PathBuf::from(indexes.at(index).data_1a(PACK_FILE_PATH).to_string().to_std_string()))
+            .collect()
+    }
+
+    /// This function marks the selected PackFile to be opened, and closes the dialog.
+    pub unsafe fn open(&mut self) {
+        let paths = self.get_selected_paths();
+        if paths.len() != 1 {
+            return show_dialog(self.dialog, tr("workshop_open_only_one"), false);
+        }
+
+        *self.path_to_open.borrow_mut() = Some(paths[0].to_path_buf());
+        self.dialog.accept();
+    }
+
+    /// This function copies the selected PackFiles to a folder of the user's choice, starting in the `/data` folder of the Game Selected.
+    pub unsafe fn copy(&mut self) {
+        let paths = self.get_selected_paths();
+        if paths.is_empty() {
+            return show_dialog(self.dialog, tr("workshop_none_selected"), false);
+        }
+
+        let mut file_dialog = QFileDialog::from_q_widget_q_string(self.dialog, &qtr("workshop_copy"));
+        setup_file_dialog(&mut file_dialog, None);
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_option_1a(QFileDialogOption::ShowDirsOnly);
+        if let Some(data_path) = get_game_selected_data_path() {
+            file_dialog.set_directory_q_string(&QString::from_std_str(data_path.to_string_lossy()));
+        }
+
+        if file_dialog.exec() == 1 {
+            let destination_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let mut copied_paths = vec![];
+            let mut problems = Problems::default();
+            for path in &paths {
+                let new_path = destination_path.join(path.file_name().unwrap());
+                if copy(path, &new_path).is_err() {
+                    problems.push(&path.to_string_lossy(), ErrorKind::IOGenericCopy(new_path));
+                    continue;
+                }
+                copied_paths.push(format!("<li>{}</li>", new_path.to_string_lossy()));
+            }
+
+            if !copied_paths.is_empty() {
+                show_dialog(self.dialog, tre("workshop_copy_success", &[&copied_paths.join("")]), true);
+            }
+            problems.report(self.dialog);
+        }
+    }
+}
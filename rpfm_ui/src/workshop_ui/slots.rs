@@ -0,0 +1,59 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2020 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to `WorkshopUISlots`.
+!*/
+
+use qt_core::Slot;
+
+use crate::workshop_ui::WorkshopUI;
+use crate::utils::catch_panic_in_slot;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of EVERY widget/action in the `WorkshopUI` struct.
+///
+/// This means everything you can do with the stuff you have in the `WorkshopUI` goes here.
+pub struct WorkshopUISlots {
+    pub open: Slot<'static>,
+    pub copy: Slot<'static>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `WorkshopUISlots`.
+impl WorkshopUISlots {
+
+    /// This function creates a new `WorkshopUISlots`.
+    pub unsafe fn new(ui: &WorkshopUI) -> Self {
+
+        // What happens when we hit the "Open" button.
+        let mut ui_open = ui.clone();
+        let open = Slot::new(move || catch_panic_in_slot(|| {
+            ui_open.open()
+        }));
+
+        // What happens when we hit the "Copy" button.
+        let mut ui_copy = ui.clone();
+        let copy = Slot::new(move || catch_panic_in_slot(|| {
+            ui_copy.copy()
+        }));
+
+        Self {
+            open,
+            copy,
+        }
+    }
+}